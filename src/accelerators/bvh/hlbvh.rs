@@ -4,9 +4,9 @@
 use super::common::*;
 use super::morton::*;
 use crate::core::geometry::*;
+use crate::core::parallel::*;
 use crate::core::pbrt::*;
 use crate::core::primitive::*;
-use rayon::prelude::*;
 use std::cell::RefCell;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
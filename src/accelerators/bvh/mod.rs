@@ -1,11 +1,17 @@
 //! Bounding Volume Hierarchy.
 
 #![allow(dead_code)]
+use crate::core::app::OPTIONS;
 use crate::core::geometry::*;
 use crate::core::light::*;
+use crate::core::logging::BVH;
 use crate::core::material::*;
 use crate::core::paramset::*;
+use crate::core::pbrt::*;
 use crate::core::primitive::*;
+use crate::core::profiler::*;
+use crate::core::scene_cache::*;
+use crate::shapes::CacheKey;
 
 mod common;
 mod hlbvh;
@@ -15,8 +21,17 @@ mod sah;
 pub use common::*;
 use hlbvh::*;
 use sah::*;
+use std::collections::HashMap;
+use std::mem::size_of;
 use std::sync::{Arc, Mutex};
 
+stat_memory_counter!("Memory/BVH tree", TREE_BYTES);
+stat_ratio!("BVH/Primitives per leaf node", PRIMITIVES_PER_LEAF);
+stat_counter!("BVH/Interior nodes", N_INTERIOR_NODES);
+stat_counter!("BVH/Leaf nodes", N_LEAF_NODES);
+stat_int_distribution!("BVH/Leaf node depth", LEAF_DEPTH);
+stat_int_distribution!("BVH/Nodes visited per ray", NODES_VISITED);
+
 /// Bounding Volume Hierarchy Accelerator.
 #[derive(Clone)]
 pub struct BVHAccel {
@@ -55,6 +70,7 @@ impl BVHAccel {
             }
         } else {
             // Build BVH from primitives.
+            let _p = ProfilePhase::new(Prof::AccelConstruction);
 
             // Initializes primitive_info array for primitives.
             let mut primitive_info: Vec<BVHPrimitiveInfo> = primitives
@@ -63,6 +79,40 @@ impl BVHAccel {
                 .map(|(i, p)| BVHPrimitiveInfo::new(i, p.world_bound()))
                 .collect();
 
+            // The tree only depends on the bounds of the primitives so a
+            // cached tree for the same bounds can be used instead.
+            let cache = OPTIONS
+                .scene_cache
+                .as_ref()
+                .filter(|_| n_primitives >= MIN_CACHED_PRIMITIVES)
+                .map(|dir| SceneCache::new(dir));
+            let mut key = CacheKey::default();
+            if let Some(cache) = cache.as_ref() {
+                let bounds: Vec<Float> = primitive_info
+                    .iter()
+                    .flat_map(|info| {
+                        let (p0, p1) = (info.bounds.p_min, info.bounds.p_max);
+                        [p0.x, p0.y, p0.z, p1.x, p1.y, p1.z]
+                    })
+                    .collect();
+                key.add(&bounds);
+                key.add_bytes(&[max_prims_in_node, split_method as u8]);
+
+                match cache.load_bvh(key, n_primitives) {
+                    Ok(Some((order, nodes))) => {
+                        TREE_BYTES.add((nodes.len() * size_of::<LinearBVHNode>()) as i64);
+                        return BVHAccel {
+                            primitives: order.iter().map(|i| primitives[*i].clone()).collect(),
+                            max_prims_in_node,
+                            split_method,
+                            nodes,
+                        };
+                    }
+                    Ok(None) => (),
+                    Err(err) => warn!(target: BVH, "{}", err),
+                }
+            }
+
             // Build BVH tree for primitives using primitive_info.
             let mut total_nodes = 0;
             let ordered_prims =
@@ -91,12 +141,30 @@ impl BVHAccel {
             // Compute representation of depth-first traversal of BVH tree.
             let mut nodes = vec![LinearBVHNode::default(); total_nodes];
             let mut offset = 0_u32;
-            Self::flatten_bvh_tree(root, &mut nodes, &mut offset);
+            Self::flatten_bvh_tree(root, &mut nodes, &mut offset, 0);
 
             debug_assert!(total_nodes == offset as usize);
+            TREE_BYTES.add((total_nodes * size_of::<LinearBVHNode>()) as i64);
 
             let prims = ordered_prims.clone();
             let prims2 = prims.lock().expect("unabled to lock ordered_prims");
+
+            if let Some(cache) = cache.as_ref() {
+                // Recover the index of each primitive from its address.
+                let index: HashMap<*const (), usize> = primitives
+                    .iter()
+                    .enumerate()
+                    .map(|(i, p)| (Arc::as_ptr(p) as *const (), i))
+                    .collect();
+                let order: Vec<usize> = prims2
+                    .iter()
+                    .map(|p| index[&(Arc::as_ptr(p) as *const ())])
+                    .collect();
+                if let Err(err) = cache.store_bvh(key, &order, &nodes) {
+                    warn!(target: BVH, "{}", err);
+                }
+            }
+
             BVHAccel {
                 primitives: prims2.to_vec(),
                 max_prims_in_node,
@@ -106,21 +174,48 @@ impl BVHAccel {
         }
     }
 
+    /// Recomputes the bounds of the nodes after primitives have moved, e.g.
+    /// when the transform of an object instance changes. The tree isn't
+    /// rebuilt so it becomes less efficient the further primitives move.
+    pub fn refit(&mut self) {
+        // Nodes are stored in depth-first order so children come after their
+        // parents.
+        for i in (0..self.nodes.len()).rev() {
+            let node = self.nodes[i];
+            let bounds = if node.n_primitives > 0 {
+                let first = node.offset as usize;
+                self.primitives[first..first + node.n_primitives as usize]
+                    .iter()
+                    .fold(Bounds3f::empty(), |b, p| b.union(&p.world_bound()))
+            } else {
+                self.nodes[i + 1]
+                    .bounds
+                    .union(&self.nodes[node.offset as usize].bounds)
+            };
+            self.nodes[i].bounds = bounds;
+        }
+    }
+
     /// Flatten the tree to the linear representation.
     ///
     /// * `node`   - The node.
     /// * `offset` - Tracks current offset into `BVHAccel::nodes`.
+    /// * `depth`  - Depth of the node in the tree.
     fn flatten_bvh_tree(
         node: Arc<BVHBuildNode>,
         nodes: &mut Vec<LinearBVHNode>,
         offset: &mut u32,
+        depth: i64,
     ) -> u32 {
         let my_offset = *offset;
         *offset += 1;
 
         if node.n_primitives > 0 {
-            debug_assert!(!node.children[0].is_none() && !node.children[1].is_none());
+            debug_assert!(node.children[0].is_none() && node.children[1].is_none());
             debug_assert!(node.n_primitives < 65536);
+            N_LEAF_NODES.inc();
+            LEAF_DEPTH.report_value(depth);
+            PRIMITIVES_PER_LEAF.add(node.n_primitives as i64, 1);
 
             nodes[my_offset as usize] = LinearBVHNode::new_leaf_node(
                 node.bounds,
@@ -129,13 +224,14 @@ impl BVHAccel {
             );
         } else {
             // Create interior flattened BVH nodes.
+            N_INTERIOR_NODES.inc();
             if let Some(child) = node.children[0].clone() {
                 // Ignore first child offset for interior node.
-                Self::flatten_bvh_tree(child, nodes, offset);
+                Self::flatten_bvh_tree(child, nodes, offset, depth + 1);
             }
 
             if let Some(child) = node.children[1].clone() {
-                let second_child_offset = Self::flatten_bvh_tree(child, nodes, offset);
+                let second_child_offset = Self::flatten_bvh_tree(child, nodes, offset, depth + 1);
                 nodes[my_offset as usize] = LinearBVHNode::new_interior_node(
                     node.bounds,
                     second_child_offset as u32,
@@ -154,7 +250,9 @@ impl Aggregate for BVHAccel {}
 impl Primitive for BVHAccel {
     /// Returns a bounding box in the world space.
     fn world_bound(&self) -> Bounds3f {
-        Bounds3f::default()
+        self.nodes
+            .first()
+            .map_or_else(Bounds3f::default, |node| node.bounds)
     }
 
     /// Returns geometric details if a ray intersects the primitive and updates
@@ -175,10 +273,12 @@ impl Primitive for BVHAccel {
             // Follow ray through BVH nodes to find primitive intersections.
             let (mut to_visit_offset, mut current_node_index) = (0, 0);
             let mut nodes_to_visit = [0_usize; 64];
+            let mut nodes_visited = 0;
 
             loop {
                 // Check ray against BVH node
                 let node = &self.nodes[current_node_index];
+                nodes_visited += 1;
                 if node.bounds.intersect_p_inv(r, &inv_dir, dir_is_neg) {
                     if node.n_primitives > 0 {
                         // Intersect ray with primitives in leaf BVH node.
@@ -214,6 +314,7 @@ impl Primitive for BVHAccel {
                     current_node_index = nodes_to_visit[to_visit_offset];
                 }
             }
+            NODES_VISITED.report_value(nodes_visited);
         }
         si
     }
@@ -233,16 +334,19 @@ impl Primitive for BVHAccel {
             // Follow ray through BVH nodes to find primitive intersections.
             let (mut to_visit_offset, mut current_node_index) = (0, 0);
             let mut nodes_to_visit = [0_usize; 64];
+            let mut nodes_visited = 0;
 
             loop {
                 // Check ray against BVH node
                 let node = &self.nodes[current_node_index];
+                nodes_visited += 1;
                 if node.bounds.intersect_p_inv(r, &inv_dir, dir_is_neg) {
                     if node.n_primitives > 0 {
                         // Intersect ray with primitives in leaf BVH node.
                         for i in 0..node.n_primitives {
                             let idx = node.offset as usize + i as usize;
                             if self.primitives[idx].intersect_p(r) {
+                                NODES_VISITED.report_value(nodes_visited);
                                 return true;
                             }
                         }
@@ -272,10 +376,45 @@ impl Primitive for BVHAccel {
                     current_node_index = nodes_to_visit[to_visit_offset];
                 }
             }
+            NODES_VISITED.report_value(nodes_visited);
         }
         false
     }
 
+    /// Returns the vertices of the triangles of all primitives in world
+    /// space.
+    fn triangles(&self) -> Vec<[Point3f; 3]> {
+        self.primitives.iter().flat_map(|p| p.triangles()).collect()
+    }
+
+    /// Visits the aggregate and all primitives.
+    ///
+    /// * `visitor` - The visitor.
+    fn accept(&self, visitor: &mut dyn PrimitiveVisitor) {
+        visitor.visit_aggregate(
+            self.nodes.len(),
+            self.nodes.len() * std::mem::size_of::<LinearBVHNode>(),
+        );
+        for primitive in self.primitives.iter() {
+            primitive.accept(visitor);
+        }
+    }
+
+    /// Returns a copy of the aggregate where the primitives use the material
+    /// `new` wherever they used `old`; or `None` if none of them use `old`.
+    /// The tree is reused.
+    ///
+    /// * `old` - The material to replace.
+    /// * `new` - The new material.
+    fn replace_material(&self, old: &ArcMaterial, new: &ArcMaterial) -> Option<ArcPrimitive> {
+        replace_material(&self.primitives, old, new).map(|primitives| {
+            Arc::new(Self {
+                primitives,
+                ..self.clone()
+            }) as ArcPrimitive
+        })
+    }
+
     /// Returns a reference to the AreaLight that describes the primitive’s
     /// emission distribution, if the primitive is itself a light source.
     /// If the primitive is not emissive, this method should return `None`.  
@@ -284,6 +423,7 @@ impl Primitive for BVHAccel {
     /// get_area_light() on the primitive from the ray-primitive intersection.
     fn get_area_light(&self) -> Option<ArcAreaLight> {
         error!(
+            target: BVH,
             "TransformedPrimitive::get_area_light() shouldn't be called; \
             should've gone to GeometricPrimitive."
         );
@@ -300,6 +440,7 @@ impl Primitive for BVHAccel {
     /// get_material() on the primitive from the ray-primitive intersection.
     fn get_material(&self) -> Option<ArcMaterial> {
         error!(
+            target: BVH,
             "TransformedPrimitive::get_material() shouldn't be called; \
             should've gone to GeometricPrimitive."
         );
@@ -323,6 +464,7 @@ impl Primitive for BVHAccel {
         _allow_multiple_lobes: bool,
     ) {
         error!(
+            target: BVH,
             "TransformedPrimitive::compute_scattering_functions() shouldn't be \
             called; should've gone to GeometricPrimitive."
         );
@@ -342,7 +484,7 @@ impl From<(&ParamSet, &Vec<ArcPrimitive>)> for BVHAccel {
             "middle" => SplitMethod::Middle,
             "equal" => SplitMethod::EqualCounts,
             sm => {
-                warn!("BVH split method '{}' unknown.  Using 'sah'.", sm);
+                warn!(target: BVH, "BVH split method '{}' unknown.  Using 'sah'.", sm);
                 SplitMethod::SAH
             }
         };
@@ -2,7 +2,6 @@
 
 #![allow(dead_code)]
 use crate::core::geometry::*;
-use crate::core::pbrt::*;
 use std::cell::RefCell;
 
 /// Stores Morton codes (interleaved bits of coordinate values).
@@ -40,9 +39,7 @@ pub fn encode_morton_3(v: &Vector3f) -> u32 {
     debug_assert!(v.y > 0.0);
     debug_assert!(v.z > 0.0);
 
-    (left_shift_3(float_to_bits(v.z)) << 2)
-        | (left_shift_3(float_to_bits(v.y)) << 1)
-        | left_shift_3(float_to_bits(v.x))
+    (left_shift_3(v.z as u32) << 2) | (left_shift_3(v.y as u32) << 1) | left_shift_3(v.x as u32)
 }
 
 pub const N_BITS: usize = 30;
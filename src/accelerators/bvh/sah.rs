@@ -256,7 +256,6 @@ impl SAH {
                 if b == N_BUCKETS {
                     b = N_BUCKETS - 1;
                 }
-                debug_assert!(b > 0);
                 debug_assert!(b < N_BUCKETS);
                 buckets[b].count += 1;
                 buckets[b].bounds = buckets[b].bounds.union(&primitive_info[i].bounds);
@@ -294,7 +293,7 @@ impl SAH {
             if n_primitives > max_prims_in_node as usize || min_cost < leaf_cost {
                 // Partition primitives at selected SAH bucket and return the
                 // pivot point as mid.
-                let split = primitive_info[start..end + 1]
+                let split = primitive_info[start..end]
                     .iter_mut()
                     .partition_in_place(|pi| {
                         let mut b = (N_BUCKETS as Float * centroid_bounds.offset(&pi.centroid)[dim])
@@ -302,7 +301,6 @@ impl SAH {
                         if b == N_BUCKETS {
                             b = N_BUCKETS - 1;
                         }
-                        debug_assert!(b > 0);
                         debug_assert!(b < N_BUCKETS);
                         b <= min_cost_split_bucket
                     });
@@ -7,6 +7,7 @@ use crate::core::material::*;
 use crate::core::paramset::*;
 use crate::core::pbrt::*;
 use crate::core::primitive::*;
+use std::sync::Arc;
 
 mod common;
 use common::*;
@@ -526,6 +527,41 @@ impl Primitive for KDTreeAccel {
         false
     }
 
+    /// Returns the vertices of the triangles of all primitives in world
+    /// space.
+    fn triangles(&self) -> Vec<[Point3f; 3]> {
+        self.primitives.iter().flat_map(|p| p.triangles()).collect()
+    }
+
+    /// Visits the aggregate and all primitives.
+    ///
+    /// * `visitor` - The visitor.
+    fn accept(&self, visitor: &mut dyn PrimitiveVisitor) {
+        visitor.visit_aggregate(
+            self.next_free_node as usize,
+            self.nodes.len() * std::mem::size_of::<KdAccelNode>()
+                + self.primitive_indices.len() * std::mem::size_of::<u32>(),
+        );
+        for primitive in self.primitives.iter() {
+            primitive.accept(visitor);
+        }
+    }
+
+    /// Returns a copy of the aggregate where the primitives use the material
+    /// `new` wherever they used `old`; or `None` if none of them use `old`.
+    /// The tree is reused.
+    ///
+    /// * `old` - The material to replace.
+    /// * `new` - The new material.
+    fn replace_material(&self, old: &ArcMaterial, new: &ArcMaterial) -> Option<ArcPrimitive> {
+        replace_material(&self.primitives, old, new).map(|primitives| {
+            Arc::new(Self {
+                primitives,
+                ..self.clone()
+            }) as ArcPrimitive
+        })
+    }
+
     /// Returns a reference to the AreaLight that describes the primitive’s
     /// emission distribution, if the primitive is itself a light source.
     /// If the primitive is not emissive, this method should return `None`.
@@ -66,7 +66,7 @@ impl Camera for EnvironmentCamera {
             Point3f::new(0.0, 0.0, 0.0),
             dir,
             INFINITY,
-            lerp(sample.time, self.data.shutter_open, self.data.shutter_close),
+            self.data.sample_time(sample.time),
             self.data.medium.clone(),
         );
 
@@ -103,12 +103,14 @@ impl From<(&ParamSet, &AnimatedTransform, Arc<Film>, Option<ArcMedium>)> for Env
             swap(&mut shutter_close, &mut shutter_open);
         }
 
-        Self::new(
+        let mut camera = Self::new(
             cam2world.clone(),
             shutter_open,
             shutter_close,
             film.clone(),
             medium.clone(),
-        )
+        );
+        camera.data.shutter_curve = ShutterCurve::from_params(params).map(Arc::new);
+        camera
     }
 }
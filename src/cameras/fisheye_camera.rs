@@ -0,0 +1,286 @@
+//! Fisheye Camera
+
+#![allow(dead_code)]
+use crate::core::camera::*;
+use crate::core::film::*;
+use crate::core::geometry::*;
+use crate::core::medium::*;
+use crate::core::paramset::*;
+use crate::core::pbrt::*;
+use std::mem::swap;
+use std::sync::Arc;
+
+/// Maps the distance of a point in the image circle from its centre to the
+/// angle of the ray from the viewing direction.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum FisheyeProjection {
+    /// Distance proportional to the angle.
+    Equidistant,
+
+    /// Equal areas of the image circle cover equal solid angles.
+    Equisolid,
+
+    /// Conformal projection that preserves the shape of small objects.
+    Stereographic,
+}
+
+/// Fisheye camera that renders the field of view into a circle inscribed in
+/// the film, e.g. dome masters for planetariums. The camera looks down the
+/// z-axis and points outside the circle receive no rays.
+#[derive(Clone)]
+pub struct FisheyeCamera {
+    /// Common camera parameters.
+    pub data: CameraData,
+
+    /// The projection.
+    pub projection: FisheyeProjection,
+
+    /// Angle between the viewing direction and the edge of the image circle
+    /// in radians.
+    pub theta_max: Float,
+
+    /// Centre of the image circle in raster space.
+    pub centre: Point2f,
+
+    /// Radius of the image circle in pixels.
+    pub radius: Float,
+}
+
+impl FisheyeCamera {
+    /// Create a new fisheye camera.
+    ///
+    /// * `camera_to_world` - Animated transformation describing the camera's
+    ///                       motion in the scene.
+    /// * `shutter_open`    - Time when shutter is open.
+    /// * `shutter_close`   - Time when shutter is closed.
+    /// * `projection`      - The projection.
+    /// * `fov`             - The field-of-view angle across the image circle
+    ///                       in degrees.
+    /// * `film`            - The film to capture the rendered image.
+    /// * `medium`          - Scattering medium the camera lies in.
+    pub fn new(
+        camera_to_world: AnimatedTransform,
+        shutter_open: Float,
+        shutter_close: Float,
+        projection: FisheyeProjection,
+        fov: Float,
+        film: Arc<Film>,
+        medium: Option<ArcMedium>,
+    ) -> Self {
+        let res = film.full_resolution;
+        let centre = Point2f::new(0.5 * res.x as Float, 0.5 * res.y as Float);
+        let radius = 0.5 * min(res.x, res.y) as Float;
+
+        Self {
+            data: CameraData::new(
+                camera_to_world,
+                shutter_open,
+                shutter_close,
+                film.clone(),
+                medium.clone(),
+            ),
+            projection,
+            theta_max: 0.5 * fov.to_radians(),
+            centre,
+            radius,
+        }
+    }
+
+    /// Returns the angle from the viewing direction for a distance from the
+    /// centre of the image circle.
+    ///
+    /// * `r` - Distance from the centre relative to the radius of the circle.
+    pub fn theta(&self, r: Float) -> Float {
+        match self.projection {
+            FisheyeProjection::Equidistant => r * self.theta_max,
+            FisheyeProjection::Equisolid => {
+                2.0 * asin(clamp(r * sin(0.5 * self.theta_max), -1.0, 1.0))
+            }
+            FisheyeProjection::Stereographic => 2.0 * atan(r * tan(0.5 * self.theta_max)),
+        }
+    }
+}
+
+impl Camera for FisheyeCamera {
+    /// Returns the common camera data.
+    fn get_data(&self) -> &CameraData {
+        &self.data
+    }
+
+    /// Returns a ray corresponding to a given sample. It also returns, a floating
+    /// point value that affects how much the radiance arriving at the film plane
+    /// will contribute to final image.
+    ///
+    /// * `sample` - The sample.
+    fn generate_ray(&self, sample: &CameraSample) -> (Ray, Float) {
+        let time = self.data.sample_time(sample.time);
+
+        // Compute the position in the image circle; raster y points down.
+        let x = (sample.p_film.x - self.centre.x) / self.radius;
+        let y = (self.centre.y - sample.p_film.y) / self.radius;
+        let r = (x * x + y * y).sqrt();
+        if r > 1.0 {
+            let ray = Ray::new(
+                Point3f::new(0.0, 0.0, 0.0),
+                Vector3f::new(0.0, 0.0, 1.0),
+                INFINITY,
+                time,
+                self.data.medium.clone(),
+            );
+            return (self.data.camera_to_world.transform_ray(&ray), 0.0);
+        }
+
+        // Compute fisheye camera ray direction.
+        let theta = self.theta(r);
+        let phi = atan2(y, x);
+        let dir = Vector3f::new(sin(theta) * cos(phi), sin(theta) * sin(phi), cos(theta));
+
+        let ray = Ray::new(
+            Point3f::new(0.0, 0.0, 0.0),
+            dir,
+            INFINITY,
+            time,
+            self.data.medium.clone(),
+        );
+
+        (self.data.camera_to_world.transform_ray(&ray), 1.0)
+    }
+
+    /// Return the spatial and directional PDFs, as a tuple, for sampling a
+    /// particular ray leaving the camera.
+    ///
+    /// * `ray` - The ray.
+    fn pdf_we(&self, _ray: &Ray) -> PDFResult {
+        panic!("NOT IMPLEMENTED");
+    }
+}
+
+impl From<(&ParamSet, &AnimatedTransform, Arc<Film>, Option<ArcMedium>)> for FisheyeCamera {
+    /// Create a `FisheyeCamera` from given parameter set, animated transform,
+    /// film and medium.
+    ///
+    /// * `p` - A tuple containing  parameter set, animated transform, film and
+    ///         medium.
+    fn from(p: (&ParamSet, &AnimatedTransform, Arc<Film>, Option<ArcMedium>)) -> Self {
+        let (params, cam2world, film, medium) = p;
+
+        // Extract common camera parameters from `ParamSet`
+        let mut shutter_open = params.find_one_float("shutteropen", 0.0);
+        let mut shutter_close = params.find_one_float("shutterclose", 1.0);
+        if shutter_close < shutter_open {
+            warn!(
+                "Shutter close time [{}] < shutter open [{}].
+                Swapping them.",
+                shutter_close, shutter_open
+            );
+            swap(&mut shutter_close, &mut shutter_open);
+        }
+
+        let mapping = params.find_one_string("mapping", String::from("equidistant"));
+        let projection = match mapping.as_str() {
+            "equidistant" => FisheyeProjection::Equidistant,
+            "equisolid" => FisheyeProjection::Equisolid,
+            "stereographic" => FisheyeProjection::Stereographic,
+            _ => {
+                warn!(
+                    "Fisheye mapping '{}' unknown. Using 'equidistant'.",
+                    mapping
+                );
+                FisheyeProjection::Equidistant
+            }
+        };
+
+        // The stereographic projection maps the direction opposite the view
+        // to infinity.
+        let max_fov = if projection == FisheyeProjection::Stereographic {
+            359.0
+        } else {
+            360.0
+        };
+        let mut fov = params.find_one_float("fov", 180.0);
+        if fov <= 0.0 || fov > max_fov {
+            let clamped = clamp(fov, 1.0, max_fov);
+            warn!(
+                "Fisheye fov {} out of range for '{}'. Using {}.",
+                fov, mapping, clamped
+            );
+            fov = clamped;
+        }
+
+        let mut camera = Self::new(
+            cam2world.clone(),
+            shutter_open,
+            shutter_close,
+            projection,
+            fov,
+            film.clone(),
+            medium.clone(),
+        );
+        camera.data.shutter_curve = ShutterCurve::from_params(params).map(Arc::new);
+        camera
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::filter::*;
+    use crate::filters::*;
+    use std::convert::TryFrom;
+
+    fn camera(mapping: &str, fov: Float) -> FisheyeCamera {
+        let mut film_params = ParamSet::new();
+        film_params.add_int("xresolution", &[200]);
+        film_params.add_int("yresolution", &[100]);
+        let filter: ArcFilter = Arc::new(BoxFilter::from(&ParamSet::new()));
+        let film = Arc::new(Film::try_from((&film_params, filter)).unwrap());
+
+        let identity = Arc::new(Transform::default());
+        let cam2world = AnimatedTransform::new(identity.clone(), identity, 0.0, 1.0);
+        let mut params = ParamSet::new();
+        params.add_string("mapping", &[String::from(mapping)]);
+        params.add_float("fov", &[fov]);
+        FisheyeCamera::from((&params, &cam2world, film, None))
+    }
+
+    fn direction(camera: &FisheyeCamera, x: Float, y: Float) -> (Vector3f, Float) {
+        let sample = CameraSample::new(Point2f::new(x, y), Point2f::new(0.5, 0.5), 0.5);
+        let (ray, wt) = camera.generate_ray(&sample);
+        (ray.d, wt)
+    }
+
+    #[test]
+    fn image_circle_covers_the_field_of_view() {
+        for mapping in ["equidistant", "equisolid", "stereographic"].iter() {
+            let camera = camera(mapping, 180.0);
+
+            // The centre looks down the z-axis and the edge of the circle is
+            // perpendicular to it; up in the image is +y.
+            let (d, wt) = direction(&camera, 100.0, 50.0);
+            assert_eq!(wt, 1.0);
+            assert!(d.z > 0.9999);
+            let (d, _) = direction(&camera, 100.0, 0.0);
+            assert!(d.z.abs() < 1e-4 && (d.y - 1.0).abs() < 1e-4);
+            let (d, _) = direction(&camera, 50.0, 50.0);
+            assert!(d.z.abs() < 1e-4 && (d.x + 1.0).abs() < 1e-4);
+
+            // Outside the circle there is nothing.
+            assert_eq!(direction(&camera, 10.0, 50.0).1, 0.0);
+        }
+    }
+
+    #[test]
+    fn projections_differ_between_centre_and_edge() {
+        // Halfway to the edge of a 180 degree fisheye.
+        let angle = |mapping: &str| acos(direction(&camera(mapping, 180.0), 125.0, 50.0).0.z);
+        assert!((angle("equidistant") - PI_OVER_FOUR).abs() < 1e-4);
+        let equisolid = 2.0 * asin(0.5 * sin(PI_OVER_FOUR));
+        assert!((angle("equisolid") - equisolid).abs() < 1e-4);
+        let stereographic = 2.0 * atan(0.5);
+        assert!((angle("stereographic") - stereographic).abs() < 1e-4);
+
+        // Wider fields of view see behind the camera.
+        let (d, _) = direction(&camera("equisolid", 360.0), 100.0, 1.0);
+        assert!(d.z < -0.9);
+    }
+}
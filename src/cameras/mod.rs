@@ -2,12 +2,14 @@
 
 #![allow(dead_code)]
 mod environment_camera;
+mod fisheye_camera;
 mod orthographic_camera;
 mod perspective_camera;
 mod realistic_camera;
 
 // Re-export
 pub use environment_camera::*;
+pub use fisheye_camera::*;
 pub use orthographic_camera::*;
 pub use perspective_camera::*;
 pub use realistic_camera::*;
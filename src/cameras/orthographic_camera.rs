@@ -79,47 +79,58 @@ impl OrthographicCamera {
             dy_camera,
         }
     }
-}
 
-impl Camera for OrthographicCamera {
-    /// Returns the common camera data.
-    fn get_data(&self) -> &CameraData {
-        &self.data
-    }
-
-    /// Returns a ray corresponding to a given sample. It also returns, a floating
-    /// point value that affects how much the radiance arriving at the film plane
-    /// will contribute to final image.
+    /// Returns the camera space ray for a given sample, accounting for depth
+    /// of field, along with the point on the near plane and the offset on the
+    /// lens that were used.
     ///
     /// * `sample` - The sample.
-    fn generate_ray(&self, sample: &CameraSample) -> (Ray, Float) {
+    fn camera_space_ray(&self, sample: &CameraSample) -> (Ray, Point3f, Point2f) {
         // Compute raster and camera sample positions.
         let p_film = Point3f::new(sample.p_film.x, sample.p_film.y, 0.0);
-
         let p_camera = self.proj_data.raster_to_camera.transform_point(&p_film);
 
         let mut ray = Ray::new(
             p_camera,
             Vector3f::new(0.0, 0.0, 1.0),
             INFINITY,
-            lerp(sample.time, self.data.shutter_open, self.data.shutter_close),
+            self.data.sample_time(sample.time),
             self.data.medium.clone(),
         );
 
         // Modify ray for depth of field.
+        let mut p_lens = Point2f::new(0.0, 0.0);
         if self.proj_data.lens_radius > 0.0 {
             // Sample point on lens.
-            let p_lens = self.proj_data.lens_radius * concentric_sample_disk(&sample.p_lens);
+            p_lens = self.proj_data.lens_radius * concentric_sample_disk(&sample.p_lens);
 
             // Compute point on plane of focus.
             let ft = self.proj_data.focal_distance / ray.d.z;
             let p_focus = ray.at(ft);
 
-            // Update ray for effect of lens.
-            ray.o = Point3f::new(p_lens.x, p_lens.y, 0.0);
+            // Update ray for effect of lens. The lens is centred on the ray
+            // of the pixel so that the image isn't tilted towards the axis.
+            ray.o = Point3f::new(p_camera.x + p_lens.x, p_camera.y + p_lens.y, 0.0);
             ray.d = (p_focus - ray.o).normalize();
         }
 
+        (ray, p_camera, p_lens)
+    }
+}
+
+impl Camera for OrthographicCamera {
+    /// Returns the common camera data.
+    fn get_data(&self) -> &CameraData {
+        &self.data
+    }
+
+    /// Returns a ray corresponding to a given sample. It also returns, a floating
+    /// point value that affects how much the radiance arriving at the film plane
+    /// will contribute to final image.
+    ///
+    /// * `sample` - The sample.
+    fn generate_ray(&self, sample: &CameraSample) -> (Ray, Float) {
+        let (ray, _, _) = self.camera_space_ray(sample);
         (self.data.camera_to_world.transform_ray(&ray), 1.0)
     }
 
@@ -131,50 +142,22 @@ impl Camera for OrthographicCamera {
     /// * `sample` - The sample.
     fn generate_ray_differential(&self, sample: &CameraSample) -> (Ray, Float) {
         // Compute main orthographic viewing ray.
-
-        // Compute raster and camera sample positions.
-        let p_film = Point3f::new(sample.p_film.x, sample.p_film.y, 0.0);
-
-        let p_camera = self.proj_data.raster_to_camera.transform_point(&p_film);
-
-        let mut ray = Ray::new(
-            p_camera,
-            Vector3f::new(0.0, 0.0, 1.0),
-            INFINITY,
-            lerp(sample.time, self.data.shutter_open, self.data.shutter_close),
-            self.data.medium.clone(),
-        );
-
-        // Modify ray for depth of field.
-        if self.proj_data.lens_radius > 0.0 {
-            // Sample point on lens.
-            let p_lens = self.proj_data.lens_radius * concentric_sample_disk(&sample.p_lens);
-
-            // Compute point on plane of focus.
-            let ft = self.proj_data.focal_distance / ray.d.z;
-            let p_focus = ray.at(ft);
-
-            // Update ray for effect of lens.
-            ray.o = Point3f::new(p_lens.x, p_lens.y, 0.0);
-            ray.d = (p_focus - ray.o).normalize();
-        }
+        let (mut ray, p_camera, p_lens) = self.camera_space_ray(sample);
 
         // Compute ray differentials for orthographic camera.
         let rd = if self.proj_data.lens_radius > 0.0 {
-            // Compute orthographic camera ray differentials accounting for lens.
-
-            // Sample point on lens.
-            let p_lens = self.proj_data.lens_radius * concentric_sample_disk(&sample.p_lens);
-            let ft = self.proj_data.focal_distance / ray.d.z;
-
-            let p_focus = p_camera + self.dx_camera + (ft * Vector3f::new(0.0, 0.0, 1.0));
-            let rx_origin = Point3f::new(p_lens.x, p_lens.y, 0.0);
-            let rx_direction = (p_focus - rx_origin).normalize();
-
-            let p_focus = p_camera + self.dy_camera + (ft * Vector3f::new(0.0, 0.0, 1.0));
-            let ry_origin = Point3f::new(p_lens.x, p_lens.y, 0.0);
-            let ry_direction = (p_focus - ry_origin).normalize();
-
+            // Compute orthographic camera ray differentials accounting for
+            // lens; the shifted rays pass through the same offset on the lens
+            // and focus on the plane of focus.
+            let ft = self.proj_data.focal_distance;
+            let shifted = |d_camera: Vector3f| {
+                let p = p_camera + d_camera;
+                let p_focus = p + (ft * Vector3f::new(0.0, 0.0, 1.0));
+                let origin = Point3f::new(p.x + p_lens.x, p.y + p_lens.y, 0.0);
+                (origin, (p_focus - origin).normalize())
+            };
+            let (rx_origin, rx_direction) = shifted(self.dx_camera);
+            let (ry_origin, ry_direction) = shifted(self.dy_camera);
             RayDifferential::new(rx_origin, ry_origin, rx_direction, ry_direction)
         } else {
             let rx_origin = ray.o + self.dx_camera;
@@ -226,7 +209,7 @@ impl From<(&ParamSet, &AnimatedTransform, Arc<Film>, Option<ArcMedium>)> for Ort
             film.full_resolution.x as Float / film.full_resolution.y as Float,
         );
         let mut screen = if frame > 1.0 {
-            Bounds2::new(Point2::new(-frame, -1.0), Point2::new(1.0, frame))
+            Bounds2::new(Point2::new(-frame, -1.0), Point2::new(frame, 1.0))
         } else {
             Bounds2::new(
                 Point2::new(-1.0, -1.0 / frame),
@@ -247,7 +230,7 @@ impl From<(&ParamSet, &AnimatedTransform, Arc<Film>, Option<ArcMedium>)> for Ort
             }
         }
 
-        Self::new(
+        let mut camera = Self::new(
             cam2world.clone(),
             screen,
             shutter_open,
@@ -256,6 +239,72 @@ impl From<(&ParamSet, &AnimatedTransform, Arc<Film>, Option<ArcMedium>)> for Ort
             focal_distance,
             film.clone(),
             medium.clone(),
-        )
+        );
+        camera.data.shutter_curve = ShutterCurve::from_params(params).map(Arc::new);
+        camera
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::filter::*;
+    use crate::filters::*;
+    use std::convert::TryFrom;
+
+    fn camera(xres: Int, yres: Int, params: &ParamSet) -> OrthographicCamera {
+        let mut film_params = ParamSet::new();
+        film_params.add_int("xresolution", &[xres]);
+        film_params.add_int("yresolution", &[yres]);
+        let filter: ArcFilter = Arc::new(BoxFilter::from(&ParamSet::new()));
+        let film = Arc::new(Film::try_from((&film_params, filter)).unwrap());
+
+        let identity = Arc::new(Transform::default());
+        let cam2world = AnimatedTransform::new(identity.clone(), identity, 0.0, 1.0);
+        OrthographicCamera::from((params, &cam2world, film, None))
+    }
+
+    fn sample(x: Float, y: Float, u: Float, v: Float) -> CameraSample {
+        CameraSample::new(Point2f::new(x, y), Point2f::new(u, v), 0.5)
+    }
+
+    #[test]
+    fn rays_are_parallel_and_differentials_offset_one_pixel() {
+        let camera = camera(200, 100, &ParamSet::new());
+        let (ray, wt) = camera.generate_ray_differential(&sample(200.0, 0.0, 0.5, 0.5));
+        assert_eq!(wt, 1.0);
+        assert!((ray.o.x - 2.0).abs() < 1e-4 && (ray.o.y - 1.0).abs() < 1e-4);
+        assert_eq!(ray.d, Vector3f::new(0.0, 0.0, 1.0));
+
+        // A pixel spans 4/200 of the screen window and raster y points down.
+        let rd = ray.differentials.unwrap();
+        assert!((rd.rx_origin.x - ray.o.x - 0.02).abs() < 1e-4);
+        assert!((rd.ry_origin.y - ray.o.y + 0.02).abs() < 1e-4);
+        assert_eq!(rd.rx_direction, ray.d);
+    }
+
+    #[test]
+    fn rays_through_the_lens_meet_on_the_plane_of_focus() {
+        let mut params = ParamSet::new();
+        params.add_float("lensradius", &[0.25]);
+        params.add_float("focaldistance", &[5.0]);
+        let camera = camera(100, 100, &params);
+
+        let on_focus_plane = |ray: &Ray| ray.at((5.0 - ray.o.z) / ray.d.z);
+        let p = sample(20.0, 70.0, 0.5, 0.5);
+        let focus = on_focus_plane(&camera.generate_ray(&p).0);
+        assert!((focus.x + 0.6).abs() < 1e-3 && (focus.y + 0.4).abs() < 1e-3);
+        for (u, v) in [(0.1, 0.2), (0.9, 0.5), (0.3, 0.95)].iter() {
+            let (ray, _) = camera.generate_ray_differential(&sample(20.0, 70.0, *u, *v));
+            assert!((on_focus_plane(&ray) - focus).length() < 1e-3);
+
+            // The lens is centred on the ray of the pixel.
+            assert!((Point2f::new(ray.o.x, ray.o.y) - Point2f::new(-0.6, -0.4)).length() <= 0.25);
+
+            let rd = ray.differentials.unwrap();
+            let rx = Ray::new(rd.rx_origin, rd.rx_direction, INFINITY, 0.0, None);
+            let rx_focus = on_focus_plane(&rx);
+            assert!((rx_focus.x - focus.x - 0.02).abs() < 1e-3);
+        }
     }
 }
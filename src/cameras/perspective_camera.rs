@@ -4,10 +4,12 @@
 use crate::core::camera::*;
 use crate::core::film::*;
 use crate::core::geometry::*;
+use crate::core::light::*;
 use crate::core::medium::*;
 use crate::core::paramset::*;
 use crate::core::pbrt::*;
 use crate::core::sampling::*;
+use crate::core::spectrum::*;
 use std::mem::swap;
 use std::sync::Arc;
 
@@ -28,6 +30,11 @@ pub struct PerspectiveCamera {
 
     /// Area covered by the image plane bounds at z=1.
     pub a: Float,
+
+    /// Fraction of the shutter interval taken to read out the image from top
+    /// to bottom with a rolling shutter; each row is exposed for the rest of
+    /// the interval. `None` if all rows are exposed at once.
+    pub rolling_shutter: Option<Float>,
 }
 
 impl PerspectiveCamera {
@@ -106,39 +113,101 @@ impl PerspectiveCamera {
             dx_camera,
             dy_camera,
             a,
+            rolling_shutter: None,
         }
     }
-}
 
-impl Camera for PerspectiveCamera {
-    /// Returns the common camera data.
-    fn get_data(&self) -> &CameraData {
-        &self.data
+    /// Returns the area of the lens; 1 for a pinhole.
+    fn lens_area(&self) -> Float {
+        if self.proj_data.lens_radius != 0.0 {
+            PI * self.proj_data.lens_radius * self.proj_data.lens_radius
+        } else {
+            1.0
+        }
     }
 
-    /// Returns a ray corresponding to a given sample. It also returns, a floating
-    /// point value that affects how much the radiance arriving at the film plane
-    /// will contribute to final image.
+    /// Returns the raster position of the point a ray leaving the lens
+    /// focuses on; `None` if it lies outside the image.
+    ///
+    /// * `camera_to_world` - Camera to world transformation at the time of
+    ///                       the ray.
+    /// * `ray`             - The ray.
+    /// * `cos_theta`       - Cosine of the angle between the ray and the
+    ///                       viewing direction.
+    fn raster_position(
+        &self,
+        camera_to_world: &Transform,
+        ray: &Ray,
+        cos_theta: Float,
+    ) -> Option<Point2f> {
+        // Compute the point the ray focuses on.
+        let t = if self.proj_data.lens_radius > 0.0 {
+            self.proj_data.focal_distance
+        } else {
+            1.0
+        } / cos_theta;
+        let p_focus = ray.at(t);
+
+        let p_camera = camera_to_world.inverse().transform_point(&p_focus);
+        let p_raster = self
+            .proj_data
+            .raster_to_camera
+            .inverse()
+            .transform_point(&p_camera);
+
+        // Return `None` for points outside the image bounds.
+        let sample_bounds = self.data.film.get_sample_bounds();
+        if p_raster.x < sample_bounds.p_min.x as Float
+            || p_raster.x >= sample_bounds.p_max.x as Float
+            || p_raster.y < sample_bounds.p_min.y as Float
+            || p_raster.y >= sample_bounds.p_max.y as Float
+        {
+            None
+        } else {
+            Some(Point2f::new(p_raster.x, p_raster.y))
+        }
+    }
+
+    /// Returns the time at which the ray for a sample is traced. With a
+    /// rolling shutter the exposure of a row starts later the further down
+    /// the image it is.
     ///
     /// * `sample` - The sample.
-    fn generate_ray(&self, sample: &CameraSample) -> (Ray, Float) {
+    fn ray_time(&self, sample: &CameraSample) -> Float {
+        match self.rolling_shutter {
+            Some(duration) => {
+                let rows = self.data.film.full_resolution.y as Float;
+                let row = clamp(sample.p_film.y / rows, 0.0, 1.0);
+                let t = row * duration + self.data.sample_shutter(sample.time) * (1.0 - duration);
+                lerp(t, self.data.shutter_open, self.data.shutter_close)
+            }
+            None => self.data.sample_time(sample.time),
+        }
+    }
+
+    /// Returns the camera space ray for a given sample, accounting for depth
+    /// of field, along with the point on the near plane and the point on the
+    /// lens that were used.
+    ///
+    /// * `sample` - The sample.
+    fn camera_space_ray(&self, sample: &CameraSample) -> (Ray, Point3f, Point2f) {
         // Compute raster and camera sample positions.
         let p_film = Point3f::new(sample.p_film.x, sample.p_film.y, 0.0);
-
         let p_camera = self.proj_data.raster_to_camera.transform_point(&p_film);
 
         let mut ray = Ray::new(
             Point3f::new(0.0, 0.0, 0.0),
             Vector3f::from(p_camera).normalize(),
             INFINITY,
-            lerp(sample.time, self.data.shutter_open, self.data.shutter_close),
+            self.ray_time(sample),
             self.data.medium.clone(),
         );
 
         // Modify ray for depth of field.
+        let mut p_lens = Point2f::new(0.0, 0.0);
         if self.proj_data.lens_radius > 0.0 {
             // Sample point on lens.
-            let p_lens = self.proj_data.lens_radius * concentric_sample_disk(&sample.p_lens);
+            p_lens = self.proj_data.lens_radius * concentric_sample_disk(&sample.p_lens);
 
             // Compute point on plane of focus.
             let ft = self.proj_data.focal_distance / ray.d.z;
@@ -149,6 +218,23 @@ impl Camera for PerspectiveCamera {
             ray.d = (p_focus - ray.o).normalize();
         }
 
+        (ray, p_camera, p_lens)
+    }
+}
+
+impl Camera for PerspectiveCamera {
+    /// Returns the common camera data.
+    fn get_data(&self) -> &CameraData {
+        &self.data
+    }
+
+    /// Returns a ray corresponding to a given sample. It also returns, a floating
+    /// point value that affects how much the radiance arriving at the film plane
+    /// will contribute to final image.
+    ///
+    /// * `sample` - The sample.
+    fn generate_ray(&self, sample: &CameraSample) -> (Ray, Float) {
+        let (ray, _, _) = self.camera_space_ray(sample);
         (self.data.camera_to_world.transform_ray(&ray), 1.0)
     }
 
@@ -160,53 +246,22 @@ impl Camera for PerspectiveCamera {
     /// * `sample` - The sample.
     fn generate_ray_differential(&self, sample: &CameraSample) -> (Ray, Float) {
         // Compute main perspective viewing ray.
-
-        // Compute raster and camera sample positions.
-        let p_film = Point3f::new(sample.p_film.x, sample.p_film.y, 0.0);
-
-        let p_camera = self.proj_data.raster_to_camera.transform_point(&p_film);
-
-        let mut ray = Ray::new(
-            Point3f::new(0.0, 0.0, 0.0),
-            Vector3f::from(p_camera).normalize(),
-            INFINITY,
-            lerp(sample.time, self.data.shutter_open, self.data.shutter_close),
-            self.data.medium.clone(),
-        );
-
-        // Modify ray for depth of field.
-        if self.proj_data.lens_radius > 0.0 {
-            // Sample point on lens.
-            let p_lens = self.proj_data.lens_radius * concentric_sample_disk(&sample.p_lens);
-
-            // Compute point on plane of focus.
-            let ft = self.proj_data.focal_distance / ray.d.z;
-            let p_focus = ray.at(ft);
-
-            // Update ray for effect of lens.
-            ray.o = Point3f::new(p_lens.x, p_lens.y, 0.0);
-            ray.d = (p_focus - ray.o).normalize();
-        }
+        let (mut ray, p_camera, p_lens) = self.camera_space_ray(sample);
 
         // Compute ray differentials for perspective camera.
         let rd = if self.proj_data.lens_radius > 0.0 {
-            // Compute perspective camera camera ray differentials accounting for lens.
-
-            // Sample point on lens.
-            let p_lens = self.proj_data.lens_radius * concentric_sample_disk(&sample.p_lens);
-
-            let dx = Vector3f::from(p_camera + self.dx_camera).normalize();
-            let ft = self.proj_data.focal_distance / dx.z;
-            let p_focus = Point3f::new(0.0, 0.0, 0.0) + (ft * dx);
-            let rx_origin = Point3f::new(p_lens.x, p_lens.y, 0.0);
-            let rx_direction = (p_focus - rx_origin).normalize();
-
-            let dy = Vector3f::from(p_camera + self.dy_camera).normalize();
-            let ft = self.proj_data.focal_distance / dy.z;
-            let p_focus = Point3f::new(0.0, 0.0, 0.0) + (ft * dy);
-            let ry_origin = Point3f::new(p_lens.x, p_lens.y, 0.0);
-            let ry_direction = (p_focus - ry_origin).normalize();
-
+            // Compute perspective camera ray differentials accounting for
+            // lens; the shifted rays pass through the same point on the lens
+            // and focus on the plane of focus.
+            let shifted = |d: Vector3f| {
+                let d = d.normalize();
+                let ft = self.proj_data.focal_distance / d.z;
+                let p_focus = Point3f::new(0.0, 0.0, 0.0) + (ft * d);
+                let origin = Point3f::new(p_lens.x, p_lens.y, 0.0);
+                (origin, (p_focus - origin).normalize())
+            };
+            let (rx_origin, rx_direction) = shifted(Vector3f::from(p_camera + self.dx_camera));
+            let (ry_origin, ry_direction) = shifted(Vector3f::from(p_camera + self.dy_camera));
             RayDifferential::new(rx_origin, ry_origin, rx_direction, ry_direction)
         } else {
             let rx_origin = ray.o;
@@ -220,12 +275,117 @@ impl Camera for PerspectiveCamera {
         (self.data.camera_to_world.transform_ray(&ray), 1.0)
     }
 
+    /// Evaluate the importance emitted from the point on the camera in a
+    /// direction. The `include_raster` is true, then a raster position
+    /// associated with the ray on the film is returned as well.
+    ///
+    /// * `ray`            - The ray.
+    /// * `include_raster` - Indicates whether or not to return the raster
+    ///                      position.
+    fn we(&self, ray: &Ray, include_raster: bool) -> (Spectrum, Option<Point2f>) {
+        // Interpolate camera matrix and check if ω is forward-facing.
+        let camera_to_world = self.data.camera_to_world.interpolate(ray.time);
+        let cos_theta = ray
+            .d
+            .dot(&camera_to_world.transform_vector(&Vector3f::new(0.0, 0.0, 1.0)));
+        if cos_theta <= 0.0 {
+            return (Spectrum::new(0.0), None);
+        }
+
+        // Map ray (p, ω) onto the raster grid.
+        let p_raster = match self.raster_position(&camera_to_world, ray, cos_theta) {
+            Some(p_raster) => p_raster,
+            None => return (Spectrum::new(0.0), None),
+        };
+
+        // Return raster position if requested.
+        let p_raster = if include_raster { Some(p_raster) } else { None };
+
+        // Compute lens area of perspective camera.
+        let lens_area = self.lens_area();
+
+        // Return importance for point on image plane.
+        let cos2_theta = cos_theta * cos_theta;
+        (
+            Spectrum::new(1.0 / (self.a * lens_area * cos2_theta * cos2_theta)),
+            p_raster,
+        )
+    }
+
     /// Return the spatial and directional PDFs, as a tuple, for sampling a
     /// particular ray leaving the camera.
     ///
     /// * `ray` - The ray.
-    fn pdf_we(&self, _ray: &Ray) -> PDFResult {
-        panic!("NOT IMPLEMENTED");
+    fn pdf_we(&self, ray: &Ray) -> PDFResult {
+        // Interpolate camera matrix and fail if ω is not forward-facing.
+        let camera_to_world = self.data.camera_to_world.interpolate(ray.time);
+        let cos_theta = ray
+            .d
+            .dot(&camera_to_world.transform_vector(&Vector3f::new(0.0, 0.0, 1.0)));
+        if cos_theta <= 0.0 {
+            return PDFResult::new(0.0, 0.0);
+        }
+
+        // Map ray (p, ω) onto the raster grid.
+        if self
+            .raster_position(&camera_to_world, ray, cos_theta)
+            .is_none()
+        {
+            return PDFResult::new(0.0, 0.0);
+        }
+
+        // Compute lens area of perspective camera.
+        let lens_area = self.lens_area();
+        PDFResult::new(
+            1.0 / lens_area,
+            1.0 / (self.a * cos_theta * cos_theta * cos_theta),
+        )
+    }
+
+    /// Samples a point on the lens and returns the importance arriving at a
+    /// reference point from it, the direction to the lens, the PDF with
+    /// respect to solid angle at the reference point and the raster position
+    /// the point is seen at. Returns `None` if the point isn't seen by the
+    /// camera.
+    ///
+    /// * `hit` - The reference point.
+    /// * `u`   - Used to sample point on the lens.
+    fn sample_wi(&self, hit: &Hit, u: &Point2f) -> Option<SampleResult> {
+        // Uniformly sample a lens interaction.
+        let p_lens = self.proj_data.lens_radius * concentric_sample_disk(u);
+        let camera_to_world = self.data.camera_to_world.interpolate(hit.time);
+        let p_lens_world = camera_to_world.transform_point(&Point3f::new(p_lens.x, p_lens.y, 0.0));
+        let n = Normal3f::from(
+            camera_to_world
+                .transform_vector(&Vector3f::new(0.0, 0.0, 1.0))
+                .normalize(),
+        );
+        let lens_hit = Hit::new(
+            p_lens_world,
+            hit.time,
+            Vector3f::zero(),
+            Vector3f::zero(),
+            n,
+            self.data.medium.clone().map(MediumInterface::from),
+        );
+
+        // Populate arguments and compute the importance value.
+        let wi = lens_hit.p - hit.p;
+        let dist = wi.length();
+        if dist == 0.0 {
+            return None;
+        }
+        let wi = wi / dist;
+
+        // Compute PDF for importance arriving at the reference point.
+        let pdf = (dist * dist) / (n.abs_dot(&wi) * self.lens_area());
+        let (we, p_raster) = self.we(&lens_hit.spawn_ray(&-wi), true);
+        if we.is_black() || pdf == 0.0 || pdf.is_infinite() {
+            return None;
+        }
+
+        let vis = VisibilityTester::new(hit.clone(), lens_hit.p);
+        Some(SampleResult::new(we, wi, pdf, p_raster, vis))
     }
 }
 
@@ -258,7 +418,7 @@ impl From<(&ParamSet, &AnimatedTransform, Arc<Film>, Option<ArcMedium>)> for Per
             film.full_resolution.x as Float / film.full_resolution.y as Float,
         );
         let mut screen = if frame > 1.0 {
-            Bounds2::new(Point2::new(-frame, -1.0), Point2::new(1.0, frame))
+            Bounds2::new(Point2::new(-frame, -1.0), Point2::new(frame, 1.0))
         } else {
             Bounds2::new(
                 Point2::new(-1.0, -1.0 / frame),
@@ -287,7 +447,7 @@ impl From<(&ParamSet, &AnimatedTransform, Arc<Film>, Option<ArcMedium>)> for Per
             fov = 2.0 * half_fov;
         }
 
-        Self::new(
+        let mut camera = Self::new(
             cam2world.clone(),
             screen,
             shutter_open,
@@ -297,6 +457,169 @@ impl From<(&ParamSet, &AnimatedTransform, Arc<Film>, Option<ArcMedium>)> for Per
             fov,
             film.clone(),
             medium.clone(),
-        )
+        );
+        camera.data.shutter_curve = ShutterCurve::from_params(params).map(Arc::new);
+        if params.find_one_bool("rollingshutter", false) {
+            let duration = params.find_one_float("rollingshutterduration", 0.1);
+            if !(0.0..=1.0).contains(&duration) {
+                warn!(
+                    "'rollingshutterduration' [{}] should be in [0, 1]. Clamping it.",
+                    duration
+                );
+            }
+            camera.rolling_shutter = Some(clamp(duration, 0.0, 1.0));
+        }
+        camera
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::filter::*;
+    use crate::filters::*;
+    use std::convert::TryFrom;
+
+    fn camera(xres: Int, yres: Int, params: &mut ParamSet) -> PerspectiveCamera {
+        let mut film_params = ParamSet::new();
+        film_params.add_int("xresolution", &[xres]);
+        film_params.add_int("yresolution", &[yres]);
+        let filter: ArcFilter = Arc::new(BoxFilter::from(&ParamSet::new()));
+        let film = Arc::new(Film::try_from((&film_params, filter)).unwrap());
+
+        let identity = Arc::new(Transform::default());
+        let cam2world = AnimatedTransform::new(identity.clone(), identity, 0.0, 1.0);
+        params.add_float("fov", &[90.0]);
+        PerspectiveCamera::from((&*params, &cam2world, film, None))
+    }
+
+    fn sample(x: Float, y: Float, u: Float, v: Float) -> CameraSample {
+        CameraSample::new(Point2f::new(x, y), Point2f::new(u, v), 0.5)
+    }
+
+    #[test]
+    fn differentials_span_one_pixel() {
+        let camera = camera(100, 100, &mut ParamSet::new());
+        let (ray, wt) = camera.generate_ray_differential(&sample(50.0, 50.0, 0.5, 0.5));
+        assert_eq!(wt, 1.0);
+        assert!(ray.d.x.abs() < 1e-4 && ray.d.y.abs() < 1e-4 && ray.d.z > 0.9999);
+
+        // A pixel spans 2/100 of the screen window at z=1 and raster y points
+        // down.
+        let rd = ray.differentials.unwrap();
+        assert!((rd.rx_direction.x / rd.rx_direction.z - 0.02).abs() < 1e-4);
+        assert!((rd.ry_direction.y / rd.ry_direction.z + 0.02).abs() < 1e-4);
+        assert!((rd.rx_origin - ray.o).length() < 1e-4);
+    }
+
+    #[test]
+    fn rays_through_the_lens_meet_on_the_plane_of_focus() {
+        let mut params = ParamSet::new();
+        params.add_float("lensradius", &[0.5]);
+        params.add_float("focaldistance", &[5.0]);
+        let camera = camera(100, 100, &mut params);
+
+        let on_focus_plane = |ray: &Ray| ray.at((5.0 - ray.o.z) / ray.d.z);
+        let focus = on_focus_plane(&camera.generate_ray(&sample(20.0, 70.0, 0.5, 0.5)).0);
+        for (u, v) in [(0.1, 0.2), (0.9, 0.5), (0.3, 0.95)].iter() {
+            let (ray, _) = camera.generate_ray_differential(&sample(20.0, 70.0, *u, *v));
+            assert!(ray.o.x != 0.0 || ray.o.y != 0.0);
+            assert!((on_focus_plane(&ray) - focus).length() < 1e-3);
+
+            // The shifted rays start at the same point on the lens.
+            let rd = ray.differentials.unwrap();
+            assert!((rd.rx_origin - ray.o).length() < 1e-4);
+            assert!((rd.ry_origin - ray.o).length() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn fov_spans_the_shorter_image_axis() {
+        let camera = camera(200, 100, &mut ParamSet::new());
+        let (ray, _) = camera.generate_ray(&sample(200.0, 50.0, 0.5, 0.5));
+        assert!((ray.d.x / ray.d.z - 2.0).abs() < 1e-3);
+        let (ray, _) = camera.generate_ray(&sample(100.0, 0.0, 0.5, 0.5));
+        assert!((ray.d.y / ray.d.z - 1.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn importance_maps_rays_to_the_raster_position_they_came_from() {
+        let camera = camera(100, 100, &mut ParamSet::new());
+        let (ray, _) = camera.generate_ray(&sample(30.0, 60.0, 0.5, 0.5));
+        let (we, p_raster) = camera.we(&ray, true);
+        let p_raster = p_raster.unwrap();
+        assert!((p_raster.x - 30.0).abs() < 1e-3 && (p_raster.y - 60.0).abs() < 1e-3);
+
+        // The image plane at z=1 is 2x2 and the lens of a pinhole has area 1.
+        let cos_theta = ray.d.z;
+        let expected = 1.0 / (4.0 * cos_theta.powi(4));
+        assert!((we.max_component_value() - expected).abs() < 1e-3 * expected);
+        let pdf = camera.pdf_we(&ray);
+        assert_eq!(pdf.pos, 1.0);
+        assert!((pdf.dir - 1.0 / (4.0 * cos_theta.powi(3))).abs() < 1e-3);
+
+        // Rays leaving the camera backwards or outside the image carry none.
+        let behind = Ray::new(ray.o, -ray.d, INFINITY, 0.5, None);
+        assert!(camera.we(&behind, true).0.is_black());
+        assert_eq!(camera.pdf_we(&behind).dir, 0.0);
+        let outside = Ray::new(ray.o, Vector3f::new(2.0, 0.0, 1.0), INFINITY, 0.5, None);
+        assert!(camera.we(&outside, false).0.is_black());
+    }
+
+    #[test]
+    fn sample_wi_connects_points_to_the_lens() {
+        let mut params = ParamSet::new();
+        params.add_float("lensradius", &[0.1]);
+        params.add_float("focaldistance", &[5.0]);
+        let camera = camera(100, 100, &mut params);
+
+        let hit = |p: Point3f| {
+            Hit::new(
+                p,
+                0.5,
+                Vector3f::zero(),
+                Vector3f::zero(),
+                Normal3f::zero(),
+                None,
+            )
+        };
+        let p = Point3f::new(0.5, -0.5, 5.0);
+        let sample = camera.sample_wi(&hit(p), &Point2f::new(0.3, 0.8)).unwrap();
+        assert!(!sample.spectrum.is_black() && sample.pdf > 0.0);
+        assert!(((sample.vis.p1 - p).normalize() - sample.wi).length() < 1e-4);
+        assert!(sample.vis.p1.z.abs() < 1e-4);
+
+        // The point is in focus so it is seen where the camera looks at it.
+        let p_raster = sample.p_raster.unwrap();
+        assert!((p_raster.x - 55.0).abs() < 1e-2 && (p_raster.y - 55.0).abs() < 1e-2);
+
+        // Points behind the camera aren't seen.
+        let behind = hit(Point3f::new(0.0, 0.0, -1.0));
+        assert!(camera.sample_wi(&behind, &Point2f::new(0.3, 0.8)).is_none());
+    }
+
+    #[test]
+    fn rolling_shutter_exposes_rows_later_down_the_image() {
+        let mut params = ParamSet::new();
+        params.add_bool("rollingshutter", &[true]);
+        params.add_float("rollingshutterduration", &[0.5]);
+        let rolling = camera(100, 100, &mut params);
+
+        // The top row is exposed over the first half of the interval and the
+        // bottom row over the second.
+        let time = |y: Float, u: Float| {
+            let sample = CameraSample::new(Point2f::new(50.0, y), Point2f::new(0.5, 0.5), u);
+            rolling.generate_ray(&sample).0.time
+        };
+        assert!((time(0.0, 0.0) - 0.0).abs() < 1e-5);
+        assert!((time(0.0, 1.0) - 0.5).abs() < 1e-5);
+        assert!((time(50.0, 0.5) - 0.5).abs() < 1e-5);
+        assert!((time(100.0, 0.0) - 0.5).abs() < 1e-5);
+        assert!((time(100.0, 1.0) - 1.0).abs() < 1e-5);
+
+        // Without it every row is exposed over the whole interval.
+        let camera = camera(100, 100, &mut ParamSet::new());
+        let sample = CameraSample::new(Point2f::new(50.0, 100.0), Point2f::new(0.5, 0.5), 0.25);
+        assert!((camera.generate_ray(&sample).0.time - 0.25).abs() < 1e-5);
     }
 }
@@ -3,15 +3,17 @@
 #![allow(dead_code)]
 use crate::core::camera::*;
 use crate::core::efloat::*;
+use crate::core::error::*;
 use crate::core::film::*;
 use crate::core::geometry::*;
 use crate::core::low_discrepency::*;
 use crate::core::medium::*;
+use crate::core::parallel::*;
 use crate::core::paramset::*;
 use crate::core::parsers::parse_float_file;
 use crate::core::pbrt::*;
 use crate::core::reflection::*;
-use rayon::prelude::*;
+use std::convert::TryFrom;
 use std::mem::swap;
 use std::sync::Arc;
 
@@ -35,7 +37,8 @@ pub struct RealisticCamera {
 }
 
 impl RealisticCamera {
-    /// Create a new realistic camera.
+    /// Create a new realistic camera; or returns an error if the lens system
+    /// cannot be focused.
     ///
     /// * `camera_to_world`   - Animated transformation describing the camera's
     ///                         motion in the scene.
@@ -67,7 +70,7 @@ impl RealisticCamera {
         lens_data: Vec<Float>,
         film: Arc<Film>,
         medium: Option<ArcMedium>,
-    ) -> Self {
+    ) -> Result<Self, Error> {
         let data = CameraData::new(
             camera_to_world,
             shutter_open,
@@ -93,7 +96,7 @@ impl RealisticCamera {
         // Compute lens-film distance for given focus distance
         let n_elements = camera.element_interfaces.len();
         camera.element_interfaces[n_elements - 1].thickness =
-            camera.focus_thick_lens(focus_distance);
+            camera.focus_thick_lens(focus_distance)?;
 
         // Compute exit pupil bounds at sampled points on the film.
         let film_diagonal = film.clone().diagonal;
@@ -116,7 +119,7 @@ impl RealisticCamera {
             );
         }
 
-        camera
+        Ok(camera)
     }
 
     /// Returns the z-depth value of the rear element.
@@ -293,8 +296,9 @@ impl RealisticCamera {
 
     /// Computes both pairs of cardinal points ([pz0, pz1], [fz0, fz1]) for the
     /// lens system where `pz0`, `pz1` are z-depths of the focal point and
-    /// `fz0`, `fz1` are the z-depths of the principal plane.
-    fn compute_thick_lens_approximation(&self) -> ([Float; 2], [Float; 2]) {
+    /// `fz0`, `fz1` are the z-depths of the principal plane; or returns an
+    /// error if rays cannot be traced through the lens system.
+    fn compute_thick_lens_approximation(&self) -> Result<([Float; 2], [Float; 2]), Error> {
         // Find height `x` from optical axis for parallel rays.
         //
         // Use a small fraction of the film's diagonal extent so that the rays
@@ -313,10 +317,10 @@ impl RealisticCamera {
         let (pz0, fz0) = if let Some(r_film) = self.trace_lenses_from_scene(&r_scene) {
             compute_cardinal_points(&r_scene, &r_film)
         } else {
-            panic!(
+            return Err(Error::InvalidParameter(String::from(
                 "Unable to trace ray from scene to film for thick lens \
-                approximation. Is aperture stop extremely small?"
-            );
+                approximation. Is aperture stop extremely small?",
+            )));
         };
 
         // Compute cardinal points for scene side of lens system.
@@ -330,36 +334,38 @@ impl RealisticCamera {
         let (pz1, fz1) = if let Some(r_scene) = self.trace_lenses_from_film(&r_film) {
             compute_cardinal_points(&r_film, &r_scene)
         } else {
-            panic!(
+            return Err(Error::InvalidParameter(String::from(
                 "Unable to trace ray from film to scene for thick lens \
-                approximation. Is aperture stop extremely small?"
-            );
+                approximation. Is aperture stop extremely small?",
+            )));
         };
 
-        ([pz0, pz1], [fz0, fz1])
+        Ok(([pz0, pz1], [fz0, fz1]))
     }
 
     /// Focuses the lens system at a given depth and returns the offset along
-    /// the z-axis from the film where the lens system should be placed.
+    /// the z-axis from the film where the lens system should be placed; or
+    /// returns an error if the lens system cannot focus at that depth.
     ///
     /// * `focus_distance` - Focus distance.
-    fn focus_thick_lens(&self, focus_distance: Float) -> Float {
+    fn focus_thick_lens(&self, focus_distance: Float) -> Result<Float, Error> {
         // Get the cardinal points.
-        let (pz, fz) = self.compute_thick_lens_approximation();
+        let (pz, fz) = self.compute_thick_lens_approximation()?;
 
         // Compute translation of lens `delta` to focus at `focus_distance`.
         let f = fz[0] - pz[0];
         let z = -focus_distance;
         let c = (pz[1] - z - pz[0]) * (pz[1] - z - 4.0 * f - pz[0]);
-        assert!(
-            c > 0.0,
-            "Coefficient must be positive. It looks focus_distance: {} \
-            is too short for a given lenses configuration",
-            focus_distance
-        );
+        if c <= 0.0 {
+            return Err(Error::InvalidParameter(format!(
+                "Coefficient must be positive. It looks focus_distance: {} \
+                is too short for a given lenses configuration",
+                focus_distance
+            )));
+        }
 
         let delta = 0.5 * (pz[1] - z + pz[0] - c.sqrt());
-        self.lens_rear_z() + delta
+        Ok(self.lens_rear_z() + delta)
     }
 
     /// Compute a 2-d bounding box of the exit pupil as seen from a point along
@@ -475,13 +481,17 @@ impl RealisticCamera {
     }
 }
 
-impl From<(&ParamSet, &AnimatedTransform, Arc<Film>, Option<ArcMedium>)> for RealisticCamera {
+impl TryFrom<(&ParamSet, &AnimatedTransform, Arc<Film>, Option<ArcMedium>)> for RealisticCamera {
+    type Error = Error;
+
     /// Create a `RealisticCamera` from given parameter set, animated transform,
     /// film and medium.
     ///
     /// * `p` - A tuple containing  parameter set, animated transform, film and
     ///         medium.
-    fn from(p: (&ParamSet, &AnimatedTransform, Arc<Film>, Option<ArcMedium>)) -> Self {
+    fn try_from(
+        p: (&ParamSet, &AnimatedTransform, Arc<Film>, Option<ArcMedium>),
+    ) -> Result<Self, Self::Error> {
         let (params, cam2world, film, medium) = p;
 
         // Extract common camera parameters from `ParamSet`
@@ -502,28 +512,24 @@ impl From<(&ParamSet, &AnimatedTransform, Arc<Film>, Option<ArcMedium>)> for Rea
         let focus_distance = params.find_one_float("focusdistance", 10.0);
         let simple_weighting = params.find_one_bool("simpleweighting", true);
         if lens_file.len() == 0 {
-            panic!("No lens description file supplied!");
+            return Err(Error::MissingParameter(String::from(
+                "No lens description file supplied!",
+            )));
         }
 
         // Load element data from lens description file
-        let lens_data = match parse_float_file(&lens_file) {
-            Ok(data) => data,
-            Err(err) => panic!(
-                "Error reading lens specification file '{}'. {}.",
-                lens_file, err
-            ),
-        };
+        let lens_data = parse_float_file(&lens_file)?;
 
-        if lens_data.len() % 4 != 0 {
-            panic!(
+        if lens_data.is_empty() || lens_data.len() % 4 != 0 {
+            return Err(Error::Parse(format!(
                 "Excess values in lens specification file '{}'; 
                 must be multiple-of-four values, read {}.",
                 lens_file,
                 lens_data.len()
-            );
+            )));
         }
 
-        Self::new(
+        let mut camera = Self::new(
             cam2world.clone(),
             shutter_open,
             shutter_close,
@@ -533,7 +539,9 @@ impl From<(&ParamSet, &AnimatedTransform, Arc<Film>, Option<ArcMedium>)> for Rea
             lens_data,
             film.clone(),
             medium.clone(),
-        )
+        )?;
+        camera.data.shutter_curve = ShutterCurve::from_params(params).map(Arc::new);
+        Ok(camera)
     }
 }
 
@@ -565,7 +573,7 @@ impl Camera for RealisticCamera {
             p_film,
             p_rear - p_film,
             INFINITY,
-            lerp(sample.time, self.data.shutter_open, self.data.shutter_close),
+            self.data.sample_time(sample.time),
             self.data.medium.clone(),
         );
 
@@ -0,0 +1,359 @@
+//! API Calls
+
+use super::*;
+use crate::core::fileutil::absolute_path;
+use crate::shapes::{CacheKey, ObjMesh};
+use serde::{Deserialize, Serialize};
+
+/// A call to one of the API methods with its arguments. Scene descriptions
+/// can be recorded as the calls made while parsing them and replayed later
+/// without parsing them again. Lists of calls serialize to the JSON scene
+/// format read by `JsonSceneParser`.
+#[derive(Clone, Serialize, Deserialize)]
+pub enum ApiCall {
+    /// `Identity`.
+    Identity,
+
+    /// `Translate dx dy dz`.
+    Translate(Float, Float, Float),
+
+    /// `Transform m00 ... m33`.
+    Transform([Float; 16]),
+
+    /// `ConcatTransform m00 ... m33`.
+    ConcatTransform([Float; 16]),
+
+    /// `Rotate angle x y z`.
+    Rotate(Float, Float, Float, Float),
+
+    /// `Scale sx sy sz`.
+    Scale(Float, Float, Float),
+
+    /// `LookAt ex ey ez lx ly lz ux uy uz`.
+    LookAt([Float; 9]),
+
+    /// `CoordinateSystem "name"`.
+    CoordinateSystem(String),
+
+    /// `CoordSysTransform "name"`.
+    CoordSysTransform(String),
+
+    /// `ActiveTransform All`.
+    ActiveTransformAll,
+
+    /// `ActiveTransform EndTime`.
+    ActiveTransformEndTime,
+
+    /// `ActiveTransform StartTime`.
+    ActiveTransformStartTime,
+
+    /// `TransformTimes start end`.
+    TransformTimes(Float, Float),
+
+    /// `Option params`.
+    Option(ParamSet),
+
+    /// `PixelFilter "name" params`.
+    PixelFilter(String, ParamSet),
+
+    /// `Film "type" params`.
+    Film(String, ParamSet),
+
+    /// `Sampler "name" params`.
+    Sampler(String, ParamSet),
+
+    /// `Accelerator "name" params`.
+    Accelerator(String, ParamSet),
+
+    /// `Integrator "name" params`.
+    Integrator(String, ParamSet),
+
+    /// `Camera "name" params`.
+    Camera(String, ParamSet),
+
+    /// `MakeNamedMedium "name" params`.
+    MakeNamedMedium(String, ParamSet),
+
+    /// `MediumInterface "inside" "outside"`.
+    MediumInterface(String, String),
+
+    /// `WorldBegin`.
+    WorldBegin,
+
+    /// `WorldEnd`.
+    WorldEnd,
+
+    /// `AttributeBegin`.
+    AttributeBegin,
+
+    /// `AttributeEnd`.
+    AttributeEnd,
+
+    /// `TransformBegin`.
+    TransformBegin,
+
+    /// `TransformEnd`.
+    TransformEnd,
+
+    /// `Texture "name" "type" "class" params`.
+    Texture(String, String, String, ParamSet),
+
+    /// `Material "name" params`.
+    Material(String, ParamSet),
+
+    /// `MakeNamedMaterial "name" params`.
+    MakeNamedMaterial(String, ParamSet),
+
+    /// `NamedMaterial "name"`.
+    NamedMaterial(String),
+
+    /// `LightSource "name" params`.
+    LightSource(String, ParamSet),
+
+    /// `AreaLightSource "name" params`.
+    AreaLightSource(String, ParamSet),
+
+    /// `Shape "name" params`.
+    Shape(String, ParamSet),
+
+    /// `ReverseOrientation`.
+    ReverseOrientation,
+
+    /// `ObjectBegin "name"`.
+    ObjectBegin(String),
+
+    /// `ObjectEnd`.
+    ObjectEnd,
+
+    /// `ObjectInstance "name"`.
+    ObjectInstance(String),
+}
+
+impl ApiCall {
+    /// Returns the calls that define the groups of an OBJ file as triangle
+    /// meshes; or an error if the file can't be read. With `"bool usemtl"`
+    /// each group with a known MTL material gets the closest pbrt material
+    /// in its own attribute block; the other groups use the current
+    /// material.
+    ///
+    /// * `params` - Parameters of the `objmesh` shape.
+    pub fn obj_mesh(params: &ParamSet) -> Result<Vec<ApiCall>, Error> {
+        let mesh = ObjMesh::from_params(params)?;
+        let use_mtl = params.find_one_bool("usemtl", false);
+
+        let mut calls = vec![];
+        for group in mesh.groups.iter() {
+            let shape = ApiCall::Shape(String::from("trianglemesh"), group.mesh_params(params));
+            let material = group
+                .material
+                .as_ref()
+                .filter(|_| use_mtl)
+                .and_then(|name| {
+                    let material = mesh.materials.get(name);
+                    if material.is_none() {
+                        warn!("MTL material '{}' not found. Using current material.", name);
+                    }
+                    material
+                });
+
+            match material.map(|m| m.pbrt_material().map(|pm| (m, pm))) {
+                Some(Ok((material, (material_type, material_params)))) => {
+                    calls.push(ApiCall::AttributeBegin);
+                    if let Some((texture, texture_params)) = material.diffuse_texture() {
+                        calls.push(ApiCall::Texture(
+                            texture,
+                            String::from("spectrum"),
+                            String::from("imagemap"),
+                            texture_params,
+                        ));
+                    }
+                    calls.push(ApiCall::Material(material_type, material_params));
+                    calls.push(shape);
+                    calls.push(ApiCall::AttributeEnd);
+                }
+                Some(Err(err)) => error!("{}", err),
+                None => calls.push(shape),
+            }
+        }
+        Ok(calls)
+    }
+}
+
+/// The shapes created for a `Shape` call with the name of their type; `None`
+/// if creating them failed or they can't be restored.
+pub type RecordedShapes = (String, Option<Vec<ArcShape>>);
+
+/// The API calls made for a scene description, the files it was read from
+/// and the shapes created.
+#[derive(Clone, Default)]
+pub struct SceneRecording {
+    /// Absolute paths of the files read and keys of their contents.
+    pub files: Vec<(String, CacheKey)>,
+
+    /// The API calls in the order they were made.
+    pub calls: Vec<ApiCall>,
+
+    /// The shapes created in the order they were created. Replaying the
+    /// calls uses them instead of creating the shapes again.
+    pub shapes: Vec<RecordedShapes>,
+}
+
+impl SceneRecording {
+    /// Returns the key of the contents of a scene file.
+    ///
+    /// * `source` - Contents of the file.
+    pub fn source_key(source: &str) -> CacheKey {
+        let mut key = CacheKey::default();
+        key.add_bytes(source.as_bytes());
+        key
+    }
+}
+
+impl Api {
+    /// Calls the API method for a call and records the call if recording is
+    /// on.
+    ///
+    /// * `call` - The API call.
+    pub fn call(&mut self, call: ApiCall) {
+        self.dispatch(&call);
+
+        if let Some(recording) = self.recording.as_mut() {
+            recording.calls.push(call);
+        }
+    }
+
+    /// Makes the calls of a recording. The shapes it holds are used instead
+    /// of creating them again; shapes that weren't restored are created.
+    ///
+    /// * `recording` - The recording.
+    pub fn replay(&mut self, recording: SceneRecording) {
+        self.restored_shapes = Some(recording.shapes.into());
+        for call in recording.calls {
+            self.call(call);
+        }
+        self.restored_shapes = None;
+    }
+
+    /// Calls the API method for a call without recording it.
+    ///
+    /// * `call` - The API call.
+    pub(super) fn dispatch(&mut self, call: &ApiCall) {
+        match call {
+            ApiCall::Identity => self.pbrt_identity(),
+            ApiCall::Translate(dx, dy, dz) => self.pbrt_translate(*dx, *dy, *dz),
+            ApiCall::Transform(tr) => self.pbrt_transform(tr),
+            ApiCall::ConcatTransform(tr) => self.pbrt_concat_transform(tr),
+            ApiCall::Rotate(angle, dx, dy, dz) => self.pbrt_rotate(*angle, *dx, *dy, *dz),
+            ApiCall::Scale(sx, sy, sz) => self.pbrt_scale(*sx, *sy, *sz),
+            ApiCall::LookAt([ex, ey, ez, lx, ly, lz, ux, uy, uz]) => {
+                self.pbrt_look_at(*ex, *ey, *ez, *lx, *ly, *lz, *ux, *uy, *uz)
+            }
+            ApiCall::CoordinateSystem(name) => self.pbrt_coordinate_system(name.clone()),
+            ApiCall::CoordSysTransform(name) => self.pbrt_coord_sys_transform(name.clone()),
+            ApiCall::ActiveTransformAll => self.pbrt_active_transform_all(),
+            ApiCall::ActiveTransformEndTime => self.pbrt_active_transform_end_time(),
+            ApiCall::ActiveTransformStartTime => self.pbrt_active_transform_start_time(),
+            ApiCall::TransformTimes(start, end) => self.pbrt_transform_times(*start, *end),
+            ApiCall::Option(params) => self.pbrt_option(params),
+            ApiCall::PixelFilter(name, params) => self.pbrt_pixel_filter(name.clone(), params),
+            ApiCall::Film(name, params) => self.pbrt_film(name.clone(), params),
+            ApiCall::Sampler(name, params) => self.pbrt_sampler(name.clone(), params),
+            ApiCall::Accelerator(name, params) => self.pbrt_accelerator(name.clone(), params),
+            ApiCall::Integrator(name, params) => self.pbrt_integrator(name.clone(), params),
+            ApiCall::Camera(name, params) => self.pbrt_camera(name.clone(), params),
+            ApiCall::MakeNamedMedium(name, params) => {
+                self.pbrt_make_named_medium(name.clone(), params)
+            }
+            ApiCall::MediumInterface(inside, outside) => {
+                self.pbrt_medium_interface(inside.clone(), outside.clone())
+            }
+            ApiCall::WorldBegin => self.pbrt_world_begin(),
+            ApiCall::WorldEnd => self.pbrt_world_end(),
+            ApiCall::AttributeBegin => self.pbrt_attribute_begin(),
+            ApiCall::AttributeEnd => self.pbrt_attribute_end(),
+            ApiCall::TransformBegin => self.pbrt_transform_begin(),
+            ApiCall::TransformEnd => self.pbrt_transform_end(),
+            ApiCall::Texture(name, texture_type, tex_name, params) => {
+                self.pbrt_texture(name.clone(), texture_type.clone(), tex_name.clone(), params)
+            }
+            ApiCall::Material(name, params) => self.pbrt_material(name.clone(), params),
+            ApiCall::MakeNamedMaterial(name, params) => {
+                self.pbrt_make_named_material(name.clone(), params)
+            }
+            ApiCall::NamedMaterial(name) => self.pbrt_named_material(name.clone()),
+            ApiCall::LightSource(name, params) => self.pbrt_light_source(name.clone(), params),
+            ApiCall::AreaLightSource(name, params) => {
+                self.pbrt_area_light_source(name.clone(), params)
+            }
+            ApiCall::Shape(name, params) => self.pbrt_shape(name.clone(), params),
+            ApiCall::ReverseOrientation => self.pbrt_reverse_orientation(),
+            ApiCall::ObjectBegin(name) => self.pbrt_object_begin(name.clone()),
+            ApiCall::ObjectEnd => self.pbrt_object_end(),
+            ApiCall::ObjectInstance(name) => self.pbrt_object_instance(name.clone()),
+        }
+    }
+
+    /// Starts recording the API calls and the scene files read.
+    pub fn start_recording(&mut self) {
+        self.recording = Some(SceneRecording::default());
+    }
+
+    /// Stops recording and returns what was recorded; or `None` if recording
+    /// wasn't started.
+    pub fn take_recording(&mut self) -> Option<SceneRecording> {
+        self.recording.take()
+    }
+
+    /// Creates the shapes of a `Shape` call. The next shapes restored for a
+    /// replay are used if they are of the same type, and the shapes created
+    /// are recorded if recording is on.
+    ///
+    /// * `name`         - Name of the shape type.
+    /// * `object2world` - Transformation from object space to world space.
+    /// * `world2object` - Transformation from world space to object space.
+    /// * `params`       - Parameter set.
+    pub(super) fn make_shape(
+        &mut self,
+        name: &str,
+        object2world: ArcTransform,
+        world2object: ArcTransform,
+        params: &ParamSet,
+    ) -> Result<Vec<ArcShape>, Error> {
+        let restored = self
+            .restored_shapes
+            .as_mut()
+            .and_then(|restored| restored.pop_front());
+        if let Some((restored_name, Some(shapes))) = restored {
+            if restored_name == name {
+                GraphicsState::count_shapes(name, &shapes);
+                return Ok(shapes);
+            }
+        }
+
+        let shapes = self.graphics_state.make_shape(
+            name,
+            object2world,
+            world2object,
+            self.graphics_state.reverse_orientation,
+            params,
+        );
+        if let Some(recording) = self.recording.as_mut() {
+            let recorded = shapes.as_ref().ok().cloned();
+            recording.shapes.push((String::from(name), recorded));
+        }
+        shapes
+    }
+
+    /// Records that a scene file was read if recording is on.
+    ///
+    /// * `path`   - Path of the file.
+    /// * `source` - Contents of the file.
+    pub fn record_source(&mut self, path: &str, source: &str) {
+        if let Some(recording) = self.recording.as_mut() {
+            let path = absolute_path(path).unwrap_or_else(|_| String::from(path));
+            recording
+                .files
+                .push((path, SceneRecording::source_key(source)));
+        }
+    }
+}
@@ -0,0 +1,685 @@
+//! Scene Builder
+
+#![allow(dead_code)]
+use super::*;
+use crate::core::app::{set_options, Options};
+
+/// A material or shape parameter given either as a constant value or as the
+/// name of a texture.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TextureParam<T> {
+    /// A constant value.
+    Value(T),
+
+    /// The name of a texture defined with `SceneBuilder::texture()`.
+    Texture(String),
+}
+
+impl From<Float> for TextureParam<Float> {
+    /// Returns a constant float parameter.
+    ///
+    /// * `v` - The value.
+    fn from(v: Float) -> Self {
+        Self::Value(v)
+    }
+}
+
+impl From<Spectrum> for TextureParam<Spectrum> {
+    /// Returns a constant spectrum parameter.
+    ///
+    /// * `s` - The value.
+    fn from(s: Spectrum) -> Self {
+        Self::Value(s)
+    }
+}
+
+impl<T> From<&str> for TextureParam<T> {
+    /// Returns a parameter that refers to a texture.
+    ///
+    /// * `name` - Name of the texture.
+    fn from(name: &str) -> Self {
+        Self::Texture(String::from(name))
+    }
+}
+
+/// Adds a float parameter that may be a texture to a parameter set.
+///
+/// * `params` - The parameter set.
+/// * `name`   - Parameter name.
+/// * `param`  - The value or texture name.
+fn add_float_param(params: &mut ParamSet, name: &str, param: TextureParam<Float>) {
+    match param {
+        TextureParam::Value(v) => params.add_float(name, &[v]),
+        TextureParam::Texture(t) => params.add_texture(name, &[t]),
+    }
+}
+
+/// Adds a spectrum parameter that may be a texture to a parameter set.
+///
+/// * `params` - The parameter set.
+/// * `name`   - Parameter name.
+/// * `param`  - The value or texture name.
+fn add_spectrum_param(params: &mut ParamSet, name: &str, param: TextureParam<Spectrum>) {
+    match param {
+        TextureParam::Value(s) => params.add_spectrum(name, &[s]),
+        TextureParam::Texture(t) => params.add_texture(name, &[t]),
+    }
+}
+
+/// Builds the parameters of a `Shape` directive. Each constructor takes the
+/// parameters the shape needs; optional ones have their own methods.
+#[derive(Clone)]
+pub struct ShapeBuilder {
+    /// Shape name, e.g. "sphere".
+    name: String,
+
+    /// Shape parameters.
+    params: ParamSet,
+}
+
+impl ShapeBuilder {
+    /// Returns a builder for a shape with no parameters.
+    ///
+    /// * `name` - Shape name.
+    fn named(name: &str) -> Self {
+        Self {
+            name: String::from(name),
+            params: ParamSet::new(),
+        }
+    }
+
+    /// Returns a builder for a sphere centred at the origin.
+    ///
+    /// * `radius` - Radius.
+    pub fn sphere(radius: Float) -> Self {
+        let mut shape = Self::named("sphere");
+        shape.params.add_float("radius", &[radius]);
+        shape
+    }
+
+    /// Returns a builder for a disk in the xy-plane facing +z.
+    ///
+    /// * `height` - Position along the z-axis.
+    /// * `radius` - Radius.
+    pub fn disk(height: Float, radius: Float) -> Self {
+        let mut shape = Self::named("disk");
+        shape.params.add_float("height", &[height]);
+        shape.params.add_float("radius", &[radius]);
+        shape
+    }
+
+    /// Returns a builder for a cylinder around the z-axis.
+    ///
+    /// * `radius` - Radius.
+    /// * `z_min`  - Minimum z-coordinate.
+    /// * `z_max`  - Maximum z-coordinate.
+    pub fn cylinder(radius: Float, z_min: Float, z_max: Float) -> Self {
+        let mut shape = Self::named("cylinder");
+        shape.params.add_float("radius", &[radius]);
+        shape.params.add_float("zmin", &[z_min]);
+        shape.params.add_float("zmax", &[z_max]);
+        shape
+    }
+
+    /// Returns a builder for a triangle mesh.
+    ///
+    /// * `indices` - Vertex indices, 3 per triangle.
+    /// * `p`       - Vertex positions.
+    pub fn triangle_mesh(indices: &[Int], p: &[Point3f]) -> Self {
+        let mut shape = Self::named("trianglemesh");
+        shape.params.add_int("indices", indices);
+        shape.params.add_point3f("P", p);
+        shape
+    }
+
+    /// Returns a builder for a triangle mesh read from a PLY file.
+    ///
+    /// * `filename` - Path of the PLY file.
+    pub fn ply_mesh(filename: &str) -> Self {
+        let mut shape = Self::named("plymesh");
+        shape
+            .params
+            .add_string("filename", &[String::from(filename)]);
+        shape
+    }
+
+    /// Sets the maximum angle swept by a sphere, disk or cylinder.
+    ///
+    /// * `phi_max` - Angle in degrees.
+    pub fn phi_max(mut self, phi_max: Float) -> Self {
+        self.params.add_float("phimax", &[phi_max]);
+        self
+    }
+
+    /// Sets the per-vertex shading normals of a triangle mesh.
+    ///
+    /// * `n` - The normals.
+    pub fn normals(mut self, n: &[Normal3f]) -> Self {
+        self.params.add_normal3f("N", n);
+        self
+    }
+
+    /// Sets the per-vertex texture coordinates of a triangle mesh.
+    ///
+    /// * `uv` - The texture coordinates.
+    pub fn uv(mut self, uv: &[Point2f]) -> Self {
+        self.params.add_point2f("uv", uv);
+        self
+    }
+
+    /// Sets the alpha mask of a triangle mesh.
+    ///
+    /// * `alpha` - Alpha value or float texture.
+    pub fn alpha(mut self, alpha: impl Into<TextureParam<Float>>) -> Self {
+        add_float_param(&mut self.params, "alpha", alpha.into());
+        self
+    }
+}
+
+impl From<ShapeBuilder> for ApiCall {
+    /// Returns the `Shape` call for a shape.
+    ///
+    /// * `shape` - The shape.
+    fn from(shape: ShapeBuilder) -> Self {
+        Self::Shape(shape.name, shape.params)
+    }
+}
+
+/// Builds the parameters of a `Material` or `MakeNamedMaterial` directive.
+/// Each constructor takes the parameters the material needs; optional ones
+/// have their own methods.
+#[derive(Clone)]
+pub struct MaterialBuilder {
+    /// Material type, e.g. "matte".
+    name: String,
+
+    /// Material parameters.
+    params: ParamSet,
+}
+
+impl MaterialBuilder {
+    /// Returns a builder for a material with no parameters.
+    ///
+    /// * `name` - Material type.
+    fn named(name: &str) -> Self {
+        Self {
+            name: String::from(name),
+            params: ParamSet::new(),
+        }
+    }
+
+    /// Returns a builder for a diffuse material.
+    ///
+    /// * `kd` - Diffuse reflectance.
+    pub fn matte(kd: impl Into<TextureParam<Spectrum>>) -> Self {
+        let mut material = Self::named("matte");
+        add_spectrum_param(&mut material.params, "Kd", kd.into());
+        material
+    }
+
+    /// Returns a builder for a plastic material.
+    ///
+    /// * `kd`        - Diffuse reflectance.
+    /// * `ks`        - Glossy reflectance.
+    /// * `roughness` - Roughness of the glossy reflection.
+    pub fn plastic(
+        kd: impl Into<TextureParam<Spectrum>>,
+        ks: impl Into<TextureParam<Spectrum>>,
+        roughness: impl Into<TextureParam<Float>>,
+    ) -> Self {
+        let mut material = Self::named("plastic");
+        add_spectrum_param(&mut material.params, "Kd", kd.into());
+        add_spectrum_param(&mut material.params, "Ks", ks.into());
+        add_float_param(&mut material.params, "roughness", roughness.into());
+        material
+    }
+
+    /// Returns a builder for a metal material.
+    ///
+    /// * `eta`       - Index of refraction.
+    /// * `k`         - Absorption coefficient.
+    /// * `roughness` - Roughness of the surface.
+    pub fn metal(
+        eta: impl Into<TextureParam<Spectrum>>,
+        k: impl Into<TextureParam<Spectrum>>,
+        roughness: impl Into<TextureParam<Float>>,
+    ) -> Self {
+        let mut material = Self::named("metal");
+        add_spectrum_param(&mut material.params, "eta", eta.into());
+        add_spectrum_param(&mut material.params, "k", k.into());
+        add_float_param(&mut material.params, "roughness", roughness.into());
+        material
+    }
+
+    /// Sets the Oren-Nayar roughness of a matte material.
+    ///
+    /// * `sigma` - Standard deviation of the microfacet angles in degrees.
+    pub fn sigma(mut self, sigma: impl Into<TextureParam<Float>>) -> Self {
+        add_float_param(&mut self.params, "sigma", sigma.into());
+        self
+    }
+
+    /// Sets whether roughness values are remapped from [0, 1] to
+    /// microfacet distribution parameters.
+    ///
+    /// * `remap` - Whether to remap.
+    pub fn remap_roughness(mut self, remap: bool) -> Self {
+        self.params.add_bool("remaproughness", &[remap]);
+        self
+    }
+
+    /// Sets the bump map.
+    ///
+    /// * `texture` - Name of the float texture.
+    pub fn bump_map(mut self, texture: &str) -> Self {
+        self.params.add_texture("bumpmap", &[String::from(texture)]);
+        self
+    }
+}
+
+/// Builds a scene as the sequence of API calls a scene file would make.
+/// Attribute and object blocks are closures so they are always balanced and
+/// `WorldBegin` is added before the first call that needs the world block.
+#[derive(Clone, Default)]
+pub struct SceneBuilder {
+    /// The API calls so far.
+    calls: Vec<ApiCall>,
+
+    /// Whether `WorldBegin` was added.
+    in_world: bool,
+
+    /// Options to render with instead of the defaults.
+    options: Option<Options>,
+}
+
+impl SceneBuilder {
+    /// Returns a new `SceneBuilder`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the options to render with, e.g. the number of threads. They
+    /// are set globally by `render_to_rgba()`; see `set_options()`.
+    ///
+    /// * `options` - The options.
+    pub fn options(&mut self, options: Options) -> &mut Self {
+        self.options = Some(options);
+        self
+    }
+
+    /// Adds an API call as is. Used for directives without typed methods.
+    ///
+    /// * `call` - The API call.
+    pub fn call(&mut self, call: ApiCall) -> &mut Self {
+        match call {
+            ApiCall::WorldBegin => self.world_begin(),
+            call => {
+                self.calls.push(call);
+                self
+            }
+        }
+    }
+
+    /// Adds an API call that is only valid in the world block.
+    ///
+    /// * `call` - The API call.
+    fn world_call(&mut self, call: ApiCall) -> &mut Self {
+        self.world_begin();
+        self.calls.push(call);
+        self
+    }
+
+    /// Starts the world block unless it was started already. Camera, film,
+    /// sampler and integrator must be set before it.
+    pub fn world_begin(&mut self) -> &mut Self {
+        if !self.in_world {
+            self.in_world = true;
+            self.calls.push(ApiCall::WorldBegin);
+        }
+        self
+    }
+
+    /// Sets the current transformation to a viewing transformation.
+    ///
+    /// * `eye`  - Position of the eye.
+    /// * `look` - Point to look at.
+    /// * `up`   - Up direction.
+    pub fn look_at(&mut self, eye: Point3f, look: Point3f, up: Vector3f) -> &mut Self {
+        self.call(ApiCall::LookAt([
+            eye.x, eye.y, eye.z, look.x, look.y, look.z, up.x, up.y, up.z,
+        ]))
+    }
+
+    /// Applies a translation to the current transformation.
+    ///
+    /// * `delta` - Translation.
+    pub fn translate(&mut self, delta: Vector3f) -> &mut Self {
+        self.call(ApiCall::Translate(delta.x, delta.y, delta.z))
+    }
+
+    /// Applies a rotation to the current transformation.
+    ///
+    /// * `angle` - Angle in degrees.
+    /// * `axis`  - Axis of rotation.
+    pub fn rotate(&mut self, angle: Float, axis: Vector3f) -> &mut Self {
+        self.call(ApiCall::Rotate(angle, axis.x, axis.y, axis.z))
+    }
+
+    /// Applies a scale to the current transformation.
+    ///
+    /// * `s` - Scale factors along each axis.
+    pub fn scale(&mut self, s: Vector3f) -> &mut Self {
+        self.call(ApiCall::Scale(s.x, s.y, s.z))
+    }
+
+    /// Sets a perspective camera.
+    ///
+    /// * `fov` - Field of view in degrees along the shorter image axis.
+    pub fn perspective_camera(&mut self, fov: Float) -> &mut Self {
+        let mut params = ParamSet::new();
+        params.add_float("fov", &[fov]);
+        self.call(ApiCall::Camera(String::from("perspective"), params))
+    }
+
+    /// Sets the image film.
+    ///
+    /// * `x_resolution` - Width in pixels.
+    /// * `y_resolution` - Height in pixels.
+    /// * `filename`     - Output image path; unused when rendering in
+    ///                    memory.
+    pub fn film(&mut self, x_resolution: Int, y_resolution: Int, filename: &str) -> &mut Self {
+        let mut params = ParamSet::new();
+        params.add_int("xresolution", &[x_resolution]);
+        params.add_int("yresolution", &[y_resolution]);
+        params.add_string("filename", &[String::from(filename)]);
+        self.call(ApiCall::Film(String::from("image"), params))
+    }
+
+    /// Sets the sampler.
+    ///
+    /// * `name`          - Sampler name, e.g. "halton".
+    /// * `pixel_samples` - Number of samples per pixel.
+    pub fn sampler(&mut self, name: &str, pixel_samples: Int) -> &mut Self {
+        let mut params = ParamSet::new();
+        params.add_int("pixelsamples", &[pixel_samples]);
+        self.call(ApiCall::Sampler(String::from(name), params))
+    }
+
+    /// Sets the integrator.
+    ///
+    /// * `name`      - Integrator name, e.g. "path".
+    /// * `max_depth` - Maximum path length.
+    pub fn integrator(&mut self, name: &str, max_depth: Int) -> &mut Self {
+        let mut params = ParamSet::new();
+        params.add_int("maxdepth", &[max_depth]);
+        self.call(ApiCall::Integrator(String::from(name), params))
+    }
+
+    /// Adds calls inside an attribute block, which restores the graphics
+    /// state and transformation afterwards.
+    ///
+    /// * `f` - Adds the calls.
+    pub fn attribute<F: FnOnce(&mut Self)>(&mut self, f: F) -> &mut Self {
+        self.world_call(ApiCall::AttributeBegin);
+        f(self);
+        self.calls.push(ApiCall::AttributeEnd);
+        self
+    }
+
+    /// Defines an object that can be instanced with `object_instance()`.
+    ///
+    /// * `name` - Object name.
+    /// * `f`    - Adds the shapes of the object.
+    pub fn object<F: FnOnce(&mut Self)>(&mut self, name: &str, f: F) -> &mut Self {
+        self.world_call(ApiCall::ObjectBegin(String::from(name)));
+        f(self);
+        self.calls.push(ApiCall::ObjectEnd);
+        self
+    }
+
+    /// Adds an instance of an object with the current transformation.
+    ///
+    /// * `name` - Object name.
+    pub fn object_instance(&mut self, name: &str) -> &mut Self {
+        self.world_call(ApiCall::ObjectInstance(String::from(name)))
+    }
+
+    /// Defines a texture.
+    ///
+    /// * `name`         - Texture name used by `TextureParam::Texture`.
+    /// * `texture_type` - "float" or "spectrum".
+    /// * `class`        - Texture class, e.g. "checkerboard".
+    /// * `params`       - Texture parameters.
+    pub fn texture(
+        &mut self,
+        name: &str,
+        texture_type: &str,
+        class: &str,
+        params: ParamSet,
+    ) -> &mut Self {
+        self.world_call(ApiCall::Texture(
+            String::from(name),
+            String::from(texture_type),
+            String::from(class),
+            params,
+        ))
+    }
+
+    /// Sets the current material.
+    ///
+    /// * `material` - The material.
+    pub fn material(&mut self, material: MaterialBuilder) -> &mut Self {
+        self.world_call(ApiCall::Material(material.name, material.params))
+    }
+
+    /// Defines a named material.
+    ///
+    /// * `name`     - Material name.
+    /// * `material` - The material.
+    pub fn make_named_material(&mut self, name: &str, material: MaterialBuilder) -> &mut Self {
+        let mut params = material.params;
+        params.add_string("type", &[material.name]);
+        self.world_call(ApiCall::MakeNamedMaterial(String::from(name), params))
+    }
+
+    /// Sets a named material as the current material.
+    ///
+    /// * `name` - Material name.
+    pub fn named_material(&mut self, name: &str) -> &mut Self {
+        self.world_call(ApiCall::NamedMaterial(String::from(name)))
+    }
+
+    /// Adds a shape with the current material and transformation.
+    ///
+    /// * `shape` - The shape.
+    pub fn shape(&mut self, shape: ShapeBuilder) -> &mut Self {
+        self.world_call(ApiCall::from(shape))
+    }
+
+    /// Flips the surface normals of the shapes that follow.
+    pub fn reverse_orientation(&mut self) -> &mut Self {
+        self.world_call(ApiCall::ReverseOrientation)
+    }
+
+    /// Adds a point light.
+    ///
+    /// * `intensity` - Radiant intensity.
+    /// * `from`      - Position.
+    pub fn point_light(&mut self, intensity: Spectrum, from: Point3f) -> &mut Self {
+        let mut params = ParamSet::new();
+        params.add_spectrum("I", &[intensity]);
+        params.add_point3f("from", &[from]);
+        self.world_call(ApiCall::LightSource(String::from("point"), params))
+    }
+
+    /// Adds a distant light.
+    ///
+    /// * `radiance` - Emitted radiance.
+    /// * `from`     - Point the light comes from.
+    /// * `to`       - Point the light goes towards.
+    pub fn distant_light(&mut self, radiance: Spectrum, from: Point3f, to: Point3f) -> &mut Self {
+        let mut params = ParamSet::new();
+        params.add_spectrum("L", &[radiance]);
+        params.add_point3f("from", &[from]);
+        params.add_point3f("to", &[to]);
+        self.world_call(ApiCall::LightSource(String::from("distant"), params))
+    }
+
+    /// Adds an infinite light with constant radiance.
+    ///
+    /// * `radiance` - Emitted radiance.
+    pub fn infinite_light(&mut self, radiance: Spectrum) -> &mut Self {
+        let mut params = ParamSet::new();
+        params.add_spectrum("L", &[radiance]);
+        self.world_call(ApiCall::LightSource(String::from("infinite"), params))
+    }
+
+    /// Makes the shapes that follow emit light.
+    ///
+    /// * `radiance`  - Emitted radiance.
+    /// * `two_sided` - Whether both sides of the surfaces emit light.
+    pub fn area_light(&mut self, radiance: Spectrum, two_sided: bool) -> &mut Self {
+        let mut params = ParamSet::new();
+        params.add_spectrum("L", &[radiance]);
+        params.add_bool("twosided", &[two_sided]);
+        self.world_call(ApiCall::AreaLightSource(String::from("diffuse"), params))
+    }
+
+    /// Returns the API calls for the scene ending with `WorldEnd`, which
+    /// renders it when replayed with `Api::call()`.
+    pub fn build(mut self) -> Vec<ApiCall> {
+        self.world_begin();
+        self.calls.push(ApiCall::WorldEnd);
+        self.calls
+    }
+
+    /// Renders the scene into an in-memory RGBA image. See
+    /// `render_to_rgba()`. Returns an error if options were set with
+    /// `options()` but others are already in use.
+    ///
+    /// * `on_tile` - Called with the pixel bounds of each finished tile
+    ///               within the image and its RGBA values in row-major
+    ///               order.
+    pub fn render_to_rgba<F>(mut self, on_tile: F) -> Result<RgbaImage, Error>
+    where
+        F: Fn(&Bounds2i, &[u8]) + Send + Sync + 'static,
+    {
+        if let Some(options) = self.options.take() {
+            set_options(options)?;
+        }
+        let calls = self.build();
+        render_with_api_to_rgba(on_tile, |api| {
+            for call in calls {
+                api.call(call);
+            }
+            Ok(())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn world_block_and_attributes_are_added() {
+        let mut scene = SceneBuilder::new();
+        scene
+            .perspective_camera(45.0)
+            .translate(Vector3f::new(0.0, 0.0, 1.0))
+            .attribute(|scene| {
+                scene
+                    .material(MaterialBuilder::matte("checks").sigma(10.0))
+                    .shape(ShapeBuilder::sphere(2.0));
+            })
+            .world_begin();
+        let calls = scene.build();
+
+        let kinds: Vec<&str> = calls
+            .iter()
+            .map(|call| match call {
+                ApiCall::Camera(..) => "Camera",
+                ApiCall::Translate(..) => "Translate",
+                ApiCall::WorldBegin => "WorldBegin",
+                ApiCall::AttributeBegin => "AttributeBegin",
+                ApiCall::Material(..) => "Material",
+                ApiCall::Shape(..) => "Shape",
+                ApiCall::AttributeEnd => "AttributeEnd",
+                ApiCall::WorldEnd => "WorldEnd",
+                _ => "other",
+            })
+            .collect();
+        assert_eq!(
+            kinds,
+            [
+                "Camera",
+                "Translate",
+                "WorldBegin",
+                "AttributeBegin",
+                "Material",
+                "Shape",
+                "AttributeEnd",
+                "WorldEnd"
+            ]
+        );
+
+        match &calls[4] {
+            ApiCall::Material(name, params) => {
+                assert_eq!(name, "matte");
+                assert_eq!(params.find_one_texture("Kd", String::new()), "checks");
+                assert_eq!(params.find_one_float("sigma", 0.0), 10.0);
+            }
+            _ => unreachable!(),
+        }
+        match &calls[5] {
+            ApiCall::Shape(name, params) => {
+                assert_eq!(name, "sphere");
+                assert_eq!(params.find_one_float("radius", 1.0), 2.0);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn built_scene_renders_like_scene_file() {
+        let source = r#"
+            LookAt 0 0 5  0 0 0  0 1 0
+            Camera "perspective" "float fov" [30]
+            Film "image" "integer xresolution" [8] "integer yresolution" [8]
+            Sampler "random" "integer pixelsamples" [2]
+            Integrator "whitted" "integer maxdepth" [5]
+            WorldBegin
+            LightSource "point" "rgb I" [20 20 20] "point from" [2 2 5]
+            Material "matte" "rgb Kd" [0.8 0.3 0.2]
+            Shape "sphere" "float radius" [1]
+            WorldEnd
+        "#;
+        let expected = render_to_rgba(source, |_, _| {}).unwrap();
+
+        let mut scene = SceneBuilder::new();
+        scene
+            .look_at(
+                Point3f::new(0.0, 0.0, 5.0),
+                Point3f::new(0.0, 0.0, 0.0),
+                Vector3f::new(0.0, 1.0, 0.0),
+            )
+            .perspective_camera(30.0)
+            .film(8, 8, "unused.png")
+            .sampler("random", 2)
+            .integrator("whitted", 5)
+            .point_light(
+                Spectrum::from_rgb(&[20.0, 20.0, 20.0], None),
+                Point3f::new(2.0, 2.0, 5.0),
+            )
+            .material(MaterialBuilder::matte(Spectrum::from_rgb(
+                &[0.8, 0.3, 0.2],
+                None,
+            )))
+            .shape(ShapeBuilder::sphere(1.0));
+        let image = scene.render_to_rgba(|_, _| {}).unwrap();
+
+        assert_eq!((image.width, image.height), (8, 8));
+        assert!(image.pixels.iter().any(|&v| v > 0));
+        assert_eq!(image, expected);
+    }
+}
@@ -0,0 +1,468 @@
+//! Scene Export
+
+#![allow(dead_code)]
+use super::*;
+use std::collections::HashSet;
+use std::fmt::{self, Write};
+use std::fs;
+use std::path::Path;
+
+/// Names of string parameters holding file paths. Relative paths are
+/// relative to the current directory when rendering and are exported as
+/// absolute paths.
+const FILE_PARAMS: [&str; 8] = [
+    "filename",
+    "mapname",
+    "lensfile",
+    "bsdffile",
+    "plugin",
+    "warmstart",
+    "warmstartvariance",
+    "cameraresponse",
+];
+
+impl SceneRecording {
+    /// Returns the recorded scene in the PBRT file format. See
+    /// `PbrtExporter`.
+    pub fn to_pbrt(&self) -> String {
+        let mut exporter = PbrtExporter::new();
+        for (file, _) in self.files.iter() {
+            exporter.line(&format!("# Exported from {}", file));
+        }
+        for call in self.calls.iter() {
+            exporter.call(call);
+        }
+        exporter.finish()
+    }
+
+    /// Writes the recorded scene to a file in the PBRT file format.
+    ///
+    /// * `path` - Path of the file.
+    pub fn export_pbrt(&self, path: &str) -> Result<(), Error> {
+        fs::write(path, self.to_pbrt())
+            .map_err(|err| Error::Io(format!("Error writing file '{}'. {}.", path, err)))
+    }
+}
+
+/// Writes API calls as a scene description in the PBRT file format. The
+/// scene it describes is the one the renderer built from the calls:
+///
+/// * Included files are inlined.
+/// * The options that weren't given before `WorldBegin` are written with
+///   their default types.
+/// * File paths are absolute.
+/// * OBJ meshes become triangle meshes with the materials of their MTL
+///   files, so the exported scene doesn't need the OBJ files.
+pub struct PbrtExporter {
+    /// The scene description written so far.
+    out: String,
+
+    /// Nesting depth of attribute, transform and object blocks.
+    depth: usize,
+
+    /// The options given before `WorldBegin`.
+    options: HashSet<&'static str>,
+}
+
+impl PbrtExporter {
+    /// Returns a new instance of `PbrtExporter`.
+    pub fn new() -> Self {
+        Self {
+            out: String::new(),
+            depth: 0,
+            options: HashSet::new(),
+        }
+    }
+
+    /// Returns the scene description written.
+    pub fn finish(self) -> String {
+        self.out
+    }
+
+    /// Writes an API call.
+    ///
+    /// * `call` - The API call.
+    pub fn call(&mut self, call: &ApiCall) {
+        match call {
+            ApiCall::Identity => self.line("Identity"),
+            ApiCall::Translate(dx, dy, dz) => self.line(&format!("Translate {} {} {}", dx, dy, dz)),
+            ApiCall::Transform(tr) => self.line(&format!("Transform {}", float_list(tr))),
+            ApiCall::ConcatTransform(tr) => {
+                self.line(&format!("ConcatTransform {}", float_list(tr)))
+            }
+            ApiCall::Rotate(angle, dx, dy, dz) => {
+                self.line(&format!("Rotate {} {} {} {}", angle, dx, dy, dz))
+            }
+            ApiCall::Scale(sx, sy, sz) => self.line(&format!("Scale {} {} {}", sx, sy, sz)),
+            ApiCall::LookAt(v) => self.line(&format!(
+                "LookAt {} {} {}  {} {} {}  {} {} {}",
+                v[0], v[1], v[2], v[3], v[4], v[5], v[6], v[7], v[8]
+            )),
+            ApiCall::CoordinateSystem(name) => self.line(&format!("CoordinateSystem \"{}\"", name)),
+            ApiCall::CoordSysTransform(name) => {
+                self.line(&format!("CoordSysTransform \"{}\"", name))
+            }
+            ApiCall::ActiveTransformAll => self.line("ActiveTransform All"),
+            ApiCall::ActiveTransformEndTime => self.line("ActiveTransform EndTime"),
+            ApiCall::ActiveTransformStartTime => self.line("ActiveTransform StartTime"),
+            ApiCall::TransformTimes(start, end) => {
+                self.line(&format!("TransformTimes {} {}", start, end))
+            }
+            ApiCall::Option(params) => self.directive("Option", &[], params),
+            ApiCall::PixelFilter(name, params) => self.option("PixelFilter", name, params),
+            ApiCall::Film(name, params) => self.option("Film", name, params),
+            ApiCall::Sampler(name, params) => self.option("Sampler", name, params),
+            ApiCall::Accelerator(name, params) => self.option("Accelerator", name, params),
+            ApiCall::Integrator(name, params) => self.option("Integrator", name, params),
+            ApiCall::Camera(name, params) => self.option("Camera", name, params),
+            ApiCall::MakeNamedMedium(name, params) => {
+                self.directive("MakeNamedMedium", &[name], params)
+            }
+            ApiCall::MediumInterface(inside, outside) => {
+                self.line(&format!("MediumInterface \"{}\" \"{}\"", inside, outside))
+            }
+            ApiCall::WorldBegin => {
+                self.default_options();
+                self.line("WorldBegin");
+            }
+            ApiCall::WorldEnd => {
+                self.line("WorldEnd");
+                self.options.clear();
+            }
+            ApiCall::AttributeBegin => self.begin("AttributeBegin"),
+            ApiCall::AttributeEnd => self.end("AttributeEnd"),
+            ApiCall::TransformBegin => self.begin("TransformBegin"),
+            ApiCall::TransformEnd => self.end("TransformEnd"),
+            ApiCall::Texture(name, texture_type, tex_name, params) => {
+                self.directive("Texture", &[name, texture_type, tex_name], params)
+            }
+            ApiCall::Material(name, params) => self.directive("Material", &[name], params),
+            ApiCall::MakeNamedMaterial(name, params) => {
+                self.directive("MakeNamedMaterial", &[name], params)
+            }
+            ApiCall::NamedMaterial(name) => self.line(&format!("NamedMaterial \"{}\"", name)),
+            ApiCall::LightSource(name, params) => self.directive("LightSource", &[name], params),
+            ApiCall::AreaLightSource(name, params) => {
+                self.directive("AreaLightSource", &[name], params)
+            }
+            ApiCall::Shape(name, params) if name == "objmesh" => match ApiCall::obj_mesh(params) {
+                Ok(calls) => {
+                    for call in calls.iter() {
+                        self.call(call);
+                    }
+                }
+                Err(err) => {
+                    warn!("{} Exporting the objmesh shape as is.", err);
+                    self.directive("Shape", &[name], params);
+                }
+            },
+            ApiCall::Shape(name, params) => self.directive("Shape", &[name], params),
+            ApiCall::ReverseOrientation => self.line("ReverseOrientation"),
+            ApiCall::ObjectBegin(name) => self.begin(&format!("ObjectBegin \"{}\"", name)),
+            ApiCall::ObjectEnd => self.end("ObjectEnd"),
+            ApiCall::ObjectInstance(name) => self.line(&format!("ObjectInstance \"{}\"", name)),
+        }
+    }
+
+    /// Writes the options that weren't given with the types the renderer
+    /// uses by default.
+    fn default_options(&mut self) {
+        let defaults = RenderOptions::new();
+        let params = ParamSet::new();
+        for (option, name) in [
+            ("PixelFilter", &defaults.filter_name),
+            ("Film", &defaults.film_name),
+            ("Sampler", &defaults.sampler_name),
+            ("Accelerator", &defaults.accelerator_name),
+            ("Integrator", &defaults.integrator_name),
+        ] {
+            if !self.options.contains(option) {
+                self.option(option, name, &params);
+            }
+        }
+        if !self.options.contains("Camera") {
+            // The default camera is at the origin of world space.
+            self.line("Identity");
+            self.option("Camera", &defaults.camera_name, &params);
+        }
+    }
+
+    /// Writes an option given before `WorldBegin`.
+    ///
+    /// * `option` - The option.
+    /// * `name`   - Type of the option.
+    /// * `params` - Parameters.
+    fn option(&mut self, option: &'static str, name: &str, params: &ParamSet) {
+        self.options.insert(option);
+        self.directive(option, &[name], params);
+    }
+
+    /// Writes a directive with its quoted arguments and parameters, one
+    /// parameter per line.
+    ///
+    /// * `directive` - The directive.
+    /// * `args`      - Arguments.
+    /// * `params`    - Parameters.
+    fn directive(&mut self, directive: &str, args: &[&str], params: &ParamSet) {
+        let mut line = String::from(directive);
+        for arg in args {
+            let _ = write!(line, " \"{}\"", arg);
+        }
+        self.line(&line);
+
+        self.depth += 1;
+        for param in export_params(params) {
+            self.line(&param);
+        }
+        self.depth -= 1;
+    }
+
+    /// Writes a directive that starts a block and indents the lines that
+    /// follow.
+    ///
+    /// * `line` - The directive.
+    fn begin(&mut self, line: &str) {
+        self.line(line);
+        self.depth += 1;
+    }
+
+    /// Writes a directive that ends a block.
+    ///
+    /// * `line` - The directive.
+    fn end(&mut self, line: &str) {
+        self.depth = self.depth.saturating_sub(1);
+        self.line(line);
+    }
+
+    /// Writes a line at the current indentation.
+    ///
+    /// * `line` - The line.
+    fn line(&mut self, line: &str) {
+        for _ in 0..self.depth {
+            self.out.push_str("    ");
+        }
+        self.out.push_str(line);
+        self.out.push('\n');
+    }
+}
+
+impl Default for PbrtExporter {
+    /// Returns the default `PbrtExporter`.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the parameters of a parameter set in the PBRT file format sorted
+/// by name. Spectra are written as `"rgb"` values and file paths are made
+/// absolute.
+///
+/// * `params` - The parameter set.
+fn export_params(params: &ParamSet) -> Vec<String> {
+    let mut entries: Vec<(String, String)> = vec![];
+    let mut add = |param_type: &str, name: &str, values: String| {
+        entries.push((
+            String::from(name),
+            format!("\"{} {}\" {}", param_type, name, values),
+        ));
+    };
+
+    for (name, values) in params.iter_bools() {
+        add(
+            "bool",
+            name,
+            list(values.iter().map(|v| format!("\"{}\"", v))),
+        );
+    }
+    for (name, values) in params.iter_ints() {
+        add("integer", name, list(values.iter()));
+    }
+    for (name, values) in params.iter_floats() {
+        add("float", name, float_list(values));
+    }
+    for (name, values) in params.iter_point2fs() {
+        add("point2", name, list(values.iter().flat_map(|p| [p.x, p.y])));
+    }
+    for (name, values) in params.iter_vector2fs() {
+        add(
+            "vector2",
+            name,
+            list(values.iter().flat_map(|v| [v.x, v.y])),
+        );
+    }
+    for (name, values) in params.iter_point3fs() {
+        add(
+            "point3",
+            name,
+            list(values.iter().flat_map(|p| [p.x, p.y, p.z])),
+        );
+    }
+    for (name, values) in params.iter_vector3fs() {
+        add(
+            "vector3",
+            name,
+            list(values.iter().flat_map(|v| [v.x, v.y, v.z])),
+        );
+    }
+    for (name, values) in params.iter_normal3fs() {
+        add(
+            "normal",
+            name,
+            list(values.iter().flat_map(|n| [n.x, n.y, n.z])),
+        );
+    }
+    for (name, values) in params.iter_spectra() {
+        add("rgb", name, list(values.iter().flat_map(|s| s.to_rgb())));
+    }
+    for (name, values) in params.iter_strings() {
+        let is_file = FILE_PARAMS.contains(&name);
+        let values = values.iter().map(|v| {
+            if is_file && !v.is_empty() {
+                format!("\"{}\"", export_path(v))
+            } else {
+                format!("\"{}\"", v)
+            }
+        });
+        add("string", name, list(values));
+    }
+    for (name, values) in params.iter_textures() {
+        add(
+            "texture",
+            name,
+            list(values.iter().map(|v| format!("\"{}\"", v))),
+        );
+    }
+
+    entries.sort_by(|a, b| a.0.cmp(&b.0));
+    entries.into_iter().map(|(_, entry)| entry).collect()
+}
+
+/// Returns a list of values in brackets.
+///
+/// * `values` - The values.
+fn list<T: fmt::Display>(values: impl Iterator<Item = T>) -> String {
+    let values: Vec<String> = values.map(|v| v.to_string()).collect();
+    format!("[{}]", values.join(" "))
+}
+
+/// Returns a list of floating point values in brackets.
+///
+/// * `values` - The values.
+fn float_list(values: &[Float]) -> String {
+    list(values.iter())
+}
+
+/// Returns the absolute path of a file. Paths of files that don't exist,
+/// e.g. output images, are made absolute relative to the current directory.
+///
+/// * `path` - The path.
+fn export_path(path: &str) -> String {
+    absolute_path(path).unwrap_or_else(|_| {
+        std::env::current_dir()
+            .ok()
+            .and_then(|dir| dir.join(Path::new(path)).to_str().map(String::from))
+            .unwrap_or_else(|| String::from(path))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::fileutil::is_absolute_path;
+    use crate::core::parsers::PbrtFileParser;
+
+    /// Returns the calls recorded while parsing a scene.
+    fn record(source: &str) -> SceneRecording {
+        let mut api = Api::new();
+        api.pbrt_init();
+        api.start_recording();
+        PbrtFileParser::in_memory()
+            .parse_source(source, &mut api)
+            .unwrap();
+        api.take_recording().unwrap()
+    }
+
+    #[test]
+    fn exported_scene_parses_to_the_same_scene() {
+        let exported = record(
+            r#"
+            LookAt 0 0 5  0 0 0  0 1 0
+            Camera "perspective" "float fov" [30]
+            PixelFilter "gaussian" "float xwidth" 2
+            WorldBegin
+            AttributeBegin
+                Texture "checks" "spectrum" "imagemap" "string filename" "checks.png"
+                Material "matte" "texture Kd" "checks" "bool remaproughness" false
+                Translate 1 0 0
+                Shape "sphere" "float radius" [0.5]
+            AttributeEnd
+            ObjectBegin "tri"
+                Shape "trianglemesh" "integer indices" [0 1 2] "point P" [0 0 0 1 0 0 0 1 0]
+            ObjectEnd
+            "#,
+        )
+        .to_pbrt();
+
+        // Options that weren't given are written with their defaults.
+        assert!(exported.starts_with("LookAt 0 0 5  0 0 0  0 1 0\n"));
+        assert!(exported.contains(
+            "Camera \"perspective\"\n    \"float fov\" [30]\n\
+             PixelFilter \"gaussian\"\n    \"float xwidth\" [2]\n\
+             Film \"image\"\nSampler \"halton\"\nAccelerator \"bvh\"\nIntegrator \"path\"\n\
+             WorldBegin\n"
+        ));
+        assert!(
+            exported.contains("AttributeBegin\n    Texture \"checks\" \"spectrum\" \"imagemap\"\n")
+        );
+        assert!(exported.contains(
+            "    Material \"matte\"\n        \"texture Kd\" [\"checks\"]\n        \
+             \"bool remaproughness\" [\"false\"]\n"
+        ));
+        assert!(exported.contains(
+            "ObjectBegin \"tri\"\n    Shape \"trianglemesh\"\n        \
+             \"point3 P\" [0 0 0 1 0 0 0 1 0]\n        \"integer indices\" [0 1 2]\nObjectEnd\n"
+        ));
+
+        // File paths are absolute.
+        let checks = export_path("checks.png");
+        assert!(is_absolute_path(&checks));
+        assert!(exported.contains(&format!("\"string filename\" [\"{}\"]", checks)));
+
+        assert_eq!(record(&exported).to_pbrt(), exported);
+    }
+
+    // Only RGB spectra give back the exact RGB values of the material.
+    #[cfg(not(feature = "sampled-spectrum"))]
+    #[test]
+    fn obj_meshes_are_exported_as_triangle_meshes() {
+        let dir = std::env::temp_dir().join("pbr_rust_export");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("quad.mtl"), "newmtl red\nKd 1 0 0\n").unwrap();
+        fs::write(
+            dir.join("quad.obj"),
+            "mtllib quad.mtl\nv 0 0 0\nv 1 0 0\nv 1 1 0\nv 0 1 0\n\
+             g quad\nusemtl red\nf 1 2 3 4\n",
+        )
+        .unwrap();
+        let obj = dir.join("quad.obj").to_string_lossy().into_owned();
+
+        let mut params = ParamSet::new();
+        params.add_string("filename", &[obj.clone()]);
+        params.add_bool("usemtl", &[true]);
+        let mut exporter = PbrtExporter::new();
+        exporter.call(&ApiCall::Shape(String::from("objmesh"), params.clone()));
+        assert_eq!(
+            exporter.finish(),
+            "AttributeBegin\n    Material \"matte\"\n        \"rgb Kd\" [1 0 0]\n    \
+             Shape \"trianglemesh\"\n        \"point3 P\" [0 0 0 1 0 0 1 1 0 0 1 0]\n        \
+             \"integer indices\" [0 1 2 0 2 3]\nAttributeEnd\n"
+        );
+
+        // Without `usemtl` the current material is used.
+        params.add_bool("usemtl", &[false]);
+        let mut exporter = PbrtExporter::new();
+        exporter.call(&ApiCall::Shape(String::from("objmesh"), params));
+        assert!(exporter.finish().starts_with("Shape \"trianglemesh\"\n"));
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
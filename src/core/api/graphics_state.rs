@@ -2,6 +2,7 @@
 
 #![allow(dead_code)]
 use super::material_instance::MaterialInstance;
+use super::registry::*;
 use super::{
     FloatTextureMap, NamedMaterialMap, SpectrumTextureMap, TransformCache, TransformSet,
     MAX_TRANSFORMS,
@@ -9,6 +10,7 @@ use super::{
 use crate::accelerators::*;
 use crate::cameras::*;
 use crate::core::camera::*;
+use crate::core::error::*;
 use crate::core::film::*;
 use crate::core::filter::*;
 use crate::core::geometry::*;
@@ -26,10 +28,25 @@ use crate::lights::*;
 use crate::materials::*;
 use crate::samplers::*;
 use crate::shapes::*;
-use crate::textures::*;
-use std::result::Result;
+use std::convert::TryFrom;
+use std::mem::size_of;
 use std::sync::{Arc, Mutex};
 
+stat_counter!("Shapes/Cones", N_CONES);
+stat_counter!("Shapes/Curve segments", N_CURVES);
+stat_counter!("Shapes/Cylinders", N_CYLINDERS);
+stat_counter!("Shapes/Disks", N_DISKS);
+stat_counter!("Shapes/Hyperboloids", N_HYPERBOLOIDS);
+stat_counter!(
+    "Shapes/Subdivision surface triangles",
+    N_LOOP_SUBDIV_TRIANGLES
+);
+stat_counter!("Shapes/Paraboloids", N_PARABOLOIDS);
+stat_counter!("Shapes/Spheres", N_SPHERES);
+stat_counter!("Shapes/Mesh triangles", N_MESH_TRIANGLES);
+stat_counter!("Shapes/Registered shapes", N_REGISTERED_SHAPES);
+stat_memory_counter!("Memory/Shapes", SHAPE_BYTES);
+
 /// Used as a stack to perform hierarchical state management.
 #[derive(Clone)]
 pub struct GraphicsState {
@@ -60,7 +77,7 @@ pub struct GraphicsState {
     /// Indicates if the current name material instances is shared.
     pub named_materials_shared: bool,
 
-    /// Current material.
+    /// Current material; `None` if shapes only bound participating media.
     pub current_material: Option<Arc<MaterialInstance>>,
 
     /// Current area light parameters.
@@ -100,14 +117,19 @@ impl GraphicsState {
         }
     }
 
-    /// Returns a material for given shape parameters.
+    /// Returns a material for given shape parameters; `None` if the current
+    /// material is "none" or "interface" and shapes only bound participating
+    /// media.
     ///
     /// * `geom_params` - Shape parameters.
-    pub fn get_material_for_shape(&self, geom_params: &ParamSet) -> Result<ArcMaterial, String> {
-        let current_material = self
-            .current_material
-            .as_ref()
-            .expect("GraphicsState has no current material");
+    pub fn get_material_for_shape(
+        &self,
+        geom_params: &ParamSet,
+    ) -> Result<Option<ArcMaterial>, Error> {
+        let current_material = match self.current_material.as_ref() {
+            Some(current_material) => current_material,
+            None => return Ok(None),
+        };
 
         if self.shape_may_set_material_parameters(geom_params) {
             // Only create a unique material for the shape if the shape's
@@ -119,9 +141,9 @@ impl GraphicsState {
                 self.float_textures.clone(),
                 self.spectrum_textures.clone(),
             );
-            self.make_material(&current_material.name, &mp)
+            self.make_material(&current_material.name, &mp).map(Some)
         } else {
-            Ok(current_material.material.clone())
+            Ok(Some(current_material.material.clone()))
         }
     }
 
@@ -132,7 +154,7 @@ impl GraphicsState {
     //
     // Therefore, we'll apply some "heuristics".
     fn shape_may_set_material_parameters(&self, ps: &ParamSet) -> bool {
-        for name in ps.textures.keys() {
+        for (name, _) in ps.iter_textures() {
             // Any texture other than one for an alpha mask is almost certainly
             // for a Material (or is unused!).
             if name != "alpha" && name != "shadowalpha" {
@@ -141,16 +163,16 @@ impl GraphicsState {
         }
 
         // Special case spheres, which are the most common non-mesh primitive.
-        for (name, param) in ps.floats.iter() {
-            if param.values.len() == 1 && name != "radius" {
+        for (name, values) in ps.iter_floats() {
+            if values.len() == 1 && name != "radius" {
                 return true;
             }
         }
 
         // Extra special case strings, since plymesh uses "filename", curve "type",
         // and loopsubdiv "scheme".
-        for (name, param) in ps.strings.iter() {
-            if param.values.len() == 1 && name != "filename" && name != "type" && name != "scheme" {
+        for (name, values) in ps.iter_strings() {
+            if values.len() == 1 && name != "filename" && name != "type" && name != "scheme" {
                 return true;
             }
         }
@@ -159,50 +181,50 @@ impl GraphicsState {
         // parameter, assume it may be for the material. This should be valid
         // (if conservative), since no materials currently take array
         // parameters.
-        for param in ps.bools.values() {
-            if param.values.len() == 1 {
+        for (_, values) in ps.iter_bools() {
+            if values.len() == 1 {
                 return true;
             }
         }
 
-        for param in ps.ints.values() {
-            if param.values.len() == 1 {
+        for (_, values) in ps.iter_ints() {
+            if values.len() == 1 {
                 return true;
             }
         }
 
-        for param in ps.point2fs.values() {
-            if param.values.len() == 1 {
+        for (_, values) in ps.iter_point2fs() {
+            if values.len() == 1 {
                 return true;
             }
         }
 
-        for param in ps.vector2fs.values() {
-            if param.values.len() == 1 {
+        for (_, values) in ps.iter_vector2fs() {
+            if values.len() == 1 {
                 return true;
             }
         }
 
-        for param in ps.point3fs.values() {
-            if param.values.len() == 1 {
+        for (_, values) in ps.iter_point3fs() {
+            if values.len() == 1 {
                 return true;
             }
         }
 
-        for param in ps.vector3fs.values() {
-            if param.values.len() == 1 {
+        for (_, values) in ps.iter_vector3fs() {
+            if values.len() == 1 {
                 return true;
             }
         }
 
-        for param in ps.normal3fs.values() {
-            if param.values.len() == 1 {
+        for (_, values) in ps.iter_normal3fs() {
+            if values.len() == 1 {
                 return true;
             }
         }
 
-        for param in ps.spectra.values() {
-            if param.values.len() == 1 {
+        for (_, values) in ps.iter_spectra() {
+            if values.len() == 1 {
                 return true;
             }
         }
@@ -224,39 +246,54 @@ impl GraphicsState {
         world2object: ArcTransform,
         reverse_orientation: bool,
         paramset: &ParamSet,
-    ) -> Result<Vec<ArcShape>, String> {
-        let p = (paramset, object2world, world2object, reverse_orientation);
+    ) -> Result<Vec<ArcShape>, Error> {
+        let constructor = shape_constructor(name)
+            .ok_or_else(|| Error::UnknownType(format!("Shape '{}' unknown.", name)))?;
+        let shapes = constructor(
+            paramset,
+            object2world,
+            world2object,
+            reverse_orientation,
+            &self.float_textures,
+        )?;
+        Self::count_shapes(name, &shapes);
+        Ok(shapes)
+    }
 
-        match name {
-            "cone" => Ok(vec![Arc::new(Cone::from(p))]),
-            "curve" => Ok(Curve::from_props(p)),
-            "cylinder" => Ok(vec![Arc::new(Cylinder::from(p))]),
-            "disk" => Ok(vec![Arc::new(Disk::from(p))]),
-            "hyperboloid" => Ok(vec![Arc::new(Hyperboloid::from(p))]),
-            "loopsubdiv" => Ok(LoopSubDiv::from_props(p)),
-            "paraboloid" => Ok(vec![Arc::new(Paraboloid::from(p))]),
-            "sphere" => Ok(vec![Arc::new(Sphere::from(p))]),
-            "trianglemesh" => Ok(TriangleMesh::from_props(p, &self.float_textures)),
-            _ => Err(format!("Shape '{}' unknown.", name)),
-        }
+    /// Counts shapes by type. Triangle meshes count their own memory.
+    ///
+    /// * `name`   - Name of the shape type.
+    /// * `shapes` - The shapes.
+    pub fn count_shapes(name: &str, shapes: &[ArcShape]) {
+        let (counter, shape_size) = match name {
+            "cone" => (&N_CONES, size_of::<Cone>()),
+            "curve" => (&N_CURVES, size_of::<Curve>()),
+            "cylinder" => (&N_CYLINDERS, size_of::<Cylinder>()),
+            "disk" => (&N_DISKS, size_of::<Disk>()),
+            "hyperboloid" => (&N_HYPERBOLOIDS, size_of::<Hyperboloid>()),
+            "loopsubdiv" => (&N_LOOP_SUBDIV_TRIANGLES, size_of::<Triangle>()),
+            "paraboloid" => (&N_PARABOLOIDS, size_of::<Paraboloid>()),
+            "sphere" => (&N_SPHERES, size_of::<Sphere>()),
+            "trianglemesh" => (&N_MESH_TRIANGLES, size_of::<Triangle>()),
+            _ => (&N_REGISTERED_SHAPES, 0),
+        };
+        counter.add(shapes.len() as i64);
+        SHAPE_BYTES.add((shapes.len() * shape_size) as i64);
     }
 
     /// Creates the given type of material from parameter set.
     ///
     /// * `name` - Name.
     /// * `mp`   - Parameter set.
-    pub fn make_material(&self, name: &str, mp: &TextureParams) -> Result<ArcMaterial, String> {
+    pub fn make_material(&self, name: &str, mp: &TextureParams) -> Result<ArcMaterial, Error> {
         match name {
-            "matte" => Ok(Arc::new(MatteMaterial::from(mp))),
-            "plastic" => Ok(Arc::new(PlasticMaterial::from(mp))),
-            "fourier" => Ok(Arc::new(FourierMaterial::from(mp))),
             "mix" => {
                 let m1 = mp.find_string("namedmaterial1", String::from(""));
                 let mat1 = match self.named_materials.get(&m1) {
                     Some(mat) => mat.material.clone(),
                     None => {
                         warn!("Named material '{}' undefined. Using 'matte'.", m1);
-                        self.make_material("matte", mp).unwrap()
+                        self.make_material("matte", mp)?
                     }
                 };
 
@@ -265,18 +302,25 @@ impl GraphicsState {
                     Some(mat) => mat.material.clone(),
                     None => {
                         warn!("Named material '{}' undefined. Using 'matte'.", m2);
-                        self.make_material("matte", mp).unwrap()
+                        self.make_material("matte", mp)?
                     }
                 };
 
                 Ok(Arc::new(MixMaterial::from((mp, mat1, mat2))))
             }
-            "" => Err(format!("Unable to create material with no name")),
-            "none" => Err(String::from("Unable to create material 'none'.")),
-            _ => {
-                warn!("Material '{}' unknown. Using 'matte'.", name);
-                Ok(Arc::new(MatteMaterial::from(mp)))
-            }
+            "" => Err(Error::InvalidParameter(String::from(
+                "Unable to create material with no name",
+            ))),
+            "none" => Err(Error::InvalidParameter(String::from(
+                "Unable to create material 'none'.",
+            ))),
+            _ => match material_constructor(name) {
+                Some(constructor) => constructor(mp),
+                None => {
+                    warn!("Material '{}' unknown. Using 'matte'.", name);
+                    Ok(Arc::new(MatteMaterial::from(mp)))
+                }
+            },
         }
     }
 
@@ -289,32 +333,10 @@ impl GraphicsState {
         name: &str,
         tex2world: &Transform,
         tp: &TextureParams,
-    ) -> Result<ArcTexture<Float>, String> {
-        let p = (tp, tex2world);
-        match name {
-            "bilerp" => Ok(Arc::new(BilerpTexture::<Float>::from(p))),
-            "checkerboard" => {
-                let dim = p.0.find_int("dimension", 2);
-                if dim == 2 {
-                    Ok(Arc::new(CheckerboardTexture2D::<Float>::from(p)))
-                } else if dim == 3 {
-                    Ok(Arc::new(CheckerboardTexture3D::<Float>::from(p)))
-                } else {
-                    Err(format!(
-                        "{} dimensional checkerboard texture not supported",
-                        dim
-                    ))
-                }
-            }
-            "constant" => Ok(Arc::new(ConstantTexture::<Float>::from(p))),
-            "dots" => Ok(Arc::new(DotsTexture::<Float>::from(p))),
-            "fbm" => Ok(Arc::new(FBmTexture::<Float>::from(p))),
-            "imagemap" => Ok(Arc::new(ImageTexture::<Float>::from(p))),
-            "mix" => Ok(Arc::new(MixTexture::<Float>::from(p))),
-            "scale" => Ok(Arc::new(ScaleTexture::<Float>::from(p))),
-            "windy" => Ok(Arc::new(WindyTexture::<Float>::from(p))),
-            _ => Err(format!("Float texture '{}' unknown.", name)),
-        }
+    ) -> Result<ArcTexture<Float>, Error> {
+        let constructor = float_texture_constructor(name)
+            .ok_or_else(|| Error::UnknownType(format!("Float texture '{}' unknown.", name)))?;
+        constructor(tp, tex2world)
     }
 
     /// Creates a spectrum texture.
@@ -326,34 +348,10 @@ impl GraphicsState {
         name: &str,
         tex2world: &Transform,
         tp: &TextureParams,
-    ) -> Result<ArcTexture<Spectrum>, String> {
-        let p = (tp, tex2world);
-        match name {
-            "bilerp" => Ok(Arc::new(BilerpTexture::<Spectrum>::from(p))),
-            "checkerboard" => {
-                let dim = p.0.find_int("dimension", 2);
-                if dim == 2 {
-                    Ok(Arc::new(CheckerboardTexture2D::<Spectrum>::from(p)))
-                } else if dim == 3 {
-                    Ok(Arc::new(CheckerboardTexture3D::<Spectrum>::from(p)))
-                } else {
-                    Err(format!(
-                        "{} dimensional checkerboard texture not supported",
-                        dim
-                    ))
-                }
-            }
-            "constant" => Ok(Arc::new(ConstantTexture::<Spectrum>::from(p))),
-            "dots" => Ok(Arc::new(DotsTexture::<Spectrum>::from(p))),
-            "fbm" => Ok(Arc::new(FBmTexture::<Spectrum>::from(p))),
-            "imagemap" => Ok(Arc::new(ImageTexture::<Spectrum>::from(p))),
-            "marble" => Ok(Arc::new(MarbleTexture::from(p))),
-            "mix" => Ok(Arc::new(MixTexture::<Spectrum>::from(p))),
-            "scale" => Ok(Arc::new(ScaleTexture::<Spectrum>::from(p))),
-            "uv" => Ok(Arc::new(UVTexture::from(p))),
-            "windy" => Ok(Arc::new(WindyTexture::<Spectrum>::from(p))),
-            _ => Err(format!("Spectrum texture '{}' unknown.", name)),
-        }
+    ) -> Result<ArcTexture<Spectrum>, Error> {
+        let constructor = spectrum_texture_constructor(name)
+            .ok_or_else(|| Error::UnknownType(format!("Spectrum texture '{}' unknown.", name)))?;
+        constructor(tp, tex2world)
     }
 
     /// Creates a medium.
@@ -362,11 +360,13 @@ impl GraphicsState {
     /// * `medium2world` - Medium to world space transform.
     /// * `paramset`     - Parameter set.
     pub fn make_medium(
-        _name: &str,
-        _medium2world: ArcTransform,
-        _paramset: &ParamSet,
-    ) -> Result<ArcMedium, String> {
-        Err(String::from("GraphicsState::make_medium() not implemented"))
+        name: &str,
+        medium2world: ArcTransform,
+        paramset: &ParamSet,
+    ) -> Result<ArcMedium, Error> {
+        let constructor = medium_constructor(name)
+            .ok_or_else(|| Error::UnknownType(format!("Medium '{}' unknown.", name)))?;
+        constructor(paramset, medium2world)
     }
 
     /// Creates a light.
@@ -380,37 +380,14 @@ impl GraphicsState {
         light2world: ArcTransform,
         medium_interface: &MediumInterface,
         paramset: &ParamSet,
-    ) -> Result<ArcLight, String> {
-        match name {
-            "point" => {
-                let p = (
-                    paramset,
-                    light2world.clone(),
-                    medium_interface.outside.clone(),
-                );
-                Ok(Arc::new(PointLight::from(p)))
-            }
-            "distant" => {
-                let p = (paramset, light2world.clone());
-                Ok(Arc::new(DistantLight::from(p)))
-            }
-            "infinite" => {
-                let p = (paramset, light2world.clone());
-                Ok(Arc::new(InfiniteAreaLight::from(p)))
-            }
-            "exinfinite" => {
-                let p = (paramset, light2world.clone());
-                Ok(Arc::new(InfiniteAreaLight::from(p)))
-            }
-            _ => Err(format!("Light '{}' unknown.", name)),
-        }
+    ) -> Result<ArcLight, Error> {
+        let constructor = light_constructor(name)
+            .ok_or_else(|| Error::UnknownType(format!("Light '{}' unknown.", name)))?;
+        constructor(paramset, light2world, medium_interface)
     }
 
     /// Creates an area light.
     ///
-    /// NOTE: Upcasting from AreaLight -> Light is not possible. So we return
-    /// Result<ArcLight, String>.
-    ///
     /// * `name`             - Name.
     /// * `light2world`      - Light to world space transform.
     /// * `medium_interface` - Medium interface.
@@ -422,7 +399,7 @@ impl GraphicsState {
         medium_interface: &MediumInterface,
         shape: ArcShape,
         paramset: &ParamSet,
-    ) -> Result<ArcLight, String> {
+    ) -> Result<ArcAreaLight, Error> {
         let p = (
             paramset,
             light2world.clone(),
@@ -431,7 +408,7 @@ impl GraphicsState {
         );
         match name {
             "diffuse" => Ok(Arc::new(DiffuseAreaLight::from(p))),
-            _ => Err(format!("AreaLight '{}' unknown.", name)),
+            _ => Err(Error::UnknownType(format!("AreaLight '{}' unknown.", name))),
         }
     }
 
@@ -444,12 +421,15 @@ impl GraphicsState {
         name: &str,
         prims: &Vec<ArcPrimitive>,
         paramset: &ParamSet,
-    ) -> Result<ArcPrimitive, String> {
+    ) -> Result<ArcPrimitive, Error> {
         let p = (paramset, prims);
         match name {
             "bvh" => Ok(Arc::new(BVHAccel::from(p))),
             "kdtree" => Ok(Arc::new(KDTreeAccel::from(p))),
-            _ => Err(format!("Accelerator '{}' unknown.", name)),
+            _ => Err(Error::UnknownType(format!(
+                "Accelerator '{}' unknown.",
+                name
+            ))),
         }
     }
 
@@ -472,7 +452,7 @@ impl GraphicsState {
         transform_end: Float,
         film: Arc<Film>,
         medium_interface: &MediumInterface,
-    ) -> Result<ArcCamera, String> {
+    ) -> Result<ArcCamera, Error> {
         assert!(
             MAX_TRANSFORMS == 2,
             "TransformCache assumes only two transforms"
@@ -499,10 +479,11 @@ impl GraphicsState {
 
         match name {
             "environment" => Ok(Arc::new(EnvironmentCamera::from(p))),
+            "fisheye" => Ok(Arc::new(FisheyeCamera::from(p))),
             "orthographic" => Ok(Arc::new(OrthographicCamera::from(p))),
             "perspective" => Ok(Arc::new(PerspectiveCamera::from(p))),
-            "realistic" => Ok(Arc::new(RealisticCamera::from(p))),
-            _ => Err(format!("Camera '{}' unknown.", name)),
+            "realistic" => Ok(Arc::new(RealisticCamera::try_from(p)?)),
+            _ => Err(Error::UnknownType(format!("Camera '{}' unknown.", name))),
         }
     }
 
@@ -515,7 +496,7 @@ impl GraphicsState {
         name: &str,
         paramset: &ParamSet,
         film: Arc<Film>,
-    ) -> Result<ArcSampler, String> {
+    ) -> Result<ArcSampler, Error> {
         let p = (paramset, film.clone().get_sample_bounds());
 
         match name {
@@ -526,7 +507,7 @@ impl GraphicsState {
             "random" => Ok(Arc::new(RandomSampler::from(p))),
             "sobol" => Ok(Arc::new(SobolSampler::from(p))),
             "stratified" => Ok(Arc::new(StratifiedSampler::from(p))),
-            _ => Err(format!("Sampler '{}' unknown.", name)),
+            _ => Err(Error::UnknownType(format!("Sampler '{}' unknown.", name))),
         }
     }
 
@@ -534,14 +515,14 @@ impl GraphicsState {
     ///
     /// * `name`     - Name.
     /// * `paramset` - Parameter set.
-    pub fn make_filter(name: &str, paramset: &ParamSet) -> Result<ArcFilter, String> {
+    pub fn make_filter(name: &str, paramset: &ParamSet) -> Result<ArcFilter, Error> {
         match name {
             "box" => Ok(Arc::new(BoxFilter::from(paramset))),
             "gaussian" => Ok(Arc::new(GaussianFilter::from(paramset))),
             "mitchell" => Ok(Arc::new(MitchellFilter::from(paramset))),
             "sinc" => Ok(Arc::new(LanczosSincFilter::from(paramset))),
             "triangle" => Ok(Arc::new(TriangleFilter::from(paramset))),
-            _ => Err(format!("Sampler '{}' unknown.", name)),
+            _ => Err(Error::UnknownType(format!("Filter '{}' unknown.", name))),
         }
     }
 
@@ -554,10 +535,10 @@ impl GraphicsState {
         name: &str,
         paramset: &ParamSet,
         filter: ArcFilter,
-    ) -> Result<Arc<Film>, String> {
+    ) -> Result<Arc<Film>, Error> {
         match name {
-            "image" => Ok(Arc::new(Film::from((paramset, filter)))),
-            _ => Err(format!("Film '{}' unknown.", name)),
+            "image" => Ok(Arc::new(Film::try_from((paramset, filter))?)),
+            _ => Err(Error::UnknownType(format!("Film '{}' unknown.", name))),
         }
     }
 }
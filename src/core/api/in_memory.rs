@@ -0,0 +1,214 @@
+//! In-memory Rendering
+
+#![allow(dead_code)]
+use super::Api;
+use crate::core::error::*;
+use crate::core::geometry::*;
+use crate::core::integrator::*;
+use crate::core::parsers::*;
+use crate::core::pbrt::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// An 8-bit RGBA image.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RgbaImage {
+    /// Width in pixels.
+    pub width: usize,
+
+    /// Height in pixels.
+    pub height: usize,
+
+    /// Gamma corrected RGBA values of the pixels in row-major order.
+    pub pixels: Vec<u8>,
+}
+
+/// Callback for the RGBA values of finished tiles.
+pub type TileCallback = Box<dyn Fn(&Bounds2i, &[u8]) + Send + Sync>;
+
+/// A `RenderObserver` that copies finished tiles into an `RgbaImage`.
+pub struct RgbaImageObserver {
+    /// Bounds of the cropped image and the image itself.
+    image: Mutex<(Bounds2i, RgbaImage)>,
+
+    /// Number of pixels of the image that are finished.
+    pixels_finished: AtomicUsize,
+
+    /// Called with the RGBA values of each finished tile.
+    on_tile: TileCallback,
+}
+
+impl RgbaImageObserver {
+    /// Returns a new `RgbaImageObserver`.
+    ///
+    /// * `on_tile` - Called with the pixel bounds of each finished tile
+    ///               within the cropped image and its RGBA values in
+    ///               row-major order.
+    pub fn new(on_tile: TileCallback) -> Self {
+        Self {
+            image: Mutex::new((Bounds2i::default(), RgbaImage::default())),
+            pixels_finished: AtomicUsize::new(0),
+            on_tile,
+        }
+    }
+
+    /// Returns a copy of the image rendered so far.
+    pub fn image(&self) -> RgbaImage {
+        self.image.lock().unwrap().1.clone()
+    }
+
+    /// Returns the fraction of the pixels of the image that are finished.
+    pub fn progress(&self) -> Float {
+        let n_pixels = {
+            let image = &self.image.lock().unwrap().1;
+            image.width * image.height
+        };
+        if n_pixels > 0 {
+            min(self.pixels_finished.load(Ordering::Relaxed), n_pixels) as Float / n_pixels as Float
+        } else {
+            0.0
+        }
+    }
+}
+
+impl RenderObserver for RgbaImageObserver {
+    /// Allocates the image.
+    ///
+    /// * `cropped_pixel_bounds` - Bounds of the pixels of the cropped image.
+    fn render_started(&self, cropped_pixel_bounds: &Bounds2i) {
+        self.pixels_finished.store(0, Ordering::Relaxed);
+        let diagonal = cropped_pixel_bounds.diagonal();
+        let width = max(diagonal.x, 0) as usize;
+        let height = max(diagonal.y, 0) as usize;
+        *self.image.lock().unwrap() = (
+            *cropped_pixel_bounds,
+            RgbaImage {
+                width,
+                height,
+                pixels: vec![0; 4 * width * height],
+            },
+        );
+    }
+
+    /// Copies a tile into the image and passes it on to the callback.
+    ///
+    /// * `tile_bounds` - Sample bounds of the tile in the image.
+    /// * `rgb`         - The RGB values of the film for the pixels of the
+    ///                   tile that are inside the cropped image, in row-major
+    ///                   order.
+    fn tile_finished(&self, tile_bounds: &Bounds2i, rgb: &[Float]) {
+        let rgba: Vec<u8> = rgb
+            .chunks_exact(3)
+            .flat_map(|c| [to_byte(c[0]), to_byte(c[1]), to_byte(c[2]), 255])
+            .collect();
+
+        let bounds = {
+            let mut guard = self.image.lock().unwrap();
+            let (image_bounds, image) = &mut *guard;
+            let bounds = tile_bounds.intersect(image_bounds);
+            if bounds.is_empty() || rgba.len() != 4 * bounds.area() as usize {
+                return;
+            }
+
+            // Copy the rows of the tile.
+            let row_len = 4 * (bounds.p_max.x - bounds.p_min.x) as usize;
+            for (i, row) in rgba.chunks_exact(row_len).enumerate() {
+                let x = (bounds.p_min.x - image_bounds.p_min.x) as usize;
+                let y = (bounds.p_min.y - image_bounds.p_min.y) as usize + i;
+                let offset = 4 * (y * image.width + x);
+                image.pixels[offset..offset + row_len].copy_from_slice(row);
+            }
+
+            // Report bounds relative to the cropped image.
+            Bounds2i::new(
+                Point2i::from(bounds.p_min - image_bounds.p_min),
+                Point2i::from(bounds.p_max - image_bounds.p_min),
+            )
+        };
+
+        self.pixels_finished
+            .fetch_add(bounds.area() as usize, Ordering::Relaxed);
+        (self.on_tile)(&bounds, &rgba);
+    }
+}
+
+/// Renders a scene description in the PBRT file format into an in-memory
+/// RGBA image instead of writing image files. Only the last image is
+/// returned if the scene renders several.
+///
+/// * `scene`   - The scene description. Included files are relative to the
+///               current directory.
+/// * `on_tile` - Called with the pixel bounds of each finished tile within
+///               the image and its RGBA values in row-major order.
+pub fn render_to_rgba<F>(scene: &str, on_tile: F) -> Result<RgbaImage, Error>
+where
+    F: Fn(&Bounds2i, &[u8]) + Send + Sync + 'static,
+{
+    render_with_api_to_rgba(on_tile, |api| {
+        PbrtFileParser::in_memory().parse_source(scene, api)
+    })
+}
+
+/// Sets up an API for rendering into an in-memory RGBA image, lets `f` make
+/// the calls for a scene and returns the last image rendered.
+///
+/// * `on_tile` - Called with the pixel bounds of each finished tile within
+///               the image and its RGBA values in row-major order.
+/// * `f`       - Makes the API calls for the scene.
+pub(crate) fn render_with_api_to_rgba<F, G>(on_tile: F, f: G) -> Result<RgbaImage, Error>
+where
+    F: Fn(&Bounds2i, &[u8]) + Send + Sync + 'static,
+    G: FnOnce(&mut Api) -> Result<(), Error>,
+{
+    let observer = Arc::new(RgbaImageObserver::new(Box::new(on_tile)));
+
+    let mut api = Api::new();
+    api.pbrt_init();
+    api.set_in_memory_output(true);
+    api.add_render_observer(observer.clone());
+    let result = f(&mut api);
+    api.pbrt_cleanup();
+
+    result.map(|_| observer.image())
+}
+
+/// Converts a linear value to a gamma corrected 8-bit value.
+///
+/// * `v` - The value.
+#[inline]
+fn to_byte(v: Float) -> u8 {
+    clamp(255.0 * gamma_correct(v) + 0.5, 0.0, 255.0) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finished_tiles_are_copied_into_image() {
+        let reported = Arc::new(AtomicUsize::new(0));
+        let counter = reported.clone();
+        let observer = RgbaImageObserver::new(Box::new(move |bounds, rgba| {
+            assert_eq!(rgba.len(), 4 * bounds.area() as usize);
+            counter.fetch_add(bounds.area() as usize, Ordering::SeqCst);
+        }));
+
+        // A 4x3 cropped image starting at (2, 1) and a tile overlapping its
+        // lower right corner.
+        observer.render_started(&Bounds2i::new(Point2i::new(2, 1), Point2i::new(6, 4)));
+        let tile = Bounds2i::new(Point2i::new(4, 2), Point2i::new(8, 8));
+        observer.tile_finished(&tile, &[1.0; 3 * 4]);
+
+        let image = observer.image();
+        assert_eq!((image.width, image.height), (4, 3));
+        assert_eq!(reported.load(Ordering::SeqCst), 4);
+        assert_eq!(observer.progress(), 4.0 / 12.0);
+        for y in 0..3 {
+            for x in 0..4 {
+                let expected = if x >= 2 && y >= 1 { 255 } else { 0 };
+                let offset = 4 * (y * 4 + x);
+                assert_eq!(image.pixels[offset..offset + 3], [expected; 3]);
+            }
+        }
+    }
+}
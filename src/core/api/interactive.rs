@@ -0,0 +1,323 @@
+//! Interactive Rendering
+
+#![allow(dead_code)]
+use super::graphics_state::GraphicsState;
+use super::material_instance::MaterialInstance;
+use super::render_options::RenderOptions;
+use super::Api;
+use crate::accelerators::*;
+use crate::core::cancel::*;
+use crate::core::error::*;
+use crate::core::geometry::*;
+use crate::core::integrator::*;
+use crate::core::light::*;
+use crate::core::paramset::*;
+use crate::core::parsers::*;
+use crate::core::primitive::*;
+use crate::core::scene::*;
+use std::sync::Arc;
+
+/// Keeps a parsed scene around so that it can be edited and rendered again.
+/// Edits only invalidate the state that depends on them:
+///
+/// * Camera edits keep the scene and its BVH.
+/// * Material edits swap the material in the primitives that use it without
+///   rebuilding the BVH.
+/// * Object instance transforms refit the BVH instead of rebuilding it.
+///
+/// Each render starts accumulating samples from scratch with a new film.
+/// The BVH is always used as the accelerator since it can be refit.
+pub struct InteractiveSession {
+    /// Render options at the end of the world block without the primitives
+    /// and lights.
+    render_options: RenderOptions,
+
+    /// Graphics state at the end of the world block.
+    graphics_state: GraphicsState,
+
+    /// The accelerator for all primitives.
+    bvh: Arc<BVHAccel>,
+
+    /// The light sources.
+    lights: Vec<ArcLight>,
+
+    /// Index in the BVH primitives of each object instance in the order of
+    /// the `ObjectInstance` calls.
+    instance_indices: Vec<usize>,
+
+    /// The scene; `None` if the geometry changed since it was created.
+    scene: Option<Arc<Scene>>,
+
+    /// Used to stop rendering early.
+    cancellation_token: CancellationToken,
+
+    /// Observers notified of rendering progress.
+    render_observers: Vec<ArcRenderObserver>,
+}
+
+impl InteractiveSession {
+    /// Returns a new `InteractiveSession` that takes the primitives and
+    /// lights of the render options.
+    ///
+    /// * `render_options`     - Render options at the end of the world
+    ///                          block.
+    /// * `graphics_state`     - Graphics state at the end of the world block.
+    /// * `cancellation_token` - Used to stop rendering early.
+    /// * `render_observers`   - Observers notified of rendering progress.
+    pub(super) fn new(
+        render_options: &mut RenderOptions,
+        graphics_state: &GraphicsState,
+        cancellation_token: CancellationToken,
+        render_observers: Vec<ArcRenderObserver>,
+    ) -> Self {
+        let primitives = std::mem::take(&mut render_options.primitives);
+        let instance_uses = std::mem::take(&mut render_options.instance_uses);
+        let lights = std::mem::take(&mut render_options.lights);
+        if render_options.accelerator_name != "bvh" {
+            warn!(
+                "Accelerator '{}' can't be refit. Using BVH.",
+                render_options.accelerator_name
+            );
+        }
+        let bvh = BVHAccel::from((&render_options.accelerator_params, &primitives));
+
+        // The BVH reorders the primitives so find the object instances.
+        let instance_indices = instance_uses
+            .iter()
+            .filter_map(|instance| bvh.primitives.iter().position(|p| Arc::ptr_eq(p, instance)))
+            .collect();
+
+        Self {
+            render_options: render_options.clone(),
+            graphics_state: graphics_state.clone(),
+            bvh: Arc::new(bvh),
+            lights,
+            instance_indices,
+            scene: None,
+            cancellation_token,
+            render_observers,
+        }
+    }
+
+    /// Parses a scene description in the PBRT file format and returns a
+    /// session for rendering it interactively. Images are kept in memory so
+    /// they should be captured with a `RenderObserver`.
+    ///
+    /// * `scene`     - The scene description. Included files are relative to
+    ///                 the current directory.
+    /// * `observers` - Observers notified of rendering progress.
+    pub fn from_source(scene: &str, observers: Vec<ArcRenderObserver>) -> Result<Self, Error> {
+        let mut api = Api::new();
+        api.pbrt_init();
+        api.set_in_memory_output(true);
+        api.set_interactive(true);
+        for observer in observers {
+            api.add_render_observer(observer);
+        }
+        let result = PbrtFileParser::in_memory().parse_source(scene, &mut api);
+        let session = api.take_interactive_session();
+        api.pbrt_cleanup();
+
+        result?;
+        session.ok_or_else(|| {
+            Error::InvalidState(String::from("Scene description has no 'WorldEnd'."))
+        })
+    }
+
+    /// Returns the token used to stop rendering early.
+    pub fn cancellation_token(&self) -> CancellationToken {
+        self.cancellation_token.clone()
+    }
+
+    /// Returns the number of object instances that can be moved with
+    /// `set_instance_transform()`.
+    pub fn instance_count(&self) -> usize {
+        self.instance_indices.len()
+    }
+
+    /// Moves the camera. The scene is kept as is.
+    ///
+    /// * `camera_to_world` - The camera to world transformation.
+    pub fn set_camera_to_world(&mut self, camera_to_world: &Transform) {
+        let t = Arc::new(*camera_to_world);
+        self.render_options.camera_to_world[0] = t.clone();
+        self.render_options.camera_to_world[1] = t;
+    }
+
+    /// Returns the camera parameters to edit, e.g. the field of view. The
+    /// scene is kept as is.
+    pub fn camera_params_mut(&mut self) -> &mut ParamSet {
+        &mut self.render_options.camera_params
+    }
+
+    /// Redefines a named material. Primitives that use the previous
+    /// definition get the new material; the BVH is kept as is. Primitives
+    /// whose shapes override material parameters have their own material
+    /// and keep it.
+    ///
+    /// * `name`   - Material name.
+    /// * `params` - Material parameters including its `type`.
+    pub fn set_named_material(&mut self, name: &str, params: &ParamSet) -> Result<(), Error> {
+        let old = self
+            .graphics_state
+            .named_materials
+            .get(name)
+            .map(|mtli| mtli.material.clone())
+            .ok_or_else(|| {
+                Error::InvalidParameter(format!("Named material '{}' unknown.", name))
+            })?;
+
+        let mat_name = params.find_one_string("type", String::new());
+        if mat_name.is_empty() {
+            return Err(Error::MissingParameter(String::from(
+                "No parameter string 'type' found for named material.",
+            )));
+        }
+        let mp = TextureParams::new(
+            params.clone(),
+            ParamSet::new(),
+            self.graphics_state.float_textures.clone(),
+            self.graphics_state.spectrum_textures.clone(),
+        );
+        let new = self.graphics_state.make_material(&mat_name, &mp)?;
+
+        if let Some(primitives) = replace_material(&self.bvh.primitives, &old, &new) {
+            Arc::make_mut(&mut self.bvh).primitives = primitives;
+            self.scene = None;
+        }
+
+        let mtli = Arc::new(MaterialInstance::new(&mat_name, new, params));
+        self.graphics_state
+            .named_materials
+            .insert(String::from(name), mtli);
+        Ok(())
+    }
+
+    /// Moves an object instance and refits the BVH.
+    ///
+    /// * `index`             - Index of the instance in the order of the
+    ///                         `ObjectInstance` calls.
+    /// * `instance_to_world` - The instance to world transformation.
+    pub fn set_instance_transform(
+        &mut self,
+        index: usize,
+        instance_to_world: &Transform,
+    ) -> Result<(), Error> {
+        let i = *self.instance_indices.get(index).ok_or_else(|| {
+            Error::InvalidParameter(format!("Object instance {} unknown.", index))
+        })?;
+
+        let t = Arc::new(*instance_to_world);
+        let animated_instance2world = AnimatedTransform::new(
+            t.clone(),
+            t,
+            self.render_options.transform_start_time,
+            self.render_options.transform_end_time,
+        );
+        let bvh = Arc::make_mut(&mut self.bvh);
+        if let Some(primitive) = bvh.primitives[i].with_transform(animated_instance2world) {
+            bvh.primitives[i] = primitive;
+            bvh.refit();
+            self.scene = None;
+        }
+        Ok(())
+    }
+
+    /// Renders the scene with the current edits.
+    pub fn render(&mut self) -> Result<(), Error> {
+        let scene = match &self.scene {
+            Some(scene) => scene.clone(),
+            None => {
+                let aggregate: ArcPrimitive = self.bvh.clone();
+                let scene = Arc::new(Scene::new(aggregate, self.lights.clone()));
+                self.scene = Some(scene.clone());
+                scene
+            }
+        };
+
+        // A new camera and film restart the accumulation of samples.
+        let mut integrator = self.render_options.make_integrator(&self.graphics_state)?;
+        Arc::get_mut(&mut integrator).unwrap().render(
+            scene,
+            &self.cancellation_token,
+            &self.render_observers,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::material::*;
+    use crate::core::medium::*;
+    use crate::core::primitives::*;
+    use crate::materials::*;
+    use crate::shapes::*;
+    use std::sync::Mutex;
+
+    #[test]
+    fn edits_keep_bvh_structure() {
+        let matte: ArcMaterial = Arc::new(MatteMaterial::from(&TextureParams::default()));
+        let identity = Arc::new(Transform::default());
+        let sphere = Arc::new(Sphere::new(
+            identity.clone(),
+            identity.clone(),
+            false,
+            1.0,
+            -1.0,
+            1.0,
+            360.0,
+        ));
+        let ball: ArcPrimitive = Arc::new(GeometricPrimitive::new(
+            sphere,
+            Some(matte.clone()),
+            None,
+            MediumInterface::new(None, None),
+        ));
+
+        let mut render_options = RenderOptions::new();
+        for x in [0.0, 10.0] {
+            let t = Arc::new(Transform::translate(&Vector3f::new(x, 0.0, 0.0)));
+            let instance: ArcPrimitive = Arc::new(TransformedPrimitive::new(
+                ball.clone(),
+                AnimatedTransform::new(t.clone(), t, 0.0, 1.0),
+            ));
+            render_options.instance_uses.push(instance.clone());
+            render_options.primitives.push(instance);
+        }
+        let mut graphics_state = GraphicsState::new(Arc::new(Mutex::new(Default::default())));
+        graphics_state.named_materials.insert(
+            String::from("ball"),
+            Arc::new(MaterialInstance::new("ball", matte, &ParamSet::new())),
+        );
+        let mut session = InteractiveSession::new(
+            &mut render_options,
+            &graphics_state,
+            CancellationToken::new(),
+            vec![],
+        );
+        assert_eq!(session.instance_count(), 2);
+        let nodes = session.bvh.nodes.len();
+
+        session
+            .set_instance_transform(1, &Transform::translate(&Vector3f::new(0.0, 20.0, 0.0)))
+            .unwrap();
+        let bounds = session.bvh.nodes[0].bounds;
+        assert_eq!(bounds.p_min, Point3f::new(-1.0, -1.0, -1.0));
+        assert_eq!(bounds.p_max, Point3f::new(1.0, 21.0, 1.0));
+        assert!(session
+            .set_instance_transform(2, &Transform::default())
+            .is_err());
+
+        let mut params = ParamSet::new();
+        params.add_string("type", &[String::from("plastic")]);
+        session.set_named_material("ball", &params).unwrap();
+        assert_eq!(session.bvh.nodes.len(), nodes);
+        let new = &session.graphics_state.named_materials["ball"].material;
+        assert!(session
+            .bvh
+            .primitives
+            .iter()
+            .all(|p| p.replace_material(new, new).is_some()));
+    }
+}
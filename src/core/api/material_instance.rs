@@ -7,7 +7,7 @@ use crate::core::paramset::*;
 /// Stores a reference to a named material and its parameters.
 #[derive(Clone)]
 pub struct MaterialInstance {
-    /// The name.
+    /// The material type name, e.g. "matte"; also for named materials.
     pub name: String,
 
     /// Reference to the material.
@@ -20,7 +20,7 @@ pub struct MaterialInstance {
 impl MaterialInstance {
     /// Create a new `MaterialInstance`.
     ///
-    /// * `name`     - The name.
+    /// * `name`     - The material type name.
     /// * `material` - Reference to the material.
     /// * `params`   - Parameters.
     pub fn new(name: &str, material: ArcMaterial, params: &ParamSet) -> Self {
@@ -1,30 +1,65 @@
 //! The API
 
 #![allow(dead_code)]
+mod api_call;
+mod builder;
+mod export;
 mod graphics_state;
+mod in_memory;
+mod interactive;
 mod material_instance;
+mod registry;
 mod render_options;
 mod transform_cache;
 mod transform_set;
 
 use crate::accelerators::*;
+use crate::core::app::OPTIONS;
+use crate::core::cancel::*;
+use crate::core::error::*;
+use crate::core::fileutil::absolute_path;
+use crate::core::film::Film;
 use crate::core::geometry::*;
+use crate::core::integrator::*;
 use crate::core::light::*;
+use crate::core::logging::take_log_messages;
 use crate::core::medium::*;
+use crate::core::parallel::init_thread_pool;
 use crate::core::paramset::*;
 use crate::core::pbrt::*;
+use crate::core::plugin::load_plugin;
 use crate::core::primitive::*;
 use crate::core::primitives::*;
+use crate::core::profiler::*;
+use crate::core::report::*;
+use crate::core::scene::*;
 use crate::core::spectrum::*;
+use crate::core::stats::*;
 use crate::core::texture::*;
+pub use api_call::*;
+pub use builder::*;
+pub use export::*;
 use graphics_state::*;
+pub use in_memory::*;
+pub use interactive::*;
 use material_instance::*;
+pub use registry::*;
 use render_options::*;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
+use std::mem::size_of;
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use transform_cache::*;
 use transform_set::*;
 
+stat_counter!("Scene/Lights", N_LIGHTS);
+stat_counter!("Scene/Area lights", N_AREA_LIGHTS);
+stat_counter!("Scene/Infinite lights", N_INFINITE_LIGHTS);
+stat_counter!("Scene/Primitives", N_PRIMITIVES);
+stat_counter!("Scene/Object instances created", N_OBJECT_INSTANCES_CREATED);
+stat_counter!("Scene/Object instances used", N_OBJECT_INSTANCES_USED);
+stat_memory_counter!("Memory/Primitives", PRIMITIVE_BYTES);
+
 /// Map of floating point textures.
 pub type FloatTextureMap = HashMap<String, ArcTexture<Float>>;
 
@@ -45,6 +80,19 @@ pub enum ApiState {
     WorldBlock,
 }
 
+/// Frames of an animation sequence to render from a world block.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum FrameSequence {
+    /// Render a single numbered frame. Used for per-frame scene files.
+    Single(usize),
+
+    /// Render frames `first..=last` from the same scene. The interval between
+    /// `TransformTimes` is split evenly among the frames and the camera's
+    /// shutter is mapped into each frame's slot; geometry and acceleration
+    /// structures are built once and reused for every frame.
+    Range(usize, usize),
+}
+
 /// State of the PBRT API.
 pub struct Api {
     /// Current state of parsing PBRT file.
@@ -77,6 +125,36 @@ pub struct Api {
 
     /// Caches the transforms.
     transform_cache: Arc<Mutex<TransformCache>>,
+
+    /// Frames of an animation sequence to render at `WorldEnd`.
+    frames: Option<FrameSequence>,
+
+    /// Used to stop rendering early.
+    cancellation_token: CancellationToken,
+
+    /// Observers notified of rendering progress.
+    render_observers: Vec<ArcRenderObserver>,
+
+    /// Keep the scene for interactive rendering at `WorldEnd` instead of
+    /// rendering it.
+    interactive: bool,
+
+    /// The scene kept at the last `WorldEnd` in interactive mode.
+    interactive_session: Option<InteractiveSession>,
+
+    /// Refuse options that load code, for scenes from untrusted sources.
+    sandboxed: bool,
+
+    /// The API calls recorded for the scene cache.
+    recording: Option<SceneRecording>,
+
+    /// Shapes restored from the scene cache that are used, in order, by the
+    /// `Shape` calls of a replay.
+    restored_shapes: Option<VecDeque<RecordedShapes>>,
+
+    /// Location of the scene file statement being processed; used to report
+    /// errors.
+    source_location: Option<SourceLocation>,
 }
 
 impl Api {
@@ -94,9 +172,91 @@ impl Api {
             pushed_transforms: vec![],
             pushed_active_transform_bits: vec![],
             transform_cache: transform_cache.clone(),
+            frames: None,
+            cancellation_token: CancellationToken::new(),
+            render_observers: vec![],
+            interactive: false,
+            interactive_session: None,
+            sandboxed: false,
+            recording: None,
+            restored_shapes: None,
+            source_location: None,
         }
     }
 
+    /// Returns the token used to stop rendering early. Cancelling it stops
+    /// the current render, writes the partially rendered image and skips
+    /// any remaining frames.
+    pub fn cancellation_token(&self) -> CancellationToken {
+        self.cancellation_token.clone()
+    }
+
+    /// Replaces the token used to stop rendering early, e.g. to share one
+    /// that is created before the scene is parsed.
+    ///
+    /// * `cancellation_token` - The token.
+    pub fn set_cancellation_token(&mut self, cancellation_token: CancellationToken) {
+        self.cancellation_token = cancellation_token;
+    }
+
+    /// Sets the location of the scene file statement that the following calls
+    /// come from so errors can point to it.
+    ///
+    /// * `location` - Location of the statement; `None` if it is unknown.
+    pub fn set_source_location(&mut self, location: Option<SourceLocation>) {
+        self.source_location = location;
+    }
+
+    /// Returns the location of the scene file statement being processed.
+    pub fn source_location(&self) -> Option<&SourceLocation> {
+        self.source_location.as_ref()
+    }
+
+    /// Logs an error with the location of the statement that caused it.
+    ///
+    /// * `err` - The error.
+    fn report_error(&self, err: Error) {
+        error!("{}", err.at(self.source_location.as_ref()));
+    }
+
+    /// Registers an observer that is notified of the progress of subsequent
+    /// renders.
+    ///
+    /// * `observer` - The observer.
+    pub fn add_render_observer(&mut self, observer: ArcRenderObserver) {
+        self.render_observers.push(observer);
+    }
+
+    /// Keep the images of subsequent renders in memory instead of writing
+    /// them to files. They can be captured with a `RenderObserver`.
+    ///
+    /// * `in_memory` - Whether or not to keep images in memory.
+    pub fn set_in_memory_output(&mut self, in_memory: bool) {
+        self.render_options.in_memory = in_memory;
+    }
+
+    /// Keep the scene at `WorldEnd` for interactive rendering instead of
+    /// rendering it. The scene can be retrieved with
+    /// `take_interactive_session()`.
+    ///
+    /// * `interactive` - Whether or not to render interactively.
+    pub fn set_interactive(&mut self, interactive: bool) {
+        self.interactive = interactive;
+    }
+
+    /// Refuse options that load code, e.g. `"string plugin"`, for scenes
+    /// from untrusted sources, even with `--allow-scene-plugins`.
+    ///
+    /// * `sandboxed` - Whether or not to refuse them.
+    pub fn set_sandboxed(&mut self, sandboxed: bool) {
+        self.sandboxed = sandboxed;
+    }
+
+    /// Returns the scene kept at the last `WorldEnd` in interactive mode.
+    pub fn take_interactive_session(&mut self) -> Option<InteractiveSession> {
+        self.interactive_session.take()
+    }
+
     /* API Methods */
 
     /// API Initialization.
@@ -105,6 +265,18 @@ impl Api {
             error!("pbrt_init() has already been called.");
         }
         self.current_api_state = ApiState::OptionsBlock;
+
+        // RGB values in the scene description are interpreted in the rendering
+        // color space, so it must be set before any parsing.
+        set_rendering_color_space(OPTIONS.color_space);
+
+        // The render report includes the time of each phase.
+        set_profiler_enabled(OPTIONS.profile || OPTIONS.report);
+
+        // Rendering uses the global thread pool.
+        if let Err(err) = init_thread_pool(OPTIONS.n_threads) {
+            warn!("Unable to use {} threads. {}", OPTIONS.n_threads, err);
+        }
     }
 
     /// API Cleanup.
@@ -117,6 +289,18 @@ impl Api {
         self.current_api_state = ApiState::Uninitialized;
     }
 
+    /// Set the frames of an animation sequence to render from subsequent world
+    /// blocks; `None` renders a single image.
+    ///
+    /// * `frames` - The frames to render.
+    pub fn pbrt_frames(&mut self, frames: Option<FrameSequence>) {
+        if self.current_api_state == ApiState::WorldBlock {
+            error!("pbrt_frames() called while inside world block. Ignoring.");
+        } else {
+            self.frames = frames;
+        }
+    }
+
     /// Set current tranformation matrix to the identity matrix.
     pub fn pbrt_identity(&mut self) {
         if self.verify_initialized("Identity") {
@@ -154,12 +338,14 @@ impl Api {
     ///          |3, 7, 11, 15|
     pub fn pbrt_transform(&mut self, tr: &[Float; 16]) {
         if self.verify_initialized("Transform") {
+            let transform = Arc::new(Transform::from(Matrix4x4::new(
+                tr[0], tr[4], tr[8], tr[12], tr[1], tr[5], tr[9], tr[13], tr[2], tr[6], tr[10],
+                tr[14], tr[3], tr[7], tr[11], tr[15],
+            )));
             for i in 0..MAX_TRANSFORMS {
-                let t = Transform::from(Matrix4x4::new(
-                    tr[0], tr[4], tr[8], tr[12], tr[1], tr[5], tr[9], tr[13], tr[2], tr[6], tr[10],
-                    tr[14], tr[3], tr[7], tr[11], tr[15],
-                ));
-                self.current_transforms[i] = Arc::new(t);
+                if self.active_transform_bits & (1 << i) > 0 {
+                    self.current_transforms[i] = transform.clone();
+                }
             }
         }
     }
@@ -178,8 +364,10 @@ impl Api {
                 tr[14], tr[3], tr[7], tr[11], tr[15],
             ));
             for i in 0..MAX_TRANSFORMS {
-                let t = *self.current_transforms[i] * transform;
-                self.current_transforms[i] = Arc::new(t);
+                if self.active_transform_bits & (1 << i) > 0 {
+                    let t = *self.current_transforms[i] * transform;
+                    self.current_transforms[i] = Arc::new(t);
+                }
             }
         }
     }
@@ -307,6 +495,60 @@ impl Api {
         }
     }
 
+    /// Set global options. `"string plugin"` loads plugins providing additional
+    /// shape, material and texture types; it is refused unless the
+    /// `--allow-scene-plugins` command line option is given and the scene
+    /// isn't sandboxed. Otherwise plugins are loaded with `load_plugin()`,
+    /// e.g. from the `--plugin` command line option.
+    ///
+    /// * `params` - Options.
+    pub fn pbrt_option(&mut self, params: &ParamSet) {
+        if !self.verify_options("Option") {
+            return;
+        }
+
+        for path in params.find_string("plugin") {
+            if let Err(err) = self.check_scene_plugin(path, OPTIONS.allow_scene_plugins) {
+                self.report_error(err);
+                continue;
+            }
+            if let Err(err) = absolute_path(path).and_then(|path| load_plugin(&path)) {
+                self.report_error(err);
+            }
+        }
+
+        let names = params
+            .iter_strings()
+            .map(|(name, _)| name)
+            .chain(params.iter_bools().map(|(name, _)| name))
+            .chain(params.iter_ints().map(|(name, _)| name))
+            .chain(params.iter_floats().map(|(name, _)| name));
+        for name in names.filter(|&name| name != "plugin") {
+            warn!("Option '{}' unknown. Ignoring.", name);
+        }
+    }
+
+    /// Returns an error if a scene file can't load a plugin.
+    ///
+    /// * `path`    - Path of the plugin library.
+    /// * `allowed` - Whether scene files can load plugins at all.
+    fn check_scene_plugin(&self, path: &str, allowed: bool) -> Result<(), Error> {
+        if self.sandboxed {
+            Err(Error::InvalidParameter(format!(
+                "Plugin '{}' refused. Scenes from untrusted sources can't load plugins.",
+                path
+            )))
+        } else if !allowed {
+            Err(Error::InvalidParameter(format!(
+                "Plugin '{}' refused. Use '--allow-scene-plugins' to let scene files \
+                 load plugins or load it with '--plugin'.",
+                path
+            )))
+        } else {
+            Ok(())
+        }
+    }
+
     /// Set the filter type and parameters used for the film.
     ///
     /// * `name`   - Filter type name.
@@ -324,8 +566,14 @@ impl Api {
     /// * `params` - Film parameters.
     pub fn pbrt_film(&mut self, film_type: String, params: &ParamSet) {
         if self.verify_options("Film") {
-            self.render_options.film_name = film_type;
-            self.render_options.film_params = params.clone();
+            // A film following a named camera belongs to that camera.
+            match self.render_options.named_cameras.last_mut() {
+                Some(camera) => camera.film = Some((film_type, params.clone())),
+                None => {
+                    self.render_options.film_name = film_type;
+                    self.render_options.film_params = params.clone();
+                }
+            }
         }
     }
 
@@ -363,15 +611,39 @@ impl Api {
     }
 
     /// Set the camera type and parameters. Also sets the camera-to-world transformation
-    /// using the inverse of the current transformation matrices.
+    /// using the inverse of the current transformation matrices. A camera with
+    /// `"string name"` is added to the cameras that each render the scene.
     ///
     /// * `name`   - Camera type name.
     /// * `params` - Camera parameters.
     pub fn pbrt_camera(&mut self, name: String, params: &ParamSet) {
         if self.verify_options("Camera") {
-            self.render_options.camera_name = name;
+            self.render_options.camera_name = name.clone();
             self.render_options.camera_params = params.clone();
             self.render_options.camera_to_world = self.current_transforms.inverse();
+            self.render_options.camera_medium = self.graphics_state.current_outside_medium.clone();
+            self.named_coordinate_systems
+                .insert(String::from("camera"), self.current_transforms.clone());
+
+            let camera_name = params.find_one_string("name", String::new());
+            if !camera_name.is_empty() {
+                let cameras = &mut self.render_options.named_cameras;
+                if cameras.iter().any(|c| c.name == camera_name) {
+                    error!(
+                        "Camera '{}' defined more than once. Ignoring it.",
+                        camera_name
+                    );
+                } else {
+                    cameras.push(NamedCamera {
+                        name: camera_name,
+                        camera_name: name,
+                        camera_params: params.clone(),
+                        camera_to_world: self.current_transforms.inverse(),
+                        camera_medium: self.graphics_state.current_outside_medium.clone(),
+                        film: None,
+                    });
+                }
+            }
         }
     }
 
@@ -387,10 +659,15 @@ impl Api {
             if medium_type.is_empty() {
                 error!("No parameter string 'type' found in MakeNamedMedium.");
             } else {
-                if let Ok(medium) =
-                    GraphicsState::make_medium(&name, self.current_transforms[0].clone(), params)
-                {
-                    self.render_options.named_media.insert(name, medium);
+                match GraphicsState::make_medium(
+                    &medium_type,
+                    self.current_transforms[0].clone(),
+                    params,
+                ) {
+                    Ok(medium) => {
+                        self.render_options.named_media.insert(name, medium);
+                    }
+                    Err(err) => error!("Unable to create medium '{}'. {}", name, err),
                 }
             }
         }
@@ -429,21 +706,45 @@ impl Api {
                 warn!("Missing end to pbrtAttributeBegin().");
                 self.pushed_graphics_states.pop();
                 self.pushed_transforms.pop();
+                self.pushed_active_transform_bits.pop();
             }
 
             while self.pushed_transforms.len() > 0 {
                 warn!("Missing end to pbrtTransformBegin().");
                 self.pushed_transforms.pop();
+                self.pushed_active_transform_bits.pop();
+            }
+
+            if let Some(name) = self.render_options.current_instance.take() {
+                warn!("Missing end to pbrtObjectBegin() for '{}'.", name);
             }
 
             // Create scene and render.
-            let mut integrator = match self.render_options.make_integrator(&self.graphics_state) {
-                Ok(integrator) => integrator,
-                Err(err) => panic!("Error creating integrator. {}", err),
+            let result = if self.interactive {
+                self.interactive_session = Some(InteractiveSession::new(
+                    &mut self.render_options,
+                    &self.graphics_state,
+                    self.cancellation_token.clone(),
+                    self.render_observers.clone(),
+                ));
+                Ok(())
+            } else if let Some(path) = OPTIONS.export.as_ref() {
+                info!("Not rendering the scene; it is exported to '{}'.", path);
+                Ok(())
+            } else if OPTIONS.stats_only {
+                let scene = self.render_options.make_scene();
+                print!("{}", scene.stats());
+                report_thread_stats();
+                print_stats();
+                println!("{}", total_memory_report());
+                clear_stats();
+                Ok(())
+            } else {
+                self.render_frames()
             };
-
-            let scene = self.render_options.make_scene();
-            Arc::get_mut(&mut integrator).unwrap().render(scene);
+            if let Err(err) = result {
+                error!("Error rendering scene. {}", err);
+            }
 
             // Clean up after rendering.
             let mut transform_cache = self.transform_cache.lock().unwrap();
@@ -484,6 +785,7 @@ impl Api {
                 self.graphics_state = graphics_state;
             } else {
                 error!("Unmatched pbrtAttributeEnd() encountered. Ignoring it.");
+                return;
             }
             if let Some(transforms) = self.pushed_transforms.pop() {
                 self.current_transforms = transforms;
@@ -507,10 +809,13 @@ impl Api {
     /// Restore the transformation matrix off the stack.
     pub fn pbrt_transform_end(&mut self) {
         if self.verify_world("TransformEnd") {
+            // Transforms pushed by `AttributeBegin` are restored by its end.
+            if self.pushed_transforms.len() <= self.pushed_graphics_states.len() {
+                error!("Unmatched pbrtTransformEnd() encountered. Ignoring it.");
+                return;
+            }
             if let Some(transforms) = self.pushed_transforms.pop() {
                 self.current_transforms = transforms;
-            } else {
-                error!("Unmatched pbrtTransformEnd() encountered. Ignoring it.");
             }
             if let Some(active_transform_bits) = self.pushed_active_transform_bits.pop() {
                 self.active_transform_bits = active_transform_bits;
@@ -591,6 +896,12 @@ impl Api {
     /// * `params` - Material parameters.
     pub fn pbrt_material(&mut self, name: String, params: &ParamSet) {
         if self.verify_world("Material") {
+            // Shapes without a material only bound participating media.
+            if name == "none" || name == "interface" {
+                self.graphics_state.current_material = None;
+                return;
+            }
+
             let empty_params = ParamSet::new();
             let mut mp = TextureParams::new(
                 params.clone(),
@@ -634,7 +945,7 @@ impl Api {
                         self.graphics_state.named_materials = nm;
                         self.graphics_state.named_materials_shared = false;
                     }
-                    let mtli = Arc::new(MaterialInstance::new(&name, mtl.clone(), params));
+                    let mtli = Arc::new(MaterialInstance::new(&mat_name, mtl.clone(), params));
                     self.graphics_state.named_materials.insert(name, mtli);
                 }
             }
@@ -665,8 +976,14 @@ impl Api {
             let mi = self.create_medium_interface();
             let light2world = self.current_transforms[0].clone();
             match GraphicsState::make_light(&name, light2world, &mi, params) {
-                Ok(lt) => self.render_options.lights.push(lt),
-                Err(err) => error!("{}", err),
+                Ok(lt) => {
+                    N_LIGHTS.inc();
+                    if lt.get_type().matches(INFINITE_LIGHT) {
+                        N_INFINITE_LIGHTS.inc();
+                    }
+                    self.render_options.lights.push(lt);
+                }
+                Err(err) => self.report_error(err),
             }
         }
     }
@@ -682,93 +999,136 @@ impl Api {
         }
     }
 
+    /// Define the groups of an OBJ file as triangle meshes with the pbrt
+    /// materials closest to their MTL materials. Groups without a known
+    /// material use the current material.
+    ///
+    /// * `params` - Parameters of the `objmesh` shape.
+    fn obj_mesh_with_materials(&mut self, params: &ParamSet) {
+        if !self.verify_world("Shape") {
+            return;
+        }
+
+        match ApiCall::obj_mesh(params) {
+            Ok(calls) => {
+                for call in calls.iter() {
+                    self.dispatch(call);
+                }
+            }
+            Err(err) => self.report_error(err),
+        }
+    }
+
     /// Define a shape.
     ///
     /// * `name`   - Shape type (e.g. sphere, cone, etc)
     /// * `params` - Shape parameters.
     pub fn pbrt_shape(&mut self, name: String, params: &ParamSet) {
+        if name == "objmesh" && params.find_one_bool("usemtl", false) {
+            self.obj_mesh_with_materials(params);
+            return;
+        }
+
         if self.verify_world("Shape") {
             let mut prims: Vec<ArcPrimitive> = vec![];
-            let mut area_lights: Vec<ArcLight> = vec![]; // Upcasting AreaLight -> Light not possible.
+            let mut area_lights: Vec<ArcLight> = vec![];
+            let holdout = params.find_one_bool("holdout", false);
 
-            if self.current_transforms.is_animated() {
+            if !self.current_transforms.is_animated() {
                 // Initialize `prims` and `area_lights` for static shape.
 
                 // Create shapes for shape `name`.
-                let mut transform_cache = self.transform_cache.lock().unwrap();
                 let tr = self.current_transforms[0].clone();
                 let tr_inv = Arc::new(tr.inverse());
-                let obj2world = transform_cache.lookup(tr.clone());
-                let world2obj = transform_cache.lookup(tr_inv);
-                let shapes = self
-                    .graphics_state
-                    .make_shape(
-                        &name,
-                        obj2world.clone(),
-                        world2obj.clone(),
-                        self.graphics_state.reverse_orientation,
-                        params,
-                    )
-                    .unwrap();
-
-                if shapes.is_empty() {
-                    return;
-                }
+                let (obj2world, world2obj) = {
+                    let mut transform_cache = self.transform_cache.lock().unwrap();
+                    (transform_cache.lookup(tr), transform_cache.lookup(tr_inv))
+                };
+                let shapes = match self.make_shape(&name, obj2world, world2obj, params) {
+                    Ok(shapes) if !shapes.is_empty() => shapes,
+                    Ok(_) => return,
+                    Err(err) => {
+                        self.report_error(err);
+                        return;
+                    }
+                };
 
-                let mtl = self.graphics_state.get_material_for_shape(params).unwrap();
+                let mtl = match self.graphics_state.get_material_for_shape(params) {
+                    Ok(mtl) => mtl,
+                    Err(err) => {
+                        self.report_error(err);
+                        return;
+                    }
+                };
                 let mi = self.create_medium_interface();
 
                 for shape in shapes.iter() {
                     // Possibly create area light for shape.
+                    let mut area: Option<ArcAreaLight> = None;
                     if let Some(area_light) = self.graphics_state.area_light.clone() {
-                        if let Ok(area) = GraphicsState::make_area_light(
+                        match GraphicsState::make_area_light(
                             &area_light,
                             self.current_transforms[0].clone(),
                             &mi,
                             shape.clone(),
-                            params,
+                            &self.graphics_state.area_light_params,
                         ) {
-                            area_lights.push(area);
+                            Ok(a) => {
+                                N_LIGHTS.inc();
+                                N_AREA_LIGHTS.inc();
+                                area_lights.push(a.clone());
+                                area = Some(a);
+                            }
+                            Err(err) => self.report_error(err),
                         }
                     }
 
-                    let prim =
-                        GeometricPrimitive::new(shape.clone(), mtl.clone(), None, mi.clone());
+                    let mut prim =
+                        GeometricPrimitive::new(shape.clone(), mtl.clone(), area, mi.clone());
+                    prim.holdout = holdout;
                     prims.push(Arc::new(prim));
+                    N_PRIMITIVES.inc();
+                    PRIMITIVE_BYTES.add(size_of::<GeometricPrimitive>() as i64);
                 }
             } else {
                 // Initialize `prims` and `area_lights` for animated shape.
 
                 // Create initial shape or shapes for animated shape.
-                if self.graphics_state.area_light.is_none() {
+                if self.graphics_state.area_light.is_some() {
                     warn!("Ignoring currently set area light when creating 'animated shape'.");
                 }
 
-                let mut transform_cache = self.transform_cache.lock().unwrap();
-                let identity = transform_cache.lookup(Arc::new(Transform::default()));
-                let shapes = self
-                    .graphics_state
-                    .make_shape(
-                        &name,
-                        identity.clone(),
-                        identity.clone(),
-                        self.graphics_state.reverse_orientation,
-                        params,
-                    )
-                    .unwrap();
-
-                if shapes.is_empty() {
-                    return;
-                }
+                let identity = self
+                    .transform_cache
+                    .lock()
+                    .unwrap()
+                    .lookup(Arc::new(Transform::default()));
+                let shapes = match self.make_shape(&name, identity.clone(), identity, params) {
+                    Ok(shapes) if !shapes.is_empty() => shapes,
+                    Ok(_) => return,
+                    Err(err) => {
+                        self.report_error(err);
+                        return;
+                    }
+                };
 
                 // Create `GeometricPrimitive`(s) for animated shape.
-                let mtl = self.graphics_state.get_material_for_shape(params).unwrap();
+                let mtl = match self.graphics_state.get_material_for_shape(params) {
+                    Ok(mtl) => mtl,
+                    Err(err) => {
+                        self.report_error(err);
+                        return;
+                    }
+                };
                 let mi = self.create_medium_interface();
 
                 for shape in shapes.iter() {
-                    let prim =
+                    let mut prim =
                         GeometricPrimitive::new(shape.clone(), mtl.clone(), None, mi.clone());
+                    prim.holdout = holdout;
                     prims.push(Arc::new(prim));
+                    N_PRIMITIVES.inc();
+                    PRIMITIVE_BYTES.add(size_of::<GeometricPrimitive>() as i64);
                 }
 
                 // Create single `TransformedPrimitive` for `prims`.
@@ -778,6 +1138,7 @@ impl Api {
                     MAX_TRANSFORMS == 2,
                     "TransformCache assumes only two transforms"
                 );
+                let mut transform_cache = self.transform_cache.lock().unwrap();
                 let obj2world = [
                     transform_cache.lookup(self.current_transforms[0].clone()),
                     transform_cache.lookup(self.current_transforms[1].clone()),
@@ -804,15 +1165,15 @@ impl Api {
             }
 
             // Add `prims` and `area_lights` to scene or current instance.
-            if let Some(mut current_instance) = self.render_options.current_instance.clone() {
+            if let Some(name) = self.render_options.current_instance.as_ref() {
                 if area_lights.len() > 0 {
                     warn!("Area lights not supported with object instancing.");
-                    Arc::get_mut(&mut current_instance)
-                        .unwrap()
-                        .append(&mut prims);
+                }
+                if let Some(instance) = self.render_options.instances.get_mut(name) {
+                    instance.append(&mut prims);
                 }
             } else {
-                self.render_options.current_instance = Some(Arc::new(prims));
+                self.render_options.primitives.append(&mut prims);
                 if area_lights.len() > 0 {
                     self.render_options.lights.append(&mut area_lights);
                 }
@@ -835,14 +1196,15 @@ impl Api {
         if self.verify_world("ObjectBegin") {
             self.pbrt_attribute_begin();
 
-            if let Some(_current_instance) = self.render_options.current_instance.clone() {
+            if self.render_options.current_instance.is_some() {
                 error!("ObjectBegin called inside of an instance definition.");
             } else {
-                let new_instance: Arc<Vec<ArcPrimitive>> = Arc::new(vec![]);
-                self.render_options
-                    .instances
-                    .insert(name, new_instance.clone());
-                self.render_options.current_instance = Some(new_instance.clone());
+                if self.render_options.instances.contains_key(&name) {
+                    warn!("Object instance '{}' redefined.", name);
+                }
+                self.render_options.instances.insert(name.clone(), vec![]);
+                self.render_options.current_instance = Some(name);
+                N_OBJECT_INSTANCES_CREATED.inc();
             }
         }
     }
@@ -850,10 +1212,9 @@ impl Api {
     /// End the definition of a named object instance.
     pub fn pbrt_object_end(&mut self) {
         if self.verify_world("ObjectEnd") {
-            if let Some(_current_instance) = self.render_options.current_instance.clone() {
+            if self.render_options.current_instance.take().is_none() {
                 error!("ObjectEnd called outside of instance definition.");
             }
-            self.render_options.current_instance = None;
 
             self.pbrt_attribute_end();
         }
@@ -865,59 +1226,208 @@ impl Api {
     pub fn pbrt_object_instance(&mut self, name: String) {
         if self.verify_world("ObjectInstance") {
             // Perform object instance error checking.
-            if let Some(_current_instance) = self.render_options.current_instance.clone() {
+            if self.render_options.current_instance.is_some() {
                 error!("ObjectInstance can't be called inside of instance definition.");
                 return;
             }
-            if let Some(instance) = self.render_options.instances.get(&name).cloned() {
-                let inst = match instance.len() {
-                    0 => {
+            let instance = match self.render_options.instances.get_mut(&name) {
+                Some(instance) => instance,
+                None => {
+                    error!("Unable to find object instance named '{}'", name);
+                    return;
+                }
+            };
+            N_OBJECT_INSTANCES_USED.inc();
+            if instance.is_empty() {
+                return;
+            }
+            if instance.len() > 1 {
+                // Create an aggregate for the instance `Primitives` the first
+                // time the object is instanced. All instances share it.
+                match GraphicsState::make_accelerator(
+                    &self.render_options.accelerator_name,
+                    instance,
+                    &self.render_options.accelerator_params,
+                ) {
+                    Ok(acc) => *instance = vec![acc],
+                    Err(err) => {
+                        self.report_error(err);
                         return;
                     }
-                    1 => (&*instance)[0].clone(),
-                    _ => {
-                        // Create an aggregate for the instance `Primitives`.
-                        match GraphicsState::make_accelerator(
-                            &self.render_options.accelerator_name,
-                            &*instance,
-                            &self.render_options.accelerator_params,
-                        ) {
-                            Ok(acc) => acc.clone(),
-                            Err(err) => {
-                                error!("{}", err);
-                                return;
-                            }
-                        }
-                    }
-                };
+                }
+            }
+            let inst = instance[0].clone();
 
-                assert!(
-                    MAX_TRANSFORMS == 2,
-                    "TransformCache assumes only two transforms"
-                );
+            assert!(
+                MAX_TRANSFORMS == 2,
+                "TransformCache assumes only two transforms"
+            );
 
-                // Create `animated_instance_to_world` transform for instance.
-                let mut transform_cache = self.transform_cache.lock().unwrap();
-                let instance2world = [
-                    transform_cache.lookup(self.current_transforms[0].clone()),
-                    transform_cache.lookup(self.current_transforms[1].clone()),
-                ];
-                let animated_instance2world = AnimatedTransform::new(
-                    instance2world[0].clone(),
-                    instance2world[1].clone(),
-                    self.render_options.transform_start_time,
-                    self.render_options.transform_end_time,
-                );
-                let prim = TransformedPrimitive::new(inst, animated_instance2world);
-                self.render_options.primitives.push(Arc::new(prim));
-            } else {
-                error!("Unable to find object instance named '{}'", name);
-            }
+            // Create `animated_instance_to_world` transform for instance.
+            let mut transform_cache = self.transform_cache.lock().unwrap();
+            let instance2world = [
+                transform_cache.lookup(self.current_transforms[0].clone()),
+                transform_cache.lookup(self.current_transforms[1].clone()),
+            ];
+            let animated_instance2world = AnimatedTransform::new(
+                instance2world[0].clone(),
+                instance2world[1].clone(),
+                self.render_options.transform_start_time,
+                self.render_options.transform_end_time,
+            );
+            let prim: ArcPrimitive =
+                Arc::new(TransformedPrimitive::new(inst, animated_instance2world));
+            self.render_options.instance_uses.push(prim.clone());
+            self.render_options.primitives.push(prim);
+            PRIMITIVE_BYTES.add(size_of::<TransformedPrimitive>() as i64);
         }
     }
 
     /* Helpers */
 
+    /// Renders the scene, or each frame of the frame sequence, from each
+    /// camera view.
+    fn render_frames(&mut self) -> Result<(), Error> {
+        match self.frames {
+            None => {
+                let scene = self.render_options.make_scene();
+                self.render_views(&self.render_options.camera_views(), scene)
+            }
+            Some(FrameSequence::Single(frame)) => {
+                let scene = self.render_options.make_scene();
+                let mut views = self.render_options.camera_views();
+                for view in views.iter_mut() {
+                    view.frame = Some(frame);
+                }
+                self.render_views(&views, scene)
+            }
+            Some(FrameSequence::Range(first, last)) => {
+                let t0 = self.render_options.transform_start_time;
+                let t1 = self.render_options.transform_end_time;
+                let frame_duration = (t1 - t0) / (last - first + 1) as Float;
+                let scene = self.render_options.make_scene();
+                let camera_views = self.render_options.camera_views();
+
+                let mut result = Ok(());
+                for frame in first..=last {
+                    info!("Rendering frame {} of {}..{}.", frame, first, last);
+                    let frame_start = t0 + (frame - first) as Float * frame_duration;
+
+                    let views: Vec<RenderOptions> = camera_views
+                        .iter()
+                        .map(|view| {
+                            let params = &view.camera_params;
+                            let shutter_open = params.find_one_float("shutteropen", 0.0);
+                            let shutter_close = params.find_one_float("shutterclose", 1.0);
+
+                            let mut render_options = view.clone();
+                            render_options.frame = Some(frame);
+                            render_options.camera_params.add_float(
+                                "shutteropen",
+                                &[frame_start + shutter_open * frame_duration],
+                            );
+                            render_options.camera_params.add_float(
+                                "shutterclose",
+                                &[frame_start + shutter_close * frame_duration],
+                            );
+                            render_options
+                        })
+                        .collect();
+                    result = self.render_views(&views, scene.clone());
+                    if result.is_err() || self.cancellation_token.is_cancelled() {
+                        break;
+                    }
+                }
+                result
+            }
+        }
+    }
+
+    /// Renders the scene from each camera view in turn. The views share the
+    /// scene and its acceleration structure. Stops at the first error or when
+    /// the render is cancelled.
+    ///
+    /// * `views` - Render options of each view.
+    /// * `scene` - The scene.
+    fn render_views(&self, views: &[RenderOptions], scene: Arc<Scene>) -> Result<(), Error> {
+        for view in views {
+            let name = view.camera_params.find_one_string("name", String::new());
+            if !name.is_empty() {
+                info!("Rendering camera '{}'.", name);
+            }
+            self.render(view, scene.clone())?;
+            if self.cancellation_token.is_cancelled() {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// Creates an integrator from the given render options and renders the
+    /// scene; or returns an error if the camera or integrator could not be
+    /// created or the image could not be written.
+    ///
+    /// * `render_options` - The render options.
+    /// * `scene`          - The scene.
+    fn render(&self, render_options: &RenderOptions, scene: Arc<Scene>) -> Result<(), Error> {
+        let camera = render_options.make_camera(&self.graphics_state)?;
+        let film = camera.get_data().film.clone();
+        let cancel = &self.cancellation_token;
+        let observers = &self.render_observers;
+        let start = Instant::now();
+        let result = render_options
+            .make_camera_integrator(camera)
+            .and_then(|mut integrator| {
+                unprofiled(|| {
+                    Arc::get_mut(&mut integrator)
+                        .unwrap()
+                        .render(scene, cancel, observers)
+                })
+            });
+        let render_time = start.elapsed();
+
+        // Report statistics gathered by this thread while building the scene
+        // along with those of the render threads.
+        report_thread_stats();
+        if !OPTIONS.quiet {
+            print_stats();
+        }
+        if OPTIONS.report && !film.filename.is_empty() {
+            Self::write_report(&film, render_time, result.as_ref().err());
+        }
+        clear_stats();
+
+        result
+    }
+
+    /// Writes the JSON report of a render next to its image. Failure to write
+    /// it is logged without failing the render.
+    ///
+    /// * `film`        - The film of the render.
+    /// * `render_time` - Wall clock time of the render.
+    /// * `error`       - Error that stopped the render.
+    fn write_report(film: &Film, render_time: Duration, error: Option<&Error>) {
+        report_thread_profile();
+        let pixels = film.cropped_pixel_bounds.area().max(1);
+        let report = RenderReport {
+            image: film.filename.clone(),
+            resolution: film.full_resolution,
+            samples_per_pixel: counter_value("Integrator/Camera rays traced") as f64
+                / pixels as f64,
+            render_time,
+            error: error.map(|err| err.to_string()),
+            phases: profile_times(),
+            scene_memory: total_memory(),
+            peak_memory: peak_memory(),
+            stats: stats_json(),
+            warnings: take_log_messages(),
+        };
+        match report.write() {
+            Ok(()) => info!("Wrote render report '{}'.", report_filename(&film.filename)),
+            Err(err) => error!("{}", err),
+        }
+    }
+
     /// Returns `true` if the API state is initialized; otherwise it reports
     /// an error and returns `false`.
     ///
@@ -982,35 +1492,237 @@ impl Api {
         }
     }
 
-    /// Returns a named medium or `None` for the given name.
+    /// Returns a named medium; `None` for vacuum or if it isn't defined.
     ///
-    /// * `name` - Medium name.
-    /// * `side` - Used to report an error if medium not found.
-    fn get_named_medium(&self, name: Option<String>, side: &str) -> Option<ArcMedium> {
-        match name {
-            Some(n) => {
-                if n.is_empty() {
-                    error!("Medium name is empty string for side '{}'.", side);
-                    None
-                } else if let Some(medium) = self.render_options.named_media.get(&n) {
-                    Some(medium.clone())
-                } else {
-                    error!("Named medium '{}' undefined for side '{}'.", n, side);
-                    None
-                }
-            }
-            None => None,
-        }
+    /// * `name` - Medium name; `None` or empty for vacuum.
+    /// * `side` - "inside" or "outside".
+    fn get_named_medium(&self, name: Option<&str>, side: &str) -> Option<ArcMedium> {
+        self.render_options
+            .named_medium(name, side)
+            .unwrap_or_else(|err| {
+                self.report_error(err);
+                None
+            })
     }
 
-    /// Creates a new medium interface.
+    /// Creates a new medium interface from the current inside and outside
+    /// media.
     fn create_medium_interface(&self) -> MediumInterface {
-        let inside =
-            self.get_named_medium(self.graphics_state.current_inside_medium.clone(), "inside");
-        let outside = self.get_named_medium(
-            self.graphics_state.current_outside_medium.clone(),
-            "outside",
-        );
+        let gs = &self.graphics_state;
+        let inside = self.get_named_medium(gs.current_inside_medium.as_deref(), "inside");
+        let outside = self.get_named_medium(gs.current_outside_medium.as_deref(), "outside");
         MediumInterface::new(inside, outside)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scene_plugins_require_opt_in_and_no_sandbox() {
+        let mut api = Api::new();
+        assert!(api.check_scene_plugin("libshapes.so", false).is_err());
+        assert!(api.check_scene_plugin("libshapes.so", true).is_ok());
+        api.set_sandboxed(true);
+        assert!(api.check_scene_plugin("libshapes.so", true).is_err());
+    }
+
+    #[test]
+    fn blocks_restore_state_and_collect_instances() {
+        let origin = Point3f::default();
+        let mut api = Api::new();
+        api.pbrt_init();
+        api.pbrt_world_begin();
+
+        api.pbrt_attribute_begin();
+        api.pbrt_translate(1.0, 0.0, 0.0);
+        api.pbrt_reverse_orientation();
+        api.pbrt_transform_begin();
+        api.pbrt_scale(2.0, 2.0, 2.0);
+        api.pbrt_translate(1.0, 0.0, 0.0);
+        api.pbrt_transform_end();
+        assert_eq!(
+            api.current_transforms[0].transform_point(&origin),
+            Point3f::new(1.0, 0.0, 0.0)
+        );
+
+        // The transforms of the attribute block can't be ended by
+        // `TransformEnd`.
+        api.pbrt_transform_end();
+        assert_eq!(
+            api.current_transforms[0].transform_point(&origin),
+            Point3f::new(1.0, 0.0, 0.0)
+        );
+        api.pbrt_attribute_end();
+        assert_eq!(api.current_transforms[0].transform_point(&origin), origin);
+        assert!(!api.graphics_state.reverse_orientation);
+        api.pbrt_attribute_end();
+        assert!(api.pushed_transforms.is_empty());
+
+        // Shapes of object definitions are kept for instancing instead of
+        // being added to the scene.
+        let mut params = ParamSet::new();
+        params.add_float("radius", &[0.5]);
+        api.pbrt_object_begin(String::from("ball"));
+        api.pbrt_shape(String::from("sphere"), &params);
+        api.pbrt_shape(String::from("no-such-shape"), &params);
+        api.pbrt_object_end();
+        assert!(api.render_options.primitives.is_empty());
+        assert_eq!(api.render_options.instances["ball"].len(), 1);
+
+        api.pbrt_translate(0.0, 2.0, 0.0);
+        api.pbrt_object_instance(String::from("ball"));
+        assert_eq!(api.render_options.primitives.len(), 1);
+        assert_eq!(api.render_options.instance_uses.len(), 1);
+    }
+
+    #[test]
+    fn instances_share_one_aggregate() {
+        let mut api = Api::new();
+        api.pbrt_init();
+        api.pbrt_world_begin();
+
+        let mut params = ParamSet::new();
+        params.add_float("radius", &[0.5]);
+        api.pbrt_object_begin(String::from("pair"));
+        api.pbrt_shape(String::from("sphere"), &params);
+        api.pbrt_translate(1.0, 0.0, 0.0);
+        api.pbrt_shape(String::from("sphere"), &params);
+        api.pbrt_object_end();
+        assert_eq!(api.render_options.instances["pair"].len(), 2);
+
+        for i in 0..3 {
+            api.pbrt_attribute_begin();
+            api.pbrt_translate(0.0, i as Float, 0.0);
+            api.pbrt_object_instance(String::from("pair"));
+            api.pbrt_attribute_end();
+        }
+        assert_eq!(api.render_options.instances["pair"].len(), 1);
+        assert_eq!(api.render_options.instance_uses.len(), 3);
+
+        let aggregate = Arc::new(BVHAccel::new(
+            &api.render_options.primitives,
+            1,
+            SplitMethod::SAH,
+        ));
+        let bounds = aggregate.world_bound();
+        assert_eq!(bounds.p_min, Point3f::new(-0.5, -0.5, -0.5));
+        assert_eq!(bounds.p_max, Point3f::new(1.5, 2.5, 0.5));
+
+        // The shapes are only counted once since the instances share them.
+        let stats = Scene::new(aggregate, vec![]).stats();
+        assert_eq!((stats.instances, stats.shapes), (3, 2));
+    }
+
+    #[test]
+    fn animated_shapes_move_over_transform_times() {
+        let mut api = Api::new();
+        api.pbrt_init();
+        api.pbrt_transform_times(0.0, 1.0);
+        api.pbrt_world_begin();
+
+        let mut params = ParamSet::new();
+        params.add_float("radius", &[0.5]);
+        api.pbrt_active_transform_end_time();
+        api.pbrt_translate(2.0, 0.0, 0.0);
+        api.pbrt_active_transform_all();
+        api.pbrt_shape(String::from("sphere"), &params);
+        assert_eq!(api.render_options.primitives.len(), 1);
+
+        let prim = api.render_options.primitives[0].clone();
+        let bounds = prim.world_bound();
+        assert_eq!(bounds.p_min, Point3f::new(-0.5, -0.5, -0.5));
+        assert_eq!(bounds.p_max, Point3f::new(2.5, 0.5, 0.5));
+
+        let ray = |x: Float, time: Float| {
+            Ray::new(
+                Point3f::new(x, 0.0, 5.0),
+                Vector3f::new(0.0, 0.0, -1.0),
+                INFINITY,
+                time,
+                None,
+            )
+        };
+        assert!(prim.intersect_p(&ray(0.0, 0.0)));
+        assert!(!prim.intersect_p(&ray(0.0, 1.0)));
+        assert!(prim.intersect_p(&ray(1.0, 0.5)));
+        assert!(prim.intersect_p(&ray(2.0, 1.0)));
+
+        let it = prim.intersect(&mut ray(1.0, 0.5)).unwrap();
+        assert_eq!(it.hit.time, 0.5);
+        assert!((it.hit.p.x - 1.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn named_media_are_assigned_to_camera_and_shapes() {
+        let mut api = Api::new();
+        api.pbrt_init();
+
+        let mut params = ParamSet::new();
+        params.add_string("type", &[String::from("heterogeneous")]);
+        params.add_float("density", &[1.0]);
+        api.pbrt_make_named_medium(String::from("fog"), &params);
+        api.pbrt_medium_interface(String::new(), String::from("fog"));
+        api.pbrt_camera(String::from("perspective"), &ParamSet::new());
+        assert_eq!(api.render_options.camera_medium.as_deref(), Some("fog"));
+        api.pbrt_world_begin();
+
+        // The camera keeps its medium when the current media change.
+        api.pbrt_medium_interface(String::from("fog"), String::new());
+        let mi = api.create_medium_interface();
+        assert!(mi.inside.is_some() && mi.outside.is_none());
+        assert!(api
+            .render_options
+            .named_medium(api.render_options.camera_medium.as_deref(), "camera")
+            .unwrap()
+            .is_some());
+
+        api.pbrt_attribute_begin();
+        api.pbrt_medium_interface(String::from("smoke"), String::from("fog"));
+        let mi = api.create_medium_interface();
+        assert!(mi.inside.is_none() && mi.outside.is_some());
+        api.pbrt_attribute_end();
+        assert!(api.create_medium_interface().inside.is_some());
+
+        let mut params = ParamSet::new();
+        params.add_string("type", &[String::from("matte")]);
+        api.pbrt_make_named_material(String::from("paint"), &params);
+        api.pbrt_attribute_begin();
+        api.pbrt_named_material(String::from("paint"));
+        // Shapes overriding material parameters create the named material's
+        // type.
+        let mut params = ParamSet::new();
+        params.add_rgb_spectrum("Kd", &[0.1, 0.2, 0.3]).unwrap();
+        api.pbrt_shape(String::from("sphere"), &params);
+        assert_eq!(
+            api.graphics_state
+                .current_material
+                .as_ref()
+                .map(|m| m.name.as_str()),
+            Some("matte")
+        );
+        api.pbrt_attribute_end();
+        assert_eq!(api.render_options.primitives.len(), 1);
+    }
+
+    #[test]
+    fn shapes_after_material_none_have_no_material() {
+        let mut api = Api::new();
+        api.pbrt_init();
+        api.pbrt_world_begin();
+
+        let mut params = ParamSet::new();
+        params.add_float("radius", &[0.5]);
+        api.pbrt_attribute_begin();
+        api.pbrt_material(String::from("interface"), &ParamSet::new());
+        api.pbrt_shape(String::from("sphere"), &params);
+        api.pbrt_attribute_end();
+        api.pbrt_shape(String::from("sphere"), &params);
+
+        let primitives = &api.render_options.primitives;
+        assert_eq!(primitives.len(), 2);
+        assert!(primitives[0].get_material().is_none());
+        assert!(primitives[1].get_material().is_some());
+    }
+}
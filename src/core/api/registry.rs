@@ -0,0 +1,536 @@
+//! Registry of named scene object types
+
+#![allow(dead_code)]
+use super::FloatTextureMap;
+use crate::core::camera::*;
+use crate::core::error::*;
+use crate::core::geometry::*;
+use crate::core::integrator::*;
+use crate::core::light::*;
+use crate::core::material::*;
+use crate::core::medium::*;
+use crate::core::paramset::*;
+use crate::core::pbrt::*;
+use crate::core::sampler::*;
+use crate::core::spectrum::*;
+use crate::core::texture::*;
+use crate::integrators::*;
+use crate::lights::*;
+use crate::materials::*;
+use crate::media::*;
+use crate::shapes::*;
+use crate::textures::*;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::sync::{Arc, RwLock};
+
+/// Creates shapes from a parameter set, object to world transform, world to
+/// object transform, reverse orientation flag and the float textures in scope.
+pub type ShapeConstructor = Arc<
+    dyn Fn(
+            &ParamSet,
+            ArcTransform,
+            ArcTransform,
+            bool,
+            &FloatTextureMap,
+        ) -> Result<Vec<ArcShape>, Error>
+        + Send
+        + Sync,
+>;
+
+/// Creates a material from texture parameters.
+pub type MaterialConstructor =
+    Arc<dyn Fn(&TextureParams) -> Result<ArcMaterial, Error> + Send + Sync>;
+
+/// Creates a float texture from texture parameters and the texture to world
+/// transform.
+pub type FloatTextureConstructor =
+    Arc<dyn Fn(&TextureParams, &Transform) -> Result<ArcTexture<Float>, Error> + Send + Sync>;
+
+/// Creates a spectrum texture from texture parameters and the texture to
+/// world transform.
+pub type SpectrumTextureConstructor =
+    Arc<dyn Fn(&TextureParams, &Transform) -> Result<ArcTexture<Spectrum>, Error> + Send + Sync>;
+
+/// Creates a light from a parameter set, light to world transform and the
+/// medium interface.
+pub type LightConstructor =
+    Arc<dyn Fn(&ParamSet, ArcTransform, &MediumInterface) -> Result<ArcLight, Error> + Send + Sync>;
+
+/// Creates a medium from a parameter set and the medium to world transform.
+pub type MediumConstructor =
+    Arc<dyn Fn(&ParamSet, ArcTransform) -> Result<ArcMedium, Error> + Send + Sync>;
+
+/// Creates an integrator from a parameter set, sampler and camera.
+pub type IntegratorConstructor =
+    Arc<dyn Fn(&ParamSet, ArcSampler, ArcCamera) -> Result<ArcIntegrator, Error> + Send + Sync>;
+
+lazy_static! {
+    /// The registry used when creating scene objects by name.
+    static ref REGISTRY: RwLock<Registry> = RwLock::new(Registry::default());
+}
+
+/// Maps scene object type names, i.e. the names used in scene files like
+/// "sphere" or "matte", to their constructors.
+struct Registry {
+    shapes: HashMap<String, ShapeConstructor>,
+    materials: HashMap<String, MaterialConstructor>,
+    float_textures: HashMap<String, FloatTextureConstructor>,
+    spectrum_textures: HashMap<String, SpectrumTextureConstructor>,
+    lights: HashMap<String, LightConstructor>,
+    media: HashMap<String, MediumConstructor>,
+    integrators: HashMap<String, IntegratorConstructor>,
+}
+
+impl Default for Registry {
+    /// Returns a registry containing the built-in types.
+    fn default() -> Self {
+        let mut r = Self {
+            shapes: HashMap::new(),
+            materials: HashMap::new(),
+            float_textures: HashMap::new(),
+            spectrum_textures: HashMap::new(),
+            lights: HashMap::new(),
+            media: HashMap::new(),
+            integrators: HashMap::new(),
+        };
+        r.add_shapes();
+        r.add_materials();
+        r.add_float_textures();
+        r.add_spectrum_textures();
+        r.add_lights();
+        r.add_media();
+        r.add_integrators();
+        r
+    }
+}
+
+impl Registry {
+    fn shape<F>(&mut self, name: &str, f: F)
+    where
+        F: Fn(
+                &ParamSet,
+                ArcTransform,
+                ArcTransform,
+                bool,
+                &FloatTextureMap,
+            ) -> Result<Vec<ArcShape>, Error>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.shapes.insert(String::from(name), Arc::new(f));
+    }
+
+    fn material<F>(&mut self, name: &str, f: F)
+    where
+        F: Fn(&TextureParams) -> Result<ArcMaterial, Error> + Send + Sync + 'static,
+    {
+        self.materials.insert(String::from(name), Arc::new(f));
+    }
+
+    fn float_texture<F>(&mut self, name: &str, f: F)
+    where
+        F: Fn(&TextureParams, &Transform) -> Result<ArcTexture<Float>, Error>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.float_textures.insert(String::from(name), Arc::new(f));
+    }
+
+    fn spectrum_texture<F>(&mut self, name: &str, f: F)
+    where
+        F: Fn(&TextureParams, &Transform) -> Result<ArcTexture<Spectrum>, Error>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.spectrum_textures
+            .insert(String::from(name), Arc::new(f));
+    }
+
+    fn light<F>(&mut self, name: &str, f: F)
+    where
+        F: Fn(&ParamSet, ArcTransform, &MediumInterface) -> Result<ArcLight, Error>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.lights.insert(String::from(name), Arc::new(f));
+    }
+
+    fn medium<F>(&mut self, name: &str, f: F)
+    where
+        F: Fn(&ParamSet, ArcTransform) -> Result<ArcMedium, Error> + Send + Sync + 'static,
+    {
+        self.media.insert(String::from(name), Arc::new(f));
+    }
+
+    fn integrator<F>(&mut self, name: &str, f: F)
+    where
+        F: Fn(&ParamSet, ArcSampler, ArcCamera) -> Result<ArcIntegrator, Error>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.integrators.insert(String::from(name), Arc::new(f));
+    }
+
+    fn add_shapes(&mut self) {
+        self.shape("cone", |ps, o2w, w2o, ro, _| {
+            Ok(vec![Arc::new(Cone::from((ps, o2w, w2o, ro)))])
+        });
+        self.shape("curve", |ps, o2w, w2o, ro, _| {
+            Curve::from_props((ps, o2w, w2o, ro))
+        });
+        self.shape("cylinder", |ps, o2w, w2o, ro, _| {
+            Ok(vec![Arc::new(Cylinder::from((ps, o2w, w2o, ro)))])
+        });
+        self.shape("disk", |ps, o2w, w2o, ro, _| {
+            Ok(vec![Arc::new(Disk::from((ps, o2w, w2o, ro)))])
+        });
+        self.shape("hyperboloid", |ps, o2w, w2o, ro, _| {
+            Ok(vec![Arc::new(Hyperboloid::from((ps, o2w, w2o, ro)))])
+        });
+        self.shape("loopsubdiv", |ps, o2w, w2o, ro, _| {
+            LoopSubDiv::from_props((ps, o2w, w2o, ro))
+        });
+        self.shape("objmesh", |ps, o2w, w2o, ro, float_textures| {
+            ObjMesh::from_props((ps, o2w, w2o, ro), float_textures)
+        });
+        self.shape("paraboloid", |ps, o2w, w2o, ro, _| {
+            Ok(vec![Arc::new(Paraboloid::from((ps, o2w, w2o, ro)))])
+        });
+        self.shape("plymesh", |ps, o2w, w2o, ro, float_textures| {
+            PlyMesh::from_props((ps, o2w, w2o, ro), float_textures)
+        });
+        self.shape("sphere", |ps, o2w, w2o, ro, _| {
+            Ok(vec![Arc::new(Sphere::from((ps, o2w, w2o, ro)))])
+        });
+        self.shape("trianglemesh", |ps, o2w, w2o, ro, float_textures| {
+            TriangleMesh::from_props((ps, o2w, w2o, ro), float_textures)
+        });
+    }
+
+    fn add_materials(&mut self) {
+        self.material("matte", |mp| Ok(Arc::new(MatteMaterial::from(mp))));
+        self.material("fluorescent", |mp| {
+            Ok(Arc::new(FluorescentMaterial::from(mp)))
+        });
+        self.material("metal", |mp| Ok(Arc::new(MetalMaterial::from(mp))));
+        self.material("mirror", |mp| Ok(Arc::new(MirrorMaterial::from(mp))));
+        self.material("glass", |mp| Ok(Arc::new(GlassMaterial::from(mp))));
+        self.material("plastic", |mp| Ok(Arc::new(PlasticMaterial::from(mp))));
+        self.material("subsurface", |mp| {
+            Ok(Arc::new(SubsurfaceMaterial::from(mp)))
+        });
+        self.material("fourier", |mp| Ok(Arc::new(FourierMaterial::try_from(mp)?)));
+    }
+
+    fn add_float_textures(&mut self) {
+        self.float_texture("bilerp", |tp, t| {
+            Ok(Arc::new(BilerpTexture::<Float>::from((tp, t))))
+        });
+        self.float_texture("checkerboard", |tp, t| match checkerboard_dimension(tp)? {
+            2 => Ok(Arc::new(CheckerboardTexture2D::<Float>::from((tp, t)))),
+            _ => Ok(Arc::new(CheckerboardTexture3D::<Float>::from((tp, t)))),
+        });
+        self.float_texture("constant", |tp, t| {
+            Ok(Arc::new(ConstantTexture::<Float>::from((tp, t))))
+        });
+        self.float_texture("dots", |tp, t| {
+            Ok(Arc::new(DotsTexture::<Float>::from((tp, t))))
+        });
+        self.float_texture("fbm", |tp, t| {
+            Ok(Arc::new(FBmTexture::<Float>::from((tp, t))))
+        });
+        self.float_texture("imagemap", |tp, t| {
+            Ok(Arc::new(ImageTexture::<Float>::try_from((tp, t))?))
+        });
+        self.float_texture("mix", |tp, t| {
+            Ok(Arc::new(MixTexture::<Float>::from((tp, t))))
+        });
+        self.float_texture("scale", |tp, t| {
+            Ok(Arc::new(ScaleTexture::<Float>::from((tp, t))))
+        });
+        self.float_texture("windy", |tp, t| {
+            Ok(Arc::new(WindyTexture::<Float>::from((tp, t))))
+        });
+    }
+
+    fn add_spectrum_textures(&mut self) {
+        self.spectrum_texture("bilerp", |tp, t| {
+            Ok(Arc::new(BilerpTexture::<Spectrum>::from((tp, t))))
+        });
+        self.spectrum_texture("checkerboard", |tp, t| match checkerboard_dimension(tp)? {
+            2 => Ok(Arc::new(CheckerboardTexture2D::<Spectrum>::from((tp, t)))),
+            _ => Ok(Arc::new(CheckerboardTexture3D::<Spectrum>::from((tp, t)))),
+        });
+        self.spectrum_texture("constant", |tp, t| {
+            Ok(Arc::new(ConstantTexture::<Spectrum>::from((tp, t))))
+        });
+        self.spectrum_texture("dots", |tp, t| {
+            Ok(Arc::new(DotsTexture::<Spectrum>::from((tp, t))))
+        });
+        self.spectrum_texture("fbm", |tp, t| {
+            Ok(Arc::new(FBmTexture::<Spectrum>::from((tp, t))))
+        });
+        self.spectrum_texture("imagemap", |tp, t| {
+            Ok(Arc::new(ImageTexture::<RGBSpectrum>::try_from((tp, t))?))
+        });
+        self.spectrum_texture("marble", |tp, t| Ok(Arc::new(MarbleTexture::from((tp, t)))));
+        self.spectrum_texture("mix", |tp, t| {
+            Ok(Arc::new(MixTexture::<Spectrum>::from((tp, t))))
+        });
+        self.spectrum_texture("scale", |tp, t| {
+            Ok(Arc::new(ScaleTexture::<Spectrum>::from((tp, t))))
+        });
+        self.spectrum_texture("uv", |tp, t| Ok(Arc::new(UVTexture::from((tp, t)))));
+        self.spectrum_texture("windy", |tp, t| {
+            Ok(Arc::new(WindyTexture::<Spectrum>::from((tp, t))))
+        });
+    }
+
+    fn add_lights(&mut self) {
+        self.light("point", |ps, light2world, mi| {
+            Ok(Arc::new(PointLight::from((
+                ps,
+                light2world,
+                mi.outside.clone(),
+            ))))
+        });
+        self.light("distant", |ps, light2world, _| {
+            Ok(Arc::new(DistantLight::from((ps, light2world))))
+        });
+        self.light("infinite", |ps, light2world, _| {
+            Ok(Arc::new(InfiniteAreaLight::from((ps, light2world))))
+        });
+        self.light("exinfinite", |ps, light2world, _| {
+            Ok(Arc::new(InfiniteAreaLight::from((ps, light2world))))
+        });
+    }
+
+    fn add_media(&mut self) {
+        self.medium("heterogeneous", |ps, medium2world| {
+            Ok(Arc::new(GridDensityMedium::try_from((ps, medium2world))?))
+        });
+    }
+
+    fn add_integrators(&mut self) {
+        self.integrator("whitted", |ps, sampler, camera| {
+            Ok(Arc::new(WhittedIntegrator::from((ps, sampler, camera))))
+        });
+        self.integrator("volpath", |ps, sampler, camera| {
+            Ok(Arc::new(VolPathIntegrator::from((ps, sampler, camera))))
+        });
+        self.integrator("mlt", |ps, sampler, camera| {
+            Ok(Arc::new(MLTIntegrator::from((ps, sampler, camera))))
+        });
+        self.integrator("restir", |ps, sampler, camera| {
+            Ok(Arc::new(ReSTIRIntegrator::from((ps, sampler, camera))))
+        });
+    }
+}
+
+/// Returns the "dimension" parameter of a checkerboard texture.
+///
+/// * `tp` - Texture parameters.
+fn checkerboard_dimension(tp: &TextureParams) -> Result<Int, Error> {
+    let dim = tp.find_int("dimension", 2);
+    if dim == 2 || dim == 3 {
+        Ok(dim)
+    } else {
+        Err(Error::Unsupported(format!(
+            "{} dimensional checkerboard texture not supported",
+            dim
+        )))
+    }
+}
+
+/// Registers a shape type. Scene files can then create it with
+/// `Shape "<name>" ...`. Registering a name that is already in use replaces
+/// the existing type.
+///
+/// * `name` - Name of the shape type.
+/// * `f`    - Constructor.
+pub fn register_shape<F>(name: &str, f: F)
+where
+    F: Fn(
+            &ParamSet,
+            ArcTransform,
+            ArcTransform,
+            bool,
+            &FloatTextureMap,
+        ) -> Result<Vec<ArcShape>, Error>
+        + Send
+        + Sync
+        + 'static,
+{
+    REGISTRY.write().unwrap().shape(name, f);
+}
+
+/// Registers a material type for `Material "<name>"` and `MakeNamedMaterial`.
+/// Registering a name that is already in use replaces the existing type.
+///
+/// * `name` - Name of the material type.
+/// * `f`    - Constructor.
+pub fn register_material<F>(name: &str, f: F)
+where
+    F: Fn(&TextureParams) -> Result<ArcMaterial, Error> + Send + Sync + 'static,
+{
+    REGISTRY.write().unwrap().material(name, f);
+}
+
+/// Registers a float texture type for `Texture "..." "float" "<name>"`.
+/// Registering a name that is already in use replaces the existing type.
+///
+/// * `name` - Name of the texture type.
+/// * `f`    - Constructor.
+pub fn register_float_texture<F>(name: &str, f: F)
+where
+    F: Fn(&TextureParams, &Transform) -> Result<ArcTexture<Float>, Error> + Send + Sync + 'static,
+{
+    REGISTRY.write().unwrap().float_texture(name, f);
+}
+
+/// Registers a spectrum texture type for `Texture "..." "spectrum" "<name>"`.
+/// Registering a name that is already in use replaces the existing type.
+///
+/// * `name` - Name of the texture type.
+/// * `f`    - Constructor.
+pub fn register_spectrum_texture<F>(name: &str, f: F)
+where
+    F: Fn(&TextureParams, &Transform) -> Result<ArcTexture<Spectrum>, Error>
+        + Send
+        + Sync
+        + 'static,
+{
+    REGISTRY.write().unwrap().spectrum_texture(name, f);
+}
+
+/// Registers a light type for `LightSource "<name>"`. Registering a name that
+/// is already in use replaces the existing type.
+///
+/// * `name` - Name of the light type.
+/// * `f`    - Constructor.
+pub fn register_light<F>(name: &str, f: F)
+where
+    F: Fn(&ParamSet, ArcTransform, &MediumInterface) -> Result<ArcLight, Error>
+        + Send
+        + Sync
+        + 'static,
+{
+    REGISTRY.write().unwrap().light(name, f);
+}
+
+/// Registers a medium type for `MakeNamedMedium "..." "string type" "<name>"`.
+/// Registering a name that is already in use replaces the existing type.
+///
+/// * `name` - Name of the medium type.
+/// * `f`    - Constructor.
+pub fn register_medium<F>(name: &str, f: F)
+where
+    F: Fn(&ParamSet, ArcTransform) -> Result<ArcMedium, Error> + Send + Sync + 'static,
+{
+    REGISTRY.write().unwrap().medium(name, f);
+}
+
+/// Registers an integrator type for `Integrator "<name>"`. Registering a name
+/// that is already in use replaces the existing type.
+///
+/// * `name` - Name of the integrator type.
+/// * `f`    - Constructor.
+pub fn register_integrator<F>(name: &str, f: F)
+where
+    F: Fn(&ParamSet, ArcSampler, ArcCamera) -> Result<ArcIntegrator, Error> + Send + Sync + 'static,
+{
+    REGISTRY.write().unwrap().integrator(name, f);
+}
+
+/// Returns the constructor for a shape type.
+///
+/// * `name` - Name of the shape type.
+pub fn shape_constructor(name: &str) -> Option<ShapeConstructor> {
+    REGISTRY.read().unwrap().shapes.get(name).cloned()
+}
+
+/// Returns the constructor for a material type.
+///
+/// * `name` - Name of the material type.
+pub fn material_constructor(name: &str) -> Option<MaterialConstructor> {
+    REGISTRY.read().unwrap().materials.get(name).cloned()
+}
+
+/// Returns the constructor for a float texture type.
+///
+/// * `name` - Name of the texture type.
+pub fn float_texture_constructor(name: &str) -> Option<FloatTextureConstructor> {
+    REGISTRY.read().unwrap().float_textures.get(name).cloned()
+}
+
+/// Returns the constructor for a spectrum texture type.
+///
+/// * `name` - Name of the texture type.
+pub fn spectrum_texture_constructor(name: &str) -> Option<SpectrumTextureConstructor> {
+    REGISTRY
+        .read()
+        .unwrap()
+        .spectrum_textures
+        .get(name)
+        .cloned()
+}
+
+/// Returns the constructor for a light type.
+///
+/// * `name` - Name of the light type.
+pub fn light_constructor(name: &str) -> Option<LightConstructor> {
+    REGISTRY.read().unwrap().lights.get(name).cloned()
+}
+
+/// Returns the constructor for a medium type.
+///
+/// * `name` - Name of the medium type.
+pub fn medium_constructor(name: &str) -> Option<MediumConstructor> {
+    REGISTRY.read().unwrap().media.get(name).cloned()
+}
+
+/// Returns the constructor for an integrator type.
+///
+/// * `name` - Name of the integrator type.
+pub fn integrator_constructor(name: &str) -> Option<IntegratorConstructor> {
+    REGISTRY.read().unwrap().integrators.get(name).cloned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registered_types_replace_and_extend_builtins() {
+        assert!(shape_constructor("sphere").is_some());
+        assert!(material_constructor("registry-test-material").is_none());
+
+        register_material("registry-test-material", |mp| {
+            Ok(Arc::new(PlasticMaterial::from(mp)))
+        });
+        register_shape("registry-test-shape", |ps, o2w, w2o, ro, _| {
+            let sphere: ArcShape = Arc::new(Sphere::from((ps, o2w, w2o, ro)));
+            Ok(vec![sphere.clone(), sphere])
+        });
+
+        assert!(material_constructor("registry-test-material").is_some());
+
+        let constructor = shape_constructor("registry-test-shape").unwrap();
+        let identity = Arc::new(Transform::default());
+        let shapes = constructor(
+            &ParamSet::new(),
+            identity.clone(),
+            identity,
+            false,
+            &FloatTextureMap::new(),
+        )
+        .unwrap();
+        assert_eq!(shapes.len(), 2);
+    }
+}
@@ -2,20 +2,52 @@
 
 #![allow(dead_code)]
 use super::graphics_state::GraphicsState;
+use super::registry::*;
 use super::transform_set::*;
+use crate::core::app::OPTIONS;
 use crate::core::camera::*;
+use crate::core::error::*;
+use crate::core::fileutil::*;
 use crate::core::integrator::*;
 use crate::core::light::*;
 use crate::core::medium::*;
 use crate::core::paramset::*;
 use crate::core::pbrt::*;
 use crate::core::primitive::*;
+use crate::core::profiler::*;
+use crate::core::sampler::*;
 use crate::core::scene::*;
-use crate::integrators::*;
 use crate::{accelerators::BVHAccel, accelerators::SplitMethod};
 use std::collections::HashMap;
 use std::sync::Arc;
 
+/// Maximum number of problems found in a scene that are logged individually.
+const MAX_SCENE_WARNINGS: usize = 10;
+
+/// A camera given a name with `"string name"`. Each named camera renders the
+/// scene to its own output image.
+#[derive(Clone)]
+pub struct NamedCamera {
+    /// Name of the camera.
+    pub name: String,
+
+    /// Camera type name.
+    pub camera_name: String,
+
+    /// Camera parameters.
+    pub camera_params: ParamSet,
+
+    /// Camera to world transformation.
+    pub camera_to_world: TransformSet,
+
+    /// Name of the medium the camera is in; `None` for vacuum.
+    pub camera_medium: Option<String>,
+
+    /// Film type name and parameters given after the camera; `None` to use
+    /// the scene's film.
+    pub film: Option<(String, ParamSet)>,
+}
+
 /// Stores rendering options.
 #[derive(Clone)]
 pub struct RenderOptions {
@@ -64,6 +96,18 @@ pub struct RenderOptions {
     /// Camera to world transformation.
     pub camera_to_world: TransformSet,
 
+    /// Name of the medium the camera is in, i.e. the outside medium when the
+    /// camera was set; `None` for vacuum.
+    pub camera_medium: Option<String>,
+
+    /// Named cameras; if there are any, each renders the scene instead of the
+    /// camera above.
+    pub named_cameras: Vec<NamedCamera>,
+
+    /// Suffix appended to the output filename of the camera, e.g. the name
+    /// of a named camera.
+    pub output_suffix: Option<String>,
+
     /// Named media.
     pub named_media: HashMap<String, ArcMedium>,
 
@@ -74,13 +118,23 @@ pub struct RenderOptions {
     pub primitives: Vec<ArcPrimitive>,
 
     /// Object instances (each is a collection of primitives).
-    pub instances: HashMap<String, Arc<Vec<ArcPrimitive>>>,
+    pub instances: HashMap<String, Vec<ArcPrimitive>>,
+
+    /// Name of the object instance being defined.
+    pub current_instance: Option<String>,
 
-    /// Current instance (a collection of primitives).
-    pub current_instance: Option<Arc<Vec<ArcPrimitive>>>,
+    /// Primitives placing object instances in the order of the
+    /// `ObjectInstance` calls. They are also in `primitives`.
+    pub instance_uses: Vec<ArcPrimitive>,
 
     /// Is there scattering media in the scene.
     pub have_scattering_media: bool,
+
+    /// Frame number used to name the output image of an animation sequence.
+    pub frame: Option<usize>,
+
+    /// Keep rendered images in memory instead of writing them to files.
+    pub in_memory: bool,
 }
 
 impl RenderOptions {
@@ -102,32 +156,49 @@ impl RenderOptions {
             camera_name: String::from("perspective"),
             camera_params: ParamSet::new(),
             camera_to_world: TransformSet::default(),
+            camera_medium: None,
+            named_cameras: vec![],
+            output_suffix: None,
             named_media: HashMap::new(),
             lights: vec![],
             primitives: vec![],
             instances: HashMap::new(),
             current_instance: None,
+            instance_uses: vec![],
             have_scattering_media: false,
+            frame: None,
+            in_memory: false,
         }
     }
 
     /// Returns an `Integrator` based on the render options.
     ///
     /// * `gs` - The `GraphicsState`.
-    pub fn make_integrator(&self, gs: &GraphicsState) -> Result<ArcIntegrator, String> {
-        let camera = self.make_camera(gs);
+    pub fn make_integrator(&self, gs: &GraphicsState) -> Result<ArcIntegrator, Error> {
+        let camera = self.make_camera(gs)?;
+        self.make_camera_integrator(camera)
+    }
+
+    /// Returns an `Integrator` based on the render options that renders the
+    /// view of the given camera.
+    ///
+    /// * `camera` - The camera.
+    pub fn make_camera_integrator(&self, camera: ArcCamera) -> Result<ArcIntegrator, Error> {
         let sampler = GraphicsState::make_sampler(
             &self.sampler_name,
             &self.sampler_params,
             camera.get_data().film.clone(),
         )?;
 
-        let integrator: Result<ArcIntegrator, String> = match self.integrator_name.as_str() {
-            "whitted" => {
-                let p = (&self.integrator_params, sampler, camera);
-                Ok(Arc::new(WhittedIntegrator::from(p)))
-            }
-            _ => Err(format!("Integrator '{}' unknown.", self.integrator_name)),
+        let integrator: Result<ArcIntegrator, Error> = match self.integrator_name.as_str() {
+            _ if OPTIONS.gpu => Self::make_gpu_integrator(&self.integrator_params, sampler, camera),
+            name => match integrator_constructor(name) {
+                Some(constructor) => constructor(&self.integrator_params, sampler, camera),
+                None => Err(Error::UnknownType(format!(
+                    "Integrator '{}' unknown.",
+                    self.integrator_name
+                ))),
+            },
         };
 
         if integrator.is_ok() {
@@ -152,8 +223,41 @@ impl RenderOptions {
         integrator
     }
 
+    /// Returns the integrator that renders on the GPU.
+    ///
+    /// * `params`  - Integrator parameters.
+    /// * `sampler` - The sampler.
+    /// * `camera`  - The camera.
+    #[cfg(feature = "gpu")]
+    fn make_gpu_integrator(
+        params: &ParamSet,
+        sampler: ArcSampler,
+        camera: ArcCamera,
+    ) -> Result<ArcIntegrator, Error> {
+        Ok(Arc::new(crate::integrators::GpuIntegrator::from((
+            params, sampler, camera,
+        ))))
+    }
+
+    /// Returns an error because GPU rendering requires the `gpu` feature.
+    ///
+    /// * `params`  - Integrator parameters.
+    /// * `sampler` - The sampler.
+    /// * `camera`  - The camera.
+    #[cfg(not(feature = "gpu"))]
+    fn make_gpu_integrator(
+        _params: &ParamSet,
+        _sampler: ArcSampler,
+        _camera: ArcCamera,
+    ) -> Result<ArcIntegrator, Error> {
+        Err(Error::Unsupported(String::from(
+            "GPU rendering requires building with the 'gpu' feature.",
+        )))
+    }
+
     /// Returns a `Scene` based on the render options.
     pub fn make_scene(&mut self) -> Arc<Scene> {
+        let _p = ProfilePhase::new(Prof::SceneConstruction);
         let scene = match GraphicsState::make_accelerator(
             &self.accelerator_name,
             &self.primitives,
@@ -167,41 +271,99 @@ impl RenderOptions {
             }
         };
         self.primitives.clear();
+        self.instance_uses.clear();
         self.lights.clear();
+
+        let warnings = scene.validate();
+        for warning in warnings.iter().take(MAX_SCENE_WARNINGS) {
+            warn!("{}", warning);
+        }
+        if warnings.len() > MAX_SCENE_WARNINGS {
+            warn!(
+                "{} more problems found in the scene.",
+                warnings.len() - MAX_SCENE_WARNINGS
+            );
+        }
+
         scene
     }
 
+    /// Returns the render options for each view of the scene; one for each
+    /// named camera or just these options if there are none. A named camera
+    /// without its own output filename writes to the scene's output filename
+    /// with the camera name appended.
+    pub fn camera_views(&self) -> Vec<RenderOptions> {
+        if self.named_cameras.is_empty() {
+            return vec![self.clone()];
+        }
+
+        self.named_cameras
+            .iter()
+            .map(|camera| {
+                let mut view = self.clone();
+                view.named_cameras.clear();
+                view.camera_name = camera.camera_name.clone();
+                view.camera_params = camera.camera_params.clone();
+                view.camera_to_world = camera.camera_to_world.clone();
+                view.camera_medium = camera.camera_medium.clone();
+                let mut own_filename = false;
+                if let Some((film_name, film_params)) = camera.film.as_ref() {
+                    view.film_name = film_name.clone();
+                    view.film_params = film_params.clone();
+                    own_filename = !film_params.find_string("filename").is_empty();
+                }
+                if !own_filename {
+                    view.output_suffix = Some(camera.name.clone());
+                }
+                view
+            })
+            .collect()
+    }
+
+    /// Returns a named medium; `None` if the name is `None` or empty, which
+    /// stands for vacuum; or an error if the medium isn't defined.
+    ///
+    /// * `name` - Medium name.
+    /// * `side` - "inside", "outside" or "camera" for the error message.
+    pub fn named_medium(&self, name: Option<&str>, side: &str) -> Result<Option<ArcMedium>, Error> {
+        match name {
+            None | Some("") => Ok(None),
+            Some(name) => match self.named_media.get(name) {
+                Some(medium) => Ok(Some(medium.clone())),
+                None => Err(Error::InvalidParameter(format!(
+                    "Named medium '{}' undefined for side '{}'.",
+                    name, side
+                ))),
+            },
+        }
+    }
+
     /// Returns a `Camera` based on the render options.
     ///
     /// * `gs` - The `GraphicsState`.
-    pub fn make_camera(&self, gs: &GraphicsState) -> ArcCamera {
-        let filter = match GraphicsState::make_filter(&self.filter_name, &self.filter_params) {
-            Ok(f) => f,
-            Err(err) => panic!("{}", err),
-        };
-        let film = match GraphicsState::make_film(&self.film_name, &self.film_params, filter) {
-            Ok(f) => f,
-            Err(err) => panic!("{}", err),
-        };
+    pub fn make_camera(&self, gs: &GraphicsState) -> Result<ArcCamera, Error> {
+        let filter = GraphicsState::make_filter(&self.filter_name, &self.filter_params)?;
+        let mut film = GraphicsState::make_film(&self.film_name, &self.film_params, filter)?;
+        if let Some(f) = Arc::get_mut(&mut film) {
+            if let Some(suffix) = self.output_suffix.as_ref() {
+                f.filename = suffixed_path(&f.filename, suffix);
+            }
+            if self.in_memory {
+                f.filename = String::new();
+            } else if let Some(frame) = self.frame {
+                f.filename = frame_path(&f.filename, frame);
+            }
+        }
 
-        let inside_medium =
-            gs.current_inside_medium
-                .clone()
-                .map_or(None, |m| match self.named_media.get(&m) {
-                    Some(medium) => Some(medium.clone()),
-                    None => None,
-                });
-        let outside_medium =
-            gs.current_outside_medium
-                .clone()
-                .map_or(None, |m| match self.named_media.get(&m) {
-                    Some(medium) => Some(medium.clone()),
-                    None => None,
-                });
-
-        let medium_interface = MediumInterface::new(inside_medium, outside_medium);
-
-        match gs.make_camera(
+        let medium = self
+            .named_medium(self.camera_medium.as_deref(), "camera")
+            .unwrap_or_else(|err| {
+                error!("{}", err);
+                None
+            });
+        let medium_interface = MediumInterface::new(medium.clone(), medium);
+
+        gs.make_camera(
             &self.camera_name,
             &self.camera_params,
             &self.camera_to_world,
@@ -209,9 +371,44 @@ impl RenderOptions {
             self.transform_end_time,
             film,
             &medium_interface,
-        ) {
-            Ok(camera) => camera,
-            Err(err) => panic!("{}", err),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn named_cameras_render_separate_views() {
+        let mut render_options = RenderOptions::new();
+        render_options
+            .film_params
+            .add_string("filename", &[String::from("image.png")]);
+        assert_eq!(render_options.camera_views().len(), 1);
+
+        let mut film_params = ParamSet::new();
+        film_params.add_string("filename", &[String::from("top.png")]);
+        for (name, film) in [
+            ("left", None),
+            ("top", Some((String::from("image"), film_params))),
+        ] {
+            render_options.named_cameras.push(NamedCamera {
+                name: String::from(name),
+                camera_name: String::from("orthographic"),
+                camera_params: ParamSet::new(),
+                camera_to_world: TransformSet::default(),
+                camera_medium: None,
+                film,
+            });
         }
+
+        let views = render_options.camera_views();
+        assert_eq!(views.len(), 2);
+        assert!(views.iter().all(|v| v.camera_name == "orthographic"));
+        assert!(views.iter().all(|v| v.named_cameras.is_empty()));
+        assert_eq!(views[0].output_suffix.as_deref(), Some("left"));
+        assert_eq!(views[1].output_suffix, None);
+        assert_eq!(views[1].film_params.find_string("filename"), ["top.png"]);
     }
 }
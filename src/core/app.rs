@@ -1,12 +1,39 @@
 //! Application related stuff
 
 #![allow(dead_code)]
+use crate::core::error::Error;
+use crate::core::fileutil::env_search_paths;
+use crate::core::integrator::TileOrder;
+use crate::core::logging::LogFormat;
 use crate::core::pbrt::Float;
+use crate::core::spectrum::ColorSpace;
 use clap::*;
+use std::sync::Mutex;
 
 lazy_static! {
-    /// The global application options.
-    pub static ref OPTIONS: Options = Options::new();
+    /// Options given with `set_options()` before the global options are used.
+    static ref PENDING_OPTIONS: Mutex<Option<Options>> = Mutex::new(None);
+
+    /// The global application options. The binary loads them from the command
+    /// line with `set_options()`; otherwise the defaults are used.
+    pub static ref OPTIONS: Options = PENDING_OPTIONS.lock().unwrap().take().unwrap_or_default();
+}
+
+/// Sets the global application options. Returns an error if they are already
+/// in use, e.g. because a scene was rendered.
+///
+/// * `options` - The options.
+pub fn set_options(options: Options) -> std::result::Result<(), Error> {
+    *PENDING_OPTIONS.lock().unwrap() = Some(options);
+    lazy_static::initialize(&OPTIONS);
+
+    // The options are still pending if they were initialized earlier.
+    match PENDING_OPTIONS.lock().unwrap().take() {
+        Some(_) => Err(Error::Unsupported(String::from(
+            "The options can't be changed once they are in use.",
+        ))),
+        None => Ok(()),
+    }
 }
 
 /// System wide options.
@@ -15,35 +42,273 @@ pub struct Options {
     /// Number of threads to use for rendering.
     pub n_threads: usize,
 
+    /// Width and height of the image tiles rendered by each thread in pixels.
+    pub tile_size: usize,
+
+    /// Order in which image tiles are rendered.
+    pub tile_order: TileOrder,
+
+    /// Optional pixel region x0, x1, y0, y1 whose tiles are rendered first.
+    pub priority_region: Option<[i32; 4]>,
+
+    /// Split the last tiles of a render so idle threads can help finish them.
+    pub split_tiles: bool,
+
+    /// Make images bit-identical across runs and numbers of threads.
+    pub deterministic: bool,
+
+    /// Render tiles by tracing their rays in batches per stage instead of one
+    /// camera sample at a time.
+    pub wavefront: bool,
+
+    /// Refine the whole image in passes of one sample per pixel and write it
+    /// periodically.
+    pub progressive: bool,
+
+    /// Optional path of the image written during progressive rendering; the
+    /// output image is used if not given.
+    pub progress_file: Option<String>,
+
+    /// Optional number of seconds after which the image is written again
+    /// during progressive rendering.
+    pub progress_interval: Option<Float>,
+
+    /// Optional number of passes after which the image is written again
+    /// during progressive rendering.
+    pub progress_passes: Option<usize>,
+
+    /// Render a preview on the GPU. Requires the `gpu` feature.
+    pub gpu: bool,
+
     /// Automatically reduce a number of quality settings to render more quickly.
     pub quick_render: bool,
 
     /// Suppress all text output other than error messages.:
     pub quiet: bool,
 
+    /// Optional verbosity of each log target in `RUST_LOG` syntax.
+    pub log_filters: Option<String>,
+
+    /// Format of log messages.
+    pub log_format: LogFormat,
+
+    /// Measure the time spent in each phase of rendering and print a summary.
+    pub profile: bool,
+
+    /// Load the scenes and report statistics about them without rendering.
+    pub stats_only: bool,
+
+    /// Optional path of a file to export the scenes to in the PBRT file format
+    /// instead of rendering them.
+    pub export: Option<String>,
+
+    /// Write a JSON report of each render next to its image.
+    pub report: bool,
+
     /// Path to the image file.
     pub image_file: String,
 
-    /// The crop window x0, x1, y0, y1.
-    pub crop_window: [[Float; 2]; 2],
+    /// Optional directory of the geometry cache holding converted meshes.
+    pub geometry_cache: Option<String>,
+
+    /// Optional directory of the scene cache holding parsed scenes and BVHs.
+    pub scene_cache: Option<String>,
+
+    /// Directories searched for texture, mesh and other asset files whose
+    /// relative paths don't exist relative to the working directory, in
+    /// order.
+    pub search_paths: Vec<String>,
+
+    /// Optional crop window x0, x1 and y0, y1 in NDC space.
+    pub crop_window: Option<[[Float; 2]; 2]>,
+
+    /// Optional pixel bounds x0, x1, y0, y1 rendered instead of the crop
+    /// window.
+    pub pixel_bounds: Option<[i32; 4]>,
+
+    /// Write the cropped pixels into the existing output image, keeping its
+    /// other pixels.
+    pub keep_uncropped: bool,
+
+    /// Optional pixel region x0, x1, y0, y1 re-rendered into the existing
+    /// output image.
+    pub region: Option<[i32; 4]>,
+
+    /// Optional first and last frame numbers of an animation sequence.
+    pub frames: Option<[usize; 2]>,
+
+    /// Optional pixel whose sample is traced instead of rendering the image.
+    pub debug_pixel: Option<[i32; 2]>,
+
+    /// Index of the sample of `debug_pixel` to trace.
+    pub debug_sample: usize,
+
+    /// Optional path of an OBJ file the traced path of `debug_pixel` is
+    /// written to.
+    pub debug_obj: Option<String>,
+
+    /// The color space in which RGB values are interpreted during rendering.
+    pub color_space: ColorSpace,
+
+    /// Optional gamma used instead of the sRGB curve for 8-bit images.
+    pub gamma: Option<Float>,
+
+    /// Address to listen on for workers when distributing tiles to them.
+    pub coordinator: Option<String>,
+
+    /// Address of the coordinator when rendering tiles for it.
+    pub worker: Option<String>,
+
+    /// Address to listen on for render requests instead of rendering the
+    /// input files.
+    pub serve: Option<String>,
+
+    /// Directory scenes submitted to the render service can include files
+    /// and use asset files from.
+    pub scene_root: Option<String>,
+
+    /// Paths of plugin libraries to load before parsing scenes.
+    pub plugins: Vec<String>,
+
+    /// Allow scene files to load plugins with `Option "string plugin"`.
+    /// Scenes submitted to the render service never can.
+    pub allow_scene_plugins: bool,
 
     /// Input file paths. Empty vector implies read from stdin.
     pub paths: Vec<String>,
 }
 
+impl Default for Options {
+    /// Returns the default options without reading the command line.
+    fn default() -> Self {
+        Self::from_args(["pbr-rust"])
+    }
+}
+
 impl Options {
     /// Loads the command line options.
     pub fn new() -> Self {
+        Self::from_args(std::env::args_os())
+    }
+
+    /// Loads the options from a list of command line arguments. The first
+    /// argument is the program name.
+    ///
+    /// * `args` - The arguments.
+    pub fn from_args<I, T>(args: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<std::ffi::OsString> + Clone,
+    {
         let matches = app_from_crate!()
             .arg(
                 Arg::with_name("nthreads")
                     .short("t")
                     .long("nthreads")
                     .value_name("NUM")
-                    .default_value("1")
+                    .default_value("0")
+                    .takes_value(true)
+                    .help(
+                        "Use specified number of threads for rendering. Use 0 for 
+                        the number of logical CPUs.",
+                    ),
+            )
+            .arg(
+                Arg::with_name("tilesize")
+                    .long("tilesize")
+                    .value_name("PIXELS")
+                    .default_value("16")
+                    .takes_value(true)
+                    .help("Use square image tiles of the specified size for rendering."),
+            )
+            .arg(
+                Arg::with_name("tileorder")
+                    .long("tileorder")
+                    .value_name("ORDER")
+                    .default_value("spiral")
+                    .possible_values(&["rowmajor", "spiral", "hilbert"])
+                    .takes_value(true)
+                    .help(
+                        "Order in which image tiles are rendered. 'spiral' starts at 
+                        the center of the image.",
+                    ),
+            )
+            .arg(
+                Arg::with_name("priority-region")
+                    .long("priority-region")
+                    .value_name("x0 x1 y0 y1")
+                    .number_of_values(4)
+                    .takes_value(true)
+                    .help("Render the tiles overlapping the given pixel rectangle first."),
+            )
+            .arg(
+                Arg::with_name("split-tiles")
+                    .long("split-tiles")
+                    .takes_value(false)
+                    .help(
+                        "Split the last tiles of a render into quarters so idle threads 
+                        can help finish them. Noise in split tiles then depends on timing.",
+                    ),
+            )
+            .arg(
+                Arg::with_name("deterministic")
+                    .long("deterministic")
+                    .takes_value(false)
+                    .conflicts_with("split-tiles")
+                    .help(
+                        "Make images bit-identical across runs and numbers of threads, 
+                        e.g. for regression tests, at the cost of some rendering speed.",
+                    ),
+            )
+            .arg(
+                Arg::with_name("wavefront")
+                    .long("wavefront")
+                    .takes_value(false)
+                    .help(
+                        "Trace the rays of each tile in batches per stage (generate, 
+                        intersect, shade, shadow) instead of one sample at a time.",
+                    ),
+            )
+            .arg(
+                Arg::with_name("progressive")
+                    .long("progressive")
+                    .takes_value(false)
+                    .help(
+                        "Refine the whole image one sample per pixel at a time and write 
+                        it periodically so the render can be monitored and stopped early.",
+                    ),
+            )
+            .arg(
+                Arg::with_name("progress-file")
+                    .long("progress-file")
+                    .value_name("FILE")
+                    .takes_value(true)
+                    .requires("progressive")
+                    .help("Write the image to the given filename during progressive rendering."),
+            )
+            .arg(
+                Arg::with_name("progress-interval")
+                    .long("progress-interval")
+                    .value_name("SECONDS")
                     .takes_value(true)
-                    .help("Use specified number of threads for rendering."),
+                    .requires("progressive")
+                    .help(
+                        "Write the image during progressive rendering once this many 
+                        seconds have passed. Defaults to 10 unless --progress-passes is given.",
+                    ),
             )
+            .arg(
+                Arg::with_name("progress-passes")
+                    .long("progress-passes")
+                    .value_name("N")
+                    .takes_value(true)
+                    .requires("progressive")
+                    .help("Write the image during progressive rendering after every N passes."),
+            )
+            .arg(Arg::with_name("gpu").long("gpu").takes_value(false).help(
+                "Render a preview on the GPU. Only triangles and diffuse 
+                        materials are supported.",
+            ))
             .arg(
                 Arg::with_name("outfile")
                     .short("o")
@@ -56,10 +321,102 @@ impl Options {
                 Arg::with_name("cropwindow")
                     .short("cw")
                     .long("cropwindow")
-                    .value_name("x0 y0 x1 y1")
+                    .value_name("x0 x1 y0 y1")
+                    .number_of_values(4)
+                    .takes_value(true)
+                    .help(
+                        "Render only the part of the image from x0 to x1 and y0 to y1, 
+                        given from 0 to 1. Overrides the film's 'cropwindow'.",
+                    ),
+            )
+            .arg(
+                Arg::with_name("pixelbounds")
+                    .long("pixelbounds")
+                    .value_name("x0 x1 y0 y1")
+                    .number_of_values(4)
+                    .takes_value(true)
+                    .help(
+                        "Render only the given pixel rectangle, excluding x1 and y1, 
+                        instead of the crop window.",
+                    ),
+            )
+            .arg(
+                Arg::with_name("keep-uncropped")
+                    .long("keep-uncropped")
+                    .takes_value(false)
+                    .help(
+                        "Write the cropped pixels into the existing output image, 
+                        keeping its other pixels, instead of writing the cropped image.",
+                    ),
+            )
+            .arg(
+                Arg::with_name("region")
+                    .long("region")
+                    .value_name("x0 x1 y0 y1")
                     .number_of_values(4)
                     .takes_value(true)
-                    .help("Specify an image crop window."),
+                    .help(
+                        "Re-render only the given pixel rectangle, excluding x1 and y1, 
+                        into the existing output image, keeping its other pixels.",
+                    ),
+            )
+            .arg(
+                Arg::with_name("debug-pixel")
+                    .long("debug-pixel")
+                    .value_name("X,Y")
+                    .takes_value(true)
+                    .help("Trace one sample of a pixel as JSON lines instead of rendering."),
+            )
+            .arg(
+                Arg::with_name("debug-sample")
+                    .long("debug-sample")
+                    .value_name("N")
+                    .takes_value(true)
+                    .requires("debug-pixel")
+                    .help("Index of the sample to trace with --debug-pixel. Defaults to 0."),
+            )
+            .arg(
+                Arg::with_name("debug-obj")
+                    .long("debug-obj")
+                    .value_name("FILE")
+                    .takes_value(true)
+                    .requires("debug-pixel")
+                    .help("Write the path traced with --debug-pixel as OBJ polylines."),
+            )
+            .arg(
+                Arg::with_name("frames")
+                    .long("frames")
+                    .value_name("FIRST LAST")
+                    .number_of_values(2)
+                    .takes_value(true)
+                    .help(
+                        "Render an animation sequence. Input paths containing '#' 
+                        are per-frame scene files; otherwise one scene is rendered 
+                        for each frame.",
+                    ),
+            )
+            .arg(
+                Arg::with_name("colorspace")
+                    .long("colorspace")
+                    .value_name("NAME")
+                    .default_value("srgb")
+                    .possible_values(&["srgb", "acescg", "rec2020", "displayp3"])
+                    .takes_value(true)
+                    .help(
+                        "Color space for RGB values used during rendering. The 
+                        output image uses this unless the film specifies its own 
+                        'colorspace'.",
+                    ),
+            )
+            .arg(
+                Arg::with_name("gamma")
+                    .long("gamma")
+                    .value_name("GAMMA")
+                    .takes_value(true)
+                    .help(
+                        "Encode 8-bit images with the given gamma instead of the sRGB 
+                        curve. Use 1 for linear values.",
+                    ),
             )
             .arg(
                 Arg::with_name("quick")
@@ -78,13 +435,179 @@ impl Options {
                     .default_value("false")
                     .help("Suppress all text output other than error messages."),
             )
+            .arg(
+                Arg::with_name("log")
+                    .long("log")
+                    .value_name("FILTERS")
+                    .takes_value(true)
+                    .help(
+                        "Verbosity of each log target in RUST_LOG syntax, e.g. 
+                        'warn,parser=debug'. Targets include parser, bvh, integrator 
+                        and film.",
+                    ),
+            )
+            .arg(
+                Arg::with_name("log-format")
+                    .long("log-format")
+                    .value_name("FORMAT")
+                    .default_value("text")
+                    .possible_values(&["text", "json"])
+                    .takes_value(true)
+                    .help("Format of log messages; 'json' writes one object per line."),
+            )
+            .arg(
+                Arg::with_name("profile")
+                    .long("profile")
+                    .takes_value(false)
+                    .help("Print a summary of the time spent in each phase of rendering."),
+            )
+            .arg(
+                Arg::with_name("report")
+                    .long("report")
+                    .takes_value(false)
+                    .help(
+                        "Write a JSON report of the render time, samples, memory, statistics 
+                        and warnings next to each image.",
+                    ),
+            )
+            .arg(
+                Arg::with_name("geometry-cache")
+                    .long("geometry-cache")
+                    .value_name("DIR")
+                    .takes_value(true)
+                    .help(
+                        "Keep large triangle meshes converted to a binary format in the given 
+                        directory and memory-map them in later renders.",
+                    ),
+            )
+            .arg(
+                Arg::with_name("scene-cache")
+                    .long("scene-cache")
+                    .value_name("DIR")
+                    .takes_value(true)
+                    .help(
+                        "Keep parsed scenes and large BVHs in a binary format in the given 
+                        directory and load them in later renders of unchanged scenes.",
+                    ),
+            )
+            .arg(
+                Arg::with_name("texturedir")
+                    .long("texturedir")
+                    .value_name("DIR")
+                    .takes_value(true)
+                    .multiple(true)
+                    .number_of_values(1)
+                    .help(
+                        "Search the given directory for textures and other files with 
+                        relative paths that aren't found. Can be given more than once.",
+                    ),
+            )
+            .arg(
+                Arg::with_name("meshdir")
+                    .long("meshdir")
+                    .value_name("DIR")
+                    .takes_value(true)
+                    .multiple(true)
+                    .number_of_values(1)
+                    .help(
+                        "Search the given directory for meshes and other files with 
+                        relative paths that aren't found. Can be given more than once.",
+                    ),
+            )
+            .arg(
+                Arg::with_name("stats-only")
+                    .long("stats-only")
+                    .takes_value(false)
+                    .help(
+                        "Load the scene and print statistics about its shapes, lights, 
+                        textures, acceleration structures and memory without rendering.",
+                    ),
+            )
+            .arg(
+                Arg::with_name("export")
+                    .long("export")
+                    .value_name("FILE")
+                    .takes_value(true)
+                    .help(
+                        "Write the scene as parsed to a single PBRT file with default options, 
+                        absolute file paths and OBJ meshes converted to triangle meshes 
+                        instead of rendering it.",
+                    ),
+            )
+            .arg(
+                Arg::with_name("coordinator")
+                    .long("coordinator")
+                    .value_name("ADDRESS")
+                    .takes_value(true)
+                    .conflicts_with("worker")
+                    .help(
+                        "Distribute image tiles to workers connecting to the given 
+                        address, e.g. 0.0.0.0:7878, and write the merged image.",
+                    ),
+            )
+            .arg(
+                Arg::with_name("worker")
+                    .long("worker")
+                    .value_name("ADDRESS")
+                    .takes_value(true)
+                    .help(
+                        "Render image tiles for the coordinator at the given 
+                        address. The scene must be the same as the coordinator's.",
+                    ),
+            )
+            .arg(
+                Arg::with_name("serve")
+                    .long("serve")
+                    .value_name("ADDRESS")
+                    .takes_value(true)
+                    .help(
+                        "Run a render service on the given address, e.g. 
+                        0.0.0.0:8080, that renders scenes submitted over HTTP. 
+                        A port alone listens on 127.0.0.1.",
+                    ),
+            )
+            .arg(
+                Arg::with_name("plugin")
+                    .long("plugin")
+                    .value_name("PATH")
+                    .takes_value(true)
+                    .multiple(true)
+                    .number_of_values(1)
+                    .help(
+                        "Load a plugin library providing additional shapes, 
+                        materials and textures. Can be repeated. Requires the 
+                        plugins feature.",
+                    ),
+            )
+            .arg(
+                Arg::with_name("allow-scene-plugins")
+                    .long("allow-scene-plugins")
+                    .takes_value(false)
+                    .help(
+                        "Allow scene files to load plugin libraries with 
+                        Option \"string plugin\". Plugins run arbitrary code, 
+                        so only use it with trusted scenes. Scenes submitted 
+                        to the render service can't load plugins.",
+                    ),
+            )
+            .arg(
+                Arg::with_name("scene-root")
+                    .long("scene-root")
+                    .value_name("DIR")
+                    .takes_value(true)
+                    .help(
+                        "Directory scenes submitted to the render service can 
+                        include files and use asset files from. They can't use 
+                        files without it.",
+                    ),
+            )
             .arg(
                 Arg::with_name("INPUT")
                     .required(false)
                     .multiple(true)
                     .help("Input files"),
             )
-            .get_matches();
+            .get_matches_from(args);
 
         let max_threads = num_cpus::get();
         let n_threads = match matches.value_of("nthreads") {
@@ -92,42 +615,135 @@ impl Options {
                 let n = s.parse::<usize>().expect("Invalid nthreads");
 
                 if n == 0 {
-                    panic!("Invalid nthreads");
+                    max_threads
                 } else if n > max_threads {
                     panic!("Num threads > max logical CPUs {}", max_threads);
+                } else {
+                    n
                 }
+            }
+
+            _ => max_threads,
+        };
 
+        let tile_size = match matches.value_of("tilesize") {
+            Some(s) => {
+                let n = s.parse::<usize>().expect("Invalid tilesize");
+                if n == 0 {
+                    panic!("Invalid tilesize");
+                }
                 n
             }
-
-            _ => 1,
+            _ => 16,
         };
 
+        // Logging isn't initialized yet so a missing output filename is
+        // reported once it is.
         let image_file = match matches.value_of("outfile") {
             Some(s) => s.to_string(),
-            _ => {
-                warn!("Missing outfile. Will use one from scene description.");
-                String::from("")
+            _ => String::from(""),
+        };
+
+        let geometry_cache = matches.value_of("geometry-cache").map(String::from);
+        let scene_cache = matches.value_of("scene-cache").map(String::from);
+
+        // Directories given on the command line are searched before those in
+        // the environment.
+        let mut search_paths: Vec<String> = vec![];
+        for name in ["texturedir", "meshdir"].iter() {
+            if let Some(dirs) = matches.values_of(name) {
+                search_paths.extend(dirs.map(String::from));
             }
+        }
+        search_paths.extend(env_search_paths());
+
+        let tile_order = match matches.value_of("tileorder") {
+            Some(s) => TileOrder::from_name(s).expect("Invalid tileorder"),
+            _ => TileOrder::Spiral,
         };
 
-        let crop_window = match matches.values_of("cropwindow") {
+        let priority_region = matches.values_of("priority-region").map(|s| {
+            let v: Vec<i32> = s
+                .map(|c| c.parse::<i32>().expect("Invalid priority-region"))
+                .collect();
+            [v[0], v[1], v[2], v[3]]
+        });
+
+        let split_tiles = matches.is_present("split-tiles");
+
+        let deterministic = matches.is_present("deterministic");
+
+        let debug_pixel = matches.value_of("debug-pixel").map(|s| {
+            let v: Vec<i32> = s
+                .split(',')
+                .map(|c| c.trim().parse::<i32>().expect("Invalid debug-pixel"))
+                .collect();
+            assert!(v.len() == 2, "Invalid debug-pixel");
+            [v[0], v[1]]
+        });
+
+        let debug_sample = match matches.value_of("debug-sample") {
+            Some(s) => s.parse::<usize>().expect("Invalid debug-sample"),
+            None => 0,
+        };
+
+        let debug_obj = matches.value_of("debug-obj").map(String::from);
+
+        let crop_window = matches.values_of("cropwindow").map(|s| {
+            let v: Vec<&str> = s.collect();
+            [
+                [
+                    v[0].parse::<Float>().expect("Invalid cropwindow.x0"),
+                    v[1].parse::<Float>().expect("Invalid cropwindow.x1"),
+                ],
+                [
+                    v[2].parse::<Float>().expect("Invalid cropwindow.y0"),
+                    v[3].parse::<Float>().expect("Invalid cropwindow.y1"),
+                ],
+            ]
+        });
+
+        let pixel_bounds = matches.values_of("pixelbounds").map(|s| {
+            let v: Vec<i32> = s
+                .map(|c| c.parse::<i32>().expect("Invalid pixelbounds"))
+                .collect();
+            [v[0], v[1], v[2], v[3]]
+        });
+
+        let keep_uncropped = matches.is_present("keep-uncropped");
+
+        let region = matches.values_of("region").map(|s| {
+            let v: Vec<i32> = s
+                .map(|c| c.parse::<i32>().expect("Invalid region"))
+                .collect();
+            [v[0], v[1], v[2], v[3]]
+        });
+
+        let frames = match matches.values_of("frames") {
             Some(s) => {
                 let v: Vec<&str> = s.collect();
-                [
-                    [
-                        v[0].parse::<Float>().expect("Invalid cropwindow.x0"),
-                        v[1].parse::<Float>().expect("Invalid cropwindow.y0"),
-                    ],
-                    [
-                        v[2].parse::<Float>().expect("Invalid cropwindow.x1"),
-                        v[3].parse::<Float>().expect("Invalid cropwindow.y1"),
-                    ],
-                ]
+                let first = v[0].parse::<usize>().expect("Invalid frames.first");
+                let last = v[1].parse::<usize>().expect("Invalid frames.last");
+                if last < first {
+                    panic!(
+                        "Invalid frames; last frame {} < first frame {}",
+                        last, first
+                    );
+                }
+                Some([first, last])
             }
-            _ => [[0.0, 1.0], [0.0, 1.0]],
+            _ => None,
         };
 
+        let color_space = match matches.value_of("colorspace") {
+            Some(s) => ColorSpace::from_name(s).expect("Invalid colorspace"),
+            _ => ColorSpace::SRGB,
+        };
+
+        let gamma = matches
+            .value_of("gamma")
+            .map(|s| s.parse::<Float>().expect("Invalid gamma"));
+
         let quick_render = match matches.value_of("quick") {
             Some(s) => s.parse::<bool>().expect("Invalid quick"),
             _ => false,
@@ -138,6 +754,57 @@ impl Options {
             _ => false,
         };
 
+        let log_filters = matches.value_of("log").map(String::from);
+
+        let log_format = match matches.value_of("log-format") {
+            Some(s) => LogFormat::from_name(s).expect("Invalid log-format"),
+            _ => LogFormat::Text,
+        };
+
+        let profile = matches.is_present("profile");
+
+        let stats_only = matches.is_present("stats-only");
+
+        let export = matches.value_of("export").map(String::from);
+
+        let report = matches.is_present("report");
+
+        let wavefront = matches.is_present("wavefront");
+
+        let gpu = matches.is_present("gpu");
+
+        let progressive = matches.is_present("progressive");
+
+        let progress_file = matches.value_of("progress-file").map(String::from);
+
+        let progress_passes = matches.value_of("progress-passes").map(|s| {
+            let n = s.parse::<usize>().expect("Invalid progress-passes");
+            if n == 0 {
+                panic!("Invalid progress-passes");
+            }
+            n
+        });
+
+        let progress_interval = match matches.value_of("progress-interval") {
+            Some(s) => Some(s.parse::<Float>().expect("Invalid progress-interval")),
+            None if progress_passes.is_none() => Some(10.0),
+            None => None,
+        };
+
+        let coordinator = matches.value_of("coordinator").map(String::from);
+
+        let worker = matches.value_of("worker").map(String::from);
+
+        let serve = matches.value_of("serve").map(String::from);
+
+        let scene_root = matches.value_of("scene-root").map(String::from);
+
+        let plugins: Vec<String> = match matches.values_of("plugin") {
+            Some(p) => p.map(String::from).collect(),
+            None => vec![],
+        };
+        let allow_scene_plugins = matches.is_present("allow-scene-plugins");
+
         let paths: Vec<String> = match matches.values_of("INPUT") {
             Some(p) => p.map(String::from).collect(),
             None => vec![],
@@ -145,11 +812,58 @@ impl Options {
 
         Self {
             n_threads,
+            tile_size,
+            tile_order,
+            priority_region,
+            split_tiles,
+            deterministic,
+            wavefront,
+            progressive,
+            progress_file,
+            progress_interval,
+            progress_passes,
+            gpu,
             quick_render,
             quiet,
+            log_filters,
+            log_format,
+            profile,
+            stats_only,
+            export,
+            report,
             image_file,
+            geometry_cache,
+            scene_cache,
+            search_paths,
             crop_window,
+            pixel_bounds,
+            keep_uncropped,
+            region,
+            frames,
+            debug_pixel,
+            debug_sample,
+            debug_obj,
+            color_space,
+            gamma,
+            coordinator,
+            worker,
+            serve,
+            scene_root,
+            plugins,
+            allow_scene_plugins,
             paths,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn options_can_not_be_changed_once_in_use() {
+        assert!(OPTIONS.serve.is_none());
+        assert!(set_options(Options::from_args(["pbr-rust", "--serve", "8080"])).is_err());
+        assert!(OPTIONS.serve.is_none());
+    }
+}
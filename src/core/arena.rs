@@ -0,0 +1,332 @@
+//! Memory Arena
+
+#![allow(dead_code)]
+use std::alloc::{alloc, dealloc, handle_alloc_error, Layout};
+use std::cell::RefCell;
+use std::mem::{align_of, needs_drop, size_of};
+use std::ptr::{self, NonNull};
+use std::slice;
+
+/// Default size of the blocks allocated by `MemoryArena` (256 kB).
+pub const DEFAULT_ARENA_BLOCK_SIZE: usize = 262144;
+
+/// Alignment of the blocks allocated by `MemoryArena`. This is a typical L1
+/// cache line size; allocations may not require a larger alignment.
+const ARENA_BLOCK_ALIGNMENT: usize = 64;
+
+/// A bump allocator for short lived objects such as the BSDFs and sample
+/// arrays used while tracing a single camera ray.
+///
+/// Objects are carved out of large blocks of memory and are all released at
+/// once with `reset()`. Allocations borrow the arena, so they cannot outlive
+/// it and the borrow checker rejects a `reset()` while any of them are still
+/// in use. Destructors of allocated objects run on `reset()` or when the
+/// arena is dropped.
+///
+/// A `MemoryArena` is meant to be owned by a single rendering thread and is
+/// neither `Send` nor `Sync`.
+pub struct MemoryArena {
+    /// Minimum size of a block.
+    block_size: usize,
+
+    /// The blocks and allocation state.
+    state: RefCell<ArenaState>,
+}
+
+/// Blocks and allocation state of a `MemoryArena`.
+struct ArenaState {
+    /// Block that allocations are currently made from.
+    current: Option<Block>,
+
+    /// Offset of the first free byte in `current`.
+    current_pos: usize,
+
+    /// Full blocks holding allocations.
+    used: Vec<Block>,
+
+    /// Blocks that are free to reuse after a `reset()`.
+    available: Vec<Block>,
+
+    /// Allocated objects that need to be dropped along with the number of
+    /// objects at that address.
+    destructors: Vec<Destructor>,
+}
+
+/// Drops a run of allocated objects.
+struct Destructor {
+    /// Address of the first object.
+    ptr: *mut u8,
+
+    /// Number of objects.
+    len: usize,
+
+    /// Drops `len` objects starting at `ptr`.
+    drop_fn: unsafe fn(*mut u8, usize),
+}
+
+/// A block of memory owned by a `MemoryArena`.
+struct Block {
+    /// Start of the block.
+    ptr: NonNull<u8>,
+
+    /// Size of the block in bytes.
+    size: usize,
+}
+
+impl MemoryArena {
+    /// Create a new `MemoryArena`.
+    ///
+    /// * `block_size` - Minimum size of the blocks in bytes. Larger blocks are
+    ///                  allocated for objects that don't fit in one.
+    pub fn new(block_size: usize) -> Self {
+        Self {
+            block_size: block_size.max(1),
+            state: RefCell::new(ArenaState {
+                current: None,
+                current_pos: 0,
+                used: vec![],
+                available: vec![],
+                destructors: vec![],
+            }),
+        }
+    }
+
+    /// Moves a value into the arena and returns a reference to it.
+    ///
+    /// * `value` - The value.
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc<T>(&self, value: T) -> &mut T {
+        let p = self.alloc_raw::<T>(1);
+        unsafe {
+            ptr::write(p.as_ptr(), value);
+            self.register_drop(p, 1);
+            &mut *p.as_ptr()
+        }
+    }
+
+    /// Allocates an array of `n` values in the arena and initializes them by
+    /// calling `f` with the index of each value.
+    ///
+    /// * `n` - Number of values.
+    /// * `f` - Returns the value at an index.
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_slice_with<T, F>(&self, n: usize, mut f: F) -> &mut [T]
+    where
+        F: FnMut(usize) -> T,
+    {
+        let p = self.alloc_raw::<T>(n);
+        unsafe {
+            // `f` may allocate from the arena too so the state isn't
+            // borrowed here. If it panics the initialized values leak.
+            for i in 0..n {
+                ptr::write(p.as_ptr().add(i), f(i));
+            }
+            self.register_drop(p, n);
+            slice::from_raw_parts_mut(p.as_ptr(), n)
+        }
+    }
+
+    /// Allocates an array of `n` default values in the arena.
+    ///
+    /// * `n` - Number of values.
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_slice<T: Default>(&self, n: usize) -> &mut [T] {
+        self.alloc_slice_with(n, |_| T::default())
+    }
+
+    /// Copies values into the arena and returns a reference to the copy.
+    ///
+    /// * `src` - The values.
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_slice_copy<T: Copy>(&self, src: &[T]) -> &mut [T] {
+        let p = self.alloc_raw::<T>(src.len());
+        unsafe {
+            ptr::copy_nonoverlapping(src.as_ptr(), p.as_ptr(), src.len());
+            slice::from_raw_parts_mut(p.as_ptr(), src.len())
+        }
+    }
+
+    /// Drops all objects allocated in the arena and makes its memory
+    /// available for new allocations. The blocks are kept for reuse.
+    pub fn reset(&mut self) {
+        let state = self.state.get_mut();
+        state.run_destructors();
+        let ArenaState {
+            current,
+            current_pos,
+            used,
+            available,
+            ..
+        } = state;
+        available.extend(current.take());
+        available.append(used);
+        *current_pos = 0;
+    }
+
+    /// Returns the total number of bytes held by the arena, whether in use or
+    /// not.
+    pub fn total_allocated(&self) -> usize {
+        let state = self.state.borrow();
+        state.current.as_ref().map_or(0, |b| b.size)
+            + state.used.iter().map(|b| b.size).sum::<usize>()
+            + state.available.iter().map(|b| b.size).sum::<usize>()
+    }
+
+    /// Returns uninitialized memory for `n` values of type `T`.
+    ///
+    /// * `n` - Number of values.
+    fn alloc_raw<T>(&self, n: usize) -> NonNull<T> {
+        let align = align_of::<T>();
+        assert!(
+            align <= ARENA_BLOCK_ALIGNMENT,
+            "MemoryArena cannot allocate objects aligned to {} bytes.",
+            align
+        );
+        let size = size_of::<T>()
+            .checked_mul(n)
+            .expect("MemoryArena allocation size overflow.");
+        if size == 0 {
+            return NonNull::dangling();
+        }
+
+        let mut state = self.state.borrow_mut();
+        let ArenaState {
+            current,
+            current_pos,
+            used,
+            available,
+            ..
+        } = &mut *state;
+
+        // Round up the offset to the alignment of `T`; the blocks are aligned
+        // to `ARENA_BLOCK_ALIGNMENT`.
+        let mut offset = (*current_pos + align - 1) & !(align - 1);
+        if current.as_ref().is_none_or(|b| offset + size > b.size) {
+            // Retire the current block and find one that is large enough
+            // among the available blocks; otherwise allocate a new one.
+            used.extend(current.take());
+            let block = match available.iter().position(|b| b.size >= size) {
+                Some(i) => available.swap_remove(i),
+                None => Block::new(size.max(self.block_size)),
+            };
+            *current = Some(block);
+            offset = 0;
+        }
+
+        *current_pos = offset + size;
+        let block = current.as_ref().unwrap();
+        unsafe { NonNull::new_unchecked(block.ptr.as_ptr().add(offset) as *mut T) }
+    }
+
+    /// Records that `n` values at `p` need to be dropped on `reset()`.
+    ///
+    /// * `p` - Address of the first value.
+    /// * `n` - Number of values.
+    fn register_drop<T>(&self, p: NonNull<T>, n: usize) {
+        unsafe fn drop_values<T>(p: *mut u8, n: usize) {
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(p as *mut T, n));
+        }
+
+        if needs_drop::<T>() && n > 0 {
+            self.state.borrow_mut().destructors.push(Destructor {
+                ptr: p.as_ptr() as *mut u8,
+                len: n,
+                drop_fn: drop_values::<T>,
+            });
+        }
+    }
+}
+
+impl Default for MemoryArena {
+    /// Returns a `MemoryArena` with `DEFAULT_ARENA_BLOCK_SIZE` blocks.
+    fn default() -> Self {
+        Self::new(DEFAULT_ARENA_BLOCK_SIZE)
+    }
+}
+
+impl Drop for MemoryArena {
+    /// Drops all objects allocated in the arena. The blocks are freed when
+    /// `state` is dropped.
+    fn drop(&mut self) {
+        self.state.get_mut().run_destructors();
+    }
+}
+
+impl ArenaState {
+    /// Drops allocated objects in reverse order of allocation.
+    fn run_destructors(&mut self) {
+        while let Some(d) = self.destructors.pop() {
+            unsafe { (d.drop_fn)(d.ptr, d.len) };
+        }
+    }
+}
+
+impl Block {
+    /// Allocates a new block.
+    ///
+    /// * `size` - Size of the block in bytes.
+    fn new(size: usize) -> Self {
+        let layout = Self::layout(size);
+        let ptr = unsafe { alloc(layout) };
+        match NonNull::new(ptr) {
+            Some(ptr) => Self { ptr, size },
+            None => handle_alloc_error(layout),
+        }
+    }
+
+    /// Returns the memory layout of a block.
+    ///
+    /// * `size` - Size of the block in bytes.
+    fn layout(size: usize) -> Layout {
+        Layout::from_size_align(size, ARENA_BLOCK_ALIGNMENT)
+            .expect("Invalid MemoryArena block size.")
+    }
+}
+
+impl Drop for Block {
+    /// Frees the block.
+    fn drop(&mut self) {
+        unsafe { dealloc(self.ptr.as_ptr(), Self::layout(self.size)) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+
+    #[test]
+    fn allocations_spanning_blocks_keep_their_values() {
+        let arena = MemoryArena::new(64);
+        let a = arena.alloc(1_u8);
+        let b = arena.alloc_slice_with(100, |i| i as u64);
+        let c = arena.alloc(3.5_f64);
+        let d = arena.alloc_slice_copy(&[7_u16; 5]);
+
+        assert_eq!(*a, 1);
+        assert!(b.iter().enumerate().all(|(i, &v)| v == i as u64));
+        assert_eq!(*c, 3.5);
+        assert_eq!(d, &[7; 5]);
+        assert_eq!(c as *const f64 as usize % align_of::<f64>(), 0);
+        assert!(arena.total_allocated() >= 100 * size_of::<u64>());
+    }
+
+    #[test]
+    fn reset_drops_values_and_reuses_blocks() {
+        let counter = Rc::new(());
+        let mut arena = MemoryArena::new(256);
+        for _ in 0..3 {
+            arena.alloc(counter.clone());
+            arena.alloc_slice_with(4, |_| counter.clone());
+            assert_eq!(Rc::strong_count(&counter), 6);
+
+            arena.reset();
+            assert_eq!(Rc::strong_count(&counter), 1);
+            assert_eq!(arena.total_allocated(), 256);
+        }
+
+        arena.alloc(counter.clone());
+        drop(arena);
+        assert_eq!(Rc::strong_count(&counter), 1);
+    }
+}
@@ -0,0 +1,251 @@
+//! Photon Beam Diffusion
+
+use super::BSSRDFTable;
+use crate::core::interpolation::*;
+use crate::core::medium::*;
+use crate::core::parallel::*;
+use crate::core::pbrt::*;
+use crate::core::reflection::*;
+use crate::core::spectrum::*;
+
+/// Number of samples used to integrate the scattering terms along the beam.
+const BEAM_SAMPLES: usize = 100;
+
+/// Returns the first moment of the Fresnel reflectance function using a
+/// polynomial fit.
+///
+/// * `eta` - Relative index of refraction.
+pub fn fresnel_moment1(eta: Float) -> Float {
+    let eta2 = eta * eta;
+    let eta3 = eta2 * eta;
+    let eta4 = eta3 * eta;
+    let eta5 = eta4 * eta;
+    if eta < 1.0 {
+        0.45966 - 1.73965 * eta + 3.37668 * eta2 - 3.904945 * eta3 + 2.49277 * eta4 - 0.68441 * eta5
+    } else {
+        -4.61686 + 11.1136 * eta - 10.4646 * eta2 + 5.11455 * eta3 - 1.27198 * eta4 + 0.12746 * eta5
+    }
+}
+
+/// Returns the second moment of the Fresnel reflectance function using a
+/// polynomial fit.
+///
+/// * `eta` - Relative index of refraction.
+pub fn fresnel_moment2(eta: Float) -> Float {
+    let eta2 = eta * eta;
+    let eta3 = eta2 * eta;
+    let eta4 = eta3 * eta;
+    let eta5 = eta4 * eta;
+    if eta < 1.0 {
+        0.27614 - 0.87350 * eta + 1.12077 * eta2 - 0.65095 * eta3 + 0.07883 * eta4 + 0.04860 * eta5
+    } else {
+        let r_eta = 1.0 / eta;
+        let r_eta2 = r_eta * r_eta;
+        let r_eta3 = r_eta2 * r_eta;
+        -547.033 + 45.3087 * r_eta3 - 218.725 * r_eta2 + 458.843 * r_eta + 404.557 * eta
+            - 189.519 * eta2
+            + 54.9327 * eta3
+            - 9.00603 * eta4
+            + 0.63942 * eta5
+    }
+}
+
+/// Returns the multiple scattering term of the radial profile using photon
+/// beam diffusion. Dipole point sources are placed along the refracted beam
+/// at depths sampled according to its attenuation.
+///
+/// * `sigma_s` - Scattering coefficient.
+/// * `sigma_a` - Absorption coefficient.
+/// * `g`       - Asymmetry parameter of the Henyey-Greenstein phase function.
+/// * `eta`     - Relative index of refraction of the boundary.
+/// * `r`       - Radius.
+pub fn beam_diffusion_ms(sigma_s: Float, sigma_a: Float, g: Float, eta: Float, r: Float) -> Float {
+    // Compute reduced scattering coefficients and diffusion coefficient.
+    let sigmap_s = sigma_s * (1.0 - g);
+    let sigmap_t = sigma_a + sigmap_s;
+    let rhop = sigmap_s / sigmap_t;
+    let d_g = (2.0 * sigma_a + sigmap_s) / (3.0 * sigmap_t * sigmap_t);
+
+    // Compute effective transport coefficient.
+    let sigma_tr = (sigma_a / d_g).sqrt();
+
+    // Determine linear extrapolation distance using Fresnel moments.
+    let fm1 = fresnel_moment1(eta);
+    let fm2 = fresnel_moment2(eta);
+    let ze = -2.0 * d_g * (1.0 + 3.0 * fm2) / (1.0 - 2.0 * fm1);
+
+    // Determine exitance scale factors.
+    let c_phi = 0.25 * (1.0 - 2.0 * fm1);
+    let c_e = 0.5 * (1.0 - 3.0 * fm2);
+
+    let mut ed = 0.0;
+    for i in 0..BEAM_SAMPLES {
+        // Sample real point source depth and compute virtual source depth.
+        let zr = -(1.0 - (i as Float + 0.5) / BEAM_SAMPLES as Float).ln() / sigmap_t;
+        let zv = -zr + 2.0 * ze;
+
+        // Distances from the point sources to the exit point.
+        let dr = (r * r + zr * zr).sqrt();
+        let dv = (r * r + zv * zv).sqrt();
+
+        // Compute dipole fluence rate using the diffusion approximation.
+        let phi_d = INV_FOUR_PI / d_g * ((-sigma_tr * dr).exp() / dr - (-sigma_tr * dv).exp() / dv);
+
+        // Compute dipole vector irradiance in the direction of the normal.
+        let ed_n = INV_FOUR_PI
+            * (zr * (1.0 + sigma_tr * dr) * (-sigma_tr * dr).exp() / (dr * dr * dr)
+                - zv * (1.0 + sigma_tr * dv) * (-sigma_tr * dv).exp() / (dv * dv * dv));
+
+        // Add the contribution of the dipole to the exitance, rescaled to
+        // account for light that leaves the medium before reaching `zr`.
+        let e = phi_d * c_phi + ed_n * c_e;
+        let kappa = 1.0 - (-2.0 * sigmap_t * (dr + zr)).exp();
+        ed += kappa * rhop * rhop * e;
+    }
+    ed / BEAM_SAMPLES as Float
+}
+
+/// Returns the single scattering term of the radial profile by integrating
+/// the light scattered once along the refracted beam towards the exit point.
+///
+/// * `sigma_s` - Scattering coefficient.
+/// * `sigma_a` - Absorption coefficient.
+/// * `g`       - Asymmetry parameter of the Henyey-Greenstein phase function.
+/// * `eta`     - Relative index of refraction of the boundary.
+/// * `r`       - Radius.
+pub fn beam_diffusion_ss(sigma_s: Float, sigma_a: Float, g: Float, eta: Float, r: Float) -> Float {
+    let sigma_t = sigma_a + sigma_s;
+    let rho = sigma_s / sigma_t;
+
+    // Minimum depth at which light exiting at `r` isn't totally internally
+    // reflected.
+    let t_crit = r * (eta * eta - 1.0).sqrt();
+
+    let mut ess = 0.0;
+    for i in 0..BEAM_SAMPLES {
+        // Sample the depth of the scattering event along the beam.
+        let ti = t_crit - (1.0 - (i as Float + 0.5) / BEAM_SAMPLES as Float).ln() / sigma_t;
+
+        // Determine length `d` of connecting segment and cos(θo).
+        let d = (r * r + ti * ti).sqrt();
+        let cos_theta_o = ti / d;
+
+        // Add the contribution of the single scattering event at depth `ti`.
+        ess += rho * (-sigma_t * (d + t_crit)).exp() / (d * d)
+            * phase_hg(cos_theta_o, g)
+            * (1.0 - fr_dielectric(-cos_theta_o, 1.0, eta))
+            * abs(cos_theta_o);
+    }
+    ess / BEAM_SAMPLES as Float
+}
+
+/// Fills a `BSSRDFTable` with the radial profile of a medium with unit
+/// extinction coefficient computed by photon beam diffusion. The table is
+/// resized to `n_rho_samples` exponentially distributed albedos and
+/// `n_radius_samples` exponentially increasing radii.
+///
+/// * `g`     - Asymmetry parameter of the Henyey-Greenstein phase function.
+/// * `eta`   - Relative index of refraction of the boundary.
+/// * `table` - The table to fill.
+pub fn compute_beam_diffusion_bssrdf(g: Float, eta: Float, table: &mut BSSRDFTable) {
+    let n_rho = table.n_rho_samples;
+    let n_radius = table.n_radius_samples;
+
+    // Choose radius values, starting at 0 and increasing geometrically.
+    if n_radius > 0 {
+        table.radius_samples[0] = 0.0;
+    }
+    if n_radius > 1 {
+        table.radius_samples[1] = 2.5e-3;
+    }
+    for i in 2..n_radius {
+        table.radius_samples[i] = table.radius_samples[i - 1] * 1.2;
+    }
+
+    // Choose albedo values, denser close to 1 where the profile changes most.
+    let denom = 1.0 - (-8.0 as Float).exp();
+    for i in 0..n_rho {
+        let t = i as Float / max(n_rho as Float - 1.0, 1.0);
+        table.rho_samples[i] = (1.0 - (-8.0 * t).exp()) / denom;
+    }
+
+    // Compute the profile and its CDF for each albedo.
+    let radius_samples = &table.radius_samples;
+    let rows: Vec<(Vec<Float>, Vec<Float>, Float)> = table
+        .rho_samples
+        .par_iter()
+        .map(|&rho| {
+            let profile: Vec<Float> = radius_samples
+                .iter()
+                .map(|&r| {
+                    TWO_PI
+                        * r
+                        * (beam_diffusion_ss(rho, 1.0 - rho, g, eta, r)
+                            + beam_diffusion_ms(rho, 1.0 - rho, g, eta, r))
+                })
+                .collect();
+            let (cdf, rho_eff) = integrate_catmull_rom(radius_samples, &profile);
+            (profile, cdf, rho_eff)
+        })
+        .collect();
+
+    for (i, (profile, cdf, rho_eff)) in rows.into_iter().enumerate() {
+        let row = i * n_radius..(i + 1) * n_radius;
+        table.profile[row.clone()].copy_from_slice(&profile);
+        table.profile_cdf[row].copy_from_slice(&cdf);
+        table.rho_eff[i] = rho_eff;
+    }
+}
+
+/// Returns the absorption and scattering coefficients `(sigma_a, sigma_s)` of
+/// a medium that has a given effective albedo and mean free path. This lets
+/// subsurface materials be specified by their diffuse appearance.
+///
+/// * `table`   - Table filled by `compute_beam_diffusion_bssrdf()`.
+/// * `rho_eff` - Effective albedo.
+/// * `mfp`     - Mean free path `1 / sigma_t`.
+pub fn subsurface_from_diffuse(
+    table: &BSSRDFTable,
+    rho_eff: &Spectrum,
+    mfp: &Spectrum,
+) -> (Spectrum, Spectrum) {
+    let mut sigma_a = Spectrum::new(0.0);
+    let mut sigma_s = Spectrum::new(0.0);
+    let rho_effs = rho_eff.samples();
+    let mfps = mfp.samples();
+    for c in 0..rho_effs.len() {
+        let rho = invert_catmull_rom(&table.rho_samples, &table.rho_eff, rho_effs[c]);
+        sigma_s.samples_mut()[c] = rho / mfps[c];
+        sigma_a.samples_mut()[c] = (1.0 - rho) / mfps[c];
+    }
+    (sigma_a, sigma_s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn subsurface_from_diffuse_inverts_table() {
+        let mut table = BSSRDFTable::new(100, 64);
+        compute_beam_diffusion_bssrdf(0.0, 1.33, &mut table);
+
+        // Effective albedo grows with the single scattering albedo and nearly
+        // all light leaves a medium that doesn't absorb.
+        assert_eq!(table.rho_eff[0], 0.0);
+        assert!(table.rho_eff.windows(2).all(|w| w[0] <= w[1]));
+        assert!((table.rho_eff[table.n_rho_samples - 1] - 1.0).abs() < 0.05);
+
+        let rho = table.rho_samples[70];
+        let mfp = 0.5;
+        let (sigma_a, sigma_s) = subsurface_from_diffuse(
+            &table,
+            &Spectrum::new(table.rho_eff[70]),
+            &Spectrum::new(mfp),
+        );
+        let sigma_s = sigma_s.samples()[0];
+        let sigma_t = sigma_a.samples()[0] + sigma_s;
+        assert!((sigma_t - 1.0 / mfp).abs() < 1e-3);
+        assert!((sigma_s / sigma_t - rho).abs() < 1e-3);
+    }
+}
@@ -0,0 +1,61 @@
+//! BSSRDF Table
+
+use crate::core::pbrt::*;
+
+/// Stores the tabulated radial scattering profile `Sr` of a homogeneous
+/// medium with unit extinction coefficient `sigma_t = 1` for a range of
+/// single scattering albedos `rho` and radii `r`. Media with other extinction
+/// coefficients are handled by scaling the radius.
+#[derive(Clone, Debug)]
+pub struct BSSRDFTable {
+    /// Number of albedo samples.
+    pub n_rho_samples: usize,
+
+    /// Number of radius samples.
+    pub n_radius_samples: usize,
+
+    /// Single scattering albedos in increasing order.
+    pub rho_samples: Vec<Float>,
+
+    /// Radii in increasing order.
+    pub radius_samples: Vec<Float>,
+
+    /// Profile values `2 * π * r * Sr(rho, r)` for each albedo (rows) and
+    /// radius (columns).
+    pub profile: Vec<Float>,
+
+    /// Effective albedo, i.e. the integral of the profile over the radius, for
+    /// each albedo sample.
+    pub rho_eff: Vec<Float>,
+
+    /// Cumulative distribution of the profile over the radius for each albedo
+    /// sample used to importance sample radii.
+    pub profile_cdf: Vec<Float>,
+}
+
+impl BSSRDFTable {
+    /// Returns a new `BSSRDFTable` with all samples set to 0.
+    ///
+    /// * `n_rho_samples`    - Number of albedo samples.
+    /// * `n_radius_samples` - Number of radius samples.
+    pub fn new(n_rho_samples: usize, n_radius_samples: usize) -> Self {
+        Self {
+            n_rho_samples,
+            n_radius_samples,
+            rho_samples: vec![0.0; n_rho_samples],
+            radius_samples: vec![0.0; n_radius_samples],
+            profile: vec![0.0; n_rho_samples * n_radius_samples],
+            rho_eff: vec![0.0; n_rho_samples],
+            profile_cdf: vec![0.0; n_rho_samples * n_radius_samples],
+        }
+    }
+
+    /// Returns the profile value for an albedo and radius sample.
+    ///
+    /// * `rho_index`    - Index of the albedo sample.
+    /// * `radius_index` - Index of the radius sample.
+    #[inline]
+    pub fn eval_profile(&self, rho_index: usize, radius_index: usize) -> Float {
+        self.profile[rho_index * self.n_radius_samples + radius_index]
+    }
+}
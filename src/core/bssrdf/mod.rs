@@ -0,0 +1,47 @@
+//! Bidirectional scattering surface reflectance distribution function.
+
+#![allow(dead_code)]
+use crate::core::geometry::*;
+use crate::core::sampler::*;
+use crate::core::scene::*;
+use crate::core::spectrum::*;
+use std::sync::Arc;
+
+mod beam_diffusion;
+mod bssrdf_table;
+mod random_walk;
+
+// Re-export
+pub use beam_diffusion::*;
+pub use bssrdf_table::*;
+pub use random_walk::*;
+
+/// BSSRDF trait provides common behavior.
+pub trait BSSRDF {
+    /// Samples the point where light that refracted into the surface leaves
+    /// it again. Returns `None` if the light is absorbed.
+    ///
+    /// * `scene`   - The scene.
+    /// * `ray`     - Ray refracted into the surface at the entry point.
+    /// * `sampler` - The sampler.
+    fn sample_exit<'a>(
+        &self,
+        scene: &'a Scene,
+        ray: &Ray,
+        sampler: &mut ArcSampler,
+    ) -> Option<BSSRDFSample<'a>>;
+}
+
+/// Exit point sampled by a BSSRDF.
+pub struct BSSRDFSample<'a> {
+    /// The exit point. Its BSDF scatters light arriving from outside into the
+    /// surface.
+    pub si: SurfaceInteraction<'a>,
+
+    /// Weight of the light leaving at the exit point relative to the light
+    /// that entered the surface.
+    pub weight: Spectrum,
+}
+
+/// Atomic reference counted `BSSRDF`.
+pub type ArcBSSRDF = Arc<dyn BSSRDF + Send + Sync>;
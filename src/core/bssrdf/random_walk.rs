@@ -0,0 +1,253 @@
+//! Random Walk Subsurface Scattering
+
+use super::*;
+use crate::core::material::*;
+use crate::core::medium::*;
+use crate::core::pbrt::*;
+use crate::core::reflection::*;
+
+/// Maximum number of scattering events inside the surface before the light is
+/// considered absorbed.
+const MAX_SCATTERING_EVENTS: usize = 256;
+
+/// Simulates subsurface scattering by tracing light that refracted into the
+/// surface through the homogeneous medium inside it until it reaches the
+/// surface again. Unlike a tabulated BSSRDF it makes no assumption about the
+/// shape of the surface, so thin geometry and corners are handled correctly.
+#[derive(Clone)]
+pub struct RandomWalkBSSRDF {
+    /// Absorption coefficient of the medium inside.
+    sigma_a: Spectrum,
+
+    /// Scattering coefficient of the medium inside.
+    sigma_s: Spectrum,
+
+    /// Asymmetry parameter of the Henyey-Greenstein phase function.
+    g: Float,
+
+    /// Index of refraction of the surface.
+    eta: Float,
+
+    /// Transport mode.
+    mode: TransportMode,
+}
+
+impl RandomWalkBSSRDF {
+    /// Create a new `RandomWalkBSSRDF`.
+    ///
+    /// * `sigma_a` - Absorption coefficient of the medium inside.
+    /// * `sigma_s` - Scattering coefficient of the medium inside.
+    /// * `g`       - Asymmetry parameter of the Henyey-Greenstein phase
+    ///               function.
+    /// * `eta`     - Index of refraction of the surface.
+    /// * `mode`    - Transport mode.
+    pub fn new(
+        sigma_a: Spectrum,
+        sigma_s: Spectrum,
+        g: Float,
+        eta: Float,
+        mode: TransportMode,
+    ) -> Self {
+        Self {
+            sigma_a,
+            sigma_s,
+            g,
+            eta,
+            mode,
+        }
+    }
+}
+
+impl BSSRDF for RandomWalkBSSRDF {
+    /// Samples the point where light that refracted into the surface leaves
+    /// it again.
+    ///
+    /// Distances between scattering events are sampled with the extinction
+    /// coefficient of a randomly chosen channel and weighted by the average
+    /// density of all channels so that chromatic media don't add noise.
+    ///
+    /// * `scene`   - The scene.
+    /// * `ray`     - Ray refracted into the surface at the entry point.
+    /// * `sampler` - The sampler.
+    fn sample_exit<'a>(
+        &self,
+        scene: &'a Scene,
+        ray: &Ray,
+        sampler: &mut ArcSampler,
+    ) -> Option<BSSRDFSample<'a>> {
+        let sigma_t = self.sigma_a + self.sigma_s;
+        let n_channels = sigma_t.samples().len();
+        let phase = HenyeyGreenstein::new(self.g);
+
+        let mut ray = Ray::new(ray.o, ray.d.normalize(), INFINITY, ray.time, None);
+        let mut beta = Spectrum::new(1.0);
+        for event in 0..MAX_SCATTERING_EVENTS {
+            // Sample the distance to the next scattering event.
+            let sampler = Arc::get_mut(sampler).unwrap();
+            let channel = sample_channel(sampler.get_1d(), n_channels);
+            ray.t_max = -(1.0 - sampler.get_1d()).ln() / sigma_t[channel];
+
+            if let Some(mut si) = scene.intersect(&mut ray) {
+                // The light leaves the medium. Weight it by the probability of
+                // reaching the surface.
+                let tr = homogeneous_transmittance(&sigma_t, ray.t_max);
+                beta *= tr / channel_average(&tr);
+
+                // Orient the exit point towards the outside.
+                if si.hit.n.dot(&ray.d) < 0.0 {
+                    si.hit.n = -si.hit.n;
+                }
+                si.shading.n = si.shading.n.face_forward(&si.hit.n.into());
+                si.hit.wo = Vector3f::from(si.hit.n);
+
+                let mut bsdf = BSDF::new(&si, Some(self.eta));
+                bsdf.add(Arc::new(SubsurfaceExit::new(self.eta, self.mode)));
+                si.bsdf = Some(Arc::new(bsdf));
+                return Some(BSSRDFSample { si, weight: beta });
+            }
+
+            // Scatter inside the medium.
+            let t = ray.t_max;
+            let tr = homogeneous_transmittance(&sigma_t, t);
+            beta *= self.sigma_s * tr / channel_average(&(sigma_t * tr));
+            if beta.is_black() {
+                return None;
+            }
+
+            // Terminate paths that carry little light with Russian roulette.
+            let beta_max = beta.max_component_value();
+            if event > 3 && beta_max < 1.0 {
+                let q = max(0.05, 1.0 - beta_max);
+                if sampler.get_1d() < q {
+                    return None;
+                }
+                beta /= 1.0 - q;
+            }
+
+            let (_, wi) = phase.sample_p(&-ray.d, &sampler.get_2d());
+            ray = Ray::new(ray.at(t), wi, INFINITY, ray.time, None);
+        }
+
+        None
+    }
+}
+
+/// BRDF at the exit point of a random walk. It scatters light arriving from
+/// outside into the surface in proportion to the Fresnel transmittance,
+/// normalized so that all transmitted light is accounted for.
+#[derive(Clone)]
+pub struct SubsurfaceExit {
+    /// BxDF type.
+    bxdf_type: BxDFType,
+
+    /// Index of refraction of the surface.
+    eta: Float,
+
+    /// Normalization factor of the Fresnel transmittance.
+    c: Float,
+
+    /// Transport mode.
+    mode: TransportMode,
+}
+
+impl SubsurfaceExit {
+    /// Create a new `SubsurfaceExit`.
+    ///
+    /// * `eta`  - Index of refraction of the surface.
+    /// * `mode` - Transport mode.
+    pub fn new(eta: Float, mode: TransportMode) -> Self {
+        Self {
+            bxdf_type: BxDFType::from(BSDF_REFLECTION | BSDF_DIFFUSE),
+            eta,
+            c: 1.0 - 2.0 * fresnel_moment1(1.0 / eta),
+            mode,
+        }
+    }
+}
+
+impl BxDF for SubsurfaceExit {
+    /// Returns the BxDF type.
+    fn get_type(&self) -> BxDFType {
+        self.bxdf_type
+    }
+
+    /// Returns the value of the distribution function for the given pair of
+    /// directions.
+    ///
+    /// * `wo` - Outgoing direction.
+    /// * `wi` - Incident direction.
+    fn f(&self, _wo: &Vector3f, wi: &Vector3f) -> Spectrum {
+        let mut f = (1.0 - fr_dielectric(cos_theta(wi), 1.0, self.eta)) / (self.c * PI);
+
+        // Undo the radiance scaling of the refraction into the surface.
+        if self.mode == TransportMode::Radiance {
+            f *= self.eta * self.eta;
+        }
+        Spectrum::new(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::accelerators::*;
+    use crate::core::paramset::*;
+    use crate::core::primitive::*;
+    use crate::core::primitives::*;
+    use crate::materials::*;
+    use crate::samplers::*;
+    use crate::shapes::*;
+
+    #[test]
+    fn walk_leaves_the_surface_without_absorption() {
+        let identity = Arc::new(Transform::default());
+        let sphere = Arc::new(Sphere::new(
+            identity.clone(),
+            identity,
+            false,
+            1.0,
+            -1.0,
+            1.0,
+            360.0,
+        ));
+        let matte: ArcMaterial = Arc::new(MatteMaterial::from(&TextureParams::default()));
+        let primitive: ArcPrimitive = Arc::new(GeometricPrimitive::new(
+            sphere,
+            Some(matte),
+            None,
+            MediumInterface::vacuum(),
+        ));
+        let primitives = vec![primitive];
+        let bvh = BVHAccel::from((&ParamSet::new(), &primitives));
+        let scene = Scene::new(Arc::new(bvh), vec![]);
+
+        let bssrdf = RandomWalkBSSRDF::new(
+            Spectrum::new(0.0),
+            Spectrum::new(5.0),
+            0.0,
+            1.33,
+            TransportMode::Radiance,
+        );
+        let mut sampler: ArcSampler = Arc::new(RandomSampler::new(1, Some(0)));
+        let ray = Ray::new(
+            Point3f::new(0.0, 0.0, 0.999),
+            Vector3f::new(0.0, 0.0, -1.0),
+            INFINITY,
+            0.0,
+            None,
+        );
+        // Russian roulette may end walks but keeps the expected weight at 1.
+        let n = 1000;
+        let mut sum = 0.0;
+        for _ in 0..n {
+            if let Some(BSSRDFSample { si, weight }) =
+                bssrdf.sample_exit(&scene, &ray, &mut sampler)
+            {
+                assert!((Vector3f::from(si.hit.p).length() - 1.0).abs() < 1e-3);
+                assert!(si.hit.n.dot(&Vector3f::from(si.hit.p)) > 0.0);
+                sum += weight.y();
+            }
+        }
+        assert!((sum / n as Float - 1.0).abs() < 0.05);
+    }
+}
@@ -5,6 +5,7 @@ use crate::core::film::*;
 use crate::core::geometry::*;
 use crate::core::light::*;
 use crate::core::medium::*;
+use crate::core::paramset::*;
 use crate::core::pbrt::*;
 use crate::core::spectrum::*;
 use std::fmt;
@@ -80,12 +81,13 @@ pub trait Camera {
     }
 
     /// Evaluate the importance emitted from the point on the camera in a
-    /// direction. The `include_raster_point` is true, then a raster position
-    /// associated with the ray on the film is returned as well.
+    /// direction. The `include_raster` is true, then a raster position
+    /// associated with the ray on the film is returned as well. Bidirectional
+    /// methods use it to splat light paths that hit the lens onto the film.
     ///
-    /// * `ray`                  - The ray.
-    /// * `include_raster_point` - Indicates whether or not to return the raster
-    ///                            position.
+    /// * `ray`            - The ray.
+    /// * `include_raster` - Indicates whether or not to return the raster
+    ///                      position.
     fn we(&self, _ray: &Ray, _include_raster: bool) -> (Spectrum, Option<Point2f>) {
         panic!("Camera::we() is not implemented");
     }
@@ -96,13 +98,15 @@ pub trait Camera {
     /// * `ray` - The ray.
     fn pdf_we(&self, ray: &Ray) -> PDFResult;
 
-    /// Returns a PDF value with respect to the solid angle at a reference point.
+    /// Samples a point on the lens and returns the importance arriving at a
+    /// reference point from it, the direction to the lens, the PDF with
+    /// respect to solid angle at the reference point and the raster position
+    /// the point is seen at. Returns `None` if the point isn't seen by the
+    /// camera.
     ///
-    /// * `interaction`          - The interaction point.
-    /// * `u`                    - Used to sample point on the lens.
-    /// * `include_raster_point` - Indicates whether or not to return the raster
-    ///                            position.
-    fn sample_wi(&self, _interaction: &Interaction, _u: &Point2f) -> SampleResult {
+    /// * `hit` - The reference point.
+    /// * `u`   - Used to sample point on the lens.
+    fn sample_wi(&self, _hit: &Hit, _u: &Point2f) -> Option<SampleResult> {
         panic!("Camera::sample_wi() is not implemented");
     }
 }
@@ -160,19 +164,19 @@ impl fmt::Display for CameraSample {
 #[derive(Clone)]
 pub struct SampleResult {
     /// The sample value.
-    spectrum: Spectrum,
+    pub spectrum: Spectrum,
 
     /// Direction from lens to interaction point.
-    wi: Vector3f,
+    pub wi: Vector3f,
 
     /// The PDF value.
-    pdf: Float,
+    pub pdf: Float,
 
     /// Raster position.
-    p_raster: Option<Point2f>,
+    pub p_raster: Option<Point2f>,
 
     /// Visibility tester.
-    vis: VisibilityTester,
+    pub vis: VisibilityTester,
 }
 
 impl SampleResult {
@@ -204,10 +208,10 @@ impl SampleResult {
 #[derive(Copy, Clone, Default)]
 pub struct PDFResult {
     /// Spatial PDF.
-    pos: Float,
+    pub pos: Float,
 
     /// Directional PDF.
-    dir: Float,
+    pub dir: Float,
 }
 
 impl PDFResult {
@@ -237,6 +241,10 @@ pub struct CameraData {
 
     /// Scattering medium the camera lies in.
     pub medium: Option<ArcMedium>,
+
+    /// Response of the shutter over time; `None` if it opens and closes
+    /// instantly.
+    pub shutter_curve: Option<Arc<ShutterCurve>>,
 }
 
 impl CameraData {
@@ -261,6 +269,155 @@ impl CameraData {
             shutter_close,
             film,
             medium: medium.clone(),
+            shutter_curve: None,
+        }
+    }
+
+    /// Returns the time at which a camera ray samples the scene, weighted by
+    /// the shutter response.
+    ///
+    /// * `u` - Sample value for time in [0, 1).
+    pub fn sample_time(&self, u: Float) -> Float {
+        lerp(
+            self.sample_shutter(u),
+            self.shutter_open,
+            self.shutter_close,
+        )
+    }
+
+    /// Returns a time relative to the shutter interval, in [0, 1], weighted by
+    /// the shutter response.
+    ///
+    /// * `u` - Sample value for time in [0, 1).
+    pub fn sample_shutter(&self, u: Float) -> Float {
+        match self.shutter_curve.as_ref() {
+            Some(curve) => curve.sample(u),
+            None => u,
+        }
+    }
+}
+
+/// Response of a shutter over the time it is open as a piecewise linear
+/// curve; e.g. ramps while it opens and closes. Camera rays sample times in
+/// proportion to the response so moving objects blur as they would on film.
+#[derive(Clone)]
+pub struct ShutterCurve {
+    /// Times of the knots relative to the shutter interval, increasing from
+    /// 0 to 1.
+    times: Vec<Float>,
+
+    /// Response at each knot.
+    values: Vec<Float>,
+
+    /// Integral of the response up to each knot normalized to 1.
+    cdf: Vec<Float>,
+}
+
+impl ShutterCurve {
+    /// Create a new `ShutterCurve`. Returns `None` if the curve is invalid or
+    /// its response is zero.
+    ///
+    /// * `times`  - Times of the knots relative to the shutter interval,
+    ///              increasing from 0 to 1.
+    /// * `values` - Non-negative response at each knot.
+    pub fn new(times: Vec<Float>, values: Vec<Float>) -> Option<Self> {
+        if times.len() < 2
+            || times.len() != values.len()
+            || times.windows(2).any(|t| t[1] < t[0])
+            || values.iter().any(|v| *v < 0.0)
+        {
+            return None;
+        }
+
+        let mut cdf = vec![0.0; times.len()];
+        for i in 1..times.len() {
+            let area = 0.5 * (times[i] - times[i - 1]) * (values[i - 1] + values[i]);
+            cdf[i] = cdf[i - 1] + area;
+        }
+        let total = cdf[cdf.len() - 1];
+        if total <= 0.0 {
+            return None;
+        }
+        for c in cdf.iter_mut() {
+            *c /= total;
+        }
+
+        Some(Self { times, values, cdf })
+    }
+
+    /// Create a trapezoidal `ShutterCurve` that ramps up linearly while the
+    /// shutter opens and down while it closes.
+    ///
+    /// * `open_ramp`  - Fraction of the shutter interval taken to open.
+    /// * `close_ramp` - Fraction of the shutter interval taken to close.
+    pub fn trapezoid(open_ramp: Float, close_ramp: Float) -> Option<Self> {
+        let open_ramp = clamp(open_ramp, 0.0, 1.0);
+        let close_ramp = clamp(close_ramp, 0.0, 1.0 - open_ramp);
+        Self::new(
+            vec![0.0, open_ramp, 1.0 - close_ramp, 1.0],
+            vec![0.0, 1.0, 1.0, 0.0],
+        )
+    }
+
+    /// Returns a time relative to the shutter interval sampled in proportion
+    /// to the response.
+    ///
+    /// * `u` - Sample value in [0, 1).
+    pub fn sample(&self, u: Float) -> Float {
+        // Find the segment containing `u` and invert the integral of the
+        // linear response over it.
+        let i = find_interval(self.cdf.len(), |i| self.cdf[i] <= u);
+        let (t0, t1) = (self.times[i], self.times[i + 1]);
+        let (v0, v1) = (self.values[i], self.values[i + 1]);
+        let area = self.cdf[i + 1] - self.cdf[i];
+        if area <= 0.0 {
+            return t0;
+        }
+
+        // Solve v0 x + (v1 - v0) x^2 / 2 = a for the fraction x of the
+        // segment, where a is the fraction of its area below `u`.
+        let a = (u - self.cdf[i]) / area * 0.5 * (v0 + v1);
+        let dv = v1 - v0;
+        let x = if dv.abs() < 1e-6 * max(v0, v1) {
+            a / v0
+        } else {
+            (-v0 + max(v0 * v0 + 2.0 * dv * a, 0.0).sqrt()) / dv
+        };
+        lerp(clamp(x, 0.0, 1.0), t0, t1)
+    }
+
+    /// Create the `ShutterCurve` given by camera parameters; a response
+    /// tabulated at equally spaced times in `"float shuttercurve"` or ramps
+    /// taking the fractions of the shutter interval `"float shutteropenramp"`
+    /// and `"float shuttercloseramp"`. Returns `None` if neither is given.
+    ///
+    /// * `params` - Camera parameters.
+    pub fn from_params(params: &ParamSet) -> Option<Self> {
+        let values = params.find_float("shuttercurve");
+        if !values.is_empty() {
+            let n = values.len();
+            let times = (0..n)
+                .map(|i| i as Float / max(n - 1, 1) as Float)
+                .collect();
+            let curve = ShutterCurve::new(times, values.to_vec());
+            if curve.is_none() {
+                warn!("'shuttercurve' needs two or more non-negative values, not all zero. Ignoring it.");
+            }
+            return curve;
+        }
+
+        let open_ramp = params.find_one_float("shutteropenramp", 0.0);
+        let close_ramp = params.find_one_float("shuttercloseramp", 0.0);
+        if open_ramp > 0.0 || close_ramp > 0.0 {
+            if open_ramp + close_ramp > 1.0 {
+                warn!(
+                    "'shutteropenramp' [{}] and 'shuttercloseramp' [{}] exceed the shutter interval.",
+                    open_ramp, close_ramp
+                );
+            }
+            ShutterCurve::trapezoid(open_ramp, close_ramp)
+        } else {
+            None
         }
     }
 }
@@ -327,3 +484,32 @@ impl ProjectiveCameraData {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shutter_curves_sample_times_in_proportion_to_the_response() {
+        // A triangle with response 2t on the first half has an integral of t^2.
+        let triangle = ShutterCurve::trapezoid(0.5, 0.5).unwrap();
+        assert!((triangle.sample(0.125) - 0.25).abs() < 1e-5);
+        assert!((triangle.sample(0.5) - 0.5).abs() < 1e-5);
+        assert!((triangle.sample(0.875) - 0.75).abs() < 1e-5);
+
+        // A flat response is uniform.
+        let flat = ShutterCurve::trapezoid(0.0, 0.0).unwrap();
+        assert!((flat.sample(0.3) - 0.3).abs() < 1e-5);
+
+        // A tabulated response rising linearly over the interval.
+        let mut params = ParamSet::new();
+        params.add_float("shuttercurve", &[0.0, 1.0]);
+        let ramp = ShutterCurve::from_params(&params).unwrap();
+        assert!((ramp.sample(0.25) - 0.5).abs() < 1e-5);
+
+        // Invalid curves are rejected.
+        params.add_float("shuttercurve", &[0.0, 0.0]);
+        assert!(ShutterCurve::from_params(&params).is_none());
+        assert!(ShutterCurve::from_params(&ParamSet::new()).is_none());
+    }
+}
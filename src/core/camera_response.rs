@@ -0,0 +1,260 @@
+//! Camera Response
+
+#![allow(dead_code)]
+use crate::core::error::*;
+use crate::core::pbrt::*;
+use std::fs;
+use std::result::Result;
+
+/// Maps irradiance to the brightness recorded by a camera. Both are in [0, 1].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ResponseCurve {
+    /// Irradiance values in increasing order.
+    pub irradiance: Vec<Float>,
+
+    /// Brightness at each irradiance value.
+    pub brightness: Vec<Float>,
+}
+
+impl ResponseCurve {
+    /// Create a new `ResponseCurve`; or returns an error if the values don't
+    /// describe a curve.
+    ///
+    /// * `irradiance` - Irradiance values in increasing order.
+    /// * `brightness` - Brightness at each irradiance value.
+    pub fn new(irradiance: Vec<Float>, brightness: Vec<Float>) -> Result<Self, Error> {
+        if irradiance.len() != brightness.len() || irradiance.len() < 2 {
+            return Err(Error::InvalidParameter(format!(
+                "Camera response curve needs at least 2 pairs of values; got {} irradiance \
+                and {} brightness values.",
+                irradiance.len(),
+                brightness.len()
+            )));
+        }
+        if irradiance.windows(2).any(|w| w[1] < w[0]) {
+            return Err(Error::InvalidParameter(String::from(
+                "Camera response curve irradiance values must be increasing.",
+            )));
+        }
+        Ok(Self {
+            irradiance,
+            brightness,
+        })
+    }
+
+    /// Returns the brightness for the given irradiance. Irradiance outside
+    /// the curve is clamped to its ends.
+    ///
+    /// * `e` - Irradiance.
+    pub fn evaluate(&self, e: Float) -> Float {
+        let n = self.irradiance.len();
+        if e <= self.irradiance[0] {
+            return self.brightness[0];
+        }
+        if e >= self.irradiance[n - 1] {
+            return self.brightness[n - 1];
+        }
+        let i = self.irradiance.partition_point(|&x| x <= e) - 1;
+        let (e0, e1) = (self.irradiance[i], self.irradiance[i + 1]);
+        let t = if e1 > e0 { (e - e0) / (e1 - e0) } else { 0.0 };
+        lerp(t, self.brightness[i], self.brightness[i + 1])
+    }
+}
+
+/// Response of a camera used in place of gamma correction when writing 8-bit
+/// images. Each RGB channel has its own curve.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CameraResponse {
+    /// Curves for the red, green and blue channels.
+    pub curves: [ResponseCurve; 3],
+}
+
+impl CameraResponse {
+    /// Create a new `CameraResponse`.
+    ///
+    /// * `curves` - Curves for the red, green and blue channels.
+    pub fn new(curves: [ResponseCurve; 3]) -> Self {
+        Self { curves }
+    }
+
+    /// Reads camera response curves from a file; or returns an error if the
+    /// file can't be read or has no matching curves. The file is one of:
+    ///
+    /// * The DoRF database (`dorfCurves.txt`) of measured curves. A single
+    ///   curve name selects the curve for all channels, three names select
+    ///   the red, green and blue curves. The names can be omitted if the file
+    ///   has only one curve.
+    /// * The EMoR basis (`emor.txt`). The curve is the mean curve plus the
+    ///   basis curves weighted by `emor_coefficients`.
+    /// * A lookup table; lines of irradiance followed by either a brightness
+    ///   value for all channels or one for each channel. A `#` starts a
+    ///   comment.
+    ///
+    /// * `path`              - Path to the file.
+    /// * `curve_names`       - Names of the DoRF curves to use.
+    /// * `emor_coefficients` - Weights of the EMoR basis curves.
+    pub fn from_file(
+        path: &str,
+        curve_names: &[String],
+        emor_coefficients: &[Float],
+    ) -> Result<Self, Error> {
+        let contents = fs::read_to_string(path)
+            .map_err(|err| Error::Io(format!("Error reading file '{}'. {}.", path, err)))?;
+        Self::parse(&contents, curve_names, emor_coefficients).map_err(|err| {
+            Error::Parse(format!("Error reading camera response '{}'. {}", path, err))
+        })
+    }
+
+    /// Parses camera response curves in any of the formats read by
+    /// `from_file()`.
+    ///
+    /// * `contents`          - Contents of the file.
+    /// * `curve_names`       - Names of the DoRF curves to use.
+    /// * `emor_coefficients` - Weights of the EMoR basis curves.
+    fn parse(
+        contents: &str,
+        curve_names: &[String],
+        emor_coefficients: &[Float],
+    ) -> Result<Self, String> {
+        // Split the file into named curves with labelled lists of values, e.g.
+        // `I = ...` and `B = ...`, and rows of values before any label.
+        type Sections = Vec<(String, Vec<Float>)>;
+        let mut curves: Vec<(String, Sections)> = vec![];
+        let mut rows: Vec<Vec<Float>> = vec![];
+        for (line_no, line) in contents.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or("").trim();
+            let (label, data) = match line.find('=') {
+                Some(i) => (Some(line[..i].trim()), &line[i + 1..]),
+                None => (None, line),
+            };
+            let values: Result<Vec<Float>, _> = data.split_whitespace().map(str::parse).collect();
+            match (label, values) {
+                (Some(label), Ok(values)) => {
+                    if curves.is_empty() {
+                        curves.push((String::new(), vec![]));
+                    }
+                    let sections = &mut curves.last_mut().unwrap().1;
+                    sections.push((String::from(label), values));
+                }
+                (None, Ok(values)) if values.is_empty() => (),
+                (None, Ok(values)) => match curves.last_mut() {
+                    Some((_, sections)) if !sections.is_empty() => {
+                        sections.last_mut().unwrap().1.extend(values)
+                    }
+                    _ => rows.push(values),
+                },
+                (None, Err(_)) if line == "graph" => (),
+                (None, Err(_)) => curves.push((String::from(line), vec![])),
+                (Some(_), Err(_)) => {
+                    return Err(format!("Unexpected text on line {}.", line_no + 1));
+                }
+            }
+        }
+
+        let section = |sections: &[(String, Vec<Float>)], label: &str| {
+            sections
+                .iter()
+                .find(|(l, _)| l == label)
+                .map(|(_, v)| v.clone())
+        };
+
+        if curves.is_empty() {
+            // Lookup table.
+            let channels = match rows.first().map(Vec::len) {
+                Some(n @ (2 | 4)) if rows.iter().all(|r| r.len() == n) => n - 1,
+                _ => return Err(String::from("Expected rows of 2 or 4 values.")),
+            };
+            let irradiance: Vec<Float> = rows.iter().map(|r| r[0]).collect();
+            let curve = |c: usize| {
+                ResponseCurve::new(
+                    irradiance.clone(),
+                    rows.iter().map(|r| r[1 + c.min(channels - 1)]).collect(),
+                )
+                .map_err(|err| err.to_string())
+            };
+            return Ok(Self::new([curve(0)?, curve(1)?, curve(2)?]));
+        }
+
+        // EMoR basis.
+        let sections = &curves[0].1;
+        if let Some(f0) = section(sections, "f0") {
+            let irradiance = section(sections, "E").ok_or("Missing 'E' values.")?;
+            let mut brightness = f0;
+            for (i, c) in emor_coefficients.iter().enumerate() {
+                let h = section(sections, &format!("h({})", i + 1))
+                    .ok_or(format!("Missing basis curve 'h({})'.", i + 1))?;
+                if h.len() != brightness.len() {
+                    return Err(format!("Basis curve 'h({})' has the wrong length.", i + 1));
+                }
+                for (b, h) in brightness.iter_mut().zip(h) {
+                    *b += c * h;
+                }
+            }
+            let curve = ResponseCurve::new(irradiance, brightness).map_err(|e| e.to_string())?;
+            return Ok(Self::new([curve.clone(), curve.clone(), curve]));
+        }
+
+        // DoRF curves.
+        let names: Vec<&str> = match curve_names.len() {
+            0 if curves.len() == 1 => vec![&curves[0].0; 3],
+            0 => {
+                return Err(String::from(
+                    "File has several curves; select them by name.",
+                ))
+            }
+            1 => vec![&curve_names[0]; 3],
+            3 => curve_names.iter().map(String::as_str).collect(),
+            n => return Err(format!("{} curve names given. Expected 1 or 3.", n)),
+        };
+        let curve = |name: &str| {
+            let (_, sections) = curves
+                .iter()
+                .find(|(n, _)| n == name)
+                .ok_or(format!("Unknown curve '{}'.", name))?;
+            let irradiance =
+                section(sections, "I").ok_or(format!("Curve '{}' has no 'I'.", name))?;
+            let brightness =
+                section(sections, "B").ok_or(format!("Curve '{}' has no 'B'.", name))?;
+            ResponseCurve::new(irradiance, brightness).map_err(|e| e.to_string())
+        };
+        Ok(Self::new([
+            curve(names[0])?,
+            curve(names[1])?,
+            curve(names[2])?,
+        ]))
+    }
+
+    /// Returns the brightness recorded for a linear value of a channel.
+    ///
+    /// * `channel` - Index of the RGB channel.
+    /// * `v`       - Linear value.
+    #[inline]
+    pub fn apply(&self, channel: usize, v: Float) -> Float {
+        self.curves[channel].evaluate(v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_and_applies_response_curves() {
+        let dorf = "first\ngraph\nI =\n0.0 0.5 1.0\nB =\n0.0 0.8 1.0\n\
+                    second\ngraph\nI =\n0.0 1.0\nB =\n0.0 1.0\n";
+        let names = [String::from("first")];
+        let response = CameraResponse::parse(dorf, &names, &[]).unwrap();
+        assert_eq!(response.apply(1, 0.25), 0.4);
+        assert_eq!(response.apply(2, 2.0), 1.0);
+        assert!(CameraResponse::parse(dorf, &[], &[]).is_err());
+
+        let emor = "E =\n0.0 1.0\nf0 =\n0.0 1.0\nh(1)=\n0.0 0.0\nh(2)=\n1.0 0.0\n";
+        let response = CameraResponse::parse(emor, &[], &[3.0, 0.5]).unwrap();
+        assert_eq!(response.apply(0, 0.0), 0.5);
+
+        let lut = "# x r g b\n0 0 0 0\n1 1 0.5 0.25\n";
+        let response = CameraResponse::parse(lut, &[], &[]).unwrap();
+        assert_eq!(response.apply(0, 0.5), 0.5);
+        assert_eq!(response.apply(2, 0.5), 0.125);
+    }
+}
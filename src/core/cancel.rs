@@ -0,0 +1,37 @@
+//! Cancellation
+
+#![allow(dead_code)]
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Shared flag used to request that a render stops early. Clones refer to
+/// the same flag so it can be handed to other threads (e.g. a signal handler
+/// or an embedding application's UI).
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken {
+    /// Whether or not cancellation was requested.
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    /// Create a new `CancellationToken` that is not cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requests cancellation. Renders check for it between samples and stop
+    /// as soon as possible.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if cancellation was requested.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    /// Clears a previous cancellation request so the token can be reused.
+    pub fn reset(&self) {
+        self.cancelled.store(false, Ordering::Relaxed);
+    }
+}
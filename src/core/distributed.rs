@@ -0,0 +1,894 @@
+//! Distributed Rendering
+
+#![allow(dead_code)]
+use crate::core::cancel::*;
+use crate::core::error::*;
+use crate::core::film::*;
+use crate::core::geometry::*;
+use crate::core::parallel::*;
+use crate::core::pbrt::*;
+use crate::core::spectrum::*;
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+use std::collections::{BTreeSet, HashMap, VecDeque};
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::net::{Shutdown, TcpListener, TcpStream};
+use std::sync::{mpsc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Identifies the protocol at the start of a connection.
+const PROTOCOL_MAGIC: u32 = 0x5042_5253;
+
+/// Version of the protocol. Workers and coordinators must match.
+const PROTOCOL_VERSION: u32 = 2;
+
+/// How often workers let the coordinator know they are alive.
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(5);
+
+/// How long the coordinator waits to hear from a worker before it is
+/// considered failed and its tiles are reassigned.
+const WORKER_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// How long workers keep trying to connect to the coordinator.
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// How often waiting threads check for cancellation.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Maximum number of values in a message. Guards against allocating
+/// memory for corrupted messages.
+const MAX_MESSAGE_VALUES: usize = 1 << 26;
+
+/// Describes the tiles of a render. Workers and the coordinator must have
+/// loaded the same scene, which is checked by comparing their jobs.
+#[derive(Copy, Clone, PartialEq)]
+pub struct RenderJob {
+    /// Sample bounds of the image.
+    pub sample_bounds: Bounds2i,
+
+    /// Width and height of the tiles in pixels.
+    pub tile_size: u32,
+
+    /// Number of tiles.
+    pub n_tiles: u32,
+
+    /// Number of samples in a `Spectrum`.
+    pub spectrum_samples: u32,
+}
+
+impl RenderJob {
+    /// Returns a new `RenderJob`.
+    ///
+    /// * `sample_bounds` - Sample bounds of the image.
+    /// * `tile_size`     - Width and height of the tiles in pixels.
+    /// * `n_tiles`       - Number of tiles.
+    pub fn new(sample_bounds: Bounds2i, tile_size: usize, n_tiles: usize) -> Self {
+        Self {
+            sample_bounds,
+            tile_size: tile_size as u32,
+            n_tiles: n_tiles as u32,
+            spectrum_samples: Spectrum::default().samples().len() as u32,
+        }
+    }
+}
+
+/// The filtered contributions of a rendered tile sent from a worker to the
+/// coordinator.
+pub struct TileResult {
+    /// Index of the tile in the image.
+    pub tile_index: usize,
+
+    /// Bounds of the pixels in the final image.
+    pub pixel_bounds: Bounds2i,
+
+    /// Contributions of all pixels in the tile.
+    pub pixels: Vec<FilmTilePixel>,
+
+    /// Splat contributions in the order they were added.
+    pub splats: Vec<(Point2f, Spectrum)>,
+}
+
+impl TileResult {
+    /// Returns a new `TileResult` holding the contributions of a rendered
+    /// `FilmTile`. They are moved out of the tile.
+    ///
+    /// * `tile_index` - Index of the tile in the image.
+    /// * `film_tile`  - The rendered tile.
+    pub fn from_film_tile(tile_index: usize, film_tile: &mut FilmTile) -> Self {
+        Self {
+            tile_index,
+            pixel_bounds: film_tile.get_pixel_bounds(),
+            pixels: std::mem::take(&mut film_tile.pixels),
+            splats: std::mem::take(&mut film_tile.splats),
+        }
+    }
+
+    /// Moves the contributions into a `FilmTile` for the same tile; or
+    /// returns an error if it covers different pixels.
+    ///
+    /// * `film_tile` - An empty tile from the coordinator's film.
+    pub fn into_film_tile(self, film_tile: &mut FilmTile) -> Result<(), Error> {
+        if self.pixel_bounds != film_tile.get_pixel_bounds()
+            || self.pixels.len() != film_tile.pixels.len()
+        {
+            return Err(Error::InvalidState(format!(
+                "Tile {} with pixel bounds {} does not match the film.",
+                self.tile_index, self.pixel_bounds
+            )));
+        }
+        film_tile.pixels = self.pixels;
+        film_tile.splats = self.splats;
+        Ok(())
+    }
+}
+
+/// Messages exchanged between workers and the coordinator.
+enum Message {
+    /// Sent by a worker when it connects.
+    Hello { job: RenderJob, n_threads: u32 },
+
+    /// Sent by a worker to ask for tiles to render.
+    Request,
+
+    /// Sent periodically by a worker while it renders.
+    Heartbeat,
+
+    /// Sent by a worker for each rendered tile.
+    Tile(TileResult),
+
+    /// Sent by the coordinator with the indices of the tiles to render.
+    Assign(Vec<usize>),
+
+    /// Sent by the coordinator when there are no tiles to render yet.
+    Wait,
+
+    /// Sent by the coordinator when rendering is finished.
+    Done,
+
+    /// Sent by the coordinator when it rejects a worker.
+    Reject(String),
+}
+
+/// Tags identifying the type of each `Message`.
+const TAG_HELLO: u8 = 1;
+const TAG_REQUEST: u8 = 2;
+const TAG_HEARTBEAT: u8 = 3;
+const TAG_TILE: u8 = 4;
+const TAG_ASSIGN: u8 = 5;
+const TAG_WAIT: u8 = 6;
+const TAG_DONE: u8 = 7;
+const TAG_REJECT: u8 = 8;
+
+/// Response of the `Schedule` to a worker asking for tiles.
+#[derive(Clone, Debug, PartialEq)]
+enum Assignment {
+    /// Positions of the tiles to render.
+    Tiles(Vec<usize>),
+
+    /// No tiles can be assigned now; ask again later.
+    Wait,
+
+    /// All tiles are finished.
+    Done,
+}
+
+/// Tracks which tiles have been assigned to which workers. Tiles are
+/// identified by their position in rendering order.
+///
+/// Workers are handed batches that shrink as fewer tiles remain. Once all
+/// tiles are assigned, idle workers steal half of the unfinished tiles of the
+/// busiest worker; whichever finishes a tile first wins. The tiles of failed
+/// workers are returned to the queue.
+struct Schedule {
+    /// Tiles that haven't been assigned in rendering order.
+    queue: VecDeque<usize>,
+
+    /// Unfinished tiles assigned to each worker.
+    assigned: HashMap<usize, BTreeSet<usize>>,
+
+    /// Number of threads of each worker.
+    n_threads: HashMap<usize, usize>,
+
+    /// Whether or not each tile is finished.
+    finished: Vec<bool>,
+
+    /// Number of unfinished tiles.
+    remaining: usize,
+}
+
+impl Schedule {
+    /// Returns a new `Schedule` where no tiles are assigned.
+    ///
+    /// * `n_tiles` - Number of tiles.
+    fn new(n_tiles: usize) -> Self {
+        Self {
+            queue: (0..n_tiles).collect(),
+            assigned: HashMap::new(),
+            n_threads: HashMap::new(),
+            finished: vec![false; n_tiles],
+            remaining: n_tiles,
+        }
+    }
+
+    /// Adds a worker.
+    ///
+    /// * `worker`    - Identifies the worker.
+    /// * `n_threads` - Number of threads the worker renders with.
+    fn add_worker(&mut self, worker: usize, n_threads: usize) {
+        self.assigned.insert(worker, BTreeSet::new());
+        self.n_threads.insert(worker, max(n_threads, 1));
+    }
+
+    /// Removes a worker and returns its unfinished tiles that no other worker
+    /// has to the front of the queue.
+    ///
+    /// * `worker` - Identifies the worker.
+    fn remove_worker(&mut self, worker: usize) {
+        self.n_threads.remove(&worker);
+        if let Some(tiles) = self.assigned.remove(&worker) {
+            for &position in tiles.iter().rev() {
+                if !self.assigned.values().any(|t| t.contains(&position)) {
+                    self.queue.push_front(position);
+                }
+            }
+        }
+    }
+
+    /// Returns the tiles a worker should render next.
+    ///
+    /// * `worker` - Identifies the worker.
+    fn next(&mut self, worker: usize) -> Assignment {
+        if self.remaining == 0 {
+            return Assignment::Done;
+        }
+
+        let batch: Vec<usize> = if !self.queue.is_empty() {
+            // Guided scheduling: hand out a share of the queued tiles
+            // proportional to the worker's threads, but at least one tile per
+            // thread so they are all busy.
+            let n_threads = self.n_threads.get(&worker).copied().unwrap_or(1);
+            let total_threads: usize = self.n_threads.values().sum();
+            let share = self.queue.len() * n_threads / max(2 * total_threads, 1);
+            let n = min(max(share, n_threads), self.queue.len());
+            self.queue.drain(..n).collect()
+        } else {
+            // Steal from the worker with the most unfinished tiles.
+            let own = self.assigned.get(&worker);
+            let victim = self
+                .assigned
+                .iter()
+                .filter(|&(&w, _)| w != worker)
+                .map(|(_, tiles)| {
+                    tiles
+                        .iter()
+                        .filter(|p| own.is_none_or(|t| !t.contains(p)))
+                        .copied()
+                        .collect::<Vec<usize>>()
+                })
+                .max_by_key(|tiles| tiles.len());
+            match victim {
+                Some(tiles) if !tiles.is_empty() => {
+                    let n = max(tiles.len() / 2, 1);
+                    tiles[tiles.len() - n..].to_vec()
+                }
+                _ => return Assignment::Wait,
+            }
+        };
+
+        self.assigned
+            .entry(worker)
+            .or_default()
+            .extend(batch.iter().copied());
+        Assignment::Tiles(batch)
+    }
+
+    /// Marks a tile as finished. Returns `true` if it wasn't finished before.
+    ///
+    /// * `position` - Position of the tile.
+    fn finish(&mut self, position: usize) -> bool {
+        if self.finished[position] {
+            return false;
+        }
+        self.finished[position] = true;
+        self.remaining -= 1;
+        for tiles in self.assigned.values_mut() {
+            tiles.remove(&position);
+        }
+        true
+    }
+
+    /// Returns a finished tile to the front of the queue, e.g. when its
+    /// result could not be used.
+    ///
+    /// * `position` - Position of the tile.
+    fn requeue(&mut self, position: usize) {
+        if self.finished[position] {
+            self.finished[position] = false;
+            self.remaining += 1;
+            self.queue.push_front(position);
+        }
+    }
+}
+
+/// Distributes the tiles of a render to workers connecting over TCP and
+/// merges the tiles they return. Returns once all tiles are merged or
+/// rendering is cancelled.
+///
+/// * `address`      - Address to listen on for workers.
+/// * `job`          - Describes the tiles; workers must have the same job.
+/// * `tile_indices` - Indices of the tiles in rendering order.
+/// * `cancel`       - Token used to stop rendering early.
+/// * `merge_tile`   - Called once with the position in rendering order and
+///                    result of each tile; possibly from several threads at
+///                    once. The tile is rendered again if it returns an
+///                    error.
+pub fn run_coordinator<F>(
+    address: &str,
+    job: &RenderJob,
+    tile_indices: &[usize],
+    cancel: &CancellationToken,
+    merge_tile: F,
+) -> Result<(), Error>
+where
+    F: Fn(usize, TileResult) -> Result<(), Error> + Sync,
+{
+    let listener = TcpListener::bind(address)
+        .map_err(|err| Error::Io(format!("Unable to listen on '{}'. {}", address, err)))?;
+    listener.set_nonblocking(true)?;
+    info!("Waiting for workers on '{}'.", address);
+
+    let positions: HashMap<usize, usize> = tile_indices
+        .iter()
+        .enumerate()
+        .map(|(position, &tile_index)| (tile_index, position))
+        .collect();
+    let schedule = Mutex::new(Schedule::new(tile_indices.len()));
+
+    let coordinator = Coordinator {
+        job,
+        tile_indices,
+        positions: &positions,
+        schedule: &schedule,
+        cancel,
+        merge_tile: &merge_tile,
+    };
+
+    thread::scope(|s| {
+        let mut next_worker = 0;
+        while !coordinator.is_finished() {
+            match listener.accept() {
+                Ok((stream, peer)) => {
+                    let worker = next_worker;
+                    next_worker += 1;
+                    info!("Worker {} connected from {}.", worker, peer);
+                    let coordinator = &coordinator;
+                    s.spawn(move || {
+                        if let Err(err) = coordinator.serve(worker, stream) {
+                            warn!("Lost worker {}. {} Reassigning its tiles.", worker, err);
+                        }
+                        coordinator.schedule.lock().unwrap().remove_worker(worker);
+                    });
+                }
+                Err(err) if err.kind() == io::ErrorKind::WouldBlock => thread::sleep(POLL_INTERVAL),
+                Err(err) => warn!("Unable to accept worker. {}", err),
+            }
+        }
+    });
+    Ok(())
+}
+
+/// State shared by the threads serving workers.
+struct Coordinator<'a, F> {
+    /// Describes the tiles.
+    job: &'a RenderJob,
+
+    /// Indices of the tiles in rendering order.
+    tile_indices: &'a [usize],
+
+    /// Position in rendering order of each tile index.
+    positions: &'a HashMap<usize, usize>,
+
+    /// Assignment of tiles to workers.
+    schedule: &'a Mutex<Schedule>,
+
+    /// Token used to stop rendering early.
+    cancel: &'a CancellationToken,
+
+    /// Merges a tile result.
+    merge_tile: &'a F,
+}
+
+impl<'a, F> Coordinator<'a, F>
+where
+    F: Fn(usize, TileResult) -> Result<(), Error> + Sync,
+{
+    /// Returns `true` if all tiles are finished or rendering is cancelled.
+    fn is_finished(&self) -> bool {
+        self.cancel.is_cancelled() || self.schedule.lock().unwrap().remaining == 0
+    }
+
+    /// Handles the messages of a worker until rendering is finished or the
+    /// connection fails.
+    ///
+    /// * `worker` - Identifies the worker.
+    /// * `stream` - Connection to the worker.
+    fn serve(&self, worker: usize, stream: TcpStream) -> Result<(), Error> {
+        stream.set_nonblocking(false)?;
+        stream.set_read_timeout(Some(WORKER_TIMEOUT))?;
+        stream.set_nodelay(true)?;
+        let mut reader = BufReader::new(stream.try_clone()?);
+        let mut writer = BufWriter::new(stream);
+
+        match read_message(&mut reader)? {
+            Message::Hello { job, n_threads } if job == *self.job => {
+                self.schedule
+                    .lock()
+                    .unwrap()
+                    .add_worker(worker, n_threads as usize);
+            }
+            Message::Hello { .. } => {
+                let msg = "The worker's scene does not match the coordinator's.";
+                send_message(&mut writer, &Message::Reject(msg.to_string()))?;
+                return Err(Error::InvalidState(msg.to_string()));
+            }
+            _ => return Err(protocol_error("Expected hello from worker.").into()),
+        }
+
+        loop {
+            // Workers are told to stop on their next message; at least a
+            // heartbeat arrives within `HEARTBEAT_INTERVAL`.
+            if self.is_finished() {
+                send_message(&mut writer, &Message::Done)?;
+                return Ok(());
+            }
+
+            match read_message(&mut reader)? {
+                Message::Request => {
+                    let assignment = self.schedule.lock().unwrap().next(worker);
+                    let message = match assignment {
+                        Assignment::Tiles(positions) => Message::Assign(
+                            positions.iter().map(|&p| self.tile_indices[p]).collect(),
+                        ),
+                        Assignment::Wait => Message::Wait,
+                        Assignment::Done => Message::Done,
+                    };
+                    send_message(&mut writer, &message)?;
+                }
+                Message::Heartbeat => {}
+                Message::Tile(result) => {
+                    let position = *self
+                        .positions
+                        .get(&result.tile_index)
+                        .ok_or_else(|| protocol_error("Worker returned an unknown tile."))?;
+                    if self.schedule.lock().unwrap().finish(position) {
+                        if let Err(err) = (self.merge_tile)(position, result) {
+                            self.schedule.lock().unwrap().requeue(position);
+                            return Err(err);
+                        }
+                    }
+                }
+                _ => return Err(protocol_error("Unexpected message from worker.").into()),
+            }
+        }
+    }
+}
+
+/// Connects to a coordinator and renders the tiles it assigns until it
+/// reports that rendering is finished.
+///
+/// * `address`     - Address of the coordinator.
+/// * `job`         - Describes the tiles; the coordinator must have the same
+///                   job.
+/// * `cancel`      - Token used to stop rendering early.
+/// * `render_tile` - Renders the tile with the given index. It should stop
+///                   early once the given token is cancelled.
+pub fn run_worker<F>(
+    address: &str,
+    job: &RenderJob,
+    cancel: &CancellationToken,
+    render_tile: F,
+) -> Result<(), Error>
+where
+    F: Fn(usize, &CancellationToken) -> TileResult + Sync,
+{
+    let stream = connect(address, cancel)?;
+    stream.set_nodelay(true)?;
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let writer = Mutex::new(BufWriter::new(stream.try_clone()?));
+    info!("Connected to coordinator '{}'.", address);
+
+    let hello = Message::Hello {
+        job: *job,
+        n_threads: current_num_threads() as u32,
+    };
+    send_message(&mut *writer.lock().unwrap(), &hello)?;
+
+    // Stops the tiles being rendered when the coordinator is done, the
+    // connection fails or rendering is cancelled.
+    let stop = CancellationToken::new();
+
+    let (sender, receiver) = mpsc::channel();
+    let result = thread::scope(|s| {
+        // Receive messages from the coordinator.
+        s.spawn(|| loop {
+            let message = read_message(&mut reader);
+            let last = !matches!(message, Ok(Message::Assign(_)) | Ok(Message::Wait));
+            if last {
+                stop.cancel();
+            }
+            if sender.send(message).is_err() || last {
+                break;
+            }
+        });
+
+        // Send heartbeats and forward cancellation.
+        s.spawn(|| {
+            let mut last_heartbeat = Instant::now();
+            while !stop.is_cancelled() {
+                if cancel.is_cancelled() {
+                    stop.cancel();
+                } else if last_heartbeat.elapsed() >= HEARTBEAT_INTERVAL {
+                    if send_message(&mut *writer.lock().unwrap(), &Message::Heartbeat).is_err() {
+                        stop.cancel();
+                    }
+                    last_heartbeat = Instant::now();
+                }
+                thread::sleep(POLL_INTERVAL);
+            }
+        });
+
+        let result = loop {
+            if cancel.is_cancelled() {
+                break Ok(());
+            }
+
+            // Once stopped, the reason is the next received message.
+            if !stop.is_cancelled() {
+                if let Err(err) = send_message(&mut *writer.lock().unwrap(), &Message::Request) {
+                    break Err(Error::from(err));
+                }
+            }
+            match receiver.recv() {
+                Ok(Ok(Message::Assign(tiles))) => {
+                    info!("Rendering {} tiles for the coordinator.", tiles.len());
+                    tiles.par_iter().for_each(|&tile_index| {
+                        let result = render_tile(tile_index, &stop);
+                        if !stop.is_cancelled() {
+                            let mut writer = writer.lock().unwrap();
+                            if send_message(&mut *writer, &Message::Tile(result)).is_err() {
+                                stop.cancel();
+                            }
+                        }
+                    });
+                }
+                Ok(Ok(Message::Wait)) => thread::sleep(HEARTBEAT_INTERVAL / 5),
+                Ok(Ok(Message::Done)) => break Ok(()),
+                Ok(Ok(Message::Reject(msg))) => break Err(Error::InvalidState(msg)),
+                Ok(Ok(_)) => {
+                    break Err(protocol_error("Unexpected message from coordinator.").into())
+                }
+                Ok(Err(err)) => break Err(Error::from(err)),
+                Err(_) => break Err(protocol_error("Lost connection to coordinator.").into()),
+            }
+        };
+
+        // Stop the other threads.
+        stop.cancel();
+        let _ = stream.shutdown(Shutdown::Both);
+        result
+    });
+
+    if result.is_ok() {
+        info!("Coordinator '{}' finished rendering.", address);
+    }
+    result
+}
+
+/// Connects to the coordinator, retrying for `CONNECT_TIMEOUT` as it may
+/// not be listening yet.
+///
+/// * `address` - Address of the coordinator.
+/// * `cancel`  - Token used to stop rendering early.
+fn connect(address: &str, cancel: &CancellationToken) -> Result<TcpStream, Error> {
+    let start = Instant::now();
+    loop {
+        match TcpStream::connect(address) {
+            Ok(stream) => return Ok(stream),
+            Err(err) if start.elapsed() >= CONNECT_TIMEOUT || cancel.is_cancelled() => {
+                return Err(Error::Io(format!(
+                    "Unable to connect to coordinator '{}'. {}",
+                    address, err
+                )));
+            }
+            Err(_) => thread::sleep(Duration::from_secs(1)),
+        }
+    }
+}
+
+/// Returns an error for malformed or unexpected messages.
+///
+/// * `msg` - The error message.
+fn protocol_error(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+/// Writes a message and flushes the writer.
+///
+/// * `w`       - The writer.
+/// * `message` - The message.
+fn send_message<W: Write>(w: &mut W, message: &Message) -> io::Result<()> {
+    match message {
+        Message::Hello { job, n_threads } => {
+            w.write_u8(TAG_HELLO)?;
+            w.write_u32::<LittleEndian>(PROTOCOL_MAGIC)?;
+            w.write_u32::<LittleEndian>(PROTOCOL_VERSION)?;
+            write_bounds(w, &job.sample_bounds)?;
+            w.write_u32::<LittleEndian>(job.tile_size)?;
+            w.write_u32::<LittleEndian>(job.n_tiles)?;
+            w.write_u32::<LittleEndian>(job.spectrum_samples)?;
+            w.write_u32::<LittleEndian>(*n_threads)?;
+        }
+        Message::Request => w.write_u8(TAG_REQUEST)?,
+        Message::Heartbeat => w.write_u8(TAG_HEARTBEAT)?,
+        Message::Tile(result) => {
+            w.write_u8(TAG_TILE)?;
+            w.write_u32::<LittleEndian>(result.tile_index as u32)?;
+            write_bounds(w, &result.pixel_bounds)?;
+            w.write_u32::<LittleEndian>(result.pixels.len() as u32)?;
+            for pixel in result.pixels.iter() {
+                write_spectrum(w, &pixel.contrib_sum)?;
+                w.write_f64::<LittleEndian>(pixel.filter_weight_sum as f64)?;
+                w.write_f64::<LittleEndian>(pixel.alpha_sum as f64)?;
+            }
+            w.write_u32::<LittleEndian>(result.splats.len() as u32)?;
+            for (p, v) in result.splats.iter() {
+                w.write_f64::<LittleEndian>(p.x as f64)?;
+                w.write_f64::<LittleEndian>(p.y as f64)?;
+                write_spectrum(w, v)?;
+            }
+        }
+        Message::Assign(tiles) => {
+            w.write_u8(TAG_ASSIGN)?;
+            w.write_u32::<LittleEndian>(tiles.len() as u32)?;
+            for &tile_index in tiles.iter() {
+                w.write_u32::<LittleEndian>(tile_index as u32)?;
+            }
+        }
+        Message::Wait => w.write_u8(TAG_WAIT)?,
+        Message::Done => w.write_u8(TAG_DONE)?,
+        Message::Reject(msg) => {
+            w.write_u8(TAG_REJECT)?;
+            w.write_u32::<LittleEndian>(msg.len() as u32)?;
+            w.write_all(msg.as_bytes())?;
+        }
+    }
+    w.flush()
+}
+
+/// Reads a message.
+///
+/// * `r` - The reader.
+fn read_message<R: Read>(r: &mut R) -> io::Result<Message> {
+    match r.read_u8()? {
+        TAG_HELLO => {
+            if r.read_u32::<LittleEndian>()? != PROTOCOL_MAGIC
+                || r.read_u32::<LittleEndian>()? != PROTOCOL_VERSION
+            {
+                return Err(protocol_error("Unsupported protocol version."));
+            }
+            let job = RenderJob {
+                sample_bounds: read_bounds(r)?,
+                tile_size: r.read_u32::<LittleEndian>()?,
+                n_tiles: r.read_u32::<LittleEndian>()?,
+                spectrum_samples: r.read_u32::<LittleEndian>()?,
+            };
+            let n_threads = r.read_u32::<LittleEndian>()?;
+            Ok(Message::Hello { job, n_threads })
+        }
+        TAG_REQUEST => Ok(Message::Request),
+        TAG_HEARTBEAT => Ok(Message::Heartbeat),
+        TAG_TILE => {
+            let tile_index = r.read_u32::<LittleEndian>()? as usize;
+            let pixel_bounds = read_bounds(r)?;
+            let n_pixels = read_len(r)?;
+            let mut pixels = Vec::with_capacity(n_pixels);
+            for _ in 0..n_pixels {
+                let contrib_sum = read_spectrum(r)?;
+                let filter_weight_sum = r.read_f64::<LittleEndian>()? as Float;
+                let alpha_sum = r.read_f64::<LittleEndian>()? as Float;
+                pixels.push(FilmTilePixel {
+                    contrib_sum,
+                    filter_weight_sum,
+                    alpha_sum,
+                });
+            }
+            let n_splats = read_len(r)?;
+            let mut splats = Vec::with_capacity(n_splats);
+            for _ in 0..n_splats {
+                let x = r.read_f64::<LittleEndian>()? as Float;
+                let y = r.read_f64::<LittleEndian>()? as Float;
+                splats.push((Point2f::new(x, y), read_spectrum(r)?));
+            }
+            Ok(Message::Tile(TileResult {
+                tile_index,
+                pixel_bounds,
+                pixels,
+                splats,
+            }))
+        }
+        TAG_ASSIGN => {
+            let n = read_len(r)?;
+            let tiles = (0..n)
+                .map(|_| r.read_u32::<LittleEndian>().map(|i| i as usize))
+                .collect::<io::Result<Vec<usize>>>()?;
+            Ok(Message::Assign(tiles))
+        }
+        TAG_WAIT => Ok(Message::Wait),
+        TAG_DONE => Ok(Message::Done),
+        TAG_REJECT => {
+            let mut bytes = vec![0; read_len(r)?];
+            r.read_exact(&mut bytes)?;
+            Ok(Message::Reject(
+                String::from_utf8_lossy(&bytes).into_owned(),
+            ))
+        }
+        _ => Err(protocol_error("Unknown message.")),
+    }
+}
+
+/// Reads the number of values that follow.
+///
+/// * `r` - The reader.
+fn read_len<R: Read>(r: &mut R) -> io::Result<usize> {
+    let n = r.read_u32::<LittleEndian>()? as usize;
+    if n > MAX_MESSAGE_VALUES {
+        Err(protocol_error("Message is too large."))
+    } else {
+        Ok(n)
+    }
+}
+
+/// Writes integer bounds.
+///
+/// * `w` - The writer.
+/// * `b` - The bounds.
+fn write_bounds<W: Write>(w: &mut W, b: &Bounds2i) -> io::Result<()> {
+    for v in [b.p_min.x, b.p_min.y, b.p_max.x, b.p_max.y] {
+        w.write_i32::<LittleEndian>(v)?;
+    }
+    Ok(())
+}
+
+/// Reads integer bounds.
+///
+/// * `r` - The reader.
+fn read_bounds<R: Read>(r: &mut R) -> io::Result<Bounds2i> {
+    let mut v = [0_i32; 4];
+    for x in v.iter_mut() {
+        *x = r.read_i32::<LittleEndian>()?;
+    }
+    Ok(Bounds2i::new(
+        Point2i::new(v[0], v[1]),
+        Point2i::new(v[2], v[3]),
+    ))
+}
+
+/// Writes the samples of a spectrum. They are sent at double precision so
+/// that nothing is lost regardless of the size of `Float`.
+///
+/// * `w` - The writer.
+/// * `s` - The spectrum.
+fn write_spectrum<W: Write>(w: &mut W, s: &Spectrum) -> io::Result<()> {
+    for &v in s.samples() {
+        w.write_f64::<LittleEndian>(v as f64)?;
+    }
+    Ok(())
+}
+
+/// Reads the samples of a spectrum.
+///
+/// * `r` - The reader.
+fn read_spectrum<R: Read>(r: &mut R) -> io::Result<Spectrum> {
+    let mut s = Spectrum::default();
+    for v in s.samples_mut() {
+        *v = r.read_f64::<LittleEndian>()? as Float;
+    }
+    Ok(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn idle_workers_steal_tiles_and_failed_workers_tiles_are_requeued() {
+        let mut schedule = Schedule::new(8);
+        schedule.add_worker(0, 2);
+        schedule.add_worker(1, 2);
+
+        // The queue is handed out in rendering order.
+        assert_eq!(schedule.next(0), Assignment::Tiles(vec![0, 1]));
+        assert_eq!(schedule.next(1), Assignment::Tiles(vec![2, 3]));
+        assert_eq!(schedule.next(1), Assignment::Tiles(vec![4, 5]));
+        assert_eq!(schedule.next(1), Assignment::Tiles(vec![6, 7]));
+
+        // Worker 0 is idle and steals half of worker 1's tiles.
+        assert!(schedule.finish(0) && schedule.finish(1));
+        assert_eq!(schedule.next(0), Assignment::Tiles(vec![5, 6, 7]));
+        assert!(schedule.finish(7));
+        assert!(!schedule.finish(7));
+
+        // Worker 1 fails; the tiles only it had are requeued.
+        schedule.remove_worker(1);
+        assert_eq!(schedule.next(0), Assignment::Tiles(vec![2, 3]));
+        for position in 2..7 {
+            schedule.finish(position);
+        }
+        assert_eq!(schedule.next(0), Assignment::Done);
+    }
+
+    #[test]
+    fn workers_render_every_tile_once() {
+        let address = "127.0.0.1:47613";
+        let job = RenderJob::new(Bounds2i::default(), 16, 20);
+        let tile_indices: Vec<usize> = (0..20).rev().collect();
+        let cancel = CancellationToken::new();
+        let merged = Mutex::new(vec![0; 20]);
+
+        thread::scope(|s| {
+            for _ in 0..2 {
+                s.spawn(|| {
+                    run_worker(address, &job, &cancel, |tile_index, _| TileResult {
+                        tile_index,
+                        pixel_bounds: Bounds2i::default(),
+                        pixels: vec![],
+                        splats: vec![],
+                    })
+                    .unwrap();
+                });
+            }
+
+            run_coordinator(address, &job, &tile_indices, &cancel, |position, result| {
+                assert_eq!(tile_indices[position], result.tile_index);
+                merged.lock().unwrap()[position] += 1;
+                Ok(())
+            })
+            .unwrap();
+        });
+
+        assert_eq!(*merged.lock().unwrap(), vec![1; 20]);
+    }
+
+    #[test]
+    fn tile_results_round_trip() {
+        let mut contrib_sum = Spectrum::default();
+        contrib_sum.samples_mut()[0] = 0.25;
+        let result = TileResult {
+            tile_index: 3,
+            pixel_bounds: Bounds2i::new(Point2i::new(0, 0), Point2i::new(1, 2)),
+            pixels: vec![
+                FilmTilePixel {
+                    contrib_sum,
+                    filter_weight_sum: 1.5,
+                    alpha_sum: 0.5,
+                };
+                2
+            ],
+            splats: vec![(Point2f::new(0.5, 1.5), contrib_sum)],
+        };
+
+        let mut bytes = vec![];
+        send_message(&mut bytes, &Message::Tile(result)).unwrap();
+        match read_message(&mut &bytes[..]).unwrap() {
+            Message::Tile(r) => {
+                assert_eq!(r.tile_index, 3);
+                assert_eq!(r.pixels.len(), 2);
+                assert_eq!(r.pixels[1].contrib_sum.samples()[0], 0.25);
+                assert_eq!(r.pixels[1].filter_weight_sum, 1.5);
+                assert_eq!(r.pixels[1].alpha_sum, 0.5);
+                assert_eq!(r.splats[0].0, Point2f::new(0.5, 1.5));
+            }
+            _ => panic!("Expected a tile result."),
+        }
+    }
+}
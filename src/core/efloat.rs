@@ -9,13 +9,13 @@ use std::ops::{Add, Div, Mul, Neg, Sub};
 #[derive(Copy, Clone, Debug, Default)]
 pub struct EFloat {
     /// The floating point value.
-    v: f32,
+    v: Float,
 
     /// The lower bound on `v`.
-    low: f32,
+    low: Float,
 
     /// The upper bound on `v`.
-    high: f32,
+    high: Float,
 
     /// 64-bit precision value corresponding to `v`.
     /// Only used for debug builds.
@@ -26,10 +26,13 @@ pub struct EFloat {
 impl EFloat {
     /// Construct a new float with error bounds.
     ///
-    /// * `v`   - The 32-bit floating point value.
+    /// * `v`   - The floating point value.
     /// * `err` - The error (default to 0.0).
-    pub fn new(v: f32, err: f32) -> Self {
-        let mut r = Self::default();
+    pub fn new(v: Float, err: Float) -> Self {
+        let mut r = Self {
+            v,
+            ..Self::default()
+        };
 
         if err == 0.0 {
             r.low = v;
@@ -39,8 +42,8 @@ impl EFloat {
             // middle. Note that this will be over-conservative in cases where v-err
             // or v+err are exactly representable in floating-point, but it's
             // probably not worth the trouble of checking this case.
-            r.low = next_float_down(r.v - err);
-            r.high = next_float_up(r.v + err);
+            r.low = next_float_down(v - err);
+            r.high = next_float_up(v + err);
         };
 
         #[cfg(debug_assertions)]
@@ -54,49 +57,49 @@ impl EFloat {
 
     /// Construct a new float with error bounds and 64-bit value.
     ///
-    /// * `v`   - The 32-bit floating point value.
+    /// * `v`   - The floating point value.
     /// * `ld`  - The 64-bit floating point value.
     /// * `err` - The error (default to 0.0).
     #[cfg(debug_assertions)]
-    pub fn precise(v: f32, ld: f64, err: f32) -> Self {
+    pub fn precise(v: Float, ld: f64, err: Float) -> Self {
         let mut r = Self::new(v, err);
         r.v_precise = ld;
         r.check();
         r
     }
     #[cfg(not(debug_assertions))]
-    pub fn precise(v: f32, _ld: f64, err: f32) -> Self {
+    pub fn precise(v: Float, _ld: f64, err: Float) -> Self {
         let r = Self::new(v, err);
         r.check();
         r
     }
 
-    /// Asserts low < high, low < v_precise < high for finite non-NAN values.
+    /// Asserts low <= high, low <= v_precise <= high for finite non-NAN values.
     fn check(&self) {
         if self.low.is_finite()
             && !self.low.is_nan()
             && self.high.is_finite()
             && !self.high.is_nan()
         {
-            assert!(self.low < self.high);
+            debug_assert!(self.low <= self.high);
         }
 
         #[cfg(debug_assertions)]
         {
             if self.v.is_finite() && !self.v.is_nan() {
-                assert!((self.low as f64) < self.v_precise);
-                assert!(self.v_precise < (self.high as f64));
+                debug_assert!((self.low as f64) <= self.v_precise);
+                debug_assert!(self.v_precise <= (self.high as f64));
             }
         }
     }
 
     /// Returns the lower bound on the original value.
-    pub fn lower_bound(&self) -> f32 {
+    pub fn lower_bound(&self) -> Float {
         self.low
     }
 
     /// Returns the upper bound on the original value.
-    pub fn upper_bound(&self) -> f32 {
+    pub fn upper_bound(&self) -> Float {
         self.high
     }
 
@@ -108,28 +111,28 @@ impl EFloat {
 
     /// Returns the relative error in the 64-bit precision value.
     #[cfg(debug_assertions)]
-    pub fn relative_error(&self) -> f32 {
-        (((self.v_precise - (self.v as f64)) / self.v_precise).abs()) as f32
+    pub fn relative_error(&self) -> Float {
+        (((self.v_precise - (self.v as f64)) / self.v_precise).abs()) as Float
     }
 
     /// Returns the absolute error.
-    pub fn get_absolute_error(&self) -> f32 {
+    pub fn get_absolute_error(&self) -> Float {
         next_float_up(max((self.high - self.v).abs(), (self.v - self.low).abs()))
     }
 
     /// Returns the square root.
-    pub fn sqrt(&self, ef: Self) -> Self {
+    pub fn sqrt(&self) -> Self {
         let mut r = Self::default();
 
-        r.v = ef.v.sqrt();
+        r.v = self.v.sqrt();
 
         #[cfg(debug_assertions)]
         {
-            r.v_precise = ef.v_precise.sqrt();
+            r.v_precise = self.v_precise.sqrt();
         }
 
-        r.low = next_float_down(ef.low.sqrt());
-        r.high = next_float_up(ef.high.sqrt());
+        r.low = next_float_down(self.low.sqrt());
+        r.high = next_float_up(self.high.sqrt());
 
         r.check();
         r
@@ -176,11 +179,11 @@ impl EFloat {
     }
 }
 
-impl From<f32> for EFloat {
-    /// Converts a 32-bit floating point value to EFloat.
+impl From<Float> for EFloat {
+    /// Converts a floating point value to EFloat.
     ///
-    /// * `v` - The 32-bit floating point value to convert.
-    fn from(v: f32) -> Self {
+    /// * `v` - The floating point value to convert.
+    fn from(v: Float) -> Self {
         Self::new(v, 0.0)
     }
 }
@@ -190,7 +193,7 @@ impl From<EFloat> for f32 {
     ///
     /// * `ef` - The EFloat value.
     fn from(ef: EFloat) -> f32 {
-        ef.v
+        ef.v as f32
     }
 }
 
@@ -238,18 +241,18 @@ impl Add for EFloat {
     }
 }
 
-impl Add<f32> for EFloat {
+impl Add<Float> for EFloat {
     type Output = Self;
 
-    /// Add an f32.
+    /// Add a Float.
     ///
     /// * `v` - The value to add.
-    fn add(self, v: f32) -> Self::Output {
+    fn add(self, v: Float) -> Self::Output {
         self + Self::Output::from(v)
     }
 }
 
-impl Add<EFloat> for f32 {
+impl Add<EFloat> for Float {
     type Output = EFloat;
 
     /// Add an EFloat.
@@ -284,18 +287,18 @@ impl Sub for EFloat {
     }
 }
 
-impl Sub<f32> for EFloat {
+impl Sub<Float> for EFloat {
     type Output = Self;
 
-    /// Subtract an f32.
+    /// Subtract a Float.
     ///
     /// * `v` - The value to subtract.
-    fn sub(self, v: f32) -> Self::Output {
+    fn sub(self, v: Float) -> Self::Output {
         self - Self::Output::from(v)
     }
 }
 
-impl Sub<EFloat> for f32 {
+impl Sub<EFloat> for Float {
     type Output = EFloat;
 
     /// Subtract an EFloat.
@@ -337,18 +340,18 @@ impl Mul for EFloat {
     }
 }
 
-impl Mul<f32> for EFloat {
+impl Mul<Float> for EFloat {
     type Output = Self;
 
-    /// Multiply an f32.
+    /// Multiply a Float.
     ///
     /// * `v` - The value to multiply.
-    fn mul(self, v: f32) -> Self::Output {
+    fn mul(self, v: Float) -> Self::Output {
         self * Self::Output::from(v)
     }
 }
 
-impl Mul<EFloat> for f32 {
+impl Mul<EFloat> for Float {
     type Output = EFloat;
 
     /// Multiply an EFloat.
@@ -378,8 +381,8 @@ impl Div for EFloat {
         if ef.low < 0.0 && ef.high > 0.0 {
             // The interval we're dividing by straddles zero, so just
             // return an interval of everything.
-            r.low = f32::NEG_INFINITY;
-            r.high = f32::INFINITY;
+            r.low = Float::NEG_INFINITY;
+            r.high = Float::INFINITY;
         } else {
             let div = [
                 self.low / ef.low,
@@ -396,18 +399,18 @@ impl Div for EFloat {
     }
 }
 
-impl Div<f32> for EFloat {
+impl Div<Float> for EFloat {
     type Output = Self;
 
-    /// Divide by f32.
+    /// Divide by Float.
     ///
     /// * `v` - The value to divide by.
-    fn div(self, v: f32) -> Self::Output {
+    fn div(self, v: Float) -> Self::Output {
         self / Self::Output::from(v)
     }
 }
 
-impl Div<EFloat> for f32 {
+impl Div<EFloat> for Float {
     type Output = EFloat;
 
     /// Divide by EFloat.
@@ -440,6 +443,284 @@ impl Neg for EFloat {
     }
 }
 
+/// Relative width of the interval of a quadric intersection's `t` value above
+/// which it is recomputed with 64-bit precision.
+pub const MAX_RELATIVE_ROOT_ERROR: Float = 1e-4;
+
+/// Arithmetic on floating point values with error bounds. This is implemented
+/// by `EFloat` and its 64-bit counterpart `EFloat64` so that computations can
+/// be written once and evaluated at either precision.
+pub trait ErrorFloat:
+    Copy
+    + From<Float>
+    + Add<Output = Self>
+    + Add<Float, Output = Self>
+    + Sub<Output = Self>
+    + Sub<Float, Output = Self>
+    + Mul<Output = Self>
+    + Mul<Float, Output = Self>
+    + Div<Output = Self>
+    + Div<Float, Output = Self>
+    + Neg<Output = Self>
+{
+    /// Machine epsilon of the underlying floating point type.
+    const MACHINE_EPSILON: f64;
+
+    /// Returns a new value with error bounds.
+    ///
+    /// * `v`   - The value.
+    /// * `err` - The error.
+    fn with_error(v: f64, err: f64) -> Self;
+
+    /// Returns the value.
+    fn value(&self) -> f64;
+}
+
+impl ErrorFloat for EFloat {
+    const MACHINE_EPSILON: f64 = MACHINE_EPSILON as f64;
+
+    fn with_error(v: f64, err: f64) -> Self {
+        Self::new(v as Float, err as Float)
+    }
+
+    fn value(&self) -> f64 {
+        self.v as f64
+    }
+}
+
+/// 64-bit precision counterpart of `EFloat`. It is used to recompute results
+/// whose error bounds grew too wide with `Float` precision.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct EFloat64 {
+    /// The floating point value.
+    v: f64,
+
+    /// The lower bound on `v`.
+    low: f64,
+
+    /// The upper bound on `v`.
+    high: f64,
+}
+
+impl EFloat64 {
+    /// Construct a new float with error bounds.
+    ///
+    /// * `v`   - The floating point value.
+    /// * `err` - The error.
+    pub fn new(v: f64, err: f64) -> Self {
+        if err == 0.0 {
+            Self { v, low: v, high: v }
+        } else {
+            Self {
+                v,
+                low: (v - err).next_down(),
+                high: (v + err).next_up(),
+            }
+        }
+    }
+
+    /// Returns the lower bound on the original value.
+    pub fn lower_bound(&self) -> f64 {
+        self.low
+    }
+
+    /// Returns the upper bound on the original value.
+    pub fn upper_bound(&self) -> f64 {
+        self.high
+    }
+}
+
+impl ErrorFloat for EFloat64 {
+    const MACHINE_EPSILON: f64 = f64::EPSILON * 0.5;
+
+    fn with_error(v: f64, err: f64) -> Self {
+        Self::new(v, err)
+    }
+
+    fn value(&self) -> f64 {
+        self.v
+    }
+}
+
+impl From<Float> for EFloat64 {
+    /// Converts a floating point value to EFloat64.
+    ///
+    /// * `v` - The floating point value to convert.
+    fn from(v: Float) -> Self {
+        Self::new(v as f64, 0.0)
+    }
+}
+
+impl From<EFloat> for EFloat64 {
+    /// Converts EFloat to EFloat64. This is exact.
+    ///
+    /// * `ef` - The EFloat value.
+    fn from(ef: EFloat) -> Self {
+        Self {
+            v: ef.v as f64,
+            low: ef.low as f64,
+            high: ef.high as f64,
+        }
+    }
+}
+
+impl From<EFloat64> for EFloat {
+    /// Converts EFloat64 to EFloat rounding the bounds outwards.
+    ///
+    /// * `ef` - The EFloat64 value.
+    fn from(ef: EFloat64) -> Self {
+        let mut low = ef.low as Float;
+        if low as f64 > ef.low {
+            low = next_float_down(low);
+        }
+        let mut high = ef.high as Float;
+        if (high as f64) < ef.high {
+            high = next_float_up(high);
+        }
+
+        let r = Self {
+            v: ef.v as Float,
+            low,
+            high,
+            #[cfg(debug_assertions)]
+            v_precise: ef.v,
+        };
+        r.check();
+        r
+    }
+}
+
+impl Add for EFloat64 {
+    type Output = Self;
+
+    /// Add an EFloat64.
+    ///
+    /// * `ef` - The value to add.
+    fn add(self, ef: EFloat64) -> Self::Output {
+        Self {
+            v: self.v + ef.v,
+            low: (self.low + ef.low).next_down(),
+            high: (self.high + ef.high).next_up(),
+        }
+    }
+}
+
+impl Sub for EFloat64 {
+    type Output = Self;
+
+    /// Subtract an EFloat64.
+    ///
+    /// * `ef` - The value to subtract.
+    fn sub(self, ef: EFloat64) -> Self::Output {
+        Self {
+            v: self.v - ef.v,
+            low: (self.low - ef.high).next_down(),
+            high: (self.high - ef.low).next_up(),
+        }
+    }
+}
+
+impl Mul for EFloat64 {
+    type Output = Self;
+
+    /// Multiply an EFloat64.
+    ///
+    /// * `ef` - The value to multiply.
+    fn mul(self, ef: EFloat64) -> Self::Output {
+        let prod = [
+            self.low * ef.low,
+            self.high * ef.low,
+            self.low * ef.high,
+            self.high * ef.high,
+        ];
+        Self {
+            v: self.v * ef.v,
+            low: prod[0].min(prod[1]).min(prod[2]).min(prod[3]).next_down(),
+            high: prod[0].max(prod[1]).max(prod[2]).max(prod[3]).next_up(),
+        }
+    }
+}
+
+impl Div for EFloat64 {
+    type Output = Self;
+
+    /// Divide by an EFloat64.
+    ///
+    /// * `ef` - The value to divide by.
+    fn div(self, ef: EFloat64) -> Self::Output {
+        if ef.low < 0.0 && ef.high > 0.0 {
+            // The interval we're dividing by straddles zero, so just
+            // return an interval of everything.
+            Self {
+                v: self.v / ef.v,
+                low: f64::NEG_INFINITY,
+                high: f64::INFINITY,
+            }
+        } else {
+            let div = [
+                self.low / ef.low,
+                self.high / ef.low,
+                self.low / ef.high,
+                self.high / ef.high,
+            ];
+            Self {
+                v: self.v / ef.v,
+                low: div[0].min(div[1]).min(div[2]).min(div[3]).next_down(),
+                high: div[0].max(div[1]).max(div[2]).max(div[3]).next_up(),
+            }
+        }
+    }
+}
+
+impl Neg for EFloat64 {
+    type Output = Self;
+
+    /// Return the negative value.
+    fn neg(self) -> Self::Output {
+        Self {
+            v: -self.v,
+            low: -self.high,
+            high: -self.low,
+        }
+    }
+}
+
+/// Implements arithmetic operators between `EFloat64` and `Float`.
+macro_rules! efloat64_float_ops {
+    ($($op: ident, $f: ident);+) => {
+        $(
+            impl $op<Float> for EFloat64 {
+                type Output = Self;
+
+                fn $f(self, v: Float) -> Self::Output {
+                    self.$f(Self::Output::from(v))
+                }
+            }
+
+            impl $op<EFloat64> for Float {
+                type Output = EFloat64;
+
+                fn $f(self, ef: EFloat64) -> Self::Output {
+                    Self::Output::from(self).$f(ef)
+                }
+            }
+        )+
+    };
+}
+
+efloat64_float_ops!(Add, add; Sub, sub; Mul, mul; Div, div);
+
+/// Coefficients of the quadratic equation whose roots are the `t` values
+/// where a ray intersects a quadric surface.
+pub trait QuadricCoefficients {
+    /// Returns the coefficients (a, b, c) of a * t ^ 2 + b * t + c = 0 for a
+    /// ray in object space.
+    ///
+    /// * `o` - Ray origin.
+    /// * `d` - Ray direction.
+    fn coefficients<E: ErrorFloat>(&self, o: [E; 3], d: [E; 3]) -> (E, E, E);
+}
+
 /// Implements a quadratic equation solver.
 pub struct Quadratic {}
 
@@ -449,30 +730,114 @@ impl Quadratic {
     /// * `a` - Coefficient of x ^ 2 term.
     /// * `b` - Coefficient of x term.
     /// * `c` - Coefficient of constant term.
-    pub fn solve(a: EFloat, b: EFloat, c: EFloat) -> Option<(EFloat, EFloat)> {
+    pub fn solve<E: ErrorFloat>(a: E, b: E, c: E) -> Option<(E, E)> {
         // Find quadratic discriminant
-        let discrim: f64 = b.v as f64 * b.v as f64 - 4.0f64 * a.v as f64 * c.v as f64;
+        let (av, bv, cv) = (a.value(), b.value(), c.value());
+        let discrim = bv * bv - 4.0 * av * cv;
         if discrim < 0.0 {
             None
         } else {
-            let root_discrim = discrim.sqrt() as f32;
-            let ef_root_discrim = EFloat::new(root_discrim, MACHINE_EPSILON * root_discrim);
+            let root_discrim = discrim.sqrt();
+            let ef_root_discrim = E::with_error(root_discrim, E::MACHINE_EPSILON * root_discrim);
 
             // Compute quadratic _t_ values
-            let q = if b.v < 0.0 {
-                -0.5f32 * (b - ef_root_discrim)
+            let q = if bv < 0.0 {
+                (b - ef_root_discrim) * -0.5
             } else {
-                -0.5f32 * (b + ef_root_discrim)
+                (b + ef_root_discrim) * -0.5
             };
 
             let t0 = q / a;
             let t1 = c / q;
 
-            if t0.v > t1.v {
+            if t0.value() > t1.value() {
                 Some((t1, t0))
             } else {
                 Some((t0, t1))
             }
         }
     }
+
+    /// Solve the quadratic equation for the intersections of a ray with a
+    /// quadric surface. If the error bounds of a root are too wide to tell
+    /// whether it lies in front of the ray origin or they exceed
+    /// `MAX_RELATIVE_ROOT_ERROR`, the roots are recomputed with 64-bit
+    /// precision.
+    ///
+    /// * `quadric` - The quadric surface.
+    /// * `o`       - Ray origin in object space.
+    /// * `d`       - Ray direction in object space.
+    pub fn solve_quadric<Q: QuadricCoefficients>(
+        quadric: &Q,
+        o: [EFloat; 3],
+        d: [EFloat; 3],
+    ) -> Option<(EFloat, EFloat)> {
+        let (a, b, c) = quadric.coefficients(o, d);
+        let (t0, t1) = Self::solve(a, b, c)?;
+        if !Self::too_wide(&t0) && !Self::too_wide(&t1) {
+            return Some((t0, t1));
+        }
+
+        let (a, b, c) = quadric.coefficients(o.map(EFloat64::from), d.map(EFloat64::from));
+        Self::solve(a, b, c).map(|(t0, t1)| (EFloat::from(t0), EFloat::from(t1)))
+    }
+
+    /// Returns `true` if the bounds of a root straddle 0 or are wider than
+    /// `MAX_RELATIVE_ROOT_ERROR` relative to its value.
+    ///
+    /// * `t` - The root.
+    fn too_wide(t: &EFloat) -> bool {
+        (t.low <= 0.0 && t.high > 0.0) || t.high - t.low > MAX_RELATIVE_ROOT_ERROR * t.v.abs()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Unit sphere centered at the origin.
+    struct UnitSphere {}
+
+    impl QuadricCoefficients for UnitSphere {
+        fn coefficients<E: ErrorFloat>(&self, o: [E; 3], d: [E; 3]) -> (E, E, E) {
+            let a = d[0] * d[0] + d[1] * d[1] + d[2] * d[2];
+            let b = (d[0] * o[0] + d[1] * o[1] + d[2] * o[2]) * 2.0;
+            let c = o[0] * o[0] + o[1] * o[1] + o[2] * o[2] - 1.0;
+            (a, b, c)
+        }
+    }
+
+    #[test]
+    fn new_bounds_the_value() {
+        let ef = EFloat::new(2.0, 0.5);
+        assert_eq!(f64::from(ef), 2.0);
+        assert!(ef.lower_bound() < 1.5 && ef.upper_bound() > 2.5);
+
+        let exact = EFloat::from(3.0);
+        assert_eq!(exact.lower_bound(), 3.0);
+        assert_eq!(exact.upper_bound(), 3.0);
+        assert_eq!(
+            exact.sqrt().lower_bound(),
+            next_float_down((3.0 as Float).sqrt())
+        );
+    }
+
+    #[test]
+    fn quadric_roots_bound_the_exact_roots() {
+        // Ray starting just above the surface with a large origin error so
+        // that the near root straddles 0 at `Float` precision.
+        let o = [
+            EFloat::new(0.0, 0.0),
+            EFloat::new(0.0, 0.0),
+            EFloat::new(1.000001, 1e-7),
+        ];
+        let d = [EFloat::from(0.0), EFloat::from(0.0), EFloat::from(-1.0)];
+
+        let (t0, t1) = Quadratic::solve_quadric(&UnitSphere {}, o, d).unwrap();
+        assert!(t0.lower_bound() <= 1e-6 && t0.upper_bound() >= 1e-6);
+        assert!(t1.lower_bound() <= 2.000001 && t1.upper_bound() >= 2.000001);
+
+        let f64_t1 = EFloat64::from(t1);
+        assert!(f64_t1.lower_bound() <= f64_t1.upper_bound());
+    }
 }
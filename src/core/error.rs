@@ -0,0 +1,121 @@
+//! Errors
+
+use std::fmt;
+
+/// Errors reported while loading files and constructing a scene.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Error {
+    /// A file could not be read or written.
+    Io(String),
+
+    /// An image could not be decoded or encoded.
+    Image(String),
+
+    /// A file could not be parsed.
+    Parse(String),
+
+    /// A required parameter was not provided.
+    MissingParameter(String),
+
+    /// A parameter has an invalid value.
+    InvalidParameter(String),
+
+    /// An unknown type of object was requested.
+    UnknownType(String),
+
+    /// A feature or file format is not supported.
+    Unsupported(String),
+
+    /// An API call was made in an invalid state.
+    InvalidState(String),
+}
+
+impl Error {
+    /// Returns the error message.
+    pub fn message(&self) -> &str {
+        match self {
+            Self::Io(msg) => msg,
+            Self::Image(msg) => msg,
+            Self::Parse(msg) => msg,
+            Self::MissingParameter(msg) => msg,
+            Self::InvalidParameter(msg) => msg,
+            Self::UnknownType(msg) => msg,
+            Self::Unsupported(msg) => msg,
+            Self::InvalidState(msg) => msg,
+        }
+    }
+
+    /// Returns the error with its message prefixed by the location of the
+    /// statement that caused it, if known.
+    ///
+    /// * `location` - Location of the statement.
+    pub fn at(self, location: Option<&SourceLocation>) -> Self {
+        let location = match location {
+            Some(location) => location,
+            None => return self,
+        };
+        let located = |msg: String| format!("{}: {}", location, msg);
+        match self {
+            Self::Io(msg) => Self::Io(located(msg)),
+            Self::Image(msg) => Self::Image(located(msg)),
+            Self::Parse(msg) => Self::Parse(located(msg)),
+            Self::MissingParameter(msg) => Self::MissingParameter(located(msg)),
+            Self::InvalidParameter(msg) => Self::InvalidParameter(located(msg)),
+            Self::UnknownType(msg) => Self::UnknownType(located(msg)),
+            Self::Unsupported(msg) => Self::Unsupported(located(msg)),
+            Self::InvalidState(msg) => Self::InvalidState(located(msg)),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    /// Formats the error message.
+    ///
+    /// * `f` - Formatter.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message())
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<std::io::Error> for Error {
+    /// Converts an I/O error.
+    ///
+    /// * `err` - The I/O error.
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(format!("{}", err))
+    }
+}
+
+/// Location of a statement in a scene file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SourceLocation {
+    /// Path of the file.
+    pub file: String,
+
+    /// Line number, starting at 1.
+    pub line: usize,
+}
+
+impl SourceLocation {
+    /// Returns a new `SourceLocation`.
+    ///
+    /// * `file` - Path of the file.
+    /// * `line` - Line number, starting at 1.
+    pub fn new(file: &str, line: usize) -> Self {
+        Self {
+            file: String::from(file),
+            line,
+        }
+    }
+}
+
+impl fmt::Display for SourceLocation {
+    /// Formats the location as `file:line`.
+    ///
+    /// * `f` - Formatter.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.file, self.line)
+    }
+}
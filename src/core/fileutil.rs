@@ -2,22 +2,149 @@
 
 #![allow(dead_code)]
 
-use std::path::PathBuf;
+use crate::core::app::OPTIONS;
+use crate::core::error::*;
+use crate::core::pbrt::Float;
+use std::cell::RefCell;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
 use std::result::Result;
 
+/// Environment variable listing directories to search for asset files,
+/// separated like `PATH`.
+pub const SEARCH_PATH_ENV_VAR: &str = "PBRT_SEARCH_PATH";
+
+thread_local! {
+    /// Restricts the asset files found by the current thread; see
+    /// `Sandbox::enter()`.
+    static SANDBOX: RefCell<Option<Sandbox>> = const { RefCell::new(None) };
+}
+
+/// Restricts the files a scene description can refer to to a directory, e.g.
+/// for scene descriptions from untrusted sources.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Sandbox {
+    /// Absolute path of the directory files must be in; `None` if no files
+    /// can be used.
+    root: Option<PathBuf>,
+}
+
+impl Sandbox {
+    /// Returns a new `Sandbox`; or returns an error if the directory does not
+    /// exist.
+    ///
+    /// * `root` - Directory files must be in; `None` if no files can be used.
+    pub fn new(root: Option<&str>) -> Result<Self, Error> {
+        let root = root.map(absolute_path).transpose()?.map(PathBuf::from);
+        Ok(Self { root })
+    }
+
+    /// Returns the absolute path of the directory files must be in.
+    pub fn root(&self) -> Option<&Path> {
+        self.root.as_deref()
+    }
+
+    /// Returns the absolute path of a file; or returns an error if it does
+    /// not exist or is outside the directory. Relative paths are relative to
+    /// the directory and the search paths are not used.
+    ///
+    /// * `path` - The path.
+    pub fn find_file(&self, path: &str) -> Result<String, Error> {
+        let outside = || Error::Io(format!("File '{}' is outside the scene root.", path));
+        let root = self.root.as_ref().ok_or_else(outside)?;
+        let abs_path = absolute_path(&root.join(path).to_string_lossy()).map_err(|_| outside())?;
+        if Path::new(&abs_path).starts_with(root) {
+            Ok(abs_path)
+        } else {
+            Err(outside())
+        }
+    }
+
+    /// Calls a function with the asset files found by the current thread
+    /// restricted to the directory (see `find_file()`).
+    ///
+    /// * `f` - The function.
+    pub fn enter<T>(&self, f: impl FnOnce() -> T) -> T {
+        /// Restores the previous sandbox, even if `f` panics.
+        struct Restore(Option<Sandbox>);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                SANDBOX.with(|sandbox| *sandbox.borrow_mut() = self.0.take());
+            }
+        }
+
+        let _restore = Restore(SANDBOX.with(|sandbox| sandbox.replace(Some(self.clone()))));
+        f()
+    }
+}
+
 /// Returns the absolute path after resolving the given path.
 ///
 /// * `path` - The path.
-pub fn absolute_path(path: &str) -> Result<String, String> {
+pub fn absolute_path(path: &str) -> Result<String, Error> {
     match PathBuf::from(path)
         .canonicalize()
         .map(PathBuf::into_os_string)
         .map(|s| s.into_string().ok())
     {
         Ok(Some(abs_path)) => Ok(abs_path),
-        Ok(None) => Err(format!("invalid path {}", path)),
-        Err(err) => Err(format!("invalid path {}. {}.", path, err)),
+        Ok(None) => Err(Error::Io(format!("invalid path {}", path))),
+        Err(err) => Err(Error::Io(format!("invalid path {}. {}.", path, err))),
+    }
+}
+
+/// Returns the directories listed in the `PBRT_SEARCH_PATH` environment
+/// variable.
+pub fn env_search_paths() -> Vec<String> {
+    env::var_os(SEARCH_PATH_ENV_VAR).map_or(vec![], |paths| {
+        env::split_paths(&paths)
+            .filter(|p| !p.as_os_str().is_empty())
+            .map(|p| p.to_string_lossy().into_owned())
+            .collect()
+    })
+}
+
+/// Returns the absolute path of an asset file such as a texture or a mesh.
+/// A relative path that doesn't exist relative to the working directory is
+/// looked up in the search paths given on the command line and in the
+/// `PBRT_SEARCH_PATH` environment variable. Inside a sandbox, only files in
+/// its directory are found (see `Sandbox::enter()`).
+///
+/// * `path` - The path.
+pub fn find_file(path: &str) -> Result<String, Error> {
+    match SANDBOX.with(|sandbox| sandbox.borrow().as_ref().map(|s| s.find_file(path))) {
+        Some(result) => result,
+        None => find_file_in(path, &OPTIONS.search_paths),
+    }
+}
+
+/// Returns the absolute path of a file. A relative path that doesn't exist
+/// relative to the working directory is looked up in the given directories
+/// in order.
+///
+/// * `path`         - The path.
+/// * `search_paths` - Directories to search.
+pub fn find_file_in(path: &str, search_paths: &[String]) -> Result<String, Error> {
+    let err = match absolute_path(path) {
+        Ok(abs_path) => return Ok(abs_path),
+        Err(err) => err,
+    };
+    if !is_relative_path(path) || search_paths.is_empty() {
+        return Err(err);
     }
+
+    search_paths
+        .iter()
+        .find_map(|dir| absolute_path(&PathBuf::from(dir).join(path).to_string_lossy()).ok())
+        .ok_or_else(|| {
+            Error::Io(format!(
+                "File '{}' not found in the working directory or the search paths '{}'.",
+                path,
+                search_paths.join("', '")
+            ))
+        })
 }
 
 /// Returns the path to the parent folder; or `None` if path is root.
@@ -42,3 +169,196 @@ pub fn is_relative_path(path: &str) -> bool {
 pub fn is_absolute_path(path: &str) -> bool {
     PathBuf::from(path).is_absolute()
 }
+
+/// Reads a file containing floating point values separated by whitespace.
+/// A `#` starts a comment that runs to the end of the line. Values may be
+/// written in scientific notation. Any other text results in an error.
+///
+/// * `path` - The path.
+pub fn read_float_file(path: &str) -> Result<Vec<Float>, Error> {
+    let contents = fs::read_to_string(path)
+        .map_err(|err| Error::Io(format!("Error reading file '{}'. {}.", path, err)))?;
+
+    let mut values: Vec<Float> = vec![];
+    for (line_no, line) in contents.lines().enumerate() {
+        let data = line.split('#').next().unwrap_or("");
+        for token in data.split_whitespace() {
+            match token.parse::<Float>() {
+                Ok(v) if v.is_finite() => values.push(v),
+                _ => {
+                    return Err(Error::Parse(format!(
+                        "Unexpected text '{}' in file '{}', line {}.",
+                        token,
+                        path,
+                        line_no + 1
+                    )))
+                }
+            }
+        }
+    }
+    Ok(values)
+}
+
+/// Returns `true` if the given path contains a frame number placeholder
+/// (a run of one or more `#` characters).
+///
+/// * `path` - The path.
+pub fn has_frame_pattern(path: &str) -> bool {
+    path.contains('#')
+}
+
+/// Returns the path with a suffix appended to the file stem, e.g.
+/// `image_diffuse.exr` for `image.exr` and `diffuse`.
+///
+/// * `path`   - The path.
+/// * `suffix` - The suffix.
+pub fn suffixed_path(path: &str, suffix: &str) -> String {
+    let p = PathBuf::from(path);
+    let stem = p
+        .file_stem()
+        .map_or(String::new(), |s| s.to_string_lossy().into_owned());
+    let file_name = match p.extension() {
+        Some(ext) => format!("{}_{}.{}", stem, suffix, ext.to_string_lossy()),
+        None => format!("{}_{}", stem, suffix),
+    };
+    p.with_file_name(file_name).to_string_lossy().into_owned()
+}
+
+/// Returns the path for a given frame number of an animation sequence.
+///
+/// The last run of `#` characters in the file name is replaced with the frame
+/// number, zero-padded to the length of the run. If there is no such run, the
+/// frame number is appended to the file stem as `_NNNN`.
+///
+/// * `path`  - The path.
+/// * `frame` - The frame number.
+pub fn frame_path(path: &str, frame: usize) -> String {
+    if let Some(end) = path.rfind('#') {
+        let start = path[..end].rfind(|c| c != '#').map_or(0, |i| i + 1);
+        let width = end - start + 1;
+        format!(
+            "{}{:0w$}{}",
+            &path[..start],
+            frame,
+            &path[end + 1..],
+            w = width
+        )
+    } else {
+        let p = PathBuf::from(path);
+        let stem = p.file_stem().and_then(|s| s.to_str()).unwrap_or("");
+        let file_name = match p.extension().and_then(|s| s.to_str()) {
+            Some(ext) => format!("{}_{:04}.{}", stem, frame, ext),
+            None => format!("{}_{:04}", stem, frame),
+        };
+        p.with_file_name(file_name)
+            .to_str()
+            .map_or(String::from(path), String::from)
+    }
+}
+
+/// Returns the path of an input file for a given frame number. Paths with a
+/// frame pattern have it replaced with the frame number; other paths, e.g. a
+/// scene shared by all frames, are used as they are.
+///
+/// * `path`  - The path.
+/// * `frame` - The frame number.
+pub fn frame_input_path(path: &str, frame: usize) -> String {
+    if has_frame_pattern(path) {
+        frame_path(path, frame)
+    } else {
+        String::from(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    fn write_temp_file(name: &str, contents: &str) -> String {
+        let path = env::temp_dir().join(name);
+        fs::write(&path, contents).unwrap();
+        String::from(path.to_str().unwrap())
+    }
+
+    #[test]
+    fn read_float_file_skips_comments_and_whitespace() {
+        let path = write_temp_file(
+            "pbr_rust_read_float_file_ok.spd",
+            "# wavelength value\n300 1.5e-1\t 310 .25 # trailing\n\n  320 -2E+1\n",
+        );
+        let values = read_float_file(&path).unwrap();
+        assert!(values == vec![300.0, 0.15, 310.0, 0.25, 320.0, -20.0]);
+    }
+
+    #[test]
+    fn read_float_file_reports_line_of_bad_token() {
+        let path = write_temp_file("pbr_rust_read_float_file_err.spd", "1 2\n3 x4\n");
+        let err = read_float_file(&path).unwrap_err();
+        assert!(matches!(err, Error::Parse(_)));
+        assert!(err.message().contains("'x4'"));
+        assert!(err.message().contains("line 2"));
+    }
+
+    #[test]
+    fn relative_paths_are_found_in_search_paths() {
+        let textures = env::temp_dir().join("pbr_rust_search_path/textures");
+        let meshes = env::temp_dir().join("pbr_rust_search_path/meshes");
+        fs::create_dir_all(&textures).unwrap();
+        fs::create_dir_all(&meshes).unwrap();
+        fs::write(meshes.join("bunny.ply"), "ply").unwrap();
+        let dirs = [
+            String::from(textures.to_str().unwrap()),
+            String::from(meshes.to_str().unwrap()),
+        ];
+
+        let found = find_file_in("bunny.ply", &dirs).unwrap();
+        assert!(found == absolute_path(meshes.join("bunny.ply").to_str().unwrap()).unwrap());
+        assert!(find_file_in("bunny.ply", &[]).is_err());
+
+        let err = find_file_in("dragon.ply", &dirs).unwrap_err();
+        assert!(err.message().contains("'dragon.ply'"));
+        assert!(err.message().contains(&dirs[1]));
+
+        // Files relative to the working directory take precedence.
+        assert!(find_file_in("Cargo.toml", &dirs) == absolute_path("Cargo.toml"));
+    }
+
+    #[test]
+    fn sandboxed_files_stay_in_the_scene_root() {
+        let dir = env::temp_dir().join("pbr_rust_sandboxed_files");
+        fs::create_dir_all(dir.join("root/textures")).unwrap();
+        fs::write(dir.join("root/textures/wood.png"), "").unwrap();
+        fs::write(dir.join("secret.png"), "").unwrap();
+        let secret = dir.join("secret.png").to_string_lossy().into_owned();
+
+        let sandbox = Sandbox::new(Some(dir.join("root").to_str().unwrap())).unwrap();
+        let wood = sandbox.enter(|| find_file("textures/wood.png")).unwrap();
+        assert!(Path::new(&wood).starts_with(sandbox.root().unwrap()));
+        assert!(sandbox.enter(|| find_file("../secret.png")).is_err());
+        assert!(sandbox.enter(|| find_file(&secret)).is_err());
+        assert!(Sandbox::new(None)
+            .unwrap()
+            .enter(|| find_file(&wood))
+            .is_err());
+
+        // The search paths aren't used and the sandbox ends with the call.
+        assert!(sandbox.enter(|| find_file("Cargo.toml")).is_err());
+        assert!(find_file(&secret).is_ok());
+    }
+
+    #[test]
+    fn frame_path_replaces_hashes() {
+        assert!(frame_path("out/frame_###.exr", 7) == "out/frame_007.exr");
+        assert!(frame_path("frame.exr", 12) == "frame_0012.exr");
+    }
+
+    #[test]
+    fn frame_input_paths_only_replace_frame_patterns() {
+        let paths: Vec<String> = ["shared.pbrt", "frame_###.pbrt"]
+            .iter()
+            .map(|p| frame_input_path(p, 3))
+            .collect();
+        assert_eq!(paths, vec!["shared.pbrt", "frame_003.pbrt"]);
+    }
+}
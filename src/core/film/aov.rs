@@ -0,0 +1,253 @@
+//! Arbitrary output variables
+
+use crate::core::geometry::*;
+use crate::core::pbrt::*;
+use crate::core::primitive::*;
+use crate::core::primitives::*;
+use crate::core::spectrum::*;
+use std::collections::HashMap;
+
+/// Auxiliary value of the first surface camera rays hit, written as an image
+/// next to the output image for denoising and compositing.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Aov {
+    /// Reflectance of the surface.
+    Albedo,
+
+    /// Shading normal in world space.
+    Normal,
+
+    /// Distance from the camera.
+    Depth,
+
+    /// Position in world space.
+    Position,
+
+    /// Identifier of the primitive starting at 1; see `PrimitiveIds`. It is
+    /// the identifier of the first sample of each pixel that hit a surface
+    /// since identifiers can't be averaged.
+    PrimId,
+}
+
+impl Aov {
+    /// Returns the AOV with the given name; "albedo", "normal", "depth",
+    /// "position" or "primid". Returns `None` if it is unknown.
+    ///
+    /// * `name` - The name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "albedo" => Some(Self::Albedo),
+            "normal" => Some(Self::Normal),
+            "depth" => Some(Self::Depth),
+            "position" => Some(Self::Position),
+            "primid" => Some(Self::PrimId),
+            _ => None,
+        }
+    }
+
+    /// Returns the name of the AOV; it is used as the suffix of its image.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Albedo => "albedo",
+            Self::Normal => "normal",
+            Self::Depth => "depth",
+            Self::Position => "position",
+            Self::PrimId => "primid",
+        }
+    }
+
+    /// Returns the three values of the AOV for a sample. The albedo is given
+    /// as XYZ values so it can be converted to the output color space; the
+    /// depth and primitive identifier are repeated.
+    ///
+    /// * `sample` - The AOVs of the sample.
+    pub fn value(&self, sample: &AovSample) -> [Float; 3] {
+        match self {
+            Self::Albedo => sample.albedo.to_xyz(),
+            Self::Normal => [sample.normal.x, sample.normal.y, sample.normal.z],
+            Self::Depth => [sample.depth; 3],
+            Self::Position => [sample.position.x, sample.position.y, sample.position.z],
+            Self::PrimId => [sample.prim_id; 3],
+        }
+    }
+
+    /// Returns `true` if the values of the samples of a pixel are averaged;
+    /// otherwise the value of the first sample is used.
+    pub fn is_filtered(&self) -> bool {
+        !matches!(self, Self::PrimId)
+    }
+}
+
+/// The AOVs of the first surface a camera ray hits.
+#[derive(Copy, Clone, Default)]
+pub struct AovSample {
+    /// Reflectance of the surface.
+    pub albedo: Spectrum,
+
+    /// Shading normal in world space.
+    pub normal: Normal3f,
+
+    /// Distance from the camera.
+    pub depth: Float,
+
+    /// Position in world space.
+    pub position: Point3f,
+
+    /// Identifier of the primitive; 0 if unknown.
+    pub prim_id: Float,
+}
+
+/// Stores the sum of the values of an AOV for the samples of a pixel that hit
+/// a surface.
+#[derive(Copy, Clone, Default)]
+pub struct AovPixel {
+    /// Sum of the values.
+    pub sum: [Float; 3],
+
+    /// Number of samples.
+    pub count: Float,
+
+    /// Values of the first sample.
+    pub first: [Float; 3],
+}
+
+impl AovPixel {
+    /// Adds the values of a sample.
+    ///
+    /// * `v` - The values.
+    pub fn add(&mut self, v: &[Float; 3]) {
+        if self.count == 0.0 {
+            self.first = *v;
+        }
+        for (s, v) in self.sum.iter_mut().zip(v.iter()) {
+            *s += v;
+        }
+        self.count += 1.0;
+    }
+
+    /// Adds the samples of another pixel.
+    ///
+    /// * `other` - The other pixel.
+    pub fn merge(&mut self, other: &AovPixel) {
+        if self.count == 0.0 {
+            self.first = other.first;
+        }
+        for (s, v) in self.sum.iter_mut().zip(other.sum.iter()) {
+            *s += v;
+        }
+        self.count += other.count;
+    }
+
+    /// Returns the average of the values; 0 if no sample hit a surface.
+    pub fn average(&self) -> [Float; 3] {
+        if self.count > 0.0 {
+            let inv_count = 1.0 / self.count;
+            [
+                self.sum[0] * inv_count,
+                self.sum[1] * inv_count,
+                self.sum[2] * inv_count,
+            ]
+        } else {
+            [0.0; 3]
+        }
+    }
+}
+
+/// Identifiers of the primitives of a scene for the `Aov::PrimId` AOV. They
+/// start at 1 in the order the aggregate stores the primitives. Instances
+/// of an object share the identifiers of its primitives.
+pub struct PrimitiveIds {
+    /// Identifiers by address of the primitive.
+    ids: HashMap<usize, Float>,
+}
+
+impl PrimitiveIds {
+    /// Returns the identifiers of the primitives of an aggregate.
+    ///
+    /// * `aggregate` - The aggregate.
+    pub fn new(aggregate: &dyn Primitive) -> Self {
+        let mut ids = Self {
+            ids: HashMap::new(),
+        };
+        aggregate.accept(&mut ids);
+        ids
+    }
+
+    /// Returns the identifier of a primitive; 0 if it isn't known.
+    ///
+    /// * `primitive` - The primitive.
+    pub fn get(&self, primitive: &dyn Primitive) -> Float {
+        let key = primitive as *const dyn Primitive as *const () as usize;
+        self.ids.get(&key).copied().unwrap_or(0.0)
+    }
+}
+
+impl PrimitiveVisitor for PrimitiveIds {
+    /// Assigns the next identifier to a primitive seen for the first time.
+    ///
+    /// * `primitive` - The primitive.
+    fn visit_geometric(&mut self, primitive: &GeometricPrimitive) {
+        let key = primitive as *const GeometricPrimitive as *const () as usize;
+        let next_id = (self.ids.len() + 1) as Float;
+        self.ids.entry(key).or_insert(next_id);
+    }
+
+    /// Visits the primitives of instances.
+    ///
+    /// * `_primitive` - The primitive.
+    fn visit_transformed(&mut self, _primitive: &TransformedPrimitive) -> bool {
+        true
+    }
+
+    /// Aggregates don't have identifiers.
+    ///
+    /// * `_n_nodes` - Number of nodes in the acceleration structure.
+    /// * `_bytes`   - Memory used by the nodes.
+    fn visit_aggregate(&mut self, _n_nodes: usize, _bytes: usize) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aov_pixels_average_the_samples_that_hit_a_surface() {
+        let sample = AovSample {
+            normal: Normal3f::new(0.0, 0.0, -1.0),
+            depth: 2.0,
+            position: Point3f::new(1.0, 2.0, 3.0),
+            ..AovSample::default()
+        };
+        assert_eq!(Aov::Depth.value(&sample), [2.0; 3]);
+        assert_eq!(Aov::Position.value(&sample), [1.0, 2.0, 3.0]);
+
+        let mut pixel = AovPixel::default();
+        assert_eq!(pixel.average(), [0.0; 3]);
+        pixel.add(&Aov::Normal.value(&sample));
+        let mut other = AovPixel::default();
+        other.add(&[0.0, 1.0, 0.0]);
+        pixel.merge(&other);
+        assert_eq!(pixel.average(), [0.0, 0.5, -0.5]);
+
+        assert_eq!(Aov::from_name("albedo"), Some(Aov::Albedo));
+        assert!(Aov::from_name("uv").is_none());
+    }
+
+    #[test]
+    fn primitive_ids_keep_the_first_sample() {
+        let sample = AovSample {
+            prim_id: 2.0,
+            ..AovSample::default()
+        };
+        assert_eq!(Aov::from_name("primid"), Some(Aov::PrimId));
+        assert!(!Aov::PrimId.is_filtered());
+
+        let mut pixel = AovPixel::default();
+        pixel.add(&Aov::PrimId.value(&sample));
+        pixel.add(&[5.0; 3]);
+        let mut merged = AovPixel::default();
+        merged.merge(&pixel);
+        merged.add(&[7.0; 3]);
+        assert_eq!(merged.first, [2.0; 3]);
+    }
+}
@@ -0,0 +1,165 @@
+//! Bloom
+
+use crate::core::pbrt::*;
+use crate::core::spectrum::*;
+
+/// Wavelengths in nanometers that the red, green and blue channels are
+/// treated as when scaling the glare kernel. Diffraction spreads longer
+/// wavelengths further.
+const CHANNEL_WAVELENGTHS: [Float; 3] = [610.0, 550.0, 465.0];
+
+/// Widths of the Gaussians making up the glare kernel relative to the widest
+/// one, and their weights. Together they approximate the heavy-tailed falloff
+/// of the point spread function of a diffraction limited aperture.
+const GLARE_LOBES: [(Float, Float); 4] = [(0.125, 0.5), (0.25, 0.25), (0.5, 0.15), (1.0, 0.1)];
+
+/// Glare around bright parts of the image due to light scattered by the lens
+/// and the eye.
+#[derive(Copy, Clone, Debug)]
+pub struct Bloom {
+    /// Fraction of the light above `threshold` that is scattered.
+    pub intensity: Float,
+
+    /// Radius of the glare in pixels for green light.
+    pub radius: Float,
+
+    /// Luminance above which light is scattered.
+    pub threshold: Float,
+}
+
+impl Bloom {
+    /// Create a new `Bloom`.
+    ///
+    /// * `intensity` - Fraction of the light above `threshold` that is
+    ///                 scattered.
+    /// * `radius`    - Radius of the glare in pixels for green light.
+    /// * `threshold` - Luminance above which light is scattered.
+    pub fn new(intensity: Float, radius: Float, threshold: Float) -> Self {
+        Self {
+            intensity,
+            radius,
+            threshold,
+        }
+    }
+
+    /// Scatters the light of the bright pixels of an image around them. The
+    /// total light is unchanged except for what is scattered off the image.
+    ///
+    /// * `rgb`         - Linear RGB values of the image in row-major order.
+    /// * `width`       - Width of the image in pixels.
+    /// * `height`      - Height of the image in pixels.
+    /// * `color_space` - Color space of the RGB values.
+    pub fn apply(&self, rgb: &mut [Float], width: usize, height: usize, color_space: ColorSpace) {
+        if self.intensity <= 0.0 || self.radius <= 0.0 || width * height == 0 {
+            return;
+        }
+
+        // Bright pass; keep the part of each pixel above the threshold
+        // luminance without changing its hue.
+        let mut bright = vec![0.0; rgb.len()];
+        for (b, c) in bright.chunks_mut(3).zip(rgb.chunks(3)) {
+            let y = color_space.rgb_to_xyz(&[c[0], c[1], c[2]])[1];
+            if y > self.threshold {
+                let s = (y - self.threshold) / y;
+                b.copy_from_slice(&[c[0] * s, c[1] * s, c[2] * s]);
+            }
+        }
+
+        for (channel, wavelength) in CHANNEL_WAVELENGTHS.iter().enumerate() {
+            let light: Vec<Float> = bright.iter().skip(channel).step_by(3).copied().collect();
+            if light.iter().all(|&v| v == 0.0) {
+                continue;
+            }
+
+            // Convolve with the glare kernel scaled by wavelength.
+            let mut glare = vec![0.0; light.len()];
+            for &(width_scale, weight) in GLARE_LOBES.iter() {
+                let sigma = self.radius / 3.0 * width_scale * wavelength / 550.0;
+                let blurred = gaussian_blur(&light, width, height, sigma);
+                for (g, b) in glare.iter_mut().zip(blurred) {
+                    *g += weight * b;
+                }
+            }
+
+            // Replace the scattered fraction of the light with its glare.
+            for (i, (g, l)) in glare.iter().zip(light.iter()).enumerate() {
+                rgb[3 * i + channel] += self.intensity * (g - l);
+            }
+        }
+    }
+}
+
+/// Returns an image convolved with a normalized Gaussian. Light spread past
+/// the edges is lost.
+///
+/// * `img`    - Single channel image in row-major order.
+/// * `width`  - Width of the image in pixels.
+/// * `height` - Height of the image in pixels.
+/// * `sigma`  - Standard deviation of the Gaussian in pixels.
+fn gaussian_blur(img: &[Float], width: usize, height: usize, sigma: Float) -> Vec<Float> {
+    if sigma < 0.5 {
+        return img.to_vec();
+    }
+
+    // Normalized 1D kernel.
+    let half_width = (3.0 * sigma).ceil() as isize;
+    let mut kernel: Vec<Float> = (-half_width..=half_width)
+        .map(|i| (-((i * i) as Float) / (2.0 * sigma * sigma)).exp())
+        .collect();
+    let sum: Float = kernel.iter().sum();
+    kernel.iter_mut().for_each(|k| *k /= sum);
+
+    // Convolve rows and then columns.
+    let convolve = |src: &[Float], stride: usize, n: usize, lines: usize, line_stride: usize| {
+        let mut dst = vec![0.0; src.len()];
+        for line in 0..lines {
+            let base = line * line_stride;
+            for i in 0..n as isize {
+                let v = src[base + i as usize * stride];
+                if v == 0.0 {
+                    continue;
+                }
+                for (k, w) in kernel.iter().enumerate() {
+                    let j = i + k as isize - half_width;
+                    if j >= 0 && j < n as isize {
+                        dst[base + j as usize * stride] += v * w;
+                    }
+                }
+            }
+        }
+        dst
+    };
+    let rows = convolve(img, 1, width, height, width);
+    convolve(&rows, width, height, width, 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bloom_spreads_bright_light_and_conserves_it() {
+        let (width, height) = (41, 41);
+        let mut rgb = vec![0.5; 3 * width * height];
+        let centre = 3 * (20 * width + 20);
+        rgb[centre..centre + 3].copy_from_slice(&[100.0; 3]);
+        let total: Float = rgb.iter().sum();
+
+        let bloom = Bloom::new(0.5, 9.0, 1.0);
+        bloom.apply(&mut rgb, width, height, ColorSpace::SRGB);
+
+        // Light is moved from the bright pixel to its neighbours.
+        assert!(rgb[centre] < 100.0 && rgb[centre] > 50.0);
+        assert!(rgb[centre + 3] > 0.5);
+        assert!(rgb[3 * (20 * width + 23)] > rgb[3 * (20 * width + 30)]);
+
+        // Pixels far away are unchanged and no light is lost.
+        assert_eq!(rgb[0], 0.5);
+        let bloomed: Float = rgb.iter().sum();
+        assert!((bloomed - total).abs() < 1e-3 * total);
+
+        // Red is spread further than blue.
+        let far = 3 * (20 * width + 28);
+        assert!(rgb[far] > rgb[far + 2]);
+    }
+}
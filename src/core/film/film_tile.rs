@@ -1,6 +1,6 @@
 //! Film tile
 
-use crate::core::film::FILTER_TABLE_WIDTH;
+use crate::core::film::{AovPixel, FILTER_TABLE_WIDTH};
 use crate::core::geometry::*;
 use crate::core::pbrt::*;
 use crate::core::spectrum::*;
@@ -24,6 +24,24 @@ pub struct FilmTile<'a> {
 
     /// Maximum sample luminence.
     max_sample_luminance: Float,
+
+    /// Splat contributions in the order they were added. These are applied
+    /// to the `Film` when the tile is merged so that their summation order
+    /// does not depend on thread scheduling.
+    pub splats: Vec<(Point2f, Spectrum)>,
+
+    /// Contributions of all pixels in the tile for each light path expression
+    /// channel of the `Film`.
+    pub channels: Vec<Vec<FilmTilePixel>>,
+
+    /// Values of the pixels in the tile for each AOV of the `Film`.
+    pub aovs: Vec<Vec<AovPixel>>,
+
+    /// Variance of the pixels sampled for the tile.
+    pub variances: Vec<(Point2i, Float)>,
+
+    /// Number of samples taken for the pixels sampled for the tile.
+    pub sample_counts: Vec<(Point2i, usize)>,
 }
 
 impl<'a> FilmTile<'a> {
@@ -34,22 +52,32 @@ impl<'a> FilmTile<'a> {
     /// * `filter_table`         - Filter table.
     /// * `max_sample_luminance` - Optional maximum sample luminence to use use.
     ///                            Defaults to `INFINITY`.
+    /// * `n_channels`           - Number of light path expression channels.
+    /// * `n_aovs`               - Number of AOVs.
     pub fn new(
         pixel_bounds: Bounds2i,
         filter_radius: Vector2f,
         filter_table: &'a [Float],
         max_sample_luminance: Option<Float>,
+        n_channels: usize,
+        n_aovs: usize,
     ) -> Self {
+        let n_pixels = max(0, pixel_bounds.area() as usize);
         Self {
             pixel_bounds,
             filter_radius,
             inv_filter_radius: Vector2f::new(1.0 / filter_radius.x, 1.0 / filter_radius.y),
             filter_table,
-            pixels: vec![FilmTilePixel::default(); max(0, pixel_bounds.area() as usize)],
+            pixels: vec![FilmTilePixel::default(); n_pixels],
             max_sample_luminance: match max_sample_luminance {
                 Some(luminence) => luminence,
                 None => INFINITY,
             },
+            splats: vec![],
+            channels: vec![vec![FilmTilePixel::default(); n_pixels]; n_channels],
+            aovs: vec![vec![AovPixel::default(); n_pixels]; n_aovs],
+            variances: vec![],
+            sample_counts: vec![],
         }
     }
 
@@ -60,12 +88,34 @@ impl<'a> FilmTile<'a> {
     /// * `l`              - Radiance value `L`.
     /// * `sample_weight`  - Weight for the sample's contribution.
     pub fn add_sample(&mut self, p_film: Point2f, l: Spectrum, sample_weight: Float) {
+        self.add_sample_with_channels(p_film, l, 1.0, &[], sample_weight);
+    }
+
+    /// Add the radiance carried by a ray for a sample along with its alpha
+    /// and the radiance of each light path expression channel. Channels are
+    /// scaled like `l` when it exceeds the maximum sample luminance.
+    ///
+    /// * `p_film`         - Point on film.
+    /// * `l`              - Radiance value `L`.
+    /// * `alpha`          - Coverage of the sample; 0 if the camera ray
+    ///                      missed or hit a held out surface.
+    /// * `channels`       - Radiance of each channel; may be empty.
+    /// * `sample_weight`  - Weight for the sample's contribution.
+    pub fn add_sample_with_channels(
+        &mut self,
+        p_film: Point2f,
+        l: Spectrum,
+        alpha: Float,
+        channels: &[Spectrum],
+        sample_weight: Float,
+    ) {
         let ly = l.y();
-        let l = if ly > self.max_sample_luminance {
-            l * self.max_sample_luminance / ly
+        let luminance_scale = if ly > self.max_sample_luminance {
+            self.max_sample_luminance / ly
         } else {
-            l
+            1.0
         };
+        let l = l * luminance_scale;
 
         // Compute sample's raster bounds.
         let p_film_discrete = p_film - Vector2f::new(0.5, 0.5);
@@ -109,10 +159,60 @@ impl<'a> FilmTile<'a> {
 
                 self.pixels[pixel_offset].contrib_sum += l * sample_weight * filter_weight;
                 self.pixels[pixel_offset].filter_weight_sum += filter_weight;
+                self.pixels[pixel_offset].alpha_sum += alpha * sample_weight * filter_weight;
+
+                for (pixels, lc) in self.channels.iter_mut().zip(channels.iter()) {
+                    pixels[pixel_offset].contrib_sum +=
+                        *lc * luminance_scale * sample_weight * filter_weight;
+                    pixels[pixel_offset].filter_weight_sum += filter_weight;
+                }
             }
         }
     }
 
+    /// Add a splat contribution that will be applied to the `Film` when the
+    /// tile is merged.
+    ///
+    /// * `p` - The pixel coordinates with respect to the overall image.
+    /// * `v` - `Splat` contribution to add to the pixel.
+    pub fn add_splat(&mut self, p: Point2f, v: Spectrum) {
+        self.splats.push((p, v));
+    }
+
+    /// Add the AOVs of a sample that hit a surface to the pixel it belongs
+    /// to. They aren't filtered so that edges stay sharp.
+    ///
+    /// * `p`      - The pixel coordinates with respect to the overall image.
+    /// * `values` - Values of each AOV of the `Film`.
+    pub fn add_aov_sample(&mut self, p: &Point2i, values: &[[Float; 3]]) {
+        if !self.pixel_bounds.contains_exclusive(p) {
+            return;
+        }
+        let pixel_offset = self.get_pixel_offset(p);
+        for (pixels, v) in self.aovs.iter_mut().zip(values.iter()) {
+            pixels[pixel_offset].add(v);
+        }
+    }
+
+    /// Records the variance of a pixel sampled for the tile. It replaces the
+    /// variance of the pixel in the `Film` when the tile is merged.
+    ///
+    /// * `p`        - The pixel coordinates with respect to the overall image.
+    /// * `variance` - Variance of the pixel.
+    pub fn add_pixel_variance(&mut self, p: Point2i, variance: Float) {
+        self.variances.push((p, variance));
+    }
+
+    /// Records the number of samples taken for a pixel sampled for the tile.
+    /// It replaces the sample count of the pixel in the `Film` when the tile
+    /// is merged.
+    ///
+    /// * `p`     - The pixel coordinates with respect to the overall image.
+    /// * `count` - Number of samples.
+    pub fn add_pixel_sample_count(&mut self, p: Point2i, count: usize) {
+        self.sample_counts.push((p, count));
+    }
+
     /// Converts pixel coordinates with respect to the overall image and to
     /// coordinates in the film tile and returns the correspdoning pixel.
     ///
@@ -138,4 +238,7 @@ pub struct FilmTilePixel {
 
     /// Sum of filter weights.
     pub filter_weight_sum: Float,
+
+    /// Sum of weighted alpha values of the pixel samples.
+    pub alpha_sum: Float,
 }
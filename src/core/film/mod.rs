@@ -2,18 +2,33 @@
 
 #![allow(dead_code)]
 use crate::core::app::OPTIONS;
+use crate::core::camera_response::*;
+use crate::core::error::*;
+use crate::core::fileutil::*;
 use crate::core::filter::*;
 use crate::core::geometry::*;
 use crate::core::image_io::*;
+use crate::core::logging::FILM;
+use crate::core::lpe::*;
+use crate::core::parallel::AtomicFloat;
 use crate::core::paramset::*;
 use crate::core::pbrt::*;
+use crate::core::profiler::*;
 use crate::core::spectrum::*;
+use log::Level;
+use std::convert::TryFrom;
 use std::sync::{Arc, RwLock};
 
+mod aov;
+mod bloom;
 mod film_tile;
+mod warm_start;
 
 // Re-export.
+pub use aov::*;
+pub use bloom::*;
 pub use film_tile::*;
+pub use warm_start::*;
 
 /// Filter table width.
 pub const FILTER_TABLE_WIDTH: usize = 16;
@@ -36,12 +51,8 @@ pub struct Pixel {
     /// the pixel.
     pub filter_weight_sum: Float,
 
-    /// Holds an unweighted sum of sample splats.
-    pub splat_xyz: [Float; 3],
-
-    /// Used to pad this struct to the 32-bit/64-bit. This will work for both
-    /// `Float` => `f32` and `Float` => `f64`.
-    pad: Float,
+    /// Holds the weighted sum of sample alpha values.
+    pub alpha_sum: Float,
 }
 
 /// Models the sensing device in a simulated camera. It stores all of the sample
@@ -57,7 +68,7 @@ pub struct Film {
     /// Filter function to use for image reconstruction from samples.
     pub filter: ArcFilter,
 
-    /// Filename of output image.
+    /// Filename of output image. The image is kept in memory if it is empty.
     pub filename: String,
 
     /// Crop window of the subset of the image to render.
@@ -72,8 +83,82 @@ pub struct Film {
     /// Maximum sample luminence.
     max_sample_luminance: Float,
 
+    /// Color space of the output image.
+    color_space: ColorSpace,
+
+    /// Optional chromatic adaptation applied to XYZ pixel values.
+    white_balance: Option<Matrix3x3>,
+
     /// Stores the image pixels.
     pixels: Arc<RwLock<Vec<Pixel>>>,
+
+    /// Holds an unweighted sum of sample splats for each pixel using XYZ
+    /// colors. Threads add to them without locking the pixels.
+    splat_xyz: Arc<Vec<[AtomicFloat; 3]>>,
+
+    /// Light path expression channels written as separate images.
+    pub lpe_channels: Arc<Vec<LpeChannel>>,
+
+    /// Stores the pixels of each light path expression channel.
+    channel_pixels: Arc<RwLock<Vec<Vec<Pixel>>>>,
+
+    /// AOVs written as separate images.
+    pub aovs: Vec<Aov>,
+
+    /// Stores the pixels of each AOV.
+    aov_pixels: Arc<RwLock<Vec<Vec<AovPixel>>>>,
+
+    /// Write an alpha channel with the output image.
+    pub alpha: bool,
+
+    /// Optional glare around bright parts of the image applied when it is
+    /// written.
+    pub bloom: Option<Bloom>,
+
+    /// Optional camera response used instead of gamma correction for 8-bit
+    /// images.
+    pub camera_response: Option<Arc<CameraResponse>>,
+
+    /// Write the cropped pixel bounds into the existing output image, keeping
+    /// its other pixels, instead of writing the cropped image.
+    pub region: bool,
+
+    /// Transfer function and dithering of 8-bit images.
+    pub ldr: LdrOptions,
+
+    /// Pixel type and compression of OpenEXR images.
+    pub exr: ExrOptions,
+
+    /// Optional number of samples per pixel derived from a previous render.
+    pub warm_start: Option<Arc<WarmStart>>,
+
+    /// Write the variance of each pixel to an image next to the output image.
+    pub write_variance: bool,
+
+    /// Stores the variance of each pixel if `write_variance` is set.
+    variance: Arc<RwLock<Vec<Float>>>,
+
+    /// Write a heatmap of the number of samples taken for each pixel to an
+    /// image next to the output image.
+    pub write_sample_count: bool,
+
+    /// Stores the number of samples of each pixel if `write_sample_count` is
+    /// set.
+    sample_counts: Arc<RwLock<Vec<usize>>>,
+}
+
+/// Returns the factor that maps luminance in cd/m^2 to pixel values for a
+/// camera with the given settings, using the saturation based sensitivity of
+/// the sensor. A pixel value of 1 is where the sensor saturates, e.g. a scene
+/// lit by the sun is exposed well at ISO 100, 1/100 s and f/16.
+///
+/// * `iso`           - Sensitivity of the sensor.
+/// * `exposure_time` - Time the shutter is open in seconds.
+/// * `f_number`      - Ratio of the focal length to the aperture diameter.
+pub fn photometric_exposure(iso: Float, exposure_time: Float, f_number: Float) -> Float {
+    // The luminance that saturates the sensor is 78 / (S q) N^2 / t with the
+    // lens and vignetting factor q = 0.65.
+    exposure_time * iso / (1.2 * 100.0 * f_number * f_number)
 }
 
 impl Film {
@@ -90,6 +175,28 @@ impl Film {
     ///                            None specified, sets to 1.0.
     /// * `max_sample_luminance` - Optional maximum sample luminence to use use.
     ///                            Defaults to `INFINITY`.
+    /// * `color_space`          - Optional color space of the output image.
+    ///                            Defaults to the rendering color space.
+    /// * `white_point`          - Optional chromaticity (x, y) of the scene
+    ///                            illuminant that is adapted to the white point
+    ///                            of the output color space.
+    /// * `lpe_channels`         - Light path expression channels written as
+    ///                            separate images.
+    /// * `aovs`                 - AOVs written as separate images.
+    /// * `alpha`                - Write an alpha channel with the output image.
+    /// * `bloom`                - Optional glare around bright parts of the
+    ///                            image applied when it is written.
+    /// * `camera_response`      - Optional camera response used instead of
+    ///                            gamma correction for 8-bit images.
+    /// * `pixel_bounds`         - Optional bounds of the pixels to render
+    ///                            instead of the crop window.
+    /// * `warm_start`           - Optional number of samples per pixel derived
+    ///                            from a previous render.
+    /// * `write_variance`       - Write the variance of each pixel to an image
+    ///                            next to the output image.
+    /// * `write_sample_count`   - Write a heatmap of the number of samples
+    ///                            taken for each pixel to an image next to the
+    ///                            output image.
     pub fn new(
         resolution: &Point2i,
         crop_window: &Bounds2f,
@@ -98,18 +205,32 @@ impl Film {
         filename: &str,
         scale: Option<Float>,
         max_sample_luminance: Option<Float>,
+        color_space: Option<ColorSpace>,
+        white_point: Option<[Float; 2]>,
+        lpe_channels: Vec<LpeChannel>,
+        aovs: Vec<Aov>,
+        alpha: bool,
+        bloom: Option<Bloom>,
+        camera_response: Option<Arc<CameraResponse>>,
+        pixel_bounds: Option<Bounds2i>,
+        warm_start: Option<Arc<WarmStart>>,
+        write_variance: bool,
+        write_sample_count: bool,
     ) -> Self {
         // Compute the film image bounds.
-        let cropped_pixel_bounds = Bounds2i::new(
-            Point2i::new(
-                (resolution.x as Float * crop_window.p_min.x).ceil() as Int,
-                (resolution.y as Float * crop_window.p_min.y).ceil() as Int,
-            ),
-            Point2i::new(
-                (resolution.x as Float * crop_window.p_max.x).ceil() as Int,
-                (resolution.y as Float * crop_window.p_max.y).ceil() as Int,
+        let cropped_pixel_bounds = match pixel_bounds {
+            Some(bounds) => bounds.intersect(&Bounds2i::new(Point2i::new(0, 0), *resolution)),
+            None => Bounds2i::new(
+                Point2i::new(
+                    (resolution.x as Float * crop_window.p_min.x).ceil() as Int,
+                    (resolution.y as Float * crop_window.p_min.y).ceil() as Int,
+                ),
+                Point2i::new(
+                    (resolution.x as Float * crop_window.p_max.x).ceil() as Int,
+                    (resolution.y as Float * crop_window.p_max.y).ceil() as Int,
+                ),
             ),
-        );
+        };
 
         // Precompute filter weight table.
         let filter = filter.clone();
@@ -127,9 +248,19 @@ impl Film {
             }
         }
 
+        let color_space = color_space.unwrap_or_else(rendering_color_space);
+
         // Allocate film image storage.
         let n = cropped_pixel_bounds.area() as usize;
         let pixels = Arc::new(RwLock::new(vec![Pixel::default(); n]));
+        let splat_xyz = Arc::new((0..n).map(|_| Default::default()).collect());
+        let channel_pixels = Arc::new(RwLock::new(vec![
+            vec![Pixel::default(); n];
+            lpe_channels.len()
+        ]));
+        let aov_pixels = Arc::new(RwLock::new(vec![vec![AovPixel::default(); n]; aovs.len()]));
+        let variance = Arc::new(RwLock::new(vec![0.0; if write_variance { n } else { 0 }]));
+        let sample_counts = Arc::new(RwLock::new(vec![0; if write_sample_count { n } else { 0 }]));
 
         Self {
             full_resolution: *resolution,
@@ -146,7 +277,25 @@ impl Film {
                 Some(luminence) => luminence,
                 None => INFINITY,
             },
+            color_space,
+            white_balance: white_point.map(|w| white_balance(w, color_space.chromaticities().3)),
             pixels,
+            splat_xyz,
+            lpe_channels: Arc::new(lpe_channels),
+            channel_pixels,
+            aovs,
+            aov_pixels,
+            alpha,
+            bloom,
+            camera_response,
+            region: false,
+            ldr: LdrOptions::default(),
+            exr: ExrOptions::default(),
+            warm_start,
+            write_variance,
+            variance,
+            write_sample_count,
+            sample_counts,
         }
     }
 
@@ -207,15 +356,15 @@ impl Film {
             filter_data.radius,
             &self.filter_table,
             Some(self.max_sample_luminance),
+            self.lpe_channels.len(),
+            self.aovs.len(),
         ))
     }
 
     /// Clear the splats for all pixels in the image.
     pub fn clear(&mut self) {
-        let mut pixels = self.pixels.write().unwrap();
-        for pixel in self.cropped_pixel_bounds {
-            let pixel_offset = self.get_pixel_offset(&pixel);
-            (*pixels)[pixel_offset].splat_xyz = [0.0; 3];
+        for splat_xyz in self.splat_xyz.iter() {
+            splat_xyz.iter().for_each(|v| v.store(0.0));
         }
     }
 
@@ -223,18 +372,84 @@ impl Film {
     ///
     /// * `tile` - The `FilmTile` to merge.
     pub fn merge_film_tile(&self, tile: Arc<FilmTile>) {
-        let mut pixels = self.pixels.write().unwrap();
-        for pixel in tile.get_pixel_bounds() {
-            let tile_pixel = tile.get_pixel_offset(&pixel);
-            let merge_pixel = self.get_pixel_offset(&pixel);
-            let xyz = tile.pixels[tile_pixel].contrib_sum.to_xyz();
-            for i in 0..3 {
-                (*pixels)[merge_pixel].xyz[i] += xyz[i];
+        {
+            let mut pixels = self.pixels.write().unwrap();
+            for pixel in tile.get_pixel_bounds() {
+                let tile_pixel = tile.get_pixel_offset(&pixel);
+                let merge_pixel = self.get_pixel_offset(&pixel);
+                let xyz = tile.pixels[tile_pixel].contrib_sum.to_xyz();
+                for i in 0..3 {
+                    (*pixels)[merge_pixel].xyz[i] += xyz[i];
+                }
+                (*pixels)[merge_pixel].filter_weight_sum +=
+                    tile.pixels[tile_pixel].filter_weight_sum;
+                (*pixels)[merge_pixel].alpha_sum += tile.pixels[tile_pixel].alpha_sum;
+            }
+        }
+
+        if !tile.channels.is_empty() {
+            let mut channel_pixels = self.channel_pixels.write().unwrap();
+            for (pixels, tile_pixels) in channel_pixels.iter_mut().zip(tile.channels.iter()) {
+                for pixel in tile.get_pixel_bounds() {
+                    let tile_pixel = &tile_pixels[tile.get_pixel_offset(&pixel)];
+                    let merge_pixel = &mut pixels[self.get_pixel_offset(&pixel)];
+                    for (v, c) in merge_pixel
+                        .xyz
+                        .iter_mut()
+                        .zip(tile_pixel.contrib_sum.to_xyz())
+                    {
+                        *v += c;
+                    }
+                    merge_pixel.filter_weight_sum += tile_pixel.filter_weight_sum;
+                }
             }
-            (*pixels)[merge_pixel].filter_weight_sum += tile.pixels[tile_pixel].filter_weight_sum;
+        }
+
+        if !tile.aovs.is_empty() {
+            let mut aov_pixels = self.aov_pixels.write().unwrap();
+            for (pixels, tile_pixels) in aov_pixels.iter_mut().zip(tile.aovs.iter()) {
+                for pixel in tile.get_pixel_bounds() {
+                    let tile_pixel = &tile_pixels[tile.get_pixel_offset(&pixel)];
+                    pixels[self.get_pixel_offset(&pixel)].merge(tile_pixel);
+                }
+            }
+        }
+
+        if self.write_variance && !tile.variances.is_empty() {
+            let mut variance = self.variance.write().unwrap();
+            for (p, v) in tile.variances.iter() {
+                if self.cropped_pixel_bounds.contains_exclusive(p) {
+                    variance[self.get_pixel_offset(p)] = *v;
+                }
+            }
+        }
+
+        if self.write_sample_count && !tile.sample_counts.is_empty() {
+            let mut sample_counts = self.sample_counts.write().unwrap();
+            for (p, n) in tile.sample_counts.iter() {
+                if self.cropped_pixel_bounds.contains_exclusive(p) {
+                    sample_counts[self.get_pixel_offset(p)] = *n;
+                }
+            }
+        }
+
+        for (p, v) in tile.splats.iter() {
+            self.add_splat(p, v);
         }
     }
 
+    /// Returns the number of samples to take for a pixel; fewer than the
+    /// samples per pixel of the sampler for pixels that were smooth in the
+    /// previous render when warm starting.
+    ///
+    /// * `p`                 - The pixel.
+    /// * `samples_per_pixel` - Number of samples per pixel of the sampler.
+    pub fn pixel_samples(&self, p: &Point2i, samples_per_pixel: usize) -> usize {
+        self.warm_start
+            .as_ref()
+            .map_or(samples_per_pixel, |w| w.pixel_samples(p, samples_per_pixel))
+    }
+
     /// Sets all pixel values in the cropped area with the given spectrum values.
     ///
     /// * `img` - The spectrum values for the cropped area.
@@ -244,17 +459,22 @@ impl Film {
         for i in (0..n_pixels).map(|i| i as usize) {
             (*pixels)[i].xyz = img[i].to_xyz();
             (*pixels)[i].filter_weight_sum = 1.0;
-            (*pixels)[i].splat_xyz = [0.0; 3];
+            (*pixels)[i].alpha_sum = 1.0;
+            self.splat_xyz[i].iter().for_each(|v| v.store(0.0));
         }
     }
 
-    /// Add `splat` contributions to a pixel.
+    /// Add `splat` contributions to a pixel, e.g. from light paths that
+    /// reach the camera. It can be called from any thread while rendering;
+    /// the splats are scaled by the `splat_scale` of `write_image()`.
     ///
     /// * `p` - The pixel coordinates with respect to the overall image.
     /// * `v` - `Splat` contribution to add to the pixel.
     pub fn add_splat(&self, p: &Point2f, v: &Spectrum) {
         if v.has_nans() {
-            warn!(
+            log_limited!(
+                Level::Warn,
+                target: FILM,
                 "Ignoring splatted spectrum with NaN values at ({}, {})",
                 p.x, p.y
             );
@@ -263,18 +483,20 @@ impl Film {
 
         let vy = v.y();
         if vy < 0.0 {
-            warn!(
+            log_limited!(
+                Level::Warn,
+                target: FILM,
                 "Ignoring splatted spectrum with negative luminance {} at ({}, {})",
                 vy, p.x, p.y
             );
         } else if vy.is_infinite() {
-            warn!(
+            log_limited!(
+                Level::Warn,
+                target: FILM,
                 "Ignoring splatted spectrum with infinite luminance at ({}, {})",
                 p.x, p.y
             );
         } else {
-            let mut pixels = self.pixels.write().unwrap();
-
             let pi = Point2i::from(p.floor());
             if !self.cropped_pixel_bounds.contains_exclusive(&pi) {
                 return;
@@ -286,31 +508,117 @@ impl Film {
                 *v
             };
 
-            let xyz = v.to_xyz();
             let pixel_offset = self.get_pixel_offset(&pi);
-            for i in 0..3 {
-                (*pixels)[pixel_offset].splat_xyz[i] += xyz[i];
+            for (splat, c) in self.splat_xyz[pixel_offset].iter().zip(v.to_xyz()) {
+                splat.add(c);
             }
         }
     }
 
-    /// Write the image to an output file.
+    /// Returns the white balanced RGB values in the output color space for the
+    /// given XYZ values.
+    ///
+    /// * `xyz` - The XYZ values.
+    fn xyz_to_output_rgb(&self, xyz: &[Float; 3]) -> [Float; 3] {
+        match self.white_balance.as_ref() {
+            Some(m) => self.color_space.xyz_to_rgb(&mul_3x3(m, xyz)),
+            None => self.color_space.xyz_to_rgb(xyz),
+        }
+    }
+
+    /// Returns the final weighted RGB values in the output color space for
+    /// the pixels in a region of the image in row-major order.
     ///
+    /// * `bounds`      - The region; it is clipped to the cropped image.
     /// * `splat_scale` - Scale factor for `add_splat()` (default = 1.0).
-    pub fn write_image(&self, splat_scale: Float) {
-        info!("Converting image to RGB and computing final weighted pixel values");
+    pub fn get_rgb(&self, bounds: &Bounds2i, splat_scale: Float) -> Vec<Float> {
+        let pixels = self.pixels.read().unwrap();
+        self.pixels_to_rgb(&pixels, bounds, splat_scale)
+    }
 
-        let mut pixels = self.pixels.write().unwrap();
+    /// Returns the final weighted alpha values for the pixels in a region of
+    /// the image in row-major order. Pixels without samples are transparent.
+    ///
+    /// * `bounds` - The region; it is clipped to the cropped image.
+    pub fn get_alpha(&self, bounds: &Bounds2i) -> Vec<Float> {
+        let bounds = bounds.intersect(&self.cropped_pixel_bounds);
+        if bounds.is_empty() || bounds.area() == 0 {
+            return vec![];
+        }
 
-        let n = 3 * self.cropped_pixel_bounds.area() as usize;
+        let pixels = self.pixels.read().unwrap();
+        bounds
+            .into_iter()
+            .map(|p| {
+                let pixel = &(*pixels)[self.get_pixel_offset(&p)];
+                if pixel.filter_weight_sum != 0.0 {
+                    clamp(pixel.alpha_sum / pixel.filter_weight_sum, 0.0, 1.0)
+                } else {
+                    0.0
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the final weighted RGB values in the output color space for
+    /// the pixels of a light path expression channel in a region of the image
+    /// in row-major order.
+    ///
+    /// * `channel` - Index of the channel in `lpe_channels`.
+    /// * `bounds`  - The region; it is clipped to the cropped image.
+    pub fn get_channel_rgb(&self, channel: usize, bounds: &Bounds2i) -> Vec<Float> {
+        let channel_pixels = self.channel_pixels.read().unwrap();
+        self.pixels_to_rgb(&channel_pixels[channel], bounds, 0.0)
+    }
+
+    /// Returns the average values of an AOV for the pixels in a region of the
+    /// image in row-major order; the values of the first sample for AOVs that
+    /// aren't filtered. The albedo is in the output color space; the other
+    /// AOVs aren't scaled or converted.
+    ///
+    /// * `aov`    - Index of the AOV in `aovs`.
+    /// * `bounds` - The region; it is clipped to the cropped image.
+    pub fn get_aov_values(&self, aov: usize, bounds: &Bounds2i) -> Vec<Float> {
+        let bounds = bounds.intersect(&self.cropped_pixel_bounds);
+        if bounds.is_empty() || bounds.area() == 0 {
+            return vec![];
+        }
+
+        let aov_pixels = self.aov_pixels.read().unwrap();
+        bounds
+            .into_iter()
+            .flat_map(|p| {
+                let pixel = &aov_pixels[aov][self.get_pixel_offset(&p)];
+                match self.aovs[aov] {
+                    Aov::Albedo => self.xyz_to_output_rgb(&pixel.average()),
+                    aov if !aov.is_filtered() => pixel.first,
+                    _ => pixel.average(),
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the final weighted RGB values in the output color space for
+    /// pixels in a region of the image in row-major order.
+    ///
+    /// * `pixels`      - The pixels of the cropped image.
+    /// * `bounds`      - The region; it is clipped to the cropped image.
+    /// * `splat_scale` - Scale factor for `add_splat()`.
+    fn pixels_to_rgb(&self, pixels: &[Pixel], bounds: &Bounds2i, splat_scale: Float) -> Vec<Float> {
+        let bounds = bounds.intersect(&self.cropped_pixel_bounds);
+        if bounds.is_empty() || bounds.area() == 0 {
+            return vec![];
+        }
+
+        let n = 3 * bounds.area() as usize;
         let mut rgb = vec![0.0; n];
 
         let mut offset = 0;
-        for p in self.cropped_pixel_bounds {
+        for p in bounds {
             // Convert pixel XYZ color to RGB.
             let pixel_offset = self.get_pixel_offset(&p);
-            (*pixels)[pixel_offset].xyz =
-                xyz_to_rgb(&[rgb[3 * offset], rgb[3 * offset + 1], rgb[3 * offset + 2]]);
+            let pixel_rgb = self.xyz_to_output_rgb(&(*pixels)[pixel_offset].xyz);
+            rgb[3 * offset..3 * offset + 3].copy_from_slice(&pixel_rgb);
 
             // Normalize pixel with weight sum.
             let filter_weight_sum = (*pixels)[pixel_offset].filter_weight_sum;
@@ -322,10 +630,18 @@ impl Film {
             }
 
             // Add splat value at pixel.
-            let splat_rgb = xyz_to_rgb(&(*pixels)[pixel_offset].splat_xyz);
-            rgb[3 * offset] += splat_scale * splat_rgb[0];
-            rgb[3 * offset + 1] += splat_scale * splat_rgb[1];
-            rgb[3 * offset + 2] += splat_scale * splat_rgb[2];
+            if splat_scale != 0.0 {
+                let splat_xyz = &self.splat_xyz[pixel_offset];
+                let xyz = [
+                    splat_xyz[0].load(),
+                    splat_xyz[1].load(),
+                    splat_xyz[2].load(),
+                ];
+                let splat_rgb = self.xyz_to_output_rgb(&xyz);
+                rgb[3 * offset] += splat_scale * splat_rgb[0];
+                rgb[3 * offset + 1] += splat_scale * splat_rgb[1];
+                rgb[3 * offset + 2] += splat_scale * splat_rgb[2];
+            }
 
             // Scale pixel value by `scale`.
             rgb[3 * offset] *= self.scale;
@@ -335,18 +651,131 @@ impl Film {
             offset += 1;
         }
 
+        rgb
+    }
+
+    /// Write the image to an output file in the output color space; or returns
+    /// an error if the file could not be written. Nothing is written if
+    /// `filename` is empty.
+    ///
+    /// * `splat_scale` - Scale factor for `add_splat()` (default = 1.0).
+    pub fn write_image(&self, splat_scale: Float) -> Result<(), Error> {
+        if self.filename.is_empty() {
+            return Ok(());
+        }
+        self.write_image_to(&self.filename, splat_scale)
+    }
+
+    /// Write the image to the given output file, and any images written next
+    /// to it, in the output color space; or returns an error if the file
+    /// could not be written.
+    ///
+    /// * `filename`    - Filename of output image.
+    /// * `splat_scale` - Scale factor for `add_splat()` (default = 1.0).
+    pub fn write_image_to(&self, filename: &str, splat_scale: Float) -> Result<(), Error> {
+        let _p = ProfilePhase::new(Prof::ImageWrite);
+        info!(target: FILM, "Converting image to RGB and computing final weighted pixel values");
+        let mut rgb = self.get_rgb(&self.cropped_pixel_bounds, splat_scale);
+        if let Some(bloom) = self.bloom.as_ref() {
+            let resolution = self.cropped_pixel_bounds.diagonal();
+            bloom.apply(
+                &mut rgb,
+                resolution.x as usize,
+                resolution.y as usize,
+                self.color_space,
+            );
+        }
+        let alpha = if self.alpha {
+            Some(self.get_alpha(&self.cropped_pixel_bounds))
+        } else {
+            None
+        };
+
         // Write RGB image
-        if let Err(err) = write_image(&self.filename, &rgb, &self.cropped_pixel_bounds) {
-            panic!("Error writing output image {}. {:}.", self.filename, err);
+        self.write_pixels(filename, &rgb, alpha.as_deref())?;
+
+        // Write the variance of the luminance of each pixel next to it,
+        // scaled like the pixel values.
+        if self.write_variance {
+            let scale = self.scale * self.scale;
+            let rgb: Vec<Float> = self
+                .variance
+                .read()
+                .unwrap()
+                .iter()
+                .flat_map(|&v| [v * scale; 3])
+                .collect();
+            self.write_pixels(&variance_filename(filename), &rgb, None)?;
+        }
+
+        // Write a heatmap of the number of samples of each pixel next to it,
+        // e.g. `image_samples.png` for `image.png`.
+        if self.write_sample_count {
+            let rgb = sample_count_heatmap(&self.sample_counts.read().unwrap());
+            self.write_pixels(&suffixed_path(filename, "samples"), &rgb, None)?;
+        }
+
+        // Write light path expression channels next to it.
+        for (i, channel) in self.lpe_channels.iter().enumerate() {
+            let rgb = self.get_channel_rgb(i, &self.cropped_pixel_bounds);
+            let filename = suffixed_path(filename, &channel.name);
+            self.write_pixels(&filename, &rgb, None)?;
+        }
+
+        // Write the AOVs next to it, e.g. `image_normal.exr`.
+        for (i, aov) in self.aovs.iter().enumerate() {
+            let values = self.get_aov_values(i, &self.cropped_pixel_bounds);
+            let filename = suffixed_path(filename, aov.name());
+            self.write_pixels(&filename, &values, None)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the pixels of the cropped image to a file; or into the region
+    /// of an existing image when re-rendering a region.
+    ///
+    /// * `filename` - Filename of the image.
+    /// * `rgb`      - RGB values of the pixels.
+    /// * `alpha`    - Optional alpha values of the pixels.
+    fn write_pixels(
+        &self,
+        filename: &str,
+        rgb: &[Float],
+        alpha: Option<&[Float]>,
+    ) -> Result<(), Error> {
+        let response = self.camera_response.as_deref();
+        if self.region {
+            write_image_region(
+                filename,
+                rgb,
+                alpha,
+                response,
+                &self.ldr,
+                &self.exr,
+                &self.cropped_pixel_bounds,
+                &self.full_resolution,
+            )
+        } else {
+            write_image(
+                filename,
+                rgb,
+                alpha,
+                response,
+                &self.ldr,
+                &self.exr,
+                &self.cropped_pixel_bounds,
+            )
         }
     }
 }
 
-impl From<(&ParamSet, ArcFilter)> for Film {
-    /// Create a `BVHAccel` from given parameter set and filter.
+impl TryFrom<(&ParamSet, ArcFilter)> for Film {
+    type Error = Error;
+
+    /// Create a `Film` from given parameter set and filter.
     ///
     /// * `p` - Tuple containing the parameter set and filter.
-    fn from(p: (&ParamSet, ArcFilter)) -> Self {
+    fn try_from(p: (&ParamSet, ArcFilter)) -> Result<Self, Self::Error> {
         let (params, filter) = p;
 
         let image_file = &OPTIONS.image_file[..];
@@ -354,7 +783,8 @@ impl From<(&ParamSet, ArcFilter)> for Film {
             let params_filename = params.find_one_string("filename", String::from(""));
             if params_filename.len() > 0 {
                 warn!(
-                    "Output filename supplied on command line, '{}' is overriding 
+                    target: FILM,
+                    "Output filename supplied on command line, '{}' is overriding
                     filename provided in scene description file, '{}'.",
                     OPTIONS.image_file, params_filename
                 );
@@ -373,33 +803,230 @@ impl From<(&ParamSet, ArcFilter)> for Film {
             yres = max(1, yres / 4);
         }
 
-        let cr = params.find_float("cropwindow");
+        // Crop window x0, x1, y0, y1 in NDC space; the command line takes
+        // precedence.
+        let cr = match OPTIONS.crop_window {
+            Some(c) => vec![c[0][0], c[0][1], c[1][0], c[1][1]],
+            None => params.find_float("cropwindow").to_vec(),
+        };
         let cwi = cr.len();
-        let mut crop = Bounds2f::default();
+        let mut crop = Bounds2f::new(Point2f::new(0.0, 0.0), Point2f::new(1.0, 1.0));
         if cwi == 4 {
             crop.p_min.x = clamp(min(cr[0], cr[1]), 0.0, 1.0);
             crop.p_max.x = clamp(max(cr[0], cr[1]), 0.0, 1.0);
             crop.p_min.y = clamp(min(cr[2], cr[3]), 0.0, 1.0);
             crop.p_max.y = clamp(max(cr[2], cr[3]), 0.0, 1.0);
+            if crop.p_min.x == crop.p_max.x || crop.p_min.y == crop.p_max.y {
+                return Err(Error::InvalidParameter(format!(
+                    "Crop window [{} {} {} {}] is empty.",
+                    cr[0], cr[1], cr[2], cr[3]
+                )));
+            }
         } else if cwi > 0 {
-            panic!("{} values supplied for 'cropwindow'. Expected 4.", cwi);
-        } else {
-            crop = Bounds2f::new(
-                Point2f::new(
-                    clamp(OPTIONS.crop_window[0][0], 0.0, 1.0),
-                    clamp(OPTIONS.crop_window[1][0], 0.0, 1.0),
-                ),
-                Point2f::new(
-                    clamp(OPTIONS.crop_window[0][1], 0.0, 1.0),
-                    clamp(OPTIONS.crop_window[1][1], 0.0, 1.0),
-                ),
-            );
+            return Err(Error::InvalidParameter(format!(
+                "{} values supplied for 'cropwindow'. Expected 4.",
+                cwi
+            )));
+        }
+
+        let mut scale = params.find_one_float("scale", 1.0);
+
+        // Expose the image like a camera with the given sensitivity, shutter
+        // speed and aperture when any of them is given.
+        let iso = params.find_one_float("iso", 100.0);
+        let exposure_time = params.find_one_float("exposuretime", 1.0);
+        let f_number = params.find_one_float("fnumber", 1.0);
+        if !params.find_float("iso").is_empty()
+            || !params.find_float("exposuretime").is_empty()
+            || !params.find_float("fnumber").is_empty()
+        {
+            if iso <= 0.0 || exposure_time <= 0.0 || f_number <= 0.0 {
+                return Err(Error::InvalidParameter(format!(
+                    "'iso' [{}], 'exposuretime' [{}] and 'fnumber' [{}] must be positive.",
+                    iso, exposure_time, f_number
+                )));
+            }
+            scale *= photometric_exposure(iso, exposure_time, f_number);
         }
 
-        let scale = params.find_one_float("scale", 1.0);
         let diagonal = params.find_one_float("diagonal", 35.0);
         let max_sample_luminance = params.find_one_float("maxsampleluminance", INFINITY);
-        Self::new(
+
+        let color_space_name = params.find_one_string("colorspace", String::from(""));
+        let color_space = if color_space_name.is_empty() {
+            None
+        } else {
+            let color_space = ColorSpace::from_name(&color_space_name);
+            if color_space.is_none() {
+                warn!(
+                    target: FILM,
+                    "Unknown colorspace '{}'. Using rendering color space.",
+                    color_space_name
+                );
+            }
+            color_space
+        };
+
+        // White balance for an illuminant given by a color temperature or a
+        // spectrum; the latter takes precedence.
+        let white_balance_temp = params.find_one_float("whitebalance", 0.0);
+        let white_point = match params.find_spectrum("whitebalanceilluminant").first() {
+            Some(illuminant) => Some(xyz_to_xy(&illuminant.to_xyz())),
+            None if white_balance_temp > 0.0 => Some(xyz_to_xy(
+                &illuminant_for_temperature(white_balance_temp).to_xyz(),
+            )),
+            None => None,
+        };
+        // Light path expression channels as pairs of names and expressions.
+        let lpe = params.find_string("lpe");
+        if lpe.len() % 2 != 0 {
+            return Err(Error::InvalidParameter(format!(
+                "{} values supplied for 'lpe'. Expected pairs of names and expressions.",
+                lpe.len()
+            )));
+        }
+        let aovs = params
+            .find_string("aovs")
+            .iter()
+            .map(|name| {
+                Aov::from_name(name).ok_or_else(|| {
+                    Error::InvalidParameter(format!(
+                        "Unknown AOV '{}'; must be 'albedo', 'normal', 'depth', 'position' or 'primid'.",
+                        name
+                    ))
+                })
+            })
+            .collect::<Result<Vec<Aov>, Error>>()?;
+
+        let lpe_channels = lpe
+            .chunks(2)
+            .map(|c| LpeChannel::new(&c[0], &c[1]))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let alpha = params.find_one_bool("alpha", false);
+
+        // Bloom is off unless an intensity is given. The default radius is 1%
+        // of the image diagonal.
+        let bloom_intensity = params.find_one_float("bloomintensity", 0.0);
+        let bloom = if bloom_intensity > 0.0 {
+            let diagonal = (xres as Float).hypot(yres as Float);
+            Some(Bloom::new(
+                bloom_intensity,
+                params.find_one_float("bloomradius", 0.01 * diagonal),
+                params.find_one_float("bloomthreshold", 1.0),
+            ))
+        } else {
+            None
+        };
+
+        // Pixels x0, x1, y0, y1, excluding x1 and y1, rendered instead of the
+        // crop window; the command line takes precedence. A region is
+        // re-rendered into the existing image.
+        let image = Bounds2i::new(Point2i::new(0, 0), Point2i::new(xres, yres));
+        let find_pixel_bounds = |name: &str, option: Option<[Int; 4]>| {
+            let r = match option {
+                Some(r) => r.to_vec(),
+                None => params.find_int(name).to_vec(),
+            };
+            match r.len() {
+                0 => Ok(None),
+                4 => {
+                    let bounds = Bounds2i::new(Point2i::new(r[0], r[2]), Point2i::new(r[1], r[3]));
+                    if bounds.intersect(&image).area() <= 0 {
+                        return Err(Error::InvalidParameter(format!(
+                            "'{}' [{} {} {} {}] doesn't cover any pixels of the {}x{} image.",
+                            name, r[0], r[1], r[2], r[3], xres, yres
+                        )));
+                    }
+                    Ok(Some(bounds))
+                }
+                n => Err(Error::InvalidParameter(format!(
+                    "{} values supplied for '{}'. Expected 4.",
+                    n, name
+                ))),
+            }
+        };
+        let region = find_pixel_bounds("region", OPTIONS.region)?;
+        let pixel_bounds = match region {
+            Some(region) => Some(region),
+            None => find_pixel_bounds("pixelbounds", OPTIONS.pixel_bounds)?,
+        };
+
+        // Write the rendered pixels into the existing image instead of
+        // writing a cropped image.
+        let keep_uncropped = region.is_some()
+            || OPTIONS.keep_uncropped
+            || params.find_one_bool("keepuncropped", false);
+
+        // Concentrate samples where a previous render was noisy. Its variance
+        // is read from the image written next to it with `writevariance`.
+        let warm_start_image = params.find_one_string("warmstart", String::from(""));
+        let warm_start = if warm_start_image.is_empty() {
+            None
+        } else {
+            let variance =
+                params.find_one_string("warmstartvariance", variance_filename(&warm_start_image));
+            Some(Arc::new(WarmStart::from_files(
+                &params.find_one_filename("warmstart", warm_start_image),
+                &params.find_one_filename("warmstartvariance", variance),
+                Point2i::new(xres, yres),
+                params.find_one_float("warmstartminfraction", 0.1),
+            )?))
+        };
+        let write_variance = params.find_one_bool("writevariance", false);
+        let write_sample_count = params.find_one_bool("writesamplecount", false);
+
+        // Camera response curves from the DoRF database, the EMoR basis or a
+        // lookup table.
+        let camera_response_file = params.find_one_string("cameraresponse", String::from(""));
+        let camera_response = if camera_response_file.is_empty() {
+            None
+        } else {
+            let path = params.find_one_filename("cameraresponse", camera_response_file);
+            Some(Arc::new(CameraResponse::from_file(
+                &path,
+                params.find_string("cameraresponsecurves"),
+                params.find_float("emorcoefficients"),
+            )?))
+        };
+
+        // 8-bit images are encoded with the sRGB curve unless a gamma is
+        // given; the command line takes precedence.
+        let gamma = match OPTIONS.gamma {
+            Some(gamma) => Some(gamma),
+            None => params.find_float("gamma").first().copied(),
+        };
+        let transfer = match gamma {
+            Some(gamma) if gamma > 0.0 => TransferFunction::Gamma(gamma),
+            Some(gamma) => {
+                return Err(Error::InvalidParameter(format!(
+                    "Gamma {} must be positive.",
+                    gamma
+                )))
+            }
+            None => TransferFunction::Srgb,
+        };
+        let ldr = LdrOptions {
+            transfer,
+            dither: params.find_one_bool("dither", false),
+        };
+
+        // OpenEXR images are written with single precision floats and ZIP
+        // compression unless given otherwise.
+        let exr_compression_name = params.find_one_string("exrcompression", String::from("zip"));
+        let exr_compression =
+            ExrCompression::from_name(&exr_compression_name).ok_or_else(|| {
+                Error::InvalidParameter(format!(
+                    "Unknown OpenEXR compression '{}'; must be 'none', 'rle', 'zip' or 'piz'.",
+                    exr_compression_name
+                ))
+            })?;
+        let exr = ExrOptions {
+            half: params.find_one_bool("exrhalf", false),
+            compression: exr_compression,
+        };
+
+        let mut film = Self::new(
             &Point2i::new(xres, yres),
             &crop,
             filter.clone(),
@@ -407,6 +1034,207 @@ impl From<(&ParamSet, ArcFilter)> for Film {
             &filename,
             Some(scale),
             Some(max_sample_luminance),
-        )
+            color_space,
+            white_point,
+            lpe_channels,
+            aovs,
+            alpha,
+            bloom,
+            camera_response,
+            pixel_bounds,
+            warm_start,
+            write_variance,
+            write_sample_count,
+        );
+        film.region = keep_uncropped;
+        film.ldr = ldr;
+        film.exr = exr;
+        Ok(film)
+    }
+}
+
+/// Returns the RGB values of a heatmap of the number of samples of each pixel
+/// that goes from black through red and yellow to white for the pixel with
+/// the most samples.
+///
+/// * `sample_counts` - Number of samples of each pixel.
+fn sample_count_heatmap(sample_counts: &[usize]) -> Vec<Float> {
+    let max_count = sample_counts.iter().copied().max().unwrap_or(0);
+    sample_counts
+        .iter()
+        .flat_map(|&n| {
+            let t = if max_count > 0 {
+                3.0 * n as Float / max_count as Float
+            } else {
+                0.0
+            };
+            [
+                clamp(t, 0.0, 1.0),
+                clamp(t - 1.0, 0.0, 1.0),
+                clamp(t - 2.0, 0.0, 1.0),
+            ]
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::filters::*;
+
+    #[test]
+    fn exposure_settings_scale_pixel_values() {
+        // Sunny 16 maps the luminance of a sunlit scene to about 1.
+        assert!((photometric_exposure(100.0, 0.01, 16.0) * 30000.0 - 1.0).abs() < 0.05);
+
+        // Doubling the exposure time or halving the light through the lens
+        // by one stop cancel out.
+        let e = photometric_exposure(200.0, 0.02, 8.0 * (2.0 as Float).sqrt());
+        assert!((e - photometric_exposure(100.0, 0.02, 8.0)).abs() < 1e-6);
+
+        let film = |params: &ParamSet| {
+            let filter: ArcFilter = Arc::new(BoxFilter::from(&ParamSet::new()));
+            Film::try_from((params, filter))
+        };
+        let mut params = ParamSet::new();
+        params.add_float("scale", &[2.0]);
+        assert_eq!(film(&params).unwrap().scale, 2.0);
+        params.add_float("iso", &[400.0]);
+        params.add_float("fnumber", &[2.0]);
+        let expected = 2.0 * photometric_exposure(400.0, 1.0, 2.0);
+        assert!((film(&params).unwrap().scale - expected).abs() < 1e-6);
+        params.add_float("exposuretime", &[0.0]);
+        assert!(film(&params).is_err());
+    }
+
+    #[test]
+    fn exr_options_come_from_the_film_parameters() {
+        let film = |params: &ParamSet| {
+            let filter: ArcFilter = Arc::new(BoxFilter::from(&ParamSet::new()));
+            Film::try_from((params, filter))
+        };
+        let mut params = ParamSet::new();
+        assert_eq!(film(&params).unwrap().exr, ExrOptions::default());
+        params.add_string("exrcompression", &[String::from("piz")]);
+        params.add_bool("exrhalf", &[true]);
+        let exr = film(&params).unwrap().exr;
+        assert_eq!(exr.compression, ExrCompression::Piz);
+        assert!(exr.half);
+        params.add_string("exrcompression", &[String::from("dwaa")]);
+        assert!(film(&params).is_err());
+    }
+
+    #[test]
+    fn ldr_options_come_from_the_film_parameters() {
+        let film = |params: &ParamSet| {
+            let filter: ArcFilter = Arc::new(BoxFilter::from(&ParamSet::new()));
+            Film::try_from((params, filter))
+        };
+        let mut params = ParamSet::new();
+        assert_eq!(film(&params).unwrap().ldr, LdrOptions::default());
+        params.add_float("gamma", &[2.2]);
+        params.add_bool("dither", &[true]);
+        let ldr = film(&params).unwrap().ldr;
+        assert_eq!(ldr.transfer, TransferFunction::Gamma(2.2));
+        assert!(ldr.dither);
+        params.add_float("gamma", &[0.0]);
+        assert!(film(&params).is_err());
+    }
+
+    #[test]
+    fn splats_are_added_from_many_threads() {
+        use crate::core::parallel::*;
+
+        let filter: ArcFilter = Arc::new(BoxFilter::from(&ParamSet::new()));
+        let mut params = ParamSet::new();
+        params.add_int("xresolution", &[2]);
+        params.add_int("yresolution", &[2]);
+        let mut film = Film::try_from((&params, filter)).unwrap();
+
+        let v = Spectrum::from_rgb(&[0.25, 0.5, 1.0], None);
+        (0..100)
+            .into_par_iter()
+            .for_each(|_| film.add_splat(&Point2f::new(1.5, 0.5), &v));
+        film.add_splat(&Point2f::new(3.5, 0.5), &v);
+
+        let bounds = film.cropped_pixel_bounds;
+        let rgb = film.get_rgb(&bounds, 0.01);
+        for (c, expected) in rgb[3..6].iter().zip(v.to_rgb().iter()) {
+            assert!((c - expected).abs() < 1e-3, "{} {}", c, expected);
+        }
+        assert!(rgb[0..3].iter().chain(rgb[6..].iter()).all(|c| *c == 0.0));
+        assert!(film.get_rgb(&bounds, 0.0).iter().all(|c| *c == 0.0));
+
+        film.clear();
+        assert!(film.get_rgb(&bounds, 1.0).iter().all(|c| *c == 0.0));
+    }
+
+    #[test]
+    fn crop_window_and_pixel_bounds_select_the_rendered_pixels() {
+        let film = |params: &ParamSet| {
+            let filter: ArcFilter = Arc::new(BoxFilter::from(&ParamSet::new()));
+            Film::try_from((params, filter))
+        };
+        let mut params = ParamSet::new();
+        params.add_int("xresolution", &[100]);
+        params.add_int("yresolution", &[50]);
+        let full = Bounds2i::new(Point2i::new(0, 0), Point2i::new(100, 50));
+        assert_eq!(film(&params).unwrap().cropped_pixel_bounds, full);
+
+        params.add_float("cropwindow", &[0.5, 0.25, 0.0, 0.5]);
+        let cropped = film(&params).unwrap();
+        let expected = Bounds2i::new(Point2i::new(25, 0), Point2i::new(50, 25));
+        assert_eq!(cropped.cropped_pixel_bounds, expected);
+        assert!(!cropped.region);
+
+        // Pixel bounds take precedence and can keep the rest of the image.
+        params.add_int("pixelbounds", &[10, 20, 5, 15]);
+        params.add_bool("keepuncropped", &[true]);
+        let bounded = film(&params).unwrap();
+        let expected = Bounds2i::new(Point2i::new(10, 5), Point2i::new(20, 15));
+        assert_eq!(bounded.cropped_pixel_bounds, expected);
+        assert!(bounded.region);
+
+        params.add_int("pixelbounds", &[200, 300, 0, 10]);
+        assert!(film(&params).is_err());
+        let mut params = ParamSet::new();
+        params.add_float("cropwindow", &[0.5, 0.5, 0.0, 1.0]);
+        assert!(film(&params).is_err());
+    }
+
+    #[test]
+    fn aovs_are_averaged_per_pixel() {
+        let filter: ArcFilter = Arc::new(BoxFilter::from(&ParamSet::new()));
+        let mut params = ParamSet::new();
+        params.add_int("xresolution", &[4]);
+        params.add_int("yresolution", &[2]);
+        params.add_string("aovs", &[String::from("depth"), String::from("normal")]);
+        let film = Film::try_from((&params, filter.clone())).unwrap();
+        assert_eq!(film.aovs, vec![Aov::Depth, Aov::Normal]);
+
+        let bounds = film.cropped_pixel_bounds;
+        let mut tile = film.get_film_tile(bounds);
+        let tile_mut = Arc::get_mut(&mut tile).unwrap();
+        tile_mut.add_aov_sample(&Point2i::new(1, 0), &[[2.0; 3], [0.0, 0.0, 1.0]]);
+        tile_mut.add_aov_sample(&Point2i::new(1, 0), &[[4.0; 3], [0.0, 1.0, 0.0]]);
+        film.merge_film_tile(tile);
+
+        let depth = film.get_aov_values(0, &bounds);
+        assert_eq!(depth.len(), 3 * 8);
+        assert_eq!(depth[3..6], [3.0; 3]);
+        assert_eq!(depth[0..3], [0.0; 3]);
+        assert_eq!(film.get_aov_values(1, &bounds)[3..6], [0.0, 0.5, 0.5]);
+
+        params.add_string("aovs", &[String::from("primid")]);
+        let film = Film::try_from((&params, filter.clone())).unwrap();
+        let mut tile = film.get_film_tile(bounds);
+        let tile_mut = Arc::get_mut(&mut tile).unwrap();
+        tile_mut.add_aov_sample(&Point2i::new(1, 0), &[[3.0; 3]]);
+        tile_mut.add_aov_sample(&Point2i::new(1, 0), &[[4.0; 3]]);
+        film.merge_film_tile(tile);
+        assert_eq!(film.get_aov_values(0, &bounds)[3..6], [3.0; 3]);
+
+        params.add_string("aovs", &[String::from("uv")]);
+        assert!(Film::try_from((&params, filter)).is_err());
     }
 }
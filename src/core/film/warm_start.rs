@@ -0,0 +1,193 @@
+//! Warm Start
+
+use crate::core::error::*;
+use crate::core::fileutil::*;
+use crate::core::geometry::*;
+use crate::core::image_io::*;
+use crate::core::pbrt::*;
+use crate::core::spectrum::*;
+use std::path::Path;
+
+/// Running mean and variance of the luminance of the samples of a pixel.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct PixelStatistics {
+    /// Number of samples.
+    pub count: usize,
+
+    /// Mean of the samples.
+    pub mean: Float,
+
+    /// Sum of squared differences from the mean.
+    m2: Float,
+}
+
+impl PixelStatistics {
+    /// Adds a sample.
+    ///
+    /// * `v` - Luminance of the sample.
+    pub fn add(&mut self, v: Float) {
+        self.count += 1;
+        let delta = v - self.mean;
+        self.mean += delta / self.count as Float;
+        self.m2 += delta * (v - self.mean);
+    }
+
+    /// Returns the variance of the mean of the samples; 0 if there are fewer
+    /// than 2 samples.
+    pub fn variance_of_mean(&self) -> Float {
+        if self.count < 2 {
+            0.0
+        } else {
+            let n = self.count as Float;
+            self.m2 / (n - 1.0) / n
+        }
+    }
+
+    /// Returns `true` once there are at least `min_samples` samples and the
+    /// variance of their mean is below `max_variance`.
+    ///
+    /// * `max_variance` - Largest variance of the mean of a converged pixel.
+    /// * `min_samples`  - Number of samples taken before checking.
+    pub fn has_converged(&self, max_variance: Float, min_samples: usize) -> bool {
+        self.count >= max(2, min_samples) && self.variance_of_mean() < max_variance
+    }
+}
+
+/// Fraction of the samples per pixel taken for each pixel, derived from the
+/// relative error of a previous render. The noisiest pixels get all samples.
+pub struct WarmStart {
+    /// Resolution of the image.
+    resolution: Point2i,
+
+    /// Fraction of the samples of each pixel in row-major order.
+    sample_fractions: Vec<Float>,
+}
+
+impl WarmStart {
+    /// Create a new `WarmStart` from the luminance of a previous render and
+    /// the variance of each pixel.
+    ///
+    /// * `resolution`   - Resolution of the image.
+    /// * `luminance`    - Luminance of each pixel in row-major order.
+    /// * `variance`     - Variance of each pixel in row-major order.
+    /// * `min_fraction` - Fraction of the samples taken for pixels without
+    ///                    noise.
+    pub fn new(
+        resolution: Point2i,
+        luminance: &[Float],
+        variance: &[Float],
+        min_fraction: Float,
+    ) -> Self {
+        // Relative error of each pixel; dark pixels are compared to a small
+        // luminance so their noise isn't exaggerated.
+        let errors: Vec<Float> = luminance
+            .iter()
+            .zip(variance.iter())
+            .map(|(&l, &v)| max(v, 0.0).sqrt() / (l.abs() + 0.01))
+            .collect();
+
+        // The pixels with the largest 10% of errors get all samples.
+        let mut sorted: Vec<Float> = errors.iter().copied().filter(|e| *e > 0.0).collect();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let reference = sorted.get(sorted.len() * 9 / 10).copied().unwrap_or(0.0);
+
+        let min_fraction = clamp(min_fraction, 0.0, 1.0);
+        let sample_fractions = errors
+            .iter()
+            .map(|&e| {
+                if reference > 0.0 {
+                    clamp(e / reference, min_fraction, 1.0)
+                } else {
+                    1.0
+                }
+            })
+            .collect();
+
+        Self {
+            resolution,
+            sample_fractions,
+        }
+    }
+
+    /// Loads a previous render and its variance image; or returns an error
+    /// if they can't be read or their resolution doesn't match.
+    ///
+    /// * `image`        - Path to the image.
+    /// * `variance`     - Path to the variance image.
+    /// * `resolution`   - Resolution of the image being rendered.
+    /// * `min_fraction` - Fraction of the samples taken for pixels without
+    ///                    noise.
+    pub fn from_files(
+        image: &str,
+        variance: &str,
+        resolution: Point2i,
+        min_fraction: Float,
+    ) -> Result<Self, Error> {
+        let image = read_image(image)?;
+        let variance = read_image(variance)?;
+        let expected = Point2::new(resolution.x as usize, resolution.y as usize);
+        if image.resolution != expected || variance.resolution != expected {
+            return Err(Error::InvalidParameter(format!(
+                "Warm start images must have the resolution of the image, {}x{}.",
+                resolution.x, resolution.y
+            )));
+        }
+
+        let luminance: Vec<Float> = image.pixels.iter().map(|p| p.y()).collect();
+        let variance: Vec<Float> = variance.pixels.iter().map(|p| p[0]).collect();
+        Ok(Self::new(resolution, &luminance, &variance, min_fraction))
+    }
+
+    /// Returns the number of samples to take for a pixel.
+    ///
+    /// * `p`                 - The pixel.
+    /// * `samples_per_pixel` - Number of samples per pixel of the sampler.
+    pub fn pixel_samples(&self, p: &Point2i, samples_per_pixel: usize) -> usize {
+        if p.x < 0 || p.y < 0 || p.x >= self.resolution.x || p.y >= self.resolution.y {
+            return samples_per_pixel;
+        }
+        let fraction = self.sample_fractions[(p.y * self.resolution.x + p.x) as usize];
+        max(1, (fraction * samples_per_pixel as Float).ceil() as usize)
+    }
+}
+
+/// Returns the filename of the variance image written next to an image, e.g.
+/// `image_variance.exr` for `image.png`. It is always an OpenEXR image.
+///
+/// * `filename` - Filename of the image.
+pub fn variance_filename(filename: &str) -> String {
+    Path::new(&suffixed_path(filename, "variance"))
+        .with_extension("exr")
+        .to_string_lossy()
+        .into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn noisy_pixels_get_more_samples() {
+        let mut stats = PixelStatistics::default();
+        for v in [1.0, 3.0, 1.0, 3.0] {
+            stats.add(v);
+        }
+        assert_eq!(stats.mean, 2.0);
+        assert!((stats.variance_of_mean() - 1.0 / 3.0).abs() < 1e-6);
+        assert!(stats.has_converged(0.5, 4));
+        assert!(!stats.has_converged(0.5, 8));
+        assert!(!stats.has_converged(0.25, 4));
+
+        // Four pixels of equal brightness; the last one is the noisiest.
+        let resolution = Point2i::new(2, 2);
+        let warm_start = WarmStart::new(resolution, &[1.0; 4], &[0.0, 0.01, 0.04, 0.16], 0.1);
+        let samples: Vec<usize> = [(0, 0), (1, 0), (0, 1), (1, 1)]
+            .iter()
+            .map(|&(x, y)| warm_start.pixel_samples(&Point2i::new(x, y), 64))
+            .collect();
+        assert_eq!(samples, [7, 16, 32, 64]);
+        assert_eq!(warm_start.pixel_samples(&Point2i::new(5, 0), 64), 64);
+
+        assert_eq!(variance_filename("out/image.png"), "out/image_variance.exr");
+    }
+}
@@ -1374,7 +1374,6 @@ fn decompose(m: &Matrix4x4, t: &mut Vector3f, r_quat: &mut Quaternion, s: &mut M
 
     // Extract rotation R from transformation matrix
     let mut r = m1;
-    let mut norm = 0.0;
     let mut count = 0;
     loop {
         // Compute the next matrix R_next in series
@@ -1387,6 +1386,7 @@ fn decompose(m: &Matrix4x4, t: &mut Vector3f, r_quat: &mut Quaternion, s: &mut M
         }
 
         // Compute norm of difference between R and R_next
+        let mut norm = 0.0;
         for i in 0..3 {
             let n = abs(r[i][0] - r_next[i][0])
                 + abs(r[i][1] - r_next[i][1])
@@ -1397,7 +1397,7 @@ fn decompose(m: &Matrix4x4, t: &mut Vector3f, r_quat: &mut Quaternion, s: &mut M
         r = r_next;
 
         count += 1;
-        if count >= 100 && norm <= 0.0001 {
+        if count >= 100 || norm <= 0.0001 {
             break;
         }
     }
@@ -1430,3 +1430,45 @@ impl DerivativeTerm {
         self.kc + self.kx * p.x + self.ky * p.y + self.kz * p.z
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use float_cmp::*;
+
+    fn scaled_rotation() -> AnimatedTransform {
+        let end = Transform::translate(&Vector3f::new(2.0, 0.0, 0.0))
+            * Transform::rotate_z(90.0)
+            * Transform::scale(2.0, 3.0, 2.0);
+        AnimatedTransform::new(Arc::new(Transform::default()), Arc::new(end), 0.0, 1.0)
+    }
+
+    #[test]
+    fn interpolate_recovers_keyframes_with_scale_and_rotation() {
+        let at = scaled_rotation();
+        let p = Point3f::new(1.0, 1.0, 1.0);
+        let start = at.interpolate(1e-6).transform_point(&p);
+        let end = at.interpolate(1.0 - 1e-6).transform_point(&p);
+        let expected = at.end_transform.transform_point(&p);
+        for i in 0..3 {
+            assert!(approx_eq!(Float, start[i], p[i], epsilon = 0.001));
+            assert!(approx_eq!(Float, end[i], expected[i], epsilon = 0.001));
+        }
+    }
+
+    #[test]
+    fn motion_bounds_contain_interpolated_corners() {
+        let at = scaled_rotation();
+        let b = Bounds3f::new(Point3f::new(-1.0, -1.0, -1.0), Point3f::new(1.0, 1.0, 1.0));
+        let bounds = at.motion_bounds(&b);
+        for step in 0..=20 {
+            let time = step as Float / 20.0;
+            for corner in 0..8 {
+                let p = at.transform_point(time, &b.corner(corner));
+                for i in 0..3 {
+                    assert!(p[i] >= bounds.p_min[i] - 0.001 && p[i] <= bounds.p_max[i] + 0.001);
+                }
+            }
+        }
+    }
+}
@@ -386,9 +386,9 @@ mod tests {
 
     #[test]
     fn empty_bounds2f_returns_min_greater_than_max_components() {
-        let b = Bounds2::<f32>::empty();
-        assert_eq!(b.p_min, Point2::new(f32::MAX, f32::MAX));
-        assert_eq!(b.p_max, Point2::new(f32::MIN, f32::MIN));
+        let b = Bounds2::<Float>::empty();
+        assert_eq!(b.p_min, Point2::new(Float::MAX, Float::MAX));
+        assert_eq!(b.p_max, Point2::new(Float::MIN, Float::MIN));
     }
 
     #[test]
@@ -400,8 +400,8 @@ mod tests {
 
     #[test]
     fn bounding_circle_of_empty_box_returns_origin_and_zero_radius() {
-        let (center, radius) = Bounds2::<f32>::empty().bounding_circle();
-        assert_eq!(center, Point2::<f32>::zero());
+        let (center, radius) = Bounds2::<Float>::empty().bounding_circle();
+        assert_eq!(center, Point2::<Float>::zero());
         assert_eq!(radius, 0.0);
     }
 
@@ -440,7 +440,7 @@ mod tests {
 
     #[test]
     fn area_of_empty_bounds2f_returns_zero() {
-        let empty = Bounds2::<f32>::empty();
+        let empty = Bounds2::<Float>::empty();
         assert_eq!(empty.area(), 0.0);
     }
 
@@ -452,7 +452,7 @@ mod tests {
 
     #[test]
     fn union_of_two_empty_bounds2f_retrns_empty() {
-        let empty = Bounds2::<f32>::empty();
+        let empty = Bounds2::<Float>::empty();
         assert!(empty.union(&empty).is_empty());
     }
 
@@ -464,7 +464,7 @@ mod tests {
 
     #[test]
     fn intersection_of_two_empty_bounds2f_returns_empty() {
-        let empty = Bounds2::<f32>::empty();
+        let empty = Bounds2::<Float>::empty();
         assert!(empty.intersect(&empty).is_empty());
     }
 
@@ -485,13 +485,13 @@ mod tests {
 
     // Define some properties for tests.
     prop_range!(range_i32, i32, -100..100i32);
-    prop_range!(range_f32, f32, -100.0..100.0f32);
+    prop_range!(range_f32, Float, -100.0..100.0 as Float);
 
     prop_non_zero_range!(non_zero_i32, i32, -100..100i32);
-    prop_non_zero_range!(non_zero_f32, f32, -100.0..100.0f32);
+    prop_non_zero_range!(non_zero_f32, Float, -100.0..100.0 as Float);
 
     prop_point2!(point2_i32, i32, -100..100i32, -100..100i32);
-    prop_point2!(point2_f32, f32, -100.0..100.0f32, -100.0..100.0f32);
+    prop_point2!(point2_f32, Float, -100.0..100.0 as Float, -100.0..100.0 as Float);
 
     proptest! {
         #[test]
@@ -552,10 +552,10 @@ mod tests {
 
         #[test]
         fn area_of_non_empty_bounds2f_returns_product_of_diagonal_components(
-            p in point2_f32(), dx in -10.0..10.0f32, dy in -10.0..10.0f32,
+            p in point2_f32(), dx in -10.0..10.0 as Float, dy in -10.0..10.0 as Float,
         ) {
             let b = Bounds2::new(p, p + Vector2::new(dx, dy));
-            prop_assert!(approx_eq!(f32, b.area(), abs(dx * dy), epsilon = 0.0001));
+            prop_assert!(approx_eq!(Float, b.area(), abs(dx * dy), epsilon = 0.0001));
         }
 
         #[test]
@@ -569,7 +569,7 @@ mod tests {
 
         #[test]
         fn maximum_extent_of_non_empty_bounds2f_returns_axis_with_max_diagonal_component(
-            p in point2_f32(), d in 0.0..10.0f32,
+            p in point2_f32(), d in 0.0..10.0 as Float,
         ) {
             prop_assert_eq!(Bounds2::new(p, p + Vector2::new(d + 0.001, d)).maximum_extent(), Axis::X);
             prop_assert_eq!(Bounds2::new(p, p + Vector2::new(d, d + 0.001)).maximum_extent(), Axis::Y);
@@ -581,7 +581,7 @@ mod tests {
 
         #[test]
         fn maximum_extent_of_non_empty_bounds2f_returns_y_axis_edge_case(
-            x in -10.0..10.0f32, d in 0.0..10.0f32,
+            x in -10.0..10.0 as Float, d in 0.0..10.0 as Float,
         ) {
             prop_assert_eq!(Bounds2::new(Point2::new(x, x), Point2::new(x + d, x + d)).maximum_extent(), Axis::Y);
         }
@@ -589,8 +589,8 @@ mod tests {
         #[test]
         fn overlaps_returns_true_when_two_bounds2f_overlap(
             p in point2_f32(),
-            dx in 0.1..1.0f32, dy in 0.1..1.0f32,
-            sx in 0.0..2.0f32, sy in 0.0..2.0f32,
+            dx in 0.1..1.0 as Float, dy in 0.1..1.0 as Float,
+            sx in 0.0..2.0 as Float, sy in 0.0..2.0 as Float,
         ) {
             let b1 = Bounds2::new(p - Vector2::new(dx, dy), p + Vector2::new(dx, dy));
             let bounds = vec!(
@@ -614,8 +614,8 @@ mod tests {
         #[test]
         fn overlaps_returns_false_when_two_bounds2f_do_not_overlap(
             p in point2_f32(),
-            dx in 1.0..2.0f32, dy in 1.0..2.0f32,
-            sx in 2.001..3.0f32, sy in 2.001..3.0f32,
+            dx in 1.0..2.0 as Float, dy in 1.0..2.0 as Float,
+            sx in 2.001..3.0 as Float, sy in 2.001..3.0 as Float,
         ) {
             let b1 = Bounds2::new(p - Vector2::new(dx, dy), p + Vector2::new(dx, dy));
             let bounds = vec!(
@@ -636,7 +636,7 @@ mod tests {
         fn offset_of_any_point_within_an_empty_bounds2f_returns_vector_towards_p_min(
             p in point2_f32()
         ) {
-            let b = Bounds2::<f32>::empty();
+            let b = Bounds2::<Float>::empty();
             prop_assert_eq!(b.offset(&p), p - b.p_min);
         }
 
@@ -653,12 +653,12 @@ mod tests {
 
         #[test]
         fn contains_returns_false_for_any_point_when_empty_bounds2f(p in point2_f32()) {
-            prop_assert!(!Bounds2::<f32>::empty().contains(&p));
+            prop_assert!(!Bounds2::<Float>::empty().contains(&p));
         }
 
         #[test]
         fn contains_returns_true_for_point_bounds2f_when_p_min_p_max_is_same_point(
-            p in point2_f32(), dx in 0.001..1.0f32, dy in 0.001..1.0f32,
+            p in point2_f32(), dx in 0.001..1.0 as Float, dy in 0.001..1.0 as Float,
         ) {
             let b = Bounds2::from(p);
             prop_assert!(b.contains(&p));
@@ -673,8 +673,8 @@ mod tests {
         #[test]
         fn contains_returns_false_when_point_is_outside_bounds2f(
             p in point2_f32(),
-            dx in 0.001..1.0f32, dy in 0.001..1.0f32,
-            sx in 0.001..1.0f32, sy in 0.001..1.0f32,
+            dx in 0.001..1.0 as Float, dy in 0.001..1.0 as Float,
+            sx in 0.001..1.0 as Float, sy in 0.001..1.0 as Float,
         ) {
             let b = Bounds2::new(p - Vector2::new(dx, dy), p + Vector2::new(dx, dy));
 
@@ -700,8 +700,8 @@ mod tests {
         #[test]
         fn contains_returns_true_when_point_is_inside_bounds2f(
             p in point2_f32(),
-            dx in 0.001..1.0f32, dy in 0.001..1.0f32,
-            tx in 0.0..1.0f32, ty in 0.0..1.0f32,
+            dx in 0.001..1.0 as Float, dy in 0.001..1.0 as Float,
+            tx in 0.0..1.0 as Float, ty in 0.0..1.0 as Float,
         ) {
             let b = Bounds2::new(p - Vector2::new(dx, dy), p + Vector2::new(dx, dy));
             let p = Point2::new(
@@ -765,11 +765,11 @@ mod tests {
         fn contains_exclusive_returns_true_when_point_is_inside_bounds2i(
             p in point2_i32(),
             dx in 1..10i32, dy in 1..10i32,
-            tx in 0.0..0.999f32, ty in 0.0..0.999f32,
+            tx in 0.0..0.999 as Float, ty in 0.0..0.999 as Float,
         ) {
             let b = Bounds2::new(p - Vector2::new(dx, dy), p + Vector2::new(dx, dy));
-            let px = lerp(tx, b.p_min.x as f32, b.p_max.x as f32);
-            let py = lerp(ty, b.p_min.y as f32, b.p_max.y as f32);
+            let px = lerp(tx, b.p_min.x as Float, b.p_max.x as Float);
+            let py = lerp(ty, b.p_min.y as Float, b.p_max.y as Float);
             let p = Point2::new(px.floor() as i32, py.floor() as i32);
             prop_assert!(b.contains_exclusive(&p));
         }
@@ -805,7 +805,7 @@ mod tests {
         #[test]
         fn lerp_interpolates_and_extrapolates_across_corners_of_bounds2f(
             p1 in point2_f32(), p2 in point2_f32(),
-            tx in -2.0..2.0f32, ty in -2.0..2.0f32,
+            tx in -2.0..2.0 as Float, ty in -2.0..2.0 as Float,
         ) {
             let b = Bounds2::new(p1, p2);
             let l = b.lerp(&Point2::new(tx, ty));
@@ -843,8 +843,8 @@ mod tests {
         }
 
         #[test]
-        fn expand_returns_empty_when_bounds2f_is_empty(delta in 0.0..100.0f32) {
-            let b1 = Bounds2::<f32>::empty();
+        fn expand_returns_empty_when_bounds2f_is_empty(delta in 0.0..100.0 as Float) {
+            let b1 = Bounds2::<Float>::empty();
             let b2 = b1.expand(delta);
             prop_assert_eq!(b2.p_min.x, b1.p_min.x - delta);
             prop_assert_eq!(b2.p_min.y, b1.p_min.y - delta);
@@ -854,7 +854,7 @@ mod tests {
 
         #[test]
         fn expand_returns_non_empty_bounds2f_for_bounds2f_from_point(
-            p in point2_f32(), delta in 0.0..100.0f32,
+            p in point2_f32(), delta in 0.0..100.0 as Float,
         ) {
             let b1 = Bounds2::from(p);
             let b2 = b1.expand(delta);
@@ -866,7 +866,7 @@ mod tests {
 
         #[test]
         fn expand_returns_non_empty_bounds2f_for_non_empty_bounds2f(
-            p1 in point2_f32(), p2 in point2_f32(), delta in 0.0..100.0f32,
+            p1 in point2_f32(), p2 in point2_f32(), delta in 0.0..100.0 as Float,
         ) {
             let b1 = Bounds2::new(p1, p2);
             let b2 = b1.expand(delta);
@@ -888,7 +888,7 @@ mod tests {
         fn union_empty_with_bounds2f_from_point_returns_latter(
             p in point2_f32(),
         ) {
-            let empty = Bounds2::<f32>::empty();
+            let empty = Bounds2::<Float>::empty();
             prop_assert_eq!(empty.union(&p), Bounds2::from(p));
         }
 
@@ -906,7 +906,7 @@ mod tests {
         fn union_empty_with_non_empty_bounds2f_returns_non_empty(
             p1 in point2_f32(), p2 in point2_f32(),
         ) {
-            let empty = Bounds2::<f32>::empty();
+            let empty = Bounds2::<Float>::empty();
             let non_empty = Bounds2::new(p1, p2);
             prop_assert_eq!(empty.union(&non_empty), non_empty);
             prop_assert_eq!(non_empty.union(&empty), non_empty);
@@ -916,12 +916,12 @@ mod tests {
         fn union_non_empty_bounds2i_with_exterior_point_returns_non_empty_bounds2i(
             p in point2_i32(),
             dx in 1..10i32, dy in 1..10i32,
-            s in 1..10i32, t in 0.0..1.0f32,
+            s in 1..10i32, t in 0.0..1.0 as Float,
         ) {
             let v = Vector2::new(dx, dy);
             let b = Bounds2::new(p - v, p + v);
 
-            let y = lerp(t - 1.0, b.p_min.y as f32, b.p_max.y as f32).round() as i32;
+            let y = lerp(t - 1.0, b.p_min.y as Float, b.p_max.y as Float).round() as i32;
             prop_assert_eq!(
                 b.union(&Point2::new(b.p_min.x - s, y)),
                 Bounds2::new(Point2::new(b.p_min.x - s, y), Point2::new(b.p_max.x, b.p_max.y))
@@ -931,7 +931,7 @@ mod tests {
                 Bounds2::new(Point2::new(b.p_min.x, y), Point2::new(b.p_max.x + s, b.p_max.y))
             );
 
-            let y = lerp(t, b.p_min.y as f32, b.p_max.y as f32).round() as i32;
+            let y = lerp(t, b.p_min.y as Float, b.p_max.y as Float).round() as i32;
             prop_assert_eq!(
                 b.union(&Point2::new(b.p_min.x - s, y)),
                 Bounds2::new(Point2::new(b.p_min.x - s, b.p_min.y), Point2::new(b.p_max.x, b.p_max.y))
@@ -941,7 +941,7 @@ mod tests {
                 Bounds2::new(Point2::new(b.p_min.x, b.p_min.y), Point2::new(b.p_max.x + s, b.p_max.y))
             );
 
-            let y = lerp(t + 1.0, b.p_min.y as f32, b.p_max.y as f32).round() as i32;
+            let y = lerp(t + 1.0, b.p_min.y as Float, b.p_max.y as Float).round() as i32;
             prop_assert_eq!(
                 b.union(&Point2::new(b.p_min.x - s, y)),
                 Bounds2::new(Point2::new(b.p_min.x - s, b.p_min.y), Point2::new(b.p_max.x, y))
@@ -951,7 +951,7 @@ mod tests {
                 Bounds2::new(Point2::new(b.p_min.x, b.p_min.y), Point2::new(b.p_max.x + s, y))
             );
 
-            let x = lerp(t, b.p_min.x as f32, b.p_max.x as f32).round() as i32;
+            let x = lerp(t, b.p_min.x as Float, b.p_max.x as Float).round() as i32;
             prop_assert_eq!(
                 b.union(&Point2::new(x, b.p_min.y - s)),
                 Bounds2::new(Point2::new(b.p_min.x, b.p_min.y - s), Point2::new(b.p_max.x, b.p_max.y))
@@ -965,8 +965,8 @@ mod tests {
         #[test]
         fn union_non_empty_bounds2f_with_exterior_point_returns_non_empty_bounds2f(
             p in point2_f32(),
-            dx in 0.001..10.0f32, dy in 0.001..10.0f32,
-            s in 0.0..1.0f32, t in 0.0..1.0f32,
+            dx in 0.001..10.0 as Float, dy in 0.001..10.0 as Float,
+            s in 0.0..1.0 as Float, t in 0.0..1.0 as Float,
         ) {
             let v = Vector2::new(dx, dy);
             let b = Bounds2::new(p - v, p + v);
@@ -1016,21 +1016,21 @@ mod tests {
         fn union_non_empty_bounds2i_with_interior_point_returns_same_bounds2i(
             p in point2_i32(),
             dx in 1..10i32, dy in 1..10i32,
-            tx in 0.0..1.0f32, ty in 0.0..1.0f32,
+            tx in 0.0..1.0 as Float, ty in 0.0..1.0 as Float,
         ) {
             let v = Vector2::new(dx, dy);
             let b = Bounds2::new(p - v, p + v);
 
-            let x = lerp(tx, b.p_min.x as f32, b.p_max.x as f32).round() as i32;
-            let y = lerp(ty, b.p_min.y as f32, b.p_max.y as f32).round() as i32;
+            let x = lerp(tx, b.p_min.x as Float, b.p_max.x as Float).round() as i32;
+            let y = lerp(ty, b.p_min.y as Float, b.p_max.y as Float).round() as i32;
             prop_assert_eq!(b.union(&Point2::new(x, y)), b);
         }
 
         #[test]
         fn union_non_empty_bounds2f_with_interior_point_returns_same_bounds2f(
             p in point2_f32(),
-            dx in 0.001..10.0f32, dy in 0.001..10.0f32,
-            tx in 0.0..1.0f32, ty in 0.0..1.0f32,
+            dx in 0.001..10.0 as Float, dy in 0.001..10.0 as Float,
+            tx in 0.0..1.0 as Float, ty in 0.0..1.0 as Float,
         ) {
             let v = Vector2::new(dx, dy);
             let b = Bounds2::new(p - v, p + v);
@@ -1043,8 +1043,8 @@ mod tests {
         #[test]
         fn union_non_empty_non_overlapping_bounds2f_returns_non_empty_bounds2f(
             p in point2_f32(),
-            dx in 0.001..10.0f32, dy in 0.001..10.0f32,
-            s in 0.002..10.0f32, t1 in -1.0..2.0f32, t2 in -1.0..2.0f32,
+            dx in 0.001..10.0 as Float, dy in 0.001..10.0 as Float,
+            s in 0.002..10.0 as Float, t1 in -1.0..2.0 as Float, t2 in -1.0..2.0 as Float,
         ) {
             let v = Vector2::new(dx, dy);
             let b1 = Bounds2::new(p - v, p + v);
@@ -1105,9 +1105,9 @@ mod tests {
         #[test]
         fn union_non_empty_overlapping_bounds2f_returns_non_empty_bounds2f(
             p in point2_f32(),
-            dx in 0.001..10.0f32, dy in 0.001..10.0f32,
-            t1 in -2.0..1.0f32, t2 in 0.0..2.0f32,
-            s1 in -2.0..1.0f32, s2 in 0.0..2.0f32,
+            dx in 0.001..10.0 as Float, dy in 0.001..10.0 as Float,
+            t1 in -2.0..1.0 as Float, t2 in 0.0..2.0 as Float,
+            s1 in -2.0..1.0 as Float, s2 in 0.0..2.0 as Float,
         ) {
             let v = Vector2::new(dx, dy);
             let b1 = Bounds2::new(p - v, p + v);
@@ -1141,7 +1141,7 @@ mod tests {
         fn intersect_empty_with_non_empty_bounds2f_returns_empty(
             p1 in point2_f32(), p2 in point2_f32(),
         ) {
-            let empty = Bounds2::<f32>::empty();
+            let empty = Bounds2::<Float>::empty();
             let non_empty = Bounds2::new(p1, p2);
             prop_assert!(empty.intersect(&non_empty).is_empty());
             prop_assert!(non_empty.intersect(&empty).is_empty());
@@ -1150,8 +1150,8 @@ mod tests {
         #[test]
         fn intersect_non_empty_non_overlapping_bounds2f_returns_empty(
             p in point2_f32(),
-            dx in 0.001..10.0f32, dy in 0.001..10.0f32,
-            s in 0.002..10.0f32, t1 in -1.0..2.0f32, t2 in -1.0..2.0f32,
+            dx in 0.001..10.0 as Float, dy in 0.001..10.0 as Float,
+            s in 0.002..10.0 as Float, t1 in -1.0..2.0 as Float, t2 in -1.0..2.0 as Float,
         ) {
             let v = Vector2::new(dx, dy);
             let b1 = Bounds2::new(p - v, p + v);
@@ -1188,9 +1188,9 @@ mod tests {
         #[test]
         fn intersect_non_empty_overlapping_bounds2f_returns_non_empty(
             p in point2_f32(),
-            dx in 0.001..10.0f32, dy in 0.001..10.0f32,
-            t1 in -2.0..1.0f32, t2 in 0.0..2.0f32,
-            s1 in -2.0..1.0f32, s2 in 0.0..2.0f32,
+            dx in 0.001..10.0 as Float, dy in 0.001..10.0 as Float,
+            t1 in -2.0..1.0 as Float, t2 in 0.0..2.0 as Float,
+            s1 in -2.0..1.0 as Float, s2 in 0.0..2.0 as Float,
         ) {
             let v = Vector2::new(dx, dy);
             let b1 = Bounds2::new(p - v, p + v);
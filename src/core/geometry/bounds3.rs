@@ -254,14 +254,15 @@ impl<T: Num + Copy> Bounds3<T> {
         }
     }
 
-    /// Returns the coordinates of one of the four corners.
+    /// Returns the coordinates of one of the eight corners.
     ///
-    /// * `corner` -
+    /// * `corner` - Corner index in [0, 7]; bits 0, 1 and 2 select the
+    ///              maximum x, y and z respectively.
     pub fn corner(&self, corner: u8) -> Point3<T>
     where
         T: Copy,
     {
-        debug_assert!(corner <= 4);
+        debug_assert!(corner < 8);
         let x = corner & 1;
         let y = if corner & 2 == 0 { 0 } else { 1 };
         let z = if corner & 4 == 0 { 0 } else { 1 };
@@ -308,6 +309,23 @@ impl<T: Num + Copy> Bounds3<T> {
     /// * `ray`        - The ray.
     /// * `inv_dir`    - Reciprocal of `ray`'s direction.
     /// * `dir_is_neg` - Ray direction is negative.
+    #[cfg(feature = "simd")]
+    pub fn intersect_p_inv(&self, ray: &Ray, inv_dir: &Vector3f, dir_is_neg: [u8; 3]) -> bool
+    where
+        T: num_traits::Float + Copy + PartialOrd + Into<Float>,
+    {
+        let p_min = Point3f::new(self.p_min.x.into(), self.p_min.y.into(), self.p_min.z.into());
+        let p_max = Point3f::new(self.p_max.x.into(), self.p_max.y.into(), self.p_max.z.into());
+        intersect_slabs_x4(&p_min, &p_max, ray, inv_dir, dir_is_neg)
+    }
+
+    /// Uses the reciprocal of a rays direction and returns `true` if it
+    /// intersects the bounding box; otherwise `false`.
+    ///
+    /// * `ray`        - The ray.
+    /// * `inv_dir`    - Reciprocal of `ray`'s direction.
+    /// * `dir_is_neg` - Ray direction is negative.
+    #[cfg(not(feature = "simd"))]
     #[rustfmt::skip]
     pub fn intersect_p_inv(&self, ray: &Ray, inv_dir: &Vector3f, dir_is_neg: [u8; 3]) -> bool
     where
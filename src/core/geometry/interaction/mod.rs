@@ -52,6 +52,11 @@ pub struct Hit {
 
     /// The medium interface used for scattering media.
     pub medium_interface: Option<MediumInterface>,
+
+    /// Offset from `p` to the origin of rays leaving on the side of the
+    /// surface normal. It moves the origin to the smooth surface described by
+    /// vertex normals to avoid shadow terminator artifacts on low-poly meshes.
+    pub shading_offset: Vector3f,
 }
 
 /// Atomic reference counted `Hit`.
@@ -82,6 +87,7 @@ impl Hit {
             wo,
             n,
             medium_interface,
+            shading_offset: Vector3f::default(),
         }
     }
 
@@ -95,11 +101,13 @@ impl Hit {
         !self.is_surface_interaction()
     }
 
-    /// Spawn's a new ray in the given direction.
+    /// Spawn's a new ray in the given direction. The origin is offset along
+    /// the geometric normal, not the shading normal, so the ray doesn't
+    /// intersect the surface it leaves.
     ///
     /// * `d` - The new direction.
     pub fn spawn_ray(&self, d: &Vector3f) -> Ray {
-        let o = Ray::offset_origin(&self.p, &self.p_error, &self.n, d);
+        let o = Ray::offset_origin(&self.ray_origin(d), &self.p_error, &self.n, d);
         Ray::new(o, *d, INFINITY, self.time, self.get_medium_in_direction(d))
     }
 
@@ -108,7 +116,7 @@ impl Hit {
     /// * `p` - The target point.
     pub fn spawn_ray_to(&self, p: &Point3f) -> Ray {
         let dir = *p - self.p;
-        let o = Ray::offset_origin(&self.p, &self.p_error, &self.n, &dir);
+        let o = Ray::offset_origin(&self.ray_origin(&dir), &self.p_error, &self.n, &dir);
         let d = *p - o;
         Ray::new(
             o,
@@ -119,6 +127,19 @@ impl Hit {
         )
     }
 
+    /// Returns the point rays leaving in a direction start from before they
+    /// are offset to avoid self-intersection; `p` moved by `shading_offset`
+    /// if the direction is on the side of the surface normal.
+    ///
+    /// * `w` - The direction.
+    fn ray_origin(&self, w: &Vector3f) -> Point3f {
+        if w.dot(&self.n) > 0.0 {
+            self.p + self.shading_offset
+        } else {
+            self.p
+        }
+    }
+
     /// Returns the medium towards a direction.
     ///
     /// * `w` - The direction.
@@ -133,14 +154,9 @@ impl Hit {
 
     /// Returns the medium when interior and exterior are the same.
     pub fn get_medium(&self) -> Option<ArcMedium> {
-        if let Some(mi) = self.medium_interface.clone() {
-            if mi.is_medium_transition() {
-                mi.inside.clone()
-            } else {
-                None
-            }
-        } else {
-            None
-        }
+        self.medium_interface.as_ref().and_then(|mi| {
+            debug_assert!(!mi.is_medium_transition());
+            mi.inside.clone()
+        })
     }
 }
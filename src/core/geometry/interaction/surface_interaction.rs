@@ -6,6 +6,7 @@ use crate::core::geometry::*;
 use crate::core::material::*;
 use crate::core::pbrt::*;
 use crate::core::primitive::*;
+use crate::core::profiler::*;
 use crate::core::reflection::*;
 use crate::core::spectrum::*;
 
@@ -94,8 +95,9 @@ impl<'a> SurfaceInteraction<'a> {
         let mut n = Normal3f::from(dpdu.cross(&dpdv).normalize());
 
         // Adjust normal based on orientation and handedness
-        if let Some(s) = shape.clone() {
-            if s.get_data().reverse_orientation ^ s.get_data().transform_swaps_handedness {
+        if let Some(s) = shape.as_ref() {
+            let data = s.get_data();
+            if data.reverse_orientation ^ data.transform_swaps_handedness {
                 n *= -1.0;
             }
         }
@@ -123,10 +125,18 @@ impl<'a> SurfaceInteraction<'a> {
 
     /// Returns updated shading geometry.
     ///
-    /// * `dpdu` - Parametric partial derivative of the point ∂p/∂u.
-    /// * `dpdv` - Parametric partial derivative of the point ∂p/∂v.
-    /// * `dndu` - Differential change ∂n/∂v in surface normal as we move along u.
-    /// * `dndv` - Differential change ∂n/∂v in surface normal as we move along v.
+    /// * `dpdu`                         - Parametric partial derivative of the
+    ///                                    point ∂p/∂u.
+    /// * `dpdv`                         - Parametric partial derivative of the
+    ///                                    point ∂p/∂v.
+    /// * `dndu`                         - Differential change ∂n/∂v in surface
+    ///                                    normal as we move along u.
+    /// * `dndv`                         - Differential change ∂n/∂v in surface
+    ///                                    normal as we move along v.
+    /// * `orientation_is_authoritative` - If `true` the geometric normal is
+    ///                                    flipped to lie in the hemisphere of
+    ///                                    the shading normal; otherwise the
+    ///                                    shading normal is flipped instead.
     pub fn set_shading_geometry(
         &mut self,
         dpdu: Vector3f,
@@ -135,23 +145,17 @@ impl<'a> SurfaceInteraction<'a> {
         dndv: Normal3f,
         orientation_is_authoritative: bool,
     ) {
-        // Compute normal.
-        let mut hit_n = self.hit.n;
+        // Compute shading normal. Orientation and handedness have already been
+        // accounted for in the geometric normal (and in any vertex normals), so
+        // the two only need to be brought into the same hemisphere.
         let mut shading_n = Normal3::from(dpdu.cross(&dpdv)).normalize();
-
-        if let Some(s) = self.shape.clone() {
-            if s.get_data().reverse_orientation ^ s.get_data().transform_swaps_handedness {
-                shading_n = -self.shading.n;
-                if orientation_is_authoritative {
-                    hit_n = hit_n.face_forward(&shading_n.into());
-                } else {
-                    shading_n = shading_n.face_forward(&hit_n.into());
-                }
-            }
+        if orientation_is_authoritative {
+            self.hit.n = self.hit.n.face_forward(&shading_n.into());
+        } else {
+            shading_n = shading_n.face_forward(&self.hit.n.into());
         }
 
         // Initialize shading partial derivative values.
-        self.hit.n = hit_n;
         self.shading = Shading::new(shading_n, dpdu, dpdv, dndu, dndv);
     }
 
@@ -170,6 +174,7 @@ impl<'a> SurfaceInteraction<'a> {
         allow_multiple_lobes: bool,
         mode: TransportMode,
     ) {
+        let _p = ProfilePhase::new(Prof::ComputeScatteringFuncs);
         self.compute_differentials(ray);
         if let Some(primitive) = self.primitive {
             primitive.compute_scattering_functions(self, mode, allow_multiple_lobes);
@@ -275,7 +275,7 @@ mod tests {
         #[test]
         #[should_panic]
         fn inverse_panics_when_matrix_is_singular(
-            a in 0.0..10.0f32, b in 0.0..10.0f32, c in 0.0..10.0f32,
+            a in 0.0..10.0 as Float, b in 0.0..10.0 as Float, c in 0.0..10.0 as Float,
         ) {
             let  _ = Matrix4x4 {
                 m: [
@@ -289,7 +289,7 @@ mod tests {
 
         #[test]
         fn inverse_returns_matrix_when_matrix_is_non_singular(
-            a in 0.001..10.0f32, b in 0.001..10.0f32, c in 0.001..10.0f32, d in 0.001..10.0f32,
+            a in 0.001..10.0 as Float, b in 0.001..10.0 as Float, c in 0.001..10.0 as Float, d in 0.001..10.0 as Float,
         ) {
             let mat = Matrix4x4 {
                 m: [
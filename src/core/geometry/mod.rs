@@ -94,11 +94,14 @@ mod interaction;
 mod interval;
 mod matrix4x4;
 mod normal;
+mod oct_normal;
 mod point2;
 mod point3;
 mod quaternion;
 mod ray;
 mod shape;
+#[cfg(feature = "simd")]
+mod simd;
 mod transform;
 mod util;
 mod vector2;
@@ -114,11 +117,14 @@ pub use interaction::*;
 pub use interval::*;
 pub use matrix4x4::*;
 pub use normal::*;
+pub use oct_normal::*;
 pub use point2::*;
 pub use point3::*;
 pub use quaternion::*;
 pub use ray::*;
 pub use shape::*;
+#[cfg(feature = "simd")]
+pub use simd::*;
 pub use transform::*;
 pub use util::*;
 pub use vector2::*;
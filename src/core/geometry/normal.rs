@@ -12,6 +12,7 @@ use std::ops::{
 
 /// A 3-D normal containing numeric values.
 #[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[repr(C)]
 pub struct Normal3<T> {
     /// X-coordinate.
     pub x: T,
@@ -0,0 +1,130 @@
+//! Octahedral encoded normals
+
+#![allow(dead_code)]
+use crate::core::geometry::*;
+use crate::core::pbrt::*;
+
+/// A unit normal stored in 32-bits using an octahedral mapping of the unit
+/// sphere onto a square. The angular error is below 0.01 degrees.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct OctNormal {
+    /// Encoded x-coordinate on the octahedron.
+    x: u16,
+
+    /// Encoded y-coordinate on the octahedron.
+    y: u16,
+}
+
+impl OctNormal {
+    /// Quantizes a value in [-1, 1] to 16-bits.
+    ///
+    /// * `f` - The value.
+    fn encode(f: Float) -> u16 {
+        (clamp((f + 1.0) / 2.0, 0.0, 1.0) * 65535.0).round() as u16
+    }
+
+    /// Returns the value in [-1, 1] of a quantized value.
+    ///
+    /// * `u` - The quantized value.
+    fn decode(u: u16) -> Float {
+        -1.0 + 2.0 * (u as Float / 65535.0)
+    }
+
+    /// Returns the encoded coordinates.
+    pub fn bits(&self) -> [u16; 2] {
+        [self.x, self.y]
+    }
+
+    /// Returns a normal from its encoded coordinates.
+    ///
+    /// * `bits` - The encoded coordinates.
+    pub fn from_bits(bits: [u16; 2]) -> Self {
+        Self {
+            x: bits[0],
+            y: bits[1],
+        }
+    }
+}
+
+impl From<Normal3f> for OctNormal {
+    /// Encodes a normal. The normal doesn't need to be normalized; its
+    /// length is not preserved. A zero normal is encoded as (0, 0, 1).
+    ///
+    /// * `n` - The normal.
+    fn from(n: Normal3f) -> Self {
+        let l1 = n.x.abs() + n.y.abs() + n.z.abs();
+        if l1 == 0.0 {
+            return Self {
+                x: Self::encode(0.0),
+                y: Self::encode(0.0),
+            };
+        }
+
+        // Project onto the octahedron |x| + |y| + |z| = 1 and fold the lower
+        // hemisphere over the diagonals of the upper one.
+        let n = n / l1;
+        if n.z >= 0.0 {
+            Self {
+                x: Self::encode(n.x),
+                y: Self::encode(n.y),
+            }
+        } else {
+            Self {
+                x: Self::encode((1.0 - n.y.abs()) * sign(n.x)),
+                y: Self::encode((1.0 - n.x.abs()) * sign(n.y)),
+            }
+        }
+    }
+}
+
+impl From<OctNormal> for Normal3f {
+    /// Decodes a normal. The result is normalized.
+    ///
+    /// * `n` - The encoded normal.
+    fn from(n: OctNormal) -> Self {
+        let x = OctNormal::decode(n.x);
+        let y = OctNormal::decode(n.y);
+        let z = 1.0 - x.abs() - y.abs();
+
+        // Unfold the lower hemisphere.
+        let v = if z < 0.0 {
+            Normal3f::new((1.0 - y.abs()) * sign(x), (1.0 - x.abs()) * sign(y), z)
+        } else {
+            Normal3f::new(x, y, z)
+        };
+        v.normalize()
+    }
+}
+
+/// Returns -1 for negative values (including -0.0) and 1 otherwise.
+///
+/// * `v` - The value.
+#[inline]
+fn sign(v: Float) -> Float {
+    (1.0 as Float).copysign(v)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decoded_normals_are_within_quantization_error() {
+        let normals = [
+            Normal3f::new(0.0, 0.0, 1.0),
+            Normal3f::new(0.0, 0.0, -1.0),
+            Normal3f::new(1.0, 0.0, 0.0),
+            Normal3f::new(0.0, -1.0, 0.0),
+            Normal3f::new(0.3, -0.8, 0.2),
+            Normal3f::new(-0.5, 0.25, -0.9),
+            Normal3f::new(-3.0, -2.0, -1.0),
+        ];
+        for &n in normals.iter() {
+            let expected = n.normalize();
+            let decoded = Normal3f::from(OctNormal::from(n));
+            assert!((decoded.length() - 1.0).abs() < 1e-5, "{:?}", decoded);
+            assert!(decoded.dot(&expected) > 0.99999, "{:?} -> {:?}", n, decoded);
+        }
+    }
+}
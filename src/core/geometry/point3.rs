@@ -11,6 +11,7 @@ use std::ops::{
 
 /// A 3-D point containing numeric values.
 #[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[repr(C)]
 pub struct Point3<T> {
     /// X-coordinate.
     pub x: T,
@@ -415,7 +416,7 @@ mod tests {
     #[test]
     fn has_nans() {
         assert!(!Point3::new(0.0, 0.0, 0.0).has_nans());
-        assert!(Point3::new(f32::NAN, f32::NAN, f32::NAN).has_nans());
+        assert!(Point3::new(Float::NAN, Float::NAN, Float::NAN).has_nans());
         assert!(Point3::new(f64::NAN, f64::NAN, f64::NAN).has_nans());
     }
 
@@ -435,27 +436,27 @@ mod tests {
 
     // Define some properties for tests.
     prop_range!(range_i32, i32, -100..100i32);
-    prop_range!(range_f32, f32, -100.0..100.0f32);
+    prop_range!(range_f32, Float, -100.0..100.0 as Float);
 
     prop_non_zero_range!(non_zero_i32, i32, -100..100i32);
-    prop_non_zero_range!(non_zero_f32, f32, -100.0..100.0f32);
+    prop_non_zero_range!(non_zero_f32, Float, -100.0..100.0 as Float);
 
     prop_point3!(point3_i32, i32, -100..100i32, -100..100i32, -100..100i32);
     prop_point3!(
         point3_f32,
-        f32,
-        -100.0..100.0f32,
-        -100.0..100.0f32,
-        -100.0..100.0f32
+        Float,
+        -100.0..100.0 as Float,
+        -100.0..100.0 as Float,
+        -100.0..100.0 as Float
     );
 
     prop_vector3!(vector3_i32, i32, -100..100i32, -100..100i32, -100..100i32);
     prop_vector3!(
         vector3_f32,
-        f32,
-        -100.0..100.0f32,
-        -100.0..100.0f32,
-        -100.0..100.0f32
+        Float,
+        -100.0..100.0 as Float,
+        -100.0..100.0 as Float,
+        -100.0..100.0 as Float
     );
 
     proptest! {
@@ -523,7 +524,7 @@ mod tests {
         }
 
         #[test]
-        fn lerp_f32(p1 in point3_f32(), p2 in point3_f32(), t in -2.0..2.0f32) {
+        fn lerp_f32(p1 in point3_f32(), p2 in point3_f32(), t in -2.0..2.0 as Float) {
             prop_assert_eq!(lerp(t, p1, p2), (1.0 - t) * p1 + t * p2);
         }
 
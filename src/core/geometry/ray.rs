@@ -239,9 +239,9 @@ mod tests {
 
     #[test]
     fn has_nans() {
-        let nan_point = Point3::new(f32::NAN, f32::NAN, f32::NAN);
-        let nan_vector = Vector3::new(f32::NAN, f32::NAN, f32::NAN);
-        let nan_t_max = f32::NAN;
+        let nan_point = Point3::new(Float::NAN, Float::NAN, Float::NAN);
+        let nan_vector = Vector3::new(Float::NAN, Float::NAN, Float::NAN);
+        let nan_t_max = Float::NAN;
         let point = Point3::new(0.0, 0.0, 0.0);
         let vector = Vector3::new(1.0, 0.0, 0.0);
 
@@ -298,22 +298,22 @@ mod tests {
     }
 
     // Define some properties for tests.
-    prop_range!(range_f32, f32, -100.0..100.0f32);
+    prop_range!(range_f32, Float, -100.0..100.0 as Float);
 
     prop_point3!(
         point3_f32,
-        f32,
-        -100.0..100.0f32,
-        -100.0..100.0f32,
-        -100.0..100.0f32
+        Float,
+        -100.0..100.0 as Float,
+        -100.0..100.0 as Float,
+        -100.0..100.0 as Float
     );
 
     prop_vector3!(
         vector3_f32,
-        f32,
-        -100.0..100.0f32,
-        -100.0..100.0f32,
-        -100.0..100.0f32
+        Float,
+        -100.0..100.0 as Float,
+        -100.0..100.0 as Float,
+        -100.0..100.0 as Float
     );
 
     proptest! {
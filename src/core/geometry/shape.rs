@@ -4,6 +4,7 @@
 use crate::core::geometry::*;
 use crate::core::low_discrepency::radical_inverse;
 use crate::core::pbrt::*;
+use std::any::Any;
 use std::sync::Arc;
 
 /// Shape common functions
@@ -43,6 +44,18 @@ pub trait Shape {
     /// Returns the surface area of the shape in object space.
     fn area(&self) -> Float;
 
+    /// Returns the shape as `Any` so its type can be recovered, e.g. to store
+    /// it in the scene cache. Shapes that can't be stored return `None`.
+    fn as_any(&self) -> Option<&dyn Any> {
+        None
+    }
+
+    /// Returns the vertices of the triangles that make up the shape in world
+    /// space. Shapes that are not triangles return no triangles.
+    fn triangles(&self) -> Vec<[Point3f; 3]> {
+        vec![]
+    }
+
     /// Sample a point on the surface and return the PDF with respect to area on
     /// the surface.
     ///
@@ -0,0 +1,114 @@
+//! SIMD
+
+use crate::core::geometry::*;
+use crate::core::pbrt::*;
+use std::simd::num::SimdFloat;
+use std::simd::{Mask, Select, Simd, SimdElement};
+
+/// Four `Float` lanes. 3-D points and vectors use the first three lanes.
+pub type FloatX4 = Simd<Float, 4>;
+
+/// Mask selecting lanes of `FloatX4`.
+pub type MaskX4 = Mask<<Float as SimdElement>::Mask, 4>;
+
+impl From<Vector3f> for FloatX4 {
+    /// Loads a vector into the first three lanes; the last lane is 0.
+    ///
+    /// * `v` - The vector.
+    fn from(v: Vector3f) -> Self {
+        Simd::from_array([v.x, v.y, v.z, 0.0])
+    }
+}
+
+impl From<Point3f> for FloatX4 {
+    /// Loads a point into the first three lanes; the last lane is 0.
+    ///
+    /// * `p` - The point.
+    fn from(p: Point3f) -> Self {
+        Simd::from_array([p.x, p.y, p.z, 0.0])
+    }
+}
+
+/// Intersects a ray with the three slabs of a bounding box at once and
+/// returns `true` if it hits the box within the ray's extent. This is the
+/// vectorized form of `Bounds3::intersect_p_inv()`.
+///
+/// * `p_min`      - Minimum corner of the bounding box.
+/// * `p_max`      - Maximum corner of the bounding box.
+/// * `ray`        - The ray.
+/// * `inv_dir`    - Reciprocal of `ray`'s direction.
+/// * `dir_is_neg` - Ray direction is negative.
+#[inline]
+pub fn intersect_slabs_x4(
+    p_min: &Point3f,
+    p_max: &Point3f,
+    ray: &Ray,
+    inv_dir: &Vector3f,
+    dir_is_neg: [u8; 3],
+) -> bool {
+    let p_min = FloatX4::from(*p_min);
+    let p_max = FloatX4::from(*p_max);
+    let o = FloatX4::from(ray.o);
+    let inv_dir = FloatX4::from(*inv_dir);
+
+    // Pick the near and far planes of each slab based on the direction.
+    let is_neg = MaskX4::from_array([
+        dir_is_neg[0] == 1,
+        dir_is_neg[1] == 1,
+        dir_is_neg[2] == 1,
+        false,
+    ]);
+    let near = is_neg.select(p_max, p_min);
+    let far = is_neg.select(p_min, p_max);
+
+    // Update the far values to ensure robust bounds intersection.
+    let t_near = (near - o) * inv_dir;
+    let t_far = (far - o) * inv_dir * FloatX4::splat(1.0 + 2.0 * gamma(3));
+
+    // The last lane doesn't constrain the interval.
+    let xyz = MaskX4::from_array([true, true, true, false]);
+    let t_min = xyz.select(t_near, FloatX4::splat(-INFINITY)).reduce_max();
+    let t_max = xyz.select(t_far, FloatX4::splat(INFINITY)).reduce_min();
+    t_min <= t_max && t_min < ray.t_max && t_max > 0.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slab_test_matches_scalar_intersection() {
+        let b = Bounds3f::new(Point3f::new(-1.0, -2.0, -3.0), Point3f::new(1.0, 2.0, 3.0));
+        let directions = [
+            Vector3f::new(1.0, 0.5, 0.25),
+            Vector3f::new(-1.0, 0.5, -0.25),
+            Vector3f::new(0.3, -1.0, 0.1),
+            Vector3f::new(-0.2, -0.1, 1.0),
+        ];
+        let origins = [
+            Point3f::new(-5.0, 0.0, 0.0),
+            Point3f::new(5.0, -1.0, 2.0),
+            Point3f::new(0.0, 10.0, 0.0),
+            Point3f::new(4.0, 4.0, -10.0),
+            Point3f::new(0.0, 0.0, 0.0),
+        ];
+        for o in origins.iter() {
+            for d in directions.iter() {
+                let ray = Ray::new(*o, *d, INFINITY, 0.0, None);
+                let inv_dir = Vector3f::new(1.0 / d.x, 1.0 / d.y, 1.0 / d.z);
+                let dir_is_neg = [
+                    (inv_dir.x < 0.0) as u8,
+                    (inv_dir.y < 0.0) as u8,
+                    (inv_dir.z < 0.0) as u8,
+                ];
+                assert_eq!(
+                    intersect_slabs_x4(&b.p_min, &b.p_max, &ray, &inv_dir, dir_is_neg),
+                    b.intersect_p(&ray).is_some(),
+                    "o = {:?}, d = {:?}",
+                    o,
+                    d
+                );
+            }
+        }
+    }
+}
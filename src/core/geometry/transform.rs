@@ -518,35 +518,49 @@ impl Transform {
         // Transform p and p_error in SurfaceInteraction
         let (p, p_error) = self.transform_point_with_error(&si.hit.p);
 
-        // Transform remaining members of SurfaceInteraction
-        let mut si = SurfaceInteraction::new(
-            p,
-            p_error,
-            si.uv,
-            self.transform_vector(&si.hit.wo).normalize(),
-            self.transform_vector(&si.dpdu),
-            self.transform_vector(&si.dpdv),
-            self.transform_normal(&si.dndu),
-            self.transform_normal(&si.dndv),
-            si.hit.time,
-            si.shape.clone(),
-        );
-
-        // Transform n in SurfaceInteraction.hit
+        // Transform the geometric normal directly rather than recomputing it
+        // from ∂p/∂u x ∂p/∂v. The normal already accounts for
+        // `ReverseOrientation` and handedness of the object-to-world
+        // transformation so it must not be flipped a second time.
         let n = self.transform_normal(&si.hit.n).normalize();
-        si.hit.n = n;
 
-        // Handle transformations for shading parameters..
-        si.shading = Shading::new(
+        // Handle transformations for shading parameters.
+        let mut shading = Shading::new(
             self.transform_normal(&si.shading.n).normalize(),
             self.transform_vector(&si.shading.dpdu),
             self.transform_vector(&si.shading.dpdv),
             self.transform_normal(&si.shading.dndu),
             self.transform_normal(&si.shading.dndv),
         );
-        si.shading.n = si.shading.n.face_forward(&Vector3::from(n));
+        shading.n = shading.n.face_forward(&Vector3::from(n));
 
-        si
+        // Transform remaining members of SurfaceInteraction
+        SurfaceInteraction {
+            hit: Hit::new(
+                p,
+                si.hit.time,
+                p_error,
+                self.transform_vector(&si.hit.wo).normalize(),
+                n,
+                si.hit.medium_interface.clone(),
+            ),
+            uv: si.uv,
+            dpdu: self.transform_vector(&si.dpdu),
+            dpdv: self.transform_vector(&si.dpdv),
+            dndu: self.transform_normal(&si.dndu),
+            dndv: self.transform_normal(&si.dndv),
+            dudx: si.dudx,
+            dudy: si.dudy,
+            dvdx: si.dvdx,
+            dvdy: si.dvdy,
+            dpdx: self.transform_vector(&si.dpdx),
+            dpdy: self.transform_vector(&si.dpdy),
+            shading,
+            shape: si.shape.clone(),
+            bsdf: si.bsdf.clone(),
+            bssrdf: si.bssrdf.clone(),
+            primitive: si.primitive,
+        }
     }
 
     /// Returns `true` if the transformation changes the handedness of the
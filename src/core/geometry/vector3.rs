@@ -13,6 +13,7 @@ use std::ops::{
 
 /// A 3-D vector containing numeric values.
 #[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[repr(C)]
 pub struct Vector3<T> {
     /// X-coordinate.
     pub x: T,
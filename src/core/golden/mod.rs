@@ -0,0 +1,189 @@
+//! Golden Image Regression Tests
+//!
+//! Renders tiny embedded scenes at a few samples per pixel and compares them
+//! against reference images in `src/core/golden/references`. Samplers are
+//! seeded per tile so the renders are deterministic regardless of the number
+//! of threads. The references are rendered with RGB spectra, so the
+//! comparisons are skipped with the `sampled-spectrum` feature.
+//!
+//! After an intentional change to the rendered images, regenerate the
+//! references with:
+//!
+//! ```bash
+//! UPDATE_GOLDEN_IMAGES=1 cargo test golden
+//! ```
+
+#![allow(dead_code)]
+use crate::core::api::*;
+use crate::core::error::*;
+use std::path::PathBuf;
+
+/// Environment variable that makes the tests write the references instead
+/// of comparing against them.
+const UPDATE_ENV_VAR: &str = "UPDATE_GOLDEN_IMAGES";
+
+/// Settings shared by the scenes.
+const HEADER: &str = r#"
+LookAt 0 0 5  0 0 0  0 1 0
+Camera "perspective" "float fov" [30]
+Film "image" "integer xresolution" [16] "integer yresolution" [16]
+Sampler "random" "integer pixelsamples" [4]
+Integrator "whitted" "integer maxdepth" [5]
+"#;
+
+/// A diffuse sphere lit by a point light.
+const MATTE_SPHERE: &str = r#"
+WorldBegin
+LightSource "point" "rgb I" [20 20 20] "point from" [2 2 5]
+Material "matte" "rgb Kd" [0.8 0.3 0.2]
+Shape "sphere" "float radius" [1]
+WorldEnd
+"#;
+
+/// A glossy sphere lit by a distant light.
+const PLASTIC_SPHERE: &str = r#"
+WorldBegin
+LightSource "distant" "point from" [1 1 1] "point to" [0 0 0] "rgb L" [3 3 3]
+Material "plastic" "rgb Kd" [0.2 0.3 0.8] "rgb Ks" [0.5 0.5 0.5]
+    "float roughness" [0.1]
+Shape "sphere" "float radius" [1]
+WorldEnd
+"#;
+
+/// A glass sphere in front of a diffuse sphere lit by a point light.
+const GLASS_SPHERE: &str = r#"
+WorldBegin
+LightSource "point" "rgb I" [20 20 20] "point from" [0 3 3]
+AttributeBegin
+  Material "glass"
+  Translate -0.5 0 1
+  Shape "sphere" "float radius" [0.6]
+AttributeEnd
+AttributeBegin
+  Material "matte" "rgb Kd" [0.2 0.8 0.3]
+  Translate 0.5 0 -1
+  Shape "sphere" "float radius" [1]
+AttributeEnd
+WorldEnd
+"#;
+
+/// Renders a scene and compares it against its reference image; or writes
+/// the reference when `UPDATE_GOLDEN_IMAGES` is set.
+///
+/// * `name`      - Name of the reference image without extension.
+/// * `world`     - The world block of the scene.
+/// * `tolerance` - Maximum root mean square error of the RGB values in
+///                 [0, 1].
+fn check_golden_image(name: &str, world: &str, tolerance: f64) -> Result<(), Error> {
+    let scene = format!("{}{}", HEADER, world);
+    let rendered = render_to_rgba(&scene, |_, _| {})?;
+    let path = reference_path(name);
+
+    if std::env::var_os(UPDATE_ENV_VAR).is_some() {
+        image::save_buffer(
+            &path,
+            &rendered.pixels,
+            rendered.width as u32,
+            rendered.height as u32,
+            image::ColorType::Rgba8,
+        )
+        .map_err(|err| Error::Image(format!("{}", err)))?;
+        return Ok(());
+    }
+
+    let reference = image::open(&path)
+        .map_err(|err| {
+            Error::Image(format!(
+                "Unable to open golden image '{}'. Set {} to create it. {}",
+                path.display(),
+                UPDATE_ENV_VAR,
+                err
+            ))
+        })?
+        .to_rgba8();
+    if (reference.width() as usize, reference.height() as usize)
+        != (rendered.width, rendered.height)
+    {
+        return Err(Error::InvalidState(format!(
+            "Golden image '{}' is {}x{} but the render is {}x{}.",
+            name,
+            reference.width(),
+            reference.height(),
+            rendered.width,
+            rendered.height
+        )));
+    }
+
+    let error = rmse(&rendered.pixels, reference.as_raw());
+    if error > tolerance {
+        return Err(Error::InvalidState(format!(
+            "Golden image '{}' differs from the render with RMSE {:.4} > {:.4}.",
+            name, error, tolerance
+        )));
+    }
+    Ok(())
+}
+
+/// Returns the path of a reference image.
+///
+/// * `name` - Name of the reference image without extension.
+fn reference_path(name: &str) -> PathBuf {
+    [
+        env!("CARGO_MANIFEST_DIR"),
+        "src/core/golden/references",
+        &format!("{}.png", name),
+    ]
+    .iter()
+    .collect()
+}
+
+/// Returns the root mean square error between the RGB values of two RGBA
+/// images normalized to [0, 1]. Alpha is ignored.
+///
+/// * `a` - RGBA values of the first image.
+/// * `b` - RGBA values of the second image.
+fn rmse(a: &[u8], b: &[u8]) -> f64 {
+    let (sum, n) = a
+        .chunks_exact(4)
+        .zip(b.chunks_exact(4))
+        .flat_map(|(p, q)| p[..3].iter().zip(q[..3].iter()))
+        .fold((0.0, 0), |(sum, n), (&x, &y)| {
+            let d = (x as f64 - y as f64) / 255.0;
+            (sum + d * d, n + 1)
+        });
+    if n > 0 {
+        (sum / n as f64).sqrt()
+    } else {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rmse_of_identical_images_is_zero() {
+        let a = [10, 20, 30, 255, 40, 50, 60, 255];
+        assert_eq!(rmse(&a, &a), 0.0);
+        assert!((rmse(&[0, 0, 0, 0], &[255, 255, 255, 255]) - 1.0).abs() < 1e-12);
+    }
+
+    #[cfg(not(feature = "sampled-spectrum"))]
+    #[test]
+    fn golden_matte_sphere() {
+        check_golden_image("matte_sphere", MATTE_SPHERE, 0.01).unwrap();
+    }
+
+    #[cfg(not(feature = "sampled-spectrum"))]
+    #[test]
+    fn golden_plastic_sphere() {
+        check_golden_image("plastic_sphere", PLASTIC_SPHERE, 0.01).unwrap();
+    }
+
+    #[cfg(not(feature = "sampled-spectrum"))]
+    #[test]
+    fn golden_glass_sphere() {
+        check_golden_image("glass_sphere", GLASS_SPHERE, 0.01).unwrap();
+    }
+}
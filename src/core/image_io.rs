@@ -1,7 +1,10 @@
 //! Image I/O
 
 #![allow(dead_code)]
+use crate::core::camera_response::*;
+use crate::core::error::Error;
 use crate::core::geometry::*;
+use crate::core::logging::FILM;
 use crate::core::pbrt::*;
 use crate::core::spectrum::*;
 use exr::prelude as exrs;
@@ -10,6 +13,134 @@ use image::*;
 use regex::Regex;
 use std::result::Result;
 
+/// Stores the RGB and alpha values of an image as they are in the file.
+struct RGBAValues {
+    /// Image resolution.
+    resolution: exrs::Vec2<usize>,
+
+    /// RGB values in row-major order.
+    rgb: Vec<Float>,
+
+    /// Optional alpha values in row-major order.
+    alpha: Option<Vec<Float>>,
+}
+
+/// Compression of OpenEXR images.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ExrCompression {
+    /// No compression.
+    None,
+
+    /// Run length encoding; fast but only compresses flat areas well.
+    Rle,
+
+    /// ZIP compression of blocks of 16 scanlines.
+    Zip,
+
+    /// Wavelet compression that suits noisy images.
+    Piz,
+}
+
+impl ExrCompression {
+    /// Returns the compression with the given name; "none", "rle", "zip" or
+    /// "piz". Returns `None` if it is unknown.
+    ///
+    /// * `name` - The name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "none" => Some(Self::None),
+            "rle" => Some(Self::Rle),
+            "zip" => Some(Self::Zip),
+            "piz" => Some(Self::Piz),
+            _ => None,
+        }
+    }
+
+    /// Returns how the pixels of a layer are encoded. Images are stored in
+    /// scanlines.
+    fn encoding(&self) -> Encoding {
+        let compression = match self {
+            Self::None => Compression::Uncompressed,
+            Self::Rle => Compression::RLE,
+            Self::Zip => Compression::ZIP16,
+            Self::Piz => Compression::PIZ,
+        };
+        Encoding {
+            compression,
+            ..Encoding::SMALL_LOSSLESS
+        }
+    }
+}
+
+/// Options for writing OpenEXR images.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ExrOptions {
+    /// Store half precision floats instead of single precision.
+    pub half: bool,
+
+    /// The compression.
+    pub compression: ExrCompression,
+}
+
+impl Default for ExrOptions {
+    /// Returns single precision floats with ZIP compression.
+    fn default() -> Self {
+        Self {
+            half: false,
+            compression: ExrCompression::Zip,
+        }
+    }
+}
+
+/// Transfer function that encodes linear values for 8-bit images.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum TransferFunction {
+    /// The sRGB curve.
+    Srgb,
+
+    /// A power curve with the given gamma; 1 keeps the values linear.
+    Gamma(Float),
+}
+
+impl TransferFunction {
+    /// Returns the encoded value of a linear value.
+    ///
+    /// * `v` - The linear value.
+    pub fn encode(&self, v: Float) -> Float {
+        match self {
+            Self::Srgb => gamma_correct(v),
+            Self::Gamma(gamma) => {
+                if v > 0.0 {
+                    v.powf(1.0 / gamma)
+                } else {
+                    0.0
+                }
+            }
+        }
+    }
+}
+
+/// Options for writing 8-bit images.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LdrOptions {
+    /// Transfer function applied to the linear RGB values.
+    pub transfer: TransferFunction,
+
+    /// Add noise of about one step of the 8-bit values to break up banding
+    /// in smooth gradients.
+    pub dither: bool,
+}
+
+impl Default for LdrOptions {
+    /// Returns the sRGB curve without dithering.
+    fn default() -> Self {
+        Self {
+            transfer: TransferFunction::Srgb,
+            dither: false,
+        }
+    }
+}
+
 /// Stores RGB image data.
 pub struct RGBImage {
     /// The pixels.
@@ -19,24 +150,40 @@ pub struct RGBImage {
     pub resolution: Point2<usize>,
 }
 
-/// Read an image.
+impl RGBImage {
+    /// Converts the pixels from the given color space to the rendering color
+    /// space.
+    ///
+    /// * `color_space` - The color space of the pixels.
+    fn convert_to_rendering_color_space(&mut self, color_space: ColorSpace) {
+        let rendering_color_space = rendering_color_space();
+        if color_space != rendering_color_space {
+            for pixel in self.pixels.iter_mut() {
+                let rgb = color_space.convert(&pixel.to_rgb(), rendering_color_space);
+                *pixel = RGBSpectrum::from(rgb);
+            }
+        }
+    }
+}
+
+/// Read an image. The pixel values are converted to the rendering color space.
 ///
 /// * `path` - Input file path.
-pub fn read_image(path: &str) -> Result<RGBImage, String> {
+pub fn read_image(path: &str) -> Result<RGBImage, Error> {
     match get_extension_from_filename(path) {
         Some(".exr") => read_exr(path),
         Some(_extension) => read_8_bit(path),
-        None => Err(format!(
+        None => Err(Error::Unsupported(format!(
             "Can't determine file type from suffix of filename {}.",
             path
-        )),
+        ))),
     }
 }
 
 /// Read a single layer OpenEXR file.
 ///
 /// * `path` - Input file path.
-fn read_exr(path: &str) -> Result<RGBImage, String> {
+fn read_exr(path: &str) -> Result<RGBImage, Error> {
     let reader = exrs::read()
         .no_deep_data()
         .largest_resolution_level()
@@ -52,30 +199,46 @@ fn read_exr(path: &str) -> Result<RGBImage, String> {
             |img: &mut RGBImage, position: exrs::Vec2<usize>, pixel: exrs::RgbaPixel| {
                 let offset = position.y() * img.resolution.x + position.x();
                 img.pixels[offset] = RGBSpectrum::from(vec![
-                    pixel.red.to_f32(),
-                    pixel.green.to_f32(),
-                    pixel.blue.to_f32(),
+                    pixel.red.to_f32() as Float,
+                    pixel.green.to_f32() as Float,
+                    pixel.blue.to_f32() as Float,
                 ]);
             },
         )
         .first_valid_layer()
         .all_attributes();
 
+    // Determine the color space from the chromaticities; OpenEXR defaults to
+    // Rec.709 (sRGB) primaries.
+    let image = reader
+        .from_file(path)
+        .map_err(|err| Error::Image(format!("{}. {:}", path, err)))?;
+    let color_space = match image.attributes.chromaticities {
+        Some(c) => {
+            let xy = |v: exrs::Vec2<f32>| [v.x() as Float, v.y() as Float];
+            ColorSpace::from_chromaticities(xy(c.red), xy(c.green), xy(c.blue), xy(c.white))
+                .unwrap_or_else(|| {
+                    warn!(target: FILM, "{}: unsupported chromaticities; assuming sRGB.", path);
+                    ColorSpace::SRGB
+                })
+        }
+        None => ColorSpace::SRGB,
+    };
+
     // Return the `RGBImage`.
-    match reader.from_file(path) {
-        Ok(image) => Ok(image.layer_data.channel_data.storage),
-        Err(err) => Err(format!("{:}", err)),
-    }
+    let mut rgb_image = image.layer_data.channel_data.storage;
+    rgb_image.convert_to_rendering_color_space(color_space);
+    Ok(rgb_image)
 }
 
 /// Read an 8-bit image format.
 ///
 /// * `path` - Input file path.
-fn read_8_bit(path: &str) -> Result<RGBImage, String> {
+fn read_8_bit(path: &str) -> Result<RGBImage, Error> {
     // Read image and convert to RGB.
     let img: RgbImage = match open(path) {
         Ok(i) => i.into_rgb8(),
-        Err(err) => return Err(format!("{:}", err)),
+        Err(err) => return Err(Error::Image(format!("{}. {:}", path, err))),
     };
 
     // Read metadata.
@@ -95,29 +258,50 @@ fn read_8_bit(path: &str) -> Result<RGBImage, String> {
         })
         .collect();
 
-    // Return the `RGBImage`.
-    Ok(RGBImage { pixels, resolution })
+    // Return the `RGBImage`; 8-bit images are assumed to be sRGB.
+    let mut rgb_image = RGBImage { pixels, resolution };
+    rgb_image.convert_to_rendering_color_space(ColorSpace::SRGB);
+    Ok(rgb_image)
 }
 
 /// Write the output image to given path.
 ///
 /// * `path`             - Output file path.
 /// * `rgb`              - Floating point RGB pixel data.
+/// * `alpha`            - Optional alpha values of the pixels. The RGB values
+///                        are premultiplied by alpha.
+/// * `response`         - Optional camera response used instead of gamma
+///                        correction for 8-bit images.
+/// * `ldr`              - Options for 8-bit images.
+/// * `exr`              - Options for OpenEXR images.
 /// * `output_bounds`    - The bounds for the image output.
-pub fn write_image(path: &str, rgb: &[Float], output_bounds: &Bounds2i) -> Result<(), String> {
+pub fn write_image(
+    path: &str,
+    rgb: &[Float],
+    alpha: Option<&[Float]>,
+    response: Option<&CameraResponse>,
+    ldr: &LdrOptions,
+    exr: &ExrOptions,
+    output_bounds: &Bounds2i,
+) -> Result<(), Error> {
     let resolution = output_bounds.diagonal();
     let res_x = resolution.x as u32;
     let res_y = resolution.y as u32;
 
+    let encoding = Encoding8Bit { response, ldr };
     match get_extension_from_filename(path) {
-        Some(".exr") => write_exr(path, rgb, res_x, res_y),
-        Some(".tga") => write_8_bit(path, rgb, res_x, res_y, ImageFormat::Tga),
-        Some(".png") => write_8_bit(path, rgb, res_x, res_y, ImageFormat::Png),
-        Some(extension) => Err(format!("Extension {} is not supported", extension)),
-        None => Err(format!(
+        Some(".exr") => write_exr(path, rgb, alpha, exr, res_x, res_y),
+        Some(".pfm") => write_pfm(path, rgb, res_x, res_y),
+        Some(".tga") => write_8_bit(path, rgb, alpha, &encoding, res_x, res_y, ImageFormat::Tga),
+        Some(".png") => write_8_bit(path, rgb, alpha, &encoding, res_x, res_y, ImageFormat::Png),
+        Some(extension) => Err(Error::Unsupported(format!(
+            "Extension {} is not supported",
+            extension
+        ))),
+        None => Err(Error::Unsupported(format!(
             "Can't determine file type from suffix of filename {}",
             path
-        )),
+        ))),
     }
 }
 
@@ -138,75 +322,621 @@ fn get_extension_from_filename(path: &str) -> Option<&str> {
         .map(|c| c.get(1).map_or("", |m| m.as_str()))
 }
 
-/// Writes the image in OpenEXR format.
+/// Writes the image in OpenEXR format. Alpha is stored with the
+/// premultiplied RGB values.
 ///
 /// * `path`        - Output file path.
 /// * `rgb`         - Floating point RGB pixel data.
+/// * `alpha`       - Optional alpha values of the pixels.
+/// * `exr`         - Pixel type and compression.
 /// * `res_x`       - X resolution.
 /// * `res_y`       - Y resolution.
-fn write_exr(path: &str, rgb: &[Float], res_x: u32, res_y: u32) -> Result<(), String> {
-    info!("Writing image {} with resolution {}x{}", path, res_x, res_y);
-    match write_rgb_f32_file(
-        String::from(path),
+fn write_exr(
+    path: &str,
+    rgb: &[Float],
+    alpha: Option<&[Float]>,
+    exr: &ExrOptions,
+    res_x: u32,
+    res_y: u32,
+) -> Result<(), Error> {
+    info!(target: FILM, "Writing image {} with resolution {}x{}", path, res_x, res_y);
+    let sample_types = match (alpha.is_some(), exr.half) {
+        (true, true) => RgbaSampleTypes::RGBA_F16,
+        (true, false) => RgbaSampleTypes::RGBA_F32,
+        (false, true) => RgbaSampleTypes::RGB_F16,
+        (false, false) => RgbaSampleTypes::RGB_F32,
+    };
+    let channels = RgbaChannels::new(sample_types, |p: exrs::Vec2<usize>| {
+        let offset = p.y() * (res_x as usize) + p.x();
+        RgbaPixel::new(
+            rgb[3 * offset] as f32,
+            rgb[3 * offset + 1] as f32,
+            rgb[3 * offset + 2] as f32,
+            alpha.map(|alpha| alpha[offset] as f32),
+        )
+    });
+    let image = Image::with_encoded_single_layer(
         (res_x as usize, res_y as usize),
-        |x, y| {
-            let offset = y * (res_x as usize) + x;
-            (rgb[offset], rgb[offset + 1], rgb[offset + 2])
-        },
-    ) {
+        exr.compression.encoding(),
+        channels,
+    );
+    let result = image.write().to_file(path);
+    match result {
         Ok(()) => Ok(()),
-        Err(err) => Err(format!("Error saving output image {}. {:}.", path, err)),
+        Err(err) => Err(Error::Image(format!(
+            "Error saving output image {}. {:}.",
+            path, err
+        ))),
     }
 }
 
-/// Writes the image in an 8-bit image format.
+/// Writes the image in an 8-bit image format. Alpha is stored with RGB values
+/// that aren't premultiplied.
 ///
 /// * `path`         - Output file path.
 /// * `rgb`          - Floating point RGB pixel data.
+/// * `alpha`        - Optional alpha values of the pixels.
+/// * `encoding`     - How the RGB values are encoded.
 /// * `res_x`        - X resolution.
 /// * `res_y`        - Y resolution.
 /// * `image_format` - Image format.
 fn write_8_bit(
     path: &str,
     rgb: &[Float],
+    alpha: Option<&[Float]>,
+    encoding: &Encoding8Bit,
     res_x: u32,
     res_y: u32,
     image_format: ImageFormat,
-) -> std::result::Result<(), String> {
-    info!("Writing image {} with resolution {}x{}", path, res_x, res_y);
+) -> Result<(), Error> {
+    info!(target: FILM, "Writing image {} with resolution {}x{}", path, res_x, res_y);
 
     // Allocate an image buffer.
     let mut imgbuf = ImageBuffer::new(res_x, res_y);
     let mut offset = 0;
     for y in 0..res_y {
         for x in 0..res_x {
-            // 8-bit format; apply gamma and clamp.
-            let rgb = apply_gamma(&[rgb[offset], rgb[offset + 1], rgb[offset + 2]]);
-            imgbuf.put_pixel(x, res_y - 1 - y, Rgb(rgb));
+            let a = alpha.map_or(1.0, |alpha| alpha[offset / 3]);
+            let y = res_y - 1 - y;
+            imgbuf.put_pixel(x, y, encoding.pixel(&rgb[offset..offset + 3], a, x, y));
             offset += 3;
         }
     }
 
-    // Write the output file.
-    match imgbuf.save_with_format(String::from(path), image_format) {
+    // Write the output file; without alpha as RGB.
+    let result = match alpha {
+        Some(_) => imgbuf.save_with_format(String::from(path), image_format),
+        None => DynamicImage::ImageRgba8(imgbuf)
+            .to_rgb8()
+            .save_with_format(String::from(path), image_format),
+    };
+    match result {
         Ok(()) => Ok(()),
-        Err(err) => Err(format!("Error saving output image {}. {:}.", path, err)),
+        Err(err) => Err(Error::Image(format!(
+            "Error saving output image {}. {:}.",
+            path, err
+        ))),
     }
 }
 
-/// Apply gamma correction to a RGB floating point pixel and return the
-/// clamped 8-bit values.
+/// Writes the image in PFM format. The linear RGB values are stored as 32-bit
+/// floats; alpha isn't stored.
 ///
-/// * `rgb` - RGB floating point pixel value.
-#[inline]
-fn apply_gamma(rgb: &[Float; 3]) -> [u8; 3] {
-    [clamp_byte(rgb[0]), clamp_byte(rgb[1]), clamp_byte(rgb[2])]
+/// * `path`  - Output file path.
+/// * `rgb`   - Floating point RGB pixel data.
+/// * `res_x` - X resolution.
+/// * `res_y` - Y resolution.
+fn write_pfm(path: &str, rgb: &[Float], res_x: u32, res_y: u32) -> Result<(), Error> {
+    info!(target: FILM, "Writing image {} with resolution {}x{}", path, res_x, res_y);
+
+    // A negative scale marks little endian values. PFM stores the bottom
+    // scanline first; the rows are in the same order as in 8-bit images.
+    let header = format!("PF\n{} {}\n-1\n", res_x, res_y);
+    let mut data = Vec::with_capacity(header.len() + 4 * rgb.len());
+    data.extend_from_slice(header.as_bytes());
+    for v in rgb.iter() {
+        data.extend_from_slice(&(*v as f32).to_le_bytes());
+    }
+    std::fs::write(path, data)
+        .map_err(|err| Error::Image(format!("Error saving output image {}. {:}.", path, err)))
+}
+
+/// Reads the RGB values of a PFM image in the order they are stored; the
+/// values of greyscale images are repeated for each channel. Returns the
+/// values and the resolution.
+///
+/// * `path` - Input file path.
+fn read_pfm(path: &str) -> Result<(Vec<Float>, Point2i), Error> {
+    let invalid = || Error::Image(format!("{}. Invalid PFM header.", path));
+    let data = std::fs::read(path).map_err(|err| Error::Image(format!("{}. {:}", path, err)))?;
+
+    // The header is three whitespace separated tokens after the type.
+    let mut tokens = Vec::with_capacity(4);
+    let mut start = 0;
+    let mut i = 0;
+    while tokens.len() < 4 && i < data.len() {
+        if data[i].is_ascii_whitespace() {
+            if i > start {
+                tokens.push(String::from_utf8_lossy(&data[start..i]).into_owned());
+            }
+            start = i + 1;
+        }
+        i += 1;
+    }
+    if tokens.len() < 4 {
+        return Err(invalid());
+    }
+    let n_channels = match tokens[0].as_str() {
+        "PF" => 3,
+        "Pf" => 1,
+        _ => return Err(invalid()),
+    };
+    let width = tokens[1].parse::<usize>().map_err(|_| invalid())?;
+    let height = tokens[2].parse::<usize>().map_err(|_| invalid())?;
+    let scale = tokens[3].parse::<f32>().map_err(|_| invalid())?;
+
+    let n = width * height;
+    let values = &data[start..];
+    if values.len() < 4 * n_channels * n {
+        return Err(Error::Image(format!("{}. Not enough pixel data.", path)));
+    }
+    let copies = if n_channels == 1 { 3 } else { 1 };
+    let mut rgb = Vec::with_capacity(3 * n);
+    for bytes in values.chunks_exact(4).take(n_channels * n) {
+        let bytes = [bytes[0], bytes[1], bytes[2], bytes[3]];
+        let v = if scale < 0.0 {
+            f32::from_le_bytes(bytes)
+        } else {
+            f32::from_be_bytes(bytes)
+        };
+        for _ in 0..copies {
+            rgb.push(v as Float);
+        }
+    }
+    Ok((rgb, Point2i::new(width as Int, height as Int)))
 }
 
-/// Clamp floating point value to 8-bit range [0, 255].
+/// Writes the pixels of a region of an image into an existing image file,
+/// keeping the pixels outside the region. If the file doesn't exist, the
+/// pixels outside the region are black.
+///
+/// * `path`       - Output file path.
+/// * `rgb`        - Floating point RGB pixel data of the region.
+/// * `alpha`      - Optional alpha values of the pixels of the region. The RGB
+///                  values are premultiplied by alpha.
+/// * `response`   - Optional camera response used instead of gamma
+///                  correction for 8-bit images.
+/// * `ldr`        - Options for 8-bit images.
+/// * `exr`        - Options for OpenEXR images.
+/// * `region`     - The bounds of the region.
+/// * `resolution` - Resolution of the whole image.
+#[allow(clippy::too_many_arguments)]
+pub fn write_image_region(
+    path: &str,
+    rgb: &[Float],
+    alpha: Option<&[Float]>,
+    response: Option<&CameraResponse>,
+    ldr: &LdrOptions,
+    exr: &ExrOptions,
+    region: &Bounds2i,
+    resolution: &Point2i,
+) -> Result<(), Error> {
+    let encoding = Encoding8Bit { response, ldr };
+    match get_extension_from_filename(path) {
+        Some(".exr") => write_exr_region(path, rgb, alpha, exr, region, resolution),
+        Some(".pfm") => write_pfm_region(path, rgb, region, resolution),
+        Some(".tga") => {
+            let format = ImageFormat::Tga;
+            write_8_bit_region(path, rgb, alpha, &encoding, region, resolution, format)
+        }
+        Some(".png") => {
+            let format = ImageFormat::Png;
+            write_8_bit_region(path, rgb, alpha, &encoding, region, resolution, format)
+        }
+        Some(extension) => Err(Error::Unsupported(format!(
+            "Extension {} is not supported",
+            extension
+        ))),
+        None => Err(Error::Unsupported(format!(
+            "Can't determine file type from suffix of filename {}",
+            path
+        ))),
+    }
+}
+
+/// Replaces a region of an OpenEXR image. The image keeps an alpha channel
+/// if it has one.
+///
+/// * `path`       - Output file path.
+/// * `rgb`        - Floating point RGB pixel data of the region.
+/// * `alpha`      - Optional alpha values of the pixels of the region.
+/// * `exr`        - Pixel type and compression.
+/// * `region`     - The bounds of the region.
+/// * `resolution` - Resolution of the whole image.
+fn write_exr_region(
+    path: &str,
+    rgb: &[Float],
+    alpha: Option<&[Float]>,
+    exr: &ExrOptions,
+    region: &Bounds2i,
+    resolution: &Point2i,
+) -> Result<(), Error> {
+    let res_x = resolution.x as u32;
+    let res_y = resolution.y as u32;
+    let n = (res_x * res_y) as usize;
+    let (mut image_rgb, mut image_alpha, has_alpha) = match read_exr_rgba(path) {
+        Ok(image) => {
+            let (width, height) = (image.resolution.width(), image.resolution.height());
+            if (width, height) != (res_x as usize, res_y as usize) {
+                return Err(Error::InvalidState(format!(
+                    "Can't update region of {}; its resolution is {}x{} instead of {}x{}.",
+                    path, width, height, res_x, res_y
+                )));
+            }
+            let has_alpha = image.alpha.is_some();
+            (
+                image.rgb,
+                image.alpha.unwrap_or_else(|| vec![1.0; n]),
+                has_alpha,
+            )
+        }
+        Err(err) => {
+            warn!(target: FILM, "{}. Writing region into an empty image.", err);
+            (vec![0.0; 3 * n], vec![0.0; n], false)
+        }
+    };
+
+    for (i, p) in region.into_iter().enumerate() {
+        let offset = p.y as usize * res_x as usize + p.x as usize;
+        image_rgb[3 * offset..3 * offset + 3].copy_from_slice(&rgb[3 * i..3 * i + 3]);
+        image_alpha[offset] = alpha.map_or(1.0, |alpha| alpha[i]);
+    }
+
+    let image_alpha = if has_alpha || alpha.is_some() {
+        Some(&image_alpha[..])
+    } else {
+        None
+    };
+    write_exr(path, &image_rgb, image_alpha, exr, res_x, res_y)
+}
+
+/// Replaces a region of a PFM image.
+///
+/// * `path`       - Output file path.
+/// * `rgb`        - Floating point RGB pixel data of the region.
+/// * `region`     - The bounds of the region.
+/// * `resolution` - Resolution of the whole image.
+fn write_pfm_region(
+    path: &str,
+    rgb: &[Float],
+    region: &Bounds2i,
+    resolution: &Point2i,
+) -> Result<(), Error> {
+    info!(target: FILM, "Writing region of image {}", path);
+    let res_x = resolution.x as u32;
+    let res_y = resolution.y as u32;
+    let mut image_rgb = match read_pfm(path) {
+        Ok((image_rgb, image_resolution)) => {
+            if image_resolution != *resolution {
+                return Err(Error::InvalidState(format!(
+                    "Can't update region of {}; its resolution is {}x{} instead of {}x{}.",
+                    path, image_resolution.x, image_resolution.y, res_x, res_y
+                )));
+            }
+            image_rgb
+        }
+        Err(err) => {
+            warn!(target: FILM, "{}. Writing region into an empty image.", err);
+            vec![0.0; 3 * (res_x * res_y) as usize]
+        }
+    };
+
+    for (i, p) in region.into_iter().enumerate() {
+        let offset = 3 * (p.y as usize * res_x as usize + p.x as usize);
+        image_rgb[offset..offset + 3].copy_from_slice(&rgb[3 * i..3 * i + 3]);
+    }
+    write_pfm(path, &image_rgb, res_x, res_y)
+}
+
+/// Reads the RGB values and the alpha values, if any, of an OpenEXR image
+/// without converting them.
+///
+/// * `path` - Input file path.
+fn read_exr_rgba(path: &str) -> Result<RGBAValues, Error> {
+    // Read the flat channels; the RGBA pixel reader loses the optional alpha
+    // channel for blocks spanning several scanlines (ZIP16, PIZ).
+    let image = exrs::read_first_flat_layer_from_file(path)
+        .map_err(|err| Error::Image(format!("{}. {:}", path, err)))?;
+    let layer = image.layer_data;
+    let n = layer.size.area();
+    let channel = |name: &str| {
+        layer
+            .channel_data
+            .list
+            .iter()
+            .find(|c| c.name.eq(name))
+            .map(|c| {
+                c.sample_data
+                    .values_as_f32()
+                    .map(|v| v as Float)
+                    .collect::<Vec<Float>>()
+            })
+    };
+
+    let mut rgb = vec![0.0; 3 * n];
+    for (c, name) in ["R", "G", "B"].iter().enumerate() {
+        let values = channel(name)
+            .ok_or_else(|| Error::Image(format!("{}. Missing channel '{}'.", path, name)))?;
+        for (i, v) in values.into_iter().enumerate() {
+            rgb[3 * i + c] = v;
+        }
+    }
+
+    Ok(RGBAValues {
+        resolution: layer.size,
+        rgb,
+        alpha: channel("A"),
+    })
+}
+
+/// Replaces a region of an image in an 8-bit image format. The image keeps an
+/// alpha channel if it has one.
+///
+/// * `path`         - Output file path.
+/// * `rgb`          - Floating point RGB pixel data of the region.
+/// * `alpha`        - Optional alpha values of the pixels of the region.
+/// * `encoding`     - How the RGB values are encoded.
+/// * `region`       - The bounds of the region.
+/// * `resolution`   - Resolution of the whole image.
+/// * `image_format` - Image format.
+fn write_8_bit_region(
+    path: &str,
+    rgb: &[Float],
+    alpha: Option<&[Float]>,
+    encoding: &Encoding8Bit,
+    region: &Bounds2i,
+    resolution: &Point2i,
+    image_format: ImageFormat,
+) -> Result<(), Error> {
+    info!(target: FILM, "Writing region of image {}", path);
+    let res_x = resolution.x as u32;
+    let res_y = resolution.y as u32;
+
+    let (mut imgbuf, has_alpha) = match open(path) {
+        Ok(img) => {
+            if img.width() != res_x || img.height() != res_y {
+                return Err(Error::InvalidState(format!(
+                    "Can't update region of {}; its resolution is {}x{} instead of {}x{}.",
+                    path,
+                    img.width(),
+                    img.height(),
+                    res_x,
+                    res_y
+                )));
+            }
+            let has_alpha = img.color().has_alpha();
+            (img.into_rgba8(), has_alpha)
+        }
+        Err(err) => {
+            warn!(target: FILM, "{}. {:}. Writing region into an empty image.", path, err);
+            (ImageBuffer::new(res_x, res_y), false)
+        }
+    };
+
+    for (i, p) in region.into_iter().enumerate() {
+        let a = alpha.map_or(1.0, |alpha| alpha[i]);
+        let (x, y) = (p.x as u32, res_y - 1 - p.y as u32);
+        imgbuf.put_pixel(x, y, encoding.pixel(&rgb[3 * i..3 * i + 3], a, x, y));
+    }
+
+    let result = if has_alpha || alpha.is_some() {
+        imgbuf.save_with_format(String::from(path), image_format)
+    } else {
+        DynamicImage::ImageRgba8(imgbuf)
+            .to_rgb8()
+            .save_with_format(String::from(path), image_format)
+    };
+    match result {
+        Ok(()) => Ok(()),
+        Err(err) => Err(Error::Image(format!(
+            "Error saving output image {}. {:}.",
+            path, err
+        ))),
+    }
+}
+
+/// How the RGB values of 8-bit images are encoded.
+struct Encoding8Bit<'a> {
+    /// Optional camera response used instead of the transfer function.
+    response: Option<&'a CameraResponse>,
+
+    /// Transfer function and dithering.
+    ldr: &'a LdrOptions,
+}
+
+impl<'a> Encoding8Bit<'a> {
+    /// Returns the 8-bit value of a pixel with RGB values premultiplied by
+    /// alpha. The transfer function or the camera response is applied to the
+    /// RGB values that aren't premultiplied.
+    ///
+    /// * `rgb` - RGB floating point pixel value.
+    /// * `a`   - Alpha.
+    /// * `x`   - X coordinate of the pixel in the image.
+    /// * `y`   - Y coordinate of the pixel in the image.
+    fn pixel(&self, rgb: &[Float], a: Float, x: u32, y: u32) -> Rgba<u8> {
+        let inv_a = if a > 0.0 { 1.0 / a } else { 0.0 };
+        let mut encoded = [0; 3];
+        for (c, e) in encoded.iter_mut().enumerate() {
+            let linear = rgb[c] * inv_a;
+            let v = match self.response {
+                Some(response) => response.apply(c, linear),
+                None => self.ldr.transfer.encode(linear),
+            };
+            let noise = if self.ldr.dither {
+                dither(x, y, c as u32)
+            } else {
+                0.0
+            };
+            *e = clamp(255.0 * v + 0.5 + noise, 0.0, 255.0) as u8;
+        }
+        Rgba([encoded[0], encoded[1], encoded[2], clamp_byte_linear(a)])
+    }
+}
+
+/// Returns noise with a triangular distribution in (-1, 1) for dithering a
+/// channel of a pixel. It only depends on the pixel, so re-rendered regions
+/// match the rest of the image.
+///
+/// * `x`       - X coordinate of the pixel.
+/// * `y`       - Y coordinate of the pixel.
+/// * `channel` - The channel.
+fn dither(x: u32, y: u32, channel: u32) -> Float {
+    let hash = |mut v: u32| {
+        v ^= v >> 16;
+        v = v.wrapping_mul(0x7feb352d);
+        v ^= v >> 15;
+        v = v.wrapping_mul(0x846ca68b);
+        v ^ (v >> 16)
+    };
+    let h = hash(x ^ hash(y ^ hash(channel)));
+    let u0 = (h & 0xffff) as Float / 65536.0;
+    let u1 = (h >> 16) as Float / 65536.0;
+    u0 + u1 - 1.0
+}
+
+/// Clamp floating point value without gamma correction to 8-bit range
+/// [0, 255].
 ///
 /// * `v` - Value to clamp.
 #[inline]
-fn clamp_byte(v: Float) -> u8 {
-    clamp(255.0 * gamma_correct(v) + 0.5, 0.0, 255.0) as u8
+fn clamp_byte_linear(v: Float) -> u8 {
+    clamp(255.0 * v + 0.5, 0.0, 255.0) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn writing_region_keeps_other_pixels() {
+        let resolution = Point2i::new(4, 3);
+        let full = Bounds2i::new(Point2i::new(0, 0), resolution);
+        let region = Bounds2i::new(Point2i::new(1, 1), Point2i::new(3, 2));
+        for name in ["pbr_rust_region.exr", "pbr_rust_region.png"] {
+            let path = std::env::temp_dir().join(name);
+            let path = path.to_str().unwrap();
+            let (ldr, exr) = (LdrOptions::default(), ExrOptions::default());
+            write_image(path, &[0.25; 3 * 12], None, None, &ldr, &exr, &full).unwrap();
+            write_image_region(
+                path,
+                &[1.0; 3 * 2],
+                None,
+                None,
+                &ldr,
+                &exr,
+                &region,
+                &resolution,
+            )
+            .unwrap();
+
+            // The 8-bit image is written bottom to top.
+            let image = read_image(path).unwrap();
+            for p in full {
+                let y = if name.ends_with(".png") { 2 - p.y } else { p.y };
+                let v = image.pixels[(y * 4 + p.x) as usize][0];
+                if region.contains_exclusive(&p) {
+                    assert_eq!(v, 1.0);
+                } else {
+                    assert!(v < 1.0);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn exr_images_keep_values_above_one() {
+        let resolution = Point2i::new(5, 3);
+        let full = Bounds2i::new(Point2i::new(0, 0), resolution);
+        let rgb: Vec<Float> = (0..3 * 15).map(|i| 0.37 * i as Float).collect();
+        let alpha: Vec<Float> = (0..15).map(|i| i as Float / 14.0).collect();
+        let path = std::env::temp_dir().join("pbr_rust_options.exr");
+        let path = path.to_str().unwrap();
+        for compression in ["none", "rle", "zip", "piz"].iter() {
+            for half in [false, true].iter() {
+                let exr = ExrOptions {
+                    half: *half,
+                    compression: ExrCompression::from_name(compression).unwrap(),
+                };
+                let ldr = LdrOptions::default();
+                write_image(path, &rgb, Some(&alpha), None, &ldr, &exr, &full).unwrap();
+
+                let image = read_exr_rgba(path).unwrap();
+                let tolerance = if *half { 0.01 } else { 1e-6 };
+                for (a, b) in image.rgb.iter().zip(rgb.iter()) {
+                    assert!((a - b).abs() <= tolerance * b.max(1.0), "{} {}", a, b);
+                }
+                let image_alpha = image.alpha.unwrap();
+                assert!((image_alpha[14] - 1.0).abs() < 1e-6);
+            }
+        }
+        assert!(ExrCompression::from_name("b44").is_none());
+    }
+
+    #[test]
+    fn pfm_images_keep_linear_values() {
+        let resolution = Point2i::new(3, 2);
+        let full = Bounds2i::new(Point2i::new(0, 0), resolution);
+        let region = Bounds2i::new(Point2i::new(1, 0), Point2i::new(2, 1));
+        let rgb: Vec<Float> = (0..3 * 6).map(|i| 0.5 * i as Float).collect();
+        let path = std::env::temp_dir().join("pbr_rust_linear.pfm");
+        let path = path.to_str().unwrap();
+        let (ldr, exr) = (LdrOptions::default(), ExrOptions::default());
+        write_image(path, &rgb, None, None, &ldr, &exr, &full).unwrap();
+        assert_eq!(read_pfm(path).unwrap(), (rgb.clone(), resolution));
+
+        write_image_region(
+            path,
+            &[-1.0; 3],
+            None,
+            None,
+            &ldr,
+            &exr,
+            &region,
+            &resolution,
+        )
+        .unwrap();
+        let (image_rgb, _) = read_pfm(path).unwrap();
+        assert_eq!(image_rgb[3..6], [-1.0; 3]);
+        assert_eq!(image_rgb[6..], rgb[6..]);
+    }
+
+    #[test]
+    fn ldr_options_encode_8_bit_values() {
+        let srgb = LdrOptions::default();
+        let linear = LdrOptions {
+            transfer: TransferFunction::Gamma(1.0),
+            dither: false,
+        };
+        let encode = |ldr: &LdrOptions, v: Float, x: u32| {
+            let encoding = Encoding8Bit {
+                response: None,
+                ldr,
+            };
+            encoding.pixel(&[v; 3], 1.0, x, 0)[0]
+        };
+        assert_eq!(encode(&srgb, 0.2, 0), 124);
+        assert_eq!(encode(&linear, 0.2, 0), 51);
+        let gamma = TransferFunction::Gamma(2.2);
+        assert!((gamma.encode(0.2) - 0.2f32.powf(1.0 / 2.2) as Float).abs() < 1e-6);
+
+        // Dithering keeps the average of a flat area.
+        let dithered = LdrOptions {
+            dither: true,
+            ..linear
+        };
+        let values: Vec<u8> = (0..1000).map(|x| encode(&dithered, 0.201, x)).collect();
+        assert!(values.iter().any(|v| *v != values[0]));
+        let mean = values.iter().map(|v| *v as Float).sum::<Float>() / 1000.0;
+        assert!((mean - 0.201 * 255.0).abs() < 0.1);
+    }
 }
@@ -1,7 +1,13 @@
 //! Common
 
+use super::{add_lpe_radiance, is_recording_lpe_path, pop_lpe_event, push_lpe_weight};
+use super::{trace_at_last_hit, without_lpe_path, TraceEvent};
+use crate::core::arena::*;
+use crate::core::bssrdf::*;
 use crate::core::geometry::*;
 use crate::core::light::*;
+use crate::core::logging::INTEGRATOR;
+use crate::core::lpe::*;
 use crate::core::pbrt::*;
 use crate::core::reflection::*;
 use crate::core::sampler::*;
@@ -15,6 +21,7 @@ use std::sync::Arc;
 /// * `it`              - The intersection information.
 /// * `scene`           - The scene.
 /// * `sampler`         - The sampler.
+/// * `arena`           - The memory arena for per-sample allocations.
 /// * `n_light_samples` - The number of samples to take for each light.
 /// * `handle_media`    - Indicates whether effects of volumetric attenuation
 ///                       should be considered.
@@ -22,6 +29,7 @@ pub fn uniform_sample_all_lights(
     it: &Interaction,
     scene: Arc<Scene>,
     sampler: &mut ArcSampler,
+    arena: &MemoryArena,
     n_light_samples: &Vec<usize>,
     handle_media: bool,
 ) -> Spectrum {
@@ -31,8 +39,12 @@ pub fn uniform_sample_all_lights(
         // Accumulate contribution of j^th light to `l`.
         let n_samples = n_light_samples[j];
 
-        let u_light_array = Arc::get_mut(sampler).unwrap().get_2d_array(n_samples);
-        let u_scattering_array = Arc::get_mut(sampler).unwrap().get_2d_array(n_samples);
+        let u_light_array = Arc::get_mut(sampler)
+            .unwrap()
+            .get_2d_array(n_samples, arena);
+        let u_scattering_array = Arc::get_mut(sampler)
+            .unwrap()
+            .get_2d_array(n_samples, arena);
 
         let nl = u_light_array.len();
         let sl = u_scattering_array.len();
@@ -54,6 +66,7 @@ pub fn uniform_sample_all_lights(
         } else {
             // Estimate direct lighting using sample arrays
             let mut ld = Spectrum::new(0.0);
+            push_lpe_weight(Spectrum::new(1.0 / n_samples as Float));
             for k in 0..n_samples {
                 ld += estimate_direct(
                     it.clone(),
@@ -66,6 +79,7 @@ pub fn uniform_sample_all_lights(
                     false,
                 );
             }
+            pop_lpe_event();
             l += ld / (n_samples as Float);
         }
     }
@@ -80,13 +94,13 @@ pub fn uniform_sample_all_lights(
 /// * `sampler`       - The sampler.
 /// * `handle_media`  - Indicates whether effects of volumetric attenuation
 ///                     should be considered.
-/// * `light_distrib` - PDF for the light's distribution.
+/// * `light_distrib` - Distribution for choosing a light.
 pub fn uniform_sample_one_light(
     it: &Interaction,
     scene: Arc<Scene>,
     sampler: &mut ArcSampler,
     handle_media: bool,
-    light_distrib: Option<&Distribution1D>,
+    light_distrib: Option<&AliasTable>,
 ) -> Spectrum {
     // Randomly choose a single light to sample, `light`.
     let n_lights = scene.lights.len();
@@ -96,7 +110,7 @@ pub fn uniform_sample_one_light(
 
     let (light_num, light_pdf) = if let Some(ld) = light_distrib {
         let sample = Arc::get_mut(sampler).unwrap().get_1d();
-        let (ln, pdf, _) = ld.sample_discrete(sample);
+        let (ln, pdf, _) = ld.sample(sample);
         if pdf == 0.0 {
             return Spectrum::new(0.0);
         }
@@ -111,6 +125,7 @@ pub fn uniform_sample_one_light(
     let light = scene.clone().lights[light_num].clone();
     let u_light = Arc::get_mut(sampler).unwrap().get_2d();
     let u_scattering = Arc::get_mut(sampler).unwrap().get_2d();
+    push_lpe_weight(Spectrum::new(1.0 / light_pdf));
     let estimate = estimate_direct(
         it,
         &u_scattering,
@@ -121,9 +136,52 @@ pub fn uniform_sample_one_light(
         handle_media,
         false,
     );
+    pop_lpe_event();
     estimate / light_pdf
 }
 
+/// Returns the radiance carried by a ray refracted into a surface with a
+/// BSSRDF. The BSSRDF samples where the light leaves the surface and direct
+/// lighting is estimated there.
+///
+/// * `bssrdf`  - The BSSRDF.
+/// * `ray`     - Ray refracted into the surface.
+/// * `scene`   - The scene.
+/// * `sampler` - The sampler.
+pub fn estimate_subsurface(
+    bssrdf: &ArcBSSRDF,
+    ray: &Ray,
+    scene: Arc<Scene>,
+    sampler: &mut ArcSampler,
+) -> Spectrum {
+    match bssrdf.sample_exit(&scene, ray, sampler) {
+        Some(BSSRDFSample { si, weight }) => {
+            // Callers record the light leaving the surface as a whole.
+            let it = Interaction::Surface { si };
+            let l = without_lpe_path(|| {
+                let mut l = Spectrum::new(0.0);
+                for light in scene.lights.iter() {
+                    let u_light = Arc::get_mut(sampler).unwrap().get_2d();
+                    let u_scattering = Arc::get_mut(sampler).unwrap().get_2d();
+                    l += estimate_direct(
+                        &it,
+                        &u_scattering,
+                        light.clone(),
+                        &u_light,
+                        scene.clone(),
+                        sampler,
+                        false,
+                        false,
+                    );
+                }
+                l
+            });
+            weight * l
+        }
+        None => Spectrum::new(0.0),
+    }
+}
+
 /// Compute a direct lighting estimate for a light source sample by applying
 /// multiple importance sampling.
 ///
@@ -166,13 +224,23 @@ pub fn estimate_direct(
     if light_pdf > 0.0 && !li.is_black() {
         // Compute BSDF or phase function's value for light sample.
         let mut f = Spectrum::new(0.0);
+        let mut reradiating = None;
         match it {
             Interaction::Surface { si } => {
                 // Evaluate BSDF for light sampling strategy.
                 if let Some(bsdf) = si.bsdf.clone() {
-                    f = bsdf.f(&hit.wo, &wi, bsdf_flags) * wi.abs_dot(&si.shading.n);
+                    let cos_theta = wi.abs_dot(&si.shading.n);
+                    f = bsdf.f(&hit.wo, &wi, bsdf_flags) * cos_theta;
                     scattering_pdf = bsdf.pdf(&hit.wo, &wi, bsdf_flags);
-                    info!("  surf f*dot : {:}, scatteringPdf: {}", f, scattering_pdf);
+                    info!(
+                        target: INTEGRATOR,
+                        "  surf f*dot : {:}, scatteringPdf: {}",
+                        f,
+                        scattering_pdf
+                    );
+                    if bsdf.reradiation.is_some() {
+                        reradiating = Some((bsdf, cos_theta));
+                    }
                 }
             }
             Interaction::Medium { mi } => {
@@ -180,34 +248,65 @@ pub fn estimate_direct(
                 let p = mi.phase.p(&mi.hit.wo, &wi);
                 f = Spectrum::new(p);
                 scattering_pdf = p;
-                info!("  medium p: {}", p);
+                info!(target: INTEGRATOR, "  medium p: {}", p);
             }
         }
 
-        if !f.is_black() {
+        if !f.is_black() || reradiating.is_some() {
             // Compute effect of visibility for light source sample.
             if let Some(vis) = visibility {
                 if handle_media {
-                    li *= vis.tr(scene.clone(), sampler.clone());
+                    li *= vis.tr(scene.clone(), sampler);
                 } else {
                     if !vis.unoccluded(scene.clone()) {
-                        debug!("  visiblity tester: shadow ray blocked");
+                        debug!(target: INTEGRATOR, "  visiblity tester: shadow ray blocked");
                         li = Spectrum::new(0.0);
                     } else {
-                        debug!("  visiblity tester: shadow ray unoccluded");
+                        debug!(target: INTEGRATOR, "  visiblity tester: shadow ray unoccluded");
                     }
                 }
             } else {
-                debug!("  no visiblity tester");
+                debug!(target: INTEGRATOR, "  no visiblity tester");
             }
+            trace_at_last_hit(|depth| TraceEvent::LightSample {
+                depth,
+                light: scene
+                    .lights
+                    .iter()
+                    .position(|l| Arc::ptr_eq(l, &light))
+                    .unwrap_or(usize::MAX),
+                wi,
+                li,
+                pdf: light_pdf,
+                f,
+                unoccluded: !li.is_black(),
+            });
 
-            // Add light's contribution to reflected radiance
+            // Add light's contribution to reflected radiance, including any
+            // light re-radiated at other wavelengths.
             if !li.is_black() {
-                if light.is_delta_light() {
-                    ld += f * li / light_pdf;
+                let scattered = match reradiating.as_ref() {
+                    Some((bsdf, cos_theta)) => {
+                        f * li + bsdf.reradiate(&hit.wo, &wi, &li, bsdf_flags) * *cos_theta
+                    }
+                    None => f * li,
+                };
+                let weight = if light.is_delta_light() {
+                    1.0
                 } else {
-                    let weight = power_heuristic(1, light_pdf, 1, scattering_pdf);
-                    ld += f * li * weight / light_pdf;
+                    power_heuristic(1, light_pdf, 1, scattering_pdf)
+                };
+                ld += scattered * weight / light_pdf;
+                if is_recording_lpe_path() {
+                    record_direct_lighting(
+                        it,
+                        &wi,
+                        &li,
+                        weight / light_pdf,
+                        bsdf_flags,
+                        &light,
+                        &scene,
+                    );
                 }
             }
         }
@@ -217,19 +316,25 @@ pub fn estimate_direct(
     if !light.is_delta_light() {
         let mut f = Spectrum::new(0.0);
         let mut sampled_specular = false;
+        let mut reradiating = None;
         match it {
             Interaction::Surface { si } => {
                 // Sample scattered direction for surface interactions.
                 if let Some(bsdf) = si.bsdf.clone() {
                     let BxDFSample {
                         f: f1,
-                        pdf: _scattering_pdf,
+                        pdf: pdf1,
                         wi: wi2,
                         sampled_type,
                     } = bsdf.sample_f(&hit.wo, u_scattering, bsdf_flags);
                     wi = wi2;
-                    f = f1 * wi.abs_dot(&si.shading.n);
+                    let cos_theta = wi.abs_dot(&si.shading.n);
+                    f = f1 * cos_theta;
+                    scattering_pdf = pdf1;
                     sampled_specular = sampled_type.matches(BSDF_SPECULAR);
+                    if bsdf.reradiation.is_some() && !sampled_specular {
+                        reradiating = Some((bsdf, cos_theta));
+                    }
                 }
             }
             Interaction::Medium { mi } => {
@@ -241,11 +346,12 @@ pub fn estimate_direct(
             }
         }
         debug!(
+            target: INTEGRATOR,
             "  BSDF / phase sampling f: {:}, scattering_pdf: {}",
             f, scattering_pdf
         );
 
-        if !f.is_black() && scattering_pdf > 0.0 {
+        if (!f.is_black() || reradiating.is_some()) && scattering_pdf > 0.0 {
             // Account for light contributions along sampled direction `wi`.
             let mut weight = 1.0;
             if !sampled_specular {
@@ -259,7 +365,7 @@ pub fn estimate_direct(
             // Find intersection and compute transmittance.
             let mut ray = hit.spawn_ray(&wi);
             let light_isect_and_tr = if handle_media {
-                scene.intersect_tr(&mut ray, sampler.clone())
+                scene.intersect_tr(&mut ray, sampler)
             } else if let Some(light_isect) = scene.intersect(&mut ray) {
                 Some((light_isect, Spectrum::new(1.0)))
             } else {
@@ -280,12 +386,30 @@ pub fn estimate_direct(
                         }
                     }
                 }
-            } else if let Some(rd) = ray.differentials {
-                li = light.le(&rd);
+            } else {
+                li = light.le(&ray);
             }
 
             if !li.is_black() {
-                ld += f * li * tr * weight / scattering_pdf;
+                let li = li * tr;
+                let scattered = match reradiating.as_ref() {
+                    Some((bsdf, cos_theta)) => {
+                        f * li + bsdf.reradiate(&hit.wo, &wi, &li, bsdf_flags) * *cos_theta
+                    }
+                    None => f * li,
+                };
+                ld += scattered * weight / scattering_pdf;
+                if is_recording_lpe_path() {
+                    record_direct_lighting(
+                        it,
+                        &wi,
+                        &li,
+                        weight / scattering_pdf,
+                        bsdf_flags,
+                        &light,
+                        &scene,
+                    );
+                }
             }
         }
     }
@@ -293,14 +417,215 @@ pub fn estimate_direct(
     ld
 }
 
+/// Adds light from a light source scattered at an interaction to the light
+/// path expression channels, split by the lobes of the BSDF.
+///
+/// * `it`         - The interaction.
+/// * `wi`         - Direction of the light.
+/// * `li`         - Incident radiance.
+/// * `scale`      - Factor of the scattered radiance.
+/// * `bsdf_flags` - The `BxDFType`s to evaluate.
+/// * `light`      - The light.
+/// * `scene`      - The scene.
+fn record_direct_lighting(
+    it: &Interaction,
+    wi: &Vector3f,
+    li: &Spectrum,
+    scale: Float,
+    bsdf_flags: BxDFType,
+    light: &ArcLight,
+    scene: &Scene,
+) {
+    let light_index = scene.lights.iter().position(|l| Arc::ptr_eq(l, light));
+    match it {
+        Interaction::Surface { si } => {
+            let bsdf = match si.bsdf.as_ref() {
+                Some(bsdf) => bsdf,
+                None => return,
+            };
+            let wo = si.hit.wo;
+            let cos_theta = wi.abs_dot(&si.shading.n);
+            for &(event_type, lobe) in [
+                (EventType::Reflection, BSDF_REFLECTION),
+                (EventType::Transmission, BSDF_TRANSMISSION),
+            ]
+            .iter()
+            {
+                for &(scatter, s) in [
+                    (ScatterType::Diffuse, BSDF_DIFFUSE),
+                    (ScatterType::Glossy, BSDF_GLOSSY),
+                    (ScatterType::Specular, BSDF_SPECULAR),
+                ]
+                .iter()
+                {
+                    if !bsdf_flags.matches(s) {
+                        continue;
+                    }
+                    let flags = BxDFType::from(lobe | s);
+                    let scattered =
+                        bsdf.f(&wo, wi, flags) * *li + bsdf.reradiate(&wo, wi, li, flags);
+                    add_lpe_radiance(
+                        || {
+                            vec![
+                                PathEvent::new(event_type, scatter),
+                                PathEvent::light(light_index),
+                            ]
+                        },
+                        scattered * cos_theta * scale,
+                    );
+                }
+            }
+        }
+        Interaction::Medium { mi } => {
+            let p = mi.phase.p(&mi.hit.wo, wi);
+            add_lpe_radiance(
+                || {
+                    vec![
+                        PathEvent::new(EventType::Volume, ScatterType::Diffuse),
+                        PathEvent::light(light_index),
+                    ]
+                },
+                *li * p * scale,
+            );
+        }
+    }
+}
+
+/// Returns the light path expression event of a sampled BxDF.
+///
+/// * `sampled_type` - The sampled `BxDFType`.
+pub fn bxdf_lpe_event(sampled_type: BxDFType) -> PathEvent {
+    let event_type = if sampled_type.matches(BSDF_TRANSMISSION) {
+        EventType::Transmission
+    } else {
+        EventType::Reflection
+    };
+    let scatter = if sampled_type.matches(BSDF_SPECULAR) {
+        ScatterType::Specular
+    } else if sampled_type.matches(BSDF_GLOSSY) {
+        ScatterType::Glossy
+    } else {
+        ScatterType::Diffuse
+    };
+    PathEvent::new(event_type, scatter)
+}
+
+/// Throughput of a path from the camera. Fluorescent surfaces re-radiate
+/// light at other wavelengths, so the throughput up to the last of them is
+/// stored as a matrix and the throughput since then as a `Spectrum`.
+#[derive(Clone)]
+pub struct PathThroughput {
+    /// Throughput up to the last surface that re-radiates light.
+    reradiated: Option<ReradiationMatrix>,
+
+    /// Throughput since the last surface that re-radiates light.
+    pub beta: Spectrum,
+}
+
+impl PathThroughput {
+    /// Create a new `PathThroughput` of 1 at all wavelengths.
+    pub fn new() -> Self {
+        Self {
+            reradiated: None,
+            beta: Spectrum::new(1.0),
+        }
+    }
+
+    /// Returns the radiance reaching the camera for radiance `l` arriving at
+    /// the end of the path.
+    ///
+    /// * `l` - The radiance.
+    pub fn apply(&self, l: &Spectrum) -> Spectrum {
+        match self.reradiated.as_ref() {
+            Some(m) => m.apply(&(self.beta * *l)),
+            None => self.beta * *l,
+        }
+    }
+
+    /// Returns `true` if no radiance reaches the camera.
+    pub fn is_black(&self) -> bool {
+        self.beta.is_black()
+    }
+
+    /// Extends the path with a direction sampled from a BSDF, including the
+    /// light it re-radiates at other wavelengths.
+    ///
+    /// * `bsdf`         - The BSDF.
+    /// * `wo`           - Outgoing direction in world-space.
+    /// * `wi`           - The sampled incident direction in world-space.
+    /// * `f`            - Value of the BSDF.
+    /// * `sampled_type` - The sampled `BxDFType`.
+    /// * `scale`        - The cosine term divided by the PDF.
+    pub fn scatter(
+        &mut self,
+        bsdf: &BSDF,
+        wo: &Vector3f,
+        wi: &Vector3f,
+        f: &Spectrum,
+        sampled_type: BxDFType,
+        scale: Float,
+    ) {
+        if bsdf.reradiation.is_none() {
+            self.beta *= *f * scale;
+            return;
+        }
+
+        let m = ReradiationMatrix::from_fn(|l| {
+            let scattered = (*f * *l + bsdf.reradiate(wo, wi, l, sampled_type)) * scale;
+            self.apply(&scattered)
+        });
+        self.reradiated = Some(m);
+        self.beta = Spectrum::new(1.0);
+    }
+}
+
+impl Default for PathThroughput {
+    /// Returns a `PathThroughput` of 1 at all wavelengths.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Returns the light power distribution in a scene.
 ///
 /// * `scene` - The scene.
-pub fn compute_light_power_distribution(scene: Arc<Scene>) -> Option<Distribution1D> {
+pub fn compute_light_power_distribution(scene: Arc<Scene>) -> Option<AliasTable> {
     if scene.lights.len() == 0 {
         None
     } else {
         let light_power: Vec<Float> = scene.lights.iter().map(|light| light.power().y()).collect();
-        Some(Distribution1D::new(light_power))
+        Some(AliasTable::new(&light_power))
+    }
+}
+
+// The expected pixel values are products of RGB values; the products of
+// upsampled spectra differ slightly.
+#[cfg(all(test, not(feature = "sampled-spectrum")))]
+mod tests {
+    use crate::core::api::*;
+
+    #[test]
+    fn direct_lighting_from_environment_is_unbiased() {
+        // A diffuse sphere lit by a uniform environment reflects `Kd` times
+        // the radiance of the environment. Light and BSDF samples are
+        // combined with MIS, so leaving out either half darkens the sphere.
+        let scene = r#"
+            LookAt 0 0 5  0 0 0  0 1 0
+            Camera "perspective" "float fov" [10]
+            Film "image" "integer xresolution" [4] "integer yresolution" [4]
+            Sampler "random" "integer pixelsamples" [256]
+            Integrator "volpath" "integer maxdepth" [1]
+            WorldBegin
+            LightSource "infinite" "rgb L" [0.5 0.5 0.5]
+            Material "matte" "rgb Kd" [0.5 0.5 0.5]
+            Shape "sphere" "float radius" [1]
+            WorldEnd
+        "#;
+        let image = render_to_rgba(scene, |_, _| {}).unwrap();
+
+        // Reflected radiance of 0.25 is encoded as 137 in sRGB.
+        for p in image.pixels.chunks(4) {
+            assert!((p[1] as i32 - 137).abs() <= 4, "{:?}", p);
+        }
     }
 }
@@ -0,0 +1,157 @@
+//! Light Path Expression Recording
+
+#![allow(dead_code)]
+use crate::core::lpe::*;
+use crate::core::spectrum::*;
+use std::cell::RefCell;
+use std::sync::Arc;
+
+thread_local! {
+    /// Path of the camera sample the current thread is computing while light
+    /// path expression channels are recorded.
+    static LPE_PATH: RefCell<Option<LpePath>> = const { RefCell::new(None) };
+}
+
+/// The events of the path of a camera sample and the radiance of each channel
+/// so far.
+struct LpePath {
+    /// The channels.
+    channels: Arc<Vec<LpeChannel>>,
+
+    /// For each event along the current path, the state of each channel's
+    /// expression after it and the product of the event weights up to it.
+    stack: Vec<(Vec<LpeState>, Spectrum)>,
+
+    /// Radiance of each channel.
+    radiance: Vec<Spectrum>,
+}
+
+/// Starts recording the path of a camera sample on the current thread. The
+/// path starts with the camera event.
+///
+/// * `channels` - The channels.
+pub fn begin_lpe_path(channels: Arc<Vec<LpeChannel>>) {
+    let camera = PathEvent::camera();
+    let states = channels
+        .iter()
+        .map(|c| c.lpe.advance(&c.lpe.start(), &camera))
+        .collect();
+    let radiance = vec![Spectrum::new(0.0); channels.len()];
+    LPE_PATH.with(|p| {
+        *p.borrow_mut() = Some(LpePath {
+            channels,
+            stack: vec![(states, Spectrum::new(1.0))],
+            radiance,
+        })
+    });
+}
+
+/// Stops recording and returns the radiance of each channel; or an empty
+/// vector if the current thread wasn't recording.
+pub fn end_lpe_path() -> Vec<Spectrum> {
+    LPE_PATH
+        .with(|p| p.borrow_mut().take())
+        .map_or_else(Vec::new, |path| path.radiance)
+}
+
+/// Returns `true` if the current thread is recording a path.
+#[inline]
+pub fn is_recording_lpe_path() -> bool {
+    LPE_PATH.with(|p| p.borrow().is_some())
+}
+
+/// Extends the path by a scattering event. Radiance contributed until the
+/// matching `pop_lpe_event()` is scaled by `weight`. The event is only
+/// created when a path is recorded.
+///
+/// * `event`  - Returns the event.
+/// * `weight` - Factor that radiance arriving after the event is multiplied
+///              with.
+#[inline]
+pub fn push_lpe_event<F: FnOnce() -> PathEvent>(event: F, weight: Spectrum) {
+    LPE_PATH.with(|p| {
+        if let Some(path) = p.borrow_mut().as_mut() {
+            let event = event();
+            let (states, throughput) = path.stack.last().unwrap();
+            let states = path
+                .channels
+                .iter()
+                .zip(states.iter())
+                .map(|(c, s)| c.lpe.advance(s, &event))
+                .collect();
+            let throughput = *throughput * weight;
+            path.stack.push((states, throughput));
+        }
+    });
+}
+
+/// Scales radiance contributed until the matching `pop_lpe_event()` by
+/// `weight` without extending the path, e.g. for Russian roulette.
+///
+/// * `weight` - Factor that radiance arriving from now on is multiplied with.
+#[inline]
+pub fn push_lpe_weight(weight: Spectrum) {
+    LPE_PATH.with(|p| {
+        if let Some(path) = p.borrow_mut().as_mut() {
+            let (states, throughput) = path.stack.last().unwrap();
+            let entry = (states.clone(), *throughput * weight);
+            path.stack.push(entry);
+        }
+    });
+}
+
+/// Calls a function without recording the radiance it adds to the path of
+/// the current thread.
+///
+/// * `f` - The function.
+pub fn without_lpe_path<T, F: FnOnce() -> T>(f: F) -> T {
+    let path = LPE_PATH.with(|p| p.borrow_mut().take());
+    let result = f();
+    LPE_PATH.with(|p| *p.borrow_mut() = path);
+    result
+}
+
+/// Removes the last event pushed with `push_lpe_event()` or
+/// `push_lpe_weight()`.
+#[inline]
+pub fn pop_lpe_event() {
+    LPE_PATH.with(|p| {
+        if let Some(path) = p.borrow_mut().as_mut() {
+            if path.stack.len() > 1 {
+                path.stack.pop();
+            }
+        }
+    });
+}
+
+/// Adds radiance arriving along the current path followed by the given events
+/// to the channels whose expression matches. The events are only created when
+/// a path is recorded.
+///
+/// * `events` - Returns the events ending the path; usually a light.
+/// * `l`      - The radiance.
+#[inline]
+pub fn add_lpe_radiance<F: FnOnce() -> Vec<PathEvent>>(events: F, l: Spectrum) {
+    if l.is_black() {
+        return;
+    }
+    LPE_PATH.with(|p| {
+        if let Some(path) = p.borrow_mut().as_mut() {
+            let events = events();
+            let (states, throughput) = path.stack.last().unwrap();
+            for ((channel, state), radiance) in path
+                .channels
+                .iter()
+                .zip(states.iter())
+                .zip(path.radiance.iter_mut())
+            {
+                let state = events
+                    .iter()
+                    .fold(state.clone(), |s, e| channel.lpe.advance(&s, e));
+                if channel.lpe.is_match(&state) {
+                    *radiance += *throughput * l;
+                }
+            }
+        }
+    });
+}
@@ -3,8 +3,17 @@
 #![allow(dead_code)]
 
 mod common;
+mod lpe_path;
+mod render_observer;
 mod sampler_integrator;
+mod scheduler;
+mod tile_order;
+mod trace;
+mod wavefront;
 
+use crate::core::arena::*;
+use crate::core::cancel::*;
+use crate::core::error::*;
 use crate::core::geometry::*;
 use crate::core::sampler::*;
 use crate::core::scene::Scene;
@@ -13,26 +22,51 @@ use std::sync::Arc;
 
 // Re-export.
 pub use common::*;
+pub use lpe_path::*;
+pub use render_observer::*;
 pub use sampler_integrator::*;
+pub use scheduler::*;
+pub use tile_order::*;
+pub use trace::*;
+pub use wavefront::*;
 
 /// Integrator interface.
 pub trait Integrator {
-    /// Render the scene.
+    /// Computes the data needed to render the scene before rendering it, e.g.
+    /// light distributions. Data worth sharing between renders of the scene
+    /// can be kept in `scene.preprocessed`.
     ///
-    /// * `scene` - The scene.
-    fn render(&mut self, scene: Arc<Scene>);
+    /// * `scene`   - The scene.
+    /// * `sampler` - The sampler.
+    fn preprocess(&mut self, _scene: Arc<Scene>, _sampler: &ArcSampler) {}
+
+    /// Render the scene; or returns an error if the image could not be
+    /// written. If rendering is cancelled, the partially rendered image is
+    /// written.
+    ///
+    /// * `scene`     - The scene.
+    /// * `cancel`    - Token used to stop rendering early.
+    /// * `observers` - Observers notified of rendering progress.
+    fn render(
+        &mut self,
+        scene: Arc<Scene>,
+        cancel: &CancellationToken,
+        observers: &[ArcRenderObserver],
+    ) -> Result<(), Error>;
 
     /// Returns the incident radiance at the origin of a given ray.
     ///
     /// * `ray`     - The ray.
     /// * `scene`   - The scene.
     /// * `sampler` - The sampler.
+    /// * `arena`   - The memory arena for per-sample allocations.
     /// * `depth`   - The recursion depth.
     fn li(
         &self,
         _ray: &mut Ray,
         _scene: Arc<Scene>,
         _sampler: &mut ArcSampler,
+        _arena: &MemoryArena,
         _depth: usize,
     ) -> Spectrum {
         Spectrum::new(0.0)
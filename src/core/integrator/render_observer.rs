@@ -0,0 +1,43 @@
+//! Render Observer
+
+use crate::core::geometry::*;
+use crate::core::pbrt::*;
+use std::sync::Arc;
+
+/// Receives progress events from the render loop. All methods do nothing by
+/// default so implementations only need to handle the events they use.
+///
+/// Methods are called from the rendering threads. Tiles are reported as
+/// finished one at a time in the order they are merged into the film.
+pub trait RenderObserver {
+    /// Called before any tiles are rendered.
+    ///
+    /// * `cropped_pixel_bounds` - Bounds of the pixels of the cropped image.
+    fn render_started(&self, _cropped_pixel_bounds: &Bounds2i) {}
+
+    /// Called when a thread starts rendering a tile.
+    ///
+    /// * `tile_bounds` - Sample bounds of the tile in the image.
+    fn tile_started(&self, _tile_bounds: &Bounds2i) {}
+
+    /// Called when a rendered tile has been merged into the film.
+    ///
+    /// * `tile_bounds` - Sample bounds of the tile in the image.
+    /// * `rgb`         - The RGB values of the film for the pixels of the
+    ///                   tile that are inside the cropped image, in row-major
+    ///                   order.
+    fn tile_finished(&self, _tile_bounds: &Bounds2i, _rgb: &[Float]) {}
+
+    /// Called when a pass over all tiles of the image is finished.
+    ///
+    /// * `pass` - The pass number starting at 0.
+    fn pass_finished(&self, _pass: usize) {}
+
+    /// Called when rendering is finished and the image has been written.
+    ///
+    /// * `cancelled` - Whether or not rendering was cancelled.
+    fn render_finished(&self, _cancelled: bool) {}
+}
+
+/// Atomic reference counted `RenderObserver`.
+pub type ArcRenderObserver = Arc<dyn RenderObserver + Send + Sync>;
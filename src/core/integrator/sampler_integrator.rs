@@ -1,16 +1,38 @@
 //! Sampler Integrator
 
 use super::*;
+use crate::core::app::OPTIONS;
+use crate::core::arena::*;
 use crate::core::camera::*;
+use crate::core::cancel::*;
+use crate::core::distributed::*;
+use crate::core::error::*;
+use crate::core::film::*;
 use crate::core::geometry::*;
+use crate::core::logging::INTEGRATOR;
+use crate::core::lpe::*;
+use crate::core::material::*;
+use crate::core::parallel::*;
 use crate::core::pbrt::*;
+use crate::core::profiler::*;
 use crate::core::reflection::*;
 use crate::core::sampler::*;
 use crate::core::scene::*;
 use crate::core::spectrum::*;
-use itertools::iproduct;
-use rayon::prelude::*;
-use std::sync::Arc;
+use crate::core::stats::*;
+use log::Level;
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+stat_counter!("Integrator/Camera rays traced", N_CAMERA_RAYS);
+
+/// Number of samples taken for a pixel before adaptive sampling checks whether
+/// it has converged.
+const ADAPTIVE_MIN_SAMPLES: usize = 16;
+
+/// Number of tiles per render thread in a batch of the tile loop.
+const TILES_PER_THREAD_IN_BATCH: usize = 16;
 
 /// Common data for sampler integrators.
 pub struct SamplerIntegratorData {
@@ -23,6 +45,14 @@ pub struct SamplerIntegratorData {
 
     /// Pixel bounds for the image.
     pub pixel_bounds: Bounds2i,
+
+    /// Stop sampling a pixel once the variance of the mean luminance of its
+    /// samples falls below this; 0 takes all samples.
+    pub max_variance: Float,
+
+    /// Radiance samples are scaled down so that none of their components
+    /// exceed this, which removes fireflies at the cost of some energy.
+    pub max_component_value: Float,
 }
 
 impl SamplerIntegratorData {
@@ -37,6 +67,8 @@ impl SamplerIntegratorData {
             camera,
             sampler,
             pixel_bounds,
+            max_variance: 0.0,
+            max_component_value: INFINITY,
         }
     }
 }
@@ -48,27 +80,35 @@ pub trait SamplerIntegrator: Integrator + Send + Sync {
     /// Returns the common data.
     fn get_data(&self) -> &SamplerIntegratorData;
 
+    /// Returns the shading stage used to render tiles with a wavefront of
+    /// rays when `--wavefront` is given; or `None` if the integrator only
+    /// supports tracing one camera sample at a time.
+    fn wavefront_shader(&self) -> Option<&dyn WavefrontShader> {
+        None
+    }
+
     /// Trace rays for specular reflection.
     ///
     /// * `ray`     - The ray.
     /// * `isect`   - The surface interaction.
     /// * `scene`   - The scene.
     /// * `sampler` - The sampler.
+    /// * `arena`   - The memory arena for per-sample allocations.
     /// * `depth`   - The recursive depth.
     fn specular_reflect(
         &self,
         ray: &mut Ray,
         isect: &SurfaceInteraction,
         scene: Arc<Scene>,
-        sampler: ArcSampler,
+        sampler: &mut ArcSampler,
+        arena: &MemoryArena,
         depth: usize,
     ) -> Spectrum {
         if let Some(bsdf) = isect.bsdf.clone() {
             // Compute specular reflection direction `wi` and BSDF value.
             let wo = isect.hit.wo;
 
-            let mut sampler = sampler.clone();
-            let sample = Arc::get_mut(&mut sampler).unwrap().get_2d();
+            let sample = Arc::get_mut(sampler).unwrap().get_2d();
             let bxdf_type = BxDFType::from(BSDF_REFLECTION | BSDF_SPECULAR);
             let BxDFSample {
                 f,
@@ -80,6 +120,15 @@ pub trait SamplerIntegrator: Integrator + Send + Sync {
             // Return contribution of specular reflection
             let ns = isect.shading.n;
             if pdf > 0.0 && !f.is_black() && wi.abs_dot(&ns) != 0.0 {
+                trace(|| TraceEvent::BsdfSample {
+                    depth,
+                    lobe: "reflection",
+                    wi,
+                    f,
+                    pdf,
+                    weight: f * wi.abs_dot(&ns) / pdf,
+                });
+
                 // Compute ray differential `rd` for specular reflection.
                 let mut rd = isect.hit.spawn_ray(&wi);
                 if let Some(differentials) = ray.differentials {
@@ -105,11 +154,13 @@ pub trait SamplerIntegrator: Integrator + Send + Sync {
                     ));
                 }
 
-                let mut sampler = sampler.clone();
-                return f
-                    * self.li(&mut rd, scene.clone(), &mut sampler, depth + 1)
-                    * wi.abs_dot(&ns)
-                    / pdf;
+                push_lpe_event(
+                    || PathEvent::new(EventType::Reflection, ScatterType::Specular),
+                    f * wi.abs_dot(&ns) / pdf,
+                );
+                let li = self.li(&mut rd, scene.clone(), sampler, arena, depth + 1);
+                pop_lpe_event();
+                return f * li * wi.abs_dot(&ns) / pdf;
             }
         }
 
@@ -122,21 +173,22 @@ pub trait SamplerIntegrator: Integrator + Send + Sync {
     /// * `isect`   - The surface interaction.
     /// * `scene`   - The scene.
     /// * `sampler` - The sampler.
+    /// * `arena`   - The memory arena for per-sample allocations.
     /// * `depth`   - The recursive depth.
     fn specular_transmit(
         &self,
         ray: &mut Ray,
         isect: &SurfaceInteraction,
         scene: Arc<Scene>,
-        sampler: ArcSampler,
+        sampler: &mut ArcSampler,
+        arena: &MemoryArena,
         depth: usize,
     ) -> Spectrum {
         if let Some(bsdf) = isect.bsdf.clone() {
             let wo = isect.hit.wo;
             let p = isect.hit.p;
 
-            let mut sampler = sampler.clone();
-            let sample = Arc::get_mut(&mut sampler).unwrap().get_2d();
+            let sample = Arc::get_mut(sampler).unwrap().get_2d();
             let bxdf_type = BxDFType::from(BSDF_TRANSMISSION | BSDF_SPECULAR);
             let BxDFSample {
                 f,
@@ -147,6 +199,15 @@ pub trait SamplerIntegrator: Integrator + Send + Sync {
 
             let mut ns = isect.shading.n;
             if pdf > 0.0 && !f.is_black() && wi.abs_dot(&ns) != 0.0 {
+                trace(|| TraceEvent::BsdfSample {
+                    depth,
+                    lobe: "transmission",
+                    wi,
+                    f,
+                    pdf,
+                    weight: f * wi.abs_dot(&ns) / pdf,
+                });
+
                 // Compute ray differential _rd_ for specular transmission
                 let mut rd = isect.hit.spawn_ray(&wi);
                 if let Some(differentials) = ray.differentials {
@@ -231,155 +292,897 @@ pub trait SamplerIntegrator: Integrator + Send + Sync {
                     ));
                 }
 
-                let mut sampler = sampler.clone();
-                return f
-                    * self.li(&mut rd, scene.clone(), &mut sampler, depth + 1)
-                    * wi.abs_dot(&ns)
-                    / pdf;
+                // Light refracted into a subsurface scattering material
+                // leaves it elsewhere after scattering inside.
+                push_lpe_event(
+                    || PathEvent::new(EventType::Transmission, ScatterType::Specular),
+                    f * wi.abs_dot(&ns) / pdf,
+                );
+                let li = match isect.bssrdf.as_ref() {
+                    Some(bssrdf) => {
+                        // The light scattered inside is recorded as diffuse
+                        // transmission of light from an unknown source.
+                        let li = estimate_subsurface(bssrdf, &rd, scene.clone(), sampler);
+                        add_lpe_radiance(
+                            || {
+                                vec![
+                                    PathEvent::new(EventType::Transmission, ScatterType::Diffuse),
+                                    PathEvent::light(None),
+                                ]
+                            },
+                            li,
+                        );
+                        li
+                    }
+                    None => self.li(&mut rd, scene.clone(), sampler, arena, depth + 1),
+                };
+                pop_lpe_event();
+                return f * li * wi.abs_dot(&ns) / pdf;
             }
         }
 
         Spectrum::new(0.0)
     }
 
-    /// Render the scene.
+    /// Renders a single sample of a pixel with tracing enabled and writes
+    /// the trace of every bounce to standard output as JSON lines. The
+    /// samples of the pixel's tile that precede it are rendered first, without
+    /// tracing, so the traced sample matches the one in a full render.
+    ///
+    /// * `scene`        - The scene.
+    /// * `pixel`        - The pixel.
+    /// * `sample_index` - Index of the sample in the pixel.
+    fn trace_pixel(
+        &mut self,
+        scene: Arc<Scene>,
+        pixel: Point2i,
+        sample_index: usize,
+    ) -> Result<(), Error> {
+        let film = self.get_data().camera.get_data().film.clone();
+        let sample_bounds = film.get_sample_bounds();
+        let pixel_bounds = self.get_data().pixel_bounds;
+        if !pixel_bounds.contains_exclusive(&pixel) {
+            return Err(Error::InvalidParameter(format!(
+                "Debug pixel {} is outside the rendered pixels {}.",
+                pixel, pixel_bounds
+            )));
+        }
+
+        // Find the tile of the pixel and seed its sampler like `render()`.
+        let tile_size = OPTIONS.tile_size as i32;
+        let n_tiles_x = (sample_bounds.diagonal().x + tile_size - 1) / tile_size;
+        // Divide each component; dividing an integer vector scales it by
+        // the reciprocal which rounds to zero.
+        let offset = pixel - sample_bounds.p_min;
+        let tile = Vector2i::new(offset.x / tile_size, offset.y / tile_size);
+        let tile_index = tile.y * n_tiles_x + tile.x;
+        let p0 = sample_bounds.p_min + tile * tile_size;
+        let p1 = Point2i::new(
+            min(p0.x + tile_size, sample_bounds.p_max.x),
+            min(p0.y + tile_size, sample_bounds.p_max.y),
+        );
+        let mut sampler = Sampler::clone(&*self.get_data().sampler, tile_index as u64);
+
+        let samples_per_pixel = Arc::get_mut(&mut sampler)
+            .unwrap()
+            .get_data()
+            .samples_per_pixel;
+        if sample_index >= samples_per_pixel {
+            return Err(Error::InvalidParameter(format!(
+                "Debug sample {} is out of range; there are {} samples per pixel.",
+                sample_index, samples_per_pixel
+            )));
+        }
+
+        let mut arena = MemoryArena::default();
+        for p in Bounds2i::new(p0, p1) {
+            Arc::get_mut(&mut sampler).unwrap().start_pixel(&p);
+            if !pixel_bounds.contains_exclusive(&p) {
+                continue;
+            }
+
+            loop {
+                let current_sample_number = Arc::get_mut(&mut sampler)
+                    .unwrap()
+                    .get_data()
+                    .current_sample_number();
+                let traced = p == pixel && current_sample_number == sample_index;
+
+                let camera_sample = Arc::get_mut(&mut sampler).unwrap().get_camera_sample(&p);
+                let (mut ray, ray_weight) = self
+                    .get_data()
+                    .camera
+                    .generate_ray_differential(&camera_sample);
+                ray.scale_differentials(1.0 / (samples_per_pixel as Float).sqrt());
+
+                if traced {
+                    start_trace();
+                    trace(|| TraceEvent::CameraRay {
+                        pixel,
+                        sample: sample_index,
+                        p_film: camera_sample.p_film,
+                        ray: ray.clone(),
+                        weight: ray_weight,
+                    });
+                }
+
+                let mut l = Spectrum::new(0.0);
+                if ray_weight > 0.0 {
+                    l = self.li(&mut ray, scene.clone(), &mut sampler, &arena, 0);
+                }
+
+                if traced {
+                    trace(|| TraceEvent::Radiance { l });
+                    let events = finish_trace();
+                    print!("{}", trace_to_json(&events));
+                    if let Some(path) = OPTIONS.debug_obj.as_ref() {
+                        std::fs::write(path, trace_to_obj(&events)).map_err(|err| {
+                            Error::Io(format!("Error writing debug path '{}'. {}.", path, err))
+                        })?;
+                    }
+                    return Ok(());
+                }
+
+                arena.reset();
+                if !Arc::get_mut(&mut sampler).unwrap().start_next_sample() {
+                    break;
+                }
+            }
+        }
+
+        Err(Error::InvalidState(format!(
+            "Debug pixel {} was not rendered.",
+            pixel
+        )))
+    }
+
+    /// Render the scene. The integrator's `preprocess()` is called first.
     ///
-    /// NOTE: The integrators that use this function should call their own
-    /// preprocess(scene, sampler) implementation before calling this.
+    /// Rendering stops between samples once `cancel` is cancelled; tiles
+    /// rendered so far, including partially rendered ones, are still merged
+    /// and written.
     ///
-    /// * `scene` - The scene.
-    fn render(&mut self, scene: Arc<Scene>) {
+    /// * `scene`     - The scene.
+    /// * `cancel`    - Token used to stop rendering early.
+    /// * `observers` - Observers notified of rendering progress.
+    fn render(
+        &mut self,
+        scene: Arc<Scene>,
+        cancel: &CancellationToken,
+        observers: &[ArcRenderObserver],
+    ) -> Result<(), Error> {
+        let sampler = self.get_data().sampler.clone();
+        self.preprocess(scene.clone(), &sampler);
+
+        if let Some([x, y]) = OPTIONS.debug_pixel {
+            return self.trace_pixel(scene, Point2i::new(x, y), OPTIONS.debug_sample);
+        }
+
         // Compute number of tiles, `n_tiles`, to use for parallel rendering
         let film = self.get_data().camera.get_data().film.clone();
         let sample_bounds = film.get_sample_bounds();
         let sample_extent = sample_bounds.diagonal();
-        let tile_size = 16;
+        let tile_size = OPTIONS.tile_size as i32;
         let n_tiles = Point2::new(
             ((sample_extent.x + tile_size - 1) / tile_size) as usize,
             ((sample_extent.y + tile_size - 1) / tile_size) as usize,
         );
 
-        info!("Rendering {}x{} tiles", n_tiles.x, n_tiles.y);
+        info!(target: INTEGRATOR, "Rendering {}x{} tiles", n_tiles.x, n_tiles.y);
+
+        // Use the wavefront path if requested and supported.
+        let wavefront = if OPTIONS.wavefront {
+            let shader = self.wavefront_shader();
+            if shader.is_none() {
+                warn!(
+                    target: INTEGRATOR,
+                    "Integrator does not support wavefront rendering. Using tile loop."
+                );
+                shader
+            } else if !film.lpe_channels.is_empty()
+                || !film.aovs.is_empty()
+                || film.alpha
+                || film.warm_start.is_some()
+                || film.write_variance
+                || film.write_sample_count
+                || self.get_data().max_variance > 0.0
+                || OPTIONS.progressive
+            {
+                warn!(
+                    target: INTEGRATOR,
+                    "Light path expressions, AOVs, alpha, warm starts, variance, \
+                    adaptive sampling and progressive rendering are not supported by \
+                    wavefront rendering. Using tile loop."
+                );
+                None
+            } else {
+                shader
+            }
+        } else {
+            None
+        };
+
+        // Indices of the tiles in the order they are rendered.
+        let tile_indices = OPTIONS.tile_order.tile_indices(n_tiles);
+
+        // Identify the primitives if they are written as an AOV.
+        let prim_ids = if film.aovs.contains(&Aov::PrimId) {
+            Some(PrimitiveIds::new(scene.aggregate.as_ref()))
+        } else {
+            None
+        };
+
+        for observer in observers {
+            observer.render_started(&film.cropped_pixel_bounds);
+        }
 
-        // Parallelize.
-        let tiles = iproduct!(0..n_tiles.x, 0..n_tiles.y).par_bridge();
-        tiles.for_each(|(tile_x, tile_y)| {
+        // Finished tiles are merged into the `Film` in rendering order so
+        // that the image does not depend on the number of threads or the
+        // order in which tiles finish. This holds the position of the next
+        // tile to merge and the finished parts of tiles waiting for it;
+        // the tile loop renders tiles in batches to keep it short.
+        type FinishedTiles<'a> = BTreeMap<usize, Vec<(TileWork, Arc<FilmTile<'a>>)>>;
+        let pending_tiles: Mutex<(usize, FinishedTiles)> = Mutex::new((0, BTreeMap::new()));
+
+        let merge_tile = |tile_bounds: Bounds2i, film_tile: Arc<FilmTile>| {
+            film.merge_film_tile(film_tile);
+            if !observers.is_empty() {
+                let rgb = film.get_rgb(&tile_bounds, 1.0);
+                for observer in observers {
+                    observer.tile_finished(&tile_bounds, &rgb);
+                }
+            }
+        };
+
+        // Merges the parts of a tile in order.
+        let merge_parts = |mut parts: Vec<(TileWork, Arc<FilmTile>)>| {
+            parts.sort_by_key(|(work, _)| work.part);
+            for (work, film_tile) in parts {
+                merge_tile(work.bounds, film_tile);
+            }
+        };
+
+        // Merges a finished tile and any preceding tiles that are done into
+        // `Film`. Split tiles are done once all of their parts are.
+        let finish_tile = |work: TileWork, film_tile| {
+            let _p = ProfilePhase::new(Prof::MergeFilmTile);
+            let mut pending = pending_tiles.lock().unwrap();
+            let (next_tile, finished_tiles) = &mut *pending;
+            finished_tiles
+                .entry(work.position)
+                .or_default()
+                .push((work, film_tile));
+            while finished_tiles
+                .get(next_tile)
+                .is_some_and(|parts| parts.len() == parts[0].0.parts)
+            {
+                merge_parts(finished_tiles.remove(next_tile).unwrap());
+                *next_tile += 1;
+            }
+        };
+
+        // Computes sample bounds for a tile.
+        let get_tile_bounds = |tile_index: usize| {
+            let tile = Point2::new(tile_index % n_tiles.x, tile_index / n_tiles.x);
+            let x0 = sample_bounds.p_min.x + tile.x as i32 * tile_size;
+            let x1 = min(x0 + tile_size, sample_bounds.p_max.x);
+            let y0 = sample_bounds.p_min.y + tile.y as i32 * tile_size;
+            let y1 = min(y0 + tile_size, sample_bounds.p_max.y);
+            Bounds2i::new(Point2i::new(x0, y0), Point2i::new(x1, y1))
+        };
+
+        // Renders a tile. With a progressive rendering pass, only the sample
+        // of each pixel with that number is taken.
+        let render_tile = |work: &TileWork, pass: Option<usize>, cancel: &CancellationToken| {
             // Render section of image corresponding to `tile`.
-            let tile = Point2::new(tile_x, tile_y);
+            let tile_index = work.tile_index;
+            let tile = Point2::new(tile_index % n_tiles.x, tile_index / n_tiles.x);
+            let render_phase = ProfilePhase::new(Prof::IntegratorRender);
+
+            // Allocate `MemoryArena` for tile.
+            let mut arena = MemoryArena::default();
 
-            // Get sampler instance for tile.
-            let seed = tile.y * n_tiles.x + tile.x;
-            let mut tile_sampler = Sampler::clone(&*self.get_data().sampler, seed as u64);
+            // Get sampler instance for tile. Each tile gets its own sampler
+            // seeded by its index, and the part of the tile if it was split,
+            // so no random state is shared across tiles.
+            let mut tile_sampler = Sampler::clone(&*self.get_data().sampler, work.seed());
 
             let samples_per_pixel = {
                 let tile_sampler_data = Arc::get_mut(&mut tile_sampler).unwrap().get_data();
                 tile_sampler_data.samples_per_pixel
             };
 
-            // Compute sample bounds for tile.
-            let x0 = sample_bounds.p_min.x + tile.x as i32 * tile_size;
-            let x1 = min(x0 + tile_size, sample_bounds.p_max.x);
-            let y0 = sample_bounds.p_min.y + tile.y as i32 * tile_size;
-            let y1 = min(y0 + tile_size, sample_bounds.p_max.y);
-            let tile_bounds = Bounds2i::new(Point2i::new(x0, y0), Point2i::new(x1, y1));
+            // Sample bounds of the tile or its part.
+            let tile_bounds = work.bounds;
 
             info!(
+                target: INTEGRATOR,
                 "Starting image tile ({}, {}) -> {:}",
-                tile_x, tile_y, tile_bounds
+                tile.x, tile.y, tile_bounds
             );
+            for observer in observers {
+                observer.tile_started(&tile_bounds);
+            }
 
             // Get `FilmTile` for tile.
             let mut film_tile = film.get_film_tile(tile_bounds);
 
-            // Loop over pixels in tile to render them.
-            for pixel in tile_bounds {
-                Arc::get_mut(&mut tile_sampler).unwrap().start_pixel(&pixel);
+            if let Some(shader) = wavefront {
+                render_wavefront_tile(
+                    shader,
+                    &self.get_data().camera,
+                    &self.get_data().pixel_bounds,
+                    self.get_data().max_component_value,
+                    tile_bounds,
+                    &scene,
+                    &mut tile_sampler,
+                    Arc::get_mut(&mut film_tile).unwrap(),
+                    tile_index as u64,
+                    cancel,
+                );
+            } else {
+                // Loop over pixels in tile to render them.
+                'pixels: for pixel in tile_bounds {
+                    {
+                        let _p = ProfilePhase::new(Prof::StartPixel);
+                        Arc::get_mut(&mut tile_sampler).unwrap().start_pixel(&pixel);
+                    }
 
-                // Do this check after the StartPixel() call; this keeps the
-                // usage of RNG values from (most) Samplers that use RNGs
-                // consistent, which improves reproducability / debugging.
-                if !self.get_data().pixel_bounds.contains_exclusive(&pixel) {
-                    continue;
-                }
+                    // Do this check after the StartPixel() call; this keeps the
+                    // usage of RNG values from (most) Samplers that use RNGs
+                    // consistent, which improves reproducability / debugging.
+                    if !self.get_data().pixel_bounds.contains_exclusive(&pixel) {
+                        continue;
+                    }
 
-                loop {
-                    // Initialize `CameraSample` for current sample.
-                    let camera_sample = Arc::get_mut(&mut tile_sampler)
-                        .unwrap()
-                        .get_camera_sample(&pixel);
-
-                    // Generate camera ray for current sample.
-                    let (mut ray, ray_weight) = self
-                        .get_data()
-                        .camera
-                        .generate_ray_differential(&camera_sample);
-                    ray.scale_differentials(1.0 / (samples_per_pixel as Float).sqrt());
-
-                    // Evaluate radiance along camera ray.
-                    let mut l = Spectrum::new(0.0);
-                    if ray_weight > 0.0 {
-                        l = self.li(&mut ray, scene.clone(), &mut tile_sampler, 0);
+                    // Take fewer samples in pixels that were smooth in a
+                    // previous render and track the variance of the pixel.
+                    // With adaptive sampling, stop once it has converged.
+                    let mut pixel_samples = film.pixel_samples(&pixel, samples_per_pixel);
+                    if let Some(pass) = pass {
+                        if pass >= pixel_samples
+                            || !Arc::get_mut(&mut tile_sampler)
+                                .unwrap()
+                                .set_sample_number(pass)
+                        {
+                            continue;
+                        }
+                        pixel_samples = 1;
                     }
+                    let max_variance = self.get_data().max_variance;
+                    let mut stats = PixelStatistics::default();
 
-                    // Issue warning if unexpected radiance value returned.
-                    let tile_sampler_data = Arc::get_mut(&mut tile_sampler).unwrap().get_data();
-                    let current_sample_number = tile_sampler_data.current_sample_number();
-                    if l.has_nans() {
-                        error!(
-                            "Not-a-number radiance value returned for pixel 
-                            ({}, {}), sample {}. Setting to black.",
-                            pixel.x, pixel.y, current_sample_number
-                        );
-                        l = Spectrum::new(0.0);
-                    } else if l.y() < -1e-5 {
-                        error!(
-                            "Negative luminance value, {}, returned for pixel 
-                            ({}, {}), sample {}. Setting to black.",
-                            l.y(),
-                            pixel.x,
-                            pixel.y,
-                            current_sample_number
-                        );
-                        l = Spectrum::new(0.0);
-                    } else if l.y().is_infinite() {
-                        error!(
-                            "Infinite luminance value returned for pixel 
-                            ({}, {}), sample {}. Setting to black.",
-                            pixel.x, pixel.y, current_sample_number
+                    loop {
+                        // Initialize `CameraSample` for current sample.
+                        let camera_sample = Arc::get_mut(&mut tile_sampler)
+                            .unwrap()
+                            .get_camera_sample(&pixel);
+
+                        // Generate camera ray for current sample.
+                        let (mut ray, ray_weight) = {
+                            let _p = ProfilePhase::new(Prof::GenerateCameraRay);
+                            self.get_data()
+                                .camera
+                                .generate_ray_differential(&camera_sample)
+                        };
+                        ray.scale_differentials(1.0 / (samples_per_pixel as Float).sqrt());
+                        N_CAMERA_RAYS.inc();
+
+                        // Compute the coverage of the sample before the ray
+                        // is modified.
+                        let alpha = if film.alpha && ray_weight > 0.0 {
+                            camera_ray_alpha(&ray, &scene)
+                        } else {
+                            1.0
+                        };
+
+                        // Record the AOVs of the first surface the ray hits.
+                        let aov_values = if !film.aovs.is_empty() && ray_weight > 0.0 {
+                            camera_ray_aovs(&ray, &scene, prim_ids.as_ref()).map(|sample| {
+                                film.aovs
+                                    .iter()
+                                    .map(|aov| aov.value(&sample))
+                                    .collect::<Vec<[Float; 3]>>()
+                            })
+                        } else {
+                            None
+                        };
+
+                        // Evaluate radiance along camera ray. Record the
+                        // radiance of the light path expression channels along
+                        // the way.
+                        let mut l = Spectrum::new(0.0);
+                        if !film.lpe_channels.is_empty() {
+                            begin_lpe_path(film.lpe_channels.clone());
+                        }
+                        if ray_weight > 0.0 {
+                            let _p = ProfilePhase::new(Prof::SamplerIntegratorLi);
+                            l = self.li(&mut ray, scene.clone(), &mut tile_sampler, &arena, 0);
+                        }
+                        let mut lpe_radiance = end_lpe_path();
+
+                        // Issue warning if unexpected radiance value returned.
+                        let tile_sampler_data = Arc::get_mut(&mut tile_sampler).unwrap().get_data();
+                        let current_sample_number = tile_sampler_data.current_sample_number();
+                        let l = clamped_radiance(
+                            checked_radiance(l, &pixel, current_sample_number),
+                            self.get_data().max_component_value,
                         );
-                        l = Spectrum::new(0.0);
-                    }
+                        if l.is_black() {
+                            // Discard the channels along with invalid radiance.
+                            lpe_radiance
+                                .iter_mut()
+                                .for_each(|c| *c = Spectrum::new(0.0));
+                        }
+                        stats.add(l.y() * ray_weight);
+
+                        //debug!(
+                        //    "Camera sample: {:} -> ray: {:} -> L = {:}",
+                        //    camera_sample, ray, l
+                        //);
+
+                        // Add camera ray's contribution to image.
+                        {
+                            let _p = ProfilePhase::new(Prof::AddFilmSample);
+                            Arc::get_mut(&mut film_tile)
+                                .unwrap()
+                                .add_sample_with_channels(
+                                    camera_sample.p_film,
+                                    l,
+                                    alpha,
+                                    &lpe_radiance,
+                                    ray_weight,
+                                );
+                            if let Some(values) = aov_values {
+                                Arc::get_mut(&mut film_tile)
+                                    .unwrap()
+                                    .add_aov_sample(&pixel, &values);
+                            }
+                        }
 
-                    //debug!(
-                    //    "Camera sample: {:} -> ray: {:} -> L = {:}",
-                    //    camera_sample, ray, l
-                    //);
+                        if cancel.is_cancelled() {
+                            break 'pixels;
+                        }
 
-                    // Add camera ray's contribution to image.
-                    Arc::get_mut(&mut film_tile).unwrap().add_sample(
-                        camera_sample.p_film,
-                        l,
-                        ray_weight,
-                    );
+                        // Free `MemoryArena` memory from computing image sample
+                        // value.
+                        arena.reset();
 
-                    if !Arc::get_mut(&mut tile_sampler).unwrap().start_next_sample() {
-                        break;
+                        if stats.count >= pixel_samples
+                            || (max_variance > 0.0
+                                && stats.has_converged(max_variance, ADAPTIVE_MIN_SAMPLES))
+                            || !Arc::get_mut(&mut tile_sampler).unwrap().start_next_sample()
+                        {
+                            break;
+                        }
+                    }
+
+                    if film.write_variance && pass.is_none() {
+                        Arc::get_mut(&mut film_tile)
+                            .unwrap()
+                            .add_pixel_variance(pixel, stats.variance_of_mean());
+                    }
+                    if film.write_sample_count && pass.is_none() {
+                        Arc::get_mut(&mut film_tile)
+                            .unwrap()
+                            .add_pixel_sample_count(pixel, stats.count);
                     }
                 }
             }
             info!(
+                target: INTEGRATOR,
                 "Finished image tile ({}, {}) -> {:}",
-                tile_x, tile_y, tile_bounds
+                tile.x, tile.y, tile_bounds
             );
 
-            // Merge image tile into `Film`.
-            film.merge_film_tile(film_tile.clone());
-        });
+            drop(render_phase);
+            report_thread_stats();
+            report_thread_profile();
+            (tile_bounds, film_tile)
+        };
+
+        let job = RenderJob::new(sample_bounds, OPTIONS.tile_size, tile_indices.len());
+        let distributed = OPTIONS.worker.is_some() || OPTIONS.coordinator.is_some();
+        if distributed && !film.lpe_channels.is_empty() {
+            warn!(
+                target: INTEGRATOR,
+                "Light path expressions are not supported by distributed rendering."
+            );
+        }
+        if distributed && !film.aovs.is_empty() {
+            warn!(target: INTEGRATOR, "AOVs are not supported by distributed rendering.");
+        }
+        if distributed && film.write_variance {
+            warn!(target: INTEGRATOR, "Pixel variance is not supported by distributed rendering.");
+        }
+        if distributed && film.write_sample_count {
+            warn!(
+                target: INTEGRATOR,
+                "Pixel sample counts are not supported by distributed rendering."
+            );
+        }
+        if OPTIONS.progressive
+            && (film.write_variance
+                || film.write_sample_count
+                || self.get_data().max_variance > 0.0)
+        {
+            warn!(
+                target: INTEGRATOR,
+                "Pixel variance, sample counts and adaptive sampling are not supported by \
+                progressive rendering."
+            );
+        }
+        if let Some(address) = OPTIONS.worker.as_ref() {
+            // Render the tiles assigned by the coordinator. It writes the
+            // image.
+            return run_worker(address, &job, cancel, |tile_index, stop| {
+                let work = TileWork::new(0, tile_index, get_tile_bounds(tile_index));
+                let (_, mut film_tile) = render_tile(&work, None, stop);
+                TileResult::from_film_tile(tile_index, Arc::get_mut(&mut film_tile).unwrap())
+            });
+        } else if let Some(address) = OPTIONS.coordinator.as_ref() {
+            // Merge the tiles rendered by workers.
+            run_coordinator(address, &job, &tile_indices, cancel, |position, result| {
+                let work = TileWork::new(
+                    position,
+                    result.tile_index,
+                    get_tile_bounds(result.tile_index),
+                );
+                let mut film_tile = film.get_film_tile(work.bounds);
+                result.into_film_tile(Arc::get_mut(&mut film_tile).unwrap())?;
+                finish_tile(work, film_tile);
+                Ok(())
+            })?;
+        } else if OPTIONS.progressive {
+            // Refine the whole image one sample per pixel at a time. The
+            // tiles of a pass are rendered in parallel and merged in order.
+            let n_passes = Arc::get_mut(&mut Sampler::clone(&*self.get_data().sampler, 0))
+                .unwrap()
+                .get_data()
+                .samples_per_pixel;
+            let progress_file = OPTIONS
+                .progress_file
+                .clone()
+                .unwrap_or_else(|| film.filename.clone());
+            info!(target: INTEGRATOR, "Rendering {} progressive passes", n_passes);
+
+            let mut last_write = (0, Instant::now());
+            for pass in 0..n_passes {
+                if cancel.is_cancelled() {
+                    break;
+                }
+                let tiles: Vec<(Bounds2i, Arc<FilmTile>)> = tile_indices
+                    .par_iter()
+                    .enumerate()
+                    .map(|(position, &tile_index)| {
+                        let work = TileWork::new(position, tile_index, get_tile_bounds(tile_index));
+                        render_tile(&work, Some(pass), cancel)
+                    })
+                    .collect();
+                for (tile_bounds, film_tile) in tiles {
+                    merge_tile(tile_bounds, film_tile);
+                }
+                for observer in observers {
+                    observer.pass_finished(pass);
+                }
 
-        info!("Rendering finished.");
+                // Write the image once enough passes or time have gone by
+                // since it was last written. The last pass is written below.
+                let (write_pass, write_time) = last_write;
+                let due = OPTIONS
+                    .progress_passes
+                    .is_some_and(|n| pass + 1 - write_pass >= n)
+                    || OPTIONS
+                        .progress_interval
+                        .is_some_and(|t| write_time.elapsed().as_secs_f64() >= t as f64);
+                if due && pass + 1 < n_passes && !progress_file.is_empty() {
+                    match film.write_image_to(&progress_file, 1.0) {
+                        Ok(()) => info!(
+                            target: INTEGRATOR,
+                            "Image of pass {} of {} written.",
+                            pass + 1,
+                            n_passes
+                        ),
+                        Err(err) => warn!(target: INTEGRATOR, "{}", err),
+                    }
+                    last_write = (pass + 1, Instant::now());
+                }
+            }
+        } else {
+            // Each thread takes tiles from the scheduler until none are left.
+            // It renders neighbouring tiles one after another, which keeps
+            // the scene data and film pixels it touches close together, and
+            // steals tiles from other threads once it runs out.
+            let mut tiles: Vec<TileWork> = tile_indices
+                .iter()
+                .enumerate()
+                .map(|(position, &tile_index)| {
+                    TileWork::new(position, tile_index, get_tile_bounds(tile_index))
+                })
+                .collect();
+            if let Some([x0, x1, y0, y1]) = OPTIONS.priority_region {
+                let priority = Bounds2i::new(Point2i::new(x0, y0), Point2i::new(x1, y1));
+                prioritize_tiles(&mut tiles, &priority);
+            }
+            let n_threads = current_num_threads();
+
+            // Tiles are scheduled in batches that are done before the next
+            // one starts, so that at most a batch of finished tiles waits to
+            // be merged behind a slow tile.
+            let start = Instant::now();
+            let busy_time = Mutex::new(Duration::default());
+            for batch in tiles.chunks(TILES_PER_THREAD_IN_BATCH * n_threads) {
+                if cancel.is_cancelled() {
+                    break;
+                }
+                let scheduler = TileScheduler::new(batch.to_vec(), n_threads, OPTIONS.split_tiles);
+                (0..n_threads).into_par_iter().for_each(|thread| {
+                    let mut busy = Duration::default();
+                    while let Some(work) = scheduler.next(thread) {
+                        if cancel.is_cancelled() {
+                            break;
+                        }
+                        let tile_start = Instant::now();
+                        let (_, film_tile) = render_tile(&work, None, cancel);
+                        let tile_time = tile_start.elapsed();
+                        report_tile_time(tile_time.as_secs_f64() * 1000.0);
+                        busy += tile_time;
+                        finish_tile(work, film_tile);
+                    }
+                    *busy_time.lock().unwrap() += busy;
+                    report_thread_stats();
+                });
+            }
+
+            // Report how well the load was balanced between the threads.
+            let busy = busy_time.into_inner().unwrap().as_millis() as i64;
+            let available = (start.elapsed() * n_threads as u32).as_millis() as i64;
+            report_thread_utilization(busy, available);
+            report_thread_stats();
+            info!(
+                target: INTEGRATOR,
+                "Render threads were busy {:.1}% of the time.",
+                100.0 * busy as f64 / max(1, available) as f64
+            );
+        }
+
+        if cancel.is_cancelled() {
+            // Tiles that were skipped leave gaps in the merge order; merge
+            // the remaining finished tiles in order.
+            let mut pending = pending_tiles.lock().unwrap();
+            for (_, parts) in std::mem::take(&mut pending.1) {
+                merge_parts(parts);
+            }
+            warn!(target: INTEGRATOR, "Rendering cancelled. Writing partial image.");
+        } else {
+            info!(target: INTEGRATOR, "Rendering finished.");
+            if !OPTIONS.progressive {
+                for observer in observers {
+                    observer.pass_finished(0);
+                }
+            }
+        }
 
         // Save final image after rendering.
-        film.clone().write_image(1.0);
-        info!("Output image written.");
+        let result = film.clone().write_image(1.0);
+        if result.is_ok() {
+            info!(target: INTEGRATOR, "Output image written.");
+        }
+        for observer in observers {
+            observer.render_finished(cancel.is_cancelled());
+        }
+        result
+    }
+}
+
+/// Returns the alpha of a camera ray; 1 if it hits a surface that isn't held
+/// out and 0 otherwise. Surfaces without a material are passed through.
+///
+/// * `ray`   - The camera ray.
+/// * `scene` - The scene.
+pub fn camera_ray_alpha(ray: &Ray, scene: &Scene) -> Float {
+    let mut ray = ray.clone();
+    while let Some(isect) = scene.intersect(&mut ray) {
+        match isect.primitive {
+            Some(p) if p.is_holdout() => return 0.0,
+            Some(p) if p.get_material().is_none() => ray = isect.hit.spawn_ray(&ray.d),
+            _ => return 1.0,
+        }
+    }
+    0.0
+}
+
+/// Returns the AOVs of the first surface a camera ray hits; or `None` if it
+/// misses. Surfaces without a material are passed through. The albedo is the
+/// reflectance of the surface for light leaving back along the ray.
+///
+/// * `ray`      - The camera ray.
+/// * `scene`    - The scene.
+/// * `prim_ids` - Identifiers of the primitives of the scene; `None` if they
+///                aren't needed.
+pub fn camera_ray_aovs(
+    ray: &Ray,
+    scene: &Scene,
+    prim_ids: Option<&PrimitiveIds>,
+) -> Option<AovSample> {
+    let mut r = ray.clone();
+    loop {
+        let mut isect = scene.intersect(&mut r)?;
+        if matches!(isect.primitive, Some(p) if p.get_material().is_none()) {
+            r = isect.hit.spawn_ray(&r.d);
+            continue;
+        }
+
+        // Estimate the reflectance with a stratified grid of directions.
+        isect.compute_scattering_functions(&r, true, TransportMode::Radiance);
+        let u: Vec<Point2f> = (0..16)
+            .map(|i| {
+                Point2f::new(
+                    ((i % 4) as Float + 0.5) / 4.0,
+                    ((i / 4) as Float + 0.5) / 4.0,
+                )
+            })
+            .collect();
+        let albedo = isect.bsdf.as_ref().map_or(Spectrum::new(0.0), |bsdf| {
+            bsdf.rho_hd(&isect.hit.wo, &u, BxDFType::from(BSDF_ALL))
+        });
+
+        return Some(AovSample {
+            albedo,
+            normal: isect.shading.n,
+            depth: (isect.hit.p - ray.o).length(),
+            position: isect.hit.p,
+            prim_id: match (prim_ids, isect.primitive) {
+                (Some(ids), Some(primitive)) => ids.get(primitive),
+                _ => 0.0,
+            },
+        });
+    }
+}
+
+/// Returns the radiance of a camera sample scaled down so that none of its
+/// components exceed a maximum value.
+///
+/// * `l`                   - The radiance.
+/// * `max_component_value` - Maximum value of a component.
+pub fn clamped_radiance(l: Spectrum, max_component_value: Float) -> Spectrum {
+    let m = l.max_component_value();
+    if m > max_component_value {
+        l * (max_component_value / m)
+    } else {
+        l
+    }
+}
+
+/// Returns the radiance of a camera sample; or black if it is not a number,
+/// negative or infinite, in which case an error is logged. The errors are
+/// rate limited so a bad material can't flood the log.
+///
+/// * `l`             - The radiance.
+/// * `pixel`         - The pixel.
+/// * `sample_number` - The sample number in the pixel.
+pub fn checked_radiance(l: Spectrum, pixel: &Point2i, sample_number: usize) -> Spectrum {
+    if l.has_nans() {
+        log_limited!(
+            Level::Error,
+            target: INTEGRATOR,
+            "Not-a-number radiance value returned for pixel
+            ({}, {}), sample {}. Setting to black.",
+            pixel.x, pixel.y, sample_number
+        );
+        Spectrum::new(0.0)
+    } else if l.y() < -1e-5 {
+        log_limited!(
+            Level::Error,
+            target: INTEGRATOR,
+            "Negative luminance value, {}, returned for pixel
+            ({}, {}), sample {}. Setting to black.",
+            l.y(),
+            pixel.x,
+            pixel.y,
+            sample_number
+        );
+        Spectrum::new(0.0)
+    } else if l.y().is_infinite() {
+        log_limited!(
+            Level::Error,
+            target: INTEGRATOR,
+            "Infinite luminance value returned for pixel
+            ({}, {}), sample {}. Setting to black.",
+            pixel.x, pixel.y, sample_number
+        );
+        Spectrum::new(0.0)
+    } else {
+        l
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::accelerators::*;
+    use crate::core::medium::*;
+    use crate::core::paramset::*;
+    use crate::core::primitive::*;
+    use crate::core::primitives::*;
+    use crate::materials::*;
+    use crate::shapes::*;
+
+    #[test]
+    fn held_out_surfaces_have_zero_alpha() {
+        let matte: ArcMaterial = Arc::new(MatteMaterial::from(&TextureParams::default()));
+        let sphere = |x: Float, holdout: bool| -> ArcPrimitive {
+            let o2w = Arc::new(Transform::translate(&Vector3f::new(x, 0.0, 0.0)));
+            let w2o = Arc::new(o2w.inverse());
+            let shape = Arc::new(Sphere::new(o2w, w2o, false, 1.0, -1.0, 1.0, 360.0));
+            let mut primitive = GeometricPrimitive::new(
+                shape,
+                Some(matte.clone()),
+                None,
+                MediumInterface::vacuum(),
+            );
+            primitive.holdout = holdout;
+            Arc::new(primitive)
+        };
+        let primitives = vec![sphere(-2.0, false), sphere(2.0, true)];
+        let bvh = BVHAccel::from((&ParamSet::new(), &primitives));
+        let scene = Scene::new(Arc::new(bvh), vec![]);
+
+        let ray = |x: Float| {
+            Ray::new(
+                Point3f::new(x, 0.0, -5.0),
+                Vector3f::new(0.0, 0.0, 1.0),
+                INFINITY,
+                0.0,
+                None,
+            )
+        };
+        assert_eq!(camera_ray_alpha(&ray(-2.0), &scene), 1.0);
+        assert_eq!(camera_ray_alpha(&ray(2.0), &scene), 0.0);
+        assert_eq!(camera_ray_alpha(&ray(0.0), &scene), 0.0);
+    }
+
+    #[test]
+    fn aovs_describe_the_first_surface_hit() {
+        let matte: ArcMaterial = Arc::new(MatteMaterial::from(&TextureParams::default()));
+        let shape = Arc::new(Sphere::new(
+            Arc::new(Transform::default()),
+            Arc::new(Transform::default()),
+            false,
+            1.0,
+            -1.0,
+            1.0,
+            360.0,
+        ));
+        let primitive: ArcPrimitive = Arc::new(GeometricPrimitive::new(
+            shape,
+            Some(matte),
+            None,
+            MediumInterface::vacuum(),
+        ));
+        let bvh = BVHAccel::from((&ParamSet::new(), &vec![primitive]));
+        let scene = Scene::new(Arc::new(bvh), vec![]);
+
+        let ray = |x: Float| {
+            Ray::new(
+                Point3f::new(x, 0.0, -5.0),
+                Vector3f::new(0.0, 0.0, 1.0),
+                INFINITY,
+                0.0,
+                None,
+            )
+        };
+        let prim_ids = PrimitiveIds::new(scene.aggregate.as_ref());
+        let aovs = camera_ray_aovs(&ray(0.0), &scene, Some(&prim_ids)).unwrap();
+        assert!((aovs.depth - 4.0).abs() < 1e-4);
+        assert!((aovs.position.z + 1.0).abs() < 1e-4);
+        assert!((aovs.normal.z.abs() - 1.0).abs() < 1e-4);
+        assert!((aovs.albedo.y() - 0.5).abs() < 1e-3);
+        assert_eq!(aovs.prim_id, 1.0);
+        assert_eq!(
+            camera_ray_aovs(&ray(0.0), &scene, None).unwrap().prim_id,
+            0.0
+        );
+        assert!(camera_ray_aovs(&ray(2.0), &scene, None).is_none());
     }
 }
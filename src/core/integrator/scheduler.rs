@@ -0,0 +1,294 @@
+//! Tile Scheduler
+
+use crate::core::geometry::*;
+use crate::core::pbrt::*;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+stat_counter!("Scheduler/Tiles stolen", N_TILES_STOLEN);
+stat_counter!("Scheduler/Tiles split", N_TILES_SPLIT);
+stat_float_distribution!("Scheduler/Tile render time (ms)", TILE_TIME);
+stat_percent!("Scheduler/Thread utilization", THREAD_UTILIZATION);
+
+/// Tiles smaller than this many pixels along either side are not split.
+const MIN_SPLIT_SIZE: i32 = 4;
+
+/// Number of parts a tile is split into.
+const SPLIT_PARTS: usize = 4;
+
+/// A tile, or part of a tile, handed to a render thread.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TileWork {
+    /// Position of the tile in rendering order. Finished tiles are merged
+    /// into the film in this order.
+    pub position: usize,
+
+    /// Index `y * n_tiles.x + x` of the tile in the grid.
+    pub tile_index: usize,
+
+    /// Sample bounds to render.
+    pub bounds: Bounds2i,
+
+    /// 0 for a whole tile; otherwise the 1-based index of the part of a
+    /// split tile.
+    pub part: usize,
+
+    /// Number of parts the tile was split into; 1 if it wasn't.
+    pub parts: usize,
+}
+
+impl TileWork {
+    /// Create a new `TileWork` for a whole tile.
+    ///
+    /// * `position`   - Position of the tile in rendering order.
+    /// * `tile_index` - Index of the tile in the grid.
+    /// * `bounds`     - Sample bounds of the tile.
+    pub fn new(position: usize, tile_index: usize, bounds: Bounds2i) -> Self {
+        Self {
+            position,
+            tile_index,
+            bounds,
+            part: 0,
+            parts: 1,
+        }
+    }
+
+    /// Returns the seed of the sampler for the work. Whole tiles are seeded
+    /// by their index so images don't change unless tiles are split.
+    pub fn seed(&self) -> u64 {
+        self.tile_index as u64 | (self.part as u64) << 32
+    }
+
+    /// Returns the quadrants of the tile; or `None` if the tile is already
+    /// split or too small.
+    fn split(&self) -> Option<Vec<Self>> {
+        let extent = self.bounds.diagonal();
+        if self.parts > 1 || extent.x < 2 * MIN_SPLIT_SIZE || extent.y < 2 * MIN_SPLIT_SIZE {
+            return None;
+        }
+        let (p0, p1) = (self.bounds.p_min, self.bounds.p_max);
+        let mid = Point2i::new(p0.x + extent.x / 2, p0.y + extent.y / 2);
+        let quadrants = [
+            Bounds2i::new(p0, mid),
+            Bounds2i::new(Point2i::new(mid.x, p0.y), Point2i::new(p1.x, mid.y)),
+            Bounds2i::new(Point2i::new(p0.x, mid.y), Point2i::new(mid.x, p1.y)),
+            Bounds2i::new(mid, p1),
+        ];
+        Some(
+            quadrants
+                .iter()
+                .enumerate()
+                .map(|(i, bounds)| Self {
+                    bounds: *bounds,
+                    part: i + 1,
+                    parts: SPLIT_PARTS,
+                    ..*self
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Hands out tiles to render threads. Each thread has a queue of contiguous
+/// bands of tiles in rendering order so neighbouring tiles are rendered one
+/// after another. Threads that run out of tiles steal them from the back of
+/// the longest queue, and once fewer tiles are left than there are threads,
+/// tiles can be split so the last expensive tiles don't leave threads idle.
+pub struct TileScheduler {
+    /// Queue of tiles of each thread.
+    queues: Vec<Mutex<VecDeque<TileWork>>>,
+
+    /// Number of tiles waiting in all queues.
+    queued: AtomicUsize,
+
+    /// Whether to split tiles at the end of rendering.
+    split_tiles: bool,
+}
+
+impl TileScheduler {
+    /// Create a new `TileScheduler`.
+    ///
+    /// * `tiles`       - The tiles in rendering order.
+    /// * `n_threads`   - Number of render threads.
+    /// * `split_tiles` - Whether to split tiles at the end of rendering.
+    pub fn new(tiles: Vec<TileWork>, n_threads: usize, split_tiles: bool) -> Self {
+        let n_threads = max(1, n_threads);
+        let band_size = max(1, tiles.len() / (4 * n_threads));
+        let mut queues = vec![VecDeque::new(); n_threads];
+        for (band, tiles) in tiles.chunks(band_size).enumerate() {
+            queues[band % n_threads].extend(tiles.iter().copied());
+        }
+
+        Self {
+            queued: AtomicUsize::new(tiles.len()),
+            queues: queues.into_iter().map(Mutex::new).collect(),
+            split_tiles,
+        }
+    }
+
+    /// Returns the next tile for a render thread; or `None` if all tiles have
+    /// been handed out.
+    ///
+    /// * `thread` - Index of the render thread.
+    pub fn next(&self, thread: usize) -> Option<TileWork> {
+        let thread = thread % self.queues.len();
+        let work = self.queues[thread].lock().unwrap().pop_front();
+        let work = match work {
+            Some(work) => work,
+            None => self.steal()?,
+        };
+        self.queued.fetch_sub(1, Ordering::SeqCst);
+
+        // Split the tile if other threads would otherwise run out of work
+        // and keep the first part.
+        let n_threads = self.queues.len();
+        if !self.split_tiles || n_threads < 2 || self.queued.load(Ordering::SeqCst) >= n_threads {
+            return Some(work);
+        }
+        match work.split() {
+            Some(mut parts) => {
+                N_TILES_SPLIT.inc();
+                let first = parts.remove(0);
+                self.queued.fetch_add(parts.len(), Ordering::SeqCst);
+                let mut queue = self.queues[thread].lock().unwrap();
+                for part in parts.into_iter().rev() {
+                    queue.push_front(part);
+                }
+                Some(first)
+            }
+            None => Some(work),
+        }
+    }
+
+    /// Returns a tile from the back of the longest queue; or `None` if all
+    /// queues are empty.
+    fn steal(&self) -> Option<TileWork> {
+        loop {
+            let victim = self
+                .queues
+                .iter()
+                .max_by_key(|q| q.lock().unwrap().len())
+                .unwrap();
+            let mut queue = victim.lock().unwrap();
+            if let Some(work) = queue.pop_back() {
+                N_TILES_STOLEN.inc();
+                return Some(work);
+            }
+            if self.queued.load(Ordering::SeqCst) == 0 {
+                return None;
+            }
+
+            // The queue was emptied by its thread in the meantime, or the
+            // tiles left are about to be counted or queued by another
+            // thread; let it run before trying again.
+            drop(queue);
+            std::thread::yield_now();
+        }
+    }
+}
+
+/// Moves the tiles overlapping a pixel region to the front of the rendering
+/// order and renumbers the positions of the tiles.
+///
+/// * `tiles`    - The tiles in rendering order.
+/// * `priority` - The pixel region.
+pub fn prioritize_tiles(tiles: &mut [TileWork], priority: &Bounds2i) {
+    // Stable so the rendering order is kept within each group.
+    tiles.sort_by_key(|t| !t.bounds.overlaps(priority));
+    for (position, tile) in tiles.iter_mut().enumerate() {
+        tile.position = position;
+    }
+}
+
+/// Records the time taken to render a tile.
+///
+/// * `ms` - Time in milliseconds.
+pub fn report_tile_time(ms: f64) {
+    TILE_TIME.report_value(ms);
+}
+
+/// Records how long render threads were busy out of the total time they were
+/// available.
+///
+/// * `busy_ms`      - Time in milliseconds threads spent rendering tiles.
+/// * `available_ms` - Time in milliseconds threads were available.
+pub fn report_thread_utilization(busy_ms: i64, available_ms: i64) {
+    THREAD_UTILIZATION.add(busy_ms, available_ms);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tiles(n: usize) -> Vec<TileWork> {
+        (0..n)
+            .map(|i| {
+                let p = Point2i::new(16 * i as i32, 0);
+                TileWork::new(i, i, Bounds2i::new(p, p + Vector2i::new(16, 16)))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn threads_render_their_bands_then_steal() {
+        // The only thread renders its band in order and then steals the
+        // other bands from their backs.
+        let scheduler = TileScheduler::new(tiles(8), 2, false);
+        let order: Vec<usize> = std::iter::from_fn(|| scheduler.next(0))
+            .map(|t| t.position)
+            .collect();
+        assert_eq!(order, [0, 2, 4, 6, 7, 5, 3, 1]);
+    }
+
+    #[test]
+    fn tiles_in_the_priority_region_come_first() {
+        let priority = Bounds2i::new(Point2i::new(100, 0), Point2i::new(120, 8));
+        let mut prioritized = tiles(8);
+        prioritize_tiles(&mut prioritized, &priority);
+        let scheduler = TileScheduler::new(prioritized, 1, false);
+        let work: Vec<TileWork> = std::iter::from_fn(|| scheduler.next(0)).collect();
+        let tile_indices: Vec<usize> = work.iter().map(|t| t.tile_index).collect();
+        assert_eq!(tile_indices, [6, 7, 0, 1, 2, 3, 4, 5]);
+        assert!(work.iter().enumerate().all(|(i, t)| t.position == i));
+    }
+
+    #[test]
+    fn last_tiles_are_split_into_quadrants() {
+        let scheduler = TileScheduler::new(tiles(2), 4, true);
+        let work: Vec<TileWork> = std::iter::from_fn(|| scheduler.next(0)).collect();
+        assert_eq!(work.len(), 8);
+        assert!(work.iter().all(|t| t.parts == 4 && t.part > 0));
+        let area: i32 = work
+            .iter()
+            .filter(|t| t.position == 1)
+            .map(|t| t.bounds.area())
+            .sum();
+        assert_eq!(area, 256);
+        assert_ne!(work[0].seed(), work[1].seed());
+        assert_eq!(TileWork::new(0, 5, work[0].bounds).seed(), 5);
+    }
+
+    #[test]
+    fn threads_taking_tiles_at_the_same_time_cover_every_pixel_once() {
+        let scheduler = TileScheduler::new(tiles(64), 4, true);
+        let work = Mutex::new(vec![]);
+        std::thread::scope(|scope| {
+            for thread in 0..4 {
+                let (scheduler, work) = (&scheduler, &work);
+                scope.spawn(move || {
+                    while let Some(t) = scheduler.next(thread) {
+                        work.lock().unwrap().push(t);
+                    }
+                });
+            }
+        });
+        let work = work.into_inner().unwrap();
+        let area: i32 = work.iter().map(|t| t.bounds.area()).sum();
+        assert_eq!(area, 64 * 256);
+        for position in 0..64 {
+            let parts = work.iter().filter(|t| t.position == position);
+            assert!(parts.clone().all(|t| t.parts == parts.clone().count()));
+        }
+    }
+}
@@ -0,0 +1,169 @@
+//! Tile Order
+
+use crate::core::geometry::*;
+
+/// Order in which image tiles are rendered.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum TileOrder {
+    /// Rows of tiles from top to bottom; each row from left to right.
+    RowMajor,
+
+    /// Spiral starting at the tile in the center of the image.
+    Spiral,
+
+    /// Hilbert curve starting at the top left tile.
+    Hilbert,
+}
+
+impl TileOrder {
+    /// Returns the tile order for a given name; or `None` if the name is not
+    /// recognized. Names are case insensitive.
+    ///
+    /// * `name` - The name (e.g. "rowmajor", "spiral", "hilbert").
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "rowmajor" | "row-major" | "scanline" => Some(Self::RowMajor),
+            "spiral" => Some(Self::Spiral),
+            "hilbert" => Some(Self::Hilbert),
+            _ => None,
+        }
+    }
+
+    /// Returns the indices `y * n_tiles.x + x` of all tiles in a grid in the
+    /// order they should be rendered.
+    ///
+    /// * `n_tiles` - Number of tiles along x and y.
+    pub fn tile_indices(&self, n_tiles: Point2<usize>) -> Vec<usize> {
+        let (nx, ny) = (n_tiles.x, n_tiles.y);
+        match self {
+            Self::RowMajor => (0..nx * ny).collect(),
+            Self::Spiral => spiral_tile_indices(nx, ny),
+            Self::Hilbert => hilbert_tile_indices(nx, ny),
+        }
+    }
+}
+
+/// Returns the tile indices in a spiral starting at the center of the grid.
+///
+/// * `nx` - Number of tiles along x.
+/// * `ny` - Number of tiles along y.
+fn spiral_tile_indices(nx: usize, ny: usize) -> Vec<usize> {
+    let total = nx * ny;
+    let mut indices = Vec::with_capacity(total);
+    if total == 0 {
+        return indices;
+    }
+
+    // Walk right, down, left, up with runs of length 1, 1, 2, 2, 3, 3, ...
+    // and keep the tiles inside the grid.
+    const DIRECTIONS: [(i64, i64); 4] = [(1, 0), (0, 1), (-1, 0), (0, -1)];
+    let (mut x, mut y) = (((nx - 1) / 2) as i64, ((ny - 1) / 2) as i64);
+    indices.push(y as usize * nx + x as usize);
+
+    let mut run = 1;
+    let mut direction = 0;
+    while indices.len() < total {
+        for _ in 0..2 {
+            let (dx, dy) = DIRECTIONS[direction];
+            for _ in 0..run {
+                x += dx;
+                y += dy;
+                if x >= 0 && y >= 0 && (x as usize) < nx && (y as usize) < ny {
+                    indices.push(y as usize * nx + x as usize);
+                }
+            }
+            direction = (direction + 1) % 4;
+        }
+        run += 1;
+    }
+
+    indices
+}
+
+/// Returns the tile indices along a Hilbert curve covering the grid.
+///
+/// * `nx` - Number of tiles along x.
+/// * `ny` - Number of tiles along y.
+fn hilbert_tile_indices(nx: usize, ny: usize) -> Vec<usize> {
+    // The curve covers a square grid with a power of 2 side; skip the tiles
+    // outside the image.
+    let n = nx.max(ny).next_power_of_two();
+    (0..n * n)
+        .map(|d| hilbert_point(n, d))
+        .filter(|&(x, y)| x < nx && y < ny)
+        .map(|(x, y)| y * nx + x)
+        .collect()
+}
+
+/// Returns the point at a distance along the Hilbert curve covering an
+/// `n x n` grid.
+///
+/// * `n` - Side of the grid; a power of 2.
+/// * `d` - Distance along the curve.
+fn hilbert_point(n: usize, d: usize) -> (usize, usize) {
+    let (mut x, mut y) = (0, 0);
+    let mut t = d;
+    let mut s = 1;
+    while s < n {
+        let rx = 1 & (t / 2);
+        let ry = 1 & (t ^ rx);
+
+        // Rotate the quadrant.
+        if ry == 0 {
+            if rx == 1 {
+                x = s - 1 - x;
+                y = s - 1 - y;
+            }
+            std::mem::swap(&mut x, &mut y);
+        }
+
+        x += s * rx;
+        y += s * ry;
+        t /= 4;
+        s *= 2;
+    }
+    (x, y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_permutation(mut indices: Vec<usize>, total: usize) -> bool {
+        indices.sort_unstable();
+        indices == (0..total).collect::<Vec<usize>>()
+    }
+
+    #[test]
+    fn every_order_visits_each_tile_once() {
+        for order in [TileOrder::RowMajor, TileOrder::Spiral, TileOrder::Hilbert].iter() {
+            for &(nx, ny) in [(0, 0), (1, 1), (5, 3), (2, 7), (8, 8)].iter() {
+                let indices = order.tile_indices(Point2::new(nx, ny));
+                assert!(
+                    is_permutation(indices, nx * ny),
+                    "{:?} {}x{}",
+                    order,
+                    nx,
+                    ny
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn spiral_starts_at_center() {
+        let indices = TileOrder::Spiral.tile_indices(Point2::new(5, 3));
+        assert_eq!(indices[0], 5 + 2);
+    }
+
+    #[test]
+    fn hilbert_visits_adjacent_tiles() {
+        let n = 8;
+        let indices = TileOrder::Hilbert.tile_indices(Point2::new(n, n));
+        for w in indices.windows(2) {
+            let (x0, y0) = ((w[0] % n) as i64, (w[0] / n) as i64);
+            let (x1, y1) = ((w[1] % n) as i64, (w[1] / n) as i64);
+            assert_eq!((x1 - x0).abs() + (y1 - y0).abs(), 1);
+        }
+    }
+}
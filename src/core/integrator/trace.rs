@@ -0,0 +1,452 @@
+//! Sample Tracing
+
+#![allow(dead_code)]
+use crate::core::geometry::*;
+use crate::core::pbrt::*;
+use crate::core::spectrum::*;
+use std::cell::RefCell;
+use std::fmt::Write;
+
+thread_local! {
+    /// Events recorded by the current thread while tracing is enabled.
+    static TRACE: RefCell<Option<Vec<TraceEvent>>> = const { RefCell::new(None) };
+}
+
+/// Something that happened while computing the radiance of a traced sample.
+#[derive(Clone)]
+pub enum TraceEvent {
+    /// The camera ray of the sample.
+    CameraRay {
+        /// The pixel.
+        pixel: Point2i,
+
+        /// Index of the sample in the pixel.
+        sample: usize,
+
+        /// Point on the film.
+        p_film: Point2f,
+
+        /// The ray.
+        ray: Ray,
+
+        /// Weight of the ray's contribution to the image.
+        weight: Float,
+    },
+
+    /// A ray hit a primitive.
+    Hit {
+        /// Number of bounces before the ray.
+        depth: usize,
+
+        /// Address of the primitive identifying it within the trace.
+        primitive: usize,
+
+        /// The hit point.
+        p: Point3f,
+
+        /// The surface normal.
+        n: Normal3f,
+
+        /// `true` if the surface has a BSDF; otherwise the ray passes through.
+        has_bsdf: bool,
+    },
+
+    /// A ray left the scene.
+    Miss {
+        /// Number of bounces before the ray.
+        depth: usize,
+    },
+
+    /// A light was sampled for direct lighting.
+    LightSample {
+        /// Number of bounces before the ray that hit the surface.
+        depth: usize,
+
+        /// Index of the light in the scene.
+        light: usize,
+
+        /// Direction to the light.
+        wi: Vector3f,
+
+        /// Incident radiance.
+        li: Spectrum,
+
+        /// Probability density of sampling `wi`.
+        pdf: Float,
+
+        /// BSDF value for `wi`.
+        f: Spectrum,
+
+        /// `true` if the light contributes, i.e. `f` isn't black and the
+        /// light isn't blocked.
+        unoccluded: bool,
+    },
+
+    /// The BSDF was sampled to continue the path.
+    BsdfSample {
+        /// Number of bounces before the ray that hit the surface.
+        depth: usize,
+
+        /// "reflection" or "transmission".
+        lobe: &'static str,
+
+        /// The sampled direction.
+        wi: Vector3f,
+
+        /// BSDF value for `wi`.
+        f: Spectrum,
+
+        /// Probability density of sampling `wi`.
+        pdf: Float,
+
+        /// Factor `f * |cos(theta)| / pdf` that radiance along `wi` is
+        /// multiplied with.
+        weight: Spectrum,
+    },
+
+    /// The radiance of the sample.
+    Radiance {
+        /// The radiance.
+        l: Spectrum,
+    },
+}
+
+impl TraceEvent {
+    /// Returns the event as a JSON object.
+    ///
+    /// * `throughput` - Product of the BSDF sample weights of the path up to
+    ///                  the event.
+    pub fn to_json(&self, throughput: &Spectrum) -> String {
+        let mut s = String::new();
+        match self {
+            Self::CameraRay {
+                pixel,
+                sample,
+                p_film,
+                ray,
+                weight,
+            } => write!(
+                s,
+                "{{\"event\":\"camera_ray\",\"pixel\":[{},{}],\"sample\":{},\"p_film\":[{},{}],\"o\":{},\"d\":{},\"weight\":{}}}",
+                pixel.x, pixel.y, sample, p_film.x, p_film.y, point_json(&ray.o), vector_json(&ray.d), weight
+            ),
+            Self::Hit {
+                depth,
+                primitive,
+                p,
+                n,
+                has_bsdf,
+            } => write!(
+                s,
+                "{{\"event\":\"hit\",\"depth\":{},\"primitive\":\"{:#x}\",\"p\":{},\"n\":[{},{},{}],\"bsdf\":{},\"throughput\":{}}}",
+                depth, primitive, point_json(p), n.x, n.y, n.z, has_bsdf, spectrum_json(throughput)
+            ),
+            Self::Miss { depth } => write!(
+                s,
+                "{{\"event\":\"miss\",\"depth\":{},\"throughput\":{}}}",
+                depth,
+                spectrum_json(throughput)
+            ),
+            Self::LightSample {
+                depth,
+                light,
+                wi,
+                li,
+                pdf,
+                f,
+                unoccluded,
+            } => write!(
+                s,
+                "{{\"event\":\"light_sample\",\"depth\":{},\"light\":{},\"wi\":{},\"li\":{},\"pdf\":{},\"f\":{},\"unoccluded\":{}}}",
+                depth, light, vector_json(wi), spectrum_json(li), pdf, spectrum_json(f), unoccluded
+            ),
+            Self::BsdfSample {
+                depth,
+                lobe,
+                wi,
+                f,
+                pdf,
+                weight,
+            } => write!(
+                s,
+                "{{\"event\":\"bsdf_sample\",\"depth\":{},\"lobe\":\"{}\",\"wi\":{},\"f\":{},\"pdf\":{},\"weight\":{}}}",
+                depth, lobe, vector_json(wi), spectrum_json(f), pdf, spectrum_json(weight)
+            ),
+            Self::Radiance { l } => write!(
+                s,
+                "{{\"event\":\"radiance\",\"l\":{}}}",
+                spectrum_json(l)
+            ),
+        }
+        .unwrap();
+        s
+    }
+}
+
+/// Starts recording the events of the current thread.
+pub fn start_trace() {
+    TRACE.with(|t| *t.borrow_mut() = Some(vec![]));
+}
+
+/// Stops recording and returns the events of the current thread.
+pub fn finish_trace() -> Vec<TraceEvent> {
+    TRACE.with(|t| t.borrow_mut().take()).unwrap_or_default()
+}
+
+/// Records an event if the current thread is tracing. The event is only
+/// created when it is recorded.
+///
+/// * `event` - Returns the event.
+#[inline]
+pub fn trace<F: FnOnce() -> TraceEvent>(event: F) {
+    TRACE.with(|t| {
+        if let Some(events) = t.borrow_mut().as_mut() {
+            events.push(event());
+        }
+    });
+}
+
+/// Records an event at the depth of the last hit if the current thread is
+/// tracing, for code that doesn't know the depth of the path. The event is
+/// only created when it is recorded.
+///
+/// * `event` - Returns the event for the depth.
+#[inline]
+pub fn trace_at_last_hit<F: FnOnce(usize) -> TraceEvent>(event: F) {
+    TRACE.with(|t| {
+        if let Some(events) = t.borrow_mut().as_mut() {
+            let depth = events
+                .iter()
+                .rev()
+                .find_map(|e| match e {
+                    TraceEvent::Hit { depth, .. } => Some(*depth),
+                    _ => None,
+                })
+                .unwrap_or(0);
+            events.push(event(depth));
+        }
+    });
+}
+
+/// Returns the events as JSON lines. The throughput of each hit and miss is
+/// the product of the weights of the BSDF samples that led to it.
+///
+/// * `events` - The events.
+pub fn trace_to_json(events: &[TraceEvent]) -> String {
+    // Throughput of the paths by depth.
+    let mut throughput = vec![Spectrum::new(1.0)];
+    let mut s = String::new();
+    for event in events {
+        let t = match event {
+            TraceEvent::Hit { depth, .. } | TraceEvent::Miss { depth } => throughput
+                .get(*depth)
+                .copied()
+                .unwrap_or(Spectrum::new(0.0)),
+            TraceEvent::BsdfSample { depth, weight, .. } => {
+                let t = throughput
+                    .get(*depth)
+                    .copied()
+                    .unwrap_or(Spectrum::new(0.0));
+                throughput.truncate(depth + 1);
+                throughput.push(t * *weight);
+                t
+            }
+            _ => Spectrum::new(1.0),
+        };
+        writeln!(s, "{}", event.to_json(&t)).unwrap();
+    }
+    s
+}
+
+/// Returns the path of the events as polylines in the Wavefront OBJ format.
+/// Each ray is a line from its origin to its hit point. Rays that leave the
+/// scene, and rays to sampled lights in the `light_samples` group, are drawn
+/// as long as the longest ray that hit something.
+///
+/// * `events` - The events.
+pub fn trace_to_obj(events: &[TraceEvent]) -> String {
+    let mut vertices: Vec<Point3f> = vec![];
+    let mut path_lines: Vec<(usize, usize)> = vec![];
+
+    // Vertex at the origin and direction of the rays by depth.
+    let mut origins: Vec<usize> = vec![];
+    let mut directions: Vec<Vector3f> = vec![];
+
+    // Rays without an end point; vertex of the origin and direction.
+    let mut misses: Vec<(usize, Vector3f)> = vec![];
+    let mut light_rays: Vec<(usize, Vector3f)> = vec![];
+
+    for event in events {
+        match event {
+            TraceEvent::CameraRay { ray, .. } => {
+                vertices.push(ray.o);
+                origins = vec![vertices.len() - 1];
+                directions = vec![ray.d];
+            }
+            TraceEvent::Hit { depth, p, .. } => {
+                if let Some(&origin) = origins.get(*depth) {
+                    vertices.push(*p);
+                    path_lines.push((origin, vertices.len() - 1));
+                    origins.truncate(depth + 1);
+                    origins.push(vertices.len() - 1);
+                }
+            }
+            TraceEvent::Miss { depth } => {
+                if let (Some(&origin), Some(&d)) = (origins.get(*depth), directions.get(*depth)) {
+                    misses.push((origin, d));
+                }
+            }
+            TraceEvent::LightSample { depth, wi, .. } => {
+                if let Some(&origin) = origins.get(depth + 1) {
+                    light_rays.push((origin, *wi));
+                }
+            }
+            TraceEvent::BsdfSample { depth, wi, .. } => {
+                directions.truncate(depth + 1);
+                directions.push(*wi);
+            }
+            TraceEvent::Radiance { .. } => {}
+        }
+    }
+
+    let length = path_lines
+        .iter()
+        .map(|&(a, b)| vertices[a].distance(vertices[b]))
+        .fold(0.0, max);
+    let length = if length > 0.0 { length } else { 1.0 };
+    let mut end_lines = |rays: &[(usize, Vector3f)]| -> Vec<(usize, usize)> {
+        rays.iter()
+            .map(|&(origin, d)| {
+                vertices.push(vertices[origin] + d.normalize() * length);
+                (origin, vertices.len() - 1)
+            })
+            .collect()
+    };
+    path_lines.extend(end_lines(&misses));
+    let light_lines = end_lines(&light_rays);
+
+    let mut s = String::new();
+    for v in vertices.iter() {
+        writeln!(s, "v {} {} {}", v.x, v.y, v.z).unwrap();
+    }
+    writeln!(s, "g path").unwrap();
+    for (a, b) in path_lines {
+        writeln!(s, "l {} {}", a + 1, b + 1).unwrap();
+    }
+    if !light_lines.is_empty() {
+        writeln!(s, "g light_samples").unwrap();
+        for (a, b) in light_lines {
+            writeln!(s, "l {} {}", a + 1, b + 1).unwrap();
+        }
+    }
+    s
+}
+
+/// Returns a point as a JSON array.
+///
+/// * `p` - The point.
+fn point_json(p: &Point3f) -> String {
+    format!("[{},{},{}]", p.x, p.y, p.z)
+}
+
+/// Returns a vector as a JSON array.
+///
+/// * `v` - The vector.
+fn vector_json(v: &Vector3f) -> String {
+    format!("[{},{},{}]", v.x, v.y, v.z)
+}
+
+/// Returns the RGB values of a spectrum as a JSON array.
+///
+/// * `s` - The spectrum.
+fn spectrum_json(s: &Spectrum) -> String {
+    let rgb = s.to_rgb();
+    format!("[{},{},{}]", rgb[0], rgb[1], rgb[2])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn throughput_follows_bsdf_samples() {
+        trace(|| TraceEvent::Miss { depth: 0 });
+        assert!(finish_trace().is_empty());
+
+        start_trace();
+        trace(|| TraceEvent::BsdfSample {
+            depth: 0,
+            lobe: "reflection",
+            wi: Vector3f::new(0.0, 0.0, 1.0),
+            f: Spectrum::new(1.0),
+            pdf: 1.0,
+            weight: Spectrum::new(0.5),
+        });
+        trace(|| TraceEvent::Miss { depth: 1 });
+        let events = finish_trace();
+        assert_eq!(events.len(), 2);
+
+        let json = trace_to_json(&events);
+        let lines: Vec<&str> = json.lines().collect();
+        assert!(lines[0].starts_with("{\"event\":\"bsdf_sample\""));
+        assert_eq!(
+            lines[1],
+            format!(
+                "{{\"event\":\"miss\",\"depth\":1,\"throughput\":{}}}",
+                spectrum_json(&Spectrum::new(0.5))
+            )
+        );
+    }
+
+    #[test]
+    fn obj_path_connects_hits_in_order() {
+        let hit = |depth: usize, z: Float| TraceEvent::Hit {
+            depth,
+            primitive: 0,
+            p: Point3f::new(0.0, 0.0, z),
+            n: Normal3f::new(0.0, 0.0, -1.0),
+            has_bsdf: true,
+        };
+        let events = vec![
+            TraceEvent::CameraRay {
+                pixel: Point2i::new(0, 0),
+                sample: 0,
+                p_film: Point2f::new(0.5, 0.5),
+                ray: Ray::new(
+                    Point3f::new(0.0, 0.0, 0.0),
+                    Vector3f::new(0.0, 0.0, 1.0),
+                    INFINITY,
+                    0.0,
+                    None,
+                ),
+                weight: 1.0,
+            },
+            hit(0, 2.0),
+            TraceEvent::BsdfSample {
+                depth: 0,
+                lobe: "reflection",
+                wi: Vector3f::new(0.0, 0.0, -1.0),
+                f: Spectrum::new(1.0),
+                pdf: 1.0,
+                weight: Spectrum::new(1.0),
+            },
+            hit(1, 1.0),
+            TraceEvent::BsdfSample {
+                depth: 1,
+                lobe: "reflection",
+                wi: Vector3f::new(1.0, 0.0, 0.0),
+                f: Spectrum::new(1.0),
+                pdf: 1.0,
+                weight: Spectrum::new(1.0),
+            },
+            TraceEvent::Miss { depth: 2 },
+        ];
+
+        let obj = trace_to_obj(&events);
+        let lines: Vec<&str> = obj.lines().collect();
+        assert_eq!(
+            lines,
+            vec!["v 0 0 0", "v 0 0 2", "v 0 0 1", "v 2 0 1", "g path", "l 1 2", "l 2 3", "l 3 4",]
+        );
+    }
+}
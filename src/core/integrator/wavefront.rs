@@ -0,0 +1,458 @@
+//! Wavefront
+
+use super::*;
+use crate::core::camera::*;
+use crate::core::cancel::*;
+use crate::core::film::*;
+use crate::core::geometry::*;
+use crate::core::medium::*;
+use crate::core::pbrt::*;
+use crate::core::profiler::*;
+use crate::core::rng::*;
+use crate::core::sampler::*;
+use crate::core::scene::*;
+use crate::core::spectrum::*;
+use std::sync::Arc;
+
+stat_counter!(
+    "Integrator/Wavefront camera rays traced",
+    N_WAVEFRONT_CAMERA_RAYS
+);
+
+/// Rays waiting for the intersection and shading stages of a wavefront.
+/// Components are stored as a structure of arrays so each stage streams
+/// through only the data it needs.
+#[derive(Default)]
+pub struct RayQueue {
+    /// x-coordinates of the origins.
+    pub ox: Vec<Float>,
+
+    /// y-coordinates of the origins.
+    pub oy: Vec<Float>,
+
+    /// z-coordinates of the origins.
+    pub oz: Vec<Float>,
+
+    /// x-components of the directions.
+    pub dx: Vec<Float>,
+
+    /// y-components of the directions.
+    pub dy: Vec<Float>,
+
+    /// z-components of the directions.
+    pub dz: Vec<Float>,
+
+    /// Maximum extents of the rays.
+    pub t_max: Vec<Float>,
+
+    /// Time values.
+    pub time: Vec<Float>,
+
+    /// Auxilliary rays offset by one sample in x and y direction.
+    pub differentials: Vec<Option<RayDifferential>>,
+
+    /// Media containing the origins.
+    pub medium: Vec<Option<ArcMedium>>,
+
+    /// Indices of the paths the rays belong to.
+    pub path: Vec<usize>,
+
+    /// Path throughputs; the weights applied to radiance found along the rays.
+    pub beta: Vec<Spectrum>,
+
+    /// Recursion depths.
+    pub depth: Vec<usize>,
+}
+
+impl RayQueue {
+    /// Returns the number of rays in the queue.
+    pub fn len(&self) -> usize {
+        self.path.len()
+    }
+
+    /// Returns `true` if the queue has no rays.
+    pub fn is_empty(&self) -> bool {
+        self.path.is_empty()
+    }
+
+    /// Removes all rays from the queue.
+    pub fn clear(&mut self) {
+        self.ox.clear();
+        self.oy.clear();
+        self.oz.clear();
+        self.dx.clear();
+        self.dy.clear();
+        self.dz.clear();
+        self.t_max.clear();
+        self.time.clear();
+        self.differentials.clear();
+        self.medium.clear();
+        self.path.clear();
+        self.beta.clear();
+        self.depth.clear();
+    }
+
+    /// Adds a ray to the queue.
+    ///
+    /// * `ray`   - The ray.
+    /// * `path`  - Index of the path the ray belongs to.
+    /// * `beta`  - Path throughput.
+    /// * `depth` - Recursion depth.
+    pub fn push(&mut self, ray: &Ray, path: usize, beta: Spectrum, depth: usize) {
+        self.ox.push(ray.o.x);
+        self.oy.push(ray.o.y);
+        self.oz.push(ray.o.z);
+        self.dx.push(ray.d.x);
+        self.dy.push(ray.d.y);
+        self.dz.push(ray.d.z);
+        self.t_max.push(ray.t_max);
+        self.time.push(ray.time);
+        self.differentials.push(ray.differentials);
+        self.medium.push(ray.medium.clone());
+        self.path.push(path);
+        self.beta.push(beta);
+        self.depth.push(depth);
+    }
+
+    /// Returns the ray at a given index.
+    ///
+    /// * `i` - The index.
+    pub fn ray(&self, i: usize) -> Ray {
+        Ray {
+            o: Point3f::new(self.ox[i], self.oy[i], self.oz[i]),
+            d: Vector3f::new(self.dx[i], self.dy[i], self.dz[i]),
+            t_max: self.t_max[i],
+            time: self.time[i],
+            differentials: self.differentials[i],
+            medium: self.medium[i].clone(),
+        }
+    }
+}
+
+/// Shadow rays waiting for the occlusion stage of a wavefront. Components are
+/// stored as a structure of arrays.
+#[derive(Default)]
+pub struct ShadowRayQueue {
+    /// x-coordinates of the origins.
+    pub ox: Vec<Float>,
+
+    /// y-coordinates of the origins.
+    pub oy: Vec<Float>,
+
+    /// z-coordinates of the origins.
+    pub oz: Vec<Float>,
+
+    /// x-components of the directions.
+    pub dx: Vec<Float>,
+
+    /// y-components of the directions.
+    pub dy: Vec<Float>,
+
+    /// z-components of the directions.
+    pub dz: Vec<Float>,
+
+    /// Maximum extents of the rays.
+    pub t_max: Vec<Float>,
+
+    /// Time values.
+    pub time: Vec<Float>,
+
+    /// Indices of the paths the rays belong to.
+    pub path: Vec<usize>,
+
+    /// Radiance added to the paths if the rays are unoccluded.
+    pub l: Vec<Spectrum>,
+}
+
+impl ShadowRayQueue {
+    /// Returns the number of rays in the queue.
+    pub fn len(&self) -> usize {
+        self.path.len()
+    }
+
+    /// Returns `true` if the queue has no rays.
+    pub fn is_empty(&self) -> bool {
+        self.path.is_empty()
+    }
+
+    /// Removes all rays from the queue.
+    pub fn clear(&mut self) {
+        self.ox.clear();
+        self.oy.clear();
+        self.oz.clear();
+        self.dx.clear();
+        self.dy.clear();
+        self.dz.clear();
+        self.t_max.clear();
+        self.time.clear();
+        self.path.clear();
+        self.l.clear();
+    }
+
+    /// Adds a shadow ray to the queue.
+    ///
+    /// * `ray`  - The shadow ray.
+    /// * `path` - Index of the path the ray belongs to.
+    /// * `l`    - Radiance added to the path if the ray is unoccluded.
+    pub fn push(&mut self, ray: &Ray, path: usize, l: Spectrum) {
+        self.ox.push(ray.o.x);
+        self.oy.push(ray.o.y);
+        self.oz.push(ray.o.z);
+        self.dx.push(ray.d.x);
+        self.dy.push(ray.d.y);
+        self.dz.push(ray.d.z);
+        self.t_max.push(ray.t_max);
+        self.time.push(ray.time);
+        self.path.push(path);
+        self.l.push(l);
+    }
+
+    /// Returns the shadow ray at a given index.
+    ///
+    /// * `i` - The index.
+    pub fn ray(&self, i: usize) -> Ray {
+        Ray::new(
+            Point3f::new(self.ox[i], self.oy[i], self.oz[i]),
+            Vector3f::new(self.dx[i], self.dy[i], self.dz[i]),
+            self.t_max[i],
+            self.time[i],
+            None,
+        )
+    }
+}
+
+/// Shading stage of a wavefront renderer. Instead of recursively tracing
+/// rays, shading queues shadow rays and rays that continue paths so that
+/// they are traced together with those of other paths.
+pub trait WavefrontShader: Send + Sync {
+    /// Shades the intersection of a ray and returns the radiance that does
+    /// not need a shadow ray, weighted by `beta`.
+    ///
+    /// * `ray`         - The ray.
+    /// * `isect`       - The surface interaction.
+    /// * `beta`        - Path throughput.
+    /// * `depth`       - Recursion depth.
+    /// * `path`        - Index of the path the ray belongs to.
+    /// * `scene`       - The scene.
+    /// * `rng`         - Random number generator of the path.
+    /// * `shadow_rays` - Queue for shadow rays.
+    /// * `next_rays`   - Queue for rays that continue the path.
+    #[allow(clippy::too_many_arguments)]
+    fn shade(
+        &self,
+        ray: &Ray,
+        isect: &mut SurfaceInteraction,
+        beta: Spectrum,
+        depth: usize,
+        path: usize,
+        scene: &Scene,
+        rng: &mut RNG,
+        shadow_rays: &mut ShadowRayQueue,
+        next_rays: &mut RayQueue,
+    ) -> Spectrum;
+
+    /// Returns the radiance along a ray that left the scene. The default
+    /// implementation returns the radiance of infinite lights.
+    ///
+    /// * `ray`   - The ray.
+    /// * `scene` - The scene.
+    fn escaped(&self, ray: &Ray, scene: &Scene) -> Spectrum {
+        let mut l = Spectrum::new(0.0);
+        for light in scene.lights.iter() {
+            l += light.le(ray);
+        }
+        l
+    }
+}
+
+/// Radiance estimate of a camera sample traced with a wavefront.
+struct WavefrontPath {
+    /// The pixel.
+    pixel: Point2i,
+
+    /// Sample number in the pixel.
+    sample_number: usize,
+
+    /// Point on the film.
+    p_film: Point2f,
+
+    /// Weight of the camera ray.
+    ray_weight: Float,
+
+    /// Radiance.
+    l: Spectrum,
+
+    /// Random number generator for the samples used after the camera ray.
+    rng: RNG,
+}
+
+/// Renders the samples of a tile by tracing all of its paths together one
+/// bounce at a time. Each bounce runs the intersection, shading and shadow
+/// stages over queues of rays. Camera samples are generated in the same order
+/// as the tile loop; later samples come from a random number generator per
+/// path seeded by `seed`.
+///
+/// * `shader`              - The shading stage.
+/// * `camera`              - The camera.
+/// * `pixel_bounds`        - Pixel bounds for the image.
+/// * `max_component_value` - Maximum value of a component of the radiance
+///                           of a sample.
+/// * `tile_bounds`         - Sample bounds of the tile.
+/// * `scene`               - The scene.
+/// * `sampler`             - Sampler for the tile.
+/// * `film_tile`           - Tile that receives the samples.
+/// * `seed`                - Seed for the random number generators of the
+///                           paths.
+/// * `cancel`              - Token used to stop rendering early.
+#[allow(clippy::too_many_arguments)]
+pub fn render_wavefront_tile(
+    shader: &dyn WavefrontShader,
+    camera: &ArcCamera,
+    pixel_bounds: &Bounds2i,
+    max_component_value: Float,
+    tile_bounds: Bounds2i,
+    scene: &Scene,
+    sampler: &mut ArcSampler,
+    film_tile: &mut FilmTile,
+    seed: u64,
+    cancel: &CancellationToken,
+) {
+    let mut paths: Vec<WavefrontPath> = vec![];
+    let mut rays = RayQueue::default();
+    let mut next_rays = RayQueue::default();
+    let mut shadow_rays = ShadowRayQueue::default();
+
+    // Generate stage: camera rays for all samples of the tile.
+    let samples_per_pixel = Arc::get_mut(sampler).unwrap().get_data().samples_per_pixel;
+    for pixel in tile_bounds {
+        if cancel.is_cancelled() {
+            break;
+        }
+
+        {
+            let _p = ProfilePhase::new(Prof::StartPixel);
+            Arc::get_mut(sampler).unwrap().start_pixel(&pixel);
+        }
+        if !pixel_bounds.contains_exclusive(&pixel) {
+            continue;
+        }
+
+        loop {
+            let sampler = Arc::get_mut(sampler).unwrap();
+            let camera_sample = sampler.get_camera_sample(&pixel);
+            let (mut ray, ray_weight) = {
+                let _p = ProfilePhase::new(Prof::GenerateCameraRay);
+                camera.generate_ray_differential(&camera_sample)
+            };
+            ray.scale_differentials(1.0 / (samples_per_pixel as Float).sqrt());
+            N_WAVEFRONT_CAMERA_RAYS.inc();
+
+            let path = paths.len();
+            if ray_weight > 0.0 {
+                rays.push(&ray, path, Spectrum::new(1.0), 0);
+            }
+            paths.push(WavefrontPath {
+                pixel,
+                sample_number: sampler.get_data().current_sample_number(),
+                p_film: camera_sample.p_film,
+                ray_weight,
+                l: Spectrum::new(0.0),
+                rng: RNG::new((seed << 32) ^ path as u64),
+            });
+
+            if !sampler.start_next_sample() {
+                break;
+            }
+        }
+    }
+
+    let mut hits: Vec<Option<SurfaceInteraction>> = vec![];
+    while !rays.is_empty() && !cancel.is_cancelled() {
+        // Intersect stage.
+        hits.clear();
+        for i in 0..rays.len() {
+            let mut ray = rays.ray(i);
+            hits.push(scene.intersect(&mut ray));
+            rays.t_max[i] = ray.t_max;
+        }
+
+        // Shade stage.
+        {
+            let _p = ProfilePhase::new(Prof::SamplerIntegratorLi);
+            for (i, hit) in hits.iter_mut().enumerate() {
+                let ray = rays.ray(i);
+                let path = &mut paths[rays.path[i]];
+                let l = match hit {
+                    Some(isect) => shader.shade(
+                        &ray,
+                        isect,
+                        rays.beta[i],
+                        rays.depth[i],
+                        rays.path[i],
+                        scene,
+                        &mut path.rng,
+                        &mut shadow_rays,
+                        &mut next_rays,
+                    ),
+                    None => rays.beta[i] * shader.escaped(&ray, scene),
+                };
+                path.l += l;
+            }
+        }
+
+        // Shadow stage.
+        for i in 0..shadow_rays.len() {
+            if !scene.intersect_p(&shadow_rays.ray(i)) {
+                paths[shadow_rays.path[i]].l += shadow_rays.l[i];
+            }
+        }
+        shadow_rays.clear();
+
+        std::mem::swap(&mut rays, &mut next_rays);
+        next_rays.clear();
+    }
+
+    // Add the samples to the tile in the order they were generated.
+    let _p = ProfilePhase::new(Prof::AddFilmSample);
+    for path in paths {
+        let l = clamped_radiance(
+            checked_radiance(path.l, &path.pixel, path.sample_number),
+            max_component_value,
+        );
+        film_tile.add_sample(path.p_film, l, path.ray_weight);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ray_queue_round_trips_rays() {
+        let mut queue = RayQueue::default();
+        let r0 = Ray::new(
+            Point3f::new(1.0, 2.0, 3.0),
+            Vector3f::new(0.0, 1.0, 0.0),
+            10.0,
+            0.5,
+            None,
+        );
+        let r1 = Ray::new(
+            Point3f::new(-1.0, 0.0, 4.0),
+            Vector3f::new(0.0, 0.0, -1.0),
+            INFINITY,
+            0.25,
+            None,
+        );
+        queue.push(&r0, 3, Spectrum::new(0.5), 1);
+        queue.push(&r1, 7, Spectrum::new(1.0), 2);
+        assert_eq!(queue.len(), 2);
+
+        let r = queue.ray(1);
+        assert_eq!((r.o, r.d, r.t_max, r.time), (r1.o, r1.d, r1.t_max, r1.time));
+        assert_eq!((queue.path[0], queue.depth[0]), (3, 1));
+
+        queue.clear();
+        assert!(queue.is_empty());
+    }
+}
@@ -0,0 +1,294 @@
+//! Light Distribution
+
+#![allow(dead_code)]
+use super::*;
+use crate::core::low_discrepency::*;
+use crate::core::sampling::*;
+use std::sync::OnceLock;
+
+/// Chooses which light to sample for direct lighting at a point.
+pub trait LightDistribution {
+    /// Returns the distribution for choosing a light to sample at a point.
+    ///
+    /// * `p` - The point.
+    fn lookup(&self, p: &Point3f) -> &AliasTable;
+}
+
+/// Atomic reference counted `LightDistribution`.
+pub type ArcLightDistribution = Arc<dyn LightDistribution + Send + Sync>;
+
+/// Returns the light distribution for a light sampling strategy; "uniform",
+/// "power" or "spatial". Unknown strategies fall back to "spatial".
+///
+/// * `name`  - The strategy.
+/// * `scene` - The scene.
+pub fn create_light_sample_distribution(name: &str, scene: &Scene) -> ArcLightDistribution {
+    if name == "uniform" || scene.lights.len() == 1 {
+        Arc::new(UniformLightDistribution::new(scene))
+    } else if name == "power" {
+        Arc::new(PowerLightDistribution::new(scene))
+    } else if name == "spatial" {
+        Arc::new(SpatialLightDistribution::new(scene, 64))
+    } else {
+        warn!(
+            "Light sample distribution type '{}' unknown. Using 'spatial'.",
+            name
+        );
+        Arc::new(SpatialLightDistribution::new(scene, 64))
+    }
+}
+
+/// Returns the light distribution for a light sampling strategy of the scene;
+/// creating it the first time it is needed and keeping it with the data
+/// precomputed for the scene.
+///
+/// * `name`  - The strategy.
+/// * `scene` - The scene.
+pub fn scene_light_sample_distribution(name: &str, scene: &Scene) -> ArcLightDistribution {
+    let distrib = scene
+        .preprocessed
+        .get_or_insert_with(name, || create_light_sample_distribution(name, scene));
+    ArcLightDistribution::clone(&distrib)
+}
+
+/// Chooses all lights with equal probability.
+pub struct UniformLightDistribution {
+    /// The distribution.
+    distrib: AliasTable,
+}
+
+impl UniformLightDistribution {
+    /// Create a new `UniformLightDistribution`.
+    ///
+    /// * `scene` - The scene.
+    pub fn new(scene: &Scene) -> Self {
+        Self {
+            distrib: AliasTable::new(&vec![1.0; scene.lights.len()]),
+        }
+    }
+}
+
+impl LightDistribution for UniformLightDistribution {
+    /// Returns the distribution for choosing a light to sample at a point.
+    ///
+    /// * `_p` - The point.
+    fn lookup(&self, _p: &Point3f) -> &AliasTable {
+        &self.distrib
+    }
+}
+
+/// Chooses lights in proportion to their power.
+pub struct PowerLightDistribution {
+    /// The distribution.
+    distrib: AliasTable,
+}
+
+impl PowerLightDistribution {
+    /// Create a new `PowerLightDistribution`.
+    ///
+    /// * `scene` - The scene.
+    pub fn new(scene: &Scene) -> Self {
+        let light_power: Vec<Float> = scene.lights.iter().map(|light| light.power().y()).collect();
+        Self {
+            distrib: AliasTable::new(&light_power),
+        }
+    }
+}
+
+impl LightDistribution for PowerLightDistribution {
+    /// Returns the distribution for choosing a light to sample at a point.
+    ///
+    /// * `_p` - The point.
+    fn lookup(&self, _p: &Point3f) -> &AliasTable {
+        &self.distrib
+    }
+}
+
+/// Chooses lights in proportion to an estimate of their contribution to the
+/// points in the voxel of a grid over the scene bounds that contains the
+/// point being lit. The distribution of a voxel is computed the first time
+/// it is needed.
+pub struct SpatialLightDistribution {
+    /// The lights.
+    lights: Vec<ArcLight>,
+
+    /// The scene bounds.
+    bounds: Bounds3f,
+
+    /// Number of voxels along each axis.
+    n_voxels: [usize; 3],
+
+    /// Distribution of each voxel.
+    voxels: Vec<OnceLock<AliasTable>>,
+}
+
+impl SpatialLightDistribution {
+    /// Create a new `SpatialLightDistribution`.
+    ///
+    /// * `scene`      - The scene.
+    /// * `max_voxels` - Number of voxels along the longest axis of the scene
+    ///                  bounds.
+    pub fn new(scene: &Scene, max_voxels: usize) -> Self {
+        // Compute the number of voxels so that they are roughly cube shaped.
+        let bounds = scene.world_bound;
+        let diag = bounds.diagonal();
+        let bmax = diag.max_component();
+        let n = |d: Float| {
+            if bmax > 0.0 {
+                max(1, (d / bmax * max_voxels as Float).round() as usize)
+            } else {
+                1
+            }
+        };
+        let n_voxels = [n(diag.x), n(diag.y), n(diag.z)];
+        let count = n_voxels[0] * n_voxels[1] * n_voxels[2];
+        debug!(
+            "SpatialLightDistribution: scene bounds {:?}, voxel res ({}, {}, {})",
+            bounds, n_voxels[0], n_voxels[1], n_voxels[2]
+        );
+
+        Self {
+            lights: scene.lights.clone(),
+            bounds,
+            n_voxels,
+            voxels: (0..count).map(|_| OnceLock::new()).collect(),
+        }
+    }
+
+    /// Returns the distribution for the points in a voxel by estimating the
+    /// contribution of each light at sample points in it.
+    ///
+    /// * `pi` - Coordinates of the voxel.
+    fn compute_distribution(&self, pi: [usize; 3]) -> AliasTable {
+        // Compute the world-space bounds of the voxel.
+        let p0 = Point3f::new(
+            pi[0] as Float / self.n_voxels[0] as Float,
+            pi[1] as Float / self.n_voxels[1] as Float,
+            pi[2] as Float / self.n_voxels[2] as Float,
+        );
+        let p1 = Point3f::new(
+            (pi[0] + 1) as Float / self.n_voxels[0] as Float,
+            (pi[1] + 1) as Float / self.n_voxels[1] as Float,
+            (pi[2] + 1) as Float / self.n_voxels[2] as Float,
+        );
+        let voxel_bounds = Bounds3f::new(self.bounds.lerp(&p0), self.bounds.lerp(&p1));
+
+        // Estimate the contribution of each light at points spread over the
+        // voxel with the Halton sequence. Points are treated as if they
+        // scatter light in all directions and shadows are ignored.
+        const N_SAMPLES: u64 = 128;
+        let mut light_contrib = vec![0.0; self.lights.len()];
+        for i in 0..N_SAMPLES {
+            let po = voxel_bounds.lerp(&Point3f::new(
+                radical_inverse(0, i),
+                radical_inverse(1, i),
+                radical_inverse(2, i),
+            ));
+            let hit = Hit::new(
+                po,
+                0.0,
+                Vector3f::default(),
+                Vector3f::new(1.0, 0.0, 0.0),
+                Normal3f::default(),
+                None,
+            );
+            let u = Point2f::new(radical_inverse(3, i), radical_inverse(4, i));
+            for (j, light) in self.lights.iter().enumerate() {
+                let Li { pdf, value, .. } = light.sample_li(&hit, &u);
+                if pdf > 0.0 {
+                    light_contrib[j] += value.y() / pdf;
+                }
+            }
+        }
+
+        // Give every light a small probability so that none is left out
+        // where the estimate missed its contribution.
+        let sum_contrib: Float = light_contrib.iter().sum();
+        let avg_contrib = sum_contrib / (N_SAMPLES as usize * light_contrib.len()) as Float;
+        let min_contrib = if avg_contrib > 0.0 {
+            0.001 * avg_contrib
+        } else {
+            1.0
+        };
+        for c in light_contrib.iter_mut() {
+            *c = max(*c, min_contrib);
+        }
+        AliasTable::new(&light_contrib)
+    }
+}
+
+impl LightDistribution for SpatialLightDistribution {
+    /// Returns the distribution for choosing a light to sample at a point.
+    ///
+    /// * `p` - The point.
+    fn lookup(&self, p: &Point3f) -> &AliasTable {
+        // Compute integer voxel coordinates for the given point.
+        let offset = self.bounds.offset(p);
+        let voxel =
+            |o: Float, n: usize| clamp((o * n as Float) as isize, 0, n as isize - 1) as usize;
+        let pi = [
+            voxel(offset.x, self.n_voxels[0]),
+            voxel(offset.y, self.n_voxels[1]),
+            voxel(offset.z, self.n_voxels[2]),
+        ];
+
+        let index = (pi[2] * self.n_voxels[1] + pi[1]) * self.n_voxels[0] + pi[0];
+        self.voxels[index].get_or_init(|| self.compute_distribution(pi))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::accelerators::*;
+    use crate::core::material::*;
+    use crate::core::medium::*;
+    use crate::core::paramset::*;
+    use crate::core::primitive::*;
+    use crate::core::primitives::*;
+    use crate::lights::*;
+    use crate::materials::*;
+    use crate::shapes::*;
+
+    #[test]
+    fn spatial_distribution_favours_nearby_lights() {
+        // A sphere spanning the lights so the scene bounds contain them.
+        let matte: ArcMaterial = Arc::new(MatteMaterial::from(&TextureParams::default()));
+        let o2w = Arc::new(Transform::default());
+        let w2o = Arc::new(o2w.inverse());
+        let shape = Arc::new(Sphere::new(o2w, w2o, false, 10.0, -10.0, 10.0, 360.0));
+        let primitives: Vec<ArcPrimitive> = vec![Arc::new(GeometricPrimitive::new(
+            shape,
+            Some(matte),
+            None,
+            MediumInterface::vacuum(),
+        ))];
+        let bvh = BVHAccel::from((&ParamSet::new(), &primitives));
+
+        let light = |x: Float| -> ArcLight {
+            Arc::new(PointLight::new(
+                Arc::new(Transform::translate(&Vector3f::new(x, 0.0, 0.0))),
+                MediumInterface::vacuum(),
+                Spectrum::new(1.0),
+            ))
+        };
+        let scene = Scene::new(Arc::new(bvh), vec![light(-9.0), light(9.0)]);
+
+        let spatial = create_light_sample_distribution("spatial", &scene);
+        let left = spatial.lookup(&Point3f::new(-8.0, 0.0, 0.0));
+        assert!(left.pmf(0) > 0.9 && left.pmf(1) > 0.0);
+        let right = spatial.lookup(&Point3f::new(8.0, 0.0, 0.0));
+        assert!(right.pmf(1) > 0.9);
+
+        // The other strategies don't depend on the point.
+        for name in ["uniform", "power"].iter() {
+            let distrib = create_light_sample_distribution(name, &scene);
+            assert_eq!(distrib.lookup(&Point3f::new(-8.0, 0.0, 0.0)).pmf(0), 0.5);
+        }
+
+        // Distributions kept with the scene are created once.
+        let a = scene_light_sample_distribution("power", &scene);
+        let b = scene_light_sample_distribution("power", &scene);
+        assert!(Arc::ptr_eq(&a, &b));
+    }
+}
@@ -7,6 +7,7 @@ use crate::core::scene::*;
 use crate::core::spectrum::*;
 use std::sync::Arc;
 
+mod light_distribution;
 mod light_type;
 mod visibility_tester;
 
@@ -140,8 +141,8 @@ pub trait Light {
     /// Returns emitted radiance due to that light along a ray that escapes the
     /// scene bounds.
     ///
-    /// * `r` - The ray differentials.
-    fn le(&self, _r: &RayDifferential) -> Spectrum {
+    /// * `ray` - The ray.
+    fn le(&self, _ray: &Ray) -> Spectrum {
         Spectrum::new(0.0)
     }
 
@@ -187,5 +188,6 @@ pub trait AreaLight: Light {
 pub type ArcAreaLight = Arc<dyn AreaLight + Send + Sync>;
 
 // Re-export
+pub use light_distribution::*;
 pub use light_type::*;
 pub use visibility_tester::*;
@@ -32,44 +32,179 @@ impl VisibilityTester {
     ///
     /// * `scene` - The scene.
     pub fn unoccluded(&self, scene: Arc<Scene>) -> bool {
-        scene.intersect_p(&self.p0.spawn_ray_to(&self.p1))
+        !scene.intersect_p(&self.p0.spawn_ray_to(&self.p1))
     }
 
     /// Computes the beam transmittance, the fraction of radiance transmitted
     /// along the segment between the two points. It accounts for both attenuation
     /// in participating media as well as any surfaces that block the ray completely.
+    /// Surfaces without a material only mark medium boundaries and let the ray
+    /// through.
     ///
     /// * `scene`   - The scene.
     /// * `sampler` - The sampler.
-    pub fn tr(&self, scene: Arc<Scene>, sampler: ArcSampler) -> Spectrum {
+    pub fn tr(&self, scene: Arc<Scene>, sampler: &mut ArcSampler) -> Spectrum {
         let mut ray = self.p0.spawn_ray_to(&self.p1);
         let mut tr = Spectrum::new(1.0);
 
         loop {
-            if let Some(isect) = scene.intersect(&mut ray) {
-                // Handle opaque surface along ray's path.
-                if let Some(_material) = isect.primitive.map(|p| p.get_material()) {
+            let hit_surface = scene.intersect(&mut ray);
+
+            // Handle opaque surface along ray's path.
+            if let Some(isect) = hit_surface.as_ref() {
+                if isect.primitive.and_then(|p| p.get_material()).is_some() {
                     return Spectrum::new(0.0);
                 }
+            }
 
-                // Update transmittance for current ray segment.
-                let medium = ray.medium.clone();
-                if let Some(tr2) = medium.map(|medium| medium.tr(&ray, sampler.clone())) {
-                    tr *= tr2;
-                }
+            // Update transmittance for current ray segment.
+            if let Some(medium) = ray.medium.as_ref() {
+                tr *= medium.tr(&ray, sampler);
+            }
 
-                // Generate next ray segment or return final transmittance.
-                ray = isect.hit.spawn_ray_to(&self.p1);
-            } else {
-                // Update transmittance for current ray segment.
-                let medium = ray.medium.clone();
-                if let Some(tr2) = medium.map(|medium| medium.tr(&ray, sampler.clone())) {
-                    tr *= tr2;
-                }
-                break;
+            // Generate next ray segment or return final transmittance.
+            match hit_surface {
+                Some(isect) => ray = isect.hit.spawn_ray_to(&self.p1),
+                None => break,
             }
         }
 
         tr
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::accelerators::*;
+    use crate::core::material::*;
+    use crate::core::medium::*;
+    use crate::core::paramset::*;
+    use crate::core::pbrt::*;
+    use crate::core::primitive::*;
+    use crate::core::primitives::*;
+    use crate::materials::*;
+    use crate::samplers::*;
+    use crate::shapes::*;
+
+    /// A medium that transmits half the radiance of every ray segment.
+    struct HalfMedium;
+
+    impl Medium for HalfMedium {
+        fn tr(&self, _ray: &Ray, sampler: &mut ArcSampler) -> Spectrum {
+            // Media draw samples for ratio tracking, so this must work.
+            Arc::get_mut(sampler).unwrap().get_1d();
+            Spectrum::new(0.5)
+        }
+
+        fn sample(
+            &self,
+            _ray: &Ray,
+            _sampler: &mut ArcSampler,
+        ) -> (Spectrum, Option<MediumInteraction>) {
+            (Spectrum::new(1.0), None)
+        }
+    }
+
+    fn sphere(z: Float, radius: Float) -> ArcShape {
+        let o2w = Arc::new(Transform::translate(&Vector3f::new(0.0, 0.0, z)));
+        let w2o = Arc::new(o2w.inverse());
+        Arc::new(Sphere::new(o2w, w2o, false, radius, -radius, radius, 360.0))
+    }
+
+    fn transmittance(primitives: Vec<ArcPrimitive>) -> Spectrum {
+        let bvh = BVHAccel::from((&ParamSet::new(), &primitives));
+        let scene = Arc::new(Scene::new(Arc::new(bvh), vec![]));
+        let p0 = Hit::new(
+            Point3f::new(0.0, 0.0, -5.0),
+            0.0,
+            Vector3f::zero(),
+            Vector3f::zero(),
+            Normal3f::zero(),
+            None,
+        );
+        let vis = VisibilityTester::new(p0, Point3f::new(0.0, 0.0, 5.0));
+        let mut sampler: ArcSampler = Arc::new(RandomSampler::new(1, Some(0)));
+        vis.tr(scene, &mut sampler)
+    }
+
+    #[test]
+    fn tr_attenuates_through_media_and_stops_at_surfaces() {
+        // A surface without material bounding a medium.
+        let medium: ArcMedium = Arc::new(HalfMedium);
+        let boundary: ArcPrimitive = Arc::new(GeometricPrimitive {
+            shape: sphere(0.0, 1.0),
+            material: None,
+            area_light: None,
+            medium_interface: MediumInterface::new(Some(medium), None),
+            holdout: false,
+        });
+        assert_eq!(
+            transmittance(vec![boundary.clone()]).samples(),
+            Spectrum::new(0.5).samples()
+        );
+
+        let matte: ArcMaterial = Arc::new(MatteMaterial::from(&TextureParams::default()));
+        let wall: ArcPrimitive = Arc::new(GeometricPrimitive::new(
+            sphere(3.0, 0.5),
+            Some(matte),
+            None,
+            MediumInterface::vacuum(),
+        ));
+        assert!(transmittance(vec![boundary, wall]).is_black());
+    }
+
+    #[test]
+    fn surfaces_without_material_are_passed_through() {
+        let scene = |primitives: Vec<ArcPrimitive>| {
+            let bvh = BVHAccel::from((&ParamSet::new(), &primitives));
+            Arc::new(Scene::new(Arc::new(bvh), vec![]))
+        };
+        let ray = || {
+            Ray::new(
+                Point3f::new(0.0, 0.0, -5.0),
+                Vector3f::new(0.0, 0.0, 1.0),
+                INFINITY,
+                0.0,
+                None,
+            )
+        };
+
+        // Neither camera nor shadow rays see a surface without material that
+        // doesn't bound a medium.
+        let invisible: ArcPrimitive = Arc::new(GeometricPrimitive::new(
+            sphere(0.0, 1.0),
+            None,
+            None,
+            MediumInterface::vacuum(),
+        ));
+        let matte: ArcMaterial = Arc::new(MatteMaterial::from(&TextureParams::default()));
+        let wall: ArcPrimitive = Arc::new(GeometricPrimitive::new(
+            sphere(3.0, 0.5),
+            Some(matte),
+            None,
+            MediumInterface::vacuum(),
+        ));
+        let s = scene(vec![invisible.clone(), wall]);
+        let isect = s.intersect(&mut ray()).unwrap();
+        assert!((isect.hit.p.z - 2.5).abs() < 0.001);
+        assert!(s.intersect_p(&ray()));
+        assert!(!scene(vec![invisible]).intersect_p(&ray()));
+
+        // Medium boundaries are returned so that the medium of the ray can be
+        // tracked, but they don't block shadow rays.
+        let medium: ArcMedium = Arc::new(HalfMedium);
+        let boundary: ArcPrimitive = Arc::new(GeometricPrimitive::new(
+            sphere(0.0, 1.0),
+            None,
+            None,
+            MediumInterface::new(Some(medium), None),
+        ));
+        let s = scene(vec![boundary]);
+        let isect = s.intersect(&mut ray()).unwrap();
+        assert!((isect.hit.p.z + 1.0).abs() < 0.001);
+        assert!(!s.intersect_p(&ray()));
+        let vis = VisibilityTester::new(isect.hit.clone(), Point3f::new(0.0, 0.0, 5.0));
+        assert!(vis.unoccluded(s));
+    }
+}
@@ -0,0 +1,201 @@
+//! Logging
+
+#![allow(dead_code)]
+use crate::core::report::json_escape;
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use std::fmt;
+use std::io::Write;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+/// Target of messages about parsing scene descriptions.
+pub const PARSER: &str = "parser";
+
+/// Target of messages about building and traversing BVHs.
+pub const BVH: &str = "bvh";
+
+/// Target of messages about rendering by integrators.
+pub const INTEGRATOR: &str = "integrator";
+
+/// Target of messages about the film and writing images.
+pub const FILM: &str = "film";
+
+/// Maximum number of warnings and errors kept for the next report.
+const MAX_LOG_MESSAGES: usize = 1000;
+
+/// Number of times a rate limited message is logged before it is suppressed.
+const MAX_REPEATED_MESSAGES: usize = 10;
+
+lazy_static! {
+    /// Warnings and errors logged since they were last taken.
+    static ref LOG_MESSAGES: Mutex<Vec<String>> = Mutex::new(vec![]);
+}
+
+/// Logs a message at most a few times from the call site; later messages are
+/// suppressed. It is meant for messages about individual pixels or samples
+/// that would otherwise flood the log.
+///
+/// ```ignore
+/// log_limited!(Level::Error, target: INTEGRATOR, "NaN radiance for pixel {}", p);
+/// ```
+#[macro_export]
+macro_rules! log_limited {
+    ($level: expr, target: $target: expr, $($arg: tt)+) => {{
+        static COUNT: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+        $crate::core::logging::log_limited(&COUNT, $target, $level, format_args!($($arg)+));
+    }};
+}
+
+/// Format of log messages.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum LogFormat {
+    /// Human readable text.
+    Text,
+
+    /// One JSON object per line with the time, level, target and message.
+    Json,
+}
+
+impl LogFormat {
+    /// Returns the log format for the given name.
+    ///
+    /// * `name` - The name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "text" => Some(Self::Text),
+            "json" => Some(Self::Json),
+            _ => None,
+        }
+    }
+}
+
+/// Logger that keeps warnings and errors for the render report and passes
+/// all messages on to `env_logger`.
+struct RecordingLogger {
+    /// The `env_logger` configured from the environment.
+    inner: env_logger::Logger,
+}
+
+impl Log for RecordingLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= Level::Warn || self.inner.enabled(metadata)
+    }
+
+    fn log(&self, record: &Record) {
+        if record.level() <= Level::Warn {
+            let mut messages = LOG_MESSAGES.lock().unwrap();
+            if messages.len() < MAX_LOG_MESSAGES {
+                messages.push(format!("{}: {}", record.level(), record.args()));
+            }
+        }
+        if self.inner.matches(record) {
+            self.inner.log(record);
+        }
+    }
+
+    fn flush(&self) {
+        self.inner.flush();
+    }
+}
+
+/// Initializes logging with `env_logger`. The verbosity of each target is
+/// configured by the `RUST_LOG` environment variable and then by `filters`,
+/// e.g. `warn,parser=debug,bvh=info`. Warnings and errors are also kept for
+/// the render report regardless of the configured verbosity.
+///
+/// * `filters` - Optional verbosity of each target in `RUST_LOG` syntax.
+/// * `format`  - Format of the messages.
+pub fn init_logger(filters: Option<&str>, format: LogFormat) {
+    let mut builder = env_logger::Builder::from_default_env();
+    if let Some(filters) = filters {
+        builder.parse_filters(filters);
+    }
+    match format {
+        // Show the target instead of the module so it can be used to filter.
+        LogFormat::Text => builder.format(|buf, record| {
+            writeln!(
+                buf,
+                "[{} {:<5} {}] {}",
+                buf.timestamp(),
+                buf.default_styled_level(record.level()),
+                record.target(),
+                record.args()
+            )
+        }),
+        LogFormat::Json => builder.format(|buf, record| {
+            writeln!(
+                buf,
+                "{{\"time\":\"{}\",\"level\":\"{}\",\"target\":\"{}\",\"message\":\"{}\"}}",
+                buf.timestamp(),
+                record.level(),
+                json_escape(record.target()),
+                json_escape(&record.args().to_string())
+            )
+        }),
+    };
+
+    let inner = builder.build();
+    let max_level = inner.filter().max(LevelFilter::Warn);
+    if log::set_boxed_logger(Box::new(RecordingLogger { inner })).is_ok() {
+        log::set_max_level(max_level);
+    }
+}
+
+/// Returns the warnings and errors logged since the last call and clears
+/// them.
+pub fn take_log_messages() -> Vec<String> {
+    std::mem::take(&mut *LOG_MESSAGES.lock().unwrap())
+}
+
+/// Logs a message unless its call site has already logged it too many times.
+/// The last message logged says that further ones are suppressed. Use the
+/// `log_limited!` macro instead of calling this directly.
+///
+/// * `count`  - Number of times the call site was reached.
+/// * `target` - Target of the message.
+/// * `level`  - Level of the message.
+/// * `args`   - The message.
+pub fn log_limited(count: &AtomicUsize, target: &str, level: Level, args: fmt::Arguments) {
+    if let Some(message) = limited_message(count.fetch_add(1, Ordering::Relaxed), args) {
+        log!(target: target, level, "{}", message);
+    }
+}
+
+/// Returns the message to log for a rate limited call site; `None` once it
+/// has been logged too many times.
+///
+/// * `n`    - Number of times the call site was reached before.
+/// * `args` - The message.
+fn limited_message(n: usize, args: fmt::Arguments) -> Option<String> {
+    match n + 1 {
+        m if m < MAX_REPEATED_MESSAGES => Some(args.to_string()),
+        MAX_REPEATED_MESSAGES => Some(format!(
+            "{} Further messages like this are suppressed.",
+            args
+        )),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeated_messages_are_suppressed() {
+        let messages: Vec<Option<String>> = (0..12)
+            .map(|n| limited_message(n, format_args!("Sample {}", n)))
+            .collect();
+        assert_eq!(messages[0].as_deref(), Some("Sample 0"));
+        assert_eq!(messages[8].as_deref(), Some("Sample 8"));
+        assert_eq!(
+            messages[9].as_deref(),
+            Some("Sample 9 Further messages like this are suppressed.")
+        );
+        assert_eq!(messages[10], None);
+        assert_eq!(messages[11], None);
+
+        assert_eq!(LogFormat::from_name("JSON"), Some(LogFormat::Json));
+        assert_eq!(LogFormat::from_name("xml"), None);
+    }
+}
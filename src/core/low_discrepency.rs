@@ -1,4 +1,32 @@
 //! Low Discrepency Sequences.
+//!
+//! Building blocks for quasi-random samplers:
+//!
+//! - Radical inverses in the first `PRIME_TABLE_SIZE` prime bases and their
+//!   inverses: `radical_inverse()` and `inverse_radical_inverse()`.
+//! - Digit scrambling with random or Faure permutation tables:
+//!   `compute_radical_inverse_permutations()`, `compute_faure_permutations()`
+//!   and `permuted_radical_inverse()`.
+//! - Sobol' sample generation for up to `NUM_SOBOL_DIMENSIONS` dimensions
+//!   with `sobol_sample()`, and scrambled (0, 2)-sequences in Gray code order
+//!   with `van_der_corput()` and `sobol_2d()`.
+//!
+//! ```
+//! use pbr_rust::core::low_discrepency::*;
+//! use pbr_rust::core::rng::RNG;
+//!
+//! // The 3rd point of the Halton sequence in the first two dimensions.
+//! assert_eq!(radical_inverse(0, 3), 0.75);
+//! assert!((radical_inverse(1, 3) - 1.0 / 9.0).abs() < 1e-6);
+//!
+//! // Its digits in base 2 can be recovered from the radical inverse.
+//! assert_eq!(inverse_radical_inverse(2, 0b11, 2), 3);
+//!
+//! // Scrambled versions of the same point.
+//! let perms = compute_radical_inverse_permutations(&mut RNG::default());
+//! let x = permuted_radical_inverse(0, 3, &perms);
+//! assert!((0.0..1.0).contains(&x));
+//! ```
 
 #![allow(dead_code)]
 
@@ -392,7 +420,7 @@ pub fn reverse_bits_32(n: u32) -> u32 {
 ///
 /// * `n` - 64-bit integer.
 #[inline]
-fn reverse_bits_64(n: u64) -> u64 {
+pub fn reverse_bits_64(n: u64) -> u64 {
     let n0 = reverse_bits_32(n as u32) as u64;
     let n1 = reverse_bits_32((n >> 32) as u32) as u64;
     (n0 << 32) | n1
@@ -402,7 +430,7 @@ fn reverse_bits_64(n: u64) -> u64 {
 ///
 /// * `base` - The base is a prime number.
 /// * `a`    - The integer value.
-fn radical_inverse_specialized(base: u16, a: u64) -> Float {
+pub fn radical_inverse_specialized(base: u16, a: u64) -> Float {
     let inv_base = 1.0 / base as Float;
     let base = base as u64;
 
@@ -429,7 +457,7 @@ fn radical_inverse_specialized(base: u16, a: u64) -> Float {
 /// * `base` - The base is a prime number.
 /// * `a`    - The integer value.
 /// * `perm` - Permutation table for each digit of base `base_index`.
-fn scrambled_radical_inverse_specialized(base: u16, a: u64, perm: &[u16]) -> Float {
+pub fn scrambled_radical_inverse_specialized(base: u16, a: u64, perm: &[u16]) -> Float {
     let inv_base = 1.0 / base as Float;
     let base = base as u64;
     let mut reversed_digits = 0_u64;
@@ -451,9 +479,10 @@ fn scrambled_radical_inverse_specialized(base: u16, a: u64, perm: &[u16]) -> Flo
     min(r, ONE_MINUS_EPSILON)
 }
 
-/// Returns the radical inverse of an integer in given base.
+/// Returns the radical inverse of an integer in the prime base with the given
+/// index, i.e. the `base_index`^th prime.
 ///
-/// * `base_index` - The base.
+/// * `base_index` - The index of the base in [0, 1023].
 /// * `a`          - The integer value.
 pub fn radical_inverse(base_index: u16, a: u64) -> Float {
     match base_index {
@@ -467,10 +496,11 @@ pub fn radical_inverse(base_index: u16, a: u64) -> Float {
     }
 }
 
-/// Returns the scrambled radical inverse of an integer in given base by putting
-/// each digit through a permutation table for the given base.
+/// Returns the scrambled radical inverse of an integer in the prime base with
+/// the given index by putting each digit through a permutation table for that
+/// base.
 ///
-/// * `base_index` - The base.
+/// * `base_index` - The index of the base in [0, 1023].
 /// * `a`          - The integer value.
 /// * `perm`       - Permutation table for each digit of base `base_index`.
 pub fn scrambled_radical_inverse(base_index: u16, a: u64, perm: &[u16]) -> Float {
@@ -481,7 +511,7 @@ pub fn scrambled_radical_inverse(base_index: u16, a: u64, perm: &[u16]) -> Float
 /// for the radical inverse functions.
 ///
 /// * `base_index` - A 16-bit integer in [0, 1023].
-fn base_index_to_prime(base_index: u16) -> u16 {
+pub fn base_index_to_prime(base_index: u16) -> u16 {
     match base_index {
         0 => 2,
         1 => 3,
@@ -1511,7 +1541,26 @@ fn base_index_to_prime(base_index: u16) -> u16 {
     }
 }
 
-/// Compute random permutation tables.
+/// Returns the scrambled radical inverse of an integer for a dimension using
+/// the digit permutations of all prime bases in `perms`.
+///
+/// * `dim`   - The dimension; its base is the `dim`^th prime.
+/// * `a`     - The integer value.
+/// * `perms` - Digit permutations of each prime base laid out one after the
+///             other as returned by `compute_radical_inverse_permutations()`
+///             or `compute_faure_permutations()`.
+pub fn permuted_radical_inverse(dim: u16, a: u64, perms: &[u16]) -> Float {
+    assert!(
+        (dim as usize) < PRIME_TABLE_SIZE,
+        "Can only compute radical inverses for {} dimensions",
+        PRIME_TABLE_SIZE
+    );
+    scrambled_radical_inverse(dim, a, &perms[PRIME_SUMS[dim as usize]..])
+}
+
+/// Compute random digit permutation tables for all prime bases. The
+/// permutations are laid out one after the other starting at the offsets in
+/// `PRIME_SUMS`.
 ///
 /// * `rng` - The random number generator.
 pub fn compute_radical_inverse_permutations(rng: &mut RNG) -> Vec<u16> {
@@ -1533,10 +1582,51 @@ pub fn compute_radical_inverse_permutations(rng: &mut RNG) -> Vec<u16> {
     perms
 }
 
-/// Computes the inverse of the radical inverse function.
+/// Compute Faure's deterministic digit permutation tables for all prime
+/// bases. They are laid out like those of
+/// `compute_radical_inverse_permutations()`.
+pub fn compute_faure_permutations() -> Vec<u16> {
+    PRIMES.iter().flat_map(|&b| faure_permutation(b)).collect()
+}
+
+/// Returns Faure's digit permutation for a base. The permutations are
+/// defined recursively starting with (0, 1) for base 2:
+///
+/// - For an even base `2c` the permutation of base `c` is doubled and then
+///   followed by the same values plus 1.
+/// - For an odd base `2c + 1` the values of the permutation of base `2c`
+///   that are at least `c` are incremented and `c` is inserted in the middle.
+///
+/// * `base` - The base.
+pub fn faure_permutation(base: usize) -> Vec<u16> {
+    match base {
+        0 | 1 => vec![0; base],
+        2 => vec![0, 1],
+        _ if base.is_multiple_of(2) => {
+            let half = faure_permutation(base / 2);
+            let even = half.iter().map(|&v| 2 * v);
+            let odd = half.iter().map(|&v| 2 * v + 1);
+            even.chain(odd).collect()
+        }
+        _ => {
+            let c = (base / 2) as u16;
+            let mut perm: Vec<u16> = faure_permutation(base - 1)
+                .iter()
+                .map(|&v| if v >= c { v + 1 } else { v })
+                .collect();
+            perm.insert(c as usize, c);
+            perm
+        }
+    }
+}
+
+/// Computes the inverse of the radical inverse function. Returns the integer
+/// whose first `n_digits` digits are reversed in `inverse`.
 ///
-/// * `base`     - The base is a prime number.
-/// * `inverse`  - The integer with reversed digits from `radical_inverse_specialized()`.
+/// * `base`     - The base is a prime number; use `base_index_to_prime()` to
+///                get the base of a dimension.
+/// * `inverse`  - The reversed digits of the radical inverse, i.e. the
+///                radical inverse scaled by `base`^`n_digits`.
 /// * `n_digits` - The number of digits.
 pub fn inverse_radical_inverse(base: u16, inverse: u64, n_digits: u64) -> u64 {
     let mut index = 0;
@@ -1824,20 +1914,28 @@ pub fn sobol_interval_to_index(m: u32, frame: u64, p: &Point2i) -> u64 {
 /// * `a`         - Sample index.
 /// * `dimension` - Dimension.
 /// * `scramble`  - Encodes the scrambling as bits of `u32` integeger.
+#[cfg(not(feature = "float-as-double"))]
 pub fn sobol_sample(a: u64, dimension: u16, scramble: u64) -> Float {
-    // TODO: There is no way to use generics or traits to implement this
-    // function for both f32/f64. Maybe macros with compiler feature flags
-    // might be the way.
     sobol_sample_f32(a, dimension, scramble)
 }
 
+/// Returns the sample value for a given sample index and dimension.
+///
+/// * `a`         - Sample index.
+/// * `dimension` - Dimension.
+/// * `scramble`  - Encodes the scrambling as bits of `u64` integeger.
+#[cfg(feature = "float-as-double")]
+pub fn sobol_sample(a: u64, dimension: u16, scramble: u64) -> Float {
+    sobol_sample_f64(a, dimension, scramble)
+}
+
 /// Returns the sample value for a given sample index and dimension.
 ///
 /// * `a`         - Sample index.
 /// * `dimension` - Dimension.
 /// * `scramble`  - Encodes the scrambling as bits of `u32` integeger.
 ///                 Default to 0.
-fn sobol_sample_f32(a: u64, dimension: u16, scramble: u64) -> f32 {
+pub fn sobol_sample_f32(a: u64, dimension: u16, scramble: u64) -> f32 {
     assert!(
         (dimension as usize) < NUM_SOBOL_DIMENSIONS,
         "Integrator has consumed too many Sobol dimensions; you \
@@ -1872,9 +1970,9 @@ fn sobol_sample_f32(a: u64, dimension: u16, scramble: u64) -> f32 {
 ///
 /// * `a`         - Sample index.
 /// * `dimension` - Dimension.
-/// * `scramble`  - Encodes the scrambling as bits of `u32` integeger.
+/// * `scramble`  - Encodes the scrambling as bits of `u64` integeger.
 ///                 Default to 0.
-fn sobol_sample_f64(a: u64, dimension: u16, scramble: u64) -> f64 {
+pub fn sobol_sample_f64(a: u64, dimension: u16, scramble: u64) -> f64 {
     assert!(
         (dimension as usize) < NUM_SOBOL_DIMENSIONS,
         "Integrator has consumed too many Sobol dimensions; you \
@@ -1904,3 +2002,34 @@ fn sobol_sample_f64(a: u64, dimension: u16, scramble: u64) -> f64 {
         DOUBLE_ONE_MINUS_EPSILON,
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inverse_radical_inverse_recovers_the_integer() {
+        for base_index in [0, 1, 2, 10] {
+            let base = base_index_to_prime(base_index);
+            let n_digits = 4;
+            let scale = (base as u64).pow(n_digits as u32);
+            for a in 0..scale.min(500) {
+                let inverse = (radical_inverse(base_index, a) as f64 * scale as f64).round();
+                assert_eq!(inverse_radical_inverse(base, inverse as u64, n_digits), a);
+            }
+        }
+    }
+
+    #[test]
+    fn faure_permutations_permute_digits_of_each_base() {
+        assert_eq!(faure_permutation(4), vec![0, 2, 1, 3]);
+        assert_eq!(faure_permutation(5), vec![0, 3, 2, 1, 4]);
+
+        let perms = compute_faure_permutations();
+        for (i, &base) in PRIMES.iter().enumerate().take(50) {
+            let mut perm = perms[PRIME_SUMS[i]..PRIME_SUMS[i] + base].to_vec();
+            perm.sort_unstable();
+            assert!(perm.iter().enumerate().all(|(j, &d)| j == d as usize));
+        }
+    }
+}
@@ -0,0 +1,486 @@
+//! Light Path Expressions
+//!
+//! A light path expression (LPE) is a regular expression over the events along
+//! a light path, from the camera to a light, in the notation used by OSL.
+//! Each event has a type, a scattering type and an optional label:
+//!
+//! * Types: `C` camera, `R` reflection, `T` transmission, `V` volume and `L`
+//!   light.
+//! * Scattering types: `D` diffuse, `G` glossy, `S` specular and `s` straight.
+//!   Camera and light events have no scattering type.
+//! * Labels: light events are labelled with the index of the light in the
+//!   scene, e.g. `'0'` for the first light. Numeric labels can be written
+//!   without quotes, which scene files need as they can't contain `'`.
+//!
+//! An event is matched by `<type scattering label>` where each part is a
+//! letter, a quoted label, a set like `[DG]` or `[^S]`, or `.` for anything;
+//! the label may be omitted. A single letter is shorthand for an event with
+//! that type or scattering type, e.g. `D` is `<.D>`, and `.` matches any
+//! event. Events are combined with `|`, grouping with `(...)`, sets of events
+//! like `[RT]` and the repetitions `*`, `+`, `?`, `{n}`, `{n,}` and `{n,m}`.
+//!
+//! For example `C<RD>L` matches direct diffuse lighting and `C<RS>.*L` matches
+//! all light that was first reflected by a mirror.
+
+#![allow(dead_code)]
+mod parser;
+
+use crate::core::error::*;
+use parser::*;
+
+/// Type of a path event.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EventType {
+    /// The camera.
+    Camera,
+
+    /// Reflection at a surface.
+    Reflection,
+
+    /// Transmission through a surface.
+    Transmission,
+
+    /// Scattering in a volume.
+    Volume,
+
+    /// A light source.
+    Light,
+}
+
+impl EventType {
+    /// Returns the event type for a letter of an expression.
+    ///
+    /// * `c` - The letter.
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            'C' => Some(Self::Camera),
+            'R' => Some(Self::Reflection),
+            'T' => Some(Self::Transmission),
+            'V' => Some(Self::Volume),
+            'L' => Some(Self::Light),
+            _ => None,
+        }
+    }
+}
+
+/// Scattering type of a path event.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ScatterType {
+    /// Diffuse scattering.
+    Diffuse,
+
+    /// Glossy scattering.
+    Glossy,
+
+    /// Specular scattering.
+    Specular,
+
+    /// Passing straight through without changing direction.
+    Straight,
+
+    /// Camera and light events don't scatter.
+    None,
+}
+
+impl ScatterType {
+    /// Returns the scattering type for a letter of an expression.
+    ///
+    /// * `c` - The letter.
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            'D' => Some(Self::Diffuse),
+            'G' => Some(Self::Glossy),
+            'S' => Some(Self::Specular),
+            's' => Some(Self::Straight),
+            _ => None,
+        }
+    }
+}
+
+/// An event along a light path.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PathEvent {
+    /// Type of the event.
+    pub event_type: EventType,
+
+    /// Scattering type of the event.
+    pub scatter: ScatterType,
+
+    /// Optional label.
+    pub label: Option<String>,
+}
+
+impl PathEvent {
+    /// Returns a new `PathEvent`.
+    ///
+    /// * `event_type` - Type of the event.
+    /// * `scatter`    - Scattering type of the event.
+    pub fn new(event_type: EventType, scatter: ScatterType) -> Self {
+        Self {
+            event_type,
+            scatter,
+            label: None,
+        }
+    }
+
+    /// Returns the event for the camera.
+    pub fn camera() -> Self {
+        Self::new(EventType::Camera, ScatterType::None)
+    }
+
+    /// Returns the event for a light.
+    ///
+    /// * `light_index` - Index of the light in the scene; `None` if it isn't
+    ///                   known.
+    pub fn light(light_index: Option<usize>) -> Self {
+        Self {
+            event_type: EventType::Light,
+            scatter: ScatterType::None,
+            label: light_index.map(|i| i.to_string()),
+        }
+    }
+}
+
+/// Matches a single path event.
+#[derive(Clone, Debug, PartialEq)]
+enum EventMatcher {
+    /// Matches any event.
+    Any,
+
+    /// Matches events by type, scattering type and label. `None` matches
+    /// anything.
+    Event {
+        event_types: Option<CharSet>,
+        scatter_types: Option<CharSet>,
+        label: Option<String>,
+    },
+
+    /// Matches events that match any (or with `negated`, none) of the
+    /// matchers.
+    OneOf(Vec<EventMatcher>, bool),
+}
+
+/// A set of event letters.
+#[derive(Clone, Debug, PartialEq)]
+struct CharSet {
+    /// The letters.
+    chars: Vec<char>,
+
+    /// Match letters not in `chars`.
+    negated: bool,
+}
+
+impl CharSet {
+    /// Returns `true` if the set contains the letter.
+    ///
+    /// * `c` - The letter.
+    fn contains(&self, c: char) -> bool {
+        self.chars.contains(&c) != self.negated
+    }
+}
+
+impl EventMatcher {
+    /// Returns `true` if the matcher matches an event.
+    ///
+    /// * `event` - The event.
+    fn matches(&self, event: &PathEvent) -> bool {
+        match self {
+            Self::Any => true,
+            Self::Event {
+                event_types,
+                scatter_types,
+                label,
+            } => {
+                let event_type = match event.event_type {
+                    EventType::Camera => 'C',
+                    EventType::Reflection => 'R',
+                    EventType::Transmission => 'T',
+                    EventType::Volume => 'V',
+                    EventType::Light => 'L',
+                };
+                let scatter = match event.scatter {
+                    ScatterType::Diffuse => Some('D'),
+                    ScatterType::Glossy => Some('G'),
+                    ScatterType::Specular => Some('S'),
+                    ScatterType::Straight => Some('s'),
+                    ScatterType::None => None,
+                };
+                event_types.as_ref().is_none_or(|s| s.contains(event_type))
+                    && scatter_types
+                        .as_ref()
+                        .is_none_or(|s| scatter.is_some_and(|c| s.contains(c)))
+                    && label
+                        .as_ref()
+                        .is_none_or(|l| event.label.as_ref() == Some(l))
+            }
+            Self::OneOf(matchers, negated) => matchers.iter().any(|m| m.matches(event)) != *negated,
+        }
+    }
+}
+
+/// A state of the automaton matching an expression.
+#[derive(Clone, Debug)]
+enum State {
+    /// Consumes an event matching the matcher and moves to the next state.
+    Event(EventMatcher, usize),
+
+    /// Moves to any of the states without consuming an event.
+    Split(Vec<usize>),
+
+    /// The whole path matched.
+    Accept,
+}
+
+/// An output channel receiving the light of the paths that match an
+/// expression.
+#[derive(Clone, Debug)]
+pub struct LpeChannel {
+    /// Name of the channel.
+    pub name: String,
+
+    /// The expression.
+    pub lpe: Lpe,
+}
+
+impl LpeChannel {
+    /// Returns a new `LpeChannel`; or an error if the expression is invalid.
+    ///
+    /// * `name`       - Name of the channel.
+    /// * `expression` - The light path expression.
+    pub fn new(name: &str, expression: &str) -> Result<Self, Error> {
+        Ok(Self {
+            name: String::from(name),
+            lpe: Lpe::new(expression)?,
+        })
+    }
+}
+
+/// A compiled light path expression.
+#[derive(Clone, Debug)]
+pub struct Lpe {
+    /// The expression.
+    pub expression: String,
+
+    /// States of the automaton.
+    states: Vec<State>,
+
+    /// The set of states before any events.
+    start: LpeState,
+}
+
+/// The set of automaton states reached by a path prefix. It is empty if no
+/// path with the prefix can match.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LpeState(Vec<usize>);
+
+impl Lpe {
+    /// Compiles a light path expression.
+    ///
+    /// * `expression` - The expression.
+    pub fn new(expression: &str) -> Result<Self, Error> {
+        let ast = Parser::new(expression).parse().map_err(|msg| {
+            Error::Parse(format!(
+                "Invalid light path expression '{}'. {}.",
+                expression, msg
+            ))
+        })?;
+
+        let mut states = vec![State::Accept];
+        let start = compile(&ast, 0, &mut states);
+        let mut lpe = Self {
+            expression: String::from(expression),
+            states,
+            start: LpeState::default(),
+        };
+        lpe.start = lpe.closure(&[start]);
+        Ok(lpe)
+    }
+
+    /// Returns the state before any events.
+    pub fn start(&self) -> LpeState {
+        self.start.clone()
+    }
+
+    /// Returns the state after an event.
+    ///
+    /// * `state` - State before the event.
+    /// * `event` - The event.
+    pub fn advance(&self, state: &LpeState, event: &PathEvent) -> LpeState {
+        let next: Vec<usize> = state
+            .0
+            .iter()
+            .filter_map(|&s| match &self.states[s] {
+                State::Event(m, next) if m.matches(event) => Some(*next),
+                _ => None,
+            })
+            .collect();
+        self.closure(&next)
+    }
+
+    /// Returns `true` if a path ending in the state matches the expression.
+    ///
+    /// * `state` - The state.
+    pub fn is_match(&self, state: &LpeState) -> bool {
+        state
+            .0
+            .iter()
+            .any(|&s| matches!(self.states[s], State::Accept))
+    }
+
+    /// Returns `true` if the whole sequence of events matches the expression.
+    ///
+    /// * `events` - The events.
+    pub fn matches(&self, events: &[PathEvent]) -> bool {
+        let state = events
+            .iter()
+            .fold(self.start(), |state, e| self.advance(&state, e));
+        self.is_match(&state)
+    }
+
+    /// Returns the states that consume events or accept, reachable from the
+    /// given states without consuming events.
+    ///
+    /// * `states` - The states.
+    fn closure(&self, states: &[usize]) -> LpeState {
+        let mut visited = vec![false; self.states.len()];
+        let mut stack = states.to_vec();
+        let mut result = vec![];
+        while let Some(s) = stack.pop() {
+            if visited[s] {
+                continue;
+            }
+            visited[s] = true;
+            match &self.states[s] {
+                State::Split(next) => stack.extend(next.iter().rev()),
+                _ => result.push(s),
+            }
+        }
+        result.sort_unstable();
+        LpeState(result)
+    }
+}
+
+impl LpeState {
+    /// Returns `true` if no path with this prefix can match.
+    pub fn is_dead(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// Adds the states for an expression to the automaton and returns its
+/// starting state.
+///
+/// * `ast`    - The expression.
+/// * `next`   - The state following the expression.
+/// * `states` - States of the automaton.
+fn compile(ast: &Ast, next: usize, states: &mut Vec<State>) -> usize {
+    let push = |states: &mut Vec<State>, state| {
+        states.push(state);
+        states.len() - 1
+    };
+
+    match ast {
+        Ast::Event(m) => push(states, State::Event(m.clone(), next)),
+        Ast::Concat(items) => items
+            .iter()
+            .rev()
+            .fold(next, |next, item| compile(item, next, states)),
+        Ast::Alt(items) => {
+            let starts = items
+                .iter()
+                .map(|item| compile(item, next, states))
+                .collect();
+            push(states, State::Split(starts))
+        }
+        Ast::Repeat(item, min, max) => {
+            // Optional repetitions after the required ones.
+            let mut start = match max {
+                None => {
+                    let lp = push(states, State::Split(vec![]));
+                    let body = compile(item, lp, states);
+                    states[lp] = State::Split(vec![body, next]);
+                    lp
+                }
+                Some(max) => (*min..*max).fold(next, |start, _| {
+                    let body = compile(item, start, states);
+                    push(states, State::Split(vec![body, next]))
+                }),
+            };
+            for _ in 0..*min {
+                start = compile(item, start, states);
+            }
+            start
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn events(s: &str) -> Vec<PathEvent> {
+        s.split(' ')
+            .map(|e| {
+                let mut c = e.chars();
+                let event_type = EventType::from_char(c.next().unwrap()).unwrap();
+                match event_type {
+                    EventType::Light => {
+                        PathEvent::light(c.next().map(|i| i as usize - '0' as usize))
+                    }
+                    EventType::Camera => PathEvent::camera(),
+                    _ => PathEvent::new(
+                        event_type,
+                        ScatterType::from_char(c.next().unwrap()).unwrap(),
+                    ),
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn expressions_match_whole_paths() {
+        let direct_diffuse = Lpe::new("C<RD>L").unwrap();
+        assert!(direct_diffuse.matches(&events("C RD L0")));
+        assert!(!direct_diffuse.matches(&events("C RG L0")));
+        assert!(!direct_diffuse.matches(&events("C RD RD L0")));
+        assert!(!direct_diffuse.matches(&events("C RD")));
+
+        let mirror = Lpe::new("C<RS>.*L").unwrap();
+        assert!(mirror.matches(&events("C RS L0")));
+        assert!(mirror.matches(&events("C RS TS RD L1")));
+        assert!(!mirror.matches(&events("C TS RS L1")));
+
+        let light1 = Lpe::new("C [^S]* <L.'1'>").unwrap();
+        assert!(Lpe::new("C[^S]*<L.1>")
+            .unwrap()
+            .matches(&events("C RD TG L1")));
+        assert!(light1.matches(&events("C RD TG L1")));
+        assert!(!light1.matches(&events("C RD L0")));
+        assert!(!light1.matches(&events("C RS L1")));
+
+        let caustics = Lpe::new("C<.D>(<[RT]S>)+L | CL").unwrap();
+        assert!(caustics.matches(&events("C RD TS TS L0")));
+        assert!(caustics.matches(&events("C L0")));
+        assert!(!caustics.matches(&events("C RD L0")));
+
+        let bounded = Lpe::new("C D{1,2} L").unwrap();
+        assert!(!bounded.matches(&events("C L0")));
+        assert!(bounded.matches(&events("C RD TD L0")));
+        assert!(!bounded.matches(&events("C RD RD RD L0")));
+        let at_least = Lpe::new("C (R|T){2,} L").unwrap();
+        assert!(!at_least.matches(&events("C RD L0")));
+        assert!(at_least.matches(&events("C RD TS RG L0")));
+
+        // Prefixes that can't match are detected early.
+        let state = direct_diffuse.advance(&direct_diffuse.start(), &PathEvent::camera());
+        let state = direct_diffuse.advance(
+            &state,
+            &PathEvent::new(EventType::Reflection, ScatterType::Specular),
+        );
+        assert!(state.is_dead());
+
+        assert!(Lpe::new("C<RD L").is_err());
+        assert!(Lpe::new("C(RD").is_err());
+        assert!(Lpe::new("CX").is_err());
+    }
+}
@@ -0,0 +1,300 @@
+//! Light Path Expression Parser
+
+use super::*;
+
+/// Parsed light path expression.
+#[derive(Clone, Debug)]
+pub(super) enum Ast {
+    /// A single event.
+    Event(EventMatcher),
+
+    /// A sequence of expressions.
+    Concat(Vec<Ast>),
+
+    /// Alternative expressions.
+    Alt(Vec<Ast>),
+
+    /// An expression repeated a minimum and optional maximum number of times.
+    Repeat(Box<Ast>, usize, Option<usize>),
+}
+
+/// Recursive descent parser for light path expressions.
+pub(super) struct Parser {
+    /// Characters of the expression.
+    chars: Vec<char>,
+
+    /// Position of the next character.
+    pos: usize,
+}
+
+impl Parser {
+    /// Returns a new `Parser`.
+    ///
+    /// * `expression` - The expression.
+    pub(super) fn new(expression: &str) -> Self {
+        Self {
+            chars: expression.chars().collect(),
+            pos: 0,
+        }
+    }
+
+    /// Parses the whole expression.
+    pub(super) fn parse(&mut self) -> Result<Ast, String> {
+        let ast = self.alternation()?;
+        match self.peek() {
+            None => Ok(ast),
+            Some(c) => Err(self.unexpected(c)),
+        }
+    }
+
+    /// Returns the next character that isn't whitespace without consuming it.
+    fn peek(&mut self) -> Option<char> {
+        while self.pos < self.chars.len() && self.chars[self.pos].is_whitespace() {
+            self.pos += 1;
+        }
+        self.chars.get(self.pos).copied()
+    }
+
+    /// Consumes the next character that isn't whitespace.
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek();
+        if c.is_some() {
+            self.pos += 1;
+        }
+        c
+    }
+
+    /// Consumes the expected character.
+    ///
+    /// * `expected` - The character.
+    fn expect(&mut self, expected: char) -> Result<(), String> {
+        match self.next() {
+            Some(c) if c == expected => Ok(()),
+            Some(c) => {
+                self.pos -= 1;
+                Err(self.unexpected(c))
+            }
+            None => Err(format!("Expected '{}'", expected)),
+        }
+    }
+
+    /// Returns the error message for an unexpected character at the current
+    /// position.
+    ///
+    /// * `c` - The character.
+    fn unexpected(&self, c: char) -> String {
+        format!("Unexpected '{}' at position {}", c, self.pos)
+    }
+
+    /// Parses `concatenation ('|' concatenation)*`.
+    fn alternation(&mut self) -> Result<Ast, String> {
+        let mut items = vec![self.concatenation()?];
+        while self.peek() == Some('|') {
+            self.pos += 1;
+            items.push(self.concatenation()?);
+        }
+        Ok(if items.len() == 1 {
+            items.remove(0)
+        } else {
+            Ast::Alt(items)
+        })
+    }
+
+    /// Parses a sequence of repeated atoms.
+    fn concatenation(&mut self) -> Result<Ast, String> {
+        let mut items = vec![];
+        while !matches!(self.peek(), None | Some('|') | Some(')')) {
+            items.push(self.repetition()?);
+        }
+        match items.len() {
+            0 => Err(format!("Empty expression at position {}", self.pos)),
+            1 => Ok(items.remove(0)),
+            _ => Ok(Ast::Concat(items)),
+        }
+    }
+
+    /// Parses an atom followed by any number of `*`, `+`, `?` or `{...}`.
+    fn repetition(&mut self) -> Result<Ast, String> {
+        let mut ast = self.atom()?;
+        loop {
+            let (min, max) = match self.peek() {
+                Some('*') => (0, None),
+                Some('+') => (1, None),
+                Some('?') => (0, Some(1)),
+                Some('{') => {
+                    self.pos += 1;
+                    self.bounds()?
+                }
+                _ => return Ok(ast),
+            };
+            self.pos += 1;
+            ast = Ast::Repeat(Box::new(ast), min, max);
+        }
+    }
+
+    /// Parses the `n`, `n,` or `n,m` of `{...}` leaving the closing brace.
+    fn bounds(&mut self) -> Result<(usize, Option<usize>), String> {
+        let min = self.number()?;
+        let max = if self.peek() == Some(',') {
+            self.pos += 1;
+            if self.peek() == Some('}') {
+                None
+            } else {
+                Some(self.number()?)
+            }
+        } else {
+            Some(min)
+        };
+        if max.is_some_and(|max| max < min) {
+            return Err(format!("Invalid repetition at position {}", self.pos));
+        }
+        match self.peek() {
+            Some('}') => Ok((min, max)),
+            Some(c) => Err(self.unexpected(c)),
+            None => Err(String::from("Expected '}'")),
+        }
+    }
+
+    /// Parses a decimal number.
+    fn number(&mut self) -> Result<usize, String> {
+        self.peek();
+        let start = self.pos;
+        while self.pos < self.chars.len() && self.chars[self.pos].is_ascii_digit() {
+            self.pos += 1;
+        }
+        let digits: String = self.chars[start..self.pos].iter().collect();
+        digits
+            .parse()
+            .map_err(|_| format!("Expected a number at position {}", start))
+    }
+
+    /// Parses a group, an event, a set of events, `.` or a single letter.
+    fn atom(&mut self) -> Result<Ast, String> {
+        match self.next() {
+            Some('(') => {
+                let ast = self.alternation()?;
+                self.expect(')')?;
+                Ok(ast)
+            }
+            Some('<') => {
+                let ast = self.event()?;
+                self.expect('>')?;
+                Ok(ast)
+            }
+            Some('[') => {
+                let negated = self.negation();
+                let mut matchers = vec![];
+                while self.peek() != Some(']') {
+                    match self.next() {
+                        Some(c) => matchers.push(self.letter(c)?),
+                        None => return Err(String::from("Expected ']'")),
+                    }
+                }
+                self.pos += 1;
+                Ok(Ast::Event(EventMatcher::OneOf(matchers, negated)))
+            }
+            Some('.') => Ok(Ast::Event(EventMatcher::Any)),
+            Some(c) => self.letter(c).map(Ast::Event),
+            None => Err(String::from("Unexpected end of expression")),
+        }
+    }
+
+    /// Returns the matcher for a single letter standing for an event type or
+    /// scattering type.
+    ///
+    /// * `c` - The letter.
+    fn letter(&mut self, c: char) -> Result<EventMatcher, String> {
+        let set = Some(CharSet {
+            chars: vec![c],
+            negated: false,
+        });
+        if EventType::from_char(c).is_some() {
+            Ok(EventMatcher::Event {
+                event_types: set,
+                scatter_types: None,
+                label: None,
+            })
+        } else if ScatterType::from_char(c).is_some() {
+            Ok(EventMatcher::Event {
+                event_types: None,
+                scatter_types: set,
+                label: None,
+            })
+        } else {
+            self.pos -= 1;
+            Err(self.unexpected(c))
+        }
+    }
+
+    /// Parses `type scattering label?` inside `<...>`.
+    fn event(&mut self) -> Result<Ast, String> {
+        let event_types = self.component(|c| EventType::from_char(c).is_some())?;
+        let scatter_types = self.component(|c| ScatterType::from_char(c).is_some())?;
+        let label = match self.peek() {
+            Some('\'') => {
+                self.pos += 1;
+                let start = self.pos;
+                while self.pos < self.chars.len() && self.chars[self.pos] != '\'' {
+                    self.pos += 1;
+                }
+                if self.pos == self.chars.len() {
+                    return Err(String::from("Unterminated label"));
+                }
+                self.pos += 1;
+                Some(self.chars[start..self.pos - 1].iter().collect())
+            }
+            Some(c) if c.is_ascii_digit() => Some(self.number()?.to_string()),
+            _ => None,
+        };
+        Ok(Ast::Event(EventMatcher::Event {
+            event_types,
+            scatter_types,
+            label,
+        }))
+    }
+
+    /// Parses a letter, `.` or a set of letters of an event; `None` matches
+    /// any letter.
+    ///
+    /// * `valid` - Returns `true` for valid letters.
+    fn component(&mut self, valid: impl Fn(char) -> bool) -> Result<Option<CharSet>, String> {
+        match self.next() {
+            Some('.') => Ok(None),
+            Some('[') => {
+                let negated = self.negation();
+                let mut chars = vec![];
+                while self.peek() != Some(']') {
+                    match self.next() {
+                        Some(c) if valid(c) => chars.push(c),
+                        Some(c) => {
+                            self.pos -= 1;
+                            return Err(self.unexpected(c));
+                        }
+                        None => return Err(String::from("Expected ']'")),
+                    }
+                }
+                self.pos += 1;
+                Ok(Some(CharSet { chars, negated }))
+            }
+            Some(c) if valid(c) => Ok(Some(CharSet {
+                chars: vec![c],
+                negated: false,
+            })),
+            Some(c) => {
+                self.pos -= 1;
+                Err(self.unexpected(c))
+            }
+            None => Err(String::from("Unexpected end of expression")),
+        }
+    }
+
+    /// Consumes a `^` at the start of a set and returns whether it was there.
+    fn negation(&mut self) -> bool {
+        if self.peek() == Some('^') {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+}
@@ -5,7 +5,7 @@ use crate::core::geometry::*;
 use crate::core::pbrt::*;
 
 /// Henyey-Greenstein phase function.
-struct HenyeyGreenstein {
+pub struct HenyeyGreenstein {
     /// The asymmetry parameter. It is the average value of the product of the
     /// phase function being approximated and the cosine of the angle between two
     /// directions. Isotropic phase functions use g = 0.
@@ -8,10 +8,12 @@ use std::sync::Arc;
 
 mod henyey_greenstein;
 mod phase_function;
+mod spectral;
 
 // Re-exports
 pub use henyey_greenstein::*;
 pub use phase_function::*;
+pub use spectral::*;
 
 /// Medium trait to handle volumetric scattering properties.
 pub trait Medium {
@@ -19,7 +21,15 @@ pub trait Medium {
     ///
     /// * `ray`     - The ray.
     /// * `sampler` - The sampler.
-    fn tr(&self, ray: &Ray, sampler: ArcSampler) -> Spectrum;
+    fn tr(&self, ray: &Ray, sampler: &mut ArcSampler) -> Spectrum;
+
+    /// Samples a scattering interaction along a ray and returns the
+    /// throughput weight of the sample and the interaction; or `None` if the
+    /// ray passes through the medium up to `ray.t_max` without scattering.
+    ///
+    /// * `ray`     - The ray.
+    /// * `sampler` - The sampler.
+    fn sample(&self, ray: &Ray, sampler: &mut ArcSampler) -> (Spectrum, Option<MediumInteraction>);
 }
 
 /// Atomic reference counted `Medium`.
@@ -59,7 +69,7 @@ impl MediumInterface {
     /// two distinct media.
     pub fn is_medium_transition(&self) -> bool {
         match (self.inside.clone(), self.outside.clone()) {
-            (Some(inside), Some(outside)) => Arc::ptr_eq(&inside, &outside),
+            (Some(inside), Some(outside)) => !Arc::ptr_eq(&inside, &outside),
             (Some(_), None) => true,
             (None, Some(_)) => true,
             (None, None) => false,
@@ -0,0 +1,92 @@
+//! Spectral Sampling
+
+use crate::core::pbrt::*;
+use crate::core::spectrum::*;
+
+/// Returns a channel of a spectrum chosen uniformly at random. Chromatic
+/// media sample distances with the coefficients of the chosen channel.
+///
+/// * `u`          - Sample value in [0, 1).
+/// * `n_channels` - Number of channels.
+pub fn sample_channel(u: Float, n_channels: usize) -> usize {
+    min((u * n_channels as Float) as usize, n_channels - 1)
+}
+
+/// Returns the average of the channels of a spectrum. It is the probability
+/// density of an event sampled with a uniformly chosen channel when the
+/// spectrum holds the density of each channel.
+///
+/// * `s` - The spectrum.
+pub fn channel_average(s: &Spectrum) -> Float {
+    s.samples().iter().sum::<Float>() / s.samples().len() as Float
+}
+
+/// Returns the transmittance of a homogeneous medium.
+///
+/// * `sigma_t` - Extinction coefficient.
+/// * `t`       - Distance.
+pub fn homogeneous_transmittance(sigma_t: &Spectrum, t: Float) -> Spectrum {
+    let mut tr = Spectrum::new(0.0);
+    for (tr, sigma_t) in tr.samples_mut().iter_mut().zip(sigma_t.samples()) {
+        *tr = (-sigma_t * t).exp();
+    }
+    tr
+}
+
+/// Path throughput of a walk through a chromatic medium with single-sample
+/// spectral MIS. Each collision is decided by one channel, the hero channel,
+/// but the contribution and the probability density of the walk are tracked
+/// for every channel. Weighting the contribution by the average density of
+/// all channels, i.e. the balance heuristic over the channels, gives every
+/// channel a low variance estimate regardless of which one was chosen.
+#[derive(Clone)]
+pub struct SpectralMIS {
+    /// Contribution of the walk for each channel.
+    f: Spectrum,
+
+    /// Probability density of the walk for each channel.
+    pdf: Spectrum,
+}
+
+impl SpectralMIS {
+    /// Create a new `SpectralMIS` for an empty walk.
+    pub fn new() -> Self {
+        Self {
+            f: Spectrum::new(1.0),
+            pdf: Spectrum::new(1.0),
+        }
+    }
+
+    /// Adds an event to the walk.
+    ///
+    /// * `f`   - Contribution of the event for each channel.
+    /// * `pdf` - Probability density of the event for each channel.
+    pub fn add(&mut self, f: &Spectrum, pdf: &Spectrum) {
+        self.f *= *f;
+        self.pdf *= *pdf;
+
+        // Only the ratio matters; rescale both so long walks don't underflow.
+        let average = channel_average(&self.pdf);
+        if average > 0.0 {
+            self.f /= average;
+            self.pdf /= average;
+        }
+    }
+
+    /// Returns the MIS weighted throughput of the walk.
+    pub fn weight(&self) -> Spectrum {
+        let average = channel_average(&self.pdf);
+        if average > 0.0 {
+            self.f / average
+        } else {
+            Spectrum::new(0.0)
+        }
+    }
+}
+
+impl Default for SpectralMIS {
+    /// Returns a `SpectralMIS` for an empty walk.
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -64,7 +64,25 @@ pub trait MicrofacetDistribution {
             self.d(wh) * abs_cos_theta(wh)
         }
     }
+
+    /// Returns a rougher copy of the distribution that blurs near-specular
+    /// highlights which cause fireflies; or `None` if it is rough enough.
+    fn regularize(&self) -> Option<ArcMicrofacetDistribution> {
+        None
+    }
 }
 
 /// Atomic reference counted `BSDF`.
 pub type ArcMicrofacetDistribution = Arc<dyn MicrofacetDistribution + Send + Sync>;
+
+/// Returns the roughness α of a regularized distribution. Values below 0.3
+/// are doubled and kept in [0.1, 0.3].
+///
+/// * `alpha` - Roughness α.
+pub fn regularized_alpha(alpha: Float) -> Float {
+    if alpha < 0.3 {
+        clamp(2.0 * alpha, 0.1, 0.3)
+    } else {
+        alpha
+    }
+}
@@ -4,7 +4,8 @@
 use crate::core::geometry::*;
 use crate::core::pbrt::*;
 use crate::core::reflection::*;
-use super::MicrofacetDistribution;
+use super::{regularized_alpha, ArcMicrofacetDistribution, MicrofacetDistribution};
+use std::sync::Arc;
 
 /// Implements the anisotropic variant of the Trowbridge-Reitz distribution.
 #[derive(Copy, Clone, Default)]
@@ -131,6 +132,20 @@ impl MicrofacetDistribution for TrowbridgeReitzDistribution {
             if flip { -wh } else { wh }
         }
     }
+
+    /// Returns a rougher copy of the distribution that blurs near-specular
+    /// highlights which cause fireflies; or `None` if it is rough enough.
+    fn regularize(&self) -> Option<ArcMicrofacetDistribution> {
+        if self.alpha_x < 0.3 || self.alpha_y < 0.3 {
+            Some(Arc::new(Self::new(
+                regularized_alpha(self.alpha_x),
+                regularized_alpha(self.alpha_y),
+                self.sample_visible_area,
+            )))
+        } else {
+            None
+        }
+    }
 }
 
 /// Helper function for sampling visible area of normals.
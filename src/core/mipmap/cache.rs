@@ -3,6 +3,7 @@
 #![allow(dead_code)]
 use super::convert_in::*;
 use super::tex_info::*;
+use crate::core::error::*;
 use crate::core::image_io::*;
 use crate::core::mipmap::*;
 use crate::core::spectrum::*;
@@ -21,7 +22,7 @@ pub trait MIPMapCacheProvider<Tmemory> {
 }
 
 /// Type for result of retrieving `MIPMapCacheProvider<Tmemory>::get()`.
-pub type MIPMapCacheResult<Tmemory> = Result<ArcMIPMap<Tmemory>, String>;
+pub type MIPMapCacheResult<Tmemory> = Result<ArcMIPMap<Tmemory>, Error>;
 
 /// Type for storing `MIPMap`s of type `Tmemory` in a `lazy_static`.
 type MIPMaps<Tmemory> = Mutex<HashMap<TexInfo, Arc<MIPMap<Tmemory>>>>;
@@ -41,7 +42,7 @@ macro_rules! cache_provider {
             /// load it from file, store it in cache and return a reference.
             ///
             /// * `tex_info` - Texture information.
-            fn get(info: TexInfo) -> Result<ArcMIPMap<$t>, String> {
+            fn get(info: TexInfo) -> MIPMapCacheResult<$t> {
                 let mut mipmaps = $id.lock().expect("Unable to access mipmap mutex");
                 match mipmaps.get(&info) {
                     Some(mipmap) => Ok(mipmap.clone()),
@@ -62,7 +63,7 @@ cache_provider!(Float, FLOAT_MIPMAPS);
 /// Load an image texture from file and build the `MIPMap`.
 ///
 /// * `info` - Texture information.
-fn generate_mipmap<Tmemory>(info: &TexInfo) -> Result<Arc<MIPMap<Tmemory>>, String>
+fn generate_mipmap<Tmemory>(info: &TexInfo) -> Result<Arc<MIPMap<Tmemory>>, Error>
 where
     Tmemory: Copy
         + Default
@@ -73,15 +74,21 @@ where
         + Add<Tmemory, Output = Tmemory>
         + AddAssign
         + Clamp<Float>,
-    Spectrum: ConvertIn<Tmemory>,
+    RGBSpectrum: ConvertIn<Tmemory>,
 {
     // Create `MipMap` for `filename`.
+    let _p = ProfilePhase::new(Prof::TextureLoading);
     let RGBImage {
         pixels: mut texels,
         resolution,
     } = match read_image(info.path.as_str()) {
         Ok(img) => img,
-        Err(err) => return Err(format!("Error reading texture {}, {:}.", info.path, err)),
+        Err(err) => {
+            return Err(Error::Image(format!(
+                "Error reading texture {}, {:}.",
+                info.path, err
+            )))
+        }
     };
 
     // Flip image in y; texture coordinate space has (0,0) at the lower
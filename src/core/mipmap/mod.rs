@@ -5,8 +5,10 @@
 use crate::core::geometry::*;
 use crate::core::memory::*;
 use crate::core::pbrt::*;
+use crate::core::profiler::*;
 use crate::core::texture::*;
 use std::hash::Hash;
+use std::mem::size_of;
 use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign};
 use std::sync::Arc;
 
@@ -19,6 +21,9 @@ pub use cache::*;
 pub use convert_in::*;
 pub use tex_info::*;
 
+stat_counter!("Texture/MIPMaps created", N_MIPMAPS);
+stat_memory_counter!("Memory/Texture MIP data", MIPMAP_MEMORY);
+
 /// Size of the weights lookup table.
 const WEIGHT_LUT_SIZE: usize = 128;
 
@@ -107,6 +112,7 @@ where
         wrap_mode: ImageWrap,
         max_anisotropy: Float,
     ) -> Self {
+        let _p = ProfilePhase::new(Prof::MIPMapCreation);
         let mut resampled_image: Vec<T> = vec![];
 
         let resolution = if !resolution[0].is_power_of_two() || !resolution[1].is_power_of_two() {
@@ -210,6 +216,14 @@ where
             }
         }
 
+        N_MIPMAPS.inc();
+        MIPMAP_MEMORY.add(
+            pyramid
+                .iter()
+                .map(|level| (level.u_size() * level.v_size() * size_of::<T>()) as i64)
+                .sum(),
+        );
+
         // Initialize EWA filter weights.
         let mut weight_lut = [0.0; WEIGHT_LUT_SIZE];
         let alpha = 2.0;
@@ -252,13 +266,17 @@ where
     pub fn lookup(&self, st: &Point2f, dst0: &Vector2f, dst1: &Vector2f) -> T {
         match self.filtering_method {
             FilteringMethod::Trilinear => {
+                let _p = ProfilePhase::new(Prof::TexFiltTrilerp);
                 let width = max(
                     max(abs(dst0[0]), abs(dst0[1])),
                     max(abs(dst1[0]), abs(dst1[1])),
                 );
                 self.lookup_triangle(st, width)
             }
-            FilteringMethod::Ewa => self.lookup_ewa(st, &dst0, &dst1),
+            FilteringMethod::Ewa => {
+                let _p = ProfilePhase::new(Prof::TexFiltEWA);
+                self.lookup_ewa(st, &dst0, &dst1)
+            }
         }
     }
 
@@ -1,35 +1,54 @@
 //! Core
 
 // Re-export.
+#[macro_use]
+pub mod stats;
+#[macro_use]
+pub mod logging;
 pub mod api;
 pub mod app;
+pub mod arena;
 pub mod bssrdf;
 pub mod camera;
+pub mod camera_response;
+pub mod cancel;
+pub mod distributed;
 pub mod efloat;
+pub mod error;
 pub mod fileutil;
 pub mod film;
 pub mod filter;
 pub mod geometry;
+#[cfg(test)]
+mod golden;
 pub mod image_io;
 pub mod integrator;
 pub mod interpolation;
 pub mod light;
 pub mod low_discrepency;
+pub mod lpe;
 pub mod material;
 pub mod medium;
 pub mod memory;
 pub mod microfacet;
 pub mod mipmap;
+pub mod parallel;
 pub mod paramset;
 pub mod parsers;
 pub mod pbrt;
+pub mod plugin;
 pub mod primitive;
 pub mod primitives;
+pub mod profiler;
 pub mod reflection;
+pub mod report;
 pub mod rng;
 pub mod sampler;
 pub mod sampling;
 pub mod scene;
+pub mod scene_cache;
+pub mod scene_stats;
+pub mod server;
 pub mod sobol_matrices;
 pub mod spectrum;
 pub mod texture;
@@ -0,0 +1,219 @@
+//! Parallel Iteration
+
+#![allow(dead_code)]
+use crate::core::error::*;
+use crate::core::pbrt::Float;
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::Mutex;
+#[cfg(feature = "parallel")]
+use std::sync::OnceLock;
+
+// With the `parallel` feature rendering and scene construction use the
+// `rayon` thread pool. Without it, e.g. for the wasm32 target where threads
+// aren't available, the same iterator methods run sequentially on the
+// calling thread.
+#[cfg(feature = "parallel")]
+pub use rayon::prelude::*;
+
+/// Returns the number of threads used for parallel iteration.
+#[cfg(feature = "parallel")]
+pub fn current_num_threads() -> usize {
+    rayon::current_num_threads()
+}
+
+/// Returns the number of threads used for parallel iteration.
+#[cfg(not(feature = "parallel"))]
+pub fn current_num_threads() -> usize {
+    1
+}
+
+/// Initializes the global thread pool; or returns an error if it was
+/// already initialized with a different number of threads.
+///
+/// * `n_threads` - Number of threads.
+#[cfg(feature = "parallel")]
+pub fn init_thread_pool(n_threads: usize) -> Result<(), Error> {
+    static POOL_THREADS: OnceLock<Result<usize, String>> = OnceLock::new();
+
+    let result = POOL_THREADS.get_or_init(|| {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(n_threads)
+            .build_global()
+            .map(|_| n_threads)
+            .map_err(|err| format!("{}", err))
+    });
+    match result {
+        Ok(n) if *n == n_threads => Ok(()),
+        Ok(n) => Err(Error::InvalidState(format!(
+            "The thread pool already has {} threads.",
+            n
+        ))),
+        Err(err) => Err(Error::InvalidState(err.clone())),
+    }
+}
+
+/// Initializes the global thread pool. Iteration is sequential so this
+/// does nothing.
+///
+/// * `n_threads` - Number of threads.
+#[cfg(not(feature = "parallel"))]
+pub fn init_thread_pool(_n_threads: usize) -> Result<(), Error> {
+    Ok(())
+}
+
+/// Calls `produce` for each index from 0 to `n`, excluding `n`, on the threads
+/// used for parallel iteration and `consume` with the results in the order
+/// of their indices, so the outcome doesn't depend on the number of threads
+/// or their timing. Threads take the next index once they are free; results
+/// that finish early wait for those before them.
+///
+/// * `n`       - Number of indices.
+/// * `produce` - Returns the result for an index.
+/// * `consume` - Called with each index and its result in order.
+pub fn for_each_in_order<T, P, C>(n: usize, produce: P, consume: C)
+where
+    T: Send,
+    P: Fn(usize) -> T + Sync,
+    C: FnMut(usize, T) + Send,
+{
+    let next_index = AtomicUsize::new(0);
+    let pending: Mutex<(usize, BTreeMap<usize, T>, C)> = Mutex::new((0, BTreeMap::new(), consume));
+
+    (0..current_num_threads())
+        .into_par_iter()
+        .for_each(|_| loop {
+            let index = next_index.fetch_add(1, Ordering::SeqCst);
+            if index >= n {
+                break;
+            }
+            let result = produce(index);
+
+            let mut guard = pending.lock().unwrap();
+            let (next_result, results, consume) = &mut *guard;
+            results.insert(index, result);
+            while let Some(result) = results.remove(next_result) {
+                consume(*next_result, result);
+                *next_result += 1;
+            }
+        });
+}
+
+/// A floating point value that threads can add to without a lock. It is
+/// stored in double precision so sums of many small values keep their
+/// accuracy when `Float` is `f32`.
+#[derive(Debug, Default)]
+pub struct AtomicFloat {
+    /// Bits of the `f64` value.
+    bits: AtomicU64,
+}
+
+impl AtomicFloat {
+    /// Create a new `AtomicFloat`.
+    ///
+    /// * `v` - Initial value.
+    pub fn new(v: Float) -> Self {
+        Self {
+            bits: AtomicU64::new((v as f64).to_bits()),
+        }
+    }
+
+    /// Returns the value.
+    pub fn load(&self) -> Float {
+        f64::from_bits(self.bits.load(Ordering::Relaxed)) as Float
+    }
+
+    /// Sets the value.
+    ///
+    /// * `v` - The value.
+    pub fn store(&self, v: Float) {
+        self.bits.store((v as f64).to_bits(), Ordering::Relaxed);
+    }
+
+    /// Adds to the value.
+    ///
+    /// * `v` - The value to add.
+    pub fn add(&self, v: Float) {
+        let mut old_bits = self.bits.load(Ordering::Relaxed);
+        loop {
+            let new_bits = (f64::from_bits(old_bits) + v as f64).to_bits();
+            match self.bits.compare_exchange_weak(
+                old_bits,
+                new_bits,
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => break,
+                Err(bits) => old_bits = bits,
+            }
+        }
+    }
+}
+
+/// Sequential stand-in for `rayon::iter::IntoParallelIterator`.
+#[cfg(not(feature = "parallel"))]
+pub trait IntoParallelIterator: IntoIterator + Sized {
+    /// Returns an iterator over the values.
+    fn into_par_iter(self) -> Self::IntoIter {
+        self.into_iter()
+    }
+}
+
+#[cfg(not(feature = "parallel"))]
+impl<I: IntoIterator> IntoParallelIterator for I {}
+
+/// Sequential stand-in for `rayon::slice::ParallelSlice` and
+/// `rayon::iter::IntoParallelRefIterator` on slices.
+#[cfg(not(feature = "parallel"))]
+pub trait ParallelSlice<T> {
+    /// Returns an iterator over the values.
+    fn par_iter(&self) -> std::slice::Iter<'_, T>;
+
+    /// Returns an iterator over chunks of the values.
+    ///
+    /// * `chunk_size` - Number of values in each chunk; the last one may be
+    ///                  shorter.
+    fn par_chunks(&self, chunk_size: usize) -> std::slice::Chunks<'_, T>;
+}
+
+#[cfg(not(feature = "parallel"))]
+impl<T> ParallelSlice<T> for [T] {
+    fn par_iter(&self) -> std::slice::Iter<'_, T> {
+        self.iter()
+    }
+
+    fn par_chunks(&self, chunk_size: usize) -> std::slice::Chunks<'_, T> {
+        self.chunks(chunk_size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn results_are_consumed_in_order() {
+        let mut consumed = vec![];
+        for_each_in_order(
+            64,
+            |i| {
+                // Early indices take longest so later ones finish first.
+                std::thread::sleep(Duration::from_micros(((64 - i) * 50) as u64));
+                i * i
+            },
+            |i, v| consumed.push((i, v)),
+        );
+        let expected: Vec<(usize, usize)> = (0..64).map(|i| (i, i * i)).collect();
+        assert_eq!(consumed, expected);
+    }
+
+    #[test]
+    fn atomic_floats_add_from_many_threads() {
+        let v = AtomicFloat::new(1.0);
+        (0..1000).into_par_iter().for_each(|_| v.add(0.5));
+        assert_eq!(v.load(), 501.0);
+        v.store(-2.0);
+        assert_eq!(v.load(), -2.0);
+    }
+}
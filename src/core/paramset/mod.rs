@@ -1,23 +1,53 @@
 //! Parameter Sets
 
 #![allow(dead_code)]
+use crate::core::error::*;
 use crate::core::fileutil::*;
 use crate::core::geometry::*;
 use crate::core::pbrt::*;
 use crate::core::spectrum::*;
 use crate::core::texture::*;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
+use std::sync::{Arc, Mutex};
 
 mod paramset_item;
+mod paramset_serde;
 mod texture_params;
 
 // Re-export
 pub use paramset_item::*;
 pub use texture_params::*;
 
+lazy_static! {
+    /// Caches spectra loaded from files by their absolute paths so that files
+    /// referenced by multiple parameters are only read once.
+    static ref CACHED_SPECTRA: Mutex<HashMap<String, Spectrum>> = Mutex::new(HashMap::new());
+
+    /// Interned parameter names. Scenes use a small set of names over and
+    /// over so parameter sets share them instead of allocating new strings.
+    static ref PARAM_NAMES: Mutex<HashSet<Arc<str>>> = Mutex::new(HashSet::new());
+}
+
 /// A hashmap of parameter sets stored by name.
-pub type ParamSetMap<T> = HashMap<String, ParamSetItem<T>>;
+pub type ParamSetMap<T> = HashMap<Arc<str>, ParamSetItem<T>>;
+
+/// Returns the shared copy of a parameter name.
+///
+/// * `name` - Parameter name.
+pub fn intern_param_name(name: &str) -> Arc<str> {
+    let mut names = PARAM_NAMES
+        .lock()
+        .expect("Unable to access parameter names mutex");
+    match names.get(name) {
+        Some(interned) => interned.clone(),
+        None => {
+            let interned: Arc<str> = Arc::from(name);
+            names.insert(interned.clone());
+            interned
+        }
+    }
+}
 
 /// Stores parameter set items of different types in hashmaps.
 #[derive(Clone)]
@@ -33,7 +63,6 @@ pub struct ParamSet {
     pub spectra: ParamSetMap<Spectrum>,
     pub strings: ParamSetMap<String>,
     pub textures: ParamSetMap<String>,
-    pub cached_spectra: HashMap<String, Spectrum>,
 }
 
 /// Define a macro that can be used to generate a function for adding/replacing
@@ -41,8 +70,8 @@ pub struct ParamSet {
 macro_rules! paramset_add {
     ($func: ident, $t: ty, $paramset: ident) => {
         pub fn $func(&mut self, name: &str, values: &[$t]) {
-            let n = String::from(name);
-            self.$paramset.insert(n, ParamSetItem::new(values.to_vec()));
+            self.$paramset
+                .insert(intern_param_name(name), ParamSetItem::new(values.to_vec()));
         }
     };
 }
@@ -52,8 +81,7 @@ macro_rules! paramset_add {
 macro_rules! paramset_erase {
     ($func: ident, $paramset: ident) => {
         pub fn $func(&mut self, name: &str) -> bool {
-            let n = String::from(name);
-            self.$paramset.remove(&n).is_some()
+            self.$paramset.remove(name).is_some()
         }
     };
 }
@@ -63,35 +91,40 @@ macro_rules! paramset_erase {
 macro_rules! paramset_find_one {
     ($func: ident, $t: ty, $paramset: ident) => {
         pub fn $func(&self, name: &str, default: $t) -> $t {
-            let n = String::from(name);
-            match self.$paramset.get(&n) {
-                Some(param) => {
-                    if param.values.len() == 1 {
-                        param.values[0].clone()
-                    } else {
-                        default.clone()
-                    }
-                }
-                None => default.clone(),
+            match self.$paramset.get(name) {
+                Some(param) if param.values.len() == 1 => param.values[0].clone(),
+                _ => default,
             }
         }
     };
 }
 
 /// Define a macro that can be used to generate a function for finding
-/// parameter set item that is stored as a list.
+/// parameter set item that is stored as a list. The values are borrowed; the
+/// slice is empty if the parameter is not found.
 macro_rules! paramset_find {
     ($func: ident, $t: ty, $paramset: ident) => {
-        pub fn $func(&self, name: &str) -> Vec<$t> {
-            let n = String::from(name);
-            match self.$paramset.get(&n) {
-                Some(param) => param.values.clone(),
-                None => vec![],
+        pub fn $func(&self, name: &str) -> &[$t] {
+            match self.$paramset.get(name) {
+                Some(param) => &param.values,
+                None => &[],
             }
         }
     };
 }
 
+/// Define a macro that can be used to generate a function for iterating over
+/// the names and values of parameter set items without copying them.
+macro_rules! paramset_iter {
+    ($func: ident, $t: ty, $paramset: ident) => {
+        pub fn $func(&self) -> impl Iterator<Item = (&str, &[$t])> {
+            self.$paramset
+                .iter()
+                .map(|(name, param)| (&**name, &*param.values))
+        }
+    };
+}
+
 /// Define a macro that can be used to print parameter set items.
 macro_rules! display_param {
     ($params: expr, $param_type: literal, $formatter: expr) => {
@@ -141,74 +174,91 @@ impl ParamSet {
             spectra: HashMap::new(),
             strings: HashMap::new(),
             textures: HashMap::new(),
-            cached_spectra: HashMap::new(),
         }
     }
 
     paramset_erase!(erase_int, ints);
     paramset_find_one!(find_one_int, Int, ints);
     paramset_find!(find_int, Int, ints);
+    paramset_iter!(iter_ints, Int, ints);
     paramset_add!(add_int, Int, ints);
 
     paramset_erase!(erase_bool, bools);
     paramset_find_one!(find_one_bool, bool, bools);
     paramset_find!(find_bool, bool, bools);
+    paramset_iter!(iter_bools, bool, bools);
     paramset_add!(add_bool, bool, bools);
 
     paramset_erase!(erase_float, floats);
     paramset_find_one!(find_one_float, Float, floats);
     paramset_find!(find_float, Float, floats);
+    paramset_iter!(iter_floats, Float, floats);
     paramset_add!(add_float, Float, floats);
 
     paramset_erase!(erase_point2f, point2fs);
     paramset_find_one!(find_one_point2f, Point2f, point2fs);
     paramset_find!(find_point2f, Point2f, point2fs);
+    paramset_iter!(iter_point2fs, Point2f, point2fs);
     paramset_add!(add_point2f, Point2f, point2fs);
 
     paramset_erase!(erase_vector2f, vector2fs);
     paramset_find_one!(find_one_vector2f, Vector2f, vector2fs);
     paramset_find!(find_vector2f, Vector2f, vector2fs);
+    paramset_iter!(iter_vector2fs, Vector2f, vector2fs);
     paramset_add!(add_vector2f, Vector2f, vector2fs);
 
     paramset_erase!(erase_point3f, point3fs);
     paramset_find_one!(find_one_point3f, Point3f, point3fs);
     paramset_find!(find_point3f, Point3f, point3fs);
+    paramset_iter!(iter_point3fs, Point3f, point3fs);
     paramset_add!(add_point3f, Point3f, point3fs);
 
     paramset_erase!(erase_vector3f, vector3fs);
     paramset_find_one!(find_one_vector3f, Vector3f, vector3fs);
     paramset_find!(find_vector3f, Vector3f, vector3fs);
+    paramset_iter!(iter_vector3fs, Vector3f, vector3fs);
     paramset_add!(add_vector3f, Vector3f, vector3fs);
 
     paramset_erase!(erase_normal3f, normal3fs);
     paramset_find_one!(find_one_normal3f, Normal3f, normal3fs);
     paramset_find!(find_normal3f, Normal3f, normal3fs);
+    paramset_iter!(iter_normal3fs, Normal3f, normal3fs);
     paramset_add!(add_normal3f, Normal3f, normal3fs);
 
     paramset_erase!(erase_string, strings);
     paramset_find_one!(find_one_string, String, strings);
     paramset_find!(find_string, String, strings);
+    paramset_iter!(iter_strings, String, strings);
     paramset_add!(add_string, String, strings);
 
     paramset_erase!(erase_texture, textures);
     paramset_find_one!(find_one_texture, String, textures);
     paramset_find!(find_texture, String, textures);
+    paramset_iter!(iter_textures, String, textures);
     paramset_add!(add_texture, String, textures);
 
     paramset_erase!(erase_spectrum, spectra);
     paramset_find_one!(find_one_spectrum, Spectrum, spectra);
     paramset_find!(find_spectrum, Spectrum, spectra);
+    paramset_iter!(iter_spectra, Spectrum, spectra);
+    paramset_add!(add_spectrum, Spectrum, spectra);
 
-    /// Add/replace an RGB spectrum.
+    /// Add/replace an RGB spectrum; or returns an error if the number of
+    /// values is not a multiple of 3.
     ///
     /// * `name`   - Parameter name.
     /// * `values` - RGB values in a linear slice.
-    pub fn add_rgb_spectrum(&mut self, name: &str, values: &[Float]) {
+    pub fn add_rgb_spectrum(&mut self, name: &str, values: &[Float]) -> Result<(), Error> {
         let n = values.len();
-        assert!(n % 3 == 0, "RGB spectrum values % 3 != 0");
+        if n % 3 != 0 {
+            return Err(Error::InvalidParameter(format!(
+                "RGB spectrum '{}' has {} values; expected a multiple of 3.",
+                name, n
+            )));
+        }
 
         self.spectra.insert(
-            String::from(name),
+            intern_param_name(name),
             ParamSetItem::new(
                 (0..n)
                     .step_by(3)
@@ -216,18 +266,25 @@ impl ParamSet {
                     .collect(),
             ),
         );
+        Ok(())
     }
 
-    /// Add/replace an XYZ spectrum.
+    /// Add/replace an XYZ spectrum; or returns an error if the number of
+    /// values is not a multiple of 3.
     ///
     /// * `name`   - Parameter name.
     /// * `values` - XYZ values in a linear slice.
-    pub fn add_xyz_spectrum(&mut self, name: &str, values: &[Float]) {
+    pub fn add_xyz_spectrum(&mut self, name: &str, values: &[Float]) -> Result<(), Error> {
         let n = values.len();
-        assert!(n % 3 == 0, "XYZ spectrum values % 3 != 0");
+        if n % 3 != 0 {
+            return Err(Error::InvalidParameter(format!(
+                "XYZ spectrum '{}' has {} values; expected a multiple of 3.",
+                name, n
+            )));
+        }
 
         self.spectra.insert(
-            String::from(name),
+            intern_param_name(name),
             ParamSetItem::new(
                 (0..n)
                     .step_by(3)
@@ -235,15 +292,22 @@ impl ParamSet {
                     .collect(),
             ),
         );
+        Ok(())
     }
 
-    /// Add/replace a blackbody spectrum.
+    /// Add/replace a blackbody spectrum; or returns an error if the number of
+    /// values is not a multiple of 2.
     ///
     /// * `name`   - Parameter name.
     /// * `values` - List of (temperature (Kelvin), scale) values in a linear array.
-    pub fn add_blackbody_spectrum(&mut self, name: &str, values: &[Float]) {
+    pub fn add_blackbody_spectrum(&mut self, name: &str, values: &[Float]) -> Result<(), Error> {
         let n = values.len();
-        assert!(n % 2 == 0, "Blackbody spectrum values % 2 != 0");
+        if n % 2 != 0 {
+            return Err(Error::InvalidParameter(format!(
+                "Blackbody spectrum '{}' has {} values; expected a multiple of 2.",
+                name, n
+            )));
+        }
 
         let lambda = CIE::lambda();
         let spectra: Vec<Spectrum> = (0..n)
@@ -261,76 +325,138 @@ impl ParamSet {
             .collect();
 
         self.spectra
-            .insert(String::from(name), ParamSetItem::new(spectra));
+            .insert(intern_param_name(name), ParamSetItem::new(spectra));
+        Ok(())
     }
 
-    /// Add/replace a sampled spectrum.
+    /// Add/replace a sampled spectrum; or returns an error if there are no
+    /// values or the number of values is not a multiple of 2.
     ///
     /// * `name`   - Parameter name.
     /// * `values` - List of (wavelength, sample) values in a linear array.
-    pub fn add_sampled_spectrum(&mut self, name: &str, values: &[Float]) {
+    pub fn add_sampled_spectrum(&mut self, name: &str, values: &[Float]) -> Result<(), Error> {
+        let n = values.len();
+        if n == 0 || n % 2 != 0 {
+            return Err(Error::InvalidParameter(format!(
+                "Sampled spectrum '{}' has {} values; expected a non-zero multiple of 2.",
+                name, n
+            )));
+        }
+
         let samples = Sample::list(values);
         let spectra = vec![Spectrum::from(&samples)];
         self.spectra
-            .insert(String::from(name), ParamSetItem::new(spectra));
+            .insert(intern_param_name(name), ParamSetItem::new(spectra));
+        Ok(())
     }
 
-    /// Add/replace a spectra from files.
+    /// Add/replace a spectra from files. Each file contains (wavelength, value)
+    /// pairs as read by `read_float_file()`. Names of built-in spectral datasets
+    /// (see `named_spectrum()`) can be used in place of file paths, and the
+    /// built-in data is used for missing standard pbrt `.spd` files (see
+    /// `standard_spd_name()`).
     ///
     /// * `name`  - Parameter name.
-    /// * `paths` - List of paths to the data files.
+    /// * `paths` - List of paths to the data files or built-in dataset names.
     pub fn add_sampled_spectrum_files(&mut self, name: &str, paths: &[String]) {
         let mut spectra: Vec<Spectrum> = vec![];
 
         for path in paths {
-            match absolute_path(path) {
-                Ok(abs_path) => {
-                    if let Some(spectrum) = self.cached_spectra.get(&abs_path) {
-                        spectra.push(spectrum.clone());
+            if let Some(spectrum) = named_spectrum(path) {
+                spectra.push(spectrum);
+                continue;
+            }
+
+            let abs_path = match find_file(path) {
+                Ok(abs_path) => abs_path,
+                Err(err) => {
+                    if let Some(named) = standard_spd_name(path) {
+                        warn!(
+                            "Spectrum file '{}' not found. Using built-in data '{}'.",
+                            path, named
+                        );
+                        spectra.extend(named_spectrum(named));
                         continue;
                     }
-                    /*
-                    match read_float_file(path) {
-                        Ok(values) => {
-                            let samples = Sample::list(&values);
-                            spectra.push(Spectrum::from(&samples));
-                        }
-                        Err(err) => {
-                            error!(
-                                "Error reading {}. Using black distribution.\n{}.",
-                                path, err
-                            );
-                            spectra.push(Spectrum::new(0.0));
-                        }
-                    } */
-                }
-                Err(err) => {
                     error!(
                         "Error reading {}. Using black distribution.\n{}.",
                         path, err
                     );
                     spectra.push(Spectrum::new(0.0));
+                    continue;
+                }
+            };
+
+            let mut cached_spectra = CACHED_SPECTRA
+                .lock()
+                .expect("Unable to access cached spectra mutex");
+            if let Some(spectrum) = cached_spectra.get(&abs_path) {
+                spectra.push(*spectrum);
+                continue;
+            }
+
+            match read_float_file(&abs_path) {
+                Ok(mut values) => {
+                    if values.len() % 2 != 0 {
+                        warn!(
+                            "Extra value found in spectrum file '{}'. Ignoring it.",
+                            path
+                        );
+                        values.pop();
+                    }
+                    let samples = Sample::list(&values);
+                    if samples.is_empty() {
+                        error!(
+                            "No samples in spectrum file '{}'. Using black distribution.",
+                            path
+                        );
+                        spectra.push(Spectrum::new(0.0));
+                    } else {
+                        let spectrum = Spectrum::from(&samples);
+                        cached_spectra.insert(abs_path, spectrum);
+                        spectra.push(spectrum);
+                    }
+                }
+                Err(err) => {
+                    error!("{} Using black distribution.", err);
+                    spectra.push(Spectrum::new(0.0));
                 }
             }
         }
 
         self.spectra
-            .insert(String::from(name), ParamSetItem::new(spectra));
+            .insert(intern_param_name(name), ParamSetItem::new(spectra));
     }
 
-    /// Finds a filename and returns the absolute path to the file.
+    /// Finds a filename and returns the absolute path to the file, looking up
+    /// relative paths in the search paths if needed (see `find_file()`).
     ///
     /// * `name`    - Parameter name.
     /// * `default` - Default file to use.
     pub fn find_one_filename(&self, name: &str, default: String) -> String {
-        let filename = self.find_one_string(name, String::from(""));
-        if filename.len() == 0 {
-            default
-        } else {
-            absolute_path(&filename).map_or(default, |s| s)
+        match self.find_string(name) {
+            [filename] if !filename.is_empty() => find_file(filename).unwrap_or(default),
+            _ => default,
         }
     }
 
+    /// Returns whether a parameter of any type is given.
+    ///
+    /// * `name` - Parameter name.
+    pub fn contains(&self, name: &str) -> bool {
+        self.bools.contains_key(name)
+            || self.ints.contains_key(name)
+            || self.floats.contains_key(name)
+            || self.point2fs.contains_key(name)
+            || self.vector2fs.contains_key(name)
+            || self.point3fs.contains_key(name)
+            || self.vector3fs.contains_key(name)
+            || self.normal3fs.contains_key(name)
+            || self.spectra.contains_key(name)
+            || self.strings.contains_key(name)
+            || self.textures.contains_key(name)
+    }
+
     /// Clear all parameter set items.
     pub fn clear(&mut self) {
         self.bools.clear();
@@ -344,7 +470,6 @@ impl ParamSet {
         self.spectra.clear();
         self.strings.clear();
         self.textures.clear();
-        self.cached_spectra.clear();
     }
 }
 
@@ -365,3 +490,79 @@ impl fmt::Display for ParamSet {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_borrows_values_and_names_are_shared() {
+        let mut ps = ParamSet::new();
+        ps.add_float("radius", &[2.0]);
+        ps.add_int("indices", &[0, 1, 2]);
+
+        let copy = ps.clone();
+        assert!(Arc::ptr_eq(
+            &ps.ints["indices"].values,
+            &copy.ints["indices"].values
+        ));
+        assert_eq!(copy.find_int("indices"), &[0, 1, 2]);
+        assert!(copy.find_int("missing").is_empty());
+        assert_eq!(copy.find_one_float("radius", 1.0), 2.0);
+        assert_eq!(copy.find_one_int("indices", 7), 7);
+
+        let mut other = ParamSet::new();
+        other.add_float("radius", &[3.0]);
+        let (name, _) = ps.floats.iter().next().unwrap();
+        let (other_name, _) = other.floats.iter().next().unwrap();
+        assert!(Arc::ptr_eq(name, other_name));
+
+        let ints: Vec<(&str, &[Int])> = ps.iter_ints().collect();
+        assert_eq!(ints, vec![("indices", &[0, 1, 2][..])]);
+    }
+    #[test]
+    fn malformed_spectra_are_rejected() {
+        let mut ps = ParamSet::new();
+        assert!(ps.add_rgb_spectrum("Kd", &[0.5, 0.5]).is_err());
+        assert!(ps.add_xyz_spectrum("Kd", &[0.5; 4]).is_err());
+        assert!(ps.add_blackbody_spectrum("L", &[6500.0]).is_err());
+        assert!(ps.add_sampled_spectrum("eta", &[]).is_err());
+        assert!(ps
+            .add_sampled_spectrum("eta", &[400.0, 1.5, 500.0])
+            .is_err());
+        assert!(ps.find_spectrum("Kd").is_empty());
+        assert!(ps.find_spectrum("eta").is_empty());
+
+        assert!(ps
+            .add_sampled_spectrum("eta", &[400.0, 1.5, 500.0, 1.4])
+            .is_ok());
+        assert_eq!(ps.find_spectrum("eta").len(), 1);
+    }
+
+    #[test]
+    fn spectra_are_read_from_spd_files() {
+        let path = std::env::temp_dir().join("pbr_rust_spectrum_file.spd");
+        std::fs::write(
+            &path,
+            "# Wavelength (nm) eta\n400.0 1.5\n500.0 1.4  # visible\n\n600.0 1.3\n",
+        )
+        .unwrap();
+
+        let mut ps = ParamSet::new();
+        ps.add_sampled_spectrum_files("eta", &[String::from(path.to_str().unwrap())]);
+        ps.add_sampled_spectrum("expected", &[400.0, 1.5, 500.0, 1.4, 600.0, 1.3])
+            .unwrap();
+        assert_eq!(
+            ps.find_one_spectrum("eta", Spectrum::new(0.0)).to_rgb(),
+            ps.find_one_spectrum("expected", Spectrum::new(0.0))
+                .to_rgb()
+        );
+
+        // Missing standard pbrt files fall back to the built-in data.
+        ps.add_sampled_spectrum_files("k", &[String::from("missing/spds/metals/Au.k.spd")]);
+        assert_eq!(
+            ps.find_one_spectrum("k", Spectrum::new(0.0)).to_rgb(),
+            named_spectrum("metal-Au-k").unwrap().to_rgb()
+        );
+    }
+}
@@ -2,19 +2,23 @@
 
 #![allow(dead_code)]
 use std::fmt;
+use std::sync::Arc;
 
 /// Stores a parameter set item consisting of a list of values of type `T`.
+/// The values are shared so that cloning a `ParamSet` doesn't copy them.
 #[derive(Clone, Default)]
 pub struct ParamSetItem<T: fmt::Display> {
     /// The values.
-    pub values: Vec<T>,
+    pub values: Arc<[T]>,
 }
 
 impl<T: fmt::Display> ParamSetItem<T> {
     /// Create new `ParamSet<T>`.
     /// * `values`    - The values.
     pub fn new(values: Vec<T>) -> Self {
-        Self { values }
+        Self {
+            values: values.into(),
+        }
     }
 }
 
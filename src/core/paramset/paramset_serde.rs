@@ -0,0 +1,436 @@
+//! Parameter Set Serialization
+
+#![allow(dead_code)]
+use super::*;
+use serde::de::{self, MapAccess, Visitor};
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Serializes a parameter set as a map from the parameter declarations of
+/// the PBRT file format, e.g. `"float radius"`, to lists of values. Points,
+/// vectors and normals are flattened into lists of floats. Spectra are
+/// written without loss; as `"rgb"` values with RGB spectra and as
+/// `"spectrum"` (wavelength, value) pairs of their samples with sampled
+/// spectra, in a list per spectrum if there are several. Parameters are
+/// sorted by name so the output is stable.
+impl Serialize for ParamSet {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut entries: Vec<(String, Vec<ParamValue>)> = vec![];
+        let mut add = |param_type: &str, name: &str, values: Vec<ParamValue>| {
+            entries.push((format!("{} {}", param_type, name), values));
+        };
+
+        for (name, values) in self.iter_bools() {
+            add(
+                "bool",
+                name,
+                values.iter().map(|v| ParamValue::Bool(*v)).collect(),
+            );
+        }
+        for (name, values) in self.iter_ints() {
+            add(
+                "integer",
+                name,
+                values.iter().map(|v| ParamValue::Int(*v)).collect(),
+            );
+        }
+        for (name, values) in self.iter_floats() {
+            add("float", name, floats(values.iter().copied()));
+        }
+        for (name, values) in self.iter_point2fs() {
+            add(
+                "point2",
+                name,
+                floats(values.iter().flat_map(|p| [p.x, p.y])),
+            );
+        }
+        for (name, values) in self.iter_vector2fs() {
+            add(
+                "vector2",
+                name,
+                floats(values.iter().flat_map(|v| [v.x, v.y])),
+            );
+        }
+        for (name, values) in self.iter_point3fs() {
+            add(
+                "point3",
+                name,
+                floats(values.iter().flat_map(|p| [p.x, p.y, p.z])),
+            );
+        }
+        for (name, values) in self.iter_vector3fs() {
+            add(
+                "vector3",
+                name,
+                floats(values.iter().flat_map(|v| [v.x, v.y, v.z])),
+            );
+        }
+        for (name, values) in self.iter_normal3fs() {
+            add(
+                "normal",
+                name,
+                floats(values.iter().flat_map(|n| [n.x, n.y, n.z])),
+            );
+        }
+        for (name, values) in self.iter_spectra() {
+            if cfg!(feature = "sampled-spectrum") {
+                let mut lists: Vec<Vec<ParamValue>> = values.iter().map(spectrum_samples).collect();
+                let samples = if lists.len() == 1 {
+                    lists.remove(0)
+                } else {
+                    lists.into_iter().map(ParamValue::List).collect()
+                };
+                add("spectrum", name, samples);
+            } else {
+                add("rgb", name, floats(values.iter().flat_map(|s| s.to_rgb())));
+            }
+        }
+        for (name, values) in self.iter_strings() {
+            add(
+                "string",
+                name,
+                values.iter().cloned().map(ParamValue::String).collect(),
+            );
+        }
+        for (name, values) in self.iter_textures() {
+            add(
+                "texture",
+                name,
+                values.iter().cloned().map(ParamValue::String).collect(),
+            );
+        }
+
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+        let mut map = serializer.serialize_map(Some(entries.len()))?;
+        for (key, values) in entries.iter() {
+            map.serialize_entry(key, values)?;
+        }
+        map.end()
+    }
+}
+
+/// Deserializes a parameter set from a map written by `Serialize`. All
+/// parameter types of the PBRT file format are accepted, including `"xyz"`,
+/// `"blackbody"` and `"spectrum"`, and a single value may be given without
+/// a list. Relative spectrum file paths are relative to the current
+/// directory.
+impl<'de> Deserialize<'de> for ParamSet {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(ParamSetVisitor)
+    }
+}
+
+/// Visits the entries of a serialized `ParamSet`.
+struct ParamSetVisitor;
+
+impl<'de> Visitor<'de> for ParamSetVisitor {
+    type Value = ParamSet;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a map of parameter declarations to values")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut params = ParamSet::new();
+
+        while let Some(key) = map.next_key::<String>()? {
+            let mut parts = key.split_whitespace();
+            let (param_type, name) = match (parts.next(), parts.next(), parts.next()) {
+                (Some(param_type), Some(name), None) => (param_type, name),
+                _ => {
+                    return Err(de::Error::custom(format!(
+                        "Parameter '{}' is not of the form 'type name'.",
+                        key
+                    )))
+                }
+            };
+
+            match param_type {
+                "bool" => params.add_bool(name, &map.next_value::<OneOrMany<bool>>()?.0),
+                "integer" => params.add_int(name, &map.next_value::<OneOrMany<Int>>()?.0),
+                "float" => params.add_float(name, &map.next_value::<OneOrMany<Float>>()?.0),
+                "point2" => {
+                    let v = tuples::<A, 2>(&mut map, &key)?;
+                    let values: Vec<Point2f> = v.iter().map(|p| Point2f::new(p[0], p[1])).collect();
+                    params.add_point2f(name, &values);
+                }
+                "vector2" => {
+                    let v = tuples::<A, 2>(&mut map, &key)?;
+                    let values: Vec<Vector2f> =
+                        v.iter().map(|p| Vector2f::new(p[0], p[1])).collect();
+                    params.add_vector2f(name, &values);
+                }
+                "point3" | "point" => {
+                    let v = tuples::<A, 3>(&mut map, &key)?;
+                    let values: Vec<Point3f> =
+                        v.iter().map(|p| Point3f::new(p[0], p[1], p[2])).collect();
+                    params.add_point3f(name, &values);
+                }
+                "vector3" | "vector" => {
+                    let v = tuples::<A, 3>(&mut map, &key)?;
+                    let values: Vec<Vector3f> =
+                        v.iter().map(|p| Vector3f::new(p[0], p[1], p[2])).collect();
+                    params.add_vector3f(name, &values);
+                }
+                "normal3" | "normal" => {
+                    let v = tuples::<A, 3>(&mut map, &key)?;
+                    let values: Vec<Normal3f> =
+                        v.iter().map(|p| Normal3f::new(p[0], p[1], p[2])).collect();
+                    params.add_normal3f(name, &values);
+                }
+                "rgb" | "color" => {
+                    let v = map.next_value::<OneOrMany<Float>>()?.0;
+                    params
+                        .add_rgb_spectrum(name, &v)
+                        .map_err(de::Error::custom)?;
+                }
+                "xyz" => {
+                    let v = map.next_value::<OneOrMany<Float>>()?.0;
+                    params
+                        .add_xyz_spectrum(name, &v)
+                        .map_err(de::Error::custom)?;
+                }
+                "blackbody" => {
+                    let v = map.next_value::<OneOrMany<Float>>()?.0;
+                    params
+                        .add_blackbody_spectrum(name, &v)
+                        .map_err(de::Error::custom)?;
+                }
+                "spectrum" => match map.next_value::<SpectrumValues>()? {
+                    SpectrumValues::Samples(v) => params
+                        .add_sampled_spectrum(name, &v.0)
+                        .map_err(de::Error::custom)?,
+                    SpectrumValues::SampleLists(v) => {
+                        let spectra: Vec<Spectrum> =
+                            v.iter().map(|v| Spectrum::from(&Sample::list(v))).collect();
+                        params.add_spectrum(name, &spectra);
+                    }
+                    SpectrumValues::Files(v) => params.add_sampled_spectrum_files(name, &v.0),
+                },
+                "string" => params.add_string(name, &map.next_value::<OneOrMany<String>>()?.0),
+                "texture" => params.add_texture(name, &map.next_value::<OneOrMany<String>>()?.0),
+                _ => {
+                    return Err(de::Error::custom(format!(
+                        "Parameter '{}' has unknown type '{}'.",
+                        key, param_type
+                    )))
+                }
+            }
+        }
+
+        Ok(params)
+    }
+}
+
+/// A serialized parameter value.
+#[derive(Serialize)]
+#[serde(untagged)]
+enum ParamValue {
+    Bool(bool),
+    Int(Int),
+    Float(Float),
+    String(String),
+    List(Vec<ParamValue>),
+}
+
+/// Returns serialized parameter values for floats.
+///
+/// * `values` - The floats.
+fn floats(values: impl Iterator<Item = Float>) -> Vec<ParamValue> {
+    values.map(ParamValue::Float).collect()
+}
+
+/// Returns the (wavelength, value) pairs of the samples of a spectrum as a
+/// list of floats.
+///
+/// * `s` - The spectrum.
+fn spectrum_samples(s: &Spectrum) -> Vec<ParamValue> {
+    let lambda = spectrum_sample_wavelengths();
+    floats(
+        lambda
+            .iter()
+            .zip(s.samples().iter())
+            .flat_map(|(&lambda, &v)| [lambda, v]),
+    )
+}
+
+/// Values given either as a single value or as a list.
+struct OneOrMany<T>(Vec<T>);
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for OneOrMany<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Values<T> {
+            One(T),
+            Many(Vec<T>),
+        }
+
+        match Values::deserialize(deserializer)? {
+            Values::One(v) => Ok(Self(vec![v])),
+            Values::Many(v) => Ok(Self(v)),
+        }
+    }
+}
+
+/// Values of a `"spectrum"` parameter.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum SpectrumValues {
+    /// (wavelength, value) pairs in a linear list.
+    Samples(OneOrMany<Float>),
+
+    /// A linear list of (wavelength, value) pairs for each spectrum.
+    SampleLists(Vec<Vec<Float>>),
+
+    /// Paths of spectrum files or names of built-in spectra.
+    Files(OneOrMany<String>),
+}
+
+/// Reads the next value as a list of floats and splits it into tuples; or
+/// returns an error if the number of floats is not a multiple of the tuple
+/// size.
+///
+/// * `map` - The map being deserialized.
+/// * `key` - The parameter declaration.
+fn tuples<'de, A: MapAccess<'de>, const N: usize>(
+    map: &mut A,
+    key: &str,
+) -> Result<Vec<[Float; N]>, A::Error> {
+    let values = map.next_value::<OneOrMany<Float>>()?.0;
+    if values.len() % N != 0 {
+        return Err(de::Error::custom(format!(
+            "Parameter '{}' has {} values; expected a multiple of {}.",
+            key,
+            values.len(),
+            N
+        )));
+    }
+    Ok(values
+        .chunks_exact(N)
+        .map(|c| {
+            let mut t = [0.0; N];
+            t.copy_from_slice(c);
+            t
+        })
+        .collect())
+}
+
+impl<T: fmt::Display + Serialize> Serialize for ParamSetItem<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.values.serialize(serializer)
+    }
+}
+
+impl<'de, T: fmt::Display + Deserialize<'de>> Deserialize<'de> for ParamSetItem<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Self::new(Vec::deserialize(deserializer)?))
+    }
+}
+
+/// Serialized form of `TextureParams`. Textures are referenced by name in
+/// the parameter sets so only those are kept.
+#[derive(Serialize, Deserialize)]
+struct SerializedTextureParams<P> {
+    geom_params: P,
+    mat_params: P,
+}
+
+/// Serializes the geometry and material parameters. The textures aren't
+/// serialized.
+impl Serialize for TextureParams {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SerializedTextureParams {
+            geom_params: &self.geom_params,
+            mat_params: &self.mat_params,
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Deserializes the geometry and material parameters. The textures are
+/// empty.
+impl<'de> Deserialize<'de> for TextureParams {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let p = SerializedTextureParams::<ParamSet>::deserialize(deserializer)?;
+        Ok(Self::new(
+            p.geom_params,
+            p.mat_params,
+            HashMap::new(),
+            HashMap::new(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn param_sets_round_trip_through_json() {
+        let mut params = ParamSet::new();
+        params.add_bool("twosided", &[true]);
+        params.add_int("indices", &[0, 1, 2]);
+        params.add_float("radius", &[1.5]);
+        params.add_point3f(
+            "P",
+            &[Point3f::new(1.0, 2.0, 3.0), Point3f::new(4.0, 5.0, 6.0)],
+        );
+        params.add_normal3f("N", &[Normal3f::new(0.0, 0.0, 1.0)]);
+        params.add_point2f("uv", &[Point2f::new(0.25, 0.75)]);
+        params.add_rgb_spectrum("Kd", &[0.25, 0.5, 0.75]).unwrap();
+        params.add_string("filename", &[String::from("mesh.ply")]);
+        params.add_texture("bumpmap", &[String::from("bumps")]);
+
+        let json = serde_json::to_string(&params).unwrap();
+        assert!(json.starts_with(r#"{"bool twosided":[true],"float radius":[1.5]"#));
+
+        let read: ParamSet = serde_json::from_str(&json).unwrap();
+        assert_eq!(read.find_bool("twosided"), [true]);
+        assert_eq!(read.find_int("indices"), [0, 1, 2]);
+        assert_eq!(read.find_float("radius"), [1.5]);
+        assert_eq!(read.find_point3f("P"), params.find_point3f("P"));
+        assert_eq!(read.find_normal3f("N"), params.find_normal3f("N"));
+        assert_eq!(read.find_point2f("uv"), params.find_point2f("uv"));
+        assert_eq!(
+            read.find_one_spectrum("Kd", Spectrum::new(0.0)).samples(),
+            params.find_one_spectrum("Kd", Spectrum::new(0.0)).samples()
+        );
+        assert_eq!(read.find_string("filename"), ["mesh.ply"]);
+        assert_eq!(read.find_texture("bumpmap"), ["bumps"]);
+    }
+
+    #[test]
+    fn single_values_and_aliases_are_accepted() {
+        let read: ParamSet = serde_json::from_str(
+            r#"{"float fov": 45, "point from": [0, 1, 2], "color L": [1, 1, 1]}"#,
+        )
+        .unwrap();
+        assert_eq!(read.find_one_float("fov", 0.0), 45.0);
+        assert_eq!(
+            read.find_one_point3f("from", Point3f::default()),
+            Point3f::new(0.0, 1.0, 2.0)
+        );
+        assert_eq!(read.find_spectrum("L").len(), 1);
+
+        assert!(serde_json::from_str::<ParamSet>(r#"{"point3 P": [0, 1]}"#).is_err());
+        assert!(serde_json::from_str::<ParamSet>(r#"{"matrix M": [1]}"#).is_err());
+        assert!(serde_json::from_str::<ParamSet>(r#"{"radius": [1]}"#).is_err());
+    }
+
+    #[test]
+    fn several_spectra_round_trip_through_json() {
+        let mut params = ParamSet::new();
+        params
+            .add_rgb_spectrum("Kd", &[0.25, 0.5, 0.75, 1.0, 0.5, 0.0])
+            .unwrap();
+
+        let json = serde_json::to_string(&params).unwrap();
+        let read: ParamSet = serde_json::from_str(&json).unwrap();
+        let spectra = read.find_spectrum("Kd");
+        assert_eq!(spectra.len(), 2);
+        for (read, s) in spectra.iter().zip(params.find_spectrum("Kd").iter()) {
+            assert_eq!(read.samples(), s.samples());
+        }
+    }
+}
@@ -3,6 +3,19 @@
 #![allow(dead_code)]
 use super::*;
 use crate::core::api::{FloatTextureMap, SpectrumTextureMap};
+use crate::core::logging::PARSER;
+use crate::textures::ConstantTexture;
+use log::Level;
+
+/// Alternate names of parameters used by scenes written for other versions of
+/// pbrt, e.g. pbrt-v4 calls the diffuse reflectance of materials `reflectance`
+/// instead of `Kd`. A parameter that isn't given is looked up under these
+/// names instead of falling back to its default.
+pub const PARAMETER_ALIASES: &[(&str, &[&str])] = &[
+    ("Kd", &["reflectance"]),
+    ("Kt", &["transmittance"]),
+    ("bumpmap", &["displacement"]),
+];
 
 /// Stores texture, geometry and material parameters of different types in hashmaps.
 #[derive(Clone)]
@@ -25,6 +38,7 @@ pub struct TextureParams {
 macro_rules! texture_params_find {
     ($func: ident, $t: ty, $paramset_func: ident) => {
         pub fn $func(&self, name: &str, mat_default: $t) -> $t {
+            let name = self.resolve_name(name);
             let default = self.mat_params.$paramset_func(name, mat_default);
             self.geom_params.$paramset_func(name, default)
         }
@@ -52,14 +66,73 @@ impl TextureParams {
         }
     }
 
-    /// Returns a floating point texture.
+    /// Returns the name a parameter is given under: its own name if the
+    /// geometry or material parameters have it; otherwise the first of its
+    /// alternate names in `PARAMETER_ALIASES` that they have, with a
+    /// deprecation warning.
+    ///
+    /// * `name` - Parameter name.
+    pub fn resolve_name<'a>(&self, name: &'a str) -> &'a str {
+        if self.geom_params.contains(name) || self.mat_params.contains(name) {
+            return name;
+        }
+
+        let aliases = PARAMETER_ALIASES
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map_or(&[][..], |(_, aliases)| aliases);
+        for alias in aliases.iter() {
+            if self.geom_params.contains(alias) || self.mat_params.contains(alias) {
+                log_limited!(
+                    Level::Warn,
+                    target: PARSER,
+                    "Parameter '{}' is deprecated; use '{}' instead.",
+                    alias,
+                    name
+                );
+                return alias;
+            }
+        }
+        name
+    }
+
+    /// Returns the floating point texture for a parameter; or `None` if the
+    /// parameter isn't given. A `texture` parameter refers to a named texture
+    /// and a `float` parameter is promoted to a `ConstantTexture`. The
+    /// geometry parameters take precedence over the material parameters.
     ///
     /// * `name` - Parameter name.
-    pub fn get_float_texture(&self, name: &str) -> Option<ArcTexture<Float>> {
-        self.float_textures.get(&String::from(name)).cloned()
+    pub fn get_float_texture_or_none(&self, name: &str) -> Option<ArcTexture<Float>> {
+        let name = self.resolve_name(name);
+        for params in [&self.geom_params, &self.mat_params].iter() {
+            if let [tex_name] = params.find_texture(name) {
+                let texture = self.float_textures.get(tex_name).cloned();
+                if texture.is_none() {
+                    error!(
+                        "Couldn't find float texture named '{}' for parameter '{}'.",
+                        tex_name, name
+                    );
+                }
+                return texture;
+            }
+            if let [value] = params.find_float(name) {
+                return Some(Arc::new(ConstantTexture::new(*value)));
+            }
+        }
+        None
     }
 
-    /// Returns a floating point texture or a default texture if not found.
+    /// Returns the floating point texture for a parameter or a
+    /// `ConstantTexture` with a default value if the parameter isn't given.
+    ///
+    /// * `name`    - Parameter name.
+    /// * `default` - Default value.
+    pub fn get_float_texture(&self, name: &str, default: Float) -> ArcTexture<Float> {
+        self.get_float_texture_or_else(name, Arc::new(ConstantTexture::new(default)))
+    }
+
+    /// Returns the floating point texture for a parameter or a default
+    /// texture if the parameter isn't given.
     ///
     /// * `name`    - Parameter name.
     /// * `default` - Default texture.
@@ -68,19 +141,50 @@ impl TextureParams {
         name: &str,
         default: ArcTexture<Float>,
     ) -> ArcTexture<Float> {
-        self.float_textures
-            .get(&String::from(name))
-            .map_or(default.clone(), |v| v.clone())
+        self.get_float_texture_or_none(name).unwrap_or(default)
     }
 
-    /// Returns a spectrum point texture.
+    /// Returns the spectrum texture for a parameter; or `None` if the
+    /// parameter isn't given. A `texture` parameter refers to a named texture
+    /// and a spectrum parameter, e.g. `rgb`, is promoted to a
+    /// `ConstantTexture`; so is a `float` parameter, as a constant spectrum.
+    /// The geometry parameters take precedence over the material parameters.
     ///
     /// * `name` - Parameter name.
-    pub fn get_spectrum_texture(&self, name: &str) -> Option<ArcTexture<Spectrum>> {
-        self.spectrum_textures.get(&String::from(name)).cloned()
+    pub fn get_spectrum_texture_or_none(&self, name: &str) -> Option<ArcTexture<Spectrum>> {
+        let name = self.resolve_name(name);
+        for params in [&self.geom_params, &self.mat_params].iter() {
+            if let [tex_name] = params.find_texture(name) {
+                let texture = self.spectrum_textures.get(tex_name).cloned();
+                if texture.is_none() {
+                    error!(
+                        "Couldn't find spectrum texture named '{}' for parameter '{}'.",
+                        tex_name, name
+                    );
+                }
+                return texture;
+            }
+            if let [value] = params.find_spectrum(name) {
+                return Some(Arc::new(ConstantTexture::new(*value)));
+            }
+            if let [value] = params.find_float(name) {
+                return Some(Arc::new(ConstantTexture::new(Spectrum::new(*value))));
+            }
+        }
+        None
     }
 
-    /// Returns a spectrum point texture or a default texture if not found.
+    /// Returns the spectrum texture for a parameter or a `ConstantTexture`
+    /// with a default value if the parameter isn't given.
+    ///
+    /// * `name`    - Parameter name.
+    /// * `default` - Default value.
+    pub fn get_spectrum_texture(&self, name: &str, default: Spectrum) -> ArcTexture<Spectrum> {
+        self.get_spectrum_texture_or_else(name, Arc::new(ConstantTexture::new(default)))
+    }
+
+    /// Returns the spectrum texture for a parameter or a default texture if
+    /// the parameter isn't given.
     ///
     /// * `name`    - Parameter name.
     /// * `default` - Default texture.
@@ -89,9 +193,7 @@ impl TextureParams {
         name: &str,
         default: ArcTexture<Spectrum>,
     ) -> ArcTexture<Spectrum> {
-        self.spectrum_textures
-            .get(&String::from(name))
-            .map_or(default.clone(), |v| v.clone())
+        self.get_spectrum_texture_or_none(name).unwrap_or(default)
     }
 
     texture_params_find!(find_float, Float, find_one_float);
@@ -116,3 +218,81 @@ impl Default for TextureParams {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parameters_are_found_under_their_alternate_names() {
+        let mut v4 = ParamSet::new();
+        v4.add_rgb_spectrum("reflectance", &[0.1, 0.2, 0.3])
+            .unwrap();
+        v4.add_float("roughness", &[0.25]);
+        let tp = TextureParams::new(v4.clone(), ParamSet::new(), HashMap::new(), HashMap::new());
+        assert_eq!(tp.resolve_name("Kd"), "reflectance");
+        assert_eq!(tp.resolve_name("roughness"), "roughness");
+        assert_eq!(
+            tp.find_spectrum("Kd", Spectrum::new(0.5)).to_rgb(),
+            Spectrum::from_rgb(&[0.1, 0.2, 0.3], None).to_rgb()
+        );
+        assert_eq!(tp.find_float("roughness", 0.0), 0.25);
+
+        // The name itself takes precedence, also over an alternate name given
+        // with the material.
+        let mut v3 = ParamSet::new();
+        v3.add_rgb_spectrum("Kd", &[0.4, 0.5, 0.6]).unwrap();
+        let tp = TextureParams::new(v3, v4, HashMap::new(), HashMap::new());
+        assert_eq!(tp.resolve_name("Kd"), "Kd");
+        assert_eq!(
+            tp.find_spectrum("Kd", Spectrum::new(0.5)).to_rgb(),
+            Spectrum::from_rgb(&[0.4, 0.5, 0.6], None).to_rgb()
+        );
+        assert_eq!(TextureParams::default().resolve_name("Kd"), "Kd");
+    }
+
+    // A constant spectrum lit by a white light is only rendered exactly gray
+    // with RGB spectra.
+    #[cfg(not(feature = "sampled-spectrum"))]
+    #[test]
+    fn constant_parameters_are_promoted_to_textures() {
+        let render = |material: &str, shape_params: &str| {
+            let scene = format!(
+                r#"
+                LookAt 0 0 5  0 0 0  0 1 0
+                Camera "perspective" "float fov" [30]
+                Film "image" "integer xresolution" [8] "integer yresolution" [8]
+                Sampler "random" "integer pixelsamples" [1]
+                Integrator "whitted"
+                WorldBegin
+                LightSource "point" "rgb I" [20 20 20] "point from" [0 0 5]
+                Texture "red" "spectrum" "constant" "rgb value" [0.8 0.1 0.1]
+                {}
+                Shape "sphere" "float radius" [1] {}
+                WorldEnd
+                "#,
+                material, shape_params
+            );
+            crate::core::api::render_to_rgba(&scene, |_, _| {}).unwrap()
+        };
+        let center = |image: &crate::core::api::RgbaImage| {
+            let i = 4 * (4 * image.width + 4);
+            [image.pixels[i], image.pixels[i + 1], image.pixels[i + 2]]
+        };
+
+        let red = render(r#"Material "matte" "rgb Kd" [0.8 0.1 0.1]"#, "");
+        let [r, g, b] = center(&red);
+        assert!(r > g && g == b);
+        assert_eq!(render(r#"Material "matte" "texture Kd" "red""#, ""), red);
+
+        let [r, g, b] = center(&render(r#"Material "matte" "float Kd" [0.8]"#, ""));
+        assert!(r > 0 && r == g && g == b);
+
+        // The shape's parameters take precedence over the material's.
+        let overridden = render(
+            r#"Material "matte" "rgb Kd" [0.1 0.8 0.1]"#,
+            r#""rgb Kd" [0.8 0.1 0.1]"#,
+        );
+        assert_eq!(overridden, red);
+    }
+}
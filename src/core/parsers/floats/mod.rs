@@ -1,5 +1,6 @@
 //! Float File Parser
 
+use crate::core::error::*;
 use crate::core::pbrt::Float;
 use pest::iterators::Pair;
 use pest::Parser;
@@ -18,7 +19,7 @@ struct FloatParser;
 /// NOTE: This will not be very efficient for extremely large files.
 ///
 /// * `path` - Path to file.
-pub fn parse_float_file(path: &str) -> Result<Vec<Float>, String> {
+pub fn parse_float_file(path: &str) -> Result<Vec<Float>, Error> {
     // Load the file and parse the `file` rule.
     let unparsed_file = file_to_string(path)?;
     let file = parse_file_rule(&unparsed_file)?;
@@ -35,10 +36,10 @@ pub fn parse_float_file(path: &str) -> Result<Vec<Float>, String> {
                     if let Ok(n) = s.parse::<Float>() {
                         v.push(n);
                     } else {
-                        return Err(format!(
+                        return Err(Error::Parse(format!(
                             "Error parsing floating point number '{}', line {}.",
                             path, line_no
-                        ));
+                        )));
                     }
                 }
             }
@@ -55,10 +56,13 @@ pub fn parse_float_file(path: &str) -> Result<Vec<Float>, String> {
 /// Read the entire file and return its contents as a String.
 ///
 /// * `path` - Path to file.
-fn file_to_string(path: &str) -> Result<String, String> {
+fn file_to_string(path: &str) -> Result<String, Error> {
     match fs::read_to_string(path) {
         Ok(s) => Ok(s),
-        _ => Err(format!("Error reading file '{}'", path)),
+        Err(err) => Err(Error::Io(format!(
+            "Error reading file '{}'. {}.",
+            path, err
+        ))),
     }
 }
 
@@ -66,9 +70,9 @@ fn file_to_string(path: &str) -> Result<String, String> {
 /// pairs for remaining rules.
 ///
 /// * `path` - Path to file.
-fn parse_file_rule(unparsed_file: &str) -> Result<Pair<'_, Rule>, String> {
+fn parse_file_rule(unparsed_file: &str) -> Result<Pair<'_, Rule>, Error> {
     match FloatParser::parse(Rule::file, &unparsed_file) {
         Ok(mut pairs) => Ok(pairs.next().unwrap()), // unwrap `file` rule never fails.
-        Err(err) => Err(format!("Error parsing file rule. {}", err)),
+        Err(err) => Err(Error::Parse(format!("Error parsing file rule. {}", err))),
     }
 }
@@ -0,0 +1,167 @@
+//! JSON Scene Parser
+
+#![allow(dead_code)]
+use crate::core::api::*;
+use crate::core::error::*;
+use std::fs;
+
+/// Parser for scenes in JSON. A scene is a list of API calls in the order a
+/// PBRT file would make them, as serialized from `ApiCall`. Calls without
+/// arguments are strings and calls with arguments are objects with the name
+/// of the call as their only key; parameters are maps from declarations to
+/// values:
+///
+/// ```json
+/// [
+///   {"LookAt": [0, 0, 5, 0, 0, 0, 0, 1, 0]},
+///   {"Camera": ["perspective", {"float fov": [30]}]},
+///   "WorldBegin",
+///   {"LightSource": ["point", {"rgb I": [20, 20, 20]}]},
+///   {"Shape": ["sphere", {"float radius": 1}]},
+///   "WorldEnd"
+/// ]
+/// ```
+pub struct JsonSceneParser {
+    /// Path to the file to parse.
+    file_path: String,
+}
+
+impl JsonSceneParser {
+    /// Returns a new instance of `JsonSceneParser`.
+    ///
+    /// * `path` - File path.
+    pub fn new(path: &str) -> Self {
+        Self {
+            file_path: String::from(path),
+        }
+    }
+
+    /// Returns a new instance of `JsonSceneParser` for a scene that is not
+    /// read from a file.
+    pub fn in_memory() -> Self {
+        Self::new("<memory>")
+    }
+
+    /// Returns whether a file should be read with this parser, i.e. whether
+    /// it has a `.json` extension.
+    ///
+    /// * `path` - File path.
+    pub fn handles(path: &str) -> bool {
+        path.to_lowercase().ends_with(".json")
+    }
+
+    /// Reads a JSON scene file and calls the API.
+    ///
+    /// * `api` - The PBRT API interface.
+    pub fn parse(&self, api: &mut Api) -> Result<(), Error> {
+        let source = file_to_string(&self.file_path)?;
+        api.record_source(&self.file_path, &source);
+        self.parse_source(&source, api)
+    }
+
+    /// Parses a scene in JSON and calls the API. No calls are made if the
+    /// scene can't be parsed.
+    ///
+    /// * `source` - The scene.
+    /// * `api`    - The PBRT API interface.
+    pub fn parse_source(&self, source: &str, api: &mut Api) -> Result<(), Error> {
+        let calls: Vec<ApiCall> = serde_json::from_str(source).map_err(|err| {
+            Error::Parse(format!("Error parsing '{}'. {}", self.file_path, err))
+        })?;
+        for call in calls {
+            api.call(call);
+        }
+        Ok(())
+    }
+}
+
+/// Read the entire file and return its contents as a String.
+///
+/// * `path` - Path to file.
+fn file_to_string(path: &str) -> Result<String, Error> {
+    match fs::read_to_string(path) {
+        Ok(s) => Ok(s),
+        Err(err) => Err(Error::Io(format!(
+            "Error reading file '{}'. {}.",
+            path, err
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_scene_renders_like_scene_file() {
+        let header = r#"
+            LookAt 0 0 5  0 0 0  0 1 0
+            Camera "perspective" "float fov" [30]
+            Film "image" "integer xresolution" [8] "integer yresolution" [8]
+            Sampler "random" "integer pixelsamples" [2]
+            Integrator "whitted" "integer maxdepth" [5]
+        "#;
+        let expected = render_to_rgba(
+            &format!(
+                "{}{}",
+                header,
+                r#"
+                WorldBegin
+                LightSource "point" "rgb I" [20 20 20] "point from" [2 2 5]
+                Material "matte" "rgb Kd" [0.8 0.3 0.2]
+                Shape "sphere" "float radius" [1]
+                WorldEnd
+                "#
+            ),
+            |_, _| {},
+        )
+        .unwrap();
+
+        let json = r#"[
+            "WorldBegin",
+            {"LightSource": ["point", {"rgb I": [20, 20, 20], "point from": [2, 2, 5]}]},
+            {"Material": ["matte", {"rgb Kd": [0.8, 0.3, 0.2]}]},
+            {"Shape": ["sphere", {"float radius": 1}]},
+            "WorldEnd"
+        ]"#;
+        let image = render_with_api_to_rgba(
+            |_, _| {},
+            |api| {
+                crate::core::parsers::PbrtFileParser::in_memory().parse_source(header, api)?;
+                JsonSceneParser::in_memory().parse_source(json, api)
+            },
+        )
+        .unwrap();
+
+        assert!(image.pixels.iter().any(|&v| v > 0));
+        assert_eq!(image, expected);
+    }
+
+    #[test]
+    fn calls_round_trip_through_json() {
+        let mut scene = SceneBuilder::new();
+        scene
+            .translate(crate::core::geometry::Vector3f::new(1.0, 2.0, 3.0))
+            .attribute(|scene| {
+                scene
+                    .material(MaterialBuilder::matte("checks"))
+                    .shape(ShapeBuilder::sphere(2.0));
+            });
+        let json = serde_json::to_string(&scene.build()).unwrap();
+        assert_eq!(
+            json,
+            concat!(
+                r#"[{"Translate":[1.0,2.0,3.0]},"WorldBegin","AttributeBegin","#,
+                r#"{"Material":["matte",{"texture Kd":["checks"]}]},"#,
+                r#"{"Shape":["sphere",{"float radius":[2.0]}]},"AttributeEnd","WorldEnd"]"#
+            )
+        );
+
+        let calls: Vec<ApiCall> = serde_json::from_str(&json).unwrap();
+        assert_eq!(serde_json::to_string(&calls).unwrap(), json);
+
+        let err = JsonSceneParser::in_memory()
+            .parse_source(r#"["WorldBegin", {"Bogus": 1}]"#, &mut Api::new());
+        assert!(matches!(err, Err(Error::Parse(_))));
+    }
+}
@@ -1,8 +1,10 @@
 //! Parsers
 
 mod floats;
+mod json;
 mod pbrt;
 
 // Re-exports.
 pub use floats::parse_float_file;
+pub use json::JsonSceneParser;
 pub use pbrt::PbrtFileParser;
@@ -3,13 +3,18 @@
 #![allow(dead_code)]
 
 use crate::core::api::*;
+use crate::core::error::*;
 use crate::core::fileutil::*;
 use crate::core::geometry::*;
+use crate::core::logging::PARSER;
 use crate::core::paramset::*;
 use crate::core::pbrt::*;
+use crate::core::spectrum::is_named_spectrum;
 use pest::iterators::*;
 use pest::Parser;
+use std::convert::TryFrom;
 use std::fs;
+use std::path::{Path, PathBuf};
 use std::result::Result;
 
 /// The `pest` parser generated from a grammar.
@@ -24,36 +29,141 @@ pub struct PbrtFileParser {
 
     /// Parent path for navigating to includes.
     parent_path: String,
+
+    /// Absolute paths of the files that include this file, outermost first.
+    include_stack: Vec<String>,
+
+    /// Restricts included and asset files to a directory; `None` if they
+    /// aren't restricted.
+    sandbox: Option<Sandbox>,
 }
 
 impl PbrtFileParser {
-    /// Returns a new instance of `PbrtFileParser`.
+    /// Returns a new instance of `PbrtFileParser`; or returns an error if the
+    /// path is not a file path.
     ///
     /// * `path` - File path.
-    pub fn new(path: &str) -> Self {
+    pub fn new(path: &str) -> Result<Self, Error> {
         if let Some(parent) = parent_path(path) {
-            Self {
+            Ok(Self {
                 file_path: String::from(path),
                 parent_path: parent,
-            }
+                include_stack: vec![],
+                sandbox: None,
+            })
         } else {
             // We were passed the root path itself which is not a file.
-            panic!("Invalid path '{}'", path);
+            Err(Error::Io(format!("Invalid path '{}'", path)))
+        }
+    }
+
+    /// Returns a new instance of `PbrtFileParser` for a scene description that
+    /// is not read from a file, e.g. one held in memory. Included files are
+    /// relative to the current directory.
+    pub fn in_memory() -> Self {
+        Self {
+            file_path: String::from("<memory>"),
+            parent_path: String::from("."),
+            include_stack: vec![],
+            sandbox: None,
+        }
+    }
+
+    /// Restricts included files and asset files such as textures and meshes
+    /// to a directory, e.g. for scene descriptions from untrusted sources.
+    /// Relative paths are relative to that directory. Returns an error if the
+    /// directory does not exist.
+    ///
+    /// * `scene_root` - Directory files must be in; `None` if no files can be
+    ///                  used.
+    pub fn sandboxed(mut self, scene_root: Option<&str>) -> Result<Self, Error> {
+        let sandbox = Sandbox::new(scene_root)?;
+        if let Some(root) = sandbox.root() {
+            self.parent_path = root.to_string_lossy().into_owned();
+        }
+        self.sandbox = Some(sandbox);
+        Ok(self)
+    }
+
+    /// Returns a parser for a file included by the file being parsed; or
+    /// returns an error if the file includes itself directly or through other
+    /// files.
+    ///
+    /// * `path` - Path of the included file; relative paths are relative to
+    ///            the directory of the file being parsed.
+    fn include(&self, path: &str) -> Result<Self, Error> {
+        let path = if is_relative_path(path) {
+            Path::new(&self.parent_path).join(path)
+        } else {
+            PathBuf::from(path)
+        };
+        let path = path.to_string_lossy().into_owned();
+
+        if let Some(sandbox) = &self.sandbox {
+            if sandbox.find_file(&path).is_err() {
+                return Err(Error::Parse(format!(
+                    "'{}' is outside the scene root and can't be included.",
+                    path
+                )));
+            }
+        }
+
+        // Files that don't exist are reported when they are parsed.
+        let mut include_stack = self.include_stack.clone();
+        if let Ok(file_path) = absolute_path(&self.file_path) {
+            include_stack.push(file_path);
+        }
+        if let Ok(included) = absolute_path(&path) {
+            if include_stack.contains(&included) {
+                return Err(Error::Parse(format!(
+                    "'{}' includes itself through '{}'.",
+                    path, self.file_path
+                )));
+            }
         }
+
+        let mut parser = Self::new(&path)?;
+        parser.include_stack = include_stack;
+        parser.sandbox = self.sandbox.clone();
+        Ok(parser)
     }
 
     /// Reads a PBRT file format and calls the API wrapper functions.
     ///
     /// * `api`  - The PBRT API interface.
-    pub fn parse(&self, api: &mut Api) -> Result<(), String> {
-        // Load the file and parse the `file` rule.
+    pub fn parse(&self, api: &mut Api) -> Result<(), Error> {
+        // Load the file and parse it.
         let unparsed_file = file_to_string(&self.file_path)?;
-        let pbrt = self.parse_pbrt_rule(&unparsed_file)?;
+        api.record_source(&self.file_path, &unparsed_file);
+        self.parse_source(&unparsed_file, api)
+    }
+
+    /// Parses a scene description in the PBRT file format and calls the API
+    /// wrapper functions. Asset files are looked up in the sandbox, if any.
+    ///
+    /// * `source` - The scene description.
+    /// * `api`    - The PBRT API interface.
+    pub fn parse_source(&self, source: &str, api: &mut Api) -> Result<(), Error> {
+        match &self.sandbox {
+            Some(sandbox) => sandbox.enter(|| self.parse_stmts(source, api)),
+            None => self.parse_stmts(source, api),
+        }
+    }
+
+    /// Parses the statements of a scene description and calls the API
+    /// wrapper functions.
+    ///
+    /// * `source` - The scene description.
+    /// * `api`    - The PBRT API interface.
+    fn parse_stmts(&self, source: &str, api: &mut Api) -> Result<(), Error> {
+        // Parse the `file` rule.
+        let pbrt = self.parse_pbrt_rule(source)?;
 
         // Parse all the `stmt` rules.
         for pair in pbrt.into_inner() {
             match pair.as_rule() {
                 Rule::stmt => {
+                    api.set_source_location(Some(self.location(&pair)));
                     let mut inner_rules = pair.into_inner();
                     self.parse_stmt_rule(&mut inner_rules, api);
                 }
@@ -61,18 +171,29 @@ impl PbrtFileParser {
                 _ => unreachable!(),
             }
         }
+        api.set_source_location(None);
 
         Ok(())
     }
 
+    /// Returns the location in the file being parsed where a token pair starts.
+    ///
+    /// * `pair` - The token pair.
+    fn location(&self, pair: &Pair<Rule>) -> SourceLocation {
+        SourceLocation::new(&self.file_path, pair.as_span().start_pos().line_col().0)
+    }
+
     /// Parse the initial `pbrt` rule of the grammar and return the resulting token
     /// pairs for remaining rules.
     ///
     /// * `unparsed_file` - Contents of the file to parse.
-    fn parse_pbrt_rule<'a>(&self, unparsed_file: &'a str) -> Result<Pair<'a, Rule>, String> {
+    fn parse_pbrt_rule<'a>(&self, unparsed_file: &'a str) -> Result<Pair<'a, Rule>, Error> {
         match PbrtParser::parse(Rule::pbrt, &unparsed_file) {
             Ok(mut pairs) => Ok(pairs.next().unwrap()),
-            Err(err) => Err(format!("Error parsing pbrt rule. {}", err)),
+            Err(err) => Err(Error::Parse(format!(
+                "Error parsing '{}'. {}",
+                self.file_path, err
+            ))),
         }
     }
 
@@ -104,21 +225,16 @@ impl PbrtFileParser {
     /// * `api`   - The PBRT API interface.
     fn parse_include_stmt(&self, pairs: &mut Pairs<Rule>, api: &mut Api) {
         let next_pair = pairs.next().unwrap();
+        let location = self.location(&next_pair);
         match next_pair.as_rule() {
             Rule::quoted_str_expr => {
                 let mut inner_rules = next_pair.into_inner();
-                let mut path = self.parse_quoted_str(&mut inner_rules);
-                debug!("Include: '{}'", path);
-
-                if is_relative_path(&path) {
-                    // Path is relative to the parent path of the file being parsed.
-                    path = self.parent_path.clone() + "/" + &path;
-                }
+                let path = self.parse_quoted_str(&mut inner_rules);
+                debug!(target: PARSER, "Include: '{}'", path);
 
-                let parser = Self::new(&path);
-                match parser.parse(api) {
-                    Ok(()) => debug!("Finished parsing include '{}'", path),
-                    Err(err) => error!("{}", err),
+                match self.include(&path).and_then(|parser| parser.parse(api)) {
+                    Ok(()) => debug!(target: PARSER, "Finished parsing include '{}'", path),
+                    Err(err) => error!(target: PARSER, "{}", err.at(Some(&location))),
                 }
             }
             _ => unreachable!(),
@@ -132,17 +248,19 @@ impl PbrtFileParser {
     fn parse_block_stmt(&self, pairs: &mut Pairs<Rule>, api: &mut Api) {
         let next_pair = pairs.next().unwrap();
         match next_pair.as_rule() {
-            Rule::world_begin_stmt => api.pbrt_world_begin(),
-            Rule::world_end_stmt => api.pbrt_world_end(),
-            Rule::attribute_begin_stmt => api.pbrt_attribute_begin(),
-            Rule::attribute_end_stmt => api.pbrt_attribute_end(),
+            Rule::world_begin_stmt => api.call(ApiCall::WorldBegin),
+            Rule::world_end_stmt => api.call(ApiCall::WorldEnd),
+            Rule::attribute_begin_stmt => api.call(ApiCall::AttributeBegin),
+            Rule::attribute_end_stmt => api.call(ApiCall::AttributeEnd),
+            Rule::transform_begin_stmt => api.call(ApiCall::TransformBegin),
+            Rule::transform_end_stmt => api.call(ApiCall::TransformEnd),
             Rule::object_begin_stmt => {
                 let mut inner_rules = next_pair.into_inner();
                 let str = self.parse_quoted_str(&mut inner_rules);
-                debug!("ObjectBegin: '{}'", str);
-                api.pbrt_object_begin(str);
+                debug!(target: PARSER, "ObjectBegin: '{}'", str);
+                api.call(ApiCall::ObjectBegin(str));
             }
-            Rule::object_end_stmt => api.pbrt_object_end(),
+            Rule::object_end_stmt => api.call(ApiCall::ObjectEnd),
             _ => unreachable!(),
         }
     }
@@ -171,6 +289,11 @@ impl PbrtFileParser {
                 self.parse_named_param_list(&mut inner_rules, "MakeNamedMedium", api)
             }
             Rule::sampler_stmt => self.parse_named_param_list(&mut inner_rules, "Sampler", api),
+            Rule::render_option_stmt => {
+                let params = self.parse_param_list(inner_rules.next().unwrap().into_inner());
+                debug!(target: PARSER, "Option {:}", params);
+                api.call(ApiCall::Option(params));
+            }
             _ => unreachable!(),
         }
     }
@@ -205,10 +328,11 @@ impl PbrtFileParser {
                     self.parse_param_list(param_list.into_inner())
                 });
                 debug!(
+                    target: PARSER,
                     "Texture: '{}', '{}', '{}' {:}",
                     name, texture_type, texture_name, params
                 );
-                api.pbrt_texture(name, texture_type, texture_name, &params)
+                api.call(ApiCall::Texture(name, texture_type, texture_name, params))
             }
             Rule::named_material_stmt => {
                 self.parse_named_stmt(&mut inner_rules, api, "NamedMaterial")
@@ -216,22 +340,31 @@ impl PbrtFileParser {
             Rule::object_instance_stmt => {
                 self.parse_named_stmt(&mut inner_rules, api, "ObjectInstance")
             }
-            Rule::reverse_orientation_stmt => api.pbrt_reverse_orientation(),
+            Rule::reverse_orientation_stmt => api.call(ApiCall::ReverseOrientation),
             Rule::medium_interface_stmt => {
-                let inside_medium = inner_rules.next().unwrap().as_str().to_string();
-                let outside_medium = inner_rules.next().unwrap().as_str().to_string();
-                debug!("MediumInterface: '{}', '{}'", inside_medium, outside_medium);
-                api.pbrt_medium_interface(inside_medium, outside_medium);
+                // A single medium is used on both sides.
+                let inside_medium = self.parse_quoted_str_expr(&mut inner_rules);
+                let outside_medium = match inner_rules.peek() {
+                    Some(_) => self.parse_quoted_str_expr(&mut inner_rules),
+                    None => inside_medium.clone(),
+                };
+                debug!(
+                    target: PARSER,
+                    "MediumInterface: '{}', '{}'",
+                    inside_medium,
+                    outside_medium
+                );
+                api.call(ApiCall::MediumInterface(inside_medium, outside_medium));
             }
             Rule::ctm_stmt => self.parse_ctm_stmt(&mut inner_rules, api),
             Rule::active_transform_stmt => {
                 let time = inner_rules.next().unwrap().as_str();
-                debug!("ActiveTransform: '{}'", time);
+                debug!(target: PARSER, "ActiveTransform: '{}'", time);
                 match time {
-                    "StartTime" => api.pbrt_active_transform_start_time(),
-                    "EndTime" => api.pbrt_active_transform_end_time(),
-                    "All" => api.pbrt_active_transform_all(),
-                    _ => warn!("Ignoring invalid ActiveTransform time '{}'", time),
+                    "StartTime" => api.call(ApiCall::ActiveTransformStartTime),
+                    "EndTime" => api.call(ApiCall::ActiveTransformEndTime),
+                    "All" => api.call(ApiCall::ActiveTransformAll),
+                    _ => warn!(target: PARSER, "Ignoring invalid ActiveTransform time '{}'", time),
                 }
             }
             _ => unreachable!(),
@@ -244,18 +377,11 @@ impl PbrtFileParser {
     /// * `api`       - The PBRT API interface.
     /// * `stmt_type` - 'NamedMaterial | ObjectInstance'
     fn parse_named_stmt(&self, pairs: &mut Pairs<Rule>, api: &mut Api, stmt_type: &str) {
-        let next_pair = pairs.next().unwrap();
-        match next_pair.as_rule() {
-            Rule::quoted_ident_expr => {
-                let mut inner_rules = next_pair.into_inner();
-                let name = self.parse_quoted_ident(&mut inner_rules);
-                debug!("{}: '{}'", stmt_type, name);
-                match stmt_type {
-                    "NamedMaterial" => api.pbrt_named_material(name),
-                    "ObjectInstance" => api.pbrt_object_instance(name),
-                    _ => unreachable!(),
-                }
-            }
+        let name = self.parse_quoted_str_expr(pairs);
+        debug!(target: PARSER, "{}: '{}'", stmt_type, name);
+        match stmt_type {
+            "NamedMaterial" => api.call(ApiCall::NamedMaterial(name)),
+            "ObjectInstance" => api.call(ApiCall::ObjectInstance(name)),
             _ => unreachable!(),
         }
     }
@@ -268,24 +394,24 @@ impl PbrtFileParser {
         let next_pair = pairs.next().unwrap();
         match next_pair.as_rule() {
             Rule::identity_stmt => {
-                debug!("Identity");
-                api.pbrt_identity();
+                debug!(target: PARSER, "Identity");
+                api.call(ApiCall::Identity);
             }
             Rule::translate_stmt => {
                 let mut inner_rules = next_pair.into_inner();
                 let x = self.parse_float(inner_rules.next().unwrap());
                 let y = self.parse_float(inner_rules.next().unwrap());
                 let z = self.parse_float(inner_rules.next().unwrap());
-                debug!("Translate: [{}, {}, {}]", x, y, z);
-                api.pbrt_translate(x, y, z);
+                debug!(target: PARSER, "Translate: [{}, {}, {}]", x, y, z);
+                api.call(ApiCall::Translate(x, y, z));
             }
             Rule::scale_stmt => {
                 let mut inner_rules = next_pair.into_inner();
                 let x = self.parse_float(inner_rules.next().unwrap());
                 let y = self.parse_float(inner_rules.next().unwrap());
                 let z = self.parse_float(inner_rules.next().unwrap());
-                debug!("Scale: [{}, {}, {}]", x, y, z);
-                api.pbrt_scale(x, y, z);
+                debug!(target: PARSER, "Scale: [{}, {}, {}]", x, y, z);
+                api.call(ApiCall::Scale(x, y, z));
             }
             Rule::rotate_stmt => {
                 let mut inner_rules = next_pair.into_inner();
@@ -293,8 +419,8 @@ impl PbrtFileParser {
                 let x = self.parse_float(inner_rules.next().unwrap());
                 let y = self.parse_float(inner_rules.next().unwrap());
                 let z = self.parse_float(inner_rules.next().unwrap());
-                debug!("Rotate: {}, [{}, {}, {}]", angle, x, y, z);
-                api.pbrt_rotate(angle, x, y, z);
+                debug!(target: PARSER, "Rotate: {}, [{}, {}, {}]", angle, x, y, z);
+                api.call(ApiCall::Rotate(angle, x, y, z));
             }
             Rule::look_at_stmt => {
                 let mut inner_rules = next_pair.into_inner();
@@ -308,53 +434,42 @@ impl PbrtFileParser {
                 let uy = self.parse_float(inner_rules.next().unwrap());
                 let uz = self.parse_float(inner_rules.next().unwrap());
                 debug!(
+                    target: PARSER,
                     "LookAt: [{}, {}, {}], [{}, {}, {}], [{}, {}, {}]",
                     ex, ey, ez, lx, ly, lz, ux, uy, uz
                 );
-                api.pbrt_look_at(ex, ey, ez, lx, ly, lz, ux, uy, uz);
+                api.call(ApiCall::LookAt([ex, ey, ez, lx, ly, lz, ux, uy, uz]));
             }
             Rule::coordinate_system_stmt => {
                 let mut inner_rules = next_pair.into_inner();
-                let name = self.parse_quoted_ident(&mut inner_rules);
-                debug!("CoordinateSystem: '{}'", name);
-                api.pbrt_coordinate_system(name);
+                let name = self.parse_quoted_str_expr(&mut inner_rules);
+                debug!(target: PARSER, "CoordinateSystem: '{}'", name);
+                api.call(ApiCall::CoordinateSystem(name));
             }
             Rule::coord_sys_transform_stmt => {
                 let mut inner_rules = next_pair.into_inner();
-                let name = self.parse_quoted_ident(&mut inner_rules);
-                debug!("CoordSysTransform: '{}'", name);
-                api.pbrt_coord_sys_transform(name);
+                let name = self.parse_quoted_str_expr(&mut inner_rules);
+                debug!(target: PARSER, "CoordSysTransform: '{}'", name);
+                api.call(ApiCall::CoordSysTransform(name));
             }
             Rule::transform_stmt => {
-                let tr = self.parse_float_list(next_pair.into_inner());
-                assert!(
-                    tr.len() == 16,
-                    "float_list in transform_stmt not of len 16."
-                );
-                debug!("Transform: {:?}", tr);
-                api.pbrt_transform(&[
-                    tr[0], tr[1], tr[2], tr[3], tr[4], tr[5], tr[6], tr[7], tr[8], tr[9], tr[10],
-                    tr[11], tr[11], tr[12], tr[13], tr[14],
-                ]);
+                if let Some(tr) = self.parse_matrix(next_pair.into_inner(), "Transform") {
+                    debug!(target: PARSER, "Transform: {:?}", tr);
+                    api.call(ApiCall::Transform(tr));
+                }
             }
             Rule::concat_transform_stmt => {
-                let tr = self.parse_float_list(next_pair.into_inner());
-                assert!(
-                    tr.len() == 16,
-                    "float_list in concat_transform_stmt not of len 16."
-                );
-                debug!("ConcatTransform: {:?}", tr);
-                api.pbrt_concat_transform(&[
-                    tr[0], tr[1], tr[2], tr[3], tr[4], tr[5], tr[6], tr[7], tr[8], tr[9], tr[10],
-                    tr[11], tr[11], tr[12], tr[13], tr[14],
-                ]);
+                if let Some(tr) = self.parse_matrix(next_pair.into_inner(), "ConcatTransform") {
+                    debug!(target: PARSER, "ConcatTransform: {:?}", tr);
+                    api.call(ApiCall::ConcatTransform(tr));
+                }
             }
             Rule::transform_times_stmt => {
                 let mut inner_rules = next_pair.into_inner();
                 let start = self.parse_float(inner_rules.next().unwrap());
                 let end = self.parse_float(inner_rules.next().unwrap());
-                debug!("TransformTimes: {}, {}", start, end);
-                api.pbrt_transform_times(start, end);
+                debug!(target: PARSER, "TransformTimes: {}, {}", start, end);
+                api.call(ApiCall::TransformTimes(start, end));
             }
             _ => unreachable!(),
         }
@@ -371,22 +486,26 @@ impl PbrtFileParser {
             self.parse_param_list(param_list.into_inner())
         });
 
-        debug!("{} '{}' {:}", option_name, name, params);
-        match option_name {
-            "Accelerator" => api.pbrt_accelerator(name, &params),
-            "Camera" => api.pbrt_camera(name, &params),
-            "Film" => api.pbrt_film(name, &params),
-            "Filter" => api.pbrt_pixel_filter(name, &params),
-            "Integrator" => api.pbrt_integrator(name, &params),
-            "MakeNamedMedium" => api.pbrt_make_named_medium(name, &params),
-            "Sampler" => api.pbrt_sampler(name, &params),
-            "AreaLightSource" => api.pbrt_area_light_source(name, &params),
-            "LightSource" => api.pbrt_light_source(name, &params),
-            "MakeNamedMaterial" => api.pbrt_make_named_material(name, &params),
-            "Material" => api.pbrt_material(name, &params),
-            "Shape" => api.pbrt_shape(name, &params),
-            _ => warn!("'{}' not supported", option_name),
-        }
+        debug!(target: PARSER, "{} '{}' {:}", option_name, name, params);
+        let call = match option_name {
+            "Accelerator" => ApiCall::Accelerator(name, params),
+            "Camera" => ApiCall::Camera(name, params),
+            "Film" => ApiCall::Film(name, params),
+            "Filter" => ApiCall::PixelFilter(name, params),
+            "Integrator" => ApiCall::Integrator(name, params),
+            "MakeNamedMedium" => ApiCall::MakeNamedMedium(name, params),
+            "Sampler" => ApiCall::Sampler(name, params),
+            "AreaLightSource" => ApiCall::AreaLightSource(name, params),
+            "LightSource" => ApiCall::LightSource(name, params),
+            "MakeNamedMaterial" => ApiCall::MakeNamedMaterial(name, params),
+            "Material" => ApiCall::Material(name, params),
+            "Shape" => ApiCall::Shape(name, params),
+            _ => {
+                warn!(target: PARSER, "'{}' not supported", option_name);
+                return;
+            }
+        };
+        api.call(call);
     }
 
     /// Parse a `param_list` rule of the grammar and return a `ParamSet`.
@@ -454,7 +573,7 @@ impl PbrtFileParser {
 
         let n = list.len();
         if n % 3 != 0 {
-            warn!("point3d_param '{}' length is not divisible by 3", ident);
+            warn!(target: PARSER, "point3d_param '{}' length is not divisible by 3", ident);
         }
 
         let values: Vec<Point3f> = (0..n)
@@ -484,7 +603,7 @@ impl PbrtFileParser {
 
         let n = list.len();
         if n % 3 != 0 {
-            warn!("vector3d_param '{}' length is not divisible by 3", ident);
+            warn!(target: PARSER, "vector3d_param '{}' length is not divisible by 3", ident);
         }
 
         let values: Vec<Vector3f> = (0..n)
@@ -514,7 +633,7 @@ impl PbrtFileParser {
 
         let n = list.len();
         if n % 3 != 0 {
-            warn!("normal3d_param '{}' length is not divisible by 3", ident);
+            warn!(target: PARSER, "normal3d_param '{}' length is not divisible by 3", ident);
         }
 
         let values: Vec<Normal3f> = (0..n)
@@ -544,7 +663,7 @@ impl PbrtFileParser {
 
         let n = list.len();
         if n % 2 != 0 {
-            warn!("point2d_param '{}' length is not divisible by 3", ident);
+            warn!(target: PARSER, "point2d_param '{}' length is not divisible by 3", ident);
         }
 
         let values: Vec<Point2f> = (0..n)
@@ -574,7 +693,7 @@ impl PbrtFileParser {
 
         let n = list.len();
         if n % 2 != 0 {
-            warn!("vector2d_param '{}' length is not divisible by 3", ident);
+            warn!(target: PARSER, "vector2d_param '{}' length is not divisible by 3", ident);
         }
 
         let values: Vec<Vector2f> = (0..n)
@@ -627,6 +746,10 @@ impl PbrtFileParser {
                 vec![self.parse_quoted_bool(&mut inner_rules)]
             }
             Rule::quoted_bool_list_expr => self.parse_quoted_bool_list(value.into_inner()),
+            Rule::bool_expr => {
+                let mut inner_rules = value.into_inner();
+                vec![self.parse_bool(&mut inner_rules)]
+            }
             _ => unreachable!(),
         };
         params.add_bool(ident, &list);
@@ -685,6 +808,7 @@ impl PbrtFileParser {
 
         let ident = pairs.next().unwrap().as_str();
         let value = pairs.next().unwrap();
+        let location = self.location(&value);
         assert!(pairs.next().is_none());
 
         let list = match value.as_rule() {
@@ -693,10 +817,13 @@ impl PbrtFileParser {
         };
 
         // ParamSet does additional validation.
-        if param_type == "color" || param_type == "rgb" {
-            params.add_rgb_spectrum(ident, &list);
+        let result = if param_type == "color" || param_type == "rgb" {
+            params.add_rgb_spectrum(ident, &list)
         } else {
-            params.add_xyz_spectrum(ident, &list);
+            params.add_xyz_spectrum(ident, &list)
+        };
+        if let Err(err) = result {
+            error!(target: PARSER, "{}", err.at(Some(&location)));
         }
     }
 
@@ -711,17 +838,24 @@ impl PbrtFileParser {
 
         let ident = pairs.next().unwrap().as_str();
         let value = pairs.next().unwrap();
+        let location = self.location(&value);
         assert!(pairs.next().is_none());
 
         match value.as_rule() {
             Rule::float_list_expr => {
                 // ParamSet does additional validation.
                 let list = self.parse_float_list(value.into_inner());
-                params.add_sampled_spectrum(ident, &list);
+                if let Err(err) = params.add_sampled_spectrum(ident, &list) {
+                    error!(target: PARSER, "{}", err.at(Some(&location)));
+                }
             }
             Rule::quoted_str_expr => {
                 let mut inner_rules = value.into_inner();
-                let filename = self.parse_quoted_str(&mut inner_rules);
+                let mut filename = self.parse_quoted_str(&mut inner_rules);
+                if !is_named_spectrum(&filename) && is_relative_path(&filename) {
+                    // Path is relative to the parent path of the file being parsed.
+                    filename = self.parent_path.clone() + "/" + &filename;
+                }
                 params.add_sampled_spectrum_files(ident, &[filename]);
             }
             _ => unreachable!(),
@@ -739,6 +873,7 @@ impl PbrtFileParser {
 
         let ident = pairs.next().unwrap().as_str();
         let value = pairs.next().unwrap();
+        let location = self.location(&value);
         assert!(pairs.next().is_none());
 
         let list = match value.as_rule() {
@@ -747,7 +882,9 @@ impl PbrtFileParser {
         };
 
         // ParamSet does additional validation.
-        params.add_blackbody_spectrum(ident, &list);
+        if let Err(err) = params.add_blackbody_spectrum(ident, &list) {
+            error!(target: PARSER, "{}", err.at(Some(&location)));
+        }
     }
 
     /// Parse an `texture_param` rule of the grammar and add parameter to a
@@ -851,21 +988,43 @@ impl PbrtFileParser {
         }
     }
 
-    /// Parse a `quoted_ident` rule of the grammar and return the unquoted
+    /// Parse a `quoted_str_expr` rule of the grammar and return the unquoted
     /// `String` value.
     ///
-    /// * `pairs`  - The inner token pairs for matched `quoted_ident` rule.
-    fn parse_quoted_ident(&self, pairs: &mut Pairs<Rule>) -> String {
+    /// * `pairs`  - The inner token pairs for matched `quoted_str_expr` rule.
+    fn parse_quoted_str_expr(&self, pairs: &mut Pairs<Rule>) -> String {
         let next_pair = pairs.next().unwrap();
         match next_pair.as_rule() {
-            Rule::quoted_ident => {
+            Rule::quoted_str_expr => {
                 let mut inner_rules = next_pair.into_inner();
-                self.parse_ident(&mut inner_rules)
+                self.parse_quoted_str(&mut inner_rules)
             }
             _ => unreachable!(),
         }
     }
 
+    /// Parse the `float_list_expr` of a `transform_stmt` or
+    /// `concat_transform_stmt` rule of the grammar and return the matrix; or
+    /// `None` if it doesn't have 16 values.
+    ///
+    /// * `pairs` - The inner token pairs for matched rule.
+    /// * `stmt`  - Name of the statement.
+    fn parse_matrix(&self, mut pairs: Pairs<Rule>, stmt: &str) -> Option<[Float; 16]> {
+        let tr = self.parse_float_list(pairs.next().unwrap().into_inner());
+        match <[Float; 16]>::try_from(tr.as_slice()) {
+            Ok(tr) => Some(tr),
+            Err(_) => {
+                error!(
+                    target: PARSER,
+                    "{} requires 16 values, not {}. Ignoring it.",
+                    stmt,
+                    tr.len()
+                );
+                None
+            }
+        }
+    }
+
     /// Parse a `quoted_bool` rule of the grammar and return the unquoted
     /// `bool` value.
     ///
@@ -952,9 +1111,158 @@ impl PbrtFileParser {
 /// Read the entire file and return its contents as a String.
 ///
 /// * `path` - Path to file.
-fn file_to_string(path: &str) -> Result<String, String> {
+fn file_to_string(path: &str) -> Result<String, Error> {
     match fs::read_to_string(path) {
         Ok(s) => Ok(s),
-        _ => Err(format!("Error reading file '{}'", path)),
+        Err(err) => Err(Error::Io(format!(
+            "Error reading file '{}'. {}.",
+            path, err
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pbrt_v3_scenes_are_parsed() {
+        let source = r#"
+LookAt 0 0 -5  0 0 0  0 1 0 # eye, look at and up
+Camera "perspective" "float fov" 45
+WorldBegin
+MakeNamedMaterial "Red Paint" "string type" "matte"
+TransformBegin
+  Translate 0 -.5 +1e-1
+  ConcatTransform [1 0 0 0  0 1 0 0  0 0 1 0  0 0 0 1]
+  CoordinateSystem "my system"
+TransformEnd
+MediumInterface "" "fog"
+MediumInterface "smoke"
+AttributeBegin
+  NamedMaterial "Red Paint"
+  Shape "trianglemesh" "integer indices" [0 1 2] "point P" [-1 0 0 1 0 0 0 1 0]
+    "float uv" [ ]
+AttributeEnd
+WorldEnd
+"#;
+        assert!(PbrtParser::parse(Rule::pbrt, source).is_ok());
+
+        let parser = PbrtFileParser::in_memory();
+        let params = r#""float v" [.8 -.5 +.1 -1.e2] "bool remaproughness" true "float uv" [ ]"#;
+        let mut pairs = PbrtParser::parse(Rule::param_list, params).unwrap();
+        let params = parser.parse_param_list(pairs.next().unwrap().into_inner());
+        assert_eq!(params.find_float("v"), [0.8, -0.5, 0.1, -100.0]);
+        assert!(params.find_one_bool("remaproughness", false));
+        assert!(params.find_float("uv").is_empty());
+
+        let mut pairs = PbrtParser::parse(Rule::transform_stmt, "Transform [1 2 3]").unwrap();
+        let pairs = pairs.next().unwrap().into_inner();
+        assert_eq!(parser.parse_matrix(pairs, "Transform"), None);
+    }
+
+    #[test]
+    fn includes_are_relative_to_the_including_file() {
+        let dir = std::env::temp_dir().join("pbr_rust_includes");
+        fs::create_dir_all(dir.join("geometry")).unwrap();
+        let files = [
+            ("scene.pbrt", "Include \"geometry/shapes.pbrt\"\n"),
+            ("geometry/shapes.pbrt", "Include \"../materials.pbrt\"\n"),
+            ("materials.pbrt", "Include \"scene.pbrt\"\n"),
+        ];
+        for (name, contents) in files.iter() {
+            fs::write(dir.join(name), contents).unwrap();
+        }
+
+        let scene = PbrtFileParser::new(&dir.join("scene.pbrt").to_string_lossy()).unwrap();
+        let shapes = scene.include("geometry/shapes.pbrt").unwrap();
+        assert_eq!(Path::new(&shapes.parent_path), dir.join("geometry"));
+        let materials = shapes.include("../materials.pbrt").unwrap();
+        assert!(materials.include("scene.pbrt").is_err());
+        assert!(materials.include("geometry/other.pbrt").is_ok());
+
+        // The cycle is reported instead of overflowing the stack.
+        let mut api = Api::new();
+        api.pbrt_init();
+        assert!(scene.parse(&mut api).is_ok());
+    }
+
+    #[test]
+    fn sandboxed_includes_stay_in_the_scene_root() {
+        let dir = std::env::temp_dir().join("pbr_rust_sandbox");
+        fs::create_dir_all(dir.join("root")).unwrap();
+        fs::write(dir.join("root/shapes.pbrt"), "").unwrap();
+        fs::write(dir.join("secret.pbrt"), "").unwrap();
+
+        let root = dir.join("root").to_string_lossy().into_owned();
+        let parser = PbrtFileParser::in_memory().sandboxed(Some(&root)).unwrap();
+        assert!(parser.include("shapes.pbrt").is_ok());
+        assert!(parser.include("../secret.pbrt").is_err());
+        assert!(parser
+            .include(&dir.join("secret.pbrt").to_string_lossy())
+            .is_err());
+        let shapes = parser.include("shapes.pbrt").unwrap();
+        assert!(shapes.include("../secret.pbrt").is_err());
+
+        let parser = PbrtFileParser::in_memory().sandboxed(None).unwrap();
+        assert!(parser
+            .include(&dir.join("root/shapes.pbrt").to_string_lossy())
+            .is_err());
+    }
+
+    #[test]
+    fn sandboxed_textures_stay_in_the_scene_root() {
+        let dir = std::env::temp_dir().join("pbr_rust_sandbox_textures");
+        fs::create_dir_all(dir.join("root")).unwrap();
+        fs::write(dir.join("root/wood.png"), "").unwrap();
+        fs::write(dir.join("secret.png"), "").unwrap();
+
+        let root = dir.join("root").to_string_lossy().into_owned();
+        let parser = PbrtFileParser::in_memory().sandboxed(Some(&root)).unwrap();
+        let sandbox = parser.sandbox.as_ref().unwrap();
+        let texture_file = |path: &str| {
+            let mut params = ParamSet::new();
+            params.add_string("filename", &[String::from(path)]);
+            sandbox.enter(|| params.find_one_filename("filename", String::from("")))
+        };
+        assert!(texture_file("wood.png").ends_with("wood.png"));
+        assert_eq!(texture_file("../secret.png"), "");
+        assert_eq!(texture_file(&dir.join("secret.png").to_string_lossy()), "");
+
+        // The scene is still rendered without the texture.
+        let source = format!(
+            "WorldBegin\nTexture \"t\" \"spectrum\" \"imagemap\" \"string filename\" \"{}\"\n",
+            dir.join("secret.png").display()
+        );
+        let mut api = Api::new();
+        api.pbrt_init();
+        assert!(parser.parse_source(&source, &mut api).is_ok());
+    }
+
+    #[test]
+    fn errors_point_to_the_statement_that_caused_them() {
+        let source =
+            "WorldBegin\n# A comment\nShape \"loopsubdiv\"\n  \"integer indices\" [0 1 5]\n";
+        let parser = PbrtFileParser::in_memory();
+        let pbrt = parser.parse_pbrt_rule(source).unwrap();
+        let lines: Vec<String> = pbrt
+            .into_inner()
+            .filter(|pair| pair.as_rule() == Rule::stmt)
+            .map(|pair| parser.location(&pair).to_string())
+            .collect();
+        assert_eq!(lines, vec!["<memory>:1", "<memory>:2", "<memory>:3"]);
+
+        let err = Error::InvalidParameter(String::from("Bad index."));
+        let location = SourceLocation::new("scene.pbrt", 3);
+        assert_eq!(
+            err.clone().at(Some(&location)),
+            Error::InvalidParameter(String::from("scene.pbrt:3: Bad index."))
+        );
+        assert_eq!(err.clone().at(None), err);
+
+        let mut api = Api::new();
+        api.pbrt_init();
+        assert!(parser.parse_source(source, &mut api).is_ok());
+        assert_eq!(api.source_location(), None);
     }
 }
@@ -7,8 +7,13 @@ use num_traits::{Num, Zero};
 use std::ops::{Add, Mul, Neg};
 
 /// Use 32-bit precision for floating point numbers.
+#[cfg(not(feature = "float-as-double"))]
 pub type Float = f32;
 
+/// Use 64-bit precision for floating point numbers.
+#[cfg(feature = "float-as-double")]
+pub type Float = f64;
+
 /// Default signed integer to 32-bit.
 pub type Int = i32;
 
@@ -16,8 +21,13 @@ pub type Int = i32;
 pub const INFINITY: Float = Float::INFINITY;
 
 /// PI (π)
+#[cfg(not(feature = "float-as-double"))]
 pub const PI: Float = std::f32::consts::PI;
 
+/// PI (π)
+#[cfg(feature = "float-as-double")]
+pub const PI: Float = std::f64::consts::PI;
+
 /// 1/PI (1/π)
 pub const INV_PI: Float = 1.0 / PI;
 
@@ -40,7 +50,7 @@ pub const FOUR_PI: Float = PI * 4.0;
 pub const INV_FOUR_PI: Float = 1.0 / FOUR_PI;
 
 /// Machine Epsilon
-pub const MACHINE_EPSILON: Float = std::f32::EPSILON * 0.5;
+pub const MACHINE_EPSILON: Float = Float::EPSILON * 0.5;
 
 /// Shadow Epsilon
 pub const SHADOW_EPSILON: Float = 0.0001;
@@ -191,14 +201,14 @@ pub fn next_float_up(v: Float) -> Float {
     let nv = if v == -0.0 { 0.0 } else { v };
 
     // Advance v to next higher float
-    let mut ui = float_to_bits(nv);
+    let mut ui = nv.to_bits();
     if nv >= 0.0 {
         ui += 1;
     } else {
         ui -= 1;
     }
 
-    bits_to_float(ui)
+    Float::from_bits(ui)
 }
 
 /// Bump a floating point value up to the next lower representable floating
@@ -213,14 +223,14 @@ pub fn next_float_down(v: Float) -> Float {
 
     // Advance v to next lower float
     let nv = if v == 0.0 { -0.0 } else { v };
-    let mut ui = float_to_bits(v);
+    let mut ui = nv.to_bits();
     if nv > 0.0 {
         ui -= 1;
     } else {
         ui += 1;
     }
 
-    bits_to_float(ui)
+    Float::from_bits(ui)
 }
 
 /// Emulates the behavior of `upper_bound` but uses a function object to get
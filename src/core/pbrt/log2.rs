@@ -1,6 +1,6 @@
 //! Log2
 
-use super::{float_to_bits, Float};
+use super::float_to_bits;
 use num_traits::Num;
 
 /// Trait to support base 2 logarithm
@@ -9,7 +9,7 @@ pub trait Log2<T: Num> {
     fn log2(self) -> T;
 }
 
-impl Log2<u32> for Float {
+impl Log2<u32> for f32 {
     /// Returns log base 2 of a value.
     fn log2(self) -> u32 {
         if self < 1.0 {
@@ -23,6 +23,20 @@ impl Log2<u32> for Float {
     }
 }
 
+impl Log2<u32> for f64 {
+    /// Returns log base 2 of a value.
+    fn log2(self) -> u32 {
+        if self < 1.0 {
+            0
+        } else {
+            let bits = self.to_bits();
+            let r = ((bits >> 52) - 1023) as u32;
+            let t = if bits & (1 << 51) == 0 { 0 } else { 1 };
+            r + t
+        }
+    }
+}
+
 impl Log2<i32> for u32 {
     /// Returns log base 2 of a value.
     fn log2(self) -> i32 {
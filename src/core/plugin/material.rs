@@ -0,0 +1,89 @@
+//! Plugin Materials
+
+use super::*;
+use crate::core::material::*;
+use crate::core::microfacet::*;
+use crate::core::reflection::*;
+
+/// A material implemented by a plugin. The plugin provides the parameters of a
+/// plastic-like BSDF at each shading point.
+pub struct PluginMaterial {
+    /// The plugin's functions.
+    vtable: PluginMaterialVTable,
+
+    /// The plugin's material.
+    material: Arc<PluginObject>,
+
+    /// Bump map.
+    bump_map: Option<ArcTexture<Float>>,
+}
+
+impl PluginMaterial {
+    /// Creates a new plugin material.
+    ///
+    /// * `name`   - Type name.
+    /// * `vtable` - The plugin's functions.
+    /// * `tp`     - Texture parameter set.
+    pub fn new(
+        name: &str,
+        vtable: PluginMaterialVTable,
+        tp: &TextureParams,
+    ) -> Result<Self, Error> {
+        let material = PluginObject::create(
+            "material",
+            name,
+            vtable.create,
+            vtable.destroy,
+            &[&tp.geom_params, &tp.mat_params],
+        )?;
+        Ok(Self {
+            vtable,
+            material,
+            bump_map: tp.get_float_texture_or_none("bumpmap"),
+        })
+    }
+}
+
+impl Material for PluginMaterial {
+    /// Initializes representations of the light-scattering properties of the
+    /// material at the intersection point on the surface.
+    ///
+    /// * `si`                   - The surface interaction at the intersection.
+    /// * `mode`                 - Transport mode (ignored).
+    /// * `allow_multiple_lobes` - Indicates whether the material should use
+    ///                            BxDFs that aggregate multiple types of
+    ///                            scattering into a single BxDF when such BxDFs
+    ///                            are available (ignored).
+    fn compute_scattering_functions(
+        &self,
+        si: &mut SurfaceInteraction,
+        _mode: TransportMode,
+        _allow_multiple_lobes: bool,
+    ) {
+        // Perform bump mapping with `bump_map`, if present.
+        if let Some(bump_map) = self.bump_map.clone() {
+            Material::bump(self, bump_map, si);
+        }
+
+        let point = PluginShadingPoint::from(&*si);
+        let mut sample = PluginMaterialSample::default();
+        (self.vtable.evaluate)(self.material.get(), &point, &mut sample);
+
+        let mut bsdf = BSDF::new(&si.clone(), None);
+
+        let kd = spectrum(&sample.diffuse).clamp_default();
+        if !kd.is_black() {
+            bsdf.add(Arc::new(LambertianReflection::new(kd)));
+        }
+
+        let ks = spectrum(&sample.specular).clamp_default();
+        if !ks.is_black() {
+            let fresnel = Arc::new(FresnelDielectric::new(sample.eta as Float, 1.0));
+            let rough = TrowbridgeReitzDistribution::roughness_to_alpha(sample.roughness as Float);
+            let distrib = Arc::new(TrowbridgeReitzDistribution::new(rough, rough, true));
+            bsdf.add(Arc::new(MicrofacetReflection::new(ks, distrib, fresnel)));
+        }
+
+        si.bsdf = Some(Arc::new(bsdf));
+    }
+}
@@ -0,0 +1,762 @@
+//! Plugins
+//!
+//! Shapes, materials and textures can be provided by shared libraries loaded
+//! at runtime with `load_plugin()`, e.g. from the `--plugin` command line
+//! option, or from scene files with `Option "string plugin" "<path>"` when
+//! `--allow-scene-plugins` is given. A plugin only depends on the C ABI
+//! described by the `#[repr(C)]` types in this module, so it can be written in
+//! any language and built against any version of the renderer with the same
+//! `PLUGIN_ABI_VERSION`.
+//!
+//! A plugin exports two functions:
+//!
+//! * `pbr_rust_plugin_abi_version() -> u32` returning `PLUGIN_ABI_VERSION`.
+//! * `pbr_rust_plugin_register(registrar: *const PluginRegistrar)` which
+//!   registers the plugin's types by name using the registrar's functions.
+//!
+//! The registered types are used like the built-in ones, e.g. `Shape "name"`.
+//! Object handles returned by `create` are shared between rendering threads,
+//! so `bounds`, `intersect`, `area`, `sample` and `evaluate` must be thread
+//! safe.
+
+#![allow(dead_code)]
+mod material;
+mod shape;
+mod texture;
+
+use crate::core::api::*;
+use crate::core::error::*;
+use crate::core::geometry::*;
+use crate::core::paramset::*;
+use crate::core::pbrt::*;
+use crate::core::spectrum::*;
+use crate::core::texture::*;
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_void};
+use std::ptr;
+use std::sync::Arc;
+
+// Re-export
+pub use material::*;
+pub use shape::*;
+pub use texture::*;
+
+/// Version of the plugin ABI. Plugins built for a different version are
+/// rejected.
+pub const PLUGIN_ABI_VERSION: u32 = 1;
+
+/// Name of the function returning a plugin's ABI version.
+pub const PLUGIN_ABI_VERSION_SYMBOL: &[u8] = b"pbr_rust_plugin_abi_version\0";
+
+/// Name of the function registering a plugin's types.
+pub const PLUGIN_REGISTER_SYMBOL: &[u8] = b"pbr_rust_plugin_register\0";
+
+/// Signature of `pbr_rust_plugin_abi_version`.
+pub type PluginAbiVersionFn = extern "C" fn() -> u32;
+
+/// Signature of `pbr_rust_plugin_register`.
+pub type PluginRegisterFn = extern "C" fn(registrar: *const PluginRegistrar);
+
+/// Gives plugins access to the parameters of the scene object being created.
+/// Names are NUL terminated strings.
+#[repr(C)]
+pub struct PluginParams {
+    /// Host data passed back to the functions below.
+    pub ctx: *const c_void,
+
+    /// Returns a float parameter or `default` if it is missing.
+    pub find_float: extern "C" fn(ctx: *const c_void, name: *const c_char, default: f64) -> f64,
+
+    /// Returns an integer parameter or `default` if it is missing.
+    pub find_int: extern "C" fn(ctx: *const c_void, name: *const c_char, default: i32) -> i32,
+
+    /// Returns a bool parameter or `default` if it is missing.
+    pub find_bool: extern "C" fn(ctx: *const c_void, name: *const c_char, default: bool) -> bool,
+
+    /// Copies a string parameter into `buf` as a NUL terminated string,
+    /// truncating it to `len - 1` bytes, and returns its full length in bytes.
+    /// Returns -1 if it is missing.
+    pub find_string: extern "C" fn(
+        ctx: *const c_void,
+        name: *const c_char,
+        buf: *mut c_char,
+        len: usize,
+    ) -> isize,
+
+    /// Writes a spectrum parameter as linear RGB to `rgb` and returns `true`.
+    /// Returns `false` if it is missing.
+    pub find_rgb:
+        extern "C" fn(ctx: *const c_void, name: *const c_char, rgb: *mut [f64; 3]) -> bool,
+}
+
+/// A ray in a shape's object space.
+#[repr(C)]
+#[derive(Copy, Clone, Default)]
+pub struct PluginRay {
+    /// Origin.
+    pub o: [f64; 3],
+
+    /// Direction, not necessarily normalized.
+    pub d: [f64; 3],
+
+    /// Maximum extent of the ray; hits at or beyond it must be ignored.
+    pub t_max: f64,
+
+    /// Time.
+    pub time: f64,
+}
+
+/// An axis aligned bounding box in a shape's object space.
+#[repr(C)]
+#[derive(Copy, Clone, Default)]
+pub struct PluginBounds {
+    /// Minimum corner.
+    pub min: [f64; 3],
+
+    /// Maximum corner.
+    pub max: [f64; 3],
+}
+
+/// A ray-shape intersection in a shape's object space.
+#[repr(C)]
+#[derive(Copy, Clone, Default)]
+pub struct PluginShapeHit {
+    /// Ray parameter of the hit.
+    pub t: f64,
+
+    /// Hit point.
+    pub p: [f64; 3],
+
+    /// Surface parameterization at the hit point.
+    pub uv: [f64; 2],
+
+    /// Partial derivative of the surface position with respect to u. The
+    /// surface normal is `dpdu × dpdv`.
+    pub dpdu: [f64; 3],
+
+    /// Partial derivative of the surface position with respect to v.
+    pub dpdv: [f64; 3],
+}
+
+/// A point sampled uniformly by area on a shape, in its object space.
+#[repr(C)]
+#[derive(Copy, Clone, Default)]
+pub struct PluginShapeSample {
+    /// Sampled point.
+    pub p: [f64; 3],
+
+    /// Surface normal at the sampled point.
+    pub n: [f64; 3],
+}
+
+/// Functions implementing a plugin shape.
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PluginShapeVTable {
+    /// Creates a shape from its parameters. Returns null on failure.
+    pub create: extern "C" fn(params: *const PluginParams) -> *mut c_void,
+
+    /// Destroys a shape returned by `create`.
+    pub destroy: extern "C" fn(shape: *mut c_void),
+
+    /// Writes the shape's object space bounds to `bounds`.
+    pub bounds: extern "C" fn(shape: *const c_void, bounds: *mut PluginBounds),
+
+    /// Intersects an object space ray with the shape. Writes the closest hit
+    /// with `0 < t < ray.t_max` to `hit` and returns `true` if there is one.
+    pub intersect: extern "C" fn(
+        shape: *const c_void,
+        ray: *const PluginRay,
+        hit: *mut PluginShapeHit,
+    ) -> bool,
+
+    /// Returns the object space surface area.
+    pub area: extern "C" fn(shape: *const c_void) -> f64,
+
+    /// Samples a point uniformly by area using `u` in [0, 1)².
+    pub sample:
+        extern "C" fn(shape: *const c_void, u: *const [f64; 2], sample: *mut PluginShapeSample),
+}
+
+/// World space details of a surface point being shaded.
+#[repr(C)]
+#[derive(Copy, Clone, Default)]
+pub struct PluginShadingPoint {
+    /// Position.
+    pub p: [f64; 3],
+
+    /// Shading normal.
+    pub n: [f64; 3],
+
+    /// Surface parameterization.
+    pub uv: [f64; 2],
+
+    /// Outgoing direction.
+    pub wo: [f64; 3],
+
+    /// Time.
+    pub time: f64,
+}
+
+impl From<&SurfaceInteraction<'_>> for PluginShadingPoint {
+    /// Returns the shading point of a surface interaction.
+    ///
+    /// * `si` - Surface interaction.
+    fn from(si: &SurfaceInteraction<'_>) -> Self {
+        let (p, n, wo) = (si.hit.p, si.shading.n, si.hit.wo);
+        Self {
+            p: [p.x as f64, p.y as f64, p.z as f64],
+            n: [n.x as f64, n.y as f64, n.z as f64],
+            uv: [si.uv.x as f64, si.uv.y as f64],
+            wo: [wo.x as f64, wo.y as f64, wo.z as f64],
+            time: si.hit.time as f64,
+        }
+    }
+}
+
+/// Reflectance parameters of a plugin material at a point. They describe a
+/// Lambertian lobe plus a Trowbridge-Reitz microfacet lobe with a dielectric
+/// Fresnel term, i.e. the `plastic` material.
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PluginMaterialSample {
+    /// Linear RGB diffuse reflectance.
+    pub diffuse: [f64; 3],
+
+    /// Linear RGB specular reflectance.
+    pub specular: [f64; 3],
+
+    /// Roughness in [0, 1].
+    pub roughness: f64,
+
+    /// Index of refraction used for the Fresnel term.
+    pub eta: f64,
+}
+
+impl Default for PluginMaterialSample {
+    /// Returns a black surface with the `plastic` material's roughness and
+    /// index of refraction.
+    fn default() -> Self {
+        Self {
+            diffuse: [0.0; 3],
+            specular: [0.0; 3],
+            roughness: 0.1,
+            eta: 1.5,
+        }
+    }
+}
+
+/// Functions implementing a plugin material.
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PluginMaterialVTable {
+    /// Creates a material from its parameters. Returns null on failure.
+    pub create: extern "C" fn(params: *const PluginParams) -> *mut c_void,
+
+    /// Destroys a material returned by `create`.
+    pub destroy: extern "C" fn(material: *mut c_void),
+
+    /// Writes the reflectance parameters at a point to `sample`. It is
+    /// initialized with `PluginMaterialSample::default()`.
+    pub evaluate: extern "C" fn(
+        material: *const c_void,
+        point: *const PluginShadingPoint,
+        sample: *mut PluginMaterialSample,
+    ),
+}
+
+/// Functions implementing a plugin texture. The same texture type can be used
+/// as a spectrum texture or as a float texture, which uses the red channel.
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PluginTextureVTable {
+    /// Creates a texture from its parameters. Returns null on failure.
+    pub create: extern "C" fn(params: *const PluginParams) -> *mut c_void,
+
+    /// Destroys a texture returned by `create`.
+    pub destroy: extern "C" fn(texture: *mut c_void),
+
+    /// Writes the linear RGB value of the texture at a point to `rgb`.
+    pub evaluate:
+        extern "C" fn(texture: *const c_void, point: *const PluginShadingPoint, rgb: *mut [f64; 3]),
+}
+
+/// Passed to `pbr_rust_plugin_register` to register a plugin's types. Names
+/// are NUL terminated strings and vtables are copied.
+#[repr(C)]
+pub struct PluginRegistrar {
+    /// Host data passed back to the functions below.
+    pub ctx: *mut c_void,
+
+    /// Registers a shape type.
+    pub register_shape:
+        extern "C" fn(ctx: *mut c_void, name: *const c_char, vtable: *const PluginShapeVTable),
+
+    /// Registers a material type.
+    pub register_material:
+        extern "C" fn(ctx: *mut c_void, name: *const c_char, vtable: *const PluginMaterialVTable),
+
+    /// Registers a texture type.
+    pub register_texture:
+        extern "C" fn(ctx: *mut c_void, name: *const c_char, vtable: *const PluginTextureVTable),
+}
+
+/// An object created by a plugin. It is destroyed with the plugin's `destroy`
+/// function once the last reference is dropped.
+pub struct PluginObject {
+    /// The plugin's handle.
+    ptr: *mut c_void,
+
+    /// Destroys `ptr`.
+    destroy: extern "C" fn(*mut c_void),
+}
+
+// Plugins are required to make their objects thread safe.
+unsafe impl Send for PluginObject {}
+unsafe impl Sync for PluginObject {}
+
+impl PluginObject {
+    /// Calls a plugin's `create` function.
+    ///
+    /// * `kind`    - Kind of object for error messages.
+    /// * `name`    - Type name for error messages.
+    /// * `create`  - The plugin's `create` function.
+    /// * `destroy` - The plugin's `destroy` function.
+    /// * `params`  - Parameter sets searched in order.
+    fn create(
+        kind: &str,
+        name: &str,
+        create: extern "C" fn(*const PluginParams) -> *mut c_void,
+        destroy: extern "C" fn(*mut c_void),
+        params: &[&ParamSet],
+    ) -> Result<Arc<Self>, Error> {
+        let ptr = create(&PluginParams::new(&params));
+        if ptr.is_null() {
+            Err(Error::InvalidParameter(format!(
+                "Plugin {} '{}' could not be created.",
+                kind, name
+            )))
+        } else {
+            Ok(Arc::new(Self { ptr, destroy }))
+        }
+    }
+
+    /// Returns the plugin's handle.
+    fn get(&self) -> *const c_void {
+        self.ptr
+    }
+}
+
+impl Drop for PluginObject {
+    /// Destroys the plugin's object.
+    fn drop(&mut self) {
+        (self.destroy)(self.ptr);
+    }
+}
+
+impl PluginParams {
+    /// Returns the functions looking up parameters in the given parameter
+    /// sets. The first set containing a parameter is used.
+    ///
+    /// * `params` - Parameter sets searched in order.
+    fn new(params: &&[&ParamSet]) -> Self {
+        Self {
+            ctx: params as *const &[&ParamSet] as *const c_void,
+            find_float: params_find_float,
+            find_int: params_find_int,
+            find_bool: params_find_bool,
+            find_string: params_find_string,
+            find_rgb: params_find_rgb,
+        }
+    }
+}
+
+/// Returns the parameter sets behind a `PluginParams` context and the name
+/// being looked up.
+///
+/// * `ctx`  - Context from `PluginParams::new()`.
+/// * `name` - NUL terminated parameter name.
+unsafe fn params_lookup<'a>(
+    ctx: *const c_void,
+    name: *const c_char,
+) -> (&'a [&'a ParamSet], &'a str) {
+    let params = *(ctx as *const &[&ParamSet]);
+    let name = CStr::from_ptr(name).to_str().unwrap_or("");
+    (params, name)
+}
+
+extern "C" fn params_find_float(ctx: *const c_void, name: *const c_char, default: f64) -> f64 {
+    let (params, name) = unsafe { params_lookup(ctx, name) };
+    params
+        .iter()
+        .find_map(|ps| ps.find_float(name).first().copied())
+        .map_or(default, |v| v as f64)
+}
+
+extern "C" fn params_find_int(ctx: *const c_void, name: *const c_char, default: i32) -> i32 {
+    let (params, name) = unsafe { params_lookup(ctx, name) };
+    params
+        .iter()
+        .find_map(|ps| ps.find_int(name).first().copied())
+        .unwrap_or(default)
+}
+
+extern "C" fn params_find_bool(ctx: *const c_void, name: *const c_char, default: bool) -> bool {
+    let (params, name) = unsafe { params_lookup(ctx, name) };
+    params
+        .iter()
+        .find_map(|ps| ps.find_bool(name).first().copied())
+        .unwrap_or(default)
+}
+
+extern "C" fn params_find_string(
+    ctx: *const c_void,
+    name: *const c_char,
+    buf: *mut c_char,
+    len: usize,
+) -> isize {
+    let (params, name) = unsafe { params_lookup(ctx, name) };
+    match params.iter().find_map(|ps| ps.find_string(name).first()) {
+        Some(s) => {
+            if !buf.is_null() && len > 0 {
+                let n = s.len().min(len - 1);
+                unsafe {
+                    ptr::copy_nonoverlapping(s.as_ptr() as *const c_char, buf, n);
+                    *buf.add(n) = 0;
+                }
+            }
+            s.len() as isize
+        }
+        None => -1,
+    }
+}
+
+extern "C" fn params_find_rgb(ctx: *const c_void, name: *const c_char, rgb: *mut [f64; 3]) -> bool {
+    let (params, name) = unsafe { params_lookup(ctx, name) };
+    match params.iter().find_map(|ps| ps.find_spectrum(name).first()) {
+        Some(s) => {
+            let [r, g, b] = s.to_rgb();
+            unsafe { *rgb = [r as f64, g as f64, b as f64] };
+            true
+        }
+        None => false,
+    }
+}
+
+/// Returns a reflectance spectrum from a plugin's linear RGB value.
+///
+/// * `rgb` - The RGB value.
+fn spectrum(rgb: &[f64; 3]) -> Spectrum {
+    let rgb = [rgb[0] as Float, rgb[1] as Float, rgb[2] as Float];
+    Spectrum::from_rgb(&rgb, Some(SpectrumType::Reflectance))
+}
+
+/// Registers the types provided by a plugin.
+///
+/// * `plugin`   - Name of the plugin for log messages.
+/// * `register` - The plugin's `pbr_rust_plugin_register` function.
+pub fn register_plugin(plugin: &str, register: PluginRegisterFn) {
+    let mut plugin = String::from(plugin);
+    let registrar = PluginRegistrar {
+        ctx: &mut plugin as *mut String as *mut c_void,
+        register_shape: registrar_register_shape,
+        register_material: registrar_register_material,
+        register_texture: registrar_register_texture,
+    };
+    register(&registrar);
+}
+
+/// Returns the plugin name behind a `PluginRegistrar` context and the name of
+/// the type being registered.
+///
+/// * `ctx`  - Context from `register_plugin()`.
+/// * `name` - NUL terminated type name.
+unsafe fn registrar_lookup<'a>(ctx: *mut c_void, name: *const c_char) -> (&'a str, String) {
+    let plugin = &*(ctx as *const String);
+    let name = CStr::from_ptr(name).to_string_lossy().into_owned();
+    (plugin, name)
+}
+
+extern "C" fn registrar_register_shape(
+    ctx: *mut c_void,
+    name: *const c_char,
+    vtable: *const PluginShapeVTable,
+) {
+    let (plugin, name) = unsafe { registrar_lookup(ctx, name) };
+    let vtable = unsafe { *vtable };
+    info!("Plugin '{}' registered shape '{}'.", plugin, name);
+
+    let type_name = name.clone();
+    register_shape(&name, move |paramset, o2w, w2o, reverse_orientation, _| {
+        let shape = PluginShape::new(&type_name, vtable, paramset, o2w, w2o, reverse_orientation)?;
+        Ok(vec![Arc::new(shape)])
+    });
+}
+
+extern "C" fn registrar_register_material(
+    ctx: *mut c_void,
+    name: *const c_char,
+    vtable: *const PluginMaterialVTable,
+) {
+    let (plugin, name) = unsafe { registrar_lookup(ctx, name) };
+    let vtable = unsafe { *vtable };
+    info!("Plugin '{}' registered material '{}'.", plugin, name);
+
+    let type_name = name.clone();
+    register_material(&name, move |tp| {
+        Ok(Arc::new(PluginMaterial::new(&type_name, vtable, tp)?))
+    });
+}
+
+extern "C" fn registrar_register_texture(
+    ctx: *mut c_void,
+    name: *const c_char,
+    vtable: *const PluginTextureVTable,
+) {
+    let (plugin, name) = unsafe { registrar_lookup(ctx, name) };
+    let vtable = unsafe { *vtable };
+    info!("Plugin '{}' registered texture '{}'.", plugin, name);
+
+    let type_name = name.clone();
+    register_float_texture(&name, move |tp, _| {
+        let texture: ArcTexture<Float> = Arc::new(PluginTexture::new(&type_name, vtable, tp)?);
+        Ok(texture)
+    });
+    let type_name = name.clone();
+    register_spectrum_texture(&name, move |tp, _| {
+        let texture: ArcTexture<Spectrum> = Arc::new(PluginTexture::new(&type_name, vtable, tp)?);
+        Ok(texture)
+    });
+}
+
+#[cfg(feature = "plugins")]
+lazy_static! {
+    /// Loaded plugin libraries. They are never unloaded since the types they
+    /// registered remain in use.
+    static ref LIBRARIES: std::sync::Mutex<Vec<(String, libloading::Library)>> =
+        std::sync::Mutex::new(vec![]);
+}
+
+/// Loads a plugin library and registers its types. Loading the same library
+/// again has no effect.
+///
+/// * `path` - Path to the shared library.
+#[cfg(feature = "plugins")]
+pub fn load_plugin(path: &str) -> Result<(), Error> {
+    let mut libraries = LIBRARIES.lock().unwrap();
+    if libraries.iter().any(|(p, _)| p == path) {
+        return Ok(());
+    }
+
+    // Loading a library runs its initializers; plugins are trusted code.
+    let library = unsafe { libloading::Library::new(path) }
+        .map_err(|e| Error::Io(format!("Unable to load plugin '{}'. {}.", path, e)))?;
+
+    let version = unsafe { library.get::<PluginAbiVersionFn>(PLUGIN_ABI_VERSION_SYMBOL) }
+        .map(|f| f())
+        .map_err(|e| Error::Unsupported(format!("'{}' is not a plugin. {}.", path, e)))?;
+    if version != PLUGIN_ABI_VERSION {
+        return Err(Error::Unsupported(format!(
+            "Plugin '{}' uses ABI version {}; expected {}.",
+            path, version, PLUGIN_ABI_VERSION
+        )));
+    }
+
+    let register = unsafe { library.get::<PluginRegisterFn>(PLUGIN_REGISTER_SYMBOL) }
+        .map(|f| *f)
+        .map_err(|e| Error::Unsupported(format!("'{}' is not a plugin. {}.", path, e)))?;
+    register_plugin(path, register);
+
+    libraries.push((String::from(path), library));
+    Ok(())
+}
+
+/// Returns an error because loading plugins requires the `plugins` feature.
+///
+/// * `path` - Path to the shared library.
+#[cfg(not(feature = "plugins"))]
+pub fn load_plugin(path: &str) -> Result<(), Error> {
+    Err(Error::Unsupported(format!(
+        "Unable to load plugin '{}'. Build with the 'plugins' feature to enable plugins.",
+        path
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A square in the z = 0 plane with half-width "size" and a texture with
+    // the colour "value", implemented the way a plugin would.
+
+    extern "C" fn quad_create(params: *const PluginParams) -> *mut c_void {
+        let params = unsafe { &*params };
+        let name = b"size\0".as_ptr() as *const c_char;
+        let size = (params.find_float)(params.ctx, name, 1.0);
+        Box::into_raw(Box::new(size)) as *mut c_void
+    }
+
+    extern "C" fn quad_destroy(quad: *mut c_void) {
+        unsafe { drop(Box::from_raw(quad as *mut f64)) };
+    }
+
+    extern "C" fn quad_bounds(quad: *const c_void, bounds: *mut PluginBounds) {
+        let size = unsafe { *(quad as *const f64) };
+        unsafe {
+            *bounds = PluginBounds {
+                min: [-size, -size, 0.0],
+                max: [size, size, 0.0],
+            }
+        };
+    }
+
+    extern "C" fn quad_intersect(
+        quad: *const c_void,
+        ray: *const PluginRay,
+        hit: *mut PluginShapeHit,
+    ) -> bool {
+        let (size, ray) = unsafe { (*(quad as *const f64), &*ray) };
+        let t = -ray.o[2] / ray.d[2];
+        let (x, y) = (ray.o[0] + t * ray.d[0], ray.o[1] + t * ray.d[1]);
+        if !(t > 0.0 && t < ray.t_max && x.abs() <= size && y.abs() <= size) {
+            return false;
+        }
+        unsafe {
+            *hit = PluginShapeHit {
+                t,
+                p: [x, y, 0.0],
+                uv: [0.5 + x / (2.0 * size), 0.5 + y / (2.0 * size)],
+                dpdu: [2.0 * size, 0.0, 0.0],
+                dpdv: [0.0, 2.0 * size, 0.0],
+            }
+        };
+        true
+    }
+
+    extern "C" fn quad_area(quad: *const c_void) -> f64 {
+        let size = unsafe { *(quad as *const f64) };
+        4.0 * size * size
+    }
+
+    extern "C" fn quad_sample(quad: *const c_void, u: *const [f64; 2], s: *mut PluginShapeSample) {
+        let (size, u) = unsafe { (*(quad as *const f64), *u) };
+        unsafe {
+            *s = PluginShapeSample {
+                p: [size * (2.0 * u[0] - 1.0), size * (2.0 * u[1] - 1.0), 0.0],
+                n: [0.0, 0.0, 1.0],
+            }
+        };
+    }
+
+    extern "C" fn colour_create(params: *const PluginParams) -> *mut c_void {
+        let params = unsafe { &*params };
+        let mut rgb = [0.0; 3];
+        let name = b"value\0".as_ptr() as *const c_char;
+        if !(params.find_rgb)(params.ctx, name, &mut rgb) {
+            return ptr::null_mut();
+        }
+        Box::into_raw(Box::new(rgb)) as *mut c_void
+    }
+
+    extern "C" fn colour_destroy(colour: *mut c_void) {
+        unsafe { drop(Box::from_raw(colour as *mut [f64; 3])) };
+    }
+
+    extern "C" fn colour_evaluate(
+        colour: *const c_void,
+        _point: *const PluginShadingPoint,
+        rgb: *mut [f64; 3],
+    ) {
+        unsafe { *rgb = *(colour as *const [f64; 3]) };
+    }
+
+    static QUAD: PluginShapeVTable = PluginShapeVTable {
+        create: quad_create,
+        destroy: quad_destroy,
+        bounds: quad_bounds,
+        intersect: quad_intersect,
+        area: quad_area,
+        sample: quad_sample,
+    };
+
+    static COLOUR: PluginTextureVTable = PluginTextureVTable {
+        create: colour_create,
+        destroy: colour_destroy,
+        evaluate: colour_evaluate,
+    };
+
+    extern "C" fn register(registrar: *const PluginRegistrar) {
+        let r = unsafe { &*registrar };
+        (r.register_shape)(
+            r.ctx,
+            b"plugin-test-quad\0".as_ptr() as *const c_char,
+            &QUAD,
+        );
+        (r.register_texture)(
+            r.ctx,
+            b"plugin-test-colour\0".as_ptr() as *const c_char,
+            &COLOUR,
+        );
+    }
+
+    #[test]
+    fn registered_plugin_types_are_created_from_params() {
+        register_plugin("test", register);
+
+        let mut ps = ParamSet::new();
+        ps.add_float("size", &[2.0]);
+        let identity = Arc::new(Transform::default());
+        let shapes = shape_constructor("plugin-test-quad").unwrap()(
+            &ps,
+            identity.clone(),
+            identity,
+            false,
+            &FloatTextureMap::new(),
+        )
+        .unwrap();
+        assert_eq!(shapes.len(), 1);
+        assert_eq!(shapes[0].area(), 16.0);
+
+        let miss = Ray::new(
+            Point3f::new(3.0, 0.0, 1.0),
+            Vector3f::new(0.0, 0.0, -1.0),
+            INFINITY,
+            0.0,
+            None,
+        );
+        assert!(!shapes[0].intersect_p(&miss, true));
+
+        let ray = Ray::new(
+            Point3f::new(1.5, 0.0, 1.0),
+            Vector3f::new(0.0, 0.0, -1.0),
+            INFINITY,
+            0.0,
+            None,
+        );
+        let hit = shapes[0].intersect(&ray, true).unwrap();
+        assert!((hit.t - 1.0).abs() < 1e-4);
+        assert_eq!(hit.isect.hit.n.z.abs(), 1.0);
+
+        // Texture parameters come from the texture's parameter set.
+        let tex2world = Transform::default();
+        let constructor = spectrum_texture_constructor("plugin-test-colour").unwrap();
+        assert!(constructor(&TextureParams::default(), &tex2world).is_err());
+
+        let mut ps = ParamSet::new();
+        ps.add_rgb_spectrum("value", &[0.25, 0.5, 0.75]).unwrap();
+        // The colour passes through linear RGB on its way to the plugin and
+        // back, which is only lossless with RGB spectra.
+        let expected = spectrum(&ps.find_spectrum("value")[0].to_rgb().map(f64::from)).to_rgb();
+        let tp = TextureParams::new(
+            ps,
+            ParamSet::new(),
+            FloatTextureMap::new(),
+            SpectrumTextureMap::new(),
+        );
+        let texture = constructor(&tp, &tex2world).unwrap();
+        let rgb = texture.evaluate(&hit.isect).to_rgb();
+        for (v, e) in rgb.iter().zip(expected.iter()) {
+            assert!((v - e).abs() < 1e-3);
+        }
+    }
+}
@@ -0,0 +1,169 @@
+//! Plugin Shapes
+
+use super::*;
+
+/// A shape implemented by a plugin.
+#[derive(Clone)]
+pub struct PluginShape {
+    /// Common shape data.
+    pub data: ShapeData,
+
+    /// The plugin's functions.
+    vtable: PluginShapeVTable,
+
+    /// The plugin's shape.
+    shape: Arc<PluginObject>,
+}
+
+impl PluginShape {
+    /// Creates a new plugin shape.
+    ///
+    /// * `name`                - Type name.
+    /// * `vtable`              - The plugin's functions.
+    /// * `paramset`            - Shape parameters.
+    /// * `object_to_world`     - The object to world transfomation.
+    /// * `world_to_object`     - The world to object transfomation.
+    /// * `reverse_orientation` - Indicates whether their surface normal directions
+    ///                           should be reversed from the default
+    pub fn new(
+        name: &str,
+        vtable: PluginShapeVTable,
+        paramset: &ParamSet,
+        object_to_world: ArcTransform,
+        world_to_object: ArcTransform,
+        reverse_orientation: bool,
+    ) -> Result<Self, Error> {
+        let shape =
+            PluginObject::create("shape", name, vtable.create, vtable.destroy, &[paramset])?;
+        Ok(Self {
+            data: ShapeData::new(object_to_world, Some(world_to_object), reverse_orientation),
+            vtable,
+            shape,
+        })
+    }
+
+    /// Intersects a world space ray with the shape and returns the object
+    /// space ray and hit.
+    ///
+    /// * `r` - The ray.
+    fn intersect_object(&self, r: &Ray) -> Option<(Ray, PluginShapeHit)> {
+        let (ray, _o_err, _d_err) = self
+            .data
+            .world_to_object
+            .clone()
+            .unwrap()
+            .transform_ray_with_error(r);
+
+        let plugin_ray = PluginRay {
+            o: [ray.o.x as f64, ray.o.y as f64, ray.o.z as f64],
+            d: [ray.d.x as f64, ray.d.y as f64, ray.d.z as f64],
+            t_max: ray.t_max as f64,
+            time: ray.time as f64,
+        };
+        let mut hit = PluginShapeHit::default();
+        if (self.vtable.intersect)(self.shape.get(), &plugin_ray, &mut hit)
+            && hit.t > 0.0
+            && hit.t < plugin_ray.t_max
+        {
+            Some((ray, hit))
+        } else {
+            None
+        }
+    }
+}
+
+impl Shape for PluginShape {
+    /// Returns the underlying shape data.
+    fn get_data(&self) -> ShapeData {
+        self.data.clone()
+    }
+
+    /// Returns a bounding box in the shapes object space.
+    fn object_bound(&self) -> Bounds3f {
+        let mut b = PluginBounds::default();
+        (self.vtable.bounds)(self.shape.get(), &mut b);
+        Bounds3f::new(point3(&b.min), point3(&b.max))
+    }
+
+    /// Returns geometric details if a ray intersects the shape intersection.
+    /// If there is no intersection, `None` is returned.
+    ///
+    /// * `r`                  - The ray.
+    /// * `test_alpha_texture` - Perform alpha texture tests (not supported).
+    fn intersect<'a>(&self, r: &Ray, _test_alpha_texture: bool) -> Option<Intersection<'a>> {
+        let (ray, hit) = self.intersect_object(r)?;
+
+        let si = SurfaceInteraction::new(
+            point3(&hit.p),
+            Vector3f::default(),
+            Point2f::new(hit.uv[0] as Float, hit.uv[1] as Float),
+            -ray.d,
+            vector3(&hit.dpdu),
+            vector3(&hit.dpdv),
+            Normal3f::default(),
+            Normal3f::default(),
+            ray.time,
+            Some(Arc::new(self.clone())),
+        );
+
+        let isect = self.data.object_to_world.transform_surface_interaction(&si);
+        Some(Intersection::new(hit.t as Float, isect))
+    }
+
+    /// Returns `true` if a ray-shape intersection succeeds; otherwise `false`.
+    ///
+    /// * `r`                  - The ray.
+    /// * `test_alpha_texture` - Perform alpha texture tests (not supported).
+    fn intersect_p(&self, r: &Ray, _test_alpha_texture: bool) -> bool {
+        self.intersect_object(r).is_some()
+    }
+
+    /// Returns the surface area of the shape in object space.
+    fn area(&self) -> Float {
+        (self.vtable.area)(self.shape.get()) as Float
+    }
+
+    /// Sample a point on the surface and return the PDF with respect to area on
+    /// the surface.
+    ///
+    /// NOTE: The returned `Hit` value will have `wo` = Vector3f::default().
+    ///
+    /// * `u` - Sample value to use.
+    fn sample_area(&self, u: &Point2f) -> (Hit, Float) {
+        let mut s = PluginShapeSample::default();
+        (self.vtable.sample)(self.shape.get(), &[u.x as f64, u.y as f64], &mut s);
+
+        let p_obj = point3(&s.p);
+        let n_obj = Normal3f::from(vector3(&s.n));
+        let mut n = self
+            .data
+            .object_to_world
+            .transform_normal(&n_obj)
+            .normalize();
+        if self.data.reverse_orientation {
+            n *= -1.0;
+        }
+
+        let p = self.data.object_to_world.transform_point(&p_obj);
+        let p_error = self
+            .data
+            .object_to_world
+            .transform_point_abs_error(&p_obj, &Vector3f::default());
+        let it = Hit::new(p, 0.0, p_error, Vector3f::default(), n, None);
+        (it, 1.0 / self.area())
+    }
+}
+
+/// Returns a point from plugin coordinates.
+///
+/// * `v` - The coordinates.
+fn point3(v: &[f64; 3]) -> Point3f {
+    Point3f::new(v[0] as Float, v[1] as Float, v[2] as Float)
+}
+
+/// Returns a vector from plugin coordinates.
+///
+/// * `v` - The coordinates.
+fn vector3(v: &[f64; 3]) -> Vector3f {
+    Vector3f::new(v[0] as Float, v[1] as Float, v[2] as Float)
+}
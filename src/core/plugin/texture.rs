@@ -0,0 +1,58 @@
+//! Plugin Textures
+
+use super::*;
+
+/// A texture implemented by a plugin.
+pub struct PluginTexture {
+    /// The plugin's functions.
+    vtable: PluginTextureVTable,
+
+    /// The plugin's texture.
+    texture: Arc<PluginObject>,
+}
+
+impl PluginTexture {
+    /// Creates a new plugin texture.
+    ///
+    /// * `name`   - Type name.
+    /// * `vtable` - The plugin's functions.
+    /// * `tp`     - Texture parameter set.
+    pub fn new(name: &str, vtable: PluginTextureVTable, tp: &TextureParams) -> Result<Self, Error> {
+        let texture = PluginObject::create(
+            "texture",
+            name,
+            vtable.create,
+            vtable.destroy,
+            &[&tp.geom_params, &tp.mat_params],
+        )?;
+        Ok(Self { vtable, texture })
+    }
+
+    /// Returns the plugin's RGB value at a surface interaction.
+    ///
+    /// * `si` - Surface interaction.
+    fn evaluate_rgb(&self, si: &SurfaceInteraction) -> [f64; 3] {
+        let point = PluginShadingPoint::from(si);
+        let mut rgb = [0.0; 3];
+        (self.vtable.evaluate)(self.texture.get(), &point, &mut rgb);
+        rgb
+    }
+}
+
+impl Texture<Float> for PluginTexture {
+    /// Evaluate the texture at surface interaction.
+    ///
+    /// * `si` - Surface interaction.
+    fn evaluate(&self, si: &SurfaceInteraction) -> Float {
+        self.evaluate_rgb(si)[0] as Float
+    }
+}
+
+impl Texture<Spectrum> for PluginTexture {
+    /// Evaluate the texture at surface interaction.
+    ///
+    /// * `si` - Surface interaction.
+    fn evaluate(&self, si: &SurfaceInteraction) -> Spectrum {
+        spectrum(&self.evaluate_rgb(si))
+    }
+}
@@ -4,6 +4,7 @@
 use crate::core::geometry::*;
 use crate::core::light::*;
 use crate::core::material::*;
+use crate::core::primitives::*;
 use std::sync::Arc;
 
 /// Primitive trait provide common behavior.
@@ -23,6 +24,44 @@ pub trait Primitive {
     /// * `r`                  - The ray.
     fn intersect_p(&self, r: &Ray) -> bool;
 
+    /// Returns the vertices of the triangles of the primitive in world space.
+    /// Aggregates return the triangles of all their primitives. Shapes that
+    /// are not triangles contribute no triangles.
+    fn triangles(&self) -> Vec<[Point3f; 3]> {
+        vec![]
+    }
+
+    /// Visits the primitive and, for aggregates and transformed primitives,
+    /// the primitives it contains.
+    ///
+    /// * `_visitor` - The visitor.
+    fn accept(&self, _visitor: &mut dyn PrimitiveVisitor) {}
+
+    /// Returns a copy of the primitive that uses the material `new` wherever
+    /// it used `old`; or `None` if it doesn't use `old`. Aggregates keep
+    /// their structure since the geometry is unchanged.
+    ///
+    /// * `old` - The material to replace.
+    /// * `new` - The new material.
+    fn replace_material(&self, _old: &ArcMaterial, _new: &ArcMaterial) -> Option<ArcPrimitive> {
+        None
+    }
+
+    /// Returns a copy of a transformed primitive with a new transformation;
+    /// or `None` if the primitive isn't transformed.
+    ///
+    /// * `_primitive_to_world` - The new animated transform.
+    fn with_transform(&self, _primitive_to_world: AnimatedTransform) -> Option<ArcPrimitive> {
+        None
+    }
+
+    /// Returns `true` if the primitive is held out of the image. Held out
+    /// primitives occlude other objects and cast shadows but render black
+    /// with zero alpha so that other footage can be composited in their place.
+    fn is_holdout(&self) -> bool {
+        false
+    }
+
     /// Returns a reference to the AreaLight that describes the primitive’s
     /// emission distribution, if the primitive is itself a light source.
     /// If the primitive is not emissive, this method should return `None`.  
@@ -55,8 +94,57 @@ pub trait Primitive {
 /// Atomic referenced counted `Primitive`.
 pub type ArcPrimitive = Arc<dyn Primitive + Send + Sync>;
 
+/// Visitor for the primitives making up a scene.
+pub trait PrimitiveVisitor {
+    /// Visits a primitive with a shape.
+    ///
+    /// * `primitive` - The primitive.
+    fn visit_geometric(&mut self, primitive: &GeometricPrimitive);
+
+    /// Visits a transformed primitive, i.e. an object instance or animated
+    /// shape, and returns whether to visit the primitive it transforms.
+    ///
+    /// * `primitive` - The primitive.
+    fn visit_transformed(&mut self, primitive: &TransformedPrimitive) -> bool;
+
+    /// Visits an aggregate before the primitives it contains.
+    ///
+    /// * `n_nodes` - Number of nodes in the acceleration structure.
+    /// * `bytes`   - Memory used by the nodes.
+    fn visit_aggregate(&mut self, n_nodes: usize, bytes: usize);
+}
+
 /// Aggregate trait defines common behaviours for ray intersection accelerators.
 pub trait Aggregate: Primitive {}
 
+/// Returns a copy of a list of primitives where those that use the material
+/// `old` use `new` instead; or `None` if none of them use `old`.
+///
+/// * `primitives` - The primitives.
+/// * `old`        - The material to replace.
+/// * `new`        - The new material.
+pub fn replace_material(
+    primitives: &[ArcPrimitive],
+    old: &ArcMaterial,
+    new: &ArcMaterial,
+) -> Option<Vec<ArcPrimitive>> {
+    let mut replaced = false;
+    let primitives = primitives
+        .iter()
+        .map(|p| match p.replace_material(old, new) {
+            Some(p) => {
+                replaced = true;
+                p
+            }
+            None => p.clone(),
+        })
+        .collect();
+    if replaced {
+        Some(primitives)
+    } else {
+        None
+    }
+}
+
 /// Atomic referenced counted `Aggregate`.
 pub type ArcAggregate = Arc<dyn Aggregate + Send + Sync>;
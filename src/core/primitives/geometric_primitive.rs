@@ -6,6 +6,8 @@ use crate::core::light::*;
 use crate::core::material::*;
 use crate::core::medium::*;
 use crate::core::primitive::*;
+use std::ptr;
+use std::sync::Arc;
 
 /// GeometricPrimitive represents a single shape in a scene.
 #[derive(Clone)]
@@ -23,30 +25,41 @@ pub struct GeometricPrimitive {
     /// Information about the participating media on the inside and outside
     /// the primitive.
     pub medium_interface: MediumInterface,
+
+    /// Hold the primitive out of the image.
+    pub holdout: bool,
 }
 
 impl GeometricPrimitive {
     /// Create a new geometric primitive.
     ///
     /// * `shape`            - The shape.
-    /// * `material`         - The material.
+    /// * `material`         - The material; `None` for a surface that only
+    ///                        bounds participating media.
     /// * `area_light`       - Optional area light that describes emmission
     ///                        characterisitics if it emits light.
     /// * `medium_interface` - Information about the participating media on the
     ///                        inside and outside the primitive.
     pub fn new(
         shape: ArcShape,
-        material: ArcMaterial,
+        material: Option<ArcMaterial>,
         area_light: Option<ArcAreaLight>,
         medium_interface: MediumInterface,
     ) -> Self {
         Self {
             shape: shape.clone(),
-            material: Some(material.clone()),
+            material: material.clone(),
             area_light: area_light.clone(),
             medium_interface: medium_interface.clone(),
+            holdout: false,
         }
     }
+
+    /// Returns `true` if rays pass through the primitive; it has neither a
+    /// material nor an area light.
+    fn is_pass_through(&self) -> bool {
+        self.material.is_none() && self.area_light.is_none()
+    }
 }
 
 impl Primitive for GeometricPrimitive {
@@ -57,10 +70,17 @@ impl Primitive for GeometricPrimitive {
 
     /// Returns geometric details if a ray intersects the primitive and updates
     /// the t_max parameter of the ray. If there is no intersection, `None` is
-    /// returned.
+    /// returned. Primitives without a material or area light are passed
+    /// through unless they mark a medium boundary, which integrators need to
+    /// see to track the medium of the ray.
     ///
     /// * `r`                  - The ray.
     fn intersect(&self, r: &mut Ray) -> Option<SurfaceInteraction> {
+        let is_medium_transition = self.medium_interface.is_medium_transition();
+        if self.is_pass_through() && !is_medium_transition {
+            return None;
+        }
+
         if let Some(mut it) = self.shape.intersect(r, true) {
             r.t_max = it.t;
             it.isect.primitive = Some(self);
@@ -69,7 +89,6 @@ impl Primitive for GeometricPrimitive {
 
             // Initialize SurfaceInteraction::mediumInterface after Shape
             // intersection.
-            let is_medium_transition = self.medium_interface.is_medium_transition();
             it.isect.hit.medium_interface = if is_medium_transition {
                 Some(self.medium_interface.clone())
             } else if let Some(medium) = r.medium.clone() {
@@ -85,10 +104,47 @@ impl Primitive for GeometricPrimitive {
     }
 
     /// Returns `true` if a ray-primitive intersection succeeds; otherwise `false`.
+    /// Primitives without a material or area light don't block rays, even if
+    /// they mark a medium boundary; the attenuation of the media is accounted
+    /// for by the beam transmittance.
     ///
     /// * `r`                  - The ray.
     fn intersect_p(&self, r: &Ray) -> bool {
-        self.shape.intersect_p(r, true)
+        !self.is_pass_through() && self.shape.intersect_p(r, true)
+    }
+
+    /// Returns the vertices of the triangles of the shape in world space.
+    fn triangles(&self) -> Vec<[Point3f; 3]> {
+        self.shape.triangles()
+    }
+
+    /// Visits the primitive.
+    ///
+    /// * `visitor` - The visitor.
+    fn accept(&self, visitor: &mut dyn PrimitiveVisitor) {
+        visitor.visit_geometric(self);
+    }
+
+    /// Returns a copy of the primitive with the material `new` if it uses
+    /// `old`; otherwise `None`.
+    ///
+    /// * `old` - The material to replace.
+    /// * `new` - The new material.
+    fn replace_material(&self, old: &ArcMaterial, new: &ArcMaterial) -> Option<ArcPrimitive> {
+        match self.material.as_ref() {
+            Some(material) if ptr::addr_eq(Arc::as_ptr(material), Arc::as_ptr(old)) => {
+                Some(Arc::new(Self {
+                    material: Some(new.clone()),
+                    ..self.clone()
+                }))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the primitive is held out of the image.
+    fn is_holdout(&self) -> bool {
+        self.holdout
     }
 
     /// Returns a reference to the AreaLight that describes the primitive’s
@@ -5,6 +5,7 @@ use crate::core::geometry::*;
 use crate::core::light::*;
 use crate::core::material::*;
 use crate::core::primitive::*;
+use std::sync::Arc;
 
 /// TransformedPrimitive stores an underlying primitive and animated transform
 /// and is used for object instancing and animated transformations.
@@ -69,6 +70,55 @@ impl Primitive for TransformedPrimitive {
         self.primitive.intersect_p(&mut ray)
     }
 
+    /// Returns the vertices of the triangles of the primitive in world space
+    /// at time 0.
+    fn triangles(&self) -> Vec<[Point3f; 3]> {
+        let prim_to_world = self.primitive_to_world.interpolate(0.0);
+        self.primitive
+            .triangles()
+            .iter()
+            .map(|t| {
+                [
+                    prim_to_world.transform_point(&t[0]),
+                    prim_to_world.transform_point(&t[1]),
+                    prim_to_world.transform_point(&t[2]),
+                ]
+            })
+            .collect()
+    }
+
+    /// Visits the primitive and the primitive it transforms unless the
+    /// visitor skips it.
+    ///
+    /// * `visitor` - The visitor.
+    fn accept(&self, visitor: &mut dyn PrimitiveVisitor) {
+        if visitor.visit_transformed(self) {
+            self.primitive.accept(visitor);
+        }
+    }
+
+    /// Returns a copy of the primitive where the underlying primitive uses
+    /// the material `new` wherever it used `old`; or `None` if it doesn't use
+    /// `old`.
+    ///
+    /// * `old` - The material to replace.
+    /// * `new` - The new material.
+    fn replace_material(&self, old: &ArcMaterial, new: &ArcMaterial) -> Option<ArcPrimitive> {
+        self.primitive.replace_material(old, new).map(|primitive| {
+            Arc::new(Self::new(primitive, self.primitive_to_world.clone())) as ArcPrimitive
+        })
+    }
+
+    /// Returns a copy of the primitive with a new transformation.
+    ///
+    /// * `primitive_to_world` - The new animated transform.
+    fn with_transform(&self, primitive_to_world: AnimatedTransform) -> Option<ArcPrimitive> {
+        Some(Arc::new(Self::new(
+            self.primitive.clone(),
+            primitive_to_world,
+        )))
+    }
+
     /// Returns a reference to the AreaLight that describes the primitive’s
     /// emission distribution, if the primitive is itself a light source.
     /// If the primitive is not emissive, this method should return `None`.  
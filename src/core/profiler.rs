@@ -0,0 +1,330 @@
+//! Profiler
+
+#![allow(dead_code)]
+use std::cell::RefCell;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Categories of work measured by the profiler. Time spent in a phase that
+/// is nested inside other phases is attributed to the combination of all
+/// active phases. Categories are listed roughly from outermost to innermost;
+/// the last active category of a combination is treated as the innermost.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Prof {
+    SceneParsing = 0,
+    SceneConstruction,
+    AccelConstruction,
+    TextureLoading,
+    MIPMapCreation,
+    IntegratorRender,
+    SamplerIntegratorLi,
+    DirectLighting,
+    AccelIntersect,
+    AccelIntersectP,
+    LightSample,
+    LightPdf,
+    ComputeScatteringFuncs,
+    BSDFEvaluation,
+    BSDFSampling,
+    BSDFPdf,
+    MergeFilmTile,
+    AddFilmSample,
+    StartPixel,
+    GenerateCameraRay,
+    TexFiltTrilerp,
+    TexFiltEWA,
+    ImageWrite,
+}
+
+/// Number of profiler categories.
+pub const NUM_PROF_CATEGORIES: usize = 23;
+
+/// Names of the profiler categories.
+const PROF_NAMES: [&str; NUM_PROF_CATEGORIES] = [
+    "Scene parsing",
+    "Scene construction",
+    "Acceleration structure construction",
+    "Texture loading",
+    "MIPMap creation",
+    "Integrator::render()",
+    "SamplerIntegrator::li()",
+    "Direct lighting",
+    "Accelerator::intersect()",
+    "Accelerator::intersect_p()",
+    "Light::sample_li()",
+    "Light::pdf_li()",
+    "Material::compute_scattering_functions()",
+    "BSDF::f()",
+    "BSDF::sample_f()",
+    "BSDF::pdf()",
+    "Film::merge_film_tile()",
+    "FilmTile::add_sample()",
+    "Sampler::start_pixel()",
+    "Camera::generate_ray[_differential]()",
+    "MIPMap::lookup() (trilinear)",
+    "MIPMap::lookup() (EWA)",
+    "Image writing",
+];
+
+/// Whether or not the profiler is measuring time.
+static PROFILER_ENABLED: AtomicBool = AtomicBool::new(false);
+
+lazy_static! {
+    /// Accumulates the time reported by all threads keyed by the set of
+    /// active categories.
+    static ref ACCUMULATOR: Mutex<HashMap<u64, (Duration, u64)>> = Mutex::new(HashMap::new());
+}
+
+thread_local! {
+    /// Profiler state for the current thread.
+    static STATE: RefCell<ThreadState> = RefCell::new(ThreadState::default());
+}
+
+/// Profiler state for a thread.
+#[derive(Default)]
+struct ThreadState {
+    /// Bit mask of the active categories.
+    current: u64,
+
+    /// Time spent and number of phases ended keyed by the set of active
+    /// categories excluding time spent in nested phases.
+    times: HashMap<u64, (Duration, u64)>,
+
+    /// Time spent in nested phases for each active phase.
+    nested: Vec<Duration>,
+}
+
+/// Enables or disables the profiler.
+///
+/// * `enabled` - Whether or not to measure time.
+pub fn set_profiler_enabled(enabled: bool) {
+    PROFILER_ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Returns `true` if the profiler is measuring time.
+pub fn profiler_enabled() -> bool {
+    PROFILER_ENABLED.load(Ordering::Relaxed)
+}
+
+/// Scoped timer that attributes the time until it is dropped to a profiler
+/// category.
+///
+/// ```ignore
+/// let _p = ProfilePhase::new(Prof::AccelIntersect);
+/// ```
+pub struct ProfilePhase {
+    /// The mask of previously active categories and the time when the phase
+    /// started; `None` if the profiler is disabled or the category was already
+    /// active.
+    state: Option<(u64, Instant)>,
+}
+
+impl ProfilePhase {
+    /// Starts timing a phase.
+    ///
+    /// * `category` - The profiler category.
+    pub fn new(category: Prof) -> Self {
+        if !profiler_enabled() {
+            return Self { state: None };
+        }
+
+        let bit = 1_u64 << category as u64;
+        let previous = STATE.with(|s| {
+            let mut s = s.borrow_mut();
+            if s.current & bit != 0 {
+                // Recursive phases are attributed to the outermost one.
+                None
+            } else {
+                let previous = s.current;
+                s.current |= bit;
+                s.nested.push(Duration::default());
+                Some(previous)
+            }
+        });
+        Self {
+            state: previous.map(|p| (p, Instant::now())),
+        }
+    }
+}
+
+impl Drop for ProfilePhase {
+    /// Ends the phase and records the time spent in it.
+    fn drop(&mut self) {
+        if let Some((previous, start)) = self.state {
+            let elapsed = start.elapsed();
+            STATE.with(|s| {
+                let mut s = s.borrow_mut();
+                let nested = s.nested.pop().unwrap_or_default();
+                let current = s.current;
+                let entry = s.times.entry(current).or_default();
+                entry.0 += elapsed.checked_sub(nested).unwrap_or_default();
+                entry.1 += 1;
+                if let Some(parent) = s.nested.last_mut() {
+                    *parent += elapsed;
+                }
+                s.current = previous;
+            });
+        }
+    }
+}
+
+/// Runs a function without attributing the time spent in it to the active
+/// phases of the current thread. This is used while a thread waits for worker
+/// threads whose time is measured separately.
+///
+/// * `f` - The function to run.
+pub fn unprofiled<F, R>(f: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    if !profiler_enabled() {
+        return f();
+    }
+
+    let start = Instant::now();
+    let result = f();
+    let elapsed = start.elapsed();
+    STATE.with(|s| {
+        if let Some(parent) = s.borrow_mut().nested.last_mut() {
+            *parent += elapsed;
+        }
+    });
+    result
+}
+
+/// Merges the times measured by the current thread into the report and
+/// resets them. Worker threads should call this when they finish a unit of
+/// work.
+pub fn report_thread_profile() {
+    let times = STATE.with(|s| std::mem::take(&mut s.borrow_mut().times));
+    if !times.is_empty() {
+        let mut accum = ACCUMULATOR.lock().unwrap();
+        for (mask, (time, count)) in times {
+            let entry = accum.entry(mask).or_default();
+            entry.0 += time;
+            entry.1 += count;
+        }
+    }
+}
+
+/// Returns the formatted profile of the times merged so far. The first table
+/// lists the time spent in each category excluding nested phases; the second
+/// one lists each combination of nested phases.
+pub fn profile_report() -> String {
+    let accum = ACCUMULATOR.lock().unwrap();
+    let total: Duration = accum.values().map(|(t, _)| *t).sum();
+    if total == Duration::default() {
+        return String::from("Profile: no data\n");
+    }
+    let percent = |t: Duration| 100.0 * t.as_secs_f64() / total.as_secs_f64();
+
+    let mut s = String::from("Profile:\n");
+    for (name, time) in flat_times(&accum) {
+        writeln!(
+            s,
+            "  {:<60}{:>10.3}s {:>6.2} %",
+            name,
+            time.as_secs_f64(),
+            percent(time)
+        )
+        .unwrap();
+    }
+
+    let mut nested: Vec<(&u64, &(Duration, u64))> = accum.iter().collect();
+    nested.sort_by_key(|&(_, (t, _))| Reverse(*t));
+    s.push_str("Profile (nested phases):\n");
+    for (mask, (time, count)) in nested {
+        writeln!(
+            s,
+            "  {}\n  {:<60}{:>10.3}s {:>6.2} % ({} calls)",
+            category_path(*mask),
+            "",
+            time.as_secs_f64(),
+            percent(*time),
+            count
+        )
+        .unwrap();
+    }
+    s
+}
+
+/// Returns the time spent in each category excluding nested phases merged
+/// so far, longest first. Categories without any time are omitted.
+pub fn profile_times() -> Vec<(&'static str, Duration)> {
+    flat_times(&ACCUMULATOR.lock().unwrap())
+}
+
+/// Returns the time of each category with the time of each combination of
+/// nested phases attributed to its innermost category, longest first.
+///
+/// * `accum` - Times of each combination of nested phases.
+fn flat_times(accum: &HashMap<u64, (Duration, u64)>) -> Vec<(&'static str, Duration)> {
+    let mut flat = [Duration::default(); NUM_PROF_CATEGORIES];
+    for (mask, (time, _)) in accum.iter() {
+        flat[63 - mask.leading_zeros() as usize] += *time;
+    }
+    let mut flat: Vec<(&'static str, Duration)> = flat
+        .iter()
+        .zip(PROF_NAMES.iter())
+        .filter(|(t, _)| **t > Duration::default())
+        .map(|(t, name)| (*name, *t))
+        .collect();
+    flat.sort_by_key(|&(_, t)| Reverse(t));
+    flat
+}
+
+/// Prints the profile of the times merged so far to standard output.
+pub fn print_profile() {
+    print!("{}", profile_report());
+}
+
+/// Clears the times merged so far.
+pub fn clear_profile() {
+    ACCUMULATOR.lock().unwrap().clear();
+}
+
+/// Returns the names of the categories in a mask separated by "/".
+///
+/// * `mask` - Bit mask of categories.
+fn category_path(mask: u64) -> String {
+    (0..NUM_PROF_CATEGORIES)
+        .filter(|&i| mask & (1 << i) != 0)
+        .map(|i| PROF_NAMES[i])
+        .collect::<Vec<&str>>()
+        .join(" / ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nested_time_is_excluded_from_outer_phase() {
+        set_profiler_enabled(true);
+        std::thread::spawn(|| {
+            {
+                let _outer = ProfilePhase::new(Prof::IntegratorRender);
+                std::thread::sleep(Duration::from_millis(20));
+                {
+                    let _inner = ProfilePhase::new(Prof::AccelIntersect);
+                    std::thread::sleep(Duration::from_millis(40));
+                }
+            }
+            let times = STATE.with(|s| s.borrow().times.clone());
+            let outer = times[&(1 << Prof::IntegratorRender as u64)].0;
+            let inner = times
+                [&((1 << Prof::IntegratorRender as u64) | (1 << Prof::AccelIntersect as u64))]
+                .0;
+            assert!(inner >= Duration::from_millis(40));
+            assert!(outer >= Duration::from_millis(20));
+            assert!(outer < inner);
+        })
+        .join()
+        .unwrap();
+        set_profiler_enabled(false);
+    }
+}
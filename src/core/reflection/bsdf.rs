@@ -2,6 +2,7 @@
 
 #![allow(dead_code)]
 use super::*;
+use crate::core::profiler::*;
 use crate::core::rng::*;
 
 /// Maximum number of BxDFs that can be stored in `BSDF`.
@@ -29,6 +30,10 @@ pub struct BSDF {
 
     /// Relative index of refraction over the surfaceboundary.
     pub eta: Float,
+
+    /// Optional re-radiation of incident light to other wavelengths. It is
+    /// scattered diffusely into the reflection hemisphere.
+    pub reradiation: Option<Arc<ReradiationMatrix>>,
 }
 
 impl BSDF {
@@ -50,6 +55,7 @@ impl BSDF {
             ss,
             ts: Vector3::from(ns).cross(&ss),
             bxdfs: Vec::with_capacity(MAX_BXDFS),
+            reradiation: None,
         }
     }
 
@@ -65,6 +71,26 @@ impl BSDF {
         self.bxdfs.push(bxdf.clone());
     }
 
+    /// Returns a copy of the BSDF whose near-specular `BxDF`s are replaced by
+    /// rougher ones. Integrators use this after the first diffuse or glossy
+    /// bounce to avoid fireflies from small, bright lights seen through
+    /// specular surfaces.
+    pub fn regularized(&self) -> Self {
+        Self {
+            ns: self.ns,
+            ng: self.ng,
+            ss: self.ss,
+            ts: self.ts,
+            bxdfs: self
+                .bxdfs
+                .iter()
+                .flat_map(|bxdf| bxdf.regularize().unwrap_or_else(|| vec![bxdf.clone()]))
+                .collect(),
+            eta: self.eta,
+            reradiation: self.reradiation.clone(),
+        }
+    }
+
     /// Returns the number of `BxDF`s that match the given type.
     ///
     /// * `bxdf_type` - The `BxdFType` to match (default to `BSDF_ALL`).
@@ -96,24 +122,102 @@ impl BSDF {
     /// * `wi_w`      - Incident direction in world-space.
     /// * `bxdf_type` - The `BxdFType` to evaluate.
     pub fn f(&self, wo_w: &Vector3f, wi_w: &Vector3f, bxdf_type: BxDFType) -> Spectrum {
+        let _p = ProfilePhase::new(Prof::BSDFEvaluation);
         let wi = self.world_to_local(wi_w);
         let wo = self.world_to_local(wo_w);
 
         if wo.z == 0.0 {
             Spectrum::new(0.0)
         } else {
-            let reflect = wi_w.dot(&self.ng) * wo_w.dot(&self.ng) > 0.0;
+            let reflect = self.is_reflection(wo_w, wi_w);
             self.bxdfs
                 .iter()
-                .filter(|bxdf| {
-                    bxdf.matches(bxdf_type)
-                        && ((reflect && bxdf.get_type().matches(BSDF_REFLECTION))
-                            || (!reflect && bxdf.get_type().matches(BSDF_TRANSMISSION)))
-                })
+                .filter(|bxdf| bxdf.matches(bxdf_type) && Self::on_side(bxdf.get_type(), reflect))
                 .fold(Spectrum::new(0.0), |a, bxdf| a + bxdf.f(&wo, &wi))
         }
     }
 
+    /// Returns `true` if a pair of directions are on the same side of the
+    /// surface. The geometric normal decides this rather than the shading
+    /// normal, which can place both directions in the same hemisphere when
+    /// light actually passes through the surface, or vice versa.
+    ///
+    /// * `wo_w` - Outgoing direction in world-space.
+    /// * `wi_w` - Incident direction in world-space.
+    fn is_reflection(&self, wo_w: &Vector3f, wi_w: &Vector3f) -> bool {
+        wi_w.dot(&self.ng) * wo_w.dot(&self.ng) > 0.0
+    }
+
+    /// Returns `true` if a lobe scatters light to the side of the surface
+    /// given by `is_reflection()`.
+    ///
+    /// * `lobe`    - The `BxDFType` of the lobe.
+    /// * `reflect` - `true` if the directions are on the same side.
+    fn on_side(lobe: BxDFType, reflect: bool) -> bool {
+        if reflect {
+            lobe.matches(BSDF_REFLECTION)
+        } else {
+            lobe.matches(BSDF_TRANSMISSION)
+        }
+    }
+
+    /// Returns the radiance re-emitted towards `wo_w` at other wavelengths for
+    /// incident radiance `li` from `wi_w`. This is in addition to the radiance
+    /// scattered by `f()` and is zero unless the BSDF has a re-radiation
+    /// matrix.
+    ///
+    /// * `wo_w`      - Outgoing direction in world-space.
+    /// * `wi_w`      - Incident direction in world-space.
+    /// * `li`        - Incident radiance.
+    /// * `bxdf_type` - The `BxdFType` to evaluate.
+    pub fn reradiate(
+        &self,
+        wo_w: &Vector3f,
+        wi_w: &Vector3f,
+        li: &Spectrum,
+        bxdf_type: BxDFType,
+    ) -> Spectrum {
+        match self.reradiation.as_ref() {
+            Some(m)
+                if bxdf_type.matches(BSDF_REFLECTION)
+                    && bxdf_type.matches(BSDF_DIFFUSE)
+                    && self.is_reflection(wo_w, wi_w) =>
+            {
+                m.apply(li) * INV_PI
+            }
+            _ => Spectrum::new(0.0),
+        }
+    }
+
+    /// Returns the Mueller matrix that takes the place of `f() * |cos(θi)|` when
+    /// transporting polarized radiance from `wi_w` to `wo_w`. The reference
+    /// direction of the incident and outgoing Stokes vectors is perpendicular
+    /// to the plane of incidence. Returns `None` if none of the matching BxDFs
+    /// model polarization.
+    ///
+    /// * `wo_w`      - Outgoing direction in world-space.
+    /// * `wi_w`      - Incident direction in world-space.
+    /// * `bxdf_type` - The `BxdFType` to evaluate.
+    pub fn mueller(
+        &self,
+        wo_w: &Vector3f,
+        wi_w: &Vector3f,
+        bxdf_type: BxDFType,
+    ) -> Option<MuellerMatrix> {
+        let wi = self.world_to_local(wi_w);
+        let wo = self.world_to_local(wo_w);
+        if wo.z == 0.0 {
+            return None;
+        }
+
+        let reflect = self.is_reflection(wo_w, wi_w);
+        self.bxdfs
+            .iter()
+            .filter(|bxdf| bxdf.matches(bxdf_type) && Self::on_side(bxdf.get_type(), reflect))
+            .filter_map(|bxdf| bxdf.mueller(&wo, &wi))
+            .fold(None, |a, m| Some(a.map_or(m, |a| a + m)))
+    }
+
     /// Returns the value of the BSDF given the outgpoing direction.
     /// direction.
     ///
@@ -121,6 +225,7 @@ impl BSDF {
     /// * `u`         - The 2D uniform random values.
     /// * `bxdf_type` - The `BxdFType` to evaluate.
     pub fn sample_f(&self, wo_w: &Vector3f, u: &Point2f, bxdf_type: BxDFType) -> BxDFSample {
+        let _p = ProfilePhase::new(Prof::BSDFSampling);
         // Choose which `BxDF` to sample.
         let matching_comps = self.num_components(bxdf_type);
         if matching_comps == 0 {
@@ -134,8 +239,8 @@ impl BSDF {
         // Get BxDF for chosen component.
         let mut count = comp;
         let mut bxdf: Option<ArcBxDF> = None;
-        for b in self.bxdfs.iter() {
-            if b.matches(bxdf_type) && count == 0 {
+        for b in self.bxdfs.iter().filter(|b| b.matches(bxdf_type)) {
+            if count == 0 {
                 bxdf = Some(b.clone());
                 break;
             }
@@ -166,7 +271,7 @@ impl BSDF {
         // Compute overall PDF with all matching BxDFs.
         if !(bxdf.get_type().matches(BSDF_SPECULAR) && matching_comps > 1) {
             for b in self.bxdfs.iter() {
-                if Arc::ptr_eq(&b, &bxdf) && b.matches(bxdf_type) {
+                if !Arc::ptr_eq(&b, &bxdf) && b.matches(bxdf_type) {
                     pdf += b.pdf(&wo, &sample.wi);
                 }
             }
@@ -175,17 +280,16 @@ impl BSDF {
             pdf /= matching_comps as Float;
         }
 
-        // Compute value of BSDF for sampled direction.
+        // Compute value of BSDF for sampled direction. A specular sample that
+        // leaves on the wrong side of the geometric surface carries no light.
+        let reflect = self.is_reflection(wo_w, &wi_world);
         let f = if !(bxdf.get_type().matches(BSDF_SPECULAR)) {
-            let reflect = wi_world.dot(&self.ng) * wo_w.dot(&self.ng) > 0.0;
             self.bxdfs
                 .iter()
-                .filter(|bxdf| {
-                    bxdf.matches(bxdf_type)
-                        && ((reflect && bxdf.get_type().matches(BSDF_REFLECTION))
-                            || (!reflect && bxdf.get_type().matches(BSDF_TRANSMISSION)))
-                })
+                .filter(|bxdf| bxdf.matches(bxdf_type) && Self::on_side(bxdf.get_type(), reflect))
                 .fold(Spectrum::new(0.0), |a, bxdf| a + bxdf.f(&wo, &sample.wi))
+        } else if Self::on_side(sampled_type, reflect) {
+            sample.f
         } else {
             Spectrum::new(0.0)
         };
@@ -225,6 +329,7 @@ impl BSDF {
     /// * `wi_w`      - Incident direction in world-space.
     /// * `bxdf_type` - The `BxdFType` to evaluate.
     pub fn pdf(&self, wo_w: &Vector3f, wi_w: &Vector3f, bxdf_type: BxDFType) -> Float {
+        let _p = ProfilePhase::new(Prof::BSDFPdf);
         if self.bxdfs.len() == 0 {
             return 0.0;
         }
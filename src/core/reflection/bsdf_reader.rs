@@ -1,9 +1,10 @@
 //! BSDF Reader
 
+use crate::core::error::*;
 use byteorder::{BigEndian, LittleEndian, ReadBytesExt};
 use std::fs::File;
 use std::io::Read;
-use std::{mem, slice, str};
+use std::{mem, slice};
 
 /// The first 8 byetes of BSDF file are the header `SCATFUN` terminated with
 /// char `0x01`.
@@ -26,10 +27,10 @@ fn is_big_endian() -> bool {
 /// Opens a file for reading or returns an error if unable to do so.
 ///
 /// * `path` - The file path.
-pub fn open_file(path: &str) -> Result<File, String> {
+pub fn open_file(path: &str) -> Result<File, Error> {
     match File::open(path) {
         Ok(file) => Ok(file),
-        Err(err) => Err(format!("Could not open {}. {}", path, err)),
+        Err(err) => Err(Error::Io(format!("Could not open {}. {}", path, err))),
     }
 }
 
@@ -40,74 +41,74 @@ pub fn open_file(path: &str) -> Result<File, String> {
 pub trait BSDFReader {
     /// Reads the header bytes and compares them to the expected
     /// header.
-    fn check_header(&mut self) -> Result<(), String>;
+    fn check_header(&mut self) -> Result<(), Error>;
 
     /// Reads one 32-bit unsigned value.
-    fn read_i32(&mut self) -> Result<i32, String>;
+    fn read_i32(&mut self) -> Result<i32, Error>;
 
     /// Reads one 32-bit floating point value.
-    fn read_f32(&mut self) -> Result<f32, String>;
+    fn read_f32(&mut self) -> Result<f32, Error>;
 
     /// Reads given number of 32-bit unsigned values.
     ///
     /// * `count` - Number of values to read.
-    fn read_i32_vec(&mut self, count: usize) -> Result<Vec<i32>, String>;
+    fn read_i32_vec(&mut self, count: usize) -> Result<Vec<i32>, Error>;
 
     /// Reads given number of 32-bit floating point values.
     ///
     /// * `count` - Number of values to read.
-    fn read_f32_vec(&mut self, count: usize) -> Result<Vec<f32>, String>;
+    fn read_f32_vec(&mut self, count: usize) -> Result<Vec<f32>, Error>;
 }
 
 impl BSDFReader for File {
     /// Reads the header bytes and compares them to the expected
     /// header.
-    fn check_header(&mut self) -> Result<(), String> {
+    fn check_header(&mut self) -> Result<(), Error> {
         let mut header = [0_u8; 8];
         match self.read_exact(&mut header) {
             Ok(_) => {
                 if header == EXPECTED_HEADER {
                     Ok(())
                 } else {
-                    Err(format!(
+                    Err(Error::Parse(format!(
                         "Invalid header '{}'. Expected '{}'.",
-                        str::from_utf8(&header).unwrap(),
-                        str::from_utf8(&EXPECTED_HEADER).unwrap(),
-                    ))
+                        String::from_utf8_lossy(&header),
+                        String::from_utf8_lossy(&EXPECTED_HEADER),
+                    )))
                 }
             }
-            Err(err) => Err(format!("Error reading header {:}", err)),
+            Err(err) => Err(Error::Io(format!("Error reading header {:}", err))),
         }
     }
 
     /// Reads one 32-bit unsigned value.
-    fn read_i32(&mut self) -> Result<i32, String> {
+    fn read_i32(&mut self) -> Result<i32, Error> {
         match if *IS_BIG_ENDIAN {
             ReadBytesExt::read_i32::<BigEndian>(self)
         } else {
             ReadBytesExt::read_i32::<LittleEndian>(self)
         } {
             Ok(v) => Ok(v),
-            Err(err) => Err(format!("Error reading one i32. {:}.", err)),
+            Err(err) => Err(Error::Io(format!("Error reading one i32. {:}.", err))),
         }
     }
 
     /// Reads one 32-bit floating point value.
-    fn read_f32(&mut self) -> Result<f32, String> {
+    fn read_f32(&mut self) -> Result<f32, Error> {
         match if *IS_BIG_ENDIAN {
             ReadBytesExt::read_f32::<BigEndian>(self)
         } else {
             ReadBytesExt::read_f32::<LittleEndian>(self)
         } {
             Ok(v) => Ok(v),
-            Err(err) => Err(format!("Error reading one i32. {:}.", err)),
+            Err(err) => Err(Error::Io(format!("Error reading one f32. {:}.", err))),
         }
     }
 
     /// Reads given number of 32-bit unsigned values.
     ///
     /// * `count` - Number of values to read.
-    fn read_i32_vec(&mut self, count: usize) -> Result<Vec<i32>, String> {
+    fn read_i32_vec(&mut self, count: usize) -> Result<Vec<i32>, Error> {
         let mut buffer = vec![0_i32; count];
         match if *IS_BIG_ENDIAN {
             ReadBytesExt::read_i32_into::<BigEndian>(self, &mut buffer)
@@ -115,14 +116,14 @@ impl BSDFReader for File {
             ReadBytesExt::read_i32_into::<LittleEndian>(self, &mut buffer)
         } {
             Ok(_) => Ok(buffer),
-            Err(err) => Err(format!("Error reading {} i32. {:}.", count, err)),
+            Err(err) => Err(Error::Io(format!("Error reading {} i32. {:}.", count, err))),
         }
     }
 
     /// Reads given number of 32-bit floating point values.
     ///
     /// * `count` - Number of values to read.
-    fn read_f32_vec(&mut self, count: usize) -> Result<Vec<f32>, String> {
+    fn read_f32_vec(&mut self, count: usize) -> Result<Vec<f32>, Error> {
         let mut buffer: Vec<f32> = vec![0.0; count];
         match if *IS_BIG_ENDIAN {
             ReadBytesExt::read_f32_into::<BigEndian>(self, &mut buffer)
@@ -130,7 +131,7 @@ impl BSDFReader for File {
             ReadBytesExt::read_f32_into::<LittleEndian>(self, &mut buffer)
         } {
             Ok(_) => Ok(buffer),
-            Err(err) => Err(format!("Error reading {} f32. {:}.", count, err)),
+            Err(err) => Err(Error::Io(format!("Error reading {} f32. {:}.", count, err))),
         }
     }
 }
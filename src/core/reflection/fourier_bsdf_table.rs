@@ -1,6 +1,7 @@
 //! Fourier BSDF Table
 
 use super::bsdf_reader::*;
+use crate::core::error::*;
 use crate::core::interpolation::*;
 use crate::core::pbrt::*;
 use std::str;
@@ -54,7 +55,7 @@ impl FourierBSDFTable {
     /// Loads a `FourierBSDF` from a binary file.
     ///
     /// * `path` - The path to the BSDF binary file.
-    pub fn from_file(path: &str) -> Result<Self, String> {
+    pub fn from_file(path: &str) -> Result<Self, Error> {
         let mut file = open_file(path)?;
         file.check_header()?;
 
@@ -65,20 +66,24 @@ impl FourierBSDFTable {
         let n_channels = file.read_i32()? as usize;
         let n_bases = file.read_i32()? as usize;
         let _unused = file.read_i32_vec(3)?;
-        let eta = file.read_f32()?;
+        let eta = file.read_f32()? as Float;
         let _unused = file.read_i32_vec(4)?;
 
         // Only a subset of BSDF files are supported for simplicity. In particular
         // monochromatic and RGB files with uniform (i.e. non-textured) material
         // properties.
         if flags != 1 || (n_channels != 1 && n_channels != 3) || n_bases != 1 {
-            return Err(String::from("Unsupported BSDF file format"));
+            return Err(Error::Unsupported(format!(
+                "Unsupported BSDF file format in {}",
+                path
+            )));
         }
 
-        let mu = file.read_f32_vec(n_mu)?;
-        let cdf = file.read_f32_vec(n_mu * n_mu)?;
+        let to_float = |v: Vec<f32>| v.into_iter().map(|x| x as Float).collect::<Vec<Float>>();
+        let mu = to_float(file.read_f32_vec(n_mu)?);
+        let cdf = to_float(file.read_f32_vec(n_mu * n_mu)?);
         let offset_and_length = file.read_i32_vec(n_mu * n_mu * 2)?;
-        let a = file.read_f32_vec(n_coeffs)?;
+        let a = to_float(file.read_f32_vec(n_coeffs)?);
 
         let mut a0 = vec![0.0; n_mu * n_mu];
         let mut a_offset = vec![0_usize; n_mu * n_mu];
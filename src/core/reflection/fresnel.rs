@@ -13,6 +13,16 @@ pub trait Fresnel {
     /// * `cos_thata_i` - Cosine of the angle made by incident direction and
     ///                   surface normal.
     fn evaluate(&self, cos_theta_i: Float) -> Spectrum;
+
+    /// Returns the Mueller matrix for the light reflected by the surface with
+    /// the reference direction perpendicular to the plane of incidence. The
+    /// default does not change the polarization.
+    ///
+    /// * `cos_thata_i` - Cosine of the angle made by incident direction and
+    ///                   surface normal.
+    fn mueller(&self, cos_theta_i: Float) -> MuellerMatrix {
+        MuellerMatrix::from(self.evaluate(cos_theta_i))
+    }
 }
 
 /// Atomic reference counted `Fresnel`.
@@ -46,6 +56,15 @@ impl Fresnel for FresnelDielectric {
     fn evaluate(&self, cos_theta_i: Float) -> Spectrum {
         Spectrum::new(fr_dielectric(cos_theta_i, self.eta_i, self.eta_t))
     }
+
+    /// Returns the Mueller matrix for the light reflected by the surface with
+    /// the reference direction perpendicular to the plane of incidence.
+    ///
+    /// * `cos_thata_i` - Cosine of the angle made by incident direction and
+    ///                   surface normal.
+    fn mueller(&self, cos_theta_i: Float) -> MuellerMatrix {
+        MuellerMatrix::fresnel_dielectric_reflection(cos_theta_i, self.eta_i, self.eta_t)
+    }
 }
 
 /// Implements `Fresnel` for conductors materials.
@@ -82,6 +101,15 @@ impl Fresnel for FresnelConductor {
         // normal.
         fr_conductor(abs(cos_theta_i), self.eta_i, self.eta_t, self.k)
     }
+
+    /// Returns the Mueller matrix for the light reflected by the surface with
+    /// the reference direction perpendicular to the plane of incidence.
+    ///
+    /// * `cos_thata_i` - Cosine of the angle made by incident direction and
+    ///                   surface normal.
+    fn mueller(&self, cos_theta_i: Float) -> MuellerMatrix {
+        MuellerMatrix::fresnel_conductor_reflection(cos_theta_i, &self.eta_i, &self.eta_t, &self.k)
+    }
 }
 
 /// Implements `Fresnel` for materials that reflect 100% of all incoming light.
@@ -110,4 +110,13 @@ impl BxDF for MicrofacetReflection {
             0.0
         }
     }
+
+    /// Returns rougher BxDFs that replace this one to blur near-specular
+    /// scattering which causes fireflies; or `None` if it is rough enough.
+    fn regularize(&self) -> Option<Vec<ArcBxDF>> {
+        self.distribution.regularize().map(|distribution| {
+            let bxdf: ArcBxDF = Arc::new(Self::new(self.r, distribution, self.fresnel.clone()));
+            vec![bxdf]
+        })
+    }
 }
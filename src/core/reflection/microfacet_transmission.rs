@@ -174,4 +174,19 @@ impl BxDF for MicrofacetTransmission {
             }
         }
     }
+
+    /// Returns rougher BxDFs that replace this one to blur near-specular
+    /// scattering which causes fireflies; or `None` if it is rough enough.
+    fn regularize(&self) -> Option<Vec<ArcBxDF>> {
+        self.distribution.regularize().map(|distribution| {
+            let bxdf: ArcBxDF = Arc::new(Self::new(
+                self.t,
+                distribution,
+                self.eta_a,
+                self.eta_b,
+                self.mode,
+            ));
+            vec![bxdf]
+        })
+    }
 }
@@ -21,6 +21,7 @@ mod lambertian_reflection;
 mod microfacet_reflection;
 mod microfacet_transmission;
 mod oren_nayar;
+mod polarization;
 mod scaled_bxdf;
 mod specular_reflection;
 mod specular_transmission;
@@ -39,6 +40,7 @@ pub use lambertian_reflection::*;
 pub use microfacet_reflection::*;
 pub use microfacet_transmission::*;
 pub use oren_nayar::*;
+pub use polarization::*;
 pub use scaled_bxdf::*;
 pub use specular_reflection::*;
 pub use specular_transmission::*;
@@ -77,6 +79,18 @@ pub trait BxDF {
         BxDFSample::new(self.f(wo, &wi), pdf, wi, self.get_type())
     }
 
+    /// Returns the Mueller matrix that takes the place of `f(wo, wi) * |cos(θi)|`
+    /// when transporting polarized radiance from `wi` to `wo`. The reference
+    /// direction of the incident and outgoing Stokes vectors is perpendicular
+    /// to the plane of incidence. Returns `None` if the BxDF does not model
+    /// polarization.
+    ///
+    /// * `wo` - Outgoing direction.
+    /// * `wi` - Incident direction.
+    fn mueller(&self, _wo: &Vector3f, _wi: &Vector3f) -> Option<MuellerMatrix> {
+        None
+    }
+
     /// Evaluates the PDF for the sampling method. Default is based on the
     /// cosine-weighted sampling in `BxDF::sample_f()` default implementation.
     fn pdf(&self, wo: &Vector3f, wi: &Vector3f) -> Float {
@@ -123,6 +137,12 @@ pub trait BxDF {
         }
         r / (PI * u1.len() as Float)
     }
+
+    /// Returns rougher BxDFs that replace this one to blur near-specular
+    /// scattering which causes fireflies; or `None` if it is rough enough.
+    fn regularize(&self) -> Option<Vec<ArcBxDF>> {
+        None
+    }
 }
 
 /// Atomic reference counted `BxDF`.
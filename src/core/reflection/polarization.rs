@@ -0,0 +1,502 @@
+//! Polarization
+
+#![allow(dead_code)]
+
+use super::*;
+use std::mem::swap;
+use std::ops::{Add, AddAssign, Mul};
+
+/// Stokes vector describing the polarization state of radiance. The linear
+/// polarization components are measured relative to a reference direction
+/// perpendicular to the direction of propagation which is not stored here.
+#[derive(Copy, Clone, Default)]
+pub struct Stokes {
+    /// The components s0 (intensity), s1 (horizontal/vertical linear
+    /// polarization), s2 (diagonal linear polarization) and s3 (circular
+    /// polarization).
+    pub s: [Spectrum; 4],
+}
+
+impl Stokes {
+    /// Create a new `Stokes` vector.
+    ///
+    /// * `s` - The components s0, s1, s2 and s3.
+    pub fn new(s: [Spectrum; 4]) -> Self {
+        Self { s }
+    }
+
+    /// Create a `Stokes` vector for unpolarized radiance.
+    ///
+    /// * `l` - The radiance.
+    pub fn unpolarized(l: Spectrum) -> Self {
+        let zero = Spectrum::new(0.0);
+        Self::new([l, zero, zero, zero])
+    }
+
+    /// Returns the total radiance irrespective of polarization.
+    pub fn intensity(&self) -> Spectrum {
+        self.s[0]
+    }
+
+    /// Returns the Stokes vector measured relative to a new reference
+    /// direction.
+    ///
+    /// * `d`     - Direction of propagation.
+    /// * `x`     - Current reference direction perpendicular to `d`.
+    /// * `x_new` - New reference direction perpendicular to `d`.
+    pub fn rotate_frame(&self, d: &Vector3f, x: &Vector3f, x_new: &Vector3f) -> Self {
+        // Angle from `x` to `x_new` measured counter-clockwise about `d`.
+        let y = d.cross(x);
+        let phi = y.dot(x_new).atan2(x.dot(x_new));
+        MuellerMatrix::rotator(phi) * self
+    }
+}
+
+impl Add for Stokes {
+    type Output = Self;
+
+    /// Adds the given Stokes vector and returns the result.
+    ///
+    /// * `other` - The Stokes vector to add.
+    fn add(self, other: Self) -> Self::Output {
+        let mut s = self;
+        s += other;
+        s
+    }
+}
+
+impl AddAssign for Stokes {
+    /// Performs the `+=` operation.
+    ///
+    /// * `other` - The Stokes vector to add.
+    fn add_assign(&mut self, other: Self) {
+        for i in 0..4 {
+            self.s[i] += other.s[i];
+        }
+    }
+}
+
+/// Mueller matrix describing how a scattering event changes the polarization
+/// state of radiance given by a `Stokes` vector.
+#[derive(Copy, Clone, Default)]
+pub struct MuellerMatrix {
+    /// The matrix elements in row-major order.
+    pub m: [[Spectrum; 4]; 4],
+}
+
+impl MuellerMatrix {
+    /// Create a new `MuellerMatrix`.
+    ///
+    /// * `m` - The matrix elements in row-major order.
+    pub fn new(m: [[Spectrum; 4]; 4]) -> Self {
+        Self { m }
+    }
+
+    /// Returns the Mueller matrix that scales radiance without changing its
+    /// polarization.
+    ///
+    /// * `s` - The scale.
+    pub fn scale(s: Spectrum) -> Self {
+        let mut mm = Self::default();
+        for i in 0..4 {
+            mm.m[i][i] = s;
+        }
+        mm
+    }
+
+    /// Returns the Mueller matrix of an ideal depolarizer which scales radiance
+    /// and makes it unpolarized.
+    ///
+    /// * `s` - The scale.
+    pub fn depolarizer(s: Spectrum) -> Self {
+        let mut mm = Self::default();
+        mm.m[0][0] = s;
+        mm
+    }
+
+    /// Returns the Mueller matrix that rotates the reference direction of a
+    /// Stokes vector.
+    ///
+    /// * `phi` - Counter-clockwise rotation angle in radians about the
+    ///           direction of propagation.
+    pub fn rotator(phi: Float) -> Self {
+        let (sin_2phi, cos_2phi) = (2.0 * phi).sin_cos();
+        Self::from_elements(&[
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, cos_2phi, sin_2phi, 0.0],
+            [0.0, -sin_2phi, cos_2phi, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// Returns the Mueller matrix of an ideal linear polarizer.
+    ///
+    /// * `theta` - Angle in radians of the transmission axis measured
+    ///             counter-clockwise from the reference direction about the
+    ///             direction of propagation.
+    pub fn linear_polarizer(theta: Float) -> Self {
+        let (s, c) = (2.0 * theta).sin_cos();
+        Self::from_elements(&[
+            [0.5, 0.5 * c, 0.5 * s, 0.0],
+            [0.5 * c, 0.5 * c * c, 0.5 * c * s, 0.0],
+            [0.5 * s, 0.5 * c * s, 0.5 * s * s, 0.0],
+            [0.0, 0.0, 0.0, 0.0],
+        ])
+    }
+
+    /// Returns the Mueller matrix for Fresnel reflection at the boundary
+    /// between two dielectric media. The reference direction is perpendicular
+    /// to the plane of incidence (s-polarization).
+    ///
+    /// * `cos_theta_i` - cos(θi) for angle between incident direction and
+    ///                   surface normal.
+    /// * `eta_i`       - Index of refraction for the exterior side.
+    /// * `eta_t`       - Index of refraction for the interior side.
+    pub fn fresnel_dielectric_reflection(cos_theta_i: Float, eta_i: Float, eta_t: Float) -> Self {
+        let (cos_theta_i, eta) = incident_side(cos_theta_i, eta_i, eta_t);
+        let e = fresnel_mueller_elements(cos_theta_i, Complex::new(eta, 0.0), false);
+        Self::from_fresnel_elements(|_| e)
+    }
+
+    /// Returns the Mueller matrix for Fresnel transmission at the boundary
+    /// between two dielectric media. The reference direction is perpendicular
+    /// to the plane of incidence (s-polarization).
+    ///
+    /// * `cos_theta_i` - cos(θi) for angle between incident direction and
+    ///                   surface normal.
+    /// * `eta_i`       - Index of refraction for the exterior side.
+    /// * `eta_t`       - Index of refraction for the interior side.
+    pub fn fresnel_dielectric_transmission(cos_theta_i: Float, eta_i: Float, eta_t: Float) -> Self {
+        let (cos_theta_i, eta) = incident_side(cos_theta_i, eta_i, eta_t);
+        let e = fresnel_mueller_elements(cos_theta_i, Complex::new(eta, 0.0), true);
+        Self::from_fresnel_elements(|_| e)
+    }
+
+    /// Returns the Mueller matrix for Fresnel reflection at the boundary
+    /// between a dielectric and a conductor. The reference direction is
+    /// perpendicular to the plane of incidence (s-polarization).
+    ///
+    /// * `cos_theta_i` - cos(θi) for angle between incident direction and
+    ///                   surface normal.
+    /// * `eta_i`       - Index of refraction for the dielectric.
+    /// * `eta_t`       - Index of refraction for the conductor.
+    /// * `k`           - The absorption coefficient of the conductor.
+    pub fn fresnel_conductor_reflection(
+        cos_theta_i: Float,
+        eta_i: &Spectrum,
+        eta_t: &Spectrum,
+        k: &Spectrum,
+    ) -> Self {
+        let cos_theta_i = clamp(abs(cos_theta_i), 0.0, 1.0);
+        Self::from_fresnel_elements(|i| {
+            let eta = Complex::new(eta_t[i] / eta_i[i], k[i] / eta_i[i]);
+            fresnel_mueller_elements(cos_theta_i, eta, false)
+        })
+    }
+
+    /// Returns a Mueller matrix with the same value for all spectrum samples.
+    ///
+    /// * `m` - The matrix elements in row-major order.
+    fn from_elements(m: &[[Float; 4]; 4]) -> Self {
+        let mut mm = Self::default();
+        for (row, values) in mm.m.iter_mut().zip(m.iter()) {
+            for (e, &v) in row.iter_mut().zip(values.iter()) {
+                *e = Spectrum::new(v);
+            }
+        }
+        mm
+    }
+
+    /// Returns a Mueller matrix for Fresnel reflection or transmission from
+    /// the elements returned by `fresnel_mueller_elements()`.
+    ///
+    /// * `elements` - Returns the elements for a spectrum sample.
+    fn from_fresnel_elements<F>(elements: F) -> Self
+    where
+        F: Fn(usize) -> [Float; 4],
+    {
+        let mut a = Spectrum::new(0.0);
+        let mut b = Spectrum::new(0.0);
+        let mut c = Spectrum::new(0.0);
+        let mut s = Spectrum::new(0.0);
+        for i in 0..a.samples().len() {
+            let e = elements(i);
+            a[i] = e[0];
+            b[i] = e[1];
+            c[i] = e[2];
+            s[i] = e[3];
+        }
+
+        let zero = Spectrum::new(0.0);
+        Self::new([
+            [a, b, zero, zero],
+            [b, a, zero, zero],
+            [zero, zero, c, s],
+            [zero, zero, -s, c],
+        ])
+    }
+}
+
+impl From<Spectrum> for MuellerMatrix {
+    /// Returns the Mueller matrix that scales radiance without changing its
+    /// polarization.
+    ///
+    /// * `s` - The scale.
+    fn from(s: Spectrum) -> Self {
+        Self::scale(s)
+    }
+}
+
+impl Add for MuellerMatrix {
+    type Output = Self;
+
+    /// Adds the given Mueller matrix and returns the result.
+    ///
+    /// * `other` - The Mueller matrix to add.
+    fn add(self, other: Self) -> Self::Output {
+        let mut mm = self;
+        for i in 0..4 {
+            for j in 0..4 {
+                mm.m[i][j] += other.m[i][j];
+            }
+        }
+        mm
+    }
+}
+
+impl Mul<Spectrum> for MuellerMatrix {
+    type Output = Self;
+
+    /// Scales the Mueller matrix and returns the result.
+    ///
+    /// * `s` - The scale.
+    fn mul(self, s: Spectrum) -> Self::Output {
+        let mut mm = self;
+        for i in 0..4 {
+            for j in 0..4 {
+                mm.m[i][j] *= s;
+            }
+        }
+        mm
+    }
+}
+
+impl Mul<Float> for MuellerMatrix {
+    type Output = Self;
+
+    /// Scales the Mueller matrix and returns the result.
+    ///
+    /// * `s` - The scale.
+    fn mul(self, s: Float) -> Self::Output {
+        self * Spectrum::new(s)
+    }
+}
+
+impl Mul<&Stokes> for MuellerMatrix {
+    type Output = Stokes;
+
+    /// Applies the Mueller matrix to a Stokes vector.
+    ///
+    /// * `s` - The Stokes vector.
+    fn mul(self, s: &Stokes) -> Self::Output {
+        let mut r = Stokes::default();
+        for i in 0..4 {
+            r.s[i] = (0..4).fold(Spectrum::new(0.0), |sum, j| sum + self.m[i][j] * s.s[j]);
+        }
+        r
+    }
+}
+
+impl Mul<Stokes> for MuellerMatrix {
+    type Output = Stokes;
+
+    /// Applies the Mueller matrix to a Stokes vector.
+    ///
+    /// * `s` - The Stokes vector.
+    fn mul(self, s: Stokes) -> Self::Output {
+        self * &s
+    }
+}
+
+/// Returns cos(θi) and the relative index of refraction as seen from the side
+/// of the incident direction.
+///
+/// * `cos_theta_i` - cos(θi) for angle between incident direction and surface
+///                   normal.
+/// * `eta_i`       - Index of refraction for the exterior side.
+/// * `eta_t`       - Index of refraction for the interior side.
+fn incident_side(cos_theta_i: Float, eta_i: Float, eta_t: Float) -> (Float, Float) {
+    let mut cos_theta_i = clamp(cos_theta_i, -1.0, 1.0);
+    let mut eta_i = eta_i;
+    let mut eta_t = eta_t;
+    if cos_theta_i < 0.0 {
+        swap(&mut eta_i, &mut eta_t);
+        cos_theta_i = -cos_theta_i;
+    }
+    (cos_theta_i, eta_t / eta_i)
+}
+
+/// Returns the elements (A, B, C, S) of the Mueller matrix
+///
+/// ```text
+/// | A B 0 0 |
+/// | B A 0 0 |
+/// | 0 0 C S |
+/// | 0 0 -S C |
+/// ```
+///
+/// for Fresnel reflection or transmission with the reference direction
+/// perpendicular to the plane of incidence.
+///
+/// * `cos_theta_i`  - cos(θi) for angle between incident direction and surface
+///                    normal in [0, 1].
+/// * `eta`          - Complex relative index of refraction.
+/// * `transmission` - Compute transmission instead of reflection. This is only
+///                    meaningful for dielectrics.
+fn fresnel_mueller_elements(cos_theta_i: Float, eta: Complex, transmission: bool) -> [Float; 4] {
+    // Compute cos(θt) using Snell's law; it is imaginary for total internal
+    // reflection.
+    let ci = Complex::new(cos_theta_i, 0.0);
+    let sin2_theta_i = Complex::new(max(0.0, 1.0 - cos_theta_i * cos_theta_i), 0.0);
+    let ct = (Complex::new(1.0, 0.0) - sin2_theta_i / (eta * eta)).sqrt();
+
+    // Complex amplitude coefficients for s- and p-polarized light.
+    let (ps, pp, scale) = if transmission {
+        let nct = eta * ct;
+        if nct.re <= 0.0 || cos_theta_i == 0.0 {
+            return [0.0; 4];
+        }
+        let two_ci = ci * 2.0;
+        (
+            two_ci / (ci + nct),
+            two_ci / (eta * ci + ct),
+            nct.re / cos_theta_i,
+        )
+    } else {
+        (
+            (ci - eta * ct) / (ci + eta * ct),
+            (eta * ci - ct) / (eta * ci + ct),
+            1.0,
+        )
+    };
+
+    let (ns, np) = (ps.norm_sqr(), pp.norm_sqr());
+    let sp = ps * pp.conj();
+    [
+        0.5 * scale * (ns + np),
+        0.5 * scale * (ns - np),
+        scale * sp.re,
+        scale * sp.im,
+    ]
+}
+
+/// A complex number used for Fresnel amplitude coefficients.
+#[derive(Copy, Clone)]
+struct Complex {
+    re: Float,
+    im: Float,
+}
+
+impl Complex {
+    /// Create a new `Complex` number.
+    ///
+    /// * `re` - Real part.
+    /// * `im` - Imaginary part.
+    fn new(re: Float, im: Float) -> Self {
+        Self { re, im }
+    }
+
+    /// Returns the complex conjugate.
+    fn conj(self) -> Self {
+        Self::new(self.re, -self.im)
+    }
+
+    /// Returns the squared magnitude.
+    fn norm_sqr(self) -> Float {
+        self.re * self.re + self.im * self.im
+    }
+
+    /// Returns the principal square root.
+    fn sqrt(self) -> Self {
+        let n = self.norm_sqr().sqrt();
+        let re = (0.5 * (n + self.re)).max(0.0).sqrt();
+        let im = (0.5 * (n - self.re)).max(0.0).sqrt();
+        Self::new(re, if self.im < 0.0 { -im } else { im })
+    }
+}
+
+impl Add for Complex {
+    type Output = Self;
+    fn add(self, o: Self) -> Self {
+        Self::new(self.re + o.re, self.im + o.im)
+    }
+}
+
+impl std::ops::Sub for Complex {
+    type Output = Self;
+    fn sub(self, o: Self) -> Self {
+        Self::new(self.re - o.re, self.im - o.im)
+    }
+}
+
+impl Mul for Complex {
+    type Output = Self;
+    fn mul(self, o: Self) -> Self {
+        Self::new(
+            self.re * o.re - self.im * o.im,
+            self.re * o.im + self.im * o.re,
+        )
+    }
+}
+
+impl Mul<Float> for Complex {
+    type Output = Self;
+    fn mul(self, s: Float) -> Self {
+        Self::new(self.re * s, self.im * s)
+    }
+}
+
+impl std::ops::Div for Complex {
+    type Output = Self;
+    fn div(self, o: Self) -> Self {
+        let d = o.norm_sqr();
+        Self::new(
+            (self.re * o.re + self.im * o.im) / d,
+            (self.im * o.re - self.re * o.im) / d,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dielectric_mueller_matches_unpolarized_fresnel() {
+        for &cos_theta_i in [1.0, 0.7, 0.3, -0.5, -0.9].iter() {
+            let r = MuellerMatrix::fresnel_dielectric_reflection(cos_theta_i, 1.0, 1.5);
+            let t = MuellerMatrix::fresnel_dielectric_transmission(cos_theta_i, 1.0, 1.5);
+            let f = fr_dielectric(cos_theta_i, 1.0, 1.5);
+            assert!((r.m[0][0][0] - f).abs() < 1e-5);
+            assert!((r.m[0][0][0] + t.m[0][0][0] - 1.0).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn brewster_angle_reflects_s_polarized_light() {
+        // At Brewster's angle p-polarized light is not reflected at all.
+        let theta_b = (1.5 as Float).atan();
+        let r = MuellerMatrix::fresnel_dielectric_reflection(theta_b.cos(), 1.0, 1.5);
+        let s = r * Stokes::unpolarized(Spectrum::new(1.0));
+        assert!((s.s[0][0] - s.s[1][0]).abs() < 1e-5);
+    }
+
+    #[test]
+    fn crossed_polarizers_block_light() {
+        let s = Stokes::unpolarized(Spectrum::new(1.0));
+        let s = MuellerMatrix::linear_polarizer(0.0) * s;
+        assert!((s.s[0][0] - 0.5).abs() < 1e-5);
+        let s = MuellerMatrix::linear_polarizer(PI / 2.0) * s;
+        assert!(s.s[0][0].abs() < 1e-5);
+    }
+}
@@ -77,4 +77,15 @@ impl BxDF for ScaledBxDF {
     fn rho_hh(&self, samples1: &[Point2f], samples2: &[Point2f]) -> Spectrum {
         self.scale * self.bxdf.rho_hh(samples1, samples2)
     }
+
+    /// Returns rougher BxDFs that replace this one to blur near-specular
+    /// scattering which causes fireflies; or `None` if it is rough enough.
+    fn regularize(&self) -> Option<Vec<ArcBxDF>> {
+        self.bxdf.regularize().map(|bxdfs| {
+            bxdfs
+                .into_iter()
+                .map(|bxdf| -> ArcBxDF { Arc::new(Self::new(bxdf, self.scale)) })
+                .collect()
+        })
+    }
 }
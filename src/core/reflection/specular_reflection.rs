@@ -3,6 +3,7 @@
 #![allow(dead_code)]
 
 use super::*;
+use crate::core::microfacet::*;
 
 /// BRDF for physically plausible specular reflection using Fresnel interface.
 #[derive(Clone)]
@@ -59,4 +60,25 @@ impl BxDF for SpecularReflection {
         let s = self.fresnel.evaluate(cos_theta(&wi)) * self.r / abs_cos_theta(&wi);
         BxDFSample::new(s, pdf, wi, self.bxdf_type)
     }
+
+    /// Returns the Mueller matrix that takes the place of `f(wo, wi) * |cos(θi)|`
+    /// when transporting polarized radiance from `wi` to `wo`.
+    ///
+    /// * `wo` - Outgoing direction.
+    /// * `wi` - Incident direction.
+    fn mueller(&self, _wo: &Vector3f, wi: &Vector3f) -> Option<MuellerMatrix> {
+        Some(self.fresnel.mueller(cos_theta(wi)) * self.r)
+    }
+
+    /// Returns rougher BxDFs that replace this one to blur near-specular
+    /// scattering which causes fireflies; or `None` if it is rough enough.
+    fn regularize(&self) -> Option<Vec<ArcBxDF>> {
+        let alpha = regularized_alpha(0.0);
+        let distribution = Arc::new(TrowbridgeReitzDistribution::new(alpha, alpha, true));
+        Some(vec![Arc::new(MicrofacetReflection::new(
+            self.r,
+            distribution,
+            self.fresnel.clone(),
+        ))])
+    }
 }
@@ -4,6 +4,7 @@
 
 use super::*;
 use crate::core::material::*;
+use crate::core::microfacet::*;
 
 /// BTDF for physically plausible specular transmission using Fresnel interface.
 #[derive(Copy, Clone)]
@@ -95,4 +96,39 @@ impl BxDF for SpecularTransmission {
             BxDFSample::from(self.bxdf_type)
         }
     }
+
+    /// Returns the Mueller matrix that takes the place of `f(wo, wi) * |cos(θi)|`
+    /// when transporting polarized radiance from `wi` to `wo`.
+    ///
+    /// * `wo` - Outgoing direction.
+    /// * `wi` - Incident direction.
+    fn mueller(&self, wo: &Vector3f, wi: &Vector3f) -> Option<MuellerMatrix> {
+        let entering = cos_theta(wo) > 0.0;
+        let eta_i = if entering { self.eta_a } else { self.eta_b };
+        let eta_t = if entering { self.eta_b } else { self.eta_a };
+
+        let mut m =
+            MuellerMatrix::fresnel_dielectric_transmission(cos_theta(wi), self.eta_a, self.eta_b)
+                * self.t;
+
+        // Account for non-symmetry with transmission to different medium
+        if self.mode == TransportMode::Radiance {
+            m = m * ((eta_i * eta_i) / (eta_t * eta_t));
+        }
+        Some(m)
+    }
+
+    /// Returns rougher BxDFs that replace this one to blur near-specular
+    /// scattering which causes fireflies; or `None` if it is rough enough.
+    fn regularize(&self) -> Option<Vec<ArcBxDF>> {
+        let alpha = regularized_alpha(0.0);
+        let distribution = Arc::new(TrowbridgeReitzDistribution::new(alpha, alpha, true));
+        Some(vec![Arc::new(MicrofacetTransmission::new(
+            self.t,
+            distribution,
+            self.eta_a,
+            self.eta_b,
+            self.mode,
+        ))])
+    }
 }
@@ -0,0 +1,167 @@
+//! Render Report
+
+#![allow(dead_code)]
+use crate::core::error::*;
+use crate::core::geometry::*;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+/// Returns the peak resident memory of the process in bytes if the platform
+/// reports it.
+pub fn peak_memory() -> Option<u64> {
+    let status = fs::read_to_string("/proc/self/status").ok()?;
+    let line = status.lines().find(|l| l.starts_with("VmHWM:"))?;
+    let kb: u64 = line.split_whitespace().nth(1)?.parse().ok()?;
+    Some(kb * 1024)
+}
+
+/// Escapes a string for use in a JSON string literal.
+///
+/// * `s` - The string.
+pub fn json_escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Returns the filename of the report written next to an image, e.g.
+/// `image.json` for `image.png`.
+///
+/// * `filename` - Filename of the image.
+pub fn report_filename(filename: &str) -> String {
+    Path::new(filename)
+        .with_extension("json")
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// Summary of a render written as JSON so tools can track the health of
+/// renders without parsing the log.
+#[derive(Clone, Debug, Default)]
+pub struct RenderReport {
+    /// Filename of the image.
+    pub image: String,
+
+    /// Resolution of the image.
+    pub resolution: Point2i,
+
+    /// Average number of camera samples taken per rendered pixel.
+    pub samples_per_pixel: f64,
+
+    /// Wall clock time of the render.
+    pub render_time: Duration,
+
+    /// Error that stopped the render.
+    pub error: Option<String>,
+
+    /// Time spent in each profiled phase so far; empty unless profiling.
+    pub phases: Vec<(&'static str, Duration)>,
+
+    /// Estimated memory used by the scene in bytes.
+    pub scene_memory: i64,
+
+    /// Peak resident memory of the process in bytes.
+    pub peak_memory: Option<u64>,
+
+    /// Statistics counters as a JSON object.
+    pub stats: String,
+
+    /// Warnings and errors logged while loading and rendering the scene.
+    pub warnings: Vec<String>,
+}
+
+impl RenderReport {
+    /// Returns the report as a JSON object.
+    pub fn to_json(&self) -> String {
+        let phases: Vec<String> = self
+            .phases
+            .iter()
+            .map(|(name, time)| format!("\"{}\":{}", json_escape(name), time.as_secs_f64()))
+            .collect();
+        let warnings: Vec<String> = self
+            .warnings
+            .iter()
+            .map(|w| format!("\"{}\"", json_escape(w)))
+            .collect();
+        let error = match self.error.as_ref() {
+            Some(err) => format!("\"{}\"", json_escape(err)),
+            None => String::from("null"),
+        };
+        let peak_memory = match self.peak_memory {
+            Some(bytes) => bytes.to_string(),
+            None => String::from("null"),
+        };
+        let stats = if self.stats.is_empty() {
+            "{}"
+        } else {
+            &self.stats
+        };
+
+        format!(
+            "{{\"image\":\"{}\",\"status\":\"{}\",\"error\":{},\
+            \"resolution\":[{},{}],\"samples_per_pixel\":{},\"render_time\":{},\
+            \"phases\":{{{}}},\"memory\":{{\"scene\":{},\"peak\":{}}},\
+            \"stats\":{},\"warnings\":[{}]}}\n",
+            json_escape(&self.image),
+            if self.error.is_some() { "failed" } else { "ok" },
+            error,
+            self.resolution.x,
+            self.resolution.y,
+            self.samples_per_pixel,
+            self.render_time.as_secs_f64(),
+            phases.join(","),
+            self.scene_memory,
+            peak_memory,
+            stats,
+            warnings.join(",")
+        )
+    }
+
+    /// Writes the report next to the image; or returns an error if the file
+    /// can't be written.
+    pub fn write(&self) -> Result<(), Error> {
+        let path = report_filename(&self.image);
+        fs::write(&path, self.to_json())
+            .map_err(|err| Error::Io(format!("Error writing report '{}'. {}.", path, err)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_is_written_as_json() {
+        let report = RenderReport {
+            image: String::from("out/image.png"),
+            resolution: Point2i::new(64, 32),
+            samples_per_pixel: 16.0,
+            render_time: Duration::from_millis(1500),
+            phases: vec![("Integrator::Render()", Duration::from_millis(1250))],
+            scene_memory: 2048,
+            stats: String::from("{\"Integrator/Camera rays traced\":32768}"),
+            warnings: vec![String::from("WARN: Parameter \"foo\" not used")],
+            ..RenderReport::default()
+        };
+        assert_eq!(
+            report.to_json(),
+            "{\"image\":\"out/image.png\",\"status\":\"ok\",\"error\":null,\
+            \"resolution\":[64,32],\"samples_per_pixel\":16,\"render_time\":1.5,\
+            \"phases\":{\"Integrator::Render()\":1.25},\"memory\":{\"scene\":2048,\"peak\":null},\
+            \"stats\":{\"Integrator/Camera rays traced\":32768},\
+            \"warnings\":[\"WARN: Parameter \\\"foo\\\" not used\"]}\n"
+        );
+        assert_eq!(report_filename(&report.image), "out/image.json");
+    }
+}
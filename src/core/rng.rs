@@ -4,8 +4,7 @@
 use crate::core::pbrt::*;
 use rand::distributions::uniform::SampleUniform;
 use rand::distributions::{Distribution, Standard, Uniform};
-use rand::{Rng, SeedableRng};
-use rand_pcg::Pcg32;
+use rand::{Error, Rng, RngCore};
 
 /// 32-bit precision value for 1 - epsilon.
 pub const FLOAT_ONE_MINUS_EPSILON: f32 = hexf32!("0x1.fffffep-1"); // 0.99999994
@@ -14,10 +13,16 @@ pub const FLOAT_ONE_MINUS_EPSILON: f32 = hexf32!("0x1.fffffep-1"); // 0.99999994
 pub const DOUBLE_ONE_MINUS_EPSILON: f64 = hexf64!("0x1.fffffffffffffp-1"); // 0.99999999999999989
 
 /// 1 - epsilon in the precision we've selected for `Float`.
+#[cfg(not(feature = "float-as-double"))]
 pub const ONE_MINUS_EPSILON: Float = FLOAT_ONE_MINUS_EPSILON;
 
+/// 1 - epsilon in the precision we've selected for `Float`.
+#[cfg(feature = "float-as-double")]
+pub const ONE_MINUS_EPSILON: Float = DOUBLE_ONE_MINUS_EPSILON;
+
 const PCG32_DEFAULT_STATE: u64 = 0x853c49e6748fea9b;
 const PCG32_DEFAULT_STREAM: u64 = 0xda3e39cb94b95bdb;
+const PCG32_MULT: u64 = 0x5851f42d4c957f2d;
 
 /// Interface for generating uniform samples.
 pub trait UniformRandom<T>
@@ -28,17 +33,22 @@ where
     fn uniform(&mut self) -> T;
 }
 
-/// Implements the pseudo-random number generator.
-#[derive(Clone)]
+/// Implements the PCG32 pseudo-random number generator.
+#[derive(Clone, Debug, PartialEq)]
 pub struct RNG {
-    r: Pcg32,
+    /// The state.
+    state: u64,
+
+    /// Increment that selects the stream; always odd.
+    inc: u64,
 }
 
 impl Default for RNG {
     /// Return a new instance of `RNG` with default state and stream.
     fn default() -> Self {
         Self {
-            r: Pcg32::new(PCG32_DEFAULT_STATE, PCG32_DEFAULT_STREAM),
+            state: PCG32_DEFAULT_STATE,
+            inc: PCG32_DEFAULT_STREAM,
         }
     }
 }
@@ -48,9 +58,92 @@ impl RNG {
     ///
     /// * `sequence_index` - The starting sequence to seed with.
     pub fn new(sequence_index: u64) -> Self {
-        Self {
-            r: Pcg32::seed_from_u64(sequence_index),
+        let mut rng = Self::default();
+        rng.set_sequence(sequence_index);
+        rng
+    }
+
+    /// Restarts the generator at the beginning of the given stream. Different
+    /// streams produce independent sequences of values.
+    ///
+    /// * `init_seq` - The stream.
+    pub fn set_sequence(&mut self, init_seq: u64) {
+        self.state = 0;
+        self.inc = (init_seq << 1) | 1;
+        self.uniform_u32();
+        self.state = self.state.wrapping_add(PCG32_DEFAULT_STATE);
+        self.uniform_u32();
+    }
+
+    /// Returns a uniformly distributed 32-bit value.
+    pub fn uniform_u32(&mut self) -> u32 {
+        let old_state = self.state;
+        self.state = old_state.wrapping_mul(PCG32_MULT).wrapping_add(self.inc);
+        let xor_shifted = (((old_state >> 18) ^ old_state) >> 27) as u32;
+        let rot = (old_state >> 59) as u32;
+        xor_shifted.rotate_right(rot)
+    }
+
+    /// Returns a uniformly distributed 32-bit value in `[0, b)` without bias.
+    ///
+    /// * `b` - The upper bound; must be greater than 0.
+    pub fn bounded_u32(&mut self, b: u32) -> u32 {
+        debug_assert!(b > 0);
+        let threshold = b.wrapping_neg() % b;
+        loop {
+            let r = self.uniform_u32();
+            if r >= threshold {
+                return r % b;
+            }
+        }
+    }
+
+    /// Moves the generator forward or backward by a number of values in
+    /// `O(log n)` time.
+    ///
+    /// * `delta` - The number of values to skip; negative to go back.
+    pub fn advance(&mut self, delta: i64) {
+        let mut cur_mult = PCG32_MULT;
+        let mut cur_plus = self.inc;
+        let mut acc_mult = 1_u64;
+        let mut acc_plus = 0_u64;
+
+        // Negative deltas wrap around the period of 2^64.
+        let mut delta = delta as u64;
+        while delta > 0 {
+            if delta & 1 == 1 {
+                acc_mult = acc_mult.wrapping_mul(cur_mult);
+                acc_plus = acc_plus.wrapping_mul(cur_mult).wrapping_add(cur_plus);
+            }
+            cur_plus = cur_mult.wrapping_add(1).wrapping_mul(cur_plus);
+            cur_mult = cur_mult.wrapping_mul(cur_mult);
+            delta /= 2;
+        }
+        self.state = acc_mult.wrapping_mul(self.state).wrapping_add(acc_plus);
+    }
+
+    /// Returns the number of values that `other` has to generate to reach
+    /// the state of this generator. Both must use the same stream.
+    ///
+    /// * `other` - The other generator.
+    pub fn distance(&self, other: &Self) -> i64 {
+        assert_eq!(self.inc, other.inc, "RNGs use different streams");
+
+        let mut cur_mult = PCG32_MULT;
+        let mut cur_plus = self.inc;
+        let mut cur_state = other.state;
+        let mut the_bit = 1_u64;
+        let mut distance = 0_u64;
+        while self.state != cur_state {
+            if self.state & the_bit != cur_state & the_bit {
+                cur_state = cur_state.wrapping_mul(cur_mult).wrapping_add(cur_plus);
+                distance |= the_bit;
+            }
+            the_bit <<= 1;
+            cur_plus = cur_mult.wrapping_add(1).wrapping_mul(cur_plus);
+            cur_mult = cur_mult.wrapping_mul(cur_mult);
         }
+        distance as i64
     }
 
     /// Returns a uniformly distributed value over the closed interval containing
@@ -63,7 +156,7 @@ impl RNG {
         T: SampleUniform,
     {
         let between = Uniform::from(lower_bound..upper_bound);
-        between.sample(&mut self.r)
+        between.sample(self)
     }
 
     /// Randomly permute a slice containing n-dimensional values in a linear
@@ -91,8 +184,7 @@ macro_rules! uniform_rand {
             /// Returns a uniformly distributed value over the closed interval
             /// containing the minimum value to maximum value of `$t`.
             fn uniform(&mut self) -> $t {
-                let r = &mut self.r;
-                r.gen::<$t>()
+                self.gen::<$t>()
             }
         }
     };
@@ -105,7 +197,68 @@ uniform_rand!(u64);
 impl UniformRandom<Float> for RNG {
     /// Returns a uniformly distributed value over the half open interval [0.0, 1.0).
     fn uniform(&mut self) -> Float {
-        let r = &mut self.r;
-        min(ONE_MINUS_EPSILON, r.gen::<Float>())
+        // Scale by 2^-32.
+        min(
+            ONE_MINUS_EPSILON,
+            self.uniform_u32() as Float / 4_294_967_296.0,
+        )
+    }
+}
+
+impl RngCore for RNG {
+    /// Returns a uniformly distributed 32-bit value.
+    fn next_u32(&mut self) -> u32 {
+        self.uniform_u32()
+    }
+
+    /// Returns a uniformly distributed 64-bit value.
+    fn next_u64(&mut self) -> u64 {
+        let lo = self.uniform_u32() as u64;
+        let hi = self.uniform_u32() as u64;
+        (hi << 32) | lo
+    }
+
+    /// Fills a buffer with random bytes.
+    ///
+    /// * `dest` - The buffer.
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(4) {
+            let bytes = self.uniform_u32().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+
+    /// Fills a buffer with random bytes.
+    ///
+    /// * `dest` - The buffer.
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn advance_and_distance_are_inverse() {
+        let mut rng = RNG::new(7);
+        let start = rng.clone();
+        let values: Vec<u32> = (0..100).map(|_| rng.uniform_u32()).collect();
+        assert_eq!(rng.distance(&start), 100);
+
+        rng.advance(-60);
+        assert_eq!(rng.distance(&start), 40);
+        assert_eq!(rng.uniform_u32(), values[40]);
+    }
+
+    #[test]
+    fn streams_are_independent() {
+        let mut a = RNG::new(1);
+        let mut b = RNG::new(2);
+        let va: Vec<u32> = (0..16).map(|_| a.uniform_u32()).collect();
+        let vb: Vec<u32> = (0..16).map(|_| b.uniform_u32()).collect();
+        assert_ne!(va, vb);
     }
 }
@@ -87,12 +87,13 @@ impl SamplerData {
             .push(Vec::<Point2f>::with_capacity(n * self.samples_per_pixel));
     }
 
-    /// Get an array of 1D samples.
+    /// Get an array of 1D samples. The samples are copied into `arena`.
     ///
-    /// * `n` - The number of samples.
-    pub fn get_1d_array(&mut self, n: usize) -> Vec<Float> {
+    /// * `n`     - The number of samples.
+    /// * `arena` - The memory arena for per-sample allocations.
+    pub fn get_1d_array<'a>(&mut self, n: usize, arena: &'a MemoryArena) -> &'a [Float] {
         if self.array_1d_offset == self.sample_array_1d.len() {
-            vec![]
+            &[]
         } else {
             assert!(self.samples_1d_array_sizes[self.array_1d_offset] == n);
             assert!(self.current_pixel_sample_index < self.samples_per_pixel);
@@ -102,16 +103,17 @@ impl SamplerData {
 
             let i = self.current_pixel_sample_index * n;
             let m = i + n * self.samples_per_pixel;
-            array[i..m].to_vec()
+            arena.alloc_slice_copy(&array[i..m])
         }
     }
 
-    /// Get an array of 2D samples.
+    /// Get an array of 2D samples. The samples are copied into `arena`.
     ///
-    /// * `n` - The number of samples.
-    pub fn get_2d_array(&mut self, n: usize) -> Vec<Point2f> {
+    /// * `n`     - The number of samples.
+    /// * `arena` - The memory arena for per-sample allocations.
+    pub fn get_2d_array<'a>(&mut self, n: usize, arena: &'a MemoryArena) -> &'a [Point2f] {
         if self.array_2d_offset == self.sample_array_2d.len() {
-            vec![]
+            &[]
         } else {
             assert!(self.samples_2d_array_sizes[self.array_2d_offset] == n);
             assert!(self.current_pixel_sample_index < self.samples_per_pixel);
@@ -121,7 +123,7 @@ impl SamplerData {
 
             let i = self.current_pixel_sample_index * n;
             let m = i + n * self.samples_per_pixel;
-            array[i..m].to_vec()
+            arena.alloc_slice_copy(&array[i..m])
         }
     }
 
@@ -3,6 +3,7 @@
 mod common;
 mod pixel_sampler;
 
+use crate::core::arena::*;
 use crate::core::camera::*;
 use crate::core::geometry::*;
 use crate::core::pbrt::*;
@@ -82,18 +83,20 @@ pub trait Sampler {
         n
     }
 
-    /// Get an array of 1D samples.
+    /// Get an array of 1D samples. The samples are copied into `arena`.
     ///
-    /// * `n` - The number of samples.
-    fn get_1d_array(&mut self, n: usize) -> Vec<Float> {
-        self.get_data().get_1d_array(n)
+    /// * `n`     - The number of samples.
+    /// * `arena` - The memory arena for per-sample allocations.
+    fn get_1d_array<'a>(&mut self, n: usize, arena: &'a MemoryArena) -> &'a [Float] {
+        self.get_data().get_1d_array(n, arena)
     }
 
-    /// Get an array of 2D samples.
+    /// Get an array of 2D samples. The samples are copied into `arena`.
     ///
-    /// * `n` - The number of samples.
-    fn get_2d_array(&mut self, n: usize) -> Vec<Point2f> {
-        self.get_data().get_2d_array(n)
+    /// * `n`     - The number of samples.
+    /// * `arena` - The memory arena for per-sample allocations.
+    fn get_2d_array<'a>(&mut self, n: usize, arena: &'a MemoryArena) -> &'a [Point2f] {
+        self.get_data().get_2d_array(n, arena)
     }
 
     /// Reset the current sample dimension counter. Returns `true` if
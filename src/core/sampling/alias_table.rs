@@ -0,0 +1,152 @@
+//! Alias Table.
+
+#![allow(dead_code)]
+use crate::core::pbrt::*;
+use crate::core::rng::ONE_MINUS_EPSILON;
+
+/// Samples a discrete distribution in constant time using the alias method.
+///
+/// Each bin is chosen uniformly and then either returns its own index with
+/// probability `q` or the index of its alias.
+#[derive(Clone, Default)]
+pub struct AliasTable {
+    /// The bins.
+    bins: Vec<AliasBin>,
+}
+
+/// A bin of an `AliasTable`.
+#[derive(Copy, Clone, Default)]
+struct AliasBin {
+    /// Probability of returning this bin's index when it is chosen.
+    q: Float,
+
+    /// Probability mass of this bin's index.
+    p: Float,
+
+    /// Index returned with probability 1 - `q` when this bin is chosen.
+    alias: usize,
+}
+
+impl AliasTable {
+    /// Returns a new `AliasTable` for the given weights. The weights don't
+    /// need to be normalized. If they are all zero, sampling returns
+    /// uniformly distributed indices with a probability mass of 0.
+    ///
+    /// * `weights` - Non-negative weight of each index.
+    pub fn new(weights: &[Float]) -> Self {
+        let n = weights.len();
+        let sum: f64 = weights.iter().map(|&w| w as f64).sum();
+        let mut bins: Vec<AliasBin> = weights
+            .iter()
+            .enumerate()
+            .map(|(i, &w)| AliasBin {
+                q: 1.0,
+                p: if sum > 0.0 {
+                    (w as f64 / sum) as Float
+                } else {
+                    0.0
+                },
+                alias: i,
+            })
+            .collect();
+        if sum == 0.0 {
+            return Self { bins };
+        }
+
+        // Partition the bins by whether their probability scaled by the
+        // number of bins is under or over 1.
+        let mut under: Vec<(usize, f64)> = vec![];
+        let mut over: Vec<(usize, f64)> = vec![];
+        for (i, &w) in weights.iter().enumerate() {
+            let p_hat = w as f64 / sum * n as f64;
+            if p_hat < 1.0 {
+                under.push((i, p_hat));
+            } else {
+                over.push((i, p_hat));
+            }
+        }
+
+        // Fill each bin that is under 1 with the excess of one that is over.
+        while let (Some(&(un, un_p)), Some(&(ov, ov_p))) = (under.last(), over.last()) {
+            under.pop();
+            over.pop();
+            bins[un].q = un_p as Float;
+            bins[un].alias = ov;
+
+            let excess = un_p + ov_p - 1.0;
+            if excess < 1.0 {
+                under.push((ov, excess));
+            } else {
+                over.push((ov, excess));
+            }
+        }
+
+        // The remaining bins are 1 up to round-off error; they keep their
+        // own index.
+        Self { bins }
+    }
+
+    /// Returns the number of indices.
+    pub fn len(&self) -> usize {
+        self.bins.len()
+    }
+
+    /// Returns `true` if there are no indices.
+    pub fn is_empty(&self) -> bool {
+        self.bins.is_empty()
+    }
+
+    /// Returns a sampled index, its probability mass and the random sample
+    /// remapped to [0, 1) for reuse.
+    ///
+    /// * `u` - The random sample in [0, 1).
+    pub fn sample(&self, u: Float) -> (usize, Float, Float) {
+        let n = self.bins.len();
+        let offset = min((u * n as Float) as usize, n - 1);
+        let up = min(u * n as Float - offset as Float, ONE_MINUS_EPSILON);
+
+        let bin = &self.bins[offset];
+        if up < bin.q {
+            (offset, bin.p, min(up / bin.q, ONE_MINUS_EPSILON))
+        } else {
+            let alias = bin.alias;
+            let u_remapped = min((up - bin.q) / (1.0 - bin.q), ONE_MINUS_EPSILON);
+            (alias, self.bins[alias].p, u_remapped)
+        }
+    }
+
+    /// Returns the probability mass of an index.
+    ///
+    /// * `index` - The index.
+    pub fn pmf(&self, index: usize) -> Float {
+        self.bins[index].p
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sampled_frequencies_match_weights() {
+        let weights = [1.0, 0.0, 3.0, 0.5, 2.5, 1.0];
+        let table = AliasTable::new(&weights);
+        let total: Float = weights.iter().sum();
+
+        let n = 60000;
+        let mut counts = [0_usize; 6];
+        for i in 0..n {
+            let u = (i as Float + 0.5) / n as Float;
+            let (index, pmf, u_remapped) = table.sample(u);
+            assert_eq!(pmf, table.pmf(index));
+            assert!((0.0..1.0).contains(&u_remapped));
+            counts[index] += 1;
+        }
+
+        for (i, &w) in weights.iter().enumerate() {
+            assert!((table.pmf(i) - w / total).abs() < 1e-6);
+            let frequency = counts[i] as Float / n as Float;
+            assert!((frequency - w / total).abs() < 1e-3, "index {}", i);
+        }
+    }
+}
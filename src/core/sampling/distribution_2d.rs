@@ -3,7 +3,7 @@
 #![allow(dead_code)]
 use crate::core::geometry::*;
 use crate::core::pbrt::*;
-use crate::core::sampling::Distribution1D;
+use crate::core::sampling::{AliasTable, Distribution1D};
 
 /// Represents a piecewise-constant 2D function’s PDF and CDF and provides
 /// methods to perform this sampling efficiently.
@@ -14,6 +14,10 @@ pub struct Distribution2D {
 
     /// Marginal sampling density p[ṽ].
     p_marginal: Distribution1D,
+
+    /// Alias table for choosing a cell in constant time. The cells are
+    /// stored row by row.
+    cells: AliasTable,
 }
 
 impl Distribution2D {
@@ -27,25 +31,34 @@ impl Distribution2D {
             .collect();
         let marginal_func: Vec<Float> = p_conditional_v.iter().map(|pcv| pcv.func_int).collect();
         let p_marginal = Distribution1D::new(marginal_func);
+        let cells = AliasTable::new(&func.concat());
         Self {
             p_conditional_v,
             p_marginal,
+            cells,
         }
     }
 
     /// Return a sample point and PDF from the distribution given a random sample.
     ///
+    /// The cell is chosen with the alias table using `u[1]` and the point is
+    /// placed uniformly within it using `u[0]` and the remapped `u[1]`.
+    ///
     /// - `u` - The random sample.
     pub fn sample_continuous(&self, u: &Point2f) -> (Point2f, Float) {
-        // Draw a sample from the p[ṽ] marginal distribution in order to find the
-        // ṽ coordinate.
-        let (d1, pdf1, v) = self.p_marginal.sample_continuous(u[1]);
+        let nu = self.p_conditional_v[0].count();
+        let nv = self.p_marginal.count();
 
-        // Use ṽ to find the precomputed conditional distribution to use for
-        // sampling ũ.
-        let (d0, pdf0, _) = self.p_conditional_v[v].sample_continuous(u[0]);
+        let (cell, _, u1) = self.cells.sample(u[1]);
+        let (iu, iv) = (cell % nu, cell / nu);
+        let d0 = (iu as Float + u[0]) / nu as Float;
+        let d1 = (iv as Float + u1) / nv as Float;
 
-        let pdf = pdf0 * pdf1;
+        let pdf = if self.p_marginal.func_int > 0.0 {
+            self.p_conditional_v[iv].func[iu] / self.p_marginal.func_int
+        } else {
+            0.0
+        };
 
         (Point2f::new(d0, d1), pdf)
     }
@@ -1,10 +1,12 @@
 //! Sampling functions
 
+mod alias_table;
 mod common;
 mod distribution_1d;
 mod distribution_2d;
 
 // Re-export.
+pub use alias_table::*;
 pub use common::*;
 pub use distribution_1d::*;
 pub use distribution_2d::*;
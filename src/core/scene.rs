@@ -4,8 +4,21 @@
 use crate::core::geometry::*;
 use crate::core::light::*;
 use crate::core::primitive::*;
+use crate::core::profiler::*;
 use crate::core::sampler::*;
 use crate::core::spectrum::*;
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+stat_counter!(
+    "Intersections/Regular ray intersection tests",
+    N_INTERSECTION_TESTS
+);
+stat_counter!(
+    "Intersections/Shadow ray intersection tests",
+    N_SHADOW_TESTS
+);
 
 /// Scene.
 #[derive(Clone)]
@@ -21,6 +34,9 @@ pub struct Scene {
 
     /// The bounding box of the scene geometry.
     pub world_bound: Bounds3f,
+
+    /// Data precomputed for the scene by integrators.
+    pub preprocessed: PreprocessCache,
 }
 
 impl Scene {
@@ -38,6 +54,7 @@ impl Scene {
                 .filter(|l| l.get_type().matches(INFINITE_LIGHT))
                 .map(|l| l.clone())
                 .collect(),
+            preprocessed: PreprocessCache::default(),
         }
     }
 
@@ -46,6 +63,8 @@ impl Scene {
     ///
     /// * `ray` - The ray to trace.
     pub fn intersect(&self, ray: &mut Ray) -> Option<SurfaceInteraction> {
+        let _p = ProfilePhase::new(Prof::AccelIntersect);
+        N_INTERSECTION_TESTS.inc();
         self.aggregate.intersect(ray)
     }
 
@@ -54,6 +73,8 @@ impl Scene {
     ///
     /// * `ray` - The ray to trace.
     pub fn intersect_p(&self, ray: &Ray) -> bool {
+        let _p = ProfilePhase::new(Prof::AccelIntersectP);
+        N_SHADOW_TESTS.inc();
         self.aggregate.intersect_p(ray)
     }
 
@@ -66,7 +87,7 @@ impl Scene {
     pub fn intersect_tr(
         &self,
         ray: &mut Ray,
-        sampler: ArcSampler,
+        sampler: &mut ArcSampler,
     ) -> Option<(SurfaceInteraction, Spectrum)> {
         let mut tr = Spectrum::new(1.0);
 
@@ -75,7 +96,7 @@ impl Scene {
 
             // Accumulate beam transmittance for ray segment
             if let Some(medium) = &ray.medium {
-                tr *= medium.tr(ray, sampler.clone());
+                tr *= medium.tr(ray, sampler);
             }
 
             // Initialize next ray segment or terminate transmittance computation.
@@ -91,3 +112,70 @@ impl Scene {
         }
     }
 }
+
+/// Data integrators precompute for a scene in `preprocess()`, e.g. light
+/// distributions or photon maps, keyed by name and type. Clones of a scene
+/// share it so that renders of several camera views, or repeated interactive
+/// renders, of the same scene compute it once.
+#[derive(Clone, Default)]
+pub struct PreprocessCache {
+    /// The data.
+    entries: Arc<Mutex<HashMap<(String, TypeId), Arc<dyn Any + Send + Sync>>>>,
+}
+
+impl PreprocessCache {
+    /// Returns the data of the given type stored with a key; or `None` if
+    /// there is none.
+    ///
+    /// * `key` - The key.
+    pub fn get<T: Any + Send + Sync>(&self, key: &str) -> Option<Arc<T>> {
+        let entries = self.entries.lock().unwrap();
+        entries
+            .get(&(String::from(key), TypeId::of::<T>()))
+            .map(|data| data.clone().downcast::<T>().unwrap())
+    }
+
+    /// Returns the data of the given type stored with a key; computing and
+    /// storing it first if there is none.
+    ///
+    /// * `key` - The key.
+    /// * `f`   - Computes the data.
+    pub fn get_or_insert_with<T, F>(&self, key: &str, f: F) -> Arc<T>
+    where
+        T: Any + Send + Sync,
+        F: FnOnce() -> T,
+    {
+        if let Some(data) = self.get(key) {
+            return data;
+        }
+
+        // Compute the data without holding the lock so that `f` can use the
+        // cache too. If another thread stored it meanwhile, that is kept.
+        let data: Arc<dyn Any + Send + Sync> = Arc::new(f());
+        let mut entries = self.entries.lock().unwrap();
+        entries
+            .entry((String::from(key), TypeId::of::<T>()))
+            .or_insert(data)
+            .clone()
+            .downcast::<T>()
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn preprocessed_data_is_computed_once_and_shared_by_clones() {
+        let cache = PreprocessCache::default();
+        let shared = cache.clone();
+        let a = cache.get_or_insert_with("photons", || vec![1, 2, 3]);
+        let b = shared.get_or_insert_with("photons", || -> Vec<i32> { unreachable!() });
+        assert!(Arc::ptr_eq(&a, &b));
+
+        // The same key with another type is separate data.
+        assert!(cache.get::<Vec<u8>>("photons").is_none());
+        assert_eq!(*cache.get_or_insert_with("photons", || 7u8), 7);
+    }
+}
@@ -0,0 +1,1396 @@
+//! Scene Cache
+
+#![allow(dead_code)]
+use crate::accelerators::LinearBVHNode;
+use crate::core::api::*;
+use crate::core::app::OPTIONS;
+use crate::core::error::*;
+use crate::core::fileutil::*;
+use crate::core::geometry::*;
+use crate::core::paramset::*;
+use crate::core::pbrt::*;
+use crate::core::spectrum::*;
+use crate::shapes::*;
+use half::f16;
+use std::collections::HashMap;
+use std::convert::TryInto;
+use std::fs;
+use std::mem::size_of;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+stat_counter!("Scene cache/Scenes loaded", N_SCENES_LOADED);
+stat_counter!("Scene cache/Scenes written", N_SCENES_WRITTEN);
+stat_counter!("Scene cache/BVHs loaded", N_BVHS_LOADED);
+stat_counter!("Scene cache/BVHs written", N_BVHS_WRITTEN);
+
+/// Identifies scene cache files and the version of their format.
+const SCENE_MAGIC: &[u8; 8] = b"PBRSCN02";
+
+/// Identifies BVH cache files and the version of their format.
+const BVH_MAGIC: &[u8; 8] = b"PBRBVH01";
+
+/// Minimum number of primitives of a BVH before it is cached. Building
+/// smaller trees is quicker than reading a file.
+pub const MIN_CACHED_PRIMITIVES: usize = 10_000;
+
+/// The index of the primitive at each position of a BVH and its nodes.
+pub type CachedBVH = (Vec<usize>, Vec<LinearBVHNode>);
+
+/// Directory of binary files holding the API calls recorded while parsing
+/// scene files, the shapes they created and the BVHs built for them. A scene
+/// that hasn't changed is replayed from its calls instead of being parsed
+/// again, and a BVH over primitives with the same bounds is read instead of
+/// being built again.
+///
+/// Shapes of the built-in types are stored as they were constructed, so the
+/// replay restores them without reading mesh files or converting meshes to
+/// world space. Triangle meshes and transforms shared by shapes are stored
+/// once. Shapes created by plugins and meshes with alpha masks aren't stored;
+/// the replay creates them again. Materials, textures, media and lights
+/// aren't stored either. They are cheap to create, apart from image textures
+/// which the texture cache shares, so the replay creates them from the
+/// recorded calls.
+pub struct SceneCache {
+    /// The directory.
+    dir: PathBuf,
+}
+
+impl SceneCache {
+    /// Create a new `SceneCache`.
+    ///
+    /// * `dir` - The directory holding the cache files.
+    pub fn new(dir: &str) -> Self {
+        Self {
+            dir: PathBuf::from(dir),
+        }
+    }
+
+    /// Returns the key of a scene file. Spectra are converted to the
+    /// rendering color space while parsing so it is part of the key.
+    ///
+    /// * `path` - Path of the scene file.
+    fn scene_key(path: &str) -> CacheKey {
+        let mut key = CacheKey::default();
+        key.add_bytes(absolute_path(path).as_deref().unwrap_or(path).as_bytes());
+        key.add_bytes(format!("{:?}", OPTIONS.color_space).as_bytes());
+        key
+    }
+
+    /// Returns the path of the cache file of a scene.
+    ///
+    /// * `key` - Key of the scene.
+    pub fn scene_path(&self, key: CacheKey) -> PathBuf {
+        self.dir.join(format!("{:016x}.scene", key.value()))
+    }
+
+    /// Returns the path of the cache file of a BVH.
+    ///
+    /// * `key` - Key of the BVH.
+    pub fn bvh_path(&self, key: CacheKey) -> PathBuf {
+        self.dir.join(format!("{:016x}.bvh", key.value()))
+    }
+
+    /// Returns the API calls and shapes recorded for a scene file. Returns
+    /// `None` if the scene isn't cached or the scene file or any file it
+    /// includes changed since, or an error if the cache file can't be read or
+    /// is invalid.
+    ///
+    /// * `path` - Path of the scene file.
+    pub fn load(&self, path: &str) -> Result<Option<SceneRecording>, Error> {
+        let cache_path = self.scene_path(Self::scene_key(path));
+        let bytes = match Self::read(&cache_path)? {
+            Some(bytes) => bytes,
+            None => return Ok(None),
+        };
+
+        let mut reader =
+            Reader::new(&bytes, SCENE_MAGIC).map_err(|err| err.in_file(&cache_path))?;
+        let recording = reader.recording().map_err(|err| err.in_file(&cache_path))?;
+
+        // Stale if any of the files it was read from changed.
+        let unchanged = recording.files.iter().all(|(file, key)| {
+            fs::read_to_string(file).is_ok_and(|source| SceneRecording::source_key(&source) == *key)
+        });
+        if !unchanged {
+            return Ok(None);
+        }
+
+        N_SCENES_LOADED.inc();
+        Ok(Some(recording))
+    }
+
+    /// Writes the API calls and shapes recorded for a scene file to the
+    /// cache; or returns an error if the file can't be written.
+    ///
+    /// * `path`      - Path of the scene file.
+    /// * `recording` - The recorded calls, files and shapes.
+    pub fn store(&self, path: &str, recording: &SceneRecording) -> Result<(), Error> {
+        let mut writer = Writer::new(SCENE_MAGIC);
+        writer.recording(recording);
+        self.write(&self.scene_path(Self::scene_key(path)), &writer.bytes)?;
+        N_SCENES_WRITTEN.inc();
+        Ok(())
+    }
+
+    /// Returns the order of the primitives and the nodes of a cached BVH.
+    /// Returns `None` if the BVH isn't cached or an error if the file can't
+    /// be read or is invalid.
+    ///
+    /// * `key`          - Key of the BVH.
+    /// * `n_primitives` - Number of primitives in the BVH.
+    pub fn load_bvh(&self, key: CacheKey, n_primitives: usize) -> Result<Option<CachedBVH>, Error> {
+        let path = self.bvh_path(key);
+        let bytes = match Self::read(&path)? {
+            Some(bytes) => bytes,
+            None => return Ok(None),
+        };
+
+        let mut reader = Reader::new(&bytes, BVH_MAGIC).map_err(|err| err.in_file(&path))?;
+        let bvh = reader.bvh(n_primitives).map_err(|err| err.in_file(&path))?;
+        N_BVHS_LOADED.inc();
+        Ok(Some(bvh))
+    }
+
+    /// Writes a BVH to the cache; or returns an error if the file can't be
+    /// written.
+    ///
+    /// * `key`   - Key of the BVH.
+    /// * `order` - Index of the primitive at each position of the BVH.
+    /// * `nodes` - The nodes.
+    pub fn store_bvh(
+        &self,
+        key: CacheKey,
+        order: &[usize],
+        nodes: &[LinearBVHNode],
+    ) -> Result<(), Error> {
+        let mut writer = Writer::new(BVH_MAGIC);
+        writer.bvh(order, nodes);
+        self.write(&self.bvh_path(key), &writer.bytes)?;
+        N_BVHS_WRITTEN.inc();
+        Ok(())
+    }
+
+    /// Returns the contents of a cache file; or `None` if it doesn't exist.
+    ///
+    /// * `path` - Path of the file.
+    fn read(path: &Path) -> Result<Option<Vec<u8>>, Error> {
+        match fs::read(path) {
+            Ok(bytes) => Ok(Some(bytes)),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(Error::Io(format!(
+                "Error reading scene cache '{}'. {}.",
+                path.display(),
+                err
+            ))),
+        }
+    }
+
+    /// Writes a cache file.
+    ///
+    /// * `path`  - Path of the file.
+    /// * `bytes` - Contents of the file.
+    fn write(&self, path: &Path, bytes: &[u8]) -> Result<(), Error> {
+        // Write to a temporary file and rename it so other renders never read
+        // a partially written file.
+        let temp_path = path.with_extension(format!("tmp{}", std::process::id()));
+        fs::create_dir_all(&self.dir)
+            .and_then(|_| fs::write(&temp_path, bytes))
+            .and_then(|_| fs::rename(&temp_path, path))
+            .map_err(|err| {
+                let _ = fs::remove_file(&temp_path);
+                Error::Io(format!(
+                    "Error writing scene cache '{}'. {}.",
+                    path.display(),
+                    err
+                ))
+            })
+    }
+}
+
+/// Error reading a cache file; it names the file once it is known.
+struct ReadError(String);
+
+impl ReadError {
+    /// Returns the error for a cache file.
+    ///
+    /// * `path` - Path of the file.
+    fn in_file(self, path: &Path) -> Error {
+        Error::Parse(format!(
+            "Error reading scene cache '{}'. {}",
+            path.display(),
+            self.0
+        ))
+    }
+}
+
+/// Writes values in the little-endian binary format of cache files.
+#[derive(Default)]
+struct Writer {
+    /// The bytes written.
+    bytes: Vec<u8>,
+}
+
+impl Writer {
+    /// Create a new `Writer` for a cache file.
+    ///
+    /// * `magic` - Identifies the type of file.
+    fn new(magic: &[u8; 8]) -> Self {
+        let mut writer = Self {
+            bytes: magic.to_vec(),
+        };
+        writer.u8(size_of::<Float>() as u8);
+        writer
+    }
+
+    /// Writes a byte.
+    fn u8(&mut self, v: u8) {
+        self.bytes.push(v);
+    }
+
+    /// Writes a 16-bit integer.
+    fn u16(&mut self, v: u16) {
+        self.bytes.extend_from_slice(&v.to_le_bytes());
+    }
+
+    /// Writes a 32-bit integer.
+    fn u32(&mut self, v: u32) {
+        self.bytes.extend_from_slice(&v.to_le_bytes());
+    }
+
+    /// Writes a 64-bit integer.
+    fn u64(&mut self, v: u64) {
+        self.bytes.extend_from_slice(&v.to_le_bytes());
+    }
+
+    /// Writes a number of values.
+    fn len(&mut self, n: usize) {
+        self.u64(n as u64);
+    }
+
+    /// Writes a floating point value.
+    fn float(&mut self, v: Float) {
+        self.bytes.extend_from_slice(&v.to_le_bytes());
+    }
+
+    /// Writes floating point values.
+    fn floats(&mut self, v: &[Float]) {
+        for f in v {
+            self.float(*f);
+        }
+    }
+
+    /// Writes a string.
+    fn string(&mut self, s: &str) {
+        self.len(s.len());
+        self.bytes.extend_from_slice(s.as_bytes());
+    }
+
+    /// Writes the parameters of a parameter set of one type.
+    ///
+    /// * `items` - The parameters.
+    /// * `value` - Writes a value.
+    fn items<'a, T: 'a>(
+        &mut self,
+        items: impl Iterator<Item = (&'a str, &'a [T])>,
+        value: impl Fn(&mut Self, &T),
+    ) {
+        let items: Vec<(&str, &[T])> = items.collect();
+        self.len(items.len());
+        for (name, values) in items {
+            self.string(name);
+            self.len(values.len());
+            for v in values {
+                value(self, v);
+            }
+        }
+    }
+
+    /// Writes a parameter set.
+    fn params(&mut self, params: &ParamSet) {
+        self.items(params.iter_bools(), |w, v| w.u8(*v as u8));
+        self.items(params.iter_ints(), |w, v| w.u64(*v as i64 as u64));
+        self.items(params.iter_floats(), |w, v| w.float(*v));
+        self.items(params.iter_point2fs(), |w, v| w.floats(&[v.x, v.y]));
+        self.items(params.iter_vector2fs(), |w, v| w.floats(&[v.x, v.y]));
+        self.items(params.iter_point3fs(), |w, v| w.floats(&[v.x, v.y, v.z]));
+        self.items(params.iter_vector3fs(), |w, v| w.floats(&[v.x, v.y, v.z]));
+        self.items(params.iter_normal3fs(), |w, v| w.floats(&[v.x, v.y, v.z]));
+        self.items(params.iter_spectra(), |w, v| {
+            w.len(v.samples().len());
+            w.floats(v.samples());
+        });
+        self.items(params.iter_strings(), |w, v| w.string(v));
+        self.items(params.iter_textures(), |w, v| w.string(v));
+    }
+
+    /// Writes a call with a name and parameters.
+    fn named_params(&mut self, tag: u8, name: &str, params: &ParamSet) {
+        self.u8(tag);
+        self.string(name);
+        self.params(params);
+    }
+
+    /// Writes a call with a name.
+    fn named(&mut self, tag: u8, name: &str) {
+        self.u8(tag);
+        self.string(name);
+    }
+
+    /// Writes an API call; its first byte identifies the method.
+    fn call(&mut self, call: &ApiCall) {
+        match call {
+            ApiCall::Identity => self.u8(0),
+            ApiCall::Translate(dx, dy, dz) => {
+                self.u8(1);
+                self.floats(&[*dx, *dy, *dz]);
+            }
+            ApiCall::Transform(tr) => {
+                self.u8(2);
+                self.floats(tr);
+            }
+            ApiCall::ConcatTransform(tr) => {
+                self.u8(3);
+                self.floats(tr);
+            }
+            ApiCall::Rotate(angle, dx, dy, dz) => {
+                self.u8(4);
+                self.floats(&[*angle, *dx, *dy, *dz]);
+            }
+            ApiCall::Scale(sx, sy, sz) => {
+                self.u8(5);
+                self.floats(&[*sx, *sy, *sz]);
+            }
+            ApiCall::LookAt(v) => {
+                self.u8(6);
+                self.floats(v);
+            }
+            ApiCall::CoordinateSystem(name) => self.named(7, name),
+            ApiCall::CoordSysTransform(name) => self.named(8, name),
+            ApiCall::ActiveTransformAll => self.u8(9),
+            ApiCall::ActiveTransformEndTime => self.u8(10),
+            ApiCall::ActiveTransformStartTime => self.u8(11),
+            ApiCall::TransformTimes(start, end) => {
+                self.u8(12);
+                self.floats(&[*start, *end]);
+            }
+            ApiCall::Option(params) => {
+                self.u8(13);
+                self.params(params);
+            }
+            ApiCall::PixelFilter(name, params) => self.named_params(14, name, params),
+            ApiCall::Film(name, params) => self.named_params(15, name, params),
+            ApiCall::Sampler(name, params) => self.named_params(16, name, params),
+            ApiCall::Accelerator(name, params) => self.named_params(17, name, params),
+            ApiCall::Integrator(name, params) => self.named_params(18, name, params),
+            ApiCall::Camera(name, params) => self.named_params(19, name, params),
+            ApiCall::MakeNamedMedium(name, params) => self.named_params(20, name, params),
+            ApiCall::MediumInterface(inside, outside) => {
+                self.named(21, inside);
+                self.string(outside);
+            }
+            ApiCall::WorldBegin => self.u8(22),
+            ApiCall::WorldEnd => self.u8(23),
+            ApiCall::AttributeBegin => self.u8(24),
+            ApiCall::AttributeEnd => self.u8(25),
+            ApiCall::TransformBegin => self.u8(26),
+            ApiCall::TransformEnd => self.u8(27),
+            ApiCall::Texture(name, texture_type, tex_name, params) => {
+                self.named(28, name);
+                self.string(texture_type);
+                self.string(tex_name);
+                self.params(params);
+            }
+            ApiCall::Material(name, params) => self.named_params(29, name, params),
+            ApiCall::MakeNamedMaterial(name, params) => self.named_params(30, name, params),
+            ApiCall::NamedMaterial(name) => self.named(31, name),
+            ApiCall::LightSource(name, params) => self.named_params(32, name, params),
+            ApiCall::AreaLightSource(name, params) => self.named_params(33, name, params),
+            ApiCall::Shape(name, params) => self.named_params(34, name, params),
+            ApiCall::ReverseOrientation => self.u8(35),
+            ApiCall::ObjectBegin(name) => self.named(36, name),
+            ApiCall::ObjectEnd => self.u8(37),
+            ApiCall::ObjectInstance(name) => self.named(38, name),
+        }
+    }
+
+    /// Writes the files, calls and shapes of a recording.
+    fn recording(&mut self, recording: &SceneRecording) {
+        self.len(recording.files.len());
+        for (file, key) in recording.files.iter() {
+            self.string(file);
+            self.u64(key.value());
+        }
+        self.len(recording.calls.len());
+        for call in recording.calls.iter() {
+            self.call(call);
+        }
+        self.shapes(&recording.shapes);
+    }
+
+    /// Writes the shapes of a recording. The transforms and triangle meshes
+    /// they share are written first, once each, and the shapes refer to them
+    /// by index. Shapes that can't be stored are written as missing.
+    ///
+    /// * `shapes` - The shapes.
+    fn shapes(&mut self, shapes: &[RecordedShapes]) {
+        let mut shared = SharedShapeData::default();
+        let mut entries = Writer::default();
+        entries.len(shapes.len());
+        for (name, shapes) in shapes {
+            entries.string(name);
+            match shapes.as_ref().filter(|s| s.iter().all(is_storable)) {
+                Some(shapes) => {
+                    entries.u8(1);
+                    entries.len(shapes.len());
+                    for shape in shapes {
+                        entries.shape(shape, &mut shared);
+                    }
+                }
+                None => entries.u8(0),
+            }
+        }
+
+        self.len(shared.transforms.len());
+        self.bytes.append(&mut shared.transform_bytes.bytes);
+        self.len(shared.meshes.len());
+        self.bytes.append(&mut shared.mesh_bytes.bytes);
+        self.bytes.append(&mut entries.bytes);
+    }
+
+    /// Writes a point.
+    fn point3f(&mut self, p: &Point3f) {
+        self.floats(&[p.x, p.y, p.z]);
+    }
+
+    /// Writes the index of a transform; the transform is written to the
+    /// shared data the first time.
+    ///
+    /// * `t`      - The transform.
+    /// * `shared` - Transforms and meshes written so far.
+    fn transform(&mut self, t: &ArcTransform, shared: &mut SharedShapeData) {
+        let key = Arc::as_ptr(t);
+        let index = match shared.transforms.get(&key) {
+            Some(index) => *index,
+            None => {
+                for row in t.m.m.iter().chain(t.m_inv.m.iter()) {
+                    shared.transform_bytes.floats(row);
+                }
+                let index = shared.transforms.len();
+                shared.transforms.insert(key, index);
+                index
+            }
+        };
+        self.u32(index as u32);
+    }
+
+    /// Writes common shape data.
+    ///
+    /// * `data`   - The shape data.
+    /// * `shared` - Transforms and meshes written so far.
+    fn shape_data(&mut self, data: &ShapeData, shared: &mut SharedShapeData) {
+        self.transform(&data.object_to_world, shared);
+        match data.world_to_object.as_ref() {
+            Some(t) => {
+                self.u8(1);
+                self.transform(t, shared);
+            }
+            None => self.u8(0),
+        }
+        self.u8(data.reverse_orientation as u8);
+    }
+
+    /// Writes the index of a triangle mesh; the mesh is written to the
+    /// shared data the first time.
+    ///
+    /// * `mesh`   - The mesh.
+    /// * `shared` - Transforms and meshes written so far.
+    fn triangle_mesh(&mut self, mesh: &Arc<TriangleMesh>, shared: &mut SharedShapeData) {
+        let key = Arc::as_ptr(mesh);
+        let index = match shared.meshes.get(&key) {
+            Some(index) => *index,
+            None => {
+                let mut w = std::mem::take(&mut shared.mesh_bytes);
+                w.shape_data(&mesh.data, shared);
+                w.len(mesh.vertex_indices.len());
+                for i in mesh.vertex_indices.iter() {
+                    w.u32(*i as u32);
+                }
+                w.len(mesh.p.len());
+                for p in mesh.p.iter() {
+                    w.point3f(p);
+                }
+                w.len(mesh.n.len());
+                for n in mesh.n.iter() {
+                    let [x, y] = n.bits();
+                    w.u16(x);
+                    w.u16(y);
+                }
+                w.len(mesh.s.len());
+                for s in mesh.s.iter() {
+                    w.floats(&[s.x, s.y, s.z]);
+                }
+                match &mesh.uv {
+                    MeshUVs::Full(uv) => {
+                        w.u8(0);
+                        w.len(uv.len());
+                        for p in uv.iter() {
+                            w.floats(&[p.x, p.y]);
+                        }
+                    }
+                    MeshUVs::Half(uv) => {
+                        w.u8(1);
+                        w.len(uv.len());
+                        for [u, v] in uv.iter() {
+                            w.u16(u.to_bits());
+                            w.u16(v.to_bits());
+                        }
+                    }
+                }
+                w.len(mesh.face_indices.len());
+                for i in mesh.face_indices.iter() {
+                    w.u64(*i as u64);
+                }
+                w.u8(mesh.smooth_terminator as u8);
+                shared.mesh_bytes = w;
+
+                let index = shared.meshes.len();
+                shared.meshes.insert(key, index);
+                index
+            }
+        };
+        self.u32(index as u32);
+    }
+
+    /// Writes a shape of a built-in type; its first byte identifies the
+    /// type. `is_storable()` must be `true` for the shape.
+    ///
+    /// * `shape`  - The shape.
+    /// * `shared` - Transforms and meshes written so far.
+    fn shape(&mut self, shape: &ArcShape, shared: &mut SharedShapeData) {
+        let any = shape.as_any().expect("shape can't be stored");
+        if let Some(s) = any.downcast_ref::<Sphere>() {
+            self.u8(0);
+            self.shape_data(&s.data, shared);
+            self.floats(&[
+                s.radius,
+                s.z_min,
+                s.z_max,
+                s.theta_min,
+                s.theta_max,
+                s.phi_max,
+            ]);
+        } else if let Some(s) = any.downcast_ref::<Cylinder>() {
+            self.u8(1);
+            self.shape_data(&s.data, shared);
+            self.floats(&[s.radius, s.z_min, s.z_max, s.phi_max]);
+        } else if let Some(s) = any.downcast_ref::<Disk>() {
+            self.u8(2);
+            self.shape_data(&s.data, shared);
+            self.floats(&[s.height, s.radius, s.inner_radius, s.phi_max]);
+        } else if let Some(s) = any.downcast_ref::<Cone>() {
+            self.u8(3);
+            self.shape_data(&s.data, shared);
+            self.floats(&[s.radius, s.height, s.phi_max]);
+        } else if let Some(s) = any.downcast_ref::<Paraboloid>() {
+            self.u8(4);
+            self.shape_data(&s.data, shared);
+            self.floats(&[s.radius, s.z_min, s.z_max, s.phi_max]);
+        } else if let Some(s) = any.downcast_ref::<Hyperboloid>() {
+            self.u8(5);
+            self.shape_data(&s.data, shared);
+            self.point3f(&s.p1);
+            self.point3f(&s.p2);
+            self.floats(&[s.z_min, s.z_max, s.phi_max, s.r_max, s.ah, s.ch]);
+        } else if let Some(s) = any.downcast_ref::<Curve>() {
+            self.u8(6);
+            self.shape_data(&s.data, shared);
+            let c = &s.common;
+            self.u8(match c.curve_type {
+                CurveType::Flat => 0,
+                CurveType::Cylinder => 1,
+                CurveType::Ribbon => 2,
+            });
+            for p in c.cp_obj.iter() {
+                self.point3f(p);
+            }
+            self.floats(&c.width);
+            for n in c.n.iter() {
+                self.floats(&[n.x, n.y, n.z]);
+            }
+            self.floats(&[c.normal_angle, c.inv_sin_normal_angle, s.u_min, s.u_max]);
+        } else if let Some(s) = any.downcast_ref::<Triangle>() {
+            self.u8(7);
+            self.shape_data(&s.data, shared);
+            self.triangle_mesh(&s.mesh, shared);
+            self.u64(s.v as u64);
+        } else {
+            panic!("shape can't be stored");
+        }
+    }
+
+    /// Writes the order of the primitives and the nodes of a BVH.
+    fn bvh(&mut self, order: &[usize], nodes: &[LinearBVHNode]) {
+        self.len(order.len());
+        for i in order {
+            self.u32(*i as u32);
+        }
+        self.len(nodes.len());
+        for node in nodes {
+            let (p_min, p_max) = (node.bounds.p_min, node.bounds.p_max);
+            self.floats(&[p_min.x, p_min.y, p_min.z, p_max.x, p_max.y, p_max.z]);
+            self.u32(node.offset);
+            self.u16(node.n_primitives);
+            self.u8(node.axis);
+        }
+    }
+}
+
+/// Transforms and triangle meshes shared by the shapes of a recording. Each
+/// is written once and found by its address afterwards.
+#[derive(Default)]
+struct SharedShapeData {
+    /// Index of each transform written.
+    transforms: HashMap<*const Transform, usize>,
+
+    /// The transforms written.
+    transform_bytes: Writer,
+
+    /// Index of each triangle mesh written.
+    meshes: HashMap<*const TriangleMesh, usize>,
+
+    /// The triangle meshes written.
+    mesh_bytes: Writer,
+}
+
+/// Returns `true` if a shape is of a built-in type that can be stored in the
+/// cache. Triangle meshes with alpha masks can't be; their textures aren't
+/// stored.
+///
+/// * `shape` - The shape.
+fn is_storable(shape: &ArcShape) -> bool {
+    let any = match shape.as_any() {
+        Some(any) => any,
+        None => return false,
+    };
+    match any.downcast_ref::<Triangle>() {
+        Some(triangle) => {
+            let mesh = &triangle.mesh;
+            mesh.alpha_mask.is_none()
+                && mesh.shadow_alpha_mask.is_none()
+                && mesh.p.len() <= u32::MAX as usize
+        }
+        None => {
+            any.is::<Sphere>()
+                || any.is::<Cylinder>()
+                || any.is::<Disk>()
+                || any.is::<Cone>()
+                || any.is::<Paraboloid>()
+                || any.is::<Hyperboloid>()
+                || any.is::<Curve>()
+        }
+    }
+}
+
+/// Reads values in the binary format of cache files.
+struct Reader<'a> {
+    /// The bytes left to read.
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    /// Create a new `Reader` for a cache file; or returns an error if it
+    /// isn't a file of the expected type.
+    ///
+    /// * `bytes` - Contents of the file.
+    /// * `magic` - Identifies the type of file.
+    fn new(bytes: &'a [u8], magic: &[u8; 8]) -> Result<Self, ReadError> {
+        if bytes.len() < magic.len() || &bytes[..magic.len()] != magic {
+            return Err(ReadError(String::from(
+                "Not a scene cache file or an unsupported version.",
+            )));
+        }
+        let mut reader = Self {
+            bytes: &bytes[magic.len()..],
+        };
+        if reader.u8()? as usize != size_of::<Float>() {
+            return Err(ReadError(String::from(
+                "Scene cache file was written by an incompatible build.",
+            )));
+        }
+        Ok(reader)
+    }
+
+    /// Returns the next bytes.
+    fn take(&mut self, n: usize) -> Result<&'a [u8], ReadError> {
+        if n > self.bytes.len() {
+            return Err(ReadError(String::from("Scene cache file is truncated.")));
+        }
+        let (taken, rest) = self.bytes.split_at(n);
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    /// Reads a byte.
+    fn u8(&mut self) -> Result<u8, ReadError> {
+        Ok(self.take(1)?[0])
+    }
+
+    /// Reads a 16-bit integer.
+    fn u16(&mut self) -> Result<u16, ReadError> {
+        Ok(u16::from_le_bytes(self.take(2)?.try_into().unwrap()))
+    }
+
+    /// Reads a 32-bit integer.
+    fn u32(&mut self) -> Result<u32, ReadError> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    /// Reads a 64-bit integer.
+    fn u64(&mut self) -> Result<u64, ReadError> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+
+    /// Reads a number of values. It can't exceed the number of bytes left so
+    /// a damaged file doesn't cause huge allocations.
+    fn len(&mut self) -> Result<usize, ReadError> {
+        let n = self.u64()?;
+        if n > self.bytes.len() as u64 {
+            return Err(ReadError(String::from("Scene cache file is damaged.")));
+        }
+        Ok(n as usize)
+    }
+
+    /// Reads a floating point value.
+    fn float(&mut self) -> Result<Float, ReadError> {
+        Ok(Float::from_le_bytes(
+            self.take(size_of::<Float>())?.try_into().unwrap(),
+        ))
+    }
+
+    /// Reads floating point values.
+    fn floats<const N: usize>(&mut self) -> Result<[Float; N], ReadError> {
+        let mut v = [0.0; N];
+        for f in v.iter_mut() {
+            *f = self.float()?;
+        }
+        Ok(v)
+    }
+
+    /// Reads a string.
+    fn string(&mut self) -> Result<String, ReadError> {
+        let n = self.len()?;
+        String::from_utf8(self.take(n)?.to_vec())
+            .map_err(|_| ReadError(String::from("Scene cache file is damaged.")))
+    }
+
+    /// Reads the parameters of a parameter set of one type.
+    ///
+    /// * `value` - Reads a value.
+    fn items<T>(
+        &mut self,
+        value: impl Fn(&mut Self) -> Result<T, ReadError>,
+    ) -> Result<Vec<(String, Vec<T>)>, ReadError> {
+        let n = self.len()?;
+        let mut items = Vec::with_capacity(n);
+        for _ in 0..n {
+            let name = self.string()?;
+            let n_values = self.len()?;
+            let values = (0..n_values)
+                .map(|_| value(self))
+                .collect::<Result<Vec<T>, ReadError>>()?;
+            items.push((name, values));
+        }
+        Ok(items)
+    }
+
+    /// Reads a parameter set.
+    fn params(&mut self) -> Result<ParamSet, ReadError> {
+        let mut params = ParamSet::new();
+        for (name, v) in self.items(|r| Ok(r.u8()? != 0))? {
+            params.add_bool(&name, &v);
+        }
+        for (name, v) in self.items(|r| Ok(r.u64()? as i64 as Int))? {
+            params.add_int(&name, &v);
+        }
+        for (name, v) in self.items(|r| r.float())? {
+            params.add_float(&name, &v);
+        }
+        for (name, v) in self.items(|r| r.floats().map(|[x, y]| Point2f::new(x, y)))? {
+            params.add_point2f(&name, &v);
+        }
+        for (name, v) in self.items(|r| r.floats().map(|[x, y]| Vector2f::new(x, y)))? {
+            params.add_vector2f(&name, &v);
+        }
+        for (name, v) in self.items(|r| r.floats().map(|[x, y, z]| Point3f::new(x, y, z)))? {
+            params.add_point3f(&name, &v);
+        }
+        for (name, v) in self.items(|r| r.floats().map(|[x, y, z]| Vector3f::new(x, y, z)))? {
+            params.add_vector3f(&name, &v);
+        }
+        for (name, v) in self.items(|r| r.floats().map(|[x, y, z]| Normal3f::new(x, y, z)))? {
+            params.add_normal3f(&name, &v);
+        }
+        for (name, v) in self.items(|r| r.spectrum())? {
+            params
+                .spectra
+                .insert(intern_param_name(&name), ParamSetItem::new(v));
+        }
+        for (name, v) in self.items(|r| r.string())? {
+            params.add_string(&name, &v);
+        }
+        for (name, v) in self.items(|r| r.string())? {
+            params.add_texture(&name, &v);
+        }
+        Ok(params)
+    }
+
+    /// Reads a spectrum.
+    fn spectrum(&mut self) -> Result<Spectrum, ReadError> {
+        let mut s = Spectrum::new(0.0);
+        if self.len()? != s.samples().len() {
+            return Err(ReadError(String::from(
+                "Scene cache file was written by an incompatible build.",
+            )));
+        }
+        for v in s.samples_mut() {
+            *v = self.float()?;
+        }
+        Ok(s)
+    }
+
+    /// Reads an API call.
+    fn call(&mut self) -> Result<ApiCall, ReadError> {
+        let call = match self.u8()? {
+            0 => ApiCall::Identity,
+            1 => {
+                let [dx, dy, dz] = self.floats()?;
+                ApiCall::Translate(dx, dy, dz)
+            }
+            2 => ApiCall::Transform(self.floats()?),
+            3 => ApiCall::ConcatTransform(self.floats()?),
+            4 => {
+                let [angle, dx, dy, dz] = self.floats()?;
+                ApiCall::Rotate(angle, dx, dy, dz)
+            }
+            5 => {
+                let [sx, sy, sz] = self.floats()?;
+                ApiCall::Scale(sx, sy, sz)
+            }
+            6 => ApiCall::LookAt(self.floats()?),
+            7 => ApiCall::CoordinateSystem(self.string()?),
+            8 => ApiCall::CoordSysTransform(self.string()?),
+            9 => ApiCall::ActiveTransformAll,
+            10 => ApiCall::ActiveTransformEndTime,
+            11 => ApiCall::ActiveTransformStartTime,
+            12 => {
+                let [start, end] = self.floats()?;
+                ApiCall::TransformTimes(start, end)
+            }
+            13 => ApiCall::Option(self.params()?),
+            14 => ApiCall::PixelFilter(self.string()?, self.params()?),
+            15 => ApiCall::Film(self.string()?, self.params()?),
+            16 => ApiCall::Sampler(self.string()?, self.params()?),
+            17 => ApiCall::Accelerator(self.string()?, self.params()?),
+            18 => ApiCall::Integrator(self.string()?, self.params()?),
+            19 => ApiCall::Camera(self.string()?, self.params()?),
+            20 => ApiCall::MakeNamedMedium(self.string()?, self.params()?),
+            21 => ApiCall::MediumInterface(self.string()?, self.string()?),
+            22 => ApiCall::WorldBegin,
+            23 => ApiCall::WorldEnd,
+            24 => ApiCall::AttributeBegin,
+            25 => ApiCall::AttributeEnd,
+            26 => ApiCall::TransformBegin,
+            27 => ApiCall::TransformEnd,
+            28 => ApiCall::Texture(
+                self.string()?,
+                self.string()?,
+                self.string()?,
+                self.params()?,
+            ),
+            29 => ApiCall::Material(self.string()?, self.params()?),
+            30 => ApiCall::MakeNamedMaterial(self.string()?, self.params()?),
+            31 => ApiCall::NamedMaterial(self.string()?),
+            32 => ApiCall::LightSource(self.string()?, self.params()?),
+            33 => ApiCall::AreaLightSource(self.string()?, self.params()?),
+            34 => ApiCall::Shape(self.string()?, self.params()?),
+            35 => ApiCall::ReverseOrientation,
+            36 => ApiCall::ObjectBegin(self.string()?),
+            37 => ApiCall::ObjectEnd,
+            38 => ApiCall::ObjectInstance(self.string()?),
+            _ => return Err(ReadError(String::from("Scene cache file is damaged."))),
+        };
+        Ok(call)
+    }
+
+    /// Reads the files, calls and shapes of a recording.
+    fn recording(&mut self) -> Result<SceneRecording, ReadError> {
+        let mut recording = SceneRecording::default();
+        for _ in 0..self.len()? {
+            let file = self.string()?;
+            let key = CacheKey::from_value(self.u64()?);
+            recording.files.push((file, key));
+        }
+        for _ in 0..self.len()? {
+            recording.calls.push(self.call()?);
+        }
+        recording.shapes = self.shapes()?;
+        Ok(recording)
+    }
+
+    /// Reads the shapes of a recording.
+    fn shapes(&mut self) -> Result<Vec<RecordedShapes>, ReadError> {
+        let n_transforms = self.len()?;
+        let mut transforms = Vec::with_capacity(n_transforms);
+        for _ in 0..n_transforms {
+            let mut m = [[0.0; 4]; 8];
+            for row in m.iter_mut() {
+                *row = self.floats()?;
+            }
+            transforms.push(Arc::new(Transform {
+                m: Matrix4x4 {
+                    m: [m[0], m[1], m[2], m[3]],
+                },
+                m_inv: Matrix4x4 {
+                    m: [m[4], m[5], m[6], m[7]],
+                },
+            }));
+        }
+
+        let n_meshes = self.len()?;
+        let mut meshes = Vec::with_capacity(n_meshes);
+        for _ in 0..n_meshes {
+            meshes.push(Arc::new(self.triangle_mesh(&transforms)?));
+        }
+
+        let n_entries = self.len()?;
+        let mut entries = Vec::with_capacity(n_entries);
+        for _ in 0..n_entries {
+            let name = self.string()?;
+            let shapes = match self.u8()? {
+                0 => None,
+                _ => {
+                    let n_shapes = self.len()?;
+                    let mut shapes = Vec::with_capacity(n_shapes);
+                    for _ in 0..n_shapes {
+                        shapes.push(self.shape(&transforms, &meshes)?);
+                    }
+                    Some(shapes)
+                }
+            };
+            entries.push((name, shapes));
+        }
+        Ok(entries)
+    }
+
+    /// Reads a point.
+    fn point3f(&mut self) -> Result<Point3f, ReadError> {
+        self.floats().map(|[x, y, z]| Point3f::new(x, y, z))
+    }
+
+    /// Reads the index of a transform and returns the transform.
+    ///
+    /// * `transforms` - The transforms read.
+    fn transform(&mut self, transforms: &[ArcTransform]) -> Result<ArcTransform, ReadError> {
+        transforms
+            .get(self.u32()? as usize)
+            .cloned()
+            .ok_or_else(|| ReadError(String::from("Scene cache file is damaged.")))
+    }
+
+    /// Reads common shape data.
+    ///
+    /// * `transforms` - The transforms read.
+    fn shape_data(&mut self, transforms: &[ArcTransform]) -> Result<ShapeData, ReadError> {
+        let object_to_world = self.transform(transforms)?;
+        let world_to_object = match self.u8()? {
+            0 => None,
+            _ => Some(self.transform(transforms)?),
+        };
+        let reverse_orientation = self.u8()? != 0;
+        Ok(ShapeData::new(
+            object_to_world,
+            world_to_object,
+            reverse_orientation,
+        ))
+    }
+
+    /// Reads a triangle mesh and returns an error if its vertex data is
+    /// inconsistent.
+    ///
+    /// * `transforms` - The transforms read.
+    fn triangle_mesh(&mut self, transforms: &[ArcTransform]) -> Result<TriangleMesh, ReadError> {
+        let data = self.shape_data(transforms)?;
+        let vertex_indices = (0..self.len()?)
+            .map(|_| self.u32().map(|i| i as usize))
+            .collect::<Result<Vec<_>, ReadError>>()?;
+        let p = (0..self.len()?)
+            .map(|_| self.point3f())
+            .collect::<Result<Vec<_>, ReadError>>()?;
+        let n = (0..self.len()?)
+            .map(|_| Ok(OctNormal::from_bits([self.u16()?, self.u16()?])))
+            .collect::<Result<Vec<_>, ReadError>>()?;
+        let s = (0..self.len()?)
+            .map(|_| self.floats().map(|[x, y, z]| Vector3f::new(x, y, z)))
+            .collect::<Result<Vec<_>, ReadError>>()?;
+        let uv = match self.u8()? {
+            0 => MeshUVs::Full(MeshBuffer::from(
+                (0..self.len()?)
+                    .map(|_| self.floats().map(|[x, y]| Point2f::new(x, y)))
+                    .collect::<Result<Vec<_>, ReadError>>()?,
+            )),
+            _ => MeshUVs::Half(MeshBuffer::from(
+                (0..self.len()?)
+                    .map(|_| Ok([f16::from_bits(self.u16()?), f16::from_bits(self.u16()?)]))
+                    .collect::<Result<Vec<_>, ReadError>>()?,
+            )),
+        };
+        let face_indices = (0..self.len()?)
+            .map(|_| self.u64().map(|i| i as usize))
+            .collect::<Result<Vec<_>, ReadError>>()?;
+        let smooth_terminator = self.u8()? != 0;
+
+        let mesh = MeshData {
+            vertex_indices: MeshBuffer::from(vertex_indices),
+            p: MeshBuffer::from(p),
+            n: MeshBuffer::from(n),
+            s: MeshBuffer::from(s),
+            uv,
+        };
+        mesh.validate()
+            .map_err(|_| ReadError(String::from("Scene cache file is damaged.")))?;
+        Ok(TriangleMesh::from_data(
+            data,
+            mesh,
+            face_indices,
+            smooth_terminator,
+        ))
+    }
+
+    /// Reads a shape of a built-in type.
+    ///
+    /// * `transforms` - The transforms read.
+    /// * `meshes`     - The triangle meshes read.
+    fn shape(
+        &mut self,
+        transforms: &[ArcTransform],
+        meshes: &[Arc<TriangleMesh>],
+    ) -> Result<ArcShape, ReadError> {
+        let damaged = || ReadError(String::from("Scene cache file is damaged."));
+
+        let shape: ArcShape = match self.u8()? {
+            0 => {
+                let data = self.shape_data(transforms)?;
+                let [radius, z_min, z_max, theta_min, theta_max, phi_max] = self.floats()?;
+                Arc::new(Sphere {
+                    data,
+                    radius,
+                    z_min,
+                    z_max,
+                    theta_min,
+                    theta_max,
+                    phi_max,
+                })
+            }
+            1 => {
+                let data = self.shape_data(transforms)?;
+                let [radius, z_min, z_max, phi_max] = self.floats()?;
+                Arc::new(Cylinder {
+                    data,
+                    radius,
+                    z_min,
+                    z_max,
+                    phi_max,
+                })
+            }
+            2 => {
+                let data = self.shape_data(transforms)?;
+                let [height, radius, inner_radius, phi_max] = self.floats()?;
+                Arc::new(Disk {
+                    data,
+                    height,
+                    radius,
+                    inner_radius,
+                    phi_max,
+                })
+            }
+            3 => {
+                let data = self.shape_data(transforms)?;
+                let [radius, height, phi_max] = self.floats()?;
+                Arc::new(Cone {
+                    data,
+                    radius,
+                    height,
+                    phi_max,
+                })
+            }
+            4 => {
+                let data = self.shape_data(transforms)?;
+                let [radius, z_min, z_max, phi_max] = self.floats()?;
+                Arc::new(Paraboloid {
+                    data,
+                    radius,
+                    z_min,
+                    z_max,
+                    phi_max,
+                })
+            }
+            5 => {
+                let data = self.shape_data(transforms)?;
+                let p1 = self.point3f()?;
+                let p2 = self.point3f()?;
+                let [z_min, z_max, phi_max, r_max, ah, ch] = self.floats()?;
+                Arc::new(Hyperboloid {
+                    data,
+                    p1,
+                    p2,
+                    z_min,
+                    z_max,
+                    phi_max,
+                    r_max,
+                    ah,
+                    ch,
+                })
+            }
+            6 => {
+                let data = self.shape_data(transforms)?;
+                let curve_type = match self.u8()? {
+                    0 => CurveType::Flat,
+                    1 => CurveType::Cylinder,
+                    2 => CurveType::Ribbon,
+                    _ => return Err(damaged()),
+                };
+                let cp_obj = [
+                    self.point3f()?,
+                    self.point3f()?,
+                    self.point3f()?,
+                    self.point3f()?,
+                ];
+                let width = self.floats()?;
+                let [x0, y0, z0, x1, y1, z1] = self.floats()?;
+                let [normal_angle, inv_sin_normal_angle, u_min, u_max] = self.floats()?;
+                Arc::new(Curve {
+                    data,
+                    common: CurveData {
+                        curve_type,
+                        cp_obj,
+                        width,
+                        n: [Normal3f::new(x0, y0, z0), Normal3f::new(x1, y1, z1)],
+                        normal_angle,
+                        inv_sin_normal_angle,
+                    },
+                    u_min,
+                    u_max,
+                })
+            }
+            7 => {
+                let data = self.shape_data(transforms)?;
+                let mesh = meshes.get(self.u32()? as usize).ok_or_else(damaged)?;
+                let v = self.u64()? as usize;
+                if !v.is_multiple_of(3) || v >= mesh.vertex_indices.len() {
+                    return Err(damaged());
+                }
+                Arc::new(Triangle {
+                    data,
+                    mesh: mesh.clone(),
+                    v,
+                })
+            }
+            _ => return Err(damaged()),
+        };
+        Ok(shape)
+    }
+
+    /// Reads a BVH and returns an error unless every primitive is in it once
+    /// and its nodes can be traversed.
+    ///
+    /// * `n_primitives` - Number of primitives in the BVH.
+    fn bvh(&mut self, n_primitives: usize) -> Result<CachedBVH, ReadError> {
+        let damaged = || ReadError(String::from("Scene cache file is damaged."));
+
+        let mut order = Vec::with_capacity(n_primitives);
+        let mut seen = vec![false; n_primitives];
+        if self.len()? != n_primitives {
+            return Err(damaged());
+        }
+        for _ in 0..n_primitives {
+            let i = self.u32()? as usize;
+            if i >= n_primitives || seen[i] {
+                return Err(damaged());
+            }
+            seen[i] = true;
+            order.push(i);
+        }
+
+        let n_nodes = self.len()?;
+        let mut nodes = Vec::with_capacity(n_nodes);
+        for i in 0..n_nodes {
+            let [x0, y0, z0, x1, y1, z1] = self.floats()?;
+            let bounds = Bounds3f {
+                p_min: Point3f::new(x0, y0, z0),
+                p_max: Point3f::new(x1, y1, z1),
+            };
+            let offset = self.u32()? as usize;
+            let n = self.u16()? as usize;
+            let axis = self.u8()?;
+            let node = if n > 0 {
+                if offset + n > n_primitives {
+                    return Err(damaged());
+                }
+                LinearBVHNode::new_leaf_node(bounds, offset as u32, n as u16)
+            } else {
+                // Children follow their parent in depth-first order.
+                if offset <= i + 1 || offset >= n_nodes || axis > 2 {
+                    return Err(damaged());
+                }
+                LinearBVHNode::new_interior_node(bounds, offset as u32, axis)
+            };
+            nodes.push(node);
+        }
+        Ok((order, nodes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recordings_and_bvhs_are_read_back() {
+        let dir = std::env::temp_dir().join("pbr_rust_scene_cache");
+        let scene = dir.join("scene.pbrt");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(&scene, "WorldBegin\nWorldEnd\n").unwrap();
+        let scene = scene.to_str().unwrap();
+        let cache = SceneCache::new(dir.to_str().unwrap());
+
+        let mut params = ParamSet::new();
+        params.add_float("radius", &[0.5]);
+        params.add_point3f("P", &[Point3f::new(1.0, 2.0, 3.0)]);
+        params.add_rgb_spectrum("Kd", &[0.25, 0.5, 0.75]).unwrap();
+        params.add_string("filename", &[String::from("a.png")]);
+        params.add_texture("bumpmap", &[String::from("bumps")]);
+        let recording = SceneRecording {
+            files: vec![(
+                absolute_path(scene).unwrap(),
+                SceneRecording::source_key("WorldBegin\nWorldEnd\n"),
+            )],
+            calls: vec![
+                ApiCall::LookAt([0.0, 0.0, 5.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0]),
+                ApiCall::WorldBegin,
+                ApiCall::Shape(String::from("sphere"), params),
+                ApiCall::MediumInterface(String::from("fog"), String::new()),
+                ApiCall::WorldEnd,
+            ],
+            shapes: vec![],
+        };
+        assert!(cache.load(scene).unwrap().is_none());
+        cache.store(scene, &recording).unwrap();
+
+        let calls = cache.load(scene).unwrap().unwrap().calls;
+        assert_eq!(calls.len(), 5);
+        assert!(matches!(&calls[0], ApiCall::LookAt(v) if v[2] == 5.0 && v[7] == 1.0));
+        match &calls[2] {
+            ApiCall::Shape(name, params) => {
+                assert_eq!(name, "sphere");
+                assert_eq!(params.find_one_float("radius", 0.0), 0.5);
+                let p = params.find_one_point3f("P", Point3f::default());
+                assert_eq!(p, Point3f::new(1.0, 2.0, 3.0));
+                let kd = params.find_one_spectrum("Kd", Spectrum::new(0.0));
+                let expected = Spectrum::from_rgb(&[0.25, 0.5, 0.75], None);
+                assert_eq!(kd.samples(), expected.samples());
+                assert_eq!(params.find_one_string("filename", String::new()), "a.png");
+                assert_eq!(params.find_one_texture("bumpmap", String::new()), "bumps");
+            }
+            _ => panic!("expected a shape"),
+        }
+        assert!(matches!(&calls[3], ApiCall::MediumInterface(a, b) if a == "fog" && b.is_empty()));
+
+        // Changing the scene file makes the recording stale.
+        fs::write(scene, "WorldBegin\n\nWorldEnd\n").unwrap();
+        assert!(cache.load(scene).unwrap().is_none());
+
+        // BVHs must reference every primitive once.
+        let mut key = CacheKey::default();
+        key.add(&[1.0 as Float]);
+        let b = Bounds3f::new(Point3f::new(0.0, 0.0, 0.0), Point3f::new(1.0, 1.0, 1.0));
+        let nodes = [
+            LinearBVHNode::new_interior_node(b, 2, 1),
+            LinearBVHNode::new_leaf_node(b, 0, 1),
+            LinearBVHNode::new_leaf_node(b, 1, 1),
+        ];
+        cache.store_bvh(key, &[1, 0], &nodes).unwrap();
+        let (order, loaded) = cache.load_bvh(key, 2).unwrap().unwrap();
+        assert_eq!(order, [1, 0]);
+        assert_eq!(loaded.len(), 3);
+        assert_eq!((loaded[0].offset, loaded[0].axis), (2, 1));
+        assert_eq!(loaded[2].n_primitives, 1);
+        assert!(matches!(cache.load_bvh(key, 3), Err(Error::Parse(_))));
+        cache.store_bvh(key, &[1, 1], &nodes).unwrap();
+        assert!(matches!(cache.load_bvh(key, 2), Err(Error::Parse(_))));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn built_in_shapes_are_restored() {
+        let dir = std::env::temp_dir().join("pbr_rust_scene_cache_shapes");
+        let scene = dir.join("scene.pbrt");
+        let scene = scene.to_str().unwrap();
+        let cache = SceneCache::new(dir.to_str().unwrap());
+
+        let mut api = Api::new();
+        api.pbrt_init();
+        api.start_recording();
+        crate::core::parsers::PbrtFileParser::in_memory()
+            .parse_source(
+                r#"WorldBegin
+                Translate 1 2 3
+                Shape "sphere" "float radius" 2 "float zmax" 1
+                ReverseOrientation
+                Shape "trianglemesh" "integer indices" [0 1 2 0 2 3]
+                    "point P" [0 0 0 1 0 0 1 1 0 0 1 0] "float uv" [0 0 1 0 1 1 0 1]
+                    "bool halfuv" true
+                Shape "curve" "point P" [0 0 0 1 1 0 2 -1 0 3 0 0] "float width" 0.1
+                Shape "trianglemesh" "integer indices" [0 1 2]
+                    "point P" [0 0 0 1 0 0 1 1 0] "float alpha" 0
+                "#,
+                &mut api,
+            )
+            .unwrap();
+        let recording = api.take_recording().unwrap();
+        cache.store(scene, &recording).unwrap();
+        let loaded = cache.load(scene).unwrap().unwrap();
+        assert_eq!(loaded.shapes.len(), 4);
+
+        for ((name, shapes), (loaded_name, loaded_shapes)) in
+            recording.shapes.iter().zip(loaded.shapes.iter()).take(3)
+        {
+            assert_eq!(name, loaded_name);
+            let (shapes, loaded_shapes) =
+                (shapes.as_ref().unwrap(), loaded_shapes.as_ref().unwrap());
+            assert_eq!(shapes.len(), loaded_shapes.len());
+            for (a, b) in shapes.iter().zip(loaded_shapes.iter()) {
+                assert_eq!(a.world_bound(), b.world_bound());
+                assert_eq!(a.area(), b.area());
+                assert_eq!(a.triangles(), b.triangles());
+                assert_eq!(
+                    a.get_data().reverse_orientation,
+                    b.get_data().reverse_orientation
+                );
+            }
+        }
+
+        // Triangles share their mesh and the mesh its half-precision uvs.
+        let triangles = loaded.shapes[1].1.as_ref().unwrap();
+        let triangle = |i: usize| triangles[i].as_any().unwrap().downcast_ref::<Triangle>();
+        let (t0, t1) = (triangle(0).unwrap(), triangle(1).unwrap());
+        assert!(Arc::ptr_eq(&t0.mesh, &t1.mesh));
+        assert!(matches!(t0.mesh.uv, MeshUVs::Half(_)));
+
+        // Meshes with alpha masks are created again.
+        assert_eq!(loaded.shapes[3].0, "trianglemesh");
+        assert!(recording.shapes[3].1.is_some());
+        assert!(loaded.shapes[3].1.is_none());
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
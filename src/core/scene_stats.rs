@@ -0,0 +1,338 @@
+//! Scene Statistics and Validation
+
+#![allow(dead_code)]
+use crate::core::geometry::*;
+use crate::core::light::*;
+use crate::core::primitive::*;
+use crate::core::primitives::*;
+use crate::core::scene::*;
+use crate::core::spectrum::*;
+use crate::core::stats::format_bytes;
+use std::collections::HashSet;
+use std::fmt;
+use std::mem::{size_of, size_of_val};
+use std::sync::Arc;
+
+/// Counts of the objects making up a scene. Primitives shared by several
+/// object instances are counted once.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SceneStats {
+    /// Number of primitives with a shape.
+    pub shapes: usize,
+
+    /// Number of triangles.
+    pub triangles: usize,
+
+    /// Number of transformed primitives, i.e. object instances and animated
+    /// shapes.
+    pub instances: usize,
+
+    /// Number of nodes in the acceleration structures.
+    pub aggregate_nodes: usize,
+
+    /// Number of lights with a delta position distribution, e.g. point and
+    /// spot lights.
+    pub delta_position_lights: usize,
+
+    /// Number of lights with a delta direction distribution, e.g. distant
+    /// lights.
+    pub delta_direction_lights: usize,
+
+    /// Number of area lights.
+    pub area_lights: usize,
+
+    /// Number of infinite lights.
+    pub infinite_lights: usize,
+
+    /// Estimate of the memory used by the primitives, shapes and acceleration
+    /// structures in bytes. Vertices shared by triangles are counted for each
+    /// triangle.
+    pub memory: usize,
+}
+
+impl SceneStats {
+    /// Returns the total number of lights.
+    pub fn lights(&self) -> usize {
+        self.delta_position_lights
+            + self.delta_direction_lights
+            + self.area_lights
+            + self.infinite_lights
+    }
+}
+
+impl fmt::Display for SceneStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Scene")?;
+        writeln!(f, "  Shapes                  {:>12}", self.shapes)?;
+        writeln!(f, "  Triangles               {:>12}", self.triangles)?;
+        writeln!(f, "  Instances               {:>12}", self.instances)?;
+        writeln!(f, "  Aggregate nodes         {:>12}", self.aggregate_nodes)?;
+        writeln!(f, "  Lights                  {:>12}", self.lights())?;
+        writeln!(
+            f,
+            "    Delta position        {:>12}",
+            self.delta_position_lights
+        )?;
+        writeln!(
+            f,
+            "    Delta direction       {:>12}",
+            self.delta_direction_lights
+        )?;
+        writeln!(f, "    Area                  {:>12}", self.area_lights)?;
+        writeln!(f, "    Infinite              {:>12}", self.infinite_lights)?;
+        writeln!(
+            f,
+            "  Memory                  {:>12}",
+            format_bytes(self.memory as i64)
+        )
+    }
+}
+
+/// A problem found in a scene that makes it render incorrectly.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SceneWarning {
+    /// The scene has no lights so it renders black.
+    NoLights,
+
+    /// A triangle with vertices on a line or at the same point; it is never
+    /// hit.
+    DegenerateTriangle([Point3f; 3]),
+
+    /// A triangle with NaN or infinite vertex coordinates.
+    NonFiniteVertex([Point3f; 3]),
+
+    /// A shape other than a triangle without surface area, e.g. a sphere with
+    /// zero radius, with its world space bounds.
+    ZeroAreaShape(Bounds3f),
+
+    /// A light emitting no power with its index in the scene's lights.
+    ZeroPowerLight(usize),
+}
+
+impl fmt::Display for SceneWarning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::NoLights => write!(f, "Scene has no lights."),
+            Self::DegenerateTriangle([p0, p1, p2]) => {
+                write!(f, "Degenerate triangle {}, {}, {}.", p0, p1, p2)
+            }
+            Self::NonFiniteVertex([p0, p1, p2]) => {
+                write!(f, "Triangle with non-finite vertex {}, {}, {}.", p0, p1, p2)
+            }
+            Self::ZeroAreaShape(bounds) => write!(f, "Shape with zero area at {}.", bounds),
+            Self::ZeroPowerLight(index) => write!(f, "Light {} emits no power.", index),
+        }
+    }
+}
+
+/// Collects the statistics and, optionally, the warnings while visiting the
+/// primitives of a scene.
+struct SceneWalker {
+    /// The statistics.
+    stats: SceneStats,
+
+    /// The warnings; `None` if they aren't collected.
+    warnings: Option<Vec<SceneWarning>>,
+
+    /// The primitives of the transformed primitives visited so far.
+    visited: HashSet<*const ()>,
+}
+
+impl SceneWalker {
+    /// Visits the primitives and lights of a scene.
+    ///
+    /// * `scene`    - The scene.
+    /// * `validate` - Whether to collect warnings.
+    fn walk(scene: &Scene, validate: bool) -> Self {
+        let mut walker = Self {
+            stats: SceneStats::default(),
+            warnings: if validate { Some(vec![]) } else { None },
+            visited: HashSet::new(),
+        };
+
+        scene.aggregate.accept(&mut walker);
+
+        for (i, light) in scene.lights.iter().enumerate() {
+            let light_type = light.get_type();
+            if light_type.matches(DELTA_POSITION_LIGHT) {
+                walker.stats.delta_position_lights += 1;
+            } else if light_type.matches(DELTA_DIRECTION_LIGHT) {
+                walker.stats.delta_direction_lights += 1;
+            } else if light_type.matches(AREA_LIGHT) {
+                walker.stats.area_lights += 1;
+            } else if light_type.matches(INFINITE_LIGHT) {
+                walker.stats.infinite_lights += 1;
+            }
+
+            if let Some(warnings) = walker.warnings.as_mut() {
+                if light.power().is_black() {
+                    warnings.push(SceneWarning::ZeroPowerLight(i));
+                }
+            }
+        }
+        if let Some(warnings) = walker.warnings.as_mut() {
+            if scene.lights.is_empty() {
+                warnings.insert(0, SceneWarning::NoLights);
+            }
+        }
+
+        walker
+    }
+}
+
+impl PrimitiveVisitor for SceneWalker {
+    /// Counts a primitive with a shape and checks its geometry.
+    ///
+    /// * `primitive` - The primitive.
+    fn visit_geometric(&mut self, primitive: &GeometricPrimitive) {
+        let triangles = primitive.shape.triangles();
+        self.stats.shapes += 1;
+        self.stats.triangles += triangles.len();
+        self.stats.memory += size_of::<GeometricPrimitive>()
+            + size_of_val(&*primitive.shape)
+            + triangles.len() * size_of::<[Point3f; 3]>();
+
+        if let Some(warnings) = self.warnings.as_mut() {
+            if triangles.is_empty() && primitive.shape.area() == 0.0 {
+                warnings.push(SceneWarning::ZeroAreaShape(primitive.shape.world_bound()));
+            }
+
+            for t in triangles {
+                let finite = t
+                    .iter()
+                    .all(|p| p.x.is_finite() && p.y.is_finite() && p.z.is_finite());
+                if !finite {
+                    warnings.push(SceneWarning::NonFiniteVertex(t));
+                } else if (t[1] - t[0]).cross(&(t[2] - t[0])).length_squared() == 0.0 {
+                    warnings.push(SceneWarning::DegenerateTriangle(t));
+                }
+            }
+        }
+    }
+
+    /// Counts a transformed primitive and visits the primitive it transforms
+    /// if it wasn't visited through another instance.
+    ///
+    /// * `primitive` - The primitive.
+    fn visit_transformed(&mut self, primitive: &TransformedPrimitive) -> bool {
+        self.stats.instances += 1;
+        self.stats.memory += size_of::<TransformedPrimitive>();
+        self.visited
+            .insert(Arc::as_ptr(&primitive.primitive) as *const ())
+    }
+
+    /// Counts the nodes of an aggregate.
+    ///
+    /// * `n_nodes` - Number of nodes in the acceleration structure.
+    /// * `bytes`   - Memory used by the nodes.
+    fn visit_aggregate(&mut self, n_nodes: usize, bytes: usize) {
+        self.stats.aggregate_nodes += n_nodes;
+        self.stats.memory += bytes;
+    }
+}
+
+impl Scene {
+    /// Returns counts of the primitives, lights and acceleration structure
+    /// nodes in the scene and an estimate of the memory they use.
+    pub fn stats(&self) -> SceneStats {
+        SceneWalker::walk(self, false).stats
+    }
+
+    /// Returns the problems found in the scene's geometry and lights; empty
+    /// if there are none. Degenerate triangles, non-finite vertices, shapes
+    /// without area and lights that emit no power are reported.
+    pub fn validate(&self) -> Vec<SceneWarning> {
+        SceneWalker::walk(self, true).warnings.unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::accelerators::*;
+    use crate::core::material::*;
+    use crate::core::medium::*;
+    use crate::core::paramset::*;
+    use crate::core::pbrt::*;
+    use crate::core::texture::*;
+    use crate::lights::*;
+    use crate::materials::*;
+    use crate::shapes::*;
+    use crate::textures::*;
+    use std::collections::HashMap;
+
+    fn primitive(shape: ArcShape) -> ArcPrimitive {
+        let kd: ArcTexture<Spectrum> = Arc::new(ConstantTexture::new(Spectrum::new(0.5)));
+        let sigma: ArcTexture<Float> = Arc::new(ConstantTexture::new(0.0));
+        let material: ArcMaterial = Arc::new(MatteMaterial::new(kd, sigma, None));
+        Arc::new(GeometricPrimitive::new(
+            shape,
+            Some(material),
+            None,
+            MediumInterface::vacuum(),
+        ))
+    }
+
+    #[test]
+    fn problems_are_reported() {
+        let identity = Arc::new(Transform::default());
+        let mut params = ParamSet::new();
+        params.add_int("indices", &[0, 1, 2, 0, 1, 3]);
+        params.add_point3f(
+            "P",
+            &[
+                Point3f::new(0.0, 0.0, 0.0),
+                Point3f::new(1.0, 0.0, 0.0),
+                Point3f::new(0.0, 1.0, 0.0),
+                Point3f::new(2.0, 0.0, 0.0),
+            ],
+        );
+        let triangles = TriangleMesh::from_props(
+            (&params, identity.clone(), identity.clone(), false),
+            &HashMap::new(),
+        )
+        .unwrap();
+        let sphere = Arc::new(Sphere::new(
+            identity.clone(),
+            identity.clone(),
+            false,
+            0.0,
+            0.0,
+            0.0,
+            360.0,
+        ));
+
+        let mut primitives: Vec<ArcPrimitive> = triangles.into_iter().map(primitive).collect();
+        primitives.push(primitive(sphere));
+        let aggregate = Arc::new(BVHAccel::new(&primitives, 1, SplitMethod::Middle));
+
+        let scene = Scene::new(aggregate.clone(), vec![]);
+        let stats = scene.stats();
+        assert_eq!((stats.shapes, stats.triangles, stats.lights()), (3, 2, 0));
+        assert!(stats.aggregate_nodes > 0 && stats.memory > 0);
+
+        let warnings = scene.validate();
+        assert_eq!(warnings.len(), 3);
+        assert_eq!(warnings[0], SceneWarning::NoLights);
+        // The BVH orders the primitives.
+        let degenerate = [
+            Point3f::new(0.0, 0.0, 0.0),
+            Point3f::new(1.0, 0.0, 0.0),
+            Point3f::new(2.0, 0.0, 0.0),
+        ];
+        assert!(warnings.contains(&SceneWarning::DegenerateTriangle(degenerate)));
+        assert!(warnings
+            .iter()
+            .any(|w| matches!(w, SceneWarning::ZeroAreaShape(_))));
+
+        let light: ArcLight = Arc::new(PointLight::new(
+            identity,
+            MediumInterface::vacuum(),
+            Spectrum::new(0.0),
+        ));
+        let scene = Scene::new(aggregate, vec![light]);
+        assert_eq!(scene.stats().delta_position_lights, 1);
+        assert_eq!(scene.validate().len(), 3);
+        assert!(scene.validate().contains(&SceneWarning::ZeroPowerLight(0)));
+    }
+}
@@ -0,0 +1,640 @@
+//! Render Service
+
+#![allow(dead_code)]
+use crate::core::api::*;
+use crate::core::cancel::*;
+use crate::core::error::*;
+use crate::core::fileutil::absolute_path;
+use crate::core::parsers::*;
+use crate::core::report::json_escape;
+use image::codecs::png::PngEncoder;
+use image::ColorType;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+/// Maximum size of a request body (64 MB).
+const MAX_BODY_SIZE: usize = 64 << 20;
+
+/// Maximum length of the request line and of each header (8 KB).
+const MAX_LINE_LENGTH: usize = 8 << 10;
+
+/// Maximum number of request headers.
+const MAX_HEADERS: usize = 64;
+
+/// Maximum number of connections handled at the same time. Further
+/// connections wait until one is closed.
+const MAX_CONNECTIONS: usize = 8;
+
+/// Time after which a connection that doesn't send or receive data is closed.
+const CONNECTION_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Maximum number of jobs waiting to be rendered or being rendered.
+const MAX_PENDING_JOBS: usize = 16;
+
+/// Maximum number of finished jobs kept so their status and image can be
+/// retrieved. The oldest ones are removed first.
+const MAX_DONE_JOBS: usize = 16;
+
+/// State of a render job.
+#[derive(Clone, Debug, PartialEq)]
+pub enum JobState {
+    /// Waiting for earlier jobs to finish.
+    Queued,
+
+    /// Being rendered.
+    Rendering,
+
+    /// Rendering finished.
+    Finished,
+
+    /// Rendering was cancelled.
+    Cancelled,
+
+    /// The scene could not be rendered.
+    Failed(String),
+}
+
+impl JobState {
+    /// Returns the name of the state.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Queued => "queued",
+            Self::Rendering => "rendering",
+            Self::Finished => "finished",
+            Self::Cancelled => "cancelled",
+            Self::Failed(_) => "failed",
+        }
+    }
+
+    /// Returns `true` if the job won't be rendered anymore.
+    pub fn is_done(&self) -> bool {
+        !matches!(self, Self::Queued | Self::Rendering)
+    }
+}
+
+/// A scene submitted to the render service.
+struct Job {
+    /// Identifies the job.
+    id: usize,
+
+    /// The scene description. It is released once rendering starts.
+    scene: Mutex<Option<String>>,
+
+    /// State of the job.
+    state: Mutex<JobState>,
+
+    /// Used to stop rendering early.
+    cancel: CancellationToken,
+
+    /// Captures the rendered image.
+    observer: Arc<RgbaImageObserver>,
+}
+
+impl Job {
+    /// Returns a new queued `Job`.
+    ///
+    /// * `id`    - Identifies the job.
+    /// * `scene` - The scene description in the PBRT file format.
+    fn new(id: usize, scene: String) -> Self {
+        Self {
+            id,
+            scene: Mutex::new(Some(scene)),
+            state: Mutex::new(JobState::Queued),
+            cancel: CancellationToken::new(),
+            observer: Arc::new(RgbaImageObserver::new(Box::new(|_, _| {}))),
+        }
+    }
+
+    /// Renders the scene unless the job was cancelled while queued. Scenes
+    /// can't load plugins or use files outside the scene root.
+    ///
+    /// * `scene_root` - Directory scenes can use files from; `None` if they
+    ///                  can't use files.
+    fn render(&self, scene_root: Option<&str>) {
+        let scene = match self.scene.lock().unwrap().take() {
+            Some(scene) if !self.cancel.is_cancelled() => scene,
+            _ => {
+                *self.state.lock().unwrap() = JobState::Cancelled;
+                return;
+            }
+        };
+        *self.state.lock().unwrap() = JobState::Rendering;
+        info!("Rendering job {}.", self.id);
+
+        // Contain panics to the job so the service keeps running.
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            let mut api = Api::new();
+            api.set_cancellation_token(self.cancel.clone());
+            api.pbrt_init();
+            api.set_in_memory_output(true);
+            api.set_sandboxed(true);
+            api.add_render_observer(self.observer.clone());
+            let result = PbrtFileParser::in_memory()
+                .sandboxed(scene_root)
+                .and_then(|parser| parser.parse_source(&scene, &mut api));
+            api.pbrt_cleanup();
+            result
+        }));
+
+        let state = match result {
+            Ok(Ok(_)) if self.cancel.is_cancelled() => JobState::Cancelled,
+            Ok(Ok(_)) => JobState::Finished,
+            Ok(Err(err)) => JobState::Failed(err.to_string()),
+            Err(_) => JobState::Failed(String::from("Rendering panicked.")),
+        };
+        info!("Job {} {}.", self.id, state.name());
+        *self.state.lock().unwrap() = state;
+    }
+
+    /// Returns the JSON representation of the job's status.
+    fn status_json(&self) -> String {
+        let state = self.state.lock().unwrap().clone();
+        let error = match &state {
+            JobState::Failed(msg) => format!("\"{}\"", json_escape(msg)),
+            _ => String::from("null"),
+        };
+        format!(
+            "{{\"id\":{},\"state\":\"{}\",\"progress\":{},\"error\":{}}}",
+            self.id,
+            state.name(),
+            self.observer.progress(),
+            error
+        )
+    }
+}
+
+/// An HTTP response.
+struct Response {
+    /// Status code.
+    status: u16,
+
+    /// Media type of the body.
+    content_type: &'static str,
+
+    /// The body.
+    body: Vec<u8>,
+}
+
+impl Response {
+    /// Returns a JSON response.
+    ///
+    /// * `status` - Status code.
+    /// * `json`   - The body.
+    fn json(status: u16, json: String) -> Self {
+        Self {
+            status,
+            content_type: "application/json",
+            body: json.into_bytes(),
+        }
+    }
+
+    /// Returns a JSON response with an error message.
+    ///
+    /// * `status` - Status code.
+    /// * `msg`    - The error message.
+    fn error(status: u16, msg: &str) -> Self {
+        Self::json(status, format!("{{\"error\":\"{}\"}}", json_escape(msg)))
+    }
+}
+
+/// Renders scenes submitted over HTTP one at a time and reports their
+/// progress. Images are kept in memory. Scenes can only include files and use
+/// asset files from the scene root and can't load plugins. Only the most
+/// recent finished jobs are kept.
+///
+/// Endpoints:
+/// * `POST /renders`              - Submit a scene description in the PBRT
+///                                  file format as the request body. Returns
+///                                  the job's status including its `id`.
+/// * `GET /renders/{id}`          - Returns the job's status and progress.
+/// * `GET /renders/{id}/preview`  - Returns the image rendered so far as PNG.
+/// * `POST /renders/{id}/cancel`  - Cancels the job.
+pub struct RenderService {
+    /// Submitted jobs that are kept, oldest first.
+    jobs: Mutex<Vec<Arc<Job>>>,
+
+    /// Identifier of the next submitted job.
+    next_id: AtomicUsize,
+
+    /// Queue of jobs to render.
+    queue: Mutex<mpsc::Sender<Arc<Job>>>,
+}
+
+impl RenderService {
+    /// Returns a new `RenderService` without jobs.
+    ///
+    /// * `queue` - Queue of jobs to render.
+    fn new(queue: mpsc::Sender<Arc<Job>>) -> Self {
+        Self {
+            jobs: Mutex::new(vec![]),
+            next_id: AtomicUsize::new(0),
+            queue: Mutex::new(queue),
+        }
+    }
+
+    /// Listens for requests and renders submitted scenes. It only returns
+    /// if the address or scene root can't be used.
+    ///
+    /// * `address`    - Address to listen on, e.g. `127.0.0.1:8080`. A port
+    ///                  alone listens on `127.0.0.1`.
+    /// * `scene_root` - Directory scenes can use files from; `None` if they
+    ///                  can't use files.
+    pub fn serve(address: &str, scene_root: Option<&str>) -> Result<(), Error> {
+        let scene_root = scene_root.map(absolute_path).transpose()?;
+        let address = listen_address(address);
+        let listener = TcpListener::bind(&address)
+            .map_err(|err| Error::Io(format!("Unable to listen on '{}'. {}", address, err)))?;
+        info!("Render service listening on '{}'.", address);
+
+        let (sender, receiver) = mpsc::channel::<Arc<Job>>();
+        let service = Arc::new(Self::new(sender));
+
+        // Render jobs in the order they are submitted.
+        thread::spawn(move || {
+            for job in receiver {
+                job.render(scene_root.as_deref());
+            }
+        });
+
+        // Handle connections on a fixed number of threads.
+        let (sender, receiver) = mpsc::sync_channel::<TcpStream>(MAX_CONNECTIONS);
+        let receiver = Arc::new(Mutex::new(receiver));
+        for _ in 0..MAX_CONNECTIONS {
+            let service = service.clone();
+            let receiver = receiver.clone();
+            thread::spawn(move || loop {
+                let stream = match receiver.lock().unwrap().recv() {
+                    Ok(stream) => stream,
+                    Err(_) => return,
+                };
+                if let Err(err) = service.handle_connection(stream) {
+                    warn!("Unable to handle request. {}", err);
+                }
+            });
+        }
+
+        for stream in listener.incoming() {
+            match stream {
+                Ok(stream) => {
+                    if sender.send(stream).is_err() {
+                        break;
+                    }
+                }
+                Err(err) => warn!("Unable to accept connection. {}", err),
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads a request, handles it and writes the response. The connection
+    /// is closed if it doesn't send or receive data in time.
+    ///
+    /// * `stream` - The connection.
+    fn handle_connection(&self, stream: TcpStream) -> io::Result<()> {
+        stream.set_read_timeout(Some(CONNECTION_TIMEOUT))?;
+        stream.set_write_timeout(Some(CONNECTION_TIMEOUT))?;
+        let mut reader = BufReader::new(stream.try_clone()?);
+        let response = match read_request(&mut reader) {
+            Ok((method, path, body)) => self.handle_request(&method, &path, body),
+            Err(err) => Response::error(400, &err.to_string()),
+        };
+
+        let mut stream = stream;
+        write!(
+            stream,
+            "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+            response.status,
+            reason_phrase(response.status),
+            response.content_type,
+            response.body.len()
+        )?;
+        stream.write_all(&response.body)?;
+        stream.flush()
+    }
+
+    /// Routes a request to its endpoint.
+    ///
+    /// * `method` - The request method.
+    /// * `path`   - The request path.
+    /// * `body`   - The request body.
+    fn handle_request(&self, method: &str, path: &str, body: Vec<u8>) -> Response {
+        let segments: Vec<&str> = path
+            .split('?')
+            .next()
+            .unwrap_or("")
+            .split('/')
+            .filter(|s| !s.is_empty())
+            .collect();
+
+        match (method, segments.as_slice()) {
+            ("POST", ["renders"]) => match String::from_utf8(body) {
+                Ok(scene) => self.submit(scene),
+                Err(_) => Response::error(400, "The scene description is not UTF-8."),
+            },
+            (_, ["renders"]) => Response::error(405, "Use POST to submit a scene."),
+            (method, ["renders", id, rest @ ..]) => {
+                let job = id.parse::<usize>().ok().and_then(|id| self.job(id));
+                match (method, job, rest) {
+                    (_, None, _) => Response::error(404, "Unknown render job."),
+                    ("GET", Some(job), []) => Response::json(200, job.status_json()),
+                    ("GET", Some(job), ["preview"]) => preview(&job),
+                    ("POST", Some(job), ["cancel"]) => {
+                        job.cancel.cancel();
+                        Response::json(200, job.status_json())
+                    }
+                    (_, Some(_), [] | ["preview"] | ["cancel"]) => {
+                        Response::error(405, "Method not allowed.")
+                    }
+                    _ => Response::error(404, "Not found."),
+                }
+            }
+            _ => Response::error(404, "Not found."),
+        }
+    }
+
+    /// Returns the job with the given identifier if it is kept.
+    ///
+    /// * `id` - Identifies the job.
+    fn job(&self, id: usize) -> Option<Arc<Job>> {
+        self.jobs
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|job| job.id == id)
+            .cloned()
+    }
+
+    /// Queues a scene for rendering unless too many jobs are pending. The
+    /// oldest finished jobs are removed.
+    ///
+    /// * `scene` - The scene description in the PBRT file format.
+    fn submit(&self, scene: String) -> Response {
+        let job = {
+            let mut jobs = self.jobs.lock().unwrap();
+
+            let done: Vec<bool> = jobs
+                .iter()
+                .map(|job| job.state.lock().unwrap().is_done())
+                .collect();
+            if done.iter().filter(|&&done| !done).count() >= MAX_PENDING_JOBS {
+                return Response::error(503, "Too many render jobs are pending.");
+            }
+            let mut excess = done
+                .iter()
+                .filter(|&&done| done)
+                .count()
+                .saturating_sub(MAX_DONE_JOBS);
+            let mut done = done.into_iter();
+            jobs.retain(|_| {
+                let remove = done.next() == Some(true) && excess > 0;
+                if remove {
+                    excess -= 1;
+                }
+                !remove
+            });
+
+            let job = Arc::new(Job::new(
+                self.next_id.fetch_add(1, Ordering::Relaxed),
+                scene,
+            ));
+            jobs.push(job.clone());
+            job
+        };
+        if self.queue.lock().unwrap().send(job.clone()).is_err() {
+            return Response::error(500, "The renderer is not running.");
+        }
+        info!("Queued job {}.", job.id);
+        Response::json(201, job.status_json())
+    }
+}
+
+/// Returns the image a job has rendered so far as PNG.
+///
+/// * `job` - The job.
+fn preview(job: &Job) -> Response {
+    let image = job.observer.image();
+    if image.width == 0 || image.height == 0 {
+        return Response::error(404, "Rendering has not started.");
+    }
+
+    let mut png = vec![];
+    match PngEncoder::new(&mut png).encode(
+        &image.pixels,
+        image.width as u32,
+        image.height as u32,
+        ColorType::Rgba8,
+    ) {
+        Ok(_) => Response {
+            status: 200,
+            content_type: "image/png",
+            body: png,
+        },
+        Err(err) => Response::error(500, &err.to_string()),
+    }
+}
+
+/// Returns the address to listen on; a port alone listens on the loopback
+/// interface so the service isn't reachable from other hosts by default.
+///
+/// * `address` - Address or port.
+fn listen_address(address: &str) -> String {
+    match address.parse::<u16>() {
+        Ok(port) => format!("127.0.0.1:{}", port),
+        Err(_) => String::from(address),
+    }
+}
+
+/// Reads an HTTP request and returns its method, path and body. The request
+/// line, the headers and the body are limited in size.
+///
+/// * `reader` - The reader.
+fn read_request<R: BufRead>(reader: &mut R) -> io::Result<(String, String, Vec<u8>)> {
+    let invalid = |msg: &str| io::Error::new(io::ErrorKind::InvalidData, msg);
+
+    let mut line = String::new();
+    read_line(reader, &mut line)?;
+    let mut parts = line.split_whitespace();
+    let (method, path) = match (parts.next(), parts.next()) {
+        (Some(method), Some(path)) => (method.to_string(), path.to_string()),
+        _ => return Err(invalid("Invalid request line.")),
+    };
+
+    // Only the body length is needed from the headers.
+    let mut content_length = 0;
+    for headers in 0.. {
+        read_line(reader, &mut line)?;
+        let header = line.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if headers == MAX_HEADERS {
+            return Err(invalid("Too many request headers."));
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("content-length") {
+                content_length = value
+                    .trim()
+                    .parse::<usize>()
+                    .map_err(|_| invalid("Invalid Content-Length."))?;
+            }
+        }
+    }
+    if content_length > MAX_BODY_SIZE {
+        return Err(invalid("Request body is too large."));
+    }
+
+    // The body grows as it is received instead of being allocated up front.
+    let mut body = vec![];
+    reader.take(content_length as u64).read_to_end(&mut body)?;
+    if body.len() < content_length {
+        return Err(invalid("Incomplete request body."));
+    }
+    Ok((method, path, body))
+}
+
+/// Reads a line of a request replacing the contents of `line`; or returns an
+/// error if the line is longer than `MAX_LINE_LENGTH` or incomplete.
+///
+/// * `reader` - The reader.
+/// * `line`   - The line.
+fn read_line<R: BufRead>(reader: &mut R, line: &mut String) -> io::Result<()> {
+    line.clear();
+    reader.take(MAX_LINE_LENGTH as u64).read_line(line)?;
+    if line.ends_with('\n') {
+        Ok(())
+    } else if line.len() >= MAX_LINE_LENGTH {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "Request line or header is too long.",
+        ))
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "Incomplete request.",
+        ))
+    }
+}
+
+/// Returns the reason phrase for the status codes used by the service.
+///
+/// * `status` - Status code.
+fn reason_phrase(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        201 => "Created",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        503 => "Service Unavailable",
+        _ => "Internal Server Error",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn requests_are_routed_to_jobs() {
+        let (sender, receiver) = mpsc::channel();
+        let service = RenderService::new(sender);
+
+        let mut request: &[u8] = b"POST /renders HTTP/1.1\r\nContent-Length: 9\r\n\r\nWorldEnd\n";
+        let (method, path, body) = read_request(&mut request).unwrap();
+        let response = service.handle_request(&method, &path, body);
+        assert_eq!(response.status, 201);
+        assert_eq!(receiver.try_recv().unwrap().id, 0);
+
+        let response = service.handle_request("POST", "/renders/0/cancel", vec![]);
+        assert_eq!(response.status, 200);
+        assert!(service.jobs.lock().unwrap()[0].cancel.is_cancelled());
+
+        let response = service.handle_request("GET", "/renders/0", vec![]);
+        assert_eq!(
+            String::from_utf8(response.body).unwrap(),
+            "{\"id\":0,\"state\":\"queued\",\"progress\":0,\"error\":null}"
+        );
+        assert_eq!(
+            service.handle_request("GET", "/renders/1", vec![]).status,
+            404
+        );
+        assert_eq!(
+            service
+                .handle_request("GET", "/renders/0/preview", vec![])
+                .status,
+            404
+        );
+        assert_eq!(
+            service.handle_request("DELETE", "/renders", vec![]).status,
+            405
+        );
+    }
+
+    #[test]
+    fn pending_jobs_are_limited_and_finished_jobs_removed() {
+        let (sender, _receiver) = mpsc::channel();
+        let service = RenderService::new(sender);
+
+        for _ in 0..MAX_PENDING_JOBS {
+            assert_eq!(service.submit(String::new()).status, 201);
+        }
+        assert_eq!(service.submit(String::new()).status, 503);
+
+        let finish_all = || {
+            for job in service.jobs.lock().unwrap().iter() {
+                *job.state.lock().unwrap() = JobState::Finished;
+            }
+        };
+        finish_all();
+        assert_eq!(service.submit(String::new()).status, 201);
+        assert_eq!(service.jobs.lock().unwrap().len(), MAX_DONE_JOBS + 1);
+        finish_all();
+        assert_eq!(service.submit(String::new()).status, 201);
+        assert_eq!(service.jobs.lock().unwrap().len(), MAX_DONE_JOBS + 1);
+        assert_eq!(
+            service.handle_request("GET", "/renders/0", vec![]).status,
+            404
+        );
+        assert_eq!(
+            service
+                .handle_request("GET", &format!("/renders/{}", MAX_PENDING_JOBS), vec![])
+                .status,
+            200
+        );
+    }
+
+    #[test]
+    fn requests_are_limited_in_size() {
+        let long_path = format!("GET /{} HTTP/1.1\r\n\r\n", "a".repeat(MAX_LINE_LENGTH));
+        assert!(read_request(&mut long_path.as_bytes()).is_err());
+
+        let many_headers = format!(
+            "GET /renders HTTP/1.1\r\n{}\r\n",
+            "X-Header: 1\r\n".repeat(MAX_HEADERS + 1)
+        );
+        assert!(read_request(&mut many_headers.as_bytes()).is_err());
+        let headers = format!(
+            "GET /renders HTTP/1.1\r\n{}\r\n",
+            "X-Header: 1\r\n".repeat(MAX_HEADERS)
+        );
+        assert!(read_request(&mut headers.as_bytes()).is_ok());
+
+        let mut too_large: &[u8] = b"POST /renders HTTP/1.1\r\nContent-Length: 67108865\r\n\r\n";
+        assert!(read_request(&mut too_large).is_err());
+        let mut incomplete: &[u8] = b"POST /renders HTTP/1.1\r\nContent-Length: 9\r\n\r\nWorld";
+        assert!(read_request(&mut incomplete).is_err());
+        let mut unterminated: &[u8] = b"GET /renders HTTP/1.1\r\nHost: x";
+        assert!(read_request(&mut unterminated).is_err());
+    }
+
+    #[test]
+    fn ports_listen_on_the_loopback_interface() {
+        assert_eq!(listen_address("8080"), "127.0.0.1:8080");
+        assert_eq!(listen_address("0.0.0.0:8080"), "0.0.0.0:8080");
+    }
+}
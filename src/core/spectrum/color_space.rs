@@ -0,0 +1,393 @@
+//! Color Spaces
+
+#![allow(dead_code)]
+use crate::core::pbrt::*;
+use std::fmt;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+
+/// A 3x3 matrix stored in row-major order.
+pub type Matrix3x3 = [[Float; 3]; 3];
+
+/// Supported RGB color spaces.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ColorSpace {
+    /// sRGB / Rec.709 primaries with D65 white point.
+    SRGB = 0,
+
+    /// ACEScg (AP1) primaries with the ACES white point.
+    ACEScg = 1,
+
+    /// Rec.2020 primaries with D65 white point.
+    Rec2020 = 2,
+
+    /// Display P3 primaries with D65 white point.
+    DisplayP3 = 3,
+}
+
+/// All supported color spaces.
+pub const COLOR_SPACES: [ColorSpace; 4] = [
+    ColorSpace::SRGB,
+    ColorSpace::ACEScg,
+    ColorSpace::Rec2020,
+    ColorSpace::DisplayP3,
+];
+
+lazy_static! {
+    /// The RGB to XYZ and XYZ to RGB matrices for each color space indexed by
+    /// `ColorSpace as usize`.
+    static ref MATRICES: Vec<(Matrix3x3, Matrix3x3)> = COLOR_SPACES
+        .iter()
+        .map(|cs| {
+            let (r, g, b, w) = cs.chromaticities();
+            let rgb_to_xyz = rgb_to_xyz_matrix(r, g, b, w);
+            (rgb_to_xyz, invert_3x3(&rgb_to_xyz))
+        })
+        .collect();
+}
+
+/// The color space in which RGB values are interpreted during rendering.
+static RENDERING_COLOR_SPACE: AtomicUsize = AtomicUsize::new(ColorSpace::SRGB as usize);
+
+/// The luminance coefficients of sRGB.
+const SRGB_Y_COEFFICIENTS: [Float; 3] = ColorSpace::SRGB.y_coefficients();
+
+/// The luminance coefficients of the rendering color space as `Float` bits.
+/// They are resolved when the color space is set so that computing the
+/// luminance of RGB values doesn't look up its matrix.
+static RENDERING_Y_COEFFICIENTS: [AtomicU64; 3] = [
+    AtomicU64::new(SRGB_Y_COEFFICIENTS[0].to_bits() as u64),
+    AtomicU64::new(SRGB_Y_COEFFICIENTS[1].to_bits() as u64),
+    AtomicU64::new(SRGB_Y_COEFFICIENTS[2].to_bits() as u64),
+];
+
+/// Returns the color space in which RGB values are interpreted during
+/// rendering.
+pub fn rendering_color_space() -> ColorSpace {
+    COLOR_SPACES[RENDERING_COLOR_SPACE.load(Ordering::Relaxed)]
+}
+
+/// Sets the color space in which RGB values are interpreted during rendering.
+/// This should be done before any scene description is parsed since RGB
+/// parameter values and textures are converted into this space as they are
+/// loaded.
+///
+/// * `color_space` - The color space.
+pub fn set_rendering_color_space(color_space: ColorSpace) {
+    RENDERING_COLOR_SPACE.store(color_space as usize, Ordering::Relaxed);
+    for (c, v) in RENDERING_Y_COEFFICIENTS
+        .iter()
+        .zip(color_space.y_coefficients().iter())
+    {
+        c.store(v.to_bits() as u64, Ordering::Relaxed);
+    }
+}
+
+/// Returns the luminance coefficients of the rendering color space; the Y
+/// values of its red, green and blue primaries.
+pub fn rendering_y_coefficients() -> [Float; 3] {
+    let c = |i: usize| Float::from_bits(RENDERING_Y_COEFFICIENTS[i].load(Ordering::Relaxed) as _);
+    [c(0), c(1), c(2)]
+}
+
+impl ColorSpace {
+    /// Returns the color space for a given name; or `None` if the name is not
+    /// recognized. Names are case insensitive.
+    ///
+    /// * `name` - The name (e.g. "srgb", "acescg", "rec2020", "displayp3").
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "srgb" | "rec709" => Some(Self::SRGB),
+            "acescg" | "aces-cg" => Some(Self::ACEScg),
+            "rec2020" | "rec.2020" => Some(Self::Rec2020),
+            "displayp3" | "display-p3" | "p3" => Some(Self::DisplayP3),
+            _ => None,
+        }
+    }
+
+    /// Returns the color space whose red, green, blue and white chromaticities
+    /// match the given ones; or `None` if there is no such color space.
+    ///
+    /// * `r` - Chromaticity (x, y) of the red primary.
+    /// * `g` - Chromaticity (x, y) of the green primary.
+    /// * `b` - Chromaticity (x, y) of the blue primary.
+    /// * `w` - Chromaticity (x, y) of the white point.
+    pub fn from_chromaticities(
+        r: [Float; 2],
+        g: [Float; 2],
+        b: [Float; 2],
+        w: [Float; 2],
+    ) -> Option<Self> {
+        let close =
+            |a: [Float; 2], b: [Float; 2]| (a[0] - b[0]).abs() < 1e-3 && (a[1] - b[1]).abs() < 1e-3;
+        COLOR_SPACES.iter().copied().find(|cs| {
+            let (cr, cg, cb, cw) = cs.chromaticities();
+            close(r, cr) && close(g, cg) && close(b, cb) && close(w, cw)
+        })
+    }
+
+    /// Returns the name of the color space.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::SRGB => "srgb",
+            Self::ACEScg => "acescg",
+            Self::Rec2020 => "rec2020",
+            Self::DisplayP3 => "displayp3",
+        }
+    }
+
+    /// Returns the chromaticities (x, y) of the red, green and blue primaries
+    /// and the white point.
+    pub const fn chromaticities(self) -> ([Float; 2], [Float; 2], [Float; 2], [Float; 2]) {
+        const D65: [Float; 2] = [0.3127, 0.3290];
+        match self {
+            Self::SRGB => ([0.64, 0.33], [0.30, 0.60], [0.15, 0.06], D65),
+            Self::ACEScg => (
+                [0.713, 0.293],
+                [0.165, 0.830],
+                [0.128, 0.044],
+                [0.32168, 0.33767],
+            ),
+            Self::Rec2020 => ([0.708, 0.292], [0.170, 0.797], [0.131, 0.046], D65),
+            Self::DisplayP3 => ([0.680, 0.320], [0.265, 0.690], [0.150, 0.060], D65),
+        }
+    }
+
+    /// Returns the luminance coefficients; the Y values of the red, green and
+    /// blue primaries.
+    pub const fn y_coefficients(self) -> [Float; 3] {
+        let (r, g, b, w) = self.chromaticities();
+        rgb_to_xyz_matrix(r, g, b, w)[1]
+    }
+
+    /// Returns the matrix that converts RGB values in this color space to XYZ.
+    pub fn rgb_to_xyz_matrix(self) -> &'static Matrix3x3 {
+        &MATRICES[self as usize].0
+    }
+
+    /// Returns the matrix that converts XYZ values to RGB in this color space.
+    pub fn xyz_to_rgb_matrix(self) -> &'static Matrix3x3 {
+        &MATRICES[self as usize].1
+    }
+
+    /// Converts RGB values in this color space to XYZ.
+    ///
+    /// * `rgb` - The RGB values.
+    pub fn rgb_to_xyz(self, rgb: &[Float; 3]) -> [Float; 3] {
+        mul_3x3(self.rgb_to_xyz_matrix(), rgb)
+    }
+
+    /// Converts XYZ values to RGB in this color space.
+    ///
+    /// * `xyz` - The XYZ values.
+    pub fn xyz_to_rgb(self, xyz: &[Float; 3]) -> [Float; 3] {
+        mul_3x3(self.xyz_to_rgb_matrix(), xyz)
+    }
+
+    /// Converts RGB values in this color space to another color space.
+    ///
+    /// * `rgb` - The RGB values.
+    /// * `to`  - The color space to convert to.
+    pub fn convert(self, rgb: &[Float; 3], to: ColorSpace) -> [Float; 3] {
+        if self == to {
+            *rgb
+        } else {
+            to.xyz_to_rgb(&self.rgb_to_xyz(rgb))
+        }
+    }
+}
+
+impl Default for ColorSpace {
+    /// Returns sRGB.
+    fn default() -> Self {
+        Self::SRGB
+    }
+}
+
+impl fmt::Display for ColorSpace {
+    /// Formats the color space using its name.
+    ///
+    /// * `f` - Formatter.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Returns the RGB to XYZ matrix for the given primaries and white point such
+/// that RGB (1, 1, 1) maps to the white point with luminance Y = 1.
+///
+/// * `r` - Chromaticity (x, y) of the red primary.
+/// * `g` - Chromaticity (x, y) of the green primary.
+/// * `b` - Chromaticity (x, y) of the blue primary.
+/// * `w` - Chromaticity (x, y) of the white point.
+const fn rgb_to_xyz_matrix(
+    r: [Float; 2],
+    g: [Float; 2],
+    b: [Float; 2],
+    w: [Float; 2],
+) -> Matrix3x3 {
+    // XYZ of each chromaticity with Y = 1.
+    let (r, g, b, w) = (xy_to_xyz(r), xy_to_xyz(g), xy_to_xyz(b), xy_to_xyz(w));
+
+    let m = [[r[0], g[0], b[0]], [r[1], g[1], b[1]], [r[2], g[2], b[2]]];
+    let s = mul_3x3(&invert_3x3(&m), &w);
+
+    [
+        [m[0][0] * s[0], m[0][1] * s[1], m[0][2] * s[2]],
+        [m[1][0] * s[0], m[1][1] * s[1], m[1][2] * s[2]],
+        [m[2][0] * s[0], m[2][1] * s[1], m[2][2] * s[2]],
+    ]
+}
+
+/// Returns the XYZ values of a chromaticity with luminance Y = 1.
+///
+/// * `c` - The chromaticity (x, y).
+const fn xy_to_xyz(c: [Float; 2]) -> [Float; 3] {
+    [c[0] / c[1], 1.0, (1.0 - c[0] - c[1]) / c[1]]
+}
+
+/// Returns the chromaticity (x, y) of XYZ values.
+///
+/// * `xyz` - The XYZ values.
+pub fn xyz_to_xy(xyz: &[Float; 3]) -> [Float; 2] {
+    let sum = xyz[0] + xyz[1] + xyz[2];
+    [xyz[0] / sum, xyz[1] / sum]
+}
+
+/// Returns the matrix that applies a Bradford chromatic adaptation to XYZ
+/// values, mapping the source white point to the destination white point.
+///
+/// * `src_white` - Chromaticity (x, y) of the source white point.
+/// * `dst_white` - Chromaticity (x, y) of the destination white point.
+pub fn white_balance(src_white: [Float; 2], dst_white: [Float; 2]) -> Matrix3x3 {
+    // Bradford transform from XYZ to LMS cone responses.
+    const BRADFORD: Matrix3x3 = [
+        [0.8951, 0.2664, -0.1614],
+        [-0.7502, 1.7135, 0.0367],
+        [0.0389, -0.0685, 1.0296],
+    ];
+
+    // Scale the cone responses of the source white to those of the
+    // destination white (von Kries).
+    let xyz = |c: [Float; 2]| [c[0] / c[1], 1.0, (1.0 - c[0] - c[1]) / c[1]];
+    let src_lms = mul_3x3(&BRADFORD, &xyz(src_white));
+    let dst_lms = mul_3x3(&BRADFORD, &xyz(dst_white));
+    let mut scaled = BRADFORD;
+    for i in 0..3 {
+        for j in 0..3 {
+            scaled[i][j] *= dst_lms[i] / src_lms[i];
+        }
+    }
+
+    mul_3x3_3x3(&invert_3x3(&BRADFORD), &scaled)
+}
+
+/// Multiplies a 3x3 matrix and a vector.
+///
+/// * `m` - The matrix.
+/// * `v` - The vector.
+pub const fn mul_3x3(m: &Matrix3x3, v: &[Float; 3]) -> [Float; 3] {
+    [
+        m[0][0] * v[0] + m[0][1] * v[1] + m[0][2] * v[2],
+        m[1][0] * v[0] + m[1][1] * v[1] + m[1][2] * v[2],
+        m[2][0] * v[0] + m[2][1] * v[1] + m[2][2] * v[2],
+    ]
+}
+
+/// Multiplies two 3x3 matrices.
+///
+/// * `a` - The left matrix.
+/// * `b` - The right matrix.
+fn mul_3x3_3x3(a: &Matrix3x3, b: &Matrix3x3) -> Matrix3x3 {
+    let mut m = [[0.0; 3]; 3];
+    for i in 0..3 {
+        for j in 0..3 {
+            m[i][j] = (0..3).fold(0.0, |sum, k| sum + a[i][k] * b[k][j]);
+        }
+    }
+    m
+}
+
+/// Returns the inverse of a non-singular 3x3 matrix.
+///
+/// * `m` - The matrix.
+const fn invert_3x3(m: &Matrix3x3) -> Matrix3x3 {
+    let c00 = m[1][1] * m[2][2] - m[1][2] * m[2][1];
+    let c01 = m[1][2] * m[2][0] - m[1][0] * m[2][2];
+    let c02 = m[1][0] * m[2][1] - m[1][1] * m[2][0];
+    let inv_det = 1.0 / (m[0][0] * c00 + m[0][1] * c01 + m[0][2] * c02);
+    [
+        [
+            c00 * inv_det,
+            (m[0][2] * m[2][1] - m[0][1] * m[2][2]) * inv_det,
+            (m[0][1] * m[1][2] - m[0][2] * m[1][1]) * inv_det,
+        ],
+        [
+            c01 * inv_det,
+            (m[0][0] * m[2][2] - m[0][2] * m[2][0]) * inv_det,
+            (m[0][2] * m[1][0] - m[0][0] * m[1][2]) * inv_det,
+        ],
+        [
+            c02 * inv_det,
+            (m[0][1] * m[2][0] - m[0][0] * m[2][1]) * inv_det,
+            (m[0][0] * m[1][1] - m[0][1] * m[1][0]) * inv_det,
+        ],
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn srgb_matches_standard_matrix() {
+        let m = ColorSpace::SRGB.rgb_to_xyz_matrix();
+        assert!((m[0][0] - 0.412453).abs() < 1e-3);
+        assert!((m[1][1] - 0.715160).abs() < 1e-3);
+        assert!((m[2][2] - 0.950227).abs() < 1e-3);
+    }
+
+    #[test]
+    fn white_maps_to_white_point() {
+        for cs in COLOR_SPACES.iter() {
+            let xyz = cs.rgb_to_xyz(&[1.0, 1.0, 1.0]);
+            let (_, _, _, w) = cs.chromaticities();
+            let sum = xyz[0] + xyz[1] + xyz[2];
+            assert!((xyz[1] - 1.0).abs() < 1e-4);
+            assert!((xyz[0] / sum - w[0]).abs() < 1e-4);
+            assert!((xyz[1] / sum - w[1]).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn white_balance_maps_source_white_to_destination_white() {
+        let src = [0.44757, 0.40745]; // Illuminant A
+        let dst = ColorSpace::SRGB.chromaticities().3;
+        let m = white_balance(src, dst);
+        let xy = xyz_to_xy(&mul_3x3(
+            &m,
+            &[src[0] / src[1], 1.0, (1.0 - src[0] - src[1]) / src[1]],
+        ));
+        assert!((xy[0] - dst[0]).abs() < 1e-4);
+        assert!((xy[1] - dst[1]).abs() < 1e-4);
+    }
+
+    #[test]
+    fn y_coefficients_are_the_luminance_of_the_primaries() {
+        for cs in COLOR_SPACES.iter() {
+            let y = cs.y_coefficients();
+            assert_eq!(cs.rgb_to_xyz(&[1.0, 0.0, 0.0])[1], y[0]);
+            assert_eq!(cs.rgb_to_xyz(&[0.0, 0.0, 1.0])[1], y[2]);
+        }
+        assert_eq!(rendering_y_coefficients(), SRGB_Y_COEFFICIENTS);
+    }
+
+    #[test]
+    fn convert_round_trips() {
+        let rgb = [0.2, 0.5, 0.8];
+        let p3 = ColorSpace::SRGB.convert(&rgb, ColorSpace::DisplayP3);
+        let back = ColorSpace::DisplayP3.convert(&p3, ColorSpace::SRGB);
+        for i in 0..3 {
+            assert!((rgb[i] - back[i]).abs() < 1e-4);
+        }
+    }
+}
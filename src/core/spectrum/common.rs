@@ -3,7 +3,7 @@
 #![allow(dead_code)]
 
 use crate::core::pbrt::*;
-use crate::core::spectrum::RGBSpectrum;
+use crate::core::spectrum::{rendering_color_space, RGBSpectrum};
 use std::ops::{
     Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
 };
@@ -17,22 +17,6 @@ pub enum SpectrumType {
     /// Surface illuminant.
     Illuminant = 1,
 }
-impl Into<usize> for SpectrumType {
-    fn into(self) -> usize {
-        self as usize
-    }
-}
-pub const NUM_RGB_SPECTRUM_TYPES: usize = 2;
-
-/// Specific colours in the RGB spectrum.
-pub const WHITE: usize = 0;
-pub const CYAN: usize = 1;
-pub const MAGENTA: usize = 2;
-pub const YELLOW: usize = 3;
-pub const RED: usize = 4;
-pub const GREEN: usize = 5;
-pub const BLUE: usize = 6;
-pub const NUM_RGB_SPECTRUM_COLOURS: usize = 7;
 
 /// Stores a spectrum sample value at a given wavelenght.
 #[derive(Copy, Clone, Default, Debug, PartialEq, PartialOrd)]
@@ -61,9 +45,8 @@ impl Sample {
         if n % 2 > 0 {
             warn!("Ignoring extra values in Sample::list().");
         }
-        (0..n)
-            .step_by(2)
-            .map(|i| Sample::new(values[i], values[i + 1]))
+        (0..n / 2)
+            .map(|i| Sample::new(values[2 * i], values[2 * i + 1]))
             .collect()
     }
 }
@@ -301,11 +284,7 @@ pub fn average_spectrum_samples(
         )
     };
 
-    loop {
-        if i + 1 < n && lambda_end >= samples[i].lambda {
-            break;
-        }
-
+    while i + 1 < n && lambda_end >= samples[i].lambda {
         let seg_lambda_start = max(lambda_start, samples[i].lambda);
         let seg_lambda_end = min(lambda_end, samples[i + 1].lambda);
 
@@ -342,30 +321,20 @@ pub fn interpolate_spectrum_samples(samples: &Vec<Sample>, l: Float) -> Float {
     lerp(t, samples[offset].value, samples[offset + 1].value)
 }
 
-/// Converts the given XYZ coefficients to RGB coefficients using RGB spectra
-/// defined for high-definition TVs.
+/// Converts the given XYZ coefficients to RGB coefficients in the rendering
+/// color space.
 ///
 /// * `xyz` - The XYZ coefficients.
-#[rustfmt::skip]
 pub fn xyz_to_rgb(xyz: &[Float; 3]) -> [Float; 3] {
-    [
-         3.240479 * xyz[0] - 1.537150 * xyz[1] - 0.498535 * xyz[2],
-        -0.969256 * xyz[0] + 1.875991 * xyz[1] + 0.041556 * xyz[2],
-         0.055648 * xyz[0] - 0.204043 * xyz[1] + 1.057311 * xyz[2],
-    ]
+    rendering_color_space().xyz_to_rgb(xyz)
 }
 
-/// Converts the given RGB coefficients to XYZ coefficients using RGB spectra
-/// defined for high-definition TVs.
+/// Converts the given RGB coefficients in the rendering color space to XYZ
+/// coefficients.
 ///
 /// * `rgb` - The RGB coefficients.
-#[rustfmt::skip]
 pub fn rgb_to_xyz(rgb: &[Float; 3]) -> [Float; 3] {
-    [
-        0.412453 * rgb[0] + 0.357580 * rgb[1] + 0.180423 * rgb[2],
-        0.212671 * rgb[0] + 0.715160 * rgb[1] + 0.072169 * rgb[2],
-        0.019334 * rgb[0] + 0.119193 * rgb[1] + 0.950227 * rgb[2],
-    ]
+    rendering_color_space().rgb_to_xyz(rgb)
 }
 
 /// Returns the emitted radiance at a given temperature and wavelengths for a
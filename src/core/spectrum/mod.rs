@@ -1,16 +1,25 @@
 //! Spectrum
 
 mod cie;
+mod color_space;
 mod common;
-mod rgb;
+mod illuminant;
+mod named;
+mod reradiation;
 mod rgb_spectrum;
+mod rgb_to_spectrum;
+mod rgb_to_spectrum_table;
 mod sampled_spectrum;
 
 // Re-export
 pub use cie::*;
+pub use color_space::*;
 pub use common::*;
-pub use rgb::*;
+pub use illuminant::*;
+pub use named::*;
+pub use reradiation::*;
 pub use rgb_spectrum::*;
+pub use rgb_to_spectrum::*;
 pub use sampled_spectrum::*;
 
 /// Default to using `RGBSpectrum` for rendering.
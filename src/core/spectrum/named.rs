@@ -0,0 +1,429 @@
+//! Named Spectra
+
+#![allow(dead_code)]
+use super::*;
+use crate::core::pbrt::*;
+use std::collections::HashMap;
+use std::path::Path;
+
+lazy_static! {
+    /// The named spectra converted to `Spectrum`, keyed by name.
+    static ref NAMED_SPECTRA: HashMap<&'static str, Spectrum> = NAMED_SPECTRUM_NAMES
+        .iter()
+        .filter_map(|&name| named_spectrum_samples(name).map(|s| (name, Spectrum::from(&s))))
+        .collect();
+}
+
+/// Names of the built-in spectral datasets.
+pub const NAMED_SPECTRUM_NAMES: [&str; 34] = [
+    "metal-Ag-eta",
+    "metal-Ag-k",
+    "metal-Al-eta",
+    "metal-Al-k",
+    "metal-Au-eta",
+    "metal-Au-k",
+    "metal-Cu-eta",
+    "metal-Cu-k",
+    "metal-CuZn-eta",
+    "metal-CuZn-k",
+    "metal-MgO-eta",
+    "metal-MgO-k",
+    "metal-TiO2-eta",
+    "metal-TiO2-k",
+    "glass-BK7",
+    "glass-BAF10",
+    "glass-FK51A",
+    "glass-LASF9",
+    "glass-F5",
+    "stdillum-A",
+    "stdillum-D50",
+    "stdillum-D65",
+    "stdillum-F1",
+    "stdillum-F2",
+    "stdillum-F3",
+    "stdillum-F4",
+    "stdillum-F5",
+    "stdillum-F6",
+    "stdillum-F7",
+    "stdillum-F8",
+    "stdillum-F9",
+    "stdillum-F10",
+    "stdillum-F11",
+    "stdillum-F12",
+];
+
+/// Returns a built-in spectral dataset as a `Spectrum`; or `None` if there is
+/// no dataset with the given name.
+///
+/// * `name` - Name of the dataset (e.g. `"metal-Cu-eta"`).
+pub fn named_spectrum(name: &str) -> Option<Spectrum> {
+    NAMED_SPECTRA.get(name).copied()
+}
+
+/// Returns the name of the built-in dataset holding the same data as one of
+/// the spectrum files shipped with the pbrt scenes, e.g. `"metal-Au-eta"` for
+/// `spds/metals/Au.eta.spd`, or `"stdillum-D65"` for `stdillum-D65.spd` and
+/// `spds/illuminants/D65.spd`; or `None` if there isn't one.
+///
+/// * `path` - Path of the spectrum file.
+pub fn standard_spd_name(path: &str) -> Option<&'static str> {
+    let path = Path::new(path);
+    let stem = path.file_name()?.to_str()?.strip_suffix(".spd")?;
+    let in_illuminants = path
+        .parent()
+        .and_then(|dir| dir.file_name())
+        .map_or(false, |dir| dir == "illuminants");
+    let name = match stem.rsplit_once('.') {
+        Some((metal, part @ ("eta" | "k"))) => format!("metal-{}-{}", metal, part),
+        _ if in_illuminants && !stem.starts_with("stdillum-") => format!("stdillum-{}", stem),
+        _ => String::from(stem),
+    };
+    NAMED_SPECTRUM_NAMES.iter().copied().find(|n| *n == name)
+}
+
+/// Returns `true` if there is a built-in spectral dataset with the given name.
+///
+/// * `name` - Name of the dataset.
+pub fn is_named_spectrum(name: &str) -> bool {
+    NAMED_SPECTRUM_NAMES.contains(&name)
+}
+
+/// Returns the (wavelength, value) samples of a built-in spectral dataset; or
+/// `None` if there is no dataset with the given name.
+///
+/// * `name` - Name of the dataset.
+pub fn named_spectrum_samples(name: &str) -> Option<Vec<Sample>> {
+    match name {
+        "metal-Ag-eta" => Some(tabulated_samples(&METAL_WAVELENGTHS, &SILVER_N)),
+        "metal-Ag-k" => Some(tabulated_samples(&METAL_WAVELENGTHS, &SILVER_K)),
+        "metal-Al-eta" => Some(tabulated_samples(&METAL_WAVELENGTHS, &ALUMINIUM_N)),
+        "metal-Al-k" => Some(tabulated_samples(&METAL_WAVELENGTHS, &ALUMINIUM_K)),
+        "metal-Au-eta" => Some(tabulated_samples(&METAL_WAVELENGTHS, &GOLD_N)),
+        "metal-Au-k" => Some(tabulated_samples(&METAL_WAVELENGTHS, &GOLD_K)),
+        "metal-Cu-eta" => Some(tabulated_samples(&METAL_WAVELENGTHS, &COPPER_N)),
+        "metal-Cu-k" => Some(tabulated_samples(&METAL_WAVELENGTHS, &COPPER_K)),
+        "metal-CuZn-eta" => Some(tabulated_samples(&BRASS_WAVELENGTHS, &BRASS_N)),
+        "metal-CuZn-k" => Some(tabulated_samples(&BRASS_WAVELENGTHS, &BRASS_K)),
+        "metal-MgO-eta" => Some(sellmeier_samples(
+            [1.111033, 0.8460085, 7.808527],
+            [0.005040813, 0.01484325, 760.7821],
+        )),
+        "metal-MgO-k" => Some(index_samples(300, |_| 0.0)),
+        "metal-TiO2-eta" => Some(index_samples(430, |l2| {
+            (5.913 + 0.2441 / (l2 - 0.0803)).sqrt()
+        })),
+        "metal-TiO2-k" => Some(index_samples(430, |_| 0.0)),
+        "glass-BK7" => Some(sellmeier_samples(
+            [1.03961212, 0.231792344, 1.01046945],
+            [0.00600069867, 0.0200179144, 103.560653],
+        )),
+        "glass-BAF10" => Some(sellmeier_samples(
+            [1.5851495, 0.143559385, 1.08521269],
+            [0.00926681282, 0.0424489805, 105.613573],
+        )),
+        "glass-FK51A" => Some(sellmeier_samples(
+            [0.971247817, 0.216901417, 0.904651666],
+            [0.00472301995, 0.0153575612, 168.68133],
+        )),
+        "glass-LASF9" => Some(sellmeier_samples(
+            [2.00029547, 0.298926886, 1.80691843],
+            [0.0121426017, 0.0538736236, 156.530829],
+        )),
+        "glass-F5" => Some(sellmeier_samples(
+            [1.3104463, 0.19603426, 0.96612977],
+            [0.00958633048, 0.0457627627, 115.011883],
+        )),
+        "stdillum-A" => Some(cie_a_illuminant_samples()),
+        "stdillum-D50" => Some(cie_d_illuminant_samples(D50_CCT)),
+        "stdillum-D65" => Some(cie_d_illuminant_samples(D65_CCT)),
+        _ => name
+            .strip_prefix("stdillum-F")
+            .and_then(|n| n.parse().ok())
+            .and_then(cie_f_illuminant_samples),
+    }
+}
+
+/// Returns samples from tabulated wavelengths and values.
+///
+/// * `lambda` - Wavelengths in nm.
+/// * `values` - Values at each wavelength.
+fn tabulated_samples(lambda: &[Float], values: &[Float]) -> Vec<Sample> {
+    lambda
+        .iter()
+        .zip(values.iter())
+        .map(|(&l, &v)| Sample::new(l, v))
+        .collect()
+}
+
+/// Returns index of refraction samples from 300nm to 800nm for a glass
+/// described by the three term Sellmeier equation.
+///
+/// * `b` - The B coefficients.
+/// * `c` - The C coefficients in μm².
+fn sellmeier_samples(b: [Float; 3], c: [Float; 3]) -> Vec<Sample> {
+    index_samples(300, |l2| {
+        let n2 = 1.0 + (0..3).fold(0.0, |sum, i| sum + b[i] * l2 / (l2 - c[i]));
+        n2.sqrt()
+    })
+}
+
+/// Returns samples every 10nm up to 800nm of a dispersion formula.
+///
+/// * `start` - First wavelength in nm.
+/// * `f`     - Returns the value for the squared wavelength in μm².
+fn index_samples<F: Fn(Float) -> Float>(start: usize, f: F) -> Vec<Sample> {
+    (start..=800)
+        .step_by(10)
+        .map(|l| {
+            let lambda = l as Float;
+            let l2 = (lambda / 1000.0) * (lambda / 1000.0);
+            Sample::new(lambda, f(l2))
+        })
+        .collect()
+}
+
+/// Number of samples in the measured metal datasets.
+const METAL_SAMPLES: usize = 56;
+
+/// Wavelengths for the measured metal datasets, every 0.05eV from 4.15eV to
+/// 1.4eV.
+const METAL_WAVELENGTHS: [Float; METAL_SAMPLES] = [
+    298.7570554,
+    302.4004341,
+    306.1337728,
+    309.960445,
+    313.8839949,
+    317.9081487,
+    322.036826,
+    326.2741526,
+    330.6244747,
+    335.092373,
+    339.6826795,
+    344.4004944,
+    349.2512056,
+    354.2405086,
+    359.374429,
+    364.6593471,
+    370.1020239,
+    375.7096303,
+    381.4897785,
+    387.4505563,
+    393.6005651,
+    399.9489613,
+    406.5055016,
+    413.2805933,
+    420.2853492,
+    427.5316483,
+    435.0322035,
+    442.8006357,
+    450.8515564,
+    459.2006593,
+    467.8648226,
+    476.8622231,
+    486.2124627,
+    495.936712,
+    506.0578694,
+    516.6007417,
+    527.5922468,
+    539.0616435,
+    551.0407911,
+    563.5644455,
+    576.6705953,
+    590.4008476,
+    604.8008683,
+    619.92089,
+    635.8162974,
+    652.5483053,
+    670.1847459,
+    688.8009889,
+    708.4810171,
+    729.3186941,
+    751.4192606,
+    774.9011125,
+    799.8979226,
+    826.5611867,
+    855.0632966,
+    885.6012714,
+];
+
+/// Index of refraction of copper.
+const COPPER_N: [Float; METAL_SAMPLES] = [
+    1.400313, 1.38, 1.358438, 1.34, 1.329063, 1.325, 1.3325, 1.34, 1.334375, 1.325, 1.317812, 1.31,
+    1.300313, 1.29, 1.281563, 1.27, 1.249062, 1.225, 1.2, 1.18, 1.174375, 1.175, 1.1775, 1.18,
+    1.178125, 1.175, 1.172812, 1.17, 1.165312, 1.16, 1.155312, 1.15, 1.142812, 1.135, 1.131562,
+    1.12, 1.092437, 1.04, 0.950375, 0.826, 0.645875, 0.468, 0.35125, 0.272, 0.230813, 0.214,
+    0.20925, 0.213, 0.21625, 0.223, 0.2365, 0.25, 0.254188, 0.26, 0.28, 0.3,
+];
+
+/// Absorption coefficient of copper.
+const COPPER_K: [Float; METAL_SAMPLES] = [
+    1.662125, 1.687, 1.703313, 1.72, 1.744563, 1.77, 1.791625, 1.81, 1.822125, 1.834, 1.85175,
+    1.872, 1.89425, 1.916, 1.931688, 1.95, 1.972438, 2.015, 2.121562, 2.21, 2.177188, 2.13,
+    2.160063, 2.21, 2.249938, 2.289, 2.326, 2.362, 2.397625, 2.433, 2.469187, 2.504, 2.535875,
+    2.564, 2.589625, 2.605, 2.595562, 2.583, 2.5765, 2.599, 2.678062, 2.809, 3.01075, 3.24,
+    3.458187, 3.67, 3.863125, 4.05, 4.239563, 4.43, 4.619563, 4.817, 5.034125, 5.26, 5.485625,
+    5.717,
+];
+
+/// Index of refraction of gold.
+const GOLD_N: [Float; METAL_SAMPLES] = [
+    1.795, 1.812, 1.822625, 1.83, 1.837125, 1.84, 1.83425, 1.824, 1.812, 1.798, 1.782, 1.766,
+    1.7525, 1.74, 1.727625, 1.716, 1.705875, 1.696, 1.68475, 1.674, 1.666, 1.658, 1.64725, 1.636,
+    1.628, 1.616, 1.59625, 1.562, 1.502125, 1.426, 1.345875, 1.242, 1.08675, 0.916, 0.7545, 0.608,
+    0.49175, 0.402, 0.3455, 0.306, 0.267625, 0.236, 0.212375, 0.194, 0.17875, 0.166, 0.161, 0.16,
+    0.160875, 0.164, 0.1695, 0.176, 0.181375, 0.188, 0.198125, 0.21,
+];
+
+/// Absorption coefficient of gold.
+const GOLD_K: [Float; METAL_SAMPLES] = [
+    1.920375, 1.92, 1.918875, 1.916, 1.911375, 1.904, 1.891375, 1.878, 1.86825, 1.86, 1.85175,
+    1.846, 1.84525, 1.848, 1.852375, 1.862, 1.883, 1.906, 1.9225, 1.936, 1.94775, 1.956, 1.959375,
+    1.958, 1.951375, 1.94, 1.9245, 1.904, 1.875875, 1.846, 1.814625, 1.796, 1.797375, 1.84, 1.9565,
+    2.12, 2.32625, 2.54, 2.730625, 2.88, 2.940625, 2.97, 3.015, 3.06, 3.07, 3.15, 3.445812, 3.8,
+    4.087687, 4.357, 4.610188, 4.86, 5.125813, 5.39, 5.63125, 5.88,
+];
+
+/// Index of refraction of silver.
+const SILVER_N: [Float; METAL_SAMPLES] = [
+    1.519, 1.496, 1.4325, 1.323, 1.142062, 0.932, 0.719062, 0.526, 0.388125, 0.294, 0.253313,
+    0.238, 0.221438, 0.209, 0.194813, 0.186, 0.192063, 0.2, 0.198063, 0.192, 0.182, 0.173,
+    0.172625, 0.173, 0.166688, 0.16, 0.1585, 0.157, 0.151063, 0.144, 0.137313, 0.132, 0.13025,
+    0.13, 0.129938, 0.13, 0.130063, 0.129, 0.124375, 0.12, 0.119313, 0.121, 0.1255, 0.131,
+    0.136125, 0.14, 0.140063, 0.14, 0.144313, 0.148, 0.145875, 0.143, 0.142563, 0.145, 0.151938,
+    0.163,
+];
+
+/// Absorption coefficient of silver.
+const SILVER_K: [Float; METAL_SAMPLES] = [
+    1.08, 0.882, 0.761063, 0.647, 0.550875, 0.504, 0.554375, 0.663, 0.818563, 0.986, 1.120687,
+    1.24, 1.34525, 1.44, 1.53375, 1.61, 1.641875, 1.67, 1.735, 1.81, 1.87875, 1.95, 2.029375, 2.11,
+    2.18625, 2.26, 2.329375, 2.4, 2.47875, 2.56, 2.64, 2.72, 2.798125, 2.88, 2.97375, 3.07,
+    3.159375, 3.25, 3.348125, 3.45, 3.55375, 3.66, 3.76625, 3.88, 4.010625, 4.15, 4.293125, 4.44,
+    4.58625, 4.74, 4.908125, 5.09, 5.28875, 5.5, 5.720624, 5.95,
+];
+
+/// Index of refraction of aluminium.
+const ALUMINIUM_N: [Float; METAL_SAMPLES] = [
+    0.273375, 0.28, 0.286813, 0.294, 0.301875, 0.31, 0.317875, 0.326, 0.33475, 0.344, 0.353813,
+    0.364, 0.374375, 0.385, 0.39575, 0.407, 0.419125, 0.432, 0.445688, 0.46, 0.474688, 0.49,
+    0.506188, 0.523, 0.540063, 0.558, 0.577313, 0.598, 0.620313, 0.644, 0.668625, 0.695, 0.72375,
+    0.755, 0.789, 0.826, 0.867, 0.912, 0.963, 1.02, 1.08, 1.15, 1.22, 1.3, 1.39, 1.49, 1.6, 1.74,
+    1.91, 2.14, 2.41, 2.63, 2.8, 2.74, 2.58, 2.24,
+];
+
+/// Absorption coefficient of aluminium.
+#[allow(clippy::approx_constant)]
+const ALUMINIUM_K: [Float; METAL_SAMPLES] = [
+    3.59375, 3.64, 3.689375, 3.74, 3.789375, 3.84, 3.894375, 3.95, 4.005, 4.06, 4.11375, 4.17,
+    4.23375, 4.3, 4.365, 4.43, 4.49375, 4.56, 4.63375, 4.71, 4.784375, 4.86, 4.938125, 5.02,
+    5.10875, 5.2, 5.29, 5.38, 5.48, 5.58, 5.69, 5.8, 5.915, 6.03, 6.15, 6.28, 6.42, 6.55, 6.7,
+    6.85, 7.0, 7.15, 7.31, 7.48, 7.65, 7.86, 8.04, 8.25, 8.44, 8.57, 8.62, 8.6, 8.45, 8.31, 8.21,
+    8.21,
+];
+
+/// Number of samples in the brass dataset.
+const BRASS_SAMPLES: usize = 13;
+
+/// Wavelengths for the brass dataset.
+const BRASS_WAVELENGTHS: [Float; BRASS_SAMPLES] = [
+    300.0, 350.0, 400.0, 450.0, 500.0, 550.0, 600.0, 650.0, 700.0, 750.0, 800.0, 850.0, 885.0,
+];
+
+/// Index of refraction of brass (Querry).
+const BRASS_N: [Float; BRASS_SAMPLES] = [
+    1.5, 1.4, 1.26, 1.14, 0.91, 0.56, 0.44, 0.41, 0.4, 0.41, 0.44, 0.48, 0.51,
+];
+
+/// Absorption coefficient of brass (Querry).
+const BRASS_K: [Float; BRASS_SAMPLES] = [
+    1.8, 1.87, 1.94, 1.97, 2.05, 2.5, 2.89, 3.26, 3.62, 3.95, 4.26, 4.55, 4.75,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_named_spectra_exist() {
+        for name in NAMED_SPECTRUM_NAMES.iter() {
+            assert!(named_spectrum(name).is_some());
+        }
+    }
+
+    #[test]
+    fn unknown_named_spectrum_is_none() {
+        assert!(named_spectrum("metal-Unobtainium-eta").is_none());
+    }
+
+    #[test]
+    fn standard_spd_files_map_to_named_spectra() {
+        assert_eq!(
+            standard_spd_name("spds/metals/Cu.eta.spd"),
+            Some("metal-Cu-eta")
+        );
+        assert_eq!(standard_spd_name("Cu.k.spd"), Some("metal-Cu-k"));
+        assert_eq!(
+            standard_spd_name("/data/stdillum-D65.spd"),
+            Some("stdillum-D65")
+        );
+        assert_eq!(
+            standard_spd_name("spds/metals/Au.eta.spd"),
+            Some("metal-Au-eta")
+        );
+        assert_eq!(
+            standard_spd_name("spds/illuminants/D50.spd"),
+            Some("stdillum-D50")
+        );
+        assert_eq!(standard_spd_name("spds/metals/Pt.eta.spd"), None);
+        assert_eq!(standard_spd_name("spds/D50.spd"), None);
+        assert_eq!(standard_spd_name("Cu.eta.txt"), None);
+    }
+
+    #[test]
+    fn standard_metal_spd_files_have_built_in_data() {
+        for metal in ["Ag", "Al", "Au", "Cu", "CuZn", "MgO", "TiO2"].iter() {
+            for part in ["eta", "k"].iter() {
+                let path = format!("spds/metals/{}.{}.spd", metal, part);
+                let name = standard_spd_name(&path).unwrap();
+                assert!(named_spectrum(name).is_some());
+            }
+        }
+    }
+
+    #[test]
+    fn metals_have_named_spectra() {
+        let gold = named_spectrum("metal-Au-eta").unwrap();
+        assert!(gold.y() > 0.0);
+        for metal in ["Ag", "Al", "Au", "CuZn", "MgO", "TiO2"].iter() {
+            assert!(is_named_spectrum(&format!("metal-{}-eta", metal)));
+            assert!(is_named_spectrum(&format!("metal-{}-k", metal)));
+        }
+
+        let tio2 = named_spectrum_samples("metal-TiO2-eta").unwrap();
+        let n = interpolate_spectrum_samples(&tio2, 590.0);
+        assert!((n - 2.6).abs() < 0.05);
+    }
+
+    #[test]
+    fn measured_metals_match_pbrt() {
+        let value = |name: &str, lambda: Float| {
+            let samples = named_spectrum_samples(name).unwrap();
+            assert_eq!(samples.len(), METAL_SAMPLES);
+            interpolate_spectrum_samples(&samples, lambda)
+        };
+        assert!((value("metal-Au-eta", 551.0407911) - 0.3455).abs() < 1e-6);
+        assert!((value("metal-Au-k", 652.5483053) - 3.15).abs() < 1e-6);
+        assert!((value("metal-Ag-eta", 298.7570554) - 1.519).abs() < 1e-6);
+        assert!((value("metal-Ag-k", 885.6012714) - 5.95).abs() < 1e-6);
+        assert!((value("metal-Al-eta", 799.8979226) - 2.8).abs() < 1e-6);
+        assert!((value("metal-Al-k", 495.936712) - 6.03).abs() < 1e-6);
+        assert!((value("metal-Cu-eta", 551.0407911) - 0.950375).abs() < 1e-6);
+    }
+
+    #[test]
+    fn bk7_index_of_refraction_at_d_line() {
+        let samples = named_spectrum_samples("glass-BK7").unwrap();
+        let n = interpolate_spectrum_samples(&samples, 587.6);
+        assert!((n - 1.5168).abs() < 1e-3);
+    }
+}
@@ -0,0 +1,134 @@
+//! Re-radiation
+
+#![allow(dead_code)]
+use super::*;
+use crate::core::pbrt::*;
+
+/// Stores how light absorbed at one wavelength of a `Spectrum` is re-emitted
+/// at other wavelengths, e.g. by fluorescence.
+#[derive(Clone, Debug)]
+pub struct ReradiationMatrix {
+    /// Number of spectrum samples.
+    n: usize,
+
+    /// Matrix with `n x n` entries in row-major order where the entry at row
+    /// `i` and column `j` is the fraction of energy absorbed at sample `j`
+    /// that is re-emitted at sample `i`.
+    m: Vec<Float>,
+}
+
+impl ReradiationMatrix {
+    /// Create a new `ReradiationMatrix` for fluorescence where absorbed
+    /// energy is re-emitted at longer wavelengths following the emission
+    /// spectrum.
+    ///
+    /// * `absorption`    - Fraction of incident light absorbed at each
+    ///                     wavelength in [0, 1].
+    /// * `emission`      - Relative emission spectrum.
+    /// * `quantum_yield` - Fraction of absorbed energy that is re-emitted.
+    pub fn new(absorption: &Spectrum, emission: &Spectrum, quantum_yield: Float) -> Self {
+        let lambda = spectrum_sample_wavelengths();
+        let n = lambda.len();
+        let mut m = vec![0.0; n * n];
+
+        for j in 0..n {
+            let a = clamp(absorption[j], 0.0, 1.0);
+
+            // Normalize emission over the wavelengths longer than the
+            // absorbed one.
+            let sum = (0..n)
+                .filter(|&i| lambda[i] > lambda[j])
+                .fold(0.0, |s, i| s + max(emission[i], 0.0));
+            if a == 0.0 || sum == 0.0 {
+                continue;
+            }
+
+            for i in (0..n).filter(|&i| lambda[i] > lambda[j]) {
+                m[i * n + j] = quantum_yield * a * max(emission[i], 0.0) / sum;
+            }
+        }
+
+        Self { n, m }
+    }
+
+    /// Create a new `ReradiationMatrix` for a linear map of spectra, e.g. the
+    /// composition of scattering events along a path.
+    ///
+    /// * `f` - The linear map.
+    pub fn from_fn<F: Fn(&Spectrum) -> Spectrum>(f: F) -> Self {
+        let n = spectrum_sample_wavelengths().len();
+        let mut m = vec![0.0; n * n];
+
+        for j in 0..n {
+            let mut e = Spectrum::new(0.0);
+            e[j] = 1.0;
+            let column = f(&e);
+            for i in 0..n {
+                m[i * n + j] = column[i];
+            }
+        }
+
+        Self { n, m }
+    }
+
+    /// Returns the re-emitted spectrum for the given incident spectrum.
+    ///
+    /// * `s` - The incident spectrum.
+    pub fn apply(&self, s: &Spectrum) -> Spectrum {
+        let mut r = Spectrum::new(0.0);
+        for i in 0..self.n {
+            r[i] = (0..self.n).fold(0.0, |sum, j| sum + self.m[i * self.n + j] * s[j]);
+        }
+        r
+    }
+}
+
+/// Returns the representative wavelength of each `Spectrum` sample in nm.
+#[cfg(feature = "sampled-spectrum")]
+pub fn spectrum_sample_wavelengths() -> Vec<Float> {
+    (0..SPECTRAL_SAMPLES)
+        .map(SampledSpectrum::sample_wavelength)
+        .collect()
+}
+
+/// Returns the representative wavelength of each `Spectrum` sample in nm.
+#[cfg(not(feature = "sampled-spectrum"))]
+pub fn spectrum_sample_wavelengths() -> Vec<Float> {
+    vec![610.0, 550.0, 465.0]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn energy_moves_to_longer_wavelengths() {
+        let lambda = spectrum_sample_wavelengths();
+        let n = lambda.len();
+        let m = ReradiationMatrix::new(&Spectrum::new(1.0), &Spectrum::new(1.0), 1.0);
+
+        // Light at the shortest wavelength is re-emitted without loss at
+        // longer wavelengths only.
+        let shortest = (0..n)
+            .min_by(|&a, &b| lambda[a].partial_cmp(&lambda[b]).unwrap())
+            .unwrap();
+        let mut s = Spectrum::new(0.0);
+        s[shortest] = 1.0;
+        let r = m.apply(&s);
+        assert_eq!(r[shortest], 0.0);
+        let total = (0..n).fold(0.0, |t, i| t + r[i]);
+        assert!((total - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn linear_maps_compose() {
+        let m = ReradiationMatrix::new(&Spectrum::new(0.5), &Spectrum::new(1.0), 1.0);
+        let s = Spectrum::new(2.0);
+        let scaled = ReradiationMatrix::from_fn(|x| m.apply(x) * 3.0 + *x);
+        let expected = m.apply(&s) * 3.0 + s;
+        let r = scaled.apply(&s);
+        for i in 0..spectrum_sample_wavelengths().len() {
+            assert!((r[i] - expected[i]).abs() < 1e-5);
+        }
+    }
+}
@@ -96,7 +96,8 @@ impl From<&Vec<Sample>> for RGBSpectrum {
         };
 
         let xyz = (0..CIE_SAMPLES).fold([0.0; 3], |v, i| {
-            let val = interpolate_spectrum_samples(samples, (CIE_LAMBDA_START + i) as Float);
+            let val =
+                interpolate_spectrum_samples(&sorted_samples, (CIE_LAMBDA_START + i) as Float);
             [
                 v[0] + val * CIE_X[i],
                 v[1] + val * CIE_Y[i],
@@ -107,9 +108,7 @@ impl From<&Vec<Sample>> for RGBSpectrum {
         let scale =
             (CIE_LAMBDA_END - CIE_LAMBDA_START) as Float / (CIE_Y_INTEGRAL * CIE_SAMPLES as Float);
 
-        Self {
-            c: [xyz[0] * scale, xyz[1] * scale, xyz[2] * scale],
-        }
+        Self::from_xyz(&[xyz[0] * scale, xyz[1] * scale, xyz[2] * scale], None)
     }
 }
 
@@ -140,7 +139,8 @@ impl CoefficientSpectrum for RGBSpectrum {
 
     /// Returns the y-coefficient of XYZ colour.
     fn y(&self) -> Float {
-        0.212671 * self.c[0] + 0.715160 * self.c[1] + 0.072169 * self.c[2]
+        let w = rendering_y_coefficients();
+        w[0] * self.c[0] + w[1] * self.c[1] + w[2] * self.c[2]
     }
 
     /// Converts RGB values to a full SPD.
@@ -0,0 +1,515 @@
+//! RGB to Spectrum Upsampling
+//!
+//! Implements the sigmoid-polynomial spectral upsampling of Jakob and Hanika,
+//! "A Low-Dimensional Function Space for Efficient Spectral Upsampling". The
+//! coefficient table for sRGB under illuminant D65 is fitted ahead of time
+//! and embedded from `rgb_to_spectrum_table.rs`. After changing the fit,
+//! regenerate it with
+//! `cargo test write_rgb_to_spectrum_table -- --ignored`.
+
+#![allow(dead_code)]
+use super::rgb_to_spectrum_table::*;
+use super::*;
+use crate::core::parallel::*;
+use crate::core::pbrt::*;
+
+/// Resolution of the coefficient table along each dimension.
+pub const RGB_TO_SPECTRUM_TABLE_RES: usize = 16;
+
+/// The sRGB to spectrum coefficient table.
+pub static RGB_TO_SPECTRUM_TABLE: RGBToSpectrumTable = RGBToSpectrumTable {
+    z_nodes: &RGB_TO_SPECTRUM_Z_NODES,
+    coeffs: &RGB_TO_SPECTRUM_COEFFS,
+};
+
+/// A spectrum `s(c0 λ² + c1 λ + c2)` where `s` is a sigmoid mapping the
+/// polynomial to [0, 1] and `λ` is in nm.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct RGBSigmoidPolynomial {
+    /// The polynomial coefficients.
+    c: [Float; 3],
+}
+
+impl RGBSigmoidPolynomial {
+    /// Create a new `RGBSigmoidPolynomial`.
+    ///
+    /// * `c0` - Coefficient of λ².
+    /// * `c1` - Coefficient of λ.
+    /// * `c2` - Constant term.
+    pub fn new(c0: Float, c1: Float, c2: Float) -> Self {
+        Self { c: [c0, c1, c2] }
+    }
+
+    /// Returns the value of the spectrum at a given wavelength.
+    ///
+    /// * `lambda` - The wavelength in nm.
+    pub fn evaluate(&self, lambda: Float) -> Float {
+        sigmoid((self.c[0] * lambda + self.c[1]) * lambda + self.c[2])
+    }
+}
+
+/// Maps the polynomial value to [0, 1].
+///
+/// * `x` - The polynomial value.
+fn sigmoid(x: Float) -> Float {
+    if x.is_infinite() {
+        if x > 0.0 {
+            1.0
+        } else {
+            0.0
+        }
+    } else {
+        0.5 + x / (2.0 * (1.0 + x * x).sqrt())
+    }
+}
+
+/// Stores sigmoid polynomial coefficients for RGB values in [0, 1]. The table
+/// is indexed by the largest RGB component, its (nonlinearly spaced) value and
+/// the other two components divided by it.
+pub struct RGBToSpectrumTable {
+    /// Values of the largest component at each z-node.
+    z_nodes: &'static [Float],
+
+    /// Coefficients indexed by `((maxc * res + z) * res + y) * res + x`.
+    coeffs: &'static [[Float; 3]],
+}
+
+impl RGBToSpectrumTable {
+    /// Fits the z-nodes and coefficients of the table for sRGB. This is slow;
+    /// `RGB_TO_SPECTRUM_TABLE` embeds the result.
+    fn fit() -> (Vec<Float>, Vec<[Float; 3]>) {
+        let res = RGB_TO_SPECTRUM_TABLE_RES;
+        let fit = SpectrumFit::new(ColorSpace::SRGB);
+
+        let z_nodes: Vec<Float> = (0..res)
+            .map(|k| smooth_step(smooth_step(k as f64 / (res - 1) as f64)) as Float)
+            .collect();
+
+        // Fit each row of constant largest component and y; walk outwards
+        // from a moderate brightness using the previous solution as the
+        // starting point for the next.
+        let rows: Vec<Vec<(usize, [Float; 3])>> = (0..3 * res)
+            .into_par_iter()
+            .map(|row| {
+                let (l, j) = (row / res, row % res);
+                let y = j as f64 / (res - 1) as f64;
+                let start = res / 5;
+                let mut out = Vec::with_capacity(res * res);
+                for i in 0..res {
+                    let x = i as f64 / (res - 1) as f64;
+                    let mut fit_z = |k: usize, coeffs: &mut [f64; 3]| {
+                        let b = z_nodes[k] as f64;
+                        let mut rgb = [0.0; 3];
+                        rgb[l] = b;
+                        rgb[(l + 1) % 3] = x * b;
+                        rgb[(l + 2) % 3] = y * b;
+                        fit.gauss_newton(&rgb, coeffs);
+                        let idx = ((l * res + k) * res + j) * res + i;
+                        out.push((idx, to_nm_coefficients(coeffs)));
+                    };
+
+                    let mut coeffs = [0.0; 3];
+                    for k in start..res {
+                        fit_z(k, &mut coeffs);
+                    }
+                    let mut coeffs = [0.0; 3];
+                    for k in (0..start).rev() {
+                        fit_z(k, &mut coeffs);
+                    }
+                }
+                out
+            })
+            .collect();
+
+        let mut coeffs = vec![[0.0; 3]; 3 * res * res * res];
+        for (idx, c) in rows.into_iter().flatten() {
+            coeffs[idx] = c;
+        }
+
+        (z_nodes, coeffs)
+    }
+
+    /// Returns the sigmoid polynomial for an RGB value with components in
+    /// [0, 1].
+    ///
+    /// * `rgb` - The RGB value.
+    pub fn lookup(&self, rgb: &[Float; 3]) -> RGBSigmoidPolynomial {
+        let rgb = [
+            clamp(rgb[0], 0.0, 1.0),
+            clamp(rgb[1], 0.0, 1.0),
+            clamp(rgb[2], 0.0, 1.0),
+        ];
+
+        // Handle uniform values.
+        if rgb[0] == rgb[1] && rgb[1] == rgb[2] {
+            return RGBSigmoidPolynomial::new(
+                0.0,
+                0.0,
+                (rgb[0] - 0.5) / (rgb[0] * (1.0 - rgb[0])).sqrt(),
+            );
+        }
+
+        // Find the largest component and compute the remapped coordinates.
+        let res = RGB_TO_SPECTRUM_TABLE_RES;
+        let maxc = if rgb[0] > rgb[1] {
+            if rgb[0] > rgb[2] {
+                0
+            } else {
+                2
+            }
+        } else if rgb[1] > rgb[2] {
+            1
+        } else {
+            2
+        };
+        let z = rgb[maxc];
+        let x = rgb[(maxc + 1) % 3] * (res - 1) as Float / z;
+        let y = rgb[(maxc + 2) % 3] * (res - 1) as Float / z;
+
+        let xi = min(x as usize, res - 2);
+        let yi = min(y as usize, res - 2);
+        let zi = find_interval(res, |i| self.z_nodes[i] < z);
+        let dx = x - xi as Float;
+        let dy = y - yi as Float;
+        let dz = (z - self.z_nodes[zi]) / (self.z_nodes[zi + 1] - self.z_nodes[zi]);
+
+        // Trilinearly interpolate the coefficients.
+        let co = |dx: usize, dy: usize, dz: usize, i: usize| {
+            self.coeffs[((maxc * res + zi + dz) * res + yi + dy) * res + xi + dx][i]
+        };
+        let mut c = [0.0; 3];
+        for (i, ci) in c.iter_mut().enumerate() {
+            *ci = lerp(
+                dz,
+                lerp(
+                    dy,
+                    lerp(dx, co(0, 0, 0, i), co(1, 0, 0, i)),
+                    lerp(dx, co(0, 1, 0, i), co(1, 1, 0, i)),
+                ),
+                lerp(
+                    dy,
+                    lerp(dx, co(0, 0, 1, i), co(1, 0, 1, i)),
+                    lerp(dx, co(0, 1, 1, i), co(1, 1, 1, i)),
+                ),
+            );
+        }
+
+        RGBSigmoidPolynomial::new(c[0], c[1], c[2])
+    }
+}
+
+/// Converts coefficients fitted over wavelengths normalized to [0, 1] to
+/// coefficients over wavelengths in nm.
+///
+/// * `c` - The coefficients.
+fn to_nm_coefficients(c: &[f64; 3]) -> [Float; 3] {
+    let c0 = CIE_LAMBDA_START as f64;
+    let c1 = 1.0 / (CIE_LAMBDA_END - CIE_LAMBDA_START) as f64;
+    [
+        (c[0] * c1 * c1) as Float,
+        (c[1] * c1 - 2.0 * c[0] * c0 * c1 * c1) as Float,
+        (c[2] - c[1] * c0 * c1 + c[0] * (c0 * c1) * (c0 * c1)) as Float,
+    ]
+}
+
+/// Returns the smooth Hermite interpolation of `x` in [0, 1].
+///
+/// * `x` - The value.
+fn smooth_step(x: f64) -> f64 {
+    x * x * (3.0 - 2.0 * x)
+}
+
+/// Spacing in nm of the wavelengths used for fitting.
+const FIT_LAMBDA_STEP: usize = 5;
+
+/// Precomputed integration tables for fitting sigmoid polynomials.
+struct SpectrumFit {
+    /// Wavelengths normalized to [0, 1].
+    lambda: Vec<f64>,
+
+    /// Integration weights of the CIE matching functions multiplied by the
+    /// illuminant, indexed by XYZ component and wavelength.
+    xyz: [Vec<f64>; 3],
+
+    /// XYZ of the illuminant.
+    white: [f64; 3],
+
+    /// The color space of the RGB values being fitted.
+    color_space: ColorSpace,
+}
+
+impl SpectrumFit {
+    /// Computes integration tables for the given color space; reflectances
+    /// are observed under illuminant D65.
+    ///
+    /// * `color_space` - The color space.
+    fn new(color_space: ColorSpace) -> Self {
+        let illuminant = cie_d_illuminant_samples(D65_CCT);
+        let range = (CIE_LAMBDA_END - CIE_LAMBDA_START) as f64;
+        let n = (CIE_SAMPLES - 1) / FIT_LAMBDA_STEP + 1;
+
+        let mut lambda = Vec::with_capacity(n);
+        let mut xyz = [vec![0.0; n], vec![0.0; n], vec![0.0; n]];
+        for i in 0..n {
+            let j = i * FIT_LAMBDA_STEP;
+            let l = (CIE_LAMBDA_START + j) as Float;
+            let w = if i == 0 || i == n - 1 { 0.5 } else { 1.0 } * FIT_LAMBDA_STEP as f64;
+            let illum = interpolate_spectrum_samples(&illuminant, l) as f64;
+            lambda.push((l as f64 - CIE_LAMBDA_START as f64) / range);
+            xyz[0][i] = CIE_X[j] as f64 * illum * w;
+            xyz[1][i] = CIE_Y[j] as f64 * illum * w;
+            xyz[2][i] = CIE_Z[j] as f64 * illum * w;
+        }
+
+        // Normalize so that the illuminant has luminance Y = 1.
+        let y_sum: f64 = xyz[1].iter().sum();
+        for c in xyz.iter_mut() {
+            c.iter_mut().for_each(|v| *v /= y_sum);
+        }
+        let white = [
+            xyz[0].iter().sum(),
+            xyz[1].iter().sum(),
+            xyz[2].iter().sum(),
+        ];
+
+        Self {
+            lambda,
+            xyz,
+            white,
+            color_space,
+        }
+    }
+
+    /// Returns the CIELAB value of an XYZ value relative to the illuminant.
+    ///
+    /// * `xyz` - The XYZ value.
+    fn lab(&self, xyz: &[f64; 3]) -> [f64; 3] {
+        let f = |t: f64| {
+            const DELTA: f64 = 6.0 / 29.0;
+            if t > DELTA * DELTA * DELTA {
+                t.cbrt()
+            } else {
+                t / (3.0 * DELTA * DELTA) + 4.0 / 29.0
+            }
+        };
+        let (fx, fy, fz) = (
+            f(xyz[0] / self.white[0]),
+            f(xyz[1] / self.white[1]),
+            f(xyz[2] / self.white[2]),
+        );
+        [116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz)]
+    }
+
+    /// Returns the CIELAB difference between the target RGB value and the
+    /// reflectance described by the coefficients.
+    ///
+    /// * `coeffs` - Coefficients over normalized wavelengths.
+    /// * `target` - CIELAB value of the target RGB.
+    fn residual(&self, coeffs: &[f64; 3], target: &[f64; 3]) -> [f64; 3] {
+        let mut xyz = [0.0; 3];
+        for (i, &l) in self.lambda.iter().enumerate() {
+            let x = (coeffs[0] * l + coeffs[1]) * l + coeffs[2];
+            let s = 0.5 + x / (2.0 * (1.0 + x * x).sqrt());
+            for (c, v) in xyz.iter_mut().enumerate() {
+                *v += self.xyz[c][i] * s;
+            }
+        }
+        let lab = self.lab(&xyz);
+        [target[0] - lab[0], target[1] - lab[1], target[2] - lab[2]]
+    }
+
+    /// Refines the coefficients so that the reflectance matches the RGB
+    /// value using Gauss-Newton iterations.
+    ///
+    /// * `rgb`    - The RGB value.
+    /// * `coeffs` - Initial coefficients over normalized wavelengths that are
+    ///              updated in place.
+    fn gauss_newton(&self, rgb: &[f64; 3], coeffs: &mut [f64; 3]) {
+        const ITERATIONS: usize = 15;
+        const EPS: f64 = 1e-4;
+
+        let xyz = self
+            .color_space
+            .rgb_to_xyz(&[rgb[0] as Float, rgb[1] as Float, rgb[2] as Float]);
+        let target = self.lab(&[xyz[0] as f64, xyz[1] as f64, xyz[2] as f64]);
+
+        for _ in 0..ITERATIONS {
+            let r = self.residual(coeffs, &target);
+
+            // Finite difference Jacobian.
+            let mut jac = [[0.0; 3]; 3];
+            for i in 0..3 {
+                let mut c0 = *coeffs;
+                let mut c1 = *coeffs;
+                c0[i] -= EPS;
+                c1[i] += EPS;
+                let r0 = self.residual(&c0, &target);
+                let r1 = self.residual(&c1, &target);
+                for j in 0..3 {
+                    jac[j][i] = (r1[j] - r0[j]) / (2.0 * EPS);
+                }
+            }
+
+            match solve_3x3(&jac, &r) {
+                Some(x) => {
+                    for i in 0..3 {
+                        coeffs[i] -= x[i];
+                    }
+                }
+                None => break,
+            }
+
+            // Keep the coefficients in a reasonable range.
+            let max_coeff = coeffs.iter().fold(0.0_f64, |m, c| m.max(c.abs()));
+            if max_coeff > 200.0 {
+                coeffs.iter_mut().for_each(|c| *c *= 200.0 / max_coeff);
+            }
+
+            if r.iter().map(|v| v * v).sum::<f64>() < 1e-6 {
+                break;
+            }
+        }
+    }
+}
+
+/// Solves the linear system `a x = b` using Cramer's rule; or returns `None`
+/// if `a` is singular.
+///
+/// * `a` - The matrix.
+/// * `b` - The right hand side.
+fn solve_3x3(a: &[[f64; 3]; 3], b: &[f64; 3]) -> Option<[f64; 3]> {
+    let det = |m: &[[f64; 3]; 3]| {
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    };
+
+    let d = det(a);
+    if d.abs() < 1e-15 {
+        return None;
+    }
+
+    let mut x = [0.0; 3];
+    for (i, xi) in x.iter_mut().enumerate() {
+        let mut m = *a;
+        for j in 0..3 {
+            m[j][i] = b[j];
+        }
+        *xi = det(&m) / d;
+    }
+    Some(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn uniform_rgb_is_constant() {
+        let table = RGBToSpectrumTable {
+            z_nodes: &[],
+            coeffs: &[],
+        };
+        let s = table.lookup(&[0.25, 0.25, 0.25]);
+        assert!((s.evaluate(400.0) - 0.25).abs() < 1e-5);
+        assert!((s.evaluate(700.0) - 0.25).abs() < 1e-5);
+    }
+
+    #[test]
+    fn fitted_reflectance_matches_rgb() {
+        let fit = SpectrumFit::new(ColorSpace::SRGB);
+        let rgb = [0.7, 0.3, 0.1];
+        let mut coeffs = [0.0; 3];
+        fit.gauss_newton(&rgb, &mut coeffs);
+
+        // Observe the reflectance under D65 and compare the RGB values.
+        let s = RGBSigmoidPolynomial {
+            c: to_nm_coefficients(&coeffs),
+        };
+        let mut xyz = [0.0; 3];
+        for (i, &l) in fit.lambda.iter().enumerate() {
+            let v = s.evaluate(l as Float * 470.0 + 360.0) as f64;
+            for c in 0..3 {
+                xyz[c] += fit.xyz[c][i] * v;
+            }
+        }
+        let out = ColorSpace::SRGB.xyz_to_rgb(&[xyz[0] as Float, xyz[1] as Float, xyz[2] as Float]);
+        for i in 0..3 {
+            assert!((out[i] - rgb[i] as Float).abs() < 1e-2);
+        }
+    }
+
+    #[test]
+    fn embedded_table_matches_the_fit() {
+        // The fit depends slightly on the precision of `Float`; compare the
+        // spectra the coefficients describe.
+        let (z_nodes, coeffs) = RGBToSpectrumTable::fit();
+        assert!(z_nodes
+            .iter()
+            .zip(RGB_TO_SPECTRUM_Z_NODES.iter())
+            .all(|(&a, &b)| (a - b).abs() < 1e-6));
+        assert_eq!(coeffs.len(), RGB_TO_SPECTRUM_COEFFS.len());
+        for (a, b) in coeffs.iter().zip(RGB_TO_SPECTRUM_COEFFS.iter()) {
+            let a = RGBSigmoidPolynomial { c: *a };
+            let b = RGBSigmoidPolynomial { c: *b };
+            for lambda in (CIE_LAMBDA_START..=CIE_LAMBDA_END).step_by(10) {
+                let lambda = lambda as Float;
+                assert!((a.evaluate(lambda) - b.evaluate(lambda)).abs() < 5e-3);
+            }
+        }
+    }
+
+    #[test]
+    #[ignore]
+    fn write_rgb_to_spectrum_table() {
+        use std::fmt::Write;
+
+        let (z_nodes, coeffs) = RGBToSpectrumTable::fit();
+        let mut src = String::from(
+            "//! RGB to Spectrum Coefficient Table\n\
+            //!\n\
+            //! Generated by `cargo test write_rgb_to_spectrum_table -- --ignored`;\n\
+            //! see `rgb_to_spectrum.rs`.\n\n\
+            use super::*;\n\
+            use crate::core::pbrt::*;\n\n",
+        );
+        let res = "RGB_TO_SPECTRUM_TABLE_RES";
+        writeln!(src, "/// Values of the largest component at each z-node.").unwrap();
+        writeln!(src, "#[rustfmt::skip]").unwrap();
+        writeln!(
+            src,
+            "pub static RGB_TO_SPECTRUM_Z_NODES: [Float; {}] = [",
+            res
+        )
+        .unwrap();
+        for z in z_nodes.iter() {
+            writeln!(src, "    {:?},", *z as f32).unwrap();
+        }
+        writeln!(src, "];\n").unwrap();
+        writeln!(
+            src,
+            "/// Sigmoid polynomial coefficients of the sRGB table."
+        )
+        .unwrap();
+        writeln!(src, "#[rustfmt::skip]").unwrap();
+        writeln!(
+            src,
+            "pub static RGB_TO_SPECTRUM_COEFFS: [[Float; 3]; 3 * {0} * {0} * {0}] = [",
+            res
+        )
+        .unwrap();
+        for c in coeffs.iter() {
+            writeln!(
+                src,
+                "    [{:?}, {:?}, {:?}],",
+                c[0] as f32, c[1] as f32, c[2] as f32
+            )
+            .unwrap();
+        }
+        writeln!(src, "];").unwrap();
+
+        let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("src/core/spectrum/rgb_to_spectrum_table.rs");
+        std::fs::write(path, src).unwrap();
+    }
+}
@@ -0,0 +1,12321 @@
+//! RGB to Spectrum Coefficient Table
+//!
+//! Generated by `cargo test write_rgb_to_spectrum_table -- --ignored`;
+//! see `rgb_to_spectrum.rs`.
+
+use super::*;
+use crate::core::pbrt::*;
+
+/// Values of the largest component at each z-node.
+#[rustfmt::skip]
+pub static RGB_TO_SPECTRUM_Z_NODES: [Float; RGB_TO_SPECTRUM_TABLE_RES] = [
+    0.0,
+    0.0004828431,
+    0.0068542426,
+    0.030198272,
+    0.08150949,
+    0.16679367,
+    0.28448358,
+    0.42536,
+    0.57464,
+    0.7155164,
+    0.8332063,
+    0.9184905,
+    0.9698017,
+    0.99314576,
+    0.99951714,
+    1.0,
+];
+
+/// Sigmoid polynomial coefficients of the sRGB table.
+#[rustfmt::skip]
+pub static RGB_TO_SPECTRUM_COEFFS: [[Float; 3]; 3 * RGB_TO_SPECTRUM_TABLE_RES * RGB_TO_SPECTRUM_TABLE_RES * RGB_TO_SPECTRUM_TABLE_RES] = [
+    [-0.0005676785, 0.8342604, -308.9419],
+    [-0.00062215485, 0.8734834, -310.66565],
+    [-0.0006905979, 0.92276245, -314.21823],
+    [-0.0007503429, 0.9657788, -317.67514],
+    [-0.00079827936, 1.000293, -320.6115],
+    [-0.0008366654, 1.027931, -323.05618],
+    [-0.00086783565, 1.0503736, -325.10095],
+    [-0.00089356303, 1.0688972, -326.82874],
+    [-0.00090538705, 1.0728534, -324.8142],
+    [-0.00090538705, 1.0645443, -319.6971],
+    [-0.00090538705, 1.0576714, -315.4826],
+    [-0.00090538705, 1.0518912, -311.95166],
+    [-0.00090538705, 1.0469612, -308.9505],
+    [-0.00090538705, 1.0427058, -306.368],
+    [-0.00090538705, 1.0389944, -304.12204],
+    [-0.00090538705, 1.0357286, -302.1506],
+    [0.00090538705, -0.71511984, -25.437622],
+    [-4.5906647e-5, 0.4585847, -346.99164],
+    [-0.00029776528, 0.6399229, -323.7418],
+    [-0.00038731593, 0.7043994, -316.76044],
+    [-0.00050218817, 0.7871074, -316.7932],
+    [-0.0006575014, 0.89893293, -321.70572],
+    [-0.00075243536, 0.96728534, -325.11905],
+    [-0.00081586273, 1.012953, -327.61447],
+    [-0.0008612796, 1.0456532, -329.53232],
+    [-0.0008954887, 1.0702838, -331.06287],
+    [-0.00090538705, 1.069608, -326.25546],
+    [-0.00090538705, 1.060047, -319.8434],
+    [-0.00090538705, 1.0524952, -314.81442],
+    [-0.00090538705, 1.0463703, -310.76218],
+    [-0.00090538705, 1.0412961, -307.42517],
+    [-0.00090538705, 1.0370189, -304.6278],
+    [0.00090538705, -0.99350667, 211.78659],
+    [0.00090538705, -0.8509022, 88.1712],
+    [0.00060469605, -0.29004216, -173.95343],
+    [8.037299e-5, 0.33302838, -330.30655],
+    [-0.00017251857, 0.54974526, -335.587],
+    [-0.00028654988, 0.63184786, -325.96204],
+    [-0.0005374849, 0.81252104, -328.0365],
+    [-0.00069474796, 0.92575043, -330.77817],
+    [-0.00078672153, 0.99197143, -332.66992],
+    [-0.00084715144, 1.035481, -334.08118],
+    [-0.00089000625, 1.0663364, -335.19147],
+    [-0.00090538705, 1.0697044, -330.02957],
+    [-0.00090538705, 1.0586898, -322.08932],
+    [-0.00090538705, 1.0502745, -316.06754],
+    [-0.00090538705, 1.0436263, -311.34366],
+    [-0.00090538705, 1.0382351, -307.53857],
+    [0.00090538705, -1.0319166, 245.35558],
+    [0.00090538705, -0.97889036, 199.1781],
+    [0.00090538705, -0.8851351, 116.45384],
+    [0.00090538705, -0.7006133, -39.488018],
+    [0.00048608604, -0.14828579, -209.61386],
+    [0.00014108121, 0.26187846, -312.56036],
+    [-9.382778e-5, 0.49308792, -343.6991],
+    [-0.00044765146, 0.74784094, -338.9058],
+    [-0.000663934, 0.9035644, -338.96396],
+    [-0.00077749806, 0.9853305, -339.3057],
+    [-0.00084763113, 1.0358263, -339.6928],
+    [-0.0008953884, 1.0702115, -340.0727],
+    [-0.00090538705, 1.0661013, -331.39902],
+    [-0.00090538705, 1.0546589, -322.58035],
+    [-0.00090538705, 1.0460815, -316.01556],
+    [-0.00090538705, 1.039405, -310.9403],
+    [0.00090538705, -1.0395577, 251.86139],
+    [0.00090538705, -1.0175179, 232.11119],
+    [0.00090538705, -0.97196627, 191.45547],
+    [0.00090538705, -0.9010438, 130.96193],
+    [0.00090538705, -0.78476447, 30.682411],
+    [0.0007755999, -0.49512756, -122.27183],
+    [0.0004244616, -0.0760186, -227.64352],
+    [0.00015149359, 0.24538445, -307.972],
+    [-0.00040608435, 0.7179127, -353.42142],
+    [-0.0006618418, 0.90205806, -348.79633],
+    [-0.0007863882, 0.9917314, -346.84738],
+    [-0.00086030085, 1.0449486, -345.86566],
+    [-0.00090538705, 1.0755441, -343.80978],
+    [-0.00090538705, 1.0598223, -330.774],
+    [-0.00090538705, 1.0487621, -321.66135],
+    [-0.00090538705, 1.0405474, -314.93576],
+    [0.00090538705, -1.0419745, 253.95813],
+    [0.00090538705, -1.0336078, 246.79317],
+    [0.00090538705, -1.0064967, 222.04202],
+    [0.00090538705, -0.96591914, 187.32164],
+    [0.00090538705, -0.9099399, 138.50716],
+    [0.00090538705, -0.82601213, 65.42938],
+    [0.00090538705, -0.69862235, -41.139965],
+    [0.00064096303, -0.3370904, -161.71626],
+    [0.00033254502, 0.021175388, -250.72098],
+    [-0.0004215358, 0.7290377, -369.58832],
+    [-0.0006862823, 0.9196552, -359.25397],
+    [-0.0008103343, 1.0089726, -354.68973],
+    [-0.00088252383, 1.0609491, -352.19357],
+    [-0.00090538705, 1.0662043, -341.4355],
+    [-0.00090538705, 1.0517914, -328.61966],
+    [-0.00090538705, 1.0416756, -319.6766],
+    [0.00090538705, -1.043417, 255.21939],
+    [0.00090538705, -1.0395327, 251.83621],
+    [0.00090538705, -1.0246971, 238.2937],
+    [0.00090538705, -0.9979867, 214.5846],
+    [0.00090538705, -0.9622593, 184.17212],
+    [0.00090538705, -0.9157386, 142.37947],
+    [0.00090538705, -0.850999, 87.49687],
+    [0.00090538705, -0.7583419, 8.579471],
+    [0.00083060184, -0.56357175, -104.76018],
+    [0.00024396677, 0.069923, -256.79037],
+    [-0.00048637117, 0.77571917, -385.02808],
+    [-0.00073112187, 0.95193964, -369.3792],
+    [-0.00084527105, 1.0341271, -362.3229],
+    [-0.00090538705, 1.0745428, -355.9554],
+    [-0.00090538705, 1.0553434, -337.43613],
+    [-0.00090538705, 1.042798, -325.39648],
+    [0.00090538705, -1.0441604, 255.86237],
+    [0.00090538705, -1.0417589, 253.742],
+    [0.00090538705, -1.0357533, 247.84879],
+    [0.00090538705, -1.0159365, 230.72156],
+    [0.00090538705, -0.9914017, 208.8846],
+    [0.00090538705, -0.96021456, 181.31062],
+    [0.00090538705, -0.9197927, 145.98425],
+    [0.00090538705, -0.86598736, 98.68524],
+    [0.00090538705, -0.7936534, 37.225136],
+    [0.00090538705, -0.6893316, -52.30553],
+    [8.2026374e-5, 0.19750622, -281.73285],
+    [-0.0005819486, 0.84453493, -398.02313],
+    [-0.0007885619, 0.99329644, -378.4909],
+    [-0.0008869927, 1.0641667, -369.38907],
+    [-0.00090538705, 1.0596936, -349.02258],
+    [-0.00090538705, 1.0439211, -332.44733],
+    [0.00090538705, -1.0450095, 256.58054],
+    [0.00090538705, -1.0435337, 255.21266],
+    [0.00090538705, -1.040711, 252.19412],
+    [0.00090538705, -1.0281622, 241.28162],
+    [0.00090538705, -1.0096794, 224.59824],
+    [0.00090538705, -0.9861627, 204.39435],
+    [0.00090538705, -0.9585976, 179.0889],
+    [0.00090538705, -0.9229251, 147.7204],
+    [0.00090538705, -0.8773578, 108.308044],
+    [0.00090538705, -0.81769323, 56.658833],
+    [0.0008140683, -0.6066496, -87.1094],
+    [-0.0001221598, 0.37208307, -318.11798],
+    [-0.00068890536, 0.9215438, -407.94223],
+    [-0.000851451, 1.0385767, -386.25275],
+    [-0.00090538705, 1.0653247, -365.02597],
+    [-0.00090538705, 1.0450485, -341.40002],
+    [0.00090538705, -1.0455457, 257.00497],
+    [0.00090538705, -1.0444514, 255.94331],
+    [0.00090538705, -1.0429419, 254.09833],
+    [0.00090538705, -1.0364425, 248.38852],
+    [0.00090538705, -1.0213038, 235.28654],
+    [0.00090538705, -1.0036901, 219.48251],
+    [0.00090538705, -0.9826467, 200.54584],
+    [0.00090538705, -0.95694244, 177.66718],
+    [0.00090538705, -0.9252728, 149.7299],
+    [0.00090538705, -0.88545436, 113.96343],
+    [0.00090538705, -0.83351296, 67.49903],
+    [0.0004978019, -0.3152466, -151.02634],
+    [-0.00034791057, 0.5722758, -360.93008],
+    [-0.00079352205, 0.9968678, -414.97174],
+    [-0.00090538705, 1.0731881, -388.7144],
+    [-0.00090538705, 1.0461832, -353.20282],
+    [0.00090538705, -1.0460546, 257.3425],
+    [0.00090538705, -1.0451524, 256.51483],
+    [0.00090538705, -1.0446949, 255.57805],
+    [0.00090538705, -1.0408052, 252.16443],
+    [0.00090538705, -1.0302736, 243.05711],
+    [0.00090538705, -1.0162666, 230.2225],
+    [0.00090538705, -0.99959713, 215.11604],
+    [0.00090538705, -0.97987324, 197.24821],
+    [0.00090538705, -0.9560261, 176.06998],
+    [0.00090538705, -0.92734665, 150.2991],
+    [0.00090538705, -0.89186364, 118.401146],
+    [0.00090538705, -0.8236264, 25.940802],
+    [0.00017783111, -0.016342783, -217.16351],
+    [-0.000581785, 0.78401256, -406.84518],
+    [-0.0008885422, 1.0652823, -419.67105],
+    [-0.00090538705, 1.0473273, -369.56796],
+    [0.00090538705, -1.0466653, 257.69604],
+    [0.00090538705, -1.0459766, 257.1252],
+    [0.00090538705, -1.0456488, 256.39142],
+    [0.00090538705, -1.0430117, 254.09117],
+    [0.00090538705, -1.0368929, 248.75618],
+    [0.00090538705, -1.0255822, 238.23772],
+    [0.00090538705, -1.0119985, 225.78662],
+    [0.00090538705, -0.995328, 211.43192],
+    [0.00090538705, -0.9768852, 194.69238],
+    [0.00090538705, -0.9553269, 174.65411],
+    [0.00090538705, -0.929124, 150.57774],
+    [0.00090538705, -0.8955796, 111.71365],
+    [0.0007503113, -0.65858227, -60.150734],
+    [-0.00013443721, 0.277963, -282.64362],
+    [-0.00081513304, 0.998107, -453.67728],
+    [-0.00090538705, 1.0484821, -393.9119],
+    [0.00090538705, -1.0473738, 258.0909],
+    [0.00090538705, -1.0470463, 257.6912],
+    [0.00090538705, -1.0462251, 256.8749],
+    [0.00090538705, -1.0444361, 255.33406],
+    [0.00090538705, -1.0407975, 252.13388],
+    [0.00090538705, -1.0327338, 244.42268],
+    [0.00090538705, -1.0206118, 233.83632],
+    [0.00090538705, -1.0075477, 221.86758],
+    [0.00090538705, -0.9924617, 208.1171],
+    [0.00090538705, -0.9750534, 192.17882],
+    [0.00090538705, -0.9549123, 173.23366],
+    [0.00090538705, -0.9309734, 149.5116],
+    [0.00090538705, -0.89612234, 73.67747],
+    [0.00034710215, -0.26791796, -148.53397],
+    [-0.00043290606, 0.56101507, -345.8608],
+    [-0.00090538705, 1.0496477, -434.2055],
+    [0.00090538705, -1.0480667, 258.476],
+    [0.00090538705, -1.0477054, 258.07593],
+    [0.00090538705, -1.0468835, 257.41318],
+    [0.00090538705, -1.0454975, 256.2538],
+    [0.00090538705, -1.0431551, 254.1816],
+    [0.00090538705, -1.0376754, 249.1033],
+    [0.00090538705, -1.0278533, 240.1647],
+    [0.00090538705, -1.0170093, 229.9858],
+    [0.00090538705, -1.0043293, 218.39334],
+    [0.00090538705, -0.98998433, 205.16478],
+    [0.00090538705, -0.9738061, 189.77626],
+    [0.00090538705, -0.95517325, 171.2531],
+    [0.00090538705, -0.9372575, 132.29445],
+    [0.00086715445, -0.8586726, -3.2610614],
+    [-2.5343388e-5, 0.09391887, -230.52629],
+    [-0.00071457704, 0.82935673, -405.95923],
+    [0.00090538705, -1.0485756, 258.763],
+    [0.00090538705, -1.0481118, 258.3463],
+    [0.00090538705, -1.0474033, 257.82053],
+    [0.00090538705, -1.0461527, 256.80634],
+    [0.00090538705, -1.04449, 255.33931],
+    [0.00090538705, -1.0409755, 252.12863],
+    [0.00090538705, -1.0338274, 245.28073],
+    [0.00090538705, -1.0244316, 236.45297],
+    [0.00090538705, -1.0138049, 226.4997],
+    [0.00090538705, -1.0016061, 215.25668],
+    [0.00090538705, -0.9882348, 202.40489],
+    [0.00090538705, -0.9731383, 187.3632],
+    [0.00090538705, -0.9598946, 164.00983],
+    [0.00090538705, -0.9493081, 97.8109],
+    [0.0004100062, -0.40613255, -106.929085],
+    [-0.00036766104, 0.42719343, -306.14078],
+    [0.00090538705, -1.0489786, 258.97845],
+    [0.00090538705, -1.0484663, 258.58997],
+    [0.00090538705, -1.0477332, 258.06836],
+    [0.00090538705, -1.0467013, 257.25427],
+    [0.00090538705, -1.0457623, 256.38135],
+    [0.00090538705, -1.0433396, 254.20451],
+    [0.00090538705, -1.0386697, 249.44907],
+    [0.00090538705, -1.0305046, 241.72255],
+    [0.00090538705, -1.0214293, 233.0579],
+    [0.00090538705, -1.0109183, 223.3226],
+    [0.00090538705, -0.9993836, 212.38489],
+    [0.00090538705, -0.9869853, 199.79135],
+    [0.00090538705, -0.97454023, 183.91483],
+    [0.00090538705, -0.9742705, 144.73366],
+    [0.0008763456, -0.94293225, 25.881227],
+    [-2.7038377e-7, 0.000292632, -200.07031],
+    [-0.0005476757, 0.81985843, -307.43732],
+    [-0.0006088474, 0.86390203, -309.4503],
+    [-0.0006817541, 0.9163948, -313.2069],
+    [-0.0007438921, 0.9611342, -316.76083],
+    [-0.00079333514, 0.9967332, -319.74896],
+    [-0.0008327718, 1.0251276, -322.22308],
+    [-0.0008647213, 1.0481312, -324.28476],
+    [-0.00089105, 1.0670879, -326.02176],
+    [-0.00090538705, 1.073806, -324.73965],
+    [-0.00090538705, 1.0653071, -319.4678],
+    [-0.00090538705, 1.0582799, -315.12433],
+    [-0.00090538705, 1.0523722, -311.4844],
+    [-0.00090538705, 1.0473357, -308.39005],
+    [-0.00090538705, 1.0429903, -305.727],
+    [-0.00090538705, 1.0392023, -303.41077],
+    [-0.00090538705, 1.0358704, -301.37753],
+    [0.00090538705, -0.839164, 130.452],
+    [0.00039146855, -0.17914636, -79.09917],
+    [-1.6483002e-5, 0.2886555, -204.5841],
+    [-0.00027787097, 0.5826453, -281.67535],
+    [-0.00049936847, 0.7850772, -316.46982],
+    [-0.00065536815, 0.897397, -321.34805],
+    [-0.0007506787, 0.9660206, -324.72137],
+    [-0.0008143894, 1.0118922, -327.17932],
+    [-0.0008600397, 1.0447605, -329.063],
+    [-0.00089444895, 1.0695351, -330.56244],
+    [-0.00090538705, 1.0700128, -326.034],
+    [-0.00090538705, 1.0603772, -319.5266],
+    [-0.00090538705, 1.0527593, -314.4123],
+    [-0.00090538705, 1.0465755, -310.2834],
+    [-0.00090538705, 1.041449, -306.87732],
+    [-0.00090538705, 1.0371249, -304.01743],
+    [0.00090538705, -0.957556, 217.05043],
+    [0.00090538705, -0.8855927, 163.65541],
+    [0.00061109255, -0.51498073, 48.40125],
+    [0.00031356278, -0.17174268, -44.83134],
+    [9.1031216e-5, 0.08187793, -112.98239],
+    [-0.0001237368, 0.31996882, -175.78499],
+    [-0.00042317415, 0.64041054, -258.64868],
+    [-0.0006938529, 0.925106, -330.5393],
+    [-0.0007859543, 0.991419, -332.38718],
+    [-0.00084649335, 1.0350071, -333.7582],
+    [-0.0008894463, 1.0659332, -334.8316],
+    [-0.00090538705, 1.0699248, -329.80295],
+    [-0.00090538705, 1.0588702, -321.78864],
+    [-0.00090538705, 1.0504175, -315.69833],
+    [-0.00090538705, 1.0437346, -310.9111],
+    [-0.00090538705, 1.0383111, -307.0473],
+    [0.00090538705, -0.98380744, 236.21602],
+    [0.00090538705, -0.94907546, 210.22713],
+    [0.00090538705, -0.899839, 172.91298],
+    [0.00063109293, -0.57719475, 82.45972],
+    [0.0004052769, -0.31708887, 11.7141695],
+    [0.00021817173, -0.104242, -45.653442],
+    [2.5136487e-5, 0.10959525, -102.31471],
+    [-0.00022844858, 0.38198802, -173.15154],
+    [-0.00046926687, 0.6388739, -239.64209],
+    [-0.00068457425, 0.8677762, -298.73218],
+    [-0.0008472639, 1.0355619, -339.4447],
+    [-0.0008950757, 1.0699863, -339.78824],
+    [-0.00090538705, 1.0662237, -331.1702],
+    [-0.00090538705, 1.0547575, -322.29044],
+    [-0.00090538705, 1.0461571, -315.66815],
+    [-0.00090538705, 1.0394584, -310.5388],
+    [0.00090538705, -0.98964524, 240.38991],
+    [0.00090538705, -0.9740122, 228.49658],
+    [0.00090538705, -0.94480664, 206.23361],
+    [0.0008306, -0.83207107, 162.53706],
+    [0.00060593075, -0.5724976, 91.69388],
+    [0.00042462288, -0.3644831, 35.197994],
+    [0.00025794096, -0.17642193, -15.323802],
+    [7.406106e-5, 0.025651459, -68.71209],
+    [-0.00015111604, 0.26735333, -131.63103],
+    [-0.00035933123, 0.48986685, -189.382],
+    [-0.00054694386, 0.68986094, -241.19121],
+    [-0.0007156924, 0.86939114, -287.6247],
+    [-0.0008676028, 1.0307386, -329.29404],
+    [-0.00090538705, 1.0598898, -330.54486],
+    [-0.00090538705, 1.0488142, -321.37976],
+    [-0.00090538705, 1.0405842, -314.60428],
+    [0.00090538705, -0.99263775, 242.48236],
+    [0.00090538705, -0.98606956, 237.30103],
+    [0.00090538705, -0.9670118, 222.65714],
+    [0.00090538705, -0.94233304, 203.44688],
+    [0.0007438513, -0.7487378, 147.07922],
+    [0.0005671844, -0.54527605, 91.645996],
+    [0.00041237628, -0.36890054, 43.92142],
+    [0.0002565296, -0.19496684, -2.5315762],
+    [7.316453e-5, 0.004426333, -54.910374],
+    [-0.00012599416, 0.21801524, -110.52155],
+    [-0.0003076038, 0.41226533, -161.00842],
+    [-0.00047215458, 0.5879434, -206.60863],
+    [-0.0006211972, 0.7468315, -247.80461],
+    [-0.0007563605, 0.89074665, -285.0803],
+    [-0.00087915966, 1.0213577, -318.8771],
+    [-0.00090538705, 1.0417006, -319.4015],
+    [0.00090538705, -0.9948707, 244.02635],
+    [0.00090538705, -0.99074566, 240.6632],
+    [0.00090538705, -0.98013115, 232.32063],
+    [0.00090538705, -0.96238536, 218.32419],
+    [0.0008449103, -0.8780165, 187.78404],
+    [0.0006726875, -0.6793607, 133.60527],
+    [0.0005234216, -0.5086224, 87.273026],
+    [0.000380973, -0.348192, 44.15709],
+    [0.00022946222, -0.18107828, -0.16479291],
+    [4.872924e-5, 0.0138240615, -51.11244],
+    [-0.00012628413, 0.20146944, -99.98303],
+    [-0.00028593637, 0.37232813, -144.43027],
+    [-0.0004312725, 0.527655, -184.80092],
+    [-0.0005636561, 0.66898656, -221.50583],
+    [-0.00068442314, 0.79780084, -254.93636],
+    [-0.000794797, 0.91543853, -285.44583],
+    [0.00090538705, -0.9965428, 245.1635],
+    [0.00090538705, -0.99334264, 242.48943],
+    [0.00090538705, -0.9879626, 238.0436],
+    [0.00090538705, -0.97524965, 227.84996],
+    [0.00090538705, -0.95906657, 214.91087],
+    [0.0007529283, -0.7817009, 165.74971],
+    [0.00060842815, -0.61603427, 120.73136],
+    [0.00047484864, -0.4646796, 79.89315],
+    [0.00033779693, -0.31226555, 39.247303],
+    [0.00018272697, -0.14345793, -5.158463],
+    [1.5254531e-5, 0.036489293, -52.0981],
+    [-0.00013931043, 0.20219783, -95.26762],
+    [-0.00028064297, 0.35352236, -134.66046],
+    [-0.00040985222, 0.4917312, -170.61786],
+    [-0.0005281154, 0.61813337, -203.48679],
+    [-0.00063654035, 0.733946, -233.58804],
+    [0.00090538705, -0.9983425, 246.40074],
+    [0.00090538705, -0.99566936, 244.11575],
+    [0.00090538705, -0.99208504, 240.98767],
+    [0.00090538705, -0.9841067, 234.38873],
+    [0.00090538705, -0.97140414, 224.15651],
+    [0.0008148675, -0.86115134, 190.84825],
+    [0.0006746648, -0.700213, 147.09433],
+    [0.0005472322, -0.5552846, 107.902435],
+    [0.00041929792, -0.41223004, 69.618515],
+    [0.000283523, -0.26307106, 30.145538],
+    [0.00012819945, -0.095551275, -13.660817],
+    [-2.1290512e-5, 0.06494035, -55.51224],
+    [-0.00015856851, 0.21212904, -93.86949],
+    [-0.00028447193, 0.34699905, -129.0005],
+    [-0.00040003183, 0.4707038, -161.2116],
+    [-0.00050625496, 0.5843522, -190.79494],
+    [0.00090538705, -0.999679, 247.29929],
+    [0.00090538705, -0.9972639, 245.1953],
+    [0.00090538705, -0.99459505, 242.71747],
+    [0.00090538705, -0.9900318, 238.73257],
+    [0.00090538705, -0.9803023, 230.79335],
+    [0.0008636426, -0.92405987, 210.83858],
+    [0.00072744105, -0.76763296, 168.31769],
+    [0.00060501095, -0.628044, 130.5206],
+    [0.0004843785, -0.49256232, 94.16721],
+    [0.00035992175, -0.35499695, 57.619946],
+    [0.00021954475, -0.20274568, 17.661001],
+    [7.5329095e-5, -0.047717847, -22.797136],
+    [-5.7957823e-5, 0.09535018, -60.107983],
+    [-0.00018055373, 0.2268285, -94.38426],
+    [-0.00029335223, 0.34772474, -125.89419],
+    [-0.00039726734, 0.45904848, -154.90376],
+    [0.00090538705, -1.0007324, 247.98938],
+    [0.00090538705, -0.9989058, 246.30495],
+    [0.00090538705, -0.9969586, 244.34134],
+    [0.00090538705, -0.9936683, 241.35512],
+    [0.00090538705, -0.9869605, 235.73149],
+    [0.0009025003, -0.97447616, 226.9658],
+    [0.0007703885, -0.8227073, 185.73001],
+    [0.0006516648, -0.68720025, 149.02837],
+    [0.00053726684, -0.55824506, 114.35384],
+    [0.0004200138, -0.42813712, 79.70666],
+    [0.0002927868, -0.28933233, 43.13929],
+    [0.00015548132, -0.14144246, 4.498833],
+    [2.60759e-5, -0.002414068, -31.77706],
+    [-9.32765e-5, 0.1257053, -65.19733],
+    [-0.00020332905, 0.24377511, -95.99171],
+    [-0.00030491178, 0.3527149, -124.40247],
+    [0.00090538705, -1.0017647, 248.66286],
+    [0.00090538705, -1.0004728, 247.35251],
+    [0.00090538705, -0.99870497, 245.50381],
+    [0.00090538705, -0.9960521, 243.0344],
+    [0.00090538705, -0.99179226, 239.27867],
+    [0.00090538705, -0.984434, 232.80995],
+    [0.00080562837, -0.86810035, 200.155],
+    [0.00069034443, -0.73644155, 164.50026],
+    [0.00058078644, -0.6126314, 131.1676],
+    [0.00046878026, -0.48802382, 97.93804],
+    [0.0003516872, -0.35961628, 64.001205],
+    [0.00022265068, -0.22023925, 27.522606],
+    [9.702073e-5, -0.08516117, -7.7354007],
+    [-1.9183151e-5, 0.039675128, -40.312702],
+    [-0.00012654474, 0.15495236, -70.39361],
+    [-0.00022581547, 0.2615059, -98.19827],
+    [0.00090538705, -1.0028865, 249.40018],
+    [0.00090538705, -1.0017073, 248.14153],
+    [0.00090538705, -1.0002203, 246.50085],
+    [0.00090538705, -0.9981491, 244.50037],
+    [0.00090538705, -0.99509615, 241.6527],
+    [0.00090538705, -0.9898237, 236.82124],
+    [0.00083458016, -0.90562105, 212.15791],
+    [0.00072265265, -0.77775514, 177.54419],
+    [0.0006166293, -0.65779626, 145.23749],
+    [0.00050945434, -0.53828037, 113.32681],
+    [0.00039962895, -0.41738057, 81.30134],
+    [0.00027904724, -0.28669822, 47.028652],
+    [0.00015743656, -0.1558254, 12.854779],
+    [4.427346e-5, -0.034175836, -18.899954],
+    [-6.0472143e-5, 0.0783705, -48.27869],
+    [-0.00015747618, 0.1825683, -75.48041],
+    [0.00090538705, -1.0039825, 250.11435],
+    [0.00090538705, -1.0028168, 248.82632],
+    [0.00090538705, -1.001776, 247.53976],
+    [0.00090538705, -0.9999252, 245.72466],
+    [0.00090538705, -0.9975404, 243.36136],
+    [0.00090538705, -0.99398184, 239.88737],
+    [0.00085828034, -0.93658566, 222.14886],
+    [0.00074962963, -0.8124669, 188.57446],
+    [0.0006467907, -0.69602257, 157.2144],
+    [0.00054366904, -0.5808237, 126.43253],
+    [0.00043935934, -0.46566913, 95.880684],
+    [0.00032630767, -0.34274298, 63.57863],
+    [0.00020924723, -0.21661253, 30.62333],
+    [9.9015575e-5, -0.09804679, -0.33234322],
+    [-3.1981663e-6, 0.011844635, -29.025272],
+    [-9.799409e-5, 0.11373573, -55.633343],
+    [0.00090538705, -1.0049266, 250.70854],
+    [0.00090538705, -1.0039393, 249.51553],
+    [0.00090538705, -1.0031164, 248.42468],
+    [0.00090538705, -1.0013338, 246.66827],
+    [0.00090538705, -0.9995455, 244.72687],
+    [0.00090538705, -0.9970047, 242.07515],
+    [0.00087740115, -0.9618469, 230.39352],
+    [0.0007721475, -0.84166414, 197.92491],
+    [0.0006723785, -0.72863406, 167.49072],
+    [0.00057292596, -0.6173723, 137.7451],
+    [0.00047274912, -0.5065751, 108.323135],
+    [0.00036615814, -0.39032295, 77.72114],
+    [0.0002539242, -0.26921076, 46.052517],
+    [0.00014652392, -0.15363191, 15.872911],
+    [4.675476e-5, -0.046312798, -12.153229],
+    [-4.5897847e-5, 0.053330425, -38.180435],
+    [0.00090538705, -1.0057641, 251.21329],
+    [0.00090538705, -1.005107, 250.2418],
+    [0.00090538705, -1.0041983, 249.12022],
+    [0.00090538705, -1.0027266, 247.59671],
+    [0.00090538705, -1.0014484, 246.01466],
+    [0.00090538705, -0.9994106, 243.78622],
+    [0.00089186296, -0.9813295, 236.87666],
+    [0.0007908976, -0.86619836, 205.85434],
+    [0.0006940549, -0.75645965, 176.32173],
+    [0.0005978413, -0.64870894, 147.50847],
+    [0.0005010175, -0.5414884, 119.023384],
+    [0.0003998738, -0.43088645, 89.865974],
+    [0.00029256954, -0.31489164, 59.50741],
+    [0.00018795776, -0.20225784, 30.093874],
+    [9.054711e-5, -0.09742729, 2.714529],
+    [-2.9746076e-8, 3.2112315e-5, -22.746605],
+    [-0.0005039251, 0.7720728, -294.1413],
+    [-0.0005600464, 0.8013474, -288.67105],
+    [-0.00063939573, 0.8632573, -295.86426],
+    [-0.0007144902, 0.925734, -305.5408],
+    [-0.0007777322, 0.9790378, -314.28802],
+    [-0.0008296306, 1.022725, -321.51874],
+    [-0.0008624114, 1.0464681, -323.6957],
+    [-0.00088917493, 1.0657378, -325.43677],
+    [-0.00090538705, 1.0745249, -324.70187],
+    [-0.00090538705, 1.0658929, -319.31235],
+    [-0.00090538705, 1.0587535, -314.86777],
+    [-0.00090538705, 1.0527505, -311.14047],
+    [-0.00090538705, 1.0476325, -307.97003],
+    [-0.00090538705, 1.0432168, -305.2404],
+    [-0.00090538705, 1.039368, -302.86557],
+    [-0.00090538705, 1.0359833, -300.78055],
+    [0.0004894849, -0.46979594, 90.2435],
+    [0.00020713126, -0.15343918, 5.8171535],
+    [5.2631873e-5, 0.01793001, -39.375774],
+    [-5.28852e-5, 0.1332056, -69.30229],
+    [-0.00015092106, 0.23802672, -96.10742],
+    [-0.00024912125, 0.34149852, -122.3404],
+    [-0.0003346446, 0.43090814, -144.85583],
+    [-0.0004077714, 0.5068792, -163.8674],
+    [-0.00047019782, 0.5713729, -179.90797],
+    [-0.0005236145, 0.6262714, -193.47781],
+    [-0.0005694806, 0.6731725, -204.99731],
+    [-0.00060900464, 0.7133866, -214.80905],
+    [-0.0006431777, 0.7479809, -223.1906],
+    [-0.00067281246, 0.7778255, -230.36737],
+    [-0.0006985763, 0.80363196, -236.5231],
+    [-0.0007210216, 0.8259868, -241.80876],
+    [0.0006842622, -0.7135608, 165.80167],
+    [0.00041028133, -0.4058879, 83.44699],
+    [0.00024827666, -0.22442766, 34.93482],
+    [0.000137186, -0.10073927, 2.0384402],
+    [5.0419185e-5, -0.005103028, -23.190554],
+    [-2.9569668e-5, 0.08170247, -45.843365],
+    [-0.00011186176, 0.16966785, -68.5799],
+    [-0.00018674841, 0.24918254, -89.03055],
+    [-0.00025276933, 0.3189734, -106.91212],
+    [-0.0003107341, 0.38002607, -122.50116],
+    [-0.00036166247, 0.43349662, -136.10947],
+    [-0.0004065083, 0.48044324, -148.01888],
+    [-0.00044610508, 0.52178025, -158.47122],
+    [-0.000481164, 0.55828214, -167.67047],
+    [-0.0005122867, 0.59060055, -175.78764],
+    [-0.0005399831, 0.61928576, -182.9668],
+    [0.00074146397, -0.78780055, 189.78226],
+    [0.00050207047, -0.52062285, 118.97138],
+    [0.00034597685, -0.3456724, 72.179405],
+    [0.0002363054, -0.22310394, 39.4396],
+    [0.00015157118, -0.12896024, 14.395264],
+    [7.943366e-5, -0.049567882, -6.5854406],
+    [9.867547e-6, 0.025939545, -26.357128],
+    [-6.026522e-5, 0.101148926, -45.89751],
+    [-0.00012455354, 0.16972879, -63.649174],
+    [-0.00018203439, 0.23084356, -79.42705],
+    [-0.00023326353, 0.28516832, -93.420044],
+    [-0.0002789492, 0.333506, -105.84459],
+    [-0.0003197687, 0.37660846, -116.90094],
+    [-0.00035632568, 0.41513887, -126.76461],
+    [-0.00038914458, 0.4496693, -135.58647],
+    [-0.00041867766, 0.48069072, -143.4956],
+    [0.0007546264, -0.8078799, 197.32133],
+    [0.0005484857, -0.5795262, 137.52686],
+    [0.0004026681, -0.41641662, 94.048615],
+    [0.00029686643, -0.29811382, 62.445698],
+    [0.00021468155, -0.20655952, 38.03259],
+    [0.00014581032, -0.13035206, 17.798264],
+    [8.3367864e-5, -0.06192654, -0.2548546],
+    [2.1035801e-5, 0.0055091185, -17.900196],
+    [-3.9850598e-5, 0.07082217, -34.89583],
+    [-9.5449665e-5, 0.13024077, -50.317856],
+    [-0.00014555576, 0.18365672, -64.156364],
+    [-0.00019064134, 0.23162688, -76.56443],
+    [-0.00023124897, 0.27476174, -87.705666],
+    [-0.00026789153, 0.31362867, -97.7307],
+    [-0.00030102782, 0.34873065, -106.772415],
+    [-0.0003310604, 0.3805064, -114.94634],
+    [0.00075444987, -0.8113772, 199.75957],
+    [0.0005695384, -0.6073396, 146.67522],
+    [0.00043762766, -0.46047667, 107.821945],
+    [0.00033675504, -0.34777555, 77.76496],
+    [0.00025747658, -0.25939688, 54.195152],
+    [0.00019126674, -0.18595645, 34.66127],
+    [0.00013245414, -0.12120913, 17.518764],
+    [7.618793e-5, -0.059906, 1.3951111],
+    [1.9933608e-5, 0.0007472116, -14.451242],
+    [-3.3297005e-5, 0.057832092, -29.313896],
+    [-8.180693e-5, 0.10971975, -42.800274],
+    [-0.00012577209, 0.15666035, -54.98517],
+    [-0.00016561108, 0.1991341, -65.99844],
+    [-0.00020175934, 0.23762645, -75.969345],
+    [-0.0002346205, 0.27258185, -85.01543],
+    [-0.00026455568, 0.304395, -93.24067],
+    [0.00074856315, -0.8076413, 199.92252],
+    [0.0005789173, -0.6208237, 151.47995],
+    [0.00045878405, -0.487634, 116.48338],
+    [0.00036404733, -0.38201785, 88.4184],
+    [0.0002878876, -0.29713598, 65.80249],
+    [0.00022413753, -0.2263517, 46.96867],
+    [0.0001679098, -0.16430238, 30.51539],
+    [0.00011555359, -0.107001066, 15.3975725],
+    [6.3731044e-5, -0.050862342, 0.68198407],
+    [1.3275245e-5, 0.0034089258, -13.480542],
+    [-3.3486154e-5, 0.0535452, -26.53816],
+    [-7.6146905e-5, 0.09920172, -38.415623],
+    [-0.000114996255, 0.14072366, -49.208015],
+    [-0.00015040024, 0.1785231, -59.02545],
+    [-0.0001827153, 0.21299417, -67.97228],
+    [-0.00021226735, 0.24449405, -76.1426],
+    [0.0007397132, -0.8000576, 198.8404],
+    [0.0005823768, -0.6270076, 154.05864],
+    [0.00047091296, -0.5037557, 121.8137],
+    [0.00038287666, -0.40591758, 95.94847],
+    [0.0003100884, -0.32487002, 74.394165],
+    [0.0002486974, -0.25668952, 56.261543],
+    [0.00019465452, -0.1969766, 40.41991],
+    [0.0001449794, -0.1424657, 26.01523],
+    [9.671916e-5, -0.08998668, 12.224784],
+    [4.9197773e-5, -0.038722817, -1.1793628],
+    [4.2558245e-6, 0.0095541505, -13.770358],
+    [-3.705244e-5, 0.053840958, -25.30786],
+    [-7.48346e-5, 0.094295464, -35.839302],
+    [-0.00010939069, 0.13126013, -45.456703],
+    [-0.00014103546, 0.16508469, -54.252758],
+    [-0.00017006439, 0.19609374, -62.31298],
+    [0.00072934135, -0.7904121, 197.06024],
+    [0.0005824007, -0.6289262, 155.32585],
+    [0.00047785524, -0.5135274, 125.22349],
+    [0.00039549265, -0.4222389, 101.19499],
+    [0.0003264908, -0.3455437, 80.86009],
+    [0.00026742768, -0.27996898, 63.43875],
+    [0.00021535784, -0.22240305, 48.168663],
+    [0.00016777088, -0.17010319, 34.33857],
+    [0.0001222719, -0.12048772, 21.278837],
+    [7.743595e-5, -0.07199434, 8.578491],
+    [3.439531e-5, -0.025678754, -3.5145843],
+    [-5.566815e-6, 0.017223852, -14.702248],
+    [-4.2269465e-5, 0.056576833, -24.957758],
+    [-7.594367e-5, 0.09265036, -34.354477],
+    [-0.00010686614, 0.12575372, -42.974487],
+    [-0.0001353056, 0.15618278, -50.89574],
+    [0.00071809103, -0.77951574, 194.83524],
+    [0.00058028934, -0.6281691, 155.76105],
+    [0.00048155498, -0.519319, 127.42544],
+    [0.00040376378, -0.43326432, 104.84679],
+    [0.00033854353, -0.36092705, 85.73536],
+    [0.00028187686, -0.29806465, 69.06264],
+    [0.00023165313, -0.24253362, 54.340767],
+    [0.00018584217, -0.19214195, 41.0134],
+    [0.00014250579, -0.14479373, 28.53829],
+    [0.000100005425, -0.09872468, 16.457657],
+    [5.8857877e-5, -0.054372016, 4.865865],
+    [2.0226045e-5, -0.012849608, -5.9693794],
+    [-1.5414938e-5, 0.025406964, -15.946393],
+    [-4.8207723e-5, 0.060576357, -25.115177],
+    [-7.8393095e-5, 0.092929706, -33.547848],
+    [-0.00010621571, 0.12273708, -41.315544],
+    [0.0007065835, -0.7681445, 192.40538],
+    [0.00057677634, -0.62563515, 155.63606],
+    [0.00048308715, -0.5224408, 128.81358],
+    [0.00040914828, -0.4407674, 107.43635],
+    [0.00034721874, -0.37220776, 89.37882],
+    [0.00029305316, -0.31219867, 73.500084],
+    [0.00024461115, -0.2586519, 59.31796],
+    [0.00020039074, -0.20998934, 46.45104],
+    [0.00015882179, -0.16451505, 34.4642],
+    [0.00011832823, -0.120542124, 22.92266],
+    [7.895016e-5, -0.07803237, 11.803686],
+    [4.1638865e-5, -0.037887044, 1.322089],
+    [7.0289493e-6, -0.0007037631, -8.379892],
+    [-2.4900048e-5, 0.033570904, -17.320606],
+    [-5.435313e-5, 0.06517028, -25.562334],
+    [-8.1552935e-5, 0.09434082, -33.169994],
+    [0.0006950396, -0.75658554, 189.86348],
+    [0.00057234184, -0.6219134, 155.12936],
+    [0.00048312152, -0.5237092, 129.63345],
+    [0.00041251793, -0.44580802, 109.28169],
+    [0.00035338246, -0.3804386, 92.106735],
+    [0.00030163108, -0.32318982, 76.99714],
+    [0.00025496504, -0.2716385, 63.36248],
+    [0.0002122183, -0.22459342, 50.930023],
+    [0.00017216266, -0.18074036, 39.36962],
+    [0.00013339442, -0.13858163, 28.297573],
+    [9.562049e-5, -0.09775019, 17.611095],
+    [5.9602116e-5, -0.05895758, 7.4781237],
+    [2.5990099e-5, -0.022818731, -1.9546592],
+    [-5.1009956e-6, 0.010582102, -10.670972],
+    [-3.3836932e-5, 0.0414371, -18.722479],
+    [-6.0419858e-5, 0.06997078, -26.168324],
+    [0.0006835504, -0.7449652, 187.25333],
+    [0.0005672648, -0.6173481, 154.34607],
+    [0.00048207384, -0.52363145, 130.0378],
+    [0.00041442498, -0.44905737, 110.58415],
+    [0.00035771847, -0.3864484, 94.16724],
+    [0.0003081218, -0.3316584, 79.740036],
+    [0.00026323035, -0.2821138, 66.6593],
+    [0.00022188775, -0.23662248, 54.647335],
+    [0.0001831779, -0.19422439, 43.472755],
+    [0.00014590385, -0.15364791, 32.81281],
+    [0.00010957565, -0.114335954, 22.519562],
+    [7.479308e-5, -0.07684006, 12.721749],
+    [4.214959e-5, -0.041718613, 3.5521953],
+    [1.1867106e-5, -0.009165279, -4.9453387],
+    [-1.6172087e-5, 0.02096228, -12.809781],
+    [-4.215062e-5, 0.048867706, -20.094791],
+    [0.0006722173, -0.7334185, 184.62003],
+    [0.00056176895, -0.6122157, 153.37062],
+    [0.0004802293, -0.522556, 130.13165],
+    [0.0004152391, -0.4509649, 111.478615],
+    [0.0003606878, -0.39079574, 95.72744],
+    [0.00031297852, -0.33815256, 81.892715],
+    [0.0002697811, -0.29052842, 69.34295],
+    [0.00022980734, -0.24656259, 57.74655],
+    [0.00019233048, -0.20550814, 46.93079],
+    [0.00015636964, -0.16633217, 36.637928],
+    [0.00012134757, -0.12839912, 26.702984],
+    [8.7724344e-5, -0.0921234, 17.221441],
+    [5.6019857e-5, -0.05799087, 8.308397],
+    [2.6516047e-5, -0.026256736, 0.023140557],
+    [-8.4920106e-7, 0.003164081, -7.658726],
+    [-2.6239306e-5, 0.030454742, -14.785503],
+    [0.00066112995, -0.7220638, 182.00267],
+    [0.00055601064, -0.60671043, 152.26271],
+    [0.0004777951, -0.5207359, 129.9914],
+    [0.00041522316, -0.45185018, 112.060936],
+    [0.00036260643, -0.39386356, 96.901924],
+    [0.0003165714, -0.343119, 83.588394],
+    [0.00027491618, -0.2972425, 71.52084],
+    [0.00023627964, -0.25477478, 60.334522],
+    [0.00019996043, -0.21499068, 49.860115],
+    [0.00016517554, -0.17707741, 39.900215],
+    [0.00013133895, -0.14040153, 30.293232],
+    [9.879311e-5, -0.105263315, 21.107376],
+    [6.799354e-5, -0.07208579, 12.4425335],
+    [3.9239672e-5, -0.04114289, 4.3628783],
+    [1.2524817e-5, -0.0124064535, -3.1415849],
+    [-1.22945685e-5, 0.014285614, -10.113671],
+    [0.000650343, -0.71097493, 179.4266],
+    [0.0005500858, -0.600953, 151.05998],
+    [0.00047491438, -0.51834667, 129.67007],
+    [0.0004145611, -0.45193708, 112.398384],
+    [0.0003637019, -0.39592755, 97.773186],
+    [0.0003191723, -0.3468857, 84.92485],
+    [0.00027889767, -0.30257118, 73.28674],
+    [0.00024153634, -0.261536, 62.4933],
+    [0.00020632429, -0.22297415, 52.349106],
+    [0.00017261274, -0.1862208, 42.69677],
+    [0.00013985831, -0.15069762, 33.39168],
+    [0.00010831114, -0.11661724, 24.48158],
+    [7.837738e-5, -0.08435524, 16.055096],
+    [5.0346596e-5, -0.054176904, 8.174515],
+    [2.425878e-5, -0.026102059, 0.84199536],
+    [-7.999927e-9, 8.63613e-6, -5.9793944],
+    [-0.00020889408, 0.33510748, -131.65707],
+    [-0.00026096808, 0.37596124, -136.07362],
+    [-0.0003018506, 0.40875, -140.36351],
+    [-0.0003389774, 0.43997034, -145.35623],
+    [-0.0003700985, 0.4664368, -149.80571],
+    [-0.00039567047, 0.48816592, -153.49185],
+    [-0.0004166103, 0.5058348, -156.45737],
+    [-0.00043377903, 0.5201588, -158.80305],
+    [-0.00044788676, 0.5317524, -160.63124],
+    [-0.00045950044, 0.54111636, -162.03166],
+    [-0.00046906905, 0.5486515, -163.07916],
+    [-0.00047694865, 0.5546782, -163.83498],
+    [-0.00048342478, 0.55945426, -164.34932],
+    [-0.0004887268, 0.5631874, -164.66306],
+    [-0.0004930413, 0.56604695, -164.80975],
+    [-0.0004965215, 0.56817186, -164.81712],
+    [0.00031023336, -0.3027366, 62.9225],
+    [0.00014203468, -0.11762918, 14.075704],
+    [4.7504636e-5, -0.014424542, -12.921303],
+    [-1.7032515e-5, 0.055276617, -30.949736],
+    [-6.9928785e-5, 0.1116622, -45.372787],
+    [-0.00011782568, 0.16214578, -58.180313],
+    [-0.00015937259, 0.20560598, -69.13375],
+    [-0.00019492074, 0.24256603, -78.39272],
+    [-0.00022532363, 0.27400765, -86.223236],
+    [-0.00025140512, 0.30084676, -92.86856],
+    [-0.00027386862, 0.32385334, -98.53124],
+    [-0.00029329443, 0.34365654, -103.37572],
+    [-0.00031015807, 0.36076823, -107.53512],
+    [-0.00032484802, 0.3756045, -111.117256],
+    [-0.00033768394, 0.3885061, -114.21008],
+    [-0.00034892987, 0.39975345, -116.88581],
+    [0.00040426178, -0.41902396, 98.66216],
+    [0.0002462095, -0.24502374, 52.744232],
+    [0.00015045924, -0.13972123, 24.928976],
+    [8.3792445e-5, -0.066732384, 5.715909],
+    [3.2245956e-5, -0.01066829, -8.963401],
+    [-1.1903111e-5, 0.03692952, -21.343925],
+    [-5.2453954e-5, 0.08025577, -32.542015],
+    [-8.8868925e-5, 0.118929684, -42.492073],
+    [-0.000120972974, 0.15288027, -51.19507],
+    [-0.0001491985, 0.18262537, -58.79502],
+    [-0.00017404748, 0.20873243, -65.444725],
+    [-0.00019598172, 0.2317133, -71.28039],
+    [-0.00021540264, 0.2520081, -76.4184],
+    [-0.0002326512, 0.2699881, -80.95649],
+    [-0.0002480158, 0.28596562, -84.97663],
+    [-0.00026174026, 0.30020365, -88.54765],
+    [0.00043101233, -0.4537426, 109.90255],
+    [0.00029147806, -0.30088782, 69.875374],
+    [0.00020075394, -0.20113683, 43.549038],
+    [0.0001359281, -0.1299886, 24.771055],
+    [8.5688276e-5, -0.07506165, 10.308571],
+    [4.3842178e-5, -0.029569259, -1.6233275],
+    [6.494811e-6, 0.010738502, -12.142956],
+    [-2.8031725e-5, 0.047743298, -21.754969],
+    [-5.9324797e-5, 0.081129104, -30.398407],
+    [-8.7308865e-5, 0.110890225, -38.08423],
+    [-0.00011228292, 0.13738345, -44.911327],
+    [-0.00013459653, 0.1610038, -50.98597],
+    [-0.00015457839, 0.1821158, -56.40515],
+    [-0.0001725191, 0.2010384, -61.253273],
+    [-0.00018867056, 0.21804637, -65.602745],
+    [-0.00020324948, 0.23337491, -69.51542],
+    [0.00043808646, -0.464581, 113.96091],
+    [0.00031322427, -0.3283105, 78.48584],
+    [0.00022922459, -0.2361759, 54.264706],
+    [0.00016733706, -0.16826059, 36.353043],
+    [0.00011888434, -0.11521002, 22.368673],
+    [7.871684e-5, -0.071401596, 10.845488],
+    [4.3538726e-5, -0.033241477, 0.84213275],
+    [1.1305695e-5, 0.0015058665, -8.22973],
+    [-1.8507071e-5, 0.03347633, -16.547888],
+    [-4.5596575e-5, 0.062429417, -24.06365],
+    [-7.002438e-5, 0.08847643, -30.813303],
+    [-9.203673e-5, 0.11190434, -36.87524],
+    [-0.00011190071, 0.13301283, -42.32963],
+    [-0.0001298647, 0.1520764, -47.249306],
+    [-0.00014614983, 0.16933729, -51.69823],
+    [-0.00016094938, 0.1850061, -55.73184],
+    [0.00043764082, -0.4662113, 115.22006],
+    [0.00032384304, -0.3422951, 83.07588],
+    [0.00024622248, -0.25738382, 60.84781],
+    [0.00018768363, -0.1932225, 43.963352],
+    [0.00014121043, -0.14233541, 30.556156],
+    [0.000102590726, -0.10016327, 19.455416],
+    [6.901167e-5, -0.06364628, 9.8647585],
+    [3.8603088e-5, -0.030747373, 1.2512769],
+    [1.0401594e-5, -0.0003936532, -6.670188],
+    [-1.5592474e-5, 0.027479537, -13.927039],
+    [-3.9255057e-5, 0.052792113, -20.506931],
+    [-6.0722552e-5, 0.075716846, -26.458967],
+    [-8.020705e-5, 0.096495494, -31.848276],
+    [-9.792132e-5, 0.11536481, -36.73782],
+    [-0.00011406085, 0.13253982, -41.184425],
+    [-0.00012879941, 0.14821036, -45.238083],
+    [0.0004337939, -0.46360543, 115.165855],
+    [0.00032883527, -0.34947145, 85.62399],
+    [0.00025649962, -0.27050117, 65.01751],
+    [0.00020133192, -0.21013768, 49.1771],
+    [0.00015690913, -0.16152586, 36.386665],
+    [0.000119778524, -0.120968, 25.71397],
+    [8.7550936e-5, -0.08587805, 16.492496],
+    [5.856649e-5, -0.054453038, 8.253241],
+    [3.1800362e-5, -0.025568299, 0.70082325],
+    [6.9470693e-6, 0.0011469634, -6.2678537],
+    [-1.5886291e-5, 0.025627827, -12.643825],
+    [-3.6724883e-5, 0.04793208, -18.446888],
+    [-5.5727472e-5, 0.06824556, -23.727715],
+    [-7.307559e-5, 0.086771905, -28.540607],
+    [-8.894275e-5, 0.1037027, -32.936237],
+    [-0.000103486454, 0.11921041, -36.960022],
+    [0.00042830867, -0.45889786, 114.43835],
+    [0.00033066567, -0.3528056, 87.01376],
+    [0.00026274446, -0.2787632, 67.738365],
+    [0.00021060326, -0.22180094, 52.828762],
+    [0.00016822852, -0.17547731, 40.662727],
+    [0.00013254334, -0.13650711, 30.416576],
+    [0.00010153189, -0.10272428, 21.539404],
+    [7.373265e-5, -0.072547205, 13.622737],
+    [4.8177368e-5, -0.044919237, 6.390922],
+    [2.4405292e-5, -0.019317528, -0.2958522],
+    [2.4073322e-6, 0.004308413, -6.45699],
+    [-1.7783106e-5, 0.025955338, -12.0966425],
+    [-3.6269197e-5, 0.04575117, -17.250614],
+    [-5.3203785e-5, 0.06386917, -21.965284],
+    [-6.8741436e-5, 0.080480725, -26.28601],
+    [-8.302536e-5, 0.09574298, -30.254173],
+    [0.00042202868, -0.45311582, 113.3478],
+    [0.0003305963, -0.35381576, 87.69727],
+    [0.00026644388, -0.28395584, 69.54157],
+    [0.00021693496, -0.22993828, 55.432133],
+    [0.00017649097, -0.18577452, 43.85556],
+    [0.00014220874, -0.14835674, 34.0294],
+    [0.00011232551, -0.11580047, 25.478886],
+    [8.556468e-5, -0.08673116, 17.85229],
+    [6.104088e-5, -0.060186464, 10.900155],
+    [3.8243397e-5, -0.035598688, 4.4728875],
+    [1.705497e-5, -0.012810607, -1.4749433],
+    [-2.4951862e-6, 0.008177194, -6.9478173],
+    [-2.0461002e-5, 0.02744145, -11.96843],
+    [-3.696761e-5, 0.04512621, -16.575579],
+    [-5.215255e-5, 0.06138469, -20.80985],
+    [-6.614661e-5, 0.0763607, -24.709103],
+    [0.00041538663, -0.4467914, 112.05681],
+    [0.00032933697, -0.3533562, 87.92908],
+    [0.0002684564, -0.28710917, 70.733154],
+    [0.00022125235, -0.23565848, 57.316383],
+    [0.00018255324, -0.1934429, 46.26954],
+    [0.00014961023, -0.15751222, 36.846806],
+    [0.000120790064, -0.12612034, 28.608517],
+    [9.4968585e-5, -0.09806276, 21.249254],
+    [7.134635e-5, -0.07247422, 14.546336],
+    [4.9416307e-5, -0.0487964, 8.353931],
+    [2.8988268e-5, -0.026801428, 2.609708],
+    [1.00586185e-5, -0.0064583193, -2.6983325],
+    [-7.397414e-6, 0.012278979, -7.584958],
+    [-2.3477754e-5, 0.029525965, -12.081592],
+    [-3.830424e-5, 0.045419116, -16.224432],
+    [-5.199668e-5, 0.06009046, -20.048223],
+    [0.0004086187, -0.4402193, 110.65687],
+    [0.00032731055, -0.3519366, 87.861305],
+    [0.0002693098, -0.28885603, 71.501045],
+    [0.00022414564, -0.23966788, 58.69094],
+    [0.0001870088, -0.19919153, 48.114704],
+    [0.00015531047, -0.16464369, 39.066853],
+    [0.00012749589, -0.13435836, 31.126446],
+    [0.00010254106, -0.10724079, 24.017134],
+    [7.972631e-5, -0.08251537, 17.540834],
+    [5.8571346e-5, -0.059656415, 11.561237],
+    [3.8845417e-5, -0.03839828, 6.007361],
+    [2.0509233e-5, -0.018675674, 0.8590461],
+    [3.5460762e-6, -0.0004518117, -3.8959126],
+    [-1.2117696e-5, 0.01636334, -8.28237],
+    [-2.6589183e-5, 0.031890575, -12.332425],
+    [-3.997826e-5, 0.04625125, -16.078005],
+    [0.000401859, -0.43357003, 109.20214],
+    [0.00032478, -0.34987476, 87.58912],
+    [0.0002693451, -0.28960553, 71.9667],
+    [0.00022600383, -0.24243172, 59.693596],
+    [0.00019027144, -0.20351414, 49.53707],
+    [0.00015971092, -0.17022896, 40.8306],
+    [0.00013284032, -0.1409848, 33.170868],
+    [0.000108694156, -0.11474868, 26.296934],
+    [8.6616215e-5, -0.09081572, 20.029852],
+    [6.616073e-5, -0.06870029, 14.244572],
+    [4.7079953e-5, -0.04812275, 8.867398],
+    [2.9305123e-5, -0.028989902, 3.8716407],
+    [1.2815237e-5, -0.0112617565, -0.75545955],
+    [-2.4454873e-6, 0.0051328335, -5.033854],
+    [-1.6570242e-5, 0.020299908, -8.991796],
+    [-2.9659695e-5, 0.034350928, -12.658604],
+    [0.00039518415, -0.42694417, 107.72559],
+    [0.00032191467, -0.347376, 87.17431],
+    [0.00026879084, -0.28963175, 72.211555],
+    [0.00022709239, -0.2442673, 60.4183],
+    [0.0001926307, -0.20675592, 50.63861],
+    [0.00016310706, -0.17461923, 42.241604],
+    [0.00013711267, -0.14634205, 34.84239],
+    [0.00011372376, -0.12093408, 28.19014],
+    [9.232694e-5, -0.09773718, 22.118176],
+    [7.250981e-5, -0.07630376, 16.511946],
+    [5.402269e-5, -0.056355327, 11.298788],
+    [3.6775145e-5, -0.037778653, 6.44745],
+    [2.073768e-5, -0.020526316, 1.9435797],
+    [5.864758e-6, -0.00453832, -2.2298193],
+    [-7.923846e-6, 0.010277633, -6.0974226],
+    [-2.0720534e-5, 0.024024121, -9.686207],
+    [0.00038863785, -0.42040163, 106.24774],
+    [0.00031882728, -0.34457782, 86.65846],
+    [0.00026780457, -0.28912392, 72.2919],
+    [0.00022759811, -0.2453979, 60.931133],
+    [0.00019429345, -0.20916338, 51.492107],
+    [0.00016571734, -0.17807405, 43.37629],
+    [0.00014053108, -0.1506882, 36.216778],
+    [0.00011784962, -0.12605533, 29.772203],
+    [9.708738e-5, -0.10354662, 23.883177],
+    [7.785876e-5, -0.08274461, 18.443308],
+    [5.992012e-5, -0.063379675, 13.382797],
+    [4.3166598e-5, -0.04532587, 8.667657],
+    [2.7560036e-5, -0.02852818, 4.2818985],
+    [1.3059303e-5, -0.012931821, 0.20999894],
+    [-4.049049e-7, 0.0015438691, -3.5696816],
+    [-1.2917179e-5, 0.014993086, -7.081915],
+    [0.00038224494, -0.41397876, 104.78176],
+    [0.00031559466, -0.3415744, 86.0703],
+    [0.00026649723, -0.2882155, 72.24749],
+    [0.0002276558, -0.24598461, 61.279793],
+    [0.00019541071, -0.21091641, 52.15065],
+    [0.0001677043, -0.18078639, 44.29146],
+    [0.00014326276, -0.15422106, 37.352074],
+    [0.0001212396, -0.1303099, 31.100803],
+    [0.000101070626, -0.108446136, 25.383501],
+    [8.238886e-5, -0.08823282, 20.099154],
+    [6.495951e-5, -0.0694115, 15.181259],
+    [4.8668957e-5, -0.051849145, 10.594462],
+    [3.347175e-5, -0.035484515, 6.321484],
+    [1.9327696e-5, -0.020264527, 2.347391],
+    [6.1758105e-6, -0.0061174915, -1.3470956],
+    [-6.0611433e-6, 0.0070429025, -4.784674],
+    [0.00037601867, -0.4076973, 103.336205],
+    [0.00031227033, -0.33843195, 85.430176],
+    [0.00026494876, -0.28700277, 72.1071],
+    [0.00022736454, -0.24614595, 61.499374],
+    [0.00019609508, -0.2121491, 52.653797],
+    [0.00016919067, -0.18290186, 45.02995],
+    [0.00014543704, -0.1570934, 38.293076],
+    [0.00012402511, -0.13385232, 32.22106],
+    [0.00010441082, -0.112592466, 26.664661],
+    [8.624004e-5, -0.09293063, 21.52628],
+    [6.928583e-5, -0.0746179, 16.74211],
+    [5.3430005e-5, -0.0575182, 12.276394],
+    [3.8621205e-5, -0.041565392, 8.110874],
+    [2.481857e-5, -0.026706498, 4.230809],
+    [1.1967118e-5, -0.012876403, 0.6186772],
+    [-3.9410217e-9, 4.2541546e-6, -2.7463913],
+    [-9.644567e-5, 0.1718936, -71.851585],
+    [-0.00014983481, 0.21982245, -80.56077],
+    [-0.00017959242, 0.24498104, -84.53243],
+    [-0.00020417561, 0.2661201, -88.13255],
+    [-0.00022452696, 0.28375146, -91.241585],
+    [-0.00024128125, 0.29826427, -93.82449],
+    [-0.0002551047, 0.31018582, -95.93736],
+    [-0.00026656565, 0.31999877, -97.6539],
+    [-0.00027611843, 0.32810098, -99.04261],
+    [-0.00028412064, 0.33481058, -100.16165],
+    [-0.00029085335, 0.34038013, -101.05879],
+    [-0.00029653858, 0.34501016, -101.77278],
+    [-0.00030135355, 0.34886152, -102.335205],
+    [-0.00030544092, 0.35206386, -102.77179],
+    [-0.00030891626, 0.35472235, -103.103516],
+    [-0.00031187417, 0.3569232, -103.34768],
+    [0.00024128307, -0.23809244, 51.918518],
+    [0.00011516488, -0.101012, 16.036654],
+    [4.4339195e-5, -0.024428189, -3.8977456],
+    [-3.2067753e-6, 0.026599586, -17.072094],
+    [-3.9610095e-5, 0.06532736, -26.985043],
+    [-7.037734e-5, 0.09777556, -35.2287],
+    [-9.6807846e-5, 0.12545791, -42.218246],
+    [-0.000119446355, 0.14903618, -48.13856],
+    [-0.00013888595, 0.16918455, -53.17115],
+    [-0.00015565615, 0.1864898, -57.471607],
+    [-0.00017019855, 0.20143475, -61.16683],
+    [-0.00018287374, 0.2144101, -64.35891],
+    [-0.00019397543, 0.22573201, -67.13006],
+    [-0.00020374337, 0.23565723, -69.54679],
+    [-0.00021237393, 0.24439506, -71.663155],
+    [-0.0002200293, 0.25211784, -73.523506],
+    [0.00029747287, -0.30720443, 73.090195],
+    [0.00018189821, -0.18176351, 40.333733],
+    [0.00011162297, -0.10540381, 20.328407],
+    [6.2927815e-5, -0.05261888, 6.5203204],
+    [2.5920026e-5, -0.012663263, -3.897631],
+    [-4.3649493e-6, 0.01986437, -12.343235],
+    [-3.0635307e-5, 0.04791678, -19.594288],
+    [-5.3852422e-5, 0.072585255, -25.945631],
+    [-7.4315496e-5, 0.09424384, -31.503668],
+    [-9.236012e-5, 0.1132824, -36.375],
+    [-0.00010831733, 0.1300727, -40.65933],
+    [-0.00012248012, 0.14493865, -44.442703],
+    [-0.00013509911, 0.1581547, -47.797623],
+    [-0.00014638584, 0.169951, -50.78465],
+    [-0.00015651816, 0.18052013, -53.45429],
+    [-0.00016564611, 0.1900239, -55.848892],
+    [0.00031284842, -0.32739866, 79.7064],
+    [0.00020986683, -0.21603958, 50.792217],
+    [0.00014399768, -0.14454459, 32.09709],
+    [9.700825e-5, -0.093541384, 18.737694],
+    [6.090126e-5, -0.054425146, 8.499647],
+    [3.14806e-5, -0.022650994, 0.19989575],
+    [6.3052457e-6, 0.004429976, -6.8543563],
+    [-1.601513e-5, 0.028336233, -13.062687],
+    [-3.6009937e-5, 0.04967304, -18.588915],
+    [-5.3894524e-5, 0.06870444, -23.507235],
+    [-6.990306e-5, 0.08570113, -27.891367],
+    [-8.4267325e-5, 0.100923136, -31.810936],
+    [-9.7196564e-5, 0.11460179, -35.327377],
+    [-0.00010887295, 0.12693675, -38.493454],
+    [-0.00011945266, 0.13809821, -41.353996],
+    [-0.00012906967, 0.14823149, -43.947178],
+    [0.00031639935, -0.33337104, 82.07322],
+    [0.00022309826, -0.23270884, 56.028732],
+    [0.00016201186, -0.16654202, 38.783455],
+    [0.00011738452, -0.11813309, 26.12],
+    [8.264451e-5, -0.080472484, 16.259613],
+    [5.4232372e-5, -0.0497292, 8.215401],
+    [3.002008e-5, -0.02360218, 1.3895656],
+    [8.6802365e-6, -0.00065145857, -4.593917],
+    [-1.0519248e-5, 0.01992836, -9.947553],
+    [-2.7864386e-5, 0.038470183, -14.762122],
+    [-4.3525768e-5, 0.055177618, -19.093906],
+    [-5.7685404e-5, 0.07025823, -22.998903],
+    [-7.051881e-5, 0.08390772, -26.529266],
+    [-8.218404e-5, 0.096300274, -29.73111],
+    [-9.281936e-5, 0.10758713, -32.644325],
+    [-0.00010254487, 0.117899, -35.303295],
+    [0.00031540147, -0.3338033, 82.76054],
+    [0.00022962881, -0.24137062, 58.889473],
+    [0.00017267883, -0.17978717, 42.88134],
+    [0.00013048414, -0.13407168, 30.94672],
+    [9.723511e-5, -0.09803835, 21.520645],
+    [6.9879425e-5, -0.06842218, 13.77047],
+    [4.6556157e-5, -0.043219678, 7.179616],
+    [2.606629e-5, -0.021134822, 1.4116328],
+    [7.658607e-6, -0.0013499516, -3.7472053],
+    [-9.053201e-6, 0.01656623, -8.411491],
+    [-2.4243316e-5, 0.032818858, -12.637384],
+    [-3.805633e-5, 0.04757585, -16.470537],
+    [-5.064015e-5, 0.061003592, -19.955431],
+    [-6.2133084e-5, 0.07325525, -23.13267],
+    [-7.265869e-5, 0.08446639, -26.038023],
+    [-8.232564e-5, 0.09475558, -28.702682],
+    [0.00031237135, -0.33170226, 82.6599],
+    [0.00023270478, -0.24589092, 60.51676],
+    [0.00017921848, -0.18811788, 45.526417],
+    [0.00013924738, -0.14486074, 34.255005],
+    [0.00010747504, -0.11045117, 25.265585],
+    [8.116782e-5, -0.08197194, 17.817259],
+    [5.868394e-5, -0.057663105, 11.459545],
+    [3.8946957e-5, -0.036365062, 5.8931623],
+    [2.1246511e-5, -0.017308429, 0.9182083],
+    [5.157184e-6, -2.6042882e-5, -3.5879405],
+    [-9.533192e-6, 0.015723664, -7.690066],
+    [-2.2953704e-5, 0.030091427, -11.429171],
+    [-3.5229845e-5, 0.043219775, -14.843483],
+    [-4.648346e-5, 0.055244274, -17.968977],
+    [-5.6825887e-5, 0.06628744, -20.837982],
+    [-6.6356304e-5, 0.07645766, -23.479004],
+    [0.00030835925, -0.32832915, 82.14748],
+    [0.00023383118, -0.24805985, 61.438816],
+    [0.00018327932, -0.19349378, 47.296818],
+    [0.00014526078, -0.15238611, 36.60147],
+    [0.00011486755, -0.11949433, 28.020147],
+    [8.956939e-5, -0.09211685, 20.866282],
+    [6.7879984e-5, -0.06866414, 14.734705],
+    [4.882853e-5, -0.048093688, 9.357827],
+    [3.175764e-5, -0.029695937, 4.5522285],
+    [1.6242015e-5, -0.013007524, 0.19715244],
+    [2.0406376e-6, 0.002240187, -3.7784362],
+    [-1.0980553e-5, 0.016201627, -7.416148],
+    [-2.293139e-5, 0.029002445, -10.749747],
+    [-3.3920027e-5, 0.040763557, -13.811351],
+    [-4.4047454e-5, 0.051596366, -16.630352],
+    [-5.3404554e-5, 0.06160036, -19.232906],
+    [0.0003038675, -0.32429245, 81.40664],
+    [0.0002337839, -0.24880046, 61.92804],
+    [0.00018576325, -0.19698541, 48.507813],
+    [0.00014945531, -0.15775189, 38.311195],
+    [0.00012030961, -0.12623025, 30.096916],
+    [9.59579e-5, -0.09988848, 23.220236],
+    [7.501746e-5, -0.07724796, 17.304459],
+    [5.6599514e-5, -0.057356257, 12.106025],
+    [4.009763e-5, -0.039560616, 7.4569554],
+    [2.5102929e-5, -0.023417678, 3.2423089],
+    [1.1362291e-5, -0.008648651, -0.6109821],
+    [-1.2697686e-6, 0.004911151, -4.1467857],
+    [-1.2896083e-5, 0.017379384, -7.3966565],
+    [-2.3613506e-5, 0.02886481, -10.389503],
+    [-3.351412e-5, 0.039469264, -13.152191],
+    [-4.2681986e-5, 0.04928488, -15.708924],
+    [0.00029916188, -0.31991813, 80.536964],
+    [0.00023299565, -0.24862811, 62.136814],
+    [0.00018719822, -0.19921887, 49.343636],
+    [0.00015240017, -0.16163218, 39.582317],
+    [0.00012437347, -0.13133572, 31.694622],
+    [0.00010089229, -0.10594619, 25.072376],
+    [8.0652266e-5, -0.08406743, 19.35943],
+    [6.282352e-5, -0.06481058, 14.328772],
+    [4.6842782e-5, -0.047570545, 9.825191],
+    [3.2322616e-5, -0.03192858, 5.740584],
+    [1.90092e-5, -0.017607123, 2.0026526],
+    [6.748133e-6, -0.0044337325, -1.4341159],
+    [-4.5628235e-6, 0.007707797, -4.6007185],
+    [-1.5012263e-5, 0.018917238, -7.523669],
+    [-2.4684652e-5, 0.02928821, -10.227661],
+    [-3.365803e-5, 0.03890636, -12.735178],
+    [0.00029439246, -0.31539324, 79.59664],
+    [0.00023172377, -0.2478502, 62.15628],
+    [0.00018791007, -0.20058052, 49.917957],
+    [0.00015445845, -0.16445653, 40.540943],
+    [0.00012743726, -0.13525732, 32.944225],
+    [0.000104749706, -0.1107344, 26.552797],
+    [8.515888e-5, -0.089562215, 21.027939],
+    [6.787855e-5, -0.07089817, 16.154327],
+    [5.237928e-5, -0.054174002, 11.786432],
+    [3.8294536e-5, -0.038994446, 7.8225336],
+    [2.5375817e-5, -0.025089046, 4.1925025],
+    [1.3464218e-5, -0.012281985, 0.8502533],
+    [2.4555259e-6, -0.00045585976, -2.2353272],
+    [-7.733796e-6, 0.0104834065, -5.089199],
+    [-1.7181816e-5, 0.02062251, -7.73422],
+    [-2.5961315e-5, 0.030041408, -10.191368],
+    [0.00028964726, -0.31082994, 78.62139],
+    [0.00023012988, -0.24666046, 62.044144],
+    [0.00018810875, -0.20131949, 50.304115],
+    [0.00015587053, -0.16650918, 41.27047],
+    [0.00012975803, -0.13829833, 33.934544],
+    [0.00010779323, -0.11456286, 27.752058],
+    [8.880042e-5, -0.09404115, 22.400047],
+    [7.202959e-5, -0.07592862, 17.672625],
+    [5.6977162e-5, -0.059684727, 13.431371],
+    [4.3294604e-5, -0.044934094, 9.579859],
+    [3.0741176e-5, -0.03141555, 6.0505934],
+    [1.9157009e-5, -0.018953364, 2.7977498],
+    [8.435784e-6, -0.0074287485, -0.20996505],
+    [-1.5034727e-6, 0.0032492473, -2.996611],
+    [-1.0733705e-5, 0.013161728, -5.5835567],
+    [-1.9323095e-5, 0.02238367, -7.990459],
+    [0.0002849786, -0.30629703, 77.63378],
+    [0.00022832036, -0.24518697, 61.83874],
+    [0.00018793497, -0.20160285, 50.551357],
+    [0.00015680093, -0.16798465, 41.82796],
+    [0.00013151523, -0.14067039, 34.727516],
+    [0.00011021052, -0.1176523, 28.734632],
+    [9.176764e-5, -0.09772793, 23.540937],
+    [7.546898e-5, -0.08012664, 18.948925],
+    [6.083195e-5, -0.06433002, 14.8257265],
+    [4.7522666e-5, -0.049978632, 11.079066],
+    [3.5308385e-5, -0.03682051, 7.6439667],
+    [2.4030553e-5, -0.024682157, 4.4753604],
+    [1.3581486e-5, -0.0134440875, 1.5419251],
+    [3.881301e-6, -0.0030169447, -1.1798929],
+    [-5.1390134e-6, 0.006676026, -3.7102926],
+    [-1.3543754e-5, 0.015705572, -6.0678077],
+    [0.00028041744, -0.30183697, 76.64835],
+    [0.00022636738, -0.24351713, 61.566418],
+    [0.00018748589, -0.20154619, 50.693832],
+    [0.00015736547, -0.16901982, 42.25358],
+    [0.00013283733, -0.14252485, 35.367428],
+    [0.00011213817, -0.12016327, 29.547379],
+    [9.420163e-5, -0.100787975, 24.49877],
+    [7.834011e-5, -0.083659716, 20.03184],
+    [6.4089356e-5, -0.06827932, 16.018494],
+    [5.1127547e-5, -0.05430027, 12.369733],
+    [3.9229246e-5, -0.041478787, 9.022761],
+    [2.8238035e-5, -0.029644283, 5.9333844],
+    [1.8046043e-5, -0.018677669, 3.070507],
+    [8.57378e-6, -0.00849044, 0.41088158],
+    [-2.4513596e-7, 0.0009911607, -2.0648685],
+    [-8.471536e-6, 0.009834109, -4.3742757],
+    [0.00027598158, -0.2974759, 75.67457],
+    [0.00022432147, -0.24171257, 61.245895],
+    [0.00018683079, -0.20123214, 50.755985],
+    [0.00015764749, -0.16971333, 42.576286],
+    [0.00013381816, -0.14397235, 35.886627],
+    [0.00011367737, -0.12221469, 30.224987],
+    [9.6208445e-5, -0.10334552, 25.309696],
+    [8.075191e-5, -0.08665502, 20.958277],
+    [6.6860455e-5, -0.07166178, 17.047012],
+    [5.422265e-5, -0.05803028, 13.489659],
+    [4.2619376e-5, -0.045523666, 10.22521],
+    [3.1896932e-5, -0.033974674, 7.2104025],
+    [2.1947564e-5, -0.023264833, 4.4143944],
+    [1.2692077e-5, -0.01330634, 1.8141953],
+    [4.0660143e-6, -0.0040276893, -0.6089456],
+    [-3.988725e-6, 0.0046350914, -2.8717577],
+    [0.00027168018, -0.29322913, 74.71849],
+    [0.00022221869, -0.2398176, 60.89074],
+    [0.00018602025, -0.20072106, 50.75576],
+    [0.00015770856, -0.17013806, 42.817493],
+    [0.00013452784, -0.1450955, 36.309277],
+    [0.00011490454, -0.12389633, 30.793634],
+    [9.786909e-5, -0.10549543, 26.001307],
+    [8.278856e-5, -0.08921083, 21.756725],
+    [6.923155e-5, -0.07457778, 17.940298],
+    [5.6896217e-5, -0.061270837, 14.468267],
+    [4.556901e-5, -0.049059168, 11.281153],
+    [3.509883e-5, -0.03777856, 8.33651],
+    [2.5378462e-5, -0.02731149, 5.6038065],
+    [1.6329008e-5, -0.017570797, 3.060279],
+    [7.887279e-6, -0.008486532, 0.6876218],
+    [-2.5710796e-9, 2.7767205e-6, -1.5302259],
+    [-3.2494347e-5, 0.083772495, -40.85306],
+    [-9.1720874e-5, 0.14053889, -53.005035],
+    [-0.000119623444, 0.16567989, -57.753166],
+    [-0.0001395918, 0.18344305, -61.052948],
+    [-0.0001556638, 0.1977597, -63.75058],
+    [-0.0001688956, 0.20954978, -65.99015],
+    [-0.00017992736, 0.2193669, -67.85903],
+    [-0.00018922544, 0.2276219, -69.4279],
+    [-0.00019714094, 0.23462856, -70.753975],
+    [-0.00020394234, 0.24062915, -71.883064],
+    [-0.0002098375, 0.24581236, -72.85175],
+    [-0.0002149895, 0.25032693, -73.68938],
+    [-0.00021952765, 0.25429103, -74.41952],
+    [-0.00022355569, 0.25779974, -75.06132],
+    [-0.00022715775, 0.2609302, -75.630394],
+    [-0.00023040257, 0.2637455, -76.139595],
+    [0.00021571934, -0.21467453, 48.488934],
+    [0.00010663684, -0.09729382, 17.97494],
+    [4.5956553e-5, -0.0321653, 1.0892797],
+    [5.9568506e-6, 0.010562181, -9.927412],
+    [-2.3498977e-5, 0.041844957, -17.941801],
+    [-4.715895e-5, 0.06681664, -24.299742],
+    [-6.7132474e-5, 0.0877775, -29.607714],
+    [-8.4248226e-5, 0.105653875, -34.113247],
+    [-9.903818e-5, 0.12103887, -37.97434],
+    [-0.000111916976, 0.134389, -41.31159],
+    [-0.00012321438, 0.14606379, -44.219353],
+    [-0.00013319432, 0.15634885, -46.77219],
+    [-0.00014206923, 0.16547266, -49.0295],
+    [-0.00015001131, 0.1736196, -51.039043],
+    [-0.00015716144, 0.18093985, -52.839596],
+    [-0.00016363562, 0.18755662, -54.46289],
+    [0.00025300134, -0.2605601, 62.57724],
+    [0.00015500664, -0.15538155, 35.34289],
+    [9.5949064e-5, -0.09181617, 18.801216],
+    [5.53687e-5, -0.048159964, 7.437229],
+    [2.5005238e-5, -0.015557983, -1.0363739],
+    [8.053223e-7, 0.0103526525, -7.75442],
+    [-1.9484592e-5, 0.03200199, -13.351284],
+    [-3.7095193e-5, 0.050725486, -18.177471],
+    [-5.259644e-5, 0.06715523, -22.401176],
+    [-6.633099e-5, 0.08167493, -26.125097],
+    [-7.856989e-5, 0.09458538, -29.42929],
+    [-8.9536916e-5, 0.10613264, -32.378902],
+    [-9.9417455e-5, 0.11651915, -35.027306],
+    [-0.00010836561, 0.12591231, -37.418488],
+    [-0.00011651, 0.13445121, -39.588905],
+    [-0.00012395842, 0.14225207, -41.568954],
+    [0.00026249603, -0.273427, 66.90975],
+    [0.0001746457, -0.17937925, 42.65352],
+    [0.00011968826, -0.120314285, 27.319523],
+    [8.078114e-5, -0.07844011, 16.4167],
+    [5.11776e-5, -0.046586838, 8.117665],
+    [2.7428769e-5, -0.021064429, 1.4716848],
+    [7.561664e-6, 0.0002456569, -4.070419],
+    [-9.628257e-6, 0.018640315, -8.846218],
+    [-2.4844776e-5, 0.03488309, -13.055702],
+    [-3.8456623e-5, 0.04938165, -16.80696],
+    [-5.0701798e-5, 0.062401354, -20.170727],
+    [-6.1771934e-5, 0.0741544, -23.20336],
+    [-7.1827715e-5, 0.08481734, -25.951561],
+    [-8.1004575e-5, 0.09453814, -28.454336],
+    [-8.941704e-5, 0.1034413, -30.744427],
+    [-9.716215e-5, 0.11163198, -32.849434],
+    [0.00026416208, -0.27691823, 68.44711],
+    [0.00018391715, -0.19110322, 46.35199],
+    [0.00013278663, -0.13623366, 32.141186],
+    [9.593103e-5, -0.09660038, 21.83707],
+    [6.749144e-5, -0.06599572, 13.865135],
+    [4.4493907e-5, -0.0412548, 7.4168377],
+    [2.5203663e-5, -0.020522775, 2.014679],
+    [8.53339e-6, -0.002633335, -2.6431634],
+    [-6.2148597e-6, 0.013165033, -6.7520494],
+    [-1.9460846e-5, 0.02732874, -10.4314995],
+    [-3.1447657e-5, 0.04012641, -13.752596],
+    [-4.2348525e-5, 0.05175016, -16.766323],
+    [-5.2306004e-5, 0.062357157, -19.514263],
+    [-6.1441126e-5, 0.072080016, -22.031399],
+    [-6.985695e-5, 0.08103111, -24.347294],
+    [-7.764147e-5, 0.08930598, -26.487047],
+    [0.00026282103, -0.27673867, 68.86678],
+    [0.00018856795, -0.1973461, 48.43359],
+    [0.00014061382, -0.14593045, 35.136875],
+    [0.00010569026, -0.108408146, 25.396433],
+    [7.847578e-5, -0.07913491, 17.778612],
+    [5.630762e-5, -0.05528308, 11.563558],
+    [3.763973e-5, -0.035205323, 6.3295856],
+    [2.1491274e-5, -0.017852856, 1.8066833],
+    [7.2127445e-6, -0.0025288884, -2.1853743],
+    [-5.6218278e-6, 0.011226049, -5.7660456],
+    [-1.727498e-5, 0.023698423, -9.010414],
+    [-2.791592e-5, 0.035075013, -11.967813],
+    [-3.7675432e-5, 0.045500122, -14.6763735],
+    [-4.6663466e-5, 0.055094413, -17.167904],
+    [-5.4974113e-5, 0.06396063, -19.469429],
+    [-6.2688036e-5, 0.07218651, -21.603977],
+    [0.00026012928, -0.2748559, 68.74995],
+    [0.00019084613, -0.2007591, 49.675182],
+    [0.00014553766, -0.15219767, 37.125362],
+    [0.00011230399, -0.116512634, 27.87199],
+    [8.6242944e-5, -0.08849435, 20.588083],
+    [6.4894506e-5, -0.06552894, 14.60777],
+    [4.6846257e-5, -0.04611369, 9.54738],
+    [3.1204097e-5, -0.02929481, 5.1623254],
+    [1.7368588e-5, -0.0144309, 1.287373],
+    [4.9310597e-6, -0.0010829401, -2.191087],
+    [-6.378598e-6, 0.011041386, -5.3491507],
+    [-1.6734506e-5, 0.022132674, -8.236822],
+    [-2.6261343e-5, 0.0323282, -10.890272],
+    [-3.506107e-5, 0.041739922, -13.338975],
+    [-4.3220607e-5, 0.050462812, -15.607887],
+    [-5.0814666e-5, 0.05857821, -17.718344],
+    [0.00025680082, -0.27212468, 68.35131],
+    [0.0001917805, -0.20255521, 50.432117],
+    [0.0001487287, -0.15641308, 38.5096],
+    [0.00011695851, -0.122309975, 29.672136],
+    [9.1934635e-5, -0.0954169, 22.686264],
+    [7.135491e-5, -0.07328408, 16.926641],
+    [5.3899614e-5, -0.054506976, 12.034562],
+    [3.87397e-5, -0.03820209, 7.7839007],
+    [2.5318906e-5, -0.023775257, 4.0220323],
+    [1.3251274e-5, -0.010812915, 0.6422994],
+    [2.2706756e-6, 0.00097158673, -2.429582],
+    [-7.801284e-6, 0.011772089, -5.244168],
+    [-1.708805e-5, 0.021723883, -7.836955],
+    [-2.5685988e-5, 0.030932687, -10.2357235],
+    [-3.3676417e-5, 0.03948739, -12.463801],
+    [-4.112919e-5, 0.047464073, -14.541123],
+    [0.00025318982, -0.26897362, 67.800224],
+    [0.00019190086, -0.20335984, 50.887875],
+    [0.00015081982, -0.15932085, 39.507004],
+    [0.000120328565, -0.1265928, 31.028244],
+    [9.62259e-5, -0.10069499, 24.304379],
+    [7.634743e-5, -0.07932056, 18.745205],
+    [5.944553e-5, -0.061140113, 14.010683],
+    [4.4738852e-5, -0.045320824, 9.887628],
+    [3.170522e-5, -0.031305365, 6.233086],
+    [1.9980367e-5, -0.018704012, 2.9467766],
+    [9.307216e-6, -0.0072406544, -0.042627726],
+    [-4.929514e-7, 0.003277912, -2.7852993],
+    [-9.544189e-6, 0.012986921, -5.3165636],
+    [-1.7939665e-5, 0.021988368, -7.663154],
+    [-2.5756319e-5, 0.030366328, -9.847105],
+    [-3.3060052e-5, 0.038192578, -11.887205],
+    [0.00024948624, -0.2656364, 67.168396],
+    [0.00019150686, -0.20352761, 51.146786],
+    [0.00015217873, -0.16135325, 40.243824],
+    [0.00012282057, -0.12983793, 32.079327],
+    [9.9538025e-5, -0.10482238, 25.586258],
+    [8.0292564e-5, -0.08413053, 20.206692],
+    [6.389912e-5, -0.06649795, 15.616587],
+    [4.9613704e-5, -0.05113088, 11.612513],
+    [3.694039e-5, -0.037499912, 8.058678],
+    [2.5533223e-5, -0.025235182, 4.8600173],
+    [1.514531e-5, -0.014071961, 1.9482813],
+    [5.600697e-6, -0.0038206582, -0.7256148],
+    [-3.2250136e-6, 0.0056537134, -3.1968005],
+    [-1.1423453e-5, 0.01445116, -5.491414],
+    [-1.906837e-5, 0.022652198, -7.6305223],
+    [-2.6222331e-5, 0.030324958, -9.631943],
+    [0.0002457971, -0.26224744, 66.498024],
+    [0.00019078043, -0.20327455, 51.27267],
+    [0.00015303241, -0.16277723, 40.79808],
+    [0.00012469158, -0.13234681, 32.913204],
+    [0.00010214474, -0.10811951, 26.625122],
+    [8.347037e-5, -0.088041425, 21.40624],
+    [6.7540575e-5, -0.070907466, 16.94714],
+    [5.3643525e-5, -0.05595726, 13.052635],
+    [4.13042e-5, -0.04268336, 9.592436],
+    [3.0191472e-5, -0.030731719, 6.475549],
+    [2.0067946e-5, -0.019848002, 3.63647],
+    [1.0761802e-5, -0.009847454, 1.0274582],
+    [2.149367e-6, -0.0005963275, -1.3861959],
+    [-5.8603446e-6, 0.008004354, -3.630291],
+    [-1.3338823e-5, 0.016032534, -5.725187],
+    [-2.0345953e-5, 0.0235534, -7.6879096],
+    [0.00024218402, -0.25888646, 65.81489],
+    [0.00018983816, -0.20273976, 51.30684],
+    [0.00015352828, -0.16376641, 41.22059],
+    [0.00012611128, -0.13431834, 33.58781],
+    [0.00010423034, -0.110801995, 27.483648],
+    [8.607337e-5, -0.09127807, 22.409115],
+    [7.0565795e-5, -0.07459699, 18.068523],
+    [5.702512e-5, -0.06002885, 14.274195],
+    [4.499424e-5, -0.0470848, 10.900442],
+    [3.4154175e-5, -0.035423588, 7.8594937],
+    [2.4275701e-5, -0.024799684, 5.0881166],
+    [1.5191537e-5, -0.015033397, 2.5399265],
+    [6.779343e-6, -0.0059927376, 0.18073347],
+    [-1.0512299e-6, 0.0024203025, -2.0149503],
+    [-8.370215e-6, 0.010281939, -4.0669994],
+    [-1.5235398e-5, 0.01765504, -5.991818],
+    [0.00023868236, -0.25560132, 65.135086],
+    [0.00018875778, -0.20201646, 51.27714],
+    [0.00015376619, -0.16443856, 41.545876],
+    [0.00012719582, -0.13588874, 34.142838],
+    [0.00010592292, -0.11301959, 28.205345],
+    [8.8237415e-5, -0.093999006, 23.261292],
+    [7.311528e-5, -0.07773021, 19.028124],
+    [5.9901246e-5, -0.06351156, 15.325139],
+    [4.815456e-5, -0.050871182, 12.03083],
+    [3.7566664e-5, -0.0394787, 9.060121],
+    [2.7915316e-5, -0.02909596, 6.351642],
+    [1.9037507e-5, -0.019547945, 3.8602264],
+    [1.0812758e-5, -0.01070485, 1.5522853],
+    [3.1513034e-6, -0.0024695245, -0.59740555],
+    [-4.0158925e-6, 0.0052330266, -2.6083727],
+    [-1.074499e-5, 0.012463857, -4.4965315],
+    [0.00023531199, -0.25242072, 64.468864],
+    [0.0001875927, -0.20116916, 51.203003],
+    [0.00015381625, -0.16487652, 41.79829],
+    [0.00012802723, -0.13715424, 34.606342],
+    [0.00010731377, -0.11487905, 28.821226],
+    [9.006088e-5, -0.09631897, 23.996027],
+    [7.5291886e-5, -0.08042683, 19.860582],
+    [6.237768e-5, -0.06652824, 16.240946],
+    [5.089266e-5, -0.054167062, 13.01949],
+    [4.053777e-5, -0.04302277, 10.113562],
+    [3.109694e-5, -0.03286368, 7.4633985],
+    [2.2410937e-5, -0.023518791, 5.0248537],
+    [1.4361223e-5, -0.014860509, 2.7649212],
+    [6.8588756e-6, -0.0067927362, 0.6586748],
+    [-1.64485e-7, 0.00075868564, -1.3131711],
+    [-6.76381e-6, 0.007853444, -3.166163],
+    [0.00023208313, -0.24936135, 63.822662],
+    [0.00018638058, -0.20024373, 51.098347],
+    [0.00015372901, -0.16514017, 41.995487],
+    [0.00012866526, -0.13818498, 34.998806],
+    [0.00010846995, -0.116458744, 29.354061],
+    [9.16163e-5, -0.09832253, 24.637829],
+    [7.71726e-5, -0.0827764, 20.591787],
+    [6.453435e-5, -0.06917169, 17.048395],
+    [5.3290474e-5, -0.057067327, 13.893745],
+    [4.3150878e-5, -0.046152107, 11.047481],
+    [3.3905042e-5, -0.0362001, 8.451254],
+    [2.5397241e-5, -0.027044019, 6.0618415],
+    [1.7510936e-5, -0.018558439, 3.846791],
+    [1.0157934e-5, -0.010648151, 1.7814171],
+    [3.2704368e-6, -0.0032397206, -0.1533624],
+    [-3.205582e-6, 0.003725484, -1.97281],
+    [0.00022900008, -0.24643232, 63.200546],
+    [0.00018514838, -0.19927329, 50.973343],
+    [0.00015354189, -0.165274, 42.150623],
+    [0.00012915432, -0.13903323, 35.33549],
+    [0.000109441506, -0.11781724, 29.82087],
+    [9.295849e-5, -0.10007362, 25.205212],
+    [7.881581e-5, -0.08484685, 21.241371],
+    [6.643254e-5, -0.07151298, 17.767982],
+    [5.54114e-5, -0.05964534, 14.674709],
+    [4.5470828e-5, -0.04894155, 11.88337],
+    [3.640571e-5, -0.039181303, 9.337],
+    [2.8063487e-5, -0.03020062, 6.9931855],
+    [2.0329462e-5, -0.021876033, 4.819961],
+    [1.3116429e-5, -0.014113442, 2.792928],
+    [6.357045e-6, -0.0068399822, 0.8931573],
+    [-2.1192559e-9, 2.2870859e-6, -0.89442676],
+    [1.8100613e-5, 0.019425627, -19.78314],
+    [-5.209677e-5, 0.08920673, -35.92786],
+    [-8.294822e-5, 0.11851462, -42.181656],
+    [-0.00010223274, 0.13638008, -45.82338],
+    [-0.0001169345, 0.14992079, -48.567013],
+    [-0.0001289454, 0.16098237, -50.818844],
+    [-0.00013905828, 0.1703079, -52.7286],
+    [-0.00014774478, 0.17833303, -54.381824],
+    [-0.00015532781, 0.18535528, -55.83722],
+    [-0.00016204265, 0.19159172, -57.138103],
+    [-0.0001680673, 0.19720702, -58.31775],
+    [-0.00017353962, 0.2023289, -59.40225],
+    [-0.00017856853, 0.20705855, -60.412464],
+    [-0.00018324167, 0.2114776, -61.365387],
+    [-0.00018763066, 0.21565285, -62.27503],
+    [-0.0001917948, 0.21963985, -63.15314],
+    [0.0002137572, -0.21406092, 49.65169],
+    [0.0001092481, -0.1026043, 20.871523],
+    [5.1970455e-5, -0.041519925, 5.092749],
+    [1.4745341e-5, -0.001920901, -5.103606],
+    [-1.2081228e-5, 0.026519053, -12.395498],
+    [-3.2941934e-5, 0.04854964, -18.018883],
+    [-5.0159513e-5, 0.06666202, -22.623081],
+    [-6.487683e-5, 0.08209059, -26.53123],
+    [-7.7690434e-5, 0.095485225, -29.914186],
+    [-8.8992325e-5, 0.10727281, -32.88402],
+    [-9.907123e-5, 0.11776636, -35.52264],
+    [-0.00010814961, 0.12720586, -37.892605],
+    [-0.00011640338, 0.13578027, -40.04301],
+    [-0.00012397417, 0.1436411, -42.013103],
+    [-0.00013097758, 0.15091158, -43.834743],
+    [-0.00013750915, 0.15769334, -45.534115],
+    [0.00023952527, -0.24609977, 59.59827],
+    [0.0001469674, -0.14767598, 34.294395],
+    [9.233759e-5, -0.08935069, 19.207712],
+    [5.5113887e-5, -0.04956402, 8.896485],
+    [2.7535125e-5, -0.020094275, 1.2590884],
+    [5.858788e-6, 0.0030447906, -4.7320795],
+    [-1.198079e-5, 0.022059193, -9.648261],
+    [-2.722469e-5, 0.03827662, -13.834273],
+    [-4.060197e-5, 0.05248099, -17.494585],
+    [-5.252608e-5, 0.06512124, -20.747063],
+    [-6.3269064e-5, 0.07649409, -23.669844],
+    [-7.303404e-5, 0.08682078, -26.32115],
+    [-8.1981336e-5, 0.09627545, -28.746727],
+    [-9.024113e-5, 0.10499899, -30.983522],
+    [-9.792083e-5, 0.113107346, -33.06189],
+    [-0.00010511039, 0.12069735, -35.007137],
+    [0.00024535423, -0.25457615, 62.611855],
+    [0.00016191968, -0.1659692, 39.87898],
+    [0.00011127159, -0.11197077, 25.942616],
+    [7.582154e-5, -0.074086145, 16.12921],
+    [4.903794e-5, -0.045432966, 8.6938095],
+    [2.7728802e-5, -0.022631574, 2.7730777],
+    [1.0093737e-5, -0.003768053, -2.1250088],
+    [-4.975726e-6, 0.012338909, -6.305417],
+    [-1.8197507e-5, 0.02645607, -9.966735],
+    [-3.0026304e-5, 0.039071508, -13.2359295],
+    [-4.0743133e-5, 0.05048945, -16.192629],
+    [-5.0541206e-5, 0.060920075, -18.89203],
+    [-5.9568247e-5, 0.07052402, -21.376347],
+    [-6.794312e-5, 0.07943032, -23.679453],
+    [-7.5763965e-5, 0.087745234, -25.829227],
+    [-8.3113206e-5, 0.09555783, -27.849003],
+    [0.0002456928, -0.25646436, 63.653904],
+    [0.00016906645, -0.17508462, 42.77787],
+    [0.00012175519, -0.124683104, 29.787199],
+    [8.819024e-5, -0.0888386, 20.513987],
+    [6.250438e-5, -0.061364423, 13.388313],
+    [4.1867854e-5, -0.03927216, 7.6499276],
+    [2.4683182e-5, -0.020869633, 2.8662415],
+    [9.957165e-6, -0.0051017078, -1.2337449],
+    [-2.9667794e-6, 0.008730674, -4.8302293],
+    [-1.45343e-5, 0.021103218, -8.046242],
+    [-2.5040117e-5, 0.032332234, -10.963964],
+    [-3.4678866e-5, 0.042628154, -13.638366],
+    [-4.3591783e-5, 0.052144237, -16.109596],
+    [-5.1889972e-5, 0.06100103, -18.409266],
+    [-5.966449e-5, 0.06929719, -20.563225],
+    [-6.699165e-5, 0.07711524, -22.593102],
+    [0.00024394468, -0.25577077, 63.89809],
+    [0.0001727952, -0.18016227, 44.488934],
+    [0.00012818385, -0.13264202, 32.24525],
+    [9.626767e-5, -0.098572366, 23.438494],
+    [7.165923e-5, -0.07225968, 16.619238],
+    [5.1751107e-5, -0.050948296, 11.085832],
+    [3.5084657e-5, -0.03309499, 6.444592],
+    [2.0755107e-5, -0.01774055, 2.4500012],
+    [8.16099e-6, -0.004245965, -1.0620712],
+    [-3.1148095e-6, 0.0078328075, -4.206036],
+    [-1.3363939e-5, 0.01880722, -7.062415],
+    [-2.2785442e-5, 0.02889106, -9.686738],
+    [-3.151892e-5, 0.038235158, -12.118356],
+    [-3.9670733e-5, 0.04695469, -14.387389],
+    [-4.732676e-5, 0.05514265, -16.518167],
+    [-5.455871e-5, 0.062876575, -18.530983],
+    [0.00024131824, -0.25392798, 63.767532],
+    [0.00017477732, -0.18315804, 45.581528],
+    [0.00013241745, -0.13802187, 33.949078],
+    [0.00010189951, -0.10544618, 25.530876],
+    [7.825312e-5, -0.08016667, 18.98269],
+    [5.9037502e-5, -0.0595991, 13.644864],
+    [4.2886724e-5, -0.042297184, 9.148099],
+    [2.8958906e-5, -0.027368784, 5.2642684],
+    [1.669581e-5, -0.014221512, 1.8415316],
+    [5.7080792e-6, -0.0024415, -1.2264811],
+    [-4.2832157e-6, 0.008268364, -4.016347],
+    [-1.347666e-5, 0.018120414, -6.5829873],
+    [-2.201247e-5, 0.027265415, -8.965572],
+    [-2.9994555e-5, 0.035815574, -11.193355],
+    [-3.7505255e-5, 0.04385991, -13.289579],
+    [-4.4612658e-5, 0.051472016, -15.273473],
+    [0.00023834508, -0.25157374, 63.45303],
+    [0.00017577804, -0.18496838, 46.316902],
+    [0.00013535451, -0.14187162, 35.20324],
+    [0.00010603456, -0.11056714, 27.112434],
+    [8.322671e-5, -0.08618295, 20.797245],
+    [6.463572e-5, -0.06628435, 15.634558],
+    [4.89671e-5, -0.049498532, 11.273091],
+    [3.5423494e-5, -0.034979828, 7.496389],
+    [2.347849e-5, -0.022169622, 4.16131],
+    [1.2765485e-5, -0.010678471, 1.167883],
+    [3.0195743e-6, -0.00022455196, -1.556398],
+    [-5.95281e-6, 0.009398584, -4.0647926],
+    [-1.4291783e-5, 0.018341014, -6.396158],
+    [-2.2100308e-5, 0.026713556, -8.579299],
+    [-2.9458417e-5, 0.034602568, -10.636718],
+    [-3.643145e-5, 0.04207861, -12.586821],
+    [0.0002352875, -0.24902807, 63.052082],
+    [0.00017619826, -0.18606402, 46.832615],
+    [0.00013747704, -0.14475375, 36.170986],
+    [0.000109202185, -0.11455199, 28.361883],
+    [8.7126566e-5, -0.090944976, 22.247238],
+    [6.908889e-5, -0.0716362, 17.23794],
+    [5.3857173e-5, -0.055316947, 12.998344],
+    [4.0669067e-5, -0.04117754, 9.320811],
+    [2.9021821e-5, -0.028684, 6.06837],
+    [1.856616e-5, -0.017465185, 3.1457372],
+    [9.049383e-6, -0.0072523504, 0.4838182],
+    [2.8470478e-7, 0.0021534802, -1.9686471],
+    [-7.866307e-6, 0.010900213, -4.249869],
+    [-1.55062e-5, 0.019097889, -6.3883758],
+    [-2.2713466e-5, 0.026831038, -8.406149],
+    [-2.9551587e-5, 0.034168113, -10.321017],
+    [0.00023228367, -0.2464636, 62.61858],
+    [0.00017626879, -0.18671696, 47.208477],
+    [0.00013906631, -0.14699665, 36.947685],
+    [0.00011171818, -0.11776839, 29.38566],
+    [9.028837e-5, -0.0948431, 23.445564],
+    [7.274001e-5, -0.07605321, 18.570217],
+    [5.789894e-5, -0.06014949, 14.438542],
+    [4.5033543e-5, -0.046353713, 10.850562],
+    [3.3659908e-5, -0.034151226, 7.674033],
+    [2.3442082e-5, -0.023184724, 4.817124],
+    [1.4137229e-5, -0.013195846, 2.2133856],
+    [5.5650007e-6, -0.003992533, -0.1866418],
+    [-2.4101869e-6, 0.0045698946, -2.4202914],
+    [-9.8902265e-6, 0.012600532, -4.5157957],
+    [-1.6952863e-5, 0.020182932, -6.4948435],
+    [-2.3660095e-5, 0.027383868, -8.3748045],
+    [0.00022940856, -0.24397728, 62.183826],
+    [0.00017613183, -0.18709557, 47.494156],
+    [0.00014029793, -0.14880554, 37.593014],
+    [0.000113782145, -0.12044864, 30.251017],
+    [9.2928356e-5, -0.098128535, 24.464806],
+    [7.5815005e-5, -0.07979759, 19.707062],
+    [6.13222e-5, -0.06426261, 15.670528],
+    [4.8746817e-5, -0.050774585, 12.162348],
+    [3.7621514e-5, -0.038835783, 9.054386],
+    [2.762118e-5, -0.0281, 6.257523],
+    [1.8510802e-5, -0.018316975, 3.70732],
+    [1.0115553e-5, -0.009300454, 1.3557892],
+    [2.3029804e-6, -0.0009091532, -0.83351845],
+    [-5.02775e-6, 0.0069647455, -2.888478],
+    [-1.1953933e-5, 0.014404168, -4.830588],
+    [-1.853658e-5, 0.02147475, -6.6769032],
+    [0.00022670315, -0.24162485, 61.766647],
+    [0.00017587964, -0.1873097, 47.722267],
+    [0.00014128773, -0.15031607, 38.146484],
+    [0.00011552667, -0.1227471, 31.002634],
+    [9.519188e-5, -0.100970045, 25.35369],
+    [7.84681e-5, -0.08304814, 20.700035],
+    [6.4286534e-5, -0.0678411, 16.747526],
+    [5.1970936e-5, -0.054627586, 13.310096],
+    [4.1069346e-5, -0.042925593, 10.263431],
+    [3.1266223e-5, -0.032398652, 7.5207624],
+    [2.2333044e-5, -0.022803137, 5.019267],
+    [1.4099587e-5, -0.0139575405, 2.712128],
+    [6.436317e-6, -0.00572366, 0.56367326],
+    [-7.5631215e-7, 0.0020048923, -1.453598],
+    [-7.555116e-6, 0.009310431, -3.3610106],
+    [-1.4020621e-5, 0.016257996, -5.175452],
+    [0.00022418951, -0.23943876, 61.378677],
+    [0.00017557447, -0.18743403, 47.915142],
+    [0.00014211555, -0.15162185, 38.63543],
+    [0.00011704356, -0.12477067, 31.67149],
+    [9.718014e-5, -0.103485145, 26.146143],
+    [8.080787e-5, -0.08593065, 21.585382],
+    [6.6905726e-5, -0.07101662, 17.707426],
+    [5.482299e-5, -0.05804803, 14.332702],
+    [4.4122284e-5, -0.046557833, 11.340547],
+    [3.449695e-5, -0.03621862, 8.646382],
+    [2.5724243e-5, -0.026792618, 6.1887927],
+    [1.7637869e-5, -0.01810232, 3.9219282],
+    [1.0110893e-5, -0.010012198, 1.8107711],
+    [3.0451095e-6, -0.0024172927, -0.17182322],
+    [-3.6358063e-6, 0.0047641387, -2.047029],
+    [-9.992079e-6, 0.011596758, -3.8316302],
+    [0.00022187855, -0.23743744, 61.027164],
+    [0.00017525992, -0.1875212, 48.088657],
+    [0.00014283851, -0.15279007, 39.079525],
+    [0.00011839884, -0.12659633, 32.2799],
+    [9.896625e-5, -0.10575866, 26.866657],
+    [8.291316e-5, -0.088536456, 22.389404],
+    [6.926319e-5, -0.0738856, 18.577953],
+    [5.7389698e-5, -0.061136033, 15.258916],
+    [4.6869274e-5, -0.049835067, 12.315148],
+    [3.7403708e-5, -0.03966391, 9.664166],
+    [2.877561e-5, -0.030390225, 7.245862],
+    [2.0822255e-5, -0.021840118, 5.0152473],
+    [1.3419036e-5, -0.013880416, 2.9378676],
+    [6.469076e-6, -0.0064075277, 0.9869077],
+    [-1.03513074e-7, 0.0006598148, -0.8586928],
+    [-6.3586963e-6, 0.0073859626, -2.615638],
+    [0.00021977515, -0.23563138, 60.716778],
+    [0.00017496727, -0.18760934, 48.25442],
+    [0.00014349881, -0.15387043, 39.49336],
+    [0.0001196415, -0.1282812, 32.844444],
+    [0.00010060455, -0.107853696, 27.53345],
+    [8.484283e-5, -0.09093368, 23.131714],
+    [7.14215e-5, -0.07652036, 19.379892],
+    [5.9736707e-5, -0.06396733, 16.110466],
+    [4.9378235e-5, -0.052835517, 13.209649],
+    [4.0055987e-5, -0.04281444, 10.596987],
+    [3.155775e-5, -0.03367703, 8.213638],
+    [2.3724138e-5, -0.02525276, 6.015424],
+    [1.6432712e-5, -0.017410634, 3.9684012],
+    [9.587848e-6, -0.010048328, 2.0460513],
+    [3.1142215e-6, -0.00308515, 0.22744656],
+    [-3.0480148e-6, 0.0035431155, -1.5040889],
+    [0.00021788046, -0.23402609, 60.450504],
+    [0.00017471971, -0.18772697, 48.421185],
+    [0.0001441285, -0.15490085, 39.888103],
+    [0.000120808974, -0.129869, 33.377827],
+    [0.000102136604, -0.1098185, 28.160458],
+    [8.664193e-5, -0.09317446, 23.827324],
+    [7.342875e-5, -0.07897644, 20.129192],
+    [6.191462e-5, -0.06660028, 16.904087],
+    [5.170188e-5, -0.055619884, 14.04143],
+    [4.2508174e-5, -0.045732744, 11.462719],
+    [3.412629e-5, -0.036716875, 9.11035],
+    [2.6400054e-5, -0.028405013, 6.94092],
+    [1.920912e-5, -0.020668263, 4.920986],
+    [1.2459128e-5, -0.013405559, 3.0243204],
+    [6.0753164e-6, -0.0065367687, 1.2301188],
+    [-2.0235829e-9, 2.1846101e-6, -0.47827095],
+    [7.016019e-5, -0.04163576, -1.4215592],
+    [-1.7825787e-5, 0.047677986, -22.969955],
+    [-5.4989345e-5, 0.084247895, -31.353714],
+    [-7.6508186e-5, 0.10495824, -35.920795],
+    [-9.1748916e-5, 0.11947843, -39.068344],
+    [-0.000103904786, 0.1310457, -41.57529],
+    [-0.000114173316, 0.14084397, -43.713024],
+    [-0.00012314486, 0.14944458, -45.607178],
+    [-0.00013117802, 0.15719059, -47.331623],
+    [-0.00013851842, 0.16431588, -48.93647],
+    [-0.00014534661, 0.17099236, -50.45869],
+    [-0.00015180217, 0.17735334, -51.927216],
+    [-0.00015799781, 0.18350703, -53.365856],
+    [-0.00016402727, 0.18954411, -54.794872],
+    [-0.00016997183, 0.19554396, -56.232338],
+    [-0.00017590458, 0.20157881, -57.69503],
+    [0.00022940194, -0.2307134, 54.572018],
+    [0.00012072888, -0.11580921, 25.109867],
+    [6.251322e-5, -0.054097235, 9.2309475],
+    [2.512345e-5, -0.014482964, -0.9522774],
+    [-1.5294117e-6, 0.013715581, -8.184918],
+    [-2.1940194e-5, 0.035273362, -13.70033],
+    [-3.8484264e-5, 0.05271642, -18.15243],
+    [-5.2528627e-5, 0.0674996, -21.918316],
+    [-6.483447e-5, 0.080436386, -25.209398],
+    [-7.585111e-5, 0.09200913, -28.151306],
+    [-8.58807e-5, 0.10254254, -30.828634],
+    [-9.514564e-5, 0.11227535, -33.3035],
+    [-0.000103819264, 0.12139331, -35.62419],
+    [-0.000112041795, 0.13004641, -37.82965],
+    [-0.000119930795, 0.13836025, -39.952484],
+    [-0.0001275881, 0.14644353, -42.02092],
+    [0.00024687315, -0.25310212, 61.725117],
+    [0.00015136218, -0.15234454, 35.979618],
+    [9.6936106e-5, -0.094658405, 21.142635],
+    [6.0229926e-5, -0.055656184, 11.0777025],
+    [3.318438e-5, -0.026886184, 3.6431332],
+    [1.2037195e-5, -0.0043800846, -2.1752872],
+    [-5.252792e-6, 0.014022832, -6.9328265],
+    [-1.991509e-5, 0.029626664, -10.965758],
+    [-3.2744894e-5, 0.043275874, -14.492348],
+    [-4.4253124e-5, 0.055514783, -17.653715],
+    [-5.4764405e-5, 0.06669115, -20.540407],
+    [-6.450259e-5, 0.077045746, -23.21529],
+    [-7.363632e-5, 0.086760186, -25.725866],
+    [-8.230014e-5, 0.095979355, -28.11005],
+    [-9.060635e-5, 0.10482417, -30.399498],
+    [-9.865224e-5, 0.113399304, -32.621628],
+    [0.00024976334, -0.25827286, 63.808662],
+    [0.00016334649, -0.1671044, 40.517],
+    [0.000112920396, -0.113705285, 26.803274],
+    [7.8174475e-5, -0.07680177, 17.287994],
+    [5.207278e-5, -0.04902229, 10.106229],
+    [3.1363048e-5, -0.026951272, 4.3907366],
+    [1.4261989e-5, -0.008709336, -0.3381908],
+    [-3.1542606e-7, 0.006850267, -4.3745556],
+    [-1.3080801e-5, 0.020481016, -7.9121604],
+    [-2.4525985e-5, 0.03270444, -11.085489],
+    [-3.498977e-5, 0.04388085, -13.987748],
+    [-4.4702803e-5, 0.054256797, -16.68299],
+    [-5.3831816e-5, 0.06401127, -19.217878],
+    [-6.250625e-5, 0.07328352, -21.628805],
+    [-7.0832706e-5, 0.08218832, -23.94581],
+    [-7.890293e-5, 0.0908245, -26.194788],
+    [0.00024874718, -0.25869286, 64.44922],
+    [0.00016923544, -0.17472741, 42.972534],
+    [0.000121929435, -0.12462987, 30.108913],
+    [8.898621e-5, -0.08965212, 21.096533],
+    [6.3987e-5, -0.06305131, 14.223227],
+    [4.3963173e-5, -0.04170814, 8.696575],
+    [2.730231e-5, -0.02392609, 4.0847044],
+    [1.302449e-5, -0.008671917, 0.123720564],
+    [4.8579835e-7, 0.0047346894, -3.3607054],
+    [-1.0762945e-5, 0.016769022, -6.4907007],
+    [-2.1046735e-5, 0.027775481, -9.354981],
+    [-3.0599225e-5, 0.038002454, -12.017741],
+    [-3.9588453e-5, 0.04762942, -14.525558],
+    [-4.814158e-5, 0.05679274, -16.913948],
+    [-5.6361423e-5, 0.0656029, -19.211761],
+    [-6.4335596e-5, 0.07415417, -21.443659],
+    [0.0002463898, -0.2573778, 64.530464],
+    [0.00017250226, -0.17925242, 44.515438],
+    [0.00012769773, -0.13177411, 32.316284],
+    [9.624018e-5, -0.09836817, 23.708986],
+    [7.223087e-5, -0.07282161, 17.110153],
+    [5.289405e-5, -0.05221122, 11.774882],
+    [3.672156e-5, -0.034948416, 7.2982297],
+    [2.2803262e-5, -0.020073788, 3.4353266],
+    [1.0543941e-5, -0.0069590854, 0.025481604],
+    [-4.709693e-7, 0.004833867, -3.0436482],
+    [-1.0544305e-5, 0.015625525, -5.8544106],
+    [-1.9902662e-5, 0.025656069, -8.468674],
+    [-2.871444e-5, 0.035104603, -10.932735],
+    [-3.7106227e-5, 0.04410636, -13.28169],
+    [-4.517866e-5, 0.052769206, -15.543623],
+    [-5.3016476e-5, 0.061184157, -17.742298],
+    [0.00024361715, -0.25543064, 64.380226],
+    [0.00017446296, -0.18221033, 45.589138],
+    [0.00013175122, -0.13690816, 33.936504],
+    [0.00010152471, -0.10479236, 25.657331],
+    [7.835361e-5, -0.08013127, 19.286482],
+    [5.962871e-5, -0.06017076, 14.119946],
+    [4.3917993e-5, -0.04339936, 9.771374],
+    [3.0356923e-5, -0.028904498, 6.0074477],
+    [1.8383149e-5, -0.016092304, 2.6762123],
+    [7.6070805e-6, -0.00455104, -0.32783693],
+    [-2.2553136e-6, 0.0060197334, -3.0818195],
+    [-1.1419244e-5, 0.015847888, -5.6443377],
+    [-2.004999e-5, 0.025108771, -8.060601],
+    [-2.8273787e-5, 0.033936802, -10.365388],
+    [-3.6190137e-5, 0.042438343, -12.586323],
+    [-4.3881882e-5, 0.0507022, -14.746537],
+    [0.0002408305, -0.2533391, 64.146614],
+    [0.00017573111, -0.18431666, 46.401447],
+    [0.00013482013, -0.14087845, 35.213795],
+    [0.00010563759, -0.10984831, 27.20769],
+    [8.317423e-5, -0.08592838, 21.025362],
+    [6.4974265e-5, -0.06652125, 16.001057],
+    [4.9672453e-5, -0.050183, 11.764673],
+    [3.643928e-5, -0.03603634, 8.09134],
+    [2.4734814e-5, -0.02351024, 4.834669],
+    [1.4186672e-5, -0.012210769, 1.8936167],
+    [4.524661e-6, -0.001851988, -0.8052303],
+    [-4.455965e-6, 0.0077829, -3.3176062],
+    [-1.2915016e-5, 0.016863285, -5.687135],
+    [-2.0977479e-5, 0.025521977, -7.948112],
+    [-2.8742252e-5, 0.033864446, -10.127868],
+    [-3.6290992e-5, 0.041978054, -12.249118],
+    [0.00023822142, -0.25134307, 63.904644],
+    [0.00017663477, -0.18595539, 47.06434],
+    [0.00013729947, -0.14414303, 36.280373],
+    [0.00010902021, -0.11404647, 28.506966],
+    [8.7161425e-5, -0.0907545, 22.482521],
+    [6.940875e-5, -0.07181495, 17.576996],
+    [5.4459295e-5, -0.055847283, 13.435659],
+    [4.1514588e-5, -0.04200546, 9.8411045],
+    [3.0052464e-5, -0.029736236, 6.6511626],
+    [1.9712712e-5, -0.018657962, 3.767749],
+    [1.0234854e-5, -0.008494648, 1.1199088],
+    [1.4221903e-6, 0.000962147, -1.3459837],
+    [-6.87945e-6, 0.009875872, -3.6720243],
+    [-1.47927785e-5, 0.018376796, -5.891824],
+    [-2.2416167e-5, 0.02656965, -8.032483],
+    [-2.9830593e-5, 0.034540925, -10.116456],
+    [0.00023588649, -0.24956876, 63.695393],
+    [0.00017736689, -0.18735349, 47.644382],
+    [0.00013942445, -0.14697489, 37.21511],
+    [0.00011193766, -0.11769324, 29.643309],
+    [9.060185e-5, -0.09494047, 23.752972],
+    [7.32319e-5, -0.07639757, 18.946978],
+    [5.8583333e-5, -0.06074367, 14.885169],
+    [4.5886834e-5, -0.047162246, 11.357242],
+    [3.4636163e-5, -0.035115965, 8.224795],
+    [2.4480929e-5, -0.02423295, 5.3920417],
+    [1.5167734e-5, -0.014244288, 2.789706],
+    [6.5057e-6, -0.0049474305, 0.36559922],
+    [-1.6546622e-6, 0.0038162516, -1.9211729],
+    [-9.433375e-6, 0.01217419, -4.1034966],
+    [-1.6928077e-5, 0.020230237, -6.208228],
+    [-2.421942e-5, 0.028070437, -8.257684],
+    [0.00023387633, -0.24808633, 63.542877],
+    [0.00017804989, -0.1886557, 48.184273],
+    [0.00014134614, -0.14954959, 38.06882],
+    [0.0001145607, -0.12098599, 30.673492],
+    [9.368169e-5, -0.098700985, 24.898325],
+    [7.664133e-5, -0.080496706, 20.176237],
+    [6.224934e-5, -0.065107875, 16.18072],
+    [4.9763967e-5, -0.05174596, 12.708259],
+    [3.8694052e-5, -0.039888807, 9.624078],
+    [2.8698081e-5, -0.029173354, 6.8344536],
+    [1.9528447e-5, -0.019336391, 4.2714357],
+    [1.0998627e-5, -0.010179683, 1.8838465],
+    [2.9627054e-6, -0.0015481807, -0.3683489],
+    [-4.696832e-6, 0.0066829887, -2.5174096],
+    [-1.2076667e-5, 0.014616656, -4.5899334],
+    [-1.9257308e-5, 0.022338638, -6.6081667],
+    [0.0002322188, -0.24693748, 63.4622],
+    [0.0001787666, -0.18996061, 48.71327],
+    [0.0001431682, -0.15198776, 38.87647],
+    [0.00011700645, -0.124060094, 31.636461],
+    [9.65288e-5, -0.10218339, 25.960827],
+    [7.977361e-5, -0.08426952, 21.309763],
+    [6.560049e-5, -0.0691045, 17.369394],
+    [5.3293545e-5, -0.055926148, 13.942626],
+    [4.2375937e-5, -0.044226788, 10.898163],
+    [3.2514574e-5, -0.033651773, 8.144273],
+    [2.3467117e-5, -0.023943081, 5.614209],
+    [1.5050669e-5, -0.014906023, 3.2576075],
+    [7.1220993e-6, -0.006388273, 1.0350622],
+    [-4.3400786e-7, 0.0017329643, -1.0851976],
+    [-7.713293e-6, 0.009559473, -3.1295218],
+    [-1.4796173e-5, 0.017176952, -5.1201305],
+    [0.0002309306, -0.24614926, 63.463673],
+    [0.00017957679, -0.19133958, 49.25267],
+    [0.00014496608, -0.15437728, 39.663563],
+    [0.00011935991, -0.12701377, 32.56047],
+    [9.9235716e-5, -0.105494246, 26.971022],
+    [8.272768e-5, -0.08782976, 22.380095],
+    [6.87411e-5, -0.072853334, 18.485415],
+    [5.658405e-5, -0.05982728, 15.095874],
+    [4.5793186e-5, -0.04825762, 12.083487],
+    [3.6043446e-5, -0.03779775, 9.358438],
+    [2.7097509e-5, -0.028194632, 6.855147],
+    [1.877579e-5, -0.019256817, 4.523993],
+    [1.093754e-5, -0.010834283, 2.3260942],
+    [3.4691002e-6, -0.0028059336, 0.23006466],
+    [-3.7241432e-6, 0.004929023, -1.7902234],
+    [-1.0722472e-5, 0.012456165, -3.7569728],
+    [0.00023002364, -0.24574172, 63.555153],
+    [0.00018052658, -0.19284791, 49.81901],
+    [0.00014679789, -0.15678592, 40.44978],
+    [0.000121686164, -0.12992224, 33.467155],
+    [0.000101872916, -0.10871467, 27.952137],
+    [8.557869e-5, -0.09126371, 23.411888],
+    [7.175042e-5, -0.07644526, 19.554718],
+    [5.9718197e-5, -0.063544154, 16.195032],
+    [4.903132e-5, -0.052079238, 13.207955],
+    [3.9372273e-5, -0.041711513, 10.505488],
+    [3.0508505e-5, -0.0321927, 8.023175],
+    [2.22636e-5, -0.023334462, 5.7121186],
+    [1.4499011e-5, -0.014988922, 3.5339222],
+    [7.1026634e-6, -0.0070365025, 1.4575422],
+    [-1.9057708e-8, 0.00062264426, -0.54294366],
+    [-6.9462076e-6, 0.008073907, -2.4897113],
+    [0.00022950862, -0.24573246, 63.743397],
+    [0.00018165419, -0.19453137, 50.425995],
+    [0.00014871123, -0.15926912, 41.25122],
+    [0.00012403795, -0.13284637, 34.37401],
+    [0.000104497, -0.11190971, 28.922783],
+    [8.8386485e-5, -0.09464003, 24.424736],
+    [7.469128e-5, -0.07995227, 20.59782],
+    [6.276161e-5, -0.067151874, 17.261488],
+    [5.2158375e-5, -0.05576945, 14.293707],
+    [4.2571068e-5, -0.045473088, 11.608181],
+    [3.3771645e-5, -0.036018997, 9.141519],
+    [2.5586694e-5, -0.027221823, 6.845517],
+    [1.7879776e-5, -0.018935744, 4.6822557],
+    [1.0540336e-5, -0.011042649, 2.6210213],
+    [3.475764e-6, -0.0034436479, 0.6361021],
+    [-3.3936267e-6, 0.0039463267, -1.2946194],
+    [0.0002293973, -0.24613923, 64.03491],
+    [0.00018299335, -0.19643024, 51.08563],
+    [0.0001507473, -0.16187479, 42.081764],
+    [0.00012646026, -0.13583803, 35.29591],
+    [0.000107155574, -0.11513407, 29.898588],
+    [9.120112e-5, -0.098016076, 25.434986],
+    [7.7615936e-5, -0.08343416, 21.631742],
+    [6.57686e-5, -0.07071252, 18.312891],
+    [5.5230565e-5, -0.059392493, 15.3589945],
+    [4.5697783e-5, -0.049148645, 12.685308],
+    [3.6946356e-5, -0.03974134, 10.229425],
+    [2.8805682e-5, -0.03098801, 7.9437947],
+    [2.1141317e-5, -0.022744795, 5.790952],
+    [1.3844176e-5, -0.014895018, 3.740512],
+    [6.822689e-6, -0.0073407847, 1.7669876],
+    [-2.3156113e-9, 2.4995027e-6, -0.15164144],
+    [0.00013331545, -0.11148331, 18.128317],
+    [1.7814042e-5, 0.0070969258, -11.149143],
+    [-2.8814628e-5, 0.05396347, -22.340954],
+    [-5.492395e-5, 0.07979865, -28.349676],
+    [-7.2457995e-5, 0.09699542, -32.285725],
+    [-8.5888474e-5, 0.11014426, -35.284218],
+    [-9.711824e-5, 0.12117588, -37.813644],
+    [-0.00010702396, 0.13096783, -40.0817],
+    [-0.00011608785, 0.13999784, -42.19924],
+    [-0.0001246213, 0.14857322, -44.23707],
+    [-0.00013285135, 0.15691864, -46.247215],
+    [-0.00014096033, 0.16521613, -48.27247],
+    [-0.00014910752, 0.17362674, -50.3515],
+    [-0.00015744317, 0.18230464, -52.52221],
+    [-0.00016611945, 0.19140846, -54.8245],
+    [-0.00017530081, 0.20111202, -57.30295],
+    [0.0002617917, -0.2639679, 63.30525],
+    [0.00014056412, -0.13685012, 30.946966],
+    [7.80518e-5, -0.0709794, 14.072433],
+    [3.8400234e-5, -0.029141104, 3.3412364],
+    [1.0301567e-5, 0.00051661313, -4.2632747],
+    [-1.1127242e-5, 0.023137413, -10.059678],
+    [-2.8395641e-5, 0.041371126, -14.729705],
+    [-4.2972584e-5, 0.05677027, -18.67352],
+    [-5.5787757e-5, 0.07031797, -22.144844],
+    [-6.74246e-5, 0.08263297, -25.303734],
+    [-7.826471e-5, 0.09412162, -28.255613],
+    [-8.858391e-5, 0.105078585, -31.077154],
+    [-9.86018e-5, 0.115739144, -33.82975],
+    [-0.00010850871, 0.12630774, -36.566944],
+    [-0.00011848283, 0.13697636, -39.339207],
+    [-0.0001287032, 0.14793852, -42.19766],
+    [0.00027203438, -0.27834874, 68.257614],
+    [0.00016582891, -0.16706815, 39.969196],
+    [0.00010836654, -0.10656385, 24.48947],
+    [7.022235e-5, -0.06625778, 14.132256],
+    [4.2238287e-5, -0.036617365, 6.495668],
+    [2.0362459e-5, -0.013406056, 0.50535804],
+    [2.4468138e-6, 0.005631681, -4.4141636],
+    [-1.2780761e-5, 0.02183568, -8.606252],
+    [-2.6144324e-5, 0.036075596, -12.294482],
+    [-3.8220616e-5, 0.048960827, -15.635904],
+    [-4.9421567e-5, 0.06092769, -18.743307],
+    [-6.004216e-5, 0.07229014, -21.69821],
+    [-7.030788e-5, 0.083289385, -24.56356],
+    [-8.040745e-5, 0.09412822, -27.392519],
+    [-9.051333e-5, 0.104992636, -30.234053],
+    [-0.000100796016, 0.116067104, -33.136932],
+    [0.00027170894, -0.28013387, 69.46132],
+    [0.00017568929, -0.17939521, 43.81269],
+    [0.00012238158, -0.123267226, 29.457392],
+    [8.6423606e-5, -0.0852824, 19.703388],
+    [5.9607602e-5, -0.05687556, 12.385166],
+    [3.8337286e-5, -0.034290932, 6.552134],
+    [2.0716641e-5, -0.015544024, 1.7004402],
+    [5.616238e-6, 0.0005507255, -2.4721668],
+    [-7.696758e-6, 0.014764631, -6.163086],
+    [-1.973397e-5, 0.027637318, -9.510879],
+    [-3.0877905e-5, 0.039572865, -12.619598],
+    [-4.1423034e-5, 0.05088316, -15.569836],
+    [-5.159785e-5, 0.06181138, -18.424746],
+    [-6.1589984e-5, 0.07255834, -21.236765],
+    [-7.1567236e-5, 0.08330451, -24.053267],
+    [-8.169299e-5, 0.094226316, -26.920778],
+    [0.00026887376, -0.27871922, 69.64809],
+    [0.00018071682, -0.18606523, 46.00473],
+    [0.00013054183, -0.13318838, 32.467705],
+    [9.636188e-5, -0.09707148, 23.19233],
+    [7.068312e-5, -0.06986826, 16.185802],
+    [5.016075e-5, -0.04807634, 10.558492],
+    [3.303684e-5, -0.029854069, 5.8423285],
+    [1.8272734e-5, -0.014111944, 1.7599187],
+    [5.197008e-6, -0.0001442509, -1.8689177],
+    [-6.656871e-6, 0.012540448, -5.1700506],
+    [-1.7635688e-5, 0.02430819, -8.237524],
+    [-2.801415e-5, 0.03544943, -11.146192],
+    [-3.8016336e-5, 0.04620183, -13.957535],
+    [-4.782871e-5, 0.05676414, -16.723251],
+    [-5.7616304e-5, 0.067313254, -19.489609],
+    [-6.7537774e-5, 0.078020036, -22.301525],
+    [0.00026551416, -0.2764551, 69.512505],
+    [0.00018374332, -0.19035225, 47.488426],
+    [0.00013608001, -0.14005703, 34.5922],
+    [0.000103304155, -0.1053961, 25.683233],
+    [7.855869e-5, -0.07917039, 18.92634],
+    [5.8705326e-5, -0.058084466, 13.481269],
+    [4.207471e-5, -0.040385053, 8.9008875],
+    [2.7680404e-5, -0.025035663, 4.9207335],
+    [1.4888735e-5, -0.011369853, 1.3705575],
+    [3.2623718e-6, 0.001073039, -1.8675671],
+    [-7.5210523e-6, 0.012633224, -4.880967],
+    [-1.7715614e-5, 0.023579333, -7.7387943],
+    [-2.7533868e-5, 0.03413653, -10.499193],
+    [-3.7158712e-5, 0.04449926, -13.212592],
+    [-4.675308e-5, 0.054841645, -15.924394],
+    [-5.6472516e-5, 0.06533075, -18.678389],
+    [0.0002623254, -0.27418688, 69.31139],
+    [0.00018586221, -0.19352327, 48.62964],
+    [0.00014029318, -0.14536755, 36.259716],
+    [0.000108658554, -0.11187701, 27.640652],
+    [8.4663465e-5, -0.08642732, 21.078491],
+    [6.5359374e-5, -0.06591484, 15.77932],
+    [4.915382e-5, -0.04866288, 11.314113],
+    [3.5097342e-5, -0.03367143, 7.4269047],
+    [2.2579048e-5, -0.020296758, 3.952827],
+    [1.1179186e-5, -0.008096142, 0.77837586],
+    [5.90674e-7, 0.0032547538, -2.1797519],
+    [-9.42614e-6, 0.014009472, -4.9868336],
+    [-1.9071551e-5, 0.02438026, -7.6976585],
+    [-2.8522163e-5, 0.03455469, -10.3608055],
+    [-3.793847e-5, 0.044703916, -13.0208],
+    [-4.747396e-5, 0.05499242, -15.720652],
+    [0.00025959217, -0.27227533, 69.158844],
+    [0.00018759782, -0.19618832, 49.605316],
+    [0.0001438102, -0.1498445, 37.67817],
+    [0.00011312641, -0.11732017, 29.295218],
+    [8.9741094e-5, -0.092493154, 22.886488],
+    [7.087867e-5, -0.072435595, 17.700916],
+    [5.5018703e-5, -0.05554317, 13.326798],
+    [4.124511e-5, -0.04084887, 9.515883],
+    [2.8964303e-5, -0.027725808, 6.1071463],
+    [1.7767521e-5, -0.015742002, 2.9895504],
+    [7.3567126e-6, -0.0045821494, 0.0819554],
+    [-2.4989208e-6, 0.005998235, -2.6786618],
+    [-1.1990535e-5, 0.016201936, -5.344686],
+    [-2.128767e-5, 0.02620908, -7.962764],
+    [-3.0547668e-5, 0.03618729, -10.576449],
+    [-3.9922357e-5, 0.04629899, -13.228131],
+    [0.0002574436, -0.27089423, 69.11272],
+    [0.00018924101, -0.19868746, 50.51463],
+    [0.00014698277, -0.15389329, 38.96399],
+    [0.00011710207, -0.12217838, 30.776396],
+    [9.4217736e-5, -0.0978565, 24.48982],
+    [7.570927e-5, -0.07815796, 19.39193],
+    [6.0122908e-5, -0.061545786, 15.087283],
+    [4.6574303e-5, -0.047084622, 11.335128],
+    [3.4485976e-5, -0.034163367, 7.97808],
+    [2.3457978e-5, -0.022358498, 4.906985],
+    [1.31980405e-5, -0.011360236, 2.041931],
+    [3.4805093e-6, -0.0009291474, -0.6789291],
+    [-5.88006e-6, 0.00913179, -3.3065674],
+    [-1.5047334e-5, 0.01899666, -5.8861046],
+    [-2.4175044e-5, 0.028829234, -8.460085],
+    [-3.3413588e-5, 0.03879023, -11.070402],
+    [0.00025594674, -0.27013978, 69.20675],
+    [0.00019097357, -0.20123424, 51.420162],
+    [0.00015003169, -0.15776868, 40.1904],
+    [0.00012083221, -0.12673467, 32.16506],
+    [9.836102e-5, -0.10282381, 25.975845],
+    [8.013506e-5, -0.08340673, 20.944857],
+    [6.476115e-5, -0.06700782, 16.691534],
+    [5.138458e-5, -0.052721456, 12.982224],
+    [3.944337e-5, -0.03995187, 9.663136],
+    [2.8545996e-5, -0.028283715, 6.626968],
+    [1.8405026e-5, -0.017411796, 3.7948074],
+    [8.7981625e-6, -0.007099826, 1.1054856],
+    [-4.5669287e-7, 0.0028459842, -1.4912276],
+    [-9.519229e-6, 0.0125957355, -4.0394845],
+    [-1.8539742e-5, 0.022309665, -6.5809374],
+    [-2.7667262e-5, 0.032146953, -9.157055],
+    [0.0002551447, -0.27007636, 69.46416],
+    [0.00019292362, -0.20397942, 52.36622],
+    [0.00015311141, -0.1616488, 41.408592],
+    [0.0001244874, -0.1311846, 33.51703],
+    [0.00010235459, -0.10760506, 27.40434],
+    [8.435105e-5, -0.08840461, 22.42302],
+    [6.913768e-5, -0.07216243, 18.205803],
+    [5.588673e-5, -0.057999957, 14.525523],
+    [4.4050703e-5, -0.04533584, 11.231784],
+    [3.324624e-5, -0.033762746, 8.219138],
+    [2.3190647e-5, -0.022980029, 5.409692],
+    [1.3664519e-5, -0.012754123, 2.7428622],
+    [4.4880017e-6, -0.0028933305, 0.16886899],
+    [-4.4960375e-6, 0.0067699766, -2.3558695],
+    [-1.3435267e-5, 0.01639342, -4.87237],
+    [-2.247736e-5, 0.026134746, -7.42173],
+    [0.00025507543, -0.27075902, 69.90434],
+    [0.000195192, -0.20704167, 53.38763],
+    [0.00015634122, -0.16567107, 42.658028],
+    [0.00012819719, -0.13567647, 34.874584],
+    [0.000106336374, -0.112357736, 28.820057],
+    [8.85026e-5, -0.09331736, 23.873455],
+    [7.340456e-5, -0.07718289, 19.679234],
+    [6.02385e-5, -0.06309984, 16.015974],
+    [4.846997e-5, -0.050499823, 12.73634],
+    [3.7723174e-5, -0.038982958, 9.736615],
+    [2.7719996e-5, -0.028253054, 6.939925],
+    [1.8243907e-5, -0.018079305, 4.286303],
+    [9.117028e-6, -0.008271879, 1.726375],
+    [1.8400512e-7, 0.0013351717, -0.7830649],
+    [-8.700782e-6, 0.0108974725, -3.2825627],
+    [-1.7683687e-5, 0.02057145, -5.812892],
+    [0.0002557821, -0.27224576, 70.54671],
+    [0.00019786891, -0.21052642, 54.51518],
+    [0.00015982371, -0.16995339, 43.972446],
+    [0.0001320704, -0.14033456, 36.273064],
+    [0.000110419845, -0.11721113, 30.25967],
+    [9.2707734e-5, -0.09827928, 25.334208],
+    [7.768426e-5, -0.08220848, 21.151245],
+    [6.456653e-5, -0.06816509, 17.494322],
+    [5.28315e-5, -0.055592004, 14.218734],
+    [4.2110023e-5, -0.044095844, 11.222269],
+    [3.2128108e-5, -0.03338445, 8.428971],
+    [2.2671959e-5, -0.023229659, 5.7794766],
+    [1.35656255e-5, -0.013443512, 3.2248373],
+    [4.6553746e-6, -0.003861621, 0.72220606],
+    [-4.2027295e-6, 0.0056699957, -1.7685755],
+    [-1.3153741e-5, 0.015306484, -4.28797],
+    [0.00025731925, -0.2746057, 71.41304],
+    [0.00020104324, -0.21453695, 55.778774],
+    [0.00016365577, -0.17460668, 45.38347],
+    [0.0001362071, -0.14527288, 37.7448],
+    [0.00011470758, -0.12228184, 31.75616],
+    [9.707139e-5, -0.10340956, 26.838955],
+    [8.208425e-5, -0.08736122, 22.65631],
+    [6.8981e-5, -0.073320776, 18.995888],
+    [5.7248166e-5, -0.06074049, 15.715148],
+    [4.6522146e-5, -0.049232353, 12.713086],
+    [3.6532372e-5, -0.0385075, 9.914484],
+    [2.7067386e-5, -0.028340006, 7.260481],
+    [1.795298e-5, -0.018543616, 4.7025204],
+    [9.036916e-6, -0.008955415, 2.1981044],
+    [1.7686176e-7, 0.00057697686, -0.29253408],
+    [-8.770775e-6, 0.0102072405, -2.809492],
+    [0.00025975943, -0.27792633, 72.529785],
+    [0.00020481145, -0.21918423, 57.210247],
+    [0.00016793766, -0.17974515, 46.923504],
+    [0.00014070814, -0.15060593, 39.322147],
+    [0.00011930062, -0.12768441, 33.341854],
+    [0.00010169522, -0.108823374, 28.420181],
+    [8.670721e-5, -0.092757426, 24.22722],
+    [7.3585965e-5, -0.078684784, 20.553913],
+    [6.182566e-5, -0.06606505, 17.259354],
+    [5.1066978e-5, -0.054514267, 14.243416],
+    [4.104195e-5, -0.043745965, 11.43138],
+    [3.1540803e-5, -0.03353581, 8.764723],
+    [2.239067e-5, -0.02369879, 6.195163],
+    [1.3440564e-5, -0.014073245, 3.6804996],
+    [4.5495294e-6, -0.0045081875, 1.181314],
+    [-4.424395e-6, 0.0051483884, -1.3420612],
+    [0.00026320014, -0.28232193, 73.9304],
+    [0.00020928476, -0.2245953, 58.84573],
+    [0.00017278045, -0.18549488, 48.62815],
+    [0.0001456827, -0.15645735, 41.039932],
+    [0.00012430667, -0.13354072, 35.05104],
+    [0.000106686006, -0.114641495, 30.111868],
+    [9.165962e-5, -0.09851754, 25.897865],
+    [7.84881e-5, -0.08437779, 22.202362],
+    [6.667126e-5, -0.0716871, 18.885542],
+    [5.5852746e-5, -0.060064107, 15.847773],
+    [4.5766174e-5, -0.0492237, 13.014566],
+    [3.620274e-5, -0.038942307, 10.327517],
+    [2.6990261e-5, -0.02903552, 7.738446],
+    [1.7978506e-5, -0.019342365, 5.205306],
+    [9.0275435e-6, -0.009712896, 2.688937],
+    [-3.0947735e-9, 3.3416702e-6, 0.15007572],
+    [0.00021577333, -0.19947816, 41.59882],
+    [5.8422695e-5, -0.037142448, 1.0474906],
+    [-1.0134797e-6, 0.023307005, -13.722147],
+    [-3.3445336e-5, 0.055944826, -21.555927],
+    [-5.4739416e-5, 0.07725566, -26.615913],
+    [-7.054719e-5, 0.09306857, -30.358877],
+    [-8.3471925e-5, 0.106050685, -33.4453],
+    [-9.484308e-5, 0.11755627, -36.207497],
+    [-0.00010538882, 0.12832431, -38.82532],
+    [-0.00011557051, 0.13882394, -41.412918],
+    [-0.00012573376, 0.14940988, -44.05761],
+    [-0.00013618171, 0.160398, -46.838734],
+    [-0.00014721988, 0.17211217, -49.83948],
+    [-0.00015919378, 0.18492411, -53.157204],
+    [-0.0001725338, 0.1993023, -56.916393],
+    [-0.00018781939, 0.21588252, -61.28763],
+    [0.0003125768, -0.3155924, 76.39237],
+    [0.0001683144, -0.16547357, 38.44023],
+    [9.864592e-5, -0.09250365, 19.839487],
+    [5.5210498e-5, -0.046865117, 8.166187],
+    [2.461743e-5, -0.014659828, -0.08289684],
+    [1.2934129e-6, 0.009935175, -6.3891],
+    [-1.7556782e-5, 0.029851582, -11.502101],
+    [-3.3526965e-5, 0.046765536, -15.852023],
+    [-4.7635735e-5, 0.061750438, -19.715101],
+    [-6.0602317e-5, 0.07556572, -23.28734],
+    [-7.295384e-5, 0.0887705, -26.71373],
+    [-8.510117e-5, 0.101803824, -30.108944],
+    [-9.7406046e-5, 0.115055494, -33.575634],
+    [-0.00011023279, 0.12892118, -37.21883],
+    [-0.00012399726, 0.14385541, -41.16],
+    [-0.0001392258, 0.16043606, -45.554348],
+    [0.00031476832, -0.32151246, 79.15989],
+    [0.00018907052, -0.19056326, 46.012695],
+    [0.00012573355, -0.12425813, 29.12839],
+    [8.474429e-5, -0.08116552, 18.100718],
+    [5.489847e-5, -0.04967965, 10.01338],
+    [3.156208e-5, -0.024988772, 3.653241],
+    [1.2356821e-5, -0.0046131467, -1.6084064],
+    [-4.1058397e-6, 0.012900935, -6.142134],
+    [-1.8716017e-5, 0.028489029, -10.187606],
+    [-3.2097345e-5, 0.042809214, -13.914227],
+    [-4.4734657e-5, 0.056375016, -17.454834],
+    [-5.7036665e-5, 0.0696214, -20.922592],
+    [-6.936697e-5, 0.08293825, -24.41971],
+    [-8.208122e-5, 0.09671014, -28.047852],
+    [-9.557232e-5, 0.111365035, -31.920942],
+    [-0.00011032578, 0.12743446, -36.181217],
+    [0.0003099318, -0.3187333, 79.239136],
+    [0.00019714395, -0.20095669, 49.337685],
+    [0.0001383212, -0.13931689, 33.625393],
+    [9.9748155e-5, -0.09875519, 23.245771],
+    [7.132372e-5, -0.06876473, 15.543627],
+    [4.8823425e-5, -0.044949677, 9.407656],
+    [3.0103665e-5, -0.025076754, 4.2724276],
+    [1.3917553e-5, -0.007843549, -0.19288409],
+    [-5.379634e-7, 0.007591856, -4.2031126],
+    [-1.3825296e-5, 0.021821735, -7.9101925],
+    [-2.6374002e-5, 0.03530077, -11.431456],
+    [-3.8549282e-5, 0.048417453, -14.867764],
+    [-5.0693685e-5, 0.061538145, -18.314829],
+    [-6.315086e-5, 0.075032964, -21.870058],
+    [-7.6297896e-5, 0.089311086, -25.6419],
+    [-9.059476e-5, 0.10487445, -29.764143],
+    [0.00030424283, -0.3145098, 78.75816],
+    [0.00020140677, -0.20685987, 51.341843],
+    [0.00014597893, -0.14868027, 36.481815],
+    [0.00010921641, -0.10998403, 26.569263],
+    [8.196937e-5, -0.081220984, 19.179594],
+    [6.029301e-5, -0.058272008, 13.266568],
+    [4.2162283e-5, -0.0390213, 8.292473],
+    [2.6403286e-5, -0.02224094, 3.9448576],
+    [1.2265012e-5, -0.007143561, 0.022862038],
+    [-7.7702396e-7, 0.006822765, -3.6149049],
+    [-1.3121438e-5, 0.020079643, -7.077058],
+    [-2.5101304e-5, 0.03298143, -10.455484],
+    [-3.702952e-5, 0.04586284, -13.837524],
+    [-4.923092e-5, 0.059073165, -17.314869],
+    [-6.206897e-5, 0.07300566, -20.991394],
+    [-7.598672e-5, 0.08814241, -24.995134],
+    [0.00029914285, -0.3106013, 78.25345],
+    [0.00020424997, -0.21102752, 52.816467],
+    [0.00015154168, -0.1555975, 38.625675],
+    [0.00011617323, -0.118315995, 29.059769],
+    [8.982242e-5, -0.09047187, 21.898773],
+    [6.879924e-5, -0.06820075, 16.157335],
+    [5.117436e-5, -0.049480796, 11.319573],
+    [3.5817273e-5, -0.03312619, 7.0826306],
+    [2.2003454e-5, -0.018375732, 3.2517896],
+    [9.228908e-6, -0.004698477, -0.30914035],
+    [-2.8876382e-6, 0.008308937, -3.7040823],
+    [-1.46620805e-5, 0.02098261, -7.0201435],
+    [-2.6386371e-5, 0.03363503, -10.33883],
+    [-3.836503e-5, 0.04659371, -13.746037],
+    [-5.0947372e-5, 0.060236044, -17.341225],
+    [-6.456407e-5, 0.07502956, -21.2482],
+    [0.00029507058, -0.3075835, 77.91252],
+    [0.00020662675, -0.21456285, 54.08005],
+    [0.00015615697, -0.16138315, 40.43229],
+    [0.00012189854, -0.12521379, 31.133734],
+    [9.623333e-5, -0.09805996, 24.14008],
+    [7.570098e-5, -0.0762886, 18.521858],
+    [5.846225e-5, -0.05796743, 13.784384],
+    [4.3425687e-5, -0.041948613, 9.633505],
+    [2.9884983e-5, -0.027488254, 5.8783064],
+    [1.7346607e-5, -0.014065368, 2.3847854],
+    [5.437542e-6, -0.001284801, -0.94903785],
+    [-6.1499595e-6, 0.01118105, -4.208114],
+    [-1.7696873e-5, 0.02363305, -7.470915],
+    [-2.9492749e-5, 0.03638281, -10.819143],
+    [-4.1873158e-5, 0.04979274, -14.348199],
+    [-5.5258806e-5, 0.064318806, -18.17855],
+    [0.00029217976, -0.30568406, 77.816696],
+    [0.0002090163, -0.21802357, 55.294556],
+    [0.00016040487, -0.16670208, 42.091537],
+    [0.0001270426, -0.1314195, 33.002155],
+    [0.000101905665, -0.104787044, 26.131123],
+    [8.17349e-5, -0.08337487, 20.598286],
+    [6.4773405e-5, -0.06533299, 15.928627],
+    [4.996729e-5, -0.049550645, 11.836677],
+    [3.662811e-5, -0.035301194, 8.135421],
+    [2.4270614e-5, -0.022071362, 4.692488],
+    [1.2525946e-5, -0.009469756, 1.4066437],
+    [1.0894196e-6, 0.0028282958, -1.8064163],
+    [-1.0315732e-5, 0.015119271, -5.0240498],
+    [-2.1971035e-5, 0.02770628, -8.325726],
+    [-3.4201177e-5, 0.04094005, -11.803716],
+    [-4.7418718e-5, 0.055267252, -15.5758295],
+    [0.00029054072, -0.30502042, 78.01119],
+    [0.0002117085, -0.22174835, 56.55877],
+    [0.0001646348, -0.17195468, 43.717808],
+    [0.00013199287, -0.13737482, 34.790462],
+    [0.00010725865, -0.11113003, 28.007017],
+    [8.734621e-5, -0.08996557, 22.529848],
+    [7.057116e-5, -0.072104014, 17.901308],
+    [5.5913893e-5, -0.05646861, 13.843942],
+    [4.270336e-5, -0.04234974, 10.17468],
+    [3.0463105e-5, -0.029242596, 6.763051],
+    [1.8828263e-5, -0.016759474, 3.5085864],
+    [7.4955055e-6, -0.0045768195, 0.32715276],
+    [-3.8112837e-6, 0.007601274, -2.8585057],
+    [-1.5370886e-5, 0.020075014, -6.1270976],
+    [-2.7501539e-5, 0.033188228, -9.569045],
+    [-4.0609644e-5, 0.047380686, -13.300196],
+    [0.0002902121, -0.30568624, 78.53117],
+    [0.00021491433, -0.22598356, 57.944496],
+    [0.00016909452, -0.17742454, 45.39199],
+    [0.00013701813, -0.14338556, 36.585335],
+    [0.0001125788, -0.11741415, 29.859718],
+    [9.2837756e-5, -0.09640413, 24.413532],
+    [7.617269e-5, -0.07864007, 19.803917],
+    [6.1593644e-5, -0.063074514, 15.760292],
+    [4.844507e-5, -0.049013045, 12.10312],
+    [3.62591e-5, -0.03595924, 8.703853],
+    [2.46753e-5, -0.023529679, 5.46299],
+    [1.3392186e-5, -0.011402546, 2.2967696],
+    [2.133744e-6, 0.0007180336, -0.8720902],
+    [-9.379134e-6, 0.013132581, -4.1223674],
+    [-2.146232e-5, 0.02618236, -7.5437713],
+    [-3.451848e-5, 0.040302943, -11.250934],
+    [0.00029127227, -0.30778983, 79.41346],
+    [0.00021881798, -0.23094286, 59.513588],
+    [0.00017399009, -0.18334667, 47.18089],
+    [0.00014233497, -0.14969751, 38.456245],
+    [0.0001180917, -0.12389488, 31.761267],
+    [9.8444216e-5, -0.10295606, 26.324131],
+    [8.182179e-5, -0.08521686, 21.714079],
+    [6.7258865e-5, -0.06965369, 17.666067],
+    [5.411263e-5, -0.055584516, 14.003362],
+    [4.19223e-5, -0.04251973, 10.598963],
+    [3.03316e-5, -0.030079978, 7.3543043],
+    [1.9041274e-5, -0.017945686, 4.186157],
+    [7.775901e-6, -0.005821621, 1.01741],
+    [-3.7443504e-6, 0.0065933852, -2.2308457],
+    [-1.5835598e-5, 0.019640712, -5.6482825],
+    [-2.889915e-5, 0.033754323, -9.349004],
+    [0.0002938384, -0.31147772, 80.703804],
+    [0.0002236073, -0.2368421, 61.327923],
+    [0.00017952015, -0.18994659, 49.148277],
+    [0.00014814528, -0.15653898, 40.467476],
+    [0.00012400217, -0.13080345, 33.776676],
+    [0.00010437414, -0.1098567, 28.327766],
+    [8.7731656e-5, -0.09207493, 23.699373],
+    [7.312811e-5, -0.07645277, 19.630539],
+    [5.992994e-5, -0.062316857, 15.946404],
+    [4.76813e-5, -0.04918219, 12.520932],
+    [3.6028763e-5, -0.03667207, 9.256125],
+    [2.4674691e-5, -0.024468528, 6.069134],
+    [1.3344254e-5, -0.012277044, 2.883058],
+    [1.7574803e-6, 0.00020347729, -0.38091835],
+    [-1.0402726e-5, 0.013315184, -3.8125587],
+    [-2.3537832e-5, 0.027491964, -7.525816],
+    [0.00029808705, -0.31695876, 82.464],
+    [0.0002294993, -0.24392822, 63.457527],
+    [0.00018590191, -0.19746965, 51.363174],
+    [0.00015466304, -0.1641511, 42.686695],
+    [0.00013052212, -0.13837853, 35.97284],
+    [0.000110839086, -0.11734423, 30.491182],
+    [9.4114876e-5, -0.09945363, 25.826883],
+    [7.941602e-5, -0.08371347, 21.72149],
+    [6.6114626e-5, -0.06945529, 18.001032],
+    [5.3757194e-5, -0.056195866, 14.539705],
+    [4.1990985e-5, -0.04355887, 11.239472],
+    [3.051842e-5, -0.031226238, 8.01741],
+    [1.9064324e-5, -0.018903168, 4.7965646],
+    [7.3481356e-6, -0.006288179, 1.498112],
+    [-4.9481578e-6, 0.0069614393, -1.9677066],
+    [-1.8226567e-5, 0.021279765, -5.7147107],
+    [0.0003042795, -0.32453325, 84.78014],
+    [0.00023676659, -0.25250906, 65.988945],
+    [0.00019339693, -0.20621008, 53.907944],
+    [0.00016213983, -0.17281656, 45.19294],
+    [0.00013789629, -0.14689504, 38.42663],
+    [0.000118079595, -0.12568891, 32.88998],
+    [0.00010120994, -0.10762104, 28.171621],
+    [8.636054e-5, -0.09170341, 24.013842],
+    [7.2905306e-5, -0.077268325, 20.242472],
+    [6.039014e-5, -0.0638312, 16.7311],
+    [4.846051e-5, -0.0510134, 13.380944],
+    [3.6816655e-5, -0.03849431, 10.108345],
+    [2.5181116e-5, -0.025976706, 6.835713],
+    [1.3270804e-5, -0.013156641, 3.4836602],
+    [7.656741e-7, 0.00031033356, -0.037863005],
+    [-1.2737224e-5, 0.01485876, -3.8425636],
+    [0.00031280264, -0.33463898, 87.77561],
+    [0.00024577405, -0.26299435, 69.03667],
+    [0.00020234493, -0.21654873, 56.88883],
+    [0.00017089715, -0.18289575, 48.086746],
+    [0.00014643368, -0.15669945, 41.234745],
+    [0.00012639628, -0.13522805, 35.61836],
+    [0.00010931208, -0.11690865, 30.82624],
+    [9.425395e-5, -0.100750975, 26.59945],
+    [8.0592865e-5, -0.08608305, 22.762283],
+    [6.787075e-5, -0.07241528, 19.186768],
+    [5.572868e-5, -0.059363864, 15.772594],
+    [4.3862045e-5, -0.046602778, 12.434597],
+    [3.1988217e-5, -0.033829194, 9.093698],
+    [1.9818697e-5, -0.020733522, 5.669073],
+    [7.0281894e-6, -0.006966058, 2.0694313],
+    [-6.78985e-6, 0.007911026, -1.8195531],
+    [0.000324237, -0.34792703, 91.63204],
+    [0.00025703677, -0.2759604, 72.761024],
+    [0.00021321514, -0.22901061, 60.451923],
+    [0.00018137414, -0.19488023, 51.505016],
+    [0.00015655348, -0.16826136, 44.528095],
+    [0.00013619507, -0.14641671, 38.80329],
+    [0.00011881849, -0.12776206, 33.9152],
+    [0.000103487866, -0.11129593, 29.60126],
+    [8.9565685e-5, -0.096336, 25.682644],
+    [7.658597e-5, -0.08238346, 22.028673],
+    [6.41822e-5, -0.06904585, 18.53659],
+    [5.204185e-5, -0.055988405, 15.118818],
+    [3.987377e-5, -0.042899013, 11.693791],
+    [2.7380309e-5, -0.029458335, 8.178132],
+    [1.4226024e-5, -0.015306016, 4.477836],
+    [-5.172835e-9, 5.582047e-6, 0.47618997],
+    [0.0003223152, -0.3107208, 70.3722],
+    [0.00010402757, -0.08551199, 13.911355],
+    [2.8797735e-5, -0.0085547315, -5.122094],
+    [-1.0973832e-5, 0.031839658, -14.988686],
+    [-3.6670142e-5, 0.05786024, -21.29977],
+    [-5.5509132e-5, 0.07696366, -25.928915],
+    [-7.0674374e-5, 0.09242477, -29.693756],
+    [-8.387721e-5, 0.10600014, -33.03141],
+    [-9.61564e-5, 0.11875785, -36.207962],
+    [-0.0001082102, 0.13142182, -39.40524],
+    [-0.0001205963, 0.14457949, -42.773354],
+    [-0.0001338606, 0.15881723, -46.465492],
+    [-0.00014864528, 0.17483671, -50.668507],
+    [-0.00016582354, 0.1936033, -55.64289],
+    [-0.00018673379, 0.21660724, -61.79398],
+    [-0.00021367005, 0.24641284, -69.822334],
+    [0.00038265222, -0.3865605, 94.13206],
+    [0.00020191557, -0.19968818, 47.156258],
+    [0.00012278892, -0.11728048, 26.254728],
+    [7.473593e-5, -0.06699592, 13.433763],
+    [4.1224306e-5, -0.031811308, 4.4357657],
+    [1.5739695e-5, -0.004971552, -2.445112],
+    [-4.9193845e-6, 0.01686081, -8.057533],
+    [-2.2558295e-5, 0.035576228, -12.884787],
+    [-3.831574e-5, 0.05237317, -17.234978],
+    [-5.300099e-5, 0.06810898, -21.330048],
+    [-6.728004e-5, 0.08349471, -25.355597],
+    [-8.177354e-5, 0.09919965, -29.488102],
+    [-9.713656e-5, 0.11593863, -33.918186],
+    [-0.00011417374, 0.13459876, -38.884666],
+    [-0.00013403402, 0.15645532, -44.733044],
+    [-0.0001585958, 0.18360129, -52.032463],
+    [0.00037375613, -0.38121283, 94.11165],
+    [0.00021865219, -0.22038987, 53.541313],
+    [0.0001471277, -0.14586908, 34.637707],
+    [0.00010244237, -0.09909301, 22.71078],
+    [7.036848e-5, -0.065372296, 14.073927],
+    [4.538868e-5, -0.03900355, 7.293686],
+    [2.4784382e-5, -0.017167347, 1.6584933],
+    [6.9887774e-6, 0.0017692876, -3.2462876],
+    [-9.009296e-6, 0.01886663, -7.691769],
+    [-2.3933457e-5, 0.034889378, -11.875126],
+    [-3.836467e-5, 0.05045763, -15.957876],
+    [-5.2844116e-5, 0.06615523, -20.09374],
+    [-6.796515e-5, 0.08262828, -24.454418],
+    [-8.446633e-5, 0.100687824, -29.257198],
+    [-0.000103388375, 0.121484555, -34.8123],
+    [-0.00012640303, 0.14687496, -41.622227],
+    [0.00036202327, -0.37154704, 92.5248],
+    [0.00022494899, -0.22896448, 56.41016],
+    [0.00015859948, -0.15970233, 38.800945],
+    [0.00011656051, -0.11565373, 27.559341],
+    [8.613317e-5, -0.08364808, 19.35977],
+    [6.223013e-5, -0.05840554, 12.868164],
+    [4.2343327e-5, -0.037320014, 7.425233],
+    [2.5042093e-5, -0.018901024, 2.6528585],
+    [9.404245e-6, -0.002183185, -1.6951658],
+    [-5.2351793e-6, 0.01353474, -5.799082],
+    [-1.9414423e-5, 0.02882595, -9.807704],
+    [-3.3629338e-5, 0.044224657, -13.861305],
+    [-4.8412978e-5, 0.060311425, -18.113924],
+    [-6.443852e-5, 0.0778249, -22.762981],
+    [-8.267185e-5, 0.0978304, -28.094635],
+    [-0.00010466805, 0.12204983, -34.573082],
+    [0.00035182136, -0.3628815, 90.98924],
+    [0.00022834128, -0.23404866, 58.231693],
+    [0.00016591916, -0.16873431, 41.57896],
+    [0.00012579266, -0.1266191, 30.81021],
+    [9.658189e-5, -0.095858835, 22.920881],
+    [7.3563235e-5, -0.07153339, 16.661837],
+    [5.4358024e-5, -0.05116228, 11.402596],
+    [3.759761e-5, -0.03331544, 6.7789793],
+    [2.240228e-5, -0.01707052, 2.5553021],
+    [8.140245e-6, -0.0017611654, -1.4397064],
+    [-5.700615e-6, 0.013157472, -5.347295],
+    [-1.9594407e-5, 0.028195243, -9.301012],
+    [-3.4047334e-5, 0.043902546, -13.446522],
+    [-4.9688904e-5, 0.0609697, -17.967966],
+    [-6.742881e-5, 0.08039885, -23.133863],
+    [-8.874994e-5, 0.103828214, -29.384377],
+    [0.00034385634, -0.35621917, 89.85208],
+    [0.00023092028, -0.2380511, 59.698135],
+    [0.00017162439, -0.17585878, 43.794445],
+    [0.0001329521, -0.13519035, 33.371307],
+    [0.00010462157, -0.10531154, 25.69478],
+    [8.2236176e-5, -0.08162897, 19.594099],
+    [6.3538035e-5, -0.061781, 14.466641],
+    [4.7208225e-5, -0.044385493, 9.959062],
+    [3.239002e-5, -0.02854178, 5.8404202],
+    [1.8465858e-5, -0.0135973785, 1.9426262],
+    [4.935626e-6, 0.00098, -1.8723866],
+    [-8.664089e-6, 0.015688226, -5.734877],
+    [-2.2829241e-5, 0.031065442, -9.786917],
+    [-3.817174e-5, 0.04778138, -14.206687],
+    [-5.5567758e-5, 0.066800036, -19.251875],
+    [-7.64565e-5, 0.089708425, -25.347662],
+    [0.0003381929, -0.35176012, 89.20978],
+    [0.00023355243, -0.24197727, 61.10076],
+    [0.0001767787, -0.18228896, 45.792454],
+    [0.00013923753, -0.14272903, 35.628048],
+    [0.00011155004, -0.11347813, 28.097448],
+    [8.960097e-5, -0.09022511, 22.097929],
+    [7.124211e-5, -0.07071713, 17.052696],
+    [5.5203218e-5, -0.053619765, 12.619516],
+    [4.0649946e-5, -0.038053896, 8.572312],
+    [2.6974598e-5, -0.023376264, 4.7449656],
+    [1.3681376e-5, -0.0090586245, 1.0002649],
+    [3.0951497e-7, 0.0053939004, -2.7912464],
+    [-1.3635195e-5, 0.020516677, -6.7706566],
+    [-2.8762652e-5, 0.03697559, -11.114627],
+    [-4.593842e-5, 0.055721022, -16.076513],
+    [-6.658254e-5, 0.078316025, -22.073935],
+    [0.00033479786, -0.34954995, 89.10147],
+    [0.00023670895, -0.24637708, 62.59983],
+    [0.00018195888, -0.18868287, 47.760185],
+    [0.0001452911, -0.1499634, 37.7863],
+    [0.00011806484, -0.12114792, 30.351437],
+    [9.6400996e-5, -0.09816216, 24.409998],
+    [7.8245466e-5, -0.07884673, 19.407377],
+    [6.237146e-5, -0.06190995, 15.011368],
+    [4.796666e-5, -0.046494294, 11.000912],
+    [3.4434535e-5, -0.031967398, 7.212402],
+    [2.12836e-5, -0.017805187, 3.5095625],
+    [8.052786e-6, -0.003512526, -0.23713554],
+    [-5.7555308e-6, 0.011448932, -4.1693773],
+    [-2.0757738e-5, 0.02775052, -8.464813],
+    [-3.7826263e-5, 0.04634766, -13.377374],
+    [-5.8384943e-5, 0.068804346, -19.323753],
+    [0.00033367213, -0.3496459, 89.56103],
+    [0.00024072142, -0.25163695, 64.30781],
+    [0.00018755738, -0.19548744, 49.82454],
+    [0.00015153749, -0.1573741, 39.981606],
+    [0.00012461873, -0.12883198, 32.60048],
+    [0.00010311598, -0.10598099, 26.682215],
+    [8.505257e-5, -0.08673824, 21.690231],
+    [6.923736e-5, -0.069846876, 17.300402],
+    [5.4876436e-5, -0.054467317, 13.295809],
+    [4.1383602e-5, -0.039977387, 9.515238],
+    [2.8272823e-5, -0.025858574, 5.823778],
+    [1.50839305e-5, -0.011616904, 2.0922575],
+    [1.3148995e-6, 0.0032899987, -1.8218708],
+    [-1.36614935e-5, 0.019543696, -6.098526],
+    [-3.073602e-5, 0.038116932, -10.995441],
+    [-5.135677e-5, 0.06059653, -16.934132],
+    [0.00033489952, -0.35218033, 90.63767],
+    [0.00024588962, -0.2581029, 66.32438],
+    [0.0001939063, -0.20307916, 52.09191],
+    [0.00015832209, -0.16535062, 42.32341],
+    [0.00013156894, -0.13693237, 34.9573],
+    [0.000110116445, -0.11409821, 29.031248],
+    [9.204849e-5, -0.09482439, 24.022373],
+    [7.6200755e-5, -0.07787979, 19.612322],
+    [6.179238e-5, -0.062437143, 15.586883],
+    [4.824403e-5, -0.047880836, 11.786298],
+    [3.5073426e-5, -0.03369618, 8.076593],
+    [2.1821636e-5, -0.019390812, 4.3291163],
+    [7.983604e-6, -0.0044199424, 0.4009423],
+    [-7.079093e-6, 0.011908524, -3.89015],
+    [-2.428175e-5, 0.030591197, -8.807381],
+    [-4.5113415e-5, 0.05325547, -14.781676],
+    [0.00033867933, -0.35740274, 92.409325],
+    [0.00025254433, -0.26615185, 68.75672],
+    [0.00020134635, -0.21184199, 54.67012],
+    [0.0001659852, -0.17427516, 44.918655],
+    [0.00013925694, -0.14583208, 37.52889],
+    [0.000117747615, -0.1229008, 31.565151],
+    [9.958469e-5, -0.10349912, 26.513773],
+    [8.3621344e-5, -0.08641185, 22.05967],
+    [6.908352e-5, -0.07081748, 17.989626],
+    [5.539397e-5, -0.05610182, 14.144001],
+    [4.2070034e-5, -0.04174959, 10.388577],
+    [2.8650964e-5, -0.027266767, 6.594365],
+    [1.4627696e-5, -0.012105168, 2.6178415],
+    [-6.4838775e-7, 0.00443658, -1.7251116],
+    [-1.8118328e-5, 0.023380013, -6.703368],
+    [-3.9323608e-5, 0.04640503, -12.76026],
+    [0.00034537277, -0.36573404, 94.99864],
+    [0.00026110397, -0.27625555, 71.737335],
+    [0.00021028335, -0.22223082, 57.68616],
+    [0.00017491943, -0.18458712, 47.889774],
+    [0.00014806705, -0.15596096, 40.43499],
+    [0.00012639009, -0.13281445, 34.402496],
+    [0.00010804163, -0.113188006, 29.282991],
+    [9.18822e-5, -0.09587169, 24.76189],
+    [7.713774e-5, -0.08004248, 20.625074],
+    [6.3227446e-5, -0.06508164, 16.71142],
+    [4.966295e-5, -0.050467428, 12.884917],
+    [3.5976038e-5, -0.035698086, 9.014597],
+    [2.1647973e-5, -0.0202159, 4.954704],
+    [6.0168713e-6, -0.003306992, 0.51840585],
+    [-1.1884322e-5, 0.016075078, -4.568682],
+    [-3.3654498e-5, 0.039667405, -10.763268],
+    [0.00035558167, -0.37785593, 98.5981],
+    [0.0002721502, -0.28906482, 75.44752],
+    [0.00022125807, -0.23484933, 61.30779],
+    [0.00018563663, -0.19685762, 51.395443],
+    [0.00015849268, -0.16786973, 43.82864],
+    [0.00013652626, -0.14437774, 37.693027],
+    [0.00011789585, -0.12442336, 32.478123],
+    [0.000101457896, -0.10678972, 27.866638],
+    [8.64305e-5, -0.090643935, 23.641348],
+    [7.2222945e-5, -0.07535579, 19.637823],
+    [5.833493e-5, -0.060390458, 15.716462],
+    [4.4283475e-5, -0.04523046, 11.742184],
+    [2.9531435e-5, -0.029299023, 7.5644183],
+    [1.33926815e-5, -0.011857856, 2.9904332],
+    [-5.133556e-6, 0.00817257, -2.2617788],
+    [-2.7706978e-5, 0.03258945, -8.662753],
+    [0.00037029551, -0.39487427, 103.515335],
+    [0.00028655035, -0.30554542, 80.154884],
+    [0.00023505524, -0.25057122, 65.77709],
+    [0.00019887034, -0.21190375, 55.66222],
+    [0.00017123554, -0.18234046, 47.926876],
+    [0.00014883823, -0.15835145, 41.648056],
+    [0.00012981819, -0.13795394, 36.307304],
+    [0.000113013644, -0.119908996, 31.580645],
+    [9.762549e-5, -0.10336378, 27.24506],
+    [8.304555e-5, -0.0876685, 23.130644],
+    [6.8754234e-5, -0.07226726, 19.09208],
+    [5.4245393e-5, -0.05661773, 14.987703],
+    [3.895187e-5, -0.040111482, 10.658738],
+    [2.2146867e-5, -0.021967864, 5.901696],
+    [2.7732385e-6, -0.0010495835, 0.42031515],
+    [-2.0908848e-5, 0.024521187, -6.275049],
+    [0.00039118624, -0.41864553, 110.262825],
+    [0.00030569048, -0.3272367, 86.2846],
+    [0.00025290725, -0.27076638, 71.47296],
+    [0.00021576467, -0.23099852, 61.042686],
+    [0.00018738718, -0.20058884, 53.066814],
+    [0.00016438687, -0.17591816, 46.595882],
+    [0.00014485297, -0.15494491, 41.094345],
+    [0.00012758696, -0.13638815, 36.226227],
+    [0.00011176008, -0.11936163, 31.759138],
+    [9.673698e-5, -0.10318551, 27.514885],
+    [8.1969636e-5, -0.08727292, 23.339779],
+    [6.6918285e-5, -0.07104541, 19.082544],
+    [5.0971736e-5, -0.053847667, 14.572094],
+    [3.334091e-5, -0.034833144, 9.58783],
+    [1.2875992e-5, -0.012767136, 3.8085072],
+    [-1.2299326e-5, 0.014370323, -3.291189],
+    [0.0004212514, -0.45248705, 119.75283],
+    [0.00033196897, -0.35679796, 94.5699],
+    [0.0002769237, -0.2977776, 79.042786],
+    [0.0002382733, -0.2563145, 68.13821],
+    [0.00020881488, -0.22469372, 59.824715],
+    [0.00018499415, -0.19910836, 53.100082],
+    [0.00016480358, -0.17740811, 47.39832],
+    [0.00014698117, -0.15824097, 42.36366],
+    [0.00013065078, -0.1406682, 37.749214],
+    [0.00011513617, -0.12396505, 33.36455],
+    [9.9848316e-5, -0.107500136, 29.043978],
+    [8.419977e-5, -0.09064376, 24.622553],
+    [6.751664e-5, -0.07267357, 19.911476],
+    [4.891887e-5, -0.05264609, 14.664697],
+    [2.7113772e-5, -0.029174618, 8.521245],
+    [-1.0453717e-8, 1.1288205e-5, 0.8907874],
+    [0.0004449805, -0.4366837, 102.20044],
+    [0.0001492747, -0.13277116, 26.21434],
+    [5.732874e-5, -0.038508758, 2.766789],
+    [1.0410666e-5, 0.009357431, -9.031119],
+    [-1.9356241e-5, 0.03968393, -16.469698],
+    [-4.0954015e-5, 0.061747693, -21.884644],
+    [-5.822819e-5, 0.079511404, -26.269743],
+    [-7.3175404e-5, 0.09503315, -30.140608],
+    [-8.7041255e-5, 0.1096041, -33.822495],
+    [-0.00010074551, 0.12419045, -37.56228],
+    [-0.000115095696, 0.1396585, -41.58619],
+    [-0.00013097061, 0.1569727, -46.15198],
+    [-0.00014955096, 0.17745146, -51.61813],
+    [-0.000172728, 0.20322815, -58.570675],
+    [-0.00020406564, 0.2383443, -68.125755],
+    [-0.00025171466, 0.29206756, -82.8498],
+    [0.00046556772, -0.47040272, 114.96024],
+    [0.00023597927, -0.23414572, 55.814194],
+    [0.00014648774, -0.14136901, 32.38079],
+    [9.393616e-5, -0.0865682, 18.450134],
+    [5.77932e-5, -0.04870567, 8.783478],
+    [3.050262e-5, -0.019992499, 1.425656],
+    [8.432601e-6, 0.003339273, -4.5770216],
+    [-1.0452921e-5, 0.023415133, -9.766416],
+    [-2.7449094e-5, 0.041597802, -14.492825],
+    [-4.3495056e-5, 0.058886822, -19.016106],
+    [-5.9388032e-5, 0.076143935, -23.563591],
+    [-7.595389e-5, 0.094276525, -28.378275],
+    [-9.4235555e-5, 0.11444607, -33.775032],
+    [-0.000115796436, 0.13841133, -40.2349],
+    [-0.00014342186, 0.16932535, -48.624992],
+    [-0.00018317121, 0.21407142, -60.844044],
+    [0.00044120624, -0.44953135, 111.159256],
+    [0.00024916237, -0.2511135, 61.232037],
+    [0.00016857429, -0.16744412, 40.066994],
+    [0.000120127996, -0.11689315, 27.212769],
+    [8.6032e-5, -0.081135616, 18.074472],
+    [5.9739803e-5, -0.053421743, 10.957847],
+    [3.8145947e-5, -0.030540863, 5.0544944],
+    [1.9495037e-5, -0.010668109, -0.09792077],
+    [2.6486687e-6, 0.0073900195, -4.804549],
+    [-1.3234441e-5, 0.024526156, -9.296394],
+    [-2.8875782e-5, 0.04151788, -13.777707],
+    [-4.5010107e-5, 0.05917104, -18.46355],
+    [-6.2541636e-5, 0.07849272, -23.626328],
+    [-8.281655e-5, 0.100997455, -29.679293],
+    [-0.00010822658, 0.12939116, -37.36437],
+    [-0.00014391501, 0.16951233, -48.287064],
+    [0.00041988696, -0.43030286, 107.260826],
+    [0.00025363822, -0.2578829, 63.666718],
+    [0.00017915158, -0.18034334, 43.99002],
+    [0.00013358945, -0.13272177, 31.858953],
+    [0.00010130329, -0.098827034, 23.189245],
+    [7.627561e-5, -0.07242643, 16.406986],
+    [5.5608452e-5, -0.050513025, 10.751567],
+    [3.7673708e-5, -0.031391636, 5.792746],
+    [2.142662e-5, -0.013967177, 1.250777],
+    [6.0965995e-6, 0.0025770823, -3.0852454],
+    [-8.980016e-6, 0.01895534, -7.4024067],
+    [-2.4483737e-5, 0.03591247, -11.898837],
+    [-4.125372e-5, 0.05438094, -16.825838],
+    [-6.0525166e-5, 0.075748555, -22.560915],
+    [-8.447325e-5, 0.102474846, -29.776676],
+    [-0.00011777296, 0.13986298, -39.92739],
+    [0.0004034961, -0.41550875, 104.25737],
+    [0.00025602552, -0.2620336, 65.28427],
+    [0.0001861946, -0.18913504, 46.721455],
+    [0.00014270516, -0.14357859, 35.086296],
+    [0.000111678455, -0.11095134, 26.725706],
+    [8.7570515e-5, -0.08549004, 20.1774],
+    [6.764697e-5, -0.064347066, 14.717626],
+    [5.034852e-5, -0.045893196, 9.931042],
+    [3.4670007e-5, -0.029072069, 5.5469117],
+    [1.9874658e-5, -0.013101802, 1.3630779],
+    [5.332344e-6, 0.0026957705, -2.798003],
+    [-9.601976e-6, 0.01902613, -7.1236405],
+    [-2.572674e-5, 0.0367751, -11.851903],
+    [-4.422364e-5, 0.057267312, -17.341858],
+    [-6.7172376e-5, 0.08284926, -24.232971],
+    [-9.9041456e-5, 0.11858113, -33.908367],
+    [0.0003915935, -0.4050031, 102.21758],
+    [0.00025820846, -0.26574364, 66.71325],
+    [0.00019204649, -0.19648279, 49.01639],
+    [0.00015012344, -0.15246238, 37.74138],
+    [0.000119984565, -0.1207058, 29.585192],
+    [9.649267e-5, -0.09585566, 23.182985],
+    [7.706457e-5, -0.07521346, 17.846224],
+    [6.020581e-5, -0.057213176, 13.174196],
+    [4.4941968e-5, -0.04082798, 8.90286],
+    [3.055308e-5, -0.025292238, 4.83377],
+    [1.6423111e-5, -0.009942354, 0.7930443],
+    [1.9237416e-6, 0.0059086555, -3.4015555],
+    [-1.372249e-5, 0.023122188, -7.9810257],
+    [-3.167261e-5, 0.042992283, -13.295022],
+    [-5.3981064e-5, 0.067829825, -19.970835],
+    [-8.5095235e-5, 0.10265514, -29.375095],
+    [0.0003836646, -0.3984122, 101.101105],
+    [0.00026099916, -0.2699617, 68.230484],
+    [0.00019773452, -0.20355532, 51.206524],
+    [0.00015700363, -0.16068393, 40.193676],
+    [0.00012748977, -0.1295207, 32.16978],
+    [0.00010439335, -0.10504551, 25.851097],
+    [8.5259824e-5, -0.08468707, 20.579224],
+    [6.865365e-5, -0.06693718, 15.96702],
+    [5.3630618e-5, -0.050798666, 11.757381],
+    [3.9487273e-5, -0.0355222, 7.75572],
+    [2.5616246e-5, -0.020452969, 3.7903123],
+    [1.1394134e-5, -0.0049096174, -0.31948343],
+    [-3.952857e-6, 0.01196383, -4.802733],
+    [-2.1582344e-5, 0.03145863, -10.007207],
+    [-4.357242e-5, 0.05590374, -16.56246],
+    [-7.448892e-5, 0.09043085, -25.85879],
+    [0.0003793899, -0.3955043, 100.88307],
+    [0.0002648857, -0.2752598, 70.00344],
+    [0.0002038654, -0.21104233, 53.48743],
+    [0.00016401273, -0.16899595, 42.654938],
+    [0.00013491404, -0.13820603, 34.70658],
+    [0.000112042275, -0.11392421, 28.423748],
+    [9.304764e-5, -0.093682334, 23.172308],
+    [7.6541655e-5, -0.076018125, 18.575773],
+    [6.1604886e-5, -0.059958603, 14.382678],
+    [4.75486e-5, -0.044769153, 10.401978],
+    [3.377408e-5, -0.029804029, 6.4642835],
+    [1.9660209e-5, -0.014384883, 2.3899379],
+    [4.4266358e-6, 0.0023497348, -2.051049],
+    [-1.3106511e-5, 0.02171161, -7.2107244],
+    [-3.508456e-5, 0.046094663, -13.733278],
+    [-6.63112e-5, 0.080869906, -23.06617],
+    [0.00037867003, -0.39624932, 101.580765],
+    [0.00027027586, -0.28211108, 72.16926],
+    [0.00021091064, -0.2194776, 56.00979],
+    [0.00017164822, -0.17795783, 45.28197],
+    [0.00014277713, -0.1473447, 37.3585],
+    [0.00011998419, -0.12310177, 31.071138],
+    [0.0001009993, -0.10283845, 25.803638],
+    [8.446841e-5, -0.08512531, 21.186878],
+    [6.94883e-5, -0.069004685, 16.972755],
+    [5.537848e-5, -0.053749885, 12.972026],
+    [4.1544496e-5, -0.03871971, 9.0164995],
+    [2.7365188e-5, -0.023236614, 4.9270196],
+    [1.2049811e-5, -0.0064299516, 0.4718295],
+    [-5.618135e-6, 0.013047542, -4.709184],
+    [-2.7890484e-5, 0.03769621, -11.285532],
+    [-5.992212e-5, 0.073246315, -20.792774],
+    [0.00038164563, -0.40085676, 103.26909],
+    [0.00027761454, -0.29102334, 74.87262],
+    [0.00021933974, -0.22938898, 58.921555],
+    [0.00018038195, -0.18809785, 48.22214],
+    [0.00015155558, -0.15746914, 40.273796],
+    [0.00012870385, -0.13311933, 33.943794],
+    [0.00010961244, -0.11271056, 28.627527],
+    [9.294775e-5, -0.09483171, 23.959513],
+    [7.781307e-5, -0.07853021, 19.692362],
+    [6.3527696e-5, -0.063078254, 15.636262],
+    [4.949265e-5, -0.047829885, 11.621769],
+    [3.5078083e-5, -0.032099206, 7.467849],
+    [1.9475674e-5, -0.014999092, 2.9391837],
+    [1.4229452e-6, 0.004862401, -2.334548],
+    [-2.1469752e-5, 0.030124161, -9.056155],
+    [-5.481881e-5, 0.06699116, -18.878588],
+    [0.00038874915, -0.40984172, 106.10146],
+    [0.00028748086, -0.3026484, 78.2964],
+    [0.00022971768, -0.24140768, 62.3984],
+    [0.00019076161, -0.20002647, 51.64495],
+    [0.00016178661, -0.16917771, 43.61846],
+    [0.00013873547, -0.14457166, 37.206665],
+    [0.000119423385, -0.123895705, 31.809538],
+    [0.00010252285, -0.10574195, 27.06133],
+    [8.7133216e-5, -0.089151785, 22.712465],
+    [7.256379e-5, -0.07338596, 18.569817],
+    [5.820027e-5, -0.05778207, 14.459595],
+    [4.339029e-5, -0.041631106, 10.194969],
+    [2.72906e-5, -0.024011027, 5.532233],
+    [8.574673e-6, -0.0034666553, 0.08621666],
+    [-1.5311145e-5, 0.022805456, -6.88532],
+    [-5.0547707e-5, 0.0615911, -17.178988],
+    [0.0004008326, -0.42417002, 110.3511],
+    [0.00030071585, -0.31792387, 82.70099],
+    [0.0002428234, -0.25639966, 66.68009],
+    [0.0002035232, -0.21456148, 55.776726],
+    [0.00017418069, -0.18325984, 47.610992],
+    [0.00015077576, -0.15823296, 41.07408],
+    [0.00013112363, -0.13716246, 35.562496],
+    [0.00011388593, -0.118625596, 30.705498],
+    [9.814617e-5, -0.101645045, 26.247963],
+    [8.319329e-5, -0.08545895, 21.990503],
+    [6.8385496e-5, -0.0693754, 17.751497],
+    [5.303068e-5, -0.052643765, 13.333378],
+    [3.622433e-5, -0.034278836, 8.476557],
+    [1.6537211e-5, -0.012721117, 2.7702405],
+    [-8.794203e-6, 0.01504447, -4.5789366],
+    [-4.6609795e-5, 0.056480013, -15.533997],
+    [0.00041943754, -0.44555828, 116.49396],
+    [0.00031864602, -0.33832073, 88.49235],
+    [0.00025984604, -0.27568322, 72.13047],
+    [0.0002197762, -0.23293324, 60.957237],
+    [0.00018980088, -0.20089497, 52.577328],
+    [0.00016586218, -0.17525493, 45.864536],
+    [0.00014573893, -0.15365006, 40.201645],
+    [0.00012806071, -0.13462017, 35.206955],
+    [0.000111880596, -0.117153585, 30.615652],
+    [9.6454445e-5, -0.100452125, 26.218431],
+    [8.109878e-5, -0.08377916, 21.821758],
+    [6.506196e-5, -0.06632108, 17.211637],
+    [4.7345322e-5, -0.046994116, 12.103137],
+    [2.6355998e-5, -0.024069041, 6.0424604],
+    [-9.831732e-7, 0.00579114, -1.8437136],
+    [-4.230373e-5, 0.050860245, -13.717686],
+    [0.0004474027, -0.4771436, 125.3921],
+    [0.00034354557, -0.36635315, 96.36204],
+    [0.00028278527, -0.30147138, 79.35917],
+    [0.00024137415, -0.25719512, 67.7529],
+    [0.00021042183, -0.22405067, 59.06082],
+    [0.0001857299, -0.19756272, 52.110172],
+    [0.0001649906, -0.17526963, 46.255688],
+    [0.00014677257, -0.1556426, 41.09642],
+    [0.00013007851, -0.13761412, 36.352127],
+    [0.00011411416, -0.12033141, 31.798702],
+    [9.813625e-5, -0.10299356, 27.225471],
+    [8.1309096e-5, -0.08469763, 22.394943],
+    [6.249846e-5, -0.06421669, 16.984911],
+    [3.9865386e-5, -0.03956297, 10.474852],
+    [9.834842e-6, -0.0068792193, 1.8586948],
+    [-3.6348403e-5, 0.043273088, -11.316635],
+    [0.0004903637, -0.5251318, 138.74403],
+    [0.00037973782, -0.40679348, 107.62044],
+    [0.0003153986, -0.33791903, 89.5097],
+    [0.0002717969, -0.29119992, 77.22594],
+    [0.0002393892, -0.25643504, 68.0852],
+    [0.00021367455, -0.22881222, 60.82143],
+    [0.0001921794, -0.20568563, 54.738438],
+    [0.00017336658, -0.18540941, 49.40305],
+    [0.0001561586, -0.16682807, 44.51099],
+    [0.00013968661, -0.14900786, 39.816303],
+    [0.00012312176, -0.13105594, 35.083805],
+    [0.00010550932, -0.111942515, 30.042477],
+    [8.552082e-5, -0.09023459, 24.316032],
+    [6.095412e-5, -0.06355995, 17.283552],
+    [2.7452148e-5, -0.027234338, 7.724115],
+    [-2.5667428e-5, 0.03021528, -7.3383],
+    [0.000561186, -0.60366786, 160.41379],
+    [0.00043676025, -0.47016132, 125.15359],
+    [0.0003659194, -0.39412642, 105.08564],
+    [0.00031870158, -0.34342363, 91.71281],
+    [0.00028414052, -0.3062905, 81.92601],
+    [0.00025712792, -0.27724755, 74.277176],
+    [0.00023488002, -0.25330812, 67.977165],
+    [0.0002156763, -0.2326255, 62.538128],
+    [0.00019831135, -0.21390499, 57.618137],
+    [0.00018180613, -0.19609402, 52.939384],
+    [0.00016520935, -0.17816915, 48.232033],
+    [0.0001473943, -0.158918, 43.17697],
+    [0.000126743, -0.13660097, 37.31733],
+    [0.00010048913, -0.108247556, 29.875101],
+    [6.2991e-5, -0.06780799, 19.270899],
+    [-2.932289e-8, 3.1652286e-5, 1.5209987],
+    [0.00055880705, -0.5522268, 130.91904],
+    [0.00018453154, -0.16927847, 35.60391],
+    [7.891863e-5, -0.060959946, 8.600861],
+    [2.6587733e-5, -0.007471927, -4.6379914],
+    [-6.0775283e-6, 0.025901081, -12.867769],
+    [-2.9521298e-5, 0.049937624, -18.80316],
+    [-4.8136088e-5, 0.06916623, -23.582071],
+    [-6.4170934e-5, 0.08591035, -27.788786],
+    [-7.900051e-5, 0.10160281, -31.786583],
+    [-9.365744e-5, 0.117339864, -35.858604],
+    [-0.00010910606, 0.13417232, -40.283333],
+    [-0.00012647585, 0.15336442, -45.404438],
+    [-0.00014741662, 0.17680033, -51.743515],
+    [-0.00017488032, 0.20789297, -60.25569],
+    [-0.000215397, 0.254252, -73.084755],
+    [-0.00028863217, 0.33896607, -96.77345],
+    [0.0005414386, -0.5470723, 133.95287],
+    [0.00026262284, -0.261008, 62.517643],
+    [0.0001644482, -0.15953009, 36.95603],
+    [0.00010851752, -0.10134375, 22.196648],
+    [7.057282e-5, -0.061653387, 12.076316],
+    [4.2175812e-5, -0.03179203, 4.427267],
+    [1.9364395e-5, -0.007661748, -1.7844633],
+    [-6.011576e-8, 0.013029117, -7.1421385],
+    [-1.7492932e-5, 0.03175026, -12.023863],
+    [-3.3954766e-5, 0.04959455, -16.71514],
+    [-5.0330764e-5, 0.06753179, -21.474554],
+    [-6.7563284e-5, 0.08662229, -26.591211],
+    [-8.690257e-5, 0.10830687, -32.465508],
+    [-0.000110391506, 0.13498497, -39.77392],
+    [-0.00014209523, 0.17152132, -49.906204],
+    [-0.00019206159, 0.23035713, -66.49653],
+    [0.0005000065, -0.5091108, 126.001656],
+    [0.00027308342, -0.2751862, 67.2346],
+    [0.00018498157, -0.18391563, 44.18631],
+    [0.0001336332, -0.13044117, 30.611511],
+    [9.813959e-5, -0.09327159, 21.125217],
+    [7.109325e-5, -0.06478018, 13.814189],
+    [4.907482e-5, -0.04143654, 7.7902694],
+    [3.0196308e-5, -0.021279318, 2.5568783],
+    [1.3252156e-5, -0.0030421054, -2.2102094],
+    [-2.6419111e-6, 0.014220621, -6.7571225],
+    [-1.8243814e-5, 0.031340163, -11.305119],
+    [-3.4335724e-5, 0.04920147, -16.09575],
+    [-5.1898456e-5, 0.06895089, -21.449669],
+    [-7.2408766e-5, 0.09237291, -27.877674],
+    [-9.842435e-5, 0.12271031, -36.336384],
+    [-0.00013369888, 0.16575624, -48.714813],
+    [0.00046863224, -0.47983348, 119.66625],
+    [0.00027601502, -0.28044325, 69.31133],
+    [0.00019491823, -0.1961691, 47.950314],
+    [0.000146663, -0.14580627, 35.1346],
+    [0.00011308026, -0.11058498, 26.132954],
+    [8.7406675e-5, -0.083509095, 19.179583],
+    [6.644939e-5, -0.061268028, 13.437075],
+    [4.8445843e-5, -0.042025443, 8.438916],
+    [3.2288088e-5, -0.024615997, 3.8865168],
+    [1.7178816e-5, -0.0081862835, -0.4421636],
+    [2.4492808e-6, 0.007999341, -4.7429614],
+    [-1.2569361e-5, 0.024703689, -9.224743],
+    [-2.8676634e-5, 0.04287808, -14.155982],
+    [-4.6983245e-5, 0.06391634, -19.943672],
+    [-6.905958e-5, 0.09002302, -27.272322],
+    [-9.431221e-5, 0.122531645, -36.889744],
+    [0.0004459674, -0.4587945, 115.15546],
+    [0.00027749155, -0.28374344, 70.741264],
+    [0.00020174011, -0.20477703, 50.649113],
+    [0.00015571718, -0.15661965, 38.35743],
+    [0.00012343547, -0.12269243, 29.666683],
+    [9.869956e-5, -0.096562676, 22.945501],
+    [7.851427e-5, -0.0751131, 17.401611],
+    [6.12003e-5, -0.056587663, 12.586848],
+    [4.5695306e-5, -0.039865173, 8.212804],
+    [3.124142e-5, -0.024132127, 4.067269],
+    [1.7217515e-5, -0.0087028975, -0.03260944],
+    [3.022049e-6, 0.007113561, -4.2766643],
+    [-1.203365e-5, 0.024150342, -8.9019],
+    [-2.8837747e-5, 0.04356633, -14.2532625],
+    [-4.8370064e-5, 0.06695487, -20.856764],
+    [-6.741275e-5, 0.093020014, -28.800182],
+    [0.00043013654, -0.4444173, 112.193375],
+    [0.00027926016, -0.28711814, 72.11425],
+    [0.00020768195, -0.21227594, 53.0009],
+    [0.00016334096, -0.16575488, 41.08898],
+    [0.00013196732, -0.13270614, 32.600647],
+    [0.00010783969, -0.107169405, 26.017906],
+    [8.813719e-5, -0.08619932, 20.589163],
+    [7.125822e-5, -0.068115845, 15.88403],
+    [5.6181103e-5, -0.051837485, 11.623357],
+    [4.2173167e-5, -0.036575515, 7.600808],
+    [2.8636006e-5, -0.02166744, 3.6393142],
+    [1.4999816e-5, -0.0064556515, -0.44216216],
+    [6.3080284e-7, 0.009834612, -4.8650274],
+    [-1.5249654e-5, 0.028247233, -9.943681],
+    [-3.3339635e-5, 0.05008937, -16.130272],
+    [-4.9110207e-5, 0.07280315, -23.205011],
+    [0.00041992925, -0.43563828, 110.574646],
+    [0.0002820972, -0.29148173, 73.699394],
+    [0.00021376218, -0.2198231, 55.333786],
+    [0.00017068165, -0.17450552, 43.692932],
+    [0.00013993352, -0.1420395, 35.330807],
+    [0.00011617976, -0.11684677, 28.821106],
+    [9.674287e-5, -0.09612322, 23.445593],
+    [8.008641e-5, -0.078252606, 18.788807],
+    [6.522366e-5, -0.062187728, 14.579674],
+    [5.1444447e-5, -0.04716233, 10.617292],
+    [3.8166374e-5, -0.03253031, 6.7287407],
+    [2.4832536e-5, -0.01764772, 2.736647],
+    [1.0823286e-5, -0.0017552126, -1.5759822],
+    [-4.619524e-6, 0.016169362, -6.5171514],
+    [-2.2160566e-5, 0.037404817, -12.531314],
+    [-3.7038582e-5, 0.059241235, -19.373343],
+    [0.0004146863, -0.4318936, 110.19661],
+    [0.00028651353, -0.2974353, 75.673355],
+    [0.00022062469, -0.2281489, 57.854477],
+    [0.00017844127, -0.18366104, 46.39067],
+    [0.00014808505, -0.15153416, 38.092297],
+    [0.00012452093, -0.12649082, 31.604881],
+    [0.00010518308, -0.10583611, 26.235674],
+    [8.8583656e-5, -0.088000685, 21.579935],
+    [7.376065e-5, -0.071961135, 17.372017],
+    [6.0018247e-5, -0.056965325, 13.414379],
+    [4.678337e-5, -0.04237689, 9.536624],
+    [3.3502558e-5, -0.027556118, 5.5627675],
+    [1.9545452e-5, -0.011733651, 1.273831],
+    [4.1054336e-6, 0.0061620693, -3.6498985],
+    [-1.3660126e-5, 0.02759877, -9.7002535],
+    [-2.9745537e-5, 0.050723214, -16.860693],
+    [0.00041418863, -0.4330455, 111.05459],
+    [0.0002929935, -0.30553886, 78.1979],
+    [0.0002288225, -0.23787668, 60.738213],
+    [0.00018719252, -0.1938628, 49.361225],
+    [0.000157013, -0.16185011, 41.068783],
+    [0.0001334763, -0.13678499, 34.5591],
+    [0.00011409755, -0.11605028, 29.157034],
+    [9.742088e-5, -0.09810631, 24.464207],
+    [8.249633e-5, -0.081940226, 20.217009],
+    [6.8631045e-5, -0.06680208, 16.21815],
+    [5.5248707e-5, -0.052052043, 12.296352],
+    [4.1785104e-5, -0.03704009, 8.2733345],
+    [2.7578226e-5, -0.02096578, 3.9229577],
+    [1.1720748e-5, -0.0026554, -1.0992577],
+    [-6.9949238e-6, 0.019737985, -7.380101],
+    [-2.6316511e-5, 0.04623227, -15.385115],
+    [0.00041863843, -0.43938512, 113.25016],
+    [0.00030213277, -0.31646746, 81.464],
+    [0.00023896614, -0.24968934, 64.17539],
+    [0.00019753851, -0.20578277, 52.791187],
+    [0.00016731775, -0.17365517, 44.44538],
+    [0.00014365085, -0.14840156, 37.86991],
+    [0.00012410241, -0.12744986, 32.398956],
+    [0.000107230706, -0.109271444, 27.635714],
+    [9.208518e-5, -0.09285072, 23.315147],
+    [7.7963305e-5, -0.07742649, 19.236698],
+    [6.427016e-5, -0.06233923, 15.223944],
+    [5.0408536e-5, -0.046905156, 11.090209],
+    [3.565364e-5, -0.030260572, 6.593992],
+    [1.8943181e-5, -0.011074939, 1.3525773],
+    [-1.4673868e-6, 0.013050934, -5.358332],
+    [-2.6172842e-5, 0.04511501, -14.761784],
+    [0.0004287437, -0.45173755, 117.02382],
+    [0.00031478106, -0.33117092, 85.73623],
+    [0.00025186213, -0.26448318, 68.41419],
+    [0.0002102499, -0.22027604, 56.91692],
+    [0.00017974919, -0.18778086, 48.45181],
+    [0.00015578569, -0.16216205, 41.76422],
+    [0.00013593907, -0.14085646, 36.18832],
+    [0.000118762044, -0.122325815, 31.323511],
+    [0.0001032898, -0.10553721, 26.899506],
+    [8.879664e-5, -0.08970386, 22.708689],
+    [7.465229e-5, -0.07412863, 18.56476],
+    [6.0200604e-5, -0.058066443, 14.265397],
+    [4.460889e-5, -0.040542863, 9.541366],
+    [2.6577773e-5, -0.01998575, 3.950173],
+    [3.6322879e-6, 0.0067510298, -3.4177449],
+    [-2.8931645e-5, 0.04692291, -14.858312],
+    [0.00044597217, -0.47174725, 122.834854],
+    [0.00033227526, -0.3511311, 91.423676],
+    [0.0002687196, -0.2835965, 73.82351],
+    [0.00022645958, -0.23859626, 62.08398],
+    [0.00019539747, -0.20543364, 53.420708],
+    [0.00017094963, -0.17924939, 46.568264],
+    [0.00015066932, -0.15744546, 40.84935],
+    [0.00013308106, -0.13844964, 35.85318],
+    [0.00011718848, -0.121193334, 31.29937],
+    [0.00010222789, -0.10484824, 26.96901],
+    [8.7515014e-5, -0.08865965, 22.660597],
+    [7.23062e-5, -0.07179064, 18.148054],
+    [5.5604596e-5, -0.053096257, 13.118681],
+    [3.5751833e-5, -0.030634588, 7.036913],
+    [9.249251e-6, -0.00021148563, -1.267827],
+    [-3.427128e-5, 0.05125951, -15.551272],
+    [0.00047316006, -0.50254893, 131.5454],
+    [0.00035690787, -0.37887824, 99.221344],
+    [0.00029155557, -0.3092566, 81.0157],
+    [0.00024803935, -0.26281166, 68.86155],
+    [0.00021605828, -0.22859867, 59.898834],
+    [0.00019090336, -0.201611, 52.818733],
+    [0.00017004642, -0.17915659, 46.91687],
+    [0.00015195186, -0.15959524, 41.763233],
+    [0.00013557072, -0.14179999, 37.06128],
+    [0.00012008239, -0.12488059, 32.575344],
+    [0.00010472646, -0.108000964, 28.082361],
+    [8.863699e-5, -0.09019496, 23.322506],
+    [7.0585396e-5, -0.07007483, 17.920404],
+    [4.838896e-5, -0.045153163, 11.2011795],
+    [1.7037168e-5, -0.009676076, 1.6010808],
+    [-4.169461e-5, 0.057510614, -16.649204],
+    [0.0005160578, -0.5504629, 144.88255],
+    [0.00039303736, -0.41921216, 110.444405],
+    [0.00032414423, -0.34562603, 91.13369],
+    [0.00027848282, -0.29677957, 78.31036],
+    [0.0002450945, -0.26099202, 68.90921],
+    [0.00021896648, -0.23291726, 61.526966],
+    [0.00019740434, -0.20967807, 55.407818],
+    [0.00017876519, -0.18951523, 50.088917],
+    [0.00016191653, -0.17120998, 45.248592],
+    [0.00014595459, -0.15378189, 40.626858],
+    [0.00013001359, -0.1362819, 35.970566],
+    [0.00011305982, -0.117565624, 30.973003],
+    [9.354263e-5, -0.09590547, 25.170153],
+    [6.8526555e-5, -0.06802407, 17.682816],
+    [3.070615e-5, -0.025775092, 6.3351464],
+    [-4.9799066e-5, 0.06401601, -17.657917],
+    [0.0005879041, -0.63000613, 166.80228],
+    [0.00045026935, -0.4826995, 127.9847],
+    [0.00037472404, -0.40178576, 106.669426],
+    [0.00032544194, -0.3489458, 92.75233],
+    [0.00028994447, -0.31083167, 82.71414],
+    [0.00026258617, -0.28140226, 74.96196],
+    [0.00024035445, -0.25743055, 68.644485],
+    [0.00022141956, -0.23695247, 63.242977],
+    [0.00020451743, -0.21860565, 58.39682],
+    [0.00018862596, -0.20128173, 53.811596],
+    [0.00017273509, -0.18387704, 49.192986],
+    [0.00015558045, -0.16500181, 44.16939],
+    [0.0001351529, -0.14244235, 38.14902],
+    [0.00010739732, -0.1117378, 29.943138],
+    [6.1376726e-5, -0.060916983, 16.382627],
+    [-5.279499e-5, 0.06411286, -16.66156],
+    [0.0007278263, -0.7840376, 208.96959],
+    [0.0005556454, -0.59907246, 159.97482],
+    [0.00046634817, -0.50313944, 134.59244],
+    [0.0004105697, -0.44320682, 118.75676],
+    [0.0003721148, -0.40187818, 107.855194],
+    [0.00034392605, -0.37157255, 99.878334],
+    [0.00032236063, -0.3483761, 93.78918],
+    [0.00030528835, -0.3299986, 88.98128],
+    [0.00029130495, -0.31492838, 85.054565],
+    [0.00027932727, -0.30199638, 81.6999],
+    [0.000268305, -0.2900652, 78.61659],
+    [0.00025685938, -0.27763826, 75.408875],
+    [0.00024255489, -0.26207167, 71.37751],
+    [0.00021976698, -0.23727316, 64.91282],
+    [0.00017096891, -0.18433164, 51.033684],
+    [-1.2697902e-7, 0.00013704933, 2.7085292],
+    [0.0006302855, -0.62438303, 148.7087],
+    [0.00020337333, -0.1887009, 40.568127],
+    [9.023604e-5, -0.0726745, 11.62519],
+    [3.507036e-5, -0.016252924, -2.3621216],
+    [9.55015e-7, 0.018639358, -10.984771],
+    [-2.3359582e-5, 0.04360574, -17.164904],
+    [-4.255657e-5, 0.06347476, -22.116611],
+    [-5.901882e-5, 0.080710486, -26.460512],
+    [-7.4190015e-5, 0.09682182, -30.580355],
+    [-8.914155e-5, 0.11295352, -34.773533],
+    [-0.000104879204, 0.13021427, -39.33669],
+    [-0.00012259964, 0.14996682, -44.645164],
+    [-0.00014407987, 0.17428993, -51.284462],
+    [-0.00017254749, 0.20704353, -60.360344],
+    [-0.00021512175, 0.2569932, -74.43472],
+    [-0.00028669267, 0.34557816, -100.35669],
+    [0.0005871152, -0.59321827, 145.37024],
+    [0.0002768642, -0.2753446, 66.08424],
+    [0.00017383284, -0.16899538, 39.329845],
+    [0.000116139345, -0.10904445, 24.13924],
+    [7.732154e-5, -0.06847016, 13.800392],
+    [4.8443024e-5, -0.038108252, 6.0246363],
+    [2.5371912e-5, -0.013691279, -0.26313257],
+    [5.836072e-6, 0.007147711, -5.6649284],
+    [-1.1591257e-5, 0.02591383, -10.568332],
+    [-2.7936936e-5, 0.043712903, -15.263475],
+    [-4.4068805e-5, 0.061509736, -20.010372],
+    [-6.0869508e-5, 0.080328666, -25.094393],
+    [-7.94103e-5, 0.101482436, -30.895355],
+    [-0.00010110379, 0.1268682, -37.993153],
+    [-0.00012667036, 0.15842363, -47.141857],
+    [-0.000119071585, 0.16401426, -51.43565],
+    [0.0005339566, -0.54351723, 134.56691],
+    [0.00028584027, -0.28802007, 70.42927],
+    [0.00019358705, -0.19254646, 46.338753],
+    [0.00014070724, -0.1375265, 32.382366],
+    [0.000104529725, -0.09966436, 22.725193],
+    [7.717485e-5, -0.070852324, 15.333874],
+    [5.505771e-5, -0.04739076, 9.277893],
+    [3.623095e-5, -0.02725643, 4.0449944],
+    [1.9476747e-5, -0.009167151, -0.69357646],
+    [3.93154e-6, 0.0078079295, -5.1815214],
+    [-1.1099558e-5, 0.024448115, -9.629511],
+    [-2.6248443e-5, 0.04150956, -14.25171],
+    [-4.2109426e-5, 0.059798665, -19.294348],
+    [-5.8914884e-5, 0.07997453, -25.014322],
+    [-7.320479e-5, 0.09966896, -31.061697],
+    [-1.737308e-5, 0.05631629, -22.980389],
+    [0.0004960189, -0.50767004, 126.63415],
+    [0.00028788956, -0.29241595, 72.30307],
+    [0.00020318839, -0.20446777, 50.022774],
+    [0.00015352406, -0.15266824, 36.8477],
+    [0.000119306336, -0.11679448, 27.682219],
+    [9.337003e-5, -0.08943919, 20.65728],
+    [7.237382e-5, -0.06713865, 14.89672],
+    [5.449788e-5, -0.04799552, 9.918173],
+    [3.8625072e-5, -0.030830873, 5.4188075],
+    [2.398918e-5, -0.014816082, 1.1814233],
+    [1.0007171e-5, 0.0007097463, -2.9735568],
+    [-3.791116e-6, 0.016331905, -7.2154107],
+    [-1.7701397e-5, 0.0325442, -11.70887],
+    [-3.123978e-5, 0.049266856, -16.52084],
+    [-3.847083e-5, 0.06163377, -20.672869],
+    [4.695653e-5, -0.011077696, -5.3250027],
+    [0.00046938486, -0.48266858, 121.16266],
+    [0.00028883072, -0.2952134, 73.62105],
+    [0.0002098845, -0.21297154, 52.703167],
+    [0.00016254882, -0.16346443, 40.07037],
+    [0.00012965777, -0.1289029, 31.21788],
+    [0.00010466733, -0.10249599, 24.423853],
+    [8.445248e-5, -0.080991864, 18.86177],
+    [6.728636e-5, -0.0625831, 14.069977],
+    [5.2103045e-5, -0.046140406, 9.757129],
+    [3.8181057e-5, -0.030880013, 5.716987],
+    [2.4995345e-5, -0.016201032, 1.7853359],
+    [1.216759e-5, -0.0016139932, -2.1822004],
+    [-4.2507543e-7, 0.01319595, -6.304071],
+    [-1.1887192e-5, 0.027730731, -10.540848],
+    [-1.4845314e-5, 0.03570453, -13.563699],
+    [8.947231e-5, -0.055307094, 6.2139983],
+    [0.00045111155, -0.46587223, 117.62009],
+    [0.0002903394, -0.2983691, 74.95397],
+    [0.00021586301, -0.22053955, 55.082382],
+    [0.0001702792, -0.17273127, 42.842255],
+    [0.00013831194, -0.1390579, 34.19265],
+    [0.000113928196, -0.11323732, 27.533833],
+    [9.418966e-5, -0.09220169, 22.082827],
+    [7.7453406e-5, -0.074225776, 17.397236],
+    [6.269939e-5, -0.058225177, 13.196258],
+    [4.9236944e-5, -0.043446526, 9.28093],
+    [3.6570636e-5, -0.02931878, 5.494451],
+    [2.43654e-5, -0.015396787, 1.703798],
+    [1.2581841e-5, -0.0014508343, -2.1876192],
+    [2.3158436e-6, 0.011821911, -6.090462],
+    [1.680166e-6, 0.017432373, -8.507763],
+    [0.0001182378, -0.08507417, 13.972095],
+    [0.00043948932, -0.45572108, 115.68176],
+    [0.00029317327, -0.30278057, 76.567444],
+    [0.00022214149, -0.22832774, 57.488064],
+    [0.00017786128, -0.18175921, 45.525707],
+    [0.0001465242, -0.14866787, 37.000454],
+    [0.0001225068, -0.12317925, 30.410326],
+    [0.000103021615, -0.102373876, 25.007318],
+    [8.649351e-5, -0.08459261, 20.364449],
+    [7.193926e-5, -0.06878712, 16.209372],
+    [5.8692767e-5, -0.05422842, 12.349022],
+    [4.6278037e-5, -0.04036517, 8.631543],
+    [3.4378114e-5, -0.026770655, 4.9286847],
+    [2.2975739e-5, -0.013236823, 1.1494569],
+    [1.32116575e-5, -0.000500171, -2.6075306],
+    [1.3416684e-5, 0.0042763287, -4.810323],
+    [0.0001377746, -0.10519632, 19.228092],
+    [0.00043362158, -0.45141777, 115.18978],
+    [0.0002978564, -0.3090658, 78.64356],
+    [0.00022938813, -0.2370931, 60.134224],
+    [0.00018601946, -0.19136168, 48.348488],
+    [0.00015506649, -0.15859632, 39.882103],
+    [0.0001312246, -0.13323866, 33.308403],
+    [0.000111821915, -0.1124818, 27.90556],
+    [9.533263e-5, -0.09471396, 23.25731],
+    [8.0797174e-5, -0.078909196, 19.096325],
+    [6.7562476e-5, -0.064351104, 15.232434],
+    [5.5159955e-5, -0.050495796, 11.515675],
+    [4.3273347e-5, -0.03691751, 7.8182626],
+    [3.1867712e-5, -0.023389686, 4.044825],
+    [2.2011218e-5, -0.010569047, 0.27342024],
+    [2.1827136e-5, -0.0053634406, -2.0358734],
+    [0.00015066839, -0.1184103, 22.705463],
+    [0.0004332036, -0.4527458, 116.122696],
+    [0.00030491967, -0.31783745, 81.35899],
+    [0.00023820496, -0.24751292, 63.2109],
+    [0.00019537285, -0.20223051, 51.503334],
+    [0.00016457339, -0.16955036, 43.033974],
+    [0.00014073684, -0.14414434, 36.43011],
+    [0.00012127257, -0.123283036, 30.987255],
+    [0.000104685045, -0.105382204, 26.294876],
+    [9.002455e-5, -0.089423954, 22.086954],
+    [7.663725e-5, -0.07468993, 18.172451],
+    [6.404518e-5, -0.06062621, 14.398775],
+    [5.1909698e-5, -0.0467842, 10.632575],
+    [4.0140338e-5, -0.03288069, 6.7646313],
+    [2.963741e-5, -0.019397294, 2.8290124],
+    [2.7936963e-5, -0.012606538, 0.1201643],
+    [0.00015827692, -0.12615883, 24.789152],
+    [0.00043847552, -0.46004638, 118.59842],
+    [0.00031505112, -0.329873, 84.93294],
+    [0.00024928898, -0.26036486, 66.93438],
+    [0.000206605, -0.21512562, 55.200787],
+    [0.00017572226, -0.18228175, 46.664013],
+    [0.00015172355, -0.1566503, 39.983776],
+    [0.00013206394, -0.13554238, 34.463554],
+    [0.000115258605, -0.11738087, 29.693272],
+    [0.000100353405, -0.10114089, 25.404316],
+    [8.6679436e-5, -0.08608699, 21.40082],
+    [7.3730975e-5, -0.07163577, 17.522476],
+    [6.111847e-5, -0.05728597, 13.622616],
+    [4.864641e-5, -0.042646658, 9.565186],
+    [3.6943267e-5, -0.027919672, 5.314366],
+    [3.2578195e-5, -0.018363606, 1.9063491],
+    [0.00016099284, -0.12889095, 25.61082],
+    [0.00045032974, -0.474351, 122.91507],
+    [0.0003292746, -0.3463132, 89.682205],
+    [0.00026360003, -0.27671325, 71.598694],
+    [0.00022062806, -0.23105702, 59.719475],
+    [0.00018939847, -0.19777027, 51.042362],
+    [0.0001650587, -0.17172332, 44.2359],
+    [0.00014507028, -0.15022661, 38.60087],
+    [0.00012793699, -0.13168673, 33.721558],
+    [0.0001126845, -0.11505509, 29.32237],
+    [9.8613986e-5, -0.099563345, 25.198431],
+    [8.5172855e-5, -0.08457821, 21.176323],
+    [7.188931e-5, -0.069512606, 17.08736],
+    [5.8401587e-5, -0.05380381, 12.752055],
+    [4.4926754e-5, -0.037230544, 8.0284195],
+    [3.6607245e-5, -0.023576243, 3.5809796],
+    [0.00015821701, -0.12605812, 25.055225],
+    [0.0004706647, -0.49777684, 129.66106],
+    [0.00034926002, -0.36900476, 96.11528],
+    [0.00028263388, -0.2982103, 77.658356],
+    [0.00023883906, -0.25156775, 65.48397],
+    [0.00020694587, -0.21750039, 56.577606],
+    [0.00018206169, -0.190821, 49.58776],
+    [0.00016160587, -0.16878763, 43.79895],
+    [0.00014404375, -0.14976181, 38.78225],
+    [0.00012836189, -0.13265093, 34.2497],
+    [0.000113814705, -0.116635635, 29.982584],
+    [9.978185e-5, -0.10101039, 25.7884],
+    [8.567498e-5, -0.08506556, 21.4667],
+    [7.089452e-5, -0.06799086, 16.774656],
+    [5.506022e-5, -0.04894777, 11.4140215],
+    [4.122728e-5, -0.029571591, 5.509195],
+    [0.00014795146, -0.11573061, 22.673773],
+    [0.00050328666, -0.5345179, 139.9861],
+    [0.00037799333, -0.40123647, 105.133705],
+    [0.00030899586, -0.32772788, 85.90161],
+    [0.00026365247, -0.27932116, 73.226036],
+    [0.0002306848, -0.24403295, 63.97391],
+    [0.00020501601, -0.21646442, 56.732967],
+    [0.00018395546, -0.19374846, 50.75221],
+    [0.00016589223, -0.17416099, 45.578693],
+    [0.0001497492, -0.15653883, 40.90498],
+    [0.00013471274, -0.13998859, 36.492325],
+    [0.000120070086, -0.123705715, 32.121937],
+    [0.0001050763, -0.10681483, 27.54998],
+    [8.880822e-5, -0.08816612, 22.445803],
+    [7.0045404e-5, -0.066046625, 16.287432],
+    [4.87477e-5, -0.038898196, 8.392025],
+    [0.00012569984, -0.09353829, 17.438839],
+    [0.0005563362, -0.59350145, 156.32391],
+    [0.00042147504, -0.44960487, 118.54188],
+    [0.00034784412, -0.3709459, 97.88564],
+    [0.00029984413, -0.31958207, 84.39135],
+    [0.00026523028, -0.28245938, 74.630875],
+    [0.00023850567, -0.25371346, 67.06356],
+    [0.00021676002, -0.23023386, 60.871307],
+    [0.00019824768, -0.21014754, 55.560207],
+    [0.00018179129, -0.19218124, 50.792717],
+    [0.0001664811, -0.17533697, 46.301792],
+    [0.0001514811, -0.1586776, 41.833202],
+    [0.00013584015, -0.14110784, 37.085003],
+    [0.000118201, -0.121018596, 31.606339],
+    [9.6170654e-5, -0.09546551, 24.556234],
+    [6.503845e-5, -0.058034994, 14.011025],
+    [8.1650855e-5, -0.049962174, 7.120775],
+    [0.0006503158, -0.6971668, 184.77768],
+    [0.00049411575, -0.5299376, 140.66495],
+    [0.00041145744, -0.44137874, 117.31388],
+    [0.00035884825, -0.38495222, 102.43963],
+    [0.00032178208, -0.34513444, 91.94478],
+    [0.00029386568, -0.31508029, 84.022255],
+    [0.00027176144, -0.29121113, 77.72663],
+    [0.00025348997, -0.2713981, 72.49436],
+    [0.00023772597, -0.25420627, 67.94404],
+    [0.00022344048, -0.23850833, 63.77368],
+    [0.00020965717, -0.22321609, 59.688614],
+    [0.00019518108, -0.20697345, 55.317497],
+    [0.00017809401, -0.18757345, 50.05127],
+    [0.00015434134, -0.16030912, 42.585712],
+    [0.00011183748, -0.11100808, 28.981796],
+    [-5.3090257e-6, 0.035182867, -12.869811],
+    [0.0008577613, -0.9248296, 246.89737],
+    [0.0006436326, -0.69461983, 185.81108],
+    [0.0005402078, -0.58344966, 156.3575],
+    [0.000478899, -0.51756465, 138.93803],
+    [0.00043897404, -0.4746748, 127.632256],
+    [0.0004118765, -0.44558236, 119.99876],
+    [0.00039345765, -0.42582864, 114.85557],
+    [0.00038154307, -0.41307768, 111.585266],
+    [0.00037500708, -0.40612125, 109.870964],
+    [0.00037339094, -0.40446812, 109.58792],
+    [0.00037675953, -0.40818948, 110.76348],
+    [0.0003856929, -0.41790769, 113.57614],
+    [0.00040130218, -0.4348053, 118.36203],
+    [0.00042457305, -0.45988253, 125.426056],
+    [0.00044691956, -0.4835771, 132.30283],
+    [-1.1513766e-6, 0.0012429268, 5.6439567],
+    [0.00065312075, -0.6473926, 154.36584],
+    [0.00020888183, -0.19436884, 42.013172],
+    [9.3516566e-5, -0.07606416, 12.498134],
+    [3.752954e-5, -0.018793885, -1.7053094],
+    [3.002852e-6, 0.016528819, -10.439072],
+    [-2.1551228e-5, 0.041750934, -16.686472],
+    [-4.0900115e-5, 0.061787907, -21.683647],
+    [-5.7464094e-5, 0.079143174, -26.061388],
+    [-7.2705814e-5, 0.09534669, -30.209135],
+    [-8.770442e-5, 0.11155386, -34.427586],
+    [-0.000103468694, 0.12888144, -39.016533],
+    [-0.00012119485, 0.14870152, -44.35588],
+    [-0.00014264646, 0.1731014, -51.038277],
+    [-0.00017096098, 0.20590994, -60.175255],
+    [-0.00021244262, 0.25529304, -74.22856],
+    [-0.00025329375, 0.3155899, -93.653496],
+    [0.00060126785, -0.6075152, 148.90602],
+    [0.00028102763, -0.27953362, 67.1252],
+    [0.00017654778, -0.17173098, 40.014748],
+    [0.000118343974, -0.11126937, 24.699415],
+    [7.92822e-5, -0.07044831, 14.299667],
+    [5.0277056e-5, -0.03995457, 6.4905667],
+    [2.7148073e-5, -0.015472235, 0.1852486],
+    [7.6040437e-6, 0.0053852755, -5.223184],
+    [-9.786974e-6, 0.024129407, -10.124088],
+    [-2.604591e-5, 0.04186211, -14.80706],
+    [-4.2019994e-5, 0.05953142, -19.528767],
+    [-5.8538266e-5, 0.07811565, -24.5648],
+    [-7.651953e-5, 0.09879213, -30.265045],
+    [-9.6813965e-5, 0.12294162, -37.091084],
+    [-0.00011673081, 0.14926636, -45.03064],
+    [-3.0038065e-5, 0.07860571, -30.97274],
+    [0.0005442503, -0.5539501, 137.16345],
+    [0.00028956332, -0.29176518, 71.36094],
+    [0.00019607967, -0.19504552, 46.961365],
+    [0.00014275483, -0.13957615, 32.89394],
+    [0.000106385334, -0.10151936, 23.18871],
+    [7.8952165e-5, -0.07262551, 15.776868],
+    [5.682282e-5, -0.049146235, 9.715694],
+    [3.8034796e-5, -0.029042332, 4.4888573],
+    [2.1370697e-5, -0.011030959, -0.23275496],
+    [5.981494e-6, 0.005806104, -4.690089],
+    [-8.791896e-6, 0.022215907, -9.086554],
+    [-2.349548e-5, 0.038875863, -13.618281],
+    [-3.8489583e-5, 0.05637477, -18.480967],
+    [-5.3179327e-5, 0.0745907, -23.747387],
+    [-5.9400918e-5, 0.08664789, -27.98963],
+    [9.666053e-5, -0.053013645, 3.1805718],
+    [0.00050421094, -0.51599765, 128.71823],
+    [0.00029134646, -0.2959015, 73.17363],
+    [0.0002055832, -0.20687056, 50.622387],
+    [0.00015551127, -0.15465519, 37.34324],
+    [0.000121115416, -0.11859804, 28.131641],
+    [9.5112824e-5, -0.09117144, 21.088314],
+    [7.4120195e-5, -0.06886839, 15.326128],
+    [5.630382e-5, -0.049776502, 10.358785],
+    [4.054784e-5, -0.03271678, 5.8831525],
+    [2.6103831e-5, -0.016876359, 1.6855258],
+    [1.2431909e-5, -0.0016338747, -2.4046206],
+    [-8.358665e-7, 0.013501021, -6.534538],
+    [-1.3719246e-5, 0.028763128, -10.808267],
+    [-2.4769872e-5, 0.043157853, -15.076158],
+    [-2.2650624e-5, 0.046644304, -17.123255],
+    [0.00017480241, -0.13310258, 23.730297],
+    [0.0004763263, -0.48974615, 122.943146],
+    [0.00029212452, -0.2985454, 74.45726],
+    [0.00021224127, -0.21534275, 53.297165],
+    [0.00016452748, -0.16544662, 40.56601],
+    [0.00013146632, -0.13070762, 31.668184],
+    [0.00010641193, -0.104230046, 24.855389],
+    [8.6202854e-5, -0.08272424, 19.291481],
+    [6.910108e-5, -0.0643705, 14.51153],
+    [5.4044056e-5, -0.048041508, 10.224391],
+    [4.032984e-5, -0.03297089, 6.227691],
+    [2.7479859e-5, -0.018600436, 2.3670053],
+    [1.5226061e-5, -0.004543356, -1.4781741],
+    [3.7431737e-6, 0.009235504, -5.360688],
+    [-5.0353947e-6, 0.02125388, -9.008303],
+    [2.0732825e-6, 0.019674148, -9.769356],
+    [0.00022655238, -0.18551286, 37.05767],
+    [0.0004572903, -0.47219297, 119.218445],
+    [0.0002935522, -0.30163187, 75.77714],
+    [0.00021822884, -0.22292924, 55.683952],
+    [0.0001722884, -0.17475136, 43.349545],
+    [0.0001401562, -0.14090396, 34.65492],
+    [0.00011570841, -0.115011185, 27.976515],
+    [9.597392e-5, -0.09397087, 22.52255],
+    [7.930031e-5, -0.0760471, 17.84774],
+    [6.467237e-5, -0.060158886, 13.671831],
+    [5.1420575e-5, -0.045571867, 9.800122],
+    [3.9098082e-5, -0.03175968, 6.086073],
+    [2.7483946e-5, -0.018383384, 2.4213336],
+    [1.684592e-5, -0.005501532, -1.2231829],
+    [9.3533345e-6, 0.005173022, -4.518466],
+    [1.9167761e-5, 0.00088845403, -4.5990605],
+    [0.0002636455, -0.22257781, 46.39295],
+    [0.00044522743, -0.46161294, 117.17969],
+    [0.00029638168, -0.30605355, 77.397896],
+    [0.00022456383, -0.23078622, 58.11045],
+    [0.00017994028, -0.1838595, 46.05599],
+    [0.00014844022, -0.15059459, 37.485355],
+    [0.00012435709, -0.12503083, 30.874529],
+    [0.00010487277, -0.10421645, 25.467157],
+    [8.8403496e-5, -0.08648243, 20.833538],
+    [7.397122e-5, -0.070784114, 16.701925],
+    [6.093141e-5, -0.056411877, 12.883581],
+    [4.8857502e-5, -0.042859826, 9.237085],
+    [3.7547885e-5, -0.029808423, 5.6590457],
+    [2.7295002e-5, -0.017339574, 2.1261985],
+    [2.0323618e-5, -0.0072123613, -1.0223169],
+    [3.114797e-5, -0.012461558, -0.86499566],
+    [0.00029310468, -0.25153768, 53.59961],
+    [0.0004391623, -0.4571311, 116.6505],
+    [0.00030114054, -0.31243315, 79.50315],
+    [0.00023192172, -0.23967882, 60.792904],
+    [0.00018821453, -0.19359198, 48.91511],
+    [0.00015709683, -0.16064982, 40.40208],
+    [0.00013318573, -0.13521232, 33.806164],
+    [0.00011377985, -0.11444136, 28.397387],
+    [9.734474e-5, -0.09671511, 23.7567],
+    [8.29259e-5, -0.08101116, 19.617313],
+    [6.989129e-5, -0.06663178, 15.793188],
+    [5.782088e-5, -0.053077526, 12.144514],
+    [4.6512363e-5, -0.040028162, 8.567899],
+    [3.623764e-5, -0.02754345, 5.034623],
+    [2.914256e-5, -0.017293155, 1.8601843],
+    [3.9593928e-5, -0.022094015, 1.8980831],
+    [0.0003193091, -0.2768258, 59.80068],
+    [0.00043876225, -0.45850492, 117.60384],
+    [0.00030837418, -0.32139975, 82.274216],
+    [0.00024091963, -0.2503008, 63.925873],
+    [0.0001977444, -0.20465606, 52.123833],
+    [0.00016677393, -0.17179118, 43.605377],
+    [0.00014286247, -0.14629836, 36.9772],
+    [0.0001233898, -0.12541673, 31.526566],
+    [0.00010685154, -0.10755164, 26.840034],
+    [9.230219e-5, -0.09168777, 22.651844],
+    [7.910788e-5, -0.077124305, 18.774792],
+    [6.683735e-5, -0.06334969, 15.065878],
+    [5.5262135e-5, -0.050016798, 11.415057],
+    [4.459014e-5, -0.037119467, 7.777299],
+    [3.6777496e-5, -0.026127618, 4.417345],
+    [4.5601384e-5, -0.029199451, 4.012597],
+    [0.00034515007, -0.30130112, 65.70638],
+    [0.00044427864, -0.46609062, 120.16255],
+    [0.0003187991, -0.3337622, 85.938934],
+    [0.0002522812, -0.26345903, 67.73363],
+    [0.00020923828, -0.2178388, 55.90008],
+    [0.0001781728, -0.18479653, 47.310265],
+    [0.0001540907, -0.15906842, 40.60287],
+    [0.00013441652, -0.1379329, 35.072758],
+    [0.000117655414, -0.11980116, 30.306576],
+    [0.00010285647, -0.10364978, 26.035667],
+    [8.936934e-5, -0.08875929, 22.06758],
+    [7.673248e-5, -0.07458596, 18.250875],
+    [6.46612e-5, -0.060724318, 14.46071],
+    [5.324518e-5, -0.047046468, 10.62156],
+    [4.413099e-5, -0.034702033, 6.917639],
+    [5.005529e-5, -0.0347425, 5.7408915],
+    [0.00037295185, -0.3271849, 71.85292],
+    [0.000456663, -0.48098573, 124.64311],
+    [0.0003334939, -0.35072133, 90.83053],
+    [0.0002670143, -0.28027046, 72.52451],
+    [0.00022365272, -0.23419872, 60.535717],
+    [0.00019222134, -0.20069212, 51.79956],
+    [0.00016778636, -0.17453508, 44.96215],
+    [0.00014777611, -0.1530023, 39.314804],
+    [0.00013068273, -0.13448699, 34.438038],
+    [0.00011553361, -0.11794023, 30.05575],
+    [0.00010164672, -0.1026078, 25.96596],
+    [8.851068e-5, -0.08789292, 22.003292],
+    [7.575223e-5, -0.073297516, 18.01911],
+    [6.327799e-5, -0.058504246, 13.88988],
+    [5.226931e-5, -0.044182893, 9.6789875],
+    [5.38665e-5, -0.03972188, 7.356292],
+    [0.00040515233, -0.35671994, 78.763985],
+    [0.00047795536, -0.50546265, 131.67627],
+    [0.00035423576, -0.37424114, 97.48942],
+    [0.0002867065, -0.30248773, 78.78044],
+    [0.00024246935, -0.25537154, 66.48057],
+    [0.00021034486, -0.2210516, 57.506172],
+    [0.0001853496, -0.19424431, 50.48042],
+    [0.00016486473, -0.17216644, 44.67687],
+    [0.00014734117, -0.15316306, 39.661987],
+    [0.00013176512, -0.13613899, 35.14667],
+    [0.00011740578, -0.12028675, 30.914492],
+    [0.000103680766, -0.10493375, 26.780048],
+    [9.009317e-5, -0.08945182, 22.560936],
+    [7.629196e-5, -0.07325511, 18.064857],
+    [6.279103e-5, -0.056322806, 13.180092],
+    [5.83407e-5, -0.045574006, 9.253091],
+    [0.00044506893, -0.39286456, 87.11095],
+    [0.000512288, -0.5440709, 142.50815],
+    [0.00038423968, -0.40786082, 106.885254],
+    [0.00031415664, -0.3331945, 87.347374],
+    [0.00026828112, -0.28421608, 74.519775],
+    [0.00023503676, -0.24862552, 65.1861],
+    [0.00020923698, -0.2209069, 57.90316],
+    [0.00018814414, -0.19814235, 51.906425],
+    [0.00017012829, -0.17858616, 46.73693],
+    [0.00015410892, -0.1610692, 42.085186],
+    [0.0001392849, -0.14470744, 37.714027],
+    [0.00012497822, -0.12872572, 33.41076],
+    [0.000110528155, -0.11232219, 28.947481],
+    [9.523798e-5, -0.09454789, 24.037853],
+    [7.8693556e-5, -0.0744154, 18.322628],
+    [6.605888e-5, -0.05513816, 12.210015],
+    [0.0004981745, -0.44037876, 97.95027],
+    [0.0005686447, -0.60665655, 159.82124],
+    [0.0004300737, -0.45879734, 120.99122],
+    [0.000354999, -0.37859228, 99.92483],
+    [0.00030630556, -0.32648164, 86.231415],
+    [0.00027134534, -0.28898084, 76.36902],
+    [0.00024447273, -0.26006612, 68.75469],
+    [0.00022271385, -0.2365582, 62.55193],
+    [0.00020429565, -0.21655332, 57.25832],
+    [0.00018803363, -0.19876802, 52.53308],
+    [0.00017302875, -0.18221149, 48.110115],
+    [0.00015847814, -0.16597389, 43.740635],
+    [0.00014351027, -0.14902806, 39.137028],
+    [0.00012697438, -0.12994352, 33.88666],
+    [0.00010718183, -0.10639255, 27.282583],
+    [8.3865394e-5, -0.0759393, 18.288527],
+    [0.0005747239, -0.5078809, 113.14064],
+    [0.0006702889, -0.7186674, 190.53342],
+    [0.0005079303, -0.54482776, 144.66315],
+    [0.00042300444, -0.4538314, 120.662895],
+    [0.00036937805, -0.39630827, 105.49603],
+    [0.0003318729, -0.3560134, 94.87333],
+    [0.00030385592, -0.3258435, 86.91894],
+    [0.00028188978, -0.30211216, 80.65847],
+    [0.0002639555, -0.28264618, 75.51629],
+    [0.00024872125, -0.26600114, 71.10784],
+    [0.00023517809, -0.25106648, 67.13459],
+    [0.00022240008, -0.23679931, 63.311947],
+    [0.00020929631, -0.22193651, 59.28917],
+    [0.00019417884, -0.2044722, 54.500557],
+    [0.0001736495, -0.18025754, 47.758465],
+    [0.0001390356, -0.13799566, 35.720135],
+    [0.0006897076, -0.605554, 134.45744],
+    [0.0009046723, -0.9756821, 260.60046],
+    [0.0006743625, -0.7280109, 194.84451],
+    [0.000565902, -0.6114087, 163.93929],
+    [0.0005028333, -0.54363257, 146.01793],
+    [0.0004627085, -0.5005412, 134.66493],
+    [0.00043645524, -0.47238052, 127.29037],
+    [0.00041980617, -0.4545653, 122.67908],
+    [0.00041068523, -0.44486815, 120.24386],
+    [0.00040826516, -0.4424072, 119.75713],
+    [0.0004126832, -0.44734162, 121.27163],
+    [0.0004251579, -0.46100116, 125.15784],
+    [0.0004486586, -0.48661345, 132.30421],
+    [0.0004899599, -0.5315341, 144.72849],
+    [0.0005667815, -0.61499083, 167.69827],
+    [0.0007457238, -0.8092077, 220.99222],
+    [-5.984073e-5, 0.06460172, 5.419727],
+    [0.00065492245, -0.6492073, 154.81177],
+    [0.00020930656, -0.19480567, 42.124477],
+    [9.3768766e-5, -0.07632465, 12.565178],
+    [3.7718622e-5, -0.018989174, -1.6548597],
+    [3.1604836e-6, 0.01636643, -10.397111],
+    [-2.1411744e-5, 0.041607928, -16.64961],
+    [-4.0771978e-5, 0.061657466, -21.65019],
+    [-5.7343437e-5, 0.079021566, -26.030441],
+    [-7.25899e-5, 0.09523147, -30.180155],
+    [-8.7591354e-5, 0.11144367, -34.400352],
+    [-0.00010335648, 0.12877518, -38.990982],
+    [-0.00012108061, 0.148598, -44.332096],
+    [-0.00014252531, 0.17299914, -51.016666],
+    [-0.00017081549, 0.20579971, -60.155388],
+    [-0.00021215042, 0.25507832, -74.1923],
+    [-0.00024840704, 0.31100288, -92.57783],
+    [0.0006023747, -0.60863334, 149.18251],
+    [0.00028134868, -0.2798566, 67.20543],
+    [0.0001767564, -0.17194115, 40.067345],
+    [0.00011851339, -0.1114403, 24.742432],
+    [7.9432975e-5, -0.07060039, 14.338035],
+    [5.0418366e-5, -0.040096793, 6.52644],
+    [2.7285267e-5, -0.015609769, 0.2198581],
+    [7.740988e-6, 0.00524878, -5.188988],
+    [-9.64646e-6, 0.023990436, -10.089502],
+    [-2.589772e-5, 0.041717034, -14.771289],
+    [-4.185792e-5, 0.059374787, -19.490625],
+    [-5.8350804e-5, 0.07793732, -24.522068],
+    [-7.628121e-5, 0.09856936, -30.212666],
+    [-9.644491e-5, 0.12260104, -37.012234],
+    [-0.00011580268, 0.14840136, -44.828934],
+    [-1.9868397e-5, 0.068813406, -28.618519],
+    [0.00054505083, -0.55476135, 137.36537],
+    [0.00028985023, -0.2920538, 71.43273],
+    [0.00019627126, -0.19523759, 47.009205],
+    [0.00014291226, -0.13973373, 32.933258],
+    [0.00010652807, -0.10166203, 23.224344],
+    [7.9089106e-5, -0.07276211, 15.810982],
+    [5.6959136e-5, -0.049281787, 9.749486],
+    [3.8174454e-5, -0.029180586, 4.5232058],
+    [2.151806e-5, -0.011175984, -0.19690894],
+    [6.1418887e-6, 0.0056494395, -4.651636],
+    [-8.60984e-6, 0.022039702, -9.04369],
+    [-2.3275259e-5, 0.03866491, -13.567508],
+    [-3.819406e-5, 0.05609456, -18.41428],
+    [-5.269495e-5, 0.07413419, -23.639591],
+    [-5.8154565e-5, 0.08546617, -27.709524],
+    [0.00010909685, -0.064934865, 6.0320344],
+    [0.0005048455, -0.51664275, 128.87967],
+    [0.00029161273, -0.29617, 73.240685],
+    [0.00020576727, -0.20705523, 50.668465],
+    [0.00015566402, -0.1548079, 37.38132],
+    [0.000121254576, -0.11873675, 28.166199],
+    [9.524709e-5, -0.09130488, 21.121506],
+    [7.425499e-5, -0.06900188, 15.359258],
+    [5.6443525e-5, -0.049914267, 10.392855],
+    [4.0697283e-5, -0.032863364, 5.9192333],
+    [2.6269056e-5, -0.01703736, 1.7249112],
+    [1.262283e-5, -0.0018184838, -2.3598053],
+    [-6.001661e-7, 0.013275023, -6.4801564],
+    [-1.3395657e-5, 0.02845534, -10.734865],
+    [-2.422755e-5, 0.042644355, -14.954448],
+    [-2.1238542e-5, 0.045301817, -16.804405],
+    [0.00018838042, -0.14603707, 26.803606],
+    [0.00047686297, -0.49029338, 123.0808],
+    [0.00029237807, -0.29880187, 74.52162],
+    [0.00021242237, -0.21552494, 53.3428],
+    [0.00016467962, -0.16559905, 40.604115],
+    [0.00013160545, -0.13084646, 31.702816],
+    [0.000106546344, -0.10436364, 24.888628],
+    [8.633799e-5, -0.08285799, 19.324648],
+    [6.924148e-5, -0.06450879, 14.545682],
+    [5.419491e-5, -0.048189268, 10.260698],
+    [4.0497674e-5, -0.033134226, 6.267579],
+    [2.7675347e-5, -0.018789297, 2.412788],
+    [1.5469675e-5, -0.0047768787, -1.422031],
+    [4.0811547e-6, 0.0089139035, -5.28401],
+    [-4.46303e-6, 0.020711606, -8.879765],
+    [3.5757882e-6, 0.018246708, -9.430727],
+    [0.00024090627, -0.19910581, 40.267467],
+    [0.0004577673, -0.47268093, 119.341835],
+    [0.0002937994, -0.30188292, 75.84047],
+    [0.00021841069, -0.22311291, 55.730183],
+    [0.00017244289, -0.17490667, 43.388542],
+    [0.00014029804, -0.14104594, 34.690464],
+    [0.00011584554, -0.11514782, 28.010603],
+    [9.611164e-5, -0.09410742, 22.55648],
+    [7.944317e-5, -0.07618797, 17.882574],
+    [6.482563e-5, -0.06030911, 13.708767],
+    [5.1591025e-5, -0.045737784, 9.840645],
+    [3.929678e-5, -0.03195163, 6.1325936],
+    [2.7732045e-5, -0.01862115, 2.4784713],
+    [1.7191107e-5, -0.0058298614, -1.1449511],
+    [9.939973e-6, 0.0046176733, -4.38697],
+    [2.0717343e-5, -0.00058093574, -4.251239],
+    [0.0002788819, -0.23693646, 49.766476],
+    [0.00044566995, -0.46206734, 117.29521],
+    [0.0002966284, -0.30630523, 77.46175],
+    [0.00022474994, -0.2309751, 58.158257],
+    [0.00018010003, -0.18402086, 46.096725],
+    [0.00014858753, -0.15074272, 37.52263],
+    [0.00012449958, -0.1251734, 30.910267],
+    [0.00010501563, -0.10435863, 25.502632],
+    [8.85512e-5, -0.08662857, 20.869802],
+    [7.4129086e-5, -0.070939265, 16.740183],
+    [6.110608e-5, -0.05658226, 12.925284],
+    [4.9060232e-5, -0.043055948, 9.284685],
+    [3.779993e-5, -0.030050125, 5.717167],
+    [2.764439e-5, -0.017671833, 2.20535],
+    [2.091597e-5, -0.007772457, -0.8898626],
+    [3.271922e-5, -0.013948086, -0.51397884],
+    [0.00030967075, -0.26709506, 57.24176],
+    [0.00043958935, -0.45757142, 116.76308],
+    [0.00030139321, -0.31269225, 79.56929],
+    [0.00023211636, -0.23987746, 60.8435],
+    [0.00018838326, -0.1937634, 48.95866],
+    [0.00015725303, -0.1608078, 40.442078],
+    [0.00013333684, -0.13536438, 33.84451],
+    [0.00011393099, -0.11459261, 28.43534],
+    [9.7500466e-5, -0.09686999, 23.795341],
+    [8.309135e-5, -0.08117453, 19.657795],
+    [7.007312e-5, -0.06680986, 15.836964],
+    [5.8030066e-5, -0.053280532, 12.1939535],
+    [4.676995e-5, -0.040275674, 8.627552],
+    [3.659114e-5, -0.027879825, 5.114821],
+    [2.973649e-5, -0.017854165, 1.992735],
+    [4.1171323e-5, -0.023582786, 2.248773],
+    [0.000337866, -0.29421154, 63.861248],
+    [0.00043919054, -0.45894867, 117.717964],
+    [0.00030863998, -0.32167384, 82.34462],
+    [0.00024112822, -0.250515, 63.9808],
+    [0.00019792668, -0.20484248, 52.171516],
+    [0.00016694328, -0.17196362, 43.64934],
+    [0.0001430263, -0.14646439, 37.019356],
+    [0.00012355331, -0.12558149, 31.568203],
+    [0.0001070192, -0.10771952, 26.882208],
+    [9.247916e-5, -0.09186367, 22.695724],
+    [7.930073e-5, -0.077314325, 18.821796],
+    [6.7056775e-5, -0.06356375, 15.1182995],
+    [5.552862e-5, -0.050273873, 11.477284],
+    [4.4949997e-5, -0.03746259, 7.8593063],
+    [3.737262e-5, -0.026689578, 4.5501204],
+    [4.7175425e-5, -0.030681642, 4.3609653],
+    [0.0003666101, -0.3213742, 70.38727],
+    [0.00044472623, -0.46655682, 120.28319],
+    [0.0003190875, -0.3340614, 86.01633],
+    [0.00025251115, -0.26369682, 67.79504],
+    [0.00020944087, -0.21804753, 55.953873],
+    [0.00017836153, -0.1849902, 47.360023],
+    [0.00015427326, -0.15925491, 40.650604],
+    [0.0001345983, -0.13811761, 35.11982],
+    [0.000117840995, -0.11998855, 30.354048],
+    [0.00010305104, -0.103844784, 26.084726],
+    [8.957937e-5, -0.088967934, 22.11962],
+    [7.6968405e-5, -0.074817866, 18.308115],
+    [6.4942855e-5, -0.060997758, 14.527349],
+    [5.3617197e-5, -0.04740269, 10.7071085],
+    [4.4730838e-5, -0.035269026, 7.051813],
+    [5.1620995e-5, -0.036213875, 6.0861034],
+    [0.00039868447, -0.3512268, 77.45337],
+    [0.00045715144, -0.48149744, 124.776375],
+    [0.00033381867, -0.35106066, 90.918915],
+    [0.00026727698, -0.28054413, 72.59574],
+    [0.00022388551, -0.2344405, 60.598526],
+    [0.00019243885, -0.20091724, 51.85789],
+    [0.00016799684, -0.17475204, 45.01818],
+    [0.0001479853, -0.15321688, 39.36998],
+    [0.00013089548, -0.13470395, 34.493534],
+    [0.00011575512, -0.11816453, 30.112745],
+    [0.0001018836, -0.10284557, 26.025883],
+    [8.877301e-5, -0.088153414, 22.068256],
+    [7.605924e-5, -0.07359837, 18.09315],
+    [6.36724e-5, -0.05888465, 13.981964],
+    [5.2882704e-5, -0.04476461, 9.817212],
+    [5.542295e-5, -0.04118247, 7.6986465],
+    [0.0004374209, -0.38684282, 85.775665],
+    [0.00047851863, -0.5060564, 131.83195],
+    [0.00035461955, -0.37464505, 97.595406],
+    [0.00028702032, -0.3028173, 78.8669],
+    [0.00024274923, -0.25566477, 66.55739],
+    [0.00021060713, -0.22132562, 57.577812],
+    [0.00018560361, -0.19450876, 50.54937],
+    [0.00016511703, -0.17242801, 44.74482],
+    [0.00014759685, -0.15342677, 39.73018],
+    [0.00013202993, -0.13641039, 35.216434],
+    [0.00011768647, -0.12057208, 30.987293],
+    [0.00010398728, -0.10524214, 26.857958],
+    [9.044426e-5, -0.08980036, 22.647844],
+    [7.6728546e-5, -0.07368105, 18.16922],
+    [6.34373e-5, -0.05694011, 13.327982],
+    [5.9896127e-5, -0.047033567, 9.595461],
+    [0.00048809103, -0.43299958, 96.447945],
+    [0.00051298534, -0.54481095, 142.70352],
+    [0.00038472255, -0.4083729, 107.02065],
+    [0.00031455522, -0.3336167, 87.45904],
+    [0.00026863886, -0.28459442, 74.61977],
+    [0.00023537347, -0.24898086, 65.27988],
+    [0.00020956401, -0.22125109, 57.993813],
+    [0.00018846922, -0.19848335, 51.995983],
+    [0.00017045767, -0.17893021, 46.82697],
+    [0.00015444901, -0.16142258, 42.17722],
+    [0.00013964294, -0.14507693, 37.809654],
+    [0.0001253648, -0.12912102, 33.512203],
+    [0.00011096187, -0.11276024, 29.058573],
+    [9.575803e-5, -0.09506403, 24.166533],
+    [7.9416845e-5, -0.07511579, 18.492928],
+    [6.764567e-5, -0.056632146, 12.562109],
+    [0.0005610685, -0.49902046, 111.58658],
+    [0.0005696037, -0.6076815, 160.09373],
+    [0.0004307419, -0.45951173, 121.18157],
+    [0.0003555547, -0.37918618, 100.083206],
+    [0.00030680763, -0.3270178, 86.374405],
+    [0.00027182084, -0.28948796, 76.50418],
+    [0.0002449374, -0.26056084, 68.8864],
+    [0.00022317831, -0.2370516, 62.68304],
+    [0.00020476841, -0.21705405, 57.391052],
+    [0.00018852318, -0.19928457, 52.66955],
+    [0.00017354416, -0.1827526, 48.252422],
+    [0.00015903189, -0.16655125, 43.89152],
+    [0.0001441228, -0.14966041, 39.30078],
+    [0.00012768553, -0.13066675, 34.07131],
+    [0.00010810284, -0.10730641, 27.510393],
+    [8.560083e-5, -0.07759406, 18.684301],
+    [0.0006827196, -0.60852677, 136.53491],
+    [0.0006718635, -0.72036254, 190.98724],
+    [0.0005090152, -0.5459973, 144.9772],
+    [0.0004239104, -0.45480856, 120.92571],
+    [0.00037020468, -0.39719993, 105.73604],
+    [0.00033266656, -0.35686916, 95.10372],
+    [0.00030464336, -0.32669201, 87.14731],
+    [0.00028268984, -0.3029734, 80.89013],
+    [0.0002647848, -0.28353766, 75.75581],
+    [0.00024959608, -0.26693976, 71.35963],
+    [0.00023611606, -0.2520702, 67.40322],
+    [0.00022342493, -0.23789187, 63.603382],
+    [0.00021044187, -0.22315092, 59.61147],
+    [0.00019550194, -0.20586213, 54.86638],
+    [0.0001752812, -0.18194267, 48.195023],
+    [0.00014154582, -0.140486, 36.340885],
+    [0.00090538705, -0.80850464, 181.95712],
+    [0.00090538705, -0.9764744, 260.82196],
+    [0.00067684636, -0.73071027, 195.57486],
+    [0.00056797644, -0.6136664, 164.55162],
+    [0.0005047692, -0.5457415, 146.5908],
+    [0.0004646359, -0.5026422, 135.23627],
+    [0.00043846224, -0.4745693, 127.886055],
+    [0.0004219739, -0.45693016, 123.323074],
+    [0.00041310492, -0.4475086, 120.96323],
+    [0.00041106174, -0.44545949, 120.589],
+    [0.00041604193, -0.45100805, 122.271164],
+    [0.00042939032, -0.46562183, 126.41781],
+    [0.00045435363, -0.4928315, 134.00008],
+    [0.000498415, -0.5407662, 147.24673],
+    [0.0005817376, -0.63132215, 172.15349],
+    [0.0007858131, -0.85298115, 232.93408],
+    [-0.00090538705, 1.0269499, -253.57063],
+    [-0.00090538705, 0.99689084, -279.89255],
+    [-0.00090538705, 0.9827288, -272.4006],
+    [-0.00090538705, 0.9723454, -266.97818],
+    [-0.00090538705, 0.9647546, -263.18475],
+    [-0.00090538705, 0.9589596, -260.6216],
+    [-0.00090538705, 0.95328635, -258.15945],
+    [-0.00090538705, 0.94768834, -255.76265],
+    [-0.00090538705, 0.9421374, -253.4063],
+    [-0.00090538705, 0.93661666, -251.07755],
+    [-0.00090538705, 0.9311168, -248.7685],
+    [-0.00090538705, 0.9256337, -246.47366],
+    [-0.00090538705, 0.92016697, -244.1895],
+    [-0.00090538705, 0.9147188, -241.9147],
+    [-0.00090538705, 0.9092932, -239.64914],
+    [-0.00090538705, 0.90389544, -237.39357],
+    [-0.00090538705, 0.898532, -235.14941],
+    [-0.00090538705, 1.0003285, -281.81958],
+    [-0.00090538705, 0.98616743, -274.33548],
+    [-0.00090538705, 0.97565496, -268.83908],
+    [-0.00090538705, 0.9690917, -265.81863],
+    [-0.00090538705, 0.9632733, -263.34085],
+    [-0.00090538705, 0.9575082, -260.93613],
+    [-0.00090538705, 0.95175344, -258.56516],
+    [-0.00090538705, 0.94598323, -256.20908],
+    [-0.00090538705, 0.9401826, -253.8562],
+    [-0.00090538705, 0.9343441, -251.49762],
+    [-0.00090538705, 0.9284654, -249.1282],
+    [-0.00090538705, 0.92254794, -246.74545],
+    [-0.00090538705, 0.91659594, -244.34856],
+    [-0.00090538705, 0.91061586, -241.93791],
+    [-0.00090538705, 0.90461576, -239.51485],
+    [-0.00090538705, 0.89860487, -237.08165],
+    [-0.00090538705, 1.003574, -283.6455],
+    [-0.00090538705, 0.9894145, -276.16818],
+    [-0.00090538705, 0.97934526, -270.9791],
+    [-0.00090538705, 0.9735073, -268.531],
+    [-0.00090538705, 0.9677161, -266.17816],
+    [-0.00090538705, 0.9619046, -263.86374],
+    [-0.00090538705, 0.95603174, -261.55576],
+    [-0.00090538705, 0.95007247, -259.23624],
+    [-0.00090538705, 0.944012, -256.89127],
+    [-0.00090538705, 0.9378426, -254.51218],
+    [-0.00090538705, 0.931562, -252.0937],
+    [-0.00090538705, 0.9251717, -249.63289],
+    [-0.00090538705, 0.9186766, -247.12831],
+    [-0.00090538705, 0.91208416, -244.58025],
+    [-0.00090538705, 0.90540385, -241.99022],
+    [-0.00090538705, 0.89864707, -239.36078],
+    [-0.00090538705, 1.006679, -285.40085],
+    [-0.00090538705, 0.9924806, -277.90292],
+    [-0.00090538705, 0.9836841, -273.5779],
+    [-0.00090538705, 0.978006, -271.3228],
+    [-0.00090538705, 0.97229636, -269.1325],
+    [-0.00090538705, 0.9664895, -266.95047],
+    [-0.00090538705, 0.96054435, -264.7489],
+    [-0.00090538705, 0.9544341, -262.50714],
+    [-0.00090538705, 0.94814134, -260.21048],
+    [-0.00090538705, 0.9416557, -257.84918],
+    [-0.00090538705, 0.9349718, -255.41605],
+    [-0.00090538705, 0.9280888, -252.90622],
+    [-0.00090538705, 0.9210092, -250.31691],
+    [-0.00090538705, 0.91373867, -247.64702],
+    [-0.00090538705, 0.9062857, -244.89699],
+    [-0.00090538705, 0.8986612, -242.06866],
+    [-0.00090538705, 1.0096425, -287.08325],
+    [-0.00090538705, 0.99537945, -279.5462],
+    [-0.00090538705, 0.98805237, -276.2166],
+    [-0.00090538705, 0.9825921, -274.19534],
+    [-0.00090538705, 0.9770221, -272.2077],
+    [-0.00090538705, 0.9712773, -270.2051],
+    [-0.00090538705, 0.9653136, -268.15833],
+    [-0.00090538705, 0.95909953, -266.044],
+    [-0.00090538705, 0.95261186, -263.84604],
+    [-0.00090538705, 0.94583356, -261.55133],
+    [-0.00090538705, 0.9387526, -259.1495],
+    [-0.00090538705, 0.9313609, -256.63245],
+    [-0.00090538705, 0.9236541, -253.99414],
+    [-0.00090538705, 0.9156311, -251.2304],
+    [-0.00090538705, 0.9072944, -248.33879],
+    [-0.00090538705, 0.8986494, -245.3199],
+    [-0.00090538705, 1.0124304, -288.67017],
+    [-0.00090538705, 0.99811465, -281.09824],
+    [-0.00090538705, 0.99245214, -278.89444],
+    [-0.00090538705, 0.98726875, -277.14584],
+    [-0.00090538705, 0.9819011, -275.40747],
+    [-0.00090538705, 0.97628254, -273.6363],
+    [-0.00090538705, 0.9703631, -271.79922],
+    [-0.00090538705, 0.9641033, -269.87192],
+    [-0.00090538705, 0.95747006, -267.83426],
+    [-0.00090538705, 0.9504351, -265.66876],
+    [-0.00090538705, 0.9429736, -263.36023],
+    [-0.00090538705, 0.9350641, -260.89554],
+    [-0.00090538705, 0.92668766, -258.26328],
+    [-0.00090538705, 0.9178285, -255.45493],
+    [-0.00090538705, 0.9084736, -252.46169],
+    [-0.00090538705, 0.8986135, -249.27466],
+    [-0.00090538705, 1.0150605, -290.171],
+    [-0.00090538705, 1.0015765, -283.1246],
+    [-0.00090538705, 0.9968847, -281.61142],
+    [-0.00090538705, 0.9920388, -280.17487],
+    [-0.00090538705, 0.98694074, -278.7358],
+    [-0.00090538705, 0.9815198, -277.2519],
+    [-0.00090538705, 0.9757178, -275.68848],
+    [-0.00090538705, 0.9694835, -274.01846],
+    [-0.00090538705, 0.96276927, -272.21692],
+    [-0.00090538705, 0.95552945, -270.26108],
+    [-0.00090538705, 0.9477192, -268.12973],
+    [-0.00090538705, 0.939294, -265.80276],
+    [-0.00090538705, 0.9302092, -263.26343],
+    [-0.00090538705, 0.9204206, -260.48935],
+    [-0.00090538705, 0.90988374, -257.46164],
+    [-0.00090538705, 0.89855504, -254.16219],
+    [-0.00090538705, 1.01755, -291.59485],
+    [-0.00090538705, 1.0055828, -285.51218],
+    [-0.00090538705, 1.0013508, -284.36554],
+    [-0.00090538705, 0.99690455, -283.28268],
+    [-0.00090538705, 0.9921479, -282.19577],
+    [-0.00090538705, 0.98700416, -281.06104],
+    [-0.00090538705, 0.9814044, -279.8441],
+    [-0.00090538705, 0.9752823, -278.51422],
+    [-0.00090538705, 0.96857065, -277.0424],
+    [-0.00090538705, 0.9611992, -275.4003],
+    [-0.00090538705, 0.95309347, -273.56015],
+    [-0.00090538705, 0.944173, -271.4966],
+    [-0.00090538705, 0.9343506, -269.1751],
+    [-0.00090538705, 0.92353106, -266.56427],
+    [-0.00090538705, 0.9116104, -263.63077],
+    [-0.00090538705, 0.8984751, -260.3383],
+    [-0.00090538705, 1.0199288, -292.9591],
+    [-0.00090538705, 1.0095711, -287.90198],
+    [-0.00090538705, 1.0058502, -287.1542],
+    [-0.00090538705, 1.001868, -286.4694],
+    [-0.00090538705, 0.99752927, -285.78998],
+    [-0.00090538705, 0.99275076, -285.07303],
+    [-0.00090538705, 0.98745143, -284.28516],
+    [-0.00090538705, 0.9815468, -283.39386],
+    [-0.00090538705, 0.97494507, -282.36752],
+    [-0.00090538705, 0.9675439, -281.17432],
+    [-0.00090538705, 0.959227, -279.78427],
+    [-0.00090538705, 0.9498611, -278.15607],
+    [-0.00090538705, 0.93929106, -276.2491],
+    [-0.00090538705, 0.9273356, -274.01907],
+    [-0.00090538705, 0.9137813, -271.40976],
+    [-0.00090538705, 0.898375, -268.35492],
+    [-0.00090538705, 1.0221999, -294.26425],
+    [-0.00090538705, 1.0135422, -290.29337],
+    [-0.00090538705, 1.010383, -289.97638],
+    [-0.00090538705, 1.0069305, -289.7354],
+    [-0.00090538705, 1.0030909, -289.52145],
+    [-0.00090538705, 0.9987751, -289.29752],
+    [-0.00090538705, 0.9938896, -289.03256],
+    [-0.00090538705, 0.9883299, -288.69653],
+    [-0.00090538705, 0.9819757, -288.2596],
+    [-0.00090538705, 0.9746849, -287.69443],
+    [-0.00090538705, 0.9662863, -286.9634],
+    [-0.00090538705, 0.95657134, -286.02756],
+    [-0.00090538705, 0.9452816, -284.84387],
+    [-0.00090538705, 0.93209344, -283.353],
+    [-0.00090538705, 0.9165949, -281.4812],
+    [-0.00090538705, 0.8982554, -279.13672],
+    [-0.00090538705, 1.0243595, -295.50696],
+    [-0.00090538705, 1.0174966, -292.6855],
+    [-0.00090538705, 1.0149488, -292.83084],
+    [-0.00090538705, 1.0120928, -293.07953],
+    [-0.00090538705, 1.0088387, -293.39304],
+    [-0.00090538705, 1.0050932, -293.74402],
+    [-0.00090538705, 1.0007519, -294.10904],
+    [-0.00090538705, 0.9956911, -294.46698],
+    [-0.00090538705, 0.98976046, -294.80078],
+    [-0.00090538705, 0.98277205, -295.09106],
+    [-0.00090538705, 0.9744868, -295.31482],
+    [-0.00090538705, 0.96459484, -295.45273],
+    [-0.00090538705, 0.95268565, -295.47308],
+    [-0.00090538705, 0.9382045, -295.3357],
+    [-0.00090538705, 0.92038125, -294.98737],
+    [-0.00090538705, 0.8981175, -294.3463],
+    [-0.00090538705, 1.0264019, -296.68298],
+    [-0.00090538705, 1.0214347, -295.07892],
+    [-0.00090538705, 1.0195467, -295.71628],
+    [-0.00090538705, 1.0173556, -296.50095],
+    [-0.00090538705, 1.0147779, -297.40735],
+    [-0.00090538705, 1.0117211, -298.42273],
+    [-0.00090538705, 1.0080742, -299.53946],
+    [-0.00090538705, 1.0036983, -300.75674],
+    [-0.00090538705, 0.998416, -302.08554],
+    [-0.00090538705, 0.99199295, -303.5338],
+    [-0.00090538705, 0.9841135, -305.12875],
+    [-0.00090538705, 0.9743405, -306.9055],
+    [-0.00090538705, 0.96205014, -308.91324],
+    [-0.00090538705, 0.94632035, -311.22888],
+    [-0.00090538705, 0.9257291, -313.96445],
+    [-0.00090538705, 0.89796126, -317.31015],
+    [-0.00090538705, 1.0283573, -297.81024],
+    [-0.00090538705, 1.025356, -297.47058],
+    [-0.00090538705, 1.0241758, -298.63126],
+    [-0.00090538705, 1.0227187, -299.99887],
+    [-0.00090538705, 1.020914, -301.56677],
+    [-0.00090538705, 1.0186758, -303.34415],
+    [-0.00090538705, 1.0158952, -305.35123],
+    [-0.00090538705, 1.0124289, -307.62738],
+    [-0.00090538705, 1.0080824, -310.22855],
+    [-0.00090538705, 1.0025856, -313.23996],
+    [-0.00090538705, 0.99555117, -316.7932],
+    [-0.00090538705, 0.9864016, -321.07703],
+    [-0.00090538705, 0.97423816, -326.39282],
+    [-0.00090538705, 0.957581, -333.2304],
+    [-0.00090538705, 0.9338069, -342.4631],
+    [-0.00090538705, 0.8977931, -355.78574],
+    [-0.00090538705, 1.0302254, -298.8879],
+    [-0.00090538705, 1.0292608, -299.85977],
+    [-0.00090538705, 1.0288345, -301.57474],
+    [-0.00090538705, 1.0281819, -303.5723],
+    [-0.00090538705, 1.0272514, -305.87335],
+    [-0.00090538705, 1.0259745, -308.51923],
+    [-0.00090538705, 1.024258, -311.57465],
+    [-0.00090538705, 1.0219716, -315.14417],
+    [-0.00090538705, 1.0189295, -319.37164],
+    [-0.00090538705, 1.0148572, -324.48953],
+    [-0.00090538705, 1.0093327, -330.8464],
+    [-0.00090538705, 1.0016735, -339.0134],
+    [-0.00090538705, 0.9906985, -349.96948],
+    [-0.00090538705, 0.97417426, -365.57425],
+    [-0.00090538705, 0.9473075, -389.81546],
+    [-0.0007967219, 0.7898692, -381.09775],
+    [-0.00090538705, 1.0326359, -300.30518],
+    [-0.00090538705, 1.0331484, -302.24576],
+    [-0.00090538705, 1.0335215, -304.54443],
+    [-0.00090538705, 1.0337443, -307.2199],
+    [-0.00090538705, 1.0337945, -310.32907],
+    [-0.00090538705, 1.0336349, -313.95935],
+    [-0.00090538705, 1.0332085, -318.24466],
+    [-0.00090538705, 1.0324292, -323.3865],
+    [-0.00090538705, 1.0311655, -329.6905],
+    [-0.00090538705, 1.0292097, -337.6466],
+    [-0.00090538705, 1.0262172, -348.05957],
+    [-0.00090538705, 1.021572, -362.3633],
+    [-0.00090538705, 1.0140604, -383.3841],
+    [-0.00090538705, 1.0009139, -417.55893],
+    [-0.00072416087, 0.7791557, -386.6448],
+    [-0.00039383012, 0.39036208, -289.48996],
+    [-0.00090538705, 1.0357286, -302.1506],
+    [-0.00090538705, 1.0370189, -304.6278],
+    [-0.00090538705, 1.0382351, -307.53857],
+    [-0.00090538705, 1.039405, -310.9403],
+    [-0.00090538705, 1.0405474, -314.93576],
+    [-0.00090538705, 1.0416756, -319.6766],
+    [-0.00090538705, 1.042798, -325.39648],
+    [-0.00090538705, 1.0439211, -332.44733],
+    [-0.00090538705, 1.0450485, -341.40002],
+    [-0.00090538705, 1.0461832, -353.20282],
+    [-0.00090538705, 1.0473273, -369.56796],
+    [-0.00090538705, 1.0484821, -393.9119],
+    [-0.00090538705, 1.0496477, -434.2055],
+    [-0.00071457704, 0.82935673, -405.95923],
+    [-0.00036766104, 0.42719343, -306.14078],
+    [-2.7038377e-7, 0.000292632, -200.07031],
+    [-0.00090538705, 0.9965704, -278.6678],
+    [-0.00090538705, 0.98247564, -271.23013],
+    [-0.00090538705, 0.9721801, -265.87314],
+    [-0.00090538705, 0.9646681, -262.15982],
+    [-0.00090538705, 0.9589351, -259.7025],
+    [-0.00090538705, 0.9533065, -257.3291],
+    [-0.00090538705, 0.947739, -255.00601],
+    [-0.00090538705, 0.9422073, -252.71059],
+    [-0.00090538705, 0.93669695, -250.43231],
+    [-0.00090538705, 0.9312004, -248.1652],
+    [-0.00090538705, 0.925715, -245.90524],
+    [-0.00090538705, 0.92024153, -243.65],
+    [-0.00090538705, 0.9147828, -241.3991],
+    [-0.00090538705, 0.9093437, -239.15318],
+    [-0.00090538705, 0.90392995, -236.91364],
+    [-0.00090538705, 0.89854836, -234.68236],
+    [-0.00090538705, 1.0000179, -280.6151],
+    [-0.00090538705, 0.98590505, -273.178],
+    [-0.00090538705, 0.9754709, -267.7419],
+    [-0.00090538705, 0.9689853, -264.83444],
+    [-0.00090538705, 0.9632272, -262.46582],
+    [-0.00090538705, 0.95750576, -260.1519],
+    [-0.00090538705, 0.9517811, -257.85565],
+    [-0.00090538705, 0.94603044, -255.5613],
+    [-0.00090538705, 0.9402411, -253.25974],
+    [-0.00090538705, 0.93440735, -250.94388],
+    [-0.00090538705, 0.9285282, -248.6101],
+    [-0.00090538705, 0.922606, -246.2571],
+    [-0.00090538705, 0.9166458, -243.88501],
+    [-0.00090538705, 0.9106548, -241.49496],
+    [-0.00090538705, 0.9046413, -239.08893],
+    [-0.00090538705, 0.89861506, -236.66965],
+    [-0.00090538705, 1.0032784, -282.46368],
+    [-0.00090538705, 0.98914766, -275.02524],
+    [-0.00090538705, 0.9791491, -269.9046],
+    [-0.00090538705, 0.9733878, -267.58957],
+    [-0.00090538705, 0.9676544, -265.34787],
+    [-0.00090538705, 0.9618847, -263.12463],
+    [-0.00090538705, 0.9560411, -260.89163],
+    [-0.00090538705, 0.9501013, -258.63412],
+    [-0.00090538705, 0.94405264, -256.3407],
+    [-0.00090538705, 0.93788904, -254.00462],
+    [-0.00090538705, 0.9316094, -251.6222],
+    [-0.00090538705, 0.9252162, -249.19165],
+    [-0.00090538705, 0.918715, -246.71248],
+    [-0.00090538705, 0.91211385, -244.18571],
+    [-0.00090538705, 0.9054227, -241.61346],
+    [-0.00090538705, 0.8986531, -238.99875],
+    [-0.00090538705, 1.0064033, -284.24445],
+    [-0.00090538705, 0.9922133, -276.77557],
+    [-0.00090538705, 0.9834852, -272.54538],
+    [-0.00090538705, 0.97787946, -270.4254],
+    [-0.00090538705, 0.9722242, -268.34656],
+    [-0.00090538705, 0.96645683, -266.2553],
+    [-0.00090538705, 0.96053964, -264.12833],
+    [-0.00090538705, 0.95444834, -261.94824],
+    [-0.00090538705, 0.94816756, -259.7029],
+    [-0.00090538705, 0.9416884, -257.38455],
+    [-0.00090538705, 0.9350067, -254.98758],
+    [-0.00090538705, 0.9281223, -252.50824],
+    [-0.00090538705, 0.9210384, -249.94469],
+    [-0.00090538705, 0.9137612, -247.29655],
+    [-0.00090538705, 0.9062995, -244.56487],
+    [-0.00090538705, 0.8986645, -241.75194],
+    [-0.00090538705, 1.0093905, -285.9543],
+    [-0.00090538705, 0.99511516, -278.43518],
+    [-0.00090538705, 0.987857, -275.2281],
+    [-0.00090538705, 0.9824639, -273.34262],
+    [-0.00090538705, 0.97694415, -271.46536],
+    [-0.00090538705, 0.97123605, -269.5525],
+    [-0.00090538705, 0.9652986, -267.57935],
+    [-0.00090538705, 0.9591026, -265.5259],
+    [-0.00090538705, 0.95262676, -263.3787],
+    [-0.00090538705, 0.9458554, -261.12662],
+    [-0.00090538705, 0.93877745, -258.76074],
+    [-0.00090538705, 0.9313856, -256.27417],
+    [-0.00090538705, 0.92367595, -253.66174],
+    [-0.00090538705, 0.91564804, -250.92006],
+    [-0.00090538705, 0.90730447, -248.04721],
+    [-0.0008674714, 0.86101735, -234.78073],
+    [-0.00090538705, 1.0122049, -287.56946],
+    [-0.00090538705, 0.9978561, -280.00372],
+    [-0.00090538705, 0.99226576, -277.9513],
+    [-0.00090538705, 0.98714346, -276.3376],
+    [-0.00090538705, 0.9818214, -274.70782],
+    [-0.00090538705, 0.9762363, -273.0248],
+    [-0.00090538705, 0.97034097, -271.25992],
+    [-0.00090538705, 0.9640981, -269.39233],
+    [-0.00090538705, 0.95747626, -267.40457],
+    [-0.00090538705, 0.95044833, -265.28104],
+    [-0.00090538705, 0.9429905, -263.00806],
+    [-0.00090538705, 0.93508166, -260.57364],
+    [-0.00090538705, 0.92670375, -257.9673],
+    [-0.0008747395, 0.8867719, -246.54193],
+    [-0.0008042018, 0.80695, -224.01646],
+    [-0.00074198114, 0.7364306, -204.08334],
+    [-0.00090538705, 1.0148635, -289.0989],
+    [-0.00090538705, 1.0013345, -282.06464],
+    [-0.00090538705, 0.99671215, -280.71457],
+    [-0.00090538705, 0.9919204, -279.41064],
+    [-0.00090538705, 0.9868629, -278.07788],
+    [-0.00090538705, 0.9814718, -276.68],
+    [-0.00090538705, 0.9756915, -275.18695],
+    [-0.00090538705, 0.96947265, -273.57523],
+    [-0.00090538705, 0.9627691, -271.82242],
+    [-0.00090538705, 0.9555361, -269.90775],
+    [-0.00090538705, 0.9477298, -267.81137],
+    [-0.00090538705, 0.9393061, -265.51437],
+    [-0.00083423685, 0.857119, -242.32986],
+    [-0.00075702247, 0.7696003, -217.59648],
+    [-0.0006893237, 0.69275147, -195.84393],
+    [-0.00062963564, 0.62488437, -176.60022],
+    [-0.00090538705, 1.0173833, -290.5517],
+    [-0.00090538705, 1.0053678, -284.50024],
+    [-0.00090538705, 1.0011959, -283.5153],
+    [-0.00090538705, 0.99679655, -282.56195],
+    [-0.00090538705, 0.99207497, -281.57846],
+    [-0.00090538705, 0.9869571, -280.5272],
+    [-0.00090538705, 0.98137635, -279.37854],
+    [-0.00090538705, 0.97526795, -278.1053],
+    [-0.00090538705, 0.968566, -276.6809],
+    [-0.00090538705, 0.9612011, -275.07895],
+    [-0.00090538705, 0.95309937, -273.27307],
+    [-0.0008111303, 0.8458854, -242.9975],
+    [-0.0007257282, 0.74895096, -215.56891],
+    [-0.0006513609, 0.6644192, -191.61343],
+    [-0.00058618037, 0.5902123, -170.5488],
+    [-0.00052873534, 0.5246987, -151.91788],
+    [-0.00090538705, 1.0197939, -291.94534],
+    [-0.00090538705, 1.009387, -286.9393],
+    [-0.00090538705, 1.0057167, -286.3505],
+    [-0.00090538705, 1.0017734, -285.79144],
+    [-0.00090538705, 0.9974638, -285.21213],
+    [-0.00090538705, 0.992707, -284.5758],
+    [-0.00090538705, 0.9874238, -283.8539],
+    [-0.00090538705, 0.98153096, -283.01736],
+    [-0.00090538705, 0.9749377, -282.037],
+    [-0.00090538705, 0.9675424, -280.8828],
+    [-0.0008060345, 0.8539685, -248.84848],
+    [-0.0007105502, 0.7454572, -218.11035],
+    [-0.0006280536, 0.6515761, -191.47833],
+    [-0.0005562317, 0.569719, -168.22043],
+    [-0.000493299, 0.4978742, -147.77177],
+    [-0.00043785278, 0.43446147, -129.6887],
+    [-0.00090538705, 1.0220978, -293.28006],
+    [-0.00090538705, 1.0133927, -289.3807],
+    [-0.00090538705, 1.0102737, -289.21887],
+    [-0.00090538705, 1.0068518, -289.09955],
+    [-0.00090538705, 1.0030353, -288.9819],
+    [-0.00090538705, 0.9987368, -288.8355],
+    [-0.00090538705, 0.9938642, -288.63403],
+    [-0.00090538705, 0.9883141, -288.3507],
+    [-0.00090538705, 0.98196703, -287.95822],
+    [-0.00082023366, 0.8830107, -260.39395],
+    [-0.0007122284, 0.7601352, -225.55244],
+    [-0.00061976316, 0.6548017, -195.64478],
+    [-0.0005398833, 0.5636745, -169.73207],
+    [-0.00047035035, 0.48422614, -147.10324],
+    [-0.00040943577, 0.4145053, -127.20922],
+    [-0.0003557811, 0.3529785, -109.61869],
+    [-0.00090538705, 1.024291, -294.55203],
+    [-0.00090538705, 1.0173846, -291.82333],
+    [-0.00090538705, 1.014866, -292.11917],
+    [-0.00090538705, 1.0120323, -292.48483],
+    [-0.00090538705, 1.0087948, -292.8907],
+    [-0.00090538705, 1.005062, -293.31595],
+    [-0.00090538705, 1.0007304, -293.7418],
+    [-0.00090538705, 0.99567693, -294.15033],
+    [-0.0008558947, 0.9356477, -278.42474],
+    [-0.0007321362, 0.7947094, -238.42506],
+    [-0.0006273278, 0.6752048, -204.46602],
+    [-0.0005376001, 0.57275677, -175.31323],
+    [-0.00046008982, 0.48412645, -150.05356],
+    [-0.00039262662, 0.40685907, -127.99499],
+    [-0.00033353397, 0.33905834, -108.60303],
+    [-0.0002814939, 0.2792336, -91.4576],
+    [-0.00090538705, 1.0263673, -295.75668],
+    [-0.00090538705, 1.0213628, -294.2676],
+    [-0.00090538705, 1.0194926, -295.04993],
+    [-0.00090538705, 1.017315, -295.9466],
+    [-0.00090538705, 1.0147476, -296.94113],
+    [-0.00090538705, 1.0116988, -298.02734],
+    [-0.00090538705, 1.008058, -299.20215],
+    [-0.00090538705, 1.003687, -300.46783],
+    [-0.0007724929, 0.8518604, -257.5282],
+    [-0.00065218896, 0.7145715, -218.48578],
+    [-0.0005502978, 0.59814656, -185.33382],
+    [-0.00046306523, 0.4983315, -156.87067],
+    [-0.0003877119, 0.4119768, -132.20679],
+    [-0.00032212998, 0.33669424, -110.667755],
+    [-0.0002646908, 0.27063823, -91.73252],
+    [-0.00021411355, 0.21235758, -74.99136],
+    [-0.00090538705, 1.028357, -296.91232],
+    [-0.00090538705, 1.0253264, -296.70996],
+    [-0.00090538705, 1.024152, -298.0096],
+    [-0.00090538705, 1.0226997, -299.48398],
+    [-0.00090538705, 1.0208986, -301.13562],
+    [-0.00090538705, 1.0186636, -302.98032],
+    [-0.00090538705, 1.0158857, -305.04263],
+    [-0.0008367093, 0.9356247, -284.04718],
+    [-0.0006966023, 0.7756116, -238.50946],
+    [-0.00057946914, 0.64167607, -200.3476],
+    [-0.00048025287, 0.52807814, -167.93727],
+    [-0.00039530563, 0.43067726, -140.10706],
+    [-0.00032192538, 0.3464065, -115.989296],
+    [-0.0002580621, 0.272939, -94.92588],
+    [-0.00020213106, 0.20847611, -76.4081],
+    [-0.0001528859, 0.15160316, -60.0359],
+    [-0.00090538705, 1.0302596, -298.01794],
+    [-0.00090538705, 1.029275, -299.14963],
+    [-0.00090538705, 1.0288429, -300.99725],
+    [-0.00090538705, 1.0281858, -303.096],
+    [-0.00090538705, 1.0272523, -305.47632],
+    [-0.00090538705, 1.0259737, -308.18588],
+    [-0.00090538705, 1.0242561, -311.29367],
+    [-0.00076397794, 0.8623517, -265.7176],
+    [-0.0006273936, 0.70607203, -221.1624],
+    [-0.00051318354, 0.5752312, -183.81395],
+    [-0.00041643105, 0.4642398, -152.08807],
+    [-0.00033358677, 0.36906263, -124.84138],
+    [-0.00026202, 0.28670904, -101.2265],
+    [-0.00019973434, 0.2149093, -80.600464],
+    [-0.00014518558, 0.15190798, -62.466007],
+    [-9.715955e-5, 0.09632459, -46.432114],
+    [-0.00090538705, 1.0327165, -299.47586],
+    [-0.00090538705, 1.033208, -301.5857],
+    [-0.00090538705, 1.0335631, -304.01038],
+    [-0.00090538705, 1.0337725, -306.7814],
+    [-0.00090538705, 1.0338129, -309.96524],
+    [-0.00090538705, 1.0336465, -313.65555],
+    [-0.0008594619, 0.98080635, -301.85876],
+    [-0.0006974721, 0.79534316, -248.9521],
+    [-0.0005641513, 0.64252585, -205.30902],
+    [-0.00045264777, 0.51455337, -168.71516],
+    [-0.00035817473, 0.4059757, -137.62366],
+    [-0.0002772741, 0.3128556, -110.917206],
+    [-0.00020738189, 0.23227374, -87.76742],
+    [-0.00014655123, 0.16201344, -67.54541],
+    [-9.3275936e-5, 0.100359686, -49.764637],
+    [-4.6371293e-5, 0.045963418, -34.042397],
+    [-0.00090538705, 1.0358704, -301.37753],
+    [-0.00090538705, 1.0371249, -304.01743],
+    [-0.00090538705, 1.0383111, -307.0473],
+    [-0.00090538705, 1.0394584, -310.5388],
+    [-0.00090538705, 1.0405842, -314.60428],
+    [-0.00090538705, 1.0417006, -319.4015],
+    [-0.000794797, 0.91543853, -285.44583],
+    [-0.00063654035, 0.733946, -233.58804],
+    [-0.00050625496, 0.5843522, -190.79494],
+    [-0.00039726734, 0.45904848, -154.90376],
+    [-0.00030491178, 0.3527149, -124.40247],
+    [-0.00022581547, 0.2615059, -98.19827],
+    [-0.00015747618, 0.1825683, -75.48041],
+    [-9.799409e-5, 0.11373573, -55.633343],
+    [-4.5897847e-5, 0.053330425, -38.180435],
+    [-2.9746076e-8, 3.2112315e-5, -22.746605],
+    [-0.00090538705, 0.9956822, -277.4326],
+    [-0.00090538705, 0.98203516, -270.18723],
+    [-0.00090538705, 0.97187436, -264.85434],
+    [-0.00090538705, 0.9645846, -261.31787],
+    [-0.00090538705, 0.9589101, -259.00665],
+    [-0.0008779814, 0.92446107, -248.97774],
+    [-0.0007858962, 0.8226862, -220.92297],
+    [-0.00071196083, 0.7409484, -198.38602],
+    [-0.0006512339, 0.67378867, -179.86209],
+    [-0.00060045643, 0.6176076, -164.35927],
+    [-0.00055738265, 0.56992525, -151.19435],
+    [-0.00052040926, 0.5289713, -139.87982],
+    [-0.000488358, 0.49344537, -130.05762],
+    [-0.00046034105, 0.46236742, -121.458015],
+    [-0.00043567587, 0.43498445, -113.8738],
+    [-0.00041382806, 0.41070688, -107.14279],
+    [-0.00090538705, 0.99931484, -279.49295],
+    [-0.00090538705, 0.9853717, -272.06857],
+    [-0.00090538705, 0.97523344, -266.7779],
+    [-0.00090538705, 0.96889216, -264.06317],
+    [-0.00090538705, 0.96318954, -261.84216],
+    [-0.000795801, 0.8416079, -228.2108],
+    [-0.0007092111, 0.7455652, -201.64407],
+    [-0.00063966593, 0.6684001, -180.29219],
+    [-0.0005825426, 0.60498947, -162.73851],
+    [-0.000534786, 0.5519482, -148.0473],
+    [-0.0004942884, 0.5069411, -135.57324],
+    [-0.00045954293, 0.46829927, -124.85526],
+    [-0.00042944076, 0.43479478, -115.55424],
+    [-0.00040314617, 0.40550235, -107.41467],
+    [-0.00038001643, 0.37971038, -100.24014],
+    [-0.0003595474, 0.35686097, -93.87669],
+    [-0.00090538705, 1.002717, -281.42548],
+    [-0.00090538705, 0.98856145, -273.87836],
+    [-0.00090538705, 0.9789718, -269.01596],
+    [-0.00090538705, 0.9732911, -266.88812],
+    [-0.0008290499, 0.8860258, -242.46257],
+    [-0.00072412903, 0.7693046, -210.08055],
+    [-0.0006422491, 0.6781846, -184.79404],
+    [-0.00057646737, 0.6049474, -164.4619],
+    [-0.00052243227, 0.5447562, -147.74292],
+    [-0.00047726382, 0.49441043, -133.74977],
+    [-0.00043897185, 0.45169875, -121.86958],
+    [-0.0004061322, 0.41503906, -111.664],
+    [-0.00037769644, 0.3832668, -102.81046],
+    [-0.0003528732, 0.35550314, -95.06559],
+    [-0.00033105336, 0.3310717, -88.24214],
+    [-0.00031175968, 0.30944267, -82.193474],
+    [-0.00090538705, 1.0058038, -283.1741],
+    [-0.00090538705, 0.9916367, -275.63504],
+    [-0.00090538705, 0.98331505, -271.72446],
+    [-0.0008935064, 0.96495354, -266.25082],
+    [-0.00076083, 0.8169547, -225.07907],
+    [-0.00066119793, 0.7057817, -194.14542],
+    [-0.0005833983, 0.6189345, -169.97195],
+    [-0.0005208761, 0.5491058, -150.52629],
+    [-0.0004695149, 0.49170753, -134.53267],
+    [-0.00042658582, 0.44369864, -121.14576],
+    [-0.00039020108, 0.40297586, -109.78105],
+    [-0.00035900806, 0.36803225, -100.01991],
+    [-0.00033201044, 0.3377581, -91.55411],
+    [-0.00030845558, 0.31131518, -84.15089],
+    [-0.0002877639, 0.28805816, -77.63111],
+    [-0.00026948104, 0.2674812, -71.85436],
+    [-0.00090538705, 1.00863, -284.77374],
+    [-0.00090538705, 0.9946199, -277.35046],
+    [-0.00090538705, 0.98769844, -274.47403],
+    [-0.00082700216, 0.89732313, -249.15276],
+    [-0.0007006145, 0.755949, -209.71796],
+    [-0.000605608, 0.64963764, -180.05551],
+    [-0.0005313768, 0.5665341, -156.8589],
+    [-0.0004717044, 0.49969083, -138.19093],
+    [-0.0004226797, 0.44473755, -122.83335],
+    [-0.0003817062, 0.39877328, -109.97775],
+    [-0.00034698495, 0.35978845, -99.06426],
+    [-0.00031722712, 0.32634348, -89.69192],
+    [-0.00029148144, 0.29737607, -81.564926],
+    [-0.0002690293, 0.2720838, -74.4599],
+    [-0.00024931744, 0.24984895, -68.204926],
+    [-0.00023191105, 0.23018621, -62.664955],
+    [-0.00090538705, 1.0113354, -286.3134],
+    [-0.00090538705, 0.9975423, -279.0431],
+    [-0.00090538705, 0.99212223, -277.26236],
+    [-0.00076800206, 0.8372814, -233.96426],
+    [-0.0006471618, 0.70176196, -196.06705],
+    [-0.0005562365, 0.5997474, -167.52998],
+    [-0.00048515308, 0.519952, -145.19795],
+    [-0.00042799348, 0.4557458, -127.217995],
+    [-0.00038102726, 0.40295035, -112.422646],
+    [-0.00034177513, 0.358789, -100.03628],
+    [-0.00030851728, 0.32133582, -89.52108],
+    [-0.00028002, 0.28920954, -80.49142],
+    [-0.00025537284, 0.2613909, -72.66279],
+    [-0.0002338873, 0.23710918, -65.82012],
+    [-0.00021503276, 0.21577054, -59.79773],
+    [-0.00019839239, 0.19690849, -54.46552],
+    [-0.00090538705, 1.013933, -287.79938],
+    [-0.00090538705, 1.0011249, -281.20224],
+    [-0.00087782595, 0.96624845, -271.56058],
+    [-0.00071537745, 0.7836907, -220.39954],
+    [-0.0005994701, 0.6533865, -183.87422],
+    [-0.00051217404, 0.5551996, -156.34123],
+    [-0.00044388886, 0.4783499, -134.78024],
+    [-0.00038896163, 0.41649035, -117.41345],
+    [-0.00034382317, 0.36561406, -103.11888],
+    [-0.00030609773, 0.32305434, -91.15],
+    [-0.0002741363, 0.2869604, -80.9888],
+    [-0.00024675488, 0.25600344, -72.263466],
+    [-0.00022307853, 0.2292018, -64.69939],
+    [-0.00020244601, 0.20581353, -58.089054],
+    [-0.00018434705, 0.18526621, -52.272396],
+    [-0.00016838088, 0.16711026, -47.123726],
+    [-0.00090538705, 1.0164355, -289.23785],
+    [-0.00090538705, 1.0051879, -283.70236],
+    [-0.00082423043, 0.91135263, -257.58063],
+    [-0.0006682104, 0.7356254, -208.22604],
+    [-0.0005567217, 0.61000067, -172.93364],
+    [-0.0004726759, 0.51524734, -146.30272],
+    [-0.000406896, 0.44103917, -125.43416],
+    [-0.00035396597, 0.38128236, -108.6176],
+    [-0.00031046115, 0.3321241, -94.77198],
+    [-0.00027409935, 0.29099724, -83.17711],
+    [-0.00024329452, 0.2561178, -73.33266],
+    [-0.00021690702, 0.2262041, -64.87925],
+    [-0.00019409477, 0.20030916, -57.551388],
+    [-0.0001742202, 0.17771612, -51.148163],
+    [-0.0001567915, 0.157872, -45.514614],
+    [-0.00014142238, 0.14034253, -40.529068],
+    [-0.00090538705, 1.0188147, -290.60916],
+    [-0.00090538705, 1.0092386, -286.20444],
+    [-0.0007759736, 0.8618855, -244.97365],
+    [-0.00062574807, 0.6923258, -197.25287],
+    [-0.0005182432, 0.5709269, -163.07559],
+    [-0.00043712737, 0.4792739, -137.26036],
+    [-0.0003736046, 0.40744925, -117.01749],
+    [-0.00032247283, 0.34958854, -100.697784],
+    [-0.0002804377, 0.30197823, -87.25721],
+    [-0.00024530126, 0.2621408, -75.999374],
+    [-0.00021553476, 0.22835332, -66.4401],
+    [-0.00019003857, 0.19937658, -58.23123],
+    [-0.0001679999, 0.17429462, -51.115467],
+    [-0.00014880302, 0.15241371, -44.897976],
+    [-0.00013197275, 0.13319845, -39.428425],
+    [-0.00011713576, 0.11622827, -34.58873],
+    [-0.00090538705, 1.0211203, -291.94336],
+    [-0.00090538705, 1.0132763, -288.7069],
+    [-0.0007323381, 0.8171202, -233.5562],
+    [-0.0005873673, 0.6531629, -187.3219],
+    [-0.00048347577, 0.53560317, -154.15924],
+    [-0.0004050168, 0.44676554, -129.08582],
+    [-0.00034353958, 0.37710428, -109.41161],
+    [-0.0002940364, 0.3209633, -93.54313],
+    [-0.0002533312, 0.27475563, -80.470055],
+    [-0.00021930259, 0.23608561, -69.51774],
+    [-0.0001904735, 0.20328532, -60.21661],
+    [-0.00016578112, 0.17515472, -52.228863],
+    [-0.00014443915, 0.150806, -45.304634],
+    [-0.00012585173, 0.12956643, -39.254642],
+    [-0.00010955889, 0.11091679, -33.932796],
+    [-9.5198775e-5, 0.09444861, -29.224207],
+    [-0.00090538705, 1.0233942, -293.26614],
+    [-0.000904193, 1.0159589, -290.82413],
+    [-0.00069272687, 0.7764514, -223.17548],
+    [-0.00055254786, 0.61761135, -178.30109],
+    [-0.00045195146, 0.5035581, -146.06647],
+    [-0.00037591462, 0.41729102, -121.67128],
+    [-0.0003163013, 0.34960374, -102.516655],
+    [-0.000268281, 0.29503077, -87.060104],
+    [-0.0002287857, 0.25010088, -74.32221],
+    [-0.00019576379, 0.21249308, -63.648228],
+    [-0.00016778594, 0.18059038, -54.58209],
+    [-0.00014382246, 0.15322806, -46.795353],
+    [-0.00012311143, 0.1295442, -40.045013],
+    [-0.0001050751, 0.10888537, -34.14687],
+    [-8.9267385e-5, 0.090747006, -28.958708],
+    [-7.5337186e-5, 0.074732035, -24.368635],
+    [-0.00090538705, 1.0256606, -294.5924],
+    [-0.00086101843, 0.97126085, -279.31296],
+    [-0.00065664004, 0.7393722, -213.70349],
+    [-0.0005208525, 0.58522946, -170.07932],
+    [-0.00042327613, 0.47439477, -138.6978],
+    [-0.00034945837, 0.3904861, -114.925705],
+    [-0.00029155184, 0.3246089, -96.24811],
+    [-0.00024488854, 0.27147263, -81.169464],
+    [-0.00020649946, 0.22771253, -68.738785],
+    [-0.00017439736, 0.19107644, -58.319702],
+    [-0.0001471961, 0.15999363, -49.46844],
+    [-0.00012389697, 0.13333237, -41.865334],
+    [-0.000103760256, 0.110254444, -35.27364],
+    [-8.6224885e-5, 0.09012413, -29.513845],
+    [-7.0857444e-5, 0.07245035, -24.447298],
+    [-5.7316778e-5, 0.056846537, -19.964874],
+    [-0.00090538705, 1.0278891, -295.90125],
+    [-0.0008215128, 0.93032146, -268.75916],
+    [-0.00062365574, 0.70545465, -205.03217],
+    [-0.00049191125, 0.55564326, -162.56258],
+    [-0.00039711525, 0.44777605, -131.96867],
+    [-0.00032534, 0.3660411, -108.77165],
+    [-0.00026900353, 0.30183116, -90.534],
+    [-0.00022358769, 0.25001732, -75.80359],
+    [-0.00018621483, 0.20733307, -63.655777],
+    [-0.00015495684, 0.17158976, -53.471123],
+    [-0.00012846773, 0.1412596, -44.81733],
+    [-0.00010577707, 0.11524115, -37.382755],
+    [-8.616571e-5, 0.0927181, -30.936483],
+    [-6.908797e-5, 0.07307112, -25.303339],
+    [-5.4122072e-5, 0.0558215, -20.347939],
+    [-4.093622e-5, 0.04059263, -15.963783],
+    [-0.00090538705, 1.0300928, -297.20062],
+    [-0.00078524655, 0.89270276, -259.0515],
+    [-0.0005934152, 0.6743345, -197.0695],
+    [-0.00046540884, 0.52853394, -155.67062],
+    [-0.00037318343, 0.42341423, -125.80699],
+    [-0.00030329594, 0.34369102, -103.142845],
+    [-0.00024840995, 0.2810233, -85.312614],
+    [-0.00020414584, 0.23043181, -70.90445],
+    [-0.00016771062, 0.18874133, -59.01819],
+    [-0.00013723072, 0.15382183, -49.050083],
+    [-0.0001113976, 0.12418572, -40.578545],
+    [-8.926682e-5, 0.0987593, -33.299335],
+    [-7.013832e-5, 0.07674673, -26.986967],
+    [-5.3480646e-5, 0.057543837, -21.47029],
+    [-3.88829e-5, 0.040683478, -16.616982],
+    [-2.6021708e-5, 0.02579802, -12.322914],
+    [-0.00090538705, 1.0327809, -298.82043],
+    [-0.0007518542, 0.8580314, -250.09521],
+    [-0.0005656122, 0.6457009, -189.73695],
+    [-0.00044107527, 0.5036283, -149.33475],
+    [-0.00035123565, 0.40106216, -120.15073],
+    [-0.0002830998, 0.32320806, -97.98231],
+    [-0.00022955911, 0.2619724, -80.53084],
+    [-0.00018636268, 0.21251537, -66.42206],
+    [-0.00015079607, 0.17174646, -54.778584],
+    [-0.00012103666, 0.13759056, -45.01137],
+    [-9.581022e-5, 0.10859689, -36.708687],
+    [-7.419687e-5, 0.08371813, -29.57331],
+    [-5.5514174e-5, 0.062177267, -23.384775],
+    [-3.9243914e-5, 0.04338426, -17.975677],
+    [-2.4985326e-5, 0.026882803, -13.216568],
+    [-1.2422689e-5, 0.0123133985, -9.005475],
+    [-0.00090538705, 1.0359833, -300.78055],
+    [-0.0007210216, 0.8259868, -241.80876],
+    [-0.0005399831, 0.61928576, -182.9668],
+    [-0.00041867766, 0.48069072, -143.4956],
+    [-0.0003310604, 0.3805064, -114.94634],
+    [-0.00026455568, 0.304395, -93.24067],
+    [-0.00021226735, 0.24449405, -76.1426],
+    [-0.00017006439, 0.19609374, -62.31298],
+    [-0.0001353056, 0.15618278, -50.89574],
+    [-0.00010621571, 0.12273708, -41.315544],
+    [-8.1552935e-5, 0.09434082, -33.169994],
+    [-6.0419858e-5, 0.06997078, -26.168324],
+    [-4.215062e-5, 0.048867706, -20.094791],
+    [-2.6239306e-5, 0.030454742, -14.785503],
+    [-1.22945685e-5, 0.014285614, -10.113671],
+    [-7.999927e-9, 8.63613e-6, -5.9793944],
+    [-0.00090538705, 0.98193777, -268.20224],
+    [-0.0008355549, 0.9010739, -245.39264],
+    [-0.00066815445, 0.7160877, -194.38463],
+    [-0.00056032656, 0.5969397, -161.53903],
+    [-0.00048417237, 0.5127869, -138.34265],
+    [-0.00042713704, 0.44975212, -120.966484],
+    [-0.00038264788, 0.4005717, -107.407],
+    [-0.00034689522, 0.36103624, -96.5035],
+    [-0.0003175021, 0.3285199, -87.5322],
+    [-0.0002929008, 0.30129117, -80.015945],
+    [-0.00027201045, 0.27815637, -73.625916],
+    [-0.00025405953, 0.2582638, -68.12753],
+    [-0.00023848074, 0.24098721, -63.3484],
+    [-0.00022484659, 0.22585481, -59.158657],
+    [-0.00021282845, 0.21250398, -55.458523],
+    [-0.00020216897, 0.20065084, -52.169903],
+    [-0.00090538705, 0.98640424, -270.75494],
+    [-0.0007693806, 0.83322746, -228.07697],
+    [-0.0006132611, 0.6601414, -180.19763],
+    [-0.0005122948, 0.5482023, -149.23878],
+    [-0.00044082597, 0.46895745, -127.32268],
+    [-0.00038722937, 0.40951675, -110.88164],
+    [-0.00034539116, 0.36310214, -98.040306],
+    [-0.00031175622, 0.32577297, -87.70874],
+    [-0.00028410117, 0.29506534, -79.20566],
+    [-0.00026095653, 0.26935095, -72.08096],
+    [-0.00024130814, 0.24750628, -66.02419],
+    [-0.00022443081, 0.22872819, -60.813454],
+    [-0.00020979097, 0.21242575, -56.28556],
+    [-0.00019698634, 0.19815353, -52.31752],
+    [-0.0001857071, 0.18556862, -48.814682],
+    [-0.00017571071, 0.17440253, -45.702957],
+    [-0.00090538705, 0.9905655, -273.1656],
+    [-0.00071217836, 0.7745239, -213.08301],
+    [-0.00056564866, 0.61157525, -167.87408],
+    [-0.00047053891, 0.50580156, -138.53188],
+    [-0.00040307778, 0.43076333, -117.71506],
+    [-0.00035242588, 0.37440646, -102.07781],
+    [-0.00031285902, 0.33036628, -89.85411],
+    [-0.0002810388, 0.29493156, -80.01451],
+    [-0.00025487287, 0.26577666, -71.9141],
+    [-0.00023297592, 0.24136205, -65.12611],
+    [-0.00021439066, 0.2206241, -59.355747],
+    [-0.00019843178, 0.20280148, -54.392082],
+    [-0.00018459452, 0.18733355, -50.079838],
+    [-0.00017249824, 0.17379755, -46.30193],
+    [-0.00016184944, 0.16186765, -42.96817],
+    [-0.0001524183, 0.15128867, -40.007927],
+    [-0.00090538705, 0.9944615, -275.45828],
+    [-0.0006622843, 0.72327155, -199.98164],
+    [-0.0005240117, 0.56906927, -157.08109],
+    [-0.00043396073, 0.46863177, -129.14061],
+    [-0.00036996705, 0.39724007, -109.278404],
+    [-0.00032186482, 0.3435584, -94.33965],
+    [-0.00028426503, 0.30157852, -82.65278],
+    [-0.0002540163, 0.26778746, -73.240746],
+    [-0.00022913932, 0.23997916, -65.49016],
+    [-0.0002083219, 0.21669139, -58.99456],
+    [-0.00019065567, 0.19691204, -53.472717],
+    [-0.00017549022, 0.1799165, -48.723316],
+    [-0.00016234565, 0.16517033, -44.597923],
+    [-0.00015085998, 0.15227032, -40.984585],
+    [-0.00014075417, 0.14090583, -37.79706],
+    [-0.00013180923, 0.130833, -34.967678],
+    [-0.00085193577, 0.9392644, -261.28732],
+    [-0.0006184186, 0.67816865, -188.44263],
+    [-0.00048733526, 0.5315969, -147.55972],
+    [-0.00040170044, 0.4358267, -120.84745],
+    [-0.0003407372, 0.36762792, -101.82253],
+    [-0.00029486427, 0.31628963, -87.49665],
+    [-0.00025898442, 0.27611446, -76.28079],
+    [-0.00023010939, 0.24376278, -67.24378],
+    [-0.00020635859, 0.21713322, -59.799904],
+    [-0.00018648358, 0.19483095, -53.560467],
+    [-0.00016961912, 0.17588945, -48.2562],
+    [-0.000155145, 0.15961598, -43.694176],
+    [-0.0001426035, 0.14549941, -39.732067],
+    [-0.00013164885, 0.13315362, -36.262398],
+    [-0.00012201455, 0.12228112, -33.20236],
+    [-0.000113491296, 0.11264825, -30.486946],
+    [-0.00080055016, 0.88586307, -247.46916],
+    [-0.00057958055, 0.63819724, -178.20769],
+    [-0.00045481874, 0.49834815, -139.10565],
+    [-0.00037307513, 0.4066981, -113.47951],
+    [-0.00031478444, 0.34132025, -95.19556],
+    [-0.00027087796, 0.29205272, -81.41212],
+    [-0.00023651504, 0.253472, -70.613045],
+    [-0.00020885096, 0.22239137, -61.907734],
+    [-0.00018609231, 0.19680205, -54.73495],
+    [-0.00016704688, 0.17536882, -48.721745],
+    [-0.00015088753, 0.15716547, -43.609474],
+    [-0.0001370208, 0.14152755, -39.212643],
+    [-0.00012500848, 0.12796445, -35.394318],
+    [-0.00011451923, 0.11610533, -32.051018],
+    [-0.00010529762, 0.1056643, -29.102982],
+    [-9.714293e-5, 0.09641674, -26.487568],
+    [-0.00075486704, 0.8383333, -235.15697],
+    [-0.0005449774, 0.60255045, -169.07214],
+    [-0.00042582283, 0.46867582, -131.55562],
+    [-0.00034753655, 0.38069317, -106.897896],
+    [-0.00029162187, 0.31782764, -89.274925],
+    [-0.00024946374, 0.27040428, -75.975334],
+    [-0.00021644913, 0.2332432, -65.54791],
+    [-0.00018986072, 0.20329347, -57.138165],
+    [-0.00016798277, 0.17862907, -50.20678],
+    [-0.00014967311, 0.1579679, -44.394855],
+    [-0.00013413849, 0.14041965, -39.453236],
+    [-0.000120809294, 0.12534511, -35.203064],
+    [-0.000109264765, 0.112272166, -31.512266],
+    [-9.918636e-5, 0.10084343, -28.280905],
+    [-9.03285e-5, 0.0907834, -25.43194],
+    [-8.2498285e-5, 0.08187572, -22.904882],
+    [-0.0007139964, 0.7957614, -224.11682],
+    [-0.00051397266, 0.5705805, -160.87157],
+    [-0.00039983052, 0.4420564, -124.777534],
+    [-0.00032463914, 0.35736254, -100.9897],
+    [-0.00027085224, 0.29675066, -83.96059],
+    [-0.0002302597, 0.25098142, -71.095634],
+    [-0.00019845192, 0.215093, -61.00188],
+    [-0.00017282578, 0.18615657, -52.857338],
+    [-0.00015173508, 0.16232023, -46.142376],
+    [-0.00013408247, 0.14234956, -40.51073],
+    [-0.00011910518, 0.12538666, -35.721806],
+    [-0.00010625504, 0.11081503, -31.602736],
+    [-9.512673e-5, 0.098178886, -28.025757],
+    [-8.5413456e-5, 0.08713329, -24.894192],
+    [-7.687844e-5, 0.07741201, -22.133446],
+    [-6.933567e-5, 0.06880595, -19.684935],
+    [-0.0006772235, 0.7574135, -214.16083],
+    [-0.00048605003, 0.54176134, -153.47249],
+    [-0.00037641986, 0.41806242, -118.6635],
+    [-0.00030401765, 0.33633757, -95.662254],
+    [-0.00025214883, 0.27776057, -79.17019],
+    [-0.0002129673, 0.2334845, -66.698166],
+    [-0.00018224669, 0.19874433, -56.90588],
+    [-0.00015748685, 0.17072152, -49.000774],
+    [-0.00013710429, 0.14763111, -42.48104],
+    [-0.00012004219, 0.12828194, -37.01187],
+    [-0.000105565225, 0.11184528, -32.360405],
+    [-9.31446e-5, 0.09772521, -28.359213],
+    [-8.2389044e-5, 0.08548087, -24.884464],
+    [-7.3002295e-5, 0.074778415, -21.84241],
+    [-6.4755564e-5, 0.06536011, -19.16068],
+    [-5.7469068e-5, 0.057023402, -16.782415],
+    [-0.0006439682, 0.7226937, -205.1364],
+    [-0.00046078648, 0.51566213, -146.76553],
+    [-0.00035524333, 0.39634168, -113.124626],
+    [-0.00028537, 0.3173133, -90.83891],
+    [-0.00023524051, 0.26058453, -74.83536],
+    [-0.00019733825, 0.21766427, -62.72062],
+    [-0.00016760273, 0.18396604, -53.202274],
+    [-0.00014362743, 0.15677178, -45.514606],
+    [-0.00012388558, 0.13435724, -39.17198],
+    [-0.00010735722, 0.1155706, -33.850063],
+    [-9.333203e-5, 0.09960978, -29.323044],
+    [-8.129882e-5, 0.08589759, -25.42846],
+    [-7.0878996e-5, 0.0740066, -22.04603],
+    [-6.178595e-5, 0.06361325, -19.08471],
+    [-5.3798176e-5, 0.054467496, -16.474157],
+    [-4.6741425e-5, 0.046372637, -14.159054],
+    [-0.00061375403, 0.6911123, -196.91812],
+    [-0.00043783183, 0.4919259, -140.66006],
+    [-0.00033601173, 0.376601, -108.08689],
+    [-0.000268444, 0.30003506, -86.45563],
+    [-0.00021990048, 0.2449941, -70.89886],
+    [-0.00018316423, 0.20331146, -59.110706],
+    [-0.00015432622, 0.17056389, -49.84263],
+    [-0.00013106513, 0.14412494, -42.35342],
+    [-0.00011190609, 0.122326, -36.172314],
+    [-9.5862815e-5, 0.10405127, -30.984552],
+    [-8.2247774e-5, 0.08852299, -26.570793],
+    [-7.0565795e-5, 0.07518106, -22.773096],
+    [-6.0450104e-5, 0.0636106, -19.474504],
+    [-5.1622676e-5, 0.05349717, -16.586384],
+    [-4.386872e-5, 0.044597823, -14.040266],
+    [-3.7019152e-5, 0.036721367, -11.782274],
+    [-0.0005861864, 0.6622639, -189.40218],
+    [-0.000416894, 0.47025493, -135.0805],
+    [-0.0003184829, 0.35859472, -103.48826],
+    [-0.00025302786, 0.28428873, -82.45857],
+    [-0.00020593753, 0.23079668, -67.312386],
+    [-0.00017026943, 0.19024938, -55.82424],
+    [-0.00014225312, 0.15837337, -46.785896],
+    [-0.000119645454, 0.13262634, -39.47872],
+    [-0.00010101926, 0.11139093, -33.44564],
+    [-8.541906e-5, 0.09358435, -28.380705],
+    [-7.217825e-5, 0.07845122, -24.070526],
+    [-6.0816426e-5, 0.06544719, -20.361345],
+    [-5.0977542e-5, 0.054168798, -17.139235],
+    [-4.239159e-5, 0.04431014, -14.317811],
+    [-3.484988e-5, 0.03563476, -11.83032],
+    [-2.818809e-5, 0.027956493, -9.624215],
+    [-0.0005609363, 0.63580984, -182.50182],
+    [-0.00039772774, 0.450399, -129.9633],
+    [-0.00030245268, 0.34211567, -99.27641],
+    [-0.00023894238, 0.26989323, -78.80217],
+    [-0.00019318974, 0.21782906, -64.03503],
+    [-0.00015850451, 0.17832793, -52.82369],
+    [-0.00013124397, 0.14725457, -43.997177],
+    [-0.000109236906, 0.12214433, -36.85772],
+    [-9.110007e-5, 0.101427086, -30.9609],
+    [-7.590645e-5, 0.08405058, -26.008923],
+    [-6.300872e-5, 0.06928012, -21.793907],
+    [-5.1940104e-5, 0.05658586, -18.165958],
+    [-4.2354422e-5, 0.045574926, -15.01395],
+    [-3.398907e-5, 0.035949267, -12.253586],
+    [-2.6640977e-5, 0.027478416, -9.81969],
+    [-2.015022e-5, 0.01998087, -7.660948],
+    [-0.00053772633, 0.6114652, -176.14398],
+    [-0.00038012542, 0.4321463, -125.25463],
+    [-0.0002877476, 0.32698774, -95.40685],
+    [-0.00022603497, 0.25669426, -75.44762],
+    [-0.00018151889, 0.20595188, -61.031834],
+    [-0.00014774194, 0.1674188, -50.076958],
+    [-0.00012117944, 0.13708772, -41.44658],
+    [-9.972671e-5, 0.11256591, -34.46228],
+    [-8.204129e-5, 0.092327185, -28.691425],
+    [-6.7222376e-5, 0.07534749, -23.84377],
+    [-5.4640583e-5, 0.060911328, -19.716558],
+    [-4.3841683e-5, 0.048502333, -16.163485],
+    [-3.448868e-5, 0.03773741, -13.076017],
+    [-2.6325795e-5, 0.028325811, -10.371797],
+    [-1.9155146e-5, 0.020042578, -7.98711],
+    [-1.2820971e-5, 0.012710646, -5.871811],
+    [-0.00051632145, 0.5889879, -170.2667],
+    [-0.00036390993, 0.415316, -120.90856],
+    [-0.0002742192, 0.31306016, -91.84149],
+    [-0.00021417484, 0.24455945, -72.36159],
+    [-0.00017080618, 0.19504528, -58.272728],
+    [-0.00013787177, 0.15741135, -47.556366],
+    [-0.00011195652, 0.1277694, -39.10828],
+    [-9.101755e-5, 0.10379356, -32.268097],
+    [-7.375017e-5, 0.08399845, -26.614138],
+    [-5.9278063e-5, 0.067386374, -21.863226],
+    [-4.698841e-5, 0.053259637, -17.817358],
+    [-3.6438705e-5, 0.041114435, -14.33358],
+    [-2.7300457e-5, 0.03057678, -11.305783],
+    [-1.9324243e-5, 0.021362701, -8.653412],
+    [-1.2317026e-5, 0.013252419, -6.314121],
+    [-6.1268092e-6, 0.0060728737, -4.2388253],
+    [-0.0004965215, 0.56817186, -164.81712],
+    [-0.00034892987, 0.39975345, -116.88581],
+    [-0.00026174026, 0.30020365, -88.54765],
+    [-0.00020324948, 0.23337491, -69.51542],
+    [-0.00016094938, 0.1850061, -55.73184],
+    [-0.00012879941, 0.14821036, -45.238083],
+    [-0.000103486454, 0.11921041, -36.960022],
+    [-8.302536e-5, 0.09574298, -30.254173],
+    [-6.614661e-5, 0.0763607, -24.709103],
+    [-5.199668e-5, 0.06009046, -20.048223],
+    [-3.997826e-5, 0.04625125, -16.078005],
+    [-2.9659695e-5, 0.034350928, -12.658604],
+    [-2.0720534e-5, 0.024024121, -9.686207],
+    [-1.2917179e-5, 0.014993086, -7.081915],
+    [-6.0611433e-6, 0.0070429025, -4.784674],
+    [-3.9410217e-9, 4.2541546e-6, -2.7463913],
+    [-0.00071891706, 0.780339, -213.02814],
+    [-0.0005277522, 0.5690477, -154.74164],
+    [-0.00042334056, 0.45365536, -122.92154],
+    [-0.00035598403, 0.37921396, -102.397285],
+    [-0.00030833267, 0.32654366, -87.87546],
+    [-0.0002725802, 0.28701615, -76.97558],
+    [-0.00024463946, 0.2561148, -68.45194],
+    [-0.00022214086, 0.23122153, -61.5827],
+    [-0.00020360568, 0.21070272, -55.917603],
+    [-0.00018805801, 0.19348058, -51.159676],
+    [-0.00017482493, 0.17881204, -47.10423],
+    [-0.0001634258, 0.16616641, -43.60512],
+    [-0.00015350706, 0.1551535, -40.554935],
+    [-0.00014480218, 0.1454791, -37.872704],
+    [-0.00013710615, 0.13691705, -35.49619],
+    [-0.00013025828, 0.12929006, -33.37663],
+    [-0.0006627016, 0.72217876, -198.03893],
+    [-0.00048594348, 0.52618104, -143.80128],
+    [-0.00038859402, 0.41824082, -113.94107],
+    [-0.00032553344, 0.3483145, -94.598976],
+    [-0.0002808158, 0.29871857, -80.87947],
+    [-0.0002472164, 0.26144248, -70.56567],
+    [-0.00022093525, 0.2322735, -62.4921],
+    [-0.00019976213, 0.2087619, -55.981243],
+    [-0.00018231425, 0.18937525, -50.609425],
+    [-0.00016767748, 0.17310072, -46.096687],
+    [-0.00015522039, 0.1592388, -42.249756],
+    [-0.0001444915, 0.14728945, -38.930496],
+    [-0.00013515833, 0.13688451, -36.037235],
+    [-0.00012697004, 0.12774624, -33.493298],
+    [-0.00011973365, 0.11966099, -31.239714],
+    [-0.00011329756, 0.112461016, -29.230196],
+    [-0.0006144493, 0.67220455, -185.14781],
+    [-0.00044981757, 0.48910758, -134.33249],
+    [-0.00035846504, 0.38750857, -106.14319],
+    [-0.00029906735, 0.32143968, -87.81293],
+    [-0.00025685667, 0.27447617, -74.78163],
+    [-0.0002250994, 0.23913005, -64.971115],
+    [-0.00020023913, 0.2114472, -57.284313],
+    [-0.00018020096, 0.18912101, -51.081474],
+    [-0.00016368409, 0.17070572, -45.961716],
+    [-0.00014982703, 0.15524393, -41.659653],
+    [-0.00013803385, 0.14207357, -37.991814],
+    [-0.00012787805, 0.13072082, -34.826942],
+    [-0.00011904525, 0.1208365, -32.068314],
+    [-0.00011129805, 0.11215697, -29.642942],
+    [-0.00010445365, 0.10447928, -27.494642],
+    [-9.836856e-5, 0.09764414, -25.57933],
+    [-0.00057259004, 0.62880516, -173.94217],
+    [-0.00041831355, 0.45674813, -126.06138],
+    [-0.00033211958, 0.36061487, -99.31503],
+    [-0.00027588315, 0.29788166, -81.86131],
+    [-0.00023583965, 0.2531981, -69.42712],
+    [-0.00020567616, 0.21952495, -60.053616],
+    [-0.00018204554, 0.19313061, -52.70272],
+    [-0.00016298963, 0.1718323, -46.767403],
+    [-0.00014727828, 0.154259, -41.866505],
+    [-0.00013409548, 0.13950141, -37.747288],
+    [-0.00012287611, 0.1269299, -34.234802],
+    [-0.000113215254, 0.1160934, -31.203754],
+    [-0.0001048142, 0.10665921, -28.561743],
+    [-9.7447235e-5, 0.098375976, -26.238981],
+    [-9.0940484e-5, 0.09105009, -24.181732],
+    [-8.515724e-5, 0.08452938, -22.347769],
+    [-0.0005359395, 0.59076524, -164.11072],
+    [-0.0003906166, 0.42827362, -118.77757],
+    [-0.0003089106, 0.33690494, -93.291405],
+    [-0.00025543146, 0.27708673, -76.60503],
+    [-0.00021728074, 0.23439813, -64.69418],
+    [-0.00018850999, 0.20218956, -55.703884],
+    [-0.0001659539, 0.17692319, -48.647514],
+    [-0.00014775622, 0.15652458, -42.946743],
+    [-0.00013274835, 0.13968794, -38.23763],
+    [-0.000120154036, 0.12554607, -34.27854],
+    [-0.00010943515, 0.113497846, -30.90203],
+    [-0.00010020555, 0.10311201, -27.987999],
+    [-9.2180315e-5, 0.09407039, -25.447859],
+    [-8.5144056e-5, 0.08613249, -23.214672],
+    [-7.893049e-5, 0.079112686, -21.236782],
+    [-7.3409166e-5, 0.07286536, -19.473677],
+    [-0.0005035878, 0.55715066, -155.41426],
+    [-0.00036609106, 0.40303707, -112.316864],
+    [-0.00028832836, 0.3158629, -87.942116],
+    [-0.0002372773, 0.25861633, -71.93387],
+    [-0.00020079473, 0.21768904, -60.48584],
+    [-0.00017325177, 0.18677387, -51.834496],
+    [-0.00015164293, 0.16250357, -45.038624],
+    [-0.00013420144, 0.142899, -39.54517],
+    [-0.00011981315, 0.12671229, -35.00552],
+    [-0.00010773678, 0.113113254, -31.187819],
+    [-9.745795e-5, 0.10152595, -27.931257],
+    [-8.860725e-5, 0.09153675, -25.120378],
+    [-8.091188e-5, 0.08284031, -22.669956],
+    [-7.416549e-5, 0.07520565, -20.51555],
+    [-6.820868e-5, 0.06845441, -18.607409],
+    [-6.291637e-5, 0.062446583, -16.906487],
+    [-0.0004748245, 0.52723205, -147.66602],
+    [-0.00034423385, 0.38052624, -106.54924],
+    [-0.0002699666, 0.29707718, -83.163315],
+    [-0.0002210716, 0.24211827, -67.75933],
+    [-0.00018607128, 0.20275877, -56.723927],
+    [-0.00015961938, 0.17299487, -48.374756],
+    [-0.00013885212, 0.14961098, -41.811043],
+    [-0.00012208223, 0.13071282, -36.502293],
+    [-0.00010824371, 0.11510372, -32.113472],
+    [-9.6626616e-5, 0.10198681, -28.421537],
+    [-8.673768e-5, 0.09080852, -25.271574],
+    [-7.8222336e-5, 0.08117097, -22.552277],
+    [-7.08186e-5, 0.07278021, -20.181427],
+    [-6.4328175e-5, 0.06541379, -18.096817],
+    [-5.8597812e-5, 0.0588998, -16.25039],
+    [-5.3507196e-5, 0.053103298, -14.604423],
+    [-0.00044908683, 0.50043136, -140.71793],
+    [-0.00032464182, 0.36033052, -101.370476],
+    [-0.00025349742, 0.28021562, -78.87116],
+    [-0.00020653137, 0.22730704, -64.009636],
+    [-0.00017285779, 0.18935318, -53.34476],
+    [-0.00014738242, 0.16062137, -45.26689],
+    [-0.00012736817, 0.13803187, -38.91154],
+    [-0.000111198766, 0.11976628, -33.768425],
+    [-9.785165e-5, 0.10467426, -29.514812],
+    [-8.664465e-5, 0.09198851, -25.9355],
+    [-7.710351e-5, 0.08117564, -22.880905],
+    [-6.888702e-5, 0.07185193, -20.243464],
+    [-6.174287e-5, 0.0637337, -17.943653],
+    [-5.5480003e-5, 0.05660612, -15.921279],
+    [-4.99507e-5, 0.050303143, -14.129818],
+    [-4.503891e-5, 0.04469431, -12.532729],
+    [-0.00042592332, 0.47628483, -134.45123],
+    [-0.0003069882, 0.342117, -96.69605],
+    [-0.00023865337, 0.2650073, -74.99724],
+    [-0.00019342486, 0.21394865, -60.625965],
+    [-0.00016094669, 0.17726335, -50.295994],
+    [-0.00013635108, 0.14946282, -42.463284],
+    [-0.000117014904, 0.12758973, -36.296104],
+    [-0.00010138603, 0.10989439, -31.302505],
+    [-8.848072e-5, 0.09526795, -27.1708],
+    [-7.764222e-5, 0.08297018, -23.69296],
+    [-6.841329e-5, 0.07248582, -20.724216],
+    [-6.0464787e-5, 0.06344396, -18.160378],
+    [-5.3553154e-5, 0.055570185, -15.924376],
+    [-4.7493868e-5, 0.048656598, -13.957835],
+    [-4.2144184e-5, 0.04254241, -12.215623],
+    [-3.7391932e-5, 0.03710126, -10.662268],
+    [-0.0004049677, 0.45441607, -128.76953],
+    [-0.00029100548, 0.32561308, -92.45674],
+    [-0.0002252144, 0.25122893, -71.48519],
+    [-0.00018156058, 0.2018497, -57.55968],
+    [-0.00015016594, 0.16631605, -47.534206],
+    [-0.0001263676, 0.13936076, -39.924313],
+    [-0.000107645625, 0.11813754, -33.928062],
+    [-9.250595e-5, 0.10095903, -29.070154],
+    [-8.0000376e-5, 0.08675442, -25.049038],
+    [-6.9494876e-5, 0.07480773, -21.663141],
+    [-6.054784e-5, 0.06462038, -18.772108],
+    [-5.2841064e-5, 0.05583309, -16.27483],
+    [-4.6138903e-5, 0.04817985, -14.096462],
+    [-4.026279e-5, 0.041459072, -12.180295],
+    [-3.507453e-5, 0.035514787, -10.482457],
+    [-3.0465426e-5, 0.030224318, -8.968453],
+    [-0.00038591956, 0.43451628, -123.59368],
+    [-0.00027647257, 0.31059325, -88.59527],
+    [-0.00021299768, 0.23869514, -68.28819],
+    [-0.00017077883, 0.19084881, -54.77019],
+    [-0.00014037176, 0.15636644, -45.023083],
+    [-0.00011729981, 0.13018236, -37.616776],
+    [-9.913716e-5, 0.109551795, -31.776608],
+    [-8.4442785e-5, 0.092844374, -27.042536],
+    [-7.230063e-5, 0.07902376, -23.122217],
+    [-6.209773e-5, 0.06739654, -19.820076],
+    [-5.3406573e-5, 0.05747913, -16.999754],
+    [-4.591894e-5, 0.048922915, -14.562969],
+    [-3.9406503e-5, 0.041469667, -12.436929],
+    [-3.3696087e-5, 0.034923527, -10.566437],
+    [-2.8653621e-5, 0.029132895, -8.90878],
+    [-2.4173662e-5, 0.023978539, -7.430365],
+    [-0.00036853034, 0.4163299, -118.85823],
+    [-0.00026320515, 0.29686955, -85.06391],
+    [-0.00020185023, 0.22725071, -65.367035],
+    [-0.00016094571, 0.18081062, -52.223446],
+    [-0.00013144313, 0.14729252, -42.732033],
+    [-0.000109036264, 0.121815585, -35.512653],
+    [-9.1385475e-5, 0.10172812, -29.815697],
+    [-7.7098244e-5, 0.08545199, -25.195139],
+    [-6.528827e-5, 0.071982816, -21.367176],
+    [-5.5361634e-5, 0.0606476, -18.141684],
+    [-4.6903882e-5, 0.050976723, -15.386013],
+    [-3.9615967e-5, 0.042631358, -13.004481],
+    [-3.3276225e-5, 0.035360366, -10.926179],
+    [-2.7716454e-5, 0.028973186, -9.097309],
+    [-2.2806385e-5, 0.023322254, -7.4762235],
+    [-1.8443521e-5, 0.01829146, -6.030152],
+    [-0.00035259273, 0.39964363, -114.508514],
+    [-0.0002510486, 0.28428417, -81.82258],
+    [-0.00019164308, 0.21676472, -62.68865],
+    [-0.00015194791, 0.17162053, -49.890617],
+    [-0.00012327752, 0.13899095, -40.635136],
+    [-0.00010148237, 0.1141653, -33.588142],
+    [-8.430211e-5, 0.09457773, -28.023172],
+    [-7.0389004e-5, 0.07869841, -23.507175],
+    [-5.8883878e-5, 0.06555216, -19.76417],
+    [-4.9210605e-5, 0.054485057, -16.609129],
+    [-4.0966734e-5, 0.045040347, -13.912832],
+    [-3.386164e-5, 0.036888205, -11.581989],
+    [-2.7679816e-5, 0.029784057, -9.547433],
+    [-2.2257644e-5, 0.02354223, -7.756659],
+    [-1.7468354e-5, 0.018018875, -6.169004],
+    [-1.3212164e-5, 0.013100773, -4.752461],
+    [-0.00033793232, 0.38427806, -110.49854],
+    [-0.00023987202, 0.27270356, -78.83731],
+    [-0.00018226678, 0.20712598, -60.22493],
+    [-0.00014368906, 0.16318107, -47.747158],
+    [-0.00011578755, 0.13137357, -38.710274],
+    [-9.455733e-5, 0.1071502, -31.822906],
+    [-7.781142e-5, 0.088024676, -26.380075],
+    [-6.424329e-5, 0.07251171, -21.960724],
+    [-5.301924e-5, 0.059663534, -18.296225],
+    [-4.3579337e-5, 0.048843663, -15.206216],
+    [-3.553227e-5, 0.039607286, -12.564665],
+    [-2.8595254e-5, 0.031632967, -10.280515],
+    [-2.2558455e-5, 0.024682201, -8.286212],
+    [-1.7262508e-5, 0.018573843, -6.530454],
+    [-1.2583879e-5, 0.0131675005, -4.9734898],
+    [-8.425305e-6, 0.008352622, -3.584019],
+    [-0.00032440145, 0.37008113, -106.789345],
+    [-0.00022956404, 0.2620139, -76.07921],
+    [-0.0001736279, 0.19823956, -57.951885],
+    [-0.00013608664, 0.15540868, -45.772045],
+    [-0.00010889821, 0.12436466, -36.938454],
+    [-8.8191744e-5, 0.10070043, -30.199461],
+    [-7.184821e-5, 0.082003474, -24.870052],
+    [-5.859966e-5, 0.06683026, -20.54043],
+    [-4.76356e-5, 0.05425812, -16.94871],
+    [-3.8411454e-5, 0.04366707, -13.918949],
+    [-3.054619e-5, 0.03462334, -11.328071],
+    [-2.376429e-5, 0.026813298, -9.087097],
+    [-1.7861212e-5, 0.02000409, -7.129978],
+    [-1.2681519e-5, 0.01401878, -5.4065285],
+    [-8.104698e-6, 0.008720183, -3.877849],
+    [-4.035818e-6, 0.004000237, -2.5132968],
+    [-0.00031187417, 0.3569232, -103.34768],
+    [-0.0002200293, 0.25211784, -73.523506],
+    [-0.00016564611, 0.1900239, -55.848892],
+    [-0.00012906967, 0.14823149, -43.947178],
+    [-0.00010254487, 0.117899, -35.303295],
+    [-8.232564e-5, 0.09475558, -28.702682],
+    [-6.6356304e-5, 0.07645766, -23.479004],
+    [-5.3404554e-5, 0.06160036, -19.232906],
+    [-4.2681986e-5, 0.04928488, -15.708924],
+    [-3.365803e-5, 0.03890636, -12.735178],
+    [-2.5961315e-5, 0.030041408, -10.191368],
+    [-1.9323095e-5, 0.02238367, -7.990459],
+    [-1.3543754e-5, 0.015705572, -6.0678077],
+    [-8.471536e-6, 0.009834109, -4.3742757],
+    [-3.988725e-6, 0.0046350914, -2.8717577],
+    [-2.5710796e-9, 2.7767205e-6, -1.5302259],
+    [-0.0005330259, 0.57839245, -157.6059],
+    [-0.00039371738, 0.42440873, -115.13014],
+    [-0.000317518, 0.34018075, -91.901634],
+    [-0.00026825062, 0.28571388, -76.88077],
+    [-0.0002333049, 0.24706897, -66.221344],
+    [-0.00020701015, 0.21797878, -58.1946],
+    [-0.0001863974, 0.19516258, -51.89588],
+    [-0.00016974482, 0.17671807, -46.8008],
+    [-0.0001559774, 0.16145784, -42.582138],
+    [-0.00014438518, 0.1485978, -39.023872],
+    [-0.00013447828, 0.13759705, -35.97704],
+    [-0.0001259064, 0.12806888, -33.33516],
+    [-0.00011841167, 0.11972863, -31.019888],
+    [-0.00011179942, 0.11236153, -28.972116],
+    [-0.00010591978, 0.1058022, -27.146353],
+    [-0.00010065506, 0.09992081, -25.506891],
+    [-0.0004912031, 0.535119, -146.45296],
+    [-0.00036249732, 0.3923958, -106.959656],
+    [-0.0002914913, 0.31365108, -85.174065],
+    [-0.00024538214, 0.26250568, -71.023476],
+    [-0.00021259276, 0.22612227, -60.954716],
+    [-0.00018788002, 0.19868778, -53.359386],
+    [-0.00016848599, 0.17714497, -47.391846],
+    [-0.0001528059, 0.15971531, -42.56031],
+    [-0.00013983533, 0.14528579, -38.5571],
+    [-0.00012890965, 0.13312, -35.178722],
+    [-0.00011956962, 0.1227093, -32.284637],
+    [-0.000111486246, 0.1136893, -29.774225],
+    [-0.00010441731, 0.1057918, -27.573416],
+    [-9.81796e-5, 0.09881397, -25.626232],
+    [-9.263204e-5, 0.092599675, -23.88958],
+    [-8.766373e-5, 0.08702617, -22.3296],
+    [-0.00045534674, 0.49798328, -136.87405],
+    [-0.00033554487, 0.36473653, -99.8957],
+    [-0.0002689394, 0.2906474, -79.33754],
+    [-0.00022551762, 0.24233395, -65.93029],
+    [-0.00019456744, 0.20788316, -56.3672],
+    [-0.00017120583, 0.18186541, -49.141655],
+    [-0.00015285343, 0.16141309, -43.458206],
+    [-0.00013800485, 0.14485279, -38.85281],
+    [-0.00012571568, 0.13113494, -35.034504],
+    [-0.00011535991, 0.11956392, -31.810492],
+    [-0.00010650436, 0.109658495, -29.047441],
+    [-9.883837e-5, 0.10107355, -26.649769],
+    [-9.213297e-5, 0.0935548, -24.547052],
+    [-8.621483e-5, 0.0869098, -22.686022],
+    [-8.0950405e-5, 0.08099028, -21.025635],
+    [-7.623462e-5, 0.0756796, -19.533623],
+    [-0.0004242659, 0.46576226, -128.55576],
+    [-0.0003120525, 0.34060875, -93.7295],
+    [-0.00024922527, 0.2705248, -74.229256],
+    [-0.00020811893, 0.22465599, -61.464813],
+    [-0.00017875612, 0.19187632, -52.33971],
+    [-0.00015656177, 0.1670848, -45.434757],
+    [-0.00013910963, 0.14757662, -39.99768],
+    [-0.00012497981, 0.13176918, -35.58844],
+    [-0.000113279566, 0.11866756, -31.93049],
+    [-0.000103416234, 0.10761141, -28.840326],
+    [-9.497907e-5, 0.09814311, -26.190823],
+    [-8.767337e-5, 0.0899344, -23.890808],
+    [-8.128146e-5, 0.08274289, -21.87298],
+    [-7.563881e-5, 0.076385334, -20.086475],
+    [-7.061814e-5, 0.07072008, -18.491982],
+    [-6.611962e-5, 0.065635964, -17.058662],
+    [-0.000397066, 0.43753704, -121.26259],
+    [-0.00029140254, 0.31938347, -88.30134],
+    [-0.0002318571, 0.25278515, -69.72345],
+    [-0.00019276737, 0.20904943, -57.520866],
+    [-0.00016478897, 0.17772986, -48.77907],
+    [-0.00014361332, 0.15401036, -42.154846],
+    [-0.00012694702, 0.1353278, -36.933548],
+    [-0.00011344455, 0.120178595, -32.696075],
+    [-0.000102258186, 0.10761561, -29.17851],
+    [-9.282434e-5, 0.09700926, -26.20546],
+    [-8.4751904e-5, 0.08792271, -23.655264],
+    [-7.776002e-5, 0.08004228, -21.440592],
+    [-7.164104e-5, 0.07313615, -19.49691],
+    [-6.62379e-5, 0.067028955, -17.775417],
+    [-6.142903e-5, 0.061585013, -16.238369],
+    [-5.711901e-5, 0.056697827, -14.856141],
+    [-0.0003730616, 0.41260347, -114.814095],
+    [-0.00027311462, 0.3005713, -83.486916],
+    [-0.00021644867, 0.23703712, -65.721306],
+    [-0.00017913229, 0.1951806, -54.014545],
+    [-0.00015237254, 0.16514854, -45.611298],
+    [-0.00013209393, 0.14237466, -39.23509],
+    [-0.0001161197, 0.124420404, -34.204422],
+    [-0.000103169565, 0.10985169, -30.118656],
+    [-9.243547e-5, 0.09776353, -26.725023],
+    [-8.33794e-5, 0.08755347, -23.855305],
+    [-7.5627584e-5, 0.07880304, -21.392675],
+    [-6.8911344e-5, 0.071211435, -19.253197],
+    [-6.303189e-5, 0.06455618, -17.374788],
+    [-5.7838755e-5, 0.058668856, -15.710475],
+    [-5.3215394e-5, 0.053419083, -14.223899],
+    [-4.907029e-5, 0.04870447, -12.88651],
+    [-0.00035171892, 0.390413, -109.06972],
+    [-0.00025680958, 0.28378585, -79.1881],
+    [-0.00020269284, 0.22296946, -62.144226],
+    [-0.0001669493, 0.18278258, -50.878727],
+    [-0.0001412712, 0.15389523, -42.776955],
+    [-0.00012178895, 0.13196221, -36.62161],
+    [-0.000106429026, 0.11465545, -31.76068],
+    [-9.396901e-5, 0.10060264, -27.809933],
+    [-8.363603e-5, 0.08893627, -24.526546],
+    [-7.49148e-5, 0.07907802, -21.748686],
+    [-6.744697e-5, 0.07062575, -19.363846],
+    [-6.0974682e-5, 0.06329016, -17.291105],
+    [-5.530703e-5, 0.056857068, -15.470582],
+    [-5.02994e-5, 0.05116427, -13.856925],
+    [-4.5839737e-5, 0.0460861, -12.415014],
+    [-4.1839987e-5, 0.04152382, -11.117259],
+    [-0.00033261615, 0.37053204, -103.918335],
+    [-0.00024218432, 0.26871827, -75.32645],
+    [-0.00019034249, 0.21033154, -58.928913],
+    [-0.00015600462, 0.17163943, -48.059097],
+    [-0.00013129368, 0.1437773, -40.22775],
+    [-0.00011252357, 0.12259741, -34.27052],
+    [-9.771283e-5, 0.10587041, -29.56178],
+    [-8.56908e-5, 0.092279345, -25.732046],
+    [-7.5716045e-5, 0.08099022, -22.547396],
+    [-6.729368e-5, 0.071446426, -19.851751],
+    [-6.0079114e-5, 0.06326046, -17.536476],
+    [-5.3824213e-5, 0.056153324, -15.523373],
+    [-4.8345137e-5, 0.049918327, -13.754528],
+    [-4.350247e-5, 0.044398762, -12.186039],
+    [-3.9188235e-5, 0.039473265, -10.783917],
+    [-3.531745e-5, 0.03504634, -9.521421],
+    [-0.00031541567, 0.35261357, -99.27102],
+    [-0.00022899383, 0.25511867, -71.83846],
+    [-0.00017919659, 0.19891952, -56.023865],
+    [-0.00014612361, 0.16157469, -45.51127],
+    [-0.0001222833, 0.13463694, -37.92411],
+    [-0.00010415414, 0.11413596, -32.14573],
+    [-8.983754e-5, 0.09793138, -27.574322],
+    [-7.8209385e-5, 0.08475615, -23.853712],
+    [-6.8556605e-5, 0.07380657, -20.758049],
+    [-6.0402657e-5, 0.064545594, -18.13644],
+    [-5.3415373e-5, 0.05659895, -15.88376],
+    [-4.735539e-5, 0.049696937, -13.924268],
+    [-4.2045216e-5, 0.043639615, -12.201831],
+    [-3.7350193e-5, 0.038275305, -10.673872],
+    [-3.3165958e-5, 0.033486463, -9.307407],
+    [-2.9410334e-5, 0.029180562, -8.076476],
+    [-0.00029984393, 0.33637625, -95.05564],
+    [-0.00021703776, 0.24278267, -68.672226],
+    [-0.0001690899, 0.18856567, -53.386715],
+    [-0.00013716218, 0.15244274, -43.1986],
+    [-0.0001141103, 0.12634341, -35.833256],
+    [-9.656145e-5, 0.106458, -30.217266],
+    [-8.269205e-5, 0.09072691, -25.77049],
+    [-7.142015e-5, 0.07792832, -22.14885],
+    [-6.205843e-5, 0.067286104, -19.133839],
+    [-5.4146924e-5, 0.058280926, -16.579277],
+    [-4.7364763e-5, 0.05055055, -14.38323],
+    [-4.1480533e-5, 0.043833736, -12.4721985],
+    [-3.63225e-5, 0.037936665, -10.791666],
+    [-3.1760348e-5, 0.032712247, -9.300269],
+    [-2.7692977e-5, 0.028046409, -7.9659357],
+    [-2.4040794e-5, 0.023849346, -6.763421],
+    [-0.00028567712, 0.32158986, -91.213196],
+    [-0.00020615091, 0.23154172, -65.784904],
+    [-0.00015988552, 0.17913106, -50.98235],
+    [-0.00012900052, 0.1441224, -41.0906],
+    [-0.00010666653, 0.11878765, -33.92782],
+    [-8.9645924e-5, 0.09946339, -28.460072],
+    [-7.618338e-5, 0.08416367, -24.126995],
+    [-6.5235414e-5, 0.07170802, -20.595581],
+    [-5.6138128e-5, 0.06134543, -17.654034],
+    [-4.844673e-5, 0.05257279, -15.160494],
+    [-4.1850646e-5, 0.045038845, -13.015945],
+    [-3.61257e-5, 0.03849009, -11.14894],
+    [-3.110544e-5, 0.032738302, -9.506442],
+    [-2.666347e-5, 0.027640577, -8.048193],
+    [-2.2701719e-5, 0.02308604, -6.742964],
+    [-1.9142828e-5, 0.018987257, -5.566136],
+    [-0.00027273028, 0.308064, -87.694885],
+    [-0.0001961958, 0.22125548, -63.140835],
+    [-0.000151469, 0.17049944, -48.781387],
+    [-0.00012153817, 0.13651206, -39.16168],
+    [-9.986105e-5, 0.11187792, -32.18479],
+    [-8.3323604e-5, 0.09306767, -26.853018],
+    [-7.023299e-5, 0.07816284, -22.624157],
+    [-5.9580943e-5, 0.06602088, -19.175379],
+    [-5.0725062e-5, 0.055913884, -16.301062],
+    [-4.3234424e-5, 0.047353603, -13.863313],
+    [-3.680789e-5, 0.03999887, -11.765802],
+    [-3.1227966e-5, 0.033603307, -9.9389715],
+    [-2.6333024e-5, 0.02798383, -8.331149],
+    [-2.200026e-5, 0.023001354, -6.9030857],
+    [-1.813432e-5, 0.018547898, -5.6243167],
+    [-1.4660068e-5, 0.014538376, -4.4708414],
+    [-0.00026084928, 0.2956401, -84.46003],
+    [-0.00018705714, 0.21180624, -60.710083],
+    [-0.00014374396, 0.16257292, -46.75909],
+    [-0.00011469028, 0.12952581, -37.390217],
+    [-9.3616894e-5, 0.105536565, -30.584686],
+    [-7.752331e-5, 0.08719918, -25.378164],
+    [-6.477416e-5, 0.072657384, -21.245235],
+    [-5.439359e-5, 0.06080359, -17.872461],
+    [-4.5758945e-5, 0.05093113, -15.05991],
+    [-3.845221e-5, 0.042565607, -12.673389],
+    [-3.2180837e-5, 0.035375074, -10.619024],
+    [-2.673349e-5, 0.029119719, -8.829003],
+    [-2.1952992e-5, 0.023621213, -7.2529187],
+    [-1.7719858e-5, 0.018744003, -5.8524537],
+    [-1.3941287e-5, 0.01438282, -4.597863],
+    [-1.0544024e-5, 0.010454579, -3.465675],
+    [-0.00024990467, 0.28418484, -81.47444],
+    [-0.00017863746, 0.20309444, -58.46735],
+    [-0.0001366287, 0.15526846, -44.894447],
+    [-0.00010838472, 0.12309064, -35.75783],
+    [-8.786845e-5, 0.09969737, -29.110876],
+    [-7.2184244e-5, 0.081796706, -24.020191],
+    [-5.974981e-5, 0.067589946, -19.97591],
+    [-4.9619244e-5, 0.05600186, -16.673302],
+    [-4.118822e-5, 0.04634553, -13.917736],
+    [-3.4050474e-5, 0.0381592, -11.5784025],
+    [-2.7921595e-5, 0.031119639, -9.563751],
+    [-2.259588e-5, 0.024993066, -7.807578],
+    [-1.7920265e-5, 0.019605553, -6.2606435],
+    [-1.3778337e-5, 0.014824815, -4.885499],
+    [-1.0079606e-5, 0.010548048, -3.6530511],
+    [-6.7527058e-6, 0.0066941716, -2.5403566],
+    [-0.00023978681, 0.2735853, -78.709175],
+    [-0.0001708539, 0.19503537, -56.39109],
+    [-0.00013005345, 0.1485151, -43.16953],
+    [-0.00010255968, 0.11714394, -34.24875],
+    [-8.255946e-5, 0.09430349, -27.749092],
+    [-6.725418e-5, 0.076807626, -22.765923],
+    [-5.5110795e-5, 0.06291112, -18.803835],
+    [-4.5211236e-5, 0.051568866, -15.566226],
+    [-3.6968147e-5, 0.042112265, -12.863384],
+    [-2.9986273e-5, 0.03409142, -10.5676775],
+    [-2.3988663e-5, 0.027191104, -8.589697],
+    [-1.8774872e-5, 0.021183174, -6.8647413],
+    [-1.4195661e-5, 0.015897796, -5.3446603],
+    [-1.0137461e-5, 0.011205724, -3.992812],
+    [-6.511954e-6, 0.007006465, -2.780716],
+    [-3.2494552e-6, 0.003220729, -1.6859025],
+    [-0.00023040257, 0.2637455, -76.139595],
+    [-0.00016363562, 0.18755662, -54.46289],
+    [-0.00012395842, 0.14225207, -41.568954],
+    [-9.716215e-5, 0.11163198, -32.849434],
+    [-7.764147e-5, 0.08930598, -26.487047],
+    [-6.2688036e-5, 0.07218651, -21.603977],
+    [-5.0814666e-5, 0.05857821, -17.718344],
+    [-4.112919e-5, 0.047464073, -14.541123],
+    [-3.3060052e-5, 0.038192578, -11.887205],
+    [-2.6222331e-5, 0.030324958, -9.631943],
+    [-2.0345953e-5, 0.0235534, -7.6879096],
+    [-1.5235398e-5, 0.01765504, -5.991818],
+    [-1.074499e-5, 0.012463857, -4.4965315],
+    [-6.76381e-6, 0.007853444, -3.166163],
+    [-3.205582e-6, 0.003725484, -1.97281],
+    [-2.1192559e-9, 2.2870859e-6, -0.89442676],
+    [-0.00044641667, 0.4841882, -131.59909],
+    [-0.00033262093, 0.35839897, -96.904625],
+    [-0.00027032322, 0.28952044, -77.90706],
+    [-0.00022994858, 0.24486344, -65.586945],
+    [-0.0002012226, 0.213073, -56.81231],
+    [-0.0001795313, 0.18905067, -50.177315],
+    [-0.00016245998, 0.17012861, -44.94657],
+    [-0.00014860799, 0.15475962, -40.693714],
+    [-0.00013710001, 0.14197703, -37.15244],
+    [-0.00012735742, 0.13114187, -34.14677],
+    [-0.00011898018, 0.12181251, -31.555117],
+    [-0.000111681664, 0.11367258, -29.290394],
+    [-0.00010525015, 0.10648843, -27.288315],
+    [-9.9525394e-5, 0.10008326, -25.500238],
+    [-9.438314e-5, 0.09432001, -23.888472],
+    [-8.972545e-5, 0.08909064, -22.423313],
+    [-0.00041115456, 0.44769508, -122.19241],
+    [-0.0003061759, 0.33127657, -89.98158],
+    [-0.0002481853, 0.2669496, -72.18286],
+    [-0.00021042615, 0.22504634, -60.585083],
+    [-0.00018348367, 0.19512877, -52.30029],
+    [-0.000163099, 0.17247593, -46.02271],
+    [-0.00014703236, 0.15460539, -41.06594],
+    [-0.00013398021, 0.14007264, -37.03069],
+    [-0.00012312579, 0.12797275, -33.66689],
+    [-0.0001139279, 0.11770623, -30.808928],
+    [-0.00010601197, 0.108858205, -28.342222],
+    [-9.9108984e-5, 0.10113081, -26.184534],
+    [-9.3020215e-5, 0.094304025, -24.275127],
+    [-8.759486e-5, 0.08821093, -22.567947],
+    [-8.2715946e-5, 0.082722075, -21.027285],
+    [-7.82911e-5, 0.07773527, -19.624954],
+    [-0.00038099286, 0.4164552, -114.13434],
+    [-0.00028338784, 0.3078895, -84.008865],
+    [-0.00022903291, 0.24741213, -67.22596],
+    [-0.00019349153, 0.20784825, -56.242905],
+    [-0.0001680652, 0.1795258, -48.37601],
+    [-0.000148793, 0.15804107, -42.40379],
+    [-0.00013358264, 0.14106837, -37.68133],
+    [-0.00012121259, 0.12725003, -33.832283],
+    [-0.00011091565, 0.11573354, -30.620384],
+    [-0.00010218251, 0.105953045, -27.888866],
+    [-9.46601e-5, 0.09751635, -25.529099],
+    [-8.8094515e-5, 0.090141475, -23.463009],
+    [-8.2297964e-5, 0.08361988, -21.632864],
+    [-7.712776e-5, 0.07779317, -19.994837],
+    [-7.2473136e-5, 0.072538406, -18.514912],
+    [-6.824648e-5, 0.06775837, -17.1662],
+    [-0.00035489196, 0.38939944, -107.15054],
+    [-0.0002635488, 0.28751594, -78.802986],
+    [-0.00021230527, 0.23033935, -62.892643],
+    [-0.00017866872, 0.19278851, -52.439453],
+    [-0.00015454726, 0.1658413, -44.93345],
+    [-0.0001362336, 0.14536487, -39.225227],
+    [-0.00012176155, 0.12916762, -34.70546],
+    [-0.00010997982, 0.115966514, -31.01753],
+    [-0.00010016375, 0.1049541, -27.937075],
+    [-9.183151e-5, 0.09559357, -25.314974],
+    [-8.4648476e-5, 0.08751219, -23.047716],
+    [-7.837378e-5, 0.08044171, -21.060831],
+    [-7.282903e-5, 0.07418352, -19.299187],
+    [-6.7878565e-5, 0.06858658, -17.720879],
+    [-6.3416985e-5, 0.06353358, -16.293367],
+    [-5.9360784e-5, 0.058931604, -14.990884],
+    [-0.0003320752, 0.36572886, -101.03607],
+    [-0.00024612027, 0.26960665, -74.22431],
+    [-0.00019757153, 0.21529427, -59.072453],
+    [-0.00016558968, 0.17949523, -49.081142],
+    [-0.00014260343, 0.15374656, -41.89016],
+    [-0.00012512441, 0.13414954, -36.412548],
+    [-0.00011129546, 0.11862889, -32.069897],
+    [-0.00010002629, 0.10596643, -28.522774],
+    [-9.062918e-5, 0.09539389, -25.557184],
+    [-8.264613e-5, 0.08639972, -23.030668],
+    [-7.575861e-5, 0.07862821, -20.844196],
+    [-6.973709e-5, 0.07182308, -18.926445],
+    [-6.4411404e-5, 0.06579437, -17.224554],
+    [-5.9651982e-5, 0.06039747, -15.6983],
+    [-5.535809e-5, 0.055519965, -14.316426],
+    [-5.1449806e-5, 0.051072694, -13.054146],
+    [-0.000311951, 0.34483492, -95.6348],
+    [-0.0002306859, 0.253737, -70.16486],
+    [-0.00018449573, 0.20193602, -55.679234],
+    [-0.00015396552, 0.16767651, -46.094494],
+    [-0.00013197641, 0.14298235, -39.181114],
+    [-0.00011523088, 0.12415945, -33.906803],
+    [-0.00010196726, 0.10923451, -29.720318],
+    [-9.1148664e-5, 0.09704626, -26.29734],
+    [-8.211977e-5, 0.08686091, -23.432995],
+    [-7.444351e-5, 0.07818911, -20.990696],
+    [-6.7815534e-5, 0.07069015, -18.875372],
+    [-6.201627e-5, 0.06411828, -17.018469],
+    [-5.688277e-5, 0.058291174, -15.369132],
+    [-5.229085e-5, 0.053069882, -13.888627],
+    [-4.8143847e-5, 0.048346292, -12.546818],
+    [-4.4365024e-5, 0.044034578, -11.319792],
+    [-0.0002940608, 0.3262457, -90.82567],
+    [-0.0002169186, 0.23957318, -66.539795],
+    [-0.00017281182, 0.18999457, -52.64472],
+    [-0.00014356645, 0.15709999, -43.42103],
+    [-0.00012246061, 0.13334146, -36.75433],
+    [-0.000106365056, 0.11520561, -31.660717],
+    [-9.360242e-5, 0.10080939, -27.613033],
+    [-8.318303e-5, 0.08904194, -24.300362],
+    [-7.44803e-5, 0.07920006, -21.525948],
+    [-6.707569e-5, 0.070814095, -19.158419],
+    [-6.0677423e-5, 0.06355668, -17.106226],
+    [-5.5074757e-5, 0.057191428, -15.303283],
+    [-5.0111146e-5, 0.05154276, -13.700514],
+    [-4.566718e-5, 0.04647677, -12.260507],
+    [-4.1649833e-5, 0.041889183, -10.954132],
+    [-3.798517e-5, 0.037697118, -9.758247],
+    [-0.00027804353, 0.30958965, -86.51335],
+    [-0.00020455803, 0.22684942, -63.281513],
+    [-0.00016230671, 0.17925355, -49.914204],
+    [-0.00013420734, 0.14757846, -41.013607],
+    [-0.00011388987, 0.12465637, -34.567764],
+    [-9.837455e-5, 0.10713477, -29.63594],
+    [-8.6059066e-5, 0.09321116, -25.712496],
+    [-7.599589e-5, 0.081819735, -22.498526],
+    [-6.758412e-5, 0.072284676, -19.804552],
+    [-6.0421695e-5, 0.064153895, -17.503857],
+    [-5.4228116e-5, 0.05711201, -15.50806],
+    [-4.8800528e-5, 0.050930984, -13.753285],
+    [-4.398811e-5, 0.045441307, -12.192052],
+    [-3.967574e-5, 0.04051361, -10.78814],
+    [-3.5773573e-5, 0.036046967, -9.513304],
+    [-3.2210246e-5, 0.031961203, -8.345107],
+    [-0.0002636111, 0.29457024, -82.621765],
+    [-0.0001933942, 0.21535137, -60.335506],
+    [-0.0001528074, 0.16953728, -47.443268],
+    [-0.00012573748, 0.13895941, -38.833824],
+    [-0.0001061284, 0.11679007, -32.587036],
+    [-9.1134425e-5, 0.09982124, -27.801012],
+    [-7.922062e-5, 0.08632279, -23.989487],
+    [-6.947733e-5, 0.075269505, -20.86439],
+    [-6.132665e-5, 0.06601016, -18.242792],
+    [-5.4381522e-5, 0.058108643, -16.002213],
+    [-4.8371447e-5, 0.05126022, -14.0571],
+    [-4.3100714e-5, 0.045244448, -12.345578],
+    [-3.8423666e-5, 0.039897293, -10.821614],
+    [-3.4228935e-5, 0.035093356, -9.450038],
+    [-3.0429766e-5, 0.030734966, -8.203452],
+    [-2.6956937e-5, 0.026744222, -7.0600204],
+    [-0.00025053084, 0.28094786, -79.08945],
+    [-0.00018325605, 0.20490432, -57.657337],
+    [-0.00014417231, 0.16070186, -45.195522],
+    [-0.0001180328, 0.13111728, -36.85006],
+    [-9.906412e-5, 0.1096295, -30.783766],
+    [-8.454134e-5, 0.09316098, -26.12989],
+    [-7.299044e-5, 0.080047205, -22.419764],
+    [-6.3536e-5, 0.069299676, -19.375134],
+    [-5.562097e-5, 0.060289513, -16.819025],
+    [-4.8871796e-5, 0.052595012, -14.632797],
+    [-4.3027067e-5, 0.045921136, -12.733462],
+    [-3.7897607e-5, 0.040054385, -11.060988],
+    [-3.334236e-5, 0.034835607, -9.570633],
+    [-2.925345e-5, 0.030143114, -8.228198],
+    [-2.5546753e-5, 0.025882013, -7.007023],
+    [-2.2155113e-5, 0.021976583, -5.8858414],
+    [-0.00023861289, 0.26852697, -75.86621],
+    [-0.00017400284, 0.19536445, -55.210434],
+    [-0.00013628424, 0.1526282, -43.140842],
+    [-0.000110990404, 0.12394788, -35.036064],
+    [-9.260374e-5, 0.103080444, -29.1343],
+    [-7.8509045e-5, 0.08706716, -24.600834],
+    [-6.728768e-5, 0.074303165, -20.983032],
+    [-5.8095316e-5, 0.063833445, -18.01161],
+    [-5.0393966e-5, 0.05504953, -15.515042],
+    [-4.382233e-5, 0.04754284, -13.37818],
+    [-3.8127328e-5, 0.041027218, -11.520401],
+    [-3.312564e-5, 0.035295412, -9.883326],
+    [-2.8680464e-5, 0.030192789, -8.423408],
+    [-2.4687091e-5, 0.025601016, -7.107337],
+    [-2.1063799e-5, 0.021427752, -5.909123],
+    [-1.7745297e-5, 0.017599242, -4.8080177],
+    [-0.00022770069, 0.25714627, -72.91067],
+    [-0.00016551763, 0.18661226, -52.964394],
+    [-0.00012904542, 0.1452169, -41.254074],
+    [-0.00010452407, 0.11736387, -33.369843],
+    [-8.666888e-5, 0.09706377, -27.618769],
+    [-7.2964875e-5, 0.08146654, -23.195505],
+    [-6.2044026e-5, 0.06902201, -19.66214],
+    [-5.309049e-5, 0.058805805, -16.757612],
+    [-4.558368e-5, 0.05022816, -14.315396],
+    [-3.9173534e-5, 0.042892523, -12.223554],
+    [-3.3614597e-5, 0.036520906, -10.403634],
+    [-2.8728908e-5, 0.030911764, -8.798773],
+    [-2.4383551e-5, 0.025914602, -7.3665223],
+    [-2.0476717e-5, 0.021414168, -6.074385],
+    [-1.692888e-5, 0.01732045, -4.8969855],
+    [-1.367645e-5, 0.0135614965, -3.814048],
+    [-0.00021766398, 0.24667154, -70.18836],
+    [-0.00015770251, 0.17854765, -50.89374],
+    [-0.00012237356, 0.13838424, -39.51404],
+    [-9.856092e-5, 0.11129139, -31.832777],
+    [-8.119315e-5, 0.09151241, -26.220324],
+    [-6.784712e-5, 0.07629696, -21.89833],
+    [-5.720136e-5, 0.064145274, -18.442476],
+    [-4.846628e-5, 0.05416129, -15.599309],
+    [-4.113715e-5, 0.045772307, -13.206872],
+    [-3.487436e-5, 0.03859298, -11.156223],
+    [-2.9439461e-5, 0.032352816, -9.370909],
+    [-2.4659332e-5, 0.026855448, -7.7954407],
+    [-2.0404717e-5, 0.021954285, -6.3884053],
+    [-1.6576469e-5, 0.017536901, -5.118054],
+    [-1.3097016e-5, 0.0135153625, -3.9595604],
+    [-9.90434e-6, 0.009819378, -2.8930871],
+    [-0.00020839338, 0.23699003, -67.67037],
+    [-0.00015047459, 0.17108588, -48.976913],
+    [-0.00011619881, 0.13205916, -37.902763],
+    [-9.303896e-5, 0.10566761, -30.409035],
+    [-7.6119795e-5, 0.08636898, -24.924547],
+    [-6.310291e-5, 0.0715051, -20.69595],
+    [-5.2709784e-5, 0.059622757, -17.311493],
+    [-4.4175013e-5, 0.04985203, -14.5247555],
+    [-3.700867e-5, 0.041636143, -12.17806],
+    [-3.0880634e-5, 0.03459997, -10.165193],
+    [-2.5558986e-5, 0.028480012, -8.411567],
+    [-2.0875137e-5, 0.023084747, -6.862986],
+    [-1.6703128e-5, 0.0182711, -5.4789705],
+    [-1.2946266e-5, 0.013929267, -4.2284656],
+    [-9.528789e-6, 0.009973234, -3.0871522],
+    [-6.3901894e-6, 0.0063343123, -2.0356164],
+    [-0.00019979666, 0.22800663, -65.332275],
+    [-0.00014376367, 0.16415511, -47.195637],
+    [-0.000110461624, 0.12618104, -36.404896],
+    [-8.79051e-5, 0.1004387, -29.085045],
+    [-7.140012e-5, 0.08158425, -23.719074],
+    [-5.8686725e-5, 0.06704505, -19.576862],
+    [-4.8526173e-5, 0.055411056, -16.258339],
+    [-4.0175513e-5, 0.045836646, -13.523632],
+    [-3.3158496e-5, 0.037779808, -11.21903],
+    [-2.7153912e-5, 0.030874997, -9.240888],
+    [-2.1935788e-5, 0.0248651, -7.5163245],
+    [-1.7339768e-5, 0.019563131, -5.992353],
+    [-1.3242927e-5, 0.014829259, -4.629356],
+    [-9.550867e-6, 0.010556126, -3.3969266],
+    [-6.189554e-6, 0.0066595557, -2.2712348],
+    [-3.0997494e-6, 0.0030722106, -1.2332213],
+    [-0.0001917948, 0.21963985, -63.15314],
+    [-0.00013750915, 0.15769334, -45.534115],
+    [-0.00010511039, 0.12069735, -35.007137],
+    [-8.3113206e-5, 0.09555783, -27.849003],
+    [-6.699165e-5, 0.07711524, -22.593102],
+    [-5.455871e-5, 0.062876575, -18.530983],
+    [-4.4612658e-5, 0.051472016, -15.273473],
+    [-3.643145e-5, 0.04207861, -12.586821],
+    [-2.9551587e-5, 0.034168113, -10.321017],
+    [-2.3660095e-5, 0.027383868, -8.3748045],
+    [-1.853658e-5, 0.02147475, -6.6769032],
+    [-1.4020621e-5, 0.016257996, -5.175452],
+    [-9.992079e-6, 0.011596758, -3.8316302],
+    [-6.3586963e-6, 0.0073859626, -2.615638],
+    [-3.0480148e-6, 0.0035431155, -1.5040889],
+    [-2.0235829e-9, 2.1846101e-6, -0.47827095],
+    [-0.0004133733, 0.44806033, -121.404305],
+    [-0.00031124993, 0.33517596, -90.27728],
+    [-0.000255434, 0.27344656, -73.25004],
+    [-0.00021922177, 0.23336782, -62.187523],
+    [-0.00019339878, 0.20475984, -54.28372],
+    [-0.00017384016, 0.18306614, -48.28301],
+    [-0.00015838978, 0.16590536, -43.529354],
+    [-0.0001457967, 0.15189609, -39.642307],
+    [-0.00013527818, 0.14017412, -36.38391],
+    [-0.00012631528, 0.13016653, -33.59647],
+    [-0.00011854767, 0.12147566, -31.170532],
+    [-0.0001117153, 0.11381447, -29.027138],
+    [-0.000105624145, 0.106968895, -27.10739],
+    [-0.000100125006, 0.1007743, -25.366018],
+    [-9.509979e-5, 0.09510042, -23.767197],
+    [-9.045234e-5, 0.08984114, -22.281754],
+    [-0.00038031043, 0.41382703, -112.57712],
+    [-0.00028634627, 0.30962282, -83.75288],
+    [-0.00023448892, 0.25208208, -67.830345],
+    [-0.0002006705, 0.2145277, -57.431126],
+    [-0.00017647383, 0.18763088, -49.975803],
+    [-0.00015810186, 0.16718382, -44.30127],
+    [-0.00014355964, 0.15097612, -39.796696],
+    [-0.00013168548, 0.13772084, -36.106537],
+    [-0.000121750345, 0.12661047, -33.00778],
+    [-0.00011326975, 0.11710844, -30.352255],
+    [-0.00010590651, 0.10884139, -28.036903],
+    [-9.941676e-5, 0.101539336, -25.987213],
+    [-9.36182e-5, 0.09500046, -24.147497],
+    [-8.837021e-5, 0.08906913, -22.474823],
+    [-8.356115e-5, 0.08362179, -20.93511],
+    [-7.909981e-5, 0.078557506, -19.50052],
+    [-0.00035215516, 0.3846583, -105.052246],
+    [-0.00026496546, 0.28767514, -78.14725],
+    [-0.00021642802, 0.23365378, -63.154526],
+    [-0.00018462722, 0.19823088, -53.316288],
+    [-0.00016180538, 0.17278309, -46.241364],
+    [-0.00014443854, 0.15339378, -40.84401],
+    [-0.00013066667, 0.13799587, -36.551376],
+    [-0.00011940294, 0.12538184, -33.02891],
+    [-0.00010996356, 0.11479202, -30.066204],
+    [-0.000101893056, 0.10572047, -27.523157],
+    [-9.48738e-5, 0.097814456, -25.302116],
+    [-8.867562e-5, 0.09081838, -23.332346],
+    [-8.312606e-5, 0.08454081, -21.560867],
+    [-7.80918e-5, 0.07883369, -19.946732],
+    [-7.3466654e-5, 0.07357921, -18.457344],
+    [-6.916356e-5, 0.0686807, -17.065979],
+    [-0.00032787138, 0.35948622, -98.55519],
+    [-0.00024639934, 0.26860926, -73.2761],
+    [-0.00020068735, 0.21758856, -59.077496],
+    [-0.00017061067, 0.18399018, -49.720234],
+    [-0.00014896643, 0.15978566, -42.972187],
+    [-0.00013246186, 0.14130521, -37.81335],
+    [-0.000119351535, 0.12660383, -33.703274],
+    [-0.000108612454, 0.11454176, -30.32535],
+    [-9.959939e-5, 0.10440022, -27.479958],
+    [-9.188165e-5, 0.09569949, -25.033913],
+    [-8.51584e-5, 0.088104546, -22.894218],
+    [-7.921117e-5, 0.08137213, -20.993376],
+    [-7.387591e-5, 0.075319625, -19.280716],
+    [-6.902555e-5, 0.06980558, -17.717005],
+    [-6.455861e-5, 0.06471703, -16.270922],
+    [-6.0391543e-5, 0.059961013, -14.916688],
+    [-0.00030669302, 0.33752114, -92.88302],
+    [-0.00023011485, 0.2518804, -69.000694],
+    [-0.00018683827, 0.20345064, -55.488934],
+    [-0.00015825257, 0.17143276, -46.54898],
+    [-0.00013762865, 0.14830717, -40.08503],
+    [-0.00012187205, 0.13061634, -35.13371],
+    [-0.000109336026, 0.11652054, -31.182573],
+    [-9.9052646e-5, 0.10493858, -27.930538],
+    [-9.041001e-5, 0.0951871, -25.18735],
+    [-8.299891e-5, 0.086809106, -22.825823],
+    [-7.6532946e-5, 0.079484865, -20.75699],
+    [-7.080383e-5, 0.07298184, -18.916164],
+    [-6.565482e-5, 0.06712513, -17.254702],
+    [-6.0964245e-5, 0.061778903, -15.734839],
+    [-5.6634646e-5, 0.056834444, -14.326355],
+    [-5.258563e-5, 0.05220205, -13.004315],
+    [-0.00028804157, 0.31816658, -87.88244],
+    [-0.00021570318, 0.2370706, -65.2146],
+    [-0.00017454913, 0.19090284, -52.303474],
+    [-0.00014726642, 0.16026843, -43.729366],
+    [-0.00012753543, 0.13808855, -37.5148],
+    [-0.00011243398, 0.1210904, -32.745785],
+    [-0.00010040128, 0.107526086, -28.934328],
+    [-9.05174e-5, 0.09636564, -25.792952],
+    [-8.2199585e-5, 0.08695661, -23.139603],
+    [-7.505737e-5, 0.07886198, -20.852362],
+    [-6.881704e-5, 0.07177539, -18.845797],
+    [-6.327916e-5, 0.065473706, -17.05769],
+    [-5.829343e-5, 0.059788737, -15.441161],
+    [-5.3742868e-5, 0.05458965, -13.959751],
+    [-4.9533577e-5, 0.049771424, -12.5842],
+    [-4.5587865e-5, 0.045247167, -11.290303],
+    [-0.0002714717, 0.3009635, -83.43549],
+    [-0.00020284555, 0.22385393, -61.8348],
+    [-0.00016355941, 0.17968018, -49.45402],
+    [-0.00013742587, 0.15026788, -41.20359],
+    [-0.00011848327, 0.12892428, -35.209873],
+    [-0.00010396063, 0.112538956, -30.602365],
+    [-9.237272e-5, 0.099445015, -26.914688],
+    [-8.2841914e-5, 0.088657565, -23.871359],
+    [-7.4811185e-5, 0.07955163, -21.29761],
+    [-6.790662e-5, 0.07170773, -19.076174],
+    [-6.186567e-5, 0.064831376, -17.124743],
+    [-5.649676e-5, 0.058707725, -15.383286],
+    [-5.165521e-5, 0.053174537, -13.80649],
+    [-4.722823e-5, 0.048105378, -12.359042],
+    [-4.312508e-5, 0.04339852, -11.012542],
+    [-3.9270406e-5, 0.038969517, -9.743415],
+    [-0.00025663502, 0.28555253, -79.44984],
+    [-0.0001912895, 0.21197218, -58.795544],
+    [-0.00015366134, 0.16957128, -46.88705],
+    [-0.00012854957, 0.14124732, -38.92531],
+    [-0.00011030851, 0.12064897, -33.12869],
+    [-9.630104e-5, 0.104809925, -28.665346],
+    [-8.5109044e-5, 0.09213523, -25.088139],
+    [-7.589253e-5, 0.081680275, -22.132317],
+    [-6.811731e-5, 0.07284442, -19.629583],
+    [-6.14242e-5, 0.06522388, -17.466827],
+    [-5.556061e-5, 0.058534734, -15.564537],
+    [-5.0341925e-5, 0.052569512, -13.864629],
+    [-4.5628505e-5, 0.047171306, -12.323185],
+    [-4.1311265e-5, 0.04221756, -10.905912],
+    [-3.7302252e-5, 0.037609488, -9.5851755],
+    [-3.3528242e-5, 0.033264842, -8.337971],
+    [-0.00024325507, 0.2716486, -75.8522],
+    [-0.00018083259, 0.20121823, -56.044083],
+    [-0.00014468723, 0.1604055, -44.559357],
+    [-0.00012049047, 0.13305767, -36.85693],
+    [-0.00010287788, 0.11312797, -31.237404],
+    [-8.9331945e-5, 0.097779036, -26.903585],
+    [-7.849461e-5, 0.08548043, -23.425608],
+    [-6.955956e-5, 0.07532363, -20.548355],
+    [-6.2013074e-5, 0.06672986, -18.109352],
+    [-5.5509183e-5, 0.05930943, -15.999226],
+    [-4.980421e-5, 0.052787896, -14.140977],
+    [-4.47198e-5, 0.04696442, -12.478271],
+    [-4.012081e-5, 0.04168684, -10.96844],
+    [-3.5901467e-5, 0.036836114, -9.578111],
+    [-3.1976324e-5, 0.03231606, -8.280327],
+    [-2.8274064e-5, 0.028046427, -7.0526032],
+    [-0.00023110921, 0.25902203, -72.583595],
+    [-0.00017131022, 0.19142368, -53.537518],
+    [-0.00013649989, 0.15204316, -42.435562],
+    [-0.00011312767, 0.12557635, -34.967545],
+    [-9.6081465e-5, 0.106250145, -29.50809],
+    [-8.295137e-5, 0.09134343, -25.291304],
+    [-7.243342e-5, 0.07938399, -21.90294],
+    [-6.375171e-5, 0.069495924, -19.0966],
+    [-5.6410976e-5, 0.061120227, -16.715075],
+    [-5.0077175e-5, 0.0538799, -14.652374],
+    [-4.4514676e-5, 0.047509097, -12.833773],
+    [-3.9550756e-5, 0.04181294, -11.204514],
+    [-3.5054323e-5, 0.036643576, -9.7230625],
+    [-3.0922594e-5, 0.031885095, -8.356869],
+    [-2.707236e-5, 0.027443677, -7.0795937],
+    [-2.3434033e-5, 0.023240866, -5.869216],
+    [-0.00022001647, 0.24748608, -69.59602],
+    [-0.00016258727, 0.18245025, -51.240627],
+    [-0.000128986, 0.14436892, -40.486454],
+    [-0.00010636086, 0.11870158, -33.23148],
+    [-8.9827656e-5, 0.09992285, -27.91746],
+    [-7.7073964e-5, 0.08541704, -23.80693],
+    [-6.6844914e-5, 0.073764846, -20.499863],
+    [-5.8392146e-5, 0.064119995, -17.757792],
+    [-5.1237188e-5, 0.055941463, -15.428309],
+    [-4.5056797e-5, 0.048863813, -13.408502],
+    [-3.9622653e-5, 0.04262897, -11.625708],
+    [-3.476712e-5, 0.03704749, -10.026607],
+    [-3.0362782e-5, 0.031975396, -8.57069],
+    [-2.6309552e-5, 0.027299633, -7.2261543],
+    [-2.2526247e-5, 0.022928504, -5.9672165],
+    [-1.894483e-5, 0.018785179, -4.772276],
+    [-0.00020982773, 0.23688692, -66.84995],
+    [-0.00015455137, 0.1741829, -49.12411],
+    [-0.00012205075, 0.13728619, -38.687565],
+    [-0.00010010567, 0.11234779, -31.627157],
+    [-8.40391e-5, 0.09406784, -26.44584],
+    [-7.162743e-5, 0.079926886, -22.432169],
+    [-6.166055e-5, 0.06855397, -19.199114],
+    [-5.3415293e-5, 0.059129916, -16.515482],
+    [-4.642848e-5, 0.051130123, -14.233253],
+    [-4.0386727e-5, 0.04419972, -12.252328],
+    [-3.506837e-5, 0.03808772, -10.501935],
+    [-3.0310413e-5, 0.03260962, -8.930061],
+    [-2.5988766e-5, 0.027624983, -7.497131],
+    [-2.2005785e-5, 0.023023328, -6.1720195],
+    [-1.8282115e-5, 0.018714882, -4.9294457],
+    [-1.4751119e-5, 0.014624278, -3.7481904],
+    [-0.00020041899, 0.22709654, -64.31249],
+    [-0.00014710824, 0.1665251, -47.16338],
+    [-0.000115613984, 0.13071331, -37.018223],
+    [-9.429036e-5, 0.10644215, -30.136198],
+    [-7.864965e-5, 0.088618174, -25.076406],
+    [-6.654962e-5, 0.07481024, -21.151293],
+    [-5.682121e-5, 0.063691795, -17.985798],
+    [-4.876434e-5, 0.05446856, -15.355412],
+    [-4.1929867e-5, 0.046631016, -13.116154],
+    [-3.6013447e-5, 0.039833967, -11.170512],
+    [-3.0799492e-5, 0.03383294, -9.449444],
+    [-2.6129243e-5, 0.028447947, -7.902135],
+    [-2.1881637e-5, 0.023541756, -6.489859],
+    [-1.7961242e-5, 0.019006234, -5.1821084],
+    [-1.4290352e-5, 0.014753343, -3.9540565],
+    [-1.08035865e-5, 0.0107090315, -2.7848277],
+    [-0.00019168585, 0.21800722, -61.955967],
+    [-0.00014017768, 0.1593947, -45.337513],
+    [-0.00010960707, 0.124580376, -35.460705],
+    [-8.88531e-5, 0.10092191, -28.742777],
+    [-7.360199e-5, 0.083515815, -23.794565],
+    [-6.1786384e-5, 0.070012406, -19.95059],
+    [-5.227507e-5, 0.05912611, -16.846851],
+    [-4.4389253e-5, 0.0500856, -14.2650175],
+    [-3.7692695e-5, 0.042395268, -12.064838],
+    [-3.1889373e-5, 0.035718843, -10.151184],
+    [-2.6769276e-5, 0.029817823, -8.456606],
+    [-2.2177517e-5, 0.02451635, -6.9313884],
+    [-1.7995788e-5, 0.019680131, -5.53758],
+    [-1.4130657e-5, 0.015203147, -4.2452335],
+    [-1.0505898e-5, 0.010998922, -3.0299318],
+    [-7.0572487e-6, 0.0069945822, -1.8711057],
+    [-0.00018353973, 0.20952746, -59.756863],
+    [-0.00013369088, 0.15272124, -43.628555],
+    [-0.00010397053, 0.118826754, -33.99966],
+    [-8.373989e-5, 0.0957322, -27.433056],
+    [-6.884565e-5, 0.078709684, -22.587473],
+    [-5.7289733e-5, 0.06548493, -18.817907],
+    [-4.7975936e-5, 0.054810334, -15.770622],
+    [-4.0245144e-5, 0.04593586, -13.233023],
+    [-3.3673066e-5, 0.038378768, -11.068311],
+    [-2.7971348e-5, 0.031811032, -9.183567],
+    [-2.2935108e-5, 0.025999648, -7.512809],
+    [-1.8413004e-5, 0.02077255, -6.0073285],
+    [-1.4289231e-5, 0.015998108, -4.6298842],
+    [-1.0472164e-5, 0.011572222, -3.3510332],
+    [-6.8868894e-6, 0.0074098087, -2.146726],
+    [-3.4701304e-6, 0.0034390287, -0.99666435],
+    [-0.00017590458, 0.20157881, -57.69503],
+    [-0.0001275881, 0.14644353, -42.02092],
+    [-9.865224e-5, 0.113399304, -32.621628],
+    [-7.890293e-5, 0.0908245, -26.194788],
+    [-6.4335596e-5, 0.07415417, -21.443659],
+    [-5.3016476e-5, 0.061184157, -17.742298],
+    [-4.3881882e-5, 0.0507022, -14.746537],
+    [-3.6290992e-5, 0.041978054, -12.249118],
+    [-2.9830593e-5, 0.034540925, -10.116456],
+    [-2.421942e-5, 0.028070437, -8.257684],
+    [-1.9257308e-5, 0.022338638, -6.6081667],
+    [-1.4796173e-5, 0.017176952, -5.1201305],
+    [-1.0722472e-5, 0.012456165, -3.7569728],
+    [-6.9462076e-6, 0.008073907, -2.4897113],
+    [-3.3936267e-6, 0.0039463267, -1.2946194],
+    [-2.3156113e-9, 2.4995027e-6, -0.15164144],
+    [-0.0004178496, 0.45253724, -122.208374],
+    [-0.00031802565, 0.34221712, -91.80498],
+    [-0.0002638627, 0.28230187, -75.27869],
+    [-0.00022881657, 0.24348393, -64.557976],
+    [-0.0002038454, 0.21578053, -56.89412],
+    [-0.00018492693, 0.1947513, -51.064762],
+    [-0.00016996465, 0.17808203, -46.432976],
+    [-0.00015774232, 0.1644303, -42.629368],
+    [-0.00014749705, 0.15295413, -39.422287],
+    [-0.00013871954, 0.14309144, -36.6571],
+    [-0.0001310521, 0.13444743, -34.225193],
+    [-0.00012423117, 0.12673111, -32.0465],
+    [-0.00011805332, 0.11971785, -30.059204],
+    [-0.00011235351, 0.11322526, -28.213064],
+    [-0.000106990046, 0.107096605, -26.464884],
+    [-0.00010183243, 0.10118742, -24.774803],
+    [-0.00038366482, 0.41710937, -113.066505],
+    [-0.0002922302, 0.31572562, -85.03663],
+    [-0.00024207798, 0.26006153, -69.633446],
+    [-0.00020943402, 0.22378239, -59.581375],
+    [-0.00018608064, 0.197786, -52.366295],
+    [-0.00016833158, 0.17798968, -46.86078],
+    [-0.00015425462, 0.16225359, -42.47406],
+    [-0.00014272414, 0.14933133, -38.86209],
+    [-0.00013303141, 0.13843818, -35.8083],
+    [-0.00012470194, 0.12904876, -33.167706],
+    [-0.00011740116, 0.12079259, -30.83807],
+    [-0.0001108815, 0.11339548, -28.743721],
+    [-0.000104950814, 0.106644586, -26.825897],
+    [-9.945207e-5, 0.1003658, -25.03651],
+    [-9.424909e-5, 0.09440806, -23.333797],
+    [-8.9215166e-5, 0.08863064, -21.678818],
+    [-0.00035479417, 0.38718185, -105.34253],
+    [-0.00027023963, 0.29313985, -79.265976],
+    [-0.00022341477, 0.24100843, -64.797585],
+    [-0.00019277546, 0.20685141, -55.305267],
+    [-0.00017077739, 0.18228726, -48.46726],
+    [-0.00015401075, 0.1635282, -43.234562],
+    [-0.00014067933, 0.14857906, -39.054764],
+    [-0.00012973254, 0.13627303, -35.604862],
+    [-0.000120506986, 0.12587336, -32.680946],
+    [-0.00011255707, 0.11688521, -30.146065],
+    [-0.00010556746, 0.10895837, -27.903301],
+    [-9.9303914e-5, 0.10183266, -25.880648],
+    [-9.358383e-5, 0.09530516, -24.021929],
+    [-8.825668e-5, 0.08920855, -22.280798],
+    [-8.319095e-5, 0.08339647, -20.616692],
+    [-7.8262914e-5, 0.077731155, -18.991375],
+    [-0.00033005181, 0.3615285, -98.72045],
+    [-0.0002512438, 0.27362925, -74.28098],
+    [-0.00020722485, 0.22448173, -60.60341],
+    [-0.00017828433, 0.1921258, -51.586853],
+    [-0.00015743832, 0.1687809, -45.070286],
+    [-0.00014150873, 0.15090685, -40.070667],
+    [-0.00012881367, 0.13663027, -36.067924],
+    [-0.00011836601, 0.124851815, -32.756924],
+    [-0.000109540575, 0.11487523, -29.944448],
+    [-0.00010191619, 0.1062315, -27.500359],
+    [-9.5193885e-5, 0.098587714, -25.332277],
+    [-8.915075e-5, 0.09169554, -23.371305],
+    [-8.361205e-5, 0.085360326, -21.563408],
+    [-7.843299e-5, 0.07942064, -19.863762],
+    [-7.34858e-5, 0.07373396, -18.23276],
+    [-6.864926e-5, 0.068164915, -16.632746],
+    [-0.0003085763, 0.33925846, -92.97075],
+    [-0.00023464317, 0.25657916, -69.924736],
+    [-0.00019302368, 0.20998734, -56.925533],
+    [-0.00016554179, 0.17918016, -48.318672],
+    [-0.00014568745, 0.15688625, -42.079556],
+    [-0.0001304797, 0.13977642, -37.281456],
+    [-0.0001183343, 0.12608151, -33.432014],
+    [-0.00010831835, 0.11475986, -30.241404],
+    [-9.983942e-5, 0.10515002, -27.52561],
+    [-9.249731e-5, 0.09680518, -25.160353],
+    [-8.6007094e-5, 0.08940723, -23.057152],
+    [-8.015553e-5, 0.08271803, -21.14977],
+    [-7.477472e-5, 0.07655006, -19.386032],
+    [-6.9724556e-5, 0.070746735, -17.722357],
+    [-6.488063e-5, 0.065168925, -16.119987],
+    [-6.012388e-5, 0.059683308, -14.541752],
+    [-0.00028972578, 0.31970826, -87.922485],
+    [-0.0002199837, 0.24152404, -66.07841],
+    [-0.00018044119, 0.19714786, -53.668194],
+    [-0.000154226, 0.16768761, -45.41814],
+    [-0.00013523463, 0.14630954, -39.42112],
+    [-0.00012065599, 0.1298666, -34.799088],
+    [-0.00010899017, 0.1166798, -31.08371],
+    [-9.9351324e-5, 0.10575762, -27.99848],
+    [-9.1175374e-5, 0.096468754, -25.36737],
+    [-8.4080326e-5, 0.08838569, -23.071198],
+    [-7.779343e-5, 0.081203185, -21.02487],
+    [-7.210983e-5, 0.07469194, -19.164461],
+    [-6.6867484e-5, 0.06867052, -17.43938],
+    [-6.193048e-5, 0.06298669, -15.80716],
+    [-5.7177156e-5, 0.057504065, -14.229732],
+    [-5.249029e-5, 0.052091103, -12.670332],
+    [-0.00027301177, 0.3023727, -83.44554],
+    [-0.0002069149, 0.2281045, -62.650253],
+    [-0.00016918925, 0.18566942, -50.75683],
+    [-0.00014408509, 0.15739225, -42.820618],
+    [-0.0001258518, 0.1368198, -37.03683],
+    [-0.000111826404, 0.12096407, -32.570023],
+    [-0.00010058273, 0.10822505, -28.972927],
+    [-9.127607e-5, 0.09765513, -25.98072],
+    [-8.3367224e-5, 0.08864948, -23.424397],
+    [-7.649013e-5, 0.080797516, -21.189247],
+    [-7.0382695e-5, 0.07380526, -19.193151],
+    [-6.4847416e-5, 0.067451134, -17.374208],
+    [-5.9727383e-5, 0.061559092, -15.683224],
+    [-5.48902e-5, 0.055980496, -14.078653],
+    [-5.0216746e-5, 0.05058152, -12.523057],
+    [-4.559142e-5, 0.04523214, -10.980032],
+    [-0.0002580558, 0.2868604, -79.43913],
+    [-0.00019516157, 0.21603833, -59.568264],
+    [-0.00015903993, 0.17531951, -48.132446],
+    [-0.00013491866, 0.14809044, -40.474663],
+    [-0.00011735675, 0.1282324, -34.88023],
+    [-0.00010382164, 0.11289777, -30.551336],
+    [-9.295229e-5, 0.100556284, -27.059374],
+    [-8.394033e-5, 0.09029923, -24.14987],
+    [-7.626853e-5, 0.08154518, -21.660044],
+    [-6.958493e-5, 0.07389854, -19.47915],
+    [-6.363686e-5, 0.06707529, -17.527697],
+    [-5.8233243e-5, 0.060860656, -15.745564],
+    [-5.32217e-5, 0.055083297, -14.084781],
+    [-4.847299e-5, 0.049597822, -12.504631],
+    [-4.387003e-5, 0.044272438, -10.968179],
+    [-3.929875e-5, 0.038978577, -9.439353],
+    [-0.00024455905, 0.2728625, -75.823715],
+    [-0.0001845037, 0.20509991, -56.774857],
+    [-0.00014980955, 0.16591074, -45.747505],
+    [-0.00012656425, 0.13961709, -38.338573],
+    [-0.00010960101, 0.12039701, -32.91346],
+    [-9.650323e-5, 0.10552778, -28.707912],
+    [-8.59678e-5, 0.093541384, -25.309975],
+    [-7.721874e-5, 0.08356377, -22.474432],
+    [-6.975843e-5, 0.07503444, -20.044058],
+    [-6.3247455e-5, 0.06757113, -17.911654],
+    [-5.744142e-5, 0.060898624, -16.00004],
+    [-5.215512e-5, 0.054808307, -14.250716],
+    [-4.7240024e-5, 0.04913286, -12.616757],
+    [-4.256972e-5, 0.043729812, -11.058191],
+    [-3.802894e-5, 0.038469147, -9.53852],
+    [-3.350483e-5, 0.033223458, -8.021932],
+    [-0.00023228237, 0.26013163, -72.535614],
+    [-0.000174763, 0.19510639, -54.223385],
+    [-0.00014134799, 0.15728997, -43.563164],
+    [-0.000118888194, 0.13183637, -36.378048],
+    [-0.0001024618, 0.11318919, -31.105211],
+    [-8.9756075e-5, 0.0987378, -27.010565],
+    [-7.951984e-5, 0.08707003, -23.697123],
+    [-7.100631e-5, 0.0773431, -20.928017],
+    [-6.373533e-5, 0.06901519, -18.551004],
+    [-5.7378744e-5, 0.061716054, -16.462069],
+    [-5.1699633e-5, 0.055178374, -14.58612],
+    [-4.6517893e-5, 0.04919892, -12.866072],
+    [-4.1688516e-5, 0.043614056, -11.255953],
+    [-3.7087426e-5, 0.038283687, -9.716399],
+    [-3.2601016e-5, 0.03307947, -8.21132],
+    [-2.8117496e-5, 0.02787497, -6.705124],
+    [-0.00022103103, 0.24846657, -69.52304],
+    [-0.00016579233, 0.18590689, -51.875362],
+    [-0.0001335302, 0.1493296, -41.547062],
+    [-0.0001117783, 0.12463426, -34.564297],
+    [-9.583536e-5, 0.10650383, -29.429035],
+    [-8.348235e-5, 0.09242897, -25.43449],
+    [-7.351501e-5, 0.08104801, -22.197235],
+    [-6.521286e-5, 0.07154644, -19.48796],
+    [-5.811157e-5, 0.06339938, -17.15894],
+    [-5.189313e-5, 0.05624736, -15.109017],
+    [-4.63273e-5, 0.04983018, -13.26499],
+    [-4.123851e-5, 0.04394939, -11.571025],
+    [-3.6484824e-5, 0.038444556, -9.981983],
+    [-3.1944302e-5, 0.033177774, -8.459056],
+    [-2.7504624e-5, 0.028021967, -6.9664607],
+    [-2.305486e-5, 0.022851478, -5.468779],
+    [-0.00021064417, 0.23770095, -66.74319],
+    [-0.0001574683, 0.17737488, -49.698524],
+    [-0.00012624999, 0.14192137, -39.67175],
+    [-0.00010513845, 0.11791309, -32.872658],
+    [-8.963203e-5, 0.10025008, -27.862076],
+    [-7.759685e-5, 0.08651515, -23.958088],
+    [-6.787131e-5, 0.07539266, -20.789635],
+    [-5.9758815e-5, 0.06609373, -18.134274],
+    [-5.28094e-5, 0.058108866, -15.848392],
+    [-4.6714173e-5, 0.051088355, -13.833426],
+    [-4.1248928e-5, 0.0447784, -12.017867],
+    [-3.6242112e-5, 0.038984794, -10.346997],
+    [-3.1554508e-5, 0.033549935, -8.776415],
+    [-2.7065873e-5, 0.028337665, -7.2677526],
+    [-2.2665035e-5, 0.023222024, -5.7854996],
+    [-1.8241675e-5, 0.018077897, -4.29435],
+    [-0.00020098666, 0.22769521, -64.16017],
+    [-0.00014968541, 0.16940224, -47.6653],
+    [-0.00011941551, 0.13497156, -37.913475],
+    [-9.888433e-5, 0.11158719, -31.281513],
+    [-8.377229e-5, 0.09434741, -26.38408],
+    [-7.202329e-5, 0.0809193, -22.562035],
+    [-6.251472e-5, 0.07002934, -19.455647],
+    [-5.4571727e-5, 0.06091203, -16.848757],
+    [-4.775744e-5, 0.05307193, -14.601501],
+    [-4.177123e-5, 0.046168163, -12.617672],
+    [-3.6394285e-5, 0.039952658, -10.827277],
+    [-3.145861e-5, 0.03423497, -9.176592],
+    [-2.6827292e-5, 0.028859911, -7.6218405],
+    [-2.238152e-5, 0.02369278, -6.125025],
+    [-1.80109e-5, 0.018608367, -4.650812],
+    [-1.3605431e-5, 0.013481811, -3.163938],
+    [-0.0001919426, 0.21832958, -61.74319],
+    [-0.00014235117, 0.16189417, -45.75155],
+    [-0.00011294463, 0.12839645, -36.251038],
+    [-9.293945e-5, 0.10557887, -29.771275],
+    [-7.818304e-5, 0.08872176, -24.976439],
+    [-6.6690656e-5, 0.07556967, -21.228346],
+    [-5.7375506e-5, 0.06488775, -18.177698],
+    [-4.958266e-5, 0.055931978, -15.614103],
+    [-4.2887183e-5, 0.048219744, -13.401125],
+    [-3.6995916e-5, 0.041418154, -11.444692],
+    [-3.1694864e-5, 0.035284277, -9.676172],
+    [-2.6819116e-5, 0.029630937, -8.042704],
+    [-2.2233662e-5, 0.024304926, -6.501028],
+    [-1.782081e-5, 0.019172681, -5.0134344],
+    [-1.3470518e-5, 0.014109317, -3.5446596],
+    [-9.072782e-6, 0.008989897, -2.0594025],
+    [-0.00018341088, 0.2094997, -59.465454],
+    [-0.00013538275, 0.15476584, -43.935677],
+    [-0.000106762105, 0.122119375, -34.665028],
+    [-8.723235e-5, 0.09981559, -28.323658],
+    [-7.2794726e-5, 0.083302766, -23.621473],
+    [-6.153035e-5, 0.07039698, -19.939678],
+    [-5.238543e-5, 0.059899114, -16.93861],
+    [-4.4723347e-5, 0.051084902, -14.413192],
+    [-3.8130056e-5, 0.04348343, -12.230124],
+    [-3.231915e-5, 0.036769036, -10.297266],
+    [-2.708086e-5, 0.030703323, -8.5471945],
+    [-2.2252909e-5, 0.025101889, -6.927779],
+    [-1.7701796e-5, 0.019813115, -5.396176],
+    [-1.3310417e-5, 0.014704029, -3.914824],
+    [-8.968881e-6, 0.009649862, -2.448477],
+    [-4.566581e-6, 0.0045249984, -0.9616453],
+    [-0.00017530081, 0.20111202, -57.30295],
+    [-0.0001287032, 0.14793852, -42.19766],
+    [-0.000100796016, 0.116067104, -33.136932],
+    [-8.169299e-5, 0.094226316, -26.920778],
+    [-6.7537774e-5, 0.078020036, -22.301525],
+    [-5.6472516e-5, 0.06533075, -18.678389],
+    [-4.747396e-5, 0.05499242, -15.720652],
+    [-3.9922357e-5, 0.04629899, -13.228131],
+    [-3.3413588e-5, 0.03879023, -11.070402],
+    [-2.7667262e-5, 0.032146953, -9.157055],
+    [-2.247736e-5, 0.026134746, -7.42173],
+    [-1.7683687e-5, 0.02057145, -5.812892],
+    [-1.3153741e-5, 0.015306484, -4.28797],
+    [-8.770775e-6, 0.0102072405, -2.809492],
+    [-4.424395e-6, 0.0051483884, -1.3420612],
+    [-3.0947735e-9, 3.3416702e-6, 0.15007572],
+    [-0.00045657918, 0.4939974, -132.96762],
+    [-0.00035047543, 0.37680563, -100.70421],
+    [-0.0002939035, 0.3142238, -83.4479],
+    [-0.0002576616, 0.27404857, -72.345795],
+    [-0.00023203013, 0.245561, -64.45147],
+    [-0.00021273547, 0.22404811, -58.46961],
+    [-0.0001975635, 0.20706809, -53.729115],
+    [-0.0001852321, 0.19320676, -49.84132],
+    [-0.00017493402, 0.18157353, -46.561382],
+    [-0.00016612465, 0.17156717, -43.72393],
+    [-0.00015841181, 0.16275434, -41.20965],
+    [-0.0001514932, 0.15480049, -38.926285],
+    [-0.00014511551, 0.14742477, -36.796227],
+    [-0.00013904391, 0.14036609, -34.74713],
+    [-0.00013303144, 0.1333491, -32.702473],
+    [-0.00012677873, 0.12603925, -30.569033],
+    [-0.00041772827, 0.4536674, -122.54682],
+    [-0.00032128274, 0.346779, -93.02341],
+    [-0.0002691993, 0.28896463, -77.02916],
+    [-0.0002355942, 0.25158432, -66.66534],
+    [-0.00021170627, 0.22494371, -59.258644],
+    [-0.00019364666, 0.20473981, -53.62262],
+    [-0.00017938738, 0.18872865, -49.13859],
+    [-0.00016774799, 0.1756039, -45.44639],
+    [-0.00015798172, 0.16453892, -42.31803],
+    [-0.000149582, 0.15497272, -39.598736],
+    [-0.00014218163, 0.1464982, -37.17608],
+    [-0.00013549477, 0.13879812, -34.962322],
+    [-0.00012927888, 0.13160267, -32.882694],
+    [-0.0001233051, 0.124656744, -30.866316],
+    [-0.000117327974, 0.1176859, -28.836735],
+    [-0.000111044195, 0.11035029, -26.699028],
+    [-0.00038540125, 0.42011762, -113.87987],
+    [-0.0002967126, 0.3215169, -86.563774],
+    [-0.0002482866, 0.26759285, -71.60081],
+    [-0.00021684704, 0.2325109, -61.84491],
+    [-0.00019439912, 0.20739757, -54.84191],
+    [-0.0001773644, 0.18828112, -49.493492],
+    [-0.00016386602, 0.17307843, -45.223644],
+    [-0.00015280645, 0.16057119, -41.695484],
+    [-0.00014348829, 0.1499851, -38.694862],
+    [-0.00013543622, 0.14079215, -36.07567],
+    [-0.0001283034, 0.13260674, -33.73113],
+    [-0.00012181758, 0.12512577, -31.577179],
+    [-0.0001157448, 0.118088305, -29.541317],
+    [-0.00010986093, 0.11124377, -27.553753],
+    [-0.00010392116, 0.10431779, -25.53787],
+    [-9.761832e-5, 0.096965306, -23.397064],
+    [-0.00035802036, 0.3917097, -106.54306],
+    [-0.00027569887, 0.29992178, -81.04415],
+    [-0.00023031126, 0.24923383, -66.9402],
+    [-0.00020068238, 0.21607608, -57.69387],
+    [-0.00017944418, 0.19224723, -51.03077],
+    [-0.00016327393, 0.17404895, -45.92541],
+    [-0.0001504199, 0.15953141, -41.837208],
+    [-0.00013985341, 0.14754958, -38.44869],
+    [-0.00013091823, 0.13737279, -35.557224],
+    [-0.00012316501, 0.1285005, -33.023956],
+    [-0.00011626397, 0.120565064, -30.746807],
+    [-0.00010995403, 0.11327503, -28.644747],
+    [-0.00010400833, 0.106376715, -26.647116],
+    [-9.8206394e-5, 0.099622995, -24.68492],
+    [-9.230371e-5, 0.092739165, -22.68127],
+    [-8.598935e-5, 0.085375056, -20.537918],
+    [-0.00033447056, 0.36728585, -100.23709],
+    [-0.00025747187, 0.28120136, -76.26164],
+    [-0.00021464977, 0.23324938, -62.88489],
+    [-0.00018655816, 0.20172714, -54.07219],
+    [-0.00016635103, 0.17899425, -47.699398],
+    [-0.00015092014, 0.16158196, -42.802303],
+    [-0.00013861907, 0.14765286, -38.870213],
+    [-0.00012847732, 0.13612384, -35.602074],
+    [-0.000119873424, 0.12630111, -32.80503],
+    [-0.000112380054, 0.117707446, -30.346361],
+    [-0.00010568179, 0.10999031, -28.12795],
+    [-9.9527e-5, 0.10286809, -26.071318],
+    [-9.3694674e-5, 0.096093036, -24.1073],
+    [-8.796723e-5, 0.08942068, -22.167492],
+    [-8.210012e-5, 0.08257564, -20.174646],
+    [-7.577895e-5, 0.07520297, -18.02896],
+    [-0.00031394008, 0.34600347, -94.74421],
+    [-0.0002414608, 0.26476815, -72.06585],
+    [-0.00020083535, 0.21916163, -59.31328],
+    [-0.00017406594, 0.18904765, -50.874374],
+    [-0.00015474854, 0.16726147, -44.752617],
+    [-0.00013995753, 0.15053001, -40.03608],
+    [-0.00012813645, 0.13711207, -36.23969],
+    [-0.000118364565, 0.1259775, -33.076466],
+    [-0.00011005023, 0.11646427, -30.361954],
+    [-0.00010278497, 0.108115055, -27.968683],
+    [-9.626567e-5, 0.10059028, -25.801937],
+    [-9.024869e-5, 0.09361664, -23.785381],
+    [-8.4517946e-5, 0.08695132, -21.85109],
+    [-7.885811e-5, 0.08035188, -19.931076],
+    [-7.302445e-5, 0.073542066, -17.947693],
+    [-6.669932e-5, 0.06616249, -15.79968],
+    [-0.00029582193, 0.32723275, -89.90171],
+    [-0.00022723146, 0.25017542, -68.342415],
+    [-0.00018850953, 0.20660374, -56.13204],
+    [-0.0001628901, 0.17771593, -48.01897],
+    [-0.00014434863, 0.15675613, -42.11657],
+    [-0.00013011698, 0.1406204, -37.558155],
+    [-0.00011871656, 0.12765074, -33.88086],
+    [-0.00010926973, 0.11686303, -30.809933],
+    [-0.000101210695, 0.10762268, -28.168194],
+    [-9.414731e-5, 0.099489704, -25.832743],
+    [-8.778708e-5, 0.09213566, -23.711779],
+    [-8.189321e-5, 0.08529435, -21.730806],
+    [-7.6253746e-5, 0.0787271, -19.822918],
+    [-7.0655165e-5, 0.07219296, -17.92042],
+    [-6.485227e-5, 0.06541458, -15.945203],
+    [-5.85245e-5, 0.05802851, -13.794665],
+    [-0.0002796518, 0.310492, -85.585205],
+    [-0.0002144463, 0.23707592, -65.00253],
+    [-0.0001773909, 0.1952878, -53.26796],
+    [-0.00015278115, 0.1674777, -45.44161],
+    [-0.00013492236, 0.14724572, -39.732605],
+    [-0.00012118379, 0.13163556, -35.31384],
+    [-0.00011015491, 0.11906218, -31.741909],
+    [-0.000100995865, 0.10858173, -28.75279],
+    [-9.316347e-5, 0.099583715, -26.175688],
+    [-8.627983e-5, 0.091643214, -23.891685],
+    [-8.00617e-5, 0.08444152, -21.811525],
+    [-7.4278185e-5, 0.07771853, -19.862284],
+    [-6.872078e-5, 0.071238995, -17.977888],
+    [-6.317735e-5, 0.06476303, -16.090824],
+    [-5.740202e-5, 0.058012012, -14.122482],
+    [-5.107154e-5, 0.050618693, -11.969004],
+    [-0.00026506637, 0.29540446, -81.69749],
+    [-0.0002028367, 0.22519341, -61.975548],
+    [-0.00016725308, 0.18498224, -50.6622],
+    [-0.0001435368, 0.15812688, -43.090157],
+    [-0.00012628286, 0.13854046, -37.552895],
+    [-0.000112981725, 0.123397075, -33.25829],
+    [-0.00010228297, 0.11117611, -29.780159],
+    [-9.338006e-5, 0.10096936, -26.863976],
+    [-8.574979e-5, 0.09218757, -24.344582],
+    [-7.902681e-5, 0.08441912, -22.10657],
+    [-7.293594e-5, 0.077353776, -20.062904],
+    [-6.725136e-5, 0.07073667, -18.142],
+    [-6.1767474e-5, 0.06433537, -16.278465],
+    [-5.627301e-5, 0.057910584, -14.404842],
+    [-5.05213e-5, 0.051182244, -12.441989],
+    [-4.418641e-5, 0.04377942, -10.284831],
+    [-0.00025177468, 0.28166863, -78.160774],
+    [-0.00019218339, 0.21430263, -59.203907],
+    [-0.00015790887, 0.17549576, -48.26616],
+    [-0.00013498797, 0.14949134, -40.921085],
+    [-0.00011827268, 0.13048054, -35.53717],
+    [-0.00010536124, 0.11575355, -31.353485],
+    [-9.49568e-5, 0.10384718, -27.959211],
+    [-8.628247e-5, 0.093884975, -25.108282],
+    [-7.88328e-5, 0.08529658, -22.640558],
+    [-7.225345e-5, 0.07768204, -20.44372],
+    [-6.6276414e-5, 0.07073866, -18.432697],
+    [-6.0680137e-5, 0.06421594, -16.537027],
+    [-5.526132e-5, 0.057883672, -14.691842],
+    [-4.9809238e-5, 0.051502775, -12.829643],
+    [-4.4076016e-5, 0.044791374, -10.870666],
+    [-3.7732898e-5, 0.03737475, -8.7086115],
+    [-0.00023953833, 0.26903772, -74.91148],
+    [-0.00018230276, 0.20421502, -56.63948],
+    [-0.00014919884, 0.1666655, -46.03852],
+    [-0.00012698869, 0.1414226, -38.896893],
+    [-0.00011075424, 0.122926496, -33.650345],
+    [-9.819042e-5, 0.10857157, -29.565964],
+    [-8.804837e-5, 0.09694621, -26.246744],
+    [-7.9577825e-5, 0.08720243, -23.454218],
+    [-7.228904e-5, 0.07878663, -21.032701],
+    [-6.583763e-5, 0.07130941, -18.872663],
+    [-5.99616e-5, 0.06447437, -16.89068],
+    [-5.4443044e-5, 0.058034748, -15.017238],
+    [-4.9080463e-5, 0.051762026, -13.187858],
+    [-4.3663134e-5, 0.045416784, -11.334865],
+    [-3.794142e-5, 0.038714793, -9.377731],
+    [-3.158327e-5, 0.0312771, -7.208844],
+    [-0.00022815619, 0.25730366, -71.89609],
+    [-0.00017303525, 0.19476695, -54.24055],
+    [-0.0001409813, 0.15834697, -43.942677],
+    [-0.00011940685, 0.13378645, -36.983727],
+    [-0.000103601145, 0.11575028, -31.860235],
+    [-9.134644e-5, 0.10172705, -27.864632],
+    [-8.143709e-5, 0.09035163, -24.612377],
+    [-7.3146875e-5, 0.08080175, -21.871874],
+    [-6.6000175e-5, 0.072538875, -19.491446],
+    [-5.966111e-5, 0.06518264, -17.363949],
+    [-5.387309e-5, 0.0584423, -15.407439],
+    [-4.8421127e-5, 0.05207406, -13.553154],
+    [-4.3104694e-5, 0.045850236, -11.736782],
+    [-3.771241e-5, 0.0395304, -9.890295],
+    [-3.19923e-5, 0.032827377, -7.932268],
+    [-2.5608068e-5, 0.025357068, -5.753546],
+    [-0.00021745251, 0.24628499, -69.06795],
+    [-0.00016423617, 0.18581031, -51.969425],
+    [-0.00013312392, 0.1504054, -41.944523],
+    [-0.00011211605, 0.12645467, -35.149292],
+    [-9.668993e-5, 0.10882705, -30.135502],
+    [-8.470716e-5, 0.09509681, -26.218666],
+    [-7.500139e-5, 0.083941095, -23.02558],
+    [-6.686798e-5, 0.07456072, -20.330818],
+    [-5.9843875e-5, 0.066430606, -17.986292],
+    [-5.3600772e-5, 0.059178397, -15.886976],
+    [-4.7886446e-5, 0.052517924, -13.952125],
+    [-4.2488027e-5, 0.04620754, -12.113509],
+    [-3.720523e-5, 0.04001961, -10.306787],
+    [-3.1825093e-5, 0.03371176, -8.463342],
+    [-2.6092057e-5, 0.026992613, -6.5005264],
+    [-1.9663918e-5, 0.019471252, -4.3072414],
+    [-0.00020726684, 0.2358164, -66.384705],
+    [-0.00015576667, 0.17720321, -49.790062],
+    [-0.0001254949, 0.14270686, -40.01025],
+    [-0.00010498643, 0.11929575, -33.360542],
+    [-8.989051e-5, 0.10202547, -28.443264],
+    [-7.8141275e-5, 0.08854861, -24.595043],
+    [-6.860813e-5, 0.07758079, -21.453012],
+    [-6.060589e-5, 0.06834367, -18.797327],
+    [-5.368266e-5, 0.060324103, -16.48307],
+    [-4.7516554e-5, 0.053156544, -14.407026],
+    [-4.1858726e-5, 0.046558365, -12.489379],
+    [-3.649745e-5, 0.040289033, -10.6621685],
+    [-3.1231597e-5, 0.034119863, -8.860734],
+    [-2.5845271e-5, 0.027805133, -7.015516],
+    [-2.0077541e-5, 0.021047441, -5.04219],
+    [-1.3577626e-5, 0.01344636, -2.8270342],
+    [-0.00019744322, 0.22573766, -63.805386],
+    [-0.00014748341, 0.16879955, -47.66545],
+    [-0.000117951684, 0.13510668, -38.103424],
+    [-9.7872326e-5, 0.1121625, -31.580547],
+    [-8.3052764e-5, 0.09519427, -26.745695],
+    [-7.149371e-5, 0.08192656, -22.9549],
+    [-6.209728e-5, 0.07111014, -19.854744],
+    [-5.419573e-5, 0.061985444, -17.230402],
+    [-4.7346875e-5, 0.054049656, -14.939723],
+    [-4.123398e-5, 0.046942733, -12.880955],
+    [-3.561038e-5, 0.040384345, -10.974882],
+    [-3.0264191e-5, 0.034133703, -9.153466],
+    [-2.4991903e-5, 0.027959498, -7.351325],
+    [-1.9572768e-5, 0.02161068, -5.497446],
+    [-1.3737885e-5, 0.014781168, -3.50516],
+    [-7.124139e-6, 0.0070572128, -1.2571653],
+    [-0.00018781939, 0.21588252, -61.28763],
+    [-0.0001392258, 0.16043606, -45.554348],
+    [-0.00011032578, 0.12743446, -36.181217],
+    [-9.059476e-5, 0.10487445, -29.764143],
+    [-7.598672e-5, 0.08814241, -24.995134],
+    [-6.456407e-5, 0.07502956, -21.2482],
+    [-5.5258806e-5, 0.064318806, -18.17855],
+    [-4.7418718e-5, 0.055267252, -15.5758295],
+    [-4.0609644e-5, 0.047380686, -13.300196],
+    [-3.451848e-5, 0.040302943, -11.250934],
+    [-2.889915e-5, 0.033754323, -9.349004],
+    [-2.3537832e-5, 0.027491964, -7.525816],
+    [-1.8226567e-5, 0.021279765, -5.7147107],
+    [-1.2737224e-5, 0.01485876, -3.8425636],
+    [-6.78985e-6, 0.007911026, -1.8195531],
+    [-5.172835e-9, 5.582047e-6, 0.47618997],
+    [-0.0005325389, 0.5755807, -154.47504],
+    [-0.00040992495, 0.4403165, -117.30335],
+    [-0.00034668553, 0.3703863, -98.03671],
+    [-0.00030704078, 0.32640645, -85.87722],
+    [-0.000279529, 0.2957589, -77.365135],
+    [-0.00025921172, 0.27300605, -71.00934],
+    [-0.0002435623, 0.2553648, -66.04639],
+    [-0.00023112839, 0.2412343, -62.036804],
+    [-0.0002209972, 0.22960703, -58.703617],
+    [-0.00021254714, 0.2197954, -55.85734],
+    [-0.00020531764, 0.21128821, -53.35657],
+    [-0.00019892961, 0.20366256, -51.08372],
+    [-0.00019302241, 0.19651408, -48.925713],
+    [-0.0001871841, 0.18937866, -46.75212],
+    [-0.00018083023, 0.18159632, -44.377045],
+    [-0.00017292402, 0.17199576, -41.470413],
+    [-0.00048421219, 0.52528393, -141.451],
+    [-0.00037416143, 0.40344313, -107.85291],
+    [-0.00031647985, 0.33942991, -90.15586],
+    [-0.0002799868, 0.29880235, -78.88499],
+    [-0.00025448622, 0.27029642, -70.941475],
+    [-0.00023553478, 0.24900256, -64.9745],
+    [-0.0002208403, 0.23238724, -60.286827],
+    [-0.00020907618, 0.21898326, -56.474342],
+    [-0.00019940245, 0.20786044, -53.280556],
+    [-0.00019124192, 0.19837831, -50.52845],
+    [-0.00018416133, 0.19005461, -48.084282],
+    [-0.00017779737, 0.18248314, -45.83488],
+    [-0.00017179588, 0.1752661, -43.66885],
+    [-0.00016574096, 0.16793439, -41.45425],
+    [-0.00015902777, 0.1598043, -38.998367],
+    [-0.00015056205, 0.14963776, -35.9519],
+    [-0.00044499864, 0.48450673, -130.89978],
+    [-0.00034469864, 0.37309676, -100.082214],
+    [-0.00029141817, 0.3137741, -83.63083],
+    [-0.00025745004, 0.27583468, -73.07324],
+    [-0.00023357608, 0.24906214, -65.59027],
+    [-0.00021573955, 0.22895977, -59.940987],
+    [-0.00020183348, 0.21319138, -55.480495],
+    [-0.00019063093, 0.20039575, -51.8328],
+    [-0.00018135014, 0.18970454, -48.75777],
+    [-0.00017344953, 0.18051504, -46.08838],
+    [-0.00016651793, 0.17236835, -43.697044],
+    [-0.00016020464, 0.16487148, -41.47405],
+    [-0.00015416076, 0.15763174, -39.30924],
+    [-0.00014796609, 0.15017444, -37.068943],
+    [-0.000140997, 0.14179358, -34.553913],
+    [-0.00013210594, 0.13118838, -31.39658],
+    [-0.000412445, 0.45068812, -122.15636],
+    [-0.00031992872, 0.34761393, -93.56344],
+    [-0.0002702194, 0.2921008, -78.1248],
+    [-0.0002383199, 0.25636548, -68.15256],
+    [-0.00021578913, 0.23102516, -61.050373],
+    [-0.00019888092, 0.2119152, -55.66577],
+    [-0.00018563762, 0.19685863, -51.39617],
+    [-0.00017491334, 0.18458049, -47.888462],
+    [-0.0001659738, 0.1742629, -44.915855],
+    [-0.00015830663, 0.16533406, -42.319508],
+    [-0.00015151873, 0.15735415, -39.97685],
+    [-0.00014526978, 0.14994092, -37.78094],
+    [-0.00013921461, 0.14270511, -35.6224],
+    [-0.00013292882, 0.13516645, -33.365788],
+    [-0.00012577107, 0.12659755, -30.805498],
+    [-0.00011654534, 0.11564, -27.556997],
+    [-0.00038489394, 0.42209917, -114.77197],
+    [-0.00029873528, 0.32584006, -87.99984],
+    [-0.00025198242, 0.27348322, -73.40104],
+    [-0.00022180946, 0.2395887, -63.91803],
+    [-0.0002004075, 0.21545261, -57.136192],
+    [-0.00018428497, 0.19718276, -51.975704],
+    [-0.00017160745, 0.1827337, -47.868973],
+    [-0.00016129612, 0.17090188, -44.481834],
+    [-0.00015265614, 0.1609113, -41.5986],
+    [-0.00014519945, 0.15221596, -39.06721],
+    [-0.00013854798, 0.14439178, -36.769253],
+    [-0.00013236997, 0.13706528, -34.600018],
+    [-0.00012632302, 0.12984957, -32.450596],
+    [-0.00011997833, 0.122258484, -30.18367],
+    [-0.00011267796, 0.11354446, -27.587587],
+    [-0.000103181876, 0.10229607, -24.262062],
+    [-0.00036118118, 0.39752594, -108.43176],
+    [-0.00028031494, 0.30694476, -83.17809],
+    [-0.00023605104, 0.2572469, -69.287384],
+    [-0.00020734171, 0.22491372, -60.219597],
+    [-0.00018690247, 0.20180506, -53.711197],
+    [-0.0001714543, 0.18425639, -48.74313],
+    [-0.00015926617, 0.17033266, -44.77719],
+    [-0.0001493157, 0.15889044, -41.49518],
+    [-0.00014094132, 0.14918908, -38.690754],
+    [-0.0001336755, 0.14070427, -36.217598],
+    [-0.00012715282, 0.1330252, -33.960747],
+    [-0.00012104859, 0.1257856, -31.817318],
+    [-0.00011502234, 0.118599914, -29.678593],
+    [-0.0001086409, 0.110976174, -27.405384],
+    [-0.00010123065, 0.102147475, -24.780207],
+    [-9.151189e-5, 0.090654224, -21.388346],
+    [-0.0003404604, 0.37608662, -102.90717],
+    [-0.0002640707, 0.2903115, -78.939926],
+    [-0.0002219321, 0.24288534, -65.6546],
+    [-0.00019447994, 0.21189378, -56.943832],
+    [-0.00017487197, 0.18967253, -50.671703],
+    [-0.0001600095, 0.17275026, -45.8708],
+    [-0.00014824944, 0.15928593, -42.02795],
+    [-0.0001386176, 0.14818741, -38.838573],
+    [-0.00013048082, 0.1387442, -36.10433],
+    [-0.00012338914, 0.13045059, -33.68378],
+    [-0.00011698786, 0.12290692, -31.464874],
+    [-0.000110958164, 0.115752816, -29.346197],
+    [-0.00010496087, 0.10860346, -27.219055],
+    [-9.855833e-5, 0.10096084, -24.942324],
+    [-9.106206e-5, 0.092039704, -22.292946],
+    [-8.1156635e-5, 0.08033662, -18.842972],
+    [-0.00032209614, 0.35711983, -98.02695],
+    [-0.00024954448, 0.2754676, -75.16413],
+    [-0.00020924247, 0.23000535, -62.40252],
+    [-0.00018288198, 0.20017925, -54.002052],
+    [-0.00016399914, 0.1787323, -47.93618],
+    [-0.00014965024, 0.16235937, -43.28191],
+    [-0.00013826814, 0.14930058, -39.5477],
+    [-0.0001289201, 0.13850793, -36.4407],
+    [-0.00012099755, 0.12929706, -33.76948],
+    [-0.00011406563, 0.12117815, -31.396797],
+    [-0.00010777883, 0.11376113, -29.213072],
+    [-0.0001018232, 0.106690384, -27.118063],
+    [-9.586028e-5, 0.09958125, -25.00294],
+    [-8.944775e-5, 0.09192947, -22.724586],
+    [-8.188292e-5, 0.08293214, -20.054575],
+    [-7.18179e-5, 0.07104567, -16.55275],
+    [-0.00030559587, 0.34011355, -93.6587],
+    [-0.00023637289, 0.2620386, -71.754814],
+    [-0.00019767358, 0.2182908, -59.450684],
+    [-0.00017226925, 0.18948583, -51.322258],
+    [-0.00015402392, 0.16871987, -45.437885],
+    [-0.00014012873, 0.15283239, -40.913258],
+    [-0.00012908244, 0.14013393, -37.275597],
+    [-0.00011998881, 0.12961535, -34.24238],
+    [-0.00011226057, 0.12061492, -31.62816],
+    [-0.00010547579, 0.11265644, -29.299276],
+    [-9.9297e-5, 0.10535812, -27.148294],
+    [-9.34142e-5, 0.0983682, -25.075888],
+    [-8.748893e-5, 0.09130133, -22.972878],
+    [-8.107405e-5, 0.083646916, -20.694128],
+    [-7.3452946e-5, 0.074584715, -18.005936],
+    [-6.324746e-5, 0.06253357, -14.456694],
+    [-0.000290564, 0.3246573, -89.696465],
+    [-0.00022425546, 0.24971549, -68.633],
+    [-0.00018696523, 0.2074756, -56.7315],
+    [-0.00016240335, 0.17957075, -48.843075],
+    [-0.0001447211, 0.15940662, -43.11925],
+    [-0.0001312281, 0.14394975, -38.70973],
+    [-0.00012048144, 0.13157298, -35.158325],
+    [-0.000111616515, 0.12130086, -32.19148],
+    [-0.00010406481, 0.11249153, -29.628998],
+    [-9.741591e-5, 0.104680866, -27.340385],
+    [-9.133882e-5, 0.09749379, -25.219929],
+    [-8.552659e-5, 0.09058138, -23.168962],
+    [-7.964061e-5, 0.08355747, -21.077908],
+    [-7.322788e-5, 0.07590401, -18.799335],
+    [-6.5557804e-5, 0.066783436, -16.09428],
+    [-5.522282e-5, 0.05457816, -12.50006],
+    [-0.00027666957, 0.3104086, -86.052055],
+    [-0.0002129314, 0.23823084, -65.730484],
+    [-0.00017688522, 0.1973229, -54.184967],
+    [-0.00015306685, 0.17021316, -46.508812],
+    [-0.00013588188, 0.15058118, -40.927185],
+    [-0.00012274506, 0.13550621, -36.61993],
+    [-0.00011226498, 0.123416096, -33.145546],
+    [-0.00010360517, 0.11336534, -30.23841],
+    [-9.621353e-5, 0.104729414, -27.72292],
+    [-8.9689405e-5, 0.097054474, -25.471272],
+    [-8.370735e-5, 0.089971095, -23.379192],
+    [-7.796258e-5, 0.083132274, -21.348433],
+    [-7.211509e-5, 0.07614973, -19.268684],
+    [-6.5705484e-5, 0.0684974, -16.990068],
+    [-5.7987978e-5, 0.059319183, -14.268069],
+    [-4.7524387e-5, 0.04696002, -10.628727],
+    [-0.00026361953, 0.29706523, -82.64791],
+    [-0.00020215726, 0.2273358, -62.984028],
+    [-0.00016720856, 0.18760403, -51.753323],
+    [-0.00014404308, 0.16119364, -44.26431],
+    [-0.00012729352, 0.14202878, -38.80788],
+    [-0.000114468545, 0.12728928, -34.590805],
+    [-0.0001042227, 0.11545198, -31.184631],
+    [-9.574438e-5, 0.10559783, -28.330757],
+    [-8.849578e-5, 0.097117424, -25.857576],
+    [-8.2084516e-5, 0.08956558, -23.63955],
+    [-7.618946e-5, 0.08257721, -21.573503],
+    [-7.050683e-5, 0.07580605, -19.561293],
+    [-6.469402e-5, 0.068860404, -17.491537],
+    [-5.828354e-5, 0.06120443, -15.211463],
+    [-5.0511928e-5, 0.051960934, -12.470348],
+    [-3.9905808e-5, 0.039432857, -8.78186],
+    [-0.00025113343, 0.28433928, -79.41045],
+    [-0.00019168363, 0.21677643, -60.329353],
+    [-0.00015769355, 0.1780741, -49.37495],
+    [-0.00013509074, 0.15226872, -42.04855],
+    [-0.00011871244, 0.13350435, -36.70015],
+    [-0.00010615187, 0.1190513, -32.56069],
+    [-9.610441e-5, 0.10742997, -29.213324],
+    [-8.7780594e-5, 0.09774477, -26.40568],
+    [-8.065486e-5, 0.08939924, -23.969564],
+    [-7.434133e-5, 0.081954956, -21.781206],
+    [-6.8521826e-5, 0.07504978, -19.738163],
+    [-6.2891995e-5, 0.06833652, -17.741959],
+    [-5.7104433e-5, 0.06141774, -15.679553],
+    [-5.0680716e-5, 0.053744886, -13.3945055],
+    [-4.283448e-5, 0.044414286, -10.628518],
+    [-3.204668e-5, 0.031676177, -6.880198],
+    [-0.00023891266, 0.27192557, -76.26207],
+    [-0.00018122244, 0.2062606, -57.69278],
+    [-0.00014804496, 0.16843517, -46.975098],
+    [-0.00012590273, 0.14312942, -39.784336],
+    [-0.000109818706, 0.12468684, -34.524017],
+    [-9.746264e-5, 0.110459484, -30.446915],
+    [-8.756689e-5, 0.09900706, -27.146627],
+    [-7.9360936e-5, 0.08945407, -24.376104],
+    [-7.2329254e-5, 0.08121461, -21.969946],
+    [-6.609074e-5, 0.07385521, -19.805672],
+    [-6.0327704e-5, 0.067014106, -17.780907],
+    [-5.4732714e-5, 0.060340516, -15.796158],
+    [-4.8950093e-5, 0.05342773, -13.735796],
+    [-4.248466e-5, 0.045708377, -11.43816],
+    [-3.4516914e-5, 0.03624173, -8.6346655],
+    [-2.3460156e-5, 0.023202792, -4.803147],
+    [-0.00022659518, 0.25945598, -73.10955],
+    [-0.00017039152, 0.19540215, -54.97727],
+    [-0.00013784612, 0.15826781, -44.44886],
+    [-0.00011602538, 0.13332044, -37.35819],
+    [-0.00010012359, 0.115086965, -32.157906],
+    [-8.788105e-5, 0.10099453, -28.120762],
+    [-7.806326e-5, 0.08963787, -24.84965],
+    [-6.9915724e-5, 0.08015844, -22.101997],
+    [-6.292998e-5, 0.07197776, -19.714363],
+    [-5.6726334e-5, 0.064663805, -17.564655],
+    [-5.098407e-5, 0.05785151, -15.549614],
+    [-4.5387835e-5, 0.051181518, -13.56745],
+    [-3.9566832e-5, 0.044230714, -11.498119],
+    [-3.2997876e-5, 0.036401294, -9.171749],
+    [-2.4807994e-5, 0.026694473, -6.304104],
+    [-1.3299979e-5, 0.01316603, -2.3410625],
+    [-0.00021367005, 0.24641284, -69.822334],
+    [-0.0001585958, 0.18360129, -52.032463],
+    [-0.00012640303, 0.14687496, -41.622227],
+    [-0.00010466805, 0.12204983, -34.573082],
+    [-8.874994e-5, 0.103828214, -29.384377],
+    [-7.64565e-5, 0.089708425, -25.347662],
+    [-6.658254e-5, 0.078316025, -22.073935],
+    [-5.8384943e-5, 0.068804346, -19.323753],
+    [-5.135677e-5, 0.06059653, -16.934132],
+    [-4.5113415e-5, 0.05325547, -14.781676],
+    [-3.9323608e-5, 0.04640503, -12.76026],
+    [-3.3654498e-5, 0.039667405, -10.763268],
+    [-2.7706978e-5, 0.03258945, -8.662753],
+    [-2.0908848e-5, 0.024521187, -6.275049],
+    [-1.2299326e-5, 0.014370323, -3.291189],
+    [-1.0453717e-8, 1.1288205e-5, 0.8907874],
+    [-0.0006464938, 0.69807637, -186.91992],
+    [-0.0004944965, 0.53071827, -141.04929],
+    [-0.0004200832, 0.4485106, -118.43286],
+    [-0.00037508743, 0.39856786, -104.62052],
+    [-0.00034492678, 0.3648735, -95.234535],
+    [-0.00032351245, 0.34073618, -88.44489],
+    [-0.00030780336, 0.32281, -83.33539],
+    [-0.00029609076, 0.3092107, -79.38875],
+    [-0.00028732425, 0.29877517, -76.28418],
+    [-0.00028080418, 0.290723, -73.80467],
+    [-0.0002760176, 0.2844752, -71.78716],
+    [-0.00027253065, 0.27953437, -70.08923],
+    [-0.00026988122, 0.27536365, -68.55508],
+    [-0.00026740046, 0.27118424, -66.95814],
+    [-0.0002637377, 0.2654361, -64.84845],
+    [-0.00025499033, 0.25365677, -60.95225],
+    [-0.00058209116, 0.63081735, -169.45364],
+    [-0.00044829384, 0.48293278, -128.77077],
+    [-0.00038134994, 0.40869477, -108.27211],
+    [-0.0003403604, 0.36302897, -95.597534],
+    [-0.00031260485, 0.331912, -86.900475],
+    [-0.00029269294, 0.30939832, -80.549194],
+    [-0.00027790235, 0.29248205, -75.71783],
+    [-0.00026668663, 0.27945223, -71.93521],
+    [-0.00025808247, 0.26923922, -68.90556],
+    [-0.00025143573, 0.26111197, -66.42532],
+    [-0.00024625254, 0.2545132, -64.33763],
+    [-0.00024209735, 0.24894544, -62.500664],
+    [-0.00023848495, 0.24384929, -60.753387],
+    [-0.00023469098, 0.23838833, -58.85466],
+    [-0.00022923607, 0.23086338, -56.317307],
+    [-0.00021779547, 0.21633632, -51.73449],
+    [-0.0005317583, 0.5783331, -155.84201],
+    [-0.00041139353, 0.44483373, -118.99553],
+    [-0.00035012836, 0.37665913, -100.109535],
+    [-0.0003122404, 0.33430618, -88.31684],
+    [-0.00028637832, 0.3052186, -80.16235],
+    [-0.00026767422, 0.2840092, -74.16293],
+    [-0.00025364736, 0.26792997, -69.561264],
+    [-0.00024287637, 0.25540346, -65.92169],
+    [-0.0002344665, 0.2454334, -62.96806],
+    [-0.00022780102, 0.23732916, -60.507687],
+    [-0.00022240376, 0.23055296, -58.389183],
+    [-0.0002178416, 0.22461076, -56.471928],
+    [-0.00021361533, 0.2189289, -54.591404],
+    [-0.00020896092, 0.2126283, -52.495186],
+    [-0.00020229498, 0.20389362, -49.663998],
+    [-0.00018890497, 0.18734162, -44.564075],
+    [-0.0004912065, 0.53612334, -144.91139],
+    [-0.00038114298, 0.41366282, -111.01137],
+    [-0.00032433603, 0.3502505, -93.39279],
+    [-0.0002889197, 0.3105382, -82.30333],
+    [-0.00026458816, 0.28309086, -74.587364],
+    [-0.00024687772, 0.26295352, -68.8769],
+    [-0.00023349698, 0.24758041, -64.468376],
+    [-0.00022312305, 0.23549916, -60.954117],
+    [-0.00021491681, 0.22577293, -58.07379],
+    [-0.00020829207, 0.21774384, -55.643543],
+    [-0.00020278871, 0.21089159, -53.516727],
+    [-0.00019797686, 0.20472585, -51.55386],
+    [-0.0001933483, 0.19866323, -49.5879],
+    [-0.0001881076, 0.19178978, -47.356834],
+    [-0.00018058067, 0.18218645, -44.312073],
+    [-0.00016569551, 0.16405198, -38.799118],
+    [-0.0004577236, 0.50134474, -135.92087],
+    [-0.00035579727, 0.38760722, -104.35057],
+    [-0.0003025799, 0.32802978, -87.75299],
+    [-0.00026917917, 0.29047072, -77.23705],
+    [-0.00024611145, 0.2643773, -69.88298],
+    [-0.00022923453, 0.24513844, -64.41442],
+    [-0.00021640863, 0.23037012, -60.17085],
+    [-0.00020639099, 0.2186855, -56.76732],
+    [-0.00019838763, 0.20919585, -53.956326],
+    [-0.0001918382, 0.20127103, -51.561413],
+    [-0.00018629612, 0.19440514, -49.439827],
+    [-0.00018133575, 0.18811233, -47.4533],
+    [-0.00017644168, 0.18180108, -45.432526],
+    [-0.00017079197, 0.17452696, -43.106907],
+    [-0.0001626316, 0.16427687, -39.90076],
+    [-0.00014655835, 0.14486115, -34.04633],
+    [-0.0004294992, 0.47210118, -128.37682],
+    [-0.00033415263, 0.36541742, -98.6911],
+    [-0.0002838846, 0.30899036, -82.93239],
+    [-0.00025215864, 0.27321985, -72.89278],
+    [-0.00023015308, 0.2482636, -65.84252],
+    [-0.00021398687, 0.22978991, -60.57973],
+    [-0.00020164432, 0.21554713, -56.479095],
+    [-0.00019194867, 0.20421879, -53.174362],
+    [-0.00018414352, 0.19495618, -50.42866],
+    [-0.00017769041, 0.18715242, -48.07173],
+    [-0.00017215461, 0.18031421, -45.964172],
+    [-0.00016711399, 0.17395918, -43.968594],
+    [-0.0001620478, 0.16748926, -41.913784],
+    [-0.00015611142, 0.1599334, -39.52143],
+    [-0.00014747579, 0.14919117, -36.190384],
+    [-0.00013043763, 0.1287155, -30.047771],
+    [-0.00040526703, 0.44706824, -121.934906],
+    [-0.00031534184, 0.34619468, -93.80162],
+    [-0.0002675375, 0.29239792, -78.74319],
+    [-0.00023722465, 0.25813556, -69.105095],
+    [-0.00021612506, 0.23414846, -62.313595],
+    [-0.00020057347, 0.21633561, -57.22829],
+    [-0.00018865742, 0.20255546, -53.253136],
+    [-0.00017925542, 0.19155034, -50.03749],
+    [-0.00017164278, 0.18250522, -47.353424],
+    [-0.00016529966, 0.174833, -45.035915],
+    [-0.00015980151, 0.16805103, -42.948364],
+    [-0.00015472995, 0.16168056, -40.95426],
+    [-0.00014955945, 0.15511745, -38.88053],
+    [-0.00014342496, 0.14736612, -36.441654],
+    [-0.00013442982, 0.13624544, -33.012688],
+    [-0.000116598654, 0.11488215, -26.624014],
+    [-0.0003841014, 0.42527968, -116.34433],
+    [-0.00029871226, 0.32926407, -89.508736],
+    [-0.00025299416, 0.27769268, -75.04251],
+    [-0.000223889, 0.24471799, -65.74705],
+    [-0.0002035716, 0.22156675, -59.178543],
+    [-0.00018855797, 0.2043315, -54.24816],
+    [-0.00017702265, 0.1909634, -50.384476],
+    [-0.00016789105, 0.18025452, -47.250057],
+    [-0.00016046544, 0.17141852, -44.62455],
+    [-0.00015424211, 0.16388522, -42.3474],
+    [-0.00014880551, 0.15718137, -40.284454],
+    [-0.0001437402, 0.15083084, -38.299786],
+    [-0.00013851684, 0.14422435, -36.218685],
+    [-0.0001322523, 0.13634416, -33.748985],
+    [-0.00012298729, 0.12493364, -30.24329],
+    [-0.00010449926, 0.10282057, -23.642681],
+    [-0.00036529562, 0.40599987, -111.414665],
+    [-0.0002837501, 0.31409582, -85.676704],
+    [-0.0002398177, 0.2644268, -71.71639],
+    [-0.00021175476, 0.232562, -62.71605],
+    [-0.00019211907, 0.21013843, -56.341507],
+    [-0.00017758078, 0.19341289, -51.547665],
+    [-0.00016638868, 0.18041548, -47.784046],
+    [-0.00015750834, 0.16998085, -44.724476],
+    [-0.00015026529, 0.16134724, -42.155087],
+    [-0.00014416971, 0.15395904, -39.919163],
+    [-0.00013881379, 0.14735097, -37.884586],
+    [-0.00013378517, 0.14104961, -35.916027],
+    [-0.00012855083, 0.1344406, -33.83711],
+    [-0.00012221068, 0.12648523, -31.349327],
+    [-0.00011274748, 0.114856206, -27.784117],
+    [-9.3712646e-5, 0.0921056, -20.999636],
+    [-0.00034828144, 0.38863978, -106.99403],
+    [-0.00027002537, 0.30024847, -82.19289],
+    [-0.00022763274, 0.25221714, -68.667694],
+    [-0.00020047437, 0.22131445, -59.92298],
+    [-0.00018143591, 0.19952764, -53.718086],
+    [-0.00016731997, 0.18325458, -49.04537],
+    [-0.0001564394, 0.17059317, -45.372307],
+    [-0.00014779436, 0.16041467, -42.38239],
+    [-0.0001407305, 0.15197845, -39.86732],
+    [-0.00013477002, 0.14474149, -37.67366],
+    [-0.00012951193, 0.13824543, -35.671047],
+    [-0.00012454626, 0.13201882, -33.724525],
+    [-0.00011933661, 0.12544245, -31.656126],
+    [-0.000112967115, 0.11745783, -29.16129],
+    [-0.00010336542, 0.10567009, -25.551105],
+    [-8.387091e-5, 0.08237112, -18.605156],
+    [-0.0003325698, 0.3726955, -102.95315],
+    [-0.00025714652, 0.28732225, -78.955765],
+    [-0.00021608386, 0.24070291, -65.805374],
+    [-0.0001897096, 0.21063322, -57.281944],
+    [-0.0001711931, 0.18940277, -51.22532],
+    [-0.0001574519, 0.17353122, -46.660126],
+    [-0.00014685436, 0.16117509, -43.06934],
+    [-0.00013843058, 0.15123716, -40.144733],
+    [-0.00013154355, 0.14299497, -37.68277],
+    [-0.00012572577, 0.13591619, -35.532787],
+    [-0.000120581964, 0.12954819, -33.565857],
+    [-0.00011570396, 0.1234208, -31.647217],
+    [-0.00011055175, 0.11690974, -29.597212],
+    [-0.00010419381, 0.108936824, -27.105257],
+    [-9.450356e-5, 0.097040564, -23.462366],
+    [-7.461358e-5, 0.07325871, -16.37134],
+    [-0.00031769776, 0.35769343, -99.17134],
+    [-0.00024471182, 0.2749097, -75.86247],
+    [-0.00020478707, 0.22949646, -63.032143],
+    [-0.00017908184, 0.20013756, -54.697773],
+    [-0.00016101314, 0.1793852, -48.768898],
+    [-0.00014759878, 0.1638648, -44.29808],
+    [-0.00013725531, 0.15178357, -40.781616],
+    [-0.00012903861, 0.14207126, -37.91835],
+    [-0.00012232637, 0.1340207, -35.508736],
+    [-0.00011665956, 0.12710832, -33.404366],
+    [-0.00011164735, 0.12088579, -31.477365],
+    [-0.00010688227, 0.114883155, -29.592863],
+    [-0.000101819736, 0.10846998, -27.569248],
+    [-9.5511205e-5, 0.100546926, -25.089529],
+    [-8.5772976e-5, 0.08858277, -21.423832],
+    [-6.5521286e-5, 0.064351305, -14.19575],
+    [-0.00030315545, 0.34311587, -95.517654],
+    [-0.00023223445, 0.2625197, -72.78972],
+    [-0.00019324936, 0.21810246, -60.224228],
+    [-0.00016808535, 0.18932077, -52.04431],
+    [-0.0001503771, 0.1689563, -46.220093],
+    [-0.00013723044, 0.15372662, -41.828346],
+    [-0.0001271038, 0.14188267, -38.376785],
+    [-0.00011907494, 0.13237691, -35.570175],
+    [-0.00011253338, 0.12451441, -33.212147],
+    [-0.00010702632, 0.11777791, -31.155891],
+    [-0.00010216527, 0.11172128, -29.274038],
+    [-9.7541466e-5, 0.10587257, -27.431042],
+    [-9.260312e-5, 0.099592455, -25.44262],
+    [-8.6380125e-5, 0.09175569, -22.984114],
+    [-7.662072e-5, 0.07974969, -19.301739],
+    [-5.598616e-5, 0.05504349, -11.929309],
+    [-0.00028826506, 0.3282787, -91.81999],
+    [-0.00021900264, 0.24943706, -69.55864],
+    [-0.00018070375, 0.20575257, -57.190197],
+    [-0.00015589633, 0.17735964, -49.117092],
+    [-0.00013841035, 0.15724386, -43.362873],
+    [-0.00012543084, 0.14220496, -39.025654],
+    [-0.000115453106, 0.13053174, -35.622963],
+    [-0.00010757271, 0.12119501, -32.864277],
+    [-0.00010118736, 0.11350811, -30.555342],
+    [-9.584663e-5, 0.1069561, -28.549921],
+    [-9.116035e-5, 0.101090826, -26.720047],
+    [-8.671357e-5, 0.09543332, -24.928211],
+    [-8.194041e-5, 0.089328244, -22.985624],
+    [-7.5836695e-5, 0.08161125, -20.556547],
+    [-6.6051434e-5, 0.06955554, -16.854696],
+    [-4.4878267e-5, 0.04420717, -9.29347],
+    [-0.00027191028, 0.31205872, -87.796745],
+    [-0.00020371744, 0.23435913, -65.844],
+    [-0.00016563847, 0.19093445, -53.55378],
+    [-0.00014079607, 0.16253868, -45.490566],
+    [-0.00012321238, 0.14235492, -39.728893],
+    [-0.00011015229, 0.12726404, -35.38757],
+    [-0.000100145655, 0.11558955, -31.992868],
+    [-9.2300455e-5, 0.10631442, -29.25728],
+    [-8.601489e-5, 0.09875221, -26.986532],
+    [-8.0831676e-5, 0.0923807, -25.032488],
+    [-7.6348166e-5, 0.08673864, -23.263763],
+    [-7.2129566e-5, 0.081326075, -21.537329],
+    [-6.7575675e-5, 0.075450785, -19.654552],
+    [-6.161005e-5, 0.06786966, -17.258402],
+    [-5.16863e-5, 0.05564117, -13.504083],
+    [-2.9374758e-5, 0.029018518, -5.589437],
+    [-0.00025171466, 0.29206756, -82.8498],
+    [-0.00018317121, 0.21407142, -60.844044],
+    [-0.00014391501, 0.16951233, -48.287064],
+    [-0.00011777296, 0.13986298, -39.92739],
+    [-9.9041456e-5, 0.11858113, -33.908367],
+    [-8.5095235e-5, 0.10265514, -29.375095],
+    [-7.448892e-5, 0.09043085, -25.85879],
+    [-6.63112e-5, 0.080869906, -23.06617],
+    [-5.992212e-5, 0.073246315, -20.792774],
+    [-5.481881e-5, 0.06699116, -18.878588],
+    [-5.0547707e-5, 0.0615911, -17.178988],
+    [-4.6609795e-5, 0.056480013, -15.533997],
+    [-4.230373e-5, 0.050860245, -13.717686],
+    [-3.6348403e-5, 0.043273088, -11.316635],
+    [-2.5667428e-5, 0.03021528, -7.3383],
+    [-2.932289e-8, 3.1652286e-5, 1.5209987],
+    [-0.000777479, 0.83892363, -224.2953],
+    [-0.00058605254, 0.6286068, -166.81412],
+    [-0.0004979702, 0.53142244, -140.12405],
+    [-0.00044699365, 0.47482497, -124.46942],
+    [-0.00041435653, 0.43824854, -114.24592],
+    [-0.0003925206, 0.4134226, -107.19685],
+    [-0.0003778559, 0.39635447, -102.2296],
+    [-0.00036841634, 0.38489184, -98.75152],
+    [-0.00036309237, 0.37779307, -96.416145],
+    [-0.00036123954, 0.3743176, -95.01077],
+    [-0.00036249205, 0.37401715, -94.39829],
+    [-0.00036664814, 0.37660295, -94.47859],
+    [-0.00037355616, 0.38180694, -95.146416],
+    [-0.00038284317, 0.38905504, -96.1931],
+    [-0.00039275544, 0.3961154, -96.9128],
+    [-0.0003915251, 0.38920686, -93.272964],
+    [-0.0006914562, 0.7487742, -200.81721],
+    [-0.0005269558, 0.5672975, -151.02087],
+    [-0.00044898828, 0.4809234, -127.20646],
+    [-0.0004030889, 0.4297631, -113.00241],
+    [-0.00037326143, 0.3962175, -103.594826],
+    [-0.0003529537, 0.3730704, -97.00749],
+    [-0.00033895785, 0.35678092, -92.26823],
+    [-0.00032952108, 0.34540513, -88.84039],
+    [-0.0003236119, 0.3377879, -86.4016],
+    [-0.00032059167, 0.33319977, -84.74381],
+    [-0.00032004144, 0.33114305, -83.71923],
+    [-0.00032164142, 0.33121425, -83.201065],
+    [-0.00032503085, 0.3329391, -83.035065],
+    [-0.00032946488, 0.33537257, -82.92302],
+    [-0.00033245655, 0.335535, -81.97304],
+    [-0.00031957883, 0.31675002, -75.37171],
+    [-0.0006270899, 0.68146324, -183.3184],
+    [-0.00048134857, 0.52009255, -138.88467],
+    [-0.00041073293, 0.44157934, -117.16291],
+    [-0.00036862332, 0.39447823, -104.0423],
+    [-0.00034095297, 0.36325824, -95.260506],
+    [-0.00032187332, 0.34145603, -89.04175],
+    [-0.00030848573, 0.3258615, -84.50204],
+    [-0.00029918415, 0.3146886, -81.14706],
+    [-0.00029300316, 0.30685452, -78.6743],
+    [-0.00028932164, 0.3016512, -76.882355],
+    [-0.0002877003, 0.29856348, -75.62019],
+    [-0.00028776188, 0.2971323, -74.74828],
+    [-0.00028903558, 0.2967744, -74.08638],
+    [-0.00029057375, 0.2963393, -73.28566],
+    [-0.00028944603, 0.29238048, -71.333496],
+    [-0.00026890234, 0.26560462, -62.68387],
+    [-0.0005769829, 0.6291962, -169.75911],
+    [-0.0004449934, 0.48256806, -129.25969],
+    [-0.00037994553, 0.41000798, -109.12319],
+    [-0.00034076927, 0.3660478, -96.8409],
+    [-0.00031480868, 0.33666953, -88.55425],
+    [-0.00029673954, 0.3159706, -82.63688],
+    [-0.00028389797, 0.30099213, -78.27167],
+    [-0.0002747924, 0.29007083, -74.99721],
+    [-0.00026851278, 0.28218412, -72.52737],
+    [-0.00026445792, 0.27664685, -70.66738],
+    [-0.00026218325, 0.2729405, -69.26617],
+    [-0.0002612811, 0.2705768, -68.177574],
+    [-0.00026121672, 0.2689085, -67.20588],
+    [-0.00026091535, 0.26665327, -65.968895],
+    [-0.0002571277, 0.26001784, -63.360874],
+    [-0.00023092476, 0.22720689, -53.121483],
+    [-0.00053678936, 0.5873985, -158.94342],
+    [-0.00041525855, 0.4519795, -121.435715],
+    [-0.0003545578, 0.38406497, -102.53611],
+    [-0.00031771534, 0.3426018, -90.91994],
+    [-0.0002931443, 0.31471828, -83.034676],
+    [-0.00027592346, 0.29494283, -77.36873],
+    [-0.00026357264, 0.28051275, -73.1572],
+    [-0.00025469135, 0.26986235, -69.96478],
+    [-0.000248416, 0.2620192, -67.518875],
+    [-0.0002441653, 0.2563203, -65.63096],
+    [-0.00024149615, 0.25225008, -64.15121],
+    [-0.00023998448, 0.24930449, -62.92993],
+    [-0.00023905584, 0.2467958, -61.761402],
+    [-0.00023754864, 0.24334934, -60.239277],
+    [-0.00023195952, 0.23488764, -57.17921],
+    [-0.00020132546, 0.19724944, -45.636894],
+    [-0.00050376676, 0.5531875, -150.11868],
+    [-0.0003904081, 0.42651945, -114.9457],
+    [-0.0003331805, 0.36231232, -97.03254],
+    [-0.00029823498, 0.32287604, -85.95655],
+    [-0.00027481597, 0.29622957, -78.40294],
+    [-0.00025832048, 0.27724123, -72.95053],
+    [-0.00024641387, 0.2633037, -68.87579],
+    [-0.00023777058, 0.2529311, -65.76467],
+    [-0.00023156547, 0.2451924, -63.355698],
+    [-0.00022723658, 0.23944578, -61.466164],
+    [-0.00022434529, 0.2351811, -59.947964],
+    [-0.00022245958, 0.23188676, -58.649742],
+    [-0.00022098009, 0.22884889, -57.35911],
+    [-0.00021868167, 0.22462323, -55.650948],
+    [-0.00021185039, 0.21489108, -52.272472],
+    [-0.0001776467, 0.17328586, -39.63575],
+    [-0.00047607676, 0.52463484, -142.78229],
+    [-0.0003692329, 0.4049317, -109.46569],
+    [-0.00031482955, 0.3437336, -92.35215],
+    [-0.00028145214, 0.3059696, -81.72114],
+    [-0.0002590048, 0.2803642, -74.44602],
+    [-0.00024313928, 0.26205727, -69.17782],
+    [-0.00023163958, 0.24856772, -65.22647],
+    [-0.0002232414, 0.23847492, -62.195267],
+    [-0.0002171529, 0.23088345, -59.832253],
+    [-0.0002128296, 0.22517024, -57.9598],
+    [-0.00020983967, 0.2208328, -56.431965],
+    [-0.0002077485, 0.21735702, -55.096855],
+    [-0.00020594099, 0.21401197, -53.737545],
+    [-0.00020314599, 0.2093012, -51.91433],
+    [-0.00019547062, 0.19869669, -48.313828],
+    [-0.00015835969, 0.15379609, -34.748833],
+    [-0.00045242073, 0.5003826, -136.58131],
+    [-0.0003508468, 0.38629878, -104.75977],
+    [-0.0002987711, 0.3275739, -88.30213],
+    [-0.00026670768, 0.29120728, -78.04217],
+    [-0.00024509153, 0.26648995, -71.004],
+    [-0.00022978164, 0.24878195, -65.89705],
+    [-0.00021865946, 0.23570547, -62.058613],
+    [-0.00021051189, 0.22589424, -59.106354],
+    [-0.0002045759, 0.21848333, -56.796402],
+    [-0.00020032321, 0.212867, -54.955753],
+    [-0.00019733011, 0.20855148, -53.44082],
+    [-0.00019516332, 0.20502393, -52.10003],
+    [-0.00019319923, 0.20154355, -50.713642],
+    [-0.00019013407, 0.19657533, -48.830696],
+    [-0.0001819245, 0.18541126, -45.08469],
+    [-0.00014244953, 0.13777354, -30.731836],
+    [-0.00043182744, 0.4794203, -131.25429],
+    [-0.0003345609, 0.36991128, -100.64638],
+    [-0.00028442216, 0.3132364, -84.730804],
+    [-0.00025347085, 0.27804834, -74.78287],
+    [-0.00023257399, 0.25409698, -67.94846],
+    [-0.00021776085, 0.23692255, -62.984604],
+    [-0.00020699383, 0.22423263, -59.251095],
+    [-0.00019910319, 0.21470661, -56.377625],
+    [-0.0001933511, 0.20750616, -54.127327],
+    [-0.00018922416, 0.2020413, -52.331352],
+    [-0.00018630816, 0.19782816, -50.848682],
+    [-0.00018417445, 0.19435851, -49.528877],
+    [-0.00018219689, 0.19088767, -48.15081],
+    [-0.00017905091, 0.18585423, -46.25533],
+    [-0.00017057006, 0.17439911, -42.430313],
+    [-0.00012920001, 0.124511115, -27.413536],
+    [-0.00041352352, 0.46094957, -126.59602],
+    [-0.00031979836, 0.35517964, -96.97565],
+    [-0.00027127864, 0.30020902, -81.50887],
+    [-0.000241273, 0.26601863, -71.824135],
+    [-0.00022100283, 0.2427323, -65.16606],
+    [-0.0002066385, 0.22603843, -60.330593],
+    [-0.0001962102, 0.2137156, -56.69614],
+    [-0.00018858505, 0.20448184, -53.90248],
+    [-0.0001830474, 0.19752195, -51.7188],
+    [-0.00017909838, 0.19226116, -49.980186],
+    [-0.00017633398, 0.18822621, -48.54835],
+    [-0.00017433299, 0.18491629, -47.274616],
+    [-0.00017247286, 0.18158913, -45.93806],
+    [-0.00016941947, 0.17666861, -44.07431],
+    [-0.00016091105, 0.16517437, -40.233475],
+    [-0.000118074124, 0.11348394, -24.667654],
+    [-0.00039683655, 0.4442836, -122.43173],
+    [-0.00030601758, 0.34155566, -93.60942],
+    [-0.00025884324, 0.287991, -78.51085],
+    [-0.00022963648, 0.25463897, -69.04643],
+    [-0.00020991077, 0.23192853, -62.540638],
+    [-0.00019595357, 0.21566977, -57.821125],
+    [-0.00018585187, 0.20370011, -54.281578],
+    [-0.00017850421, 0.19477014, -51.570213],
+    [-0.00017321447, 0.18808497, -49.461445],
+    [-0.00016949796, 0.18308453, -47.794147],
+    [-0.0001669618, 0.17930707, -46.432938],
+    [-0.00016519497, 0.176262, -45.231594],
+    [-0.00016358477, 0.17321602, -43.97103],
+    [-0.00016079907, 0.16859058, -42.184822],
+    [-0.00015250988, 0.15731522, -38.393623],
+    [-0.00010861904, 0.104256295, -22.390537],
+    [-0.00038109042, 0.42874056, -118.589874],
+    [-0.0002926106, 0.32842952, -90.39531],
+    [-0.00024652184, 0.27598837, -75.58897],
+    [-0.00021796855, 0.24331847, -66.30324],
+    [-0.00019870362, 0.22109488, -59.926163],
+    [-0.00018511125, 0.20522656, -55.310806],
+    [-0.00017532549, 0.19359906, -51.863163],
+    [-0.00016827142, 0.18499003, -49.238373],
+    [-0.00016327002, 0.1786222, -47.21534],
+    [-0.0001598498, 0.17394915, -45.636414],
+    [-0.0001576302, 0.17052187, -44.369385],
+    [-0.00015621362, 0.1678634, -43.271362],
+    [-0.00015500314, 0.16525576, -42.12671],
+    [-0.00015268131, 0.16113144, -40.470425],
+    [-0.0001448845, 0.15036306, -36.802563],
+    [-0.00010036136, 0.0963796, -20.476654],
+    [-0.00036542863, 0.4134648, -114.85713],
+    [-0.00027871653, 0.31494254, -87.11985],
+    [-0.00023342192, 0.26331255, -72.522934],
+    [-0.00020534302, 0.23113681, -63.36703],
+    [-0.00018642767, 0.20928551, -57.08943],
+    [-0.0001731396, 0.19374695, -52.563103],
+    [-0.00016365158, 0.18244518, -49.20368],
+    [-0.0001569102, 0.17417948, -46.671684],
+    [-0.00015225049, 0.16818677, -44.749615],
+    [-0.00014921249, 0.16393262, -43.28281],
+    [-0.00014742791, 0.16098084, -42.142284],
+    [-0.00014651495, 0.15887082, -41.18914],
+    [-0.00014589861, 0.15690652, -40.212914],
+    [-0.00014428925, 0.15354575, -38.753727],
+    [-0.00013731844, 0.14363757, -35.299927],
+    [-9.261345e-5, 0.08920296, -18.7727],
+    [-0.00034841723, 0.39702776, -110.8784],
+    [-0.00026276193, 0.2995269, -83.393814],
+    [-0.0002178038, 0.24823216, -68.88382],
+    [-0.00018986329, 0.21620935, -59.772],
+    [-0.00017105717, 0.194491, -53.534977],
+    [-0.00015792128, 0.17913446, -49.062336],
+    [-0.00014866226, 0.16809613, -45.77744],
+    [-0.00014224285, 0.16019067, -43.344475],
+    [-0.0001380057, 0.15466261, -41.54772],
+    [-0.00013549595, 0.15098299, -40.23388],
+    [-0.00013435207, 0.14872313, -39.275856],
+    [-0.00013420482, 0.14743608, -38.53815],
+    [-0.00013450235, 0.14644986, -37.816093],
+    [-0.00013399146, 0.14425758, -36.657593],
+    [-0.00012831873, 0.1357067, -33.54511],
+    [-8.3889005e-5, 0.08129433, -16.935663],
+    [-0.00032685412, 0.37623414, -105.85848],
+    [-0.0002407431, 0.2781876, -78.2242],
+    [-0.0001947286, 0.22582604, -63.451344],
+    [-0.00016568153, 0.19272012, -54.07972],
+    [-0.00014597378, 0.170142, -47.642258],
+    [-0.00013226744, 0.15426727, -43.056103],
+    [-0.0001228204, 0.14309888, -39.754982],
+    [-0.00011660388, 0.13545719, -37.403725],
+    [-0.00011294478, 0.13056983, -35.781975],
+    [-0.000111361216, 0.12788095, -34.72959],
+    [-0.00011146086, 0.12693238, -34.112206],
+    [-0.000112846654, 0.12725163, -33.78813],
+    [-0.0001149492, 0.12815176, -33.549072],
+    [-0.00011650937, 0.12812074, -32.940903],
+    [-0.000113107075, 0.12192166, -30.419096],
+    [-6.903353e-5, 0.06757307, -13.724339],
+    [-0.00028863217, 0.33896607, -96.77345],
+    [-0.00019206159, 0.23035713, -66.49653],
+    [-0.00013369888, 0.16575624, -48.714813],
+    [-9.431221e-5, 0.122531645, -36.889744],
+    [-6.741275e-5, 0.093020014, -28.800182],
+    [-4.9110207e-5, 0.07280315, -23.205011],
+    [-3.7038582e-5, 0.059241235, -19.373343],
+    [-2.9745537e-5, 0.050723214, -16.860693],
+    [-2.6316511e-5, 0.04623227, -15.385115],
+    [-2.6172842e-5, 0.04511501, -14.761784],
+    [-2.8931645e-5, 0.04692291, -14.858312],
+    [-3.427128e-5, 0.05125951, -15.551272],
+    [-4.169461e-5, 0.057510614, -16.649204],
+    [-4.9799066e-5, 0.06401601, -17.657917],
+    [-5.279499e-5, 0.06411286, -16.66156],
+    [-1.2697902e-7, 0.00013704933, 2.7085292],
+    [-0.0008731015, 0.94176114, -251.60591],
+    [-0.0006492239, 0.6961542, -184.60826],
+    [-0.000550825, 0.5876901, -154.85757],
+    [-0.00049569085, 0.52646977, -137.9239],
+    [-0.00046165398, 0.48823363, -127.20859],
+    [-0.00044007323, 0.46350935, -120.13015],
+    [-0.00042692458, 0.44786838, -115.47611],
+    [-0.00042016615, 0.43904212, -112.61864],
+    [-0.00041877193, 0.4358613, -111.22482],
+    [-0.00042233514, 0.4378153, -111.13491],
+    [-0.00043091294, 0.44487146, -112.309586],
+    [-0.0004450038, 0.45742908, -114.81079],
+    [-0.00046561356, 0.47634974, -118.796486],
+    [-0.00049432495, 0.502938, -124.485115],
+    [-0.0005323531, 0.537632, -131.71187],
+    [-0.00055917614, 0.55496126, -132.59457],
+    [-0.00076902437, 0.8324488, -223.08466],
+    [-0.0005802029, 0.6244104, -166.0977],
+    [-0.00049414224, 0.52914417, -139.8593],
+    [-0.00044490935, 0.47425473, -124.61742],
+    [-0.00041392882, 0.43933114, -114.79848],
+    [-0.00039378562, 0.41621324, -108.17032],
+    [-0.00038094964, 0.4010029, -103.66233],
+    [-0.0003735741, 0.39164448, -100.70478],
+    [-0.00037066458, 0.38700977, -98.97822],
+    [-0.0003717232, 0.3865026, -98.30411],
+    [-0.00037658407, 0.3898706, -98.59108],
+    [-0.00038533224, 0.3971026, -99.80249],
+    [-0.00039822835, 0.40831867, -101.9179],
+    [-0.0004154243, 0.42339784, -104.81214],
+    [-0.00043504455, 0.43968457, -107.57026],
+    [-0.00042467742, 0.4191369, -99.03558],
+    [-0.0006935861, 0.7534094, -202.50429],
+    [-0.0005281914, 0.57049114, -152.21709],
+    [-0.0004508224, 0.48452517, -128.45547],
+    [-0.0004058867, 0.4342459, -114.44594],
+    [-0.00037721943, 0.4018267, -105.30397],
+    [-0.00035825407, 0.3800159, -99.03939],
+    [-0.00034581576, 0.36529642, -94.68344],
+    [-0.00033820875, 0.35577655, -91.71085],
+    [-0.00033448287, 0.35037944, -89.81721],
+    [-0.0003341098, 0.3484828, -88.81921],
+    [-0.00033682087, 0.34973428, -88.60232],
+    [-0.00034250438, 0.35392824, -89.083595],
+    [-0.00035107078, 0.3608368, -90.159096],
+    [-0.0003620194, 0.36968607, -91.54552],
+    [-0.0003720781, 0.37639838, -91.97556],
+    [-0.0003394415, 0.33246663, -77.3959],
+    [-0.00063629105, 0.6935573, -186.95834],
+    [-0.00048752973, 0.5284748, -141.42995],
+    [-0.0004165819, 0.44937772, -119.49783],
+    [-0.0003749081, 0.40259582, -106.4229],
+    [-0.00034805472, 0.37213734, -97.81014],
+    [-0.00033007172, 0.3514105, -91.84516],
+    [-0.0003180501, 0.33718303, -87.63523],
+    [-0.00031041296, 0.32769164, -84.689735],
+    [-0.00030625396, 0.32190862, -82.71816],
+    [-0.00030503966, 0.31920996, -81.53803],
+    [-0.00030645172, 0.31919593, -81.02414],
+    [-0.00031027567, 0.32155967, -81.069664],
+    [-0.00031623576, 0.32588798, -81.52608],
+    [-0.00032348096, 0.3310528, -82.02326],
+    [-0.00032793244, 0.33213323, -81.07788],
+    [-0.00027895998, 0.2705327, -61.766075],
+    [-0.000591287, 0.6467187, -174.82979],
+    [-0.00045483757, 0.49482897, -132.82076],
+    [-0.0003887965, 0.42097571, -112.2846],
+    [-0.00034967513, 0.37692702, -99.93939],
+    [-0.00032428312, 0.34804502, -91.75127],
+    [-0.00030713485, 0.3282344, -86.038025],
+    [-0.0002955248, 0.3144806, -81.96496],
+    [-0.0002879716, 0.30512312, -79.06906],
+    [-0.00028360987, 0.29917938, -77.07232],
+    [-0.0002819119, 0.29603356, -75.79523],
+    [-0.00028253664, 0.29526508, -75.10814],
+    [-0.00028521373, 0.29651237, -74.8914],
+    [-0.00028956184, 0.29925656, -74.97097],
+    [-0.00029452343, 0.30215773, -74.92371],
+    [-0.00029547786, 0.299711, -73.11118],
+    [-0.0002334298, 0.22361305, -49.806488],
+    [-0.0005550216, 0.60915196, -165.1401],
+    [-0.00042795108, 0.46729675, -125.80546],
+    [-0.0003657514, 0.39754122, -106.35883],
+    [-0.0003286733, 0.35567608, -94.59565],
+    [-0.00030448468, 0.32808882, -86.75536],
+    [-0.0002880579, 0.30906615, -81.257324],
+    [-0.00027684722, 0.29576397, -77.312195],
+    [-0.00026944978, 0.28660583, -74.47945],
+    [-0.00026503624, 0.28064838, -72.49176],
+    [-0.00026309057, 0.2772896, -71.17372],
+    [-0.00026326368, 0.27610222, -70.3946],
+    [-0.00026525732, 0.2766977, -70.02852],
+    [-0.00026863028, 0.2784968, -69.88664],
+    [-0.00027219902, 0.28002757, -69.51194],
+    [-0.00027094947, 0.2753478, -67.145676],
+    [-0.0001980209, 0.18692665, -40.368187],
+    [-0.00052518636, 0.57843184, -157.2564],
+    [-0.00040539916, 0.44434798, -119.98908],
+    [-0.00034625898, 0.37784663, -101.40581],
+    [-0.00031084495, 0.33775446, -90.114044],
+    [-0.00028766546, 0.31124932, -82.5635],
+    [-0.0002718733, 0.29291618, -77.25266],
+    [-0.0002610507, 0.28004697, -73.42823],
+    [-0.00025385903, 0.2711336, -70.667885],
+    [-0.0002495019, 0.26526827, -68.713875],
+    [-0.00024747805, 0.26186538, -67.395355],
+    [-0.00024744033, 0.26050073, -66.58278],
+    [-0.00024907984, 0.26077595, -66.14809],
+    [-0.00025192642, 0.26208225, -65.8951],
+    [-0.00025472423, 0.26286948, -65.34584],
+    [-0.0002521711, 0.2568642, -62.64719],
+    [-0.00016995877, 0.15771715, -32.784],
+    [-0.0005001925, 0.55289614, -150.74643],
+    [-0.0003861255, 0.424889, -115.09041],
+    [-0.00032944995, 0.3609975, -97.197174],
+    [-0.0002954091, 0.32236233, -86.291435],
+    [-0.00027308933, 0.29677528, -78.9857],
+    [-0.00025786535, 0.27905634, -73.84054],
+    [-0.00024742336, 0.26660717, -70.13163],
+    [-0.00024047805, 0.25797623, -67.45156],
+    [-0.00023626331, 0.25228792, -65.551155],
+    [-0.00023429502, 0.24897517, -64.26458],
+    [-0.00023423521, 0.24762419, -63.465122],
+    [-0.00023577806, 0.24784033, -63.025845],
+    [-0.00023844809, 0.24900922, -62.749],
+    [-0.00024095977, 0.24954574, -62.14629],
+    [-0.00023781392, 0.2429392, -59.29381],
+    [-0.00014749933, 0.13424104, -26.626654],
+    [-0.00047887614, 0.5313356, -145.29765],
+    [-0.00036932045, 0.40808752, -110.896774],
+    [-0.00031464247, 0.34629744, -93.556244],
+    [-0.0002817452, 0.30886877, -82.9686],
+    [-0.00026016784, 0.2840707, -75.87219],
+    [-0.00024546217, 0.26690912, -70.876396],
+    [-0.00023539863, 0.2548741, -67.28005],
+    [-0.00022873783, 0.24656226, -64.68836],
+    [-0.00022474171, 0.24112758, -62.859917],
+    [-0.0002229456, 0.23802374, -61.63432],
+    [-0.00022302735, 0.23685388, -60.88929],
+    [-0.00022469643, 0.23723966, -60.502052],
+    [-0.00022749361, 0.23858315, -60.27872],
+    [-0.00023014382, 0.23930572, -59.73101],
+    [-0.00022704423, 0.23275754, -56.889416],
+    [-0.00012956241, 0.115422614, -21.631565],
+    [-0.00046032635, 0.51281446, -140.67061],
+    [-0.0003543125, 0.39326087, -107.23538],
+    [-0.00030125055, 0.33315423, -90.33413],
+    [-0.00026930784, 0.29672498, -80.00825],
+    [-0.00024837744, 0.27261063, -73.0922],
+    [-0.00023415245, 0.25596362, -68.23345],
+    [-0.00022447182, 0.244345, -64.7492],
+    [-0.00021813637, 0.23639318, -62.255497],
+    [-0.00021443475, 0.23129012, -60.518158],
+    [-0.00021292451, 0.22851288, -59.38283],
+    [-0.00021330625, 0.22768871, -58.73341],
+    [-0.00021531738, 0.22846869, -58.45453],
+    [-0.00021853852, 0.23029579, -58.362267],
+    [-0.00022174878, 0.23164365, -57.97988],
+    [-0.00021935036, 0.22583891, -55.322308],
+    [-0.00011555894, 0.10069347, -17.661112],
+    [-0.0004437553, 0.4965364, -136.66447],
+    [-0.0003404638, 0.37976897, -103.9462],
+    [-0.0002886845, 0.3209788, -87.38426],
+    [-0.00025752856, 0.285365, -77.27015],
+    [-0.0002371613, 0.26184246, -70.50934],
+    [-0.00022338705, 0.24567658, -65.77809],
+    [-0.0002141012, 0.23448585, -62.40825],
+    [-0.00020813923, 0.22694425, -60.024944],
+    [-0.00020481639, 0.22226131, -58.401024],
+    [-0.00020371596, 0.21994124, -57.38907],
+    [-0.00020456959, 0.21964404, -56.88131],
+    [-0.00020715829, 0.2210666, -56.773907],
+    [-0.00021113016, 0.22372214, -56.9003],
+    [-0.00021537476, 0.22619575, -56.81034],
+    [-0.00021443906, 0.22194017, -54.542355],
+    [-0.00010531932, 0.08993406, -14.693344],
+    [-0.0004283543, 0.4816991, -133.0804],
+    [-0.00032702333, 0.36686715, -100.84499],
+    [-0.00027619995, 0.30903396, -84.52476],
+    [-0.0002456602, 0.27405044, -74.57262],
+    [-0.00022576988, 0.2510273, -67.942314],
+    [-0.00021241787, 0.23531264, -63.330505],
+    [-0.00020354537, 0.2245703, -60.080265],
+    [-0.00019801773, 0.21750447, -57.82425],
+    [-0.00019517724, 0.21335267, -56.342396],
+    [-0.00019463775, 0.21165127, -55.495464],
+    [-0.00019617258, 0.21210448, -55.18691],
+    [-0.00019962685, 0.21447705, -55.330025],
+    [-0.00020475461, 0.21839316, -55.78599],
+    [-0.00021063532, 0.22263408, -56.15285],
+    [-0.00021216055, 0.22099285, -54.547928],
+    [-9.913668e-5, 0.08354139, -12.843633],
+    [-0.00041299147, 0.46719006, -129.6452],
+    [-0.00031281158, 0.35339007, -97.644615],
+    [-0.00026255954, 0.29609695, -81.4545],
+    [-0.00023241389, 0.26150906, -71.60294],
+    [-0.00021287687, 0.23885828, -65.07073],
+    [-0.00019989983, 0.22354963, -60.56749],
+    [-0.00019145886, 0.21327972, -57.443954],
+    [-0.00018644638, 0.20677954, -55.339306],
+    [-0.00018423129, 0.20331427, -54.040413],
+    [-0.00018446308, 0.20245828, -53.41771],
+    [-0.00018696995, 0.20397456, -53.38965],
+    [-0.00019168889, 0.2077258, -53.894436],
+    [-0.00019853763, 0.21350956, -54.836998],
+    [-0.00020690923, 0.2204358, -55.89749],
+    [-0.00021236068, 0.22298203, -55.361126],
+    [-9.7970085e-5, 0.08269286, -12.442131],
+    [-0.00039545415, 0.45082906, -125.8225],
+    [-0.00029534442, 0.33687752, -93.73756],
+    [-0.00024498094, 0.27941108, -77.49321],
+    [-0.00021473382, 0.24471612, -67.616035],
+    [-0.00019521189, 0.22210342, -61.10021],
+    [-0.00018242515, 0.20702633, -56.66499],
+    [-0.00017438267, 0.1972103, -53.66721],
+    [-0.00016999771, 0.19140613, -51.75055],
+    [-0.00016865914, 0.18890028, -50.70715],
+    [-0.00017004817, 0.18930244, -50.416603],
+    [-0.00017405432, 0.19244342, -50.814953],
+    [-0.00018073666, 0.19831887, -51.87465],
+    [-0.00019025864, 0.20699053, -53.568066],
+    [-0.00020253457, 0.21812241, -55.71562],
+    [-0.0002143703, 0.22750464, -56.932056],
+    [-0.00010393105, 0.08999527, -14.226483],
+    [-0.00036957036, 0.42649004, -120.09497],
+    [-0.00026644694, 0.3091938, -87.106224],
+    [-0.00021283599, 0.2484079, -70.02472],
+    [-0.0001795522, 0.21070008, -59.408886],
+    [-0.00015765023, 0.1857799, -52.34098],
+    [-0.00014341442, 0.16935275, -47.59736],
+    [-0.00013498192, 0.15925975, -44.560215],
+    [-0.00013129016, 0.15427011, -42.875893],
+    [-0.0001316927, 0.15363505, -42.32712],
+    [-0.00013580677, 0.15690641, -42.77963],
+    [-0.00014345822, 0.16386458, -44.158737],
+    [-0.00015470941, 0.1745319, -46.446823],
+    [-0.00016993919, 0.18922609, -49.685192],
+    [-0.00018982698, 0.20845316, -53.912327],
+    [-0.00021357893, 0.23057765, -58.39984],
+    [-0.00012048774, 0.11024281, -19.639732],
+    [-0.00028669267, 0.34557816, -100.35669],
+    [-0.000119071585, 0.16401426, -51.43565],
+    [-1.737308e-5, 0.05631629, -22.980389],
+    [4.695653e-5, -0.011077696, -5.3250027],
+    [8.947231e-5, -0.055307094, 6.2139983],
+    [0.0001182378, -0.08507417, 13.972095],
+    [0.0001377746, -0.10519632, 19.228092],
+    [0.00015066839, -0.1184103, 22.705463],
+    [0.00015827692, -0.12615883, 24.789152],
+    [0.00016099284, -0.12889095, 25.61082],
+    [0.00015821701, -0.12605812, 25.055225],
+    [0.00014795146, -0.11573061, 22.673773],
+    [0.00012569984, -0.09353829, 17.438839],
+    [8.1650855e-5, -0.049962174, 7.120775],
+    [-5.3090257e-6, 0.035182867, -12.869811],
+    [-1.1513766e-6, 0.0012429268, 5.6439567],
+    [-0.00090538705, 0.97648305, -260.8287],
+    [-0.0006702666, 0.7186555, -190.53767],
+    [-0.00056827115, 0.6062632, -159.72243],
+    [-0.0005117517, 0.5435029, -142.36278],
+    [-0.00047731044, 0.5047798, -131.50089],
+    [-0.00045592262, 0.48020333, -124.44221],
+    [-0.0004434392, 0.46520585, -119.935776],
+    [-0.00043780796, 0.45750213, -117.3475],
+    [-0.0004380674, 0.4559844, -116.358315],
+    [-0.0004439521, 0.46028218, -116.84186],
+    [-0.00045577373, 0.47061673, -118.81997],
+    [-0.0004744717, 0.487827, -122.460304],
+    [-0.00050185825, 0.51357514, -128.1122],
+    [-0.00054110854, 0.5507346, -136.3648],
+    [-0.0005967589, 0.6029403, -147.78218],
+    [-0.0006469306, 0.64136595, -152.97227],
+    [-0.000795269, 0.8607612, -230.62152],
+    [-0.00059774617, 0.6432281, -171.06693],
+    [-0.00050890894, 0.5449141, -143.99866],
+    [-0.00045858033, 0.48879907, -128.41568],
+    [-0.00042726906, 0.45347384, -118.47493],
+    [-0.00040726358, 0.430454, -111.85627],
+    [-0.0003949325, 0.41572833, -107.45777],
+    [-0.00038841116, 0.40721786, -104.70213],
+    [-0.00038674142, 0.40382782, -103.27695],
+    [-0.0003895107, 0.4050457, -103.02327],
+    [-0.0003967039, 0.4107686, -103.885284],
+    [-0.00040865561, 0.42123216, -105.88491],
+    [-0.0004260501, 0.43697292, -109.09946],
+    [-0.00044977787, 0.45858422, -113.56738],
+    [-0.0004790593, 0.48438826, -118.567665],
+    [-0.00046981196, 0.46226802, -108.702],
+    [-0.00071586476, 0.77751535, -208.93478],
+    [-0.00054353086, 0.5869956, -156.58472],
+    [-0.00046386645, 0.49849886, -132.13116],
+    [-0.00041800932, 0.4471833, -117.831726],
+    [-0.00038905742, 0.41441607, -108.58353],
+    [-0.0003701994, 0.39267668, -102.32325],
+    [-0.00035817348, 0.37835085, -98.05515],
+    [-0.0003512643, 0.36952236, -95.24629],
+    [-0.00034854436, 0.36513436, -93.59614],
+    [-0.00034954344, 0.36462086, -92.93435],
+    [-0.00035409583, 0.36773106, -93.17018],
+    [-0.0003622546, 0.37442103, -94.25857],
+    [-0.00037419508, 0.38472036, -96.154785],
+    [-0.00038982806, 0.3982428, -98.6603],
+    [-0.00040621334, 0.41112605, -100.51796],
+    [-0.0003624581, 0.3525381, -81.151924],
+    [-0.00065605994, 0.71501154, -192.69356],
+    [-0.0005014191, 0.54346514, -145.4055],
+    [-0.00042847585, 0.46215916, -122.867256],
+    [-0.00038599144, 0.41446126, -109.53492],
+    [-0.00035888405, 0.38369027, -100.826225],
+    [-0.00034099148, 0.3630206, -94.86298],
+    [-0.00032932666, 0.3491326, -90.72815],
+    [-0.00032229326, 0.34023896, -87.92372],
+    [-0.0003190007, 0.3353253, -86.1615],
+    [-0.0003189595, 0.33380976, -85.26851],
+    [-0.00032192952, 0.33536926, -85.13736],
+    [-0.00032781868, 0.33981642, -85.68881],
+    [-0.00033653973, 0.3469193, -86.81521],
+    [-0.00034750297, 0.35578775, -88.19513],
+    [-0.00035662006, 0.36137292, -88.271866],
+    [-0.000287093, 0.27463743, -61.281437],
+    [-0.0006094192, 0.6664562, -180.11755],
+    [-0.00046775528, 0.50881433, -136.53807],
+    [-0.00039991128, 0.4329579, -115.450455],
+    [-0.00036005166, 0.38807136, -102.8689],
+    [-0.00033442694, 0.3589013, -94.591866],
+    [-0.0003173599, 0.33914065, -88.879234],
+    [-0.00030607378, 0.3256948, -84.87397],
+    [-0.00029906814, 0.3168798, -82.10592],
+    [-0.00029548962, 0.3117228, -80.29853],
+    [-0.00029484666, 0.30964246, -79.27996],
+    [-0.00029686326, 0.31028178, -78.93523],
+    [-0.00030136976, 0.31337702, -79.16709],
+    [-0.0003081345, 0.31855208, -79.83311],
+    [-0.00031628532, 0.3246294, -80.54067],
+    [-0.00032101676, 0.3257974, -79.533676],
+    [-0.00023034359, 0.21536845, -45.940445],
+    [-0.000572087, 0.627786, -170.14352],
+    [-0.0004402222, 0.48062503, -129.35643],
+    [-0.00037634288, 0.40899685, -109.39274],
+    [-0.00033857377, 0.3663444, -97.40668],
+    [-0.00031416773, 0.33848634, -89.48236],
+    [-0.00029781877, 0.31951174, -83.984924],
+    [-0.0002869146, 0.30650142, -80.10405],
+    [-0.00028003348, 0.29785585, -77.39215],
+    [-0.00027635644, 0.29263982, -75.58306],
+    [-0.00027540023, 0.29028255, -74.50828],
+    [-0.00027687443, 0.29041407, -74.050224],
+    [-0.00028057012, 0.29273266, -74.102806],
+    [-0.00028617773, 0.29678407, -74.50464],
+    [-0.00029266326, 0.30122337, -74.82071],
+    [-0.00029473408, 0.29970407, -73.14973],
+    [-0.00018578704, 0.16836363, -33.59724],
+    [-0.00054158503, 0.5963959, -162.09145],
+    [-0.0004172583, 0.45727256, -123.44106],
+    [-0.00035651334, 0.38897607, -104.36077],
+    [-0.00032043812, 0.34812778, -92.85428],
+    [-0.0002970527, 0.32136446, -85.22315],
+    [-0.0002813396, 0.30308187, -79.91383],
+    [-0.00027081743, 0.2904998, -76.15284],
+    [-0.00026412946, 0.2820881, -73.51107],
+    [-0.0002604894, 0.27694684, -71.731865],
+    [-0.00025942773, 0.2745208, -70.65114],
+    [-0.00026065388, 0.27444124, -70.15208],
+    [-0.0002639453, 0.27639386, -70.12583],
+    [-0.0002689581, 0.2798902, -70.40266],
+    [-0.00027457575, 0.28349742, -70.52436],
+    [-0.00027517707, 0.28049394, -68.48335],
+    [-0.00014948391, 0.12962842, -23.259016],
+    [-0.000516221, 0.5705154, -155.50085],
+    [-0.0003977483, 0.43760222, -118.4951],
+    [-0.00033950704, 0.37195358, -100.114105],
+    [-0.00030482223, 0.33257928, -88.99781],
+    [-0.00028230564, 0.3067437, -81.613976],
+    [-0.00026716647, 0.28908205, -76.47223],
+    [-0.0002570284, 0.27692512, -72.82833],
+    [-0.00025058948, 0.2688007, -70.26849],
+    [-0.00024709478, 0.26384237, -68.54495],
+    [-0.00024609253, 0.2615142, -67.498825],
+    [-0.0002473032, 0.2614599, -67.01675],
+    [-0.00025051023, 0.26337248, -66.99193],
+    [-0.00025537005, 0.26676422, -67.25475],
+    [-0.000260747, 0.27017894, -67.33972],
+    [-0.00026082864, 0.26666135, -65.16829],
+    [-0.00011891899, 0.09656543, -14.262497],
+    [-0.00049476774, 0.5488703, -150.04266],
+    [-0.00038084443, 0.42074335, -114.29623],
+    [-0.0003246112, 0.35720217, -96.46835],
+    [-0.00029107742, 0.31904015, -85.67116],
+    [-0.0002693113, 0.29400152, -78.49853],
+    [-0.00025470046, 0.27690822, -73.50903],
+    [-0.00024495384, 0.26518038, -69.98174],
+    [-0.00023881684, 0.2573954, -67.51583],
+    [-0.00023556415, 0.25271797, -65.87173],
+    [-0.00023476692, 0.25063685, -64.89697],
+    [-0.00023616651, 0.25081816, -64.484055],
+    [-0.00023957077, 0.25298098, -64.532875],
+    [-0.000244669, 0.25667185, -64.88221],
+    [-0.00025036818, 0.2604751, -65.07511],
+    [-0.00025080962, 0.25735363, -63.000874],
+    [-9.240825e-5, 0.0673933, -6.1360006],
+    [-0.0004762707, 0.5304823, -145.4669],
+    [-0.0003658455, 0.4059845, -110.66469],
+    [-0.0003112143, 0.34410498, -93.26873],
+    [-0.0002786345, 0.30693883, -82.731606],
+    [-0.0002575233, 0.28259128, -75.74093],
+    [-0.00024340813, 0.26602867, -70.89257],
+    [-0.00023406756, 0.25474286, -67.48412],
+    [-0.00022828837, 0.24735415, -65.12583],
+    [-0.00022537535, 0.2430589, -63.58627],
+    [-0.00022492734, 0.241375, -62.72052],
+    [-0.00022671775, 0.24200274, -62.429802],
+    [-0.0002305989, 0.24470815, -62.625896],
+    [-0.00023632956, 0.2491102, -63.165524],
+    [-0.00024293146, 0.253909, -63.619247],
+    [-0.00024467785, 0.25217456, -61.892765],
+    [-6.874355e-5, 0.040778253, 1.4958962],
+    [-0.00045991119, 0.51452756, -141.56676],
+    [-0.00035208615, 0.39266068, -107.43478],
+    [-0.0002986997, 0.33204818, -90.36298],
+    [-0.00026689837, 0.29568473, -80.033264],
+    [-0.0002463586, 0.27193645, -73.19929],
+    [-0.00023271565, 0.25587785, -68.48422],
+    [-0.0002238045, 0.24505787, -65.20009],
+    [-0.00021844897, 0.23813474, -62.96677],
+    [-0.00021598535, 0.23433761, -61.561184],
+    [-0.00021604677, 0.23322064, -60.84769],
+    [-0.0002184525, 0.2345327, -60.739872],
+    [-0.0002231247, 0.23811364, -61.168175],
+    [-0.00022993943, 0.2437053, -62.02],
+    [-0.00023813083, 0.2502267, -62.919518],
+    [-0.00024235329, 0.25112006, -61.8571],
+    [-4.6989215e-5, 0.015604987, 8.979296],
+    [-0.00044484407, 0.5001739, -138.1374],
+    [-0.00033877013, 0.379986, -104.412926],
+    [-0.00028627398, 0.3202489, -87.558586],
+    [-0.00025507118, 0.28449222, -77.3834],
+    [-0.00023501711, 0.26125094, -70.681335],
+    [-0.00022182579, 0.24567501, -66.0937],
+    [-0.00021337712, 0.23535721, -62.943214],
+    [-0.00020852867, 0.22898985, -60.858227],
+    [-0.00020665115, 0.225838, -59.624744],
+    [-0.00020742083, 0.22550145, -59.11875],
+    [-0.00021072163, 0.22779793, -59.271378],
+    [-0.00021658152, 0.23267959, -60.04153],
+    [-0.00022506442, 0.24008636, -61.366474],
+    [-0.00023576837, 0.24931712, -62.964897],
+    [-0.00024412591, 0.2546094, -63.02039],
+    [-2.6371004e-5, -0.009184679, 16.693153],
+    [-0.00042984812, 0.48622987, -134.88829],
+    [-0.00032460538, 0.3666888, -101.28687],
+    [-0.000272576, 0.3073661, -84.52616],
+    [-0.00024173359, 0.27196383, -74.43932],
+    [-0.00022203897, 0.24910016, -67.836136],
+    [-0.00020926016, 0.23397104, -63.36761],
+    [-0.00020131229, 0.2242018, -60.363857],
+    [-0.00019708408, 0.2185158, -58.460754],
+    [-0.00019598313, 0.21621676, -57.454056],
+    [-0.00019774091, 0.21696365, -57.234882],
+    [-0.00020233159, 0.22067109, -57.759167],
+    [-0.00020994252, 0.22746174, -59.02933],
+    [-0.00022093972, 0.23759672, -61.064064],
+    [-0.0002355496, 0.2510378, -63.74888],
+    [-0.00025071655, 0.26360086, -65.66066],
+    [-6.2376016e-6, -0.03475271, 25.145588],
+    [-0.00041240276, 0.4702229, -131.2137],
+    [-0.00030673813, 0.34994912, -97.36168],
+    [-0.00025439583, 0.29022443, -80.483246],
+    [-0.00022335169, 0.25460646, -70.3416],
+    [-0.00020363988, 0.23174916, -63.746788],
+    [-0.00019108101, 0.21688761, -59.35634],
+    [-0.00018362458, 0.20767643, -56.50628],
+    [-0.00018018577, 0.2028648, -54.838448],
+    [-0.00018020422, 0.2017912, -54.15739],
+    [-0.00018346836, 0.2041779, -54.370705],
+    [-0.00019006581, 0.21006466, -55.46686],
+    [-0.00020041577, 0.2198265, -57.5134],
+    [-0.00021537658, 0.23425454, -60.664745],
+    [-0.00023631412, 0.25452656, -65.11555],
+    [-0.0002633004, 0.2797691, -70.278595],
+    [1.3323877e-5, -0.062040363, 35.048145],
+    [-0.00038488721, 0.4446025, -125.24657],
+    [-0.00027483195, 0.31948817, -90.09],
+    [-0.00021799022, 0.25516617, -72.050606],
+    [-0.00018275052, 0.21538214, -60.88529],
+    [-0.00015973518, 0.18931514, -53.519604],
+    [-0.00014516599, 0.17257124, -48.695694],
+    [-0.00013718526, 0.16298108, -45.78868],
+    [-0.00013475132, 0.15932973, -44.446785],
+    [-0.00013726756, 0.16092129, -44.465385],
+    [-0.0001444413, 0.16740899, -45.736237],
+    [-0.00015627498, 0.17877287, -48.236687],
+    [-0.00017324851, 0.19549525, -52.06779],
+    [-0.00019679188, 0.21902727, -57.559994],
+    [-0.00023032294, 0.25279498, -65.49001],
+    [-0.00028144848, 0.3040274, -77.34088],
+    [2.4270874e-5, -0.08514982, 46.211655],
+    [-0.00025329375, 0.3155899, -93.653496],
+    [-3.0038065e-5, 0.07860571, -30.97274],
+    [9.666053e-5, -0.053013645, 3.1805718],
+    [0.00017480241, -0.13310258, 23.730297],
+    [0.00022655238, -0.18551286, 37.05767],
+    [0.0002636455, -0.22257781, 46.39295],
+    [0.00029310468, -0.25153768, 53.59961],
+    [0.0003193091, -0.2768258, 59.80068],
+    [0.00034515007, -0.30130112, 65.70638],
+    [0.00037295185, -0.3271849, 71.85292],
+    [0.00040515233, -0.35671994, 78.763985],
+    [0.00044506893, -0.39286456, 87.11095],
+    [0.0004981745, -0.44037876, 97.95027],
+    [0.0005747239, -0.5078809, 113.14064],
+    [0.0006897076, -0.605554, 134.45744],
+    [-5.984073e-5, 0.06460172, 5.419727],
+    [-0.00090538705, 0.9764752, -260.8225],
+    [-0.00067194377, 0.7204489, -191.0103],
+    [-0.0005696582, 0.60773987, -160.10924],
+    [-0.0005130286, 0.5448571, -142.71571],
+    [-0.00047855626, 0.5060964, -131.84247],
+    [-0.00045718622, 0.48153424, -124.78601],
+    [-0.00044475906, 0.46659145, -120.29222],
+    [-0.00043922346, 0.45898327, -117.72695],
+    [-0.0004396231, 0.45760685, -116.77224],
+    [-0.0004457056, 0.46210465, -117.304825],
+    [-0.00045780616, 0.4727215, -119.35225],
+    [-0.00047690634, 0.4903385, -123.09236],
+    [-0.0005048968, 0.516696, -128.89323],
+    [-0.0005451153, 0.55482817, -137.38235],
+    [-0.0006024632, 0.6087249, -149.20575],
+    [-0.00065511046, 0.6494062, -154.86368],
+    [-0.0007973699, 0.8630277, -231.22491],
+    [-0.0005991402, 0.6447234, -171.46182],
+    [-0.00051008, 0.54616475, -144.32697],
+    [-0.00045966456, 0.48995256, -128.71693],
+    [-0.0004283279, 0.4545964, -118.76676],
+    [-0.00040833527, 0.43158633, -112.149376],
+    [-0.00039604702, 0.41690204, -107.76032],
+    [-0.00038959773, 0.4084633, -105.021835],
+    [-0.00038803226, 0.40517816, -103.622116],
+    [-0.00039094643, 0.40654242, -103.40421],
+    [-0.00039833804, 0.41246593, -104.315315],
+    [-0.00041056704, 0.4232097, -106.38344],
+    [-0.00042835684, 0.43934873, -109.69496],
+    [-0.00045267204, 0.4615487, -114.30511],
+    [-0.0004828572, 0.48824593, -119.516884],
+    [-0.00047363844, 0.46588197, -109.49615],
+    [-0.00071764283, 0.7794393, -209.44804],
+    [-0.0005447474, 0.5883045, -156.93114],
+    [-0.0004648992, 0.4996052, -132.42221],
+    [-0.00041896928, 0.44820777, -118.09986],
+    [-0.00038999558, 0.41541377, -108.84345],
+    [-0.00037114762, 0.39368168, -102.58395],
+    [-0.00035915663, 0.37938946, -98.323425],
+    [-0.00035230618, 0.37061933, -95.52846],
+    [-0.00034967047, 0.366316, -93.8988],
+    [-0.00035078535, 0.3659195, -93.26552],
+    [-0.00035549392, 0.3691876, -93.5399],
+    [-0.00036386543, 0.37609246, -94.68069],
+    [-0.00037610013, 0.38668802, -96.648796],
+    [-0.00039215002, 0.40062737, -99.2545],
+    [-0.00040912008, 0.41408363, -101.24567],
+    [-0.00036404602, 0.3538018, -81.338486],
+    [-0.0006576354, 0.7167214, -193.15067],
+    [-0.00050251983, 0.54465306, -145.72058],
+    [-0.00042941698, 0.46317053, -123.133896],
+    [-0.0003868686, 0.4154003, -109.781235],
+    [-0.0003597418, 0.3846053, -101.06513],
+    [-0.00034185775, 0.3639416, -95.1024],
+    [-0.00033022303, 0.35008246, -90.97402],
+    [-0.00032324044, 0.34123933, -88.18158],
+    [-0.0003200207, 0.3363989, -86.43705],
+    [-0.00032007816, 0.33498305, -85.56834],
+    [-0.00032318037, 0.33667633, -85.46981],
+    [-0.0003292465, 0.3413024, -86.06482],
+    [-0.0003382073, 0.34864664, -87.24968],
+    [-0.00034949995, 0.3578442, -88.70834],
+    [-0.0003590463, 0.36384627, -88.8806],
+    [-0.00028712774, 0.27421662, -61.014908],
+    [-0.0006108642, 0.6680291, -180.53899],
+    [-0.00046877927, 0.5099229, -136.83276],
+    [-0.00040079115, 0.43390638, -115.70109],
+    [-0.00036087324, 0.38895372, -103.10086],
+    [-0.00033523072, 0.35976154, -94.81697],
+    [-0.0003181715, 0.3400063, -89.10476],
+    [-0.00030691293, 0.32658687, -85.1054],
+    [-0.0002999532, 0.3178175, -82.34816],
+    [-0.00029644032, 0.3127266, -80.55674],
+    [-0.00029588613, 0.31073612, -79.560036],
+    [-0.00029802055, 0.31149483, -79.244415],
+    [-0.0003026839, 0.31474885, -79.51494],
+    [-0.0003096586, 0.32013553, -80.23218],
+    [-0.0003180922, 0.32649538, -81.00719],
+    [-0.0003231726, 0.32799995, -80.07617],
+    [-0.00022917801, 0.21361038, -45.30293],
+    [-0.0005734474, 0.6292714, -170.5424],
+    [-0.00044119547, 0.48168215, -129.63809],
+    [-0.0003771819, 0.4099043, -109.63309],
+    [-0.00033935803, 0.36718947, -97.62937],
+    [-0.00031493555, 0.33931082, -89.69862],
+    [-0.00029859407, 0.32034144, -84.2016],
+    [-0.00028771567, 0.3073558, -80.32622],
+    [-0.00028087813, 0.29875365, -77.62462],
+    [-0.0002772629, 0.2936, -75.83061],
+    [-0.00027639023, 0.2913275, -74.77648],
+    [-0.00027797496, 0.29157132, -74.34585],
+    [-0.00028181655, 0.29403788, -74.434494],
+    [-0.00028761855, 0.29828572, -74.8839],
+    [-0.00029436298, 0.30298403, -75.2618],
+    [-0.00029674184, 0.3017606, -73.65692],
+    [-0.00018356556, 0.16539948, -32.615826],
+    [-0.0005428927, 0.59782845, -162.47705],
+    [-0.00041819937, 0.45829818, -123.71502],
+    [-0.00035732592, 0.38985798, -104.59495],
+    [-0.00032119852, 0.34895003, -93.0715],
+    [-0.0002977974, 0.32216692, -85.43416],
+    [-0.0002820919, 0.30388972, -80.125336],
+    [-0.00027159514, 0.29133216, -76.36982],
+    [-0.0002649496, 0.2829629, -73.73814],
+    [-0.00026137, 0.27788284, -71.97377],
+    [-0.00026038958, 0.27553946, -70.91322],
+    [-0.00026172335, 0.27556956, -70.440994],
+    [-0.00026515685, 0.27766678, -70.45008],
+    [-0.0002703584, 0.28135443, -70.77334],
+    [-0.0002762275, 0.28521398, -70.95541],
+    [-0.00027712333, 0.28249353, -68.977486],
+    [-0.00014620816, 0.12543333, -21.918432],
+    [-0.00051750115, 0.57192254, -155.88062],
+    [-0.00039867204, 0.43861258, -118.76571],
+    [-0.00034030518, 0.37282306, -100.34561],
+    [-0.0003055695, 0.33339036, -89.21267],
+    [-0.00028303795, 0.30753577, -81.82282],
+    [-0.00026790684, 0.28988007, -76.681725],
+    [-0.0002577948, 0.27774838, -73.04352],
+    [-0.00025139857, 0.26966688, -70.49393],
+    [-0.0002479646, 0.26477024, -68.78539],
+    [-0.0002470442, 0.26252565, -67.75974],
+    [-0.00024836315, 0.26258212, -67.304855],
+    [-0.000251714, 0.2646416, -67.316055],
+    [-0.00025676575, 0.26822868, -67.626465],
+    [-0.0002623997, 0.27190247, -67.77366],
+    [-0.00026278623, 0.2686798, -65.668434],
+    [-0.000114478506, 0.09098572, -12.510477],
+    [-0.0004960395, 0.55027366, -150.42244],
+    [-0.00038176205, 0.42175105, -114.566925],
+    [-0.00032540382, 0.35806918, -96.69991],
+    [-0.00029181966, 0.31984913, -85.88612],
+    [-0.00027003928, 0.29479215, -78.70764],
+    [-0.00025543733, 0.2777058, -73.71904],
+    [-0.00024571773, 0.2660043, -70.19775],
+    [-0.00023962514, 0.25826412, -67.7426],
+    [-0.00023643552, 0.25365117, -66.11424],
+    [-0.00023572326, 0.25165722, -65.16094],
+    [-0.00023723597, 0.25195476, -64.77669],
+    [-0.00024079089, 0.25427222, -64.86357],
+    [-0.0002460919, 0.2581705, -65.263664],
+    [-0.00025206665, 0.26225314, -65.52409],
+    [-0.0002528452, 0.25946113, -63.524876],
+    [-8.655974e-5, 0.060116902, -3.8730328],
+    [-0.00047754927, 0.531899, -145.85153],
+    [-0.0003667654, 0.40699935, -110.93824],
+    [-0.00031200788, 0.34497717, -93.50248],
+    [-0.00027937762, 0.30775258, -82.9486],
+    [-0.00025825278, 0.28338733, -75.95221],
+    [-0.0002441479, 0.2668331, -71.10512],
+    [-0.00023483638, 0.2555759, -67.70326],
+    [-0.00022910451, 0.2482353, -65.356606],
+    [-0.00022625866, 0.24400905, -63.833996],
+    [-0.00022590144, 0.24241883, -62.991417],
+    [-0.00022781343, 0.24317214, -62.73182],
+    [-0.000231858, 0.24604617, -62.969635],
+    [-0.00023781158, 0.2506775, -63.56572],
+    [-0.00024472296, 0.25579226, -64.09637],
+    [-0.00024686847, 0.25445324, -62.46166],
+    [-6.1055056e-5, 0.031262267, 4.439853],
+    [-0.00046121102, 0.515975, -141.96121],
+    [-0.00035301596, 0.39369202, -107.71389],
+    [-0.00029949975, 0.33293235, -90.60094],
+    [-0.00026764715, 0.2965094, -80.25409],
+    [-0.0002470945, 0.27274406, -73.41452],
+    [-0.00023346372, 0.25669587, -68.70123],
+    [-0.0002245849, 0.2459081, -65.42464],
+    [-0.00021928102, 0.23903783, -63.2042],
+    [-0.00021689099, 0.23531678, -61.81744],
+    [-0.00021705237, 0.23430355, -61.129765],
+    [-0.00021959275, 0.23575553, -61.05682],
+    [-0.0002244487, 0.23952726, -61.53261],
+    [-0.00023151888, 0.24538338, -62.449993],
+    [-0.00024007523, 0.25228024, -63.441704],
+    [-0.00024480297, 0.2536818, -62.499718],
+    [-3.6699206e-5, 0.0028977084, 12.900835],
+    [-0.00044617607, 0.50166625, -138.54593],
+    [-0.0003397132, 0.38103917, -104.69939],
+    [-0.00028708144, 0.3211478, -87.80177],
+    [-0.00025582587, 0.28532952, -77.6088],
+    [-0.00023575973, 0.26207194, -70.9013],
+    [-0.00022258318, 0.24650918, -66.316154],
+    [-0.00021417106, 0.23622821, -63.174404],
+    [-0.00020938113, 0.22992128, -61.10429],
+    [-0.00020758648, 0.2268557, -59.892315],
+    [-0.00020846963, 0.22663774, -59.41604],
+    [-0.00021192538, 0.2290963, -59.60933],
+    [-0.0002179997, 0.23420209, -60.435658],
+    [-0.00022678805, 0.2419274, -61.840137],
+    [-0.00023794596, 0.25162917, -63.555286],
+    [-0.00024698596, 0.2576183, -63.77921],
+    [-1.2033798e-5, -0.026886983, 22.153551],
+    [-0.0004312157, 0.48777458, -135.3137],
+    [-0.00032555562, 0.36776027, -101.58036],
+    [-0.0002733816, 0.30827242, -84.77323],
+    [-0.00024248358, 0.27280515, -74.667595],
+    [-0.00022277753, 0.2499257, -68.05907],
+    [-0.00021001691, 0.23481351, -63.593994],
+    [-0.00020211174, 0.22508788, -60.60076],
+    [-0.00019795138, 0.21947256, -58.71524],
+    [-0.00019694715, 0.21727505, -57.73407],
+    [-0.00019883839, 0.21816246, -57.550377],
+    [-0.00020361386, 0.22206467, -58.1239],
+    [-0.00021148611, 0.2291306, -59.46357],
+    [-0.00022286786, 0.23966986, -61.60007],
+    [-0.0002380793, 0.2537409, -64.44251],
+    [-0.0002542485, 0.2673423, -66.60984],
+    [1.5250106e-5, -0.0613279, 33.35326],
+    [-0.000413785, 0.4718047, -131.6535],
+    [-0.0003076594, 0.35100624, -97.65485],
+    [-0.000255155, 0.29109666, -80.7245],
+    [-0.0002240468, 0.2554045, -70.5616],
+    [-0.00020432098, 0.23252891, -63.960815],
+    [-0.00019178317, 0.21768773, -59.574776],
+    [-0.00018437815, 0.2085299, -56.73785],
+    [-0.00018102153, 0.2038048, -55.0918],
+    [-0.00018115886, 0.202857, -54.44268],
+    [-0.00018458867, 0.20541953, -54.700764],
+    [-0.00019141851, 0.21155317, -55.859837],
+    [-0.00020210592, 0.22167347, -57.997643],
+    [-0.0002175831, 0.2366494, -61.288147],
+    [-0.00023938554, 0.2578366, -65.97031],
+    [-0.00026802038, 0.28481168, -71.56668],
+    [5.022624e-5, -0.10785806, 49.246937],
+    [-0.00038613376, 0.446079, -125.66704],
+    [-0.00027549174, 0.32030362, -90.32738],
+    [-0.00021837067, 0.2556773, -72.20587],
+    [-0.00018295636, 0.21570924, -60.992176],
+    [-0.00015983748, 0.18953654, -53.599327],
+    [-0.00014523459, 0.17276105, -48.767605],
+    [-0.00013729116, 0.16321315, -45.8717],
+    [-0.00013496878, 0.15968089, -44.5604],
+    [-0.00013767708, 0.16147493, -44.630684],
+    [-0.0001451319, 0.16825825, -45.976856],
+    [-0.00015735427, 0.18003207, -48.58181],
+    [-0.00017486827, 0.19732761, -52.559307],
+    [-0.00019922394, 0.2217259, -58.273075],
+    [-0.000234182, 0.25702003, -66.59311],
+    [-0.00028857, 0.3117352, -79.32854],
+    [0.00011133576, -0.19414678, 80.24547],
+    [-0.00024840704, 0.31100288, -92.57783],
+    [-1.9868397e-5, 0.068813406, -28.618519],
+    [0.00010909685, -0.064934865, 6.0320344],
+    [0.00018838042, -0.14603707, 26.803606],
+    [0.00024090627, -0.19910581, 40.267467],
+    [0.0002788819, -0.23693646, 49.766476],
+    [0.00030967075, -0.26709506, 57.24176],
+    [0.000337866, -0.29421154, 63.861248],
+    [0.0003666101, -0.3213742, 70.38727],
+    [0.00039868447, -0.3512268, 77.45337],
+    [0.0004374209, -0.38684282, 85.775665],
+    [0.00048809103, -0.43299958, 96.447945],
+    [0.0005610685, -0.49902046, 111.58658],
+    [0.0006827196, -0.60852677, 136.53491],
+    [0.00090538705, -0.80850464, 181.95712],
+    [-0.00090538705, 1.0269499, -253.57063],
+    [0.00090538705, -0.90967524, 225.43646],
+    [-0.00090538705, 0.63856554, -115.47078],
+    [-0.00020034418, -0.2812841, 126.053055],
+    [0.00074715243, -0.96348166, 249.23541],
+    [0.00090538705, -1.069226, 266.8896],
+    [0.00090538705, -1.0612991, 263.94757],
+    [0.00090538705, -1.0570225, 262.32553],
+    [0.00090538705, -1.0546178, 261.39667],
+    [0.00090538705, -1.0539861, 261.12244],
+    [0.00090538705, -1.0521845, 260.40433],
+    [0.00090538705, -1.0518783, 260.2582],
+    [0.00090538705, -1.0513631, 260.0315],
+    [0.00090538705, -1.0497837, 259.40033],
+    [0.00090538705, -1.0495151, 259.2661],
+    [0.00090538705, -1.0494179, 259.208],
+    [0.00090538705, -1.0489786, 258.97845],
+    [-0.00090538705, 0.78077537, -174.23793],
+    [-0.00090538705, 0.46134266, -51.069855],
+    [7.924077e-5, -0.48258528, 162.33376],
+    [0.0006735447, -0.91048414, 239.59283],
+    [0.0009027739, -1.0755291, 269.48245],
+    [0.00090538705, -1.0637751, 264.83282],
+    [0.00090538705, -1.0584531, 262.81766],
+    [0.00090538705, -1.0553722, 261.61868],
+    [0.00090538705, -1.053748, 260.96216],
+    [0.00090538705, -1.0524061, 260.40448],
+    [0.00090538705, -1.051362, 259.951],
+    [0.00090538705, -1.0510095, 259.76523],
+    [0.00090538705, -1.0497166, 259.23282],
+    [0.00090538705, -1.0488585, 258.83585],
+    [0.00090538705, -1.0486062, 258.68396],
+    [0.00090538705, -1.0484663, 258.58997],
+    [-0.00090538705, 0.7545566, -165.8269],
+    [-0.00090538705, 0.41341352, -40.25518],
+    [0.00019653628, -0.56703806, 177.4567],
+    [0.0006317469, -0.8803897, 234.09828],
+    [0.0008612612, -1.04564, 263.99265],
+    [0.00090538705, -1.0655692, 265.40964],
+    [0.00090538705, -1.059441, 263.09067],
+    [0.00090538705, -1.0551349, 261.40863],
+    [0.00090538705, -1.0531315, 260.5822],
+    [0.00090538705, -1.0524629, 260.2565],
+    [0.00090538705, -1.0508046, 259.57492],
+    [0.00090538705, -1.050266, 259.29068],
+    [0.00090538705, -1.0497408, 259.03873],
+    [0.00090538705, -1.0484022, 258.47018],
+    [0.00090538705, -1.0479268, 258.20187],
+    [0.00090538705, -1.0477332, 258.06836],
+    [-0.00090538705, 0.75087464, -167.09837],
+    [-0.00090538705, 0.50285393, -80.48791],
+    [0.00022546346, -0.5878656, 179.80559],
+    [0.00060465233, -0.8608816, 230.44917],
+    [0.0008111219, -1.0095397, 257.37735],
+    [0.00090538705, -1.0711356, 267.37064],
+    [0.00090538705, -1.0604634, 263.37915],
+    [0.00090538705, -1.0561739, 261.6944],
+    [0.00090538705, -1.0539001, 260.74905],
+    [0.00090538705, -1.0519354, 259.89078],
+    [0.00090538705, -1.0509001, 259.3978],
+    [0.00090538705, -1.0495926, 258.8072],
+    [0.00090538705, -1.0490744, 258.48624],
+    [0.00090538705, -1.0479957, 257.97876],
+    [0.00090538705, -1.0471594, 257.56097],
+    [0.00090538705, -1.0467013, 257.25427],
+    [-0.00090538705, 0.7626043, -174.24411],
+    [-0.00090538705, 0.6072158, -123.42538],
+    [-0.00012453247, -0.33586854, 118.84858],
+    [0.00054993405, -0.82148445, 220.98424],
+    [0.00077016815, -0.98005295, 251.83388],
+    [0.00089542085, -1.0702349, 268.2479],
+    [0.00090538705, -1.0625031, 264.00552],
+    [0.00090538705, -1.0566037, 261.67484],
+    [0.00090538705, -1.0542812, 260.67026],
+    [0.00090538705, -1.0523244, 259.775],
+    [0.00090538705, -1.0504401, 258.88412],
+    [0.00090538705, -1.048964, 258.17792],
+    [0.00090538705, -1.0481641, 257.73477],
+    [0.00090538705, -1.0475776, 257.3213],
+    [0.00090538705, -1.0462477, 256.68854],
+    [0.00090538705, -1.0457623, 256.38135],
+    [-0.00090538705, 0.78098446, -183.47461],
+    [-0.00090538705, 0.6866626, -155.13614],
+    [-0.00090538705, 0.27431688, -32.55085],
+    [0.00030137232, -0.64252, 174.16437],
+    [0.0006766995, -0.91275555, 233.8016],
+    [0.0008511243, -1.0383414, 261.18774],
+    [0.00090538705, -1.0668355, 265.2816],
+    [0.00090538705, -1.0575335, 261.61523],
+    [0.00090538705, -1.0538847, 260.00262],
+    [0.00090538705, -1.0515492, 258.8613],
+    [0.00090538705, -1.0498506, 257.9295],
+    [0.00090538705, -1.0481099, 256.98373],
+    [0.00090538705, -1.0467843, 256.2583],
+    [0.00090538705, -1.0456365, 255.56114],
+    [0.00090538705, -1.0450319, 255.05447],
+    [0.00090538705, -1.0433396, 254.20451],
+    [-0.00090538705, 0.8004981, -192.62897],
+    [-0.00090538705, 0.74273753, -177.15102],
+    [-0.00090538705, 0.5893286, -136.58794],
+    [-0.00024473615, -0.24932188, 70.515495],
+    [0.00046545808, -0.7606617, 190.28763],
+    [0.0007396242, -0.95806134, 236.37537],
+    [0.00088373927, -1.0618242, 260.474],
+    [0.00090538705, -1.0581895, 258.7213],
+    [0.00090538705, -1.051213, 256.39236],
+    [0.00090538705, -1.0481164, 255.0615],
+    [0.00090538705, -1.0462571, 254.0562],
+    [0.00090538705, -1.0443422, 253.02574],
+    [0.00090538705, -1.0424795, 252.0193],
+    [0.00090538705, -1.0415456, 251.24533],
+    [0.00090538705, -1.0395747, 250.21411],
+    [0.00090538705, -1.0386697, 249.44907],
+    [-0.00090538705, 0.8186449, -200.83812],
+    [-0.00090538705, 0.78262025, -192.64415],
+    [-0.00090538705, 0.70952463, -176.32425],
+    [-0.00090538705, 0.48659956, -126.97273],
+    [1.251335e-5, -0.43454152, 96.07701],
+    [0.0005417659, -0.8156033, 191.4222],
+    [0.000774251, -0.98299265, 233.18498],
+    [0.0009040579, -1.0764536, 256.40027],
+    [0.00090538705, -1.0492967, 250.12953],
+    [0.00090538705, -1.0423917, 248.05176],
+    [0.00090538705, -1.0392, 246.74936],
+    [0.00090538705, -1.0366151, 245.57802],
+    [0.00090538705, -1.0346408, 244.53276],
+    [0.00090538705, -1.0335509, 243.64905],
+    [0.00090538705, -1.0315316, 242.59953],
+    [0.00090538705, -1.0305046, 241.72255],
+    [-0.00090538705, 0.8346201, -207.90034],
+    [-0.00090538705, 0.8117912, -203.89658],
+    [-0.00090538705, 0.77261543, -197.21977],
+    [-0.00090538705, 0.6909993, -183.508],
+    [-0.00090538705, 0.42174187, -138.65166],
+    [0.00010011413, -0.4976141, 90.1565],
+    [0.00057411724, -0.83889633, 182.58348],
+    [0.0007928991, -0.99641925, 225.13043],
+    [0.00090538705, -1.071446, 245.91365],
+    [0.00090538705, -1.0398107, 240.42549],
+    [0.00090538705, -1.0324908, 238.57463],
+    [0.00090538705, -1.0289907, 237.28975],
+    [0.00090538705, -1.0264419, 236.14561],
+    [0.00090538705, -1.0245136, 235.08621],
+    [0.00090538705, -1.0225564, 234.02774],
+    [0.00090538705, -1.0214293, 233.0579],
+    [-0.00090538705, 0.84837484, -213.8845],
+    [-0.00090538705, 0.8337854, -212.33989],
+    [-0.00090538705, 0.8114078, -210.1011],
+    [-0.00090538705, 0.7732189, -206.3955],
+    [-0.00090538705, 0.69430685, -198.89755],
+    [-0.00090538705, 0.43889737, -174.95555],
+    [8.390221e-5, -0.4859415, 57.664177],
+    [0.0005751152, -0.83961487, 163.18529],
+    [0.0008001287, -1.0016246, 211.40129],
+    [0.00090538705, -1.0666976, 232.84372],
+    [0.00090538705, -1.0327107, 229.49687],
+    [0.00090538705, -1.0216315, 227.83716],
+    [0.00090538705, -1.0174224, 226.61871],
+    [0.00090538705, -1.0149403, 225.5007],
+    [0.00090538705, -1.0129759, 224.40927],
+    [0.00090538705, -1.0109183, 223.3226],
+    [-0.00090538705, 0.8601366, -218.94205],
+    [-0.00090538705, 0.85083926, -218.86504],
+    [-0.00090538705, 0.8376574, -218.84341],
+    [-0.00090538705, 0.8177488, -218.88527],
+    [-0.00090538705, 0.7845522, -219.0436],
+    [-0.00090538705, 0.7187388, -219.48799],
+    [-0.00090538705, 0.52782714, -221.04204],
+    [-5.4575834e-5, -0.38623732, -17.052153],
+    [0.00054245564, -0.8161, 127.78338],
+    [0.0007965109, -0.9990198, 189.2744],
+    [0.00090538705, -1.0631096, 215.6214],
+    [0.00090538705, -1.0274998, 215.74097],
+    [0.00090538705, -1.0094669, 215.49199],
+    [0.00090538705, -1.0047472, 214.5288],
+    [0.00090538705, -1.0016681, 213.48358],
+    [0.00090538705, -0.9993836, 212.38489],
+    [-0.00090538705, 0.8702006, -223.23132],
+    [-0.00090538705, 0.86439085, -224.03905],
+    [-0.00090538705, 0.8566058, -225.17557],
+    [-0.00090538705, 0.8457649, -226.81105],
+    [-0.00090538705, 0.8297856, -229.26872],
+    [-0.00090538705, 0.80410326, -233.29019],
+    [-0.00090538705, 0.7563868, -240.87549],
+    [-0.00090538705, 0.6377944, -259.94067],
+    [-0.00032272932, -0.055009555, -138.37083],
+    [0.00045375145, -0.75223297, 59.949257],
+    [0.0007784022, -0.9859815, 152.05614],
+    [0.00090538705, -1.0603012, 190.6879],
+    [0.00090538705, -1.0212604, 196.79102],
+    [0.00090538705, -0.99906355, 200.14389],
+    [0.00090538705, -0.9904487, 200.59659],
+    [0.00090538705, -0.9869853, 199.79135],
+    [-0.00090538705, 0.8788488, -226.89204],
+    [-0.00090538705, 0.8753895, -228.23308],
+    [-0.00090538705, 0.87093705, -229.98529],
+    [-0.00090538705, 0.865073, -232.32085],
+    [-0.00090538705, 0.8570845, -235.5266],
+    [-0.00090538705, 0.845663, -240.15033],
+    [-0.00090538705, 0.8281154, -247.31503],
+    [-0.00090538705, 0.79788446, -259.76056],
+    [-0.00090538705, 0.7337551, -286.34753],
+    [-0.00057374453, 0.32133606, -241.32368],
+    [0.00015253075, -0.41095454, -71.824356],
+    [0.00073241355, -0.95286965, 81.04985],
+    [0.00090538705, -1.0580406, 149.98637],
+    [0.00090538705, -1.0128359, 168.57628],
+    [0.00090538705, -0.98909056, 178.18597],
+    [0.00090538705, -0.97454023, 183.91483],
+    [-0.00090538705, 0.88632506, -230.03973],
+    [-0.00090538705, 0.88447964, -231.69742],
+    [-0.00090538705, 0.8821638, -233.76912],
+    [-0.00090538705, 0.8792249, -236.39522],
+    [-0.00090538705, 0.8754247, -239.79535],
+    [-0.00090538705, 0.8703728, -244.32532],
+    [-0.00090538705, 0.8633879, -250.62326],
+    [-0.00090538705, 0.8531621, -259.90225],
+    [-0.00090538705, 0.8368354, -274.81992],
+    [-0.00090538705, 0.80672234, -302.51324],
+    [-0.0008067855, 0.65297383, -330.51117],
+    [-0.00021825127, 0.06338305, -194.53253],
+    [0.00036371997, -0.51931745, -60.183826],
+    [0.00090538705, -1.0562042, 69.480545],
+    [0.00090538705, -0.99968183, 121.57171],
+    [0.00090538705, -0.9742705, 144.73366],
+    [-0.00090538705, 0.8928314, -232.76747],
+    [-0.00090538705, 0.89211094, -234.60574],
+    [-0.00090538705, 0.8912043, -236.82959],
+    [-0.00090538705, 0.89006877, -239.54684],
+    [-0.00090538705, 0.88864124, -242.91853],
+    [-0.00090538705, 0.8868255, -247.17737],
+    [-0.00090538705, 0.88447005, -252.70041],
+    [-0.00090538705, 0.88132304, -260.11154],
+    [-0.00090538705, 0.8769338, -270.52264],
+    [-0.00090538705, 0.8704116, -286.1289],
+    [-0.00090538705, 0.8597174, -311.95236],
+    [-0.00090538705, 0.83894897, -362.5422],
+    [-0.00053070567, 0.45772043, -295.9999],
+    [-5.1376148e-5, -0.0196971, -186.2507],
+    [0.00041815575, -0.4870949, -78.83881],
+    [0.0008763456, -0.94293225, 25.881227],
+    [-0.00090538705, 0.898532, -235.14941],
+    [-0.00090538705, 0.89860487, -237.08165],
+    [-0.00090538705, 0.89864707, -239.36078],
+    [-0.00090538705, 0.8986612, -242.06866],
+    [-0.00090538705, 0.8986494, -245.3199],
+    [-0.00090538705, 0.8986135, -249.27466],
+    [-0.00090538705, 0.89855504, -254.16219],
+    [-0.00090538705, 0.8984751, -260.3383],
+    [-0.00090538705, 0.898375, -268.35492],
+    [-0.00090538705, 0.8982554, -279.13672],
+    [-0.00090538705, 0.8981175, -294.3463],
+    [-0.00090538705, 0.89796126, -317.31015],
+    [-0.00090538705, 0.8977931, -355.78574],
+    [-0.0007967219, 0.7898692, -381.09775],
+    [-0.00039383012, 0.39036208, -289.48996],
+    [-2.7038377e-7, 0.000292632, -200.07031],
+    [-0.00090538705, 0.91180074, -227.01857],
+    [-0.00090538705, 0.6270586, -110.30743],
+    [0.00018455666, -0.55841273, 184.58861],
+    [0.00084472477, -1.0337337, 270.91406],
+    [0.00090538705, -1.0461525, 267.00095],
+    [0.00090538705, -1.0325085, 261.80338],
+    [0.00090538705, -1.0250529, 258.94028],
+    [0.00090538705, -1.0208395, 257.2992],
+    [0.00090538705, -1.0180081, 256.17966],
+    [0.00090538705, -1.0152274, 255.08147],
+    [0.00090538705, -1.0135028, 254.38034],
+    [0.00090538705, -1.0115304, 253.58757],
+    [0.00090538705, -1.0098631, 252.90973],
+    [0.00090538705, -1.0086343, 252.39574],
+    [0.00090538705, -1.0072509, 251.82382],
+    [0.00090538705, -1.0057641, 251.21329],
+    [-0.00090538705, 0.7760883, -171.55515],
+    [-0.0008514939, 0.42617908, -44.801247],
+    [0.00030459487, -0.6345936, 194.58815],
+    [0.00077792094, -0.985635, 261.023],
+    [0.00090538705, -1.057495, 270.5483],
+    [0.00090538705, -1.0377778, 263.14157],
+    [0.00090538705, -1.0276867, 259.31888],
+    [0.00090538705, -1.0221821, 257.20215],
+    [0.00090538705, -1.0183287, 255.69907],
+    [0.00090538705, -1.0157588, 254.67006],
+    [0.00090538705, -1.0131533, 253.62871],
+    [0.00090538705, -1.0114388, 252.91302],
+    [0.00090538705, -1.0094503, 252.09721],
+    [0.00090538705, -1.0078022, 251.40546],
+    [0.00090538705, -1.0065187, 250.84608],
+    [0.00090538705, -1.005107, 250.2418],
+    [-0.00090538705, 0.751426, -164.08275],
+    [-0.00058946747, 0.2671371, -25.342833],
+    [0.00026293623, -0.5353264, 161.23111],
+    [0.0007226192, -0.9458177, 252.16403],
+    [0.00090538705, -1.0765731, 276.3503],
+    [0.00090538705, -1.0442308, 264.4527],
+    [0.00090538705, -1.0317174, 259.80984],
+    [0.00090538705, -1.02373, 256.82266],
+    [0.00090538705, -1.01899, 255.01866],
+    [0.00090538705, -1.0154054, 253.6374],
+    [0.00090538705, -1.0129564, 252.66972],
+    [0.00090538705, -1.0105387, 251.7166],
+    [0.00090538705, -1.0088907, 251.04286],
+    [0.00090538705, -1.0070086, 250.2869],
+    [0.00090538705, -1.0054288, 249.6408],
+    [0.00090538705, -1.0041983, 249.12022],
+    [-0.00090538705, 0.7488858, -165.94061],
+    [-0.00057580695, 0.31867468, -50.678482],
+    [0.00014810938, -0.37839484, 115.604034],
+    [0.0005631309, -0.7682522, 205.84723],
+    [0.00084392383, -1.0284135, 265.00623],
+    [0.00090538705, -1.0550946, 267.13257],
+    [0.00090538705, -1.0365617, 260.5015],
+    [0.00090538705, -1.0266663, 256.90515],
+    [0.00090538705, -1.020306, 254.5542],
+    [0.00090538705, -1.0160352, 252.93939],
+    [0.00090538705, -1.0126586, 251.64107],
+    [0.00090538705, -1.0102518, 250.6816],
+    [0.00090538705, -1.007898, 249.7439],
+    [0.00090538705, -1.0062147, 249.03851],
+    [0.00090538705, -1.0043691, 248.28122],
+    [0.00090538705, -1.0027266, 247.59671],
+    [-0.00090538705, 0.7612909, -173.40474],
+    [-0.00063570566, 0.4255691, -86.269714],
+    [-5.105963e-5, -0.14121255, 49.931927],
+    [0.00036379122, -0.54235893, 145.99536],
+    [0.0006414506, -0.80701214, 208.29478],
+    [0.0008280121, -0.9813103, 248.33426],
+    [0.00090538705, -1.0442016, 261.67868],
+    [0.00090538705, -1.030812, 256.99347],
+    [0.00090538705, -1.0223368, 253.97513],
+    [0.00090538705, -1.0166402, 251.90845],
+    [0.00090538705, -1.0126841, 250.43892],
+    [0.00090538705, -1.0094802, 249.22914],
+    [0.00090538705, -1.007167, 248.32173],
+    [0.00090538705, -1.0048693, 247.42233],
+    [0.00090538705, -1.0031922, 246.7293],
+    [0.00090538705, -1.0014484, 246.01466],
+    [-0.00090538705, 0.78008187, -182.8124],
+    [-0.0007171088, 0.5432789, -122.52651],
+    [-0.0002304943, 0.06948767, -8.139049],
+    [0.00013411132, -0.28518045, 77.358215],
+    [0.00041093773, -0.5539975, 142.0165],
+    [0.000621212, -0.7574334, 190.74004],
+    [0.0007688659, -0.89842993, 224.0091],
+    [0.0008563863, -0.9806041, 243.0659],
+    [0.00090478244, -1.0249263, 253.10768],
+    [0.00090538705, -1.0179867, 250.67336],
+    [0.00090538705, -1.0126774, 248.81154],
+    [0.00090538705, -1.00884, 247.42418],
+    [0.00090538705, -1.0057462, 246.27867],
+    [0.00090538705, -1.0034463, 245.38617],
+    [0.00090538705, -1.0011722, 244.50644],
+    [0.00090538705, -0.9994106, 243.78622],
+    [-0.00090538705, 0.79986066, -192.06892],
+    [-0.00080204767, 0.65750116, -156.59464],
+    [-0.00038589118, 0.25094497, -58.083965],
+    [-6.273107e-5, -0.064616, 18.306892],
+    [0.00019143206, -0.3125935, 78.264824],
+    [0.000393155, -0.5091508, 125.71602],
+    [0.0005540401, -0.6656163, 163.41063],
+    [0.00067789986, -0.78565437, 192.23038],
+    [0.00075724465, -0.86180377, 210.35318],
+    [0.0008044363, -0.90626574, 220.76294],
+    [0.0008364119, -0.9357705, 227.54205],
+    [0.00085894176, -0.9560267, 232.08618],
+    [0.0008732928, -0.9682982, 234.70833],
+    [0.0008821839, -0.97525597, 236.05557],
+    [0.0008879095, -0.97914577, 236.66916],
+    [0.00089186296, -0.9813295, 236.87666],
+    [-0.00090538705, 0.8181879, -200.33803],
+    [-0.0008839237, 0.7637029, -187.73537],
+    [-0.00052062975, 0.4078068, -101.24347],
+    [-0.00023070455, 0.123846285, -32.27634],
+    [3.2533953e-6, -0.105189726, 23.307262],
+    [0.00019369341, -0.29148182, 68.47333],
+    [0.00034969285, -0.4439182, 105.38651],
+    [0.00047794284, -0.5690544, 135.6435],
+    [0.00058124657, -0.6696134, 159.90479],
+    [0.0006543367, -0.74032515, 176.87541],
+    [0.0007011393, -0.7850416, 187.49474],
+    [0.0007325678, -0.81456363, 194.40463],
+    [0.00075649767, -0.8366935, 199.51393],
+    [0.0007726283, -0.8511238, 202.74593],
+    [0.00078317453, -0.8600462, 204.63614],
+    [0.0007908976, -0.86619836, 205.85434],
+    [-0.00090538705, 0.83429134, -207.43495],
+    [-0.00090538705, 0.8115195, -203.5567],
+    [-0.00063819234, 0.54444003, -138.84055],
+    [-0.0003753808, 0.28639317, -75.99696],
+    [-0.00015894331, 0.07393486, -24.283669],
+    [2.0710364e-5, -0.10233388, 18.592205],
+    [0.00017076306, -0.24945797, 54.349625],
+    [0.0002966671, -0.3727886, 84.29451],
+    [0.00040259422, -0.47642234, 109.42673],
+    [0.0004911498, -0.56291133, 130.36826],
+    [0.0005592429, -0.6291482, 146.35156],
+    [0.0006064323, -0.674659, 157.25572],
+    [0.0006385958, -0.70526665, 164.50766],
+    [0.0006627618, -0.72795385, 169.82118],
+    [0.00068138045, -0.74515235, 173.79263],
+    [0.0006940549, -0.75645965, 176.32173],
+    [-0.00090538705, 0.84814036, -213.43797],
+    [-0.00090538705, 0.83358836, -212.00009],
+    [-0.0007415081, 0.6644036, -171.86147],
+    [-0.000501214, 0.42796353, -114.14655],
+    [-0.0003000009, 0.23000132, -65.84217],
+    [-0.00013031703, 0.06310515, -25.137318],
+    [1.361599e-5, -0.07840044, 9.355042],
+    [0.0001362779, -0.19891824, 38.71112],
+    [0.00024117866, -0.30189997, 63.774788],
+    [0.0003310841, -0.39006692, 85.211395],
+    [0.00040812048, -0.46551093, 103.5323],
+    [0.00047143863, -0.52736235, 118.520096],
+    [0.0005191756, -0.5737388, 129.70761],
+    [0.00055338454, -0.606666, 137.59045],
+    [0.0005781274, -0.6301822, 143.16129],
+    [0.0005978413, -0.64870894, 147.50847],
+    [-0.00090538705, 0.85997325, -218.50398],
+    [-0.00090538705, 0.8506995, -218.5195],
+    [-0.00083293824, 0.77052027, -201.08434],
+    [-0.0006116403, 0.5523674, -147.72595],
+    [-0.00042374077, 0.36714262, -102.43323],
+    [-0.00026318, 0.20889066, -63.748962],
+    [-0.00012523902, 0.07297087, -30.537428],
+    [-6.196492e-6, -0.04427872, -1.9022056],
+    [9.691262e-5, -0.14577743, 22.87144],
+    [0.0001864714, -0.233873, 44.358395],
+    [0.00026440367, -0.31046143, 63.022987],
+    [0.00033225247, -0.37706384, 79.237686],
+    [0.00039066936, -0.4343145, 93.15661],
+    [0.00043809434, -0.48063827, 104.388466],
+    [0.0004741674, -0.515658, 112.83742],
+    [0.0005010175, -0.5414884, 119.023384],
+    [-0.00090538705, 0.870092, -222.79472],
+    [-0.00090538705, 0.8642958, -223.68394],
+    [-0.00090538705, 0.8565253, -224.89172],
+    [-0.0007093154, 0.6625509, -177.51161],
+    [-0.0005331392, 0.48858526, -134.89256],
+    [-0.00038090398, 0.33826822, -98.075584],
+    [-0.00024870355, 0.20775418, -66.1183],
+    [-0.00013341234, 0.09396398, -38.266],
+    [-3.2505817e-5, -0.005590672, -13.908683],
+    [5.6072415e-5, -0.09293786, 7.4509153],
+    [0.00013400927, -0.16974202, 26.220966],
+    [0.00020269485, -0.23737533, 42.73799],
+    [0.00026327313, -0.29696736, 57.27903],
+    [0.0003166499, -0.34941202, 70.063225],
+    [0.00036246388, -0.39433643, 80.9966],
+    [0.0003998738, -0.43088645, 89.865974],
+    [-0.00090538705, 0.878783, -226.45204],
+    [-0.00090538705, 0.8753299, -227.86584],
+    [-0.00090538705, 0.8708851, -229.68237],
+    [-0.00079631637, 0.76081955, -204.11272],
+    [-0.0006305348, 0.5968689, -163.88045],
+    [-0.00048589747, 0.4538268, -128.78445],
+    [-0.0003591326, 0.32846886, -98.033615],
+    [-0.00024758978, 0.21818152, -70.98662],
+    [-0.00014910338, 0.120828, -47.119015],
+    [-6.188845e-5, 0.034646332, -25.998272],
+    [1.5535486e-5, -0.04182572, -7.2653913],
+    [8.440403e-5, -0.10980905, 9.379435],
+    [0.00014575278, -0.1703273, 24.187592],
+    [0.0002004507, -0.22423977, 37.370113],
+    [0.0002492203, -0.27226087, 49.10245],
+    [0.00029256954, -0.31489164, 59.50741],
+    [-0.00090538705, 0.88629305, -229.59294],
+    [-0.00090538705, 0.88444847, -231.31631],
+    [-0.00090538705, 0.88213515, -233.44666],
+    [-0.00087428396, 0.8489961, -228.01204],
+    [-0.0007177775, 0.6940062, -189.92188],
+    [-0.00058008486, 0.55763876, -156.41158],
+    [-0.0004584375, 0.4371628, -126.81043],
+    [-0.00035056748, 0.33033967, -100.568504],
+    [-0.00025460232, 0.23532033, -77.23148],
+    [-0.0001689844, 0.1505658, -56.421173],
+    [-9.240933e-5, 0.074786626, -37.82065],
+    [-2.3778155e-5, 0.0068960492, -21.162766],
+    [3.7839745e-5, -0.054026697, -6.221138],
+    [9.323611e-5, -0.10876527, 7.1969314],
+    [0.00014308411, -0.15798615, 19.255333],
+    [0.00018795776, -0.20225784, 30.093874],
+    [-0.00090538705, 0.89282626, -232.31142],
+    [-0.00090538705, 0.89210266, -234.20958],
+    [-0.00090538705, 0.8911947, -236.48738],
+    [-0.00090538705, 0.8900591, -239.25311],
+    [-0.0007963553, 0.78161806, -213.4406],
+    [-0.0006650237, 0.6513838, -181.39261],
+    [-0.0005481779, 0.53550905, -152.88057],
+    [-0.0004438621, 0.43206164, -127.429405],
+    [-0.0003504469, 0.33943126, -104.64307],
+    [-0.00026656454, 0.25626537, -84.18885],
+    [-0.00019106049, 0.18142183, -65.78575],
+    [-0.00012295449, 0.11393044, -49.195053],
+    [-6.140974e-5, 0.05296266, -34.212833],
+    [-5.7087973e-6, -0.0021922176, -20.664162],
+    [4.476581e-5, -0.05214607, -8.398406],
+    [9.054711e-5, -0.09742729, 2.714529],
+    [-0.00090538705, 0.89854836, -234.68236],
+    [-0.00090538705, 0.89861506, -236.66965],
+    [-0.00090538705, 0.8986531, -238.99875],
+    [-0.00090538705, 0.8986645, -241.75194],
+    [-0.0008674714, 0.86101735, -234.78073],
+    [-0.00074198114, 0.7364306, -204.08334],
+    [-0.00062963564, 0.62488437, -176.60022],
+    [-0.00052873534, 0.5246987, -151.91788],
+    [-0.00043785278, 0.43446147, -129.6887],
+    [-0.0003557811, 0.3529785, -109.61869],
+    [-0.0002814939, 0.2792336, -91.4576],
+    [-0.00021411355, 0.21235758, -74.99136],
+    [-0.0001528859, 0.15160316, -60.0359],
+    [-9.715955e-5, 0.09632459, -46.432114],
+    [-4.6371293e-5, 0.045963418, -34.042397],
+    [-2.9746076e-8, 3.2112315e-5, -22.746605],
+    [0.00090538705, -0.9217957, 230.3761],
+    [-0.0005438412, 0.37227443, -64.30948],
+    [0.00018582208, -0.30607715, 91.584404],
+    [0.00052321196, -0.62029684, 163.69322],
+    [0.0006338219, -0.7217839, 186.69409],
+    [0.00066414307, -0.74741644, 192.09917],
+    [0.00067728944, -0.75694877, 193.79224],
+    [0.0006821187, -0.7587561, 193.7169],
+    [0.0006823196, -0.75632167, 192.68065],
+    [0.00067991985, -0.7515581, 191.1265],
+    [0.0006763819, -0.74584687, 189.37488],
+    [0.0006718514, -0.7393116, 187.45201],
+    [0.00066693476, -0.7325215, 185.48929],
+    [0.0006616169, -0.725451, 183.47874],
+    [0.00065601006, -0.71819824, 181.44173],
+    [0.000650343, -0.71097493, 179.4266],
+    [-0.00090538705, 0.7722007, -169.36043],
+    [-0.00022123862, 0.10791262, -10.453948],
+    [0.00015039678, -0.24200241, 70.99845],
+    [0.00035919348, -0.43797594, 116.36397],
+    [0.0004700651, -0.5415447, 140.22293],
+    [0.00051476306, -0.5822965, 149.4222],
+    [0.00053516135, -0.59996855, 153.23605],
+    [0.00054668746, -0.6092574, 155.10199],
+    [0.0005530696, -0.61370766, 155.84752],
+    [0.0005561585, -0.6150778, 155.88283],
+    [0.0005572473, -0.61459833, 155.49538],
+    [0.0005571117, -0.6130068, 154.85736],
+    [0.0005561209, -0.61065215, 154.04987],
+    [0.00055451645, -0.60776174, 153.12552],
+    [0.0005524872, -0.60451305, 152.12546],
+    [0.0005500858, -0.600953, 151.05998],
+    [-0.00060437445, 0.50038034, -108.85473],
+    [-0.00015426504, 0.068363324, -5.970487],
+    [9.7878576e-5, -0.17209934, 50.78844],
+    [0.00025190524, -0.31803682, 84.93774],
+    [0.0003505573, -0.41116387, 106.63208],
+    [0.00040642076, -0.46344322, 118.716484],
+    [0.00043429807, -0.48894674, 124.49955],
+    [0.00045018617, -0.50299245, 127.59002],
+    [0.00046037626, -0.51161206, 129.40915],
+    [0.00046696793, -0.51681495, 130.42961],
+    [0.00047107338, -0.51966465, 130.90134],
+    [0.00047353483, -0.520968, 131.01419],
+    [0.00047486805, -0.5212187, 130.88428],
+    [0.00047542987, -0.52075845, 130.59193],
+    [0.0004753958, -0.51975393, 130.17616],
+    [0.00047491438, -0.51834667, 129.67007],
+    [-0.000453003, 0.37413713, -82.70217],
+    [-0.00015149509, 0.08294624, -12.929045],
+    [4.2168398e-5, -0.10366852, 31.622976],
+    [0.00016925973, -0.22543435, 60.481113],
+    [0.0002565204, -0.30863395, 80.08267],
+    [0.00031551486, -0.36460686, 93.202675],
+    [0.0003520258, -0.39892656, 101.18131],
+    [0.00037317348, -0.4184401, 105.64669],
+    [0.0003866333, -0.43055493, 108.35934],
+    [0.0003959254, -0.4386696, 110.12666],
+    [0.000402474, -0.44415727, 111.2744],
+    [0.00040703977, -0.44775048, 111.976074],
+    [0.0004101852, -0.4499913, 112.36018],
+    [0.00041231912, -0.45127398, 112.52022],
+    [0.0004137169, -0.45186394, 112.51907],
+    [0.0004145611, -0.45193708, 112.398384],
+    [-0.00039150065, 0.32884082, -74.75985],
+    [-0.00016793914, 0.11190018, -22.527079],
+    [-1.2284418e-5, -0.039018735, 13.74506],
+    [9.857323e-5, -0.14627962, 39.449],
+    [0.00017843605, -0.22324349, 57.803482],
+    [0.00023671152, -0.27915174, 71.06905],
+    [0.00027796303, -0.31851605, 80.35998],
+    [0.00030565582, -0.3447178, 86.497734],
+    [0.00032341635, -0.36127847, 90.328804],
+    [0.00033547988, -0.37231645, 92.840675],
+    [0.00034421045, -0.38013062, 94.584114],
+    [0.00035069633, -0.38577822, 95.81209],
+    [0.00035552573, -0.38982984, 96.66094],
+    [0.00035910585, -0.39268047, 97.225],
+    [0.00036175462, -0.39463866, 97.57798],
+    [0.0003637019, -0.39592755, 97.773186],
+    [-0.00036637447, 0.31542656, -73.80113],
+    [-0.00018997314, 0.14360142, -32.269947],
+    [-6.042057e-5, 0.017452475, -1.8112563],
+    [3.667662e-5, -0.077010535, 20.963493],
+    [0.00011048111, -0.14869975, 38.21236],
+    [0.00016701188, -0.20347069, 51.352196],
+    [0.00021021302, -0.24517396, 61.318127],
+    [0.00024222364, -0.275918, 68.629105],
+    [0.000265167, -0.2977936, 73.79744],
+    [0.00028104786, -0.3127655, 77.30052],
+    [0.00029232036, -0.32323766, 79.71984],
+    [0.00030073762, -0.3309271, 81.47025],
+    [0.000307206, -0.33672085, 82.765785],
+    [0.00031223026, -0.34111238, 83.72537],
+    [0.00031613634, -0.34441987, 84.42562],
+    [0.0003191723, -0.3468857, 84.92485],
+    [-0.00035799097, 0.31609398, -75.79497],
+    [-0.00021288382, 0.17431183, -41.41503],
+    [-0.00010206562, 0.066043675, -15.179615],
+    [-1.6002125e-5, -0.018002557, 5.167908],
+    [5.1703304e-5, -0.084067196, 21.143398],
+    [0.00010546607, -0.13646147, 33.794254],
+    [0.00014839123, -0.17821923, 43.857563],
+    [0.00018254355, -0.21135782, 51.823425],
+    [0.00020904104, -0.23696794, 57.957146],
+    [0.00022906747, -0.25621182, 62.54251],
+    [0.00024383371, -0.27028155, 65.87066],
+    [0.00025470677, -0.2805241, 68.26991],
+    [0.00026300206, -0.28823635, 70.056015],
+    [0.00026951885, -0.29420587, 71.42054],
+    [0.00027472, -0.29888836, 72.474205],
+    [0.00027889767, -0.30257118, 73.28674],
+    [-0.0003578982, 0.32330522, -79.060135],
+    [-0.00023494454, 0.20285417, -49.772953],
+    [-0.00013817578, 0.10805156, -26.732538],
+    [-6.094505e-5, 0.032405525, -8.358949],
+    [1.3870997e-6, -0.02861941, 6.4519396],
+    [5.2137773e-5, -0.07826881, 18.490417],
+    [9.372617e-5, -0.11891159, 28.333435],
+    [0.00012793619, -0.15229566, 36.406754],
+    [0.00015601631, -0.17964412, 43.00822],
+    [0.00017868428, -0.2016576, 48.30819],
+    [0.00019659795, -0.21897995, 52.463406],
+    [0.00021053861, -0.2323799, 55.66144],
+    [0.00022122006, -0.24256112, 58.074135],
+    [0.00022952721, -0.25039884, 59.915455],
+    [0.0002361532, -0.25657922, 61.35317],
+    [0.00024153634, -0.261536, 62.4933],
+    [-0.0003620558, 0.33353674, -82.827774],
+    [-0.00025555096, 0.2289645, -57.341507],
+    [-0.00016969659, 0.14465779, -36.80057],
+    [-9.9667806e-5, 0.07589568, -20.0539],
+    [-4.198878e-5, 0.019274324, -6.271343],
+    [5.8971264e-6, -0.027711973, 5.1583495],
+    [4.590409e-5, -0.06694064, 14.693269],
+    [7.948528e-5, -0.099837966, 22.681486],
+    [0.00010775286, -0.12749608, 29.389563],
+    [0.00013153005, -0.15072337, 35.01476],
+    [0.0001513293, -0.17002141, 39.67924],
+    [0.00016755161, -0.18578254, 43.478508],
+    [0.0001806903, -0.1984922, 46.531097],
+    [0.00019119476, -0.2085935, 48.945297],
+    [0.00019956173, -0.2165779, 50.841423],
+    [0.00020632429, -0.22297415, 52.349106],
+    [-0.00036838715, 0.34503138, -86.72635],
+    [-0.00027454997, 0.25271523, -64.18059],
+    [-0.00019741531, 0.1768175, -45.64826],
+    [-0.00013336666, 0.11379392, -30.26379],
+    [-7.972893e-5, 0.06102082, -17.38628],
+    [-3.4488363e-5, 0.01652166, -6.5327883],
+    [3.8955036e-6, -0.021216566, 2.666443],
+    [3.6616824e-5, -0.05336754, 10.498306],
+    [6.4611435e-5, -0.08085161, 17.187805],
+    [8.861674e-5, -0.10439431, 22.912334],
+    [0.00010920592, -0.124559656, 27.80978],
+    [0.00012677108, -0.1417327, 31.974146],
+    [0.00014159034, -0.15618587, 35.47182],
+    [0.00015395823, -0.16820875, 38.373528],
+    [0.0001642034, -0.17812586, 40.758694],
+    [0.00017261274, -0.1862208, 42.69677],
+    [-0.00037576992, 0.35687765, -90.571945],
+    [-0.00029197318, 0.27429503, -70.36627],
+    [-0.0002219644, 0.20528594, -53.483707],
+    [-0.00016296015, 0.14711858, -39.256153],
+    [-0.00011285599, 0.09772618, -27.178],
+    [-7.003562e-5, 0.05552024, -16.860575],
+    [-3.3240223e-5, 0.019262781, -8.0008955],
+    [-1.4775188e-6, -0.012022644, -0.35994172],
+    [2.6043055e-5, -0.03911444, 6.2528625],
+    [4.9957052e-5, -0.06263877, 11.990848],
+    [7.0777605e-5, -0.08310134, 16.97786],
+    [8.891742e-5, -0.10090904, 21.313538],
+    [0.00010468779, -0.11636848, 25.072912],
+    [0.00011830403, -0.12969112, 28.30763],
+    [0.00012995579, -0.14106335, 31.06321],
+    [0.00013985831, -0.15069762, 33.39168],
+    [-0.0003835762, 0.368594, -94.27426],
+    [-0.00030792516, 0.29392222, -75.97398],
+    [-0.00024384868, 0.23066013, -60.470955],
+    [-0.00018915725, 0.17665607, -47.23824],
+    [-0.00014216463, 0.13025193, -35.869698],
+    [-0.00010155234, 0.09015037, -26.047468],
+    [-6.627843e-5, 0.055325553, -17.520172],
+    [-3.550894e-5, 0.024955884, -10.086434],
+    [-8.570692e-6, -0.0016221844, -3.5835872],
+    [1.5084669e-5, -0.024949461, 2.1209707],
+    [3.5907316e-5, -0.045470152, 7.1361732],
+    [5.4267974e-5, -0.06355021, 11.551769],
+    [7.047263e-5, -0.0794918, 15.441873],
+    [8.476762e-5, -0.0935381, 18.866108],
+    [9.733345e-5, -0.105867, 21.868034],
+    [0.00010831114, -0.11661724, 24.48158],
+    [-0.0003914486, 0.37992856, -97.7918],
+    [-0.00032253333, 0.31180894, -81.072334],
+    [-0.00026347244, 0.25341502, -66.74027],
+    [-0.00021251172, 0.2030209, -54.372448],
+    [-0.0001682775, 0.1592743, -43.637222],
+    [-0.00012968047, 0.121102214, -34.2714],
+    [-9.5847296e-5, 0.08764391, -26.06381],
+    [-6.6070475e-5, 0.058201693, -18.843248],
+    [-3.9772236e-5, 0.03220548, -12.469791],
+    [-1.6476619e-5, 0.009185418, -6.8281007],
+    [4.211548e-6, -0.01124881, -1.8223444],
+    [2.2621809e-5, -0.029422838, 2.6274397],
+    [3.9030572e-5, -0.045609917, 6.5883594],
+    [5.3669988e-5, -0.06003961, 10.116797],
+    [6.673377e-5, -0.0729035, 13.259804],
+    [7.837738e-5, -0.08435524, 16.055096],
+    [-0.00039918255, 0.39075553, -101.108986],
+    [-0.00033592712, 0.32814962, -85.72183],
+    [-0.0002811626, 0.2739329, -72.39639],
+    [-0.00023346108, 0.22669908, -60.78747],
+    [-0.00019168851, 0.18533057, -50.620697],
+    [-0.00015493318, 0.1489284, -41.675323],
+    [-0.00012245611, 0.11676359, -33.772297],
+    [-9.36519e-5, 0.08823856, -26.764833],
+    [-6.8020556e-5, 0.06285952, -20.531605],
+    [-4.514663e-5, 0.040215995, -14.97174],
+    [-2.4681847e-5, 0.019963693, -10.000615],
+    [-6.3331545e-6, 0.0018127876, -5.546983],
+    [1.0148059e-5, -0.014482719, -1.5503603],
+    [2.497277e-5, -0.029131645, 2.0405993],
+    [3.8321086e-5, -0.042312335, 5.269754],
+    [5.0346596e-5, -0.054176904, 8.174515],
+    [-0.00040666296, 0.40101987, -104.22409],
+    [-0.00034822823, 0.34311658, -89.97488],
+    [-0.0002971853, 0.29252392, -77.52423],
+    [-0.00025235605, 0.24808039, -66.58675],
+    [-0.00021279271, 0.20885116, -56.93269],
+    [-0.00017772456, 0.17407556, -48.375076],
+    [-0.00014652022, 0.14313018, -40.760654],
+    [-0.00011865736, 0.11549903, -33.96253],
+    [-9.37006e-5, 0.09075175, -27.87487],
+    [-7.128513e-5, 0.06852762, -22.40894],
+    [-5.1102594e-5, 0.048521545, -17.489677],
+    [-3.289152e-5, 0.030474747, -13.053405],
+    [-1.642832e-5, 0.014165812, -9.045628],
+    [-1.5217215e-6, -0.00059472566, -5.419712],
+    [1.1993151e-5, -0.013970264, -2.1354313],
+    [2.425878e-5, -0.026102059, 0.84199536],
+    [-0.00041382806, 0.41070688, -107.14279],
+    [-0.0003595474, 0.35686097, -93.87669],
+    [-0.00031175968, 0.30944267, -82.193474],
+    [-0.00026948104, 0.2674812, -71.85436],
+    [-0.00023191105, 0.23018621, -62.664955],
+    [-0.00019839239, 0.19690849, -54.46552],
+    [-0.00016838088, 0.16711026, -47.123726],
+    [-0.00014142238, 0.14034253, -40.529068],
+    [-0.00011713576, 0.11622827, -34.58873],
+    [-9.5198775e-5, 0.09444861, -29.224207],
+    [-7.5337186e-5, 0.074732035, -24.368635],
+    [-5.7316778e-5, 0.056846537, -19.964874],
+    [-4.093622e-5, 0.04059263, -15.963783],
+    [-2.6021708e-5, 0.02579802, -12.322914],
+    [-1.2422689e-5, 0.0123133985, -9.005475],
+    [-7.999927e-9, 8.63613e-6, -5.9793944],
+    [-0.00090538705, 0.80074555, -178.92244],
+    [-0.0002488557, 0.16633627, -27.652163],
+    [0.00013042997, -0.19012868, 55.297417],
+    [0.0002946422, -0.34495375, 91.37152],
+    [0.00035105718, -0.3973447, 103.424095],
+    [0.0003718036, -0.41565078, 107.45082],
+    [0.0003811982, -0.4231511, 108.94115],
+    [0.00038578248, -0.42612076, 109.376976],
+    [0.0003877123, -0.4266227, 109.24449],
+    [0.00038798855, -0.42561072, 108.76777],
+    [0.000387179, -0.42362368, 108.07296],
+    [0.0003856437, -0.42100176, 107.23943],
+    [0.00038362129, -0.41796908, 106.31925],
+    [0.00038127482, -0.41467798, 105.34761],
+    [0.00037871514, -0.41123077, 104.347984],
+    [0.00037601867, -0.4076973, 103.336205],
+    [-0.0004923121, 0.41893488, -91.56927],
+    [-9.841309e-5, 0.043677393, -2.8124824],
+    [9.1720394e-5, -0.13689248, 39.63978],
+    [0.00019645436, -0.2363029, 62.965706],
+    [0.00025183125, -0.28862333, 75.18955],
+    [0.00027817712, -0.3130874, 80.82235],
+    [0.00029232417, -0.32583672, 83.68246],
+    [0.0003006716, -0.33302873, 85.229706],
+    [0.00030594307, -0.3372884, 86.08706],
+    [0.00030932354, -0.3397569, 86.52516],
+    [0.000311427, -0.3410244, 86.68377],
+    [0.00031262363, -0.3414461, 86.64686],
+    [0.00031315789, -0.34125605, 86.46969],
+    [0.0003132007, -0.34061757, 86.19075],
+    [0.00031287482, -0.33964768, 85.837555],
+    [0.00031227033, -0.33843195, 85.430176],
+    [-0.00028640975, 0.23625584, -51.11076],
+    [-6.9560156e-5, 0.028009268, -1.4891522],
+    [5.568103e-5, -0.09196399, 26.974056],
+    [0.00013351001, -0.16636567, 44.568527],
+    [0.00018288012, -0.21343453, 55.66599],
+    [0.00021242842, -0.24141085, 62.22193],
+    [0.00022981506, -0.25764638, 65.982185],
+    [0.00024085112, -0.26775217, 68.28359],
+    [0.00024825393, -0.27435613, 69.75266],
+    [0.00025346017, -0.27884924, 70.721275],
+    [0.00025722253, -0.28196028, 71.36324],
+    [0.00025996458, -0.2840989, 71.77619],
+    [0.00026195185, -0.2855216, 72.021194],
+    [0.00026336467, -0.28640246, 72.13963],
+    [0.00026433184, -0.28686556, 72.16101],
+    [0.00026494876, -0.28700277, 72.1071],
+    [-0.00021487345, 0.1767606, -38.82554],
+    [-6.974373e-5, 0.036545265, -5.2156816],
+    [2.4043735e-5, -0.053943183, 16.417967],
+    [8.713118e-5, -0.114681, 30.894012],
+    [0.00013078802, -0.15661378, 40.86007],
+    [0.00016077656, -0.18531407, 47.657257],
+    [0.00018066801, -0.2042232, 52.109344],
+    [0.00019391299, -0.21668103, 55.01611],
+    [0.00020315935, -0.22525927, 56.99422],
+    [0.00020985663, -0.23136692, 58.38158],
+    [0.00021486905, -0.23584472, 59.379883],
+    [0.00021871596, -0.23919801, 60.110348],
+    [0.00022171321, -0.24173406, 60.64664],
+    [0.00022406573, -0.24365196, 61.03646],
+    [0.00022591525, -0.24508935, 61.312756],
+    [0.00022736454, -0.24614595, 61.499374],
+    [-0.00018619817, 0.15586555, -35.222763],
+    [-7.85612e-5, 0.05138807, -10.061201],
+    [-3.5586006e-6, -0.021368599, 7.4345746],
+    [5.0126502e-5, -0.07337544, 19.914816],
+    [8.9337955e-5, -0.11128765, 28.990826],
+    [0.00011827411, -0.1391958, 35.654297],
+    [0.00013939553, -0.15949193, 40.48367],
+    [0.0001545329, -0.17395374, 43.907482],
+    [0.0001654328, -0.18428212, 46.335686],
+    [0.00017352504, -0.19187291, 48.104958],
+    [0.00017969623, -0.19759215, 49.424164],
+    [0.00018451407, -0.20199463, 50.427116],
+    [0.0001883534, -0.20544684, 51.202206],
+    [0.00019146073, -0.20818974, 51.807526],
+    [0.00019400228, -0.21038574, 52.282215],
+    [0.00019609508, -0.2121491, 52.653797],
+    [-0.00017482071, 0.15012093, -34.93623],
+    [-8.982817e-5, 0.06731586, -14.9179325],
+    [-2.740229e-5, 0.006511328, -0.2320469],
+    [1.942839e-5, -0.03907038, 10.763259],
+    [5.511617e-5, -0.0737639, 19.118595],
+    [8.260879e-5, -0.10044406, 25.53147],
+    [0.00010385555, -0.12101436, 30.464113],
+    [0.00012013783, -0.1367255, 34.22003],
+    [0.00013249341, -0.14859076, 37.04479],
+    [0.00014188947, -0.15755647, 39.16768],
+    [0.00014917486, -0.1644549, 40.790462],
+    [0.00015494027, -0.16986561, 42.053627],
+    [0.0001595845, -0.17417999, 43.052044],
+    [0.00016338644, -0.17767182, 43.85207],
+    [0.00016654232, -0.18053378, 44.50039],
+    [0.00016919067, -0.18290186, 45.02995],
+    [-0.00017138297, 0.1510437, -36.04521],
+    [-0.00010140186, 0.08265451, -19.45953],
+    [-4.796035e-5, 0.030431107, -6.8020983],
+    [-6.4408605e-6, -0.010127011, 3.0199807],
+    [2.6251026e-5, -0.04203903, 10.74013],
+    [5.2251027e-5, -0.067390956, 16.865269],
+    [7.306369e-5, -0.08765381, 21.75302],
+    [8.9740395e-5, -0.1038562, 25.65352],
+    [0.00010302769, -0.11672863, 28.744398],
+    [0.00011355383, -0.12688667, 31.175344],
+    [0.00012189474, -0.13489571, 33.083897],
+    [0.00012857912, -0.14127594, 34.59668],
+    [0.00013401898, -0.14643307, 35.812454],
+    [0.00013850888, -0.15065725, 36.801872],
+    [0.00014226239, -0.15415896, 37.616154],
+    [0.00014543704, -0.1570934, 38.293076],
+    [-0.0001718649, 0.15505056, -37.75094],
+    [-0.00011250449, 0.09689006, -23.607885],
+    [-6.578981e-5, 0.051116597, -12.481572],
+    [-2.8497796e-5, 0.014582127, -3.605918],
+    [1.6172191e-6, -0.014908763, 3.5534945],
+    [2.6159427e-5, -0.038925786, 9.378875],
+    [4.629801e-5, -0.05861395, 14.149095],
+    [6.289631e-5, -0.07481934, 18.070261],
+    [7.658651e-5, -0.08816176, 21.29336],
+    [8.783918e-5, -0.09910256, 23.930767],
+    [9.7054835e-5, -0.108035035, 26.078325],
+    [0.000104598446, -0.11531828, 27.823612],
+    [0.000110808905, -0.121286444, 29.248196],
+    [0.00011597697, -0.12622663, 30.422203],
+    [0.000120326375, -0.13035992, 31.399641],
+    [0.00012402511, -0.13385232, 32.22106],
+    [-0.00017434826, 0.16047028, -39.68913],
+    [-0.00012287097, 0.10992083, -27.368193],
+    [-8.137805e-5, 0.06917012, -17.438316],
+    [-4.752696e-5, 0.035925962, -9.340666],
+    [-1.9632987e-5, 0.008538453, -2.6728804],
+    [3.541808e-6, -0.014205737, 2.861006],
+    [2.2923343e-5, -0.033214815, 7.4825845],
+    [3.9214643e-5, -0.049178842, 11.360254],
+    [5.2954252e-5, -0.06262674, 14.623107],
+    [6.4553045e-5, -0.073962174, 17.369663],
+    [7.432687e-5, -0.08349545, 19.675648],
+    [8.254349e-5, -0.09148998, 21.60536],
+    [8.9446876e-5, -0.09818615, 23.217545],
+    [9.526209e-5, -0.10380621, 24.566555],
+    [0.00010019324, -0.108552046, 25.701813],
+    [0.00010441082, -0.112592466, 26.664661],
+    [-0.00017784901, 0.16647157, -41.68435],
+    [-0.00013244127, 0.12179497, -30.772533],
+    [-9.511849e-5, 0.08506631, -21.803518],
+    [-6.412238e-5, 0.05456232, -14.35648],
+    [-3.8154485e-5, 0.029009404, -8.120329],
+    [-1.6238308e-5, 0.007449063, -2.860879],
+    [2.3723976e-6, -0.01085171, 1.600998],
+    [1.8255625e-5, -0.026461037, 5.4041667],
+    [3.1864693e-5, -0.03982478, 8.65763],
+    [4.3557222e-5, -0.051294856, 11.447432],
+    [5.361539e-5, -0.06114902, 13.841484],
+    [6.226204e-5, -0.06960667, 15.893425],
+    [6.968457e-5, -0.07685242, 17.648397],
+    [7.605295e-5, -0.083054, 19.147451],
+    [8.152343e-5, -0.08836586, 20.428423],
+    [8.624004e-5, -0.09293063, 21.52628],
+    [-0.00018183589, 0.17262404, -43.650013],
+    [-0.00014123834, 0.13261077, -33.85934],
+    [-0.000107322805, 0.09917606, -25.679234],
+    [-7.8734076e-5, 0.07098977, -18.784395],
+    [-5.4449116e-5, 0.04704709, -12.929023],
+    [-3.368304e-5, 0.026576558, -7.9243636],
+    [-1.5825382e-5, 0.008977772, -3.6234822],
+    [-3.9494995e-7, -0.0062228306, 0.089540884],
+    [1.2991358e-5, -0.019402508, 3.3070703],
+    [2.4641458e-5, -0.030864643, 6.1033936],
+    [3.4804794e-5, -0.04085514, 8.538736],
+    [4.3683274e-5, -0.049573097, 10.6618595],
+    [5.1440475e-5, -0.05717986, 12.512279],
+    [5.8213314e-5, -0.063810535, 14.123083],
+    [6.412412e-5, -0.069585964, 15.52389],
+    [6.928583e-5, -0.0746179, 16.74211],
+    [-0.00018601268, 0.17870146, -45.54395],
+    [-0.0001493172, 0.14247772, -36.665985],
+    [-0.00011823782, 0.11179052, -29.14538],
+    [-9.1706934e-5, 0.085590675, -22.725193],
+    [-6.890333e-5, 0.06307056, -17.207613],
+    [-4.9186336e-5, 0.043599706, -12.438174],
+    [-3.204929e-5, 0.026679244, -8.294627],
+    [-1.7087767e-5, 0.011910701, -4.679311],
+    [-3.974864e-6, -0.0010281857, -1.5132203],
+    [7.555366e-6, -0.012399743, 1.2679652],
+    [1.772126e-5, -0.022419438, 3.7170856],
+    [2.6703181e-5, -0.031265352, 5.877819],
+    [3.4650504e-5, -0.039085034, 7.7863593],
+    [4.1687e-5, -0.04600076, 9.472701],
+    [4.7916383e-5, -0.05211503, 10.961989],
+    [5.3430005e-5, -0.0575182, 12.276394],
+    [-0.0001902109, 0.18458591, -47.346947],
+    [-0.0001567421, 0.15150072, -39.22614],
+    [-0.00012806049, 0.12314066, -32.265244],
+    [-0.000103309416, 0.098662816, -26.257572],
+    [-8.181915e-5, 0.07740781, -21.04143],
+    [-6.305909e-5, 0.058852907, -16.488598],
+    [-4.6604444e-5, 0.042579412, -12.496348],
+    [-3.2111344e-5, 0.028248165, -8.98146],
+    [-1.9299001e-5, 0.0155820865, -5.8759246],
+    [-7.936325e-6, 0.0043530143, -3.123731],
+    [2.1685755e-6, -0.0056286105, -0.678337],
+    [1.1175769e-5, -0.014520987, 1.4991069],
+    [1.9219782e-5, -0.022457127, 3.4412692],
+    [2.641397e-5, -0.029549163, 5.1756954],
+    [3.2854095e-5, -0.035891846, 6.725662],
+    [3.8621205e-5, -0.041565392, 8.110874],
+    [-0.00019433464, 0.19021912, -49.052017],
+    [-0.00016357702, 0.15977487, -41.569286],
+    [-0.00013694925, 0.13341147, -35.08947],
+    [-0.000113753114, 0.110441126, -29.44374],
+    [-9.3434595e-5, 0.090317816, -24.498047],
+    [-7.554922e-5, 0.07260323, -20.14476],
+    [-5.9736878e-5, 0.056942057, -16.296608],
+    [-4.570278e-5, 0.043043286, -12.882109],
+    [-3.3203803e-5, 0.030666776, -9.842255],
+    [-2.203793e-5, 0.019612892, -7.127986],
+    [-1.2035911e-5, 0.009714301, -4.6981735],
+    [-3.0551726e-6, 0.0008299671, -2.518143],
+    [5.025024e-6, -0.007159592, -0.5585173],
+    [1.2307465e-5, -0.014356141, 1.2057306],
+    [1.8880217e-5, -0.020846913, 2.7960567],
+    [2.481857e-5, -0.026706498, 4.230809],
+    [-0.00019833019, 0.19557612, -50.65868],
+    [-0.00016988195, 0.16738443, -43.720947],
+    [-0.00014503334, 0.14275327, -37.659187],
+    [-0.00012320736, 0.12111371, -32.333607],
+    [-0.00010394048, 0.102008455, -27.631828],
+    [-8.685633e-5, 0.085066, -23.462532],
+    [-7.164676e-5, 0.06998202, -19.750898],
+    [-5.8057067e-5, 0.056504868, -16.435045],
+    [-4.5875247e-5, 0.044424966, -13.463426],
+    [-3.4923676e-5, 0.033566635, -10.792828],
+    [-2.5052293e-5, 0.02378139, -8.386715],
+    [-1.6133881e-5, 0.014943295, -6.2140965],
+    [-8.059722e-6, 0.0069446736, -4.2484713],
+    [-7.3659453e-7, -0.00030683653, -2.4671023],
+    [5.9158747e-6, -0.0068909135, -0.85037005],
+    [1.1967118e-5, -0.012876403, 0.6186772],
+    [-0.00020216897, 0.20065084, -52.169903],
+    [-0.00017571071, 0.17440253, -45.702957],
+    [-0.0001524183, 0.15128867, -40.007927],
+    [-0.00013180923, 0.130833, -34.967678],
+    [-0.000113491296, 0.11264825, -30.486946],
+    [-9.714293e-5, 0.09641674, -26.487568],
+    [-8.2498285e-5, 0.08187572, -22.904882],
+    [-6.933567e-5, 0.06880595, -19.684935],
+    [-5.7469068e-5, 0.057023402, -16.782415],
+    [-4.6741425e-5, 0.046372637, -14.159054],
+    [-3.7019152e-5, 0.036721367, -11.782274],
+    [-2.818809e-5, 0.027956493, -9.624215],
+    [-2.015022e-5, 0.01998087, -7.660948],
+    [-1.2820971e-5, 0.012710646, -5.871811],
+    [-6.1268092e-6, 0.0060728737, -4.2388253],
+    [-3.9410217e-9, 4.2541546e-6, -2.7463913],
+    [-0.00086991495, 0.7766254, -174.68752],
+    [0.00090538705, -0.90569997, 223.57202],
+    [0.00010853105, -0.14693928, 42.00211],
+    [0.00020813636, -0.24160615, 64.27644],
+    [0.0002434951, -0.27467275, 71.9511],
+    [0.00025858387, -0.28822565, 74.98665],
+    [0.00026644816, -0.29485348, 76.38165],
+    [0.00027078902, -0.29813033, 76.98787],
+    [0.00027314844, -0.29954362, 77.15947],
+    [0.00027431172, -0.2998492, 77.076096],
+    [0.0002747138, -0.29946414, 76.83656],
+    [0.00027461152, -0.29863337, 76.49866],
+    [0.00027416612, -0.29750937, 76.098145],
+    [0.00027348395, -0.29619217, 75.658295],
+    [0.0002726381, -0.29474962, 75.19479],
+    [0.00027168018, -0.29322913, 74.71849],
+    [0.00090538705, -0.9057671, 223.5287],
+    [-5.0552706e-5, 0.016199317, 1.2098423],
+    [7.1091614e-5, -0.100018434, 28.718489],
+    [0.00013683169, -0.16287883, 43.59795],
+    [0.00017120418, -0.19558363, 51.30596],
+    [0.00018919936, -0.21246311, 55.236446],
+    [0.00019980181, -0.22219475, 57.4602],
+    [0.00020668232, -0.22833614, 58.828384],
+    [0.00021139621, -0.23239572, 59.702053],
+    [0.00021472828, -0.2351345, 60.263317],
+    [0.00021713047, -0.23699042, 60.61692],
+    [0.00021888148, -0.23823325, 60.827328],
+    [0.00022016148, -0.23903699, 60.936035],
+    [0.00022109177, -0.23951851, 60.970867],
+    [0.00022175712, -0.23975919, 60.951153],
+    [0.00022221869, -0.2398176, 60.89074],
+    [-0.00017187999, 0.1404951, -29.98222],
+    [-3.6602796e-5, 0.010424147, 1.0512766],
+    [4.2577325e-5, -0.065709054, 19.189764],
+    [9.183089e-5, -0.113057025, 30.459785],
+    [0.00012282626, -0.14278917, 37.521683],
+    [0.00014193647, -0.1610086, 41.82647],
+    [0.00015413042, -0.17251447, 44.52132],
+    [0.00016244757, -0.18025722, 46.313923],
+    [0.0001684551, -0.1857613, 47.57049],
+    [0.00017297348, -0.18982548, 48.482967],
+    [0.00017646972, -0.19290437, 49.160606],
+    [0.00017923428, -0.19528072, 49.671337],
+    [0.00018145844, -0.1971406, 50.05984],
+    [0.00018327333, -0.19861148, 50.356705],
+    [0.00018477152, -0.19978325, 50.583477],
+    [0.00018602025, -0.20072106, 50.75576],
+    [-0.00012921619, 0.10525587, -22.764364],
+    [-3.8813552e-5, 0.01783774, -1.791432],
+    [1.9909294e-5, -0.038932275, 11.810899],
+    [5.9678376e-5, -0.077355, 21.00559],
+    [8.721167e-5, -0.10391917, 27.352371],
+    [0.00010624529, -0.122227155, 31.714495],
+    [0.000119383825, -0.13479634, 34.695408],
+    [0.00012872768, -0.14366782, 36.785942],
+    [0.00013565572, -0.15018556, 38.30991],
+    [0.00014099071, -0.1551528, 39.461002],
+    [0.00014522526, -0.15905072, 40.355316],
+    [0.00014866583, -0.16217877, 41.06509],
+    [0.000151514, -0.1647339, 41.637836],
+    [0.00015390884, -0.16685194, 42.1063],
+    [0.00015594948, -0.16862972, 42.493835],
+    [0.00015770856, -0.17013806, 42.817493],
+    [-0.00011268278, 0.0935405, -20.835768],
+    [-4.5598772e-5, 0.028383426, -5.1339455],
+    [1.2028635e-6, -0.017066972, 5.808493],
+    [3.4835764e-5, -0.049709287, 13.658301],
+    [5.950865e-5, -0.07362974, 19.402903],
+    [7.778116e-5, -0.0913126, 23.641808],
+    [9.130672e-5, -0.10436206, 26.761574],
+    [0.00010137307, -0.11403064, 29.064241],
+    [0.000109030814, -0.1213439, 30.797602],
+    [0.00011502321, -0.12702899, 32.137554],
+    [0.00011983968, -0.13156523, 33.20013],
+    [0.00012380148, -0.13526753, 34.061577],
+    [0.00012712301, -0.13834603, 34.772793],
+    [0.00012995218, -0.14094575, 35.36888],
+    [0.00013239456, -0.1431702, 35.874897],
+    [0.00013452784, -0.1450955, 36.309277],
+    [-0.000106621104, 0.09098123, -20.907715],
+    [-5.3565822e-5, 0.03926547, -8.399536],
+    [-1.4593077e-5, 0.0012774525, 0.78239477],
+    [1.4688928e-5, -0.027253166, 7.6724324],
+    [3.70692e-5, -0.04904182, 12.9286175],
+    [5.4376455e-5, -0.06587047, 16.982792],
+    [6.783199e-5, -0.078929365, 20.12322],
+    [7.831046e-5, -0.08907129, 22.556335],
+    [8.652516e-5, -0.09699324, 24.450977],
+    [9.306756e-5, -0.1032747, 25.947712],
+    [9.838415e-5, -0.108353876, 27.152927],
+    [0.00010279121, -0.11254148, 28.142096],
+    [0.00010651056, -0.11605558, 28.9682],
+    [0.00010969905, -0.1190504, 29.668722],
+    [0.000112469424, -0.121636815, 30.270601],
+    [0.00011490454, -0.12389633, 30.793634],
+    [-0.000105335566, 0.0924175, -21.812298],
+    [-6.155841e-5, 0.049618796, -11.42917],
+    [-2.8133072e-5, 0.01693805, -3.5042355],
+    [-2.144492e-6, -0.008466009, 2.6523287],
+    [1.8353412e-5, -0.02849228, 7.501722],
+    [3.46993e-5, -0.044448253, 11.361595],
+    [4.783422e-5, -0.057253756, 14.455438],
+    [5.843124e-5, -0.06756696, 16.943136],
+    [6.700451e-5, -0.07589107, 18.946932],
+    [7.3983305e-5, -0.08264701, 20.569155],
+    [7.972999e-5, -0.08819087, 21.896463],
+    [8.453258e-5, -0.092806086, 22.99789],
+    [8.860752e-5, -0.09670588, 23.925388],
+    [9.211475e-5, -0.1000479, 24.717377],
+    [9.517254e-5, -0.10294875, 25.402283],
+    [9.786909e-5, -0.10549543, 26.001307],
+    [-0.000106392305, 0.09568291, -23.067316],
+    [-6.916971e-5, 0.059200346, -14.193358],
+    [-3.988318e-5, 0.030492043, -7.2125216],
+    [-1.649195e-5, 0.007564654, -1.6398238],
+    [2.4206465e-6, -0.010966873, 2.861821],
+    [1.7863804e-5, -0.026090108, 6.5328307],
+    [3.0572075e-5, -0.03852445, 9.54838],
+    [4.1088788e-5, -0.04880239, 12.038147],
+    [4.982269e-5, -0.057324752, 14.0997505],
+    [5.709859e-5, -0.06441035, 15.810857],
+    [6.319191e-5, -0.070329994, 17.237486],
+    [6.8338755e-5, -0.07531623, 18.436386],
+    [7.273446e-5, -0.07956176, 19.45461],
+    [7.653328e-5, -0.08321889, 20.329369],
+    [7.985417e-5, -0.08640512, 21.089378],
+    [8.278856e-5, -0.08921083, 21.756725],
+    [-0.0001086352, 0.099772856, -24.454418],
+    [-7.627285e-5, 0.06798453, -16.704693],
+    [-5.0193084e-5, 0.04236266, -10.459567],
+    [-2.890792e-5, 0.021451134, -5.364105],
+    [-1.1350868e-5, 0.0042053964, -1.1636192],
+    [3.259308e-6, -0.010140336, 2.3286572],
+    [1.55062e-5, -0.022158563, 5.2523856],
+    [2.5832636e-5, -0.032284003, 7.7136383],
+    [3.457923e-5, -0.040851254, 9.794074],
+    [4.2012118e-5, -0.048121918, 11.557543],
+    [4.8348098e-5, -0.054309294, 13.056127],
+    [5.3773045e-5, -0.059596535, 14.334578],
+    [5.8448313e-5, -0.064142875, 15.431835],
+    [6.251149e-5, -0.0680843, 16.38118],
+    [6.6075714e-5, -0.07153271, 17.210014],
+    [6.923155e-5, -0.07457778, 17.940298],
+    [-0.000111473455, 0.10419034, -25.86922],
+    [-8.285076e-5, 0.07602127, -18.988064],
+    [-5.932912e-5, 0.052867513, -13.332788],
+    [-3.9787865e-5, 0.033630583, -8.635182],
+    [-2.3402394e-5, 0.017501563, -4.6976857],
+    [-9.554254e-6, 0.003873302, -1.3719776],
+    [2.2282777e-6, -0.007717625, 1.4551798],
+    [1.2310316e-5, -0.017630167, 3.8715174],
+    [2.0978165e-5, -0.026145976, 5.9458876],
+    [2.8458837e-5, -0.03348852, 7.7329297],
+    [3.4934983e-5, -0.039837703, 9.276644],
+    [4.0557887e-5, -0.04534267, 10.613514],
+    [4.5458193e-5, -0.050132368, 11.775111],
+    [4.9750644e-5, -0.05432023, 12.789223],
+    [5.3535092e-5, -0.058005087, 13.68008],
+    [5.6896217e-5, -0.061270837, 14.468267],
+    [-0.00011458972, 0.108679995, -27.26078],
+    [-8.8929184e-5, 0.0833829, -21.070133],
+    [-6.7496054e-5, 0.062248476, -15.898558],
+    [-4.9423677e-5, 0.044425953, -11.538015],
+    [-3.406009e-5, 0.02927485, -7.831847],
+    [-2.0906551e-5, 0.016304877, -4.660105],
+    [-9.5757605e-6, 0.0051350435, -1.9295561],
+    [2.3698362e-7, -0.0045345915, 0.43321663],
+    [8.774226e-6, -0.012942933, 2.4867022],
+    [1.6231175e-5, -0.020282375, 4.2780147],
+    [2.2766491e-5, -0.026709385, 5.8454776],
+    [2.8510618e-5, -0.032352686, 7.2206264],
+    [3.3573157e-5, -0.03732051, 8.429986],
+    [3.8049253e-5, -0.041706923, 9.496621],
+    [4.2023068e-5, -0.045595225, 10.440973],
+    [4.556901e-5, -0.049059168, 11.281153],
+    [-0.00011780772, 0.113108575, -28.604622],
+    [-9.454923e-5, 0.090144195, -22.975767],
+    [-7.48537e-5, 0.070693016, -18.20821],
+    [-5.8036057e-5, 0.05408161, -14.137048],
+    [-4.3571057e-5, 0.03979331, -10.635736],
+    [-3.105004e-5, 0.02742588, -7.6057386],
+    [-2.0150781e-5, 0.016661964, -4.9692883],
+    [-1.0616181e-5, 0.0072481884, -2.664286],
+    [-2.2388876e-6, -0.0010199107, -0.64060694],
+    [5.149968e-6, -0.008308958, 1.1426064],
+    [1.1689138e-5, -0.014755934, 2.7189496],
+    [1.7493776e-5, -0.02047459, 4.116328],
+    [2.266017e-5, -0.025560094, 5.3580933],
+    [2.7270231e-5, -0.030093467, 6.4641323],
+    [3.1395288e-5, -0.03414531, 7.4517837],
+    [3.509883e-5, -0.03777856, 8.33651],
+    [-0.0001210282, 0.11740791, -29.890005],
+    [-9.975538e-5, 0.09637475, -24.726961],
+    [-8.1528146e-5, 0.07834837, -20.302015],
+    [-6.5795e-5, 0.062785976, -16.482082],
+    [-5.212601e-5, 0.04926416, -13.163334],
+    [-4.018166e-5, 0.037448358, -10.26371],
+    [-2.9690638e-5, 0.02707106, -7.717578],
+    [-2.0433805e-5, 0.017916083, -5.4718895],
+    [-1.2232432e-5, 0.009807001, -3.4833415],
+    [-4.9394853e-6, 0.0025985928, -1.7162819],
+    [1.567173e-6, -0.0038298396, -0.14107305],
+    [7.3896003e-6, -0.009579207, 1.2670709],
+    [1.2613732e-5, -0.014734519, 2.5290325],
+    [1.7312655e-5, -0.019368118, 3.6625881],
+    [2.1549287e-5, -0.023542326, 4.6830587],
+    [2.5378462e-5, -0.02731149, 5.6038065],
+    [-0.00012419492, 0.12154493, -31.113321],
+    [-0.00010459006, 0.10213617, -26.342636],
+    [-8.762016e-5, 0.085331775, -22.212015],
+    [-7.283403e-5, 0.07068714, -18.612333],
+    [-5.9874874e-5, 0.05785051, -15.457231],
+    [-4.845723e-5, 0.046540286, -12.677559],
+    [-3.8350296e-5, 0.03652868, -10.217369],
+    [-2.9365538e-5, 0.027629502, -8.030922],
+    [-2.1347736e-5, 0.019689396, -6.080536],
+    [-1.4167952e-5, 0.012580896, -4.3348827],
+    [-7.718286e-6, 0.0061972807, -2.7677286],
+    [-1.907794e-6, 0.00044855158, -1.3569443],
+    [3.3406927e-6, -0.0047416952, -0.0837399],
+    [8.093096e-6, -0.009438762, 1.0679479],
+    [1.2406106e-5, -0.013698837, 2.1119444],
+    [1.6329008e-5, -0.017570797, 3.060279],
+    [-0.0001272769, 0.12550631, -32.27478],
+    [-0.00010909242, 0.1074826, -27.839067],
+    [-9.321132e-5, 0.091737874, -23.964067],
+    [-7.9259495e-5, 0.07790315, -20.559118],
+    [-6.6937115e-5, 0.065682516, -17.551485],
+    [-5.600169e-5, 0.054836527, -14.882318],
+    [-4.6254994e-5, 0.045169365, -12.503467],
+    [-3.753362e-5, 0.036519516, -10.375214],
+    [-2.970175e-5, 0.02875266, -8.464521],
+    [-2.264566e-5, 0.021756282, -6.7437077],
+    [-1.6269489e-5, 0.015435496, -5.1894255],
+    [-1.0491767e-5, 0.009709607, -3.7818127],
+    [-5.243029e-6, 0.004509789, -2.5039265],
+    [-4.6355555e-7, -0.00022316258, -1.3411893],
+    [3.8982575e-6, -0.004540428, -0.2809958],
+    [7.887279e-6, -0.008486532, 0.6876218],
+    [-0.00013025828, 0.12929006, -33.37663],
+    [-0.00011329756, 0.112461016, -29.230196],
+    [-9.836856e-5, 0.09764414, -25.57933],
+    [-8.515724e-5, 0.08452938, -22.347769],
+    [-7.3409166e-5, 0.07286536, -19.473677],
+    [-6.291637e-5, 0.062446583, -16.906487],
+    [-5.3507196e-5, 0.053103298, -14.604423],
+    [-4.503891e-5, 0.04469431, -12.532729],
+    [-3.7391932e-5, 0.03710126, -10.662268],
+    [-3.0465426e-5, 0.030224318, -8.968453],
+    [-2.4173662e-5, 0.023978539, -7.430365],
+    [-1.8443521e-5, 0.01829146, -6.030152],
+    [-1.3212164e-5, 0.013100773, -4.752461],
+    [-8.425305e-6, 0.008352622, -3.584019],
+    [-4.035818e-6, 0.004000237, -2.5132968],
+    [-2.5710796e-9, 2.7767205e-6, -1.5302259],
+    [-0.0006134135, 0.5462422, -122.36994],
+    [-0.00090538705, 0.9106134, -226.76399],
+    [0.00010379684, -0.13292055, 37.52077],
+    [0.00017113978, -0.19730043, 52.77995],
+    [0.00019600536, -0.2206494, 58.2301],
+    [0.00020800825, -0.23154967, 60.699677],
+    [0.00021491278, -0.23753974, 61.998367],
+    [0.00021927216, -0.24110018, 62.72194],
+    [0.0002221896, -0.24330127, 63.12716],
+    [0.0002242176, -0.24467735, 63.34188],
+    [0.00022566623, -0.24552688, 63.437237],
+    [0.0002267247, -0.24603094, 63.456196],
+    [0.00022751583, -0.24630632, 63.4263],
+    [0.0002281226, -0.24643126, 63.365894],
+    [0.00022860341, -0.24646035, 63.28767],
+    [0.00022900008, -0.24643232, 63.200546],
+    [-0.00090538705, 0.911761, -227.22333],
+    [-2.3003748e-5, -0.0023587432, 4.6815047],
+    [6.5864144e-5, -0.08774451, 25.021198],
+    [0.00011246476, -0.13256572, 35.70485],
+    [0.00013666246, -0.15570606, 41.1938],
+    [0.00015029505, -0.16858087, 44.21545],
+    [0.00015902086, -0.17668927, 46.09182],
+    [0.00016512722, -0.1822599, 47.35978],
+    [0.00016967017, -0.18632251, 48.26752],
+    [0.00017320753, -0.18942092, 48.9461],
+    [0.00017606113, -0.19186862, 49.470985],
+    [0.00017843007, -0.19385913, 49.888725],
+    [0.00018044523, -0.19551943, 50.229786],
+    [0.00018219657, -0.19693659, 50.51502],
+    [0.00018374849, -0.19817269, 50.759254],
+    [0.00018514838, -0.19927329, 50.973343],
+    [-0.000116250114, 0.09320984, -19.330511],
+    [-1.7684697e-5, -0.0017740658, 3.3846333],
+    [4.010106e-5, -0.057567626, 16.739244],
+    [7.570979e-5, -0.09196792, 24.974556],
+    [9.7900214e-5, -0.11335806, 30.085276],
+    [0.000111979374, -0.12685227, 33.293976],
+    [0.000121523626, -0.13592547, 35.43658],
+    [0.00012846552, -0.14246286, 36.967995],
+    [0.00013379968, -0.1474364, 38.123043],
+    [0.00013807324, -0.1513811, 39.031063],
+    [0.00014161174, -0.1546154, 39.76903],
+    [0.00014462069, -0.15734017, 40.385506],
+    [0.00014723642, -0.1596887, 40.912666],
+    [0.00014955312, -0.16175282, 41.372692],
+    [0.00015163833, -0.16359843, 41.781456],
+    [0.00015354189, -0.165274, 42.150623],
+    [-8.771183e-5, 0.06997903, -14.655398],
+    [-2.194908e-5, 0.006280786, 0.6530976],
+    [2.0838559e-5, -0.03520215, 10.623954],
+    [4.9789654e-5, -0.06327712, 17.37105],
+    [6.976909e-5, -0.08263199, 22.017694],
+    [8.368132e-5, -0.096071415, 25.236383],
+    [9.362567e-5, -0.10563427, 27.517931],
+    [0.00010106206, -0.112745345, 29.20656],
+    [0.0001068778, -0.11827285, 30.512419],
+    [0.000111599875, -0.12273308, 31.560602],
+    [0.00011555207, -0.1264435, 32.428062],
+    [0.00011894326, -0.12960894, 33.164448],
+    [0.00012191386, -0.1323671, 33.803143],
+    [0.00012456198, -0.13481419, 34.367466],
+    [0.00012695807, -0.13701919, 34.874123],
+    [0.00012915432, -0.13903323, 35.33549],
+    [-7.7431854e-5, 0.06317028, -13.669333],
+    [-2.8582148e-5, 0.015663976, -2.2067385],
+    [5.5155388e-6, -0.017513104, 5.797433],
+    [3.0055013e-5, -0.04139155, 11.556482],
+    [4.807714e-5, -0.05891763, 15.780419],
+    [6.146709e-5, -0.07191909, 18.909527],
+    [7.1531445e-5, -0.08166588, 21.250095],
+    [7.9275334e-5, -0.08913909, 23.03942],
+    [8.542539e-5, -0.095050246, 24.44999],
+    [9.0464535e-5, -0.09987315, 25.596819],
+    [9.4708004e-5, -0.10391743, 26.555119],
+    [9.836493e-5, -0.10738857, 27.374832],
+    [0.00010157869, -0.110427566, 28.090227],
+    [0.00010445029, -0.11313371, 28.725437],
+    [0.000107053056, -0.11557907, 29.297976],
+    [0.000109441506, -0.11781724, 29.82087],
+    [-7.436989e-5, 0.06264958, -14.051722],
+    [-3.5631605e-5, 0.024852356, -4.9014583],
+    [-7.181276e-6, -0.0029167593, 1.819973],
+    [1.4224457e-5, -0.023810148, 6.8754587],
+    [3.0624135e-5, -0.03981075, 10.744833],
+    [4.3351432e-5, -0.0522167, 13.742144],
+    [5.3322005e-5, -0.06192016, 16.083248],
+    [6.122707e-5, -0.069596194, 17.93169],
+    [6.761449e-5, -0.07578155, 19.417765],
+    [7.289697e-5, -0.080881484, 20.640005],
+    [7.736763e-5, -0.0851843, 21.668581],
+    [8.123124e-5, -0.088891566, 22.552578],
+    [8.463182e-5, -0.092145145, 23.326555],
+    [8.7672626e-5, -0.095046766, 24.015305],
+    [9.0429334e-5, -0.09767102, 24.636997],
+    [9.295849e-5, -0.10007362, 25.205212],
+    [-7.456241e-5, 0.06483012, -14.991832],
+    [-4.248211e-5, 0.033442073, -7.371623],
+    [-1.7998715e-5, 0.009479818, -1.5550575],
+    [1.0563116e-6, -0.009170026, 2.97066],
+    [1.611962e-5, -0.023908906, 6.545652],
+    [2.8174727e-5, -0.035697024, 9.402976],
+    [3.7915164e-5, -0.04521201, 11.707115],
+    [4.5852394e-5, -0.052954186, 13.579564],
+    [5.2391537e-5, -0.059320655, 15.116865],
+    [5.7861187e-5, -0.06463429, 16.397629],
+    [6.2518055e-5, -0.06914782, 17.483461],
+    [6.6554145e-5, -0.073050484, 18.420536],
+    [7.011062e-5, -0.07648155, 19.242844],
+    [7.3291325e-5, -0.07954351, 19.975414],
+    [7.617358e-5, -0.08231271, 20.636894],
+    [7.881581e-5, -0.08484685, 21.241371],
+    [-7.633383e-5, 0.06822539, -16.159964],
+    [-4.894253e-5, 0.041361038, -9.621985],
+    [-2.7402031e-5, 0.020229058, -4.4796457],
+    [-1.0184766e-5, 0.0033373684, -0.37009114],
+    [3.7627442e-6, -0.010343999, 2.9572222],
+    [1.5187668e-5, -0.021546166, 5.680189],
+    [2.463297e-5, -0.030800879, 7.9282193],
+    [3.2503092e-5, -0.038504567, 9.797818],
+    [3.911136e-5, -0.04496471, 11.3638735],
+    [4.4713495e-5, -0.050432708, 12.68768],
+    [4.95211e-5, -0.05511699, 13.820113],
+    [5.370472e-5, -0.059185807, 14.802283],
+    [5.7397378e-5, -0.062770545, 15.666316],
+    [6.0700775e-5, -0.06597174, 16.436802],
+    [6.3692634e-5, -0.06886624, 17.13255],
+    [6.643254e-5, -0.07151298, 17.767982],
+    [-7.889563e-5, 0.07215437, -17.409084],
+    [-5.497306e-5, 0.04864294, -11.674776],
+    [-3.5704303e-5, 0.029700268, -7.055084],
+    [-1.9968507e-5, 0.014229369, -3.2827127],
+    [-6.9674566e-6, 0.0014483586, -0.16708952],
+    [3.8810967e-6, -0.009213577, 2.4309533],
+    [1.3010887e-5, -0.018181995, 4.615197],
+    [2.075142e-5, -0.025780452, 6.4645724],
+    [2.7358392e-5, -0.03226025, 8.040405],
+    [3.303684e-5, -0.037823133, 9.391954],
+    [3.795763e-5, -0.042637482, 10.56037],
+    [4.2264764e-5, -0.046845444, 11.580426],
+    [4.6077363e-5, -0.050564744, 12.480947],
+    [4.9491384e-5, -0.053890344, 13.285194],
+    [5.2582825e-5, -0.05689749, 14.011615],
+    [5.54114e-5, -0.05964534, 14.674709],
+    [-8.1848564e-5, 0.076284505, -18.67016],
+    [-6.058995e-5, 0.05535253, -13.555194],
+    [-4.3128417e-5, 0.0381548, -9.352889],
+    [-2.861445e-5, 0.023858422, -5.8599486],
+    [-1.642687e-5, 0.011853901, -2.927559],
+    [-6.1018827e-6, 0.0016858168, -0.44449282],
+    [2.7131866e-6, -0.0069924034, 1.6739174],
+    [1.0291107e-5, -0.014449015, 3.4932275],
+    [1.6846272e-5, -0.020895028, 5.0650196],
+    [2.2550328e-5, -0.026499527, 6.430643],
+    [2.7544384e-5, -0.031401657, 7.6241474],
+    [3.1947853e-5, -0.03571934, 8.674406],
+    [3.5863053e-5, -0.039553743, 9.606217],
+    [3.937644e-5, -0.042990476, 10.4405775],
+    [4.25596e-5, -0.046100475, 11.194897],
+    [4.5470828e-5, -0.04894155, 11.88337],
+    [-8.498037e-5, 0.08044767, -19.910337],
+    [-6.582834e-5, 0.061558608, -15.286644],
+    [-4.983896e-5, 0.04578474, -11.425541],
+    [-3.6351023e-5, 0.03247677, -8.16829],
+    [-2.4870202e-5, 0.02114893, -5.3961043],
+    [-1.5020021e-5, 0.011431004, -3.0184257],
+    [-6.5091876e-6, 0.0030363384, -0.96511596],
+    [8.9106175e-7, -0.004260345, 0.81896186],
+    [7.3629126e-6, -0.010638587, 2.3777645],
+    [1.3053454e-5, -0.01624345, 3.7468195],
+    [1.8083289e-5, -0.02119397, 4.955291],
+    [2.255352e-5, -0.025590038, 6.027667],
+    [2.6550852e-5, -0.029517418, 6.984986],
+    [3.015048e-5, -0.03305058, 7.8455324],
+    [3.3417167e-5, -0.036253754, 8.625081],
+    [3.640571e-5, -0.039181303, 9.337],
+    [-8.8174624e-5, 0.084558144, -21.114473],
+    [-7.0728216e-5, 0.06732546, -16.88966],
+    [-5.596117e-5, 0.052735668, -13.312762],
+    [-4.3347438e-5, 0.04027146, -10.257127],
+    [-3.2486096e-5, 0.02953831, -7.6261377],
+    [-2.3066614e-5, 0.020230435, -5.344897],
+    [-1.484518e-5, 0.012107615, -3.354544],
+    [-7.627607e-6, 0.004978383, -1.6081529],
+    [-1.2574551e-6, -0.0013116101, -0.06788371],
+    [4.392766e-6, -0.0068882317, 1.2971307],
+    [9.428294e-6, -0.011855457, 2.512398],
+    [1.3937165e-5, -0.016300334, 3.5992818],
+    [1.7994125e-5, -0.020296833, 4.575944],
+    [2.16641e-5, -0.023909274, 5.4581885],
+    [2.500398e-5, -0.027194079, 6.2598867],
+    [2.8063487e-5, -0.03020062, 6.9931855],
+    [-9.136699e-5, 0.08857375, -22.276512],
+    [-7.532828e-5, 0.07270985, -18.38175],
+    [-6.15922e-5, 0.059120174, -15.0452585],
+    [-4.9732273e-5, 0.047384772, -12.16409],
+    [-3.941811e-5, 0.037178118, -9.658423],
+    [-3.0390056e-5, 0.02824422, -7.4654727],
+    [-2.244141e-5, 0.020379176, -5.5352154],
+    [-1.5405676e-5, 0.013418641, -3.8273203],
+    [-9.147291e-6, 0.0072287237, -2.3089237],
+    [-3.5547266e-6, 0.0016992011, -0.95296144],
+    [1.4647419e-6, -0.003261626, 0.263089],
+    [5.9888985e-6, -0.0077307294, 1.3581387],
+    [1.0083912e-5, -0.011773638, 2.348294],
+    [1.3806746e-5, -0.01544681, 3.2474382],
+    [1.7207232e-5, -0.01879968, 4.067734],
+    [2.0329462e-5, -0.021876033, 4.819961],
+    [-9.452194e-5, 0.09247551, -23.395039],
+    [-7.966384e-5, 0.077760905, -19.777721],
+    [-6.6808854e-5, 0.06502705, -16.64723],
+    [-5.560567e-5, 0.053927597, -13.918555],
+    [-4.5778164e-5, 0.04419016, -11.524825],
+    [-3.7106496e-5, 0.03559776, -9.412757],
+    [-2.9413652e-5, 0.027975587, -7.539417],
+    [-2.255548e-5, 0.021181203, -5.8698096],
+    [-1.6413602e-5, 0.015097574, -4.375175],
+    [-1.0889701e-5, 0.009627444, -3.031607],
+    [-5.901509e-6, 0.004689379, -1.819083],
+    [-1.3795434e-6, 0.00021456536, -0.7206778],
+    [2.735415e-6, -0.0038556857, 0.27804795],
+    [6.4943433e-6, -0.0075719287, 1.189537],
+    [9.941651e-6, -0.010978239, 2.024642],
+    [1.3116429e-5, -0.014113442, 2.792928],
+    [-9.762089e-5, 0.09625749, -24.471085],
+    [-8.3766456e-5, 0.082520954, -21.090134],
+    [-7.167293e-5, 0.07052758, -18.138102],
+    [-6.104688e-5, 0.059987765, -15.543821],
+    [-5.165467e-5, 0.050670784, -13.250589],
+    [-4.330831e-5, 0.042390883, -11.212741],
+    [-3.5854664e-5, 0.03499669, -9.393055],
+    [-2.9167955e-5, 0.0283638, -7.7609367],
+    [-2.3143908e-5, 0.022389023, -6.291],
+    [-1.7695342e-5, 0.016986042, -4.962006],
+    [-1.2748745e-5, 0.012082032, -3.756029],
+    [-8.2417655e-6, 0.007615189, -2.6578548],
+    [-4.1209364e-6, 0.0035324881, -1.6544245],
+    [-3.4014434e-7, -0.00021183267, -0.7344647],
+    [3.1407549e-6, -0.0036576358, 0.11184803],
+    [6.357045e-6, -0.0068399822, 0.8931573],
+    [-0.00010065506, 0.09992081, -25.506891],
+    [-8.766373e-5, 0.08702617, -22.3296],
+    [-7.623462e-5, 0.0756796, -19.533623],
+    [-6.611962e-5, 0.065635964, -17.058662],
+    [-5.711901e-5, 0.056697827, -14.856141],
+    [-4.907029e-5, 0.04870447, -12.88651],
+    [-4.1839987e-5, 0.04152382, -11.117259],
+    [-3.531745e-5, 0.03504634, -9.521421],
+    [-2.9410334e-5, 0.029180562, -8.076476],
+    [-2.4040794e-5, 0.023849346, -6.763421],
+    [-1.9142828e-5, 0.018987257, -5.566136],
+    [-1.4660068e-5, 0.014538376, -4.4708414],
+    [-1.0544024e-5, 0.010454579, -3.465675],
+    [-6.7527058e-6, 0.0066941716, -2.5403566],
+    [-3.2494552e-6, 0.003220729, -1.6859025],
+    [-2.1192559e-9, 2.2870859e-6, -0.89442676],
+    [-0.0004733593, 0.4196349, -93.37033],
+    [0.00090538705, -0.9182418, 230.43465],
+    [0.000110050285, -0.13508612, 37.78969],
+    [0.00015808626, -0.18120068, 48.78144],
+    [0.00017709607, -0.1990912, 52.973217],
+    [0.0001873656, -0.20848969, 55.12036],
+    [0.00019396706, -0.21434906, 56.420383],
+    [0.00019868446, -0.21841094, 57.294266],
+    [0.00020231209, -0.22144875, 57.92859],
+    [0.00020526577, -0.22386582, 58.42033],
+    [0.00020778831, -0.22589655, 58.82561],
+    [0.0002100329, -0.2276879, 59.17945],
+    [0.00021210201, -0.22933759, 59.505005],
+    [0.00021406716, -0.23091334, 59.818233],
+    [0.00021598033, -0.23246406, 60.130577],
+    [0.00021788046, -0.23402609, 60.450504],
+    [0.00090538705, -0.9197846, 231.09161],
+    [-1.1762656e-6, -0.019970722, 8.642271],
+    [6.934972e-5, -0.08813129, 24.985575],
+    [0.00010479953, -0.1224022, 33.205166],
+    [0.00012339416, -0.14025731, 37.463337],
+    [0.00013469238, -0.15098953, 39.99929],
+    [0.00014251542, -0.1583344, 41.717285],
+    [0.00014843386, -0.1638301, 42.990242],
+    [0.00015320005, -0.16821374, 43.996906],
+    [0.00015721977, -0.17188255, 44.833553],
+    [0.00016073378, -0.17507197, 45.557167],
+    [0.00016389592, -0.17793208, 46.20404],
+    [0.0001668106, -0.18056455, 46.798702],
+    [0.00016955179, -0.18304127, 47.358456],
+    [0.00017217426, -0.18541531, 47.89606],
+    [0.00017471971, -0.18772697, 48.421185],
+    [-8.261613e-5, 0.06360008, -12.402732],
+    [-2.6612302e-6, -0.013719949, 6.156768],
+    [4.3630116e-5, -0.058628745, 16.963331],
+    [7.163386e-5, -0.08581303, 23.508026],
+    [8.898284e-5, -0.102610156, 27.543196],
+    [0.000100424804, -0.113630176, 30.178982],
+    [0.000108660526, -0.12151304, 32.054596],
+    [0.0001150344, -0.12757671, 33.489925],
+    [0.00012024421, -0.13250615, 34.651398],
+    [0.00012468311, -0.13668752, 35.632866],
+    [0.00012858925, -0.14035465, 36.491177],
+    [0.00013211617, -0.14365825, 37.262928],
+    [0.00013536832, -0.1467008, 37.973007],
+    [0.00013841996, -0.149555, 38.639053],
+    [0.00014132605, -0.15227468, 39.274105],
+    [0.0001441285, -0.15490085, 39.888103],
+    [-6.2690466e-5, 0.047876302, -9.361756],
+    [-9.376442e-6, -0.0039075506, 3.1195378],
+    [2.5124129e-5, -0.03748205, 11.223084],
+    [4.829628e-5, -0.06004604, 16.671543],
+    [6.4214255e-5, -0.075530075, 20.407166],
+    [7.546678e-5, -0.0864464, 23.034962],
+    [8.3838255e-5, -0.09453808, 24.976942],
+    [9.042061e-5, -0.1008759, 26.493153],
+    [9.584497e-5, -0.106079996, 27.734446],
+    [0.00010048621, -0.11051912, 28.790596],
+    [0.000104578314, -0.114423536, 29.717686],
+    [0.00010827459, -0.117944166, 30.552477],
+    [0.00011168007, -0.121184364, 31.320143],
+    [0.000114869596, -0.12421778, 32.038616],
+    [0.00011789849, -0.12709883, 32.721146],
+    [0.000120808974, -0.129869, 33.377827],
+    [-5.6595913e-5, 0.044564288, -9.102434],
+    [-1.6903394e-5, 0.00587943, 0.25235835],
+    [1.0731874e-5, -0.021087693, 6.778799],
+    [3.05756e-5, -0.040461577, 11.469073],
+    [4.513595e-5, -0.05467141, 14.907893],
+    [5.602085e-5, -0.06527932, 17.472057],
+    [6.4389315e-5, -0.0734168, 19.435516],
+    [7.107257e-5, -0.07989888, 20.99628],
+    [7.661802e-5, -0.08526371, 22.285358],
+    [8.1375336e-5, -0.08985558, 23.386679],
+    [8.557141e-5, -0.09389811, 24.354778],
+    [8.935843e-5, -0.09754134, 25.226274],
+    [9.284193e-5, -0.10088938, 26.026575],
+    [9.609767e-5, -0.10401694, 26.773916],
+    [9.918163e-5, -0.106979236, 27.481775],
+    [0.000102136604, -0.1098185, 28.160458],
+    [-5.583946e-5, 0.04586628, -9.838517],
+    [-2.4231887e-5, 0.014973462, -2.3470957],
+    [-1.0549192e-6, -0.0076988884, 3.1535482],
+    [1.638071e-5, -0.024761822, 7.2941265],
+    [2.9756731e-5, -0.03785001, 10.469597],
+    [4.0183888e-5, -0.04804504, 12.941531],
+    [4.8454833e-5, -0.05612098, 14.897513],
+    [5.517558e-5, -0.06267189, 16.48191],
+    [6.079587e-5, -0.06813999, 17.802452],
+    [6.563058e-5, -0.07283553, 18.93483],
+    [6.9895505e-5, -0.07697137, 19.93103],
+    [7.3740004e-5, -0.08069498, 20.82709],
+    [7.726956e-5, -0.08411055, 21.648476],
+    [8.056068e-5, -0.087293684, 22.413677],
+    [8.3670326e-5, -0.09030068, 23.13646],
+    [8.664193e-5, -0.09317446, 23.827324],
+    [-5.7445362e-5, 0.04909789, -10.965209],
+    [-3.1128173e-5, 0.023312714, -4.697104],
+    [-1.1068143e-5, 0.0036456657, 0.08522821],
+    [4.552575e-6, -0.011673966, 3.8108912],
+    [1.6928232e-5, -0.02381062, 6.7621365],
+    [2.6875994e-5, -0.033562046, 9.132438],
+    [3.4980836e-5, -0.041500308, 11.060673],
+    [4.168904e-5, -0.048062954, 12.653257],
+    [4.7353653e-5, -0.053597134, 13.994803],
+    [5.2245436e-5, -0.058369722, 15.150473],
+    [5.6563455e-5, -0.06257723, 16.168306],
+    [6.04516e-5, -0.066361874, 17.083096],
+    [6.4014304e-5, -0.069826916, 17.920122],
+    [6.732846e-5, -0.07304846, 18.698019],
+    [7.0451846e-5, -0.07608368, 19.430796],
+    [7.342875e-5, -0.07897644, 20.129192],
+    [-6.018204e-5, 0.053175088, -12.24412],
+    [-3.7570437e-5, 0.030972855, -6.8353176],
+    [-1.9808684e-5, 0.013523904, -2.5835826],
+    [-5.6025942e-6, -0.0004357078, 0.8181758],
+    [5.932439e-6, -0.011770716, 3.5801215],
+    [1.542115e-5, -0.02109245, 5.850893],
+    [2.3318964e-5, -0.028847113, 7.7390494],
+    [2.9971208e-5, -0.035373587, 9.327121],
+    [3.565287e-5, -0.040942322, 10.681075],
+    [4.0587e-5, -0.045773212, 11.854638],
+    [4.4949917e-5, -0.050040387, 12.890413],
+    [4.8876438e-5, -0.053877193, 13.821059],
+    [5.246823e-5, -0.05738427, 14.671243],
+    [5.5801833e-5, -0.060637444, 15.459555],
+    [5.893559e-5, -0.06369454, 16.200176],
+    [6.191462e-5, -0.06660028, 16.904087],
+    [-6.348136e-5, 0.057614226, -13.572543],
+    [-4.3597618e-5, 0.038053054, -8.797927],
+    [-2.759914e-5, 0.022307584, -4.9540653],
+    [-1.4526407e-5, 0.009438647, -1.8122798],
+    [-3.702171e-6, -0.0012172187, 0.7890338],
+    [5.3648328e-6, -0.010141722, 2.9672544],
+    [1.30401995e-5, -0.01769371, 4.809866],
+    [1.9603287e-5, -0.024147708, 6.3838406],
+    [2.5274996e-5, -0.029721124, 7.742268],
+    [3.0236366e-5, -0.034592472, 8.928795],
+    [3.4637527e-5, -0.038910072, 9.979736],
+    [3.8600323e-5, -0.04279449, 10.924646],
+    [4.222106e-5, -0.046341136, 11.786934],
+    [4.557474e-5, -0.04962438, 12.584851],
+    [4.8719787e-5, -0.052702177, 13.332637],
+    [5.170188e-5, -0.055619884, 14.04143],
+    [-6.7061985e-5, 0.062186662, -14.90444],
+    [-4.9263315e-5, 0.044646587, -10.615678],
+    [-3.4659235e-5, 0.030249586, -7.094999],
+    [-2.2514783e-5, 0.018274903, -4.1665726],
+    [-1.2297239e-5, 0.008199682, -1.7028009],
+    [-3.6113825e-6, -0.00036429559, 0.3910923],
+    [3.8424655e-6, -0.0077116685, 2.1870687],
+    [1.0296371e-5, -0.01407082, 3.7409368],
+    [1.593417e-5, -0.019622874, 5.0969872],
+    [2.090581e-5, -0.024515765, 6.2914267],
+    [2.5336914e-5, -0.02887364, 7.354672],
+    [2.9333678e-5, -0.032801613, 8.31251],
+    [3.298434e-5, -0.036387146, 9.186414],
+    [3.6360598e-5, -0.039701387, 9.993868],
+    [3.951989e-5, -0.04280136, 10.748892],
+    [4.2508174e-5, -0.045732744, 11.462719],
+    [-7.077862e-5, 0.06678213, -16.21951],
+    [-5.4620916e-5, 0.050834317, -12.313891],
+    [-4.114588e-5, 0.037530214, -9.055389],
+    [-2.977525e-5, 0.026301762, -6.3051834],
+    [-2.0080433e-5, 0.016727597, -3.9602728],
+    [-1.1737235e-5, 0.008488758, -1.9426594],
+    [-4.4958256e-6, 0.001339254, -0.1921663],
+    [1.8398433e-6, -0.0049141156, 1.3384913],
+    [7.4263685e-6, -0.010425819, 2.6871386],
+    [1.2391513e-5, -0.015322018, 3.8846896],
+    [1.6841797e-5, -0.019708028, 4.9569697],
+    [2.0868205e-5, -0.02367393, 5.926091],
+    [2.4548946e-5, -0.027297271, 6.81111],
+    [2.7950371e-5, -0.030643925, 7.6282234],
+    [3.112768e-5, -0.033768736, 8.39093],
+    [3.412629e-5, -0.036716875, 9.11035],
+    [-7.45549e-5, 0.071348704, -17.509766],
+    [-5.971891e-5, 0.05668454, -13.913304],
+    [-4.717541e-5, 0.044282943, -10.871491],
+    [-3.6459016e-5, 0.033686005, -8.272254],
+    [-2.721827e-5, 0.02454768, -6.030873],
+    [-1.9182819e-5, 0.016601594, -4.0821123],
+    [-1.21412795e-5, 0.009639324, -2.3749104],
+    [-5.9258095e-6, 0.0034952557, -0.8686773],
+    [-4.010297e-7, -0.0019643065, 0.46936932],
+    [4.5439915e-6, -0.006849007, 1.6661279],
+    [9.001728e-6, -0.0112503385, 2.7440577],
+    [1.3050948e-5, -0.015246326, 3.7223256],
+    [1.6759845e-5, -0.0189046, 4.6175613],
+    [2.0187967e-5, -0.022284301, 5.4443173],
+    [2.3386792e-5, -0.025436599, 6.2151957],
+    [2.6400054e-5, -0.028405013, 6.94092],
+    [-7.835127e-5, 0.07586456, -18.77334],
+    [-6.459997e-5, 0.062254667, -15.430985],
+    [-5.2836782e-5, 0.050609645, -12.570965],
+    [-4.267995e-5, 0.0405533, -10.101074],
+    [-3.38364e-5, 0.031796686, -7.950473],
+    [-2.607743e-5, 0.02411415, -6.0638237],
+    [-1.9221932e-5, 0.017326895, -4.397263],
+    [-1.3124526e-5, 0.0112913, -2.915546],
+    [-7.66688e-6, 0.005890377, -1.5899513],
+    [-2.7513886e-6, 0.0010275557, -0.3967606],
+    [1.7035759e-6, -0.0033779917, 0.68388826],
+    [5.7674733e-6, -0.007395111, 1.668923],
+    [9.500264e-6, -0.011083294, 2.5729814],
+    [1.2954652e-5, -0.014494891, 3.4089537],
+    [1.6177279e-5, -0.017676285, 4.1882687],
+    [1.920912e-5, -0.020668263, 4.920986],
+    [-8.214879e-5, 0.08032402, -20.011473],
+    [-6.930138e-5, 0.06759327, -16.881153],
+    [-5.8199963e-5, 0.056590304, -14.175509],
+    [-4.852618e-5, 0.04700099, -11.817448],
+    [-4.0032086e-5, 0.038580533, -9.746875],
+    [-3.252175e-5, 0.031135391, -7.9162655],
+    [-2.5838324e-5, 0.024510525, -6.287539],
+    [-1.9854484e-5, 0.018580008, -4.829754],
+    [-1.4465856e-5, 0.013240547, -3.517522],
+    [-9.585723e-6, 0.008406254, -2.3297257],
+    [-5.141188e-6, 0.0040048896, -1.2485937],
+    [-1.0702138e-6, -2.507944e-5, -0.2589836],
+    [2.6808602e-6, -0.003736926, 0.6522235],
+    [6.158985e-6, -0.0071773026, 1.496522],
+    [9.405922e-6, -0.010387748, 2.2841535],
+    [1.2459128e-5, -0.013405559, 3.0243204],
+    [-8.594026e-5, 0.08473011, -21.22695],
+    [-7.3855575e-5, 0.072741605, -18.275743],
+    [-6.332116e-5, 0.062289044, -15.702513],
+    [-5.4067586e-5, 0.05310624, -13.441849],
+    [-4.588244e-5, 0.044983223, -11.442145],
+    [-3.859597e-5, 0.037752096, -9.662124],
+    [-3.2070846e-5, 0.031276982, -8.068372],
+    [-2.6194777e-5, 0.025446685, -6.6335373],
+    [-2.0874759e-5, 0.02016907, -5.3349476],
+    [-1.6033153e-5, 0.015367178, -4.1536617],
+    [-1.1604333e-5, 0.010975911, -3.0736492],
+    [-7.5322278e-6, 0.006939612, -2.0812008],
+    [-3.768361e-6, 0.0032101267, -1.1644481],
+    [-2.7031774e-7, -0.0002547116, -0.31299484],
+    [2.9996272e-6, -0.003492441, 0.48242038],
+    [6.0753164e-6, -0.0065367687, 1.2301188],
+    [-8.972545e-5, 0.08909064, -22.423313],
+    [-7.82911e-5, 0.07773527, -19.624954],
+    [-6.824648e-5, 0.06775837, -17.1662],
+    [-5.9360784e-5, 0.058931604, -14.990884],
+    [-5.1449806e-5, 0.051072694, -13.054146],
+    [-4.4365024e-5, 0.044034578, -11.319792],
+    [-3.798517e-5, 0.037697118, -9.758247],
+    [-3.2210246e-5, 0.031961203, -8.345107],
+    [-2.6956937e-5, 0.026744222, -7.0600204],
+    [-2.2155113e-5, 0.021976583, -5.8858414],
+    [-1.7745297e-5, 0.017599242, -4.8080177],
+    [-1.367645e-5, 0.0135614965, -3.814048],
+    [-9.90434e-6, 0.009819378, -2.8930871],
+    [-6.3901894e-6, 0.0063343123, -2.0356164],
+    [-3.0997494e-6, 0.0030722106, -1.2332213],
+    [-2.0235829e-9, 2.1846101e-6, -0.47827095],
+    [-0.0003861614, 0.33962584, -74.71939],
+    [-0.00090538705, 0.9134306, -228.56104],
+    [0.00012507348, -0.14899531, 41.45147],
+    [0.0001602349, -0.18281272, 49.54014],
+    [0.00017584865, -0.19751945, 52.993782],
+    [0.00018533181, -0.20625949, 55.005756],
+    [0.00019212336, -0.2124077, 56.397243],
+    [0.00019750833, -0.21722293, 57.474117],
+    [0.00020209671, -0.22130084, 58.380684],
+    [0.0002062259, -0.22496937, 59.196125],
+    [0.00021010297, -0.22842892, 59.968613],
+    [0.00021386541, -0.23181191, 60.729877],
+    [0.0002176103, -0.2352115, 61.502148],
+    [0.00022141052, -0.23869745, 62.302044],
+    [0.00022532357, -0.24232475, 63.14266],
+    [0.0002293973, -0.24613923, 64.03491],
+    [-0.00090538705, 0.91586715, -229.65723],
+    [2.1246362e-5, -0.040788036, 13.830216],
+    [7.979866e-5, -0.097718604, 27.573874],
+    [0.00010781254, -0.12491788, 34.133236],
+    [0.00012315567, -0.13970338, 37.676456],
+    [0.00013329081, -0.14938587, 39.97963],
+    [0.00014089711, -0.15659942, 41.684685],
+    [0.00014710272, -0.16245432, 43.062424],
+    [0.0001524672, -0.1675011, 44.24711],
+    [0.00015730591, -0.17204978, 45.314274],
+    [0.00016181516, -0.17629303, 46.31082],
+    [0.00016612688, -0.1803602, 47.268204],
+    [0.00017033577, -0.18434407, 48.208965],
+    [0.00017451338, -0.18831468, 49.15012],
+    [0.00017871667, -0.19232781, 50.105213],
+    [0.00018299335, -0.19643024, 51.08563],
+    [-5.6884e-5, 0.039596856, -6.4892197],
+    [1.3002894e-5, -0.02831629, 9.897229],
+    [5.2316158e-5, -0.066658266, 19.17864],
+    [7.548138e-5, -0.089252464, 24.649054],
+    [8.997103e-5, -0.10334036, 28.051289],
+    [0.000100058074, -0.11310412, 30.400602],
+    [0.00010780011, -0.12056712, 32.19019],
+    [0.00011418289, -0.12670073, 33.65723],
+    [0.000119724944, -0.13201632, 34.92668],
+    [0.00012472586, -0.13680942, 36.07078],
+    [0.00012937369, -0.14126554, 37.134857],
+    [0.00013379526, -0.14550975, 38.14948],
+    [0.0001380813, -0.14963152, 39.1365],
+    [0.00014230052, -0.15369858, 40.112488],
+    [0.00014650743, -0.15776478, 41.090595],
+    [0.0001507473, -0.16187479, 42.081764],
+    [-4.35558e-5, 0.029869877, -4.8123436],
+    [3.1733196e-6, -0.015703373, 6.2196903],
+    [3.2949913e-5, -0.04481439, 13.281917],
+    [5.2680185e-5, -0.064114116, 17.966656],
+    [6.622351e-5, -0.07734468, 21.175066],
+    [7.608351e-5, -0.086953476, 23.50069],
+    [8.378576e-5, -0.094440125, 25.308952],
+    [9.0176145e-5, -0.10063844, 26.803528],
+    [9.573235e-5, -0.106020026, 28.099752],
+    [0.00010074063, -0.11086771, 29.266857],
+    [0.00010538358, -0.115362, 30.34904],
+    [0.00010978482, -0.11962513, 31.376236],
+    [0.000114032955, -0.123744756, 32.369923],
+    [0.00011819466, -0.12778684, 33.34627],
+    [0.00012232256, -0.13180353, 34.31806],
+    [0.00012646026, -0.13583803, 35.29591],
+    [-4.1082294e-5, 0.029824112, -5.2912107],
+    [-6.1022033e-6, -0.004383469, 3.0101917],
+    [1.8044528e-5, -0.028038947, 8.759756],
+    [3.526744e-5, -0.044922758, 12.866117],
+    [4.788873e-5, -0.05729005, 15.873201],
+    [5.745939e-5, -0.0666557, 18.148176],
+    [6.506793e-5, -0.07408886, 19.95142],
+    [7.141746e-5, -0.08028258, 21.452232],
+    [7.6941775e-5, -0.08566533, 22.75546],
+    [8.191297e-5, -0.090506196, 23.927013],
+    [8.6508924e-5, -0.09498122, 25.01005],
+    [9.085155e-5, -0.09921117, 26.034184],
+    [9.502831e-5, -0.1032827, 27.020681],
+    [9.910498e-5, -0.10726101, 27.985552],
+    [0.000103133236, -0.1111974, 28.941395],
+    [0.000107155574, -0.11513407, 29.898588],
+    [-4.2632044e-5, 0.03319435, -6.491874],
+    [-1.45875765e-5, 0.0057072192, 0.1927012],
+    [5.873552e-6, -0.014374277, 5.081931],
+    [2.1219283e-5, -0.029445153, 8.753479],
+    [3.2995824e-5, -0.041010175, 11.57106],
+    [4.224727e-5, -0.05008927, 13.78196],
+    [4.9741357e-5, -0.057435907, 15.569577],
+    [5.603874e-5, -0.06360254, 17.06884],
+    [6.152293e-5, -0.068968005, 18.372475],
+    [6.644968e-5, -0.07378539, 19.542503],
+    [7.099181e-5, -0.078225866, 20.620926],
+    [7.5269774e-5, -0.08240885, 21.637054],
+    [7.9370664e-5, -0.08642075, 22.612114],
+    [8.3360144e-5, -0.090326756, 23.562128],
+    [8.7289656e-5, -0.09417797, 24.499666],
+    [9.120112e-5, -0.098016076, 25.434986],
+    [-4.5910467e-5, 0.037927628, -7.9557376],
+    [-2.2377535e-5, 0.014816767, -2.3249156],
+    [-4.498746e-6, -0.0027596198, 1.9611764],
+    [9.407431e-6, -0.016438607, 5.2986374],
+    [2.0441363e-5, -0.02729354, 7.947509],
+    [2.9361932e-5, -0.03606655, 10.087941],
+    [3.6727826e-5, -0.043305807, 11.85336],
+    [4.297176e-5, -0.04943745, 13.347814],
+    [4.842051e-5, -0.054784264, 14.650311],
+    [5.3309108e-5, -0.059578884, 15.817891],
+    [5.7803816e-5, -0.06398609, 16.891],
+    [6.202355e-5, -0.06812387, 17.898628],
+    [6.605536e-5, -0.07207866, 18.862019],
+    [6.996518e-5, -0.07591596, 19.797293],
+    [7.380469e-5, -0.07968718, 20.717096],
+    [7.7615936e-5, -0.08343416, 21.631742],
+    [-5.000739e-5, 0.043238346, -9.51377],
+    [-2.961193e-5, 0.023173105, -4.616699],
+    [-1.3631099e-5, 0.0074382136, -0.7739571],
+    [-8.532074e-7, -0.0051493263, 2.301488],
+    [9.543668e-6, -0.015393107, 4.8047786],
+    [1.814032e-5, -0.023861999, 6.874236],
+    [2.5365767e-5, -0.03097718, 8.6124525],
+    [3.1554722e-5, -0.037068184, 10.099886],
+    [3.6975376e-5, -0.042399853, 11.401358],
+    [4.18367e-5, -0.047179043, 12.567597],
+    [4.6295638e-5, -0.05156136, 13.636818],
+    [5.0468578e-5, -0.055662345, 14.6374235],
+    [5.444249e-5, -0.05956842, 15.590673],
+    [5.828394e-5, -0.06334577, 16.512861],
+    [6.2045154e-5, -0.067046374, 17.416792],
+    [6.57686e-5, -0.07071252, 18.312891],
+    [-5.452337e-5, 0.048799414, -11.099718],
+    [-3.6417725e-5, 0.030958068, -6.738621],
+    [-2.1882064e-5, 0.016625358, -3.2334058],
+    [-1.0004812e-5, 0.004909085, -0.36707145],
+    [-1.4902605e-7, -0.0048148534, 2.012272],
+    [8.145741e-6, -0.012998243, 4.014676],
+    [1.5223889e-5, -0.019979568, 5.7226944],
+    [2.1353117e-5, -0.026022475, 7.2007303],
+    [2.6750773e-5, -0.031341553, 8.501309],
+    [3.1595933e-5, -0.036114052, 9.667904],
+    [3.603231e-5, -0.040482484, 10.735524],
+    [4.0171846e-5, -0.04455803, 11.731513],
+    [4.4100943e-5, -0.048426587, 12.677019],
+    [4.7886657e-5, -0.05215488, 13.588475],
+    [5.15821e-5, -0.055795804, 14.47892],
+    [5.5230565e-5, -0.059392493, 15.3589945],
+    [-5.927406e-5, 0.054472186, -12.688529],
+    [-4.2900814e-5, 0.0383139, -8.733146],
+    [-2.9495206e-5, 0.025077492, -5.4917574],
+    [-1.8347906e-5, 0.014067185, -2.794778],
+    [-8.9505165e-6, 0.0047838246, -0.52048296],
+    [-9.278898e-7, -0.0031413273, 1.4211125],
+    [6.004391e-6, -0.009988184, 3.098376],
+    [1.2068518e-5, -0.015975762, 4.5648623],
+    [1.7444203e-5, -0.021281531, 5.8640304],
+    [2.228175e-5, -0.026054244, 7.032368],
+    [2.670812e-5, -0.030419815, 8.100814],
+    [3.082814e-5, -0.034482382, 9.094991],
+    [3.4726098e-5, -0.038325775, 10.035546],
+    [3.8469298e-5, -0.042017013, 10.938997],
+    [4.2111755e-5, -0.045609906, 11.818616],
+    [4.5697783e-5, -0.049148645, 12.685308],
+    [-6.4174914e-5, 0.060202822, -14.273091],
+    [-4.914823e-5, 0.045352936, -10.633125],
+    [-3.6644502e-5, 0.03299125, -7.602163],
+    [-2.6095964e-5, 0.022559745, -5.04394],
+    [-1.7086819e-5, 0.013649433, -2.8585362],
+    [-9.3048475e-6, 0.0059529166, -0.9708107],
+    [-2.5100087e-6, -0.0007663851, 0.67710704],
+    [3.4868733e-6, -0.006695153, 2.1309216],
+    [8.838747e-6, -0.011984529, 3.4276743],
+    [1.36733e-5, -0.016760912, 4.5983834],
+    [1.8100029e-5, -0.02113289, 5.669736],
+    [2.221356e-5, -0.025194477, 6.6648693],
+    [2.609408e-5, -0.029025458, 7.603441],
+    [2.980814e-5, -0.032692116, 8.5018],
+    [3.34105e-5, -0.036249053, 9.373418],
+    [3.6946356e-5, -0.03974134, 10.229425],
+    [-6.9191105e-5, 0.065978214, -15.854547],
+    [-5.5232817e-5, 0.052166384, -12.464797],
+    [-4.3459844e-5, 0.040513143, -9.604175],
+    [-3.3406865e-5, 0.03056033, -7.1606083],
+    [-2.472681e-5, 0.021965982, -5.050414],
+    [-1.7154885e-5, 0.014468977, -3.209662],
+    [-1.0484995e-5, 0.007865903, -1.5885226],
+    [-4.5532856e-6, 0.0019948739, -0.14731254],
+    [7.7349574e-7, -0.0032759141, 1.1463047],
+    [5.606276e-6, -0.008056325, 2.3193085],
+    [1.0040044e-5, -0.012440583, 3.3948646],
+    [1.415822e-5, -0.016511573, 4.393377],
+    [1.8034412e-5, -0.020342542, 5.3328996],
+    [2.1732714e-5, -0.023997353, 6.2291875],
+    [2.5307705e-5, -0.02753044, 7.0956807],
+    [2.8805682e-5, -0.03098801, 7.9437947],
+    [-7.4314885e-5, 0.07180662, -17.438179],
+    [-6.121709e-5, 0.058830597, -14.249825],
+    [-5.004261e-5, 0.047757175, -11.528514],
+    [-4.0402185e-5, 0.038202472, -9.180192],
+    [-3.2000724e-5, 0.029875249, -7.133482],
+    [-2.4610204e-5, 0.022550318, -5.3331704],
+    [-1.8051041e-5, 0.016050188, -3.7357159],
+    [-1.217909e-5, 0.010232266, -2.3061213],
+    [-6.876605e-6, 0.0049799746, -1.0157542],
+    [-2.0452019e-6, 0.00019579007, 0.15935586],
+    [2.3991008e-6, -0.0042036306, 1.2397122],
+    [6.5299264e-6, -0.008291454, 2.2433338],
+    [1.0413224e-5, -0.012133313, 3.1864014],
+    [1.41085775e-5, -0.015788585, 4.083569],
+    [1.7669114e-5, -0.019310277, 4.9479294],
+    [2.1141317e-5, -0.022744795, 5.790952],
+    [-7.955368e-5, 0.07770772, -19.031336],
+    [-6.7156376e-5, 0.065411665, -16.006723],
+    [-5.647538e-5, 0.054815862, -13.400046],
+    [-4.7179154e-5, 0.04559281, -11.130947],
+    [-3.901286e-5, 0.037490632, -9.137605],
+    [-3.1777246e-5, 0.03031227, -7.3716345],
+    [-2.5313793e-5, 0.023900833, -5.794502],
+    [-1.949418e-5, 0.01812924, -4.374975],
+    [-1.4212948e-5, 0.012892958, -3.087354],
+    [-9.381657e-6, 0.008104247, -1.9100507],
+    [-4.9247924e-6, 0.0036881135, -0.82460165],
+    [-7.7634985e-7, -0.0004210626, 0.18516186],
+    [3.122831e-6, -0.0042821737, 1.1337652],
+    [6.8267645e-6, -0.007949051, 2.0344965],
+    [1.03853845e-5, -0.011471527, 2.8996694],
+    [1.3844176e-5, -0.014895018, 3.740512],
+    [-8.492475e-5, 0.08370849, -20.642662],
+    [-7.310129e-5, 0.071968906, -17.751917],
+    [-6.28283e-5, 0.06176739, -15.239731],
+    [-5.38188e-5, 0.05282004, -13.036345],
+    [-4.5849665e-5, 0.044905994, -11.087477],
+    [-3.8744427e-5, 0.03785048, -9.35016],
+    [-3.23615e-5, 0.0315132, -7.789888],
+    [-2.6585474e-5, 0.02577971, -6.378505],
+    [-2.1320755e-5, 0.020555157, -5.092663],
+    [-1.6487003e-5, 0.015759759, -3.912713],
+    [-1.2015434e-5, 0.011325154, -2.8218112],
+    [-7.845909e-6, 0.007191519, -1.805205],
+    [-3.9248216e-6, 0.0033054703, -0.84972286],
+    [-2.0308634e-7, -0.00038192517, 0.056720104],
+    [3.3650174e-6, -0.003916292, 0.9253991],
+    [6.822689e-6, -0.0073407847, 1.7669876],
+    [-9.045234e-5, 0.08984114, -22.281754],
+    [-7.909981e-5, 0.078557506, -19.50052],
+    [-6.916356e-5, 0.0686807, -17.065979],
+    [-6.0391543e-5, 0.059961013, -14.916688],
+    [-5.258563e-5, 0.05220205, -13.004315],
+    [-4.5587865e-5, 0.045247167, -11.290303],
+    [-3.9270406e-5, 0.038969517, -9.743415],
+    [-3.3528242e-5, 0.033264842, -8.337971],
+    [-2.8274064e-5, 0.028046427, -7.0526032],
+    [-2.3434033e-5, 0.023240866, -5.869216],
+    [-1.894483e-5, 0.018785179, -4.772276],
+    [-1.4751119e-5, 0.014624278, -3.7481904],
+    [-1.08035865e-5, 0.0107090315, -2.7848277],
+    [-7.0572487e-6, 0.0069945822, -1.8711057],
+    [-3.4701304e-6, 0.0034390287, -0.99666435],
+    [-2.3156113e-9, 2.4995027e-6, -0.15164144],
+    [-0.00032070855, 0.27776858, -59.858704],
+    [0.00090538705, -0.9217879, 232.65797],
+    [0.00014851644, -0.17356187, 48.18285],
+    [0.00017521715, -0.19921228, 54.321983],
+    [0.00018896938, -0.21216428, 57.367184],
+    [0.00019835331, -0.22087409, 59.387535],
+    [0.000205765, -0.22770175, 60.960274],
+    [0.00021218874, -0.23361397, 62.321236],
+    [0.00021813042, -0.2391057, 63.5908],
+    [0.0002238909, -0.24447034, 64.8401],
+    [0.00022966988, -0.24990214, 66.116165],
+    [0.00023561297, -0.25554276, 67.45326],
+    [0.00024183677, -0.26150587, 68.87898],
+    [0.00024844377, -0.267892, 70.41783],
+    [0.00025553151, -0.27479747, 72.09343],
+    [0.00026320014, -0.28232193, 73.9304],
+    [0.00090538705, -0.9242867, 233.82005],
+    [4.8341913e-5, -0.068157986, 21.001991],
+    [9.726162e-5, -0.11599657, 32.627644],
+    [0.00011998415, -0.13812928, 37.989346],
+    [0.00013351432, -0.15121216, 41.13919],
+    [0.00014329565, -0.160615, 43.39182],
+    [0.00015123807, -0.1682257, 45.210255],
+    [0.00015818427, -0.17487739, 46.798878],
+    [0.00016457963, -0.18101016, 48.26561],
+    [0.0001706939, -0.18689045, 49.675777],
+    [0.00017670885, -0.19269784, 51.07341],
+    [0.00018275858, -0.19856487, 52.491024],
+    [0.00018895052, -0.20459801, 53.954807],
+    [0.00019537793, -0.21088992, 55.487606],
+    [0.00020212731, -0.21752693, 57.110775],
+    [0.00020928476, -0.2245953, 58.84573],
+    [-3.131024e-5, 0.014165827, 0.074172385],
+    [3.2477918e-5, -0.04819372, 15.218508],
+    [6.6748726e-5, -0.081804514, 23.406385],
+    [8.6444204e-5, -0.10109999, 28.103811],
+    [9.926958e-5, -0.113622844, 31.144363],
+    [0.000108830915, -0.12292949, 33.398365],
+    [0.000116682546, -0.1305575, 35.24312],
+    [0.00012357041, -0.13724582, 36.860153],
+    [0.00012990134, -0.14339766, 38.34861],
+    [0.00013592409, -0.14925973, 39.769157],
+    [0.00014180667, -0.15499876, 41.162834],
+    [0.0001476729, -0.1607378, 42.55996],
+    [0.00015362141, -0.16657501, 43.984768],
+    [0.00015973719, -0.1725952, 45.458233],
+    [0.00016609815, -0.17887634, 46.99969],
+    [0.00017278045, -0.18549488, 48.62815],
+    [-2.4375799e-5, 0.01054053, 0.29516396],
+    [1.8677436e-5, -0.031671155, 10.5721245],
+    [4.5393866e-5, -0.057928234, 16.979736],
+    [6.2792365e-5, -0.07502847, 21.153973],
+    [7.491733e-5, -0.08692709, 24.055271],
+    [8.4176005e-5, -0.095996335, 26.263659],
+    [9.183238e-5, -0.103486635, 28.085934],
+    [9.855284e-5, -0.11005856, 29.684464],
+    [0.00010471673, -0.11608862, 31.151892],
+    [0.00011055975, -0.121810935, 32.54588],
+    [0.00011624155, -0.12738429, 33.905567],
+    [0.00012187942, -0.13292547, 35.25978],
+    [0.00012756628, -0.13852713, 36.631428],
+    [0.00013338144, -0.14426854, 38.04014],
+    [0.00013939702, -0.15022196, 39.50384],
+    [0.0001456827, -0.15645735, 41.039932],
+    [-2.5771385e-5, 0.014217496, -1.0793496],
+    [6.8257095e-6, -0.017810106, 6.7322063],
+    [2.8960585e-5, -0.039600488, 12.057063],
+    [4.4578468e-5, -0.054983158, 15.818581],
+    [5.6070647e-5, -0.06629531, 18.583887],
+    [6.504497e-5, -0.07511959, 20.739492],
+    [7.251116e-5, -0.08245451, 22.530264],
+    [7.906257e-5, -0.08888839, 24.100817],
+    [8.505564e-5, -0.09477519, 25.53828],
+    [9.0717134e-5, -0.1003404, 26.898228],
+    [9.6202166e-5, -0.10573843, 28.218752],
+    [0.00010162427, -0.11108245, 29.527805],
+    [0.00010707319, -0.11646204, 30.847546],
+    [0.0001126248, -0.12195308, 32.196777],
+    [0.00011834739, -0.12762398, 33.592476],
+    [0.00012430667, -0.13354072, 35.05104],
+    [-3.0052995e-5, 0.020236535, -2.9212222],
+    [-3.612272e-6, -0.005786574, 3.4349992],
+    [1.547334e-5, -0.024601126, 8.037881],
+    [2.9693709e-5, -0.03862906, 11.472455],
+    [4.0618237e-5, -0.049404558, 14.111002],
+    [4.9342583e-5, -0.058004852, 16.216284],
+    [5.6649544e-5, -0.06520347, 17.97785],
+    [6.3058804e-5, -0.07151563, 19.522305],
+    [6.890462e-5, -0.07727337, 20.931402],
+    [7.440701e-5, -0.08269555, 22.259096],
+    [7.971863e-5, -0.08793425, 23.542917],
+    [8.495156e-5, -0.093101256, 24.810488],
+    [9.019411e-5, -0.09828476, 26.083633],
+    [9.5520394e-5, -0.1035589, 27.38072],
+    [0.00010099662, -0.10898999, 28.71821],
+    [0.000106686006, -0.114641495, 30.111868],
+    [-3.5516885e-5, 0.027107025, -4.9054117],
+    [-1.3066765e-5, 0.004979226, 0.5060383],
+    [3.864789e-6, -0.011731904, 4.598354],
+    [1.6981605e-5, -0.024687173, 7.7734523],
+    [2.7398757e-5, -0.034977525, 10.2962265],
+    [3.590134e-5, -0.04337436, 12.354709],
+    [4.3082622e-5, -0.05046335, 14.092262],
+    [4.938351e-5, -0.056681395, 15.616204],
+    [5.5113338e-5, -0.062335804, 17.002184],
+    [6.0485643e-5, -0.06763906, 18.30259],
+    [6.565195e-5, -0.072742164, 19.554699],
+    [7.0724425e-5, -0.07775704, 20.786175],
+    [7.579134e-5, -0.08277184, 22.018833],
+    [8.092647e-5, -0.08786041, 23.270967],
+    [8.6195236e-5, -0.09308825, 24.558828],
+    [9.165962e-5, -0.09851754, 25.897865],
+    [-4.154643e-5, 0.03432522, -6.930512],
+    [-2.1852537e-5, 0.014888816, -2.1720233],
+    [-6.504642e-6, -0.00027560288, 1.5448078],
+    [5.7365255e-6, -0.012378598, 4.513506],
+    [1.5708752e-5, -0.022240816, 6.9335365],
+    [2.4009167e-5, -0.03044904, 8.947932],
+    [3.109189e-5, -0.03745121, 10.666252],
+    [3.731753e-5, -0.043604407, 12.176136],
+    [4.2964133e-5, -0.049184773, 13.545542],
+    [4.8236867e-5, -0.0543965, 14.824812],
+    [5.3286454e-5, -0.059389822, 16.051039],
+    [5.822623e-5, -0.06427785, 17.252184],
+    [6.314564e-5, -0.069149956, 18.450363],
+    [6.811926e-5, -0.07408075, 19.66406],
+    [7.32128e-5, -0.07913603, 20.909595],
+    [7.84881e-5, -0.08437779, 22.202362],
+    [-4.7910315e-5, 0.04172702, -8.969773],
+    [-3.020376e-5, 0.024231102, -4.682026],
+    [-1.6051457e-5, 0.010234063, -1.2485148],
+    [-4.507458e-6, -0.0011901539, 1.5557538],
+    [5.0846875e-6, -0.010685502, 3.8875008],
+    [1.3200827e-5, -0.018719919, 5.860856],
+    [2.020271e-5, -0.025650164, 7.5630546],
+    [2.6381227e-5, -0.03176406, 9.064683],
+    [3.1976415e-5, -0.037299883, 10.424348],
+    [3.7180213e-5, -0.042448625, 11.689128],
+    [4.214141e-5, -0.047358625, 12.895646],
+    [4.697497e-5, -0.052144624, 14.072269],
+    [5.1772553e-5, -0.056898236, 15.24167],
+    [5.6610483e-5, -0.061695807, 16.422758],
+    [6.155586e-5, -0.06660453, 17.632195],
+    [6.667126e-5, -0.0716871, 18.885542],
+    [-5.453441e-5, 0.04928183, -11.0246],
+    [-3.83026e-5, 0.033225145, -7.085824],
+    [-2.5067555e-5, 0.020122984, -3.869333],
+    [-1.4078462e-5, 0.009238713, -1.1957941],
+    [-4.8029087e-6, 4.9184782e-5, 1.0623078],
+    [3.1513218e-6, -0.007831718, 2.9992354],
+    [1.0084195e-5, -0.01469994, 4.687375],
+    [1.6235881e-5, -0.020793043, 6.1849685],
+    [2.1808124e-5, -0.02631117, 7.5412107],
+    [2.6973114e-5, -0.031425603, 8.798303],
+    [3.1874235e-5, -0.03627927, 9.991529],
+    [3.662745e-5, -0.04098793, 11.1495075],
+    [4.132721e-5, -0.045645997, 12.295599],
+    [4.60526e-5, -0.05033258, 13.4494],
+    [5.0872997e-5, -0.05511713, 14.628128],
+    [5.5852746e-5, -0.060064107, 15.847773],
+    [-6.1417115e-5, 0.05701822, -13.108276],
+    [-4.6299043e-5, 0.04204728, -9.432505],
+    [-3.377244e-5, 0.029635219, -6.3831434],
+    [-2.3222025e-5, 0.019177068, -3.81262],
+    [-1.4203207e-5, 0.0102352, -1.6140912],
+    [-6.3837665e-6, 0.002482123, 0.29248923],
+    [4.925215e-7, -0.004335166, 1.9690514],
+    [6.6307866e-6, -0.010419623, 3.4653614],
+    [1.2202476e-5, -0.015941277, 4.823197],
+    [1.735693e-5, -0.021048607, 6.0791235],
+    [2.2226499e-5, -0.025873505, 7.265688],
+    [2.6925522e-5, -0.030530108, 8.411102],
+    [3.1550957e-5, -0.03511534, 9.539351],
+    [3.618537e-5, -0.03971179, 10.67089],
+    [4.0901414e-5, -0.044392176, 11.823741],
+    [4.5766174e-5, -0.0492237, 13.014566],
+    [-6.859545e-5, 0.064995356, -15.240007],
+    [-5.432429e-5, 0.050848544, -11.763542],
+    [-4.2343196e-5, 0.038966514, -8.842254],
+    [-3.2133914e-5, 0.02883866, -6.3513546],
+    [-2.3315883e-5, 0.0200897, -4.199071],
+    [-1.5601072e-5, 0.012435275, -2.315802],
+    [-8.765426e-6, 0.0056538973, -0.64727783],
+    [-2.6289874e-6, -0.00043260303, 0.85021436],
+    [2.9581242e-6, -0.0059728962, 2.2132103],
+    [8.125828e-6, -0.011096109, 3.4734983],
+    [1.2991741e-5, -0.015919406, 4.659981],
+    [1.7663684e-5, -0.0205504, 5.799247],
+    [2.2238999e-5, -0.02508638, 6.9153695],
+    [2.6803747e-5, -0.029613445, 8.02967],
+    [3.1434694e-5, -0.034208424, 9.161187],
+    [3.620274e-5, -0.038942307, 10.327517],
+    [-7.613154e-5, 0.07329266, -17.442986],
+    [-6.250108e-5, 0.05976742, -14.116446],
+    [-5.0933264e-5, 0.04828527, -11.291426],
+    [-4.0980885e-5, 0.038404733, -8.859847],
+    [-3.2310996e-5, 0.02979688, -6.741141],
+    [-2.4668736e-5, 0.022209706, -4.873535],
+    [-1.7854241e-5, 0.015445384, -3.2085018],
+    [-1.1706255e-5, 0.0093441, -1.7067963],
+    [-6.0905186e-6, 0.0037726073, -0.33565742],
+    [-8.913331e-7, -0.0013841058, 0.9332301],
+    [3.99566e-6, -0.0062299725, 2.1254978],
+    [8.668067e-6, -0.010862369, 3.2651973],
+    [1.3219194e-5, -0.015374542, 4.3753943],
+    [1.7736764e-5, -0.019854238, 5.4778156],
+    [2.2301865e-5, -0.024382617, 6.592568],
+    [2.6990261e-5, -0.02903552, 7.738446],
+    [-8.410852e-5, 0.082007565, -19.74425],
+    [-7.095207e-5, 0.06893981, -16.527554],
+    [-5.9685768e-5, 0.057747282, -13.771815],
+    [-4.9915147e-5, 0.048039813, -11.381351],
+    [-4.1343035e-5, 0.0395233, -9.284012],
+    [-3.3739732e-5, 0.03197025, -7.4239516],
+    [-2.6923764e-5, 0.025200788, -5.7569904],
+    [-2.0748006e-5, 0.019068936, -4.247246],
+    [-1.5089819e-5, 0.013452879, -2.8647447],
+    [-9.8438395e-6, 0.008247829, -1.5836786],
+    [-4.9156665e-6, 0.00335976, -0.38085786],
+    [-2.1731813e-7, -0.0012990847, 0.76538765],
+    [4.336975e-6, -0.0058143907, 1.8762468],
+    [8.832336e-6, -0.010271286, 2.9727912],
+    [1.335249e-5, -0.014753563, 4.075776],
+    [1.7978506e-5, -0.019342365, 5.205306],
+    [-9.263153e-5, 0.09125787, -22.175516],
+    [-7.980777e-5, 0.078508094, -19.034515],
+    [-6.8744266e-5, 0.06750753, -16.324099],
+    [-5.9085778e-5, 0.057904128, -13.957795],
+    [-5.056205e-5, 0.049429968, -11.869769],
+    [-4.2962423e-5, 0.041876074, -10.008652],
+    [-3.6119392e-5, 0.03507616, -8.333546],
+    [-2.9896539e-5, 0.028894713, -6.8111053],
+    [-2.4179917e-5, 0.023218436, -5.413428],
+    [-1.8871788e-5, 0.017950052, -4.11654],
+    [-1.3885016e-5, 0.013002747, -2.8990242],
+    [-9.139012e-6, 0.008296124, -1.7410282],
+    [-4.55536e-6, 0.0037518633, -0.6231878],
+    [-5.4848286e-8, -0.0007092304, 0.4740924],
+    [4.4445687e-6, -0.005169245, 1.5711395],
+    [9.0275435e-6, -0.009712896, 2.688937],
+    [-0.00010183243, 0.10118742, -24.774803],
+    [-8.9215166e-5, 0.08863064, -21.678818],
+    [-7.8262914e-5, 0.077731155, -18.991375],
+    [-6.864926e-5, 0.068164915, -16.632746],
+    [-6.012388e-5, 0.059683308, -14.541752],
+    [-5.249029e-5, 0.052091103, -12.670332],
+    [-4.559142e-5, 0.04523214, -10.980032],
+    [-3.929875e-5, 0.038978577, -9.439353],
+    [-3.350483e-5, 0.033223458, -8.021932],
+    [-2.8117496e-5, 0.02787497, -6.705124],
+    [-2.305486e-5, 0.022851478, -5.468779],
+    [-1.8241675e-5, 0.018077897, -4.29435],
+    [-1.3605431e-5, 0.013481811, -3.163938],
+    [-9.072782e-6, 0.008989897, -2.0594025],
+    [-4.566581e-6, 0.0045249984, -0.9616453],
+    [-3.0947735e-9, 3.3416702e-6, 0.15007572],
+    [-0.00025229258, 0.21050234, -43.13822],
+    [-0.00090538705, 0.9137714, -229.04349],
+    [0.00018211863, -0.21040745, 58.42427],
+    [0.00020374419, -0.23109272, 63.36252],
+    [0.00021671763, -0.24330296, 66.23457],
+    [0.00022655385, -0.2524961, 68.38318],
+    [0.00023503086, -0.26042712, 70.239136],
+    [0.00024296602, -0.26790002, 71.99919],
+    [0.0002508215, -0.27536815, 73.774055],
+    [0.00025890183, -0.28313023, 75.636665],
+    [0.000267438, -0.29141355, 77.64269],
+    [0.00027663074, -0.30041695, 79.84114],
+    [0.0002866773, -0.31033763, 82.28097],
+    [0.00029779001, -0.32138953, 85.015724],
+    [0.00031021237, -0.33381978, 88.10758],
+    [0.000324237, -0.34792703, 91.63204],
+    [-0.00090538705, 0.9171167, -230.63748],
+    [8.308006e-5, -0.104924224, 30.88939],
+    [0.00012268958, -0.14383145, 40.397457],
+    [0.00014190823, -0.16259141, 44.958363],
+    [0.00015467741, -0.17498499, 47.957123],
+    [0.00016476563, -0.18475193, 50.315636],
+    [0.00017356768, -0.19327745, 52.37562],
+    [0.00018175632, -0.20122965, 54.30191],
+    [0.00018972348, -0.20899785, 56.190594],
+    [0.00019773355, -0.21684512, 58.106686],
+    [0.00020598894, -0.22497335, 60.100212],
+    [0.00021466354, -0.23355675, 62.214504],
+    [0.00022392298, -0.24276203, 64.4912],
+    [0.00023393935, -0.25276324, 66.973976],
+    [0.0002449032, -0.26375386, 69.711525],
+    [0.00025703677, -0.2759604, 72.761024],
+    [-2.893558e-7, -0.018160082, 8.660457],
+    [5.845822e-5, -0.07597393, 22.803303],
+    [8.810807e-5, -0.10520621, 29.968927],
+    [0.00010528854, -0.12210588, 34.10469],
+    [0.00011734176, -0.1339303, 36.992504],
+    [0.00012703652, -0.14342892, 39.310303],
+    [0.00013553983, -0.15176278, 41.34486],
+    [0.00014344012, -0.15951796, 43.241077],
+    [0.00015108578, -0.16704176, 45.084938],
+    [0.00015871284, -0.17457001, 46.934895],
+    [0.0001665022, -0.1822838, 48.835964],
+    [0.0001746083, -0.1903383, 50.82682],
+    [0.00018317642, -0.19887996, 52.944088],
+    [0.00019235512, -0.20805903, 55.22546],
+    [0.00020230611, -0.21803963, 57.712204],
+    [0.00021321514, -0.22901061, 60.451923],
+    [-5.996943e-7, -0.014593695, 7.101258],
+    [3.9838415e-5, -0.05448716, 16.879856],
+    [6.400832e-5, -0.07837379, 22.746279],
+    [7.964785e-5, -0.093819804, 26.538887],
+    [9.105459e-5, -0.10507027, 29.298958],
+    [0.0001003291, -0.11421106, 31.540627],
+    [0.00010847772, -0.12224403, 33.511425],
+    [0.000116035655, -0.12970288, 35.343414],
+    [0.00012332527, -0.13690953, 37.116364],
+    [0.00013056606, -0.14408354, 38.88477],
+    [0.0001379255, -0.15139289, 40.69042],
+    [0.00014554602, -0.15898071, 42.569008],
+    [0.00015356034, -0.16698086, 44.554005],
+    [0.00016210304, -0.17552938, 46.679504],
+    [0.00017131935, -0.18477339, 48.982475],
+    [0.00018137414, -0.19488023, 51.505016],
+    [-6.548835e-6, -0.0063503827, 4.5861363],
+    [2.4679515e-5, -0.037212614, 12.161442],
+    [4.537387e-5, -0.057699002, 17.199247],
+    [5.983262e-5, -0.07201414, 20.72104],
+    [7.07243e-5, -0.082791, 23.371761],
+    [7.96514e-5, -0.09162016, 25.54319],
+    [8.749319e-5, -0.099377155, 27.451607],
+    [9.47473e-5, -0.10655849, 29.219906],
+    [0.00010172114, -0.11347138, 30.924236],
+    [0.00010862512, -0.12032648, 32.616913],
+    [0.00011561962, -0.1272847, 34.337967],
+    [0.00012283995, -0.13448209, 36.12133],
+    [0.00013041183, -0.14204533, 37.998657],
+    [0.00013846139, -0.15010184, 40.001858],
+    [0.00014712424, -0.15878882, 42.165348],
+    [0.00015655348, -0.16826136, 44.528095],
+    [-1.4204009e-5, 0.003076874, 1.8885244],
+    [1.1605776e-5, -0.022465311, 8.164373],
+    [2.9921026e-5, -0.040619425, 12.632694],
+    [4.34497e-5, -0.054035783, 15.937419],
+    [5.3943786e-5, -0.06444094, 18.50083],
+    [6.260944e-5, -0.073031045, 20.617208],
+    [7.02119e-5, -0.08056791, 22.47467],
+    [7.721913e-5, -0.08751858, 24.188795],
+    [8.393017e-5, -0.09418202, 25.833704],
+    [9.055203e-5, -0.100765556, 27.46088],
+    [9.724221e-5, -0.10742723, 29.10965],
+    [0.00010413323, -0.11430028, 30.813217],
+    [0.000111347006, -0.121507496, 32.602253],
+    [0.00011900558, -0.12917203, 34.50757],
+    [0.00012723927, -0.13742553, 36.56215],
+    [0.00013619507, -0.14641671, 38.80329],
+    [-2.2502694e-5, 0.012827863, -0.8209425],
+    [-1.9244824e-7, -0.009275526, 4.6141744],
+    [1.6421502e-5, -0.025759494, 8.674059],
+    [2.9205403e-5, -0.038451906, 11.802931],
+    [3.9391125e-5, -0.04856579, 14.297135],
+    [4.7875328e-5, -0.0569892, 16.37482],
+    [5.5307533e-5, -0.064368404, 18.19543],
+    [6.212679e-5, -0.07114153, 19.867357],
+    [6.862765e-5, -0.07760314, 21.46362],
+    [7.501776e-5, -0.08396123, 23.035864],
+    [8.145559e-5, -0.09037482, 24.623644],
+    [8.807374e-5, -0.096977174, 26.260166],
+    [9.499362e-5, -0.10389051, 27.975931],
+    [0.00010233595, -0.111236535, 29.801355],
+    [0.000110229324, -0.11914499, 31.768908],
+    [0.00011881849, -0.12776206, 33.9152],
+    [-3.1156556e-5, 0.02272476, -3.5214295],
+    [-1.1240203e-5, 0.0029749586, 1.337828],
+    [4.127e-6, -0.012283978, 5.097743],
+    [1.631739e-5, -0.02439729, 8.085367],
+    [2.6257967e-5, -0.034277603, 10.5235195],
+    [3.462737e-5, -0.042596217, 12.576849],
+    [4.1955926e-5, -0.049880233, 14.37523],
+    [4.8645794e-5, -0.05653082, 16.017843],
+    [5.4987177e-5, -0.06283816, 17.5766],
+    [6.1191364e-5, -0.069013916, 19.104044],
+    [6.742101e-5, -0.0752213, 20.64076],
+    [7.3811694e-5, -0.08159653, 22.22067],
+    [8.0487e-5, -0.08826394, 23.874784],
+    [8.756901e-5, -0.09534647, 25.633797],
+    [9.518741e-5, -0.10297484, 27.530376],
+    [0.000103487866, -0.11129593, 29.60126],
+    [-4.0137416e-5, 0.03280709, -6.236907],
+    [-2.1913425e-5, 0.014721418, -1.7850691],
+    [-7.4685213e-6, 0.0003691435, 1.7525735],
+    [4.2598954e-6, -0.011292401, 4.629649],
+    [1.4006335e-5, -0.020986494, 7.0227084],
+    [2.2309598e-5, -0.029245716, 9.062237],
+    [2.9595236e-5, -0.03649249, 10.852151],
+    [3.6214366e-5, -0.043076757, 12.478872],
+    [4.244692e-5, -0.04927835, 14.0117035],
+    [4.8508646e-5, -0.055313364, 15.504271],
+    [5.456911e-5, -0.06135193, 16.99886],
+    [6.0769922e-5, -0.06753627, 18.530872],
+    [6.723941e-5, -0.073995434, 20.132465],
+    [7.4103584e-5, -0.080856204, 21.835262],
+    [8.14958e-5, -0.08825283, 23.672777],
+    [8.9565685e-5, -0.096336, 25.682644],
+    [-4.952449e-5, 0.043199465, -9.007686],
+    [-3.2511114e-5, 0.026303748, -4.8472958],
+    [-1.8742538e-5, 0.012616239, -1.4728044],
+    [-7.359271e-6, 0.0012925272, 1.3213543],
+    [2.244369e-6, -0.008264121, 3.6808534],
+    [1.0516469e-5, -0.01649655, 5.7141314],
+    [1.7808168e-5, -0.023752982, 7.506742],
+    [2.4414283e-5, -0.030326886, 9.13105],
+    [3.059111e-5, -0.03647426, 10.650392],
+    [3.6554706e-5, -0.042411473, 12.118425],
+    [4.2482945e-5, -0.048316907, 13.57947],
+    [4.8526534e-5, -0.05434192, 15.071174],
+    [5.482138e-5, -0.060623065, 16.627544],
+    [6.1500294e-5, -0.06729389, 18.28186],
+    [6.870289e-5, -0.07449479, 20.069126],
+    [7.658597e-5, -0.08238346, 22.028673],
+    [-5.9457787e-5, 0.05407508, -11.883473],
+    [-4.329881e-5, 0.038017944, -7.928463],
+    [-3.0008354e-5, 0.024799764, -4.669273],
+    [-1.8863471e-5, 0.013709167, -1.9325281],
+    [-9.347033e-6, 0.0042362106, 0.40631396],
+    [-1.073036e-6, -0.00400068, 2.4406707],
+    [6.260597e-6, -0.011300956, 4.2440567],
+    [1.290461e-5, -0.01791396, 5.877873],
+    [1.908158e-5, -0.024061756, 7.39698],
+    [2.4995868e-5, -0.02994883, 8.852041],
+    [3.0830863e-5, -0.035759058, 10.288707],
+    [3.674815e-5, -0.041654643, 11.747301],
+    [4.2894768e-5, -0.047783267, 13.264571],
+    [4.941348e-5, -0.054288376, 14.876232],
+    [5.64536e-5, -0.06131997, 16.619638],
+    [6.41822e-5, -0.06904585, 18.53659],
+    [-7.012867e-5, 0.06565179, -14.923559],
+    [-5.4540127e-5, 0.050152335, -11.104981],
+    [-4.1556865e-5, 0.037234373, -7.9195905],
+    [-3.0548315e-5, 0.026276257, -5.2156544],
+    [-2.1058433e-5, 0.016827714, -2.8831098],
+    [-1.2744243e-5, 0.008549462, -0.83887184],
+    [-5.33688e-6, 0.0011749566, 0.9824743],
+    [1.384935e-6, -0.0055157305, 2.6350374],
+    [7.6149986e-6, -0.011715748, 4.16647],
+    [1.3535005e-5, -0.017606704, 5.6217046],
+    [1.9322255e-5, -0.023366231, 7.044796],
+    [2.514695e-5, -0.0291651, 8.478165],
+    [3.1170064e-5, -0.035164937, 9.962],
+    [3.7548398e-5, -0.041523173, 11.535459],
+    [4.444517e-5, -0.048403643, 13.239303],
+    [5.204185e-5, -0.055988405, 15.118818],
+    [-8.178868e-5, 0.07820452, -18.200611],
+    [-6.652559e-5, 0.06301953, -14.458618],
+    [-5.3690692e-5, 0.050244, -11.30819],
+    [-4.2715164e-5, 0.039315946, -8.61192],
+    [-3.318383e-5, 0.029824764, -6.2693143],
+    [-2.4782834e-5, 0.02145963, -4.2042027],
+    [-1.7265838e-5, 0.013976189, -2.3566067],
+    [-1.0431003e-5, 0.00717384, -0.67719334],
+    [-4.103714e-6, 0.000878644, 0.8769113],
+    [1.8771932e-6, -0.005070312, 2.3454642],
+    [7.671697e-6, -0.010833129, 3.7681172],
+    [1.3446662e-5, -0.016577108, 5.1863856],
+    [1.937445e-5, -0.022475203, 6.6432343],
+    [2.5630065e-5, -0.02870304, 8.182311],
+    [3.239583e-5, -0.035443485, 9.849062],
+    [3.987377e-5, -0.042899013, 11.693791],
+    [-9.477376e-5, 0.09209217, -21.807863],
+    [-7.960526e-5, 0.07699156, -18.085688],
+    [-6.675898e-5, 0.06419906, -14.930871],
+    [-5.5704568e-5, 0.05318955, -12.214779],
+    [-4.6052533e-5, 0.04357705, -9.842804],
+    [-3.7506972e-5, 0.035068072, -7.7429414],
+    [-2.9835579e-5, 0.027431937, -5.858532],
+    [-2.2848695e-5, 0.020480052, -4.1431932],
+    [-1.6383905e-5, 0.014050647, -2.5570693],
+    [-1.0293661e-5, 0.007996488, -1.0638164],
+    [-4.4339345e-6, 0.002173585, 0.37217796],
+    [1.3473601e-6, -0.0035703692, 1.7886542],
+    [7.2200246e-6, -0.009405617, 3.2278543],
+    [1.3374172e-5, -0.01552283, 4.737119],
+    [2.0017322e-5, -0.022130016, 6.3680906],
+    [2.7380309e-5, -0.029458335, 8.178132],
+    [-0.00010954757, 0.10780379, -25.871056],
+    [-9.4233896e-5, 0.092547536, -22.109322],
+    [-8.120169e-5, 0.07956322, -18.906837],
+    [-6.993956e-5, 0.06834328, -16.139032],
+    [-6.0070077e-5, 0.05851287, -13.713869],
+    [-5.1306164e-5, 0.049786747, -11.5612755],
+    [-4.3422122e-5, 0.041940466, -9.626064],
+    [-3.6234465e-5, 0.03479139, -7.863292],
+    [-2.9587485e-5, 0.028184365, -6.2347336],
+    [-2.3341649e-5, 0.021980178, -4.706041],
+    [-1.7363527e-5, 0.016045518, -3.2442772],
+    [-1.1514712e-5, 0.010241926, -1.8151807],
+    [-5.6390877e-6, 0.0044130664, -0.38001874],
+    [4.5070666e-7, -0.0016287791, 1.1077542],
+    [6.9828116e-6, -0.008112151, 2.704817],
+    [1.4226024e-5, -0.015306016, 4.477836],
+    [-0.00012677873, 0.12603925, -30.569033],
+    [-0.000111044195, 0.11035029, -26.699028],
+    [-9.761832e-5, 0.096965306, -23.397064],
+    [-8.598935e-5, 0.085375056, -20.537918],
+    [-7.577895e-5, 0.07520297, -18.02896],
+    [-6.669932e-5, 0.06616249, -15.79968],
+    [-5.85245e-5, 0.05802851, -13.794665],
+    [-5.107154e-5, 0.050618693, -11.969004],
+    [-4.418641e-5, 0.04377942, -10.284831],
+    [-3.7732898e-5, 0.03737475, -8.7086115],
+    [-3.158327e-5, 0.0312771, -7.208844],
+    [-2.5608068e-5, 0.025357068, -5.753546],
+    [-1.9663918e-5, 0.019471252, -4.3072414],
+    [-1.3577626e-5, 0.01344636, -2.8270342],
+    [-7.124139e-6, 0.0070572128, -1.2571653],
+    [-5.172835e-9, 5.582047e-6, 0.47618997],
+    [-0.00015349427, 0.11046544, -17.715887],
+    [0.00090538705, -0.9241554, 234.20224],
+    [0.00022886592, -0.26267567, 73.035095],
+    [0.0002478559, -0.280709, 77.31571],
+    [0.00026083097, -0.29290214, 80.18211],
+    [0.0002716102, -0.3030408, 82.56846],
+    [0.0002816422, -0.31255516, 84.82641],
+    [0.0002916719, -0.3221785, 87.13583],
+    [0.00030217055, -0.33237505, 89.610695],
+    [0.00031349983, -0.34350315, 92.339424],
+    [0.00032599337, -0.35589662, 95.40518],
+    [0.0003400042, -0.36991253, 98.897705],
+    [0.00035594363, -0.3859704, 102.92327],
+    [0.0003743199, -0.404592, 107.61475],
+    [0.00039579262, -0.42645755, 113.14602],
+    [0.0004212514, -0.45248705, 119.75283],
+    [0.00090538705, -0.92768717, 235.97928],
+    [0.00012611723, -0.15183303, 43.734856],
+    [0.00015749369, -0.18271716, 51.308514],
+    [0.00017488851, -0.199727, 55.45719],
+    [0.0001877801, -0.21229766, 58.516464],
+    [0.00019878765, -0.22304054, 61.13373],
+    [0.0002090023, -0.23304376, 63.578835],
+    [0.00021901727, -0.2428993, 65.99871],
+    [0.0002292239, -0.25299868, 68.49076],
+    [0.00023992665, -0.26364776, 71.13138],
+    [0.00025140063, -0.27512443, 73.9903],
+    [0.00026392637, -0.2877137, 77.13946],
+    [0.00027781824, -0.30173653, 80.66018],
+    [0.00029345142, -0.31757742, 84.65023],
+    [0.00031129693, -0.33572042, 89.23298],
+    [0.00033196897, -0.35679796, 94.5699],
+    [3.985211e-5, -0.061167557, 20.269802],
+    [9.237769e-5, -0.11319218, 33.088963],
+    [0.00011754912, -0.13811572, 39.231895],
+    [0.0001332825, -0.1536553, 43.05527],
+    [0.00014536631, -0.1655778, 45.98706],
+    [0.00015581251, -0.17589286, 48.526108],
+    [0.0001655235, -0.18550314, 50.89678],
+    [0.00017500391, -0.19491419, 53.224922],
+    [0.00018459208, -0.20446587, 55.595375],
+    [0.00019455297, -0.21442524, 58.075],
+    [0.00020512487, -0.22503352, 60.72445],
+    [0.00021654801, -0.23653485, 63.60532],
+    [0.00022908732, -0.24919942, 66.78602],
+    [0.0002430545, -0.263346, 70.34742],
+    [0.00025883497, -0.2793694, 74.389915],
+    [0.0002769237, -0.2977776, 79.042786],
+    [3.1148476e-5, -0.049039446, 16.537743],
+    [6.859658e-5, -0.086221606, 25.717714],
+    [9.014615e-5, -0.10763275, 31.009874],
+    [0.00010463907, -0.12201978, 34.56477],
+    [0.00011600422, -0.13329792, 37.35167],
+    [0.00012588524, -0.14311029, 39.7786],
+    [0.00013507472, -0.15225081, 42.043053],
+    [0.00014402722, -0.16117564, 44.258728],
+    [0.00015305016, -0.17019409, 46.502907],
+    [0.00016238501, -0.1795498, 48.83664],
+    [0.00017224858, -0.18946227, 51.315113],
+    [0.00018285825, -0.20015238, 53.99402],
+    [0.00019445227, -0.21186271, 56.93468],
+    [0.00020730906, -0.22487736, 60.20906],
+    [0.0002217706, -0.23954582, 63.90579],
+    [0.0002382733, -0.2563145, 68.13821],
+    [2.0004501e-5, -0.035456672, 12.659282],
+    [4.9824866e-5, -0.06511902, 19.992765],
+    [6.902672e-5, -0.084240936, 24.727432],
+    [8.263449e-5, -0.097790726, 28.083677],
+    [9.345239e-5, -0.10856223, 30.752724],
+    [0.00010287363, -0.11794879, 33.080536],
+    [0.000111622794, -0.12667674, 35.24787],
+    [0.00012012736, -0.13517547, 37.361794],
+    [0.00012867866, -0.14373824, 39.495605],
+    [0.00013750541, -0.15259603, 41.707184],
+    [0.00014681274, -0.16195641, 44.04869],
+    [0.00015680578, -0.17202757, 46.572575],
+    [0.0001677087, -0.18303749, 49.336376],
+    [0.00017978276, -0.19525222, 52.40737],
+    [0.00019334863, -0.2089987, 55.868294],
+    [0.00020881488, -0.22469372, 59.824715],
+    [8.499364e-6, -0.022056198, 8.945374],
+    [3.382232e-5, -0.047280055, 15.187625],
+    [5.1404913e-5, -0.06481732, 19.534876],
+    [6.439677e-5, -0.07778011, 22.750608],
+    [7.483167e-5, -0.088193186, 25.335186],
+    [8.390904e-5, -0.09725612, 27.586348],
+    [9.230974e-5, -0.105651654, 29.674004],
+    [0.000100449295, -0.113797426, 31.702295],
+    [0.00010861358, -0.12198135, 33.743187],
+    [0.00011702686, -0.1304298, 35.853394],
+    [0.00012588954, -0.13934559, 38.083836],
+    [0.00013540163, -0.14893146, 40.48555],
+    [0.0001457815, -0.15940917, 43.114426],
+    [0.00015728375, -0.17103755, 46.03579],
+    [0.00017022139, -0.184135, 49.330067],
+    [0.00018499415, -0.19910836, 53.100082],
+    [-3.0759104e-6, -0.008916894, 5.3698335],
+    [1.936624e-5, -0.0312954, 10.911667],
+    [3.579506e-5, -0.047700595, 14.981222],
+    [4.836894e-5, -0.06026391, 18.100782],
+    [5.856878e-5, -0.07045781, 20.63361],
+    [6.7415174e-5, -0.07930253, 22.832712],
+    [7.5555974e-5, -0.087447815, 24.859732],
+    [8.3405816e-5, -0.09531049, 26.818604],
+    [9.125388e-5, -0.10318187, 28.782122],
+    [9.932685e-5, -0.11129073, 30.807577],
+    [0.00010782634, -0.11984099, 32.946136],
+    [0.00011695287, -0.12903574, 35.24886],
+    [0.00012692531, -0.13909693, 37.771614],
+    [0.00013799935, -0.15028404, 40.579773],
+    [0.00015049042, -0.16291732, 43.75409],
+    [0.00016480358, -0.17740811, 47.39832],
+    [-1.4841149e-5, 0.004200263, 1.8477823],
+    [5.6786457e-6, -0.016278403, 6.9212594],
+    [2.1286593e-5, -0.031876717, 10.792227],
+    [3.3584536e-5, -0.044176113, 13.847853],
+    [4.3678785e-5, -0.054275025, 16.358599],
+    [5.2406012e-5, -0.063008904, 18.53133],
+    [6.0374772e-5, -0.07098792, 20.517733],
+    [6.8005065e-5, -0.07863429, 22.42305],
+    [7.5597134e-5, -0.08625048, 24.322836],
+    [8.3386956e-5, -0.09407461, 26.27669],
+    [9.158294e-5, -0.10231733, 28.337427],
+    [0.00010039136, -0.11118739, 30.557463],
+    [0.000110037, -0.120912425, 32.994022],
+    [0.00012078345, -0.13175951, 35.714294],
+    [0.00013295718, -0.14405948, 38.801537],
+    [0.00014698117, -0.15824097, 42.36366],
+    [-2.7043005e-5, 0.017617129, -1.7165353],
+    [-7.81287e-6, -0.0015864344, 3.0419314],
+    [7.2367734e-6, -0.016635388, 6.77711],
+    [1.9369907e-5, -0.028777536, 9.794253],
+    [2.9462055e-5, -0.03888127, 12.306827],
+    [3.8181992e-5, -0.04761328, 14.47956],
+    [4.60726e-5, -0.05551738, 16.447454],
+    [5.3554944e-5, -0.063016735, 18.315914],
+    [6.094658e-5, -0.070431195, 20.164795],
+    [6.8499714e-5, -0.07801524, 22.057743],
+    [7.6435914e-5, -0.08599265, 24.050825],
+    [8.4972795e-5, -0.09458347, 26.199228],
+    [9.434676e-5, -0.10402681, 28.562977],
+    [0.00010483569, -0.11460379, 31.212683],
+    [0.000116785974, -0.1266648, 34.236374],
+    [0.00013065078, -0.1406682, 37.749214],
+    [-3.9998493e-5, 0.031703353, -5.425635],
+    [-2.1597369e-5, 0.0133196525, -0.8704894],
+    [-6.8828704e-6, -0.0013994657, 2.782482],
+    [5.19212e-6, -0.013487738, 5.7859697],
+    [1.5360998e-5, -0.023672119, 8.318434],
+    [2.4175737e-5, -0.03250206, 10.515349],
+    [3.2092954e-5, -0.040434174, 12.489828],
+    [3.950998e-5, -0.047867425, 14.341107],
+    [4.6759993e-5, -0.055137277, 16.15287],
+    [5.4118336e-5, -0.06252143, 17.994518],
+    [6.182732e-5, -0.070264526, 19.927292],
+    [7.0122645e-5, -0.078604676, 22.010841],
+    [7.925899e-5, -0.087799184, 24.309668],
+    [8.953577e-5, -0.09815052, 26.899624],
+    [0.000101328966, -0.11003837, 29.875927],
+    [0.00011513617, -0.12396505, 33.36455],
+    [-5.4104905e-5, 0.046897262, -9.39625],
+    [-3.6161066e-5, 0.028965743, -4.9543147],
+    [-2.1576458e-5, 0.014374134, -1.3341881],
+    [-9.44466e-6, 0.0022274964, 1.6827263],
+    [8.763422e-7, -0.00811029, 4.2524004],
+    [9.867626e-6, -0.017117606, 6.492579],
+    [1.7917866e-5, -0.025182454, 8.499149],
+    [2.537204e-5, -0.032650627, 10.357933],
+    [3.2555112e-5, -0.039849, 12.150346],
+    [3.9765277e-5, -0.04707816, 13.951441],
+    [4.7273912e-5, -0.05461196, 15.829692],
+    [5.5344168e-5, -0.06271604, 17.851576],
+    [6.425739e-5, -0.07167435, 20.088175],
+    [7.434387e-5, -0.0818201, 22.622917],
+    [8.6020955e-5, -0.093574055, 25.561136],
+    [9.9848316e-5, -0.107500136, 29.043978],
+    [-6.988492e-5, 0.06375777, -13.7735195],
+    [-5.2060503e-5, 0.04594381, -9.362883],
+    [-3.7398324e-5, 0.03127488, -5.7256875],
+    [-2.5078369e-5, 0.01894105, -2.6642118],
+    [-1.4515536e-5, 0.008362705, -0.036495786],
+    [-5.271347e-6, -0.00089586544, 2.264534],
+    [3.0028011e-6, -0.009182193, 4.3245845],
+    [1.0607109e-5, -0.016796611, 6.218001],
+    [1.782744e-5, -0.02402613, 8.016099],
+    [2.4957926e-5, -0.031166913, 9.792703],
+    [3.2298423e-5, -0.038521346, 11.623327],
+    [4.0150757e-5, -0.046393912, 13.584081],
+    [4.883569e-5, -0.055108055, 15.755821],
+    [5.8727335e-5, -0.06504072, 18.232775],
+    [7.030027e-5, -0.07666963, 21.134317],
+    [8.419977e-5, -0.09064376, 24.622553],
+    [-8.807585e-5, 0.08305932, -18.755487],
+    [-7.001674e-5, 0.06501124, -14.290049],
+    [-5.5042572e-5, 0.050033055, -10.579295],
+    [-4.2373587e-5, 0.037353765, -7.4349856],
+    [-3.1452873e-5, 0.026421564, -4.7220726],
+    [-2.1864294e-5, 0.01682312, -2.3391254],
+    [-1.3279907e-5, 0.008231822, -0.20574747],
+    [-5.4244547e-6, 0.00037273255, 1.7459542],
+    [1.9547276e-6, -0.0070073097, 3.578712],
+    [9.11607e-6, -0.014167983, 5.3570814],
+    [1.635156e-5, -0.021403339, 7.154354],
+    [2.399748e-5, -0.029052349, 9.055133],
+    [3.2430733e-5, -0.03749479, 11.154232],
+    [4.2089843e-5, -0.04717204, 13.561805],
+    [5.3530166e-5, -0.058642324, 16.417082],
+    [6.751664e-5, -0.07267357, 19.911476],
+    [-0.000109801935, 0.10597121, -24.638584],
+    [-9.107302e-5, 0.08725488, -20.011776],
+    [-7.548495e-5, 0.07166747, -16.154232],
+    [-6.225132e-5, 0.058429796, -12.87549],
+    [-5.0813396e-5, 0.046987627, -10.039885],
+    [-4.075637e-5, 0.036928825, -7.546347],
+    [-3.1757478e-5, 0.027932096, -5.3159165],
+    [-2.355088e-5, 0.019732242, -3.2832477],
+    [-1.5900943e-5, 0.0120936185, -1.3901113],
+    [-8.576939e-6, 0.0047849035, 0.42082253],
+    [-1.3244583e-6, -0.002449507, 2.2130709],
+    [6.1730243e-6, -0.009928116, 4.0659385],
+    [1.433108e-5, -0.018069234, 6.0836763],
+    [2.3679682e-5, -0.027405864, 8.39909],
+    [3.4896155e-5, -0.03861802, 11.181471],
+    [4.891887e-5, -0.05264609, 14.664697],
+    [-0.00013693367, 0.1344289, -31.911213],
+    [-0.00011691024, 0.11441771, -26.96862],
+    [-0.000100262994, 0.09777605, -22.855097],
+    [-8.614056e-5, 0.08365789, -19.36341],
+    [-7.394418e-5, 0.07146793, -16.347649],
+    [-6.323455e-5, 0.060769055, -13.700574],
+    [-5.3674907e-5, 0.051225904, -11.339808],
+    [-4.4993718e-5, 0.0425677, -9.198733],
+    [-3.6957656e-5, 0.034561425, -7.219913],
+    [-2.9348192e-5, 0.026988491, -5.3493404],
+    [-2.1937107e-5, 0.019620232, -3.530399],
+    [-1.4450967e-5, 0.012182277, -1.6950113],
+    [-6.5156323e-6, 0.0042989138, 0.25016764],
+    [2.4323153e-6, -0.004595649, 2.4457862],
+    [1.3236666e-5, -0.0153477425, 5.102128],
+    [2.7113772e-5, -0.029174618, 8.521245],
+    [-0.00017292402, 0.17199576, -41.470413],
+    [-0.00015056205, 0.14963776, -35.9519],
+    [-0.00013210594, 0.13118838, -31.39658],
+    [-0.00011654534, 0.11564, -27.556997],
+    [-0.000103181876, 0.10229607, -24.262062],
+    [-9.151189e-5, 0.090654224, -21.388346],
+    [-8.1156635e-5, 0.08033662, -18.842972],
+    [-7.18179e-5, 0.07104567, -16.55275],
+    [-6.324746e-5, 0.06253357, -14.456694],
+    [-5.522282e-5, 0.05457816, -12.50006],
+    [-4.7524387e-5, 0.04696002, -10.628727],
+    [-3.9905808e-5, 0.039432857, -8.78186],
+    [-3.204668e-5, 0.031676177, -6.880198],
+    [-2.3460156e-5, 0.023202792, -4.803147],
+    [-1.3299979e-5, 0.01316603, -2.3410625],
+    [-1.0453717e-8, 1.1288205e-5, 0.8907874],
+    [-1.5241875e-5, -0.0322057, 19.09062],
+    [-0.00090538705, 0.91319185, -228.93967],
+    [0.00028934007, -0.3308639, 92.13613],
+    [0.0003072376, -0.34768885, 96.093735],
+    [0.00032074476, -0.36034444, 99.06241],
+    [0.00033287832, -0.3718171, 101.77913],
+    [0.00034493813, -0.38338757, 104.5586],
+    [0.00035766268, -0.39578274, 107.57939],
+    [0.00037159096, -0.4095369, 110.97355],
+    [0.0003872219, -0.42515087, 114.86638],
+    [0.00040510157, -0.44318002, 119.39844],
+    [0.00042589055, -0.4643031, 124.743004],
+    [0.00045044365, -0.48940435, 131.1272],
+    [0.00047992586, -0.5196942, 138.86305],
+    [0.00051600387, -0.55690926, 148.39935],
+    [0.000561186, -0.60366786, 160.41379],
+    [-0.00090538705, 0.9171147, -230.9036],
+    [0.0001746206, -0.20584196, 58.744686],
+    [0.00020109772, -0.2319107, 65.14865],
+    [0.00021810894, -0.24858451, 69.23025],
+    [0.00023186553, -0.26207468, 72.535385],
+    [0.0002443555, -0.27436987, 75.55921],
+    [0.00025652788, -0.28642002, 78.53845],
+    [0.00026896605, -0.29881063, 81.61938],
+    [0.00028211018, -0.3119858, 84.91347],
+    [0.00029635947, -0.3263508, 88.52312],
+    [0.00031213267, -0.34233376, 92.55711],
+    [0.00032991552, -0.360434, 97.142975],
+    [0.00035031262, -0.3812754, 102.44058],
+    [0.00037411653, -0.40567803, 108.660614],
+    [0.0004024133, -0.43476784, 116.09279],
+    [0.00043676025, -0.47016132, 125.15359],
+    [8.789279e-5, -0.11356245, 34.577763],
+    [0.00013230291, -0.15777348, 45.537918],
+    [0.00015440599, -0.17973627, 50.977333],
+    [0.00016984642, -0.195061, 54.77094],
+    [0.00018270085, -0.20783235, 57.936703],
+    [0.00019448047, -0.21956696, 60.8532],
+    [0.00020594744, -0.23103113, 63.712074],
+    [0.00021758676, -0.24271402, 66.636],
+    [0.00022977185, -0.25499383, 69.72025],
+    [0.00024284238, -0.2682163, 73.05233],
+    [0.00025715094, -0.2827421, 76.72388],
+    [0.00027310144, -0.29898578, 80.84064],
+    [0.00029118962, -0.31745762, 85.53308],
+    [0.0003120556, -0.33881775, 90.970314],
+    [0.00033656205, -0.36395696, 97.38077],
+    [0.0003659194, -0.39412642, 105.08564],
+    [7.0552196e-5, -0.09244535, 28.522528],
+    [0.0001038674, -0.1257167, 36.792717],
+    [0.00012330672, -0.1451296, 41.621277],
+    [0.00013752916, -0.15933284, 45.156075],
+    [0.00014954915, -0.17135029, 48.151157],
+    [0.00016062077, -0.18244271, 50.9216],
+    [0.0001714042, -0.19327535, 53.63401],
+    [0.00018232825, -0.20428146, 56.397224],
+    [0.00019372755, -0.21580054, 59.29683],
+    [0.00020590796, -0.22814405, 62.411663],
+    [0.00021918707, -0.2416366, 65.82417],
+    [0.0002339273, -0.2566496, 69.62894],
+    [0.000250572, -0.2736384, 73.94214],
+    [0.00026969035, -0.29318824, 78.91333],
+    [0.00029204602, -0.31608522, 84.74352],
+    [0.00031870158, -0.34342363, 91.71281],
+    [5.43751e-5, -0.07360734, 23.284088],
+    [8.201846e-5, -0.101281054, 30.176315],
+    [9.97644e-5, -0.119060464, 34.61042],
+    [0.00011312191, -0.13245004, 37.953175],
+    [0.00012449289, -0.14386101, 40.805996],
+    [0.0001349838, -0.15440702, 43.44736],
+    [0.00014519968, -0.16469847, 46.030266],
+    [0.00015553954, -0.17513898, 48.656162],
+    [0.00016631704, -0.18604691, 51.40533],
+    [0.00017782042, -0.19771579, 54.352043],
+    [0.0001903495, -0.2104518, 57.573986],
+    [0.00020424702, -0.22460562, 61.160336],
+    [0.00021993258, -0.24060732, 65.22062],
+    [0.00023794614, -0.25901073, 69.89602],
+    [0.00025901213, -0.28055945, 75.37624],
+    [0.00028414052, -0.3062905, 81.92601],
+    [3.932618e-5, -0.056512244, 18.61808],
+    [6.363719e-5, -0.08089563, 24.699554],
+    [8.030708e-5, -0.09763538, 28.881886],
+    [9.309238e-5, -0.11048406, 32.09613],
+    [0.00010399456, -0.1214517, 34.843586],
+    [0.00011403528, -0.1315674, 37.381668],
+    [0.0001237957, -0.14141794, 39.857502],
+    [0.00013366339, -0.15139565, 42.36973],
+    [0.00014394414, -0.161811, 44.99663],
+    [0.00015491941, -0.17295048, 47.810654],
+    [0.00016688296, -0.18511376, 50.88776],
+    [0.00018017182, -0.19864525, 54.315395],
+    [0.00019520028, -0.21396871, 58.201286],
+    [0.0002125047, -0.23163314, 62.685112],
+    [0.00023280852, -0.25237918, 67.95541],
+    [0.00025712792, -0.27724755, 74.277176],
+    [2.4839512e-5, -0.04034951, 14.269001],
+    [4.706608e-5, -0.06267505, 19.842981],
+    [6.3084524e-5, -0.07878795, 23.873636],
+    [7.554981e-5, -0.091338105, 27.0175],
+    [8.6151325e-5, -0.10202198, 29.69743],
+    [9.586293e-5, -0.111821, 32.158905],
+    [0.000105264946, -0.12132147, 34.54895],
+    [0.00011474916, -0.13092016, 36.967323],
+    [0.00012462438, -0.14093056, 39.493046],
+    [0.00013517459, -0.15164165, 42.199135],
+    [0.0001466964, -0.16335572, 45.16214],
+    [0.0001595318, -0.17642175, 48.470535],
+    [0.0001741038, -0.19127166, 52.233948],
+    [0.00019096454, -0.20846942, 56.59558],
+    [0.00021086696, -0.22878423, 61.75083],
+    [0.00023488002, -0.25330812, 67.977165],
+    [1.0330763e-5, -0.024395116, 10.026431],
+    [3.1252497e-5, -0.04543416, 15.282874],
+    [4.692496e-5, -0.061218932, 19.234587],
+    [5.929897e-5, -0.07369412, 22.36247],
+    [6.976931e-5, -0.08425928, 25.014915],
+    [7.927108e-5, -0.09385682, 27.42754],
+    [8.840245e-5, -0.10309131, 29.751932],
+    [9.757437e-5, -0.112379044, 32.092735],
+    [0.00010710992, -0.12204798, 34.532578],
+    [0.00011730423, -0.13239829, 37.14725],
+    [0.00012846522, -0.14374347, 40.01604],
+    [0.00014094928, -0.15644675, 43.230927],
+    [0.00015520061, -0.17096096, 46.90663],
+    [0.00017180547, -0.18788378, 51.19465],
+    [0.0001915742, -0.2080413, 56.30441],
+    [0.0002156763, -0.2326255, 62.538128],
+    [-4.7869435e-6, -0.007971338, 5.70263],
+    [1.5391299e-5, -0.028280415, 10.778659],
+    [3.0956726e-5, -0.043971665, 14.708638],
+    [4.3443062e-5, -0.05657242, 17.869583],
+    [5.3967244e-5, -0.06720166, 20.53942],
+    [6.3393476e-5, -0.07672982, 22.935518],
+    [7.2344665e-5, -0.08578648, 25.21566],
+    [8.126651e-5, -0.09482318, 27.4933],
+    [9.050951e-5, -0.1041957, 29.858028],
+    [0.00010039025, -0.114225835, 32.391026],
+    [0.00011123713, -0.1252477, 35.176697],
+    [0.00012343035, -0.13764825, 38.312885],
+    [0.00013744833, -0.15191446, 41.922764],
+    [0.00015393055, -0.16869716, 46.171],
+    [0.00017377634, -0.18891126, 51.289085],
+    [0.00019831135, -0.21390499, 57.618137],
+    [-2.1168438e-5, 0.009644232, 1.1044008],
+    [-1.2715732e-6, -0.010393905, 6.1130633],
+    [1.4413192e-5, -0.02621502, 10.075892],
+    [2.7183598e-5, -0.03911063, 13.3112],
+    [3.7959635e-5, -0.050000787, 16.046923],
+    [4.7480396e-5, -0.059628673, 18.468218],
+    [5.6365105e-5, -0.06861974, 20.731646],
+    [6.5104054e-5, -0.077470675, 22.96191],
+    [7.409109e-5, -0.086581156, 25.259588],
+    [8.367809e-5, -0.096308716, 27.714788],
+    [9.422536e-5, -0.10701962, 30.419935],
+    [0.000106148975, -0.11913687, 33.481823],
+    [0.000119975906, -0.13319609, 37.035713],
+    [0.00013642167, -0.1499242, 41.26519],
+    [0.0001565132, -0.17036393, 46.433544],
+    [0.00018180613, -0.19609402, 52.939384],
+    [-3.9639566e-5, 0.029335845, -3.998855],
+    [-1.9586343e-5, 0.009133366, 1.0494221],
+    [-3.5289486e-6, -0.007068625, 5.1064954],
+    [9.696915e-6, -0.020428145, 8.457289],
+    [2.0908394e-5, -0.031761378, 11.303555],
+    [3.0733812e-5, -0.041698724, 13.801874],
+    [3.9720828e-5, -0.050792318, 16.090096],
+    [4.837584e-5, -0.059554745, 18.296732],
+    [5.7148274e-5, -0.068441965, 20.536407],
+    [6.644465e-5, -0.07786687, 22.913078],
+    [7.667405e-5, -0.08824503, 25.53148],
+    [8.830418e-5, -0.10005145, 28.511364],
+    [0.0001019296, -0.11388961, 32.00487],
+    [0.00011837041, -0.13059132, 36.22166],
+    [0.00013883367, -0.15137987, 41.470005],
+    [0.00016520935, -0.17816915, 48.232033],
+    [-6.136578e-5, 0.05232848, -9.92124],
+    [-4.067365e-5, 0.031481843, -4.715862],
+    [-2.392908e-5, 0.014586789, -0.48831475],
+    [-1.0027985e-5, 0.0005460468, 3.0307965],
+    [1.8029048e-6, -0.011411886, 6.031739],
+    [1.2142307e-5, -0.021866608, 8.658065],
+    [2.1465414e-5, -0.03129597, 11.028651],
+    [3.021812e-5, -0.0401503, 13.2560625],
+    [3.886321e-5, -0.048898596, 15.457977],
+    [4.787375e-5, -0.058021095, 17.755157],
+    [5.773406e-5, -0.068009585, 20.271387],
+    [6.898854e-5, -0.079416506, 23.145733],
+    [8.232669e-5, -0.09294086, 26.554113],
+    [9.871225e-5, -0.10955855, 30.742025],
+    [0.00011960659, -0.13074781, 36.081146],
+    [0.0001473943, -0.158918, 43.17697],
+    [-8.817218e-5, 0.080518305, -17.14337],
+    [-6.6219036e-5, 0.05840996, -11.630518],
+    [-4.8353995e-5, 0.04039215, -7.1280036],
+    [-3.346275e-5, 0.025358748, -3.3650508],
+    [-2.0767964e-5, 0.012535252, -0.1512119],
+    [-9.69704e-6, 0.001349164, 2.6548634],
+    [2.019734e-7, -0.00865296, 5.1655846],
+    [9.321696e-6, -0.017866435, 7.4793515],
+    [1.8055442e-5, -0.02668875, 9.695532],
+    [2.685997e-5, -0.035582833, 11.930238],
+    [3.6293044e-5, -0.045114674, 14.325696],
+    [4.7016158e-5, -0.055955213, 17.050554],
+    [5.986007e-5, -0.068945855, 20.316195],
+    [7.599292e-5, -0.08526781, 24.419134],
+    [9.724062e-5, -0.10676449, 29.821749],
+    [0.000126743, -0.13660097, 37.31733],
+    [-0.0001232529, 0.11719754, -26.492384],
+    [-9.908771e-5, 0.092885174, -20.443876],
+    [-7.943023e-5, 0.073079966, -15.505738],
+    [-6.305562e-5, 0.05656739, -11.381631],
+    [-4.912212e-5, 0.04251027, -7.866492],
+    [-3.7020083e-5, 0.030300329, -4.810739],
+    [-2.6282258e-5, 0.019469967, -2.0989492],
+    [-1.6523332e-5, 0.009632414, 0.36470893],
+    [-7.39107e-6, 0.00043303985, 2.6684325],
+    [1.4879267e-6, -0.008505173, 4.9063272],
+    [1.058739e-5, -0.01766165, 7.1984205],
+    [2.060222e-5, -0.027740337, 9.7212305],
+    [3.255974e-5, -0.03978119, 12.735544],
+    [4.7947407e-5, -0.055286802, 16.617746],
+    [6.914087e-5, -0.07665122, 21.966717],
+    [0.00010048913, -0.108247556, 29.875101],
+    [-0.0001731406, 0.16906305, -39.640007],
+    [-0.00014492184, 0.14071581, -32.61167],
+    [-0.00012222571, 0.11789074, -26.940807],
+    [-0.000103484075, 0.09902997, -22.247314],
+    [-8.7663924e-5, 0.08310637, -18.280249],
+    [-7.404356e-5, 0.06940125, -14.863658],
+    [-6.208921e-5, 0.05738206, -11.866801],
+    [-5.1380415e-5, 0.04662839, -9.18615],
+    [-4.1559117e-5, 0.03678149, -6.733087],
+    [-3.2283475e-5, 0.027498139, -4.422564],
+    [-2.3171777e-5, 0.01839431, -2.1591272],
+    [-1.3705173e-5, 0.008947105, 0.18752714],
+    [-3.0144283e-6, -0.0017204483, 2.8363128],
+    [1.0602358e-5, -0.01532516, 6.215977],
+    [3.0443298e-5, -0.035186917, 11.154655],
+    [6.2991e-5, -0.06780799, 19.270899],
+    [-0.00025499033, 0.25365677, -60.95225],
+    [-0.00021779547, 0.21633632, -51.73449],
+    [-0.00018890497, 0.18734162, -44.564075],
+    [-0.00016569551, 0.16405198, -38.799118],
+    [-0.00014655835, 0.14486115, -34.04633],
+    [-0.00013043763, 0.1287155, -30.047771],
+    [-0.000116598654, 0.11488215, -26.624014],
+    [-0.00010449926, 0.10282057, -23.642681],
+    [-9.3712646e-5, 0.0921056, -20.999636],
+    [-8.387091e-5, 0.08237112, -18.605156],
+    [-7.461358e-5, 0.07325871, -16.37134],
+    [-6.5521286e-5, 0.064351305, -14.19575],
+    [-5.598616e-5, 0.05504349, -11.929309],
+    [-4.4878267e-5, 0.04420717, -9.29347],
+    [-2.9374758e-5, 0.029018518, -5.589437],
+    [-2.932289e-8, 3.1652286e-5, 1.5209987],
+    [0.00012722728, -0.18171772, 58.230988],
+    [0.00090538705, -0.92511404, 234.85161],
+    [0.00035329204, -0.4032348, 112.424484],
+    [0.0003708521, -0.41954315, 116.21527],
+    [0.00038509315, -0.43283063, 119.32085],
+    [0.00039870577, -0.4457505, 122.39419],
+    [0.00041293618, -0.45952535, 125.73477],
+    [0.00042856223, -0.47492063, 129.53073],
+    [0.0004462449, -0.4925962, 133.94644],
+    [0.0004666908, -0.5132697, 139.16356],
+    [0.0004907551, -0.5378214, 145.40752],
+    [0.0005195554, -0.56741196, 152.97786],
+    [0.0005546444, -0.60366243, 162.29488],
+    [0.00059830036, -0.6489596, 173.97919],
+    [0.00065406464, -0.70702106, 188.99922],
+    [0.0007278263, -0.7840376, 208.96959],
+    [0.00090538705, -0.9294393, 237.11403],
+    [0.00022076786, -0.2578649, 73.32749],
+    [0.00024545105, -0.28217176, 79.30682],
+    [0.00026298736, -0.29941124, 83.54376],
+    [0.00027801507, -0.31423295, 87.199196],
+    [0.0002922504, -0.3283575, 90.70283],
+    [0.00030660213, -0.34269765, 94.28309],
+    [0.0003216885, -0.3578773, 98.0968],
+    [0.00033803526, -0.37443098, 102.2793],
+    [0.0003561779, -0.39290756, 106.97055],
+    [0.00037673383, -0.41394404, 112.334],
+    [0.00040047517, -0.43834075, 118.57585],
+    [0.00042842622, -0.46716297, 125.971375],
+    [0.0004620164, -0.5019004, 134.90625],
+    [0.0005033419, -0.54474086, 145.94766],
+    [0.0005556454, -0.59907246, 159.97482],
+    [0.00013316204, -0.1636441, 48.40362],
+    [0.00017081619, -0.20124832, 57.76484],
+    [0.00019180405, -0.22217536, 62.971485],
+    [0.00020780254, -0.23813887, 66.94827],
+    [0.00022187436, -0.25221878, 70.46604],
+    [0.00023528974, -0.26569456, 73.84558],
+    [0.00024876482, -0.27928966, 77.2688],
+    [0.00026280805, -0.29352012, 80.86604],
+    [0.0002778592, -0.30883482, 84.7513],
+    [0.0002943651, -0.32569242, 89.04169],
+    [0.00031283562, -0.34461856, 93.871956],
+    [0.00033389943, -0.36626354, 99.40935],
+    [0.00035837924, -0.3914802, 105.87362],
+    [0.0003874021, -0.42143863, 113.56671],
+    [0.00042258296, -0.45781714, 122.92217],
+    [0.00046634817, -0.50313944, 134.59244],
+    [0.000109194836, -0.13550498, 40.505177],
+    [0.00013871599, -0.1651205, 47.907196],
+    [0.00015734347, -0.18381594, 52.58572],
+    [0.00017207848, -0.19862558, 56.298817],
+    [0.00018522891, -0.21187481, 59.62929],
+    [0.00019783367, -0.22461271, 62.840652],
+    [0.00021050237, -0.23745686, 66.08852],
+    [0.00022368052, -0.25086033, 69.48752],
+    [0.00023776006, -0.26522386, 73.139534],
+    [0.00025314145, -0.28095838, 77.14946],
+    [0.0002702819, -0.29853478, 81.6379],
+    [0.00028974484, -0.31853473, 86.75416],
+    [0.00031226454, -0.34171733, 92.69338],
+    [0.00033884283, -0.3691195, 99.72246],
+    [0.0003709044, -0.40221685, 108.22147],
+    [0.0004105697, -0.44320682, 118.75676],
+    [8.9576366e-5, -0.113035284, 34.320526],
+    [0.000114861126, -0.1384877, 40.70085],
+    [0.00013196716, -0.15572955, 45.031662],
+    [0.00014578177, -0.1696769, 48.542374],
+    [0.00015820589, -0.18224788, 51.714146],
+    [0.00017015295, -0.19436646, 54.779354],
+    [0.0001821748, -0.20659259, 57.87924],
+    [0.00019468258, -0.21934502, 61.119858],
+    [0.00020804277, -0.23299848, 64.59648],
+    [0.00022263348, -0.24794096, 68.40812],
+    [0.00023888837, -0.26461855, 72.66886],
+    [0.00025734433, -0.28358445, 77.52051],
+    [0.00027870337, -0.30556288, 83.14886],
+    [0.00030392612, -0.33154556, 89.808586],
+    [0.00033438264, -0.36294746, 97.86326],
+    [0.0003721148, -0.40187818, 107.855194],
+    [7.245026e-5, -0.09378256, 29.104614],
+    [9.53142e-5, -0.11686047, 34.90288],
+    [0.000111471796, -0.13319738, 39.017326],
+    [0.00012465581, -0.14655098, 42.38792],
+    [0.00013654026, -0.15861243, 45.43916],
+    [0.00014797674, -0.17024428, 48.38813],
+    [0.0001594912, -0.18198082, 51.369724],
+    [0.00017147989, -0.19422592, 54.486256],
+    [0.00018429964, -0.20734456, 57.830513],
+    [0.00019832134, -0.22171709, 61.499493],
+    [0.0002139739, -0.2377842, 65.60579],
+    [0.00023179238, -0.25609642, 70.29028],
+    [0.00025248187, -0.27737972, 75.73891],
+    [0.0002770156, -0.30263656, 82.2086],
+    [0.00030679372, -0.33330947, 90.06919],
+    [0.00034392605, -0.37157255, 99.878334],
+    [5.6535715e-5, -0.07617829, 24.402674],
+    [7.802046e-5, -0.09791207, 29.872992],
+    [9.3671704e-5, -0.1137759, 33.87639],
+    [0.0001064779, -0.12677926, 37.165512],
+    [0.00011798908, -0.13848943, 40.133915],
+    [0.00012904083, -0.14975363, 42.994938],
+    [0.00014015843, -0.16110599, 45.883488],
+    [0.00015173932, -0.17295183, 48.902264],
+    [0.00016414281, -0.18565859, 52.144665],
+    [0.00017774479, -0.19961159, 55.708866],
+    [0.00019298375, -0.21526094, 59.70976],
+    [0.0002104117, -0.23317374, 64.292274],
+    [0.0002307646, -0.2541065, 69.64983],
+    [0.00025507153, -0.27911708, 76.053085],
+    [0.0002848356, -0.3097508, 83.89745],
+    [0.00032236063, -0.3483761, 93.78918],
+    [4.0868228e-5, -0.059095036, 19.89836],
+    [6.166355e-5, -0.08016995, 25.210258],
+    [7.7192795e-5, -0.09594157, 29.196623],
+    [8.98688e-5, -0.10883894, 32.464375],
+    [0.00010116793, -0.12035563, 35.388554],
+    [0.00011194756, -0.13136172, 38.18832],
+    [0.00012275632, -0.14241548, 41.004715],
+    [0.0001340088, -0.15393986, 43.9449],
+    [0.00014607888, -0.16631722, 47.106026],
+    [0.00015935836, -0.17994896, 50.590294],
+    [0.00017430772, -0.19530728, 54.518112],
+    [0.00019151365, -0.21299425, 59.04312],
+    [0.00021176934, -0.2338238, 64.373146],
+    [0.00023620244, -0.25895318, 70.80373],
+    [0.00026649455, -0.29010776, 78.775566],
+    [0.00030528835, -0.3299986, 88.98128],
+    [2.4577166e-5, -0.041555498, 15.325958],
+    [4.5208595e-5, -0.06249606, 20.609554],
+    [6.0972518e-5, -0.078532204, 24.66756],
+    [7.379572e-5, -0.09160139, 27.983154],
+    [8.5065505e-5, -0.103106946, 30.908463],
+    [9.568847e-5, -0.11396941, 33.675354],
+    [0.00010626419, -0.12479924, 36.438057],
+    [0.0001172435, -0.13605651, 39.313126],
+    [0.00012902805, -0.14815198, 42.404858],
+    [0.00014203793, -0.16151576, 45.8227],
+    [0.00015676898, -0.17665601, 49.69608],
+    [0.0001738599, -0.19422728, 54.191826],
+    [0.00019418944, -0.21513012, 59.53953],
+    [0.00021903426, -0.24067251, 66.07269],
+    [0.0002503487, -0.27285603, 74.30167],
+    [0.00029130495, -0.31492838, 85.054565],
+    [6.697534e-6, -0.022512333, 10.408624],
+    [2.765217e-5, -0.04380717, 15.785436],
+    [4.398647e-5, -0.06044535, 19.999197],
+    [5.728678e-5, -0.07401947, 23.446102],
+    [6.8772555e-5, -0.0857616, 26.434702],
+    [7.938778e-5, -0.0966302, 29.206259],
+    [8.981096e-5, -0.10731633, 31.93531],
+    [0.00010055554, -0.11834416, 34.754604],
+    [0.00011207057, -0.13017285, 37.78067],
+    [0.0001248191, -0.14327638, 41.13402],
+    [0.00013934694, -0.15821365, 44.95685],
+    [0.00015636638, -0.1757139, 49.434788],
+    [0.00017687552, -0.19679867, 54.827892],
+    [0.0002023627, -0.22299048, 61.52372],
+    [0.00023518354, -0.25669762, 70.135254],
+    [0.00027932727, -0.30199638, 81.6999],
+    [-1.405054e-5, -0.00060775515, 4.79461],
+    [7.778121e-6, -0.022811618, 10.402751],
+    [2.5044848e-5, -0.040416945, 14.863282],
+    [3.9185285e-5, -0.05486361, 18.533659],
+    [5.1240095e-5, -0.0672007, 21.67577],
+    [6.208891e-5, -0.07832011, 24.513632],
+    [7.248399e-5, -0.088987894, 27.240547],
+    [8.30342e-5, -0.09982572, 30.01386],
+    [9.426569e-5, -0.11137164, 32.97005],
+    [0.00010670691, -0.12416645, 36.24648],
+    [0.00012097542, -0.13884217, 40.003788],
+    [0.0001378813, -0.15622745, 44.45264],
+    [0.00015858476, -0.17750832, 49.89451],
+    [0.00018487146, -0.20450877, 56.792927],
+    [0.00021968764, -0.24023572, 65.9118],
+    [0.000268305, -0.2900652, 78.61659],
+    [-3.96388e-5, 0.026222518, -2.0443304],
+    [-1.6203703e-5, 0.0023708164, 3.978832],
+    [2.496308e-6, -0.016707709, 8.812199],
+    [1.7882649e-5, -0.032437578, 12.808652],
+    [3.0941505e-5, -0.0458113, 16.215237],
+    [4.2437438e-5, -0.05760193, 19.225365],
+    [5.3070406e-5, -0.06852093, 22.018063],
+    [6.352685e-5, -0.07926889, 24.770412],
+    [7.445015e-5, -0.090503804, 27.649176],
+    [8.647375e-5, -0.10287388, 30.818838],
+    [0.000100318495, -0.117116615, 34.46666],
+    [0.000116927964, -0.13419662, 38.837543],
+    [0.00013768011, -0.155521, 44.28861],
+    [0.0001647753, -0.1833342, 51.389202],
+    [0.00020204387, -0.22153871, 61.128746],
+    [0.00025685938, -0.27763826, 75.408875],
+    [-7.354065e-5, 0.06158772, -11.02496],
+    [-4.736388e-5, 0.03494423, -4.303501],
+    [-2.6429627e-5, 0.013583888, 1.1034758],
+    [-9.1969605e-6, -0.00403584, 5.576943],
+    [5.37827e-6, -0.018964283, 9.377273],
+    [1.8057595e-5, -0.0319697, 12.695963],
+    [2.9457777e-5, -0.043676876, 15.689488],
+    [4.0169016e-5, -0.054686442, 18.509058],
+    [5.08639e-5, -0.065685555, 21.32844],
+    [6.231644e-5, -0.07746635, 24.348423],
+    [7.541243e-5, -0.090935215, 27.798906],
+    [9.128526e-5, -0.10725047, 31.973595],
+    [0.00011160662, -0.12811784, 37.30468],
+    [0.00013915921, -0.15637213, 44.509857],
+    [0.00017912844, -0.1972869, 54.923046],
+    [0.00024255489, -0.26207167, 71.37751],
+    [-0.00012285668, 0.11282487, -23.999136],
+    [-9.184522e-5, 0.08128916, -16.063248],
+    [-6.720597e-5, 0.05616741, -9.717695],
+    [-4.7054767e-5, 0.03557866, -4.500533],
+    [-3.0144343e-5, 0.018272148, -0.10282412],
+    [-1.560083e-5, 0.0033683828, 3.69366],
+    [-2.767968e-6, -0.00979533, 7.0540433],
+    [8.897938e-6, -0.021769928, 10.1161995],
+    [1.9921297e-5, -0.033088718, 13.014178],
+    [3.093234e-5, -0.044394903, 15.910352],
+    [4.286165e-5, -0.05664015, 19.045599],
+    [5.710651e-5, -0.07125301, 22.781677],
+    [7.5763484e-5, -0.090372585, 27.659716],
+    [0.00010243198, -0.11766209, 34.60506],
+    [0.00014442379, -0.16054417, 45.49],
+    [0.00021976698, -0.23727316, 64.91282],
+    [-0.00020541107, 0.19821388, -45.536232],
+    [-0.00016469034, 0.15693995, -35.213043],
+    [-0.0001330507, 0.124769785, -27.129108],
+    [-0.0001076612, 0.098895535, -20.602877],
+    [-8.6747554e-5, 0.07754895, -15.202308],
+    [-6.911696e-5, 0.059535693, -10.633681],
+    [-5.3921736e-5, 0.044003904, -6.686455],
+    [-4.0521438e-5, 0.030308392, -3.200552],
+    [-2.8386421e-5, 0.017914474, -0.042866834],
+    [-1.7014181e-5, 0.006313871, 2.913554],
+    [-5.8191813e-6, -0.0050870846, 5.817511],
+    [6.088804e-6, -0.017192988, 8.896394],
+    [2.0531616e-5, -0.0318585, 12.617434],
+    [4.1951338e-5, -0.05360358, 18.119398],
+    [8.1091275e-5, -0.09332114, 28.140566],
+    [0.00017096891, -0.18433164, 51.033684],
+    [-0.0003915251, 0.38920686, -93.272964],
+    [-0.00031957883, 0.31675002, -75.37171],
+    [-0.00026890234, 0.26560462, -62.68387],
+    [-0.00023092476, 0.22720689, -53.121483],
+    [-0.00020132546, 0.19724944, -45.636894],
+    [-0.0001776467, 0.17328586, -39.63575],
+    [-0.00015835969, 0.15379609, -34.748833],
+    [-0.00014244953, 0.13777354, -30.731836],
+    [-0.00012920001, 0.124511115, -27.413536],
+    [-0.000118074124, 0.11348394, -24.667654],
+    [-0.00010861904, 0.104256295, -22.390537],
+    [-0.00010036136, 0.0963796, -20.476654],
+    [-9.261345e-5, 0.08920296, -18.7727],
+    [-8.3889005e-5, 0.08129433, -16.935663],
+    [-6.903353e-5, 0.06757307, -13.724339],
+    [-1.2697902e-7, 0.00013704933, 2.7085292],
+    [0.00021779821, -0.27813023, 83.79341],
+    [-0.00090538705, 0.9127635, -228.78352],
+    [0.0003976383, -0.45349544, 126.51886],
+    [0.00041510139, -0.4695571, 130.2152],
+    [0.00042987126, -0.48329058, 133.41476],
+    [0.00044452565, -0.4972279, 136.7386],
+    [0.0004602953, -0.5125713, 140.48013],
+    [0.00047800672, -0.530134, 144.8394],
+    [0.00049843383, -0.55069286, 150.01093],
+    [0.00052246224, -0.5751534, 156.22551],
+    [0.000551215, -0.60467917, 163.78307],
+    [0.0005862199, -0.6408655, 173.09767],
+    [0.00062968704, -0.68603206, 184.77386],
+    [0.000685006, -0.7437455, 199.74353],
+    [0.0007577311, -0.81986177, 219.539],
+    [0.0008577613, -0.9248296, 246.89737],
+    [-0.00090538705, 0.9168237, -230.85303],
+    [0.0002507783, -0.29187906, 82.897194],
+    [0.00027515012, -0.31588998, 88.8103],
+    [0.00029331582, -0.33378917, 93.222015],
+    [0.00030934828, -0.34966353, 97.15423],
+    [0.00032487503, -0.36514685, 101.01565],
+    [0.00034080667, -0.38115567, 105.03623],
+    [0.00035780235, -0.39835772, 109.384544],
+    [0.0003764629, -0.41736695, 114.216835],
+    [0.00039743775, -0.43885258, 119.70473],
+    [0.0004215093, -0.46362597, 126.05751],
+    [0.00044969033, -0.492742, 133.54834],
+    [0.00048337103, -0.5276528, 142.55423],
+    [0.0005245601, -0.5704605, 153.62186],
+    [0.0005763223, -0.62437665, 167.58743],
+    [0.0006436326, -0.69461983, 185.81108],
+    [0.00016119206, -0.19498712, 57.13233],
+    [0.00019588933, -0.22969735, 65.7934],
+    [0.00021680674, -0.2506082, 71.01282],
+    [0.00023346649, -0.26729473, 75.187836],
+    [0.000248529, -0.2824373, 78.99077],
+    [0.00026318125, -0.29723418, 82.72287],
+    [0.00027813623, -0.31240788, 86.56643],
+    [0.00029393382, -0.32850868, 90.66108],
+    [0.00031107262, -0.34604794, 95.13737],
+    [0.00033008892, -0.36557883, 100.13726],
+    [0.00035162168, -0.38776323, 105.83132],
+    [0.0003764872, -0.41344926, 112.438675],
+    [0.0004057877, -0.44378433, 120.256355],
+    [0.00044108147, -0.48039275, 129.7054],
+    [0.0004846813, -0.5256877, 141.4119],
+    [0.0005402078, -0.58344966, 156.3575],
+    [0.00013391477, -0.16328502, 48.287045],
+    [0.00016169286, -0.19123018, 55.295727],
+    [0.00018033368, -0.21000646, 60.014458],
+    [0.00019571022, -0.22553091, 63.92663],
+    [0.00020981795, -0.2398188, 67.53869],
+    [0.00022361627, -0.25384164, 71.095436],
+    [0.00023770863, -0.26821285, 74.752045],
+    [0.0002525672, -0.28341487, 78.631165],
+    [0.00026863633, -0.29990396, 82.849396],
+    [0.0002863958, -0.318175, 87.53371],
+    [0.00030641936, -0.33882153, 92.836876],
+    [0.0003294387, -0.36260217, 98.954544],
+    [0.00035643877, -0.39053953, 106.15083],
+    [0.0003888042, -0.42407247, 114.79775],
+    [0.00042857334, -0.4653207, 125.44372],
+    [0.000478899, -0.51756465, 138.93803],
+    [0.00011285036, -0.13928445, 41.705116],
+    [0.00013694652, -0.16362815, 47.833702],
+    [0.0001541, -0.18099295, 52.217327],
+    [0.00016853516, -0.19564155, 55.925724],
+    [0.00018189673, -0.20923817, 59.377678],
+    [0.0001950207, -0.22263111, 62.787277],
+    [0.00020845016, -0.23637351, 66.294655],
+    [0.00022262071, -0.25091106, 70.013245],
+    [0.00023794896, -0.26667175, 74.05243],
+    [0.0002548901, -0.28412488, 78.53259],
+    [0.00027399155, -0.30383617, 83.59916],
+    [0.00029595572, -0.32653257, 89.43934],
+    [0.0003217321, -0.35319772, 96.30672],
+    [0.00035266049, -0.3852205, 104.559586],
+    [0.0003907186, -0.42465204, 114.72745],
+    [0.00043897404, -0.4746748, 127.632256],
+    [9.517615e-5, -0.11949338, 36.36445],
+    [0.000117181735, -0.14180093, 41.997375],
+    [0.00013339586, -0.15827726, 46.170834],
+    [0.00014718072, -0.17231958, 49.738014],
+    [0.00015999486, -0.18540604, 53.071274],
+    [0.00017261285, -0.19832392, 56.36953],
+    [0.00018554892, -0.21159773, 59.7658],
+    [0.00019922298, -0.2256575, 63.36959],
+    [0.00021404204, -0.24092147, 67.28782],
+    [0.00023045632, -0.25785363, 71.63942],
+    [0.0002490124, -0.2770182, 76.56932],
+    [0.00027041882, -0.29914746, 82.26583],
+    [0.00029564174, -0.3252405, 88.986115],
+    [0.00032605897, -0.35672274, 97.09725],
+    [0.00036372722, -0.39572236, 107.14758],
+    [0.0004118765, -0.44558236, 119.99876],
+    [7.923905e-5, -0.101942495, 31.703552],
+    [0.000100129546, -0.12318082, 37.079964],
+    [0.00011583318, -0.13918857, 41.146034],
+    [0.00012921695, -0.15286562, 44.63042],
+    [0.00014165639, -0.16560793, 47.88508],
+    [0.00015390922, -0.17818671, 51.105038],
+    [0.0001664855, -0.19112284, 54.422493],
+    [0.00017980559, -0.20484708, 57.94719],
+    [0.00019428122, -0.21978268, 61.7873],
+    [0.00021037272, -0.23640373, 66.0643],
+    [0.00022864596, -0.255294, 70.92801],
+    [0.00024984294, -0.2772193, 76.575134],
+    [0.00027499042, -0.3032399, 83.27822],
+    [0.00030557453, -0.33489057, 91.43189],
+    [0.00034385454, -0.37450466, 101.63636],
+    [0.00039345765, -0.42582864, 114.85557],
+    [6.388778e-5, -0.08530594, 27.354172],
+    [8.4373074e-5, -0.10618482, 32.6509],
+    [9.9956866e-5, -0.12211388, 36.706696],
+    [0.00011317844, -0.13566329, 40.16742],
+    [0.00012540298, -0.14822, 43.382965],
+    [0.00013741183, -0.16058034, 46.55486],
+    [0.00014973381, -0.17328481, 49.820354],
+    [0.00016280556, -0.18678121, 53.293564],
+    [0.00017705631, -0.20151074, 57.087242],
+    [0.00019297103, -0.21797286, 61.32931],
+    [0.00021115202, -0.23678833, 66.17894],
+    [0.00023240279, -0.2587858, 71.84879],
+    [0.00025785263, -0.28512982, 78.63783],
+    [0.00028917307, -0.31754416, 86.98872],
+    [0.00032896627, -0.35871193, 97.59059],
+    [0.00038154307, -0.41307768, 111.585266],
+    [4.8132533e-5, -0.06848361, 23.01975],
+    [6.880412e-5, -0.08959959, 28.386456],
+    [8.468242e-5, -0.10586948, 32.537716],
+    [9.801078e-5, -0.1195639, 36.04375],
+    [0.00011019159, -0.13210887, 39.264393],
+    [0.00012207175, -0.1443684, 42.41842],
+    [0.00013422644, -0.15693092, 45.6553],
+    [0.00014712645, -0.17027955, 49.098175],
+    [0.00016123343, -0.18488897, 52.868355],
+    [0.0001770705, -0.20129775, 57.103695],
+    [0.00019529671, -0.22018494, 61.97823],
+    [0.00021680485, -0.24247035, 67.72778],
+    [0.00024287886, -0.2694768, 74.69162],
+    [0.00027546714, -0.303211, 83.38441],
+    [0.00031770626, -0.34690258, 94.63458],
+    [0.00037500708, -0.40612125, 109.870964],
+    [3.0897456e-5, -0.05031787, 18.397083],
+    [5.2320127e-5, -0.07224491, 23.97858],
+    [6.897098e-5, -0.089344144, 28.349337],
+    [8.276468e-5, -0.103551276, 31.994535],
+    [9.512627e-5, -0.11631583, 35.279797],
+    [0.00010701126, -0.12861334, 38.45217],
+    [0.00011907987, -0.14111994, 41.68348],
+    [0.00013186171, -0.15437937, 45.112297],
+    [0.00014586918, -0.16891871, 48.873325],
+    [0.00016168151, -0.18533432, 53.119164],
+    [0.00018003634, -0.204386, 58.04444],
+    [0.00020195072, -0.22712103, 63.91746],
+    [0.000228926, -0.25508487, 71.13421],
+    [0.00026331682, -0.29070026, 80.315384],
+    [0.00030907293, -0.33802885, 92.50156],
+    [0.00037339094, -0.40446812, 109.58792],
+    [1.0719513e-5, -0.029269032, 13.091082],
+    [3.3560456e-5, -0.052689344, 19.060146],
+    [5.1536306e-5, -0.07118571, 23.79512],
+    [6.631379e-5, -0.08644036, 27.716509],
+    [7.921645e-5, -0.099797964, 31.162802],
+    [9.1312424e-5, -0.11234949, 34.410183],
+    [0.00010339598, -0.12490888, 37.665466],
+    [0.000116097995, -0.13812438, 41.0939],
+    [0.0001300095, -0.1526042, 44.850876],
+    [0.00014579069, -0.16902843, 49.110424],
+    [0.00016428373, -0.18826443, 54.094604],
+    [0.00018667507, -0.21153368, 60.116196],
+    [0.00021476715, -0.2406905, 67.6499],
+    [0.0002515053, -0.2787628, 77.47071],
+    [0.00030210018, -0.33110294, 90.94823],
+    [0.00037675953, -0.40818948, 110.76348],
+    [-1.4783467e-5, -0.0028585126, 6.4722285],
+    [1.0446716e-5, -0.028767468, 13.081053],
+    [3.0465664e-5, -0.049401496, 18.369255],
+    [4.692058e-5, -0.06642147, 22.751041],
+    [6.0994385e-5, -0.081026375, 26.527176],
+    [7.371396e-5, -0.094263494, 29.962133],
+    [8.601497e-5, -0.10709199, 33.299503],
+    [9.869122e-5, -0.120328024, 36.747257],
+    [0.00011246658, -0.1347173, 40.49596],
+    [0.00012811898, -0.15106162, 44.750748],
+    [0.00014663486, -0.17037758, 49.771893],
+    [0.00016942351, -0.1941162, 55.930756],
+    [0.00019870422, -0.22456025, 63.81139],
+    [0.00023828652, -0.2656235, 74.41454],
+    [0.00029540277, -0.3247282, 89.63725],
+    [0.0003856929, -0.41790769, 113.57614],
+    [-5.0167273e-5, 0.033632737, -2.6536403],
+    [-2.088442e-5, 0.0035305133, 5.0257277],
+    [2.3727218e-6, -0.020472962, 11.180778],
+    [2.146747e-5, -0.040255487, 16.27876],
+    [3.7651174e-5, -0.057083607, 20.636452],
+    [5.1858653e-5, -0.071907796, 24.492823],
+    [6.4942986e-5, -0.08560072, 28.068766],
+    [7.781646e-5, -0.09910047, 31.603119],
+    [9.141098e-5, -0.11336792, 35.341324],
+    [0.00010670259, -0.12941061, 39.54128],
+    [0.00012488152, -0.14845654, 44.517395],
+    [0.00014765457, -0.17226471, 50.719917],
+    [0.00017780242, -0.20369609, 58.880478],
+    [0.00022041306, -0.24797708, 70.33441],
+    [0.0002860903, -0.31598106, 87.85736],
+    [0.00040130218, -0.4348053, 118.36203],
+    [-0.00010591408, 0.091042064, -17.024858],
+    [-6.912963e-5, 0.05322429, -7.3903003],
+    [-4.02182e-5, 0.023369659, 0.2597345],
+    [-1.6689917e-5, -0.0010280615, 6.546536],
+    [3.0501903e-6, -0.021581227, 11.871979],
+    [2.0101641e-5, -0.039406046, 16.515741],
+    [3.5295478e-5, -0.055349793, 20.691223],
+    [4.9365808e-5, -0.07016501, 24.589188],
+    [6.315378e-5, -0.08471839, 28.430626],
+    [7.781083e-5, -0.10020042, 32.520275],
+    [9.484568e-5, -0.11817143, 37.257973],
+    [0.00011637034, -0.14081296, 43.202557],
+    [0.00014589005, -0.17173694, 51.277874],
+    [0.0001903154, -0.21804619, 63.297707],
+    [0.0002660809, -0.29658598, 83.55644],
+    [0.00042457305, -0.45988253, 125.426056],
+    [-0.00021391078, 0.20222351, -44.90824],
+    [-0.00015993745, 0.1468848, -30.889542],
+    [-0.0001189561, 0.104638495, -20.108175],
+    [-8.652892e-5, 0.07104942, -11.47861],
+    [-5.9978425e-5, 0.04342066, -4.3337994],
+    [-3.7588117e-5, 0.02001459, 1.7589664],
+    [-1.8179635e-5, -0.00036716773, 7.099988],
+    [-8.826294e-7, -0.018613383, 11.913269],
+    [1.5025672e-5, -0.035465997, 16.387148],
+    [3.0256671e-5, -0.051658455, 20.708887],
+    [4.5749028e-5, -0.06816244, 25.127756],
+    [6.3276246e-5, -0.08681531, 30.115252],
+    [8.68139e-5, -0.111730576, 36.72548],
+    [0.00012528374, -0.15210457, 47.307964],
+    [0.00020437063, -0.23428588, 68.57093],
+    [0.00044691956, -0.4835771, 132.30283],
+    [-0.00055917614, 0.55496126, -132.59457],
+    [-0.00042467742, 0.4191369, -99.03558],
+    [-0.0003394415, 0.33246663, -77.3959],
+    [-0.00027895998, 0.2705327, -61.766075],
+    [-0.0002334298, 0.22361305, -49.806488],
+    [-0.0001980209, 0.18692665, -40.368187],
+    [-0.00016995877, 0.15771715, -32.784],
+    [-0.00014749933, 0.13424104, -26.626654],
+    [-0.00012956241, 0.115422614, -21.631565],
+    [-0.00011555894, 0.10069347, -17.661112],
+    [-0.00010531932, 0.08993406, -14.693344],
+    [-9.913668e-5, 0.08354139, -12.843633],
+    [-9.7970085e-5, 0.08269286, -12.442131],
+    [-0.00010393105, 0.08999527, -14.226483],
+    [-0.00012048774, 0.11024281, -19.639732],
+    [-1.1513766e-6, 0.0012429268, 5.6439567],
+    [0.00024600705, -0.3084256, 91.88926],
+    [0.00090538705, -0.9253012, 234.98055],
+    [0.00041252814, -0.47038034, 131.25433],
+    [0.0004299635, -0.48635966, 134.91797],
+    [0.00044491, -0.50024, 138.14792],
+    [0.00045991672, -0.51452136, 141.55647],
+    [0.00047621145, -0.53040093, 145.43542],
+    [0.00049464154, -0.5487128, 149.98994],
+    [0.0005160234, -0.57027775, 155.42603],
+    [0.00054130936, -0.5960715, 161.99283],
+    [0.00057172345, -0.62736475, 170.01852],
+    [0.00060895196, -0.6659217, 179.96179],
+    [0.0006554644, -0.71433765, 192.50014],
+    [0.00071510265, -0.7766612, 208.69283],
+    [0.00079427444, -0.85965675, 230.3124],
+    [0.0009046723, -0.9756821, 260.60046],
+    [0.00090538705, -0.9298214, 237.36295],
+    [0.0002605591, -0.30298516, 86.02567],
+    [0.00028492633, -0.32699662, 91.94135],
+    [0.00030333482, -0.34514955, 96.41998],
+    [0.000319722, -0.36139607, 100.45016],
+    [0.00033569598, -0.37735105, 104.436066],
+    [0.00035217142, -0.39393586, 108.60908],
+    [0.0003698238, -0.41183555, 113.14236],
+    [0.00038928216, -0.43169397, 118.20005],
+    [0.00041123753, -0.4542244, 123.96539],
+    [0.00043653275, -0.48030224, 130.66457],
+    [0.00046627046, -0.5110777, 138.59586],
+    [0.0005019786, -0.54814893, 148.1748],
+    [0.0005458905, -0.5938561, 160.01085],
+    [0.0006014557, -0.6518187, 175.0477],
+    [0.0006743625, -0.7280109, 194.84451],
+    [0.00017007488, -0.20496784, 59.922916],
+    [0.00020405478, -0.2389792, 68.41602],
+    [0.00022503176, -0.2599686, 73.66081],
+    [0.000241948, -0.2769338, 77.91169],
+    [0.00025736436, -0.29245615, 81.816605],
+    [0.00027244902, -0.3077159, 85.672485],
+    [0.00028791733, -0.32343873, 89.66269],
+    [0.00030432185, -0.3401885, 93.930466],
+    [0.00032218368, -0.35850057, 98.61273],
+    [0.0003420713, -0.3789622, 103.86044],
+    [0.00036467094, -0.40228525, 109.85733],
+    [0.00039086878, -0.42939174, 116.84198],
+    [0.00042187134, -0.46153936, 125.140434],
+    [0.00045940155, -0.5005263, 135.21945],
+    [0.0005060446, -0.5490533, 147.78073],
+    [0.000565902, -0.6114087, 163.93929],
+    [0.00014186282, -0.17225197, 50.806824],
+    [0.0001692212, -0.19980071, 57.723988],
+    [0.00018794202, -0.21868093, 62.475655],
+    [0.00020357255, -0.23448585, 66.465256],
+    [0.00021802807, -0.24915116, 70.17964],
+    [0.00023224976, -0.26363075, 73.85941],
+    [0.00024684233, -0.27854002, 77.66046],
+    [0.00026228954, -0.29437402, 81.70885],
+    [0.00027905492, -0.31160942, 86.126564],
+    [0.00029764816, -0.33077273, 91.04893],
+    [0.00031868558, -0.35250247, 96.64054],
+    [0.00034296175, -0.37762362, 103.114494],
+    [0.00037155562, -0.4072578, 110.76092],
+    [0.0004059985, -0.44299844, 119.992355],
+    [0.00044856832, -0.48721755, 131.42342],
+    [0.0005028333, -0.54363257, 146.01793],
+    [0.00012044647, -0.14788063, 44.12986],
+    [0.00014425554, -0.17196372, 50.20158],
+    [0.00016149791, -0.1894446, 54.622036],
+    [0.00017618632, -0.2043761, 58.409317],
+    [0.00018989632, -0.21835376, 61.965317],
+    [0.0002034458, -0.23220836, 65.49994],
+    [0.00021737866, -0.24649465, 69.15393],
+    [0.00023214126, -0.26166993, 73.04386],
+    [0.00024816924, -0.2781825, 77.28453],
+    [0.000265947, -0.29653254, 82.00442],
+    [0.0002860641, -0.3173302, 87.36065],
+    [0.00030928574, -0.34136853, 93.55776],
+    [0.00033665492, -0.36972952, 100.87513],
+    [0.00036965872, -0.40395692, 109.71162],
+    [0.00041051512, -0.44635415, 120.66276],
+    [0.0004627085, -0.5005412, 134.66493],
+    [0.00010272428, -0.12805812, 38.78819],
+    [0.00012451556, -0.15018116, 44.384132],
+    [0.000140822, -0.16678022, 48.59699],
+    [0.00015486193, -0.18111053, 52.245255],
+    [0.00016803187, -0.1945888, 55.686188],
+    [0.00018108767, -0.20798415, 59.114384],
+    [0.00019454371, -0.22182201, 62.663296],
+    [0.00020883052, -0.23654391, 66.44555],
+    [0.00022437522, -0.25258958, 70.57377],
+    [0.00024165795, -0.2704545, 75.17516],
+    [0.00026127015, -0.29075024, 80.40709],
+    [0.0002839871, -0.3142792, 86.47626],
+    [0.00031087565, -0.34214625, 93.66749],
+    [0.0003434734, -0.3759444, 102.39172],
+    [0.00038410176, -0.41807938, 113.269806],
+    [0.00043645524, -0.47238052, 127.29037],
+    [8.6943226e-5, -0.11070671, 34.191307],
+    [0.00010767067, -0.13181603, 39.545784],
+    [0.00012346565, -0.14794937, 43.6531],
+    [0.00013710775, -0.16192168, 47.221542],
+    [0.00014991591, -0.175073, 50.589382],
+    [0.0001626279, -0.18815522, 53.94706],
+    [0.0001757528, -0.20168895, 57.4269],
+    [0.00018972224, -0.21611734, 61.14202],
+    [0.00020496908, -0.23188595, 65.20652],
+    [0.000221987, -0.24950427, 69.75118],
+    [0.00024139127, -0.26960793, 74.93944],
+    [0.00026399817, -0.29304084, 80.98843],
+    [0.0002909495, -0.32098386, 88.20222],
+    [0.00032391702, -0.3551663, 97.02628],
+    [0.000365472, -0.3982479, 108.145966],
+    [0.00041980617, -0.4545653, 122.67908],
+    [7.1910596e-5, -0.0944571, 29.959846],
+    [9.2288894e-5, -0.115269065, 35.251842],
+    [0.00010795966, -0.13132437, 39.350548],
+    [0.000121442004, -0.1451766, 42.898735],
+    [0.0001340527, -0.15816543, 46.234863],
+    [0.0001465502, -0.17106482, 49.55509],
+    [0.00015946203, -0.18441492, 52.996857],
+    [0.00017323566, -0.19867538, 56.677505],
+    [0.00018832441, -0.2143131, 60.716618],
+    [0.00020525057, -0.23186691, 65.25248],
+    [0.00022467328, -0.25201738, 70.45994],
+    [0.0002474828, -0.27568415, 76.57546],
+    [0.00027494674, -0.30417684, 83.93591],
+    [0.00030896155, -0.33945444, 93.04532],
+    [0.0003525245, -0.38461304, 104.70046],
+    [0.00041068523, -0.44486815, 120.24386],
+    [5.6618625e-5, -0.07819299, 25.793957],
+    [7.7270255e-5, -0.09933795, 31.182343],
+    [9.3235154e-5, -0.11574075, 35.380314],
+    [0.000106823645, -0.12974434, 38.97748],
+    [0.00011940957, -0.1427479, 42.32751],
+    [0.0001318163, -0.15559278, 45.643826],
+    [0.00014461507, -0.16886444, 49.07544],
+    [0.00015828837, -0.18305896, 52.749073],
+    [0.00017332438, -0.19867931, 56.793564],
+    [0.00019028987, -0.2163102, 61.35891],
+    [0.00020991113, -0.23670135, 66.63767],
+    [0.00023318743, -0.26088443, 72.89495],
+    [0.00026157292, -0.29036036, 80.51648],
+    [0.00029730768, -0.32744092, 90.09647],
+    [0.00034405466, -0.37590513, 102.60642],
+    [0.00040826516, -0.4424072, 119.75713],
+    [3.998642e-5, -0.06075638, 21.392044],
+    [6.152715e-5, -0.08286373, 27.036638],
+    [7.8296674e-5, -0.10013885, 31.468048],
+    [9.234663e-5, -0.11466105, 35.208824],
+    [0.00010513032, -0.12791161, 38.633423],
+    [0.00011758632, -0.14085054, 41.985455],
+    [0.00013036792, -0.1541482, 45.43565],
+    [0.00014401707, -0.16836229, 49.12652],
+    [0.00015907515, -0.18405083, 53.201004],
+    [0.00017617393, -0.20186588, 57.82642],
+    [0.00019613345, -0.22265397, 63.22013],
+    [0.00022010367, -0.24760185, 69.68693],
+    [0.00024980828, -0.27848786, 77.68357],
+    [0.00028799483, -0.31814525, 87.93775],
+    [0.00033936018, -0.37141493, 101.69273],
+    [0.0004126832, -0.44734162, 121.27163],
+    [2.0548276e-5, -0.040613182, 16.363289],
+    [4.3705306e-5, -0.064431466, 22.455147],
+    [6.1917824e-5, -0.08323967, 27.289812],
+    [7.697143e-5, -0.098844536, 31.320156],
+    [9.030707e-5, -0.11271415, 34.91686],
+    [0.00010301937, -0.12596905, 38.364292],
+    [0.000115900635, -0.13942318, 41.869835],
+    [0.00012959141, -0.15373622, 45.602097],
+    [0.00014471683, -0.16955331, 49.72642],
+    [0.00016199892, -0.18762013, 54.434204],
+    [0.00018238378, -0.2089137, 59.976303],
+    [0.00020723081, -0.23483717, 66.71305],
+    [0.00023864378, -0.2675606, 75.20162],
+    [0.00028012344, -0.31069216, 86.36811],
+    [0.00033801567, -0.3707678, 101.88988],
+    [0.0004251579, -0.46100116, 125.15784],
+    [-4.119396e-6, -0.015255045, 10.075154],
+    [2.1727928e-5, -0.041893438, 16.897758],
+    [4.222114e-5, -0.06310614, 22.360407],
+    [5.909289e-5, -0.08064423, 26.900936],
+    [7.363731e-5, -0.09582328, 30.85042],
+    [8.701163e-5, -0.10982763, 34.509083],
+    [0.00010020214, -0.12367124, 38.135235],
+    [0.000114021444, -0.13819219, 41.943195],
+    [0.00012922974, -0.1541761, 46.134453],
+    [0.00014667975, -0.17250474, 50.935444],
+    [0.00016749071, -0.19433527, 56.64349],
+    [0.00019330582, -0.22136512, 63.69463],
+    [0.0002267658, -0.25631902, 72.78835],
+    [0.00027251628, -0.30398506, 85.15329],
+    [0.00033964112, -0.37371534, 103.188095],
+    [0.0004486586, -0.48661345, 132.30421],
+    [-3.8782204e-5, 0.020234065, 1.2905003],
+    [-8.348848e-6, -0.0111822765, 9.34329],
+    [1.5811176e-5, -0.03624255, 15.805767],
+    [3.567493e-5, -0.05694415, 21.17636],
+    [5.257395e-5, -0.07463862, 25.794188],
+    [6.754404e-5, -0.09038308, 29.92616],
+    [8.160455e-5, -0.105225086, 33.83877],
+    [9.5793745e-5, -0.12023677, 37.806263],
+    [0.00011110904, -0.13645083, 42.093666],
+    [0.00012861719, -0.15497315, 46.98544],
+    [0.00014968122, -0.17721628, 52.845078],
+    [0.00017632752, -0.2052773, 60.211834],
+    [0.00021196029, -0.24267347, 69.989365],
+    [0.00026299545, -0.29602215, 83.87594],
+    [0.00034334336, -0.3796452, 105.54372],
+    [0.0004899599, -0.5315341, 144.72849],
+    [-9.488422e-5, 0.07765954, -12.964723],
+    [-5.5786506e-5, 0.03726638, -2.6165648],
+    [-2.5074236e-5, 0.0053635505, 5.6135387],
+    [-2.6298979e-8, -0.020796843, 12.409369],
+    [2.1085807e-5, -0.042968318, 18.209785],
+    [3.945404e-5, -0.06236607, 23.320772],
+    [5.600092e-5, -0.07993604, 27.982176],
+    [7.161897e-5, -0.09659952, 32.42935],
+    [8.7438755e-5, -0.11353016, 36.96402],
+    [0.00010487377, -0.13219918, 41.96563],
+    [0.00012568076, -0.15443558, 47.906403],
+    [0.00015242003, -0.18290113, 55.473225],
+    [0.00018954794, -0.22221677, 65.85629],
+    [0.00024622295, -0.2818509, 81.489],
+    [0.00034546448, -0.38553214, 108.45798],
+    [0.0005667815, -0.61499083, 167.69827],
+    [-0.00020997452, 0.19572806, -42.43686],
+    [-0.00014994979, 0.13383411, -26.65506],
+    [-0.000104583705, 0.086739704, -14.539976],
+    [-6.862057e-5, 0.049161777, -4.789351],
+    [-3.8971448e-5, 0.017972229, 3.3755348],
+    [-1.3675164e-5, -0.008827776, 10.456518],
+    [8.610642e-6, -0.032615516, 16.802433],
+    [2.8896917e-5, -0.05443734, 22.681284],
+    [4.805581e-5, -0.075205036, 28.329557],
+    [6.703771e-5, -0.09592033, 34.009552],
+    [8.73769e-5, -0.11820357, 40.146114],
+    [0.00011210082, -0.1452425, 47.571808],
+    [0.00014693534, -0.18304272, 57.84921],
+    [0.00020475133, -0.24502973, 74.45407],
+    [0.00032588938, -0.37300694, 108.14802],
+    [0.0007457238, -0.8092077, 220.99222],
+    [-0.0006469306, 0.64136595, -152.97227],
+    [-0.00046981196, 0.46226802, -108.702],
+    [-0.0003624581, 0.3525381, -81.151924],
+    [-0.000287093, 0.27463743, -61.281437],
+    [-0.00023034359, 0.21536845, -45.940445],
+    [-0.00018578704, 0.16836363, -33.59724],
+    [-0.00014948391, 0.12962842, -23.259016],
+    [-0.00011891899, 0.09656543, -14.262497],
+    [-9.240825e-5, 0.0673933, -6.1360006],
+    [-6.874355e-5, 0.040778253, 1.4958962],
+    [-4.6989215e-5, 0.015604987, 8.979296],
+    [-2.6371004e-5, -0.009184679, 16.693153],
+    [-6.2376016e-6, -0.03475271, 25.145588],
+    [1.3323877e-5, -0.062040363, 35.048145],
+    [2.4270874e-5, -0.08514982, 46.211655],
+    [-5.984073e-5, 0.06460172, 5.419727],
+    [0.0002482014, -0.31078893, 92.522385],
+    [-0.00090538705, 0.9127295, -228.77315],
+    [0.00041371793, -0.4717297, 131.63278],
+    [0.0004311511, -0.48770237, 135.29376],
+    [0.00044611163, -0.5015944, 138.52611],
+    [0.00046114638, -0.51590306, 141.94139],
+    [0.0004774836, -0.5318261, 145.83148],
+    [0.0004959715, -0.5501982, 150.40173],
+    [0.00051743013, -0.5718441, 155.85912],
+    [0.0005428179, -0.59774595, 162.4545],
+    [0.0005733671, -0.62918305, 170.51833],
+    [0.0006107765, -0.6679329, 180.51279],
+    [0.00065753795, -0.7166148, 193.12172],
+    [0.0007175304, -0.7793166, 209.41484],
+    [0.00079723576, -0.86288184, 231.18556],
+    [0.00090538705, -0.9764744, 260.82196],
+    [-0.00090538705, 0.9168049, -230.85583],
+    [0.0002613347, -0.30386624, 86.273926],
+    [0.00028570316, -0.3278793, 92.19021],
+    [0.0003041317, -0.34605318, 96.67436],
+    [0.00032054752, -0.3623298, 100.712456],
+    [0.0003365576, -0.37832287, 104.70842],
+    [0.00035307676, -0.39495397, 108.89369],
+    [0.00037078196, -0.4129098, 113.44187],
+    [0.00039030443, -0.43283656, 118.517715],
+    [0.0004123389, -0.45545137, 124.30547],
+    [0.0004377331, -0.48163483, 131.03271],
+    [0.0004675967, -0.5125445, 138.99968],
+    [0.00050346943, -0.5497912, 148.6252],
+    [0.0005476027, -0.5957344, 160.52382],
+    [0.0006034788, -0.65402794, 175.64835],
+    [0.00067684636, -0.73071027, 195.57486],
+    [0.0001707744, -0.2057547, 60.14313],
+    [0.00020470204, -0.23971525, 68.624054],
+    [0.00022568523, -0.26071241, 73.87125],
+    [0.0002426227, -0.27770063, 78.12839],
+    [0.00025806774, -0.29325378, 82.04157],
+    [0.0002731873, -0.3085509, 85.907455],
+    [0.00028869693, -0.324318, 89.90948],
+    [0.00030515055, -0.34112027, 94.191284],
+    [0.0003230705, -0.35949454, 98.89013],
+    [0.0003430283, -0.38003117, 104.15784],
+    [0.00036571437, -0.40344656, 110.1793],
+    [0.00039201995, -0.43066803, 117.19451],
+    [0.00042316032, -0.4629625, 125.53195],
+    [0.0004608721, -0.50214267, 135.66219],
+    [0.0005077637, -0.5509339, 148.29338],
+    [0.00056797644, -0.6136664, 164.55162],
+    [0.00014249083, -0.17296116, 51.00626],
+    [0.00016981892, -0.20048144, 57.916912],
+    [0.00018854755, -0.21937145, 62.67159],
+    [0.00020419923, -0.23519967, 66.66761],
+    [0.00021868298, -0.24989562, 70.390305],
+    [0.00023293895, -0.2644122, 74.080055],
+    [0.00024757208, -0.27936518, 77.89284],
+    [0.00026306682, -0.29525024, 81.9549],
+    [0.0002798884, -0.31254593, 86.388756],
+    [0.00029854928, -0.3317817, 91.33046],
+    [0.00031966888, -0.35359928, 96.945496],
+    [0.00034404694, -0.37882918, 103.44838],
+    [0.00037277016, -0.4086012, 111.1314],
+    [0.00040738215, -0.44452173, 120.410515],
+    [0.0004501809, -0.48898384, 131.90584],
+    [0.0005047692, -0.5457415, 146.5908],
+    [0.00012104886, -0.14856291, 44.322437],
+    [0.00014483742, -0.17262763, 50.39023],
+    [0.00016208841, -0.19011936, 54.814034],
+    [0.0001767979, -0.20507433, 58.60785],
+    [0.00019053642, -0.21908319, 62.172367],
+    [0.00020412069, -0.23297557, 65.717224],
+    [0.00021809441, -0.24730603, 69.38314],
+    [0.00023290506, -0.26253313, 73.287],
+    [0.00024898993, -0.2791069, 77.54408],
+    [0.0002668357, -0.29752994, 82.28349],
+    [0.00028703536, -0.31841594, 87.66331],
+    [0.00031035932, -0.34256354, 93.88952],
+    [0.0003378585, -0.3710631, 101.24369],
+    [0.00037103196, -0.40547088, 110.127975],
+    [0.00041211804, -0.44811174, 121.14352],
+    [0.0004646359, -0.5026422, 135.23627],
+    [0.00010332557, -0.12874092, 38.981525],
+    [0.00012510155, -0.15085107, 44.574986],
+    [0.00014141694, -0.16746157, 48.79142],
+    [0.00015547832, -0.18181603, 52.446465],
+    [0.00016867765, -0.19532667, 55.896294],
+    [0.00018176937, -0.20876122, 59.335163],
+    [0.00019526792, -0.22264522, 62.896572],
+    [0.00020960481, -0.2374213, 66.69345],
+    [0.00022520869, -0.25353077, 70.83881],
+    [0.00024256254, -0.2714722, 75.46072],
+    [0.0002622612, -0.29186058, 80.717415],
+    [0.0002850854, -0.31550422, 86.817154],
+    [0.00031211064, -0.34351704, 94.04713],
+    [0.0003448877, -0.37750578, 102.82187],
+    [0.0003857602, -0.41989964, 113.768364],
+    [0.00043846224, -0.4745693, 127.886055],
+    [8.756034e-5, -0.1114092, 34.390816],
+    [0.000108276035, -0.13250954, 39.74388],
+    [0.00012407984, -0.14865452, 43.85491],
+    [0.00013774418, -0.16265216, 47.430542],
+    [0.00015058304, -0.17583752, 50.807804],
+    [0.000163333, -0.18896139, 54.176872],
+    [0.0001765033, -0.20254464, 57.67019],
+    [0.00019052599, -0.2170308, 61.400944],
+    [0.0002058364, -0.2328682, 65.48399],
+    [0.0002229306, -0.2505687, 70.05074],
+    [0.00024242801, -0.2707724, 75.265785],
+    [0.00026515138, -0.29433, 81.34805],
+    [0.00029225176, -0.32243204, 88.60415],
+    [0.00032541665, -0.35682437, 97.48385],
+    [0.00036724386, -0.40019456, 108.67979],
+    [0.0004219739, -0.45693016, 123.323074],
+    [7.255782e-5, -0.09519576, 30.170254],
+    [9.292801e-5, -0.11600286, 35.461998],
+    [0.000108607615, -0.13207027, 39.564686],
+    [0.00012211257, -0.14594865, 43.120388],
+    [0.00013475581, -0.15897389, 46.466667],
+    [0.00014729443, -0.17191865, 49.79939],
+    [0.00016025509, -0.18532224, 53.25577],
+    [0.00017408724, -0.19964649, 56.953762],
+    [0.0001892457, -0.21535982, 61.013332],
+    [0.00020625586, -0.23300444, 65.57366],
+    [0.00022578171, -0.25326595, 70.81092],
+    [0.00024872125, -0.27707216, 76.96373],
+    [0.00027635315, -0.30574423, 84.371956],
+    [0.0003105933, -0.34126163, 93.54497],
+    [0.00035447316, -0.3867561, 105.288864],
+    [0.00041310492, -0.4475086, 120.96323],
+    [5.7309247e-5, -0.07898344, 26.01985],
+    [7.7959216e-5, -0.10013079, 31.410023],
+    [9.393262e-5, -0.11654597, 35.612213],
+    [0.000107544205, -0.13057679, 39.217358],
+    [0.000120165096, -0.14361988, 42.57853],
+    [0.00013261636, -0.15651429, 45.908573],
+    [0.00014546962, -0.16984601, 49.356705],
+    [0.00015920802, -0.18411182, 53.04981],
+    [0.00017432225, -0.1998174, 57.117455],
+    [0.00019138248, -0.21755105, 61.710594],
+    [0.00021112159, -0.23806943, 67.02361],
+    [0.00023454674, -0.26241252, 73.32376],
+    [0.00026312735, -0.29209724, 81.000984],
+    [0.0002991288, -0.3294618, 90.6564],
+    [0.000346259, -0.37833232, 103.27375],
+    [0.00041106174, -0.44545949, 120.589],
+    [4.0734798e-5, -0.061615955, 21.63864],
+    [6.228431e-5, -0.08373725, 27.288223],
+    [7.906437e-5, -0.10102772, 31.724874],
+    [9.313779e-5, -0.115578465, 35.47423],
+    [0.00010595847, -0.12887149, 38.910973],
+    [0.00011846407, -0.14186613, 42.278614],
+    [0.00013130695, -0.15523185, 45.747654],
+    [0.00014503037, -0.16952777, 49.461018],
+    [0.0001601783, -0.18531474, 53.562397],
+    [0.00017738732, -0.20324995, 58.22046],
+    [0.00019748391, -0.2241866, 63.654324],
+    [0.0002216302, -0.24932434, 70.17216],
+    [0.0002515694, -0.28046197, 78.23609],
+    [0.00029008204, -0.32046714, 88.582756],
+    [0.00034193302, -0.37425208, 102.47404],
+    [0.00041604193, -0.45100805, 122.271164],
+    [2.1370155e-5, -0.041561425, 16.636648],
+    [4.4552064e-5, -0.06541149, 22.738426],
+    [6.2784085e-5, -0.08424564, 27.581444],
+    [7.7862525e-5, -0.09988175, 31.621454],
+    [9.12374e-5, -0.11379754, 35.231667],
+    [0.000104004765, -0.1271152, 38.696926],
+    [0.00011695631, -0.14064819, 42.22454],
+    [0.00013073381, -0.15505762, 45.98352],
+    [0.00014596566, -0.17099217, 50.140175],
+    [0.00016337897, -0.18920289, 54.8873],
+    [0.00018392925, -0.21067673, 60.478386],
+    [0.00020899173, -0.23683357, 67.27816],
+    [0.0002406953, -0.26986974, 75.85063],
+    [0.0002825928, -0.31344807, 87.13623],
+    [0.00034113092, -0.3742097, 102.83967],
+    [0.00042939032, -0.46562183, 126.41781],
+    [-3.2083612e-6, -0.016312858, 10.382166],
+    [2.268918e-5, -0.04301114, 17.222466],
+    [4.322197e-5, -0.064272545, 22.69991],
+    [6.012932e-5, -0.08185515, 27.254143],
+    [7.4716576e-5, -0.09708628, 31.219315],
+    [8.815175e-5, -0.111161694, 34.898632],
+    [0.00010142393, -0.12509844, 38.551266],
+    [0.000115347495, -0.1397367, 42.39215],
+    [0.00013068506, -0.1558647, 46.623463],
+    [0.00014829788, -0.17437348, 51.47415],
+    [0.00016931673, -0.19643235, 57.244736],
+    [0.00019540491, -0.22375989, 64.376785],
+    [0.00022924373, -0.2591237, 73.5811],
+    [0.00027555466, -0.30739126, 86.106964],
+    [0.00034359537, -0.37809622, 104.400406],
+    [0.00045435363, -0.4928315, 134.00008],
+    [-3.7776055e-5, 0.019053467, 1.6369132],
+    [-7.2464272e-6, -0.012473673, 9.721424],
+    [1.6989934e-5, -0.037624016, 16.210302],
+    [3.6920817e-5, -0.058406405, 21.605011],
+    [5.388336e-5, -0.076178245, 26.246197],
+    [6.8924826e-5, -0.09200881, 30.403948],
+    [8.30805e-5, -0.10696247, 34.349186],
+    [9.73963e-5, -0.12211952, 38.35829],
+    [0.00011287663, -0.13852048, 42.698494],
+    [0.00013059661, -0.15728031, 47.65668],
+    [0.00015193466, -0.17982793, 53.600685],
+    [0.00017895031, -0.20829579, 61.079205],
+    [0.00021510581, -0.24626237, 71.0119],
+    [0.00026694607, -0.30048054, 85.13259],
+    [0.00034870784, -0.38561377, 107.20252],
+    [0.000498415, -0.5407662, 147.24673],
+    [-9.384118e-5, 0.076405615, -12.587746],
+    [-5.454591e-5, 0.03579091, -2.1777322],
+    [-2.367542e-5, 0.0037067197, 6.1041393],
+    [1.5090767e-6, -0.02261328, 12.946404],
+    [2.2747288e-5, -0.044934575, 18.791098],
+    [4.1240834e-5, -0.06448282, 23.947018],
+    [5.7919435e-5, -0.082211666, 28.656048],
+    [7.369729e-5, -0.0990657, 33.15978],
+    [8.973242e-5, -0.1162477, 37.767586],
+    [0.00010746015, -0.13525265, 42.865353],
+    [0.00012866319, -0.15793796, 48.933056],
+    [0.00015594861, -0.18701567, 56.671013],
+    [0.00019387397, -0.22721475, 67.29813],
+    [0.00025183646, -0.2882561, 83.31431],
+    [0.0003535645, -0.39461312, 111.00112],
+    [0.0005817376, -0.63132215, 172.15349],
+    [-0.00020940525, 0.19491532, -42.15484],
+    [-0.00014884352, 0.13243553, -26.21422],
+    [-0.00010307804, 0.08489578, -13.975501],
+    [-6.678308e-5, 0.046940167, -4.1177335],
+    [-3.6830595e-5, 0.015398945, 4.148781],
+    [-1.1239005e-5, -0.0117485495, 11.331679],
+    [1.1352899e-5, -0.035900798, 17.785742],
+    [3.196924e-5, -0.0581192, 23.78326],
+    [5.150188e-5, -0.07933835, 29.567305],
+    [7.093896e-5, -0.10060256, 35.412136],
+    [9.191015e-5, -0.12363607, 41.770737],
+    [0.00011759243, -0.1517902, 49.52016],
+    [0.00015392777, -0.19130793, 60.28793],
+    [0.00021432695, -0.2561992, 77.70724],
+    [0.00034109617, -0.39035964, 113.09404],
+    [0.0007858131, -0.85298115, 232.93408],
+    [-0.00065511046, 0.6494062, -154.86368],
+    [-0.00047363844, 0.46588197, -109.49615],
+    [-0.00036404602, 0.3538018, -81.338486],
+    [-0.00028712774, 0.27421662, -61.014908],
+    [-0.00022917801, 0.21361038, -45.30293],
+    [-0.00018356556, 0.16539948, -32.615826],
+    [-0.00014620816, 0.12543333, -21.918432],
+    [-0.000114478506, 0.09098572, -12.510477],
+    [-8.655974e-5, 0.060116902, -3.8730328],
+    [-6.1055056e-5, 0.031262267, 4.439853],
+    [-3.6699206e-5, 0.0028977084, 12.900835],
+    [-1.2033798e-5, -0.026886983, 22.153551],
+    [1.5250106e-5, -0.0613279, 33.35326],
+    [5.022624e-5, -0.10785806, 49.246937],
+    [0.00011133576, -0.19414678, 80.24547],
+    [-0.00090538705, 1.0269499, -253.57063],
+];
@@ -18,6 +18,14 @@ pub const SAMPLED_LAMBDA_END: usize = 700;
 /// Number of spectral samples to use for `SampledSpectrum`.
 pub const SPECTRAL_SAMPLES: usize = 60;
 
+lazy_static! {
+    /// The D65 illuminant normalized to luminance Y = 1.
+    static ref D65_ILLUMINANT: SampledSpectrum = {
+        let d65 = SampledSpectrum::from(&cie_d_illuminant_samples(D65_CCT));
+        d65 / d65.y()
+    };
+}
+
 /// SampledSpectrum represents an spectral power distribution (SPD) with
 /// uniformly spaced samples between a starting and ending wavelength.
 ///
@@ -41,6 +49,29 @@ impl SampledSpectrum {
         assert!(!ret.has_nans());
         ret
     }
+
+    /// Create a new `SampledSpectrum` by evaluating a function at the centre
+    /// wavelength of each sample.
+    ///
+    /// * `f` - Function returning the value at a wavelength in nm.
+    pub fn from_fn<F: Fn(Float) -> Float>(f: F) -> Self {
+        let mut c = [0.0; SPECTRAL_SAMPLES];
+        for (i, ci) in c.iter_mut().enumerate() {
+            *ci = f(Self::sample_wavelength(i));
+        }
+        Self { c }
+    }
+
+    /// Returns the centre wavelength of a sample in nm.
+    ///
+    /// * `i` - Index of the sample.
+    pub fn sample_wavelength(i: usize) -> Float {
+        lerp(
+            (i as Float + 0.5) / SPECTRAL_SAMPLES as Float,
+            SAMPLED_LAMBDA_START as Float,
+            SAMPLED_LAMBDA_END as Float,
+        )
+    }
 }
 
 impl Default for SampledSpectrum {
@@ -92,6 +123,21 @@ impl From<&Vec<Sample>> for SampledSpectrum {
             sort_spectrum_samples(&mut sorted_samples);
         };
 
+        // Samples at the centre wavelengths of our samples, e.g. from a
+        // serialized `ParamSet`, are used as they are.
+        if sorted_samples.len() == SPECTRAL_SAMPLES
+            && sorted_samples
+                .iter()
+                .enumerate()
+                .all(|(i, s)| s.lambda == Self::sample_wavelength(i))
+        {
+            let mut c = [0.0; SPECTRAL_SAMPLES];
+            for (ci, s) in c.iter_mut().zip(sorted_samples.iter()) {
+                *ci = s.value;
+            }
+            return Self { c };
+        }
+
         let mut c = [0.0; SPECTRAL_SAMPLES];
         for i in 0..SPECTRAL_SAMPLES {
             // Compute average value of given SPD over i^th sample's range.
@@ -105,7 +151,7 @@ impl From<&Vec<Sample>> for SampledSpectrum {
                 SAMPLED_LAMBDA_START as Float,
                 SAMPLED_LAMBDA_END as Float,
             );
-            c[i] = average_spectrum_samples(samples, lambda0, lambda1);
+            c[i] = average_spectrum_samples(&sorted_samples, lambda0, lambda1);
         }
 
         Self { c }
@@ -151,56 +197,37 @@ impl CoefficientSpectrum for SampledSpectrum {
     /// Returns the y-coefficient of XYZ colour.
     fn y(&self) -> Float {
         let yy = (0..SPECTRAL_SAMPLES).fold(0.0, |a, i| a + CIE_CURVES.y[i] * self.c[i]);
-        yy * (SAMPLED_LAMBDA_END - SAMPLED_LAMBDA_START) as Float / (SPECTRAL_SAMPLES as Float)
+        yy * (SAMPLED_LAMBDA_END - SAMPLED_LAMBDA_START) as Float
+            / (CIE_Y_INTEGRAL * SPECTRAL_SAMPLES as Float)
     }
 
-    /// Converts RGB values to a full SPD.
+    /// Converts RGB values to a full SPD using sigmoid-polynomial upsampling.
+    /// Reflectances are clamped to [0, 1]; illuminants are scaled and
+    /// multiplied by the D65 illuminant so that RGB (1, 1, 1) is white.
     ///
     /// * `rgb`           - RGB colour value.
     /// * `spectrum_type` - Indicates type of colour value. If `None`,
     ///                     defaults to `SpectrumType::Reflectance`.
-    #[rustfmt::skip]
     fn from_rgb(rgb: &[Float; 3], spectrum_type: Option<SpectrumType>) -> Self {
-        let spectrum_type = spectrum_type.map_or(SpectrumType::Reflectance, |s| s);
-        let mut r = Self::default();
-
-        // Convert spectrum to RGB.
-        if rgb[0] <= rgb[1] && rgb[0] <= rgb[2] {
-            // Compute reflectance `SampledSpectrum` with `rgb[0]` as minimum.
-            r += rgb[0] * RGB_TO_SPECTRUM[spectrum_type][WHITE];
-            if rgb[1] <= rgb[2] {
-                r += (rgb[1] - rgb[0]) * RGB_TO_SPECTRUM[spectrum_type][CYAN];
-                r += (rgb[2] - rgb[1]) * RGB_TO_SPECTRUM[spectrum_type][BLUE];
-            } else {
-                r += (rgb[2] - rgb[0]) * RGB_TO_SPECTRUM[spectrum_type][CYAN];
-                r += (rgb[1] - rgb[2]) * RGB_TO_SPECTRUM[spectrum_type][GREEN];
-            }
-        } else if rgb[1] <= rgb[0] && rgb[1] <= rgb[2] {
-            // Compute reflectance `SampledSpectrum` with 1rgb[1]1 as minimum.
-            r += rgb[1] * RGB_TO_SPECTRUM[spectrum_type][WHITE];
-            if rgb[0] <= rgb[2] {
-                r += (rgb[0] - rgb[1]) * RGB_TO_SPECTRUM[spectrum_type][MAGENTA];
-                r += (rgb[2] - rgb[0]) * RGB_TO_SPECTRUM[spectrum_type][BLUE];
-            } else {
-                r += (rgb[2] - rgb[1]) * RGB_TO_SPECTRUM[spectrum_type][MAGENTA];
-                r += (rgb[0] - rgb[2]) * RGB_TO_SPECTRUM[spectrum_type][RED];
+        // The upsampling table is fitted for sRGB.
+        let rgb = rendering_color_space().convert(rgb, ColorSpace::SRGB);
+
+        match spectrum_type.unwrap_or(SpectrumType::Reflectance) {
+            SpectrumType::Reflectance => {
+                let rsp = RGB_TO_SPECTRUM_TABLE.lookup(&rgb);
+                Self::from_fn(|lambda| rsp.evaluate(lambda))
             }
-        } else {
-            // Compute reflectance `SampledSpectrum` with `rgb[2]` as minimum.
-            r += rgb[2] * RGB_TO_SPECTRUM[spectrum_type][WHITE];
-            if rgb[0] <= rgb[1] {
-                r += (rgb[0] - rgb[2]) * RGB_TO_SPECTRUM[spectrum_type][YELLOW];
-                r += (rgb[1] - rgb[0]) * RGB_TO_SPECTRUM[spectrum_type][GREEN];
-            } else {
-                r += (rgb[1] - rgb[2]) * RGB_TO_SPECTRUM[spectrum_type][YELLOW];
-                r += (rgb[0] - rgb[1]) * RGB_TO_SPECTRUM[spectrum_type][RED];
+            SpectrumType::Illuminant => {
+                let m = max(max(rgb[0], rgb[1]), rgb[2]);
+                if m <= 0.0 {
+                    return Self::new(0.0);
+                }
+                let scale = 2.0 * m;
+                let rsp =
+                    RGB_TO_SPECTRUM_TABLE.lookup(&[rgb[0] / scale, rgb[1] / scale, rgb[2] / scale]);
+                Self::from_fn(|lambda| scale * rsp.evaluate(lambda)) * *D65_ILLUMINANT
             }
         }
-
-        match spectrum_type {
-            SpectrumType::Reflectance => r * 0.94,
-            SpectrumType::Illuminant => r * 0.86445,
-        }
     }
 
     /// Convert the SPD to RGB cooefficients.
@@ -450,3 +477,50 @@ impl fmt::Display for SampledSpectrum {
         write!(f, "]")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn constant_spectrum_y_equals_value() {
+        let s = SampledSpectrum::new(0.5);
+        assert!((s.y() - 0.5).abs() < 0.01);
+    }
+
+    #[test]
+    fn to_xyz_y_matches_y() {
+        let s = SampledSpectrum::new(2.0);
+        assert!((s.to_xyz()[1] - s.y()).abs() < 1e-4);
+    }
+
+    #[test]
+    fn illuminant_rgb_round_trips() {
+        let rgb = [0.8, 0.4, 0.2];
+        let s = SampledSpectrum::from_rgb(&rgb, Some(SpectrumType::Illuminant));
+        let out = s.to_rgb();
+        for i in 0..3 {
+            assert!((out[i] - rgb[i]).abs() < 0.05);
+        }
+    }
+
+    #[test]
+    fn unsorted_samples_match_sorted_samples() {
+        let sorted = vec![
+            Sample {
+                lambda: 400.0,
+                value: 0.0,
+            },
+            Sample {
+                lambda: 700.0,
+                value: 1.0,
+            },
+        ];
+        let unsorted = vec![sorted[1], sorted[0]];
+        let s1 = SampledSpectrum::from(&sorted);
+        let s2 = SampledSpectrum::from(&unsorted);
+        for i in 0..SPECTRAL_SAMPLES {
+            assert!(s1[i] == s2[i]);
+        }
+    }
+}
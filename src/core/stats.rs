@@ -0,0 +1,565 @@
+//! Statistics
+
+#![allow(dead_code)]
+use crate::core::report::json_escape;
+use std::cell::Cell;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::sync::{Mutex, Once};
+use std::thread::LocalKey;
+
+lazy_static! {
+    /// Statistics that have been used by at least one thread.
+    static ref STATS: Mutex<Vec<&'static (dyn StatReporter + Sync)>> = Mutex::new(vec![]);
+
+    /// Accumulates the statistics reported by all threads.
+    static ref ACCUMULATOR: Mutex<StatsAccumulator> = Mutex::new(StatsAccumulator::default());
+}
+
+/// Declares a counter statistic.
+///
+/// The title is of the form "Category/Title" and is used to group statistics
+/// in the report.
+///
+/// ```ignore
+/// stat_counter!("Integrator/Camera rays traced", N_CAMERA_RAYS);
+/// N_CAMERA_RAYS.inc();
+/// ```
+#[macro_export]
+macro_rules! stat_counter {
+    ($title: expr, $name: ident) => {
+        $crate::declare_stat!($title, $name, $crate::core::stats::Counter);
+    };
+}
+
+/// Declares a statistic counting memory in bytes.
+#[macro_export]
+macro_rules! stat_memory_counter {
+    ($title: expr, $name: ident) => {
+        $crate::declare_stat!($title, $name, $crate::core::stats::MemoryCounter);
+    };
+}
+
+/// Declares a statistic tracking the average, minimum and maximum of integer
+/// values.
+#[macro_export]
+macro_rules! stat_int_distribution {
+    ($title: expr, $name: ident) => {
+        $crate::declare_stat!($title, $name, $crate::core::stats::IntDistribution);
+    };
+}
+
+/// Declares a statistic tracking the average, minimum and maximum of floating
+/// point values.
+#[macro_export]
+macro_rules! stat_float_distribution {
+    ($title: expr, $name: ident) => {
+        $crate::declare_stat!($title, $name, $crate::core::stats::FloatDistribution);
+    };
+}
+
+/// Declares a statistic reported as a percentage of a numerator and
+/// denominator.
+#[macro_export]
+macro_rules! stat_percent {
+    ($title: expr, $name: ident) => {
+        $crate::declare_stat!($title, $name, $crate::core::stats::Percent);
+    };
+}
+
+/// Declares a statistic reported as a ratio of a numerator and denominator.
+#[macro_export]
+macro_rules! stat_ratio {
+    ($title: expr, $name: ident) => {
+        $crate::declare_stat!($title, $name, $crate::core::stats::Ratio);
+    };
+}
+
+/// Declares a statistic with per-thread storage. Use the `stat_*` macros
+/// instead.
+#[macro_export]
+macro_rules! declare_stat {
+    ($title: expr, $name: ident, $t: ty) => {
+        static $name: $crate::core::stats::Stat<$t> = {
+            thread_local! {
+                static LOCAL: std::cell::Cell<$t> = std::cell::Cell::new(<$t>::default());
+            }
+            $crate::core::stats::Stat::new($title, &LOCAL)
+        };
+    };
+}
+
+/// A statistic whose values are accumulated per thread and merged into the
+/// report by `report_thread_stats()`.
+pub struct Stat<T: 'static + StatValue> {
+    /// Title of the form "Category/Title".
+    title: &'static str,
+
+    /// The value accumulated by the current thread.
+    local: &'static LocalKey<Cell<T>>,
+
+    /// Used to register the statistic on first use.
+    registered: Once,
+}
+
+impl<T: 'static + StatValue> Stat<T> {
+    /// Create a new `Stat`. Use the `stat_*` macros instead.
+    ///
+    /// * `title` - Title of the form "Category/Title".
+    /// * `local` - The value accumulated by the current thread.
+    pub const fn new(title: &'static str, local: &'static LocalKey<Cell<T>>) -> Self {
+        Self {
+            title,
+            local,
+            registered: Once::new(),
+        }
+    }
+
+    /// Updates the value for the current thread.
+    ///
+    /// * `f` - Function that updates the value.
+    fn update<F: FnOnce(&mut T)>(&'static self, f: F) {
+        self.registered.call_once(|| {
+            STATS
+                .lock()
+                .unwrap()
+                .push(self as &(dyn StatReporter + Sync))
+        });
+        self.local.with(|c| {
+            let mut v = c.get();
+            f(&mut v);
+            c.set(v);
+        });
+    }
+}
+
+impl Stat<Counter> {
+    /// Increments the counter.
+    pub fn inc(&'static self) {
+        self.add(1);
+    }
+
+    /// Adds to the counter.
+    ///
+    /// * `n` - The amount to add.
+    pub fn add(&'static self, n: i64) {
+        self.update(|v| v.0 += n);
+    }
+}
+
+impl Stat<MemoryCounter> {
+    /// Adds to the memory counter.
+    ///
+    /// * `bytes` - The number of bytes to add.
+    pub fn add(&'static self, bytes: i64) {
+        self.update(|v| v.0 += bytes);
+    }
+}
+
+impl Stat<IntDistribution> {
+    /// Adds a value to the distribution.
+    ///
+    /// * `value` - The value.
+    pub fn report_value(&'static self, value: i64) {
+        self.update(|v| {
+            v.min = if v.count == 0 {
+                value
+            } else {
+                v.min.min(value)
+            };
+            v.max = if v.count == 0 {
+                value
+            } else {
+                v.max.max(value)
+            };
+            v.sum += value;
+            v.count += 1;
+        });
+    }
+}
+
+impl Stat<FloatDistribution> {
+    /// Adds a value to the distribution.
+    ///
+    /// * `value` - The value.
+    pub fn report_value(&'static self, value: f64) {
+        self.update(|v| {
+            v.min = if v.count == 0 {
+                value
+            } else {
+                v.min.min(value)
+            };
+            v.max = if v.count == 0 {
+                value
+            } else {
+                v.max.max(value)
+            };
+            v.sum += value;
+            v.count += 1;
+        });
+    }
+}
+
+impl Stat<Percent> {
+    /// Adds to the numerator and denominator.
+    ///
+    /// * `num`   - Amount to add to the numerator.
+    /// * `denom` - Amount to add to the denominator.
+    pub fn add(&'static self, num: i64, denom: i64) {
+        self.update(|v| {
+            v.num += num;
+            v.denom += denom;
+        });
+    }
+}
+
+impl Stat<Ratio> {
+    /// Adds to the numerator and denominator.
+    ///
+    /// * `num`   - Amount to add to the numerator.
+    /// * `denom` - Amount to add to the denominator.
+    pub fn add(&'static self, num: i64, denom: i64) {
+        self.update(|v| {
+            v.num += num;
+            v.denom += denom;
+        });
+    }
+}
+
+/// Interface for merging the values of the current thread into the
+/// accumulator.
+trait StatReporter {
+    /// Merges the value for the current thread into the accumulator and resets
+    /// it.
+    ///
+    /// * `accum` - The accumulator.
+    fn report(&self, accum: &mut StatsAccumulator);
+}
+
+impl<T: 'static + StatValue> StatReporter for Stat<T> {
+    /// Merges the value for the current thread into the accumulator and resets
+    /// it.
+    ///
+    /// * `accum` - The accumulator.
+    fn report(&self, accum: &mut StatsAccumulator) {
+        let v = self.local.with(|c| c.replace(T::default()));
+        v.report(self.title, accum);
+    }
+}
+
+/// Interface for the values of statistics.
+pub trait StatValue: Copy + Default {
+    /// Merges the value into the accumulator.
+    ///
+    /// * `title` - Title of the statistic.
+    /// * `accum` - The accumulator.
+    fn report(&self, title: &'static str, accum: &mut StatsAccumulator);
+}
+
+/// Value of a counter statistic.
+#[derive(Copy, Clone, Default)]
+pub struct Counter(i64);
+
+/// Value of a memory counter statistic in bytes.
+#[derive(Copy, Clone, Default)]
+pub struct MemoryCounter(i64);
+
+/// Value of an integer distribution statistic.
+#[derive(Copy, Clone, Default)]
+pub struct IntDistribution {
+    sum: i64,
+    count: i64,
+    min: i64,
+    max: i64,
+}
+
+/// Value of a floating point distribution statistic.
+#[derive(Copy, Clone, Default)]
+pub struct FloatDistribution {
+    sum: f64,
+    count: i64,
+    min: f64,
+    max: f64,
+}
+
+/// Value of a percentage statistic.
+#[derive(Copy, Clone, Default)]
+pub struct Percent {
+    num: i64,
+    denom: i64,
+}
+
+/// Value of a ratio statistic.
+#[derive(Copy, Clone, Default)]
+pub struct Ratio {
+    num: i64,
+    denom: i64,
+}
+
+impl StatValue for Counter {
+    fn report(&self, title: &'static str, accum: &mut StatsAccumulator) {
+        *accum.counters.entry(title).or_insert(0) += self.0;
+    }
+}
+
+impl StatValue for MemoryCounter {
+    fn report(&self, title: &'static str, accum: &mut StatsAccumulator) {
+        *accum.memory_counters.entry(title).or_insert(0) += self.0;
+    }
+}
+
+impl StatValue for IntDistribution {
+    fn report(&self, title: &'static str, accum: &mut StatsAccumulator) {
+        if self.count > 0 {
+            let d = accum.int_distributions.entry(title).or_insert(Self {
+                min: i64::MAX,
+                max: i64::MIN,
+                ..Self::default()
+            });
+            d.sum += self.sum;
+            d.count += self.count;
+            d.min = d.min.min(self.min);
+            d.max = d.max.max(self.max);
+        }
+    }
+}
+
+impl StatValue for FloatDistribution {
+    fn report(&self, title: &'static str, accum: &mut StatsAccumulator) {
+        if self.count > 0 {
+            let d = accum.float_distributions.entry(title).or_insert(Self {
+                min: f64::INFINITY,
+                max: f64::NEG_INFINITY,
+                ..Self::default()
+            });
+            d.sum += self.sum;
+            d.count += self.count;
+            d.min = d.min.min(self.min);
+            d.max = d.max.max(self.max);
+        }
+    }
+}
+
+impl StatValue for Percent {
+    fn report(&self, title: &'static str, accum: &mut StatsAccumulator) {
+        let p = accum.percentages.entry(title).or_insert((0, 0));
+        p.0 += self.num;
+        p.1 += self.denom;
+    }
+}
+
+impl StatValue for Ratio {
+    fn report(&self, title: &'static str, accum: &mut StatsAccumulator) {
+        let r = accum.ratios.entry(title).or_insert((0, 0));
+        r.0 += self.num;
+        r.1 += self.denom;
+    }
+}
+
+/// Accumulates statistics from all threads.
+#[derive(Default)]
+pub struct StatsAccumulator {
+    counters: BTreeMap<&'static str, i64>,
+    memory_counters: BTreeMap<&'static str, i64>,
+    int_distributions: BTreeMap<&'static str, IntDistribution>,
+    float_distributions: BTreeMap<&'static str, FloatDistribution>,
+    percentages: BTreeMap<&'static str, (i64, i64)>,
+    ratios: BTreeMap<&'static str, (i64, i64)>,
+}
+
+impl StatsAccumulator {
+    /// Returns the report formatted with one section per category.
+    pub fn format(&self) -> String {
+        let mut by_category: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+        let mut add = |title: &'static str, value: String| {
+            let (category, name) = split_title(title);
+            by_category
+                .entry(category)
+                .or_default()
+                .push(format!("{:<42}{}", name, value));
+        };
+
+        for (&title, &v) in self.counters.iter().filter(|(_, &v)| v != 0) {
+            add(title, format!("{:12}", v));
+        }
+        for (&title, &v) in self.memory_counters.iter().filter(|(_, &v)| v != 0) {
+            add(title, format!("{:>12}", format_bytes(v)));
+        }
+        for (&title, d) in self.int_distributions.iter() {
+            let avg = d.sum as f64 / d.count as f64;
+            add(
+                title,
+                format!("{:12.3} avg [range {} - {}]", avg, d.min, d.max),
+            );
+        }
+        for (&title, d) in self.float_distributions.iter() {
+            let avg = d.sum / d.count as f64;
+            add(
+                title,
+                format!("{:12.3} avg [range {:.3} - {:.3}]", avg, d.min, d.max),
+            );
+        }
+        for (&title, &(num, denom)) in self.percentages.iter().filter(|(_, &(_, d))| d != 0) {
+            let p = 100.0 * num as f64 / denom as f64;
+            add(title, format!("{:12} / {:12} ({:.2}%)", num, denom, p));
+        }
+        for (&title, &(num, denom)) in self.ratios.iter().filter(|(_, &(_, d))| d != 0) {
+            let r = num as f64 / denom as f64;
+            add(title, format!("{:12} / {:12} ({:.2}x)", num, denom, r));
+        }
+
+        let mut s = String::from("Statistics:\n");
+        for (category, items) in by_category.iter() {
+            writeln!(s, "  {}", category).unwrap();
+            for item in items.iter() {
+                writeln!(s, "    {}", item).unwrap();
+            }
+        }
+        s
+    }
+
+    /// Returns the statistics as a JSON object keyed by title. Counters and
+    /// memory counters are numbers, distributions have their average, minimum
+    /// and maximum and percentages and ratios their numerator and denominator.
+    pub fn to_json(&self) -> String {
+        let mut items: Vec<String> = vec![];
+        let mut add = |title: &str, value: String| {
+            items.push(format!("\"{}\":{}", json_escape(title), value));
+        };
+
+        for (&title, &v) in self.counters.iter().chain(self.memory_counters.iter()) {
+            add(title, v.to_string());
+        }
+        for (&title, d) in self.int_distributions.iter() {
+            let avg = d.sum as f64 / d.count as f64;
+            add(
+                title,
+                format!("{{\"avg\":{},\"min\":{},\"max\":{}}}", avg, d.min, d.max),
+            );
+        }
+        for (&title, d) in self.float_distributions.iter() {
+            let avg = d.sum / d.count as f64;
+            add(
+                title,
+                format!("{{\"avg\":{},\"min\":{},\"max\":{}}}", avg, d.min, d.max),
+            );
+        }
+        for (&title, &(num, denom)) in self.percentages.iter().chain(self.ratios.iter()) {
+            add(title, format!("{{\"num\":{},\"denom\":{}}}", num, denom));
+        }
+        format!("{{{}}}", items.join(","))
+    }
+}
+
+/// Merges the statistics accumulated by the current thread into the report
+/// and resets them. Worker threads should call this when they finish a unit
+/// of work.
+pub fn report_thread_stats() {
+    let stats = STATS.lock().unwrap().clone();
+    let mut accum = ACCUMULATOR.lock().unwrap();
+    for stat in stats.iter() {
+        stat.report(&mut accum);
+    }
+}
+
+/// Returns the formatted report of the statistics merged so far.
+pub fn stats_report() -> String {
+    ACCUMULATOR.lock().unwrap().format()
+}
+
+/// Returns the statistics merged so far as a JSON object.
+pub fn stats_json() -> String {
+    ACCUMULATOR.lock().unwrap().to_json()
+}
+
+/// Returns the value of a counter merged so far; 0 if it hasn't been used.
+///
+/// * `title` - Title of the counter.
+pub fn counter_value(title: &str) -> i64 {
+    ACCUMULATOR
+        .lock()
+        .unwrap()
+        .counters
+        .get(title)
+        .copied()
+        .unwrap_or(0)
+}
+
+/// Returns the sum of the memory counters merged so far in bytes. This is an
+/// estimate of the memory used by the scene.
+pub fn total_memory() -> i64 {
+    ACCUMULATOR.lock().unwrap().memory_counters.values().sum()
+}
+
+/// Returns the total memory formatted in suitable units.
+pub fn total_memory_report() -> String {
+    format!("Estimated memory: {}", format_bytes(total_memory()))
+}
+
+/// Prints the report of the statistics merged so far to standard output.
+pub fn print_stats() {
+    print!("{}", stats_report());
+}
+
+/// Clears the statistics merged so far.
+pub fn clear_stats() {
+    *ACCUMULATOR.lock().unwrap() = StatsAccumulator::default();
+}
+
+/// Splits a title of the form "Category/Title" into its category and title.
+/// Titles without a category are placed in the "Misc" category.
+///
+/// * `title` - The title.
+fn split_title(title: &str) -> (&str, &str) {
+    match title.find('/') {
+        Some(i) => (&title[..i], &title[i + 1..]),
+        None => ("Misc", title),
+    }
+}
+
+/// Returns a memory size formatted in suitable units.
+///
+/// * `bytes` - The size in bytes.
+pub fn format_bytes(bytes: i64) -> String {
+    let kb = bytes as f64 / 1024.0;
+    if kb.abs() < 1024.0 {
+        format!("{:.2} kB", kb)
+    } else if (kb / 1024.0).abs() < 1024.0 {
+        format!("{:.2} MiB", kb / 1024.0)
+    } else {
+        format!("{:.2} GiB", kb / (1024.0 * 1024.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    stat_counter!("Test/Counter", TEST_COUNTER);
+    stat_int_distribution!("Test/Distribution", TEST_DISTRIBUTION);
+    stat_percent!("Test/Percent", TEST_PERCENT);
+
+    #[test]
+    fn stats_are_merged_from_all_threads() {
+        let threads: Vec<_> = (0..4)
+            .map(|i| {
+                std::thread::spawn(move || {
+                    TEST_COUNTER.add(10);
+                    TEST_DISTRIBUTION.report_value(i);
+                    TEST_PERCENT.add(i % 2, 1);
+                    report_thread_stats();
+                })
+            })
+            .collect();
+        for t in threads {
+            t.join().unwrap();
+        }
+
+        let accum = ACCUMULATOR.lock().unwrap();
+        assert_eq!(accum.counters["Test/Counter"], 40);
+        let d = accum.int_distributions["Test/Distribution"];
+        assert_eq!((d.sum, d.count, d.min, d.max), (6, 4, 0, 3));
+        assert_eq!(accum.percentages["Test/Percent"], (2, 4));
+        assert!(accum.format().contains("Counter"));
+    }
+}
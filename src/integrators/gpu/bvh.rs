@@ -0,0 +1,135 @@
+//! GPU Bounding Volume Hierarchy
+
+use crate::core::geometry::*;
+use bytemuck::{Pod, Zeroable};
+use std::cmp::Ordering;
+
+/// Maximum number of triangles in a leaf node.
+const MAX_TRIANGLES_IN_NODE: usize = 4;
+
+/// Node of a flattened bounding volume hierarchy laid out for a GPU storage
+/// buffer. Nodes are stored in depth-first order so the first child of an
+/// interior node immediately follows it.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, Pod, Zeroable)]
+pub struct GpuBVHNode {
+    /// Minimum corner of the bounding box.
+    pub p_min: [f32; 3],
+
+    /// Offset of the first triangle for leaf nodes; offset of the second
+    /// child for interior nodes.
+    pub offset: u32,
+
+    /// Maximum corner of the bounding box.
+    pub p_max: [f32; 3],
+
+    /// Number of triangles for leaf nodes; 0 for interior nodes.
+    pub n_triangles: u32,
+}
+
+/// Builds a bounding volume hierarchy over triangles by splitting them at the
+/// median centroid along the axis of largest extent. Returns the nodes and
+/// the indices of the triangles in the order referenced by the leaves.
+///
+/// * `bounds` - Bounding boxes of the triangles.
+pub fn build_gpu_bvh(bounds: &[Bounds3f]) -> (Vec<GpuBVHNode>, Vec<usize>) {
+    let mut nodes = vec![];
+    let mut order: Vec<usize> = (0..bounds.len()).collect();
+    if !bounds.is_empty() {
+        build_node(bounds, &mut order, 0, &mut nodes);
+    }
+    (nodes, order)
+}
+
+/// Adds a node for a range of triangles and its children to `nodes`.
+///
+/// * `bounds`  - Bounding boxes of all triangles.
+/// * `indices` - Indices of the triangles in the node; reordered so that
+///               those of the first child come first.
+/// * `start`   - Offset of `indices[0]` in the final triangle order.
+/// * `nodes`   - The nodes.
+fn build_node(
+    bounds: &[Bounds3f],
+    indices: &mut [usize],
+    start: usize,
+    nodes: &mut Vec<GpuBVHNode>,
+) {
+    let node_bounds = indices
+        .iter()
+        .fold(Bounds3f::empty(), |b, &i| b.union(&bounds[i]));
+    let node_index = nodes.len();
+    nodes.push(GpuBVHNode {
+        p_min: [
+            node_bounds.p_min.x as f32,
+            node_bounds.p_min.y as f32,
+            node_bounds.p_min.z as f32,
+        ],
+        offset: start as u32,
+        p_max: [
+            node_bounds.p_max.x as f32,
+            node_bounds.p_max.y as f32,
+            node_bounds.p_max.z as f32,
+        ],
+        n_triangles: indices.len() as u32,
+    });
+    if indices.len() <= MAX_TRIANGLES_IN_NODE {
+        return;
+    }
+
+    // Stop splitting if all centroids coincide.
+    let centroid = |i: usize| bounds[i].p_min + (bounds[i].p_max - bounds[i].p_min) * 0.5;
+    let centroid_bounds = indices
+        .iter()
+        .fold(Bounds3f::empty(), |b, &i| b.union(&centroid(i)));
+    let axis = centroid_bounds.maximum_extent();
+    if centroid_bounds.p_max[axis] == centroid_bounds.p_min[axis] {
+        return;
+    }
+
+    let mid = indices.len() / 2;
+    indices.select_nth_unstable_by(mid, |&a, &b| {
+        centroid(a)[axis]
+            .partial_cmp(&centroid(b)[axis])
+            .unwrap_or(Ordering::Equal)
+    });
+
+    let (left, right) = indices.split_at_mut(mid);
+    build_node(bounds, left, start, nodes);
+    nodes[node_index].offset = nodes.len() as u32;
+    nodes[node_index].n_triangles = 0;
+    build_node(bounds, right, start + mid, nodes);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::pbrt::*;
+
+    #[test]
+    fn leaves_reference_each_triangle_once_inside_node_bounds() {
+        let bounds: Vec<Bounds3f> = (0..37)
+            .map(|i| {
+                let p = Point3f::new(i as Float, ((i * 7) % 5) as Float, ((i * 3) % 11) as Float);
+                Bounds3f::new(p, p + Vector3f::new(0.5, 0.5, 0.5))
+            })
+            .collect();
+        let (nodes, order) = build_gpu_bvh(&bounds);
+
+        let mut seen = vec![0; bounds.len()];
+        for node in nodes.iter().filter(|n| n.n_triangles > 0) {
+            let start = node.offset as usize;
+            for &i in &order[start..start + node.n_triangles as usize] {
+                seen[i] += 1;
+                let b = &bounds[i];
+                for axis in 0..3 {
+                    assert!(node.p_min[axis] <= b.p_min[axis] as f32);
+                    assert!(node.p_max[axis] >= b.p_max[axis] as f32);
+                }
+            }
+        }
+        assert!(seen.iter().all(|&n| n == 1));
+        assert!(nodes
+            .iter()
+            .all(|n| n.n_triangles as usize <= MAX_TRIANGLES_IN_NODE));
+    }
+}
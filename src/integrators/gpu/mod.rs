@@ -0,0 +1,389 @@
+//! GPU Integrator
+
+#![allow(dead_code)]
+mod bvh;
+mod scene;
+
+use crate::core::camera::*;
+use crate::core::cancel::*;
+use crate::core::error::*;
+use crate::core::geometry::*;
+use crate::core::integrator::*;
+use crate::core::logging::INTEGRATOR;
+use crate::core::paramset::*;
+use crate::core::pbrt::*;
+use crate::core::rng::*;
+use crate::core::sampler::*;
+use crate::core::scene::*;
+use crate::core::spectrum::*;
+use bytemuck::{Pod, Zeroable};
+use std::sync::Arc;
+use wgpu::util::DeviceExt;
+
+// Re-export.
+pub use scene::*;
+
+/// Width and height of the compute shader workgroups.
+const WORKGROUP_SIZE: u32 = 8;
+
+/// Parameters of a pass laid out for a GPU uniform buffer.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, Pod, Zeroable)]
+struct GpuParams {
+    /// Width of the image in pixels.
+    width: u32,
+
+    /// Height of the image in pixels.
+    height: u32,
+
+    /// Number of point and distant lights.
+    n_lights: u32,
+
+    /// Maximum length of a path.
+    max_depth: u32,
+
+    /// Index of the pass; used to seed random numbers.
+    pass_index: u32,
+
+    /// Number of BVH nodes.
+    n_nodes: u32,
+
+    /// Padding required for the alignment of `environment`.
+    pad: [u32; 2],
+
+    /// RGB radiance of rays that leave the scene; the last component is unused.
+    environment: [f32; 4],
+}
+
+/// Camera ray laid out for a GPU storage buffer.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, Pod, Zeroable)]
+struct GpuCameraRay {
+    /// Origin; the last component is the weight of the ray.
+    o: [f32; 4],
+
+    /// Direction; the last component is unused.
+    d: [f32; 4],
+}
+
+/// Experimental integrator that traces paths in a wgpu compute shader. It is
+/// meant for quick previews: only triangles are rendered, every surface is
+/// diffuse with the reflectance of its material baked at the vertices, area
+/// lights are only found by hitting them and infinite lights are replaced
+/// by their average radiance.
+pub struct GpuIntegrator {
+    /// The camera.
+    camera: ArcCamera,
+
+    /// Number of passes; one camera ray per pixel is traced in each pass.
+    samples_per_pixel: usize,
+
+    /// Maximum length of a path.
+    max_depth: usize,
+}
+
+impl GpuIntegrator {
+    /// Create a new `GpuIntegrator`.
+    ///
+    /// * `max_depth`         - Maximum length of a path.
+    /// * `camera`            - The camera.
+    /// * `samples_per_pixel` - Number of samples per pixel.
+    pub fn new(max_depth: usize, camera: ArcCamera, samples_per_pixel: usize) -> Self {
+        Self {
+            camera,
+            samples_per_pixel,
+            max_depth,
+        }
+    }
+}
+
+impl Integrator for GpuIntegrator {
+    /// Render the scene.
+    ///
+    /// * `scene`     - The scene.
+    /// * `cancel`    - Token used to stop rendering early.
+    /// * `observers` - Observers notified of rendering progress.
+    fn render(
+        &mut self,
+        scene: Arc<Scene>,
+        cancel: &CancellationToken,
+        observers: &[ArcRenderObserver],
+    ) -> Result<(), Error> {
+        let gpu_scene = GpuScene::from(&*scene);
+        if gpu_scene.triangles.is_empty() {
+            warn!(
+                target: INTEGRATOR,
+                "Scene has no triangles. GPU rendering only supports triangle meshes."
+            );
+        }
+        info!(
+            target: INTEGRATOR,
+            "Uploading {} triangles, {} BVH nodes and {} lights to the GPU.",
+            gpu_scene.triangles.len(),
+            gpu_scene.nodes.len(),
+            gpu_scene.lights.len()
+        );
+
+        let (device, queue) = pollster::block_on(request_device())?;
+
+        let film = self.camera.get_data().film.clone();
+        let bounds = film.cropped_pixel_bounds;
+        let extent = bounds.diagonal();
+        let (width, height) = (extent.x as u32, extent.y as u32);
+        let n_pixels = (width * height) as usize;
+
+        for observer in observers {
+            observer.render_started(&bounds);
+        }
+
+        // Upload the scene. Bindings can't be empty so pad with one element.
+        let storage_buffer = |label: &str, contents: &[u8]| {
+            device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some(label),
+                contents,
+                usage: wgpu::BufferUsages::STORAGE,
+            })
+        };
+        let nodes = padded(&gpu_scene.nodes);
+        let triangles = padded(&gpu_scene.triangles);
+        let lights = padded(&gpu_scene.lights);
+        let node_buffer = storage_buffer("BVH nodes", bytemuck::cast_slice(&nodes));
+        let triangle_buffer = storage_buffer("Triangles", bytemuck::cast_slice(&triangles));
+        let light_buffer = storage_buffer("Lights", bytemuck::cast_slice(&lights));
+
+        let mut params = GpuParams {
+            width,
+            height,
+            n_lights: gpu_scene.lights.len() as u32,
+            max_depth: self.max_depth as u32,
+            pass_index: 0,
+            n_nodes: gpu_scene.nodes.len() as u32,
+            pad: [0; 2],
+            environment: [
+                gpu_scene.environment[0],
+                gpu_scene.environment[1],
+                gpu_scene.environment[2],
+                0.0,
+            ],
+        };
+        let params_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Parameters"),
+            size: std::mem::size_of::<GpuParams>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let ray_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Camera rays"),
+            size: (n_pixels.max(1) * std::mem::size_of::<GpuCameraRay>()) as u64,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let accumulated_size = (n_pixels.max(1) * 4 * std::mem::size_of::<f32>()) as u64;
+        let accumulated_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Accumulated radiance"),
+            size: accumulated_size,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+        let readback_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Readback"),
+            size: accumulated_size,
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Path tracer"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("path_tracer.wgsl").into()),
+        });
+        let pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("Path tracer"),
+            layout: None,
+            module: &module,
+            entry_point: "main",
+        });
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Path tracer"),
+            layout: &pipeline.get_bind_group_layout(0),
+            entries: &[
+                (0, &params_buffer),
+                (1, &node_buffer),
+                (2, &triangle_buffer),
+                (3, &light_buffer),
+                (4, &ray_buffer),
+                (5, &accumulated_buffer),
+            ]
+            .iter()
+            .map(|&(binding, buffer)| wgpu::BindGroupEntry {
+                binding,
+                resource: buffer.as_entire_binding(),
+            })
+            .collect::<Vec<_>>(),
+        });
+
+        // Trace one camera ray per pixel in each pass. Camera rays are
+        // generated on the CPU so that every camera model is supported.
+        let mut rng = RNG::new(0);
+        let mut rays = vec![GpuCameraRay::default(); n_pixels];
+        for pass in 0..self.samples_per_pixel {
+            if cancel.is_cancelled() {
+                break;
+            }
+
+            for y in 0..height as i32 {
+                for x in 0..width as i32 {
+                    let jitter = Point2f::new(rng.uniform(), rng.uniform());
+                    let p_film = Point2f::new(
+                        (bounds.p_min.x + x) as Float + jitter.x,
+                        (bounds.p_min.y + y) as Float + jitter.y,
+                    );
+                    let p_lens = Point2f::new(rng.uniform(), rng.uniform());
+                    let sample = CameraSample::new(p_film, p_lens, rng.uniform());
+                    let (ray, weight) = self.camera.generate_ray(&sample);
+                    rays[(y * width as i32 + x) as usize] = GpuCameraRay {
+                        o: [
+                            ray.o.x as f32,
+                            ray.o.y as f32,
+                            ray.o.z as f32,
+                            weight as f32,
+                        ],
+                        d: [ray.d.x as f32, ray.d.y as f32, ray.d.z as f32, 0.0],
+                    };
+                }
+            }
+            queue.write_buffer(&ray_buffer, 0, bytemuck::cast_slice(&rays));
+
+            params.pass_index = pass as u32;
+            queue.write_buffer(&params_buffer, 0, bytemuck::bytes_of(&params));
+
+            let mut encoder = device.create_command_encoder(&Default::default());
+            {
+                let mut compute_pass = encoder.begin_compute_pass(&Default::default());
+                compute_pass.set_pipeline(&pipeline);
+                compute_pass.set_bind_group(0, &bind_group, &[]);
+                compute_pass.dispatch_workgroups(
+                    width.div_ceil(WORKGROUP_SIZE),
+                    height.div_ceil(WORKGROUP_SIZE),
+                    1,
+                );
+            }
+            queue.submit(Some(encoder.finish()));
+            device.poll(wgpu::Maintain::Wait);
+
+            for observer in observers {
+                observer.pass_finished(pass);
+            }
+        }
+
+        // Read the accumulated radiance back into the film. The last
+        // component counts the samples of the pixel.
+        let mut encoder = device.create_command_encoder(&Default::default());
+        encoder.copy_buffer_to_buffer(
+            &accumulated_buffer,
+            0,
+            &readback_buffer,
+            0,
+            accumulated_size,
+        );
+        queue.submit(Some(encoder.finish()));
+        let slice = readback_buffer.slice(..);
+        slice.map_async(wgpu::MapMode::Read, |_| {});
+        device.poll(wgpu::Maintain::Wait);
+        let image: Vec<Spectrum> = {
+            let data = slice.get_mapped_range();
+            let accumulated: &[[f32; 4]] = bytemuck::cast_slice(&data);
+            accumulated[..n_pixels]
+                .iter()
+                .map(|v| {
+                    let n = v[3].max(1.0);
+                    let rgb = [
+                        (v[0] / n) as Float,
+                        (v[1] / n) as Float,
+                        (v[2] / n) as Float,
+                    ];
+                    Spectrum::from_rgb(&rgb, Some(SpectrumType::Illuminant))
+                })
+                .collect()
+        };
+        readback_buffer.unmap();
+        film.set_image(&image);
+
+        // The whole image is reported as a single tile.
+        if !observers.is_empty() {
+            let rgb = film.get_rgb(&bounds, 1.0);
+            for observer in observers {
+                observer.tile_finished(&bounds, &rgb);
+            }
+        }
+
+        if cancel.is_cancelled() {
+            warn!(target: INTEGRATOR, "Rendering cancelled. Writing partial image.");
+        } else {
+            info!(target: INTEGRATOR, "Rendering finished.");
+        }
+        let result = film.write_image(1.0);
+        if result.is_ok() {
+            info!(target: INTEGRATOR, "Output image written.");
+        }
+        for observer in observers {
+            observer.render_finished(cancel.is_cancelled());
+        }
+        result
+    }
+}
+
+impl From<(&ParamSet, ArcSampler, ArcCamera)> for GpuIntegrator {
+    /// Create a `GpuIntegrator` from given parameter set, sampler and camera.
+    ///
+    /// * `p` - A tuple containing parameter set, sampler and camera.
+    fn from(p: (&ParamSet, ArcSampler, ArcCamera)) -> Self {
+        let (params, sampler, camera) = p;
+
+        let max_depth = params.find_one_int("maxdepth", 5) as usize;
+
+        let mut sampler = Sampler::clone(&*sampler, 0);
+        let samples_per_pixel = Arc::get_mut(&mut sampler)
+            .unwrap()
+            .get_data()
+            .samples_per_pixel;
+
+        Self::new(max_depth, camera, samples_per_pixel)
+    }
+}
+
+/// Returns a device and its queue from the first available adapter.
+async fn request_device() -> Result<(wgpu::Device, wgpu::Queue), Error> {
+    let instance = wgpu::Instance::default();
+    let adapter = instance
+        .request_adapter(&wgpu::RequestAdapterOptions {
+            power_preference: wgpu::PowerPreference::HighPerformance,
+            force_fallback_adapter: false,
+            compatible_surface: None,
+        })
+        .await
+        .ok_or_else(|| Error::Unsupported(String::from("No GPU adapter found.")))?;
+    info!(target: INTEGRATOR, "Using GPU adapter '{}'.", adapter.get_info().name);
+
+    adapter
+        .request_device(
+            &wgpu::DeviceDescriptor {
+                label: None,
+                required_features: wgpu::Features::empty(),
+                required_limits: adapter.limits(),
+            },
+            None,
+        )
+        .await
+        .map_err(|err| Error::Unsupported(format!("Unable to create GPU device. {}", err)))
+}
+
+/// Returns a copy of a slice with one default element if it is empty.
+///
+/// * `v` - The slice.
+fn padded<T: Copy + Default>(v: &[T]) -> Vec<T> {
+    if v.is_empty() {
+        vec![T::default()]
+    } else {
+        v.to_vec()
+    }
+}
@@ -0,0 +1,225 @@
+// Path tracing kernel of the GPU backend. Each invocation traces one camera
+// ray of the current pass and adds its radiance to the pixel's accumulator.
+// Surfaces are diffuse with reflectance interpolated from the vertices.
+
+struct Params {
+    width: u32,
+    height: u32,
+    n_lights: u32,
+    max_depth: u32,
+    pass_index: u32,
+    n_nodes: u32,
+    pad0: u32,
+    pad1: u32,
+    environment: vec4<f32>,
+}
+
+struct Node {
+    p_min: vec3<f32>,
+    offset: u32,
+    p_max: vec3<f32>,
+    n_triangles: u32,
+}
+
+struct Triangle {
+    p0: vec4<f32>,
+    p1: vec4<f32>,
+    p2: vec4<f32>,
+    albedo0: vec4<f32>,
+    albedo1: vec4<f32>,
+    albedo2: vec4<f32>,
+    emission: vec4<f32>,
+}
+
+struct Light {
+    p: vec4<f32>,
+    l: vec4<f32>,
+}
+
+// Camera ray; `o.w` holds the weight of the ray.
+struct CameraRay {
+    o: vec4<f32>,
+    d: vec4<f32>,
+}
+
+struct Hit {
+    t: f32,
+    triangle: u32,
+    b1: f32,
+    b2: f32,
+}
+
+const PI: f32 = 3.14159265358979;
+const NO_HIT: u32 = 0xffffffffu;
+
+@group(0) @binding(0) var<uniform> params: Params;
+@group(0) @binding(1) var<storage, read> nodes: array<Node>;
+@group(0) @binding(2) var<storage, read> triangles: array<Triangle>;
+@group(0) @binding(3) var<storage, read> lights: array<Light>;
+@group(0) @binding(4) var<storage, read> rays: array<CameraRay>;
+@group(0) @binding(5) var<storage, read_write> accumulated: array<vec4<f32>>;
+
+var<private> rng_state: u32;
+
+// PCG hash based random number generator.
+fn next_random() -> f32 {
+    rng_state = rng_state * 747796405u + 2891336453u;
+    var word = ((rng_state >> ((rng_state >> 28u) + 4u)) ^ rng_state) * 277803737u;
+    word = (word >> 22u) ^ word;
+    return f32(word >> 8u) / 16777216.0;
+}
+
+fn intersect_box(node: Node, o: vec3<f32>, inv_d: vec3<f32>, t_max: f32) -> bool {
+    let t0 = (node.p_min - o) * inv_d;
+    let t1 = (node.p_max - o) * inv_d;
+    let t_near = max(max(min(t0.x, t1.x), min(t0.y, t1.y)), min(t0.z, t1.z));
+    let t_far = min(min(max(t0.x, t1.x), max(t0.y, t1.y)), max(t0.z, t1.z));
+    return t_near <= t_far * 1.00000024 && t_far > 0.0 && t_near < t_max;
+}
+
+// Moller-Trumbore ray-triangle intersection. Returns t and the barycentric
+// coordinates of the second and third vertex; t < 0 if there is no hit.
+fn intersect_triangle(tri: Triangle, o: vec3<f32>, d: vec3<f32>) -> vec3<f32> {
+    let e1 = tri.p1.xyz - tri.p0.xyz;
+    let e2 = tri.p2.xyz - tri.p0.xyz;
+    let pv = cross(d, e2);
+    let det = dot(e1, pv);
+    if abs(det) < 1e-12 {
+        return vec3<f32>(-1.0, 0.0, 0.0);
+    }
+    let inv_det = 1.0 / det;
+    let tv = o - tri.p0.xyz;
+    let b1 = dot(tv, pv) * inv_det;
+    if b1 < 0.0 || b1 > 1.0 {
+        return vec3<f32>(-1.0, 0.0, 0.0);
+    }
+    let qv = cross(tv, e1);
+    let b2 = dot(d, qv) * inv_det;
+    if b2 < 0.0 || b1 + b2 > 1.0 {
+        return vec3<f32>(-1.0, 0.0, 0.0);
+    }
+    return vec3<f32>(dot(e2, qv) * inv_det, b1, b2);
+}
+
+// Returns the closest intersection along the ray, or one with t <= t_max if
+// `any_hit` is true.
+fn intersect(o: vec3<f32>, d: vec3<f32>, t_max: f32, any_hit: bool) -> Hit {
+    var hit = Hit(t_max, NO_HIT, 0.0, 0.0);
+    if params.n_nodes == 0u {
+        return hit;
+    }
+
+    let inv_d = 1.0 / d;
+    var stack: array<u32, 64>;
+    var stack_size = 1u;
+    stack[0] = 0u;
+    while stack_size > 0u {
+        stack_size -= 1u;
+        let index = stack[stack_size];
+        let node = nodes[index];
+        if !intersect_box(node, o, inv_d, hit.t) {
+            continue;
+        }
+        if node.n_triangles > 0u {
+            for (var i = node.offset; i < node.offset + node.n_triangles; i++) {
+                let r = intersect_triangle(triangles[i], o, d);
+                if r.x > 0.0 && r.x < hit.t {
+                    hit = Hit(r.x, i, r.y, r.z);
+                    if any_hit {
+                        return hit;
+                    }
+                }
+            }
+        } else if stack_size < 63u {
+            // The first child immediately follows its parent.
+            stack[stack_size] = node.offset;
+            stack[stack_size + 1u] = index + 1u;
+            stack_size += 2u;
+        }
+    }
+    return hit;
+}
+
+// Returns a cosine weighted direction in the hemisphere around `n`.
+fn sample_cosine_hemisphere(n: vec3<f32>) -> vec3<f32> {
+    let u1 = next_random();
+    let u2 = next_random();
+    let r = sqrt(u1);
+    let phi = 2.0 * PI * u2;
+    var s = vec3<f32>(1.0, 0.0, 0.0);
+    if abs(n.x) > 0.9 {
+        s = vec3<f32>(0.0, 1.0, 0.0);
+    }
+    let t = normalize(cross(n, s));
+    let b = cross(n, t);
+    return normalize(t * (r * cos(phi)) + b * (r * sin(phi)) + n * sqrt(max(0.0, 1.0 - u1)));
+}
+
+@compute @workgroup_size(8, 8)
+fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+    if id.x >= params.width || id.y >= params.height {
+        return;
+    }
+    let pixel = id.y * params.width + id.x;
+    rng_state = pixel * 9781u + params.pass_index * 6271u + 1u;
+    let ray = rays[pixel];
+    let weight = ray.o.w;
+    if weight <= 0.0 {
+        accumulated[pixel] += vec4<f32>(0.0, 0.0, 0.0, 1.0);
+        return;
+    }
+
+    var o = ray.o.xyz;
+    var d = normalize(ray.d.xyz);
+    var beta = vec3<f32>(1.0);
+    var l = vec3<f32>(0.0);
+    for (var depth = 0u; depth < params.max_depth; depth++) {
+        let hit = intersect(o, d, 3.0e38, false);
+        if hit.triangle == NO_HIT {
+            l += beta * params.environment.xyz;
+            break;
+        }
+
+        let tri = triangles[hit.triangle];
+        l += beta * tri.emission.xyz;
+
+        var n = normalize(cross(tri.p1.xyz - tri.p0.xyz, tri.p2.xyz - tri.p0.xyz));
+        if dot(n, d) > 0.0 {
+            n = -n;
+        }
+        let b0 = 1.0 - hit.b1 - hit.b2;
+        let albedo = b0 * tri.albedo0.xyz + hit.b1 * tri.albedo1.xyz + hit.b2 * tri.albedo2.xyz;
+        let p_hit = o + d * hit.t;
+        let p = p_hit + n * (1e-4 * max(1.0, max(abs(p_hit.x), max(abs(p_hit.y), abs(p_hit.z)))));
+
+        // Direct lighting from point and distant lights.
+        for (var i = 0u; i < params.n_lights; i++) {
+            let light = lights[i];
+            var wi = light.p.xyz;
+            var li = light.l.xyz;
+            var dist = 3.0e38;
+            if light.p.w > 0.5 {
+                wi = light.p.xyz - p;
+                let d2 = dot(wi, wi);
+                dist = sqrt(d2);
+                wi = wi / dist;
+                li = li / d2;
+            }
+            let cos_theta = dot(n, wi);
+            if cos_theta > 0.0 && intersect(p, wi, dist, true).triangle == NO_HIT {
+                l += beta * albedo / PI * li * cos_theta;
+            }
+        }
+
+        // Continue the path in a cosine weighted direction; the cosine and
+        // PDF cancel out for diffuse surfaces.
+        beta *= albedo;
+        if max(beta.x, max(beta.y, beta.z)) <= 0.0 {
+            break;
+        }
+        o = p;
+        d = sample_cosine_hemisphere(n);
+    }
+
+    accumulated[pixel] += vec4<f32>(l * weight, 1.0);
+}
@@ -0,0 +1,213 @@
+//! GPU Scene
+
+use super::bvh::*;
+use crate::core::geometry::*;
+use crate::core::light::*;
+use crate::core::material::*;
+use crate::core::pbrt::*;
+use crate::core::reflection::*;
+use crate::core::scene::*;
+use crate::core::spectrum::*;
+use bytemuck::{Pod, Zeroable};
+
+/// Triangle with baked material properties laid out for a GPU storage buffer.
+/// The last component of each array is unused.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, Pod, Zeroable)]
+pub struct GpuTriangle {
+    /// Vertex positions in world space.
+    pub p: [[f32; 4]; 3],
+
+    /// Diffuse RGB reflectance at the vertices.
+    pub albedo: [[f32; 4]; 3],
+
+    /// Emitted RGB radiance.
+    pub emission: [f32; 4],
+}
+
+/// Delta light laid out for a GPU storage buffer.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, Pod, Zeroable)]
+pub struct GpuLight {
+    /// Position of a point light or direction towards a distant light. The
+    /// last component is 1 for point lights and 0 for distant lights.
+    pub p: [f32; 4],
+
+    /// RGB intensity of a point light or radiance of a distant light. The last
+    /// component is unused.
+    pub l: [f32; 4],
+}
+
+/// Scene data uploaded to the GPU. Shapes other than triangles are skipped
+/// and every surface is treated as diffuse with the reflectance of its
+/// material baked at the triangle vertices.
+pub struct GpuScene {
+    /// Nodes of the bounding volume hierarchy over `triangles`.
+    pub nodes: Vec<GpuBVHNode>,
+
+    /// Triangles in the order referenced by the leaf nodes.
+    pub triangles: Vec<GpuTriangle>,
+
+    /// Point and distant lights.
+    pub lights: Vec<GpuLight>,
+
+    /// Average RGB radiance of the infinite lights.
+    pub environment: [f32; 3],
+}
+
+impl From<&Scene> for GpuScene {
+    /// Create a `GpuScene` from a scene.
+    ///
+    /// * `scene` - The scene.
+    fn from(scene: &Scene) -> Self {
+        let vertices = scene.aggregate.triangles();
+        let bounds: Vec<Bounds3f> = vertices
+            .iter()
+            .map(|v| Bounds3f::new(v[0], v[1]).union(&v[2]))
+            .collect();
+        let world_bound = bounds.iter().fold(Bounds3f::empty(), |b, tb| b.union(tb));
+        let eps = if vertices.is_empty() {
+            0.0
+        } else {
+            1e-4 * world_bound.diagonal().length().max(1.0)
+        };
+
+        let (nodes, order) = build_gpu_bvh(&bounds);
+        let triangles = order
+            .iter()
+            .map(|&i| {
+                let [p0, p1, p2] = vertices[i];
+                let n = (p1 - p0).cross(&(p2 - p0));
+                let n = if n.length_squared() > 0.0 {
+                    n.normalize()
+                } else {
+                    n
+                };
+
+                // Bake the material slightly inside the triangle at each
+                // vertex so the intersection lands on this triangle.
+                let mut albedo = [[0.0; 4]; 3];
+                let mut emission = Spectrum::new(0.0);
+                for (j, p) in [p0, p1, p2].iter().enumerate() {
+                    let q = *p + ((p0 - *p) + (p1 - *p) + (p2 - *p)) * 0.1;
+                    let (rho, le) = bake_surface(scene, &q, &n, eps);
+                    albedo[j] = rgba(&rho);
+                    emission += le / 3.0;
+                }
+
+                GpuTriangle {
+                    p: [point_rgba(&p0), point_rgba(&p1), point_rgba(&p2)],
+                    albedo,
+                    emission: rgba(&emission),
+                }
+            })
+            .collect();
+
+        // Evaluate lights from the center of the scene.
+        let center = world_bound.p_min + world_bound.diagonal() * 0.5;
+        let center = if vertices.is_empty() {
+            Point3f::default()
+        } else {
+            center
+        };
+        let hit = Hit::new(
+            center,
+            0.0,
+            Vector3f::default(),
+            Vector3f::default(),
+            Normal3f::default(),
+            None,
+        );
+        let mut lights = vec![];
+        let mut environment = Spectrum::new(0.0);
+        for light in scene.lights.iter() {
+            let light_type = light.get_type();
+            if light_type.matches(DELTA_DIRECTION_LIGHT) {
+                let li = light.sample_li(&hit, &Point2f::new(0.5, 0.5));
+                lights.push(GpuLight {
+                    p: [li.wi.x as f32, li.wi.y as f32, li.wi.z as f32, 0.0],
+                    l: rgba(&li.value),
+                });
+            } else if light_type.matches(DELTA_POSITION_LIGHT) {
+                let li = light.sample_li(&hit, &Point2f::new(0.5, 0.5));
+                if let Some(vis) = li.visibility {
+                    let mut p = point_rgba(&vis.p1);
+                    p[3] = 1.0;
+                    let d2 = vis.p1.distance_squared(center);
+                    lights.push(GpuLight {
+                        p,
+                        l: rgba(&(li.value * d2)),
+                    });
+                }
+            } else if light_type.matches(INFINITE_LIGHT) {
+                // Average the radiance over a grid of sample directions.
+                const N: usize = 8;
+                for y in 0..N {
+                    for x in 0..N {
+                        let u = Point2f::new(
+                            (x as Float + 0.5) / N as Float,
+                            (y as Float + 0.5) / N as Float,
+                        );
+                        environment += light.sample_li(&hit, &u).value / (N * N) as Float;
+                    }
+                }
+            }
+        }
+        let environment = rgba(&environment);
+
+        Self {
+            nodes,
+            triangles,
+            lights,
+            environment: [environment[0], environment[1], environment[2]],
+        }
+    }
+}
+
+/// Returns the hemispherical-directional reflectance and emitted radiance of
+/// the surface at a point, looking at it from either side.
+///
+/// * `scene` - The scene.
+/// * `p`     - The point.
+/// * `n`     - Normal of the surface at `p`.
+/// * `eps`   - Distance from the surface at which rays start.
+fn bake_surface(scene: &Scene, p: &Point3f, n: &Vector3f, eps: Float) -> (Spectrum, Spectrum) {
+    // Stratified samples for estimating the reflectance.
+    const N: usize = 4;
+    let u: Vec<Point2f> = (0..N * N)
+        .map(|i| {
+            Point2f::new(
+                ((i % N) as Float + 0.5) / N as Float,
+                ((i / N) as Float + 0.5) / N as Float,
+            )
+        })
+        .collect();
+
+    for side in [1.0, -1.0].iter() {
+        let mut ray = Ray::new(*p + *n * (*side * eps), -*n * *side, 2.0 * eps, 0.0, None);
+        if let Some(mut isect) = scene.intersect(&mut ray) {
+            let wo = isect.hit.wo;
+            isect.compute_scattering_functions(&ray, false, TransportMode::Radiance);
+            let rho = isect.bsdf.as_ref().map_or(Spectrum::new(0.0), |bsdf| {
+                bsdf.rho_hd(&wo, &u, BxDFType::from(BSDF_ALL))
+            });
+            return (rho, isect.le(&wo));
+        }
+    }
+    (Spectrum::new(0.5), Spectrum::new(0.0))
+}
+
+/// Returns the RGB components of a spectrum and 0.
+///
+/// * `s` - The spectrum.
+fn rgba(s: &Spectrum) -> [f32; 4] {
+    let rgb = s.to_rgb();
+    [rgb[0] as f32, rgb[1] as f32, rgb[2] as f32, 0.0]
+}
+
+/// Returns the coordinates of a point and 0.
+///
+/// * `p` - The point.
+fn point_rgba(p: &Point3f) -> [f32; 4] {
+    [p.x as f32, p.y as f32, p.z as f32, 0.0]
+}
@@ -0,0 +1,773 @@
+//! Metropolis Light Transport Integrator
+
+#![allow(dead_code)]
+
+use crate::core::app::OPTIONS;
+use crate::core::arena::*;
+use crate::core::camera::*;
+use crate::core::cancel::*;
+use crate::core::error::*;
+use crate::core::geometry::*;
+use crate::core::integrator::*;
+use crate::core::logging::INTEGRATOR;
+use crate::core::parallel::*;
+use crate::core::paramset::*;
+use crate::core::pbrt::*;
+use crate::core::rng::*;
+use crate::core::sampler::*;
+use crate::core::sampling::*;
+use crate::core::scene::*;
+use crate::core::spectrum::*;
+use crate::integrators::VolPathIntegrator;
+use std::sync::{Arc, Mutex};
+
+/// Index of the sample stream used to generate camera rays.
+const CAMERA_STREAM_INDEX: usize = 0;
+
+/// Index of the sample stream used to trace paths from the camera ray.
+const PATH_STREAM_INDEX: usize = 1;
+
+/// Number of sample streams.
+const N_SAMPLE_STREAMS: usize = 2;
+
+/// Number of mutations each Markov chain runs between merges of the splats
+/// of all chains with `--deterministic`.
+const MUTATIONS_PER_BATCH: u64 = 4096;
+
+/// A sample value in primary sample space along with the values needed to
+/// undo a rejected mutation.
+#[derive(Copy, Clone, Default)]
+struct PrimarySample {
+    /// The sample value in [0, 1).
+    value: Float,
+
+    /// Iteration in which `value` was last modified.
+    last_modification_iteration: u64,
+
+    /// `value` before the current mutation.
+    value_backup: Float,
+
+    /// `last_modification_iteration` before the current mutation.
+    modify_backup: u64,
+}
+
+impl PrimarySample {
+    /// Saves the sample before it is mutated.
+    fn backup(&mut self) {
+        self.value_backup = self.value;
+        self.modify_backup = self.last_modification_iteration;
+    }
+
+    /// Restores the sample saved by `backup()`.
+    fn restore(&mut self) {
+        self.value = self.value_backup;
+        self.last_modification_iteration = self.modify_backup;
+    }
+}
+
+/// Generates the sample values of a Markov chain in primary sample space.
+/// Each iteration either replaces all sample values with new random ones, a
+/// large step, or perturbs them slightly, a small step. Sample values are
+/// mutated lazily when they are requested.
+///
+/// Sample values are interleaved from independent streams so that the
+/// values used for one purpose don't shift when another consumes a
+/// different number of them.
+pub struct MLTSampler {
+    /// The random number generator.
+    rng: RNG,
+
+    /// Standard deviation of the perturbation of small steps.
+    sigma: Float,
+
+    /// Probability of taking a large step.
+    large_step_probability: Float,
+
+    /// Number of sample streams.
+    stream_count: usize,
+
+    /// The sample vector.
+    x: Vec<PrimarySample>,
+
+    /// The current iteration.
+    current_iteration: u64,
+
+    /// Whether the current iteration is a large step.
+    large_step: bool,
+
+    /// The last iteration that took a large step and was accepted.
+    last_large_step_iteration: u64,
+
+    /// The current stream.
+    stream_index: usize,
+
+    /// Index of the next sample value in the current stream.
+    sample_index: usize,
+}
+
+impl MLTSampler {
+    /// Create a new `MLTSampler`. The first iteration is a large step.
+    ///
+    /// * `rng_sequence_index`     - Sequence for the random number generator.
+    /// * `sigma`                  - Standard deviation of the perturbation of
+    ///                              small steps.
+    /// * `large_step_probability` - Probability of taking a large step.
+    /// * `stream_count`           - Number of sample streams.
+    pub fn new(
+        rng_sequence_index: u64,
+        sigma: Float,
+        large_step_probability: Float,
+        stream_count: usize,
+    ) -> Self {
+        Self {
+            rng: RNG::new(rng_sequence_index),
+            sigma,
+            large_step_probability,
+            stream_count,
+            x: vec![],
+            current_iteration: 0,
+            large_step: true,
+            last_large_step_iteration: 0,
+            stream_index: 0,
+            sample_index: 0,
+        }
+    }
+
+    /// Starts a new iteration and decides whether it is a large step.
+    pub fn start_iteration(&mut self) {
+        self.current_iteration += 1;
+        let u: Float = self.rng.uniform();
+        self.large_step = u < self.large_step_probability;
+    }
+
+    /// Accepts the sample values of the current iteration.
+    pub fn accept(&mut self) {
+        if self.large_step {
+            self.last_large_step_iteration = self.current_iteration;
+        }
+    }
+
+    /// Rejects the sample values of the current iteration and restores the
+    /// ones of the previous iteration.
+    pub fn reject(&mut self) {
+        let current_iteration = self.current_iteration;
+        for xi in self
+            .x
+            .iter_mut()
+            .filter(|xi| xi.last_modification_iteration == current_iteration)
+        {
+            xi.restore();
+        }
+        self.current_iteration -= 1;
+    }
+
+    /// Starts taking sample values from a stream.
+    ///
+    /// * `index` - The stream.
+    pub fn start_stream(&mut self, index: usize) {
+        debug_assert!(index < self.stream_count);
+        self.stream_index = index;
+        self.sample_index = 0;
+    }
+
+    /// Returns the next sample value of the current stream.
+    pub fn next_1d(&mut self) -> Float {
+        let index = self.stream_index + self.stream_count * self.sample_index;
+        self.sample_index += 1;
+        self.ensure_ready(index);
+        self.x[index].value
+    }
+
+    /// Brings a sample value up to date with the current iteration by
+    /// applying the mutations it missed.
+    ///
+    /// * `index` - Index of the sample value.
+    fn ensure_ready(&mut self, index: usize) {
+        // Enlarge `x` if necessary.
+        if index >= self.x.len() {
+            self.x.resize(index + 1, PrimarySample::default());
+        }
+        let xi = &mut self.x[index];
+
+        // Reset `xi` if a large step took place in the meantime.
+        if xi.last_modification_iteration < self.last_large_step_iteration {
+            xi.value = self.rng.uniform();
+            xi.last_modification_iteration = self.last_large_step_iteration;
+        }
+
+        // Apply remaining sequence of mutations to sample.
+        xi.backup();
+        if self.large_step {
+            xi.value = self.rng.uniform();
+        } else {
+            let n_small = self.current_iteration - xi.last_modification_iteration;
+
+            // Apply `n_small` small step mutations. Their sum is normally
+            // distributed with `n_small` times the variance of one.
+            let u: Float = self.rng.uniform();
+            let normal_sample = (2.0 as Float).sqrt() * erf_inv(2.0 * u - 1.0);
+            let eff_sigma = self.sigma * (n_small as Float).sqrt();
+            xi.value += normal_sample * eff_sigma;
+            xi.value -= xi.value.floor();
+        }
+        xi.last_modification_iteration = self.current_iteration;
+    }
+}
+
+/// Lets integrators that take an `ArcSampler` draw sample values from an
+/// `MLTSampler` that is shared with the Markov chain mutating it.
+struct SharedMLTSampler {
+    /// The common sampler data.
+    data: SamplerData,
+
+    /// The sampler.
+    sampler: Arc<Mutex<MLTSampler>>,
+}
+
+impl Sampler for SharedMLTSampler {
+    /// Returns the underlying `SamplerData`.
+    fn get_data(&mut self) -> &mut SamplerData {
+        &mut self.data
+    }
+
+    /// Generates a new instance of an initial `Sampler` for use by a rendering
+    /// thread.
+    ///
+    /// * `seed` - The seed for the random number generator.
+    fn clone(&self, seed: u64) -> ArcSampler {
+        let sampler = self.sampler.lock().unwrap();
+        Arc::new(Self {
+            data: self.data.clone(),
+            sampler: Arc::new(Mutex::new(MLTSampler::new(
+                seed,
+                sampler.sigma,
+                sampler.large_step_probability,
+                sampler.stream_count,
+            ))),
+        })
+    }
+
+    /// Returns the sample value for the next dimension of the current sample
+    /// vector.
+    fn get_1d(&mut self) -> Float {
+        self.sampler.lock().unwrap().next_1d()
+    }
+
+    /// Returns the sample value for the next two dimensions of the current
+    /// sample vector.
+    fn get_2d(&mut self) -> Point2f {
+        let mut sampler = self.sampler.lock().unwrap();
+        let x = sampler.next_1d();
+        let y = sampler.next_1d();
+        Point2f::new(x, y)
+    }
+}
+
+/// The state of a Markov chain between mutations.
+struct MarkovChain {
+    /// Random number generator deciding whether mutations are accepted.
+    rng: RNG,
+
+    /// The sampler mutating the sample vector.
+    mlt_sampler: Arc<Mutex<MLTSampler>>,
+
+    /// Sampler that draws from `mlt_sampler`.
+    sampler: ArcSampler,
+
+    /// Radiance of the current path.
+    l_current: Spectrum,
+
+    /// Point on the film the current path contributes to.
+    p_current: Point2f,
+}
+
+/// Implements Metropolis light transport in primary sample space. Markov
+/// chains of mutated sample vectors distribute their paths over the image
+/// in proportion to the luminance they carry, which finds light that is hard
+/// to reach by independent samples. Paths are traced with
+/// `VolPathIntegrator`, so unlike pbrt's MLT, which mutates the sample
+/// vectors of bidirectional paths, there are no light subpaths and no choice
+/// of connection strategy; caustics seen directly are still as hard to find
+/// as with `volpath`.
+pub struct MLTIntegrator {
+    /// The camera.
+    camera: ArcCamera,
+
+    /// Traces the paths for sample vectors.
+    path: VolPathIntegrator,
+
+    /// Number of samples used to estimate the normalization constant and to
+    /// choose the starting points of the chains.
+    n_bootstrap: usize,
+
+    /// Number of Markov chains.
+    n_chains: usize,
+
+    /// Average number of mutations per pixel.
+    mutations_per_pixel: usize,
+
+    /// Standard deviation of the perturbation of small steps.
+    sigma: Float,
+
+    /// Probability of taking a large step.
+    large_step_probability: Float,
+}
+
+impl MLTIntegrator {
+    /// Create a new `MLTIntegrator`.
+    ///
+    /// * `camera`                 - The camera.
+    /// * `sampler`                - The sampler.
+    /// * `max_depth`              - Maximum number of bounces.
+    /// * `n_bootstrap`            - Number of bootstrap samples.
+    /// * `n_chains`               - Number of Markov chains.
+    /// * `mutations_per_pixel`    - Average number of mutations per pixel.
+    /// * `sigma`                  - Standard deviation of the perturbation
+    ///                              of small steps.
+    /// * `large_step_probability` - Probability of taking a large step.
+    pub fn new(
+        camera: ArcCamera,
+        sampler: ArcSampler,
+        max_depth: usize,
+        n_bootstrap: usize,
+        n_chains: usize,
+        mutations_per_pixel: usize,
+        sigma: Float,
+        large_step_probability: Float,
+    ) -> Self {
+        let pixel_bounds = camera.get_data().film.get_sample_bounds();
+        Self {
+            camera: camera.clone(),
+            path: VolPathIntegrator::new(
+                max_depth,
+                camera,
+                sampler,
+                pixel_bounds,
+                1.0,
+                "power",
+                false,
+            ),
+            n_bootstrap,
+            n_chains,
+            mutations_per_pixel,
+            sigma,
+            large_step_probability,
+        }
+    }
+
+    /// Returns a new `MLTSampler` for a chain along with a `Sampler` that
+    /// draws from it.
+    ///
+    /// * `rng_sequence_index` - Sequence for the random number generator.
+    fn new_sampler(&self, rng_sequence_index: u64) -> (Arc<Mutex<MLTSampler>>, ArcSampler) {
+        let mlt_sampler = Arc::new(Mutex::new(MLTSampler::new(
+            rng_sequence_index,
+            self.sigma,
+            self.large_step_probability,
+            N_SAMPLE_STREAMS,
+        )));
+        let sampler = Arc::new(SharedMLTSampler {
+            data: SamplerData::new(self.mutations_per_pixel),
+            sampler: mlt_sampler.clone(),
+        });
+        (mlt_sampler, sampler)
+    }
+
+    /// Returns the radiance of the path for the current sample vector of an
+    /// `MLTSampler` and the point on the film it contributes to.
+    ///
+    /// * `scene`       - The scene.
+    /// * `arena`       - The memory arena for per-sample allocations.
+    /// * `mlt_sampler` - The `MLTSampler`.
+    /// * `sampler`     - Sampler that draws from `mlt_sampler`.
+    fn l(
+        &self,
+        scene: Arc<Scene>,
+        arena: &MemoryArena,
+        mlt_sampler: &Mutex<MLTSampler>,
+        sampler: &mut ArcSampler,
+    ) -> (Spectrum, Point2f) {
+        // Generate the camera ray.
+        mlt_sampler
+            .lock()
+            .unwrap()
+            .start_stream(CAMERA_STREAM_INDEX);
+        let sample_bounds = Bounds2f::from(self.camera.get_data().film.get_sample_bounds());
+        let camera_sample = {
+            let s = Arc::get_mut(sampler).unwrap();
+            let p_raster = sample_bounds.lerp(&s.get_2d());
+            let p_lens = s.get_2d();
+            let time = s.get_1d();
+            CameraSample::new(p_raster, p_lens, time)
+        };
+        let (mut ray, ray_weight) = self.camera.generate_ray_differential(&camera_sample);
+        ray.scale_differentials(1.0 / (self.mutations_per_pixel as Float).sqrt());
+        if ray_weight == 0.0 {
+            return (Spectrum::new(0.0), camera_sample.p_film);
+        }
+
+        // Trace the path.
+        mlt_sampler.lock().unwrap().start_stream(PATH_STREAM_INDEX);
+        let l = self.path.li(&mut ray, scene, sampler, arena, 0) * ray_weight;
+        (l, camera_sample.p_film)
+    }
+
+    /// Returns the normalization constant, the average luminance of the
+    /// image, along with the distribution of the bootstrap samples to start
+    /// chains from.
+    ///
+    /// * `scene`  - The scene.
+    /// * `cancel` - Token used to stop rendering early.
+    fn bootstrap(&self, scene: Arc<Scene>, cancel: &CancellationToken) -> (Float, Distribution1D) {
+        let weights: Vec<Float> = (0..self.n_bootstrap)
+            .into_par_iter()
+            .map(|i| {
+                if cancel.is_cancelled() {
+                    return 0.0;
+                }
+                let arena = MemoryArena::default();
+                let (mlt_sampler, mut sampler) = self.new_sampler(i as u64);
+                let (l, _) = self.l(scene.clone(), &arena, &mlt_sampler, &mut sampler);
+                l.y()
+            })
+            .collect();
+        let bootstrap = Distribution1D::new(weights);
+        (bootstrap.func_int, bootstrap)
+    }
+
+    /// Returns a Markov chain starting at the path of a bootstrap sample.
+    ///
+    /// * `scene`     - The scene.
+    /// * `bootstrap` - Distribution of the bootstrap samples.
+    /// * `chain`     - Index of the chain.
+    fn start_chain(
+        &self,
+        scene: Arc<Scene>,
+        bootstrap: &Distribution1D,
+        chain: usize,
+    ) -> MarkovChain {
+        let mut rng = RNG::new(chain as u64);
+        let arena = MemoryArena::default();
+        let (bootstrap_index, _, _) = bootstrap.sample_discrete(rng.uniform());
+        let (mlt_sampler, mut sampler) = self.new_sampler(bootstrap_index as u64);
+        let (l_current, p_current) = self.l(scene, &arena, &mlt_sampler, &mut sampler);
+        MarkovChain {
+            rng,
+            mlt_sampler,
+            sampler,
+            l_current,
+            p_current,
+        }
+    }
+
+    /// Runs mutations of a Markov chain and splats the contributions of its
+    /// paths.
+    ///
+    /// * `scene`       - The scene.
+    /// * `chain`       - The Markov chain.
+    /// * `n_mutations` - Number of mutations.
+    /// * `splat`       - Adds a contribution to a point on the film.
+    /// * `cancel`      - Token used to stop rendering early.
+    fn run_chain<F>(
+        &self,
+        scene: Arc<Scene>,
+        chain: &mut MarkovChain,
+        n_mutations: u64,
+        mut splat: F,
+        cancel: &CancellationToken,
+    ) where
+        F: FnMut(&Point2f, Spectrum),
+    {
+        let mut arena = MemoryArena::default();
+        let MarkovChain {
+            rng,
+            mlt_sampler,
+            sampler,
+            l_current,
+            p_current,
+        } = chain;
+
+        for _ in 0..n_mutations {
+            if cancel.is_cancelled() {
+                break;
+            }
+
+            mlt_sampler.lock().unwrap().start_iteration();
+            let (l_proposed, p_proposed) = self.l(scene.clone(), &arena, mlt_sampler, sampler);
+            arena.reset();
+
+            // Compute acceptance probability for proposed sample.
+            let y_current = l_current.y();
+            let y_proposed = l_proposed.y();
+            let accept = if y_current > 0.0 {
+                min(1.0, y_proposed / y_current)
+            } else {
+                1.0
+            };
+
+            // Splat both current and proposed samples to the film.
+            if accept > 0.0 && y_proposed > 0.0 {
+                splat(&p_proposed, l_proposed * accept / y_proposed);
+            }
+            if accept < 1.0 && y_current > 0.0 {
+                splat(p_current, *l_current * (1.0 - accept) / y_current);
+            }
+
+            // Accept or reject the proposal.
+            let u: Float = rng.uniform();
+            if u < accept {
+                *p_current = p_proposed;
+                *l_current = l_proposed;
+                mlt_sampler.lock().unwrap().accept();
+            } else {
+                mlt_sampler.lock().unwrap().reject();
+            }
+        }
+    }
+}
+
+impl Integrator for MLTIntegrator {
+    /// Computes the data the paths are traced with for the scene.
+    ///
+    /// * `scene`   - The scene.
+    /// * `sampler` - The sampler.
+    fn preprocess(&mut self, scene: Arc<Scene>, sampler: &ArcSampler) {
+        self.path.preprocess(scene, sampler);
+    }
+
+    /// Render the scene.
+    ///
+    /// * `scene`     - The scene.
+    /// * `cancel`    - Token used to stop rendering early.
+    /// * `observers` - Observers notified of rendering progress.
+    fn render(
+        &mut self,
+        scene: Arc<Scene>,
+        cancel: &CancellationToken,
+        observers: &[ArcRenderObserver],
+    ) -> Result<(), Error> {
+        let sampler = self.path.get_data().sampler.clone();
+        self.preprocess(scene.clone(), &sampler);
+
+        let film = self.camera.get_data().film.clone();
+        if !film.lpe_channels.is_empty() {
+            warn!(
+                target: INTEGRATOR,
+                "Light path expressions are not supported by the MLT integrator. \
+                Channels will be black."
+            );
+        }
+        for observer in observers {
+            observer.render_started(&film.cropped_pixel_bounds);
+        }
+
+        // Generate bootstrap samples and compute the normalization constant.
+        info!(
+            target: INTEGRATOR,
+            "Generating {} bootstrap paths", self.n_bootstrap
+        );
+        let (b, bootstrap) = self.bootstrap(scene.clone(), cancel);
+
+        // Run the Markov chains. Chains don't start from paths that carry
+        // no light so there is nothing to do if all of them are black.
+        let n_total_mutations =
+            self.mutations_per_pixel as u64 * film.get_sample_bounds().area() as u64;
+        if b > 0.0 {
+            info!(
+                target: INTEGRATOR,
+                "Rendering {} mutations with {} chains", n_total_mutations, self.n_chains
+            );
+            let n_chains = self.n_chains as u64;
+            let chain_mutations = |i: usize| {
+                let i = i as u64;
+                min((i + 1) * n_total_mutations / n_chains, n_total_mutations)
+                    - i * n_total_mutations / n_chains
+            };
+            if OPTIONS.deterministic {
+                // The chains run a batch of mutations at a time and the
+                // splats of a batch are added to the film in the order of
+                // the chains, so that their sums don't depend on timing.
+                let chains: Vec<Mutex<MarkovChain>> = (0..self.n_chains)
+                    .into_par_iter()
+                    .map(|i| Mutex::new(self.start_chain(scene.clone(), &bootstrap, i)))
+                    .collect();
+                let max_mutations = (0..self.n_chains).map(chain_mutations).max().unwrap_or(0);
+                let mut batch_start = 0;
+                while batch_start < max_mutations && !cancel.is_cancelled() {
+                    for_each_in_order(
+                        self.n_chains,
+                        |i| {
+                            let n_mutations = min(
+                                chain_mutations(i).saturating_sub(batch_start),
+                                MUTATIONS_PER_BATCH,
+                            );
+                            let mut chain = chains[i].lock().unwrap();
+                            let mut splats = vec![];
+                            self.run_chain(
+                                scene.clone(),
+                                &mut chain,
+                                n_mutations,
+                                |p, v| splats.push((*p, v)),
+                                cancel,
+                            );
+                            splats
+                        },
+                        |_, splats| {
+                            for (p, v) in splats {
+                                film.add_splat(&p, &v);
+                            }
+                        },
+                    );
+                    batch_start += MUTATIONS_PER_BATCH;
+                }
+            } else {
+                (0..self.n_chains).into_par_iter().for_each(|i| {
+                    let mut chain = self.start_chain(scene.clone(), &bootstrap, i);
+                    self.run_chain(
+                        scene.clone(),
+                        &mut chain,
+                        chain_mutations(i),
+                        |p, v| film.add_splat(p, &v),
+                        cancel,
+                    );
+                });
+            }
+        } else {
+            warn!(target: INTEGRATOR, "No bootstrap path carries light.");
+        }
+
+        let splat_scale = b / self.mutations_per_pixel as Float;
+        if cancel.is_cancelled() {
+            warn!(target: INTEGRATOR, "Rendering cancelled. Writing partial image.");
+        } else {
+            info!(target: INTEGRATOR, "Rendering finished.");
+            if !observers.is_empty() {
+                let bounds = film.cropped_pixel_bounds;
+                let rgb = film.get_rgb(&bounds, splat_scale);
+                for observer in observers {
+                    observer.tile_finished(&bounds, &rgb);
+                    observer.pass_finished(0);
+                }
+            }
+        }
+
+        // Save final image after rendering.
+        let result = film.write_image(splat_scale);
+        if result.is_ok() {
+            info!(target: INTEGRATOR, "Output image written.");
+        }
+        for observer in observers {
+            observer.render_finished(cancel.is_cancelled());
+        }
+        result
+    }
+
+    /// Returns the incident radiance at the origin of a given ray.
+    ///
+    /// * `ray`     - The ray.
+    /// * `scene`   - The scene.
+    /// * `sampler` - The sampler.
+    /// * `arena`   - The memory arena for per-sample allocations.
+    /// * `depth`   - The recursion depth.
+    fn li(
+        &self,
+        ray: &mut Ray,
+        scene: Arc<Scene>,
+        sampler: &mut ArcSampler,
+        arena: &MemoryArena,
+        depth: usize,
+    ) -> Spectrum {
+        self.path.li(ray, scene, sampler, arena, depth)
+    }
+}
+
+impl From<(&ParamSet, ArcSampler, ArcCamera)> for MLTIntegrator {
+    /// Create a `MLTIntegrator` from given parameter set and camera.
+    ///
+    /// * `p` - A tuple containing parameter set and camera.
+    fn from(p: (&ParamSet, ArcSampler, ArcCamera)) -> Self {
+        let (params, sampler, camera) = p;
+
+        let max_depth = params.find_one_int("maxdepth", 5).max(0) as usize;
+        let n_bootstrap = params.find_one_int("bootstrapsamples", 100000).max(1) as usize;
+        let n_chains = params.find_one_int("chains", 1000).max(1) as usize;
+        let mutations_per_pixel = params.find_one_int("mutationsperpixel", 100).max(1) as usize;
+        let large_step_probability = params.find_one_float("largestepprobability", 0.3);
+        let sigma = params.find_one_float("sigma", 0.01);
+
+        Self::new(
+            camera.clone(),
+            sampler.clone(),
+            max_depth,
+            n_bootstrap,
+            n_chains,
+            mutations_per_pixel,
+            sigma,
+            large_step_probability,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::api::*;
+
+    #[test]
+    fn rejected_mutations_restore_the_sample_vector() {
+        let mut sampler = MLTSampler::new(7, 0.01, 0.0, 2);
+        sampler.start_stream(0);
+        let x: Vec<Float> = (0..4).map(|_| sampler.next_1d()).collect();
+
+        // Small steps stay close to the current values.
+        sampler.start_iteration();
+        sampler.start_stream(0);
+        let y: Vec<Float> = (0..4).map(|_| sampler.next_1d()).collect();
+        assert!(x.iter().zip(y.iter()).all(|(a, b)| {
+            let d = (a - b).abs();
+            d > 0.0 && min(d, 1.0 - d) < 0.1
+        }));
+
+        sampler.reject();
+        sampler.start_stream(0);
+        let z: Vec<Float> = (0..4).map(|_| sampler.next_1d()).collect();
+        assert_eq!(x, z);
+    }
+
+    #[test]
+    fn mlt_matches_the_brightness_of_path_tracing() {
+        let render = |integrator: &str| -> Float {
+            let scene = format!(
+                r#"
+                LookAt 0 0 5  0 0 0  0 1 0
+                Camera "perspective" "float fov" [30]
+                Film "image" "integer xresolution" [8] "integer yresolution" [8]
+                Sampler "random" "integer pixelsamples" [64]
+                Integrator {}
+                WorldBegin
+                LightSource "point" "rgb I" [10 10 10] "point from" [2 2 3]
+                Material "matte" "rgb Kd" [0.5 0.5 0.5]
+                Shape "sphere" "float radius" [1]
+                WorldEnd
+                "#,
+                integrator
+            );
+            let image = render_to_rgba(&scene, |_, _| {}).unwrap();
+            let sum: u64 = image.pixels.chunks(4).map(|p| p[1] as u64).sum();
+            sum as Float / (image.pixels.len() / 4) as Float
+        };
+
+        let reference = render(r#""volpath""#);
+        let mlt = render(
+            r#""mlt" "integer bootstrapsamples" [4096] "integer chains" [16]
+            "integer mutationsperpixel" [64]"#,
+        );
+        assert!(reference > 0.0);
+        assert!(
+            (mlt - reference).abs() < 0.1 * reference,
+            "{} != {}",
+            mlt,
+            reference
+        );
+    }
+}
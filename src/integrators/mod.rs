@@ -1,6 +1,16 @@
 //! Integrators
 
+#[cfg(feature = "gpu")]
+mod gpu;
+mod mlt;
+mod restir;
+mod volpath;
 mod whitted;
 
 // Re-export.
+#[cfg(feature = "gpu")]
+pub use gpu::*;
+pub use mlt::*;
+pub use restir::*;
+pub use volpath::*;
 pub use whitted::*;
@@ -0,0 +1,724 @@
+//! ReSTIR Direct Lighting Integrator
+
+#![allow(dead_code)]
+
+use crate::core::arena::*;
+use crate::core::camera::*;
+use crate::core::cancel::*;
+use crate::core::error::*;
+use crate::core::geometry::*;
+use crate::core::integrator::*;
+use crate::core::light::*;
+use crate::core::logging::INTEGRATOR;
+use crate::core::material::*;
+use crate::core::parallel::*;
+use crate::core::paramset::*;
+use crate::core::pbrt::*;
+use crate::core::reflection::*;
+use crate::core::rng::*;
+use crate::core::sampler::*;
+use crate::core::sampling::*;
+use crate::core::scene::*;
+use crate::core::spectrum::*;
+use std::sync::Arc;
+
+/// Number of candidates of a reservoir reused from the previous pass is
+/// limited to this multiple of the candidates generated for each pass so that
+/// old samples don't dominate.
+const TEMPORAL_HISTORY_LIMIT: usize = 20;
+
+/// A light sample chosen by resampled importance sampling from a stream of
+/// candidates. A sample is a light and the 2D sample values used to choose a
+/// point on it, so it can be replayed at any point in the scene.
+#[derive(Copy, Clone, Default)]
+struct Reservoir {
+    /// Index of the chosen light.
+    light: usize,
+
+    /// Sample values used to choose a point on the light.
+    u: Point2f,
+
+    /// Target function of the chosen sample at the shading point of the
+    /// reservoir.
+    p_hat: Float,
+
+    /// Sum of the resampling weights of the candidates.
+    w_sum: Float,
+
+    /// Number of candidates.
+    m: usize,
+}
+
+impl Reservoir {
+    /// Adds a candidate and returns `true` if it replaced the chosen sample.
+    ///
+    /// * `light`  - Index of the light.
+    /// * `u`      - Sample values used to choose a point on the light.
+    /// * `p_hat`  - Target function of the candidate.
+    /// * `weight` - Resampling weight of the candidate.
+    /// * `m`      - Number of candidates it represents.
+    /// * `u_pick` - Uniform random value used to choose the sample.
+    fn update(
+        &mut self,
+        light: usize,
+        u: Point2f,
+        p_hat: Float,
+        weight: Float,
+        m: usize,
+        u_pick: Float,
+    ) -> bool {
+        self.w_sum += weight;
+        self.m += m;
+        if weight > 0.0 && u_pick * self.w_sum < weight {
+            self.light = light;
+            self.u = u;
+            self.p_hat = p_hat;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns the weight that turns the contribution of the chosen sample
+    /// into an estimate of the direct lighting.
+    fn contribution_weight(&self) -> Float {
+        if self.p_hat > 0.0 && self.m > 0 {
+            self.w_sum / (self.m as Float * self.p_hat)
+        } else {
+            0.0
+        }
+    }
+}
+
+/// The point on a surface seen through a pixel.
+#[derive(Clone)]
+struct ShadingPoint {
+    /// The surface hit.
+    hit: Hit,
+
+    /// The BSDF.
+    bsdf: ArcBSDF,
+
+    /// The shading normal.
+    ns: Normal3f,
+
+    /// Distance from the camera.
+    depth: Float,
+}
+
+impl ShadingPoint {
+    /// Returns `true` if the surfaces are similar enough for their light
+    /// samples to be shared; their normals are within about 25° and their
+    /// distances from the camera within 10%.
+    ///
+    /// * `other` - The other shading point.
+    fn is_similar(&self, other: &ShadingPoint) -> bool {
+        self.ns.dot(&other.ns) > 0.9 && abs(self.depth - other.depth) < 0.1 * self.depth
+    }
+}
+
+/// Implements direct lighting with reservoir-based spatiotemporal importance
+/// resampling (ReSTIR). Each pixel resamples many light candidates down to
+/// one and then combines its choice with those of the same pixel in the
+/// previous pass and of nearby pixels, so that good light samples are shared.
+/// This suits scenes with very many lights. Only light arriving directly from
+/// the lights is computed.
+pub struct ReSTIRIntegrator {
+    /// The camera.
+    camera: ArcCamera,
+
+    /// The sampler; it determines the number of passes.
+    sampler: ArcSampler,
+
+    /// Number of light candidates generated for each pixel in each pass.
+    n_candidates: usize,
+
+    /// Number of neighbouring pixels whose reservoirs are reused.
+    n_spatial_samples: usize,
+
+    /// Radius in pixels of the neighbourhood for spatial reuse.
+    spatial_radius: Float,
+
+    /// Reuse the reservoir of each pixel from the previous pass. This makes
+    /// each pass less noisy but correlates the passes, so the average of all
+    /// passes converges more slowly.
+    temporal_reuse: bool,
+
+    /// Light sampling strategy used for candidates; "uniform", "power" or
+    /// "spatial".
+    light_sample_strategy: String,
+
+    /// Distribution for choosing the light of a candidate. Computed in
+    /// `preprocess()`.
+    light_distrib: Option<ArcLightDistribution>,
+}
+
+impl ReSTIRIntegrator {
+    /// Create a new `ReSTIRIntegrator`.
+    ///
+    /// * `camera`                - The camera.
+    /// * `sampler`               - The sampler; it determines the number of
+    ///                             passes.
+    /// * `n_candidates`          - Number of light candidates generated for
+    ///                             each pixel in each pass.
+    /// * `n_spatial_samples`     - Number of neighbouring pixels whose
+    ///                             reservoirs are reused.
+    /// * `spatial_radius`        - Radius in pixels of the neighbourhood for
+    ///                             spatial reuse.
+    /// * `temporal_reuse`        - Reuse the reservoir of each pixel from the
+    ///                             previous pass.
+    /// * `light_sample_strategy` - Light sampling strategy; "uniform",
+    ///                             "power" or "spatial".
+    pub fn new(
+        camera: ArcCamera,
+        sampler: ArcSampler,
+        n_candidates: usize,
+        n_spatial_samples: usize,
+        spatial_radius: Float,
+        temporal_reuse: bool,
+        light_sample_strategy: &str,
+    ) -> Self {
+        Self {
+            camera,
+            sampler,
+            n_candidates: max(1, n_candidates),
+            n_spatial_samples,
+            spatial_radius,
+            temporal_reuse,
+            light_sample_strategy: String::from(light_sample_strategy),
+            light_distrib: None,
+        }
+    }
+
+    /// Traces a camera ray to the first surface with a material and returns
+    /// the light emitted towards the camera along with the shading point; or
+    /// `None` for the shading point if the ray leaves the scene.
+    ///
+    /// * `ray`   - The camera ray.
+    /// * `scene` - The scene.
+    fn trace_camera_ray(&self, ray: &mut Ray, scene: &Scene) -> (Spectrum, Option<ShadingPoint>) {
+        let origin = ray.o;
+        loop {
+            let mut isect = match scene.intersect(ray) {
+                Some(isect) => isect,
+                None => {
+                    let mut le = Spectrum::new(0.0);
+                    for light in scene.lights.iter() {
+                        le += light.le(ray);
+                    }
+                    return (le, None);
+                }
+            };
+
+            // Held out surfaces render black.
+            if isect.primitive.is_some_and(|p| p.is_holdout()) {
+                return (Spectrum::new(0.0), None);
+            }
+
+            isect.compute_scattering_functions(ray, true, TransportMode::Radiance);
+            match isect.bsdf.clone() {
+                Some(bsdf) => {
+                    let le = isect.le(&-ray.d);
+                    let depth = (isect.hit.p - origin).length();
+                    let sp = ShadingPoint {
+                        hit: isect.hit.clone(),
+                        bsdf,
+                        ns: isect.shading.n,
+                        depth,
+                    };
+                    return (le, Some(sp));
+                }
+                None => *ray = isect.hit.spawn_ray(&ray.d),
+            }
+        }
+    }
+
+    /// Returns the unshadowed contribution of a light sample at a shading
+    /// point divided by the probability density of choosing the point on the
+    /// light, and the visibility tester for the sample.
+    ///
+    /// * `sp`    - The shading point.
+    /// * `scene` - The scene.
+    /// * `light` - Index of the light.
+    /// * `u`     - Sample values used to choose a point on the light.
+    fn evaluate(
+        sp: &ShadingPoint,
+        scene: &Scene,
+        light: usize,
+        u: &Point2f,
+    ) -> (Spectrum, Option<VisibilityTester>) {
+        let Li {
+            wi,
+            pdf,
+            visibility,
+            value,
+        } = scene.lights[light].sample_li(&sp.hit, u);
+        if pdf == 0.0 || value.is_black() {
+            return (Spectrum::new(0.0), None);
+        }
+        // Fluorescent surfaces also re-radiate the light at other wavelengths.
+        let bsdf_flags = BxDFType::from(BSDF_ALL);
+        let scattered = sp.bsdf.f(&sp.hit.wo, &wi, bsdf_flags) * value
+            + sp.bsdf.reradiate(&sp.hit.wo, &wi, &value, bsdf_flags);
+        (scattered * wi.abs_dot(&sp.ns) / pdf, visibility)
+    }
+
+    /// Returns the target function of a light sample at a shading point.
+    ///
+    /// * `sp`    - The shading point.
+    /// * `scene` - The scene.
+    /// * `light` - Index of the light.
+    /// * `u`     - Sample values used to choose a point on the light.
+    fn target(sp: &ShadingPoint, scene: &Scene, light: usize, u: &Point2f) -> Float {
+        max(0.0, Self::evaluate(sp, scene, light, u).0.y())
+    }
+
+    /// Resamples the light candidates of a shading point into a reservoir.
+    /// The chosen sample is discarded if it is occluded.
+    ///
+    /// * `sp`    - The shading point.
+    /// * `scene` - The scene.
+    /// * `u`     - Returns uniform random values.
+    fn initial_reservoir<F>(&self, sp: &ShadingPoint, scene: &Scene, mut u: F) -> Reservoir
+    where
+        F: FnMut() -> Float,
+    {
+        let mut reservoir = Reservoir::default();
+        let distrib = match self.light_distrib.as_ref() {
+            Some(distrib) if !scene.lights.is_empty() => distrib.lookup(&sp.hit.p),
+            _ => return reservoir,
+        };
+
+        for _ in 0..self.n_candidates {
+            let (light, pmf, _) = distrib.sample(u());
+            let u_light = Point2f::new(u(), u());
+            let p_hat = if pmf > 0.0 {
+                Self::target(sp, scene, light, &u_light)
+            } else {
+                0.0
+            };
+            let weight = if pmf > 0.0 { p_hat / pmf } else { 0.0 };
+            reservoir.update(light, u_light, p_hat, weight, 1, u());
+        }
+
+        if reservoir.w_sum > 0.0 && !Self::is_visible(sp, scene, &reservoir) {
+            reservoir.w_sum = 0.0;
+        }
+        reservoir
+    }
+
+    /// Returns `true` if the light sample chosen by a reservoir isn't
+    /// occluded.
+    ///
+    /// * `sp`        - The shading point.
+    /// * `scene`     - The scene.
+    /// * `reservoir` - The reservoir.
+    fn is_visible(sp: &ShadingPoint, scene: &Scene, reservoir: &Reservoir) -> bool {
+        let Li { visibility, .. } = scene.lights[reservoir.light].sample_li(&sp.hit, &reservoir.u);
+        visibility.is_none_or(|vis| !scene.intersect_p(&vis.p0.spawn_ray_to(&vis.p1)))
+    }
+
+    /// Adds the sample of another reservoir to a reservoir of a shading
+    /// point by re-evaluating the target function at the shading point.
+    ///
+    /// * `reservoir` - The reservoir.
+    /// * `other`     - The reservoir to add.
+    /// * `m`         - Number of candidates to count for `other`.
+    /// * `sp`        - The shading point.
+    /// * `scene`     - The scene.
+    /// * `u_pick`    - Uniform random value used to choose the sample.
+    fn combine(
+        reservoir: &mut Reservoir,
+        other: &Reservoir,
+        m: usize,
+        sp: &ShadingPoint,
+        scene: &Scene,
+        u_pick: Float,
+    ) {
+        if other.m == 0 {
+            return;
+        }
+        let p_hat = if other.w_sum > 0.0 {
+            Self::target(sp, scene, other.light, &other.u)
+        } else {
+            0.0
+        };
+        let weight = p_hat * other.contribution_weight() * m as Float;
+        reservoir.update(other.light, other.u, p_hat, weight, m, u_pick);
+    }
+
+    /// Returns the direct lighting at a shading point estimated from the
+    /// sample chosen by its reservoir.
+    ///
+    /// * `sp`        - The shading point.
+    /// * `scene`     - The scene.
+    /// * `reservoir` - The reservoir.
+    fn shade(sp: &ShadingPoint, scene: &Scene, reservoir: &Reservoir) -> Spectrum {
+        let w = reservoir.contribution_weight();
+        if w == 0.0 {
+            return Spectrum::new(0.0);
+        }
+        let (contrib, visibility) = Self::evaluate(sp, scene, reservoir.light, &reservoir.u);
+        match visibility {
+            Some(vis) if scene.intersect_p(&vis.p0.spawn_ray_to(&vis.p1)) => Spectrum::new(0.0),
+            _ => contrib * w,
+        }
+    }
+
+    /// Renders one pass and returns the radiance of each pixel along with the
+    /// reservoirs to reuse in the next pass.
+    ///
+    /// * `scene`      - The scene.
+    /// * `pass`       - Index of the pass.
+    /// * `previous`   - Reservoirs of the previous pass.
+    fn render_pass(
+        &self,
+        scene: &Scene,
+        pass: usize,
+        previous: &[Reservoir],
+    ) -> (Vec<Spectrum>, Vec<Reservoir>) {
+        let film = self.camera.get_data().film.clone();
+        let bounds = film.cropped_pixel_bounds;
+        let resolution = bounds.diagonal();
+        let n_pixels = bounds.area() as usize;
+        let pixel = |i: usize| {
+            bounds.p_min + Vector2i::new(i as i32 % resolution.x, i as i32 / resolution.x)
+        };
+        let new_rng = |i: usize, stage: usize| {
+            let mut rng = RNG::new(mix_bits(i as u64));
+            rng.advance(((pass * 2 + stage) * 65536) as i64);
+            rng
+        };
+
+        // Find the shading point of each pixel and resample its light
+        // candidates.
+        let initial: Vec<(Spectrum, Option<ShadingPoint>, Reservoir)> = (0..n_pixels)
+            .into_par_iter()
+            .map(|i| {
+                let mut rng = new_rng(i, 0);
+                let mut u = || -> Float { rng.uniform() };
+                let p = Point2f::from(pixel(i));
+                let camera_sample = CameraSample {
+                    p_film: p + Vector2f::new(u(), u()),
+                    p_lens: Point2f::new(u(), u()),
+                    time: u(),
+                };
+                let (mut ray, ray_weight) = self.camera.generate_ray_differential(&camera_sample);
+                if ray_weight == 0.0 {
+                    return (Spectrum::new(0.0), None, Reservoir::default());
+                }
+                let (le, sp) = self.trace_camera_ray(&mut ray, scene);
+                let mut reservoir = match sp.as_ref() {
+                    Some(sp) => self.initial_reservoir(sp, scene, &mut u),
+                    None => Reservoir::default(),
+                };
+
+                // Reuse the reservoir of the pixel from the previous pass.
+                if let (Some(sp), Some(prev)) = (sp.as_ref(), previous.get(i)) {
+                    let m = min(prev.m, TEMPORAL_HISTORY_LIMIT * self.n_candidates);
+                    Self::combine(&mut reservoir, prev, m, sp, scene, u());
+                }
+                (le * ray_weight, sp, reservoir)
+            })
+            .collect();
+
+        // Combine the reservoirs of random neighbouring pixels with similar
+        // surfaces and shade each pixel with the chosen sample.
+        (0..n_pixels)
+            .into_par_iter()
+            .map(|i| {
+                let (le, sp, reservoir) = &initial[i];
+                let sp = match sp.as_ref() {
+                    Some(sp) => sp,
+                    None => return (*le, Reservoir::default()),
+                };
+                let mut rng = new_rng(i, 1);
+                let mut u = || -> Float { rng.uniform() };
+
+                let mut combined = *reservoir;
+                for _ in 0..self.n_spatial_samples {
+                    let d = concentric_sample_disk(&Point2f::new(u(), u())) * self.spatial_radius;
+                    let p = pixel(i) + Vector2i::new(d.x.round() as i32, d.y.round() as i32);
+                    if !bounds.contains_exclusive(&p) {
+                        continue;
+                    }
+                    let offset = p - bounds.p_min;
+                    let j = (offset.y * resolution.x + offset.x) as usize;
+                    if j == i {
+                        continue;
+                    }
+                    if let (_, Some(other_sp), other) = &initial[j] {
+                        if sp.is_similar(other_sp) {
+                            Self::combine(&mut combined, other, other.m, sp, scene, u());
+                        }
+                    }
+                }
+
+                (*le + Self::shade(sp, scene, &combined), combined)
+            })
+            .unzip()
+    }
+}
+
+/// Returns a well distributed hash of a value so that random number streams
+/// chosen by consecutive indices aren't correlated.
+///
+/// * `v` - The value.
+fn mix_bits(mut v: u64) -> u64 {
+    v ^= v >> 31;
+    v = v.wrapping_mul(0x7fb5d329728ea185);
+    v ^= v >> 27;
+    v = v.wrapping_mul(0x81dadef4bc2dd44d);
+    v ^= v >> 33;
+    v
+}
+
+impl Integrator for ReSTIRIntegrator {
+    /// Computes the light distribution for the scene. This must be called
+    /// before `li()`; `render()` does so.
+    ///
+    /// * `scene`    - The scene.
+    /// * `_sampler` - The sampler.
+    fn preprocess(&mut self, scene: Arc<Scene>, _sampler: &ArcSampler) {
+        self.light_distrib = Some(scene_light_sample_distribution(
+            &self.light_sample_strategy,
+            &scene,
+        ));
+    }
+
+    /// Render the scene.
+    ///
+    /// * `scene`     - The scene.
+    /// * `cancel`    - Token used to stop rendering early.
+    /// * `observers` - Observers notified of rendering progress.
+    fn render(
+        &mut self,
+        scene: Arc<Scene>,
+        cancel: &CancellationToken,
+        observers: &[ArcRenderObserver],
+    ) -> Result<(), Error> {
+        let sampler = self.sampler.clone();
+        self.preprocess(scene.clone(), &sampler);
+
+        let film = self.camera.get_data().film.clone();
+        let bounds = film.cropped_pixel_bounds;
+        if !film.lpe_channels.is_empty() {
+            warn!(
+                target: INTEGRATOR,
+                "Light path expressions are not supported by the ReSTIR integrator. \
+                Channels will be black."
+            );
+        }
+        for observer in observers {
+            observer.render_started(&bounds);
+        }
+
+        // Each pass adds one sample to every pixel. The image is the average
+        // of the passes.
+        let n_passes = Arc::get_mut(&mut Sampler::clone(&*self.sampler, 0))
+            .unwrap()
+            .get_data()
+            .samples_per_pixel;
+        info!(target: INTEGRATOR, "Rendering {} passes", n_passes);
+        let mut sum = vec![Spectrum::new(0.0); bounds.area() as usize];
+        let mut reservoirs = vec![];
+        let mut n_rendered = 0;
+        for pass in 0..n_passes {
+            if cancel.is_cancelled() {
+                break;
+            }
+            let previous = if self.temporal_reuse {
+                reservoirs
+            } else {
+                vec![]
+            };
+            let (l, next) = self.render_pass(&scene, pass, &previous);
+            reservoirs = next;
+            for (s, l) in sum.iter_mut().zip(l.iter()) {
+                *s += *l;
+            }
+            n_rendered += 1;
+
+            if !observers.is_empty() {
+                let image: Vec<Spectrum> = sum.iter().map(|s| *s / n_rendered as Float).collect();
+                film.set_image(&image);
+                let rgb = film.get_rgb(&bounds, 1.0);
+                for observer in observers {
+                    observer.tile_finished(&bounds, &rgb);
+                    observer.pass_finished(pass);
+                }
+            }
+        }
+
+        if cancel.is_cancelled() {
+            warn!(target: INTEGRATOR, "Rendering cancelled. Writing partial image.");
+        } else {
+            info!(target: INTEGRATOR, "Rendering finished.");
+        }
+
+        // Save final image after rendering.
+        let image: Vec<Spectrum> = sum
+            .iter()
+            .map(|s| *s / max(1, n_rendered) as Float)
+            .collect();
+        film.set_image(&image);
+        let result = film.write_image(1.0);
+        if result.is_ok() {
+            info!(target: INTEGRATOR, "Output image written.");
+        }
+        for observer in observers {
+            observer.render_finished(cancel.is_cancelled());
+        }
+        result
+    }
+
+    /// Returns the incident radiance at the origin of a given ray estimated by
+    /// resampling light candidates without reuse.
+    ///
+    /// * `ray`     - The ray.
+    /// * `scene`   - The scene.
+    /// * `sampler` - The sampler.
+    /// * `_arena`  - The memory arena for per-sample allocations.
+    /// * `_depth`  - The recursion depth.
+    fn li(
+        &self,
+        ray: &mut Ray,
+        scene: Arc<Scene>,
+        sampler: &mut ArcSampler,
+        _arena: &MemoryArena,
+        _depth: usize,
+    ) -> Spectrum {
+        let (le, sp) = self.trace_camera_ray(ray, &scene);
+        match sp {
+            Some(sp) => {
+                let sampler = Arc::get_mut(sampler).unwrap();
+                let reservoir = self.initial_reservoir(&sp, &scene, || sampler.get_1d());
+                le + Self::shade(&sp, &scene, &reservoir)
+            }
+            None => le,
+        }
+    }
+}
+
+impl From<(&ParamSet, ArcSampler, ArcCamera)> for ReSTIRIntegrator {
+    /// Create a `ReSTIRIntegrator` from given parameter set and camera.
+    ///
+    /// * `p` - A tuple containing parameter set and camera.
+    fn from(p: (&ParamSet, ArcSampler, ArcCamera)) -> Self {
+        let (params, sampler, camera) = p;
+
+        let n_candidates = params.find_one_int("candidates", 32).max(1) as usize;
+        let n_spatial_samples = params.find_one_int("spatialsamples", 5).max(0) as usize;
+        let spatial_radius = params.find_one_float("spatialradius", 10.0);
+        let temporal_reuse = params.find_one_bool("temporalreuse", false);
+        let light_sample_strategy =
+            params.find_one_string("lightsamplestrategy", String::from("power"));
+
+        Self::new(
+            camera,
+            sampler,
+            n_candidates,
+            n_spatial_samples,
+            spatial_radius,
+            temporal_reuse,
+            &light_sample_strategy,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::api::*;
+
+    #[test]
+    fn reservoirs_choose_candidates_in_proportion_to_their_weights() {
+        let mut rng = RNG::new(7);
+        let n = 10000;
+        let mut chosen = 0;
+        for _ in 0..n {
+            let mut reservoir = Reservoir::default();
+            for (light, weight) in [(0, 1.0), (1, 3.0), (2, 0.0)] {
+                reservoir.update(light, Point2f::default(), weight, weight, 1, rng.uniform());
+            }
+            assert_eq!(reservoir.m, 3);
+            assert!(reservoir.light < 2);
+            if reservoir.light == 1 {
+                chosen += 1;
+            }
+        }
+        let fraction = chosen as Float / n as Float;
+        assert!((fraction - 0.75).abs() < 0.02, "{}", fraction);
+    }
+
+    /// Returns the RGB values of a render of a floor lit by a grid of point
+    /// lights.
+    ///
+    /// * `integrator`   - Integrator statement.
+    /// * `pixelsamples` - Number of samples per pixel.
+    fn render_lights(integrator: &str, pixelsamples: usize) -> Vec<u8> {
+        let mut lights = String::new();
+        for i in 0..64 {
+            let (x, z) = ((i % 8) as Float - 3.5, (i / 8) as Float - 3.5);
+            lights += &format!(
+                "LightSource \"point\" \"rgb I\" [0.2 0.2 0.2] \"point from\" [{} 1 {}]\n",
+                x, z
+            );
+        }
+        let scene = format!(
+            r#"
+            LookAt 0 6 6  0 0 0  0 1 0
+            Camera "perspective" "float fov" [40]
+            Film "image" "integer xresolution" [16] "integer yresolution" [16]
+            Sampler "random" "integer pixelsamples" [{}]
+            {}
+            WorldBegin
+            {}
+            Material "matte" "rgb Kd" [0.5 0.5 0.5]
+            Shape "trianglemesh" "integer indices" [0 1 2 0 2 3]
+                "point P" [-5 0 -5  5 0 -5  5 0 5  -5 0 5]
+            WorldEnd
+            "#,
+            pixelsamples, integrator, lights
+        );
+        let image = render_to_rgba(&scene, |_, _| {}).unwrap();
+        image
+            .pixels
+            .chunks(4)
+            .flat_map(|p| p[..3].to_vec())
+            .collect()
+    }
+
+    /// Returns the sum of the squared differences between two images.
+    ///
+    /// * `a` - RGB values of the first image.
+    /// * `b` - RGB values of the second image.
+    fn squared_error(a: &[u8], b: &[u8]) -> u64 {
+        a.iter()
+            .zip(b.iter())
+            .map(|(&a, &b)| (a as i64 - b as i64).pow(2) as u64)
+            .sum()
+    }
+
+    #[test]
+    fn spatial_reuse_reduces_noise_from_many_lights() {
+        let reference = render_lights(r#"Integrator "volpath" "integer maxdepth" [1]"#, 256);
+        let brightness = |rgb: &[u8]| rgb.iter().map(|&v| v as Float).sum::<Float>();
+
+        let restir = |options: &str| {
+            let integrator = format!(
+                r#"Integrator "restir" "integer candidates" [4] "float spatialradius" [2] {}"#,
+                options
+            );
+            render_lights(&integrator, 16)
+        };
+        let without_reuse = restir(r#""integer spatialsamples" [0]"#);
+        let with_reuse = restir("");
+
+        let relative = brightness(&with_reuse) / brightness(&reference);
+        assert!((relative - 1.0).abs() < 0.05, "{}", relative);
+        assert!(
+            squared_error(&with_reuse, &reference) < squared_error(&without_reuse, &reference) / 2
+        );
+    }
+}
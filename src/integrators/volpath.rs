@@ -0,0 +1,608 @@
+//! Volumetric Path Tracing Integrator
+
+#![allow(dead_code)]
+
+use crate::core::arena::*;
+use crate::core::camera::*;
+use crate::core::cancel::*;
+use crate::core::error::*;
+use crate::core::geometry::*;
+use crate::core::integrator::*;
+use crate::core::light::*;
+use crate::core::logging::INTEGRATOR;
+use crate::core::lpe::*;
+use crate::core::material::*;
+use crate::core::paramset::*;
+use crate::core::pbrt::*;
+use crate::core::primitive::*;
+use crate::core::profiler::*;
+use crate::core::reflection::*;
+use crate::core::sampler::*;
+use crate::core::scene::*;
+use crate::core::spectrum::*;
+use std::sync::Arc;
+
+/// Implements path tracing that accounts for scattering and attenuation in
+/// participating media.
+pub struct VolPathIntegrator {
+    /// The `SamplerIntegratorData`.
+    data: SamplerIntegratorData,
+
+    /// Maximum number of bounces.
+    max_depth: usize,
+
+    /// Russian roulette is applied once the maximum component of the path
+    /// throughput falls below this threshold.
+    rr_threshold: Float,
+
+    /// Light sampling strategy; "uniform", "power" or "spatial".
+    light_sample_strategy: String,
+
+    /// Distribution for choosing a light to sample. Computed in
+    /// `preprocess()`.
+    light_distrib: Option<ArcLightDistribution>,
+
+    /// Roughen near-specular BSDFs once the path has scattered off a diffuse
+    /// or glossy surface or in a medium.
+    regularize: bool,
+}
+
+impl VolPathIntegrator {
+    /// Create a new `VolPathIntegrator`.
+    ///
+    /// * `max_depth`             - Maximum number of bounces.
+    /// * `camera`                - The camera.
+    /// * `sampler`               - The sampler.
+    /// * `pixel_bounds`          - Pixel bounds for the image.
+    /// * `rr_threshold`          - Throughput below which Russian roulette
+    ///                             is applied.
+    /// * `light_sample_strategy` - Light sampling strategy; "uniform",
+    ///                             "power" or "spatial".
+    /// * `regularize`            - Roughen near-specular BSDFs once the path
+    ///                             has scattered off a diffuse or glossy
+    ///                             surface or in a medium.
+    pub fn new(
+        max_depth: usize,
+        camera: ArcCamera,
+        sampler: ArcSampler,
+        pixel_bounds: Bounds2i,
+        rr_threshold: Float,
+        light_sample_strategy: &str,
+        regularize: bool,
+    ) -> Self {
+        Self {
+            data: SamplerIntegratorData::new(camera, sampler, pixel_bounds),
+            max_depth,
+            rr_threshold,
+            light_sample_strategy: String::from(light_sample_strategy),
+            light_distrib: None,
+            regularize,
+        }
+    }
+
+    /// Returns the radiance of lights seen by a ray that escapes the scene.
+    ///
+    /// * `ray`   - The ray.
+    /// * `scene` - The scene.
+    fn escaped_radiance(ray: &Ray, scene: &Scene) -> Spectrum {
+        let mut l = Spectrum::new(0.0);
+        for (light_index, light) in scene.lights.iter().enumerate() {
+            let le = light.le(ray);
+            add_lpe_radiance(|| vec![PathEvent::light(Some(light_index))], le);
+            l += le;
+        }
+        l
+    }
+
+    /// Returns the index of the area light of the primitive at a surface
+    /// interaction among the scene's lights; or `None` if it has none.
+    ///
+    /// * `isect` - The surface interaction.
+    /// * `scene` - The scene.
+    fn area_light_index(isect: &SurfaceInteraction, scene: &Scene) -> Option<usize> {
+        let area_light = isect.primitive.and_then(|p| p.get_area_light())?;
+        let ptr = Arc::as_ptr(&area_light) as *const ();
+        scene
+            .lights
+            .iter()
+            .position(|light| Arc::as_ptr(light) as *const () == ptr)
+    }
+}
+
+impl SamplerIntegrator for VolPathIntegrator {
+    /// Returns the common data.
+    fn get_data(&self) -> &SamplerIntegratorData {
+        &self.data
+    }
+}
+
+impl Integrator for VolPathIntegrator {
+    /// Computes the light distribution for the scene. This must be called
+    /// before `li()`; `render()` does so.
+    ///
+    /// * `scene`    - The scene.
+    /// * `_sampler` - The sampler.
+    fn preprocess(&mut self, scene: Arc<Scene>, _sampler: &ArcSampler) {
+        self.light_distrib = Some(scene_light_sample_distribution(
+            &self.light_sample_strategy,
+            &scene,
+        ));
+    }
+
+    /// Render the scene.
+    ///
+    /// * `scene`     - The scene.
+    /// * `cancel`    - Token used to stop rendering early.
+    /// * `observers` - Observers notified of rendering progress.
+    fn render(
+        &mut self,
+        scene: Arc<Scene>,
+        cancel: &CancellationToken,
+        observers: &[ArcRenderObserver],
+    ) -> Result<(), Error> {
+        SamplerIntegrator::render(self, scene, cancel, observers)
+    }
+
+    /// Returns the incident radiance at the origin of a given ray. The events
+    /// along the path are recorded for light path expressions; light
+    /// re-radiated by fluorescent surfaces along BSDF samples isn't.
+    ///
+    /// * `r`       - The ray.
+    /// * `scene`   - The scene.
+    /// * `sampler` - The sampler.
+    /// * `arena`   - The memory arena for per-sample allocations.
+    /// * `depth`   - The recursion depth.
+    fn li(
+        &self,
+        r: &mut Ray,
+        scene: Arc<Scene>,
+        sampler: &mut ArcSampler,
+        _arena: &MemoryArena,
+        _depth: usize,
+    ) -> Spectrum {
+        let _p = ProfilePhase::new(Prof::SamplerIntegratorLi);
+
+        let mut l = Spectrum::new(0.0);
+        let mut throughput = PathThroughput::new();
+        let mut ray = r.clone();
+        let mut specular_bounce = false;
+        let mut any_non_specular_bounces = false;
+        let mut bounces = 0;
+
+        // Tracks the scaling of radiance by refraction so that it can be
+        // factored out of the throughput used for Russian roulette.
+        let mut eta_scale = 1.0;
+
+        loop {
+            // Intersect `ray` with scene and store intersection in `isect`.
+            let found_intersection = scene.intersect(&mut ray);
+
+            // Sample the participating medium, if present.
+            let mut mi = None;
+            if let Some(medium) = ray.medium.clone() {
+                let (weight, interaction) = medium.sample(&ray, sampler);
+                throughput.beta *= weight;
+                push_lpe_weight(weight);
+                mi = interaction;
+            }
+            if throughput.is_black() {
+                break;
+            }
+
+            if let Some(mi) = mi {
+                // Handle scattering at point in medium for volumetric path
+                // tracer.
+                if bounces >= self.max_depth {
+                    break;
+                }
+                let wo = mi.hit.wo;
+                let phase = mi.phase.clone();
+                let hit = mi.hit.clone();
+                let it = Interaction::Medium { mi };
+                let light_distrib = self.light_distrib.as_ref().map(|d| d.lookup(&hit.p));
+                l += throughput.apply(&uniform_sample_one_light(
+                    &it,
+                    scene.clone(),
+                    sampler,
+                    true,
+                    light_distrib,
+                ));
+
+                let u = Arc::get_mut(sampler).unwrap().get_2d();
+                let (_, wi) = phase.sample_p(&wo, &u);
+                push_lpe_event(
+                    || PathEvent::new(EventType::Volume, ScatterType::Diffuse),
+                    Spectrum::new(1.0),
+                );
+                ray = hit.spawn_ray(&wi);
+                specular_bounce = false;
+                any_non_specular_bounces = true;
+            } else {
+                // Handle scattering at point on surface for volumetric path
+                // tracer.
+                let mut isect = match found_intersection {
+                    Some(isect) => isect,
+                    None => {
+                        // Add emitted light from the environment.
+                        trace(|| TraceEvent::Miss { depth: bounces });
+                        if bounces == 0 || specular_bounce {
+                            l += throughput.apply(&Self::escaped_radiance(&ray, &scene));
+                        }
+                        break;
+                    }
+                };
+
+                // Held out surfaces render black.
+                if isect.primitive.is_some_and(|p| p.is_holdout()) {
+                    break;
+                }
+
+                // Possibly add emitted light at intersection.
+                if bounces == 0 || specular_bounce {
+                    let le = isect.le(&-ray.d);
+                    add_lpe_radiance(
+                        || vec![PathEvent::light(Self::area_light_index(&isect, &scene))],
+                        le,
+                    );
+                    l += throughput.apply(&le);
+                }
+
+                // Terminate path if maximum depth reached.
+                if bounces >= self.max_depth {
+                    break;
+                }
+
+                // Compute scattering functions and skip over medium
+                // boundaries.
+                isect.compute_scattering_functions(&ray, true, TransportMode::Radiance);
+                trace(|| TraceEvent::Hit {
+                    depth: bounces,
+                    primitive: isect
+                        .primitive
+                        .map_or(0, |p| p as *const dyn Primitive as *const () as usize),
+                    p: isect.hit.p,
+                    n: isect.hit.n,
+                    has_bsdf: isect.bsdf.is_some(),
+                });
+                let mut bsdf = match isect.bsdf.clone() {
+                    Some(bsdf) => bsdf,
+                    None => {
+                        ray = isect.hit.spawn_ray(&ray.d);
+                        continue;
+                    }
+                };
+                if self.regularize && any_non_specular_bounces {
+                    bsdf = Arc::new(bsdf.regularized());
+                    isect.bsdf = Some(bsdf.clone());
+                }
+                let hit = isect.hit.clone();
+                let ns = isect.shading.n;
+
+                // Sample illumination from lights to find attenuated path
+                // contribution.
+                let it = Interaction::Surface { si: isect };
+                if bsdf.num_components(BxDFType::from(BSDF_ALL & !BSDF_SPECULAR)) > 0 {
+                    let light_distrib = self.light_distrib.as_ref().map(|d| d.lookup(&hit.p));
+                    l += throughput.apply(&uniform_sample_one_light(
+                        &it,
+                        scene.clone(),
+                        sampler,
+                        true,
+                        light_distrib,
+                    ));
+                }
+
+                // Sample BSDF to get new path direction.
+                let wo = -ray.d;
+                let u = Arc::get_mut(sampler).unwrap().get_2d();
+                let BxDFSample {
+                    f,
+                    pdf,
+                    wi,
+                    sampled_type,
+                } = {
+                    let _p = ProfilePhase::new(Prof::BSDFSampling);
+                    bsdf.sample_f(&wo, &u, BxDFType::from(BSDF_ALL))
+                };
+                if (f.is_black() && bsdf.reradiation.is_none()) || pdf == 0.0 {
+                    break;
+                }
+                trace(|| TraceEvent::BsdfSample {
+                    depth: bounces,
+                    lobe: if sampled_type.matches(BSDF_TRANSMISSION) {
+                        "transmission"
+                    } else {
+                        "reflection"
+                    },
+                    wi,
+                    f,
+                    pdf,
+                    weight: f * wi.abs_dot(&ns) / pdf,
+                });
+                // Fluorescent surfaces also re-radiate light arriving from
+                // `wi` at other wavelengths.
+                throughput.scatter(&bsdf, &wo, &wi, &f, sampled_type, wi.abs_dot(&ns) / pdf);
+                push_lpe_event(|| bxdf_lpe_event(sampled_type), f * wi.abs_dot(&ns) / pdf);
+                debug_assert!(!throughput.beta.y().is_infinite());
+                specular_bounce = sampled_type.matches(BSDF_SPECULAR);
+                any_non_specular_bounces |= !specular_bounce;
+                if sampled_type.matches(BSDF_SPECULAR) && sampled_type.matches(BSDF_TRANSMISSION) {
+                    let eta = bsdf.eta;
+                    eta_scale *= if wo.dot(&Vector3f::from(hit.n)) > 0.0 {
+                        eta * eta
+                    } else {
+                        1.0 / (eta * eta)
+                    };
+                }
+                ray = hit.spawn_ray(&wi);
+            }
+
+            // Possibly terminate the path with Russian roulette. Factor out
+            // radiance scaling due to refraction in `rr_beta`.
+            let rr_beta = throughput.apply(&Spectrum::new(eta_scale));
+            if rr_beta.max_component_value() < self.rr_threshold && bounces > 3 {
+                let q = max(0.05, 1.0 - rr_beta.max_component_value());
+                if Arc::get_mut(sampler).unwrap().get_1d() < q {
+                    break;
+                }
+                throughput.beta /= 1.0 - q;
+                push_lpe_weight(Spectrum::new(1.0 / (1.0 - q)));
+                debug_assert!(!throughput.beta.y().is_infinite());
+            }
+
+            bounces += 1;
+        }
+
+        l
+    }
+}
+
+impl From<(&ParamSet, ArcSampler, ArcCamera)> for VolPathIntegrator {
+    /// Create a `VolPathIntegrator` from given parameter set and camera.
+    ///
+    /// * `p` - A tuple containing parameter set and camera.
+    fn from(p: (&ParamSet, ArcSampler, ArcCamera)) -> Self {
+        let (params, sampler, camera) = p;
+
+        let max_depth = params.find_one_int("maxdepth", 5).max(0) as usize;
+
+        let pb = params.find_int("pixelbounds");
+        let np = pb.len();
+
+        let mut pixel_bounds = camera.get_data().film.get_sample_bounds();
+        if np > 0 {
+            if np != 4 {
+                error!(
+                    target: INTEGRATOR,
+                    "Expected 4 values for 'pixelbounds' parameter. Got {}", np
+                );
+            } else {
+                pixel_bounds = pixel_bounds.intersect(&Bounds2i::new(
+                    Point2i::new(pb[0], pb[1]),
+                    Point2i::new(pb[2], pb[3]),
+                ));
+                if pixel_bounds.area() == 0 {
+                    error!(target: INTEGRATOR, "Degenerate 'pixelbounds' specified.");
+                }
+            }
+        }
+
+        let rr_threshold = params.find_one_float("rrthreshold", 1.0);
+
+        let light_sample_strategy =
+            params.find_one_string("lightsamplestrategy", String::from("spatial"));
+
+        let regularize = params.find_one_bool("regularize", false);
+
+        let mut integrator = Self::new(
+            max_depth,
+            camera.clone(),
+            sampler.clone(),
+            pixel_bounds,
+            rr_threshold,
+            &light_sample_strategy,
+            regularize,
+        );
+        integrator.data.max_variance = params.find_one_float("maxvariance", 0.0);
+        integrator.data.max_component_value = params.find_one_float("maxcomponentvalue", INFINITY);
+        integrator
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::api::*;
+    use crate::core::fileutil::*;
+    use crate::core::image_io::*;
+    use crate::core::parsers::PbrtFileParser;
+    use crate::core::spectrum::*;
+
+    /// Returns the sum of the RGB values of a render of a sphere lit by a
+    /// point light with the camera and light inside the given medium.
+    ///
+    /// * `integrator` - Name of the integrator.
+    /// * `medium`     - Statements that set up the medium.
+    fn render_fog(integrator: &str, medium: &str) -> u64 {
+        let scene = format!(
+            r#"
+            LookAt 0 0 5  0 0 0  0 1 0
+            {}
+            Camera "perspective" "float fov" [30]
+            Film "image" "integer xresolution" [16] "integer yresolution" [16]
+            Sampler "random" "integer pixelsamples" [16]
+            Integrator "{}" "integer maxdepth" [5]
+            WorldBegin
+            LightSource "point" "rgb I" [10 10 10] "point from" [2 2 3]
+            Material "matte" "rgb Kd" [0.5 0.5 0.5]
+            Shape "sphere" "float radius" [0.5]
+            WorldEnd
+            "#,
+            medium, integrator
+        );
+        let image = render_to_rgba(&scene, |_, _| {}).unwrap();
+        image
+            .pixels
+            .chunks(4)
+            .map(|p| p[0] as u64 + p[1] as u64 + p[2] as u64)
+            .sum()
+    }
+
+    #[test]
+    fn fog_scatters_light_towards_the_camera() {
+        let fog = r#"
+            MakeNamedMedium "fog" "string type" "heterogeneous"
+                "float density" [1] "rgb sigma_a" [0.05 0.05 0.05]
+                "rgb sigma_s" [0.5 0.5 0.5]
+                "point p0" [-10 -10 -10] "point p1" [10 10 10]
+            MediumInterface "" "fog"
+        "#;
+
+        // Without media the path tracer agrees with the Whitted integrator
+        // on a diffuse scene lit directly.
+        let vacuum = render_fog("volpath", "");
+        assert!(vacuum > 0);
+
+        // Light scattered by the fog brightens the background.
+        let foggy = render_fog("volpath", fog);
+        assert!(foggy > vacuum, "{} <= {}", foggy, vacuum);
+
+        // The Whitted integrator ignores media.
+        assert_eq!(render_fog("whitted", fog), render_fog("whitted", ""));
+    }
+
+    #[test]
+    fn light_path_expressions_record_path_events() {
+        let dir = std::env::temp_dir().join("pbr_rust_volpath_lpe");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("image.exr");
+        let path = path.to_str().unwrap();
+        let scene = format!(
+            r#"
+            LookAt 0 0 5  0 0 0  0 1 0
+            Camera "perspective" "float fov" [4]
+            Film "image" "integer xresolution" [4] "integer yresolution" [4]
+                "string filename" "{}"
+                "string lpe" ["diffuse" "C<RD>L" "mirror" "C<RS>.*L"]
+            Sampler "random" "integer pixelsamples" [4]
+            Integrator "volpath" "integer maxdepth" [3]
+            WorldBegin
+            LightSource "point" "rgb I" [10 10 10] "point from" [0 0 3]
+            Material "matte" "rgb Kd" [0.5 0.5 0.5]
+            Shape "sphere" "float radius" [0.5]
+            WorldEnd
+            "#,
+            path
+        );
+        let mut api = Api::new();
+        api.pbrt_init();
+        PbrtFileParser::in_memory()
+            .parse_source(&scene, &mut api)
+            .unwrap();
+        api.pbrt_cleanup();
+
+        // All light is reflected diffusely once on the way to the camera.
+        let image = read_image(path).unwrap();
+        let diffuse = read_image(&suffixed_path(path, "diffuse")).unwrap();
+        let mirror = read_image(&suffixed_path(path, "mirror")).unwrap();
+        for ((p, d), m) in image.pixels.iter().zip(diffuse.pixels).zip(mirror.pixels) {
+            assert!(p.y() > 0.0);
+            assert!((p.y() - d.y()).abs() < 1e-3 * p.y(), "{} != {}", p, d);
+            assert!(m.is_black());
+        }
+    }
+
+    #[test]
+    fn fluorescence_shows_up_in_indirect_light() {
+        // A white sphere is lit by a blue light and by a fluorescent wall
+        // behind the camera that turns the blue light red. Only the light
+        // bounced off the wall is red.
+        let scene = r#"
+            LookAt 0 0 5  0 0 0  0 1 0
+            Camera "perspective" "float fov" [4]
+            Film "image" "integer xresolution" [4] "integer yresolution" [4]
+            Sampler "random" "integer pixelsamples" [16]
+            Integrator "volpath" "integer maxdepth" [2]
+            WorldBegin
+            LightSource "point" "rgb I" [0 0 50] "point from" [0 0 7]
+            AttributeBegin
+            Translate 0 0 8
+            Material "fluorescent" "rgb Kd" [0 0 0] "rgb absorption" [0 0 1]
+                "rgb emission" [1 0 0]
+            Shape "disk" "float radius" [20]
+            AttributeEnd
+            Material "matte" "rgb Kd" [1 1 1]
+            Shape "sphere" "float radius" [0.5]
+            WorldEnd
+        "#;
+        let image = render_to_rgba(scene, |_, _| {}).unwrap();
+        for p in image.pixels.chunks(4) {
+            assert!(p[0] > 0 && p[2] > 0, "{:?}", p);
+        }
+    }
+
+    /// Returns the RGB values of a render of a floor lit by a small, bright
+    /// light through a glass sphere.
+    ///
+    /// * `options` - Extra integrator parameters.
+    fn render_caustic(options: &str) -> Vec<u8> {
+        let scene = format!(
+            r#"
+            LookAt 0 4 4  0 0 0  0 1 0
+            Camera "perspective" "float fov" [40]
+            Film "image" "integer xresolution" [16] "integer yresolution" [16]
+            Sampler "random" "integer pixelsamples" [16]
+            Integrator "volpath" "integer maxdepth" [5] {}
+            WorldBegin
+            AttributeBegin
+              AreaLightSource "diffuse" "rgb L" [5000 5000 5000]
+              Translate 0 3 0
+              Shape "sphere" "float radius" [0.05]
+            AttributeEnd
+            AttributeBegin
+              Material "glass"
+              Translate 0 1 0
+              Shape "sphere" "float radius" [0.7]
+            AttributeEnd
+            Material "matte" "rgb Kd" [0.5 0.5 0.5]
+            Shape "trianglemesh" "integer indices" [0 1 2 0 2 3]
+                "point P" [-3 0 -3  3 0 -3  3 0 3  -3 0 3]
+            WorldEnd
+            "#,
+            options
+        );
+        let image = render_to_rgba(&scene, |_, _| {}).unwrap();
+        image
+            .pixels
+            .chunks(4)
+            .flat_map(|p| p[..3].to_vec())
+            .collect()
+    }
+
+    /// Returns the sum of absolute differences between horizontally adjacent
+    /// values of a 16x16 RGB image, which grows with noise.
+    ///
+    /// * `rgb` - RGB values of the image.
+    fn roughness(rgb: &[u8]) -> u64 {
+        rgb.chunks(16 * 3)
+            .flat_map(|row| row.iter().zip(row[3..].iter()))
+            .map(|(&a, &b)| (a as i64 - b as i64).unsigned_abs())
+            .sum()
+    }
+
+    // The thresholds are tuned for the RGB spectra of the default build.
+    #[cfg(not(feature = "sampled-spectrum"))]
+    #[test]
+    fn clamping_and_regularization_reduce_fireflies() {
+        let reference = render_caustic("");
+        assert!(reference.contains(&255));
+
+        // Clamping keeps every sample, and so every pixel, below 0.5.
+        let clamped = render_caustic(r#""float maxcomponentvalue" [0.5]"#);
+        assert!(clamped.iter().all(|&v| v < 200));
+
+        // Regularization lets light sampling find the light through the glass
+        // once the path has bounced off the floor.
+        let regularized = render_caustic(r#""bool regularize" "true""#);
+        assert!(roughness(&regularized) < roughness(&reference));
+    }
+}
@@ -2,13 +2,22 @@
 
 #![allow(dead_code)]
 
+use crate::core::arena::*;
 use crate::core::camera::*;
+use crate::core::cancel::*;
+use crate::core::error::*;
 use crate::core::geometry::*;
 use crate::core::integrator::*;
 use crate::core::light::*;
+use crate::core::logging::INTEGRATOR;
+use crate::core::lpe::*;
 use crate::core::material::*;
 use crate::core::paramset::*;
+use crate::core::pbrt::*;
+use crate::core::primitive::*;
+use crate::core::profiler::*;
 use crate::core::reflection::*;
+use crate::core::rng::*;
 use crate::core::sampler::*;
 use crate::core::scene::*;
 use crate::core::spectrum::*;
@@ -21,6 +30,15 @@ pub struct WhittedIntegrator {
 
     /// Maximum recursion depth.
     max_depth: usize,
+
+    /// Carry radiance as Stokes vectors so that polarization due to Fresnel
+    /// reflection and refraction is accounted for.
+    polarized: bool,
+
+    /// Optional angle in radians of the transmission axis of a linear
+    /// polarizing filter in front of the camera. It is measured from the
+    /// camera's x-axis. Only used when `polarized` is `true`.
+    polarizer: Option<Float>,
 }
 
 impl WhittedIntegrator {
@@ -30,17 +48,263 @@ impl WhittedIntegrator {
     /// * `camera`       - The camera.
     /// * `sampler`      - The sampler.
     /// * `pixel_bounds` - Pixel bounds for the image.
+    /// * `polarized`    - Carry radiance as Stokes vectors so that
+    ///                    polarization due to Fresnel reflection and
+    ///                    refraction is accounted for.
+    /// * `polarizer`    - Optional angle in radians of the transmission axis of
+    ///                    a linear polarizing filter in front of the camera
+    ///                    measured from the camera's x-axis.
     pub fn new(
         max_depth: usize,
         camera: ArcCamera,
         sampler: ArcSampler,
         pixel_bounds: Bounds2i,
+        polarized: bool,
+        polarizer: Option<Float>,
     ) -> Self {
         Self {
             data: SamplerIntegratorData::new(camera, sampler, pixel_bounds),
             max_depth,
+            polarized,
+            polarizer,
+        }
+    }
+
+    /// Returns the radiance reflected at a surface interaction directly from
+    /// the light sources.
+    ///
+    /// * `isect`   - The surface interaction.
+    /// * `scene`   - The scene.
+    /// * `sampler` - The sampler.
+    fn direct_lighting(
+        &self,
+        isect: &SurfaceInteraction,
+        scene: Arc<Scene>,
+        sampler: &mut ArcSampler,
+        depth: usize,
+    ) -> Spectrum {
+        let _p = ProfilePhase::new(Prof::DirectLighting);
+        let mut l = Spectrum::new(0.0);
+
+        let n = isect.shading.n;
+        let wo = isect.hit.wo;
+        let bsdf = isect.bsdf.clone().unwrap();
+
+        // Add contribution of each light source.
+        for (light_index, light) in scene.lights.iter().enumerate() {
+            let sample = Arc::get_mut(sampler).unwrap().get_2d();
+            let Li {
+                wi,
+                pdf,
+                visibility,
+                value: li,
+            } = {
+                let _p = ProfilePhase::new(Prof::LightSample);
+                light.sample_li(&isect.hit, &sample)
+            };
+
+            if li.is_black() || pdf == 0.0 {
+                continue;
+            }
+
+            let bsdf_flags = BxDFType::from(BSDF_ALL);
+            let f = bsdf.f(&wo, &wi, bsdf_flags);
+
+            // If no visiblity tester, then unoccluded = true.
+            let fluorescent = bsdf.reradiation.is_some();
+            let unoccluded = (!f.is_black() || fluorescent)
+                && visibility.map_or(true, |vis| vis.unoccluded(scene.clone()));
+            trace(|| TraceEvent::LightSample {
+                depth,
+                light: light_index,
+                wi,
+                li,
+                pdf,
+                f,
+                unoccluded,
+            });
+            if unoccluded {
+                let scattered = f * li + bsdf.reradiate(&wo, &wi, &li, bsdf_flags);
+                l += scattered * wi.abs_dot(&n) / pdf;
+
+                if is_recording_lpe_path() {
+                    // Record the light scattered by each lobe separately.
+                    for &(event_type, lobe) in [
+                        (EventType::Reflection, BSDF_REFLECTION),
+                        (EventType::Transmission, BSDF_TRANSMISSION),
+                    ]
+                    .iter()
+                    {
+                        for &(scatter, s) in [
+                            (ScatterType::Diffuse, BSDF_DIFFUSE),
+                            (ScatterType::Glossy, BSDF_GLOSSY),
+                            (ScatterType::Specular, BSDF_SPECULAR),
+                        ]
+                        .iter()
+                        {
+                            let flags = BxDFType::from(lobe | s);
+                            let scattered =
+                                bsdf.f(&wo, &wi, flags) * li + bsdf.reradiate(&wo, &wi, &li, flags);
+                            add_lpe_radiance(
+                                || {
+                                    vec![
+                                        PathEvent::new(event_type, scatter),
+                                        PathEvent::light(Some(light_index)),
+                                    ]
+                                },
+                                scattered * wi.abs_dot(&n) / pdf,
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
+        l
+    }
+
+    /// Returns the index of the area light of the primitive at a surface
+    /// interaction among the scene's lights; or `None` if it has none.
+    ///
+    /// * `isect` - The surface interaction.
+    /// * `scene` - The scene.
+    fn area_light_index(isect: &SurfaceInteraction, scene: &Scene) -> Option<usize> {
+        let area_light = isect.primitive.and_then(|p| p.get_area_light())?;
+        let ptr = Arc::as_ptr(&area_light) as *const ();
+        scene
+            .lights
+            .iter()
+            .position(|light| Arc::as_ptr(light) as *const () == ptr)
+    }
+
+    /// Returns the radiance arriving at the origin of a given ray as a Stokes
+    /// vector along with its reference direction.
+    ///
+    /// Light sources and non-specular scattering are treated as unpolarized;
+    /// the Mueller matrices of specular BxDFs account for polarization due to
+    /// Fresnel reflection and refraction.
+    ///
+    /// * `ray`     - The ray.
+    /// * `scene`   - The scene.
+    /// * `sampler` - The sampler.
+    /// * `depth`   - The recursion depth.
+    fn li_polarized(
+        &self,
+        ray: &mut Ray,
+        scene: Arc<Scene>,
+        sampler: &mut ArcSampler,
+        depth: usize,
+    ) -> (Stokes, Vector3f) {
+        if let Some(mut isect) = scene.intersect(ray) {
+            // Held out surfaces render black.
+            if isect.primitive.is_some_and(|p| p.is_holdout()) {
+                let (x, _) = coordinate_system(&(-ray.d).normalize());
+                return (Stokes::unpolarized(Spectrum::new(0.0)), x);
+            }
+            let wo = isect.hit.wo.normalize();
+
+            // Compute scattering functions for surface interaction.
+            isect.compute_scattering_functions(ray, false, TransportMode::Radiance);
+            trace(|| TraceEvent::Hit {
+                depth,
+                primitive: isect
+                    .primitive
+                    .map_or(0, |p| p as *const dyn Primitive as *const () as usize),
+                p: isect.hit.p,
+                n: isect.hit.n,
+                has_bsdf: isect.bsdf.is_some(),
+            });
+            if isect.bsdf.is_none() {
+                let mut new_ray = isect.hit.spawn_ray(&ray.d);
+                return self.li_polarized(&mut new_ray, scene.clone(), sampler, depth);
+            }
+
+            // Emitted and directly reflected light is unpolarized.
+            let l = isect.le(&wo) + self.direct_lighting(&isect, scene.clone(), sampler, depth);
+            let mut s = Stokes::unpolarized(l);
+            let (x, _) = coordinate_system(&wo);
+
+            if depth + 1 < self.max_depth {
+                // Trace rays for specular reflection and refraction and
+                // measure the results relative to `x`.
+                for &flags in [
+                    BSDF_REFLECTION | BSDF_SPECULAR,
+                    BSDF_TRANSMISSION | BSDF_SPECULAR,
+                ]
+                .iter()
+                {
+                    let bxdf_type = BxDFType::from(flags);
+                    if let Some((ls, lx)) =
+                        self.specular_polarized(&isect, scene.clone(), sampler, depth, bxdf_type)
+                    {
+                        s += ls.rotate_frame(&wo, &lx, &x);
+                    }
+                }
+            }
+
+            (s, x)
+        } else {
+            trace(|| TraceEvent::Miss { depth });
+            let mut l = Spectrum::new(0.0);
+            for light in scene.lights.iter() {
+                l += light.le(ray);
+            }
+            let (x, _) = coordinate_system(&(-ray.d).normalize());
+            (Stokes::unpolarized(l), x)
         }
     }
+
+    /// Trace a ray for specular reflection or refraction and returns the
+    /// scattered radiance as a Stokes vector along with its reference
+    /// direction.
+    ///
+    /// * `isect`     - The surface interaction.
+    /// * `scene`     - The scene.
+    /// * `sampler`   - The sampler.
+    /// * `depth`     - The recursion depth.
+    /// * `bxdf_type` - Specular reflection or transmission.
+    fn specular_polarized(
+        &self,
+        isect: &SurfaceInteraction,
+        scene: Arc<Scene>,
+        sampler: &mut ArcSampler,
+        depth: usize,
+        bxdf_type: BxDFType,
+    ) -> Option<(Stokes, Vector3f)> {
+        let bsdf = isect.bsdf.clone()?;
+        let wo = isect.hit.wo.normalize();
+        let ns = isect.shading.n;
+
+        let sample = Arc::get_mut(sampler).unwrap().get_2d();
+        let BxDFSample {
+            f,
+            pdf,
+            wi,
+            sampled_type: _,
+        } = bsdf.sample_f(&wo, &sample, bxdf_type);
+        if pdf == 0.0 || f.is_black() || wi.abs_dot(&ns) == 0.0 {
+            return None;
+        }
+
+        let mut rd = isect.hit.spawn_ray(&wi);
+        let (li, lx) = self.li_polarized(&mut rd, scene.clone(), sampler, depth + 1);
+
+        // Measure the incident light relative to the normal of the plane of
+        // incidence. At normal incidence any reference direction will do.
+        let wi = wi.normalize();
+        let perp = wi.cross(&Vector3f::from(ns));
+        let perp = if perp.length_squared() > 1e-12 {
+            perp.normalize()
+        } else {
+            lx
+        };
+        let li = li.rotate_frame(&-wi, &lx, &perp);
+
+        let m = bsdf
+            .mueller(&wo, &wi, bxdf_type)
+            .unwrap_or_else(|| MuellerMatrix::from(f * wi.abs_dot(&ns)));
+        Some(((m * (1.0 / pdf)) * li, perp))
+    }
 }
 
 impl SamplerIntegrator for WhittedIntegrator {
@@ -48,14 +312,137 @@ impl SamplerIntegrator for WhittedIntegrator {
     fn get_data(&self) -> &SamplerIntegratorData {
         &self.data
     }
+
+    /// Returns the shading stage used to render tiles with a wavefront of
+    /// rays; or `None` for polarized rendering which is only supported by
+    /// the tile loop.
+    fn wavefront_shader(&self) -> Option<&dyn WavefrontShader> {
+        if self.polarized {
+            None
+        } else {
+            Some(self)
+        }
+    }
+}
+
+impl WavefrontShader for WhittedIntegrator {
+    /// Shades the intersection of a ray and returns the emitted radiance
+    /// weighted by `beta`. Direct lighting is queued as shadow rays and
+    /// specular reflection and transmission as rays that continue the path.
+    ///
+    /// Unlike `li()`, rays spawned for specular reflection and transmission
+    /// carry no differentials.
+    ///
+    /// * `ray`         - The ray.
+    /// * `isect`       - The surface interaction.
+    /// * `beta`        - Path throughput.
+    /// * `depth`       - Recursion depth.
+    /// * `path`        - Index of the path the ray belongs to.
+    /// * `scene`       - The scene.
+    /// * `rng`         - Random number generator of the path.
+    /// * `shadow_rays` - Queue for shadow rays.
+    /// * `next_rays`   - Queue for rays that continue the path.
+    fn shade(
+        &self,
+        ray: &Ray,
+        isect: &mut SurfaceInteraction,
+        beta: Spectrum,
+        depth: usize,
+        path: usize,
+        scene: &Scene,
+        rng: &mut RNG,
+        shadow_rays: &mut ShadowRayQueue,
+        next_rays: &mut RayQueue,
+    ) -> Spectrum {
+        let wo = isect.hit.wo;
+
+        // Held out surfaces render black.
+        if isect.primitive.is_some_and(|p| p.is_holdout()) {
+            return Spectrum::new(0.0);
+        }
+
+        // Compute scattering functions for surface interaction.
+        isect.compute_scattering_functions(ray, false, TransportMode::Radiance);
+        let bsdf = match isect.bsdf.clone() {
+            Some(bsdf) => bsdf,
+            None => {
+                next_rays.push(&isect.hit.spawn_ray(&ray.d), path, beta, depth);
+                return Spectrum::new(0.0);
+            }
+        };
+
+        // Compute emitted light if ray hit an area light source.
+        let mut l = isect.le(&wo);
+
+        // Queue a shadow ray for each light source.
+        let n = isect.shading.n;
+        let bsdf_flags = BxDFType::from(BSDF_ALL);
+        for light in scene.lights.iter() {
+            let sample = Point2f::new(rng.uniform(), rng.uniform());
+            let Li {
+                wi,
+                pdf,
+                visibility,
+                value: li,
+            } = {
+                let _p = ProfilePhase::new(Prof::LightSample);
+                light.sample_li(&isect.hit, &sample)
+            };
+            if li.is_black() || pdf == 0.0 {
+                continue;
+            }
+
+            let f = bsdf.f(&wo, &wi, bsdf_flags);
+            if !f.is_black() || bsdf.reradiation.is_some() {
+                let scattered = f * li + bsdf.reradiate(&wo, &wi, &li, bsdf_flags);
+                let ld = scattered * wi.abs_dot(&n) / pdf;
+                match visibility {
+                    Some(vis) => shadow_rays.push(&vis.p0.spawn_ray_to(&vis.p1), path, beta * ld),
+                    None => l += ld,
+                }
+            }
+        }
+
+        if depth + 1 < self.max_depth {
+            // Queue rays for specular reflection and refraction.
+            for &flags in [
+                BSDF_REFLECTION | BSDF_SPECULAR,
+                BSDF_TRANSMISSION | BSDF_SPECULAR,
+            ]
+            .iter()
+            {
+                let sample = Point2f::new(rng.uniform(), rng.uniform());
+                let BxDFSample {
+                    f,
+                    pdf,
+                    wi,
+                    sampled_type: _,
+                } = bsdf.sample_f(&wo, &sample, BxDFType::from(flags));
+                let cos = wi.abs_dot(&isect.shading.n);
+                if pdf > 0.0 && !f.is_black() && cos != 0.0 {
+                    let rd = isect.hit.spawn_ray(&wi);
+                    next_rays.push(&rd, path, beta * f * cos / pdf, depth + 1);
+                }
+            }
+        }
+
+        beta * l
+    }
 }
 
 impl Integrator for WhittedIntegrator {
     /// Render the scene.
     ///
-    /// * `scene` - The scene.
-    fn render(&mut self, scene: Arc<Scene>) {
-        SamplerIntegrator::render(self, scene)
+    /// * `scene`     - The scene.
+    /// * `cancel`    - Token used to stop rendering early.
+    /// * `observers` - Observers notified of rendering progress.
+    fn render(
+        &mut self,
+        scene: Arc<Scene>,
+        cancel: &CancellationToken,
+        observers: &[ArcRenderObserver],
+    ) -> Result<(), Error> {
+        SamplerIntegrator::render(self, scene, cancel, observers)
     }
 
     /// Returns the incident radiance at the origin of a given ray.
@@ -63,60 +450,78 @@ impl Integrator for WhittedIntegrator {
     /// * `ray`     - The ray.
     /// * `scene`   - The scene.
     /// * `sampler` - The sampler.
+    /// * `arena`   - The memory arena for per-sample allocations.
     /// * `depth`   - The recursion depth.
     fn li(
         &self,
         ray: &mut Ray,
         scene: Arc<Scene>,
         sampler: &mut ArcSampler,
+        arena: &MemoryArena,
         depth: usize,
     ) -> Spectrum {
+        if self.polarized {
+            let (s, x) = self.li_polarized(ray, scene, sampler, depth);
+            return match self.polarizer {
+                Some(theta) => {
+                    // Measure the light relative to the camera's x-axis and
+                    // pass it through the polarizing filter.
+                    let d = (-ray.d).normalize();
+                    let camera_x = self
+                        .data
+                        .camera
+                        .get_data()
+                        .camera_to_world
+                        .transform_vector(ray.time, &Vector3f::new(1.0, 0.0, 0.0));
+                    let axis = (camera_x - d * camera_x.dot(&d)).normalize();
+                    let s = s.rotate_frame(&d, &x, &axis);
+                    (MuellerMatrix::linear_polarizer(theta) * s).intensity()
+                }
+                None => s.intensity(),
+            };
+        }
+
         let mut l = Spectrum::new(0.0);
 
         // Find closest ray intersection or return background radiance.
         if let Some(mut isect) = scene.intersect(ray) {
+            // Held out surfaces render black.
+            if isect.primitive.is_some_and(|p| p.is_holdout()) {
+                return l;
+            }
+
             // Compute emitted and reflected light at ray intersection point.
 
             // Initialize common variables for Whitted integrator.
-            let n = isect.shading.n;
             let wo = isect.hit.wo;
 
             // Compute scattering functions for surface interaction.
             isect.compute_scattering_functions(ray, false, TransportMode::Radiance);
+            trace(|| TraceEvent::Hit {
+                depth,
+                primitive: isect
+                    .primitive
+                    .map_or(0, |p| p as *const dyn Primitive as *const () as usize),
+                p: isect.hit.p,
+                n: isect.hit.n,
+                has_bsdf: isect.bsdf.is_some(),
+            });
             if isect.bsdf.is_none() {
                 let mut new_ray = isect.hit.spawn_ray(&ray.d);
-                return self.li(&mut new_ray, scene.clone(), sampler, depth);
+                return self.li(&mut new_ray, scene.clone(), sampler, arena, depth);
             }
 
             // Compute emitted light if ray hit an area light source.
-            l += isect.le(&wo);
+            let le = isect.le(&wo);
+            add_lpe_radiance(
+                || vec![PathEvent::light(Self::area_light_index(&isect, &scene))],
+                le,
+            );
+            l += le;
 
             // Add contribution of each light source.
-            for light in scene.lights.iter() {
-                let sample = Arc::get_mut(sampler).unwrap().get_2d();
-                let Li {
-                    wi,
-                    pdf,
-                    visibility,
-                    value: li,
-                } = light.sample_li(&isect.hit, &sample);
-
-                if li.is_black() || pdf == 0.0 {
-                    continue;
-                }
+            l += self.direct_lighting(&isect, scene.clone(), sampler, depth);
 
-                let f = isect
-                    .bsdf
-                    .clone()
-                    .unwrap()
-                    .f(&wo, &wi, BxDFType::from(BSDF_ALL));
-
-                // If no visiblity tester, then unoccluded = true.
-                let unoccluded = visibility.map_or(true, |vis| vis.unoccluded(scene.clone()));
-                if !f.is_black() && unoccluded {
-                    l += f * li * wi.abs_dot(&n) / pdf;
-                }
-            }
             if depth + 1 < self.max_depth {
                 // Trace rays for specular reflection and refraction.
                 l += SamplerIntegrator::specular_reflect(
@@ -124,7 +529,8 @@ impl Integrator for WhittedIntegrator {
                     ray,
                     &isect,
                     scene.clone(),
-                    sampler.clone(),
+                    sampler,
+                    arena,
                     depth,
                 );
                 l += SamplerIntegrator::specular_transmit(
@@ -132,15 +538,17 @@ impl Integrator for WhittedIntegrator {
                     ray,
                     &isect,
                     scene.clone(),
-                    sampler.clone(),
+                    sampler,
+                    arena,
                     depth,
                 );
             }
         } else {
-            if let Some(rd) = ray.differentials {
-                for light in scene.lights.iter() {
-                    l += light.le(&rd);
-                }
+            trace(|| TraceEvent::Miss { depth });
+            for (light_index, light) in scene.lights.iter().enumerate() {
+                let le = light.le(ray);
+                add_lpe_radiance(|| vec![PathEvent::light(Some(light_index))], le);
+                l += le;
             }
         }
 
@@ -155,26 +563,102 @@ impl From<(&ParamSet, ArcSampler, ArcCamera)> for WhittedIntegrator {
     fn from(p: (&ParamSet, ArcSampler, ArcCamera)) -> Self {
         let (params, sampler, camera) = p;
 
-        let max_depth = params.find_one_int("max_depth", 5) as usize;
+        let max_depth = params.find_one_int("maxdepth", 5) as usize;
 
-        let pb = params.find_int("pixel_bounds");
+        let pb = params.find_int("pixelbounds");
         let np = pb.len();
 
         let mut pixel_bounds = camera.get_data().film.get_sample_bounds();
         if np > 0 {
             if np != 4 {
-                error!("Expected 4 values for 'pixel_bounds' parameter. Got {}", np);
+                error!(
+                    target: INTEGRATOR,
+                    "Expected 4 values for 'pixelbounds' parameter. Got {}",
+                    np
+                );
             } else {
                 pixel_bounds = pixel_bounds.intersect(&Bounds2i::new(
                     Point2i::new(pb[0], pb[1]),
                     Point2i::new(pb[2], pb[3]),
                 ));
                 if pixel_bounds.area() == 0 {
-                    error!("Degenerate 'pixel_bounds' specified.");
+                    error!(target: INTEGRATOR, "Degenerate 'pixelbounds' specified.");
                 }
             }
         }
 
-        Self::new(max_depth, camera.clone(), sampler.clone(), pixel_bounds)
+        let polarizer = params.find_float("polarizer");
+        let polarizer = if polarizer.is_empty() {
+            None
+        } else {
+            Some(polarizer[0].to_radians())
+        };
+        let polarized = params.find_one_bool("polarized", polarizer.is_some());
+        if polarizer.is_some() && !polarized {
+            warn!(
+                target: INTEGRATOR,
+                "'polarizer' parameter is ignored when 'polarized' is false."
+            );
+        }
+
+        let mut integrator = Self::new(
+            max_depth,
+            camera.clone(),
+            sampler.clone(),
+            pixel_bounds,
+            polarized,
+            polarizer,
+        );
+        integrator.data.max_variance = params.find_one_float("maxvariance", 0.0);
+        integrator.data.max_component_value = params.find_one_float("maxcomponentvalue", INFINITY);
+        integrator
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::api::*;
+
+    /// Returns the sum of the RGB values of a render of a mirror sphere that
+    /// reflects a diffuse sphere behind the camera.
+    ///
+    /// * `max_depth` - Maximum recursion depth.
+    fn render_mirror(max_depth: i32) -> u64 {
+        let scene = format!(
+            r#"
+            LookAt 0 0 5  0 0 0  0 1 0
+            Camera "perspective" "float fov" [30]
+            Film "image" "integer xresolution" [16] "integer yresolution" [16]
+            Sampler "random" "integer pixelsamples" [4]
+            Integrator "whitted" "integer maxdepth" [{}]
+            WorldBegin
+            LightSource "point" "rgb I" [20 20 20] "point from" [0 3 7]
+            AttributeBegin
+              Material "mirror"
+              Shape "sphere" "float radius" [1]
+            AttributeEnd
+            AttributeBegin
+              Material "matte" "rgb Kd" [0.8 0.8 0.8]
+              Translate 0 0 12
+              Shape "sphere" "float radius" [3]
+            AttributeEnd
+            WorldEnd
+            "#,
+            max_depth
+        );
+        let image = render_to_rgba(&scene, |_, _| {}).unwrap();
+        image
+            .pixels
+            .chunks(4)
+            .map(|p| p[0] as u64 + p[1] as u64 + p[2] as u64)
+            .sum()
+    }
+
+    #[test]
+    fn mirrors_reflect_up_to_the_maximum_depth() {
+        // Perfect mirrors receive no direct lighting so they only show what
+        // they reflect.
+        assert_eq!(render_mirror(1), 0);
+        assert!(render_mirror(2) > 0);
     }
 }
@@ -0,0 +1,42 @@
+//! A physically based renderer following the design of PBRT-v3.
+//!
+//! The renderer is driven by the `pbr-rust` binary. The `core` module is
+//! public so that other crates can reuse its building blocks, e.g. the
+//! sequences in `core::low_discrepency` for custom samplers.
+
+#![feature(iter_partition_in_place)] // Can be removed once stable
+#![cfg_attr(feature = "simd", feature(portable_simd))]
+
+extern crate byteorder;
+extern crate clap;
+#[macro_use]
+extern crate log;
+extern crate env_logger;
+extern crate exr;
+extern crate float_cmp;
+#[macro_use]
+extern crate hexf;
+extern crate image;
+extern crate itertools;
+#[macro_use]
+extern crate lazy_static;
+extern crate num_traits;
+extern crate ordered_float;
+extern crate pest;
+#[macro_use]
+extern crate pest_derive;
+extern crate rand;
+extern crate typed_arena;
+
+#[macro_use]
+pub mod core;
+mod accelerators;
+mod cameras;
+mod filters;
+mod integrators;
+mod lights;
+mod materials;
+mod media;
+mod samplers;
+mod shapes;
+mod textures;
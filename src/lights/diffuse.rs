@@ -78,18 +78,6 @@ impl DiffuseAreaLight {
             area,
         }
     }
-
-    /// Returns emitted radiance based on `two_sided` flag.
-    ///
-    /// * `intr` - The interaction point.
-    /// * `w`    - Direction.
-    fn l(&self, intr: &Hit, w: &Vector3f) -> Spectrum {
-        if self.two_sided || intr.n.dot(w) > 0.0 {
-            self.l_emit
-        } else {
-            Spectrum::new(0.0)
-        }
-    }
 }
 
 impl Light for DiffuseAreaLight {
@@ -188,6 +176,22 @@ impl Light for DiffuseAreaLight {
     }
 }
 
+impl AreaLight for DiffuseAreaLight {
+    /// Returns emitted radiance based on `two_sided` flag. One-sided lights
+    /// only emit on the side the surface normal faces, which accounts for
+    /// `ReverseOrientation` and transforms that swap handedness.
+    ///
+    /// * `hit` - The interaction point.
+    /// * `w`   - Direction.
+    fn l(&self, hit: &Hit, w: &Vector3f) -> Spectrum {
+        if self.two_sided || hit.n.dot(w) > 0.0 {
+            self.l_emit
+        } else {
+            Spectrum::new(0.0)
+        }
+    }
+}
+
 impl From<(&ParamSet, ArcTransform, Option<ArcMedium>, ArcShape)> for DiffuseAreaLight {
     /// Create a `DiffuseAreaLight` from given parameter set, light to world transform
     /// medium, and shape.
@@ -8,12 +8,12 @@ use crate::core::image_io::*;
 use crate::core::light::*;
 use crate::core::medium::*;
 use crate::core::mipmap::*;
+use crate::core::parallel::*;
 use crate::core::paramset::*;
 use crate::core::pbrt::*;
 use crate::core::sampling::*;
 use crate::core::scene::*;
 use crate::core::spectrum::*;
-use rayon::prelude::*;
 use std::sync::Arc;
 
 /// Implements an infinite area light source using a latitude-longitude radiance
@@ -190,6 +190,17 @@ impl Light for InfiniteAreaLight {
         PI * self.world_radius * self.world_radius * spectrum
     }
 
+    /// Returns emitted radiance due to that light along a ray that escapes the
+    /// scene bounds.
+    ///
+    /// * `ray` - The ray.
+    fn le(&self, ray: &Ray) -> Spectrum {
+        let w = self.world_to_light.transform_vector(&ray.d).normalize();
+        let st = Point2f::new(spherical_phi(&w) * INV_TWO_PI, spherical_theta(&w) * INV_PI);
+        let rgb = self.l_map.lookup_triangle(&st, 0.0).to_rgb();
+        Spectrum::from_rgb(&rgb, Some(SpectrumType::Illuminant))
+    }
+
     /// Returns the probability density with respect to solid angle for the light’s
     /// `sample_li()`.
     ///
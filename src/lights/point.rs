@@ -134,8 +134,32 @@ impl From<(&ParamSet, ArcTransform, Option<ArcMedium>)> for PointLight {
 
         let intensity = params.find_one_spectrum("I", Spectrum::new(1.0));
         let sc = params.find_one_spectrum("scale", Spectrum::new(1.0));
+        let mut intensity = intensity * sc;
+
+        // Scale the intensity so that the light emits the given luminous
+        // flux; the luminance of spectra is in photometric units.
+        let power = params.find_one_float("power", 0.0);
+        let y = intensity.y();
+        if power > 0.0 && y > 0.0 {
+            intensity *= power / (FOUR_PI * y);
+        }
+
         let p = params.find_one_point3f("from", Point3f::default());
         let l2w = Transform::translate(&Vector3f::new(p.x, p.y, p.z)) * *light_to_world;
-        Self::new(Arc::new(l2w), MediumInterface::from(medium), intensity * sc)
+        Self::new(Arc::new(l2w), MediumInterface::from(medium), intensity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn power_sets_the_luminous_flux() {
+        let mut params = ParamSet::new();
+        params.add_rgb_spectrum("I", &[1.0, 0.5, 0.25]).unwrap();
+        params.add_float("power", &[800.0]);
+        let light = PointLight::from((&params, Arc::new(Transform::default()), None));
+        assert!((light.power().y() - 800.0).abs() < 0.01);
     }
 }
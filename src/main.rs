@@ -1,61 +1,152 @@
-#![feature(iter_partition_in_place)] // Can be removed once stable
-
-extern crate byteorder;
-extern crate clap;
 #[macro_use]
 extern crate log;
-extern crate env_logger;
-extern crate exr;
-extern crate float_cmp;
-#[macro_use]
-extern crate hexf;
-extern crate image;
-extern crate itertools;
-#[macro_use]
-extern crate lazy_static;
-extern crate num_traits;
-extern crate ordered_float;
-extern crate pest;
-#[macro_use]
-extern crate pest_derive;
-extern crate rand;
-extern crate rand_pcg;
-extern crate typed_arena;
-
-mod accelerators;
-mod cameras;
-mod core;
-mod filters;
-mod integrators;
-mod lights;
-mod materials;
-mod samplers;
-mod shapes;
-mod textures;
-
-use crate::core::api::*;
-use crate::core::app::*;
-use crate::core::parsers::*;
+
+use pbr_rust::core::api::*;
+use pbr_rust::core::app::*;
+use pbr_rust::core::fileutil::*;
+use pbr_rust::core::logging::*;
+use pbr_rust::core::parsers::*;
+use pbr_rust::core::plugin::*;
+use pbr_rust::core::profiler::*;
+use pbr_rust::core::scene_cache::*;
+use pbr_rust::core::server::*;
 
 fn main() {
-    // Initialize `env_logger`.
-    env_logger::init();
+    // Load the program options from the command line.
+    let options = Options::new();
+    if let Err(err) = set_options(options.clone()) {
+        panic!("{}", err);
+    }
+
+    // Initialize logging.
+    init_logger(options.log_filters.as_deref(), options.log_format);
+    if options.image_file.is_empty() {
+        warn!("Missing outfile. Will use one from scene description.");
+    }
 
-    // Load the program options.
-    let options = OPTIONS.clone();
+    // Load plugins before any scene refers to their types. Scenes submitted
+    // to the render service can use them too.
+    for path in options.plugins.iter() {
+        if let Err(err) = absolute_path(path).and_then(|path| load_plugin(&path)) {
+            error!("{}", err);
+        }
+    }
+
+    // Render scenes submitted over HTTP instead of the input files.
+    if let Some(address) = options.serve.as_ref() {
+        if let Err(err) = RenderService::serve(address, options.scene_root.as_deref()) {
+            error!("{}", err);
+        }
+        return;
+    }
 
     // Initialize PBRT API.
     let mut api = Api::new();
     api.pbrt_init();
 
+    // Stop rendering and write the partial image on Ctrl-C; exit immediately
+    // if it is pressed again.
+    let cancellation_token = api.cancellation_token();
+    if let Err(err) = ctrlc::set_handler(move || {
+        if cancellation_token.is_cancelled() {
+            std::process::exit(130);
+        }
+        warn!("Cancelling render. Press Ctrl-C again to exit immediately.");
+        cancellation_token.cancel();
+    }) {
+        warn!("Unable to handle Ctrl-C. {}", err);
+    }
+
+    // Record the scene descriptions to export them.
+    if options.export.is_some() {
+        api.start_recording();
+    }
+
     // Process scene description.
-    for path in options.paths.iter() {
-        let parser = PbrtFileParser::new(path);
-        match parser.parse(&mut api) {
-            Ok(_) => (),
+    match options.frames {
+        Some([first, last]) if options.paths.iter().any(|p| has_frame_pattern(p)) => {
+            // Render each frame from its own scene description.
+            for frame in first..=last {
+                if api.cancellation_token().is_cancelled() {
+                    break;
+                }
+                api.pbrt_frames(Some(FrameSequence::Single(frame)));
+                for path in options.paths.iter() {
+                    parse_file(&frame_input_path(path, frame), &mut api);
+                }
+            }
+        }
+        Some([first, last]) => {
+            // Render all frames from the same scene description.
+            api.pbrt_frames(Some(FrameSequence::Range(first, last)));
+            for path in options.paths.iter() {
+                parse_file(path, &mut api);
+            }
+        }
+        None => {
+            for path in options.paths.iter() {
+                parse_file(path, &mut api);
+            }
+        }
+    }
+
+    if let (Some(path), Some(recording)) = (options.export.as_ref(), api.take_recording()) {
+        match recording.export_pbrt(path) {
+            Ok(()) => info!("Exported scene to '{}'.", path),
             Err(err) => error!("{}", err),
         }
     }
 
     api.pbrt_cleanup();
+
+    report_thread_profile();
+    if options.profile && !options.quiet {
+        print_profile();
+    }
+}
+
+/// Parses a scene description file, in the PBRT file format or in JSON if it
+/// has a `.json` extension, and reports any errors. With a scene
+/// cache an unchanged scene is replayed from the cache instead and a changed
+/// one is recorded into it. The scene cache isn't used when exporting.
+///
+/// * `path` - The scene file path.
+/// * `api`  - The PBRT API interface.
+fn parse_file(path: &str, api: &mut Api) {
+    let _p = ProfilePhase::new(Prof::SceneParsing);
+    let cache = match OPTIONS.export {
+        Some(_) => None,
+        None => OPTIONS.scene_cache.as_ref().map(|dir| SceneCache::new(dir)),
+    };
+    if let Some(cache) = cache.as_ref() {
+        match cache.load(path) {
+            Ok(Some(recording)) => {
+                info!("Loaded '{}' from the scene cache.", path);
+                api.replay(recording);
+                return;
+            }
+            Ok(None) => api.start_recording(),
+            Err(err) => {
+                warn!("{}", err);
+                api.start_recording();
+            }
+        }
+    }
+
+    let result = if JsonSceneParser::handles(path) {
+        JsonSceneParser::new(path).parse(api)
+    } else {
+        PbrtFileParser::new(path).and_then(|parser| parser.parse(api))
+    };
+    let recording = cache.as_ref().and_then(|_| api.take_recording());
+    match result {
+        Ok(_) => {
+            if let (Some(cache), Some(recording)) = (cache, recording) {
+                if let Err(err) = cache.store(path, &recording) {
+                    warn!("{}", err);
+                }
+            }
+        }
+        Err(err) => error!("{}", err),
+    }
 }
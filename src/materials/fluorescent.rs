@@ -0,0 +1,105 @@
+//! Fluorescent Material
+
+#![allow(dead_code)]
+use crate::core::geometry::*;
+use crate::core::material::*;
+use crate::core::paramset::*;
+use crate::core::pbrt::*;
+use crate::core::reflection::*;
+use crate::core::spectrum::*;
+use crate::core::texture::*;
+use std::sync::Arc;
+
+/// Implements diffuse surfaces that absorb light at some wavelengths and
+/// re-emit it at longer wavelengths, e.g. fluorescent paints and optical
+/// brighteners.
+pub struct FluorescentMaterial {
+    /// Spectral diffuse reflection of light that is not absorbed.
+    kd: ArcTexture<Spectrum>,
+
+    /// Fraction of incident light absorbed at each wavelength.
+    absorption: Spectrum,
+
+    /// Re-radiation of absorbed light.
+    reradiation: Arc<ReradiationMatrix>,
+
+    /// Bump map.
+    bump_map: Option<ArcTexture<Float>>,
+}
+
+impl FluorescentMaterial {
+    /// Create a new `FluorescentMaterial`.
+    ///
+    /// * `kd`            - Spectral diffuse reflection of light that is not
+    ///                     absorbed.
+    /// * `absorption`    - Fraction of incident light absorbed at each
+    ///                     wavelength in [0, 1].
+    /// * `emission`      - Relative emission spectrum of absorbed light.
+    /// * `quantum_yield` - Fraction of absorbed energy that is re-emitted.
+    /// * `bump_map`      - Optional bump map.
+    pub fn new(
+        kd: ArcTexture<Spectrum>,
+        absorption: Spectrum,
+        emission: Spectrum,
+        quantum_yield: Float,
+        bump_map: Option<ArcTexture<Float>>,
+    ) -> Self {
+        Self {
+            kd: kd.clone(),
+            absorption: absorption.clamp(0.0, 1.0),
+            reradiation: Arc::new(ReradiationMatrix::new(
+                &absorption,
+                &emission,
+                quantum_yield,
+            )),
+            bump_map: bump_map.clone(),
+        }
+    }
+}
+
+impl Material for FluorescentMaterial {
+    /// Initializes representations of the light-scattering properties of the
+    /// material at the intersection point on the surface.
+    ///
+    /// * `si`                   - The surface interaction at the intersection.
+    /// * `mode`                 - Transport mode (ignored).
+    /// * `allow_multiple_lobes` - Indicates whether the material should use
+    ///                            BxDFs that aggregate multiple types of
+    ///                            scattering into a single BxDF when such BxDFs
+    ///                            are available (ignored).
+    fn compute_scattering_functions(
+        &self,
+        si: &mut SurfaceInteraction,
+        _mode: TransportMode,
+        _allow_multiple_lobes: bool,
+    ) {
+        // Perform bump mapping with `bump_map`, if present.
+        if let Some(bump_map) = self.bump_map.clone() {
+            Material::bump(self, bump_map, si);
+        }
+
+        let mut bsdf = BSDF::new(&si.clone(), None);
+
+        // The Lambertian lobe is always added, even when black, so that the
+        // re-radiated light can be sampled.
+        let r = self.kd.evaluate(si).clamp_default() * (Spectrum::new(1.0) - self.absorption);
+        bsdf.add(Arc::new(LambertianReflection::new(r)));
+        bsdf.reradiation = Some(self.reradiation.clone());
+
+        si.bsdf = Some(Arc::new(bsdf));
+    }
+}
+
+impl From<&TextureParams> for FluorescentMaterial {
+    /// Create a fluorescent material from given parameter set.
+    ///
+    /// * `tp` - Texture parameter set.
+    fn from(tp: &TextureParams) -> Self {
+        let kd = tp.get_spectrum_texture("Kd", Spectrum::new(0.5));
+        let absorption = tp.find_spectrum("absorption", Spectrum::new(0.0));
+        let emission = tp.find_spectrum("emission", Spectrum::new(0.0));
+        let quantum_yield = tp.find_float("yield", 1.0);
+        let bump_map = tp.get_float_texture_or_none("bumpmap");
+        Self::new(kd, absorption, emission, quantum_yield, bump_map)
+    }
+}
@@ -1,6 +1,7 @@
 //! Fourier Material
 
 #![allow(dead_code)]
+use crate::core::error::*;
 use crate::core::geometry::*;
 use crate::core::material::*;
 use crate::core::paramset::*;
@@ -8,6 +9,7 @@ use crate::core::pbrt::*;
 use crate::core::reflection::*;
 use crate::core::texture::*;
 use std::collections::HashMap;
+use std::convert::TryFrom;
 use std::sync::{Arc, Mutex};
 
 lazy_static! {
@@ -25,12 +27,13 @@ pub struct FourierMaterial {
 }
 
 impl FourierMaterial {
-    /// Create a new `FourierMaterial`.
+    /// Create a new `FourierMaterial`; or returns an error if the BSDF data
+    /// file could not be loaded.
     ///
     ///
     /// * `path`     - Path to the Fourier BSDF data file.
     /// * `bump_map` - Optional bump map.
-    pub fn new(path: &str, bump_map: Option<ArcTexture<Float>>) -> Self {
+    pub fn new(path: &str, bump_map: Option<ArcTexture<Float>>) -> Result<Self, Error> {
         let key = String::from(path);
 
         // Use preloaded BSDF data if available.
@@ -38,22 +41,15 @@ impl FourierMaterial {
         let bsdf_table = if let Some(table) = tables.get(&key) {
             table.clone()
         } else {
-            match FourierBSDFTable::from_file(path) {
-                Ok(table) => {
-                    let t = Arc::new(table);
-                    tables.insert(key, t.clone());
-                    t
-                }
-                Err(err) => {
-                    panic!("Unable to load file {}. {:}.", path, err);
-                }
-            }
+            let table = Arc::new(FourierBSDFTable::from_file(path)?);
+            tables.insert(key, table.clone());
+            table
         };
 
-        Self {
+        Ok(Self {
             bsdf_table,
             bump_map: bump_map.clone(),
-        }
+        })
     }
 }
 
@@ -90,12 +86,14 @@ impl Material for FourierMaterial {
     }
 }
 
-impl From<&TextureParams> for FourierMaterial {
+impl TryFrom<&TextureParams> for FourierMaterial {
+    type Error = Error;
+
     /// Create a Fourier material from given parameter set.
     ///
     /// * `tp` - Texture parameter set.
-    fn from(tp: &TextureParams) -> Self {
-        let bump_map = tp.get_float_texture("bumpmap");
+    fn try_from(tp: &TextureParams) -> Result<Self, Self::Error> {
+        let bump_map = tp.get_float_texture_or_none("bumpmap");
         let path = tp.find_filename("bsdfffile", String::from(""));
         Self::new(&path, bump_map)
     }
@@ -0,0 +1,167 @@
+//! Glass Material
+
+#![allow(dead_code)]
+use crate::core::geometry::*;
+use crate::core::material::*;
+use crate::core::microfacet::*;
+use crate::core::paramset::*;
+use crate::core::pbrt::*;
+use crate::core::reflection::*;
+use crate::core::spectrum::*;
+use crate::core::texture::*;
+use std::sync::Arc;
+
+/// Implements a dielectric such as glass that reflects and refracts light. It
+/// is perfectly specular unless it is given a roughness.
+pub struct GlassMaterial {
+    /// Spectral reflection.
+    kr: ArcTexture<Spectrum>,
+
+    /// Spectral transmission.
+    kt: ArcTexture<Spectrum>,
+
+    /// Roughness along u-direction.
+    u_roughness: ArcTexture<Float>,
+
+    /// Roughness along v-direction.
+    v_roughness: ArcTexture<Float>,
+
+    /// Index of refraction.
+    eta: ArcTexture<Float>,
+
+    /// Bump map.
+    bump_map: Option<ArcTexture<Float>>,
+
+    /// Remap roughness value to [0, 1] where higher values represent larger
+    /// highlights. If this is `false`, use the microfacet distributions `alpha`
+    /// parameter.
+    remap_roughness: bool,
+}
+
+impl GlassMaterial {
+    /// Create a new `GlassMaterial`.
+    ///
+    /// * `kr`              - Spectral reflection.
+    /// * `kt`              - Spectral transmission.
+    /// * `u_roughness`     - Roughness along u-direction.
+    /// * `v_roughness`     - Roughness along v-direction.
+    /// * `eta`             - Index of refraction.
+    /// * `remap_roughness` - Remap roughness value to [0, 1] where higher values
+    ///                       represent larger highlights. If this is `false`,
+    ///                       use the microfacet distributions `alpha` parameter.
+    /// * `bump_map`        - Optional bump map.
+    pub fn new(
+        kr: ArcTexture<Spectrum>,
+        kt: ArcTexture<Spectrum>,
+        u_roughness: ArcTexture<Float>,
+        v_roughness: ArcTexture<Float>,
+        eta: ArcTexture<Float>,
+        remap_roughness: bool,
+        bump_map: Option<ArcTexture<Float>>,
+    ) -> Self {
+        Self {
+            kr,
+            kt,
+            u_roughness,
+            v_roughness,
+            eta,
+            bump_map,
+            remap_roughness,
+        }
+    }
+}
+
+impl Material for GlassMaterial {
+    /// Initializes representations of the light-scattering properties of the
+    /// material at the intersection point on the surface.
+    ///
+    /// * `si`                   - The surface interaction at the intersection.
+    /// * `mode`                 - Transport mode.
+    /// * `allow_multiple_lobes` - Indicates whether the material should use
+    ///                            BxDFs that aggregate multiple types of
+    ///                            scattering into a single BxDF when such BxDFs
+    ///                            are available.
+    fn compute_scattering_functions(
+        &self,
+        si: &mut SurfaceInteraction,
+        mode: TransportMode,
+        allow_multiple_lobes: bool,
+    ) {
+        // Perform bump mapping with `bump_map`, if present.
+        if let Some(bump_map) = self.bump_map.clone() {
+            Material::bump(self, bump_map, si);
+        }
+
+        let eta = self.eta.evaluate(si);
+        let mut u_rough = self.u_roughness.evaluate(si);
+        let mut v_rough = self.v_roughness.evaluate(si);
+        let r = self.kr.evaluate(si).clamp_default();
+        let t = self.kt.evaluate(si).clamp_default();
+
+        let mut bsdf = BSDF::new(&si.clone(), Some(eta));
+        if r.is_black() && t.is_black() {
+            si.bsdf = Some(Arc::new(bsdf));
+            return;
+        }
+
+        let is_specular = u_rough == 0.0 && v_rough == 0.0;
+        if is_specular && allow_multiple_lobes {
+            bsdf.add(Arc::new(FresnelSpecular::new(r, t, 1.0, eta, mode)));
+        } else {
+            if self.remap_roughness {
+                u_rough = TrowbridgeReitzDistribution::roughness_to_alpha(u_rough);
+                v_rough = TrowbridgeReitzDistribution::roughness_to_alpha(v_rough);
+            }
+            let distrib: Option<ArcMicrofacetDistribution> = if is_specular {
+                None
+            } else {
+                Some(Arc::new(TrowbridgeReitzDistribution::new(
+                    u_rough, v_rough, true,
+                )))
+            };
+
+            if !r.is_black() {
+                let fresnel = Arc::new(FresnelDielectric::new(1.0, eta));
+                match distrib.clone() {
+                    Some(distrib) => {
+                        bsdf.add(Arc::new(MicrofacetReflection::new(r, distrib, fresnel)))
+                    }
+                    None => bsdf.add(Arc::new(SpecularReflection::new(r, fresnel))),
+                }
+            }
+            if !t.is_black() {
+                match distrib {
+                    Some(distrib) => bsdf.add(Arc::new(MicrofacetTransmission::new(
+                        t, distrib, 1.0, eta, mode,
+                    ))),
+                    None => bsdf.add(Arc::new(SpecularTransmission::new(t, 1.0, eta, mode))),
+                }
+            }
+        }
+        si.bsdf = Some(Arc::new(bsdf));
+    }
+}
+
+impl From<&TextureParams> for GlassMaterial {
+    /// Create a glass material from given parameter set.
+    ///
+    /// * `tp` - Texture parameter set.
+    fn from(tp: &TextureParams) -> Self {
+        let kr = tp.get_spectrum_texture("Kr", Spectrum::new(1.0));
+        let kt = tp.get_spectrum_texture("Kt", Spectrum::new(1.0));
+        let eta = tp.get_float_texture_or_else("eta", tp.get_float_texture("index", 1.5));
+        let u_roughness = tp.get_float_texture("uroughness", 0.0);
+        let v_roughness = tp.get_float_texture("vroughness", 0.0);
+        let bump_map = tp.get_float_texture_or_none("bumpmap");
+        let remap_roughness = tp.find_bool("remaproughness", true);
+        Self::new(
+            kr,
+            kt,
+            u_roughness,
+            v_roughness,
+            eta,
+            remap_roughness,
+            bump_map,
+        )
+    }
+}
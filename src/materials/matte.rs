@@ -8,7 +8,6 @@ use crate::core::pbrt::*;
 use crate::core::reflection::*;
 use crate::core::spectrum::*;
 use crate::core::texture::*;
-use crate::textures::*;
 use std::sync::Arc;
 
 /// Implements purely diffuse surfaces.
@@ -86,10 +85,9 @@ impl From<&TextureParams> for MatteMaterial {
     ///
     /// * `tp` - Texture parameter set.
     fn from(tp: &TextureParams) -> Self {
-        let kd = tp
-            .get_spectrum_texture_or_else("Kd", Arc::new(ConstantTexture::new(Spectrum::new(0.5))));
-        let sigma = tp.get_float_texture_or_else("sigma", Arc::new(ConstantTexture::new(0.0)));
-        let bump_map = tp.get_float_texture("bumpmap");
+        let kd = tp.get_spectrum_texture("Kd", Spectrum::new(0.5));
+        let sigma = tp.get_float_texture("sigma", 0.0);
+        let bump_map = tp.get_float_texture_or_none("bumpmap");
         Self::new(kd, sigma, bump_map)
     }
 }
@@ -0,0 +1,132 @@
+//! Metal Material
+
+#![allow(dead_code)]
+use crate::core::geometry::*;
+use crate::core::material::*;
+use crate::core::microfacet::*;
+use crate::core::paramset::*;
+use crate::core::pbrt::*;
+use crate::core::reflection::*;
+use crate::core::spectrum::*;
+use crate::core::texture::*;
+use std::sync::Arc;
+
+/// Implements metal material described by a conductor's index of refraction
+/// and absorption coefficient.
+pub struct MetalMaterial {
+    /// Index of refraction.
+    eta: ArcTexture<Spectrum>,
+
+    /// Absorption coefficient.
+    k: ArcTexture<Spectrum>,
+
+    /// Roughness along u-direction.
+    u_roughness: ArcTexture<Float>,
+
+    /// Roughness along v-direction.
+    v_roughness: ArcTexture<Float>,
+
+    /// Bump map.
+    bump_map: Option<ArcTexture<Float>>,
+
+    /// Remap roughness value to [0, 1] where higher values represent larger
+    /// highlights. If this is `false`, use the microfacet distributions `alpha`
+    /// parameter.
+    remap_roughness: bool,
+}
+
+impl MetalMaterial {
+    /// Create a new `MetalMaterial`.
+    ///
+    /// * `eta`             - Index of refraction.
+    /// * `k`               - Absorption coefficient.
+    /// * `u_roughness`     - Roughness along u-direction.
+    /// * `v_roughness`     - Roughness along v-direction.
+    /// * `remap_roughness` - Remap roughness value to [0, 1] where higher values
+    ///                       represent larger highlights. If this is `false`,
+    ///                       use the microfacet distributions `alpha` parameter.
+    /// * `bump_map`        - Optional bump map.
+    pub fn new(
+        eta: ArcTexture<Spectrum>,
+        k: ArcTexture<Spectrum>,
+        u_roughness: ArcTexture<Float>,
+        v_roughness: ArcTexture<Float>,
+        remap_roughness: bool,
+        bump_map: Option<ArcTexture<Float>>,
+    ) -> Self {
+        Self {
+            eta: eta.clone(),
+            k: k.clone(),
+            u_roughness: u_roughness.clone(),
+            v_roughness: v_roughness.clone(),
+            remap_roughness,
+            bump_map: bump_map.clone(),
+        }
+    }
+}
+
+impl Material for MetalMaterial {
+    /// Initializes representations of the light-scattering properties of the
+    /// material at the intersection point on the surface.
+    ///
+    /// * `si`                   - The surface interaction at the intersection.
+    /// * `mode`                 - Transport mode (ignored).
+    /// * `allow_multiple_lobes` - Indicates whether the material should use
+    ///                            BxDFs that aggregate multiple types of
+    ///                            scattering into a single BxDF when such BxDFs
+    ///                            are available (ignored).
+    fn compute_scattering_functions(
+        &self,
+        si: &mut SurfaceInteraction,
+        _mode: TransportMode,
+        _allow_multiple_lobes: bool,
+    ) {
+        // Perform bump mapping with `bump_map`, if present.
+        if let Some(bump_map) = self.bump_map.clone() {
+            Material::bump(self, bump_map, si);
+        }
+
+        let mut bsdf = BSDF::new(&si.clone(), None);
+
+        let mut u_rough = self.u_roughness.evaluate(si);
+        let mut v_rough = self.v_roughness.evaluate(si);
+        if self.remap_roughness {
+            u_rough = TrowbridgeReitzDistribution::roughness_to_alpha(u_rough);
+            v_rough = TrowbridgeReitzDistribution::roughness_to_alpha(v_rough);
+        }
+
+        let fresnel = Arc::new(FresnelConductor::new(
+            Spectrum::new(1.0),
+            self.eta.evaluate(si),
+            self.k.evaluate(si),
+        ));
+        let distrib = Arc::new(TrowbridgeReitzDistribution::new(u_rough, v_rough, true));
+        bsdf.add(Arc::new(MicrofacetReflection::new(
+            Spectrum::new(1.0),
+            distrib,
+            fresnel,
+        )));
+
+        si.bsdf = Some(Arc::new(bsdf));
+    }
+}
+
+impl From<&TextureParams> for MetalMaterial {
+    /// Create a metal material from given parameter set. The index of
+    /// refraction and absorption coefficient default to those of copper.
+    ///
+    /// * `tp` - Texture parameter set.
+    fn from(tp: &TextureParams) -> Self {
+        let copper_eta = named_spectrum("metal-Cu-eta").unwrap();
+        let copper_k = named_spectrum("metal-Cu-k").unwrap();
+
+        let eta = tp.get_spectrum_texture("eta", copper_eta);
+        let k = tp.get_spectrum_texture("k", copper_k);
+        let roughness = tp.get_float_texture("roughness", 0.01);
+        let u_roughness = tp.get_float_texture_or_else("uroughness", roughness.clone());
+        let v_roughness = tp.get_float_texture_or_else("vroughness", roughness.clone());
+        let bump_map = tp.get_float_texture_or_none("bumpmap");
+        let remap_roughness = tp.find_bool("remaproughness", true);
+        Self::new(eta, k, u_roughness, v_roughness, remap_roughness, bump_map)
+    }
+}
@@ -0,0 +1,74 @@
+//! Mirror Material
+
+#![allow(dead_code)]
+use crate::core::geometry::*;
+use crate::core::material::*;
+use crate::core::paramset::*;
+use crate::core::pbrt::*;
+use crate::core::reflection::*;
+use crate::core::spectrum::*;
+use crate::core::texture::*;
+use std::sync::Arc;
+
+/// Implements a perfectly specular mirror.
+pub struct MirrorMaterial {
+    /// Spectral reflection.
+    kr: ArcTexture<Spectrum>,
+
+    /// Bump map.
+    bump_map: Option<ArcTexture<Float>>,
+}
+
+impl MirrorMaterial {
+    /// Create a new `MirrorMaterial`.
+    ///
+    /// * `kr`       - Spectral reflection.
+    /// * `bump_map` - Optional bump map.
+    pub fn new(kr: ArcTexture<Spectrum>, bump_map: Option<ArcTexture<Float>>) -> Self {
+        Self { kr, bump_map }
+    }
+}
+
+impl Material for MirrorMaterial {
+    /// Initializes representations of the light-scattering properties of the
+    /// material at the intersection point on the surface.
+    ///
+    /// * `si`                   - The surface interaction at the intersection.
+    /// * `mode`                 - Transport mode (ignored).
+    /// * `allow_multiple_lobes` - Indicates whether the material should use
+    ///                            BxDFs that aggregate multiple types of
+    ///                            scattering into a single BxDF when such BxDFs
+    ///                            are available (ignored).
+    fn compute_scattering_functions(
+        &self,
+        si: &mut SurfaceInteraction,
+        _mode: TransportMode,
+        _allow_multiple_lobes: bool,
+    ) {
+        // Perform bump mapping with `bump_map`, if present.
+        if let Some(bump_map) = self.bump_map.clone() {
+            Material::bump(self, bump_map, si);
+        }
+
+        let mut bsdf = BSDF::new(&si.clone(), None);
+        let r = self.kr.evaluate(si).clamp_default();
+        if !r.is_black() {
+            bsdf.add(Arc::new(SpecularReflection::new(
+                r,
+                Arc::new(FresnelNoOp::new()),
+            )));
+        }
+        si.bsdf = Some(Arc::new(bsdf));
+    }
+}
+
+impl From<&TextureParams> for MirrorMaterial {
+    /// Create a mirror material from given parameter set.
+    ///
+    /// * `tp` - Texture parameter set.
+    fn from(tp: &TextureParams) -> Self {
+        let kr = tp.get_spectrum_texture("Kr", Spectrum::new(0.9));
+        let bump_map = tp.get_float_texture_or_none("bumpmap");
+        Self::new(kr, bump_map)
+    }
+}
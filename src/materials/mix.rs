@@ -8,7 +8,6 @@ use crate::core::pbrt::*;
 use crate::core::reflection::*;
 use crate::core::spectrum::*;
 use crate::core::texture::*;
-use crate::textures::*;
 use std::sync::Arc;
 
 /// Combines two materials with varying weights.
@@ -92,10 +91,7 @@ impl From<(&TextureParams, ArcMaterial, ArcMaterial)> for MixMaterial {
     /// * `props` - Mix material creation properties.
     fn from(props: (&TextureParams, ArcMaterial, ArcMaterial)) -> Self {
         let (tp, mat1, mat2) = props;
-        let scale = tp.get_spectrum_texture_or_else(
-            "amount",
-            Arc::new(ConstantTexture::new(Spectrum::new(0.5))),
-        );
+        let scale = tp.get_spectrum_texture("amount", Spectrum::new(0.5));
         Self::new(mat1.clone(), mat2.clone(), scale)
     }
 }
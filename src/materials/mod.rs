@@ -1,12 +1,22 @@
 //! Materials
 
+mod fluorescent;
 mod fourier;
+mod glass;
 mod matte;
+mod metal;
+mod mirror;
 mod mix;
 mod plastic;
+mod subsurface;
 
 // Re-export
+pub use fluorescent::*;
 pub use fourier::*;
+pub use glass::*;
 pub use matte::*;
+pub use metal::*;
+pub use mirror::*;
 pub use mix::*;
 pub use plastic::*;
+pub use subsurface::*;
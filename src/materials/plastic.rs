@@ -9,7 +9,6 @@ use crate::core::pbrt::*;
 use crate::core::reflection::*;
 use crate::core::spectrum::*;
 use crate::core::texture::*;
-use crate::textures::*;
 use std::sync::Arc;
 
 /// Implements plastic material.
@@ -113,17 +112,10 @@ impl From<&TextureParams> for PlasticMaterial {
     ///
     /// * `tp` - Texture parameter set.
     fn from(tp: &TextureParams) -> Self {
-        let kd = tp.get_spectrum_texture_or_else(
-            "Kd",
-            Arc::new(ConstantTexture::new(Spectrum::new(0.25))),
-        );
-        let ks = tp.get_spectrum_texture_or_else(
-            "Ks",
-            Arc::new(ConstantTexture::new(Spectrum::new(0.25))),
-        );
-        let roughness =
-            tp.get_float_texture_or_else("roughness", Arc::new(ConstantTexture::new(0.1)));
-        let bump_map = tp.get_float_texture("bumpmap");
+        let kd = tp.get_spectrum_texture("Kd", Spectrum::new(0.25));
+        let ks = tp.get_spectrum_texture("Ks", Spectrum::new(0.25));
+        let roughness = tp.get_float_texture("roughness", 0.1);
+        let bump_map = tp.get_float_texture_or_none("bumpmap");
         let remap_roughness = tp.find_bool("remaproughness", true);
         Self::new(kd, ks, roughness, remap_roughness, bump_map)
     }
@@ -0,0 +1,206 @@
+//! Subsurface Material
+
+#![allow(dead_code)]
+use crate::core::bssrdf::*;
+use crate::core::geometry::*;
+use crate::core::material::*;
+use crate::core::paramset::*;
+use crate::core::pbrt::*;
+use crate::core::reflection::*;
+use crate::core::spectrum::*;
+use crate::core::texture::*;
+use std::sync::Arc;
+
+/// Scattering coefficients of a subsurface material.
+#[derive(Clone)]
+pub enum SubsurfaceCoefficients {
+    /// Absorption and scattering coefficients.
+    Physical {
+        /// Absorption coefficient.
+        sigma_a: ArcTexture<Spectrum>,
+
+        /// Scattering coefficient.
+        sigma_s: ArcTexture<Spectrum>,
+    },
+
+    /// Coefficients derived from the diffuse appearance of the surface.
+    Diffuse {
+        /// Effective albedo of the surface.
+        reflectance: ArcTexture<Spectrum>,
+
+        /// Mean free path.
+        mfp: ArcTexture<Spectrum>,
+
+        /// Profile table used to invert `reflectance`.
+        table: Arc<BSSRDFTable>,
+    },
+}
+
+/// Implements a translucent material with a smooth dielectric boundary whose
+/// light refracted into the surface is scattered by a random walk through
+/// the medium inside.
+pub struct SubsurfaceMaterial {
+    /// Scale factor applied to the scattering coefficients.
+    scale: Float,
+
+    /// Spectral reflection.
+    kr: ArcTexture<Spectrum>,
+
+    /// Spectral transmission.
+    kt: ArcTexture<Spectrum>,
+
+    /// Scattering coefficients.
+    coefficients: SubsurfaceCoefficients,
+
+    /// Asymmetry parameter of the Henyey-Greenstein phase function.
+    g: Float,
+
+    /// Index of refraction of the surface.
+    eta: Float,
+
+    /// Bump map.
+    bump_map: Option<ArcTexture<Float>>,
+}
+
+impl SubsurfaceMaterial {
+    /// Create a new `SubsurfaceMaterial`.
+    ///
+    /// * `scale`        - Scale factor applied to the scattering coefficients.
+    /// * `kr`           - Spectral reflection.
+    /// * `kt`           - Spectral transmission.
+    /// * `coefficients` - Scattering coefficients.
+    /// * `g`            - Asymmetry parameter of the Henyey-Greenstein phase
+    ///                    function.
+    /// * `eta`          - Index of refraction of the surface.
+    /// * `bump_map`     - Optional bump map.
+    pub fn new(
+        scale: Float,
+        kr: ArcTexture<Spectrum>,
+        kt: ArcTexture<Spectrum>,
+        coefficients: SubsurfaceCoefficients,
+        g: Float,
+        eta: Float,
+        bump_map: Option<ArcTexture<Float>>,
+    ) -> Self {
+        Self {
+            scale,
+            kr,
+            kt,
+            coefficients,
+            g,
+            eta,
+            bump_map,
+        }
+    }
+}
+
+impl Material for SubsurfaceMaterial {
+    /// Initializes representations of the light-scattering properties of the
+    /// material at the intersection point on the surface.
+    ///
+    /// * `si`                   - The surface interaction at the intersection.
+    /// * `mode`                 - Transport mode.
+    /// * `allow_multiple_lobes` - Indicates whether the material should use
+    ///                            BxDFs that aggregate multiple types of
+    ///                            scattering into a single BxDF when such BxDFs
+    ///                            are available.
+    fn compute_scattering_functions(
+        &self,
+        si: &mut SurfaceInteraction,
+        mode: TransportMode,
+        allow_multiple_lobes: bool,
+    ) {
+        // Perform bump mapping with `bump_map`, if present.
+        if let Some(bump_map) = self.bump_map.clone() {
+            Material::bump(self, bump_map, si);
+        }
+
+        // Initialize BSDF for the smooth dielectric boundary.
+        let mut bsdf = BSDF::new(&si.clone(), Some(self.eta));
+        let r = self.kr.evaluate(si).clamp_default();
+        let t = self.kt.evaluate(si).clamp_default();
+        if allow_multiple_lobes {
+            bsdf.add(Arc::new(FresnelSpecular::new(r, t, 1.0, self.eta, mode)));
+        } else {
+            if !r.is_black() {
+                let fresnel = Arc::new(FresnelDielectric::new(1.0, self.eta));
+                bsdf.add(Arc::new(SpecularReflection::new(r, fresnel)));
+            }
+            if !t.is_black() {
+                bsdf.add(Arc::new(SpecularTransmission::new(t, 1.0, self.eta, mode)));
+            }
+        }
+        si.bsdf = Some(Arc::new(bsdf));
+
+        // Initialize the BSSRDF for light refracted into the surface.
+        let (sigma_a, sigma_s) = match &self.coefficients {
+            SubsurfaceCoefficients::Physical { sigma_a, sigma_s } => (
+                sigma_a.evaluate(si).clamp_default(),
+                sigma_s.evaluate(si).clamp_default(),
+            ),
+            SubsurfaceCoefficients::Diffuse {
+                reflectance,
+                mfp,
+                table,
+            } => subsurface_from_diffuse(
+                table,
+                &reflectance.evaluate(si).clamp(0.0, 1.0),
+                &mfp.evaluate(si),
+            ),
+        };
+        si.bssrdf = Some(Arc::new(RandomWalkBSSRDF::new(
+            sigma_a * self.scale,
+            sigma_s * self.scale,
+            self.g,
+            self.eta,
+            mode,
+        )));
+    }
+}
+
+impl From<&TextureParams> for SubsurfaceMaterial {
+    /// Create a subsurface material from given parameter set.
+    ///
+    /// * `tp` - Texture parameter set.
+    fn from(tp: &TextureParams) -> Self {
+        let mode = tp.find_string("mode", String::from("randomwalk"));
+        if mode != "randomwalk" {
+            warn!(
+                "Subsurface mode '{}' unsupported. Using 'randomwalk'.",
+                mode
+            );
+        }
+
+        let g = tp.find_float("g", 0.0);
+        let eta = tp.find_float("eta", 1.33);
+        let scale = tp.find_float("scale", 1.0);
+
+        let coefficients = match tp.get_spectrum_texture_or_none("reflectance") {
+            Some(reflectance) => {
+                let mfp = tp.get_spectrum_texture("mfp", Spectrum::new(1.0));
+                let mut table = BSSRDFTable::new(100, 64);
+                compute_beam_diffusion_bssrdf(g, eta, &mut table);
+                SubsurfaceCoefficients::Diffuse {
+                    reflectance,
+                    mfp,
+                    table: Arc::new(table),
+                }
+            }
+            None => {
+                // Default to the coefficients of skim milk in mm^-1.
+                let sigma_a = tp.get_spectrum_texture(
+                    "sigma_a",
+                    Spectrum::from_rgb(&[0.0011, 0.0024, 0.014], None),
+                );
+                let sigma_s = tp
+                    .get_spectrum_texture("sigma_s", Spectrum::from_rgb(&[2.55, 3.21, 3.77], None));
+                SubsurfaceCoefficients::Physical { sigma_a, sigma_s }
+            }
+        };
+
+        let kr = tp.get_spectrum_texture("Kr", Spectrum::new(1.0));
+        let kt = tp.get_spectrum_texture("Kt", Spectrum::new(1.0));
+        let bump_map = tp.get_float_texture_or_none("bumpmap");
+        Self::new(scale, kr, kt, coefficients, g, eta, bump_map)
+    }
+}
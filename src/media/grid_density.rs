@@ -0,0 +1,380 @@
+//! Grid Density Medium
+
+#![allow(dead_code)]
+use crate::core::error::*;
+use crate::core::geometry::*;
+use crate::core::medium::*;
+use crate::core::paramset::*;
+use crate::core::pbrt::*;
+use crate::core::sampler::*;
+use crate::core::spectrum::*;
+use std::convert::TryFrom;
+use std::sync::Arc;
+
+/// Transmittance below which ratio tracking uses Russian roulette.
+const RR_THRESHOLD: Float = 0.1;
+
+/// A heterogeneous medium whose density is given on a regular 3D grid over
+/// [0, 1]^3 in medium space. The scattering coefficients are scaled by the
+/// density, which is interpolated trilinearly between grid points.
+///
+/// The coefficients may differ per channel, e.g. in tinted smoke. Tracking is
+/// done against a single majorant for all channels and each collision is
+/// decided by one randomly chosen channel with single-sample spectral MIS so
+/// chromatic media don't add colour noise.
+pub struct GridDensityMedium {
+    /// Absorption coefficient.
+    sigma_a: Spectrum,
+
+    /// Scattering coefficient.
+    sigma_s: Spectrum,
+
+    /// Extinction coefficient.
+    sigma_t: Spectrum,
+
+    /// The asymmetry parameter of the Henyey-Greenstein phase function.
+    g: Float,
+
+    /// Number of grid points along x-axis.
+    nx: usize,
+
+    /// Number of grid points along y-axis.
+    ny: usize,
+
+    /// Number of grid points along z-axis.
+    nz: usize,
+
+    /// World to medium space transform.
+    world_to_medium: Transform,
+
+    /// Density at the grid points with x varying fastest.
+    density: Vec<Float>,
+
+    /// Majorant of the extinction coefficient of all channels over the grid.
+    sigma_maj: Float,
+}
+
+impl GridDensityMedium {
+    /// Create a new `GridDensityMedium`.
+    ///
+    /// * `sigma_a`         - Absorption coefficient.
+    /// * `sigma_s`         - Scattering coefficient.
+    /// * `g`               - The asymmetry parameter of the Henyey-Greenstein
+    ///                       phase function.
+    /// * `nx`              - Number of grid points along x-axis.
+    /// * `ny`              - Number of grid points along y-axis.
+    /// * `nz`              - Number of grid points along z-axis.
+    /// * `medium_to_world` - Medium to world space transform.
+    /// * `density`         - Density at the grid points with x varying fastest.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        sigma_a: Spectrum,
+        sigma_s: Spectrum,
+        g: Float,
+        nx: usize,
+        ny: usize,
+        nz: usize,
+        medium_to_world: &Transform,
+        density: Vec<Float>,
+    ) -> Self {
+        debug_assert!(density.len() == nx * ny * nz);
+
+        let sigma_t = sigma_a + sigma_s;
+        let max_density = density.iter().copied().fold(0.0, Float::max);
+        let sigma_maj = sigma_t.max_component_value() * max_density;
+
+        Self {
+            sigma_a,
+            sigma_s,
+            sigma_t,
+            g,
+            nx,
+            ny,
+            nz,
+            world_to_medium: medium_to_world.inverse(),
+            density,
+            sigma_maj,
+        }
+    }
+
+    /// Returns the density at a grid point; 0 outside the grid.
+    ///
+    /// * `x` - Index along x-axis.
+    /// * `y` - Index along y-axis.
+    /// * `z` - Index along z-axis.
+    fn d(&self, x: i64, y: i64, z: i64) -> Float {
+        let (nx, ny, nz) = (self.nx as i64, self.ny as i64, self.nz as i64);
+        if x < 0 || y < 0 || z < 0 || x >= nx || y >= ny || z >= nz {
+            0.0
+        } else {
+            self.density[((z * ny + y) * nx + x) as usize]
+        }
+    }
+
+    /// Returns the density at a point by interpolating the grid.
+    ///
+    /// * `p` - Point in medium space.
+    fn density_at(&self, p: &Point3f) -> Float {
+        // Grid points are at the centers of the cells.
+        let x = p.x * self.nx as Float - 0.5;
+        let y = p.y * self.ny as Float - 0.5;
+        let z = p.z * self.nz as Float - 0.5;
+        let (x0, y0, z0) = (x.floor(), y.floor(), z.floor());
+        let (dx, dy, dz) = (x - x0, y - y0, z - z0);
+        let (x0, y0, z0) = (x0 as i64, y0 as i64, z0 as i64);
+
+        let d00 = lerp(dx, self.d(x0, y0, z0), self.d(x0 + 1, y0, z0));
+        let d10 = lerp(dx, self.d(x0, y0 + 1, z0), self.d(x0 + 1, y0 + 1, z0));
+        let d01 = lerp(dx, self.d(x0, y0, z0 + 1), self.d(x0 + 1, y0, z0 + 1));
+        let d11 = lerp(
+            dx,
+            self.d(x0, y0 + 1, z0 + 1),
+            self.d(x0 + 1, y0 + 1, z0 + 1),
+        );
+        let d0 = lerp(dy, d00, d10);
+        let d1 = lerp(dy, d01, d11);
+        lerp(dz, d0, d1)
+    }
+
+    /// Returns the ray with a normalized direction, the ray in medium space
+    /// and the parametric range where it overlaps the grid; or `None` if it
+    /// misses the grid.
+    ///
+    /// * `r` - The ray in world space.
+    fn clip(&self, r: &Ray) -> Option<(Ray, Ray, Float, Float)> {
+        let length = r.d.length();
+        if length == 0.0 || self.sigma_maj <= 0.0 {
+            return None;
+        }
+        let world = Ray::new(r.o, r.d / length, r.t_max * length, r.time, None);
+        let ray = self.world_to_medium.transform_ray(&world);
+        let bounds = Bounds3f::new(Point3f::new(0.0, 0.0, 0.0), Point3f::new(1.0, 1.0, 1.0));
+        let (t_min, t_max) = bounds.intersect_p(&ray)?;
+        Some((world, ray, t_min, t_max))
+    }
+
+    /// Returns the distance from `t` to the next tentative collision sampled
+    /// with the majorant.
+    ///
+    /// * `sampler` - The sampler.
+    fn sample_step(&self, sampler: &mut ArcSampler) -> Float {
+        let sampler = Arc::get_mut(sampler).unwrap();
+        -(1.0 - sampler.get_1d()).ln() / self.sigma_maj
+    }
+}
+
+impl Medium for GridDensityMedium {
+    /// Returns the beam transmittance along a given ray.
+    ///
+    /// Uses ratio tracking with the majorant of all channels so every channel
+    /// gets an unbiased estimate from the same collisions.
+    ///
+    /// * `ray`     - The ray.
+    /// * `sampler` - The sampler.
+    fn tr(&self, r: &Ray, sampler: &mut ArcSampler) -> Spectrum {
+        let (_, ray, t_min, t_max) = match self.clip(r) {
+            Some(clipped) => clipped,
+            None => return Spectrum::new(1.0),
+        };
+
+        let mut tr = Spectrum::new(1.0);
+        let mut t = t_min;
+        loop {
+            t += self.sample_step(sampler);
+            if t >= t_max {
+                break;
+            }
+
+            // Each channel keeps the fraction of its null collisions.
+            let sigma_t = self.sigma_t * self.density_at(&ray.at(t));
+            tr *= Spectrum::new(1.0) - sigma_t / self.sigma_maj;
+
+            // Terminate estimates that carry little light with Russian roulette.
+            let tr_max = tr.max_component_value();
+            if tr_max < RR_THRESHOLD {
+                let q = max(0.05, 1.0 - tr_max);
+                if Arc::get_mut(sampler).unwrap().get_1d() < q {
+                    return Spectrum::new(0.0);
+                }
+                tr /= 1.0 - q;
+            }
+        }
+        tr
+    }
+
+    /// Samples a scattering interaction along a ray and returns the
+    /// throughput weight of the sample and the interaction; or `None` if the
+    /// ray passes through the medium up to `ray.t_max` without scattering.
+    ///
+    /// Uses delta tracking where real and null collisions are decided by a
+    /// randomly chosen hero channel. The walk is weighted with spectral MIS
+    /// so the other channels are estimated as if they had been tracked with
+    /// their own coefficients. The ray's medium must be this medium, as it
+    /// becomes the medium of the interaction.
+    ///
+    /// * `ray`     - The ray.
+    /// * `sampler` - The sampler.
+    fn sample(&self, r: &Ray, sampler: &mut ArcSampler) -> (Spectrum, Option<MediumInteraction>) {
+        let (world, ray, t_min, t_max) = match self.clip(r) {
+            Some(clipped) => clipped,
+            None => return (Spectrum::new(1.0), None),
+        };
+        let medium = match r.medium.clone() {
+            Some(medium) => medium,
+            None => return (self.tr(r, sampler), None),
+        };
+
+        let n_channels = self.sigma_t.samples().len();
+        let channel = sample_channel(Arc::get_mut(sampler).unwrap().get_1d(), n_channels);
+
+        // The exponential terms of the majorant cancel out and are omitted.
+        let mut walk = SpectralMIS::new();
+        let mut t = t_min;
+        loop {
+            t += self.sample_step(sampler);
+            if t >= t_max {
+                return (walk.weight(), None);
+            }
+
+            let density = self.density_at(&ray.at(t));
+            let sigma_t = self.sigma_t * density;
+            let sigma_n = Spectrum::new(self.sigma_maj) - sigma_t;
+            let u = Arc::get_mut(sampler).unwrap().get_1d();
+            if u < sigma_t[channel] / self.sigma_maj {
+                // Real collision; absorption ends the walk so only scattering
+                // contributes.
+                walk.add(&(self.sigma_s * density), &sigma_t);
+                let mi = MediumInteraction::new(
+                    world.at(t),
+                    -world.d,
+                    r.time,
+                    medium,
+                    Arc::new(HenyeyGreenstein::new(self.g)),
+                );
+                return (walk.weight(), Some(mi));
+            }
+
+            // Null collision.
+            walk.add(&sigma_n, &sigma_n);
+        }
+    }
+}
+
+impl TryFrom<(&ParamSet, ArcTransform)> for GridDensityMedium {
+    type Error = Error;
+
+    /// Create a `GridDensityMedium` from given parameter set and medium to
+    /// world transform.
+    ///
+    /// * `p` - A tuple containing the parameter set and medium to world
+    ///         transform.
+    fn try_from(p: (&ParamSet, ArcTransform)) -> Result<Self, Self::Error> {
+        let (params, medium_to_world) = p;
+
+        let sig_a = params.find_one_spectrum(
+            "sigma_a",
+            Spectrum::from_rgb(&[0.0011, 0.0024, 0.014], None),
+        );
+        let sig_s =
+            params.find_one_spectrum("sigma_s", Spectrum::from_rgb(&[2.55, 3.21, 3.77], None));
+        let scale = params.find_one_float("scale", 1.0);
+        let g = params.find_one_float("g", 0.0);
+
+        let nx = params.find_one_int("nx", 1);
+        let ny = params.find_one_int("ny", 1);
+        let nz = params.find_one_int("nz", 1);
+        if nx < 1 || ny < 1 || nz < 1 {
+            return Err(Error::InvalidParameter(format!(
+                "Grid resolution {}x{}x{} must be positive.",
+                nx, ny, nz
+            )));
+        }
+        let (nx, ny, nz) = (nx as usize, ny as usize, nz as usize);
+
+        let density = params.find_float("density");
+        if density.len() != nx * ny * nz {
+            return Err(Error::InvalidParameter(format!(
+                "Grid medium has {} density values; expected nx*ny*nz = {}.",
+                density.len(),
+                nx * ny * nz
+            )));
+        }
+
+        // Map the grid bounds [p0, p1] onto [0, 1]^3.
+        let p0 = params.find_one_point3f("p0", Point3f::new(0.0, 0.0, 0.0));
+        let p1 = params.find_one_point3f("p1", Point3f::new(1.0, 1.0, 1.0));
+        let data_to_medium = Transform::translate(&Vector3f::new(p0.x, p0.y, p0.z))
+            * Transform::scale(p1.x - p0.x, p1.y - p0.y, p1.z - p0.z);
+
+        Ok(Self::new(
+            sig_a * scale,
+            sig_s * scale,
+            g,
+            nx,
+            ny,
+            nz,
+            &(*medium_to_world * data_to_medium),
+            density.to_vec(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::samplers::*;
+
+    #[test]
+    fn chromatic_walks_match_transmittance() {
+        // A unit cube with different extinction in each channel.
+        let sigma_a = Spectrum::from_rgb(&[0.1, 0.3, 0.6], None);
+        let sigma_s = Spectrum::from_rgb(&[0.4, 0.7, 1.4], None);
+        let identity = Transform::default();
+        let medium: ArcMedium = Arc::new(GridDensityMedium::new(
+            sigma_a,
+            sigma_s,
+            0.0,
+            2,
+            2,
+            2,
+            &identity,
+            vec![1.0; 8],
+        ));
+
+        let mut sampler: ArcSampler = Arc::new(RandomSampler::new(1, Some(0)));
+        let ray = Ray::new(
+            Point3f::new(0.5, 0.5, -1.0),
+            Vector3f::new(0.0, 0.0, 2.0),
+            INFINITY,
+            0.0,
+            Some(medium.clone()),
+        );
+        // The density falls off linearly to 0 over the outer quarter of the
+        // cube, so the optical depth through it is 7/8 of a uniform cube.
+        let expected = homogeneous_transmittance(&(sigma_a + sigma_s), 0.875);
+
+        // Escaping walks are weighted so each channel gets its transmittance
+        // and scattering walks so they get the scattering albedo of the rest.
+        let n = 20000;
+        let mut tr = Spectrum::new(0.0);
+        let mut escaped = Spectrum::new(0.0);
+        let mut scattered = Spectrum::new(0.0);
+        for _ in 0..n {
+            tr += medium.tr(&ray, &mut sampler);
+            match medium.sample(&ray, &mut sampler) {
+                (beta, None) => escaped += beta,
+                (beta, Some(mi)) => {
+                    assert!(mi.hit.p.z >= 0.0 && mi.hit.p.z <= 1.0);
+                    scattered += beta;
+                }
+            }
+        }
+        let albedo = sigma_s / (sigma_a + sigma_s);
+        let expected_scattered = albedo * (Spectrum::new(1.0) - expected);
+        for c in 0..3 {
+            assert!((tr[c] / n as Float - expected[c]).abs() < 0.02);
+            assert!((escaped[c] / n as Float - expected[c]).abs() < 0.02);
+            assert!((scattered[c] / n as Float - expected_scattered[c]).abs() < 0.02);
+        }
+    }
+}
@@ -0,0 +1,6 @@
+//! Media
+
+mod grid_density;
+
+// Re-export.
+pub use grid_density::*;
@@ -24,8 +24,9 @@ pub struct HaltonSampler {
     /// Sample bounds.
     sample_bounds: Bounds2i,
 
-    /// Stores precomputed radical inverse permutations.
-    radical_inverse_permutations: Vec<u16>,
+    /// Stores precomputed radical inverse permutations. They are shared by
+    /// all clones of the sampler.
+    radical_inverse_permutations: Arc<Vec<u16>>,
 
     /// The scale factor, either `2^j` or `3^k` for corresponding exponents `j`
     /// and `k` stored in `base_exponents`.
@@ -58,21 +59,15 @@ impl HaltonSampler {
     /// * `sample_bounds`     - Sample bounds.
     /// * `sample_at_center`  - Indicates whether or not to jitter each sample's
     ///                         center point.
-    /// * `seed`              - Optional seed for random number generator.
+    /// * `permutations`      - Digit permutations for the radical inverse of
+    ///                         each dimension.
     fn new(
         samples_per_pixel: usize,
         sample_bounds: Bounds2i,
         sample_at_center: bool,
-        seed: Option<u64>,
+        permutations: Arc<Vec<u16>>,
     ) -> Self {
-        let mut rng = match seed {
-            Some(s) => RNG::new(s),
-            None => RNG::default(),
-        };
-
-        // Find radical inverse, base scales and exponents that cover sampling area.
-        let radical_inverse_permutations = compute_radical_inverse_permutations(&mut rng);
-
+        // Find base scales and exponents that cover sampling area.
         let res = sample_bounds.p_max - sample_bounds.p_min;
         let mut base_scales = Point2::<u64>::default();
         let mut base_exponents = Point2::<u64>::default();
@@ -105,7 +100,7 @@ impl HaltonSampler {
             pixel_for_offset: Arc::new(Mutex::new(Point2i::new(Int::MAX, Int::MAX))),
             offset_for_current_pixel: AtomicUsize::new(0),
             sample_bounds,
-            radical_inverse_permutations,
+            radical_inverse_permutations: permutations,
             base_scales,
             base_exponents,
             sample_stride,
@@ -113,18 +108,6 @@ impl HaltonSampler {
         }
     }
 
-    /// Returns the radical inverse permutation for a given dimension.
-    ///
-    /// * `dim` - Dimension.
-    fn permutation_for_dimension(&self, dim: u16) -> &[u16] {
-        assert!(
-            (dim as usize) <= PRIME_TABLE_SIZE,
-            "HaltonSampler can only sample {} dimensions",
-            PRIME_TABLE_SIZE
-        );
-        &self.radical_inverse_permutations[PRIME_SUMS[dim as usize]..]
-    }
-
     /// Performs the inverse mapping from the current pixel and given sample
     /// index to a global index into the overall set of sample vectors.
     ///
@@ -176,7 +159,7 @@ impl HaltonSampler {
         } else if dim == 1 {
             radical_inverse(dim, index / self.base_scales[1])
         } else {
-            scrambled_radical_inverse(dim, index, self.permutation_for_dimension(dim))
+            permuted_radical_inverse(dim, index, &self.radical_inverse_permutations)
         }
     }
 }
@@ -190,13 +173,14 @@ impl Sampler for HaltonSampler {
     /// Generates a new instance of an initial `Sampler` for use by a rendering
     /// thread.
     ///
-    /// * `seed` - The seed for the random number generator (if any).
-    fn clone(&self, seed: u64) -> ArcSampler {
+    /// * `seed` - The seed for the random number generator (if any). The
+    ///            Halton sequence is deterministic so this is ignored.
+    fn clone(&self, _seed: u64) -> ArcSampler {
         Arc::new(Self::new(
             self.data.samples_per_pixel,
             self.sample_bounds,
             self.sample_at_pixel_center,
-            Some(seed),
+            self.radical_inverse_permutations.clone(),
         ))
     }
 
@@ -314,7 +298,25 @@ impl From<(&ParamSet, Bounds2i)> for HaltonSampler {
 
         let sample_at_center = params.find_one_bool("samplepixelcenter", false);
 
-        Self::new(samples_per_pixel, sample_bounds, sample_at_center, None)
+        // Scramble the digits of dimensions past the first two with random or
+        // Faure permutations.
+        let permutation = params.find_one_string("permutation", String::from("random"));
+        let permutations = match permutation.as_str() {
+            "faure" => compute_faure_permutations(),
+            name => {
+                if name != "random" {
+                    warn!("Digit permutation '{}' unknown. Using 'random'.", name);
+                }
+                compute_radical_inverse_permutations(&mut RNG::default())
+            }
+        };
+
+        Self::new(
+            samples_per_pixel,
+            sample_bounds,
+            sample_at_center,
+            Arc::new(permutations),
+        )
     }
 }
 
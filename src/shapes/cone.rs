@@ -5,6 +5,7 @@ use crate::core::efloat::*;
 use crate::core::geometry::*;
 use crate::core::paramset::*;
 use crate::core::pbrt::*;
+use std::any::Any;
 use std::sync::Arc;
 
 /// A cone centered on the z-axis with base centered at [0, 0, 0].
@@ -60,6 +61,11 @@ impl Shape for Cone {
         self.data.clone()
     }
 
+    /// Returns the shape as `Any` so it can be stored in the scene cache.
+    fn as_any(&self) -> Option<&dyn Any> {
+        Some(self)
+    }
+
     /// Returns a bounding box in the shapes object space.
     fn object_bound(&self) -> Bounds3f {
         Bounds3f::new(
@@ -82,8 +88,6 @@ impl Shape for Cone {
             .unwrap()
             .transform_ray_with_error(r);
 
-        // Compute quadratic cone coefficients
-
         // Initialize EFloat ray coordinate values
         let ox = EFloat::new(ray.o.x, o_err.x);
         let oy = EFloat::new(ray.o.y, o_err.y);
@@ -93,15 +97,8 @@ impl Shape for Cone {
         let dy = EFloat::new(ray.d.y, d_err.y);
         let dz = EFloat::new(ray.d.z, d_err.z);
 
-        let mut k = EFloat::from(self.radius) / EFloat::from(self.height);
-        k = k * k;
-
-        let a = dx * dx + dy * dy - k * dz * dz;
-        let b = 2.0 * (dx * ox + dy * oy - k * dz * (oz - self.height));
-        let c = ox * ox + oy * oy - k * (oz - self.height) * (oz - self.height);
-
         // Solve quadratic equation for t values
-        if let Some((t0, t1)) = Quadratic::solve(a, b, c) {
+        if let Some((t0, t1)) = Quadratic::solve_quadric(self, [ox, oy, oz], [dx, dy, dz]) {
             // Check quadric shape t0 and t1 for nearest intersection
             if t0.upper_bound() > ray.t_max || t1.lower_bound() <= 0.0 {
                 return None;
@@ -230,8 +227,6 @@ impl Shape for Cone {
             .unwrap()
             .transform_ray_with_error(r);
 
-        // Compute quadratic cone coefficients
-
         // Initialize EFloat ray coordinate values
         let ox = EFloat::new(ray.o.x, o_err.x);
         let oy = EFloat::new(ray.o.y, o_err.y);
@@ -241,15 +236,8 @@ impl Shape for Cone {
         let dy = EFloat::new(ray.d.y, d_err.y);
         let dz = EFloat::new(ray.d.z, d_err.z);
 
-        let mut k = EFloat::from(self.radius) / EFloat::from(self.height);
-        k = k * k;
-
-        let a = dx * dx + dy * dy - k * dz * dz;
-        let b = 2.0 * (dx * ox + dy * oy - k * dz * (oz - self.height));
-        let c = ox * ox + oy * oy - k * (oz - self.height) * (oz - self.height);
-
         // Solve quadratic equation for t values
-        if let Some((t0, t1)) = Quadratic::solve(a, b, c) {
+        if let Some((t0, t1)) = Quadratic::solve_quadric(self, [ox, oy, oz], [dx, dy, dz]) {
             // Check quadric shape t0 and t1 for nearest intersection
             if t0.upper_bound() > ray.t_max || t1.lower_bound() <= 0.0 {
                 return false;
@@ -321,6 +309,23 @@ impl Shape for Cone {
     }
 }
 
+impl QuadricCoefficients for Cone {
+    /// Returns the coefficients (a, b, c) of a * t ^ 2 + b * t + c = 0 for a
+    /// ray in object space.
+    ///
+    /// * `o` - Ray origin.
+    /// * `d` - Ray direction.
+    fn coefficients<E: ErrorFloat>(&self, o: [E; 3], d: [E; 3]) -> (E, E, E) {
+        let mut k = E::from(self.radius) / E::from(self.height);
+        k = k * k;
+
+        let a = d[0] * d[0] + d[1] * d[1] - k * d[2] * d[2];
+        let b = (d[0] * o[0] + d[1] * o[1] - k * d[2] * (o[2] - self.height)) * 2.0;
+        let c = o[0] * o[0] + o[1] * o[1] - k * (o[2] - self.height) * (o[2] - self.height);
+        (a, b, c)
+    }
+}
+
 impl From<(&ParamSet, ArcTransform, ArcTransform, bool)> for Cone {
     /// Create a `Cone` from given parameter set, object to world transform,
     /// world to object transform and whether or not surface normal orientation
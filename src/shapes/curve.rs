@@ -1,9 +1,11 @@
 //! Curves
 
 #![allow(dead_code)]
+use crate::core::error::*;
 use crate::core::geometry::*;
 use crate::core::paramset::*;
 use crate::core::pbrt::*;
+use std::any::Any;
 use std::sync::Arc;
 
 /// Curve types.
@@ -112,7 +114,7 @@ impl Curve {
 
     /// Create `Curve`s from given parameter set, object to world transform,
     /// world to object transform and whether or not surface normal orientation
-    /// is reversed.
+    /// is reversed; or returns an error if the parameters are invalid.
     ///
     /// NOTE: Because we return a set of curves as `Vec<Arc<Shape>>` we cannot
     /// implement this as `From` trait :(
@@ -120,7 +122,9 @@ impl Curve {
     /// * `p` - A tuple containing the parameter set, object to world transform,
     ///         world to object transform and whether or not surface normal
     ///         orientation is reversed.
-    pub fn from_props(p: (&ParamSet, ArcTransform, ArcTransform, bool)) -> Vec<ArcShape> {
+    pub fn from_props(
+        p: (&ParamSet, ArcTransform, ArcTransform, bool),
+    ) -> Result<Vec<ArcShape>, Error> {
         let (params, o2w, w2o, reverse_orientation) = p;
 
         let width = params.find_one_float("width", 1.0);
@@ -129,18 +133,18 @@ impl Curve {
 
         let degree = params.find_one_int("degree", 3_i32) as usize;
         if degree != 2 && degree != 3 {
-            panic!(
+            return Err(Error::InvalidParameter(format!(
                 "Invalid degree {}: only degree 2 and 3 curves are supported.",
                 degree
-            );
+            )));
         }
 
         let basis = params.find_one_string("basis", String::from("bezier"));
         if basis != "bezier" && basis != "bspline" {
-            panic!(
-                "Invalid basis '{}': only ''bezier' and 'bspline' are supported.",
+            return Err(Error::InvalidParameter(format!(
+                "Invalid basis '{}': only 'bezier' and 'bspline' are supported.",
                 basis
-            );
+            )));
         }
 
         let cp = params.find_point3f("P");
@@ -150,26 +154,26 @@ impl Curve {
             // After the first segment, which uses degree+1 control points,
             // subsequent segments reuse the last control point of the previous
             // one and then use degree more control points.
-            if ((ncp - 1 - degree) % degree) != 0 {
-                panic!(
+            if ncp < degree + 1 || ((ncp - 1 - degree) % degree) != 0 {
+                return Err(Error::InvalidParameter(format!(
                     "Invalid number of control points {}: for the degree {} 
                     Bezier basis {} + n * {} are required, for n >= 0.",
                     ncp,
                     degree,
                     degree + 1,
                     degree
-                );
+                )));
             }
             n_segments = (ncp - 1) / degree;
         } else {
             if ncp < degree + 1 {
-                panic!(
+                return Err(Error::InvalidParameter(format!(
                     "Invalid number of control points {}: for the degree {} 
                       b-spline basis, must have >= {}.",
                     ncp,
                     degree,
                     degree + 1
-                );
+                )));
             }
             n_segments = ncp - degree;
         }
@@ -190,18 +194,20 @@ impl Curve {
         if nnorm > 0 {
             if curve_type != CurveType::Ribbon {
                 warn!("Curve normals are only used with 'ribbon' type curves.");
-                n = vec![];
+                n = &[];
             } else if nnorm != n_segments + 1 {
-                panic!(
+                return Err(Error::InvalidParameter(format!(
                     "Invalid number of normals {}: must provide {} normals for ribbon 
                     curves with {} segments.",
                     nnorm,
                     n_segments + 1,
                     n_segments
-                );
+                )));
             }
         } else if curve_type == CurveType::Ribbon {
-            panic!("Must provide normals 'N' at curve endpoints with ribbon curves.");
+            return Err(Error::MissingParameter(String::from(
+                "Must provide normals 'N' at curve endpoints with ribbon curves.",
+            )));
         }
 
         let split_depth = params.find_one_float("splitdepth", 3.0) as i32;
@@ -299,7 +305,7 @@ impl Curve {
             );
             curves.extend(c);
         }
-        curves
+        Ok(curves)
     }
 
     /// Recursively split curve in 2 sections if there is an intersection to
@@ -515,6 +521,11 @@ impl Shape for Curve {
         self.data.clone()
     }
 
+    /// Returns the shape as `Any` so it can be stored in the scene cache.
+    fn as_any(&self) -> Option<&dyn Any> {
+        Some(self)
+    }
+
     /// Returns a bounding box in the shapes object space.
     fn object_bound(&self) -> Bounds3f {
         // Compute object-space control points for curve segment, cp_obj
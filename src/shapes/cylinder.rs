@@ -5,6 +5,7 @@ use crate::core::efloat::*;
 use crate::core::geometry::*;
 use crate::core::paramset::*;
 use crate::core::pbrt::*;
+use std::any::Any;
 use std::sync::Arc;
 
 /// A cylinder.
@@ -68,6 +69,11 @@ impl Shape for Cylinder {
         self.data.clone()
     }
 
+    /// Returns the shape as `Any` so it can be stored in the scene cache.
+    fn as_any(&self) -> Option<&dyn Any> {
+        Some(self)
+    }
+
     /// Returns a bounding box in the shapes object space.
     fn object_bound(&self) -> Bounds3f {
         Bounds3f::new(
@@ -90,21 +96,17 @@ impl Shape for Cylinder {
             .unwrap()
             .transform_ray_with_error(r);
 
-        // Compute quadratic cylinder coefficients
-
         // Initialize EFloat ray coordinate values
         let ox = EFloat::new(ray.o.x, o_err.x);
         let oy = EFloat::new(ray.o.y, o_err.y);
+        let oz = EFloat::new(ray.o.z, o_err.z);
 
         let dx = EFloat::new(ray.d.x, d_err.x);
         let dy = EFloat::new(ray.d.y, d_err.y);
-
-        let a = dx * dx + dy * dy;
-        let b = 2.0 * (dx * ox + dy * oy);
-        let c = ox * ox + oy * oy - EFloat::from(self.radius) * EFloat::from(self.radius);
+        let dz = EFloat::new(ray.d.z, d_err.z);
 
         // Solve quadratic equation for t values
-        if let Some((t0, t1)) = Quadratic::solve(a, b, c) {
+        if let Some((t0, t1)) = Quadratic::solve_quadric(self, [ox, oy, oz], [dx, dy, dz]) {
             // Check quadric shape t0 and t1 for nearest intersection
             if t0.upper_bound() > ray.t_max || t1.lower_bound() <= 0.0 {
                 return None;
@@ -234,21 +236,17 @@ impl Shape for Cylinder {
             .unwrap()
             .transform_ray_with_error(r);
 
-        // Compute quadratic cylinder coefficients
-
         // Initialize EFloat ray coordinate values
         let ox = EFloat::new(ray.o.x, o_err.x);
         let oy = EFloat::new(ray.o.y, o_err.y);
+        let oz = EFloat::new(ray.o.z, o_err.z);
 
         let dx = EFloat::new(ray.d.x, d_err.x);
         let dy = EFloat::new(ray.d.y, d_err.y);
-
-        let a = dx * dx + dy * dy;
-        let b = 2.0 * (dx * ox + dy * oy);
-        let c = ox * ox + oy * oy - EFloat::from(self.radius) * EFloat::from(self.radius);
+        let dz = EFloat::new(ray.d.z, d_err.z);
 
         // Solve quadratic equation for t values
-        if let Some((t0, t1)) = Quadratic::solve(a, b, c) {
+        if let Some((t0, t1)) = Quadratic::solve_quadric(self, [ox, oy, oz], [dx, dy, dz]) {
             // Check quadric shape t0 and t1 for nearest intersection
             if t0.upper_bound() > ray.t_max || t1.lower_bound() <= 0.0 {
                 return false;
@@ -351,6 +349,20 @@ impl Shape for Cylinder {
     }
 }
 
+impl QuadricCoefficients for Cylinder {
+    /// Returns the coefficients (a, b, c) of a * t ^ 2 + b * t + c = 0 for a
+    /// ray in object space.
+    ///
+    /// * `o` - Ray origin.
+    /// * `d` - Ray direction.
+    fn coefficients<E: ErrorFloat>(&self, o: [E; 3], d: [E; 3]) -> (E, E, E) {
+        let a = d[0] * d[0] + d[1] * d[1];
+        let b = (d[0] * o[0] + d[1] * o[1]) * 2.0;
+        let c = o[0] * o[0] + o[1] * o[1] - E::from(self.radius) * E::from(self.radius);
+        (a, b, c)
+    }
+}
+
 impl From<(&ParamSet, ArcTransform, ArcTransform, bool)> for Cylinder {
     /// Create a `Cylinder` from given parameter set, object to world transform,
     /// world to object transform and whether or not surface normal orientation
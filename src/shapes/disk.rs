@@ -4,6 +4,7 @@
 use crate::core::paramset::*;
 use crate::core::pbrt::*;
 use crate::core::{geometry::*, sampling::concentric_sample_disk};
+use std::any::Any;
 use std::sync::Arc;
 
 /// A disk centered on the z-axis.
@@ -65,6 +66,11 @@ impl Shape for Disk {
         self.data.clone()
     }
 
+    /// Returns the shape as `Any` so it can be stored in the scene cache.
+    fn as_any(&self) -> Option<&dyn Any> {
+        Some(self)
+    }
+
     /// Returns a bounding box in the shapes object space.
     fn object_bound(&self) -> Bounds3f {
         Bounds3f::new(
@@ -0,0 +1,466 @@
+//! Geometry Cache
+
+#![allow(dead_code)]
+use super::MeshUVs;
+use crate::core::error::*;
+use crate::core::geometry::*;
+use crate::core::pbrt::*;
+use half::f16;
+use memmap2::Mmap;
+use std::fs::{self, File};
+use std::marker::PhantomData;
+use std::mem::size_of;
+use std::ops::Deref;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+stat_counter!("Geometry cache/Meshes mapped", N_MESHES_MAPPED);
+stat_counter!("Geometry cache/Meshes written", N_MESHES_WRITTEN);
+
+/// Identifies geometry cache files and the version of their format.
+const MAGIC: &[u8; 8] = b"PBRGEO01";
+
+/// Written in native byte order to detect files from other platforms.
+const BYTE_ORDER_CHECK: u64 = 0x0102_0304_0506_0708;
+
+/// Number of 64-bit words in the header following `MAGIC`.
+const HEADER_WORDS: usize = 9;
+
+/// Sections of a cache file start at multiples of this many bytes so they can
+/// be used in place once mapped.
+const ALIGNMENT: usize = 16;
+
+/// Minimum number of triangles of a mesh before it is cached. Converting
+/// smaller meshes is quicker than mapping a file.
+pub const MIN_CACHED_TRIANGLES: usize = 10_000;
+
+/// Types stored in geometry cache files.
+///
+/// # Safety
+///
+/// The types must be plain data without padding or invalid bit patterns so
+/// they can be read from and written to a file as bytes.
+pub unsafe trait CacheData: Copy {}
+
+unsafe impl CacheData for u8 {}
+unsafe impl CacheData for usize {}
+unsafe impl CacheData for Float {}
+unsafe impl CacheData for Point2f {}
+unsafe impl CacheData for Point3f {}
+unsafe impl CacheData for Vector3f {}
+unsafe impl CacheData for Normal3f {}
+unsafe impl CacheData for OctNormal {}
+unsafe impl CacheData for [f16; 2] {}
+
+/// Returns the bytes of a slice of cache data.
+///
+/// * `data` - The data.
+fn as_bytes<T: CacheData>(data: &[T]) -> &[u8] {
+    // SAFETY: `CacheData` types have no padding so all bytes are initialized.
+    unsafe { std::slice::from_raw_parts(data.as_ptr() as *const u8, std::mem::size_of_val(data)) }
+}
+
+/// Vertex data of a mesh that is either in memory or mapped from a geometry
+/// cache file. Only the pages of a mapped file that are used become resident
+/// and they are shared with other renders of the same mesh.
+#[derive(Clone)]
+pub enum MeshBuffer<T> {
+    /// Data in memory.
+    Owned(Vec<T>),
+
+    /// Data in a mapped cache file.
+    Mapped {
+        /// The mapped file.
+        map: Arc<Mmap>,
+
+        /// Offset of the data in bytes.
+        offset: usize,
+
+        /// Number of values.
+        len: usize,
+
+        /// Type of the values.
+        marker: PhantomData<T>,
+    },
+}
+
+impl<T: CacheData> MeshBuffer<T> {
+    /// Returns the values of a section of a mapped cache file; or an error if
+    /// the section doesn't fit in the file.
+    ///
+    /// * `map`    - The mapped file.
+    /// * `offset` - Offset of the section in bytes. It is advanced past the
+    ///              section.
+    /// * `len`    - Number of values in the section.
+    fn mapped(map: &Arc<Mmap>, offset: &mut usize, len: usize) -> Result<Self, Error> {
+        let start = align(*offset);
+        let end = len
+            .checked_mul(size_of::<T>())
+            .and_then(|n| n.checked_add(start))
+            .filter(|end| *end <= map.len())
+            .ok_or_else(|| Error::Parse(String::from("Geometry cache file is truncated.")))?;
+        debug_assert!((map.as_ptr() as usize + start) % std::mem::align_of::<T>() == 0);
+        *offset = end;
+        Ok(Self::Mapped {
+            map: Arc::clone(map),
+            offset: start,
+            len,
+            marker: PhantomData,
+        })
+    }
+}
+
+impl<T> MeshBuffer<T> {
+    /// Returns `true` if the data is mapped from a cache file.
+    pub fn is_mapped(&self) -> bool {
+        matches!(self, Self::Mapped { .. })
+    }
+
+    /// Returns the memory allocated for the data in bytes; mapped data
+    /// doesn't count.
+    pub fn memory_size(&self) -> usize {
+        match self {
+            Self::Owned(v) => v.len() * size_of::<T>(),
+            Self::Mapped { .. } => 0,
+        }
+    }
+}
+
+impl<T> Deref for MeshBuffer<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        match self {
+            Self::Owned(v) => v,
+            Self::Mapped {
+                map, offset, len, ..
+            } => {
+                // SAFETY: `mapped()` checked that the section lies within the
+                // file and is aligned, and `T` is `CacheData`. The file is
+                // mapped read-only for as long as `map` lives.
+                unsafe { std::slice::from_raw_parts(map.as_ptr().add(*offset) as *const T, *len) }
+            }
+        }
+    }
+}
+
+impl<T> From<Vec<T>> for MeshBuffer<T> {
+    /// Returns a buffer owning the values.
+    ///
+    /// * `v` - The values.
+    fn from(v: Vec<T>) -> Self {
+        Self::Owned(v)
+    }
+}
+
+impl<T> Default for MeshBuffer<T> {
+    /// Returns an empty buffer.
+    fn default() -> Self {
+        Self::Owned(vec![])
+    }
+}
+
+/// Returns an offset rounded up to the alignment of cache file sections.
+///
+/// * `offset` - The offset in bytes.
+fn align(offset: usize) -> usize {
+    offset.div_ceil(ALIGNMENT) * ALIGNMENT
+}
+
+/// The vertex data of a triangle mesh in world space as it is stored in the
+/// geometry cache.
+#[derive(Clone)]
+pub struct MeshData {
+    /// Vertex indices; three per triangle.
+    pub vertex_indices: MeshBuffer<usize>,
+
+    /// Vertex positions.
+    pub p: MeshBuffer<Point3f>,
+
+    /// Octahedral encoded vertex normals.
+    pub n: MeshBuffer<OctNormal>,
+
+    /// Tangent vectors per vertex.
+    pub s: MeshBuffer<Vector3f>,
+
+    /// Parametric uv-coordinates per vertex.
+    pub uv: MeshUVs,
+}
+
+impl MeshData {
+    /// Returns an error if vertex indices are out of bounds or the number of
+    /// vertex attributes doesn't match the number of vertices.
+    pub fn validate(&self) -> Result<(), Error> {
+        let np = self.p.len();
+        let attribute_ok = |n: usize| n == 0 || n == np;
+        if self.vertex_indices.len() % 3 != 0
+            || self.vertex_indices.iter().any(|i| *i >= np)
+            || !attribute_ok(self.n.len())
+            || !attribute_ok(self.s.len())
+            || !attribute_ok(self.uv.len())
+        {
+            return Err(Error::Parse(String::from(
+                "Geometry cache file has inconsistent vertex data.",
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Key of a mesh in the geometry cache. It is an FNV-1a hash of the inputs
+/// of the conversion to world space. Unlike `DefaultHasher` it doesn't change
+/// between builds so cache files stay valid.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CacheKey(u64);
+
+impl Default for CacheKey {
+    /// Returns the key of no inputs.
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl CacheKey {
+    /// Returns a key from its value.
+    ///
+    /// * `value` - The value.
+    pub fn from_value(value: u64) -> Self {
+        Self(value)
+    }
+
+    /// Returns the value of the key.
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+
+    /// Adds bytes to the key.
+    ///
+    /// * `bytes` - The bytes.
+    pub fn add_bytes(&mut self, bytes: &[u8]) {
+        for b in bytes {
+            self.0 = (self.0 ^ *b as u64).wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    /// Adds values to the key.
+    ///
+    /// * `data` - The values.
+    pub fn add<T: CacheData>(&mut self, data: &[T]) {
+        // Include the length so consecutive slices can't run into each other.
+        self.add_bytes(&(data.len() as u64).to_le_bytes());
+        self.add_bytes(as_bytes(data));
+    }
+}
+
+/// Directory of binary files holding converted triangle meshes. A mesh is
+/// written on first load and memory-mapped read-only afterwards, which saves
+/// the conversion and keeps only the parts of the mesh that are used in
+/// memory.
+pub struct GeometryCache {
+    /// The directory.
+    dir: PathBuf,
+}
+
+impl GeometryCache {
+    /// Create a new `GeometryCache`.
+    ///
+    /// * `dir` - The directory holding the cache files.
+    pub fn new(dir: &str) -> Self {
+        Self {
+            dir: PathBuf::from(dir),
+        }
+    }
+
+    /// Returns the path of the cache file of a mesh.
+    ///
+    /// * `key` - Key of the mesh.
+    pub fn path(&self, key: CacheKey) -> PathBuf {
+        self.dir.join(format!("{:016x}.geo", key.0))
+    }
+
+    /// Maps the cached data of a mesh. Returns `None` if the mesh isn't
+    /// cached or an error if the file can't be read or is invalid.
+    ///
+    /// * `key` - Key of the mesh.
+    pub fn load(&self, key: CacheKey) -> Result<Option<MeshData>, Error> {
+        let path = self.path(key);
+        let file = match File::open(&path) {
+            Ok(file) => file,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => {
+                return Err(Error::Io(format!(
+                    "Error opening geometry cache '{}'. {}.",
+                    path.display(),
+                    err
+                )))
+            }
+        };
+
+        // SAFETY: Cache files are written to a temporary file and renamed so
+        // a mapped file is complete and never modified in place.
+        let map = unsafe { Mmap::map(&file) }.map_err(|err| {
+            Error::Io(format!(
+                "Error mapping geometry cache '{}'. {}.",
+                path.display(),
+                err
+            ))
+        })?;
+        let data = Self::parse(Arc::new(map)).map_err(|err| {
+            Error::Parse(format!(
+                "Error reading geometry cache '{}'. {}",
+                path.display(),
+                err
+            ))
+        })?;
+        N_MESHES_MAPPED.inc();
+        Ok(Some(data))
+    }
+
+    /// Returns the mesh data in a mapped cache file; or an error if it isn't
+    /// valid.
+    ///
+    /// * `map` - The mapped file.
+    fn parse(map: Arc<Mmap>) -> Result<MeshData, Error> {
+        let header_size = MAGIC.len() + HEADER_WORDS * size_of::<u64>();
+        if map.len() < header_size || &map[..MAGIC.len()] != MAGIC {
+            return Err(Error::Parse(String::from(
+                "Not a geometry cache file or an unsupported version.",
+            )));
+        }
+        let header: Vec<u64> = map[MAGIC.len()..header_size]
+            .chunks(size_of::<u64>())
+            .map(|b| u64::from_ne_bytes([b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7]]))
+            .collect();
+        if header[0] != BYTE_ORDER_CHECK
+            || header[1] != size_of::<Float>() as u64
+            || header[2] != size_of::<usize>() as u64
+        {
+            return Err(Error::Parse(String::from(
+                "Geometry cache file was written on an incompatible platform or build.",
+            )));
+        }
+        let half_uvs = header[3] != 0;
+        let len = |i: usize| header[i] as usize;
+
+        let mut offset = header_size;
+        let vertex_indices = MeshBuffer::mapped(&map, &mut offset, len(4))?;
+        let p = MeshBuffer::mapped(&map, &mut offset, len(5))?;
+        let n = MeshBuffer::mapped(&map, &mut offset, len(6))?;
+        let s = MeshBuffer::mapped(&map, &mut offset, len(7))?;
+        let uv = if half_uvs {
+            MeshUVs::Half(MeshBuffer::mapped(&map, &mut offset, len(8))?)
+        } else {
+            MeshUVs::Full(MeshBuffer::mapped(&map, &mut offset, len(8))?)
+        };
+
+        let data = MeshData {
+            vertex_indices,
+            p,
+            n,
+            s,
+            uv,
+        };
+        data.validate()?;
+        Ok(data)
+    }
+
+    /// Writes the data of a mesh to the cache; or returns an error if the
+    /// file can't be written.
+    ///
+    /// * `key`  - Key of the mesh.
+    /// * `data` - The mesh data.
+    pub fn store(&self, key: CacheKey, data: &MeshData) -> Result<(), Error> {
+        let (half_uvs, uv_len) = match &data.uv {
+            MeshUVs::Full(uv) => (false, uv.len()),
+            MeshUVs::Half(uv) => (true, uv.len()),
+        };
+        let header: [u64; HEADER_WORDS] = [
+            BYTE_ORDER_CHECK,
+            size_of::<Float>() as u64,
+            size_of::<usize>() as u64,
+            half_uvs as u64,
+            data.vertex_indices.len() as u64,
+            data.p.len() as u64,
+            data.n.len() as u64,
+            data.s.len() as u64,
+            uv_len as u64,
+        ];
+
+        let mut bytes: Vec<u8> = MAGIC.to_vec();
+        for word in header.iter() {
+            bytes.extend_from_slice(&word.to_ne_bytes());
+        }
+        let mut section = |data: &[u8]| {
+            bytes.resize(align(bytes.len()), 0);
+            bytes.extend_from_slice(data);
+        };
+        section(as_bytes(&data.vertex_indices));
+        section(as_bytes(&data.p));
+        section(as_bytes(&data.n));
+        section(as_bytes(&data.s));
+        match &data.uv {
+            MeshUVs::Full(uv) => section(as_bytes(uv)),
+            MeshUVs::Half(uv) => section(as_bytes(uv)),
+        }
+
+        // Write to a temporary file and rename it so other renders never map
+        // a partially written file.
+        let path = self.path(key);
+        let temp_path = path.with_extension(format!("tmp{}", std::process::id()));
+        fs::create_dir_all(&self.dir)
+            .and_then(|_| fs::write(&temp_path, &bytes))
+            .and_then(|_| fs::rename(&temp_path, &path))
+            .map_err(|err| {
+                let _ = fs::remove_file(&temp_path);
+                Error::Io(format!(
+                    "Error writing geometry cache '{}'. {}.",
+                    path.display(),
+                    err
+                ))
+            })?;
+        N_MESHES_WRITTEN.inc();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn meshes_are_mapped_from_the_cache() {
+        let dir = std::env::temp_dir().join("pbr_rust_geometry_cache");
+        let cache = GeometryCache::new(dir.to_str().unwrap());
+        let mut key = CacheKey::default();
+        key.add(&[1usize, 2, 3]);
+        assert_ne!(key, CacheKey::default());
+
+        let data = MeshData {
+            vertex_indices: MeshBuffer::from(vec![0, 1, 2, 2, 1, 3]),
+            p: MeshBuffer::from(vec![
+                Point3f::new(0.0, 0.0, 0.0),
+                Point3f::new(1.0, 0.0, 0.0),
+                Point3f::new(0.0, 1.0, 0.0),
+                Point3f::new(1.0, 1.0, 0.5),
+            ]),
+            n: MeshBuffer::default(),
+            s: MeshBuffer::default(),
+            uv: MeshUVs::new(vec![Point2f::new(0.5, 0.25); 4], true),
+        };
+        cache.store(key, &data).unwrap();
+
+        let mapped = cache.load(key).unwrap().unwrap();
+        assert!(mapped.p.is_mapped() && mapped.p.memory_size() == 0);
+        assert_eq!(*mapped.vertex_indices, *data.vertex_indices);
+        assert_eq!(*mapped.p, *data.p);
+        assert!(mapped.n.is_empty() && mapped.s.is_empty());
+        assert_eq!(mapped.uv.get(3), Point2f::new(0.5, 0.25));
+
+        // Missing and damaged files.
+        assert!(cache.load(CacheKey(1)).unwrap().is_none());
+        let bytes = fs::read(cache.path(key)).unwrap();
+        fs::write(cache.path(key), &bytes[..bytes.len() - 8]).unwrap();
+        assert!(matches!(cache.load(key), Err(Error::Parse(_))));
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
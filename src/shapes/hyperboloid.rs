@@ -5,6 +5,7 @@ use crate::core::efloat::*;
 use crate::core::geometry::*;
 use crate::core::paramset::*;
 use crate::core::pbrt::*;
+use std::any::Any;
 use std::mem::swap;
 use std::sync::Arc;
 
@@ -127,6 +128,11 @@ impl Shape for Hyperboloid {
         self.data.clone()
     }
 
+    /// Returns the shape as `Any` so it can be stored in the scene cache.
+    fn as_any(&self) -> Option<&dyn Any> {
+        Some(self)
+    }
+
     /// Returns a bounding box in the shapes object space.
     fn object_bound(&self) -> Bounds3f {
         Bounds3f::new(
@@ -149,8 +155,6 @@ impl Shape for Hyperboloid {
             .unwrap()
             .transform_ray_with_error(r);
 
-        // Compute quadratic hyperboloid coefficients
-
         // Initialize EFloat ray coordinate values
         let ox = EFloat::new(ray.o.x, o_err.x);
         let oy = EFloat::new(ray.o.y, o_err.y);
@@ -160,12 +164,8 @@ impl Shape for Hyperboloid {
         let dy = EFloat::new(ray.d.y, d_err.y);
         let dz = EFloat::new(ray.d.z, d_err.z);
 
-        let a = self.ah * dx * dx + self.ah * dy * dy - self.ch * dz * dz;
-        let b = 2.0 * (self.ah * dx * ox + self.ah * dy * oy - self.ch * dz * oz);
-        let c = self.ah * ox * ox + self.ah * oy * oy - self.ch * oz * oz - 1.0;
-
         // Solve quadratic equation for t values
-        if let Some((t0, t1)) = Quadratic::solve(a, b, c) {
+        if let Some((t0, t1)) = Quadratic::solve_quadric(self, [ox, oy, oz], [dx, dy, dz]) {
             // Check quadric shape t0 and t1 for nearest intersection
             if t0.upper_bound() > ray.t_max || t1.lower_bound() <= 0.0 {
                 return None;
@@ -305,8 +305,6 @@ impl Shape for Hyperboloid {
             .unwrap()
             .transform_ray_with_error(r);
 
-        // Compute quadratic hyperboloid coefficients
-
         // Initialize EFloat ray coordinate values
         let ox = EFloat::new(ray.o.x, o_err.x);
         let oy = EFloat::new(ray.o.y, o_err.y);
@@ -316,12 +314,8 @@ impl Shape for Hyperboloid {
         let dy = EFloat::new(ray.d.y, d_err.y);
         let dz = EFloat::new(ray.d.z, d_err.z);
 
-        let a = self.ah * dx * dx + self.ah * dy * dy - self.ch * dz * dz;
-        let b = 2.0 * (self.ah * dx * ox + self.ah * dy * oy - self.ch * dz * oz);
-        let c = self.ah * ox * ox + self.ah * oy * oy - self.ch * oz * oz - 1.0;
-
         // Solve quadratic equation for t values
-        if let Some((t0, t1)) = Quadratic::solve(a, b, c) {
+        if let Some((t0, t1)) = Quadratic::solve_quadric(self, [ox, oy, oz], [dx, dy, dz]) {
             // Check quadric shape t0 and t1 for nearest intersection
             if t0.upper_bound() > ray.t_max || t1.lower_bound() <= 0.0 {
                 return false;
@@ -420,6 +414,20 @@ impl Shape for Hyperboloid {
     }
 }
 
+impl QuadricCoefficients for Hyperboloid {
+    /// Returns the coefficients (a, b, c) of a * t ^ 2 + b * t + c = 0 for a
+    /// ray in object space.
+    ///
+    /// * `o` - Ray origin.
+    /// * `d` - Ray direction.
+    fn coefficients<E: ErrorFloat>(&self, o: [E; 3], d: [E; 3]) -> (E, E, E) {
+        let a = d[0] * d[0] * self.ah + d[1] * d[1] * self.ah - d[2] * d[2] * self.ch;
+        let b = (d[0] * o[0] * self.ah + d[1] * o[1] * self.ah - d[2] * o[2] * self.ch) * 2.0;
+        let c = o[0] * o[0] * self.ah + o[1] * o[1] * self.ah - o[2] * o[2] * self.ch - 1.0;
+        (a, b, c)
+    }
+}
+
 impl From<(&ParamSet, ArcTransform, ArcTransform, bool)> for Hyperboloid {
     /// Create a `Hyperboloid` from given parameter set, object to world transform,
     /// world to object transform and whether or not surface normal orientation
@@ -3,6 +3,7 @@
 #![allow(dead_code)]
 
 use super::TriangleMesh;
+use crate::core::error::*;
 use crate::core::geometry::*;
 use crate::core::paramset::*;
 use crate::core::pbrt::*;
@@ -663,15 +664,17 @@ impl LoopSubDiv {
             ns,
             vec![],
             vec![],
+            false,
             None,
             None,
             vec![],
+            false,
         )
     }
 
     /// Create `LoopSubDiv` from given parameter set, object to world transform,
     /// world to object transform and whether or not surface normal orientation
-    /// is reversed.
+    /// is reversed; or returns an error if the parameters are invalid.
     ///
     /// NOTE: Because we return a set of curves as `Vec<Arc<Shape>>` we cannot
     /// implement this as `From` trait :(
@@ -679,31 +682,47 @@ impl LoopSubDiv {
     /// * `p` - A tuple containing the parameter set, object to world transform,
     ///         world to object transform and whether or not surface normal
     ///         orientation is reversed.
-    pub fn from_props(p: (&ParamSet, ArcTransform, ArcTransform, bool)) -> Vec<ArcShape> {
+    pub fn from_props(
+        p: (&ParamSet, ArcTransform, ArcTransform, bool),
+    ) -> Result<Vec<ArcShape>, Error> {
         let (params, o2w, w2o, reverse_orientation) = p;
 
-        let n_levels = params.find_one_int("nlevels", 3) as usize;
-        let vertex_indices: Vec<usize> = params
-            .find_int("indices")
-            .iter()
-            .map(|i| *i as usize)
-            .collect();
+        let n_levels = params.find_one_int("nlevels", 3).max(0) as usize;
+        let indices = params.find_int("indices");
         let p = params.find_point3f("P");
-        if vertex_indices.len() == 0 {
-            panic!("Vertex indices 'indices' not provided for LoopSubDiv shape.");
+        if indices.len() == 0 {
+            return Err(Error::MissingParameter(String::from(
+                "Vertex indices 'indices' not provided for LoopSubDiv shape.",
+            )));
+        }
+        if indices.len() % 3 != 0 {
+            return Err(Error::InvalidParameter(format!(
+                "Number of vertex indices {} not a multiple of 3 for LoopSubDiv shape.",
+                indices.len()
+            )));
         }
         if p.len() == 0 {
-            panic!("Vertex positions 'P' not provided for LoopSubDiv shape.");
+            return Err(Error::MissingParameter(String::from(
+                "Vertex positions 'P' not provided for LoopSubDiv shape.",
+            )));
+        }
+        if let Some(i) = indices.iter().find(|&&i| i < 0 || i as usize >= p.len()) {
+            return Err(Error::InvalidParameter(format!(
+                "LoopSubDiv shape has out-of-bounds vertex index {} ({} 'P' values were given).",
+                i,
+                p.len()
+            )));
         }
+        let vertex_indices: Vec<usize> = indices.iter().map(|i| *i as usize).collect();
 
-        Self::subdivide(
+        Ok(Self::subdivide(
             o2w.clone(),
             w2o.clone(),
             reverse_orientation,
             n_levels,
             vertex_indices,
-            p,
-        )
+            p.to_vec(),
+        ))
     }
 }
 
@@ -781,3 +800,30 @@ fn next(i: i64) -> i64 {
 fn prev(i: i64) -> i64 {
     (i + 2) % 3
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn malformed_meshes_are_rejected() {
+        let identity = Arc::new(Transform::default());
+        let p = [
+            Point3f::new(0.0, 0.0, 0.0),
+            Point3f::new(1.0, 0.0, 0.0),
+            Point3f::new(0.0, 1.0, 0.0),
+        ];
+        let make = |indices: &[Int]| {
+            let mut params = ParamSet::new();
+            params.add_int("indices", indices);
+            params.add_point3f("P", &p);
+            params.add_int("nlevels", &[1]);
+            LoopSubDiv::from_props((&params, identity.clone(), identity.clone(), false))
+        };
+
+        assert!(matches!(make(&[0, 1]), Err(Error::InvalidParameter(_))));
+        assert!(matches!(make(&[0, 1, 3]), Err(Error::InvalidParameter(_))));
+        assert!(matches!(make(&[0, -1, 2]), Err(Error::InvalidParameter(_))));
+        assert!(!make(&[0, 1, 2]).unwrap().is_empty());
+    }
+}
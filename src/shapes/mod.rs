@@ -4,9 +4,12 @@ mod cone;
 mod curve;
 mod cylinder;
 mod disk;
+mod geometry_cache;
 mod hyperboloid;
 mod loopsubdiv;
+mod objmesh;
 mod paraboloid;
+mod plymesh;
 mod sphere;
 mod triangle;
 
@@ -15,8 +18,11 @@ pub use cone::*;
 pub use curve::*;
 pub use cylinder::*;
 pub use disk::*;
+pub use geometry_cache::*;
 pub use hyperboloid::*;
 pub use loopsubdiv::*;
+pub use objmesh::*;
 pub use paraboloid::*;
+pub use plymesh::*;
 pub use sphere::*;
 pub use triangle::*;
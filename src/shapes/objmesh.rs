@@ -0,0 +1,513 @@
+//! Wavefront OBJ Meshes
+
+#![allow(dead_code)]
+use super::TriangleMesh;
+use crate::core::error::*;
+use crate::core::fileutil::*;
+use crate::core::geometry::*;
+use crate::core::paramset::*;
+use crate::core::pbrt::*;
+use crate::core::texture::*;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+/// A material read from an MTL file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MtlMaterial {
+    /// Name of the material.
+    pub name: String,
+
+    /// Diffuse color `Kd`.
+    pub kd: [Float; 3],
+
+    /// Specular color `Ks`.
+    pub ks: [Float; 3],
+
+    /// Specular exponent `Ns`.
+    pub ns: Float,
+
+    /// Path of the diffuse color texture `map_Kd`.
+    pub map_kd: Option<String>,
+}
+
+impl MtlMaterial {
+    /// Create a new `MtlMaterial` with the defaults of the MTL format.
+    ///
+    /// * `name` - Name of the material.
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: String::from(name),
+            kd: [0.8, 0.8, 0.8],
+            ks: [0.0, 0.0, 0.0],
+            ns: 0.0,
+            map_kd: None,
+        }
+    }
+
+    /// Returns the materials in an MTL file.
+    ///
+    /// * `source` - Contents of the file.
+    /// * `dir`    - Directory of the file; texture paths are relative to it.
+    pub fn parse(source: &str, dir: &Path) -> Result<Vec<Self>, Error> {
+        let mut materials: Vec<Self> = vec![];
+        for (line_number, line) in source.lines().enumerate() {
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            let invalid = || {
+                Error::Parse(format!(
+                    "Invalid MTL statement on line {}: '{}'.",
+                    line_number + 1,
+                    line
+                ))
+            };
+            match tokens.as_slice() {
+                ["newmtl", name @ ..] if !name.is_empty() => {
+                    materials.push(Self::new(&name.join(" ")));
+                }
+                [] => (),
+                [statement, ..] if statement.starts_with('#') => (),
+                [statement, args @ ..] => {
+                    let material = match materials.last_mut() {
+                        Some(material) => material,
+                        None => return Err(invalid()),
+                    };
+                    match *statement {
+                        "Kd" => material.kd = parse_floats(args).ok_or_else(invalid)?,
+                        "Ks" => material.ks = parse_floats(args).ok_or_else(invalid)?,
+                        "Ns" => material.ns = parse_floats::<1>(args).ok_or_else(invalid)?[0],
+                        // Options before the file name are ignored.
+                        "map_Kd" => {
+                            let file = args.last().ok_or_else(invalid)?;
+                            material.map_kd = Some(dir.join(file).to_string_lossy().into_owned());
+                        }
+                        _ => (), // Not used.
+                    }
+                }
+            }
+        }
+        Ok(materials)
+    }
+
+    /// Returns the name and parameters of a texture for the diffuse color
+    /// map; or `None` if the material has none.
+    pub fn diffuse_texture(&self) -> Option<(String, ParamSet)> {
+        self.map_kd.as_ref().map(|path| {
+            let mut params = ParamSet::new();
+            params.add_string("filename", std::slice::from_ref(path));
+            (self.diffuse_texture_name(), params)
+        })
+    }
+
+    /// Returns the name of the texture for the diffuse color map.
+    fn diffuse_texture_name(&self) -> String {
+        format!("{}:Kd", self.name)
+    }
+
+    /// Returns the type and parameters of the pbrt material closest to the
+    /// material. Materials with a specular color become `plastic` with the
+    /// roughness corresponding to their specular exponent and the others
+    /// become `matte`. A diffuse color map refers to the texture returned by
+    /// `diffuse_texture()`.
+    pub fn pbrt_material(&self) -> Result<(String, ParamSet), Error> {
+        let mut params = ParamSet::new();
+        if self.map_kd.is_some() {
+            params.add_texture("Kd", &[self.diffuse_texture_name()]);
+        } else {
+            params.add_rgb_spectrum("Kd", &self.kd)?;
+        }
+
+        if self.ks.iter().all(|c| *c <= 0.0) {
+            return Ok((String::from("matte"), params));
+        }
+        params.add_rgb_spectrum("Ks", &self.ks)?;
+        // Blinn-Phong exponent to microfacet distribution alpha.
+        params.add_float("roughness", &[(2.0 / (self.ns + 2.0)).sqrt()]);
+        params.add_bool("remaproughness", &[false]);
+        Ok((String::from("plastic"), params))
+    }
+}
+
+/// Returns a number of floating point values; or `None` if there are too few
+/// values or they aren't numbers. Values past the number are ignored.
+///
+/// * `tokens` - The values.
+fn parse_floats<const N: usize>(tokens: &[&str]) -> Option<[Float; N]> {
+    let mut values = [0.0; N];
+    for (value, token) in values.iter_mut().zip(tokens.iter()) {
+        *value = token.parse().ok()?;
+    }
+    if tokens.len() < N {
+        return None;
+    }
+    Some(values)
+}
+
+/// Triangles of an OBJ file in the same group with the same material.
+#[derive(Clone, Default)]
+pub struct ObjGroup {
+    /// Name of the group or object; empty for faces outside of groups.
+    pub name: String,
+
+    /// Name of the MTL material of the faces.
+    pub material: Option<String>,
+
+    /// Vertex indices; three per triangle.
+    pub vertex_indices: Vec<usize>,
+
+    /// Vertex positions.
+    pub p: Vec<Point3f>,
+
+    /// Vertex normals; empty unless every vertex has one.
+    pub n: Vec<Normal3f>,
+
+    /// Parametric uv-coordinates per vertex; empty unless every vertex has
+    /// them.
+    pub uv: Vec<Point2f>,
+}
+
+impl ObjGroup {
+    /// Returns the parameters of a `trianglemesh` for the group.
+    ///
+    /// * `params` - Parameters of the `objmesh` shape. Parameters that aren't
+    ///              specific to OBJ files, e.g. alpha textures, are kept.
+    pub fn mesh_params(&self, params: &ParamSet) -> ParamSet {
+        let mut mesh_params = params.clone();
+        mesh_params.erase_string("filename");
+        mesh_params.erase_bool("usemtl");
+        let indices: Vec<Int> = self.vertex_indices.iter().map(|i| *i as Int).collect();
+        mesh_params.add_int("indices", &indices);
+        mesh_params.add_point3f("P", &self.p);
+        if !self.n.is_empty() {
+            mesh_params.add_normal3f("N", &self.n);
+        }
+        if !self.uv.is_empty() {
+            mesh_params.add_point2f("uv", &self.uv);
+        }
+        mesh_params
+    }
+}
+
+/// Builds the vertices of a group from the position, uv-coordinate and
+/// normal references of its faces.
+#[derive(Default)]
+struct GroupBuilder {
+    /// The group.
+    group: ObjGroup,
+
+    /// Index of the vertex of each combination of position, uv-coordinate and
+    /// normal.
+    vertices: HashMap<(usize, Option<usize>, Option<usize>), usize>,
+
+    /// Whether every vertex has a uv-coordinate.
+    all_uvs: bool,
+
+    /// Whether every vertex has a normal.
+    all_normals: bool,
+}
+
+/// Triangle meshes read from a Wavefront OBJ file and the MTL materials they
+/// use.
+#[derive(Clone, Default)]
+pub struct ObjMesh {
+    /// Triangles of each group and material in the order they appear.
+    pub groups: Vec<ObjGroup>,
+
+    /// Materials of the MTL files by name.
+    pub materials: HashMap<String, MtlMaterial>,
+}
+
+impl ObjMesh {
+    /// Reads an OBJ file and the MTL files it references; or returns an error
+    /// if the OBJ file can't be read or isn't valid. MTL files that can't be
+    /// read are skipped.
+    ///
+    /// * `path` - Path of the file.
+    pub fn read(path: &str) -> Result<Self, Error> {
+        let source = fs::read_to_string(path)
+            .map_err(|err| Error::Io(format!("Error reading OBJ file '{}'. {}.", path, err)))?;
+        let dir = parent_path(path).unwrap_or_else(|| String::from("."));
+        Self::parse(&source, Path::new(&dir))
+            .map_err(|err| Error::Parse(format!("Error reading OBJ file '{}'. {}", path, err)))
+    }
+
+    /// Returns the meshes in the contents of an OBJ file. Polygons are split
+    /// into triangle fans.
+    ///
+    /// * `source` - Contents of the file.
+    /// * `dir`    - Directory of the file; MTL files are relative to it.
+    pub fn parse(source: &str, dir: &Path) -> Result<Self, Error> {
+        let mut mesh = Self::default();
+        let mut positions: Vec<Point3f> = vec![];
+        let mut uvs: Vec<Point2f> = vec![];
+        let mut normals: Vec<Normal3f> = vec![];
+
+        let mut builders: Vec<GroupBuilder> = vec![];
+        let mut builder_index: HashMap<(String, Option<String>), usize> = HashMap::new();
+        let mut group_name = String::new();
+        let mut material: Option<String> = None;
+        let mut polygon: Vec<usize> = vec![];
+
+        for (line_number, line) in source.lines().enumerate() {
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            let invalid = || {
+                Error::Parse(format!(
+                    "Invalid OBJ statement on line {}: '{}'.",
+                    line_number + 1,
+                    line
+                ))
+            };
+            match tokens.as_slice() {
+                ["v", args @ ..] => {
+                    let [x, y, z] = parse_floats(args).ok_or_else(invalid)?;
+                    positions.push(Point3f::new(x, y, z));
+                }
+                ["vt", args @ ..] => {
+                    let [u, v] = parse_floats(args).ok_or_else(invalid)?;
+                    uvs.push(Point2f::new(u, v));
+                }
+                ["vn", args @ ..] => {
+                    let [x, y, z] = parse_floats(args).ok_or_else(invalid)?;
+                    normals.push(Normal3f::new(x, y, z));
+                }
+                ["g" | "o", name @ ..] => group_name = name.join(" "),
+                ["usemtl", name @ ..] => material = Some(name.join(" ")),
+                ["mtllib", files @ ..] => {
+                    for file in files {
+                        mesh.load_materials(&dir.join(file));
+                    }
+                }
+                ["f", vertices @ ..] => {
+                    if vertices.len() < 3 {
+                        return Err(invalid());
+                    }
+
+                    let key = (group_name.clone(), material.clone());
+                    let index = *builder_index.entry(key).or_insert_with(|| {
+                        builders.push(GroupBuilder {
+                            group: ObjGroup {
+                                name: group_name.clone(),
+                                material: material.clone(),
+                                ..ObjGroup::default()
+                            },
+                            all_uvs: true,
+                            all_normals: true,
+                            ..GroupBuilder::default()
+                        });
+                        builders.len() - 1
+                    });
+                    let builder = &mut builders[index];
+
+                    polygon.clear();
+                    for vertex in vertices {
+                        let mut refs = vertex.split('/');
+                        let p = resolve_index(refs.next(), positions.len()).ok_or_else(invalid)?;
+                        let uv = match refs.next() {
+                            Some(r) if !r.is_empty() => {
+                                Some(resolve_index(Some(r), uvs.len()).ok_or_else(invalid)?)
+                            }
+                            _ => None,
+                        };
+                        let n = match refs.next() {
+                            Some(r) if !r.is_empty() => {
+                                Some(resolve_index(Some(r), normals.len()).ok_or_else(invalid)?)
+                            }
+                            _ => None,
+                        };
+
+                        let group = &mut builder.group;
+                        let vertex_index =
+                            *builder.vertices.entry((p, uv, n)).or_insert_with(|| {
+                                group.p.push(positions[p]);
+                                group.uv.push(uv.map_or_else(Point2f::default, |i| uvs[i]));
+                                group
+                                    .n
+                                    .push(n.map_or_else(Normal3f::default, |i| normals[i]));
+                                group.p.len() - 1
+                            });
+                        builder.all_uvs &= uv.is_some();
+                        builder.all_normals &= n.is_some();
+                        polygon.push(vertex_index);
+                    }
+
+                    for i in 1..polygon.len() - 1 {
+                        builder.group.vertex_indices.extend_from_slice(&[
+                            polygon[0],
+                            polygon[i],
+                            polygon[i + 1],
+                        ]);
+                    }
+                }
+                _ => (), // Comments and statements that aren't used.
+            }
+        }
+
+        mesh.groups = builders
+            .into_iter()
+            .map(|mut builder| {
+                if !builder.all_uvs {
+                    builder.group.uv.clear();
+                }
+                if !builder.all_normals {
+                    builder.group.n.clear();
+                }
+                builder.group
+            })
+            .collect();
+        if mesh.groups.is_empty() {
+            return Err(Error::Parse(String::from("OBJ file has no faces.")));
+        }
+        Ok(mesh)
+    }
+
+    /// Adds the materials of an MTL file; or logs a warning if it can't be
+    /// found or read.
+    ///
+    /// * `path` - Path of the file.
+    fn load_materials(&mut self, path: &Path) {
+        let dir = path.parent().unwrap_or_else(|| Path::new("."));
+        let materials = find_file(&path.to_string_lossy())
+            .and_then(|path| fs::read_to_string(path).map_err(|err| Error::Io(err.to_string())))
+            .and_then(|source| MtlMaterial::parse(&source, dir));
+        match materials {
+            Ok(materials) => {
+                for material in materials {
+                    self.materials.insert(material.name.clone(), material);
+                }
+            }
+            Err(err) => warn!("Skipping MTL file '{}'. {}", path.display(), err),
+        }
+    }
+
+    /// Returns the meshes of the OBJ file given by the `filename` parameter of
+    /// an `objmesh` shape; or an error if it can't be read.
+    ///
+    /// * `params` - Parameters of the shape.
+    pub fn from_params(params: &ParamSet) -> Result<Self, Error> {
+        let filename = params.find_one_filename("filename", String::from(""));
+        if filename.is_empty() {
+            return Err(Error::MissingParameter(format!(
+                "OBJ file '{}' not found for objmesh shape",
+                params.find_one_string("filename", String::from(""))
+            )));
+        }
+        Self::read(&filename)
+    }
+
+    /// Create the triangles of all groups of an OBJ file; or returns an error
+    /// if the file can't be read or the parameters are invalid. Materials are
+    /// assigned by the API when `usemtl` is set.
+    ///
+    /// NOTE: Because we return a set of triangles as `Vec<Arc<Shape>>` we
+    /// cannot implement this as `From` trait :(
+    ///
+    /// * `p`              - A tuple containing the parameter set, object to
+    ///                      world transform, world to object transform and
+    ///                      whether or not surface normal orientation is reversed.
+    /// * `float_textures` - Float textures.
+    pub fn from_props(
+        p: (&ParamSet, ArcTransform, ArcTransform, bool),
+        float_textures: &HashMap<String, ArcTexture<Float>>,
+    ) -> Result<Vec<ArcShape>, Error> {
+        let (params, o2w, w2o, reverse_orientation) = p;
+        let mesh = Self::from_params(params)?;
+
+        let mut shapes = vec![];
+        for group in mesh.groups.iter() {
+            shapes.append(&mut TriangleMesh::from_props(
+                (
+                    &group.mesh_params(params),
+                    o2w.clone(),
+                    w2o.clone(),
+                    reverse_orientation,
+                ),
+                float_textures,
+            )?);
+        }
+        Ok(shapes)
+    }
+}
+
+/// Returns the index of an element referenced in a face; or `None` if the
+/// reference is missing or out of bounds. References start at 1 and negative
+/// ones count back from the last element.
+///
+/// * `reference` - The reference.
+/// * `len`       - Number of elements defined so far.
+fn resolve_index(reference: Option<&str>, len: usize) -> Option<usize> {
+    let i: i64 = reference?.parse().ok()?;
+    let index = if i > 0 { i - 1 } else { len as i64 + i };
+    if index >= 0 && (index as usize) < len && i != 0 {
+        Some(index as usize)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn groups_and_materials_are_read() {
+        let dir = std::env::temp_dir().join("pbr_rust_objmesh");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("quad.mtl"),
+            "newmtl red\nKd 1 0 0\n\nnewmtl shiny\nKd 0.5 0.5 0.5\nKs 0.2 0.2 0.2\n\
+             Ns 6\nmap_Kd -bm 1 wood.png\n",
+        )
+        .unwrap();
+
+        let obj = "# A quad and a triangle
+mtllib quad.mtl
+v 0 0 0
+v 1 0 0
+v 1 1 0
+v 0 1 0
+vt 0 0
+vt 1 0
+vt 1 1
+vt 0 1
+vn 0 0 1
+g quad
+usemtl red
+f 1/1/1 2/2/1 3/3/1 4/4/1
+g tri
+usemtl shiny
+f -4//1 -3//1 -2//1
+";
+        let mesh = ObjMesh::parse(obj, &dir).unwrap();
+        assert_eq!(mesh.groups.len(), 2);
+
+        let quad = &mesh.groups[0];
+        assert_eq!(
+            (quad.name.as_str(), quad.material.as_deref()),
+            ("quad", Some("red"))
+        );
+        assert_eq!(quad.vertex_indices, [0, 1, 2, 0, 2, 3]);
+        assert_eq!(quad.uv[2], Point2f::new(1.0, 1.0));
+        assert_eq!(quad.n.len(), 4);
+
+        // Vertices without uv-coordinates drop them for the whole group.
+        let tri = &mesh.groups[1];
+        assert_eq!(tri.p, [quad.p[0], quad.p[1], quad.p[2]]);
+        assert!(tri.uv.is_empty() && tri.n.len() == 3);
+
+        let (name, params) = mesh.materials["red"].pbrt_material().unwrap();
+        assert_eq!(name, "matte");
+        assert!(params.find_texture("Kd").is_empty());
+        let shiny = &mesh.materials["shiny"];
+        let (name, params) = shiny.pbrt_material().unwrap();
+        assert_eq!(name, "plastic");
+        assert_eq!(params.find_one_float("roughness", 0.0), 0.5);
+        assert_eq!(params.find_one_texture("Kd", String::new()), "shiny:Kd");
+        let (texture, params) = shiny.diffuse_texture().unwrap();
+        assert_eq!(texture, "shiny:Kd");
+        let wood = dir.join("wood.png").to_string_lossy().into_owned();
+        assert_eq!(params.find_one_string("filename", String::new()), wood);
+
+        // Out of bounds references.
+        assert!(ObjMesh::parse("v 0 0 0\nf 1 2 3\n", &dir).is_err());
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -5,6 +5,7 @@ use crate::core::efloat::*;
 use crate::core::geometry::*;
 use crate::core::paramset::*;
 use crate::core::pbrt::*;
+use std::any::Any;
 use std::sync::Arc;
 
 /// A paraboloid centered on the z-axis.
@@ -67,6 +68,11 @@ impl Shape for Paraboloid {
         self.data.clone()
     }
 
+    /// Returns the shape as `Any` so it can be stored in the scene cache.
+    fn as_any(&self) -> Option<&dyn Any> {
+        Some(self)
+    }
+
     /// Returns a bounding box in the shapes object space.
     fn object_bound(&self) -> Bounds3f {
         Bounds3f::new(
@@ -89,8 +95,6 @@ impl Shape for Paraboloid {
             .unwrap()
             .transform_ray_with_error(r);
 
-        // Compute quadratic paraboloid coefficients
-
         // Initialize EFloat ray coordinate values
         let ox = EFloat::new(ray.o.x, o_err.x);
         let oy = EFloat::new(ray.o.y, o_err.y);
@@ -100,14 +104,8 @@ impl Shape for Paraboloid {
         let dy = EFloat::new(ray.d.y, d_err.y);
         let dz = EFloat::new(ray.d.z, d_err.z);
 
-        let k = EFloat::from(self.z_max) / (EFloat::from(self.radius) * EFloat::from(self.radius));
-
-        let a = k * (dx * dx + dy * dy);
-        let b = 2.0 * k * (dx * ox + dy * oy) - dz;
-        let c = k * (ox * ox + oy * oy) - oz;
-
         // Solve quadratic equation for t values
-        if let Some((t0, t1)) = Quadratic::solve(a, b, c) {
+        if let Some((t0, t1)) = Quadratic::solve_quadric(self, [ox, oy, oz], [dx, dy, dz]) {
             // Check quadric shape t0 and t1 for nearest intersection
             if t0.upper_bound() > ray.t_max || t1.lower_bound() <= 0.0 {
                 return None;
@@ -245,8 +243,6 @@ impl Shape for Paraboloid {
             .unwrap()
             .transform_ray_with_error(r);
 
-        // Compute quadratic paraboloid coefficients
-
         // Initialize EFloat ray coordinate values
         let ox = EFloat::new(ray.o.x, o_err.x);
         let oy = EFloat::new(ray.o.y, o_err.y);
@@ -256,14 +252,8 @@ impl Shape for Paraboloid {
         let dy = EFloat::new(ray.d.y, d_err.y);
         let dz = EFloat::new(ray.d.z, d_err.z);
 
-        let k = EFloat::from(self.z_max) / (EFloat::from(self.radius) * EFloat::from(self.radius));
-
-        let a = k * (dx * dx + dy * dy);
-        let b = 2.0 * k * (dx * ox + dy * oy) - dz;
-        let c = k * (ox * ox + oy * oy) - oz;
-
         // Solve quadratic equation for t values
-        if let Some((t0, t1)) = Quadratic::solve(a, b, c) {
+        if let Some((t0, t1)) = Quadratic::solve_quadric(self, [ox, oy, oz], [dx, dy, dz]) {
             // Check quadric shape t0 and t1 for nearest intersection
             if t0.upper_bound() > ray.t_max || t1.lower_bound() <= 0.0 {
                 return false;
@@ -335,6 +325,22 @@ impl Shape for Paraboloid {
     }
 }
 
+impl QuadricCoefficients for Paraboloid {
+    /// Returns the coefficients (a, b, c) of a * t ^ 2 + b * t + c = 0 for a
+    /// ray in object space.
+    ///
+    /// * `o` - Ray origin.
+    /// * `d` - Ray direction.
+    fn coefficients<E: ErrorFloat>(&self, o: [E; 3], d: [E; 3]) -> (E, E, E) {
+        let k = E::from(self.z_max) / (E::from(self.radius) * E::from(self.radius));
+
+        let a = k * (d[0] * d[0] + d[1] * d[1]);
+        let b = k * (d[0] * o[0] + d[1] * o[1]) * 2.0 - d[2];
+        let c = k * (o[0] * o[0] + o[1] * o[1]) - o[2];
+        (a, b, c)
+    }
+}
+
 impl From<(&ParamSet, ArcTransform, ArcTransform, bool)> for Paraboloid {
     /// Create a `Paraboloid` from given parameter set, object to world transform,
     /// world to object transform and whether or not surface normal orientation
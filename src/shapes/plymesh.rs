@@ -0,0 +1,618 @@
+//! PLY Meshes
+
+#![allow(dead_code)]
+use super::TriangleMesh;
+use crate::core::error::*;
+use crate::core::geometry::*;
+use crate::core::paramset::*;
+use crate::core::pbrt::*;
+use crate::core::texture::*;
+use byteorder::{BigEndian, ByteOrder, LittleEndian};
+use std::collections::HashMap;
+use std::fs;
+
+/// Encoding of the data following the header of a PLY file.
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum PlyFormat {
+    /// Values are written as text separated by whitespace.
+    Ascii,
+
+    /// Values are binary little endian.
+    BinaryLittleEndian,
+
+    /// Values are binary big endian.
+    BinaryBigEndian,
+}
+
+/// Scalar types of PLY properties.
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum PlyType {
+    Int8,
+    UInt8,
+    Int16,
+    UInt16,
+    Int32,
+    UInt32,
+    Float32,
+    Float64,
+}
+
+impl PlyType {
+    /// Returns the type with the given name; or an error if it isn't a PLY
+    /// type.
+    ///
+    /// * `name` - Name of the type in the header.
+    fn parse(name: &str) -> Result<Self, Error> {
+        match name {
+            "char" | "int8" => Ok(Self::Int8),
+            "uchar" | "uint8" => Ok(Self::UInt8),
+            "short" | "int16" => Ok(Self::Int16),
+            "ushort" | "uint16" => Ok(Self::UInt16),
+            "int" | "int32" => Ok(Self::Int32),
+            "uint" | "uint32" => Ok(Self::UInt32),
+            "float" | "float32" => Ok(Self::Float32),
+            "double" | "float64" => Ok(Self::Float64),
+            _ => Err(Error::Parse(format!(
+                "Unknown PLY property type '{}'.",
+                name
+            ))),
+        }
+    }
+
+    /// Returns the size of a binary value in bytes.
+    fn size(&self) -> usize {
+        match self {
+            Self::Int8 | Self::UInt8 => 1,
+            Self::Int16 | Self::UInt16 => 2,
+            Self::Int32 | Self::UInt32 | Self::Float32 => 4,
+            Self::Float64 => 8,
+        }
+    }
+}
+
+/// A property of the elements of a PLY file.
+#[derive(Clone, Debug)]
+struct PlyProperty {
+    /// Name of the property.
+    name: String,
+
+    /// Type of the number of values of a list property; `None` for scalar
+    /// properties.
+    count: Option<PlyType>,
+
+    /// Type of the values.
+    value: PlyType,
+}
+
+/// Elements of the same kind in a PLY file, e.g. vertices or faces.
+#[derive(Clone, Debug)]
+struct PlyElement {
+    /// Name of the elements.
+    name: String,
+
+    /// Number of elements.
+    count: usize,
+
+    /// Properties of each element.
+    properties: Vec<PlyProperty>,
+}
+
+/// Reads the values of a PLY file following its header.
+struct PlyReader<'a> {
+    /// Encoding of the values.
+    format: PlyFormat,
+
+    /// The bytes following the header.
+    bytes: &'a [u8],
+
+    /// Offset of the next value.
+    offset: usize,
+}
+
+impl<'a> PlyReader<'a> {
+    /// Reads a value; or returns an error if the file ends or the value isn't
+    /// a number.
+    ///
+    /// * `ty` - Type of the value.
+    fn read(&mut self, ty: PlyType) -> Result<f64, Error> {
+        match self.format {
+            PlyFormat::Ascii => {
+                let rest = &self.bytes[self.offset..];
+                let start = rest
+                    .iter()
+                    .position(|b| !b.is_ascii_whitespace())
+                    .ok_or_else(Self::truncated)?;
+                let len = rest[start..]
+                    .iter()
+                    .position(|b| b.is_ascii_whitespace())
+                    .unwrap_or(rest.len() - start);
+                self.offset += start + len;
+                let token = String::from_utf8_lossy(&rest[start..start + len]);
+                token
+                    .parse::<f64>()
+                    .map_err(|_| Error::Parse(format!("Invalid PLY value '{}'.", token)))
+            }
+            PlyFormat::BinaryLittleEndian => self.read_binary::<LittleEndian>(ty),
+            PlyFormat::BinaryBigEndian => self.read_binary::<BigEndian>(ty),
+        }
+    }
+
+    /// Reads a binary value.
+    ///
+    /// * `ty` - Type of the value.
+    fn read_binary<B: ByteOrder>(&mut self, ty: PlyType) -> Result<f64, Error> {
+        let b = self
+            .bytes
+            .get(self.offset..self.offset + ty.size())
+            .ok_or_else(Self::truncated)?;
+        self.offset += ty.size();
+        Ok(match ty {
+            PlyType::Int8 => b[0] as i8 as f64,
+            PlyType::UInt8 => b[0] as f64,
+            PlyType::Int16 => B::read_i16(b) as f64,
+            PlyType::UInt16 => B::read_u16(b) as f64,
+            PlyType::Int32 => B::read_i32(b) as f64,
+            PlyType::UInt32 => B::read_u32(b) as f64,
+            PlyType::Float32 => B::read_f32(b) as f64,
+            PlyType::Float64 => B::read_f64(b),
+        })
+    }
+
+    /// Returns the error for a file that ends too early.
+    fn truncated() -> Error {
+        Error::Parse(String::from("PLY file is truncated."))
+    }
+}
+
+/// Vertex data of a PLY vertex property.
+#[derive(Copy, Clone, PartialEq)]
+enum VertexAttribute {
+    X,
+    Y,
+    Z,
+    NX,
+    NY,
+    NZ,
+    U,
+    V,
+    Other,
+}
+
+impl VertexAttribute {
+    /// Returns the vertex data a property holds.
+    ///
+    /// * `name` - Name of the property.
+    fn from_name(name: &str) -> Self {
+        match name {
+            "x" => Self::X,
+            "y" => Self::Y,
+            "z" => Self::Z,
+            "nx" => Self::NX,
+            "ny" => Self::NY,
+            "nz" => Self::NZ,
+            "u" | "s" | "texture_u" | "texture_s" => Self::U,
+            "v" | "t" | "texture_v" | "texture_t" => Self::V,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// A triangle mesh read from a PLY file.
+#[derive(Clone, Default)]
+pub struct PlyMesh {
+    /// Vertex indices; three per triangle.
+    pub vertex_indices: Vec<usize>,
+
+    /// Vertex positions.
+    pub p: Vec<Point3f>,
+
+    /// Vertex normals; empty if the file has none.
+    pub n: Vec<Normal3f>,
+
+    /// Parametric uv-coordinates per vertex; empty if the file has none.
+    pub uv: Vec<Point2f>,
+
+    /// Face index of each triangle; empty if the file has none.
+    pub face_indices: Vec<usize>,
+}
+
+impl PlyMesh {
+    /// Reads a PLY file; or returns an error if it can't be read or isn't a
+    /// valid PLY file.
+    ///
+    /// * `path` - Path of the file.
+    pub fn read(path: &str) -> Result<Self, Error> {
+        let bytes = fs::read(path)
+            .map_err(|err| Error::Io(format!("Error reading PLY file '{}'. {}.", path, err)))?;
+        Self::parse(&bytes)
+            .map_err(|err| Error::Parse(format!("Error reading PLY file '{}'. {}", path, err)))
+    }
+
+    /// Returns the mesh in the contents of a PLY file; or an error if it
+    /// isn't a valid PLY file.
+    ///
+    /// * `bytes` - Contents of the file.
+    pub fn parse(bytes: &[u8]) -> Result<Self, Error> {
+        let (format, elements, offset) = Self::parse_header(bytes)?;
+        let mut reader = PlyReader {
+            format,
+            bytes: &bytes[offset..],
+            offset: 0,
+        };
+
+        let mut mesh = Self::default();
+        let mut n_vertices = None;
+        for element in elements.iter() {
+            match element.name.as_str() {
+                "vertex" => {
+                    mesh.read_vertices(&mut reader, element)?;
+                    n_vertices = Some(element.count);
+                }
+                "face" => mesh.read_faces(&mut reader, element)?,
+                _ => Self::skip(&mut reader, element)?,
+            }
+        }
+
+        let n_vertices =
+            n_vertices.ok_or_else(|| Error::Parse(String::from("PLY file has no vertices.")))?;
+        if mesh.vertex_indices.is_empty() {
+            return Err(Error::Parse(String::from("PLY file has no faces.")));
+        }
+        if let Some(i) = mesh.vertex_indices.iter().find(|i| **i >= n_vertices) {
+            return Err(Error::Parse(format!(
+                "PLY file has out-of-bounds vertex index {} ({} vertices).",
+                i, n_vertices
+            )));
+        }
+        Ok(mesh)
+    }
+
+    /// Returns the format and elements in the header of a PLY file and the
+    /// offset of the data following it.
+    ///
+    /// * `bytes` - Contents of the file.
+    fn parse_header(bytes: &[u8]) -> Result<(PlyFormat, Vec<PlyElement>, usize), Error> {
+        if !bytes.starts_with(b"ply") {
+            return Err(Error::Parse(String::from("Not a PLY file.")));
+        }
+
+        let mut format = None;
+        let mut elements: Vec<PlyElement> = vec![];
+        let mut offset = 0;
+        loop {
+            let rest = &bytes[offset..];
+            let len = rest
+                .iter()
+                .position(|b| *b == b'\n')
+                .ok_or_else(|| Error::Parse(String::from("PLY header has no 'end_header'.")))?;
+            let line = String::from_utf8_lossy(&rest[..len]);
+            offset += len + 1;
+
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            match tokens.as_slice() {
+                ["end_header"] => break,
+                ["format", "ascii", _] => format = Some(PlyFormat::Ascii),
+                ["format", "binary_little_endian", _] => {
+                    format = Some(PlyFormat::BinaryLittleEndian)
+                }
+                ["format", "binary_big_endian", _] => format = Some(PlyFormat::BinaryBigEndian),
+                ["element", name, count] => elements.push(PlyElement {
+                    name: String::from(*name),
+                    count: count.parse().map_err(|_| {
+                        Error::Parse(format!("Invalid PLY element count '{}'.", count))
+                    })?,
+                    properties: vec![],
+                }),
+                ["property", "list", count, value, name] => {
+                    let property = PlyProperty {
+                        name: String::from(*name),
+                        count: Some(PlyType::parse(count)?),
+                        value: PlyType::parse(value)?,
+                    };
+                    Self::add_property(&mut elements, property)?;
+                }
+                ["property", value, name] => {
+                    let property = PlyProperty {
+                        name: String::from(*name),
+                        count: None,
+                        value: PlyType::parse(value)?,
+                    };
+                    Self::add_property(&mut elements, property)?;
+                }
+                ["ply"] | ["comment", ..] | ["obj_info", ..] | [] => (),
+                ["format", ..] => {
+                    return Err(Error::Parse(format!("Unsupported PLY format '{}'.", line)))
+                }
+                _ => return Err(Error::Parse(format!("Invalid PLY header line '{}'.", line))),
+            }
+        }
+
+        let format =
+            format.ok_or_else(|| Error::Parse(String::from("PLY header has no format.")))?;
+        Ok((format, elements, offset))
+    }
+
+    /// Adds a property to the last element of the header.
+    ///
+    /// * `elements` - Elements of the header.
+    /// * `property` - The property.
+    fn add_property(elements: &mut [PlyElement], property: PlyProperty) -> Result<(), Error> {
+        match elements.last_mut() {
+            Some(element) => {
+                element.properties.push(property);
+                Ok(())
+            }
+            None => Err(Error::Parse(format!(
+                "PLY property '{}' is not part of an element.",
+                property.name
+            ))),
+        }
+    }
+
+    /// Reads the vertices.
+    ///
+    /// * `reader`  - Reads the values.
+    /// * `element` - The vertex element.
+    fn read_vertices(&mut self, reader: &mut PlyReader, element: &PlyElement) -> Result<(), Error> {
+        let attributes: Vec<VertexAttribute> = element
+            .properties
+            .iter()
+            .map(|p| match p.count {
+                Some(_) => VertexAttribute::Other,
+                None => VertexAttribute::from_name(&p.name),
+            })
+            .collect();
+        let has = |a: &[VertexAttribute]| a.iter().all(|a| attributes.contains(a));
+        if !has(&[VertexAttribute::X, VertexAttribute::Y, VertexAttribute::Z]) {
+            return Err(Error::Parse(String::from(
+                "PLY vertices have no 'x', 'y' and 'z' properties.",
+            )));
+        }
+        let has_normals = has(&[
+            VertexAttribute::NX,
+            VertexAttribute::NY,
+            VertexAttribute::NZ,
+        ]);
+        let has_uvs = has(&[VertexAttribute::U, VertexAttribute::V]);
+
+        self.p.reserve(element.count);
+        for _ in 0..element.count {
+            let mut v = [0.0; 8];
+            for (property, attribute) in element.properties.iter().zip(attributes.iter()) {
+                let value = Self::read_property(reader, property)?;
+                match attribute {
+                    VertexAttribute::X => v[0] = value,
+                    VertexAttribute::Y => v[1] = value,
+                    VertexAttribute::Z => v[2] = value,
+                    VertexAttribute::NX => v[3] = value,
+                    VertexAttribute::NY => v[4] = value,
+                    VertexAttribute::NZ => v[5] = value,
+                    VertexAttribute::U => v[6] = value,
+                    VertexAttribute::V => v[7] = value,
+                    VertexAttribute::Other => (),
+                }
+            }
+            let v = v.map(|v| v as Float);
+            self.p.push(Point3f::new(v[0], v[1], v[2]));
+            if has_normals {
+                self.n.push(Normal3f::new(v[3], v[4], v[5]));
+            }
+            if has_uvs {
+                self.uv.push(Point2f::new(v[6], v[7]));
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads the faces. Polygons with more than three vertices, such as the
+    /// quads some exporters write, are split into triangle fans.
+    ///
+    /// * `reader`  - Reads the values.
+    /// * `element` - The face element.
+    fn read_faces(&mut self, reader: &mut PlyReader, element: &PlyElement) -> Result<(), Error> {
+        let is_indices = |p: &PlyProperty| {
+            p.count.is_some() && (p.name == "vertex_indices" || p.name == "vertex_index")
+        };
+        if !element.properties.iter().any(is_indices) {
+            return Err(Error::Parse(String::from(
+                "PLY faces have no 'vertex_indices' property.",
+            )));
+        }
+        let has_face_indices = element
+            .properties
+            .iter()
+            .any(|p| p.count.is_none() && p.name == "face_indices");
+
+        let mut polygon: Vec<usize> = vec![];
+        for face in 0..element.count {
+            polygon.clear();
+            let mut face_index = face;
+            for property in element.properties.iter() {
+                if is_indices(property) {
+                    let n = reader.read(property.count.unwrap())? as usize;
+                    for _ in 0..n {
+                        polygon.push(reader.read(property.value)? as usize);
+                    }
+                } else if property.count.is_none() && property.name == "face_indices" {
+                    face_index = reader.read(property.value)? as usize;
+                } else {
+                    Self::read_property(reader, property)?;
+                }
+            }
+
+            if polygon.len() < 3 {
+                warn!(
+                    "Skipping PLY face with {} vertices; at least 3 are needed.",
+                    polygon.len()
+                );
+                continue;
+            }
+            for i in 1..polygon.len() - 1 {
+                self.vertex_indices
+                    .extend_from_slice(&[polygon[0], polygon[i], polygon[i + 1]]);
+                if has_face_indices {
+                    self.face_indices.push(face_index);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Skips the elements the mesh doesn't use.
+    ///
+    /// * `reader`  - Reads the values.
+    /// * `element` - The elements.
+    fn skip(reader: &mut PlyReader, element: &PlyElement) -> Result<(), Error> {
+        for _ in 0..element.count {
+            for property in element.properties.iter() {
+                Self::read_property(reader, property)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads a property and returns its value; the first value of list
+    /// properties or 0 for empty lists.
+    ///
+    /// * `reader`   - Reads the values.
+    /// * `property` - The property.
+    fn read_property(reader: &mut PlyReader, property: &PlyProperty) -> Result<f64, Error> {
+        match property.count {
+            None => reader.read(property.value),
+            Some(count) => {
+                let n = reader.read(count)? as usize;
+                let mut first = 0.0;
+                for i in 0..n {
+                    let value = reader.read(property.value)?;
+                    if i == 0 {
+                        first = value;
+                    }
+                }
+                Ok(first)
+            }
+        }
+    }
+
+    /// Create the triangles of a mesh read from a PLY file; or returns an
+    /// error if the file can't be read or the parameters are invalid.
+    ///
+    /// NOTE: Because we return a set of triangles as `Vec<Arc<Shape>>` we
+    /// cannot implement this as `From` trait :(
+    ///
+    /// * `p`              - A tuple containing the parameter set, object to
+    ///                      world transform, world to object transform and
+    ///                      whether or not surface normal orientation is reversed.
+    /// * `float_textures` - Float textures.
+    pub fn from_props(
+        p: (&ParamSet, ArcTransform, ArcTransform, bool),
+        float_textures: &HashMap<String, ArcTexture<Float>>,
+    ) -> Result<Vec<ArcShape>, Error> {
+        let (params, o2w, w2o, reverse_orientation) = p;
+
+        let filename = params.find_one_filename("filename", String::from(""));
+        if filename.is_empty() {
+            return Err(Error::MissingParameter(format!(
+                "PLY file '{}' not found for plymesh shape",
+                params.find_one_string("filename", String::from(""))
+            )));
+        }
+        let mesh = Self::read(&filename)?;
+
+        // The rest of the parameters, e.g. alpha textures, apply to the mesh
+        // as they would to a `trianglemesh`.
+        let mut mesh_params = params.clone();
+        let indices: Vec<Int> = mesh.vertex_indices.iter().map(|i| *i as Int).collect();
+        mesh_params.add_int("indices", &indices);
+        mesh_params.add_point3f("P", &mesh.p);
+        mesh_params.add_normal3f("N", &mesh.n);
+        mesh_params.add_point2f("uv", &mesh.uv);
+        let face_indices: Vec<Int> = mesh.face_indices.iter().map(|i| *i as Int).collect();
+        mesh_params.add_int("faceIndices", &face_indices);
+
+        TriangleMesh::from_props(
+            (&mesh_params, o2w, w2o, reverse_orientation),
+            float_textures,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ascii_and_binary_files_are_read() {
+        let ascii = b"ply
+format ascii 1.0
+comment a unit quad
+element vertex 4
+property float x
+property float y
+property float z
+property float u
+property float v
+element face 1
+property list uchar int vertex_indices
+end_header
+0 0 0 0 0
+1 0 0 1 0
+1 1 0 1 1
+0 1 0 0 1
+4 0 1 2 3
+";
+        let mesh = PlyMesh::parse(ascii).unwrap();
+        assert_eq!(mesh.p.len(), 4);
+        assert_eq!(mesh.vertex_indices, [0, 1, 2, 0, 2, 3]);
+        assert_eq!(mesh.uv[2], Point2f::new(1.0, 1.0));
+        assert!(mesh.n.is_empty() && mesh.face_indices.is_empty());
+
+        // The same triangle in both byte orders, with normals, face indices
+        // and an element that isn't used.
+        let header = |format: &str| {
+            format!(
+                "ply\nformat {} 1.0\nelement vertex 3\nproperty float x\n\
+                 property float y\nproperty float z\nproperty float nx\n\
+                 property float ny\nproperty float nz\nelement face 1\n\
+                 property list uchar uint vertex_indices\nproperty int face_indices\n\
+                 element edge 1\nproperty short vertex1\nproperty short vertex2\n\
+                 end_header\n",
+                format
+            )
+        };
+        let vertices: [[f32; 6]; 3] = [
+            [0.0, 0.0, 0.0, 0.0, 0.0, 1.0],
+            [1.0, 0.0, 0.0, 0.0, 0.0, 1.0],
+            [0.0, 2.0, 0.0, 0.0, 0.0, 1.0],
+        ];
+        for (format, big_endian) in [("binary_little_endian", false), ("binary_big_endian", true)] {
+            let mut bytes = header(format).into_bytes();
+            for v in vertices.iter().flatten() {
+                bytes.extend(if big_endian {
+                    v.to_be_bytes()
+                } else {
+                    v.to_le_bytes()
+                });
+            }
+            bytes.push(3);
+            for i in [0_u32, 1, 2, 7] {
+                bytes.extend(if big_endian {
+                    i.to_be_bytes()
+                } else {
+                    i.to_le_bytes()
+                });
+            }
+            bytes.extend([0, 1, 0, 2]);
+
+            let mesh = PlyMesh::parse(&bytes).unwrap();
+            assert_eq!(mesh.p[2], Point3f::new(0.0, 2.0, 0.0));
+            assert_eq!(mesh.n[1], Normal3f::new(0.0, 0.0, 1.0));
+            assert_eq!(mesh.vertex_indices, [0, 1, 2]);
+            assert_eq!(mesh.face_indices, [7]);
+
+            // Missing data and out of bounds indices.
+            assert!(PlyMesh::parse(&bytes[..bytes.len() - 5]).is_err());
+        }
+        let out_of_bounds = String::from_utf8_lossy(ascii).replace("4 0 1 2 3", "3 0 1 4");
+        assert!(PlyMesh::parse(out_of_bounds.as_bytes()).is_err());
+    }
+}
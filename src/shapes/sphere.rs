@@ -6,6 +6,7 @@ use crate::core::geometry::*;
 use crate::core::paramset::*;
 use crate::core::pbrt::*;
 use crate::core::sampling::*;
+use std::any::Any;
 use std::sync::Arc;
 
 /// A sphere at origin [0, 0, 0].
@@ -77,6 +78,11 @@ impl Shape for Sphere {
         self.data.clone()
     }
 
+    /// Returns the shape as `Any` so it can be stored in the scene cache.
+    fn as_any(&self) -> Option<&dyn Any> {
+        Some(self)
+    }
+
     /// Returns a bounding box in the shapes object space.
     fn object_bound(&self) -> Bounds3f {
         Bounds3f::new(
@@ -99,8 +105,6 @@ impl Shape for Sphere {
             .unwrap()
             .transform_ray_with_error(r);
 
-        // Compute quadratic sphere coefficients
-
         // Initialize EFloat ray coordinate values
         let ox = EFloat::new(ray.o.x, o_err.x);
         let oy = EFloat::new(ray.o.y, o_err.y);
@@ -110,12 +114,8 @@ impl Shape for Sphere {
         let dy = EFloat::new(ray.d.y, d_err.y);
         let dz = EFloat::new(ray.d.z, d_err.z);
 
-        let a = dx * dx + dy * dy + dz * dz;
-        let b = 2.0 * (dx * ox + dy * oy + dz * oz);
-        let c = ox * ox + oy * oy + oz * oz - EFloat::from(self.radius) * EFloat::from(self.radius);
-
         // Solve quadratic equation for t values
-        if let Some((t0, t1)) = Quadratic::solve(a, b, c) {
+        if let Some((t0, t1)) = Quadratic::solve_quadric(self, [ox, oy, oz], [dx, dy, dz]) {
             // Check quadric shape t0 and t1 for nearest intersection
             if t0.upper_bound() > ray.t_max || t1.lower_bound() <= 0.0 {
                 return None;
@@ -132,12 +132,13 @@ impl Shape for Sphere {
             // Compute sphere hit position and phi
             let mut p_hit = ray.at(Float::from(t_shape_hit));
 
-            // Refine sphere intersection point
-            p_hit *= self.radius / p_hit.distance(Point3::new(0.0, 0.0, 0.0));
-
+            // Refine sphere intersection point. Points on the z-axis are moved
+            // off it first so they stay on the sphere within the error bounds,
+            // which are tighter than the move with double precision.
             if p_hit.x == 0.0 && p_hit.y == 0.0 {
                 p_hit.x = 1e-5 * self.radius;
             }
+            p_hit *= self.radius / p_hit.distance(Point3::new(0.0, 0.0, 0.0));
 
             let mut phi = p_hit.y.atan2(p_hit.x);
             if phi < 0.0 {
@@ -162,10 +163,10 @@ impl Shape for Sphere {
                 p_hit = ray.at(Float::from(t_shape_hit));
 
                 // Refine sphere intersection point
-                p_hit *= self.radius / p_hit.distance(Point3::new(0.0, 0.0, 0.0));
                 if p_hit.x == 0.0 && p_hit.y == 0.0 {
                     p_hit.x = 1e-5 * self.radius;
                 }
+                p_hit *= self.radius / p_hit.distance(Point3::new(0.0, 0.0, 0.0));
 
                 phi = p_hit.y.atan2(p_hit.x);
                 if phi < 0.0 {
@@ -269,8 +270,6 @@ impl Shape for Sphere {
             .unwrap()
             .transform_ray_with_error(r);
 
-        // Compute quadratic sphere coefficients
-
         // Initialize EFloat ray coordinate values
         let ox = EFloat::new(ray.o.x, o_err.x);
         let oy = EFloat::new(ray.o.y, o_err.y);
@@ -280,12 +279,8 @@ impl Shape for Sphere {
         let dy = EFloat::new(ray.d.y, d_err.y);
         let dz = EFloat::new(ray.d.z, d_err.z);
 
-        let a = dx * dx + dy * dy + dz * dz;
-        let b = 2.0 * (dx * ox + dy * oy + dz * oz);
-        let c = ox * ox + oy * oy + oz * oz - EFloat::from(self.radius) * EFloat::from(self.radius);
-
         // Solve quadratic equation for _t_ values
-        if let Some((t0, t1)) = Quadratic::solve(a, b, c) {
+        if let Some((t0, t1)) = Quadratic::solve_quadric(self, [ox, oy, oz], [dx, dy, dz]) {
             // Check quadric shape _t0_ and _t1_ for nearest intersection
             if t0.upper_bound() > ray.t_max || t1.lower_bound() <= 0.0 {
                 return false;
@@ -302,12 +297,13 @@ impl Shape for Sphere {
             // Compute sphere hit position and phi
             let mut p_hit = ray.at(Float::from(t_shape_hit));
 
-            // Refine sphere intersection point
-            p_hit *= self.radius / p_hit.distance(Point3::new(0.0, 0.0, 0.0));
-
+            // Refine sphere intersection point. Points on the z-axis are moved
+            // off it first so they stay on the sphere within the error bounds,
+            // which are tighter than the move with double precision.
             if p_hit.x == 0.0 && p_hit.y == 0.0 {
                 p_hit.x = 1e-5 * self.radius;
             }
+            p_hit *= self.radius / p_hit.distance(Point3::new(0.0, 0.0, 0.0));
 
             let mut phi = p_hit.y.atan2(p_hit.x);
             if phi < 0.0 {
@@ -332,10 +328,10 @@ impl Shape for Sphere {
                 p_hit = ray.at(Float::from(t_shape_hit));
 
                 // Refine sphere intersection point
-                p_hit *= self.radius / p_hit.distance(Point3::new(0.0, 0.0, 0.0));
                 if p_hit.x == 0.0 && p_hit.y == 0.0 {
                     p_hit.x = 1e-5 * self.radius;
                 }
+                p_hit *= self.radius / p_hit.distance(Point3::new(0.0, 0.0, 0.0));
 
                 phi = p_hit.y.atan2(p_hit.x);
                 if phi < 0.0 {
@@ -393,6 +389,21 @@ impl Shape for Sphere {
     }
 }
 
+impl QuadricCoefficients for Sphere {
+    /// Returns the coefficients (a, b, c) of a * t ^ 2 + b * t + c = 0 for a
+    /// ray in object space.
+    ///
+    /// * `o` - Ray origin.
+    /// * `d` - Ray direction.
+    fn coefficients<E: ErrorFloat>(&self, o: [E; 3], d: [E; 3]) -> (E, E, E) {
+        let a = d[0] * d[0] + d[1] * d[1] + d[2] * d[2];
+        let b = (d[0] * o[0] + d[1] * o[1] + d[2] * o[2]) * 2.0;
+        let c =
+            o[0] * o[0] + o[1] * o[1] + o[2] * o[2] - E::from(self.radius) * E::from(self.radius);
+        (a, b, c)
+    }
+}
+
 impl From<(&ParamSet, ArcTransform, ArcTransform, bool)> for Sphere {
     /// Create a `Sphere` from given parameter set, object to world transform,
     /// world to object transform and whether or not surface normal orientation
@@ -1,16 +1,23 @@
 //! Triangles and triangle meshes
 
 #![allow(dead_code)]
+use super::{CacheKey, GeometryCache, MeshBuffer, MeshData, MIN_CACHED_TRIANGLES};
+use crate::core::app::OPTIONS;
+use crate::core::error::*;
 use crate::core::geometry::*;
 use crate::core::paramset::*;
 use crate::core::pbrt::*;
 use crate::core::sampling::*;
 use crate::core::texture::*;
 use crate::textures::*;
+use half::f16;
+use std::any::Any;
 use std::collections::HashMap;
 use std::mem::size_of;
 use std::sync::Arc;
 
+stat_memory_counter!("Memory/Triangle meshes", TRI_MESH_BYTES);
+
 /// Triangle mesh
 #[derive(Clone)]
 pub struct TriangleMesh {
@@ -23,19 +30,20 @@ pub struct TriangleMesh {
     /// Vertex indices. For the ith triangle, its three vertex positions are
     /// p[vertex_indices[3 * i]], p[vertex_indices[3 * i + 1]], and
     /// p[vertex_indices[3 * i + 2]]
-    pub vertex_indices: Vec<usize>,
+    pub vertex_indices: MeshBuffer<usize>,
 
     /// Vertex positions.
-    pub p: Vec<Point3f>,
+    pub p: MeshBuffer<Point3f>,
 
-    /// Vertex normals. This will be empty if there are none.
-    pub n: Vec<Normal3f>,
+    /// Octahedral encoded vertex normals. This will be empty if there are
+    /// none.
+    pub n: MeshBuffer<OctNormal>,
 
     /// Tangent vectors per vertex. This will be empty if there are none.
-    pub s: Vec<Vector3f>,
+    pub s: MeshBuffer<Vector3f>,
 
     /// Paramteric uv-coordinates per vertex. This will be empty if there are none.
-    pub uv: Vec<Point2f>,
+    pub uv: MeshUVs,
 
     /// Optional alpha mask texture, which can be used to cut away parts of
     /// triangle surfaces
@@ -46,10 +54,15 @@ pub struct TriangleMesh {
 
     /// Face indices.
     pub face_indices: Vec<usize>,
+
+    /// Start rays leaving the mesh from the smooth surface described by the
+    /// vertex normals to avoid shadow terminator artifacts.
+    pub smooth_terminator: bool,
 }
 
 impl TriangleMesh {
-    /// Create a new triangle mesh.
+    /// Create a new triangle mesh. Large meshes are mapped from the geometry
+    /// cache if it is enabled and written to it on first load.
     ///
     /// * `object_to_world`     - The object to world transfomation.
     /// * `reverse_orientation` - Indicates whether their surface normal directions
@@ -62,6 +75,7 @@ impl TriangleMesh {
     /// * `n`                   - Vertex normals.
     /// * `s`                   - Tangent vectors per vertex.
     /// * `uv`                  - Paramteric uv-coordinates.
+    /// * `half_uvs`            - Store uv-coordinates in half-precision.
     /// * `alpha_mask`          - Optional alpha mask texture, which can be used to
     ///                           cut away parts of triangle surfaces
     /// * `shadow_alpha_mask`   - Optional shadow alpha mask texture.
@@ -74,34 +88,132 @@ impl TriangleMesh {
         n: Vec<Normal3f>,
         s: Vec<Vector3f>,
         uv: Vec<Point2f>,
+        half_uvs: bool,
         alpha_mask: Option<ArcTexture<Float>>,
         shadow_alpha_mask: Option<ArcTexture<Float>>,
         face_indices: Vec<usize>,
     ) -> Self {
-        let num_triangles = vertex_indices.len() % 3;
-        assert!(num_triangles == 0);
-
-        // Transform mesh vertices to world space.
-        let tp = p.iter().map(|v| object_to_world.transform_point(&v));
+        let num_triangles = vertex_indices.len() / 3;
+        assert!(vertex_indices.len() % 3 == 0);
+
+        // The key depends on everything that goes into the conversion so a
+        // changed mesh or transform gets a new cache file.
+        let cache = OPTIONS
+            .geometry_cache
+            .as_ref()
+            .filter(|_| num_triangles >= MIN_CACHED_TRIANGLES)
+            .map(|dir| GeometryCache::new(dir));
+        let mut key = CacheKey::default();
+        if cache.is_some() {
+            for row in object_to_world.m.m.iter() {
+                key.add(row);
+            }
+            key.add_bytes(&[reverse_orientation as u8, half_uvs as u8]);
+            key.add(&vertex_indices);
+            key.add(&p);
+            key.add(&n);
+            key.add(&s);
+            key.add(&uv);
+        }
 
-        // Transform normals to world space.
-        let tn = n.iter().map(|v| object_to_world.transform_normal(&v));
+        let convert = || {
+            // Transform mesh vertices to world space.
+            let tp = p.iter().map(|v| object_to_world.transform_point(&v));
+
+            // Transform normals to world space. Vertex normals are
+            // authoritative for the orientation of the geometric normal, so
+            // they are flipped here when `ReverseOrientation` is in effect.
+            let tn = n.iter().map(|v| {
+                let tv = object_to_world.transform_normal(&v);
+                OctNormal::from(if reverse_orientation { -tv } else { tv })
+            });
+
+            // Transform normals to world space.
+            let ts = s.iter().map(|v| object_to_world.transform_vector(&v));
+
+            MeshData {
+                vertex_indices: MeshBuffer::from(vertex_indices),
+                p: MeshBuffer::from(tp.collect::<Vec<_>>()),
+                n: MeshBuffer::from(tn.collect::<Vec<_>>()),
+                s: MeshBuffer::from(ts.collect::<Vec<_>>()),
+                uv: MeshUVs::new(uv, half_uvs),
+            }
+        };
 
-        // Transform normals to world space.
-        let ts = s.iter().map(|v| object_to_world.transform_vector(&v));
+        let data = match cache {
+            Some(cache) => match cache.load(key) {
+                Ok(Some(data)) => data,
+                result => {
+                    if let Err(err) = result {
+                        warn!("{} Converting the mesh again.", err);
+                    }
+                    let data = convert();
+                    if let Err(err) = cache.store(key, &data) {
+                        warn!("{}", err);
+                    }
+                    data
+                }
+            },
+            None => convert(),
+        };
 
-        Self {
+        let mesh = Self {
             num_triangles,
-            vertex_indices,
-            p: tp.collect(),
-            n: tn.collect(),
-            s: ts.collect(),
-            uv,
+            vertex_indices: data.vertex_indices,
+            p: data.p,
+            n: data.n,
+            s: data.s,
+            uv: data.uv,
             alpha_mask,
             shadow_alpha_mask,
             face_indices,
+            smooth_terminator: false,
             data: ShapeData::new(object_to_world.clone(), None, reverse_orientation),
-        }
+        };
+        TRI_MESH_BYTES.add(mesh.memory_size() as i64);
+        mesh
+    }
+
+    /// Create a triangle mesh from vertex data already in world space, e.g.
+    /// restored from the scene cache.
+    ///
+    /// * `data`              - Common shape data.
+    /// * `mesh`              - The vertex data.
+    /// * `face_indices`      - Face indices.
+    /// * `smooth_terminator` - Avoid shadow terminator artifacts.
+    pub fn from_data(
+        data: ShapeData,
+        mesh: MeshData,
+        face_indices: Vec<usize>,
+        smooth_terminator: bool,
+    ) -> Self {
+        let mesh = Self {
+            num_triangles: mesh.vertex_indices.len() / 3,
+            vertex_indices: mesh.vertex_indices,
+            p: mesh.p,
+            n: mesh.n,
+            s: mesh.s,
+            uv: mesh.uv,
+            alpha_mask: None,
+            shadow_alpha_mask: None,
+            face_indices,
+            smooth_terminator,
+            data,
+        };
+        TRI_MESH_BYTES.add(mesh.memory_size() as i64);
+        mesh
+    }
+
+    /// Returns the memory used by the mesh in bytes. Data mapped from the
+    /// geometry cache doesn't count.
+    pub fn memory_size(&self) -> usize {
+        size_of::<Self>()
+            + self.vertex_indices.memory_size()
+            + self.face_indices.len() * size_of::<usize>()
+            + self.p.memory_size()
+            + self.n.memory_size()
+            + self.s.memory_size()
+            + self.uv.memory_size()
     }
 
     /// Create a triangle mesh from vertex positions, normals, tangents, uv-coordinates
@@ -121,10 +233,13 @@ impl TriangleMesh {
     /// * `n`                   - Vertex normals.
     /// * `s`                   - Tangent vectors per vertex.
     /// * `uv`                  - Paramteric uv-coordinates.
+    /// * `half_uvs`            - Store uv-coordinates in half-precision.
     /// * `alpha_mask`          - Optional alpha mask texture, which can be used to
     ///                           cut away parts of triangle surfaces
     /// * `shadow_alpha_mask`   - Optional shadow alpha mask texture.
     /// * `face_indices`        - Face indices.
+    /// * `smooth_terminator`   - Start rays leaving the mesh from the smooth
+    ///                           surface described by the vertex normals.
     pub fn create(
         object_to_world: ArcTransform,
         world_to_object: ArcTransform,
@@ -134,14 +249,16 @@ impl TriangleMesh {
         n: Vec<Normal3f>,
         s: Vec<Vector3f>,
         uv: Vec<Point2f>,
+        half_uvs: bool,
         alpha_mask: Option<ArcTexture<Float>>,
         shadow_alpha_mask: Option<ArcTexture<Float>>,
         face_indices: Vec<usize>,
+        smooth_terminator: bool,
     ) -> Vec<ArcShape> {
-        let num_triangles = vertex_indices.len() % 3;
-        assert!(num_triangles == 0);
+        let num_triangles = vertex_indices.len() / 3;
+        assert!(vertex_indices.len() % 3 == 0);
 
-        let mesh = Self::new(
+        let mut mesh = Self::new(
             object_to_world.clone(),
             reverse_orientation,
             vertex_indices,
@@ -149,10 +266,12 @@ impl TriangleMesh {
             n,
             s,
             uv,
+            half_uvs,
             alpha_mask,
             shadow_alpha_mask,
             face_indices,
         );
+        mesh.smooth_terminator = smooth_terminator;
 
         let m = Arc::new(mesh);
         let mut tris = Vec::<ArcShape>::with_capacity(num_triangles);
@@ -172,7 +291,7 @@ impl TriangleMesh {
 
     /// Create a triangel mesh from given parameter set, object to world transform,
     /// world to object transform and whether or not surface normal orientation
-    /// is reversed.
+    /// is reversed; or returns an error if the parameters are invalid.
     ///
     /// NOTE: Because we return a set of curves as `Vec<Arc<Shape>>` we cannot
     /// implement this as `From` trait :(
@@ -184,7 +303,7 @@ impl TriangleMesh {
     pub fn from_props(
         p: (&ParamSet, ArcTransform, ArcTransform, bool),
         float_textures: &HashMap<String, ArcTexture<Float>>,
-    ) -> Vec<ArcShape> {
+    ) -> Result<Vec<ArcShape>, Error> {
         let (params, o2w, w2o, reverse_orientation) = p;
 
         let vi: Vec<usize> = params
@@ -215,7 +334,7 @@ impl TriangleMesh {
                 for i in 0..nuvi {
                     temp_uvs.push(Point2f::new(fuv[2 * i], fuv[2 * i + 1]));
                 }
-                uvs = temp_uvs;
+                uvs = &temp_uvs;
             }
         }
         if nuvi > 0 {
@@ -225,7 +344,7 @@ impl TriangleMesh {
                     found {}.  Discarding.",
                     npi, nuvi
                 );
-                uvs = vec![];
+                uvs = &[];
             } else if nuvi > npi {
                 error!(
                     "More 'uv' provided than will be used for triangle 
@@ -236,35 +355,42 @@ impl TriangleMesh {
         }
 
         if nvi == 0 {
-            error!("Vertex indices 'indices' not provided with triangle mesh shape");
-            return vec![];
+            return Err(Error::MissingParameter(String::from(
+                "Vertex indices 'indices' not provided with triangle mesh shape",
+            )));
+        }
+        if nvi % 3 != 0 {
+            return Err(Error::InvalidParameter(format!(
+                "Number of vertex indices {} not a multiple of 3 for triangle mesh shape",
+                nvi
+            )));
         }
         if npi == 0 {
-            error!("Vertex positions 'P' not provided with triangle mesh shape");
-            return vec![];
+            return Err(Error::MissingParameter(String::from(
+                "Vertex positions 'P' not provided with triangle mesh shape",
+            )));
         }
 
         let mut s = params.find_vector3f("S");
         let nsi = s.len();
         if nsi > 0 && nsi != npi {
             error!("Number of 'S' for triangle mesh must match 'P'.");
-            s = vec![];
+            s = &[];
         }
 
         let mut n = params.find_normal3f("N");
         let nni = n.len();
         if nni > 0 && nni != npi {
             error!("Number of 'N' for triangle mesh must match 'P'.");
-            n = vec![];
+            n = &[];
         }
         for i in 0..nvi {
             if vi[i] >= npi {
-                error!(
+                return Err(Error::InvalidParameter(format!(
                     "trianglemesh has out-of-bounds vertex index {} ({} 'P' 
                     values were given",
                     vi[i], npi
-                );
-                return vec![];
+                )));
             }
         }
 
@@ -283,55 +409,129 @@ impl TriangleMesh {
             face_indices = vec![];
         }
 
+        // A constant alpha other than 0 never cuts anything away so the mesh
+        // doesn't get an alpha mask for it.
+        let constant_alpha = |name: &str| -> Option<ArcTexture<Float>> {
+            match params.find_one_float(name, 1.0) {
+                alpha if alpha == 0.0 => Some(Arc::new(ConstantTexture::new(alpha))),
+                _ => None,
+            }
+        };
+
         let alpha_tex_name = params.find_one_texture("alpha", String::from(""));
         let alpha_tex = if alpha_tex_name.len() > 0 {
             if let Some(tex) = float_textures.get(&alpha_tex_name) {
-                tex.clone()
+                Some(tex.clone())
             } else {
                 warn!(
                     "Couldn't find float texture '{}' for 'alpha' parameter. 
                     Using float 'alpha' parameterer instead.",
                     alpha_tex_name
                 );
-                let alpha = params.find_one_float("alpha", 1.0);
-                Arc::new(ConstantTexture::new(alpha))
+                constant_alpha("alpha")
             }
         } else {
-            let alpha = params.find_one_float("alpha", 1.0);
-            Arc::new(ConstantTexture::new(alpha))
+            constant_alpha("alpha")
         };
 
         let shadow_alpha_tex_name = params.find_one_texture("shadowalpha", String::from(""));
         let shadow_alpha_tex = if shadow_alpha_tex_name.len() > 0 {
             if let Some(tex) = float_textures.get(&shadow_alpha_tex_name) {
-                tex.clone()
+                Some(tex.clone())
             } else {
                 warn!(
                     "Couldn't find float texture '{}' for 'shadowalpha' 
                     parameter.  Using float 'shadowalpha' parameterer instead.",
                     alpha_tex_name
                 );
-                let alpha = params.find_one_float("shadowalpha", 1.0);
-                Arc::new(ConstantTexture::new(alpha))
+                constant_alpha("shadowalpha")
             }
         } else {
-            let alpha = params.find_one_float("shadowalpha", 1.0);
-            Arc::new(ConstantTexture::new(alpha))
+            constant_alpha("shadowalpha")
         };
 
-        Self::create(
+        Ok(Self::create(
             o2w.clone(),
             w2o.clone(),
             reverse_orientation,
             vi,
-            p,
-            n,
-            s,
-            uvs,
-            Some(alpha_tex),
-            Some(shadow_alpha_tex),
+            p.to_vec(),
+            n.to_vec(),
+            s.to_vec(),
+            uvs.to_vec(),
+            params.find_one_bool("halfuv", false),
+            alpha_tex,
+            shadow_alpha_tex,
             face_indices,
-        )
+            params.find_one_bool("smoothterminator", false),
+        ))
+    }
+
+    /// Returns the decoded normal of a vertex.
+    ///
+    /// * `i` - Index of the vertex.
+    pub fn normal(&self, i: usize) -> Normal3f {
+        Normal3f::from(self.n[i])
+    }
+}
+
+/// Parametric uv-coordinates of a triangle mesh's vertices.
+#[derive(Clone)]
+pub enum MeshUVs {
+    /// Coordinates stored as `Float`s.
+    Full(MeshBuffer<Point2f>),
+
+    /// Coordinates stored as half-precision floats. This halves memory use
+    /// and is precise enough for textures up to about 2048 texels across.
+    Half(MeshBuffer<[f16; 2]>),
+}
+
+impl MeshUVs {
+    /// Creates storage for uv-coordinates.
+    ///
+    /// * `uv`   - The uv-coordinates.
+    /// * `half` - Store the coordinates in half-precision.
+    pub fn new(uv: Vec<Point2f>, half: bool) -> Self {
+        if half {
+            Self::Half(MeshBuffer::from(
+                uv.iter()
+                    .map(|p| [f16::from_f64(p.x.into()), f16::from_f64(p.y.into())])
+                    .collect::<Vec<_>>(),
+            ))
+        } else {
+            Self::Full(MeshBuffer::from(uv))
+        }
+    }
+
+    /// Returns the number of uv-coordinates.
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Full(uv) => uv.len(),
+            Self::Half(uv) => uv.len(),
+        }
+    }
+
+    /// Returns `true` if there are no uv-coordinates.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the memory used by the uv-coordinates in bytes.
+    pub fn memory_size(&self) -> usize {
+        match self {
+            Self::Full(uv) => uv.memory_size(),
+            Self::Half(uv) => uv.memory_size(),
+        }
+    }
+
+    /// Returns the uv-coordinates of a vertex.
+    ///
+    /// * `i` - Index of the vertex.
+    pub fn get(&self, i: usize) -> Point2f {
+        match self {
+            Self::Full(uv) => uv[i],
+            Self::Half(uv) => Point2f::new(uv[i][0].into(), uv[i][1].into()),
+        }
     }
 }
 
@@ -344,8 +544,8 @@ pub struct Triangle {
     /// The mesh.
     pub mesh: Arc<TriangleMesh>,
 
-    /// The index of the first vertex in the triangle. The other two are
-    /// calculated as v + 1 and v + 2.
+    /// The index of the first vertex index of the triangle in the mesh's
+    /// `vertex_indices`. The other two follow it.
     pub v: usize,
 }
 
@@ -378,14 +578,36 @@ impl Triangle {
 }
 
 impl Triangle {
+    /// Returns the index of a vertex of the triangle in the mesh.
+    ///
+    /// * `i` - Index of the vertex in the triangle; 0, 1 or 2.
+    fn vertex(&self, i: usize) -> usize {
+        self.mesh.vertex_indices[self.v + i]
+    }
+
+    /// Returns the offset from a point on the triangle to the smooth surface
+    /// described by the vertex normals. The point is projected onto the
+    /// tangent plane of each vertex it lies below and the projections are
+    /// interpolated (Hanika, "Hacking the Shadow Terminator", 2021).
+    ///
+    /// * `p` - The point on the triangle.
+    /// * `b` - Barycentric coordinates of the point.
+    fn shading_offset(&self, p: &Point3f, b: &[Float; 3]) -> Vector3f {
+        (0..3).fold(Vector3f::default(), |offset, i| {
+            let n = Vector3f::from(self.mesh.normal(self.vertex(i)));
+            let d = min(0.0, (*p - self.mesh.p[self.vertex(i)]).dot(&n));
+            offset - n * (b[i] * d)
+        })
+    }
+
     /// Returns the uv-coordinates for the triangle. If there are no uv
     /// coordinates, then default ones [(0,0), (1,0), (1,1)] are returned.
     fn get_uvs(&self) -> [Point2f; 3] {
-        if self.mesh.uv.len() > 0 {
+        if !self.mesh.uv.is_empty() {
             [
-                self.mesh.uv[self.v],
-                self.mesh.uv[self.v + 1],
-                self.mesh.uv[self.v + 2],
+                self.mesh.uv.get(self.vertex(0)),
+                self.mesh.uv.get(self.vertex(1)),
+                self.mesh.uv.get(self.vertex(2)),
             ]
         } else {
             [
@@ -403,13 +625,18 @@ impl Shape for Triangle {
         self.data.clone()
     }
 
+    /// Returns the shape as `Any` so it can be stored in the scene cache.
+    fn as_any(&self) -> Option<&dyn Any> {
+        Some(self)
+    }
+
     /// Returns a bounding box in the shapes object space.
     fn object_bound(&self) -> Bounds3f {
         // We can unwrap safely because the factory methods guarantee world_to_object
         // is passed. If it is constructed without that, then tough luck!
         let world_to_object = self.data.world_to_object.clone().unwrap();
         (0..3).fold(Bounds3f::empty(), |b, i| {
-            b.union(&world_to_object.transform_point(&self.mesh.p[self.v + i]))
+            b.union(&world_to_object.transform_point(&self.mesh.p[self.vertex(i)]))
         })
     }
 
@@ -418,7 +645,9 @@ impl Shape for Triangle {
     /// Default is to transform the object bounds with the object-to0world
     /// transformation. Override for tighter bounds implementation.
     fn world_bound(&self) -> Bounds3f {
-        (0..3).fold(Bounds3f::empty(), |b, i| b.union(&self.mesh.p[self.v + i]))
+        (0..3).fold(Bounds3f::empty(), |b, i| {
+            b.union(&self.mesh.p[self.vertex(i)])
+        })
     }
 
     /// Returns geometric details if a ray intersects the shape intersection.
@@ -428,9 +657,9 @@ impl Shape for Triangle {
     /// * `test_alpha_texture` - Perform alpha texture tests.
     fn intersect<'a>(&self, r: &Ray, test_alpha_texture: bool) -> Option<Intersection<'a>> {
         // Get triangle vertices in p0, p1, and p2
-        let p0 = self.mesh.p[self.v];
-        let p1 = self.mesh.p[self.v + 1];
-        let p2 = self.mesh.p[self.v + 2];
+        let p0 = self.mesh.p[self.vertex(0)];
+        let p1 = self.mesh.p[self.vertex(1)];
+        let p2 = self.mesh.p[self.vertex(2)];
 
         // Perform ray-triangle intersection test.
 
@@ -623,9 +852,9 @@ impl Shape for Triangle {
             // Compute shading normal ns for triangle.
             let mut ns = isect.hit.n;
             if has_vertex_normals {
-                let ns2 = b0 * self.mesh.n[self.v]
-                    + b1 * self.mesh.n[self.v + 1]
-                    + b2 * self.mesh.n[self.v + 2];
+                let ns2 = b0 * self.mesh.normal(self.vertex(0))
+                    + b1 * self.mesh.normal(self.vertex(1))
+                    + b2 * self.mesh.normal(self.vertex(2));
                 if ns2.length_squared() > 0.0 {
                     ns = ns2.normalize();
                 }
@@ -634,9 +863,9 @@ impl Shape for Triangle {
             // Compute shading tangent ss for triangle.
             let mut ss = isect.dpdu;
             if has_vertex_tangents {
-                let ss2 = b0 * self.mesh.s[self.v]
-                    + b1 * self.mesh.s[self.v + 1]
-                    + b2 * self.mesh.s[self.v + 2];
+                let ss2 = b0 * self.mesh.s[self.vertex(0)]
+                    + b1 * self.mesh.s[self.vertex(1)]
+                    + b2 * self.mesh.s[self.vertex(2)];
                 if ss2.length_squared() > 0.0 {
                     ss = ss2;
                 }
@@ -659,8 +888,8 @@ impl Shape for Triangle {
                 // Compute deltas for triangle partial derivatives of normal
                 let duv02 = uv[0] - uv[2];
                 let duv12 = uv[1] - uv[2];
-                let dn1 = self.mesh.n[self.v] - self.mesh.n[self.v + 2];
-                let dn2 = self.mesh.n[self.v + 1] - self.mesh.n[self.v + 2];
+                let dn1 = self.mesh.normal(self.vertex(0)) - self.mesh.normal(self.vertex(2));
+                let dn2 = self.mesh.normal(self.vertex(1)) - self.mesh.normal(self.vertex(2));
 
                 let determinant = duv02[0] * duv12[1] - duv02[1] * duv12[0];
                 let degenerate_uv = determinant.abs() < 1e-8;
@@ -671,9 +900,12 @@ impl Shape for Triangle {
                     // (rather than giving up) so that ray differentials for
                     // rays reflected from triangles with degenerate
                     // parameterizations are still reasonable.
-                    let dn = Vector3::from(self.mesh.n[self.v + 2] - self.mesh.n[self.v]).cross(
-                        &Vector3::from(self.mesh.n[self.v + 1] - self.mesh.n[self.v]),
-                    );
+                    let dn = Vector3::from(
+                        self.mesh.normal(self.vertex(2)) - self.mesh.normal(self.vertex(0)),
+                    )
+                    .cross(&Vector3::from(
+                        self.mesh.normal(self.vertex(1)) - self.mesh.normal(self.vertex(0)),
+                    ));
                     if dn.length_squared() == 0.0 {
                         (Normal3f::default(), Normal3f::default())
                     } else {
@@ -691,11 +923,11 @@ impl Shape for Triangle {
                 (Normal3f::default(), Normal3f::default())
             };
 
-            if self.get_data().reverse_orientation {
-                ts = -ts;
-            }
-
             isect.set_shading_geometry(ss, ts, dndu, dndv, true);
+
+            if has_vertex_normals && self.mesh.smooth_terminator {
+                isect.hit.shading_offset = self.shading_offset(&p_hit, &[b0, b1, b2]);
+            }
         }
 
         Some(Intersection::new(t, isect))
@@ -707,9 +939,9 @@ impl Shape for Triangle {
     /// * `test_alpha_texture` - Perform alpha texture tests.
     fn intersect_p(&self, r: &Ray, test_alpha_texture: bool) -> bool {
         // Get triangle vertices in p0, p1, and p2
-        let p0 = self.mesh.p[self.v];
-        let p1 = self.mesh.p[self.v + 1];
-        let p2 = self.mesh.p[self.v + 2];
+        let p0 = self.mesh.p[self.vertex(0)];
+        let p1 = self.mesh.p[self.vertex(1)];
+        let p2 = self.mesh.p[self.vertex(2)];
 
         // Perform ray-triangle intersection test.
 
@@ -871,11 +1103,20 @@ impl Shape for Triangle {
         true
     }
 
+    /// Returns the vertices of the triangle in world space.
+    fn triangles(&self) -> Vec<[Point3f; 3]> {
+        vec![[
+            self.mesh.p[self.vertex(0)],
+            self.mesh.p[self.vertex(1)],
+            self.mesh.p[self.vertex(2)],
+        ]]
+    }
+
     /// Returns the surface area of the shape in object space.
     fn area(&self) -> Float {
-        let p0 = self.mesh.p[self.v];
-        let p1 = self.mesh.p[self.v + 1];
-        let p2 = self.mesh.p[self.v + 2];
+        let p0 = self.mesh.p[self.vertex(0)];
+        let p1 = self.mesh.p[self.vertex(1)];
+        let p2 = self.mesh.p[self.vertex(2)];
         0.5 * (p1 - p0).cross(&(p2 - p0)).length()
     }
 
@@ -889,9 +1130,9 @@ impl Shape for Triangle {
         let b = uniform_sample_triangle(u);
 
         // Get triangle vertices in `p0`, `p1`, and `p2`.
-        let p0 = self.mesh.p[self.v];
-        let p1 = self.mesh.p[self.v + 1];
-        let p2 = self.mesh.p[self.v + 2];
+        let p0 = self.mesh.p[self.vertex(0)];
+        let p1 = self.mesh.p[self.vertex(1)];
+        let p2 = self.mesh.p[self.vertex(2)];
 
         let p = b[0] * p0 + b[1] * p1 + (1.0 - b[0] - b[1]) * p2;
 
@@ -902,9 +1143,9 @@ impl Shape for Triangle {
         // approach as was used in intersect().
         if self.mesh.n.len() > 0 {
             let ns = Vector3f::from(
-                b[0] * self.mesh.n[self.v]
-                    + b[1] * self.mesh.n[self.v + 1]
-                    + (1.0 - b[0] - b[1]) * self.mesh.n[self.v + 2],
+                b[0] * self.mesh.normal(self.vertex(0))
+                    + b[1] * self.mesh.normal(self.vertex(1))
+                    + (1.0 - b[0] - b[1]) * self.mesh.normal(self.vertex(2)),
             );
             n = n.face_forward(&ns);
         } else if self.data.reverse_orientation ^ self.data.transform_swaps_handedness {
@@ -919,3 +1160,68 @@ impl Shape for Triangle {
         (it, pdf)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn smooth_terminator_moves_ray_origin_to_smooth_surface() {
+        // A flat triangle whose vertex normals tilt outwards like a dome.
+        let identity = Arc::new(Transform::default());
+        let p = vec![
+            Point3f::new(-1.0, -1.0, 0.0),
+            Point3f::new(1.0, -1.0, 0.0),
+            Point3f::new(0.0, 1.0, 0.0),
+        ];
+        let n: Vec<Normal3f> = p
+            .iter()
+            .map(|v| Normal3f::new(v.x, v.y, 2.0).normalize())
+            .collect();
+        let make = |smooth_terminator: bool| {
+            TriangleMesh::create(
+                identity.clone(),
+                identity.clone(),
+                false,
+                vec![0, 1, 2],
+                p.clone(),
+                n.clone(),
+                vec![],
+                vec![],
+                false,
+                None,
+                None,
+                vec![],
+                smooth_terminator,
+            )
+        };
+
+        let ray = Ray::new(
+            Point3f::new(0.0, -0.2, 1.0),
+            Vector3f::new(0.0, 0.0, -1.0),
+            INFINITY,
+            0.0,
+            None,
+        );
+        let hit = |smooth_terminator: bool| {
+            make(smooth_terminator)[0]
+                .intersect(&ray, false)
+                .unwrap()
+                .isect
+                .hit
+        };
+
+        // Rays leaving above the surface start from the dome, those going
+        // through it from the triangle.
+        let smooth = hit(true);
+        let up = Vector3f::new(0.3, 0.0, 1.0);
+        let down = Vector3f::new(0.3, 0.0, -1.0);
+        assert!(smooth.shading_offset.z > 0.01);
+        assert!(smooth.spawn_ray(&up).o.z > 0.01);
+        assert!(smooth.spawn_ray(&down).o.z <= 0.0);
+
+        let flat = hit(false);
+        assert_eq!(flat.shading_offset, Vector3f::default());
+        assert!(flat.spawn_ray(&up).o.z < 1e-4);
+    }
+}
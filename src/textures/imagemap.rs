@@ -2,6 +2,7 @@
 
 #![allow(dead_code)]
 use super::get_texture_mapping;
+use crate::core::error::*;
 use crate::core::geometry::*;
 use crate::core::mipmap::*;
 use crate::core::paramset::*;
@@ -9,6 +10,7 @@ use crate::core::pbrt::*;
 use crate::core::spectrum::*;
 use crate::core::texture::*;
 use crate::textures::*;
+use std::convert::TryFrom;
 use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign};
 
 /// Stores an image texture with MIPMaps using texels of type `Tmemory`.
@@ -24,7 +26,7 @@ where
         + Add<Tmemory, Output = Tmemory>
         + AddAssign
         + Clamp<Float>,
-    Spectrum: ConvertIn<Tmemory>,
+    RGBSpectrum: ConvertIn<Tmemory>,
 {
     /// 2D mapping.
     mapping: ArcTextureMapping2D,
@@ -36,7 +38,8 @@ where
 macro_rules! new_image_texture {
     ($t: ty) => {
         impl ImageTexture<$t> {
-            /// Create a new `ImageTexture<$ty>`.
+            /// Create a new `ImageTexture<$ty>`; or returns an error if the image
+            /// could not be loaded.
             ///
             /// * `mapping`          - The 2D mapping.
             /// * `path`             - The path to the image file.
@@ -54,7 +57,7 @@ macro_rules! new_image_texture {
                 scale: Float,
                 gamma: bool,
                 max_anisotropy: Float,
-            ) -> Self {
+            ) -> Result<Self, Error> {
                 let tex_info = TexInfo::new(
                     path,
                     filtering_method,
@@ -63,11 +66,8 @@ macro_rules! new_image_texture {
                     gamma,
                     max_anisotropy,
                 );
-                let mipmap = match MIPMapCache::get(tex_info) {
-                    Ok(mipmap) => mipmap,
-                    Err(err) => panic!("Unable to load MIPMap: {}", err),
-                };
-                Self { mapping, mipmap }
+                let mipmap = MIPMapCache::get(tex_info)?;
+                Ok(Self { mapping, mipmap })
             }
         }
     };
@@ -121,13 +121,15 @@ impl Texture<Float> for ImageTexture<Float> {
 
 macro_rules! from_params {
     ($t: ty) => {
-        impl From<(&TextureParams, &Transform)> for ImageTexture<$t> {
+        impl TryFrom<(&TextureParams, &Transform)> for ImageTexture<$t> {
+            type Error = Error;
+
             /// Create a `ImageTexture<$t>` from given parameter set and
             /// transformation from texture space to world space.
             ///
             /// * `p` - Tuple containing texture parameters and texture space
             ///         to world space transform.
-            fn from(p: (&TextureParams, &Transform)) -> Self {
+            fn try_from(p: (&TextureParams, &Transform)) -> Result<Self, Self::Error> {
                 let (tp, tex2world) = p;
 
                 // Initialize 2D texture mapping `map` from `tp`.
@@ -70,8 +70,7 @@ macro_rules! from_params {
                     .$get_texture_or_else_func("tex1", Arc::new(ConstantTexture::new(0.0.into())));
                 let tex2 = tp
                     .$get_texture_or_else_func("tex2", Arc::new(ConstantTexture::new(1.0.into())));
-                let amt =
-                    tp.get_float_texture_or_else("amount", Arc::new(ConstantTexture::new(0.5)));
+                let amt = tp.get_float_texture("amount", 0.5);
                 Self::new(tex1, tex2, amt)
             }
         }
@@ -0,0 +1,55 @@
+//! Renders with `--deterministic`. The options are global to the process, so
+//! these tests live in their own test binary where nothing has used them yet.
+
+#![cfg(feature = "parallel")]
+
+use pbr_rust::core::api::render_to_rgba;
+use pbr_rust::core::app::{set_options, Options, OPTIONS};
+
+/// Renders a scene with `--deterministic` on a pool of threads and returns
+/// the RGBA values of the image.
+///
+/// * `integrator` - The integrator directive of the scene.
+/// * `n_threads`  - Number of render threads.
+fn render(integrator: &str, n_threads: usize) -> Vec<u8> {
+    // Another test may have set the options already.
+    let _ = set_options(Options::from_args(["pbr-rust", "--deterministic"]));
+    assert!(OPTIONS.deterministic);
+
+    let scene = format!(
+        r#"
+        LookAt 0 0 5  0 0 0  0 1 0
+        Camera "perspective" "float fov" [30]
+        Film "image" "integer xresolution" [40] "integer yresolution" [24]
+        Sampler "random" "integer pixelsamples" [4]
+        Integrator {}
+        WorldBegin
+        LightSource "point" "rgb I" [10 10 10] "point from" [2 2 3]
+        Material "matte" "rgb Kd" [0.5 0.5 0.5]
+        Shape "sphere" "float radius" [1]
+        WorldEnd
+        "#,
+        integrator
+    );
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(n_threads)
+        .build()
+        .unwrap();
+    pool.install(|| render_to_rgba(&scene, |_, _| {}).unwrap().pixels)
+}
+
+#[test]
+fn images_do_not_depend_on_the_number_of_threads() {
+    let integrator = r#""volpath""#;
+    assert_eq!(render(integrator, 1), render(integrator, 4));
+}
+
+#[test]
+fn mlt_images_do_not_depend_on_the_number_of_threads() {
+    // Enough mutations per chain for several batches.
+    let integrator = r#""mlt" "integer bootstrapsamples" [1024] "integer chains" [4]
+        "integer mutationsperpixel" [64]"#;
+    let image = render(integrator, 1);
+    assert!(image.chunks(4).any(|p| p[1] > 0));
+    assert_eq!(image, render(integrator, 4));
+}